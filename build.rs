@@ -0,0 +1,9 @@
+// gRPC API（proto/）のコードを生成する
+// protocはprotoc-bin-vendoredに同梱のものを使うため、別途インストールする必要はない
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    tonic_build::configure()
+        .build_client(false)
+        .compile_protos(&["proto/bookstore/v1/order_service.proto"], &["proto"])?;
+    Ok(())
+}
@@ -0,0 +1,2 @@
+ALTER TABLE inventories
+    ADD COLUMN release_date DATE NULL AFTER quantity_on_hand;
@@ -0,0 +1,2 @@
+ALTER TABLE order_lines
+    ADD COLUMN fulfillment_type VARCHAR(10) NOT NULL DEFAULT 'Physical' AFTER unit_price_currency;
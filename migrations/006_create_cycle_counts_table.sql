@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS cycle_counts (
+    id CHAR(36) PRIMARY KEY,
+    status VARCHAR(20) NOT NULL,
+    approved_by VARCHAR(100),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_status (status)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS cycle_count_lines (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    cycle_count_id CHAR(36) NOT NULL,
+    book_id CHAR(36) NOT NULL,
+    counted_quantity INT UNSIGNED,
+    system_quantity INT UNSIGNED,
+    FOREIGN KEY (cycle_count_id) REFERENCES cycle_counts(id) ON DELETE CASCADE,
+    UNIQUE KEY uk_cycle_count_book (cycle_count_id, book_id),
+    INDEX idx_cycle_count_id (cycle_count_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
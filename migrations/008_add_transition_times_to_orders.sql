@@ -0,0 +1,4 @@
+ALTER TABLE orders
+    ADD COLUMN confirmed_at TIMESTAMP NULL AFTER status,
+    ADD COLUMN shipped_at TIMESTAMP NULL AFTER confirmed_at,
+    ADD COLUMN delivered_at TIMESTAMP NULL AFTER shipped_at;
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS customer_devices (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    customer_id CHAR(36) NOT NULL,
+    token VARCHAR(512) NOT NULL,
+    platform VARCHAR(20) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_customer_token (customer_id, token),
+    INDEX idx_customer_id (customer_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
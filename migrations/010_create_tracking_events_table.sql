@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS tracking_events (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    event_id CHAR(36) NOT NULL,
+    order_id CHAR(36) NOT NULL,
+    stage VARCHAR(20) NOT NULL,
+    occurred_at TIMESTAMP NOT NULL,
+    location VARCHAR(255),
+    description VARCHAR(500),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_event_id (event_id),
+    INDEX idx_order_id_occurred_at (order_id, occurred_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
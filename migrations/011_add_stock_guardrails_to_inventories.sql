@@ -0,0 +1,2 @@
+ALTER TABLE inventories
+    ADD COLUMN frozen BOOLEAN NOT NULL DEFAULT FALSE AFTER release_date;
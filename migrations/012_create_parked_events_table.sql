@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS parked_events (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    event_id CHAR(36) NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    handler VARCHAR(64) NOT NULL,
+    order_id CHAR(36) NOT NULL,
+    correlation_id CHAR(36) NOT NULL,
+    order_status VARCHAR(20) NOT NULL,
+    expected_status VARCHAR(20) NOT NULL,
+    payload LONGTEXT NOT NULL,
+    parked_at TIMESTAMP NOT NULL,
+    UNIQUE KEY uk_event_id_handler (event_id, handler),
+    INDEX idx_parked_at (parked_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,2 @@
+ALTER TABLE orders
+    ADD COLUMN event_sequence BIGINT UNSIGNED NOT NULL DEFAULT 0 AFTER delivered_at;
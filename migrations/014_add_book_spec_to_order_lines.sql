@@ -0,0 +1,5 @@
+ALTER TABLE order_lines
+    ADD COLUMN weight_grams INT UNSIGNED NULL AFTER fulfillment_type,
+    ADD COLUMN width_mm INT UNSIGNED NULL AFTER weight_grams,
+    ADD COLUMN height_mm INT UNSIGNED NULL AFTER width_mm,
+    ADD COLUMN thickness_mm INT UNSIGNED NULL AFTER height_mm;
@@ -0,0 +1,3 @@
+ALTER TABLE orders
+    ADD COLUMN recipient_name VARCHAR(255) NULL AFTER building,
+    ADD COLUMN recipient_phone VARCHAR(20) NULL AFTER recipient_name;
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS order_line_attributes (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    order_id CHAR(36) NOT NULL,
+    book_id CHAR(36) NOT NULL,
+    attribute_key VARCHAR(50) NOT NULL,
+    attribute_value VARCHAR(200) NOT NULL,
+    FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE,
+    UNIQUE KEY uk_order_book_attribute (order_id, book_id, attribute_key),
+    INDEX idx_order_id (order_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS book_prices (
+    book_id CHAR(36) PRIMARY KEY,
+    unit_price_amount BIGINT NOT NULL,
+    unit_price_currency VARCHAR(3) NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,2 @@
+ALTER TABLE inventories
+    ADD COLUMN waitlist_enabled BOOLEAN NOT NULL DEFAULT FALSE AFTER frozen;
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS waitlist_entries (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    order_id CHAR(36) NOT NULL,
+    customer_id CHAR(36) NOT NULL,
+    book_id CHAR(36) NOT NULL,
+    quantity INT UNSIGNED NOT NULL,
+    joined_at TIMESTAMP(6) NOT NULL,
+    UNIQUE KEY uk_order_book (order_id, book_id),
+    INDEX idx_book_id (book_id, id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS saga_compensations (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    event_id CHAR(36) NOT NULL,
+    order_id CHAR(36) NOT NULL,
+    failed_step VARCHAR(32) NOT NULL,
+    failure_reason TEXT NOT NULL,
+    occurred_at TIMESTAMP(6) NOT NULL,
+    UNIQUE KEY uk_event_id (event_id),
+    INDEX idx_failed_step (failed_step)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS inbox_messages (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    source VARCHAR(32) NOT NULL,
+    external_id VARCHAR(255) NOT NULL,
+    payload LONGTEXT NOT NULL,
+    received_at TIMESTAMP(6) NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    attempts INT UNSIGNED NOT NULL DEFAULT 0,
+    last_error TEXT NULL,
+    processed_at TIMESTAMP(6) NULL,
+    UNIQUE KEY uk_source_external_id (source, external_id),
+    INDEX idx_status_id (status, id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS legacy_order_imports (
+    legacy_order_no VARCHAR(64) PRIMARY KEY,
+    outcome VARCHAR(20) NOT NULL,
+    order_id CHAR(36) NULL,
+    payload LONGTEXT NOT NULL,
+    reasons TEXT NOT NULL,
+    recorded_at TIMESTAMP(6) NOT NULL,
+    INDEX idx_outcome_recorded_at (outcome, recorded_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
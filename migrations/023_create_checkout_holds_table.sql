@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS checkout_holds (
+    order_id CHAR(36) NOT NULL,
+    book_id CHAR(36) NOT NULL,
+    quantity INT UNSIGNED NOT NULL,
+    expires_at TIMESTAMP(6) NOT NULL,
+    PRIMARY KEY (order_id, book_id),
+    INDEX idx_expires_at (expires_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
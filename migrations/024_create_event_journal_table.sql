@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS event_journal (
+    position BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+    event_id CHAR(36) NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    aggregate_id VARCHAR(64) NOT NULL,
+    occurred_at TIMESTAMP(6) NOT NULL,
+    payload LONGTEXT NOT NULL,
+    UNIQUE KEY uk_event_id (event_id),
+    INDEX idx_occurred_at (occurred_at, position)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
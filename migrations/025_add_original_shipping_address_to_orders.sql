@@ -0,0 +1,2 @@
+ALTER TABLE orders
+    ADD COLUMN original_shipping_address TEXT NULL AFTER building;
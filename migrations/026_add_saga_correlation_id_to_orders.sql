@@ -0,0 +1,2 @@
+ALTER TABLE orders
+    ADD COLUMN saga_correlation_id CHAR(36) NULL AFTER event_sequence;
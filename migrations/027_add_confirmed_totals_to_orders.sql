@@ -0,0 +1,2 @@
+ALTER TABLE orders
+    ADD COLUMN confirmed_totals TEXT NULL AFTER saga_correlation_id;
@@ -0,0 +1,3 @@
+ALTER TABLE orders
+    ADD COLUMN tracking_token CHAR(64) NULL AFTER confirmed_totals,
+    ADD UNIQUE INDEX idx_orders_tracking_token (tracking_token);
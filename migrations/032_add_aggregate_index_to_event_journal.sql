@@ -0,0 +1,2 @@
+ALTER TABLE event_journal
+    ADD INDEX idx_aggregate_id (aggregate_id, position);
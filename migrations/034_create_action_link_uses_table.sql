@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS action_link_uses (
+    id CHAR(36) PRIMARY KEY,
+    link_id CHAR(36) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    order_id CHAR(36) NOT NULL,
+    expires_at TIMESTAMP(6) NOT NULL,
+    outcome VARCHAR(20) NOT NULL,
+    error TEXT NULL,
+    used_at TIMESTAMP(6) NOT NULL,
+    INDEX idx_order_used_at (order_id, used_at),
+    INDEX idx_link_id (link_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS order_locks (
+    order_id CHAR(36) PRIMARY KEY,
+    locked_by VARCHAR(255) NOT NULL,
+    locked_at TIMESTAMP(6) NOT NULL,
+    expires_at TIMESTAMP(6) NOT NULL,
+    INDEX idx_expires_at (expires_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS processed_events (
+    event_id CHAR(36) NOT NULL,
+    handler_name VARCHAR(100) NOT NULL,
+    processed_at TIMESTAMP(6) NOT NULL,
+    PRIMARY KEY (event_id, handler_name),
+    INDEX idx_processed_at (processed_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
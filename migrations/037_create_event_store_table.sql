@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS event_store (
+    stream_id VARCHAR(64) NOT NULL,
+    version BIGINT UNSIGNED NOT NULL,
+    event_id CHAR(36) NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    occurred_at TIMESTAMP(6) NOT NULL,
+    payload LONGTEXT NOT NULL,
+    PRIMARY KEY (stream_id, version),
+    UNIQUE KEY uk_event_id (event_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,2 @@
+ALTER TABLE orders
+    ADD COLUMN version BIGINT UNSIGNED NOT NULL DEFAULT 1 AFTER tracking_token;
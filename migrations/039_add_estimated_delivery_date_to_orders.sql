@@ -0,0 +1,2 @@
+ALTER TABLE orders
+    ADD COLUMN estimated_delivery_date DATE NULL AFTER delivered_at;
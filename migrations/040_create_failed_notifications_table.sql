@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS failed_notifications (
+    id CHAR(36) PRIMARY KEY,
+    channel VARCHAR(20) NOT NULL,
+    order_id CHAR(36) NOT NULL,
+    phone VARCHAR(20) NULL,
+    event_type VARCHAR(64) NOT NULL,
+    message TEXT NOT NULL,
+    correlation_id CHAR(36) NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    attempts INT UNSIGNED NOT NULL,
+    last_error TEXT NOT NULL,
+    next_attempt_at TIMESTAMP(6) NULL,
+    created_at TIMESTAMP(6) NOT NULL,
+    updated_at TIMESTAMP(6) NOT NULL,
+    delivered_at TIMESTAMP(6) NULL,
+    INDEX idx_status_next_attempt_at (status, next_attempt_at),
+    INDEX idx_status_updated_at (status, updated_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
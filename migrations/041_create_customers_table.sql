@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS customers (
+    id CHAR(36) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    email VARCHAR(254) NOT NULL,
+    postal_code VARCHAR(7) NULL,
+    prefecture VARCHAR(50) NULL,
+    city VARCHAR(100) NULL,
+    street VARCHAR(200) NULL,
+    building VARCHAR(200) NULL,
+    registered_at TIMESTAMP(6) NOT NULL,
+    UNIQUE INDEX idx_email (email)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,2 @@
+ALTER TABLE orders
+    ADD COLUMN hold_reason VARCHAR(32) NULL AFTER estimated_delivery_date;
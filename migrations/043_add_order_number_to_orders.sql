@@ -0,0 +1,3 @@
+ALTER TABLE orders
+    ADD COLUMN order_number VARCHAR(32) NULL AFTER id,
+    ADD UNIQUE INDEX idx_orders_order_number (order_number);
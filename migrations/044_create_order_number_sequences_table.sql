@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS order_number_sequences (
+    year SMALLINT UNSIGNED PRIMARY KEY,
+    last_value BIGINT UNSIGNED NOT NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
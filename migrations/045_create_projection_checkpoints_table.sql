@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS projection_checkpoints (
+    projection_name VARCHAR(100) NOT NULL,
+    aggregate_id VARCHAR(36) NOT NULL,
+    last_sequence_number BIGINT UNSIGNED NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    PRIMARY KEY (projection_name, aggregate_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
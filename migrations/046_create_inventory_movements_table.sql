@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS inventory_movements (
+    version BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+    book_id CHAR(36) NOT NULL,
+    quantity_delta BIGINT NOT NULL,
+    quantity_on_hand INT UNSIGNED NOT NULL,
+    recorded_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_book_id (book_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
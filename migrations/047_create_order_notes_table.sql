@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS order_notes (
+    note_id CHAR(36) PRIMARY KEY,
+    order_id CHAR(36) NOT NULL,
+    author VARCHAR(255) NOT NULL,
+    body TEXT NOT NULL,
+    created_at TIMESTAMP(6) NOT NULL,
+    INDEX idx_order_id_created_at (order_id, created_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS subscription_registry (
+    handler_name VARCHAR(255) PRIMARY KEY,
+    event_types VARCHAR(1024) NOT NULL,
+    state VARCHAR(32) NOT NULL,
+    paused_events VARCHAR(32) NULL,
+    filter_description VARCHAR(1024) NULL,
+    delivery_guarantee VARCHAR(32) NOT NULL,
+    updated_at TIMESTAMP(6) NOT NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS status_override_audits (
+    audit_id CHAR(36) PRIMARY KEY,
+    order_id CHAR(36) NOT NULL,
+    from_status VARCHAR(32) NOT NULL,
+    to_status VARCHAR(32) NOT NULL,
+    reason TEXT NOT NULL,
+    operator VARCHAR(255) NOT NULL,
+    event_id CHAR(36) NOT NULL,
+    created_at TIMESTAMP(6) NOT NULL,
+    INDEX idx_order_id_created_at (order_id, created_at)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,2 @@
+ALTER TABLE orders
+    ADD COLUMN sales_channel VARCHAR(32) NOT NULL DEFAULT 'Online' AFTER customer_id;
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS allocation_quotas (
+    book_id CHAR(36) PRIMARY KEY,
+    per_customer_cap INT UNSIGNED NULL,
+    channel_shares TEXT NOT NULL,
+    allocation_pool INT UNSIGNED NOT NULL,
+    channel_allocated TEXT NOT NULL,
+    updated_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
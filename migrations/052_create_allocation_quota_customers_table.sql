@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS allocation_quota_customers (
+    book_id CHAR(36) NOT NULL,
+    customer_id CHAR(36) NOT NULL,
+    quantity INT UNSIGNED NOT NULL,
+    PRIMARY KEY (book_id, customer_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
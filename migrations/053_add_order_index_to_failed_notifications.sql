@@ -0,0 +1,2 @@
+ALTER TABLE failed_notifications
+    ADD INDEX idx_order_id (order_id, created_at);
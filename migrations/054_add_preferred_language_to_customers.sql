@@ -0,0 +1,2 @@
+ALTER TABLE customers
+    ADD COLUMN preferred_language VARCHAR(8) NOT NULL DEFAULT 'ja' AFTER building;
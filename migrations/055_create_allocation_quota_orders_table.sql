@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS allocation_quota_orders (
+    book_id CHAR(36) NOT NULL,
+    order_id CHAR(36) NOT NULL,
+    customer_id CHAR(36) NOT NULL,
+    sales_channel VARCHAR(16) NOT NULL,
+    quantity INT UNSIGNED NOT NULL,
+    PRIMARY KEY (book_id, order_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,5 @@
+-- 購入制限の判定を顧客ごとに直列化するためのロック行
+-- 注文の作成・書籍の追加で購入制限を確認する保存は、集計の前にこの行を排他ロックする
+CREATE TABLE IF NOT EXISTS customer_purchase_locks (
+    customer_id CHAR(36) NOT NULL PRIMARY KEY
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,3 @@
+-- 在庫数が負にならないことをデータベースでも検査する
+ALTER TABLE inventories
+    ADD CONSTRAINT chk_inventories_quantity_on_hand_non_negative CHECK (quantity_on_hand >= 0);
@@ -0,0 +1,87 @@
+-- SQLite版のスキーマ（デモ・テスト用）
+-- 注文・在庫・書籍カタログのリポジトリが使うテーブルだけを、MySQLのマイグレーション（054まで）を適用した後の列構成で作成する
+-- 日時はsqlxがRFC3339の文字列で保存するため、文字列の比較で前後関係を判定できる
+
+CREATE TABLE IF NOT EXISTS orders (
+    id TEXT PRIMARY KEY,
+    order_number TEXT UNIQUE,
+    customer_id TEXT NOT NULL,
+    sales_channel TEXT NOT NULL DEFAULT 'Online',
+    status TEXT NOT NULL,
+    confirmed_at TEXT,
+    shipped_at TEXT,
+    delivered_at TEXT,
+    estimated_delivery_date TEXT,
+    hold_reason TEXT,
+    event_sequence INTEGER NOT NULL DEFAULT 0,
+    saga_correlation_id TEXT,
+    confirmed_totals TEXT,
+    tracking_token TEXT UNIQUE,
+    version INTEGER NOT NULL DEFAULT 1,
+    postal_code TEXT,
+    prefecture TEXT,
+    city TEXT,
+    street TEXT,
+    building TEXT,
+    original_shipping_address TEXT,
+    recipient_name TEXT,
+    recipient_phone TEXT,
+    created_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_orders_customer_id ON orders (customer_id);
+CREATE INDEX IF NOT EXISTS idx_orders_status ON orders (status);
+
+CREATE TABLE IF NOT EXISTS order_lines (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    order_id TEXT NOT NULL REFERENCES orders (id) ON DELETE CASCADE,
+    book_id TEXT NOT NULL,
+    quantity INTEGER NOT NULL,
+    unit_price_amount INTEGER NOT NULL,
+    unit_price_currency TEXT NOT NULL DEFAULT 'JPY',
+    fulfillment_type TEXT NOT NULL DEFAULT 'Physical',
+    weight_grams INTEGER,
+    width_mm INTEGER,
+    height_mm INTEGER,
+    thickness_mm INTEGER,
+    UNIQUE (order_id, book_id)
+);
+
+CREATE TABLE IF NOT EXISTS order_line_attributes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    order_id TEXT NOT NULL REFERENCES orders (id) ON DELETE CASCADE,
+    book_id TEXT NOT NULL,
+    attribute_key TEXT NOT NULL,
+    attribute_value TEXT NOT NULL,
+    UNIQUE (order_id, book_id, attribute_key)
+);
+
+CREATE TABLE IF NOT EXISTS inventories (
+    book_id TEXT PRIMARY KEY,
+    quantity_on_hand INTEGER NOT NULL CHECK (quantity_on_hand >= 0),
+    release_date TEXT,
+    frozen BOOLEAN NOT NULL DEFAULT FALSE,
+    waitlist_enabled BOOLEAN NOT NULL DEFAULT FALSE
+);
+CREATE INDEX IF NOT EXISTS idx_inventories_quantity ON inventories (quantity_on_hand);
+
+CREATE TABLE IF NOT EXISTS inventory_movements (
+    version INTEGER PRIMARY KEY AUTOINCREMENT,
+    book_id TEXT NOT NULL,
+    quantity_delta INTEGER NOT NULL,
+    quantity_on_hand INTEGER NOT NULL,
+    recorded_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_inventory_movements_book_id ON inventory_movements (book_id);
+
+CREATE TABLE IF NOT EXISTS book_prices (
+    book_id TEXT PRIMARY KEY,
+    unit_price_amount INTEGER NOT NULL,
+    unit_price_currency TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS book_translations (
+    book_id TEXT NOT NULL,
+    language TEXT NOT NULL,
+    title TEXT NOT NULL,
+    PRIMARY KEY (book_id, language)
+);
@@ -1,8 +1,59 @@
+pub mod action_link_config;
+pub mod alerting_config;
+pub mod app_config;
+pub mod auth_config;
+pub mod bootstrap;
+pub mod cancellation_config;
+pub mod checkout_hold_config;
 pub mod database_config;
 pub mod database_error;
 pub mod database_migration;
+pub mod dataset_anonymizer;
+pub mod delivery_estimate_config;
 pub mod driven;
 pub mod driver;
+pub mod event_export_config;
+pub mod fulfillment_config;
+pub mod grpc_config;
+pub mod late_event_config;
+pub mod notification_config;
+pub mod order_number_config;
+pub mod pricing_config;
+pub mod purchase_limit_config;
+pub mod repositories;
+pub mod risk_hold_config;
+pub mod saga_config;
+pub mod segmentation_config;
+pub mod shipping_fee_config;
+pub mod sla_config;
+pub mod sqlite_database;
+pub mod startup_diagnostics;
+pub mod telemetry;
 
+pub use action_link_config::ActionLinkConfig;
+pub use alerting_config::{AlertChannel, AlertingConfig};
+pub use app_config::{AppConfig, CorsPolicy, Profile};
+pub use auth_config::AuthConfig;
+pub use bootstrap::{build_application, Application};
+pub use cancellation_config::CancellationConfig;
+pub use checkout_hold_config::CheckoutHoldConfig;
 pub use database_config::DatabaseConfig;
 pub use database_migration::DatabaseMigration;
+pub use dataset_anonymizer::{AnonymizationReport, DatasetAnonymizer};
+pub use delivery_estimate_config::DeliveryEstimateConfig;
+pub use event_export_config::{EventExportConfig, ExportStorage};
+pub use fulfillment_config::FulfillmentConfig;
+pub use grpc_config::GrpcConfig;
+pub use late_event_config::LateEventConfig;
+pub use notification_config::NotificationConfig;
+pub use order_number_config::OrderNumberConfig;
+pub use pricing_config::PricingConfig;
+pub use purchase_limit_config::PurchaseLimitConfig;
+pub use repositories::{Repositories, StorageBackend};
+pub use risk_hold_config::RiskHoldConfig;
+pub use saga_config::SagaConfig;
+pub use segmentation_config::SegmentationConfig;
+pub use shipping_fee_config::ShippingFeeConfig;
+pub use sla_config::SlaConfig;
+pub use sqlite_database::SqliteDatabase;
+pub use startup_diagnostics::StartupDiagnostics;
@@ -0,0 +1,79 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use chrono::TimeDelta;
+use std::env;
+
+/// アクションリンクの有効期間のデフォルト値（時間）
+const DEFAULT_ACTION_LINK_TTL_HOURS: i64 = 72;
+/// 署名用のシークレットの最小文字数
+const MIN_ACTION_LINK_SECRET_LENGTH: usize = 32;
+
+/// 通知に載せるアクションリンクの設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct ActionLinkConfig {
+    /// 署名用のシークレット（未設定の場合は起動ごとに生成し、再起動前に発行したリンクは無効になる）
+    pub secret: Option<String>,
+    /// リンクのベースURL
+    pub base_url: String,
+    /// リンクの有効期間
+    pub ttl: TimeDelta,
+}
+
+impl ActionLinkConfig {
+    /// 環境変数から設定を読み取る
+    /// - ACTION_LINK_SECRET: リンクの署名（HMAC-SHA256）に使うシークレット（32文字以上）
+    /// - ACTION_LINK_BASE_URL: リンクのベースURL（デフォルト: http://localhost:3000）
+    /// - ACTION_LINK_TTL_HOURS: リンクの有効期間（時間）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let secret = env::var("ACTION_LINK_SECRET").ok();
+        if secret
+            .as_ref()
+            .is_some_and(|secret| secret.chars().count() < MIN_ACTION_LINK_SECRET_LENGTH)
+        {
+            return Err(ConfigError::InvalidValue(format!(
+                "ACTION_LINK_SECRET must be at least {} characters",
+                MIN_ACTION_LINK_SECRET_LENGTH
+            )));
+        }
+
+        let hours: i64 = parse_env("ACTION_LINK_TTL_HOURS", DEFAULT_ACTION_LINK_TTL_HOURS)?;
+        if hours <= 0 {
+            return Err(ConfigError::InvalidValue(
+                "ACTION_LINK_TTL_HOURS must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            secret,
+            base_url: env::var("ACTION_LINK_BASE_URL")
+                .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+            ttl: TimeDelta::hours(hours),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env_parses_secret_and_ttl() {
+        env::set_var("ACTION_LINK_SECRET", "0123456789abcdef0123456789abcdef");
+        env::set_var("ACTION_LINK_TTL_HOURS", "24");
+        let config = ActionLinkConfig::from_env().unwrap();
+        assert_eq!(
+            config.secret.as_deref(),
+            Some("0123456789abcdef0123456789abcdef")
+        );
+        assert_eq!(config.ttl, TimeDelta::hours(24));
+
+        env::set_var("ACTION_LINK_SECRET", "too-short");
+        assert!(ActionLinkConfig::from_env().is_err());
+
+        env::remove_var("ACTION_LINK_SECRET");
+        env::remove_var("ACTION_LINK_TTL_HOURS");
+        let config = ActionLinkConfig::from_env().unwrap();
+        assert!(config.secret.is_none());
+        assert_eq!(config.ttl, TimeDelta::hours(72));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::adapter::database_config::ConfigError;
+use crate::domain::alerting::AnomalyThresholds;
+use std::env;
+use std::time::Duration;
+
+/// アラートの送信先
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlertChannel {
+    Console,
+    Webhook(String),
+    Slack(String),
+}
+
+/// アラート設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct AlertingConfig {
+    pub channel: AlertChannel,
+    pub thresholds: AnomalyThresholds,
+}
+
+/// 環境変数を数値として読み取る（未設定の場合はデフォルト値）
+pub(crate) fn parse_env<T: std::str::FromStr>(name: &str, default: T) -> Result<T, ConfigError>
+where
+    T::Err: std::fmt::Display,
+{
+    match env::var(name) {
+        Ok(value) => value
+            .parse::<T>()
+            .map_err(|e| ConfigError::InvalidValue(format!("Invalid {}: {}", name, e))),
+        Err(_) => Ok(default),
+    }
+}
+
+/// 送信先URLの環境変数を読み取る
+fn required_url(name: &str) -> Result<String, ConfigError> {
+    env::var(name).map_err(|_| {
+        ConfigError::InvalidValue(format!("{} is required for this ALERT_CHANNEL", name))
+    })
+}
+
+impl AlertingConfig {
+    /// 環境変数から設定を読み取る
+    /// 環境変数が設定されていない場合はデフォルト値を使用
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let channel = match env::var("ALERT_CHANNEL")
+            .unwrap_or_else(|_| "console".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "console" => AlertChannel::Console,
+            "webhook" => AlertChannel::Webhook(required_url("ALERT_WEBHOOK_URL")?),
+            "slack" => AlertChannel::Slack(required_url("ALERT_SLACK_WEBHOOK_URL")?),
+            other => {
+                return Err(ConfigError::InvalidValue(format!(
+                    "Invalid ALERT_CHANNEL: {}",
+                    other
+                )))
+            }
+        };
+
+        let defaults = AnomalyThresholds::default();
+        let thresholds = AnomalyThresholds {
+            window: Duration::from_secs(parse_env(
+                "ALERT_WINDOW_SECONDS",
+                defaults.window.as_secs(),
+            )?),
+            check_interval: Duration::from_secs(parse_env(
+                "ALERT_CHECK_INTERVAL_SECONDS",
+                defaults.check_interval.as_secs(),
+            )?),
+            reservation_failure_rate: parse_env(
+                "ALERT_RESERVATION_FAILURE_RATE",
+                defaults.reservation_failure_rate,
+            )?,
+            compensation_rate: parse_env("ALERT_COMPENSATION_RATE", defaults.compensation_rate)?,
+            dead_letter_count: parse_env("ALERT_DEAD_LETTER_COUNT", defaults.dead_letter_count)?,
+            min_sample_size: parse_env("ALERT_MIN_SAMPLE_SIZE", defaults.min_sample_size)?,
+        };
+
+        if thresholds.check_interval.is_zero() {
+            return Err(ConfigError::InvalidValue(
+                "ALERT_CHECK_INTERVAL_SECONDS must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            channel,
+            thresholds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // テスト間の環境変数の競合を防ぐためのロック
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    const ALL_VARS: [&str; 9] = [
+        "ALERT_CHANNEL",
+        "ALERT_WEBHOOK_URL",
+        "ALERT_SLACK_WEBHOOK_URL",
+        "ALERT_WINDOW_SECONDS",
+        "ALERT_CHECK_INTERVAL_SECONDS",
+        "ALERT_RESERVATION_FAILURE_RATE",
+        "ALERT_COMPENSATION_RATE",
+        "ALERT_DEAD_LETTER_COUNT",
+        "ALERT_MIN_SAMPLE_SIZE",
+    ];
+
+    fn clear_env() {
+        for name in ALL_VARS {
+            env::remove_var(name);
+        }
+    }
+
+    #[test]
+    fn test_from_env_with_defaults() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        clear_env();
+
+        let config = AlertingConfig::from_env().unwrap();
+
+        assert_eq!(config.channel, AlertChannel::Console);
+        assert_eq!(config.thresholds.window, Duration::from_secs(600));
+        assert_eq!(config.thresholds.reservation_failure_rate, 0.05);
+    }
+
+    #[test]
+    fn test_from_env_with_slack_channel() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        clear_env();
+        env::set_var("ALERT_CHANNEL", "slack");
+        env::set_var(
+            "ALERT_SLACK_WEBHOOK_URL",
+            "https://hooks.slack.com/services/test",
+        );
+        env::set_var("ALERT_RESERVATION_FAILURE_RATE", "0.1");
+
+        let config = AlertingConfig::from_env().unwrap();
+
+        assert_eq!(
+            config.channel,
+            AlertChannel::Slack("https://hooks.slack.com/services/test".to_string())
+        );
+        assert_eq!(config.thresholds.reservation_failure_rate, 0.1);
+
+        clear_env();
+    }
+
+    #[test]
+    fn test_webhook_channel_requires_url() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        clear_env();
+        env::set_var("ALERT_CHANNEL", "webhook");
+
+        assert!(AlertingConfig::from_env().is_err());
+
+        clear_env();
+    }
+}
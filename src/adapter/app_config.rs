@@ -0,0 +1,689 @@
+use crate::adapter::auth_config::AuthConfig;
+use crate::adapter::database_config::{ConfigError, DatabaseConfig};
+use crate::adapter::event_export_config::EventExportConfig;
+use crate::adapter::driven::EventBusConfig;
+use crate::domain::event_export::DEFAULT_EXPORT_CHUNK_SIZE;
+use crate::domain::retry_policy::{DeadLetterRouting, RetryPolicy};
+use axum::http::{HeaderValue, Method};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+use tracing_subscriber::EnvFilter;
+
+/// APP_CONFIG_FILEが未設定の場合に読み込む設定ファイル（存在しない場合は読み込まない）
+const DEFAULT_CONFIG_FILE: &str = "config/app.toml";
+
+/// 実行環境のプロファイル
+/// サーバーの待ち受けアドレス・CORS・ログレベルのデフォルト値と、設定ファイルの[profiles.*]のどれを適用するかを決める
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Profile {
+    /// 開発環境（すべてのオリジンを許可し、アプリケーションのログをデバッグレベルから出力する）
+    #[default]
+    Dev,
+    /// テスト環境（ループバックの空いているポートで待ち受け、警告以上のログだけを出力する）
+    Test,
+    /// 本番環境（許可したオリジン以外からのブラウザのリクエストを受け付けない）
+    Prod,
+}
+
+impl Profile {
+    pub fn from_string(value: &str) -> Result<Self, ConfigError> {
+        match value {
+            "dev" => Ok(Profile::Dev),
+            "test" => Ok(Profile::Test),
+            "prod" => Ok(Profile::Prod),
+            _ => Err(ConfigError::InvalidValue(format!(
+                "Invalid profile: {} (expected dev / test / prod)",
+                value
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Dev => "dev",
+            Profile::Test => "test",
+            Profile::Prod => "prod",
+        }
+    }
+
+    fn default_bind_address(&self) -> SocketAddr {
+        match self {
+            Profile::Dev | Profile::Prod => SocketAddr::from(([0, 0, 0, 0], 3000)),
+            Profile::Test => SocketAddr::from(([127, 0, 0, 1], 0)),
+        }
+    }
+
+    fn default_cors(&self) -> CorsPolicy {
+        match self {
+            Profile::Dev | Profile::Test => CorsPolicy::AllowAny,
+            Profile::Prod => CorsPolicy::SameOrigin,
+        }
+    }
+
+    /// 認証のシークレットを設定せずに起動できるか（ローカルでの動作確認とテストのみ認証なしで動かせる）
+    fn allows_unauthenticated(&self) -> bool {
+        matches!(self, Profile::Dev | Profile::Test)
+    }
+
+    fn default_log_filter(&self) -> &'static str {
+        match self {
+            Profile::Dev => "info,bookstore_order_management=debug",
+            Profile::Test => "warn",
+            Profile::Prod => "info",
+        }
+    }
+}
+
+/// CORSのポリシー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CorsPolicy {
+    /// すべてのオリジンからのリクエストを許可する
+    AllowAny,
+    /// 指定したオリジンからのリクエストだけを許可する
+    AllowOrigins(Vec<String>),
+    /// 別のオリジンからのリクエストを許可しない（CORSのヘッダーを返さない）
+    SameOrigin,
+}
+
+impl CorsPolicy {
+    /// 許可するオリジンの一覧から作成（"*"はすべてのオリジン、空の場合は同一オリジンのみ）
+    fn from_origins(origins: Vec<String>) -> Result<Self, ConfigError> {
+        let origins: Vec<String> = origins
+            .into_iter()
+            .map(|origin| origin.trim().to_string())
+            .filter(|origin| !origin.is_empty())
+            .collect();
+        if origins.iter().any(|origin| origin == "*") {
+            if origins.len() > 1 {
+                return Err(ConfigError::InvalidValue(
+                    "CORS allowed origins cannot combine \"*\" with specific origins".to_string(),
+                ));
+            }
+            return Ok(CorsPolicy::AllowAny);
+        }
+        if origins.is_empty() {
+            return Ok(CorsPolicy::SameOrigin);
+        }
+        for origin in &origins {
+            let is_http = origin.starts_with("http://") || origin.starts_with("https://");
+            if !is_http || HeaderValue::from_str(origin).is_err() {
+                return Err(ConfigError::InvalidValue(format!(
+                    "Invalid CORS origin: {}",
+                    origin
+                )));
+            }
+        }
+        Ok(CorsPolicy::AllowOrigins(origins))
+    }
+
+    /// REST APIに適用するCORSのレイヤーを作成
+    pub fn layer(&self) -> CorsLayer {
+        match self {
+            CorsPolicy::AllowAny => CorsLayer::permissive(),
+            CorsPolicy::AllowOrigins(origins) => CorsLayer::new()
+                .allow_origin(AllowOrigin::list(
+                    origins
+                        .iter()
+                        .filter_map(|origin| HeaderValue::from_str(origin).ok()),
+                ))
+                .allow_methods([
+                    Method::GET,
+                    Method::POST,
+                    Method::PUT,
+                    Method::PATCH,
+                    Method::DELETE,
+                ])
+                .allow_headers(Any),
+            CorsPolicy::SameOrigin => CorsLayer::new(),
+        }
+    }
+}
+
+/// アプリケーション全体の設定
+/// プロファイルのデフォルト値 → 設定ファイル（共通の値 → [profiles.<プロファイル>]の値） → 環境変数 の順に上書きして決める
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub profile: Profile,
+    /// 読み込んだ設定ファイル（読み込んでいない場合はNone）
+    pub config_file: Option<PathBuf>,
+    /// REST APIの待ち受けアドレス
+    pub bind_address: SocketAddr,
+    pub cors: CorsPolicy,
+    pub database: DatabaseConfig,
+    pub event_bus: EventBusConfig,
+    /// REST API・gRPCの認証
+    pub auth: AuthConfig,
+    /// イベントのエクスポート
+    pub event_export: EventExportConfig,
+    /// 出力するログ・スパンのレベル（tracingのEnvFilterの書式）
+    pub log_filter: String,
+}
+
+impl AppConfig {
+    /// 環境変数と設定ファイルから設定を読み取る
+    /// - APP_PROFILE: プロファイル（dev / test / prod、デフォルト: dev）
+    /// - APP_CONFIG_FILE: TOMLの設定ファイルのパス（デフォルト: config/app.toml、存在しない場合は読み込まない）
+    /// - SERVER_BIND_ADDRESS: REST APIの待ち受けアドレス（デフォルト: 0.0.0.0:3000、testでは127.0.0.1:0）
+    /// - CORS_ALLOWED_ORIGINS: 許可するオリジン（"*" / カンマ区切りのオリジン / 空文字で同一オリジンのみ、デフォルト: prod以外は"*"）
+    /// - RUST_LOG: 出力するログ・スパンのレベル
+    /// - DATABASE_* / EVENT_*: DatabaseConfig / EventBusConfig を参照
+    /// - AUTH_JWT_*: AuthConfig を参照（dev・test以外のプロファイルではAUTH_JWT_SECRETが必須）
+    /// - EVENT_EXPORT_*: EventExportConfig を参照
+    pub fn load() -> Result<Self, ConfigError> {
+        let profile = match env::var("APP_PROFILE") {
+            Ok(value) => Profile::from_string(value.trim())?,
+            Err(_) => Profile::default(),
+        };
+        let config_file = match env::var("APP_CONFIG_FILE") {
+            Ok(path) => Some(PathBuf::from(path)),
+            Err(_) => Some(PathBuf::from(DEFAULT_CONFIG_FILE)).filter(|path| path.exists()),
+        };
+        let file = match &config_file {
+            Some(path) => ConfigFile::read(path)?,
+            None => ConfigFile::default(),
+        };
+        let mut config = Self::resolve(profile, file)?;
+        config.config_file = config_file;
+        Ok(config)
+    }
+
+    /// TOMLの設定ファイルの内容と環境変数から設定を読み取る（環境変数が設定されている項目は環境変数を優先）
+    ///
+    /// # Arguments
+    /// * `profile` - 適用するプロファイル
+    /// * `toml` - 設定ファイルの内容
+    pub fn from_toml(profile: Profile, toml: &str) -> Result<Self, ConfigError> {
+        Self::resolve(profile, ConfigFile::parse(toml)?)
+    }
+
+    fn resolve(profile: Profile, file: ConfigFile) -> Result<Self, ConfigError> {
+        let settings = file.for_profile(profile);
+
+        let bind_address = match env::var("SERVER_BIND_ADDRESS") {
+            Ok(value) => parse_bind_address("SERVER_BIND_ADDRESS", &value)?,
+            Err(_) => match settings.server.bind_address {
+                Some(value) => parse_bind_address("server.bind_address", &value)?,
+                None => profile.default_bind_address(),
+            },
+        };
+
+        let cors = match env::var("CORS_ALLOWED_ORIGINS") {
+            Ok(value) => CorsPolicy::from_origins(value.split(',').map(str::to_string).collect())?,
+            Err(_) => match settings.cors.allowed_origins {
+                Some(origins) => CorsPolicy::from_origins(origins)?,
+                None => profile.default_cors(),
+            },
+        };
+
+        let log_filter = env::var("RUST_LOG")
+            .ok()
+            .or(settings.logging.level)
+            .unwrap_or_else(|| profile.default_log_filter().to_string());
+        EnvFilter::try_new(&log_filter).map_err(|e| {
+            ConfigError::InvalidValue(format!("Invalid log level {}: {}", log_filter, e))
+        })?;
+
+        let database = DatabaseConfig::from_env_with_defaults(
+            settings.database.apply(DatabaseConfig::default()),
+        )?;
+        let event_bus = EventBusConfig::from_env_with_retry_defaults(
+            settings.event_bus.retry.apply(RetryPolicy::default())?,
+        )?;
+
+        let event_export = EventExportConfig::from_env_with_defaults(
+            settings
+                .event_export
+                .chunk_size
+                .unwrap_or(DEFAULT_EXPORT_CHUNK_SIZE),
+            settings.event_export.nightly.unwrap_or(true),
+        )?;
+
+        let auth = AuthConfig::from_env_with_defaults(settings.auth.apply())?;
+        if !auth.is_enabled() && !profile.allows_unauthenticated() {
+            return Err(ConfigError::InvalidValue(format!(
+                "AUTH_JWT_SECRET (auth.jwt_secret) is required in the {} profile",
+                profile.as_str()
+            )));
+        }
+
+        Ok(Self {
+            profile,
+            config_file: None,
+            bind_address,
+            cors,
+            database,
+            event_bus,
+            auth,
+            event_export,
+            log_filter,
+        })
+    }
+}
+
+fn parse_bind_address(name: &str, value: &str) -> Result<SocketAddr, ConfigError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|e| ConfigError::InvalidValue(format!("Invalid {}: {} ({})", name, value, e)))
+}
+
+/// TOMLの設定ファイル
+/// 共通の値と、プロファイルごとに上書きする値（[profiles.prod.server] など）を持つ
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default)]
+    server: ServerSection,
+    #[serde(default)]
+    cors: CorsSection,
+    #[serde(default)]
+    database: DatabaseSection,
+    #[serde(default)]
+    event_bus: EventBusSection,
+    #[serde(default)]
+    logging: LoggingSection,
+    #[serde(default)]
+    auth: AuthSection,
+    #[serde(default)]
+    event_export: EventExportSection,
+    #[serde(default)]
+    profiles: HashMap<String, ConfigFile>,
+}
+
+impl ConfigFile {
+    fn read(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            ConfigError::InvalidValue(format!(
+                "Failed to read config file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::parse(&content).map_err(|e| {
+            ConfigError::InvalidValue(format!("{} (config file: {})", e, path.display()))
+        })
+    }
+
+    fn parse(content: &str) -> Result<Self, ConfigError> {
+        let file: Self = toml::from_str(content)
+            .map_err(|e| ConfigError::InvalidValue(format!("Invalid config file: {}", e)))?;
+        for (name, overrides) in &file.profiles {
+            Profile::from_string(name)?;
+            if !overrides.profiles.is_empty() {
+                return Err(ConfigError::InvalidValue(format!(
+                    "profiles.{} cannot contain nested profiles",
+                    name
+                )));
+            }
+        }
+        Ok(file)
+    }
+
+    /// 共通の値にプロファイルの値を上書きした設定
+    fn for_profile(mut self, profile: Profile) -> Self {
+        let Some(overrides) = self.profiles.remove(profile.as_str()) else {
+            return self;
+        };
+        Self {
+            server: ServerSection {
+                bind_address: overrides.server.bind_address.or(self.server.bind_address),
+            },
+            cors: CorsSection {
+                allowed_origins: overrides.cors.allowed_origins.or(self.cors.allowed_origins),
+            },
+            database: overrides.database.or(self.database),
+            event_bus: EventBusSection {
+                retry: overrides.event_bus.retry.or(self.event_bus.retry),
+            },
+            logging: LoggingSection {
+                level: overrides.logging.level.or(self.logging.level),
+            },
+            auth: overrides.auth.or(self.auth),
+            event_export: EventExportSection {
+                chunk_size: overrides
+                    .event_export
+                    .chunk_size
+                    .or(self.event_export.chunk_size),
+                nightly: overrides.event_export.nightly.or(self.event_export.nightly),
+            },
+            profiles: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ServerSection {
+    bind_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CorsSection {
+    allowed_origins: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LoggingSection {
+    level: Option<String>,
+}
+
+/// イベントのエクスポート（EVENT_EXPORT_CHUNK_SIZE・EVENT_EXPORT_NIGHTLYに対応）
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EventExportSection {
+    chunk_size: Option<usize>,
+    nightly: Option<bool>,
+}
+
+/// REST API・gRPCの認証（AUTH_JWT_*に対応）
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AuthSection {
+    jwt_secret: Option<String>,
+    issuer: Option<String>,
+    audience: Option<String>,
+}
+
+impl AuthSection {
+    /// 項目ごとにプロファイルの値を優先する
+    fn or(self, base: Self) -> Self {
+        Self {
+            jwt_secret: self.jwt_secret.or(base.jwt_secret),
+            issuer: self.issuer.or(base.issuer),
+            audience: self.audience.or(base.audience),
+        }
+    }
+
+    fn apply(self) -> AuthConfig {
+        AuthConfig {
+            jwt_secret: self.jwt_secret,
+            issuer: self.issuer,
+            audience: self.audience,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DatabaseSection {
+    host: Option<String>,
+    port: Option<u16>,
+    name: Option<String>,
+    user: Option<String>,
+    password: Option<String>,
+    max_connections: Option<u32>,
+    invariant_checks: Option<bool>,
+}
+
+impl DatabaseSection {
+    /// 項目ごとにプロファイルの値を優先する
+    fn or(self, base: Self) -> Self {
+        Self {
+            host: self.host.or(base.host),
+            port: self.port.or(base.port),
+            name: self.name.or(base.name),
+            user: self.user.or(base.user),
+            password: self.password.or(base.password),
+            max_connections: self.max_connections.or(base.max_connections),
+            invariant_checks: self.invariant_checks.or(base.invariant_checks),
+        }
+    }
+
+    /// 設定されている項目で上書きしたデータベース接続設定
+    fn apply(self, defaults: DatabaseConfig) -> DatabaseConfig {
+        DatabaseConfig {
+            host: self.host.unwrap_or(defaults.host),
+            port: self.port.unwrap_or(defaults.port),
+            database: self.name.unwrap_or(defaults.database),
+            username: self.user.unwrap_or(defaults.username),
+            password: self.password.unwrap_or(defaults.password),
+            max_connections: self.max_connections.unwrap_or(defaults.max_connections),
+            invariant_checks: self.invariant_checks.unwrap_or(defaults.invariant_checks),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EventBusSection {
+    #[serde(default)]
+    retry: RetrySection,
+}
+
+/// イベント処理の既定のリトライポリシー（EVENT_RETRY_*に対応）
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RetrySection {
+    max_attempts: Option<u32>,
+    backoff_ms: Option<u64>,
+    backoff_multiplier: Option<u32>,
+    max_backoff_ms: Option<u64>,
+    dead_letter: Option<String>,
+}
+
+impl RetrySection {
+    /// 項目ごとにプロファイルの値を優先する
+    fn or(self, base: Self) -> Self {
+        Self {
+            max_attempts: self.max_attempts.or(base.max_attempts),
+            backoff_ms: self.backoff_ms.or(base.backoff_ms),
+            backoff_multiplier: self.backoff_multiplier.or(base.backoff_multiplier),
+            max_backoff_ms: self.max_backoff_ms.or(base.max_backoff_ms),
+            dead_letter: self.dead_letter.or(base.dead_letter),
+        }
+    }
+
+    /// 設定されている項目で上書きしたリトライポリシー
+    fn apply(self, defaults: RetryPolicy) -> Result<RetryPolicy, ConfigError> {
+        let dead_letter = match self.dead_letter {
+            Some(value) => DeadLetterRouting::from_string(value.trim()).map_err(|_| {
+                ConfigError::InvalidValue(format!("Invalid event_bus.retry.dead_letter: {}", value))
+            })?,
+            None => defaults.dead_letter,
+        };
+        RetryPolicy::new(
+            self.max_attempts.unwrap_or(defaults.max_attempts),
+            self.backoff_ms.unwrap_or(defaults.initial_backoff_ms),
+            self.backoff_multiplier
+                .unwrap_or(defaults.backoff_multiplier),
+            self.max_backoff_ms.unwrap_or(defaults.max_backoff_ms),
+            dead_letter,
+        )
+        .map_err(|e| ConfigError::InvalidValue(format!("Invalid event_bus.retry: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::database_config::tests::ENV_LOCK;
+
+    const CONFIG: &str = r#"
+        [server]
+        bind_address = "0.0.0.0:8080"
+
+        [database]
+        host = "db.internal"
+        max_connections = 20
+
+        [event_bus.retry]
+        max_attempts = 5
+        dead_letter = "discard"
+
+        [profiles.prod.cors]
+        allowed_origins = ["https://shop.example.com"]
+
+        [profiles.prod.database]
+        max_connections = 50
+
+        [profiles.prod.logging]
+        level = "warn"
+
+        [event_export]
+        nightly = false
+
+        [profiles.prod.event_export]
+        nightly = true
+
+        [profiles.prod.auth]
+        jwt_secret = "0123456789abcdef0123456789abcdef"
+        issuer = "bookstore-auth"
+    "#;
+
+    #[test]
+    fn test_profile_overrides_common_values_from_file() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::remove_var("SERVER_BIND_ADDRESS");
+        env::remove_var("CORS_ALLOWED_ORIGINS");
+        env::remove_var("AUTH_JWT_SECRET");
+
+        let dev = AppConfig::from_toml(Profile::Dev, CONFIG).unwrap();
+        assert_eq!(dev.bind_address, SocketAddr::from(([0, 0, 0, 0], 8080)));
+        assert_eq!(dev.cors, CorsPolicy::AllowAny);
+        let retry = dev.event_bus.retry_policies.default_policy;
+        assert_eq!(retry.max_attempts, 5);
+        assert_eq!(retry.dead_letter, DeadLetterRouting::Discard);
+        assert_eq!(
+            retry.initial_backoff_ms,
+            RetryPolicy::default().initial_backoff_ms
+        );
+
+        let prod = AppConfig::from_toml(Profile::Prod, CONFIG).unwrap();
+        assert_eq!(prod.bind_address, SocketAddr::from(([0, 0, 0, 0], 8080)));
+        assert_eq!(
+            prod.cors,
+            CorsPolicy::AllowOrigins(vec!["https://shop.example.com".to_string()])
+        );
+        assert!(prod.auth.is_enabled());
+        assert_eq!(prod.auth.issuer.as_deref(), Some("bookstore-auth"));
+        assert!(!dev.auth.is_enabled());
+        assert!(!dev.event_export.nightly);
+        assert!(prod.event_export.nightly);
+
+        // 設定ファイルがない場合はプロファイルのデフォルト値
+        let test = AppConfig::from_toml(Profile::Test, "").unwrap();
+        assert_eq!(test.bind_address, SocketAddr::from(([127, 0, 0, 1], 0)));
+        assert_eq!(
+            AppConfig::from_toml(
+                Profile::Prod,
+                "[auth]\njwt_secret = \"0123456789abcdef0123456789abcdef\""
+            )
+            .unwrap()
+            .cors,
+            CorsPolicy::SameOrigin
+        );
+    }
+
+    #[test]
+    fn test_prod_profile_requires_auth_secret() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::remove_var("AUTH_JWT_SECRET");
+
+        // dev・testは認証なしで起動できるが、prodはシークレットがないと起動しない
+        assert!(AppConfig::from_toml(Profile::Dev, "").is_ok());
+        assert!(AppConfig::from_toml(Profile::Test, "").is_ok());
+        assert!(AppConfig::from_toml(Profile::Prod, "").is_err());
+
+        // 環境変数のシークレットは設定ファイルより優先する
+        env::set_var("AUTH_JWT_SECRET", "too-short");
+        assert!(AppConfig::from_toml(Profile::Prod, CONFIG).is_err());
+        env::set_var("AUTH_JWT_SECRET", "fedcba9876543210fedcba9876543210");
+        let config = AppConfig::from_toml(Profile::Prod, "").unwrap();
+        assert_eq!(
+            config.auth.jwt_secret.as_deref(),
+            Some("fedcba9876543210fedcba9876543210")
+        );
+        env::remove_var("AUTH_JWT_SECRET");
+    }
+
+    #[test]
+    fn test_file_sections_are_merged_per_item() {
+        let file = ConfigFile::parse(CONFIG)
+            .unwrap()
+            .for_profile(Profile::Prod);
+        let database = file.database.apply(DatabaseConfig::default());
+        assert_eq!(database.host, "db.internal");
+        assert_eq!(database.max_connections, 50);
+        assert_eq!(database.database, "bookstore_db");
+        assert_eq!(file.logging.level.as_deref(), Some("warn"));
+        assert_eq!(file.event_bus.retry.max_attempts, Some(5));
+    }
+
+    #[test]
+    fn test_environment_overrides_file() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::set_var("SERVER_BIND_ADDRESS", "127.0.0.1:9000");
+        env::set_var("CORS_ALLOWED_ORIGINS", "");
+        let config = AppConfig::from_toml(Profile::Dev, CONFIG).unwrap();
+        assert_eq!(
+            config.bind_address,
+            SocketAddr::from(([127, 0, 0, 1], 9000))
+        );
+        assert_eq!(config.cors, CorsPolicy::SameOrigin);
+
+        env::set_var("SERVER_BIND_ADDRESS", "localhost");
+        assert!(AppConfig::from_toml(Profile::Dev, CONFIG).is_err());
+        env::remove_var("SERVER_BIND_ADDRESS");
+        env::remove_var("CORS_ALLOWED_ORIGINS");
+    }
+
+    #[test]
+    fn test_example_config_file_is_valid() {
+        let file = ConfigFile::parse(include_str!("../../config/app.example.toml")).unwrap();
+        for profile in [Profile::Dev, Profile::Test, Profile::Prod] {
+            let settings = file.clone().for_profile(profile);
+            settings
+                .event_bus
+                .retry
+                .apply(RetryPolicy::default())
+                .unwrap();
+            CorsPolicy::from_origins(settings.cors.allowed_origins.unwrap_or_default()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_invalid_files_are_rejected() {
+        // 不明な項目・プロファイル
+        assert!(ConfigFile::parse("[server]\nport = 3000").is_err());
+        assert!(
+            ConfigFile::parse("[profiles.staging.server]\nbind_address = \"0.0.0.0:1\"").is_err()
+        );
+        // 試行回数0のリトライポリシー
+        let file = ConfigFile::parse("[event_bus.retry]\nmax_attempts = 0").unwrap();
+        assert!(file.event_bus.retry.apply(RetryPolicy::default()).is_err());
+    }
+
+    #[test]
+    fn test_cors_origins_are_validated() {
+        assert_eq!(
+            CorsPolicy::from_origins(vec!["*".to_string()]).unwrap(),
+            CorsPolicy::AllowAny
+        );
+        assert_eq!(
+            CorsPolicy::from_origins(vec![" ".to_string()]).unwrap(),
+            CorsPolicy::SameOrigin
+        );
+        assert!(CorsPolicy::from_origins(vec!["shop.example.com".to_string()]).is_err());
+        assert!(CorsPolicy::from_origins(vec![
+            "*".to_string(),
+            "https://shop.example.com".to_string()
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_profile_from_string() {
+        assert_eq!(Profile::from_string("prod").unwrap(), Profile::Prod);
+        assert_eq!(
+            Profile::from_string(Profile::Test.as_str()).unwrap(),
+            Profile::Test
+        );
+        assert!(Profile::from_string("production").is_err());
+    }
+}
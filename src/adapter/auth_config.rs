@@ -0,0 +1,77 @@
+use crate::adapter::database_config::ConfigError;
+use std::env;
+
+/// 署名用のシークレットの最小文字数
+const MIN_AUTH_JWT_SECRET_LENGTH: usize = 32;
+
+/// APIの認証（Bearerトークン）の設定を管理する構造体
+#[derive(Debug, Clone, Default)]
+pub struct AuthConfig {
+    /// JWTの署名（HS256）の検証に使うシークレット（未設定の場合は認証を行わない）
+    pub jwt_secret: Option<String>,
+    /// 受け付けるトークンの発行者（issクレーム、未設定の場合は検証しない）
+    pub issuer: Option<String>,
+    /// 受け付けるトークンの対象者（audクレーム、未設定の場合は検証しない）
+    pub audience: Option<String>,
+}
+
+impl AuthConfig {
+    /// 環境変数から設定を読み取る
+    /// - AUTH_JWT_SECRET: JWTの署名の検証に使うシークレット（32文字以上）
+    /// - AUTH_JWT_ISSUER: 受け付けるトークンの発行者
+    /// - AUTH_JWT_AUDIENCE: 受け付けるトークンの対象者
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_env_with_defaults(Self::default())
+    }
+
+    /// 環境変数から設定を読み取る
+    /// 環境変数が設定されていない項目は指定した設定の値を使用（設定ファイルの値を環境変数で上書きする）
+    pub fn from_env_with_defaults(defaults: Self) -> Result<Self, ConfigError> {
+        let jwt_secret = env::var("AUTH_JWT_SECRET").ok().or(defaults.jwt_secret);
+        if jwt_secret
+            .as_ref()
+            .is_some_and(|secret| secret.chars().count() < MIN_AUTH_JWT_SECRET_LENGTH)
+        {
+            return Err(ConfigError::InvalidValue(format!(
+                "AUTH_JWT_SECRET must be at least {} characters",
+                MIN_AUTH_JWT_SECRET_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            jwt_secret,
+            issuer: env::var("AUTH_JWT_ISSUER").ok().or(defaults.issuer),
+            audience: env::var("AUTH_JWT_AUDIENCE").ok().or(defaults.audience),
+        })
+    }
+
+    /// 認証を行うか
+    pub fn is_enabled(&self) -> bool {
+        self.jwt_secret.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::database_config::tests::ENV_LOCK;
+
+    #[test]
+    fn test_from_env_requires_long_enough_secret() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::set_var("AUTH_JWT_SECRET", "0123456789abcdef0123456789abcdef");
+        env::set_var("AUTH_JWT_ISSUER", "bookstore-auth");
+        let config = AuthConfig::from_env().unwrap();
+        assert!(config.is_enabled());
+        assert_eq!(config.issuer.as_deref(), Some("bookstore-auth"));
+
+        env::set_var("AUTH_JWT_SECRET", "too-short");
+        assert!(AuthConfig::from_env().is_err());
+
+        env::remove_var("AUTH_JWT_SECRET");
+        env::remove_var("AUTH_JWT_ISSUER");
+        let config = AuthConfig::from_env().unwrap();
+        assert!(!config.is_enabled());
+        assert!(config.issuer.is_none());
+    }
+}
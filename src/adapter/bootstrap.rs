@@ -0,0 +1,1048 @@
+//! アプリケーションの組み立て
+//!
+//! 永続化先ごとに作成したリポジトリ（Repositories）から、イベントハンドラーの購読・バックグラウンドタスク・
+//! アプリケーションサービス・REST APIのルーターまでを組み立てる。
+//! 起動時（main）と、SQLiteのインメモリデータベースでREST API全体を動かす統合テストで同じ組み立てを使う。
+
+use crate::adapter::driven::{ConsoleAlerting, FcmPushNotificationAdapter, HmacActionLinkSigner, HttpWebhookSender, InMemoryEventBus, InMemoryNotificationHub, LocalFileObjectStorage, S3CompatibleObjectStorage, SimulatedCarrierAdapter, SimulatedNotificationSender, SimulatedPaymentGateway, SlackAlerting, TokenDownloadLinkGenerator, WebhookAlerting};
+use crate::adapter::driver::auth::{require_authorization, AuthState, JwtAuthenticator};
+use crate::adapter::driver::rest_api::{create_router, http_request_span, order_lock_guard, AppState, AppStateInner};
+use crate::adapter::repositories::Repositories;
+use crate::adapter::{ActionLinkConfig, AlertChannel, AlertingConfig, AppConfig, CancellationConfig, CheckoutHoldConfig, DeliveryEstimateConfig, ExportStorage, FulfillmentConfig, LateEventConfig, NotificationConfig, PricingConfig, PurchaseLimitConfig, RiskHoldConfig, SagaConfig, SegmentationConfig, ShippingFeeConfig, SlaConfig, StartupDiagnostics};
+use crate::application::service::{ActionLinkApplicationService, CatalogApplicationService, CheckoutHoldApplicationService, CustomerApplicationService, CustomerExportApplicationService, CycleCountApplicationService, DeadLetterApplicationService, DeviceApplicationService, DiagnosticsApplicationService, EventTraceApplicationService, EventExportApplicationService, ForecastApplicationService, InboxApplicationService, InventoryApplicationService, LegacyImportApplicationService, NotificationApplicationService, OrderApplicationService, OrderLockApplicationService, OrderRepairApplicationService, ParkedEventApplicationService, PendingOperationApplicationService, ProjectionApplicationService, SagaMetricsApplicationService, SubscriptionApplicationService, TimelineApplicationService, TrackingApplicationService, WaitlistApplicationService, WebhookApplicationService};
+use crate::domain;
+use crate::domain::action_link::ActionLinkIssuer;
+use crate::domain::alerting::AnomalyDetector;
+use crate::domain::checkout_hold::CheckoutHoldSweeper;
+use crate::domain::customer_export::CustomerExporter;
+use crate::domain::customer_webhook::WebhookDispatcher;
+use crate::domain::diagnostics::DiagnosticsReport;
+use crate::domain::event_export::{EventExportJob, EventExporter, EventImporter};
+use crate::domain::inbox::{InboxProcessor, INBOX_SOURCE_CARRIER};
+use crate::domain::late_event::LateEventGuard;
+use crate::domain::load_shedding::HandlerCriticality;
+use crate::domain::metrics::BusinessMetrics;
+use crate::domain::port::{ActionLinkSigner, AlertingPort, Logger, NotificationSender, ObjectStoragePort, PaymentPort, PushNotificationPort, SubscriptionManager};
+use crate::domain::notification_retry::NotificationRetrier;
+use crate::domain::pending_operation::PendingOperationRetrier;
+use crate::domain::pre_order::PreOrderReleaseJob;
+use crate::domain::projection::{ProjectionProgress, ProjectionRegistry};
+use crate::domain::saga_orchestrator::{OrderSagaOrchestrator, SagaMode};
+use crate::domain::saga_timeout::SagaTimeoutMonitor;
+use crate::domain::sla::SlaMonitor;
+use crate::domain::task_supervisor::{TaskSupervisionPolicy, TaskSupervisor};
+
+use axum::{middleware, Router};
+use chrono::TimeDelta;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_http::trace::TraceLayer;
+use uuid::Uuid;
+
+/// 電子書籍のダウンロードリンクの有効期間（時間）
+const DOWNLOAD_LINK_TTL_HOURS: i64 = 72;
+
+/// 予約注文の発売日チェック間隔
+const PRE_ORDER_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 外部システムから受信したメッセージの処理間隔
+const INBOX_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 発行に失敗したイベントの再試行間隔
+const PENDING_OPERATION_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 夜間のイベントエクスポートの確認間隔（前日分が完了済みの場合は何もしない）
+const EVENT_EXPORT_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 自動で一時停止したイベントハンドラーの再開を試みるかの確認間隔（クールダウンを過ぎたハンドラーのみ再開を試みる）
+const HANDLER_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 組み立てたアプリケーション
+pub struct Application {
+    /// アプリケーション状態（gRPCサーバーとサービスを共有する）
+    pub state: AppState,
+    /// 状態・ミドルウェアを設定したREST APIのルーター
+    pub router: Router,
+    /// REST API・gRPCの認証（AUTH_JWT_SECRETを設定した場合のみ）
+    pub authenticator: Option<Arc<JwtAuthenticator>>,
+}
+
+/// リポジトリからアプリケーションを組み立てる
+/// イベントハンドラーを購読し、バックグラウンドタスクを起動してからREST APIのルーターを作成する
+///
+/// # Arguments
+/// * `app_config` - アプリケーションの設定
+/// * `repositories` - 永続化先ごとに作成したリポジトリ
+/// * `logger` - ロガー
+/// * `diagnostics` - データベースの接続まで記録した起動時の診断（イベントバス・ハンドラーの確認を追加して完了する）
+///
+/// # Returns
+/// * `Ok(Application)` - 組み立てたアプリケーション
+/// * `Err` - 設定の誤り、または起動時の診断で問題が見つかった
+pub async fn build_application(
+    app_config: &AppConfig,
+    repositories: Repositories,
+    logger: Arc<dyn Logger>,
+    mut diagnostics: StartupDiagnostics,
+) -> Result<Application, Box<dyn std::error::Error>> {
+    let order_repository = repositories.orders.clone();
+    let inventory_repository = repositories.inventory.clone();
+    let tracking_repository = repositories.tracking_events.clone();
+    // 顧客リポジトリ（注文の作成時に顧客の存在を検証し、通知の言語を取得する）
+    let customer_repository = repositories.customers.clone();
+    let parked_event_repository = repositories.parked_events.clone();
+
+    // アラートの送信先を作成（異常検知とハンドラーの自動一時停止で使う）
+    let alerting_config = AlertingConfig::from_env()?;
+    let alerting: Arc<dyn AlertingPort> = match alerting_config.channel {
+        AlertChannel::Console => Arc::new(ConsoleAlerting::new(logger.clone())),
+        AlertChannel::Webhook(url) => Arc::new(WebhookAlerting::new(url)),
+        AlertChannel::Slack(url) => Arc::new(SlackAlerting::new(url)),
+    };
+
+    // バックグラウンドタスクの監督を作成（止まったタスクを再起動し、/health/ready で生存状況を返す）
+    let task_supervisor = Arc::new(
+        TaskSupervisor::new(TaskSupervisionPolicy::default()).with_alerting(alerting.clone()),
+    );
+
+    // イベントバスを作成（EVENT_DISPATCH_LANESで配信方式を指定）
+    // 発行したイベントはエクスポート用にジャーナルへ記録する
+    let event_bus_config = app_config.event_bus.clone();
+    diagnostics.probe_event_bus(&event_bus_config).await;
+    let retry_policies = event_bus_config.retry_policies.clone();
+    let handler_auto_pause = event_bus_config.handler_health.is_some();
+    let event_journal = repositories.event_journal.clone();
+    let event_bus = Arc::new(
+        InMemoryEventBus::new(event_bus_config)
+            .with_journal(event_journal.clone())
+            .with_alerting(alerting.clone()),
+    );
+    // 失敗し続けて自動で一時停止したハンドラーの再開を定期的に試みる（HANDLER_AUTO_PAUSE=true の場合）
+    if handler_auto_pause {
+        event_bus.spawn_health_probe(HANDLER_HEALTH_CHECK_INTERVAL);
+    }
+
+    // 遅延イベント（注文が想定の状態を過ぎてから届いたイベント）の扱いを設定
+    let late_event_config = LateEventConfig::from_env()?;
+    let late_event_guard = LateEventGuard::new(
+        late_event_config.policies,
+        parked_event_repository.clone(),
+        logger.clone(),
+    );
+
+    // 配送料の料金表と配送業者の上限を設定
+    let shipping_fee_config = ShippingFeeConfig::from_env()?;
+    // 都道府県ごとの配送日数から配達予定日を見積もる
+    let delivery_estimate_config = DeliveryEstimateConfig::from_env()?;
+
+    // 確定時に明細の単価と照合する書籍カタログと、価格が変更されていた場合の扱いを設定
+    let pricing_config = PricingConfig::from_env()?;
+    let book_catalog = repositories.book_catalog.clone();
+
+    // 確定済みの注文のキャンセル受付期限を設定
+    let cancellation_config = CancellationConfig::from_env()?;
+    // 注文の作成・書籍の追加時の購入制限を設定（未設定の場合は制限しない）
+    let purchase_limit_config = PurchaseLimitConfig::from_env()?;
+
+    // 発送・配達を自動で進めるか（FULFILLMENT_MODE=manual / auto / hybrid）
+    let fulfillment_config = FulfillmentConfig::from_env()?;
+
+    // 注文確定後のサーガの進め方（SAGA_MODE=choreography / orchestration）
+    let saga_config = SagaConfig::from_env()?;
+
+    // 順番待ちが有効な書籍の、在庫を超える注文の先着順の待ち行列
+    let waitlist_repository = repositories.waitlist.clone();
+
+    // チェックアウト中の在庫の仮押さえ（確定時に在庫予約へ引き継ぐ）
+    let checkout_hold_config = CheckoutHoldConfig::from_env()?;
+    let checkout_hold_repository = repositories.checkout_holds.clone();
+
+    // 限定版などの書籍の購入上限・販売チャネル別の割当枠（在庫の予約時に適用する）
+    let allocation_quota_repository = repositories.allocation_quotas.clone();
+
+    // 処理済みのイベントIDを永続化し、再起動後に再配信されたイベントを二重に処理しない
+    let processed_event_repository = repositories.processed_events.clone();
+
+    // イベントハンドラーを作成して登録
+    let inventory_handler = domain::handler::InventoryReservationHandler::new(
+        inventory_repository.clone(),
+        order_repository.clone(),
+        event_bus.clone(),
+        processed_event_repository.clone(),
+        logger.clone(),
+    )
+    .with_late_event_guard(late_event_guard.clone())
+    .with_waitlist(waitlist_repository.clone())
+    .with_checkout_holds(checkout_hold_repository.clone())
+    .with_allocation_quotas(allocation_quota_repository.clone());
+    let allocation_return_handler = domain::handler::AllocationReturnHandler::new(
+        allocation_quota_repository.clone(),
+        logger.clone(),
+    );
+    let waitlist_promotion_handler = domain::handler::WaitlistPromotionHandler::new(
+        waitlist_repository.clone(),
+        inventory_repository.clone(),
+        order_repository.clone(),
+        event_bus.clone(),
+        logger.clone(),
+    )
+    .with_delivery_estimator(delivery_estimate_config.estimator.clone());
+    let shipping_handler = domain::handler::ShippingHandler::new(
+        order_repository.clone(),
+        event_bus.clone(),
+        processed_event_repository.clone(),
+        logger.clone(),
+    )
+    .with_late_event_guard(late_event_guard.clone())
+    .with_carrier_limits(shipping_fee_config.policy.carrier_limits())
+    .with_delivery_estimator(delivery_estimate_config.estimator.clone());
+    let delivery_handler = domain::handler::DeliveryHandler::new(
+        order_repository.clone(),
+        event_bus.clone(),
+        processed_event_repository.clone(),
+        logger.clone(),
+    )
+    .with_late_event_guard(late_event_guard.clone())
+    // 発送した荷物は配送業者のシミュレーターに引き渡し、通知された結果で配達完了・配達失敗にする
+    .with_carrier(Arc::new(SimulatedCarrierAdapter::new(
+        fulfillment_config.carrier_delivery_delay,
+        fulfillment_config.carrier_failure_rate,
+    )));
+    // orchestrationでは在庫の予約 → 請求 → 発送をオーケストレーターが順に指示する（請求は決済代行のシミュレーターに送る）
+    let payment: Arc<dyn PaymentPort> = Arc::new(SimulatedPaymentGateway::new(
+        saga_config.payment_delay,
+        saga_config.payment_failure_rate,
+    ));
+    let saga_orchestrator = OrderSagaOrchestrator::new(
+        order_repository.clone(),
+        inventory_repository.clone(),
+        payment,
+        event_bus.clone(),
+        processed_event_repository.clone(),
+        logger.clone(),
+    )
+    .with_step_timeout(saga_config.step_timeout)
+    .with_fulfillment_mode(fulfillment_config.mode)
+    .with_carrier_limits(shipping_fee_config.policy.carrier_limits())
+    .with_delivery_estimator(delivery_estimate_config.estimator.clone());
+    // 通知の送信（送信に失敗した通知はイベントのデッドレターキューとは別の再送キューに入れる）
+    let notification_config = NotificationConfig::from_env()?;
+    let notification_sender: Arc<dyn NotificationSender> = Arc::new(
+        SimulatedNotificationSender::new(logger.clone(), notification_config.failure_rate),
+    );
+    let failed_notification_repository = repositories.failed_notifications.clone();
+    // 接続中の顧客（WebSocket）への通知の配信先
+    let notification_hub = Arc::new(InMemoryNotificationHub::new());
+    let notification_handler = domain::handler::NotificationHandler::new(logger.clone())
+        .with_sender(notification_sender.clone())
+        .with_channel(notification_hub.clone())
+        .with_retry_queue(
+            failed_notification_repository.clone(),
+            notification_config.retry_policy,
+        )
+        .with_order_repository(order_repository.clone())
+        .with_customer_repository(customer_repository.clone());
+    let push_notification: Arc<dyn PushNotificationPort> =
+        Arc::new(FcmPushNotificationAdapter::new(logger.clone()));
+    // 通知に載せる署名付きのアクションリンク（ログインせずにキャンセル・受け取り確認ができる）
+    let action_link_config = ActionLinkConfig::from_env()?;
+    let action_link_secret = action_link_config.secret.unwrap_or_else(|| {
+        logger.warn(
+            "Main",
+            "ACTION_LINK_SECRET is not set; action links issued before restart will be rejected",
+            None,
+            None,
+        );
+        format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+    });
+    let action_link_signer: Arc<dyn ActionLinkSigner> =
+        Arc::new(HmacActionLinkSigner::new(&action_link_secret));
+    let push_notification_handler = domain::handler::PushNotificationHandler::new(
+        order_repository.clone(),
+        push_notification.clone(),
+        logger.clone(),
+    )
+    .with_action_links(ActionLinkIssuer::new(
+        action_link_signer.clone(),
+        action_link_config.base_url,
+        action_link_config.ttl,
+    ));
+    // 顧客ごとのWebhookの配信（購読・配信ログはMySQLに保存し、HMAC署名付きでPOSTする）
+    let webhook_subscription_repository = repositories.webhook_subscriptions.clone();
+    let webhook_delivery_repository = repositories.webhook_deliveries.clone();
+    let webhook_dispatcher = WebhookDispatcher::new(
+        webhook_subscription_repository.clone(),
+        webhook_delivery_repository.clone(),
+        Arc::new(HttpWebhookSender::new()),
+    );
+    let customer_webhook_handler = domain::handler::CustomerWebhookHandler::new(
+        order_repository.clone(),
+        webhook_dispatcher.clone(),
+        logger.clone(),
+    );
+    // 合計金額が閾値以上の注文を確定時に不正の疑いとして保留にする（RISK_HOLD_THRESHOLDで有効化）
+    let risk_hold_handler = RiskHoldConfig::from_env()?.threshold.map(|threshold| {
+        domain::handler::RiskHoldHandler::new(
+            order_repository.clone(),
+            event_bus.clone(),
+            logger.clone(),
+            threshold,
+        )
+    });
+    // 注文の確定・配達完了から顧客セグメントの派生イベントを発行（SEGMENT_*で判定のルールを設定）
+    let segmentation_handler = domain::handler::CustomerSegmentationHandler::new(
+        order_repository.clone(),
+        event_bus.clone(),
+        logger.clone(),
+        SegmentationConfig::from_env()?.rules,
+    );
+    let download_base_url = std::env::var("DOWNLOAD_BASE_URL")
+        .unwrap_or_else(|_| "http://localhost:3000".to_string());
+    let fulfillment_router = domain::handler::FulfillmentRouter::new(
+        order_repository.clone(),
+        event_bus.clone(),
+        Arc::new(TokenDownloadLinkGenerator::new(
+            download_base_url,
+            TimeDelta::hours(DOWNLOAD_LINK_TTL_HOURS),
+        )),
+        processed_event_repository.clone(),
+        logger.clone(),
+    )
+    .with_late_event_guard(late_event_guard);
+    let return_handler = domain::handler::ReturnHandler::new(
+        inventory_repository.clone(),
+        event_bus.clone(),
+        processed_event_repository,
+        logger.clone(),
+    );
+    let tracking_projection_progress = ProjectionProgress::new();
+    let tracking_projection_handler = domain::handler::TrackingProjectionHandler::new(
+        tracking_repository.clone(),
+        logger.clone(),
+    )
+    .with_progress(tracking_projection_progress.clone());
+    let consistency_verifier = domain::handler::EventualConsistencyVerifier::new(
+        order_repository.clone(),
+        inventory_repository.clone(),
+        logger.clone(),
+    );
+
+    // 補償ハンドラーを作成
+    let inventory_compensation_handler =
+        domain::handler::InventoryReservationFailureCompensationHandler::new(
+            order_repository.clone(),
+            event_bus.clone(),
+            logger.clone(),
+        );
+    let shipping_compensation_handler =
+        domain::handler::ShippingFailureCompensationHandler::new(
+            inventory_repository.clone(),
+            order_repository.clone(),
+            event_bus.clone(),
+            logger.clone(),
+        );
+    let delivery_compensation_handler =
+        domain::handler::DeliveryFailureCompensationHandler::new(
+            order_repository.clone(),
+            event_bus.clone(),
+            logger.clone(),
+        );
+    let saga_coordinator =
+        domain::handler::SagaCompensationCoordinator::new(event_bus.clone(), logger.clone());
+    let compensation_completion_handler =
+        domain::handler::CompensationCompletionHandler::new(logger.clone());
+
+    // ビジネスメトリクスハンドラーを作成
+    let business_metrics = BusinessMetrics::new();
+    let metrics_handler =
+        domain::handler::BusinessMetricsHandler::new(business_metrics.clone(), logger.clone());
+
+    // サーガの集計用に補償を記録するハンドラーを作成
+    let saga_compensation_repository = repositories.saga_compensations.clone();
+    let compensation_recorder =
+        domain::handler::SagaCompensationRecorder::new(saga_compensation_repository.clone());
+
+    // イベントハンドラーをイベントバスに登録
+    // 在庫予約・通知の前に高額な注文を保留にする（在庫予約から続けて発送されないよう先に購読する）
+    if let Some(risk_hold_handler) = risk_hold_handler {
+        event_bus
+            .subscribe_order_confirmed(risk_hold_handler)
+            .await?;
+    }
+    // 注文確定時は在庫予約を自動実行（発送・配達はFULFILLMENT_MODEに従う）
+    // orchestrationではオーケストレーターが在庫の予約・請求・発送と失敗時の補償をまとめて進める
+    match saga_config.mode {
+        SagaMode::Choreography => {
+            event_bus
+                .subscribe_order_confirmed(inventory_handler.clone())
+                .await?;
+        }
+        SagaMode::Orchestration => {
+            event_bus
+                .subscribe_order_confirmed(saga_orchestrator)
+                .await?;
+        }
+    }
+    // 予約注文の発売日到来時にも在庫予約を実行
+    event_bus
+        .subscribe_pre_order_activated(inventory_handler)
+        .await?;
+    // 入荷・在庫の解放・仮押さえの期限切れ・順番待ちの注文のキャンセルで、順番待ちの注文を先着順に繰り上げる
+    event_bus
+        .subscribe_inventory_adjusted(waitlist_promotion_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_inventory_released(waitlist_promotion_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_checkout_hold_expired(waitlist_promotion_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_cancelled(waitlist_promotion_handler)
+        .await?;
+    // キャンセルした・在庫を解放した注文の割当を割当枠に戻す
+    event_bus
+        .subscribe_inventory_released(allocation_return_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_cancelled(allocation_return_handler)
+        .await?;
+    // 在庫予約後に電子書籍のダウンロードリンクを発行（電子書籍のみの注文はここで完了）
+    event_bus
+        .subscribe_inventory_reserved(fulfillment_router)
+        .await?;
+
+    // auto / hybridでは在庫予約後（保留中の注文は保留の解除後）に発送し、発送後に配達完了にする
+    if fulfillment_config.mode.is_automatic() {
+        if saga_config.mode == SagaMode::Choreography {
+            event_bus
+                .subscribe_inventory_reserved(shipping_handler.clone())
+                .await?;
+        }
+        event_bus
+            .subscribe_order_released(shipping_handler.clone())
+            .await?;
+        event_bus
+            .subscribe_order_shipped(delivery_handler)
+            .await?;
+    }
+
+    // 確定後に配送先住所が変更された場合は倉庫側で配送先を再検証
+    event_bus
+        .subscribe_shipping_address_changed(shipping_handler)
+        .await?;
+
+    // 通知・プッシュ通知・ビジネスメトリクスは欠けても注文の処理に影響しないため、
+    // 配信待ちのイベントがたまっている間は配信を見送る（EVENT_LOAD_SHEDDING=true の場合）
+    let non_critical_event_bus = event_bus.with_criticality(HandlerCriticality::NonCritical);
+
+    // 通知ハンドラーを各イベントに登録（並行処理で通知送信）
+    non_critical_event_bus
+        .subscribe_order_confirmed(notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_order_shipped(notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_order_delivered(notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_order_cancelled(notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_digital_items_fulfilled(notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_waitlist_joined(notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_waitlist_promoted(notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_fulfillment_sla_breached(notification_handler)
+        .await?;
+
+    // プッシュ通知ハンドラーを注文ステータスが変わるイベントに登録（顧客トピックへ配信）
+    non_critical_event_bus
+        .subscribe_order_confirmed(push_notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_order_shipped(push_notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_order_delivered(push_notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_order_cancelled(push_notification_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_digital_items_fulfilled(push_notification_handler)
+        .await?;
+
+    // 顧客Webhookハンドラーを注文ステータスが変わるイベントと顧客セグメントの派生イベントに登録（顧客の購読へ配信）
+    event_bus
+        .subscribe_order_confirmed(customer_webhook_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_cancelled(customer_webhook_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_shipping_address_changed(customer_webhook_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_shipped(customer_webhook_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_delivered(customer_webhook_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_digital_items_fulfilled(customer_webhook_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_high_value_order_placed(customer_webhook_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_customer_became_repeat_buyer(customer_webhook_handler)
+        .await?;
+
+    // 顧客セグメントハンドラーを登録（派生イベントはWebhookやイベントのエクスポートで外部に届く）
+    event_bus
+        .subscribe_order_confirmed(segmentation_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_delivered(segmentation_handler)
+        .await?;
+
+    // 配送追跡タイムラインへの投影を永続化したチェックポイントから再開してから購読する
+    // （再起動前に適用済みのイベントが再配信されても二重に投影しない）
+    tracking_projection_handler.recover().await?;
+
+    // 配送追跡タイムラインへの投影ハンドラーを登録
+    event_bus
+        .subscribe_order_confirmed(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_shipped(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_delivered(tracking_projection_handler.clone())
+        .await?;
+    // キャンセル・住所変更・保留・保留の解除・返品は投影しないが、注文ごとの連番を進めるために購読する
+    event_bus
+        .subscribe_order_cancelled(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_shipping_address_changed(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_held(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_released(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_order_status_forcefully_changed(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_return_requested(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_return_approved(tracking_projection_handler.clone())
+        .await?;
+    event_bus
+        .subscribe_carrier_tracking_updated(tracking_projection_handler)
+        .await?;
+
+    // 整合性検証ハンドラーを登録（並行処理で検証実行）
+    event_bus
+        .subscribe_order_confirmed(consistency_verifier.clone())
+        .await?;
+    event_bus
+        .subscribe_order_delivered(consistency_verifier)
+        .await?;
+
+    // 返品ハンドラーを登録（承認した返品の書籍を在庫に戻す）
+    event_bus.subscribe_return_approved(return_handler).await?;
+
+    // 補償ハンドラーを登録（在庫予約・発送の失敗はorchestrationではオーケストレーターが補償する）
+    if saga_config.mode == SagaMode::Choreography {
+        event_bus
+            .subscribe_inventory_reservation_failed(inventory_compensation_handler)
+            .await?;
+        event_bus
+            .subscribe_shipping_failed(shipping_compensation_handler)
+            .await?;
+    }
+    event_bus
+        .subscribe_delivery_failed(delivery_compensation_handler)
+        .await?;
+    event_bus
+        .subscribe_saga_compensation_started(saga_coordinator)
+        .await?;
+    event_bus
+        .subscribe_saga_compensation_completed(compensation_completion_handler)
+        .await?;
+
+    // ビジネスメトリクスハンドラーを登録
+    non_critical_event_bus
+        .subscribe_order_confirmed(metrics_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_order_cancelled(metrics_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_order_delivered(metrics_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_inventory_reserved(metrics_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_inventory_reservation_failed(metrics_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_shipping_failed(metrics_handler.clone())
+        .await?;
+    non_critical_event_bus
+        .subscribe_delivery_failed(metrics_handler)
+        .await?;
+
+    // 補償の記録ハンドラーを登録
+    event_bus
+        .subscribe_inventory_reservation_failed(compensation_recorder.clone())
+        .await?;
+    event_bus
+        .subscribe_shipping_failed(compensation_recorder.clone())
+        .await?;
+    event_bus
+        .subscribe_delivery_failed(compensation_recorder)
+        .await?;
+
+    logger.debug("Main", "イベントハンドラーを登録しました", None, None);
+
+    // 起動時の診断を完了し、結果をログに出力する（GET /admin/diagnostics で最後のレポートを確認できる）
+    diagnostics.record_handlers(&event_bus.subscriptions().await);
+    let diagnostics_report = diagnostics.into_report();
+    diagnostics_report.log(logger.as_ref());
+    if diagnostics_report.has_failures() {
+        return Err(startup_failure(&diagnostics_report, logger.as_ref()));
+    }
+
+    // 異常検知タスクを起動
+    AnomalyDetector::new(
+        business_metrics.clone(),
+        event_bus.clone(),
+        alerting,
+        logger.clone(),
+        alerting_config.thresholds,
+    )
+    .spawn();
+    logger.debug("Main", "異常検知タスクを起動しました", None, None);
+
+    // 予約注文の発売日ジョブを起動（発売日を迎えた予約注文の在庫予約を再開）
+    task_supervisor.supervise(
+        "pre_order_release",
+        Arc::new(
+            PreOrderReleaseJob::new(
+                order_repository.clone(),
+                inventory_repository.clone(),
+                event_bus.clone(),
+                logger.clone(),
+            )
+            .with_shipping_fee_policy(shipping_fee_config.policy.clone())
+            .with_delivery_estimator(delivery_estimate_config.estimator.clone()),
+        ),
+        PRE_ORDER_CHECK_INTERVAL,
+    );
+    logger.debug("Main", "予約注文の発売日ジョブを起動しました", None, None);
+
+    // 発行に失敗したイベントの再試行ジョブを起動（コマンドは202で応答し、ここで発行を完了させる）
+    let pending_operation_repository = repositories.pending_operations.clone();
+    task_supervisor.supervise(
+        "pending_operation_retrier",
+        Arc::new(
+            PendingOperationRetrier::new(
+                pending_operation_repository.clone(),
+                event_bus.clone(),
+                logger.clone(),
+            ),
+        ),
+        PENDING_OPERATION_RETRY_INTERVAL,
+    );
+    logger.debug("Main", "発行に失敗したイベントの再試行ジョブを起動しました", None, None);
+
+    // 送信に失敗した通知の再送ジョブを起動（チャネルごとの待ち時間を空けて送り直す）
+    task_supervisor.supervise(
+        "notification_retrier",
+        Arc::new(
+            NotificationRetrier::new(
+                failed_notification_repository.clone(),
+                notification_sender.clone(),
+                notification_config.retry_policy,
+                logger.clone(),
+            ),
+        ),
+        notification_config.retry_interval,
+    );
+    logger.debug("Main", "送信に失敗した通知の再送ジョブを起動しました", None, None);
+
+    // 期限切れの仮押さえの解放ジョブを起動（確定しなかった注文の在庫を戻す）
+    task_supervisor.supervise(
+        "checkout_hold_sweeper",
+        Arc::new(
+            CheckoutHoldSweeper::new(
+                checkout_hold_repository.clone(),
+                inventory_repository.clone(),
+                event_bus.clone(),
+                logger.clone(),
+            ),
+        ),
+        checkout_hold_config.sweep_interval,
+    );
+    logger.debug("Main", "在庫の仮押さえの解放ジョブを起動しました", None, None);
+
+    // フルフィルメントSLA監視タスクを起動（発送・配達の期限超過を検出）
+    let sla_config = SlaConfig::from_env()?;
+    task_supervisor.supervise(
+        "sla_monitor",
+        Arc::new(
+            SlaMonitor::new(
+                order_repository.clone(),
+                event_bus.clone(),
+                logger.clone(),
+                sla_config.policy,
+            ),
+        ),
+        sla_config.check_interval,
+    );
+    logger.debug("Main", "SLA監視タスクを起動しました", None, None);
+
+    // サーガのタイムアウト監視タスクを起動（在庫予約・配達完了のまま停滞した注文の補償を開始）
+    task_supervisor.supervise(
+        "saga_timeout_monitor",
+        Arc::new(SagaTimeoutMonitor::new(
+            order_repository.clone(),
+            event_journal.clone(),
+            event_bus.clone(),
+            logger.clone(),
+            saga_config.timeout_policy,
+        )),
+        saga_config.timeout_check_interval,
+    );
+    logger.debug("Main", "サーガのタイムアウト監視タスクを起動しました", None, None);
+
+    // 監督中のタスクの監視を起動（終了した・生存通知が途切れたタスクを再起動する）
+    task_supervisor.spawn_watchdog();
+    logger.debug("Main", "バックグラウンドタスクの監視を起動しました", None, None);
+    logger.debug("Main", "注文フロー:", None, None);
+    logger.debug("Main", "  1. 注文確定 → 在庫予約（自動）+ 通知送信", None, None);
+    logger.debug("Main", "     ※未発売の書籍を含む場合は発売待ち → 発売日に在庫予約（自動）", None, None);
+    logger.debug("Main", "     ※順番待ちが有効な書籍の在庫が足りない場合は順番待ち → 入荷・キャンセルで先着順に在庫予約（自動）", None, None);
+    logger.debug("Main", "     ※電子書籍はダウンロードリンクを発行（電子書籍のみの注文はFulfilledで完了）", None, None);
+    logger.debug(
+        "Main",
+        &format!("フルフィルメントモード: {}", fulfillment_config.mode.as_str()),
+        None,
+        None,
+    );
+    logger.debug(
+        "Main",
+        &format!("サーガのモード: {}", saga_config.mode.as_str()),
+        None,
+        None,
+    );
+    if saga_config.mode == SagaMode::Orchestration {
+        logger.debug("Main", "     ※オーケストレーターが在庫予約 → 請求 → 発送を順に指示（失敗・タイムアウト時は逆順に取り消してキャンセル）", None, None);
+    }
+    if fulfillment_config.mode.is_automatic() {
+        logger.debug("Main", "  2. 注文発送 → 在庫予約後に自動", None, None);
+        logger.debug("Main", "  3. 配達完了 → 配送業者のシミュレーターからの通知で自動（失敗時はDeliveryFailedで補償）", None, None);
+    }
+    if fulfillment_config.mode.allows_manual_transitions() {
+        logger.debug("Main", "  2. 注文発送 → 手動操作（POST /orders/:id/ship）", None, None);
+        logger.debug("Main", "  3. 配達完了 → 手動操作（POST /orders/:id/deliver）", None, None);
+    }
+
+    // アプリケーションサービスを作成（注文番号は注文の作成時に採番して顧客に伝える）
+    let order_service = OrderApplicationService::new(order_repository.clone(), event_bus.clone())
+    .with_purchase_policy(purchase_limit_config.policy)
+    .with_sla_policy(sla_config.policy)
+    .with_shipping_fee_policy(shipping_fee_config.policy)
+    .with_delivery_estimator(delivery_estimate_config.estimator)
+    .with_price_protection(book_catalog.clone(), pricing_config.price_change_policy)
+    .with_fulfillment_mode(fulfillment_config.mode)
+    .with_cancellation_policy(cancellation_config.policy)
+    .with_pending_operations(pending_operation_repository.clone())
+    .with_customers(customer_repository.clone())
+    .with_order_numbers(repositories.order_numbers.clone())
+    .with_status_override_audits(repositories.status_override_audits.clone())
+    .with_live_events(event_bus.clone());
+
+    let order_service = Arc::new(order_service);
+
+    // アクションリンクの操作を実行するサービスを作成（使われたトークンを記録する）
+    let action_link_service = ActionLinkApplicationService::new(
+        order_service.clone(),
+        action_link_signer,
+        repositories.action_link_audits.clone(),
+    );
+
+    // 送信に失敗した通知の照会・手動の再送サービスを作成
+    let notification_service = NotificationApplicationService::new(
+        failed_notification_repository.clone(),
+        notification_sender,
+        notification_config.retry_policy,
+    )
+    .with_live_notifications(notification_hub);
+
+    // 再試行待ちの操作の照会サービスを作成
+    let pending_operation_service =
+        PendingOperationApplicationService::new(pending_operation_repository);
+
+    // 書籍カタログサービスを作成
+    let catalog_service = CatalogApplicationService::new(book_catalog);
+
+    // 在庫サービスを作成
+    let inventory_service =
+        InventoryApplicationService::new(inventory_repository.clone(), event_bus.clone())
+            .with_allocation_quotas(allocation_quota_repository.clone());
+
+    // 確定した注文の履歴から書籍ごとの需要を見積もる需要予測サービスを作成
+    let forecast_service =
+        ForecastApplicationService::new(order_repository.clone(), inventory_repository.clone());
+
+    // 順番待ちの照会サービスを作成
+    let waitlist_service =
+        WaitlistApplicationService::new(waitlist_repository, order_repository.clone());
+
+    // 在庫の仮押さえサービスを作成
+    let checkout_hold_service = CheckoutHoldApplicationService::new(
+        order_repository.clone(),
+        inventory_repository.clone(),
+        checkout_hold_repository,
+        checkout_hold_config.hold_duration,
+    )
+    .with_allocation_quotas(allocation_quota_repository);
+
+    // 棚卸しサービスを作成
+    let cycle_count_service = CycleCountApplicationService::new(
+        repositories.cycle_counts.clone(),
+        inventory_repository.clone(),
+        event_bus.clone(),
+    );
+
+    // 顧客サービスを作成
+    let customer_service =
+        CustomerApplicationService::new(customer_repository.clone(), order_repository.clone());
+
+    // デバイスサービスを作成
+    let device_service = DeviceApplicationService::new(
+        repositories.device_registrations.clone(),
+        push_notification,
+    );
+
+    // 配送追跡サービスを作成
+    let tracking_service = Arc::new(TrackingApplicationService::new(
+        order_repository.clone(),
+        tracking_repository.clone(),
+        event_bus.clone(),
+    ));
+
+    // 受信メッセージの処理タスクを起動（inboxに記録した配送業者のメッセージを取り込む）
+    let inbox_repository = repositories.inbox.clone();
+    task_supervisor.supervise(
+        "inbox_processor",
+        Arc::new(
+            InboxProcessor::new(inbox_repository.clone(), logger.clone())
+                .with_handler(INBOX_SOURCE_CARRIER, tracking_service.clone()),
+        ),
+        INBOX_POLL_INTERVAL,
+    );
+    let inbox_service = InboxApplicationService::new(inbox_repository);
+
+    // 保留イベントサービスを作成
+    let parked_event_service = ParkedEventApplicationService::new(parked_event_repository);
+
+    // デッドレターキューサービスを作成
+    let dead_letter_service =
+        DeadLetterApplicationService::new(event_bus.clone()).with_retry_policies(retry_policies);
+
+    // プロジェクション監視サービスを作成
+    let projection_registry = ProjectionRegistry::new()
+        .with_projection(
+            domain::handler::TrackingProjectionHandler::PROJECTION_NAME,
+            tracking_projection_progress,
+        );
+    let projection_service =
+        ProjectionApplicationService::new(projection_registry, event_bus.clone());
+
+    // サーガ集計サービスを作成
+    let saga_metrics_service =
+        SagaMetricsApplicationService::new(order_repository.clone(), saga_compensation_repository);
+
+    // 旧システムの注文の取り込みサービスを作成
+    let legacy_import_service = LegacyImportApplicationService::new(
+        order_repository.clone(),
+        repositories.legacy_imports.clone(),
+    );
+
+    // イベントのエクスポートを作成（EVENT_EXPORT_STORAGEで書き出し先を指定）
+    let event_export_config = &app_config.event_export;
+    let export_storage: Arc<dyn ObjectStoragePort> = match &event_export_config.storage {
+        ExportStorage::Local(dir) => Arc::new(LocalFileObjectStorage::new(dir.clone())),
+        ExportStorage::S3 { endpoint, bucket } => Arc::new(S3CompatibleObjectStorage::new(
+            endpoint.clone(),
+            bucket.clone(),
+        )),
+    };
+    let event_exporter = EventExporter::new(event_journal.clone(), export_storage.clone())
+        .with_chunk_size(event_export_config.chunk_size);
+    let event_importer = EventImporter::new(event_journal.clone(), export_storage.clone());
+    if event_export_config.nightly {
+        task_supervisor.supervise(
+            "event_export",
+            Arc::new(EventExportJob::new(event_exporter.clone(), logger.clone())),
+            EVENT_EXPORT_CHECK_INTERVAL,
+        );
+        logger.debug("Main", "夜間のイベントエクスポートジョブを起動しました", None, None);
+    }
+    let event_export_service = EventExportApplicationService::new(event_exporter, event_importer);
+
+    // 顧客のデータのエクスポートサービスを作成（アーカイブはイベントのエクスポートと同じストレージに置く）
+    let customer_export_service = CustomerExportApplicationService::new(Arc::new(CustomerExporter::new(
+        customer_repository,
+        order_repository.clone(),
+        failed_notification_repository,
+        export_storage,
+    )));
+
+    // Webhookサービスを作成
+    let webhook_service = WebhookApplicationService::new(
+        webhook_subscription_repository,
+        webhook_delivery_repository,
+        webhook_dispatcher,
+    );
+
+    // 注文タイムラインサービスを作成（サポート向け）
+    let timeline_service = TimelineApplicationService::new(
+        order_repository.clone(),
+        event_journal.clone(),
+        repositories.order_notes.clone(),
+        tracking_repository,
+    );
+
+    // 停滞した注文の修復サービスを作成
+    let order_repair_service = OrderRepairApplicationService::new(
+        order_repository.clone(),
+        event_journal,
+        event_bus.clone(),
+    );
+
+    // 注文の編集ロックサービスを作成
+    let order_lock_service = OrderLockApplicationService::new(
+        order_repository.clone(),
+        repositories.order_locks.clone(),
+    );
+
+    // 購読管理サービスを作成
+    // 保存した一時停止・購読解除の状態を、登録したハンドラーに反映する
+    let subscription_service = SubscriptionApplicationService::new(event_bus.clone())
+        .with_registry(repositories.subscription_registry.clone())
+        .with_dispatch_control(event_bus.clone());
+    let restored_subscriptions = subscription_service.restore_subscriptions().await?;
+    if restored_subscriptions > 0 {
+        logger.info(
+            "Main",
+            &format!(
+                "保存した購読の状態を復元しました: {}件",
+                restored_subscriptions
+            ),
+            None,
+            None,
+        );
+    }
+
+    // アプリケーション状態を作成
+    let app_state = AppStateInner {
+        order_service,
+        inventory_service: Arc::new(inventory_service),
+        catalog_service: Arc::new(catalog_service),
+        waitlist_service: Arc::new(waitlist_service),
+        checkout_hold_service: Arc::new(checkout_hold_service),
+        cycle_count_service: Arc::new(cycle_count_service),
+        customer_service: Arc::new(customer_service),
+        customer_export_service: Arc::new(customer_export_service),
+        device_service: Arc::new(device_service),
+        tracking_service,
+        timeline_service: Arc::new(timeline_service),
+        inbox_service: Arc::new(inbox_service),
+        legacy_import_service: Arc::new(legacy_import_service),
+        parked_event_service: Arc::new(parked_event_service),
+        dead_letter_service: Arc::new(dead_letter_service),
+        event_trace_service: Arc::new(EventTraceApplicationService::new(event_bus.clone())),
+        projection_service: Arc::new(projection_service),
+        saga_metrics_service: Arc::new(saga_metrics_service),
+        order_repair_service: Arc::new(order_repair_service),
+        order_lock_service: Arc::new(order_lock_service),
+        pending_operation_service: Arc::new(pending_operation_service),
+        action_link_service: Arc::new(action_link_service),
+        notification_service: Arc::new(notification_service),
+        subscription_service: Arc::new(subscription_service),
+        event_export_service: Arc::new(event_export_service),
+        webhook_service: Arc::new(webhook_service),
+        forecast_service: Arc::new(forecast_service),
+        diagnostics_service: Arc::new(DiagnosticsApplicationService::new(diagnostics_report).with_dispatch_control(event_bus.clone()).with_task_supervisor(task_supervisor.clone())),
+        business_metrics,
+    };
+
+    // REST APIの認証（dev・testのプロファイルではAUTH_JWT_SECRETを設定しない場合は認証を行わない。
+    // それ以外のプロファイルではシークレットがないと設定の読み込みで起動を止める）
+    let authenticator = JwtAuthenticator::from_config(&app_config.auth).map(Arc::new);
+    let auth_state = authenticator.clone().map(|authenticator| AuthState {
+        authenticator,
+        order_service: app_state.order_service.clone(),
+    });
+    if auth_state.is_none() {
+        logger.warn(
+            "Main",
+            &format!(
+                "AUTH_JWT_SECRET is not set; REST API authentication is disabled ({} profile)",
+                app_config.profile.as_str()
+            ),
+            None,
+            None,
+        );
+    }
+
+    // REST APIルーターを作成
+    // 認証のミドルウェアは編集ロックの確認より先に実行する（後から追加したレイヤーが外側になる）
+    let app = create_router().layer(middleware::from_fn_with_state(
+        app_state.clone(),
+        order_lock_guard,
+    ));
+    let app = match auth_state {
+        Some(auth_state) => app.layer(middleware::from_fn_with_state(
+            auth_state,
+            require_authorization,
+        )),
+        None => app,
+    };
+    let app = app
+        .layer(TraceLayer::new_for_http().make_span_with(http_request_span))
+        .layer(app_config.cors.layer())
+        .with_state(app_state.clone());
+
+    Ok(Application {
+        state: app_state,
+        router: app,
+        authenticator,
+    })
+}
+
+/// 起動時の診断で見つかった問題をログに出力し、起動を中止するエラーを作成
+pub fn startup_failure(
+    report: &DiagnosticsReport,
+    logger: &dyn Logger,
+) -> Box<dyn std::error::Error> {
+    report.log(logger);
+    report.failure_summary().into()
+}
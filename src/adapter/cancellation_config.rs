@@ -0,0 +1,51 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::cancellation_policy::CancellationPolicy;
+use chrono::TimeDelta;
+
+/// 注文のキャンセル受付期限の設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct CancellationConfig {
+    pub policy: CancellationPolicy,
+}
+
+impl CancellationConfig {
+    /// 環境変数から設定を読み取る
+    /// - CANCELLATION_WINDOW_MINUTES: 確定からキャンセルを受け付ける期間（分、0または未設定の場合は期限なし）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let minutes: i64 = parse_env("CANCELLATION_WINDOW_MINUTES", 0)?;
+        if minutes < 0 {
+            return Err(ConfigError::InvalidValue(
+                "CANCELLATION_WINDOW_MINUTES must be 0 or greater".to_string(),
+            ));
+        }
+
+        let policy = if minutes == 0 {
+            CancellationPolicy::unlimited()
+        } else {
+            CancellationPolicy::within(TimeDelta::minutes(minutes))
+        };
+        Ok(Self { policy })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_env_parses_cancellation_window() {
+        env::set_var("CANCELLATION_WINDOW_MINUTES", "30");
+        assert_eq!(
+            CancellationConfig::from_env().unwrap().policy.window(),
+            Some(TimeDelta::minutes(30))
+        );
+
+        env::set_var("CANCELLATION_WINDOW_MINUTES", "-5");
+        assert!(CancellationConfig::from_env().is_err());
+
+        env::remove_var("CANCELLATION_WINDOW_MINUTES");
+        assert_eq!(CancellationConfig::from_env().unwrap().policy.window(), None);
+    }
+}
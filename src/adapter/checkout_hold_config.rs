@@ -0,0 +1,71 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use chrono::TimeDelta;
+use std::time::Duration;
+
+/// 在庫の仮押さえの期間のデフォルト値（分）
+const DEFAULT_HOLD_MINUTES: i64 = 15;
+/// 期限切れの仮押さえの解放間隔のデフォルト値（秒）
+const DEFAULT_SWEEP_INTERVAL_SECONDS: u64 = 60;
+
+/// チェックアウト中の在庫の仮押さえの設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct CheckoutHoldConfig {
+    /// 仮押さえの期間
+    pub hold_duration: TimeDelta,
+    /// 期限切れの仮押さえを解放するジョブの実行間隔
+    pub sweep_interval: Duration,
+}
+
+impl CheckoutHoldConfig {
+    /// 環境変数から設定を読み取る
+    /// - CHECKOUT_HOLD_MINUTES: チェックアウト開始から在庫を仮押さえする期間（分）
+    /// - CHECKOUT_HOLD_SWEEP_INTERVAL_SECONDS: 期限切れの仮押さえを解放する間隔（秒）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let minutes: i64 = parse_env("CHECKOUT_HOLD_MINUTES", DEFAULT_HOLD_MINUTES)?;
+        let sweep_interval = Duration::from_secs(parse_env(
+            "CHECKOUT_HOLD_SWEEP_INTERVAL_SECONDS",
+            DEFAULT_SWEEP_INTERVAL_SECONDS,
+        )?);
+
+        if minutes <= 0 {
+            return Err(ConfigError::InvalidValue(
+                "CHECKOUT_HOLD_MINUTES must be greater than 0".to_string(),
+            ));
+        }
+        if sweep_interval.is_zero() {
+            return Err(ConfigError::InvalidValue(
+                "CHECKOUT_HOLD_SWEEP_INTERVAL_SECONDS must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            hold_duration: TimeDelta::minutes(minutes),
+            sweep_interval,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_env_parses_hold_duration_and_sweep_interval() {
+        env::set_var("CHECKOUT_HOLD_MINUTES", "10");
+        env::set_var("CHECKOUT_HOLD_SWEEP_INTERVAL_SECONDS", "30");
+        let config = CheckoutHoldConfig::from_env().unwrap();
+        assert_eq!(config.hold_duration, TimeDelta::minutes(10));
+        assert_eq!(config.sweep_interval, Duration::from_secs(30));
+
+        env::set_var("CHECKOUT_HOLD_MINUTES", "0");
+        assert!(CheckoutHoldConfig::from_env().is_err());
+
+        env::remove_var("CHECKOUT_HOLD_MINUTES");
+        env::remove_var("CHECKOUT_HOLD_SWEEP_INTERVAL_SECONDS");
+        let config = CheckoutHoldConfig::from_env().unwrap();
+        assert_eq!(config.hold_duration, TimeDelta::minutes(15));
+        assert_eq!(config.sweep_interval, Duration::from_secs(60));
+    }
+}
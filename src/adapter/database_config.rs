@@ -9,6 +9,9 @@ pub struct DatabaseConfig {
     pub username: String,
     pub password: String,
     pub max_connections: u32,
+    /// リポジトリの保存・読み込み時に集約の不変条件を検証するかどうか
+    /// 未設定の場合はデバッグビルドでのみ有効
+    pub invariant_checks: bool,
 }
 
 /// 設定エラー
@@ -27,30 +30,58 @@ impl std::fmt::Display for ConfigError {
 
 impl std::error::Error for ConfigError {}
 
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 3306,
+            database: "bookstore_db".to_string(),
+            username: "bookstore_user".to_string(),
+            password: "bookstore_password".to_string(),
+            max_connections: 10,
+            invariant_checks: cfg!(debug_assertions),
+        }
+    }
+}
+
 impl DatabaseConfig {
     /// 環境変数から設定を読み取る
     /// 環境変数が設定されていない場合はデフォルト値を使用
     pub fn from_env() -> Result<Self, ConfigError> {
-        let host = env::var("DATABASE_HOST").unwrap_or_else(|_| "localhost".to_string());
+        Self::from_env_with_defaults(Self::default())
+    }
 
-        let port = env::var("DATABASE_PORT")
-            .unwrap_or_else(|_| "3306".to_string())
-            .parse::<u16>()
-            .map_err(|e| ConfigError::InvalidValue(format!("Invalid DATABASE_PORT: {}", e)))?;
+    /// 環境変数から設定を読み取る
+    /// 環境変数が設定されていない項目は指定した設定の値を使用（設定ファイルの値を環境変数で上書きする）
+    pub fn from_env_with_defaults(defaults: Self) -> Result<Self, ConfigError> {
+        let host = env::var("DATABASE_HOST").unwrap_or(defaults.host);
+
+        let port = match env::var("DATABASE_PORT") {
+            Ok(value) => value
+                .parse::<u16>()
+                .map_err(|e| ConfigError::InvalidValue(format!("Invalid DATABASE_PORT: {}", e)))?,
+            Err(_) => defaults.port,
+        };
 
-        let database = env::var("DATABASE_NAME").unwrap_or_else(|_| "bookstore_db".to_string());
+        let database = env::var("DATABASE_NAME").unwrap_or(defaults.database);
 
-        let username = env::var("DATABASE_USER").unwrap_or_else(|_| "bookstore_user".to_string());
+        let username = env::var("DATABASE_USER").unwrap_or(defaults.username);
 
-        let password =
-            env::var("DATABASE_PASSWORD").unwrap_or_else(|_| "bookstore_password".to_string());
+        let password = env::var("DATABASE_PASSWORD").unwrap_or(defaults.password);
 
-        let max_connections = env::var("DATABASE_MAX_CONNECTIONS")
-            .unwrap_or_else(|_| "10".to_string())
-            .parse::<u32>()
-            .map_err(|e| {
+        let max_connections = match env::var("DATABASE_MAX_CONNECTIONS") {
+            Ok(value) => value.parse::<u32>().map_err(|e| {
                 ConfigError::InvalidValue(format!("Invalid DATABASE_MAX_CONNECTIONS: {}", e))
-            })?;
+            })?,
+            Err(_) => defaults.max_connections,
+        };
+
+        let invariant_checks = match env::var("DATABASE_INVARIANT_CHECKS") {
+            Ok(value) => value.parse::<bool>().map_err(|e| {
+                ConfigError::InvalidValue(format!("Invalid DATABASE_INVARIANT_CHECKS: {}", e))
+            })?,
+            Err(_) => defaults.invariant_checks,
+        };
 
         Ok(Self {
             host,
@@ -59,6 +90,7 @@ impl DatabaseConfig {
             username,
             password,
             max_connections,
+            invariant_checks,
         })
     }
 
@@ -72,13 +104,13 @@ impl DatabaseConfig {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use std::env;
     use std::sync::Mutex;
 
-    // テスト間の環境変数の競合を防ぐためのロック
-    static ENV_LOCK: Mutex<()> = Mutex::new(());
+    // テスト間の環境変数の競合を防ぐためのロック（DATABASE_*を読み取るAppConfigのテストでも使う）
+    pub(crate) static ENV_LOCK: Mutex<()> = Mutex::new(());
 
     #[test]
     fn test_from_env_with_all_variables() {
@@ -91,6 +123,7 @@ mod tests {
         env::set_var("DATABASE_USER", "testuser");
         env::set_var("DATABASE_PASSWORD", "testpass");
         env::set_var("DATABASE_MAX_CONNECTIONS", "20");
+        env::set_var("DATABASE_INVARIANT_CHECKS", "false");
 
         let config = DatabaseConfig::from_env().unwrap();
 
@@ -100,6 +133,7 @@ mod tests {
         assert_eq!(config.username, "testuser");
         assert_eq!(config.password, "testpass");
         assert_eq!(config.max_connections, 20);
+        assert!(!config.invariant_checks);
 
         // クリーンアップ
         env::remove_var("DATABASE_HOST");
@@ -108,6 +142,7 @@ mod tests {
         env::remove_var("DATABASE_USER");
         env::remove_var("DATABASE_PASSWORD");
         env::remove_var("DATABASE_MAX_CONNECTIONS");
+        env::remove_var("DATABASE_INVARIANT_CHECKS");
     }
 
     #[test]
@@ -121,6 +156,7 @@ mod tests {
         env::remove_var("DATABASE_USER");
         env::remove_var("DATABASE_PASSWORD");
         env::remove_var("DATABASE_MAX_CONNECTIONS");
+        env::remove_var("DATABASE_INVARIANT_CHECKS");
 
         let config = DatabaseConfig::from_env().unwrap();
 
@@ -130,6 +166,37 @@ mod tests {
         assert_eq!(config.username, "bookstore_user");
         assert_eq!(config.password, "bookstore_password");
         assert_eq!(config.max_connections, 10);
+        assert_eq!(config.invariant_checks, cfg!(debug_assertions));
+    }
+
+    #[test]
+    fn test_from_env_with_defaults_prefers_environment() {
+        let _lock = ENV_LOCK.lock().unwrap();
+
+        env::remove_var("DATABASE_HOST");
+        env::remove_var("DATABASE_NAME");
+        env::remove_var("DATABASE_USER");
+        env::remove_var("DATABASE_PASSWORD");
+        env::remove_var("DATABASE_MAX_CONNECTIONS");
+        env::remove_var("DATABASE_INVARIANT_CHECKS");
+        env::set_var("DATABASE_PORT", "3308");
+
+        let defaults = DatabaseConfig {
+            host: "db.internal".to_string(),
+            port: 3307,
+            max_connections: 50,
+            ..DatabaseConfig::default()
+        };
+        let config = DatabaseConfig::from_env_with_defaults(defaults).unwrap();
+
+        // 環境変数が設定されていない項目は指定した設定の値
+        assert_eq!(config.host, "db.internal");
+        assert_eq!(config.max_connections, 50);
+        assert_eq!(config.database, "bookstore_db");
+        // 環境変数が設定されている項目は環境変数の値
+        assert_eq!(config.port, 3308);
+
+        env::remove_var("DATABASE_PORT");
     }
 
     #[test]
@@ -141,6 +208,7 @@ mod tests {
             username: "user".to_string(),
             password: "pass".to_string(),
             max_connections: 10,
+            invariant_checks: false,
         };
 
         let conn_str = config.connection_string();
@@ -4,6 +4,238 @@ use sqlx::{MySql, Pool};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// マイグレーションファイルのリスト（バージョン, SQL）
+const MIGRATIONS: &[(&str, &str)] = &[
+    (
+        "001",
+        include_str!("../../migrations/001_create_orders_table.sql"),
+    ),
+    (
+        "002",
+        include_str!("../../migrations/002_create_order_lines_table.sql"),
+    ),
+    (
+        "003",
+        include_str!("../../migrations/003_create_inventories_table.sql"),
+    ),
+    (
+        "004",
+        include_str!("../../migrations/004_add_release_date_to_inventories.sql"),
+    ),
+    (
+        "005",
+        include_str!("../../migrations/005_add_fulfillment_type_to_order_lines.sql"),
+    ),
+    (
+        "006",
+        include_str!("../../migrations/006_create_cycle_counts_table.sql"),
+    ),
+    (
+        "007",
+        include_str!("../../migrations/007_create_cycle_count_lines_table.sql"),
+    ),
+    (
+        "008",
+        include_str!("../../migrations/008_add_transition_times_to_orders.sql"),
+    ),
+    (
+        "009",
+        include_str!("../../migrations/009_create_customer_devices_table.sql"),
+    ),
+    (
+        "010",
+        include_str!("../../migrations/010_create_tracking_events_table.sql"),
+    ),
+    (
+        "011",
+        include_str!("../../migrations/011_add_stock_guardrails_to_inventories.sql"),
+    ),
+    (
+        "012",
+        include_str!("../../migrations/012_create_parked_events_table.sql"),
+    ),
+    (
+        "013",
+        include_str!("../../migrations/013_add_event_sequence_to_orders.sql"),
+    ),
+    (
+        "014",
+        include_str!("../../migrations/014_add_book_spec_to_order_lines.sql"),
+    ),
+    (
+        "015",
+        include_str!("../../migrations/015_add_recipient_to_orders.sql"),
+    ),
+    (
+        "016",
+        include_str!("../../migrations/016_create_order_line_attributes_table.sql"),
+    ),
+    (
+        "017",
+        include_str!("../../migrations/017_create_book_prices_table.sql"),
+    ),
+    (
+        "018",
+        include_str!("../../migrations/018_add_waitlist_to_inventories.sql"),
+    ),
+    (
+        "019",
+        include_str!("../../migrations/019_create_waitlist_entries_table.sql"),
+    ),
+    (
+        "020",
+        include_str!("../../migrations/020_create_saga_compensations_table.sql"),
+    ),
+    (
+        "021",
+        include_str!("../../migrations/021_create_inbox_messages_table.sql"),
+    ),
+    (
+        "022",
+        include_str!("../../migrations/022_create_legacy_order_imports_table.sql"),
+    ),
+    (
+        "023",
+        include_str!("../../migrations/023_create_checkout_holds_table.sql"),
+    ),
+    (
+        "024",
+        include_str!("../../migrations/024_create_event_journal_table.sql"),
+    ),
+    (
+        "025",
+        include_str!("../../migrations/025_add_original_shipping_address_to_orders.sql"),
+    ),
+    (
+        "026",
+        include_str!("../../migrations/026_add_saga_correlation_id_to_orders.sql"),
+    ),
+    (
+        "027",
+        include_str!("../../migrations/027_add_confirmed_totals_to_orders.sql"),
+    ),
+    (
+        "028",
+        include_str!("../../migrations/028_create_book_translations_table.sql"),
+    ),
+    (
+        "029",
+        include_str!("../../migrations/029_add_tracking_token_to_orders.sql"),
+    ),
+    (
+        "030",
+        include_str!("../../migrations/030_create_webhook_subscriptions_table.sql"),
+    ),
+    (
+        "031",
+        include_str!("../../migrations/031_create_webhook_deliveries_table.sql"),
+    ),
+    (
+        "032",
+        include_str!("../../migrations/032_add_aggregate_index_to_event_journal.sql"),
+    ),
+    (
+        "033",
+        include_str!("../../migrations/033_create_pending_operations_table.sql"),
+    ),
+    (
+        "034",
+        include_str!("../../migrations/034_create_action_link_uses_table.sql"),
+    ),
+    (
+        "035",
+        include_str!("../../migrations/035_create_order_locks_table.sql"),
+    ),
+    (
+        "036",
+        include_str!("../../migrations/036_create_processed_events_table.sql"),
+    ),
+    (
+        "037",
+        include_str!("../../migrations/037_create_event_store_table.sql"),
+    ),
+    (
+        "038",
+        include_str!("../../migrations/038_add_version_to_orders.sql"),
+    ),
+    (
+        "039",
+        include_str!("../../migrations/039_add_estimated_delivery_date_to_orders.sql"),
+    ),
+    (
+        "040",
+        include_str!("../../migrations/040_create_failed_notifications_table.sql"),
+    ),
+    (
+        "041",
+        include_str!("../../migrations/041_create_customers_table.sql"),
+    ),
+    (
+        "042",
+        include_str!("../../migrations/042_add_hold_reason_to_orders.sql"),
+    ),
+    (
+        "043",
+        include_str!("../../migrations/043_add_order_number_to_orders.sql"),
+    ),
+    (
+        "044",
+        include_str!("../../migrations/044_create_order_number_sequences_table.sql"),
+    ),
+    (
+        "045",
+        include_str!("../../migrations/045_create_projection_checkpoints_table.sql"),
+    ),
+    (
+        "046",
+        include_str!("../../migrations/046_create_inventory_movements_table.sql"),
+    ),
+    (
+        "047",
+        include_str!("../../migrations/047_create_order_notes_table.sql"),
+    ),
+    (
+        "048",
+        include_str!("../../migrations/048_create_subscription_registry_table.sql"),
+    ),
+    (
+        "049",
+        include_str!("../../migrations/049_create_status_override_audits_table.sql"),
+    ),
+    (
+        "050",
+        include_str!("../../migrations/050_add_sales_channel_to_orders.sql"),
+    ),
+    (
+        "051",
+        include_str!("../../migrations/051_create_allocation_quotas_table.sql"),
+    ),
+    (
+        "052",
+        include_str!("../../migrations/052_create_allocation_quota_customers_table.sql"),
+    ),
+    (
+        "053",
+        include_str!("../../migrations/053_add_order_index_to_failed_notifications.sql"),
+    ),
+    (
+        "054",
+        include_str!("../../migrations/054_add_preferred_language_to_customers.sql"),
+    ),
+    (
+        "055",
+        include_str!("../../migrations/055_create_allocation_quota_orders_table.sql"),
+    ),
+    (
+        "056",
+        include_str!("../../migrations/056_create_customer_purchase_locks_table.sql"),
+    ),
+    (
+        "057",
+        include_str!("../../migrations/057_restore_inventory_quantity_check.sql"),
+    ),
+];
+
 /// データベースマイグレーションを管理する構造体
 pub struct DatabaseMigration {
     pool: Pool<MySql>,
@@ -16,25 +248,78 @@ impl DatabaseMigration {
         Self { pool, logger }
     }
 
+    /// 最新のマイグレーションのバージョン
+    pub fn latest_version() -> &'static str {
+        MIGRATIONS
+            .last()
+            .map(|(version, _)| *version)
+            .unwrap_or_default()
+    }
+
+    /// 未適用のマイグレーションのバージョンを取得
+    /// 起動時の診断で、マイグレーションがすべて適用されているかを確認するために使う
+    ///
+    /// # Returns
+    /// * `Ok(Vec<&str>)` - 未適用のバージョン（古い順）
+    /// * `Err(DatabaseError)` - 適用済みバージョンの取得失敗
+    pub async fn pending_versions(&self) -> Result<Vec<&'static str>, DatabaseError> {
+        let applied: Vec<String> = sqlx::query_scalar("SELECT version FROM schema_migrations")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                DatabaseError::MigrationError(format!("Failed to load applied migrations: {}", e))
+            })?;
+
+        Ok(MIGRATIONS
+            .iter()
+            .map(|(version, _)| *version)
+            .filter(|version| !applied.iter().any(|applied| applied == version))
+            .collect())
+    }
+
     /// マイグレーションを実行
-    /// べき等性を保証（CREATE TABLE IF NOT EXISTS）
+    /// 適用済みのバージョンをschema_migrationsテーブルに記録し、未適用のものだけを実行する
+    /// （ALTER TABLEなど再実行できないマイグレーションにも対応するため）
     pub async fn run(&self) -> Result<(), DatabaseError> {
-        // マイグレーションファイルのリスト
-        let migrations = [
-            include_str!("../../migrations/001_create_orders_table.sql"),
-            include_str!("../../migrations/002_create_order_lines_table.sql"),
-            include_str!("../../migrations/003_create_inventories_table.sql"),
-        ];
+        // 適用済みバージョンを記録するテーブルを作成
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS schema_migrations (
+                version VARCHAR(32) PRIMARY KEY,
+                applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::MigrationError(format!("Failed to create schema_migrations: {}", e))
+        })?;
 
         // 各マイグレーションを順番に実行
-        for (index, migration_sql) in migrations.iter().enumerate() {
+        for &(version, migration_sql) in MIGRATIONS {
+            let applied: i64 =
+                sqlx::query_scalar("SELECT COUNT(*) FROM schema_migrations WHERE version = ?")
+                    .bind(version)
+                    .fetch_one(&self.pool)
+                    .await
+                    .map_err(|e| {
+                        DatabaseError::MigrationError(format!(
+                            "Failed to check migration {}: {}",
+                            version, e
+                        ))
+                    })?;
+            if applied > 0 {
+                continue;
+            }
+
             let mut context = HashMap::new();
-            context.insert("migration_index".to_string(), (index + 1).to_string());
+            context.insert("migration_version".to_string(), version.to_string());
             context.insert("status".to_string(), "starting".to_string());
-            
+
             self.logger.debug(
                 "DatabaseMigration",
-                &format!("Migration {} starting", index + 1),
+                &format!("Migration {} starting", version),
                 None,
                 Some(context),
             );
@@ -43,16 +328,27 @@ impl DatabaseMigration {
                 .execute(&self.pool)
                 .await
                 .map_err(|e| {
-                    DatabaseError::MigrationError(format!("Migration {} failed: {}", index + 1, e))
+                    DatabaseError::MigrationError(format!("Migration {} failed: {}", version, e))
+                })?;
+
+            sqlx::query("INSERT INTO schema_migrations (version) VALUES (?)")
+                .bind(version)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    DatabaseError::MigrationError(format!(
+                        "Failed to record migration {}: {}",
+                        version, e
+                    ))
                 })?;
 
             let mut context = HashMap::new();
-            context.insert("migration_index".to_string(), (index + 1).to_string());
+            context.insert("migration_version".to_string(), version.to_string());
             context.insert("status".to_string(), "completed successfully".to_string());
-            
+
             self.logger.debug(
                 "DatabaseMigration",
-                &format!("Migration {} completed successfully", index + 1),
+                &format!("Migration {} completed successfully", version),
                 None,
                 Some(context),
             );
@@ -0,0 +1,448 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::anonymization::{Anonymizer, ANONYMIZED_ATTRIBUTE_VALUE};
+use crate::domain::model::{CustomerId, ShippingAddress};
+use crate::domain::port::Logger;
+use serde::Serialize;
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, MySqlConnection, Pool, Row};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 複製するテーブル（参照先のテーブルから順に複製する）
+/// それ以外のテーブル（イベント・通知・Webhookの履歴など）は複製せず、複製先では空にする
+pub const COPIED_TABLES: [&str; 8] = [
+    "inventories",
+    "book_prices",
+    "book_translations",
+    "customers",
+    "orders",
+    "order_lines",
+    "order_line_attributes",
+    "order_number_sequences",
+];
+
+/// 1回のクエリで複製する行数のデフォルト値
+const DEFAULT_BATCH_SIZE: u32 = 500;
+
+/// 住所を保持する列（郵便番号, 都道府県, 市区町村, 番地, 建物名）
+const ADDRESS_COLUMNS: [&str; 5] = ["postal_code", "prefecture", "city", "street", "building"];
+
+/// 匿名化したデータの複製結果
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AnonymizationReport {
+    /// テーブルごとの複製した行数（複製した順）
+    pub copied_rows: Vec<(String, u64)>,
+    /// 複製先で空にしたテーブル
+    pub cleared_tables: Vec<String>,
+    /// 付け替えた顧客IDの数
+    pub remapped_customers: usize,
+}
+
+/// 複製する1行（列名と値。値は文字列に変換して読み書きする）
+struct CopiedRow<'a> {
+    columns: &'a [String],
+    values: Vec<Option<String>>,
+}
+
+impl CopiedRow<'_> {
+    fn get(&self, column: &str) -> Option<&str> {
+        self.columns
+            .iter()
+            .position(|c| c == column)
+            .and_then(|i| self.values[i].as_deref())
+    }
+
+    fn set(&mut self, column: &str, value: Option<String>) {
+        if let Some(i) = self.columns.iter().position(|c| c == column) {
+            self.values[i] = value;
+        }
+    }
+
+    /// 値がある列だけを置き換える（NULLの列はNULLのままにする）
+    fn replace(&mut self, column: &str, value: impl FnOnce() -> String) {
+        if self.get(column).is_some() {
+            self.set(column, Some(value()));
+        }
+    }
+}
+
+/// ステージング用のデータセットの作成
+/// 複製元（本番と同じ形のデータベース）の注文・顧客・在庫などを、個人情報を匿名化して複製先のデータベースに書き込む
+/// 注文のステータスや件数・金額はそのまま複製し、顧客IDは付け替えても注文と顧客の参照を保つ
+pub struct DatasetAnonymizer {
+    source: Pool<MySql>,
+    target: Pool<MySql>,
+    logger: Arc<dyn Logger>,
+    batch_size: u32,
+}
+
+impl DatasetAnonymizer {
+    /// 新しいDatasetAnonymizerインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `source` - 複製元のMySQLコネクションプール
+    /// * `target` - 複製先のMySQLコネクションプール（マイグレーション済みであること）
+    /// * `logger` - 進捗を出力するロガー
+    pub fn new(source: Pool<MySql>, target: Pool<MySql>, logger: Arc<dyn Logger>) -> Self {
+        Self {
+            source,
+            target,
+            logger,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// 1回のクエリで複製する行数を設定する（未設定の場合は500行）
+    pub fn with_batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// 匿名化したデータを複製する
+    /// 複製先のschema_migrations以外のテーブルを空にしてから、COPIED_TABLESを順に複製する
+    /// 途中で失敗した場合、複製先は途中までの状態になる（再実行すると最初から複製し直す）
+    ///
+    /// # Arguments
+    /// * `anonymizer` - 個人情報の匿名化サービス
+    ///
+    /// # Returns
+    /// * `Ok(AnonymizationReport)` - 複製結果
+    /// * `Err(DatabaseError)` - クエリの失敗、または匿名化できない値があった場合
+    pub async fn run(
+        &self,
+        anonymizer: &mut Anonymizer,
+    ) -> Result<AnonymizationReport, DatabaseError> {
+        self.observe_cities(anonymizer).await?;
+
+        // 外部キー制約はセッション単位で無効にするため、複製先は1つの接続で書き込む
+        let mut target = self.target.acquire().await.map_err(|e| {
+            DatabaseError::ConnectionError(format!("Failed to acquire target connection: {}", e))
+        })?;
+        execute(&mut target, "SET FOREIGN_KEY_CHECKS = 0").await?;
+
+        let mut report = AnonymizationReport {
+            cleared_tables: self.clear_target(&mut target).await?,
+            ..Default::default()
+        };
+        for table in COPIED_TABLES {
+            let rows = self.copy_table(&mut target, table, anonymizer).await?;
+
+            let mut context = HashMap::new();
+            context.insert("table".to_string(), table.to_string());
+            context.insert("rows".to_string(), rows.to_string());
+            self.logger
+                .info("DatasetAnonymizer", "Table copied", None, Some(context));
+            report.copied_rows.push((table.to_string(), rows));
+        }
+
+        execute(&mut target, "SET FOREIGN_KEY_CHECKS = 1").await?;
+        report.remapped_customers = anonymizer.remapped_customers();
+        Ok(report)
+    }
+
+    /// 住所の置き換えに使う都道府県と市区町村を複製元から記録する
+    async fn observe_cities(&self, anonymizer: &mut Anonymizer) -> Result<(), DatabaseError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT prefecture, city FROM customers WHERE prefecture IS NOT NULL AND city IS NOT NULL
+            UNION
+            SELECT prefecture, city FROM orders WHERE prefecture IS NOT NULL AND city IS NOT NULL
+            "#,
+        )
+        .fetch_all(&self.source)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to load cities: {}", e)))?;
+
+        for row in rows {
+            anonymizer.observe_city(row.get("prefecture"), row.get("city"));
+        }
+        Ok(())
+    }
+
+    /// 複製先のschema_migrations以外のテーブルを空にする
+    async fn clear_target(
+        &self,
+        target: &mut MySqlConnection,
+    ) -> Result<Vec<String>, DatabaseError> {
+        let tables: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT CAST(table_name AS CHAR) FROM information_schema.tables
+            WHERE table_schema = DATABASE() AND table_type = 'BASE TABLE' AND table_name <> 'schema_migrations'
+            ORDER BY table_name
+            "#,
+        )
+        .fetch_all(&mut *target)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to list target tables: {}", e)))?;
+
+        for table in &tables {
+            execute(target, &format!("DELETE FROM `{}`", table)).await?;
+        }
+        Ok(tables)
+    }
+
+    /// テーブルを主キーの順に少しずつ読み、匿名化して複製先に書き込む
+    async fn copy_table(
+        &self,
+        target: &mut MySqlConnection,
+        table: &str,
+        anonymizer: &mut Anonymizer,
+    ) -> Result<u64, DatabaseError> {
+        let columns_rows = sqlx::query(
+            r#"
+            SELECT CAST(column_name AS CHAR) AS column_name, CAST(column_key AS CHAR) AS column_key
+            FROM information_schema.columns
+            WHERE table_schema = DATABASE() AND table_name = ?
+            ORDER BY ordinal_position
+            "#,
+        )
+        .bind(table)
+        .fetch_all(&self.source)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("Failed to load columns of {}: {}", table, e))
+        })?;
+        let columns: Vec<String> = columns_rows
+            .iter()
+            .map(|row| row.get::<String, _>("column_name"))
+            .collect();
+        if columns.is_empty() {
+            return Err(DatabaseError::QueryError(format!(
+                "Table {} does not exist in the source database",
+                table
+            )));
+        }
+        let primary_key: Vec<String> = columns_rows
+            .iter()
+            .filter(|row| row.get::<String, _>("column_key") == "PRI")
+            .map(|row| format!("`{}`", row.get::<String, _>("column_name")))
+            .collect();
+
+        let select = format!(
+            "SELECT {} FROM `{}` ORDER BY {} LIMIT ? OFFSET ?",
+            columns
+                .iter()
+                .map(|c| format!("CAST(`{}` AS CHAR)", c))
+                .collect::<Vec<_>>()
+                .join(", "),
+            table,
+            primary_key.join(", ")
+        );
+        let placeholders = format!("({})", vec!["?"; columns.len()].join(", "));
+        let column_list = columns
+            .iter()
+            .map(|c| format!("`{}`", c))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut copied = 0u64;
+        loop {
+            let rows: Vec<MySqlRow> = sqlx::query(&select)
+                .bind(self.batch_size)
+                .bind(copied)
+                .fetch_all(&self.source)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!("Failed to read {}: {}", table, e))
+                })?;
+            if rows.is_empty() {
+                break;
+            }
+
+            let mut batch = Vec::with_capacity(rows.len());
+            for row in &rows {
+                let mut copied_row = CopiedRow {
+                    columns: &columns,
+                    values: (0..columns.len())
+                        .map(|i| row.get::<Option<String>, _>(i))
+                        .collect(),
+                };
+                anonymize_row(table, &mut copied_row, anonymizer)?;
+                batch.push(copied_row.values);
+            }
+
+            let insert = format!(
+                "INSERT INTO `{}` ({}) VALUES {}",
+                table,
+                column_list,
+                vec![placeholders.as_str(); batch.len()].join(", ")
+            );
+            let mut query = sqlx::query(&insert);
+            for value in batch.into_iter().flatten() {
+                query = query.bind(value);
+            }
+            query.execute(&mut *target).await.map_err(|e| {
+                DatabaseError::QueryError(format!("Failed to write {}: {}", table, e))
+            })?;
+
+            copied += rows.len() as u64;
+            if rows.len() < self.batch_size as usize {
+                break;
+            }
+        }
+        Ok(copied)
+    }
+}
+
+/// 複製先の接続でSQLを実行する
+async fn execute(target: &mut MySqlConnection, sql: &str) -> Result<(), DatabaseError> {
+    sqlx::query(sql)
+        .execute(target)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to execute {}: {}", sql, e)))?;
+    Ok(())
+}
+
+/// テーブルごとに個人情報の列を匿名化する
+fn anonymize_row(
+    table: &str,
+    row: &mut CopiedRow<'_>,
+    anonymizer: &mut Anonymizer,
+) -> Result<(), DatabaseError> {
+    match table {
+        "customers" => {
+            let customer_id = remap_customer_id(row, "id", anonymizer)?;
+            row.replace("name", || anonymizer.person_name());
+            row.set(
+                "email",
+                customer_id.map(|id| anonymizer.email(id).as_str().to_string()),
+            );
+            anonymize_address(table, row, anonymizer)?;
+        }
+        "orders" => {
+            remap_customer_id(row, "customer_id", anonymizer)?;
+            let address = anonymize_address(table, row, anonymizer)?;
+            let original = address
+                .map(|address| serde_json::to_string(&anonymizer.address_input(&address)))
+                .transpose()
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!("Failed to serialize address: {}", e))
+                })?;
+            if row.get("original_shipping_address").is_some() {
+                row.set("original_shipping_address", original);
+            }
+            row.replace("recipient_name", || anonymizer.person_name());
+            row.replace("recipient_phone", || anonymizer.phone());
+            row.replace("tracking_token", || anonymizer.tracking_token().to_string());
+        }
+        "order_line_attributes" => {
+            row.replace("attribute_value", || ANONYMIZED_ATTRIBUTE_VALUE.to_string());
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// 顧客IDの列を付け替える
+fn remap_customer_id(
+    row: &mut CopiedRow<'_>,
+    column: &str,
+    anonymizer: &mut Anonymizer,
+) -> Result<Option<CustomerId>, DatabaseError> {
+    let Some(original) = row.get(column) else {
+        return Ok(None);
+    };
+    let original = CustomerId::from_string(original).map_err(|e| {
+        DatabaseError::QueryError(format!("Invalid customer id {}: {}", original, e))
+    })?;
+    let remapped = anonymizer.customer_id(original);
+    row.set(column, Some(remapped.to_string()));
+    Ok(Some(remapped))
+}
+
+/// 住所の列を同じ都道府県のランダムな住所に置き換える（住所がない行はそのままにする）
+fn anonymize_address(
+    table: &str,
+    row: &mut CopiedRow<'_>,
+    anonymizer: &mut Anonymizer,
+) -> Result<Option<ShippingAddress>, DatabaseError> {
+    let (Some(postal_code), Some(prefecture), Some(city), Some(street)) = (
+        row.get("postal_code"),
+        row.get("prefecture"),
+        row.get("city"),
+        row.get("street"),
+    ) else {
+        return Ok(None);
+    };
+    let invalid = |e| DatabaseError::QueryError(format!("Invalid address in {}: {}", table, e));
+    let original = ShippingAddress::new(
+        postal_code.to_string(),
+        prefecture.to_string(),
+        city.to_string(),
+        street.to_string(),
+        row.get("building").map(str::to_string),
+    )
+    .map_err(invalid)?;
+    let anonymized = anonymizer.shipping_address(&original).map_err(invalid)?;
+
+    for (column, value) in ADDRESS_COLUMNS.into_iter().zip([
+        Some(anonymized.postal_code()),
+        Some(anonymized.prefecture()),
+        Some(anonymized.city()),
+        Some(anonymized.street()),
+        anonymized.building(),
+    ]) {
+        row.set(column, value.map(str::to_string));
+    }
+    Ok(Some(anonymized))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anonymize_row_replaces_personal_columns_of_orders() {
+        let columns: Vec<String> = [
+            "id",
+            "customer_id",
+            "status",
+            "postal_code",
+            "prefecture",
+            "city",
+            "street",
+            "building",
+            "original_shipping_address",
+            "recipient_name",
+            "recipient_phone",
+            "tracking_token",
+        ]
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+        let customer_id = CustomerId::new();
+        let mut row = CopiedRow {
+            columns: &columns,
+            values: vec![
+                Some("7c9e6679-7425-40de-944b-e07fc1f90ae7".to_string()),
+                Some(customer_id.to_string()),
+                Some("Shipped".to_string()),
+                Some("0600001".to_string()),
+                Some("北海道".to_string()),
+                Some("札幌市中央区".to_string()),
+                Some("北一条西3-3-3".to_string()),
+                None,
+                Some("{}".to_string()),
+                None,
+                None,
+                None,
+            ],
+        };
+        let mut anonymizer = Anonymizer::new(1);
+        anonymize_row("orders", &mut row, &mut anonymizer).unwrap();
+
+        let remapped = anonymizer.customer_id(customer_id);
+        assert_eq!(row.get("customer_id"), Some(remapped.to_string().as_str()));
+        assert_eq!(row.get("status"), Some("Shipped"));
+        assert_eq!(row.get("prefecture"), Some("北海道"));
+        assert_ne!(row.get("street"), Some("北一条西3-3-3"));
+        assert_eq!(row.get("building"), None);
+        assert!(row
+            .get("original_shipping_address")
+            .unwrap()
+            .contains(row.get("street").unwrap()));
+        // NULLの列（ギフトでない注文の受取人など）はNULLのままにする
+        assert_eq!(row.get("recipient_name"), None);
+        assert_eq!(row.get("tracking_token"), None);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::adapter::shipping_fee_config::parse_entries;
+use crate::domain::delivery_estimate::DeliveryEstimator;
+use std::collections::HashMap;
+use std::env;
+
+/// 配達予定日の見積もりの設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct DeliveryEstimateConfig {
+    pub estimator: DeliveryEstimator,
+}
+
+impl DeliveryEstimateConfig {
+    /// 環境変数から設定を読み取る
+    /// - DELIVERY_HANDLING_DAYS: 確定から発送までの日数（デフォルト: 1）
+    /// - DELIVERY_DEFAULT_LEAD_TIME_DAYS: 発送から配達までの既定の日数（デフォルト: 2）
+    /// - DELIVERY_PREFECTURE_LEAD_TIMES: 都道府県ごとの発送から配達までの日数（例: "北海道:3,沖縄県:4"）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let defaults = DeliveryEstimator::default();
+
+        let prefecture_lead_times = match env::var("DELIVERY_PREFECTURE_LEAD_TIMES") {
+            Ok(value) => parse_entries("DELIVERY_PREFECTURE_LEAD_TIMES", &value)?
+                .into_iter()
+                .map(|(prefecture, days)| {
+                    let days = u32::try_from(days).map_err(|e| {
+                        ConfigError::InvalidValue(format!(
+                            "Invalid DELIVERY_PREFECTURE_LEAD_TIMES days: {}:{} ({})",
+                            prefecture, days, e
+                        ))
+                    })?;
+                    Ok((prefecture, days))
+                })
+                .collect::<Result<HashMap<_, _>, ConfigError>>()?,
+            Err(_) => defaults.prefecture_lead_times().clone(),
+        };
+
+        Ok(Self {
+            estimator: DeliveryEstimator::new(
+                parse_env("DELIVERY_HANDLING_DAYS", defaults.handling_days())?,
+                parse_env(
+                    "DELIVERY_DEFAULT_LEAD_TIME_DAYS",
+                    defaults.default_lead_time_days(),
+                )?,
+                prefecture_lead_times,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env_overrides_and_validation() {
+        env::set_var("DELIVERY_PREFECTURE_LEAD_TIMES", "沖縄県:5, 鹿児島県:3");
+        env::set_var("DELIVERY_HANDLING_DAYS", "2");
+        let config = DeliveryEstimateConfig::from_env().unwrap();
+        assert_eq!(config.estimator.handling_days(), 2);
+        assert_eq!(config.estimator.lead_time_days("沖縄県"), 5);
+        assert_eq!(config.estimator.lead_time_days("鹿児島県"), 3);
+        assert_eq!(config.estimator.lead_time_days("北海道"), 2);
+
+        env::set_var("DELIVERY_PREFECTURE_LEAD_TIMES", "沖縄県:-1");
+        assert!(DeliveryEstimateConfig::from_env().is_err());
+
+        env::remove_var("DELIVERY_PREFECTURE_LEAD_TIMES");
+        env::remove_var("DELIVERY_HANDLING_DAYS");
+    }
+}
@@ -1,12 +1,132 @@
 // 駆動される側アダプター（リポジトリ実装など）
 
+mod action_link_audit_repository;
+mod action_link_signer;
+mod alerting;
+mod allocation_quota_repository;
+mod book_catalog;
+mod checkout_hold_repository;
 mod console_logger;
+mod customer_repository;
+mod cycle_count_repository;
+mod device_registration_repository;
+mod dispatch_gate;
+mod download_link;
 mod event_bus;
+mod event_journal;
+mod event_sourced_order_repository;
+mod event_store;
+mod failed_notification_repository;
+#[cfg(feature = "test-support")]
+mod in_memory_inventory_repository;
+#[cfg(feature = "test-support")]
+mod in_memory_order_repository;
+mod in_memory_stores;
+mod inbox_repository;
 mod inventory_repository;
+#[cfg(feature = "kafka")]
+mod kafka_event_bus;
+mod legacy_import_repository;
+mod notification_hub;
+mod notification_sender;
+mod object_storage;
+mod order_lock_repository;
+mod order_note_repository;
+mod order_number_generator;
 mod order_repository;
+mod parked_event_repository;
+mod pending_operation_repository;
+mod processed_event_repository;
+mod push_notification;
+#[cfg(feature = "rabbitmq")]
+mod rabbitmq_event_bus;
+mod saga_compensation_repository;
+mod schema_registry;
+mod simulated_carrier;
+mod simulated_payment;
+mod sqlite_book_catalog;
+mod sqlite_inventory_repository;
+mod sqlite_order_repository;
+mod status_override_audit_repository;
+mod subscription_registry_repository;
+mod tracing_logger;
+mod tracking_event_repository;
+mod waitlist_repository;
+mod webhook_delivery_repository;
+mod webhook_sender;
+mod webhook_subscription_repository;
 
+pub use action_link_audit_repository::MySqlActionLinkAuditRepository;
+pub use action_link_signer::HmacActionLinkSigner;
+pub use alerting::{ConsoleAlerting, SlackAlerting, WebhookAlerting};
+pub use allocation_quota_repository::MySqlAllocationQuotaRepository;
+pub use book_catalog::MySqlBookCatalog;
+pub use checkout_hold_repository::MySqlCheckoutHoldRepository;
 pub use console_logger::ConsoleLogger;
+pub use customer_repository::MySqlCustomerRepository;
+pub use cycle_count_repository::MySqlCycleCountRepository;
+pub use device_registration_repository::MySqlDeviceRegistrationRepository;
+pub use dispatch_gate::{DispatchGate, InFlightGuard};
+pub use download_link::TokenDownloadLinkGenerator;
 pub use event_bus::InMemoryEventBus;
-pub use event_bus::EventBusConfig;
+pub use event_bus::{DispatchMode, EventBusConfig};
+pub use event_journal::MySqlEventJournal;
+pub use event_sourced_order_repository::EventSourcedOrderRepository;
+pub use event_store::MySqlEventStore;
+pub use failed_notification_repository::MySqlFailedNotificationRepository;
+#[cfg(feature = "test-support")]
+pub use in_memory_inventory_repository::InMemoryInventoryRepository;
+#[cfg(feature = "test-support")]
+pub use in_memory_order_repository::InMemoryOrderRepository;
+pub use in_memory_stores::{
+    InMemoryActionLinkAuditRepository, InMemoryAllocationQuotaRepository,
+    InMemoryCheckoutHoldRepository, InMemoryCustomerRepository, InMemoryCycleCountRepository,
+    InMemoryDeviceRegistrationRepository, InMemoryEventJournal,
+    InMemoryFailedNotificationRepository, InMemoryInboxRepository,
+    InMemoryLegacyImportRepository, InMemoryOrderLockRepository, InMemoryOrderNoteRepository,
+    InMemoryOrderNumberGenerator, InMemoryParkedEventRepository,
+    InMemoryPendingOperationRepository, InMemoryProcessedEventRepository,
+    InMemorySagaCompensationRepository, InMemoryStatusOverrideAuditRepository,
+    InMemorySubscriptionRegistryRepository, InMemoryTrackingEventRepository,
+    InMemoryWaitlistRepository, InMemoryWebhookDeliveryRepository,
+    InMemoryWebhookSubscriptionRepository,
+};
+pub use inbox_repository::MySqlInboxRepository;
 pub use inventory_repository::MySqlInventoryRepository;
+#[cfg(feature = "kafka")]
+pub use kafka_event_bus::{
+    partition_key, KafkaConfig, KafkaDeadLetter, KafkaEventBus, KafkaEventConsumer,
+    DEFAULT_KAFKA_TOPIC,
+};
+pub use legacy_import_repository::MySqlLegacyImportRepository;
+pub use notification_hub::InMemoryNotificationHub;
+pub use notification_sender::SimulatedNotificationSender;
+pub use object_storage::{LocalFileObjectStorage, S3CompatibleObjectStorage};
+pub use order_lock_repository::MySqlOrderLockRepository;
+pub use order_note_repository::MySqlOrderNoteRepository;
+pub use order_number_generator::MySqlOrderNumberGenerator;
 pub use order_repository::MySqlOrderRepository;
+pub use parked_event_repository::MySqlParkedEventRepository;
+pub use pending_operation_repository::MySqlPendingOperationRepository;
+pub use processed_event_repository::MySqlProcessedEventRepository;
+pub use push_notification::FcmPushNotificationAdapter;
+#[cfg(feature = "rabbitmq")]
+pub use rabbitmq_event_bus::{
+    routing_key, DeliveryDisposition, RabbitMqConfig, RabbitMqEventBus, RabbitMqEventConsumer,
+    DEFAULT_RABBITMQ_EXCHANGE,
+};
+pub use saga_compensation_repository::MySqlSagaCompensationRepository;
+pub use schema_registry::{HttpSchemaRegistry, InMemorySchemaRegistry};
+pub use simulated_carrier::SimulatedCarrierAdapter;
+pub use simulated_payment::SimulatedPaymentGateway;
+pub use sqlite_book_catalog::SqliteBookCatalog;
+pub use sqlite_inventory_repository::SqliteInventoryRepository;
+pub use sqlite_order_repository::SqliteOrderRepository;
+pub use status_override_audit_repository::MySqlStatusOverrideAuditRepository;
+pub use subscription_registry_repository::MySqlSubscriptionRegistryRepository;
+pub use tracing_logger::TracingLogger;
+pub use tracking_event_repository::MySqlTrackingEventRepository;
+pub use waitlist_repository::MySqlWaitlistRepository;
+pub use webhook_delivery_repository::MySqlWebhookDeliveryRepository;
+pub use webhook_sender::HttpWebhookSender;
+pub use webhook_subscription_repository::MySqlWebhookSubscriptionRepository;
@@ -0,0 +1,99 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::action_link::{ActionLinkOutcome, ActionLinkUse, CustomerAction};
+use crate::domain::model::OrderId;
+use crate::domain::port::{ActionLinkAuditRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+/// MySQLアクションリンクの使用記録リポジトリ
+/// MySQLデータベース（action_link_usesテーブル）にアクションリンクの使用記録を保存する
+#[derive(Clone)]
+pub struct MySqlActionLinkAuditRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlActionLinkAuditRepository {
+    /// 新しいMySQLアクションリンクの使用記録リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlActionLinkAuditRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から使用記録を構築する
+    fn record_from_row(row: &MySqlRow) -> Result<ActionLinkUse, RepositoryError> {
+        let parse_uuid = |column: &str| {
+            Uuid::parse_str(row.get(column)).map_err(|e| {
+                RepositoryError::FetchFailed(format!("{}の解析に失敗しました: {}", column, e))
+            })
+        };
+        Ok(ActionLinkUse {
+            id: parse_uuid("id")?,
+            link_id: parse_uuid("link_id")?,
+            action: CustomerAction::from_string(row.get("action")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("操作の解析に失敗しました: {}", e))
+            })?,
+            order_id: OrderId::from_uuid(parse_uuid("order_id")?),
+            expires_at: row.get::<DateTime<Utc>, _>("expires_at"),
+            outcome: ActionLinkOutcome::from_string(row.get("outcome")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("結果の解析に失敗しました: {}", e))
+            })?,
+            error: row.get("error"),
+            used_at: row.get::<DateTime<Utc>, _>("used_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl ActionLinkAuditRepository for MySqlActionLinkAuditRepository {
+    #[tracing::instrument(name = "db.action_link_uses.save", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "action_link_uses", order_id = %record.order_id, outcome = %record.outcome), err)]
+    async fn save(&self, record: &ActionLinkUse) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO action_link_uses (id, link_id, action, order_id, expires_at, outcome, error, used_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(record.id.to_string())
+        .bind(record.link_id.to_string())
+        .bind(record.action.as_str())
+        .bind(record.order_id.to_string())
+        .bind(record.expires_at)
+        .bind(record.outcome.to_string())
+        .bind(&record.error)
+        .bind(record.used_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("アクションリンクの使用記録の保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.action_link_uses.find_by_order", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "action_link_uses", order_id = %order_id), err)]
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<ActionLinkUse>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT id, link_id, action, order_id, expires_at, outcome, error, used_at FROM action_link_uses WHERE order_id = ? ORDER BY used_at ASC, id ASC",
+        )
+        .bind(order_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("アクションリンクの使用記録の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::record_from_row).collect()
+    }
+}
@@ -0,0 +1,121 @@
+use crate::domain::action_link::{ActionLinkClaims, CustomerAction};
+use crate::domain::model::OrderId;
+use crate::domain::port::{ActionLinkError, ActionLinkSigner};
+use chrono::DateTime;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+/// HMAC-SHA256によるアクションリンクの署名の実装
+/// トークンは"{リンクID}.{操作}.{注文ID}.{有効期限（UNIX秒）}.{署名}"の形式で、
+/// 署名は署名部分を除いた文字列をシークレットでHMAC-SHA256した16進数
+/// セッションなしで使えるよう、トークンだけで内容を検証できる
+pub struct HmacActionLinkSigner {
+    secret: Vec<u8>,
+}
+
+impl HmacActionLinkSigner {
+    /// 新しいアクションリンクの署名を作成
+    ///
+    /// # Arguments
+    /// * `secret` - 署名に使うシークレット（変更すると発行済みのリンクはすべて無効になる）
+    pub fn new(secret: &str) -> Self {
+        Self {
+            secret: secret.as_bytes().to_vec(),
+        }
+    }
+
+    fn mac(&self, message: &str) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMACは任意の長さの鍵を受け付ける");
+        mac.update(message.as_bytes());
+        mac
+    }
+}
+
+impl ActionLinkSigner for HmacActionLinkSigner {
+    fn sign(&self, claims: &ActionLinkClaims) -> String {
+        let message = format!(
+            "{}.{}.{}.{}",
+            claims.link_id.simple(),
+            claims.action.as_str(),
+            claims.order_id.as_uuid().simple(),
+            claims.expires_at.timestamp()
+        );
+        let signature = hex::encode(self.mac(&message).finalize().into_bytes());
+        format!("{}.{}", message, signature)
+    }
+
+    fn verify(&self, token: &str) -> Result<ActionLinkClaims, ActionLinkError> {
+        let (message, signature) = token
+            .rsplit_once('.')
+            .ok_or_else(|| ActionLinkError::Malformed("署名がありません".to_string()))?;
+        let signature = hex::decode(signature)
+            .map_err(|_| ActionLinkError::Malformed("署名が16進数ではありません".to_string()))?;
+        // 署名の比較は定数時間で行う
+        self.mac(message)
+            .verify_slice(&signature)
+            .map_err(|_| ActionLinkError::InvalidSignature)?;
+
+        let parts: Vec<&str> = message.split('.').collect();
+        let [link_id, action, order_id, expires_at] = parts.as_slice() else {
+            return Err(ActionLinkError::Malformed(
+                "トークンの要素の数が正しくありません".to_string(),
+            ));
+        };
+        Ok(ActionLinkClaims {
+            link_id: Uuid::parse_str(link_id).map_err(|e| {
+                ActionLinkError::Malformed(format!("リンクIDの解析に失敗しました: {}", e))
+            })?,
+            action: CustomerAction::from_string(action)
+                .map_err(|e| ActionLinkError::Malformed(e.to_string()))?,
+            order_id: Uuid::parse_str(order_id)
+                .map(OrderId::from_uuid)
+                .map_err(|e| {
+                    ActionLinkError::Malformed(format!("注文IDの解析に失敗しました: {}", e))
+                })?,
+            expires_at: expires_at
+                .parse::<i64>()
+                .ok()
+                .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+                .ok_or_else(|| {
+                    ActionLinkError::Malformed("有効期限の解析に失敗しました".to_string())
+                })?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeDelta, Utc};
+
+    #[test]
+    fn test_verify_accepts_only_tokens_signed_with_the_same_secret() {
+        let signer = HmacActionLinkSigner::new("action-link-secret");
+        let claims = ActionLinkClaims::new(
+            CustomerAction::CancelOrder,
+            OrderId::new(),
+            Utc::now(),
+            TimeDelta::hours(72),
+        );
+        let token = signer.sign(&claims);
+
+        assert_eq!(signer.verify(&token).unwrap(), claims);
+        assert!(matches!(
+            HmacActionLinkSigner::new("another-secret").verify(&token),
+            Err(ActionLinkError::InvalidSignature)
+        ));
+
+        // 操作を書き換えたトークンは署名が一致しない
+        let tampered = token.replacen("cancel_order", "confirm_delivery", 1);
+        assert!(matches!(
+            signer.verify(&tampered),
+            Err(ActionLinkError::InvalidSignature)
+        ));
+        assert!(matches!(
+            signer.verify("not-a-token"),
+            Err(ActionLinkError::Malformed(_))
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::domain::alerting::{Alert, AlertSeverity};
+use crate::domain::port::{AlertingError, AlertingPort, Logger};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// アラートを追加コンテキストに変換
+fn alert_context(alert: &Alert) -> HashMap<String, String> {
+    let mut context = HashMap::new();
+    context.insert("anomaly".to_string(), alert.kind.name().to_string());
+    context.insert(
+        "observed_value".to_string(),
+        alert.observed_value.to_string(),
+    );
+    context.insert("threshold".to_string(), alert.threshold.to_string());
+    context.insert(
+        "window_seconds".to_string(),
+        alert.window_seconds.to_string(),
+    );
+    context
+}
+
+/// コンソールアラート実装
+/// ロガー経由でアラートを出力する
+pub struct ConsoleAlerting {
+    logger: Arc<dyn Logger>,
+}
+
+impl ConsoleAlerting {
+    pub fn new(logger: Arc<dyn Logger>) -> Self {
+        Self { logger }
+    }
+}
+
+#[async_trait]
+impl AlertingPort for ConsoleAlerting {
+    async fn send_alert(&self, alert: &Alert) -> Result<(), AlertingError> {
+        let context = Some(alert_context(alert));
+        match alert.severity {
+            AlertSeverity::Warning => self.logger.warn("Alert", &alert.message, None, context),
+            AlertSeverity::Critical => self.logger.error("Alert", &alert.message, None, context),
+        }
+        Ok(())
+    }
+}
+
+/// Webhookアラート実装
+/// アラートをJSONとして指定されたURLにPOSTする
+pub struct WebhookAlerting {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookAlerting {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+        }
+    }
+}
+
+#[async_trait]
+impl AlertingPort for WebhookAlerting {
+    async fn send_alert(&self, alert: &Alert) -> Result<(), AlertingError> {
+        self.client
+            .post(&self.url)
+            .json(alert)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| AlertingError::DeliveryFailed(e.to_string()))?;
+        Ok(())
+    }
+}
+
+/// Slackアラート実装
+/// Slack Incoming Webhookにメッセージを送信する
+pub struct SlackAlerting {
+    client: reqwest::Client,
+    webhook_url: String,
+}
+
+impl SlackAlerting {
+    pub fn new(webhook_url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            webhook_url,
+        }
+    }
+
+    /// Slackに送信するメッセージ本文を作成
+    fn format_message(alert: &Alert) -> String {
+        let icon = match alert.severity {
+            AlertSeverity::Warning => ":warning:",
+            AlertSeverity::Critical => ":rotating_light:",
+        };
+        format!(
+            "{} *[{:?}] {}*\n{}\n観測値: {} / 閾値: {}",
+            icon,
+            alert.severity,
+            alert.kind.name(),
+            alert.message,
+            alert.observed_value,
+            alert.threshold
+        )
+    }
+}
+
+#[async_trait]
+impl AlertingPort for SlackAlerting {
+    async fn send_alert(&self, alert: &Alert) -> Result<(), AlertingError> {
+        let payload = serde_json::json!({ "text": Self::format_message(alert) });
+        self.client
+            .post(&self.webhook_url)
+            .json(&payload)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| AlertingError::DeliveryFailed(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::alerting::AnomalyKind;
+    use chrono::Utc;
+
+    #[test]
+    fn test_slack_message_contains_alert_details() {
+        let alert = Alert {
+            kind: AnomalyKind::ReservationFailureRate,
+            severity: AlertSeverity::Warning,
+            message: "在庫予約失敗率が閾値を超えました".to_string(),
+            observed_value: 0.1,
+            threshold: 0.05,
+            window_seconds: 600,
+            detected_at: Utc::now(),
+        };
+
+        let message = SlackAlerting::format_message(&alert);
+
+        assert!(message.contains("reservation_failure_rate"));
+        assert!(message.contains("在庫予約失敗率が閾値を超えました"));
+        assert!(message.contains("0.05"));
+    }
+}
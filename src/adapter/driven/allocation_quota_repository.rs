@@ -0,0 +1,312 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::{
+    AllocationQuota, BookId, CustomerId, OrderAllocation, OrderId, SalesChannel,
+};
+use crate::domain::port::{AllocationQuotaRepository, RepositoryError};
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+
+// MySQL関連のインポート
+use sqlx::{MySql, Pool, QueryBuilder, Row};
+
+/// MySQL割当枠リポジトリ
+/// MySQLデータベースで書籍ごとの割当枠を管理する
+/// 設定と販売チャネルごとの割当済みの数量はallocation_quotasテーブル、
+/// 顧客ごとの割当済みの数量はallocation_quota_customersテーブル、
+/// 注文ごとの割当はallocation_quota_ordersテーブルに保存する
+#[derive(Clone)]
+pub struct MySqlAllocationQuotaRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlAllocationQuotaRepository {
+    /// 新しいMySQL割当枠リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlAllocationQuotaRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// 販売チャネルごとの数量をJSONから復元する
+    fn channel_quantities_from_json(
+        json: &str,
+        label: &str,
+    ) -> Result<BTreeMap<SalesChannel, u32>, RepositoryError> {
+        serde_json::from_str(json).map_err(|e| {
+            RepositoryError::FetchFailed(format!("{}の解析に失敗しました: {}", label, e))
+        })
+    }
+
+    /// 販売チャネルごとの数量をJSONにする
+    fn channel_quantities_to_json(
+        quantities: &BTreeMap<SalesChannel, u32>,
+        label: &str,
+    ) -> Result<String, RepositoryError> {
+        serde_json::to_string(quantities).map_err(|e| {
+            RepositoryError::OperationFailed(format!("{}のシリアライズに失敗しました: {}", label, e))
+        })
+    }
+}
+
+#[async_trait]
+impl AllocationQuotaRepository for MySqlAllocationQuotaRepository {
+    #[tracing::instrument(name = "db.allocation_quotas.find_by_book_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "allocation_quotas", book_id = %book_id), err)]
+    async fn find_by_book_id(
+        &self,
+        book_id: BookId,
+    ) -> Result<Option<AllocationQuota>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT per_customer_cap, channel_shares, allocation_pool, channel_allocated FROM allocation_quotas WHERE book_id = ?",
+        )
+        .bind(book_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("割当枠の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let customer_rows = sqlx::query(
+            "SELECT customer_id, quantity FROM allocation_quota_customers WHERE book_id = ?",
+        )
+        .bind(book_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("顧客ごとの割当の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+        let customer_allocated = customer_rows
+            .iter()
+            .map(|row| {
+                let customer_id = CustomerId::from_string(&row.get::<String, _>("customer_id"))
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!("顧客IDの解析に失敗しました: {}", e))
+                    })?;
+                Ok((customer_id, row.get::<u32, _>("quantity")))
+            })
+            .collect::<Result<HashMap<_, _>, RepositoryError>>()?;
+
+        let order_rows = sqlx::query(
+            "SELECT order_id, customer_id, sales_channel, quantity FROM allocation_quota_orders WHERE book_id = ?",
+        )
+        .bind(book_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("注文ごとの割当の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+        let order_allocations = order_rows
+            .iter()
+            .map(|row| {
+                let order_id = OrderId::from_string(&row.get::<String, _>("order_id"))
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+                    })?;
+                let customer_id = CustomerId::from_string(&row.get::<String, _>("customer_id"))
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!("顧客IDの解析に失敗しました: {}", e))
+                    })?;
+                let channel = SalesChannel::from_string(&row.get::<String, _>("sales_channel"))
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!(
+                            "販売チャネルの解析に失敗しました: {}",
+                            e
+                        ))
+                    })?;
+                let allocation = OrderAllocation {
+                    customer_id,
+                    channel,
+                    quantity: row.get::<u32, _>("quantity"),
+                };
+                Ok((order_id, allocation))
+            })
+            .collect::<Result<HashMap<_, _>, RepositoryError>>()?;
+
+        let quota = AllocationQuota::new(
+            book_id,
+            row.get::<Option<u32>, _>("per_customer_cap"),
+            Self::channel_quantities_from_json(
+                &row.get::<String, _>("channel_shares"),
+                "割当比率",
+            )?,
+            row.get::<u32, _>("allocation_pool"),
+        )
+        .map_err(|e| RepositoryError::FetchFailed(format!("割当枠の再構築に失敗しました: {}", e)))?
+        .with_allocations(
+            Self::channel_quantities_from_json(
+                &row.get::<String, _>("channel_allocated"),
+                "販売チャネルごとの割当",
+            )?,
+            customer_allocated,
+            order_allocations,
+        );
+        Ok(Some(quota))
+    }
+
+    #[tracing::instrument(name = "db.allocation_quotas.save", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "allocation_quotas", book_id = %quota.book_id()), err)]
+    async fn save(&self, quota: &AllocationQuota) -> Result<(), RepositoryError> {
+        let channel_shares = Self::channel_quantities_to_json(quota.channel_shares(), "割当比率")?;
+        let channel_allocated = Self::channel_quantities_to_json(
+            quota.channel_allocated(),
+            "販売チャネルごとの割当",
+        )?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO allocation_quotas (book_id, per_customer_cap, channel_shares, allocation_pool, channel_allocated)
+            VALUES (?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                per_customer_cap = VALUES(per_customer_cap),
+                channel_shares = VALUES(channel_shares),
+                allocation_pool = VALUES(allocation_pool),
+                channel_allocated = VALUES(channel_allocated)
+            "#,
+        )
+        .bind(quota.book_id().to_string())
+        .bind(quota.per_customer_cap())
+        .bind(channel_shares)
+        .bind(quota.allocation_pool())
+        .bind(channel_allocated)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("割当枠の保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        // 顧客ごとの割当を置き換える
+        sqlx::query("DELETE FROM allocation_quota_customers WHERE book_id = ?")
+            .bind(quota.book_id().to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("顧客ごとの割当の削除に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        if !quota.customer_allocated().is_empty() {
+            let mut query = QueryBuilder::<MySql>::new(
+                "INSERT INTO allocation_quota_customers (book_id, customer_id, quantity) ",
+            );
+            query.push_values(quota.customer_allocated(), |mut row, (customer_id, quantity)| {
+                row.push_bind(quota.book_id().to_string())
+                    .push_bind(customer_id.to_string())
+                    .push_bind(*quantity);
+            });
+            query
+                .build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!("顧客ごとの割当の保存に失敗しました: {}", e))
+                })
+                .map_err(RepositoryError::from)?;
+        }
+
+        // 注文ごとの割当を置き換える
+        sqlx::query("DELETE FROM allocation_quota_orders WHERE book_id = ?")
+            .bind(quota.book_id().to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("注文ごとの割当の削除に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        if !quota.order_allocations().is_empty() {
+            let mut query = QueryBuilder::<MySql>::new(
+                "INSERT INTO allocation_quota_orders (book_id, order_id, customer_id, sales_channel, quantity) ",
+            );
+            query.push_values(quota.order_allocations(), |mut row, (order_id, allocation)| {
+                row.push_bind(quota.book_id().to_string())
+                    .push_bind(order_id.to_string())
+                    .push_bind(allocation.customer_id.to_string())
+                    .push_bind(allocation.channel.to_string())
+                    .push_bind(allocation.quantity);
+            });
+            query
+                .build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!("注文ごとの割当の保存に失敗しました: {}", e))
+                })
+                .map_err(RepositoryError::from)?;
+        }
+
+        // トランザクションをコミット
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.allocation_quotas.delete", skip_all, fields(db.system = "mysql", db.operation = "DELETE", db.sql.table = "allocation_quotas", book_id = %book_id), err)]
+    async fn delete(&self, book_id: BookId) -> Result<bool, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        sqlx::query("DELETE FROM allocation_quota_customers WHERE book_id = ?")
+            .bind(book_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("顧客ごとの割当の削除に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+        sqlx::query("DELETE FROM allocation_quota_orders WHERE book_id = ?")
+            .bind(book_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("注文ごとの割当の削除に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+        let result = sqlx::query("DELETE FROM allocation_quotas WHERE book_id = ?")
+            .bind(book_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("割当枠の削除に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        // トランザクションをコミット
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::book_translation::{BookTitles, Language};
+use crate::domain::model::{BookId, Money};
+use crate::domain::port::{BookCatalog, RepositoryError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, QueryBuilder, Row};
+
+/// MySQL書籍カタログ
+/// MySQLデータベース（book_prices・book_translationsテーブル）で書籍の現在の価格と言語ごとのタイトルを管理する
+#[derive(Clone)]
+pub struct MySqlBookCatalog {
+    pool: Pool<MySql>,
+}
+
+impl MySqlBookCatalog {
+    /// 新しいMySQL書籍カタログを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlBookCatalogのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から書籍IDと価格を構築する
+    fn price_from_row(row: &MySqlRow) -> Result<(BookId, Money), RepositoryError> {
+        let book_id = BookId::from_string(&row.get::<String, _>("book_id")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+        })?;
+        let price = Money::new(row.get("unit_price_amount"), row.get("unit_price_currency"))
+            .map_err(|e| {
+                RepositoryError::FetchFailed(format!("金額の構築に失敗しました: {}", e))
+            })?;
+        Ok((book_id, price))
+    }
+}
+
+#[async_trait]
+impl BookCatalog for MySqlBookCatalog {
+    #[tracing::instrument(name = "db.book_prices.set_price", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "book_prices", book_id = %book_id), err)]
+    async fn set_price(&self, book_id: BookId, price: Money) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO book_prices (book_id, unit_price_amount, unit_price_currency)
+            VALUES (?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                unit_price_amount = VALUES(unit_price_amount),
+                unit_price_currency = VALUES(unit_price_currency)
+            "#,
+        )
+        .bind(book_id.to_string())
+        .bind(price.amount())
+        .bind(price.currency())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("書籍の価格の保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.book_prices.find_price", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "book_prices", book_id = %book_id), err)]
+    async fn find_price(&self, book_id: BookId) -> Result<Option<Money>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT book_id, unit_price_amount, unit_price_currency FROM book_prices WHERE book_id = ?",
+        )
+        .bind(book_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("書籍の価格の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        row.map(|row| Self::price_from_row(&row).map(|(_, price)| price))
+            .transpose()
+    }
+
+    #[tracing::instrument(name = "db.book_prices.find_prices", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "book_prices", book_count = book_ids.len()), err)]
+    async fn find_prices(
+        &self,
+        book_ids: &[BookId],
+    ) -> Result<HashMap<BookId, Money>, RepositoryError> {
+        if book_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut query = QueryBuilder::<MySql>::new(
+            "SELECT book_id, unit_price_amount, unit_price_currency FROM book_prices WHERE book_id IN (",
+        );
+        let mut separated = query.separated(", ");
+        for book_id in book_ids {
+            separated.push_bind(book_id.to_string());
+        }
+        separated.push_unseparated(")");
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("書籍の価格の取得に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+        rows.iter().map(Self::price_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.book_translations.set_title", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "book_translations", book_id = %book_id, language = %language), err)]
+    async fn set_title(
+        &self,
+        book_id: BookId,
+        language: Language,
+        title: &str,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO book_translations (book_id, language, title)
+            VALUES (?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                title = VALUES(title)
+            "#,
+        )
+        .bind(book_id.to_string())
+        .bind(language.code())
+        .bind(title)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("書籍のタイトルの保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.book_translations.find_titles", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "book_translations", book_count = book_ids.len()), err)]
+    async fn find_titles(
+        &self,
+        book_ids: &[BookId],
+    ) -> Result<HashMap<BookId, BookTitles>, RepositoryError> {
+        if book_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut query = QueryBuilder::<MySql>::new(
+            "SELECT book_id, language, title FROM book_translations WHERE book_id IN (",
+        );
+        let mut separated = query.separated(", ");
+        for book_id in book_ids {
+            separated.push_bind(book_id.to_string());
+        }
+        separated.push_unseparated(")");
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("書籍のタイトルの取得に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        let mut titles: HashMap<BookId, BookTitles> = HashMap::new();
+        for row in rows {
+            let book_id = BookId::from_string(&row.get::<String, _>("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            let code = row.get::<String, _>("language");
+            // 対応をやめた言語のタイトルは読み飛ばす
+            let Some(language) = Language::from_tag(&code) else {
+                continue;
+            };
+            titles
+                .entry(book_id)
+                .or_default()
+                .insert(language, row.get("title"));
+        }
+        Ok(titles)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::checkout_hold::CheckoutHold;
+use crate::domain::model::{BookId, OrderId};
+use crate::domain::port::{CheckoutHoldRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, QueryBuilder, Row};
+
+/// MySQL在庫仮押さえリポジトリ
+/// MySQLデータベース（checkout_holdsテーブル）でチェックアウト中の注文の在庫の仮押さえを管理する
+#[derive(Clone)]
+pub struct MySqlCheckoutHoldRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlCheckoutHoldRepository {
+    /// 新しいMySQL在庫仮押さえリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlCheckoutHoldRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から仮押さえを構築する
+    fn hold_from_row(row: &MySqlRow) -> Result<CheckoutHold, RepositoryError> {
+        let parse_error =
+            |e: uuid::Error| RepositoryError::FetchFailed(format!("IDの解析に失敗しました: {}", e));
+        Ok(CheckoutHold {
+            order_id: OrderId::from_string(&row.get::<String, _>("order_id"))
+                .map_err(parse_error)?,
+            book_id: BookId::from_string(&row.get::<String, _>("book_id")).map_err(parse_error)?,
+            quantity: row.get("quantity"),
+            expires_at: row.get::<DateTime<Utc>, _>("expires_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl CheckoutHoldRepository for MySqlCheckoutHoldRepository {
+    #[tracing::instrument(name = "db.checkout_holds.replace_for_order", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "checkout_holds", order_id = %order_id, hold_count = holds.len()), err)]
+    async fn replace_for_order(
+        &self,
+        order_id: OrderId,
+        holds: &[CheckoutHold],
+    ) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 既存の仮押さえを削除
+        sqlx::query("DELETE FROM checkout_holds WHERE order_id = ?")
+            .bind(order_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("仮押さえの削除に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        if !holds.is_empty() {
+            let mut query = QueryBuilder::<MySql>::new(
+                "INSERT INTO checkout_holds (order_id, book_id, quantity, expires_at) ",
+            );
+            query.push_values(holds, |mut row, hold| {
+                row.push_bind(hold.order_id.to_string())
+                    .push_bind(hold.book_id.to_string())
+                    .push_bind(hold.quantity)
+                    .push_bind(hold.expires_at);
+            });
+            query
+                .build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("仮押さえの保存に失敗しました: {}", e)))
+                .map_err(RepositoryError::from)?;
+        }
+
+        // トランザクションをコミット
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.checkout_holds.find_by_order", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "checkout_holds", order_id = %order_id), err)]
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<CheckoutHold>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT order_id, book_id, quantity, expires_at FROM checkout_holds WHERE order_id = ?",
+        )
+        .bind(order_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("仮押さえの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::hold_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.checkout_holds.find_expired", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "checkout_holds"), err)]
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<CheckoutHold>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT order_id, book_id, quantity, expires_at FROM checkout_holds WHERE expires_at <= ? ORDER BY expires_at",
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("期限切れの仮押さえの取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::hold_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.checkout_holds.remove_order", skip_all, fields(db.system = "mysql", db.operation = "DELETE", db.sql.table = "checkout_holds", order_id = %order_id), err)]
+    async fn remove_order(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM checkout_holds WHERE order_id = ?")
+            .bind(order_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("仮押さえの削除に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+}
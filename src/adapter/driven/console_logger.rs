@@ -98,6 +98,35 @@ impl ConsoleLogger {
     pub fn new() -> Self {
         Self
     }
+
+    /// ログエントリを作成
+    /// コンテキストの execution_time_ms（ハンドラーが計測した処理時間）は実行時間として出力する
+    fn entry(
+        level: LogLevel,
+        component: &str,
+        message: &str,
+        correlation_id: Option<Uuid>,
+        context: Option<HashMap<String, String>>,
+    ) -> LogEntry {
+        let mut entry = LogEntry::new(level, message.to_string(), component.to_string());
+
+        if let Some(corr_id) = correlation_id {
+            entry = entry.with_correlation_id(corr_id);
+        }
+
+        if let Some(ctx) = context {
+            for (key, value) in ctx {
+                entry = match (key.as_str(), value.parse::<u64>()) {
+                    ("execution_time_ms", Ok(millis)) => {
+                        entry.with_execution_time(Duration::from_millis(millis))
+                    }
+                    _ => entry.with_context(key, value),
+                };
+            }
+        }
+
+        entry
+    }
 }
 
 impl Default for ConsoleLogger {
@@ -114,18 +143,7 @@ impl Logger for ConsoleLogger {
         correlation_id: Option<Uuid>,
         context: Option<HashMap<String, String>>,
     ) {
-        let mut entry = LogEntry::new(LogLevel::Debug, message.to_string(), component.to_string());
-
-        if let Some(corr_id) = correlation_id {
-            entry = entry.with_correlation_id(corr_id);
-        }
-
-        if let Some(ctx) = context {
-            for (key, value) in ctx {
-                entry = entry.with_context(key, value);
-            }
-        }
-
+        let entry = Self::entry(LogLevel::Debug, component, message, correlation_id, context);
         println!("{}", entry.format());
     }
 
@@ -136,18 +154,7 @@ impl Logger for ConsoleLogger {
         correlation_id: Option<Uuid>,
         context: Option<HashMap<String, String>>,
     ) {
-        let mut entry = LogEntry::new(LogLevel::Info, message.to_string(), component.to_string());
-
-        if let Some(corr_id) = correlation_id {
-            entry = entry.with_correlation_id(corr_id);
-        }
-
-        if let Some(ctx) = context {
-            for (key, value) in ctx {
-                entry = entry.with_context(key, value);
-            }
-        }
-
+        let entry = Self::entry(LogLevel::Info, component, message, correlation_id, context);
         println!("{}", entry.format());
     }
 
@@ -158,18 +165,7 @@ impl Logger for ConsoleLogger {
         correlation_id: Option<Uuid>,
         context: Option<HashMap<String, String>>,
     ) {
-        let mut entry = LogEntry::new(LogLevel::Warning, message.to_string(), component.to_string());
-
-        if let Some(corr_id) = correlation_id {
-            entry = entry.with_correlation_id(corr_id);
-        }
-
-        if let Some(ctx) = context {
-            for (key, value) in ctx {
-                entry = entry.with_context(key, value);
-            }
-        }
-
+        let entry = Self::entry(LogLevel::Warning, component, message, correlation_id, context);
         println!("{}", entry.format());
     }
 
@@ -180,18 +176,7 @@ impl Logger for ConsoleLogger {
         correlation_id: Option<Uuid>,
         context: Option<HashMap<String, String>>,
     ) {
-        let mut entry = LogEntry::new(LogLevel::Error, message.to_string(), component.to_string());
-
-        if let Some(corr_id) = correlation_id {
-            entry = entry.with_correlation_id(corr_id);
-        }
-
-        if let Some(ctx) = context {
-            for (key, value) in ctx {
-                entry = entry.with_context(key, value);
-            }
-        }
-
+        let entry = Self::entry(LogLevel::Error, component, message, correlation_id, context);
         eprintln!("{}", entry.format());
     }
 }
@@ -248,6 +233,19 @@ mod tests {
         assert!(formatted.contains("key1=value1"));
     }
 
+    #[test]
+    fn test_entry_takes_execution_time_from_context() {
+        let mut context = HashMap::new();
+        context.insert("execution_time_ms".to_string(), "12".to_string());
+        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
+
+        let entry = ConsoleLogger::entry(LogLevel::Debug, "TestComponent", "Test message", None, Some(context));
+
+        assert_eq!(entry.execution_time, Some(Duration::from_millis(12)));
+        assert_eq!(entry.additional_context.len(), 1);
+        assert!(entry.format().contains("[execution_time: 12ms]"));
+    }
+
     #[test]
     fn test_console_logger_creation() {
         let logger = ConsoleLogger::new();
@@ -0,0 +1,153 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::book_translation::Language;
+use crate::domain::model::{Customer, CustomerId, EmailAddress, ShippingAddress};
+use crate::domain::port::{CustomerRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+const SELECT_COLUMNS: &str = "SELECT id, name, email, postal_code, prefecture, city, street, building, preferred_language, registered_at FROM customers";
+
+/// MySQL顧客リポジトリ
+/// MySQLデータベース（customersテーブル）を使用して顧客集約を永続化する
+#[derive(Clone)]
+pub struct MySqlCustomerRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlCustomerRepository {
+    /// 新しいMySQL顧客リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlCustomerRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から顧客を再構築する
+    fn customer_from_row(row: &MySqlRow) -> Result<Customer, RepositoryError> {
+        let id = CustomerId::from_string(row.get("id")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("顧客IDの解析に失敗しました: {}", e))
+        })?;
+        let email = EmailAddress::new(row.get("email")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("メールアドレスの解析に失敗しました: {}", e))
+        })?;
+
+        // 既定の配送先住所を再構築
+        let default_shipping_address =
+            if let (Some(postal_code), Some(prefecture), Some(city), Some(street)) = (
+                row.get::<Option<String>, _>("postal_code"),
+                row.get::<Option<String>, _>("prefecture"),
+                row.get::<Option<String>, _>("city"),
+                row.get::<Option<String>, _>("street"),
+            ) {
+                Some(
+                    ShippingAddress::new(
+                        postal_code,
+                        prefecture,
+                        city,
+                        street,
+                        row.get::<Option<String>, _>("building"),
+                    )
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!(
+                            "既定の配送先住所の構築に失敗しました: {}",
+                            e
+                        ))
+                    })?,
+                )
+            } else {
+                None
+            };
+        let preferred_language = Language::from_tag(row.get("preferred_language")).ok_or_else(|| {
+            RepositoryError::FetchFailed(format!(
+                "通知の言語の解析に失敗しました: {}",
+                row.get::<String, _>("preferred_language")
+            ))
+        })?;
+
+        Ok(Customer::reconstruct(
+            id,
+            row.get("name"),
+            email,
+            default_shipping_address,
+            preferred_language,
+            row.get::<DateTime<Utc>, _>("registered_at"),
+        ))
+    }
+}
+
+#[async_trait]
+impl CustomerRepository for MySqlCustomerRepository {
+    #[tracing::instrument(name = "db.customers.save", skip_all, fields(db.system = "mysql", db.operation = "UPSERT", db.sql.table = "customers", customer_id = %customer.id()), err)]
+    async fn save(&self, customer: &Customer) -> Result<(), RepositoryError> {
+        let address = customer.default_shipping_address();
+        sqlx::query(
+            r#"
+            INSERT INTO customers (id, name, email, postal_code, prefecture, city, street, building, preferred_language, registered_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                name = VALUES(name),
+                email = VALUES(email),
+                postal_code = VALUES(postal_code),
+                prefecture = VALUES(prefecture),
+                city = VALUES(city),
+                street = VALUES(street),
+                building = VALUES(building),
+                preferred_language = VALUES(preferred_language)
+            "#,
+        )
+        .bind(customer.id().to_string())
+        .bind(customer.name())
+        .bind(customer.email().as_str())
+        .bind(address.map(|a| a.postal_code()))
+        .bind(address.map(|a| a.prefecture()))
+        .bind(address.map(|a| a.city()))
+        .bind(address.map(|a| a.street()))
+        .bind(address.and_then(|a| a.building()))
+        .bind(customer.preferred_language().code())
+        .bind(customer.registered_at())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("顧客の保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.customers.find_by_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "customers", customer_id = %customer_id), err)]
+    async fn find_by_id(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Option<Customer>, RepositoryError> {
+        let row = sqlx::query(&format!("{} WHERE id = ?", SELECT_COLUMNS))
+            .bind(customer_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("顧客の取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        row.as_ref().map(Self::customer_from_row).transpose()
+    }
+
+    #[tracing::instrument(name = "db.customers.find_by_email", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "customers"), err)]
+    async fn find_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Option<Customer>, RepositoryError> {
+        let row = sqlx::query(&format!("{} WHERE email = ?", SELECT_COLUMNS))
+            .bind(email.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("顧客の取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        row.as_ref().map(Self::customer_from_row).transpose()
+    }
+}
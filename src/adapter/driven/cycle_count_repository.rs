@@ -0,0 +1,160 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::{BookId, CycleCount, CycleCountId, CycleCountLine, CycleCountStatus};
+use crate::domain::port::{CycleCountRepository, RepositoryError};
+use async_trait::async_trait;
+
+// MySQL関連のインポート
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL棚卸しリポジトリ
+/// MySQLデータベースを使用して棚卸しを永続化する
+#[derive(Clone)]
+pub struct MySqlCycleCountRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlCycleCountRepository {
+    /// 新しいMySQL棚卸しリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlCycleCountRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CycleCountRepository for MySqlCycleCountRepository {
+    #[tracing::instrument(name = "db.cycle_counts.save", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "cycle_counts", cycle_count_id = %cycle_count.id()), err)]
+    async fn save(&self, cycle_count: &CycleCount) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 棚卸しデータをcycle_countsテーブルにUPSERT
+        sqlx::query(
+            r#"
+            INSERT INTO cycle_counts (id, status, approved_by)
+            VALUES (?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                approved_by = VALUES(approved_by)
+            "#,
+        )
+        .bind(cycle_count.id().to_string())
+        .bind(cycle_count.status().to_string())
+        .bind(cycle_count.approved_by())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("棚卸しの保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        // 既存の棚卸し明細を削除
+        sqlx::query("DELETE FROM cycle_count_lines WHERE cycle_count_id = ?")
+            .bind(cycle_count.id().to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("棚卸し明細の削除に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 棚卸し明細データをcycle_count_linesテーブルにINSERT
+        for line in cycle_count.lines() {
+            sqlx::query(
+                r#"
+                INSERT INTO cycle_count_lines (cycle_count_id, book_id, counted_quantity, system_quantity)
+                VALUES (?, ?, ?, ?)
+                "#,
+            )
+            .bind(cycle_count.id().to_string())
+            .bind(line.book_id().to_string())
+            .bind(line.counted_quantity())
+            .bind(line.system_quantity())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("棚卸し明細の保存に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+        }
+
+        // トランザクションをコミット
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.cycle_counts.find_by_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "cycle_counts", cycle_count_id = %cycle_count_id), err)]
+    async fn find_by_id(
+        &self,
+        cycle_count_id: CycleCountId,
+    ) -> Result<Option<CycleCount>, RepositoryError> {
+        let row = sqlx::query("SELECT id, status, approved_by FROM cycle_counts WHERE id = ?")
+            .bind(cycle_count_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("棚卸しの取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        let row = match row {
+            Some(row) => row,
+            None => return Ok(None),
+        };
+
+        let status = CycleCountStatus::from_string(row.get("status")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("棚卸しステータスの解析に失敗しました: {}", e))
+        })?;
+        let approved_by: Option<String> = row.get("approved_by");
+
+        // 棚卸し明細を登録順に取得
+        let line_rows = sqlx::query(
+            r#"
+            SELECT book_id, counted_quantity, system_quantity
+            FROM cycle_count_lines
+            WHERE cycle_count_id = ?
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(cycle_count_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("棚卸し明細の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut lines = Vec::new();
+        for line_row in line_rows {
+            let book_id = BookId::from_string(line_row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            lines.push(CycleCountLine::reconstruct(
+                book_id,
+                line_row.get::<Option<u32>, _>("counted_quantity"),
+                line_row.get::<Option<u32>, _>("system_quantity"),
+            ));
+        }
+
+        Ok(Some(CycleCount::reconstruct(
+            cycle_count_id,
+            status,
+            lines,
+            approved_by,
+        )))
+    }
+}
@@ -0,0 +1,101 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::{CustomerId, DevicePlatform, DeviceRegistration, DeviceToken};
+use crate::domain::port::{DeviceRegistrationRepository, RepositoryError};
+use async_trait::async_trait;
+
+// MySQL関連のインポート
+use sqlx::{MySql, Pool, Row};
+
+/// MySQLデバイス登録リポジトリ
+/// MySQLデータベースを使用して顧客のデバイストークンを永続化する
+#[derive(Clone)]
+pub struct MySqlDeviceRegistrationRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlDeviceRegistrationRepository {
+    /// 新しいMySQLデバイス登録リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlDeviceRegistrationRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DeviceRegistrationRepository for MySqlDeviceRegistrationRepository {
+    #[tracing::instrument(name = "db.customer_devices.save", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "customer_devices", customer_id = %registration.customer_id()), err)]
+    async fn save(&self, registration: &DeviceRegistration) -> Result<(), RepositoryError> {
+        // デバイス登録をcustomer_devicesテーブルにUPSERT
+        sqlx::query(
+            r#"
+            INSERT INTO customer_devices (customer_id, token, platform)
+            VALUES (?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                platform = VALUES(platform)
+            "#,
+        )
+        .bind(registration.customer_id().to_string())
+        .bind(registration.token().as_str())
+        .bind(registration.platform().to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("デバイス登録の保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.customer_devices.delete", skip_all, fields(db.system = "mysql", db.operation = "DELETE", db.sql.table = "customer_devices", customer_id = %customer_id), err)]
+    async fn delete(
+        &self,
+        customer_id: CustomerId,
+        token: &DeviceToken,
+    ) -> Result<bool, RepositoryError> {
+        let result =
+            sqlx::query("DELETE FROM customer_devices WHERE customer_id = ? AND token = ?")
+                .bind(customer_id.to_string())
+                .bind(token.as_str())
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!("デバイス登録の削除に失敗しました: {}", e))
+                })
+                .map_err(RepositoryError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(name = "db.customer_devices.find_by_customer", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "customer_devices", customer_id = %customer_id), err)]
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<DeviceRegistration>, RepositoryError> {
+        // 顧客のデバイス登録を登録順に取得
+        let rows = sqlx::query(
+            "SELECT token, platform FROM customer_devices WHERE customer_id = ? ORDER BY id ASC",
+        )
+        .bind(customer_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("デバイス登録の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut registrations = Vec::new();
+        for row in rows {
+            let token = DeviceToken::new(row.get("token")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("デバイストークンの解析に失敗しました: {}", e))
+            })?;
+            let platform = DevicePlatform::from_string(row.get("platform")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("プラットフォームの解析に失敗しました: {}", e))
+            })?;
+            registrations.push(DeviceRegistration::new(customer_id, token, platform));
+        }
+
+        Ok(registrations)
+    }
+}
@@ -0,0 +1,208 @@
+use crate::domain::event_bus::EventDispatchStatus;
+use crate::domain::port::EventDispatchControl;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// イベントの配信の一時停止と処理中のイベント数
+#[derive(Debug, Default)]
+struct GateState {
+    paused_since: Option<DateTime<Utc>>,
+    in_flight: usize,
+}
+
+/// イベントの配信ゲート
+/// ローリングデプロイの前に新しいイベントの配信を止め、処理中のハンドラーが終わるのを待つために使う
+/// 一時停止の判定と処理中のイベント数の加算は同じロックで行うため、一時停止した後に処理を始めるイベントはない
+#[derive(Debug, Default)]
+pub struct DispatchGate {
+    state: Mutex<GateState>,
+    /// 一時停止・再開・処理の終了の通知
+    changed: Notify,
+}
+
+/// 処理中のイベント（破棄すると処理中のイベント数から外れる）
+#[must_use]
+pub struct InFlightGuard<'a> {
+    gate: &'a DispatchGate,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.gate.lock_state().in_flight -= 1;
+        self.gate.changed.notify_waiters();
+    }
+}
+
+impl DispatchGate {
+    /// 配信中のゲートを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, GateState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// 一時停止中か
+    pub fn is_paused(&self) -> bool {
+        self.lock_state().paused_since.is_some()
+    }
+
+    /// 一時停止中でなければ、イベントの処理を始める
+    ///
+    /// # Returns
+    /// * `Some(InFlightGuard)` - 処理を始めた（処理を終えたら破棄する）
+    /// * `None` - 一時停止中
+    pub fn try_enter(&self) -> Option<InFlightGuard<'_>> {
+        let mut state = self.lock_state();
+        if state.paused_since.is_some() {
+            return None;
+        }
+        state.in_flight += 1;
+        Some(InFlightGuard { gate: self })
+    }
+
+    /// 一時停止中でもイベントの処理を始める（再開時に、ためたイベントを配信し終えるまで一時停止を解除しない場合に使う）
+    pub fn enter_while_paused(&self) -> InFlightGuard<'_> {
+        self.lock_state().in_flight += 1;
+        InFlightGuard { gate: self }
+    }
+
+    /// 一時停止中の場合は再開を待ってから、イベントの処理を始める（ブローカーからの受信用）
+    pub async fn enter(&self) -> InFlightGuard<'_> {
+        loop {
+            let changed = self.changed.notified();
+            if let Some(guard) = self.try_enter() {
+                return guard;
+            }
+            changed.await;
+        }
+    }
+
+    /// 一時停止中の場合は再開を待つ（ブローカーから次のメッセージを受信する前に使う）
+    pub async fn wait_until_resumed(&self) {
+        loop {
+            let changed = self.changed.notified();
+            if !self.is_paused() {
+                return;
+            }
+            changed.await;
+        }
+    }
+
+    /// 新しいイベントの処理を止め、処理中のイベントが終わるのを待つ
+    /// 既に一時停止中の場合は、一時停止した日時を変えずに待つ
+    ///
+    /// # Arguments
+    /// * `now` - 一時停止した日時
+    /// * `drain_timeout` - 処理中のイベントが終わるのを待つ上限
+    ///
+    /// # Returns
+    /// * `true` - 処理中のイベントがなくなった
+    /// * `false` - 待つ上限を過ぎても処理中のイベントが残っている（一時停止はしている）
+    pub async fn pause(&self, now: DateTime<Utc>, drain_timeout: Duration) -> bool {
+        self.lock_state().paused_since.get_or_insert(now);
+        self.changed.notify_waiters();
+        tokio::time::timeout(drain_timeout, self.wait_idle())
+            .await
+            .is_ok()
+    }
+
+    /// 処理中のイベントがなくなるまで待つ
+    async fn wait_idle(&self) {
+        loop {
+            let changed = self.changed.notified();
+            if self.lock_state().in_flight == 0 {
+                return;
+            }
+            changed.await;
+        }
+    }
+
+    /// 一時停止を解除する
+    ///
+    /// # Returns
+    /// * `true` - 一時停止を解除した
+    /// * `false` - 一時停止していなかった
+    pub fn resume(&self) -> bool {
+        let resumed = self.lock_state().paused_since.take().is_some();
+        self.changed.notify_waiters();
+        resumed
+    }
+
+    /// 一時停止の状態と処理中のイベント数
+    ///
+    /// # Arguments
+    /// * `buffered_events` - 一時停止中にためているイベント数
+    pub fn status(&self, buffered_events: usize) -> EventDispatchStatus {
+        let state = self.lock_state();
+        EventDispatchStatus {
+            paused: state.paused_since.is_some(),
+            paused_since: state.paused_since,
+            in_flight_events: state.in_flight,
+            buffered_events,
+        }
+    }
+}
+
+/// ブローカーから受信するプロセスの配信の制御
+/// 一時停止中は受信しないため、イベントはブローカーに残り、ためておくイベントはない
+#[async_trait]
+impl EventDispatchControl for DispatchGate {
+    async fn pause_dispatch(&self, drain_timeout: Duration) -> EventDispatchStatus {
+        self.pause(Utc::now(), drain_timeout).await;
+        self.status(0)
+    }
+
+    async fn resume_dispatch(&self) -> usize {
+        self.resume();
+        0
+    }
+
+    fn dispatch_status(&self) -> EventDispatchStatus {
+        self.status(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_pause_waits_for_in_flight_events_and_blocks_new_ones_until_resumed() {
+        let gate = Arc::new(DispatchGate::new());
+        let in_flight = gate.try_enter().unwrap();
+
+        // 処理中のイベントがある間は、待つ上限を過ぎても一時停止だけはする
+        assert!(!gate.pause(Utc::now(), Duration::from_millis(10)).await);
+        assert!(gate.try_enter().is_none());
+        assert_eq!(gate.status(0).in_flight_events, 1);
+
+        let pausing = tokio::spawn({
+            let gate = gate.clone();
+            async move { gate.pause(Utc::now(), Duration::from_secs(5)).await }
+        });
+        drop(in_flight);
+        assert!(pausing.await.unwrap());
+
+        let waiting = tokio::spawn({
+            let gate = gate.clone();
+            async move {
+                let _guard = gate.enter().await;
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!waiting.is_finished());
+
+        assert!(gate.resume());
+        waiting.await.unwrap();
+        assert!(!gate.resume());
+        let status = gate.status(0);
+        assert!(!status.paused);
+        assert_eq!(status.in_flight_events, 0);
+    }
+}
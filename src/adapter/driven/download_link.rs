@@ -0,0 +1,78 @@
+use crate::domain::model::{BookId, DownloadLink, OrderId};
+use crate::domain::port::{DownloadLinkError, DownloadLinkGenerator};
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use uuid::Uuid;
+
+/// トークン付きダウンロードリンク生成の実装
+/// 推測できないトークンと有効期限を持つURLを発行する
+/// 実際の配信ではCDNの署名付きURLなどに置き換える想定
+pub struct TokenDownloadLinkGenerator {
+    base_url: String,
+    ttl: TimeDelta,
+}
+
+impl TokenDownloadLinkGenerator {
+    /// 新しいダウンロードリンク生成器を作成
+    ///
+    /// # Arguments
+    /// * `base_url` - ダウンロードURLのベース（例: https://downloads.example.com）
+    /// * `ttl` - リンクの有効期間
+    pub fn new(base_url: String, ttl: TimeDelta) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            ttl,
+        }
+    }
+}
+
+#[async_trait]
+impl DownloadLinkGenerator for TokenDownloadLinkGenerator {
+    async fn generate_download_link(
+        &self,
+        order_id: OrderId,
+        book_id: BookId,
+    ) -> Result<DownloadLink, DownloadLinkError> {
+        let url = format!(
+            "{}/downloads/{}/{}?token={}",
+            self.base_url,
+            order_id,
+            book_id,
+            Uuid::new_v4().simple()
+        );
+        Ok(DownloadLink::new(book_id, url, Utc::now() + self.ttl))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_generate_download_link() {
+        let generator = TokenDownloadLinkGenerator::new(
+            "https://downloads.example.com/".to_string(),
+            TimeDelta::hours(72),
+        );
+        let order_id = OrderId::new();
+        let book_id = BookId::new();
+
+        let first = generator
+            .generate_download_link(order_id, book_id)
+            .await
+            .unwrap();
+        let second = generator
+            .generate_download_link(order_id, book_id)
+            .await
+            .unwrap();
+
+        assert_eq!(first.book_id(), book_id);
+        assert!(first.url().starts_with(&format!(
+            "https://downloads.example.com/downloads/{}/{}?token=",
+            order_id, book_id
+        )));
+        assert!(first.expires_at() > Utc::now() + TimeDelta::hours(71));
+        // リンクごとに異なるトークンが発行される
+        assert_ne!(first.url(), second.url());
+    }
+}
@@ -1,19 +1,55 @@
-use crate::domain::event::DomainEvent;
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::adapter::driven::dispatch_gate::DispatchGate;
+use crate::adapter::telemetry;
+use crate::domain::alerting::{Alert, AlertSeverity, AnomalyKind};
+use crate::domain::event::{DomainEvent, EVENT_TYPES};
 use crate::domain::event_bus::{
-    DeliveryFailedHandlerWrapper, DynEventHandler, EventHandler, HandlerError,
-    InventoryReservationFailedHandlerWrapper,
-    OrderCancelledHandlerWrapper, OrderConfirmedHandlerWrapper,
-    OrderDeliveredHandlerWrapper, OrderShippedHandlerWrapper,
+    CarrierTrackingUpdatedHandlerWrapper, CheckoutHoldExpiredHandlerWrapper,
+    CustomerBecameRepeatBuyerHandlerWrapper, DeadLetter,
+    DeliveryFailedHandlerWrapper, DigitalItemsFulfilledHandlerWrapper, DynEventHandler,
+    EventDispatchStatus, EventFilter, EventHandler, FulfillmentSlaBreachedHandlerWrapper, HandlerError,
+    HandlerErrorContext, HighValueOrderPlacedHandlerWrapper, InventoryAdjustedHandlerWrapper,
+    InventoryReleasedHandlerWrapper, InventoryReservationFailedHandlerWrapper,
+    InventoryReservedHandlerWrapper, ItemsRestockedHandlerWrapper, OrderCancelledHandlerWrapper,
+    OrderConfirmedHandlerWrapper, OrderDeliveredHandlerWrapper, OrderHeldHandlerWrapper,
+    OrderReleasedHandlerWrapper, OrderShippedHandlerWrapper,
+    OrderStatusForcefullyChangedHandlerWrapper, PausedEventHandling,
+    PreOrderActivatedHandlerWrapper, ReturnApprovedHandlerWrapper, ReturnRequestedHandlerWrapper,
     SagaCompensationCompletedHandlerWrapper, SagaCompensationStartedHandlerWrapper,
-    ShippingFailedHandlerWrapper,
+    SagaTimedOutHandlerWrapper, ShippingAddressChangedHandlerWrapper,
+    ShippingFailedHandlerWrapper, SubscriptionState, SubscriptionStatus,
+    WaitlistJoinedHandlerWrapper, WaitlistPromotedHandlerWrapper,
+};
+use crate::domain::event_trace::{EventTrace, EventTraceBuffer, HandlerOutcome, HandlerTrace};
+use crate::domain::handler_health::{HandlerHealth, HandlerHealthPolicy};
+use crate::domain::load_shedding::{
+    HandlerCriticality, LoadShedder, LoadSheddingPolicy, LoadSheddingStatus, LoadSheddingTransition,
+};
+use crate::domain::port::{
+    AlertingPort, DeadLetterMonitor, EventBus, EventBusError, EventJournal, EventStreamMonitor,
+    EventDispatchControl, EventTraceMonitor, LiveEventSource, SubscriptionManager,
+};
+use crate::domain::projection::EventStreamHead;
+use crate::domain::retry_policy::{
+    DeadLetterRouting, DeliveryGuarantee, RetryPolicies, RetryPolicy,
 };
-use crate::domain::port::{EventBus, EventBusError};
 use crate::domain::serialization::EventSerializer;
 use async_trait::async_trait;
-use std::collections::VecDeque;
-use std::sync::Arc;
-use std::time::{Duration, SystemTime};
-use tokio::sync::{Mutex, RwLock};
+use chrono::{DateTime, Utc};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
+use tokio::task::JoinHandle;
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// ライブイベントの受信側が受信せずにためておけるイベント数（超えた分は古い順に取りこぼす）
+const LIVE_EVENT_CAPACITY: usize = 256;
 
 /// 失敗したイベント処理の情報
 #[allow(dead_code)]
@@ -22,6 +58,8 @@ pub struct FailedEventProcessing {
     pub event: DomainEvent,
     pub handler_name: String,
     pub error: String,
+    /// 失敗したイベント・集約・処理ステップ
+    pub context: HandlerErrorContext,
     pub attempt_count: u32,
     pub first_failed_at: SystemTime,
     pub last_failed_at: SystemTime,
@@ -36,37 +74,347 @@ pub struct DeadLetterEntry {
     pub added_at: SystemTime,
 }
 
+/// イベントの配信方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DispatchMode {
+    /// publishの呼び出し中にすべてのハンドラーを実行する
+    #[default]
+    Inline,
+    /// イベントをレーンにバッファし、バックグラウンドのワーカーで非同期に処理する
+    /// 同じ集約のイベントは集約IDのハッシュで同じレーンに振り分けられて発行順に処理され、
+    /// 異なるレーンのイベントは並行して処理される
+    Buffered {
+        /// ワーカーレーンの数
+        lanes: usize,
+    },
+}
+
 /// イベントバス設定
 #[derive(Debug, Clone)]
 pub struct EventBusConfig {
-    /// 最大リトライ回数
-    pub max_retry_attempts: u32,
-    /// リトライ間隔
-    pub retry_delay: Duration,
+    /// イベントタイプごとのリトライポリシー（試行回数・待機時間・リトライを使い切った場合の扱い）
+    pub retry_policies: RetryPolicies,
     /// デッドレターキューの最大サイズ
     pub dead_letter_queue_max_size: usize,
     /// ハンドラータイムアウト
     pub handler_timeout: Duration,
+    /// イベントの配信方式
+    pub dispatch_mode: DispatchMode,
+    /// 失敗し続けるハンドラーの自動一時停止（Noneの場合は一時停止しない）
+    pub handler_health: Option<HandlerHealthPolicy>,
+    /// 配信のトレースを保持する件数（0の場合はトレースモードを無効にする）
+    pub trace_capacity: usize,
+    /// 配信待ちのイベントがたまったときの負荷制限（バッファ付き配信のみ、Noneの場合は負荷制限しない）
+    pub load_shedding: Option<LoadSheddingPolicy>,
 }
 
 impl Default for EventBusConfig {
     fn default() -> Self {
         Self {
-            max_retry_attempts: 3,
-            retry_delay: Duration::from_millis(1000),
+            retry_policies: RetryPolicies::default(),
             dead_letter_queue_max_size: 1000,
             handler_timeout: Duration::from_secs(30),
+            dispatch_mode: DispatchMode::Inline,
+            handler_health: None,
+            trace_capacity: 0,
+            load_shedding: None,
+        }
+    }
+}
+
+impl EventBusConfig {
+    /// 環境変数から設定を読み取る
+    /// - EVENT_DISPATCH_LANES: 1以上を指定するとバッファ付きの非同期配信になる（デフォルト: 0 = 同期配信）
+    /// - EVENT_RETRY_MAX_ATTEMPTS / EVENT_RETRY_BACKOFF_MS / EVENT_RETRY_BACKOFF_MULTIPLIER /
+    ///   EVENT_RETRY_MAX_BACKOFF_MS / EVENT_RETRY_DEAD_LETTER: 既定のリトライポリシー
+    /// - EVENT_RETRY_POLICY_OVERRIDES: イベントタイプごとのリトライポリシー
+    ///   （「イベントタイプ=試行回数:待機ms:倍率:上限ms:dead_letter|discard」をカンマ区切り、
+    ///   例: "OrderConfirmed=5:200:2:5000:dead_letter,FulfillmentSlaBreached=1:0:1:0:discard"）
+    /// - HANDLER_AUTO_PAUSE: trueの場合、失敗し続けるハンドラーを自動で一時停止する（デフォルト: false）
+    /// - HANDLER_AUTO_PAUSE_FAILURE_RATE / HANDLER_AUTO_PAUSE_WINDOW_SECONDS /
+    ///   HANDLER_AUTO_PAUSE_MIN_DELIVERIES / HANDLER_AUTO_PAUSE_COOLDOWN_SECONDS: 自動一時停止のポリシー
+    /// - EVENT_TRACE_CAPACITY: 1以上を指定するとトレースモードになり、直近の指定件数のイベントの配信を記録する（デフォルト: 0 = 無効）
+    /// - EVENT_LOAD_SHEDDING: trueの場合、配信待ちのイベントがたまると重要でないハンドラーへの配信を見送る（デフォルト: false）
+    /// - EVENT_LOAD_SHEDDING_QUEUE_DEPTH / EVENT_LOAD_SHEDDING_RESUME_DEPTH: 負荷制限を始める・終える配信待ちのイベント数
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_env_with_retry_defaults(RetryPolicy::default())
+    }
+
+    /// 環境変数から設定を読み取る
+    /// EVENT_RETRY_*が設定されていない項目は指定したリトライポリシーの値を使用（設定ファイルの値を環境変数で上書きする）
+    pub fn from_env_with_retry_defaults(defaults: RetryPolicy) -> Result<Self, ConfigError> {
+        let lanes: usize = parse_env("EVENT_DISPATCH_LANES", 0)?;
+        let dispatch_mode = match lanes {
+            0 => DispatchMode::Inline,
+            lanes => DispatchMode::Buffered { lanes },
+        };
+
+        let dead_letter = match std::env::var("EVENT_RETRY_DEAD_LETTER") {
+            Ok(value) => parse_dead_letter_routing("EVENT_RETRY_DEAD_LETTER", &value)?,
+            Err(_) => defaults.dead_letter,
+        };
+        let default_policy = RetryPolicy::new(
+            parse_env("EVENT_RETRY_MAX_ATTEMPTS", defaults.max_attempts)?,
+            parse_env("EVENT_RETRY_BACKOFF_MS", defaults.initial_backoff_ms)?,
+            parse_env(
+                "EVENT_RETRY_BACKOFF_MULTIPLIER",
+                defaults.backoff_multiplier,
+            )?,
+            parse_env("EVENT_RETRY_MAX_BACKOFF_MS", defaults.max_backoff_ms)?,
+            dead_letter,
+        )
+        .map_err(|e| ConfigError::InvalidValue(format!("Invalid EVENT_RETRY_*: {}", e)))?;
+
+        let mut overrides = BTreeMap::new();
+        if let Ok(value) = std::env::var("EVENT_RETRY_POLICY_OVERRIDES") {
+            for entry in value.split(',').filter(|entry| !entry.trim().is_empty()) {
+                let (event_type, policy) = parse_retry_policy_override(entry)?;
+                overrides.insert(event_type, policy);
+            }
+        }
+
+        let handler_health = if parse_env("HANDLER_AUTO_PAUSE", false)? {
+            Some(parse_handler_health_policy()?)
+        } else {
+            None
+        };
+
+        let load_shedding = if parse_env("EVENT_LOAD_SHEDDING", false)? {
+            Some(parse_load_shedding_policy()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            retry_policies: RetryPolicies {
+                default_policy,
+                overrides,
+            },
+            dispatch_mode,
+            handler_health,
+            trace_capacity: parse_env("EVENT_TRACE_CAPACITY", 0)?,
+            load_shedding,
+            ..Self::default()
+        })
+    }
+}
+
+/// 負荷制限のポリシーを環境変数から読み取る
+fn parse_load_shedding_policy() -> Result<LoadSheddingPolicy, ConfigError> {
+    let defaults = LoadSheddingPolicy::default();
+    let policy = LoadSheddingPolicy {
+        shed_at_depth: parse_env("EVENT_LOAD_SHEDDING_QUEUE_DEPTH", defaults.shed_at_depth)?,
+        resume_at_depth: parse_env(
+            "EVENT_LOAD_SHEDDING_RESUME_DEPTH",
+            defaults.resume_at_depth,
+        )?,
+    };
+    if policy.resume_at_depth >= policy.shed_at_depth {
+        return Err(ConfigError::InvalidValue(format!(
+            "EVENT_LOAD_SHEDDING_RESUME_DEPTH ({}) must be less than EVENT_LOAD_SHEDDING_QUEUE_DEPTH ({})",
+            policy.resume_at_depth, policy.shed_at_depth
+        )));
+    }
+    Ok(policy)
+}
+
+/// ハンドラーの自動一時停止のポリシーを環境変数から読み取る
+fn parse_handler_health_policy() -> Result<HandlerHealthPolicy, ConfigError> {
+    let defaults = HandlerHealthPolicy::default();
+    let policy = HandlerHealthPolicy {
+        failure_rate: parse_env("HANDLER_AUTO_PAUSE_FAILURE_RATE", defaults.failure_rate)?,
+        window: Duration::from_secs(parse_env(
+            "HANDLER_AUTO_PAUSE_WINDOW_SECONDS",
+            defaults.window.as_secs(),
+        )?),
+        min_deliveries: parse_env(
+            "HANDLER_AUTO_PAUSE_MIN_DELIVERIES",
+            defaults.min_deliveries,
+        )?,
+        cooldown: Duration::from_secs(parse_env(
+            "HANDLER_AUTO_PAUSE_COOLDOWN_SECONDS",
+            defaults.cooldown.as_secs(),
+        )?),
+    };
+    if !(policy.failure_rate > 0.0 && policy.failure_rate <= 1.0) {
+        return Err(ConfigError::InvalidValue(format!(
+            "Invalid HANDLER_AUTO_PAUSE_FAILURE_RATE: {} (must be in (0, 1])",
+            policy.failure_rate
+        )));
+    }
+    if policy.window.is_zero() || policy.cooldown.is_zero() {
+        return Err(ConfigError::InvalidValue(
+            "HANDLER_AUTO_PAUSE_WINDOW_SECONDS and HANDLER_AUTO_PAUSE_COOLDOWN_SECONDS must be greater than 0"
+                .to_string(),
+        ));
+    }
+    Ok(policy)
+}
+
+/// デッドレターの扱いを解析する
+fn parse_dead_letter_routing(name: &str, value: &str) -> Result<DeadLetterRouting, ConfigError> {
+    DeadLetterRouting::from_string(value.trim())
+        .map_err(|_| ConfigError::InvalidValue(format!("Invalid {}: {}", name, value)))
+}
+
+/// EVENT_RETRY_POLICY_OVERRIDESの1エントリ（「イベントタイプ=試行回数:待機ms:倍率:上限ms:扱い」）を解析する
+fn parse_retry_policy_override(entry: &str) -> Result<(String, RetryPolicy), ConfigError> {
+    let invalid = || {
+        ConfigError::InvalidValue(format!(
+            "Invalid EVENT_RETRY_POLICY_OVERRIDES entry: {}",
+            entry
+        ))
+    };
+    let (event_type, policy) = entry.split_once('=').ok_or_else(invalid)?;
+    let event_type = event_type.trim();
+    if !EVENT_TYPES.contains(&event_type) {
+        return Err(ConfigError::InvalidValue(format!(
+            "Unknown event type in EVENT_RETRY_POLICY_OVERRIDES: {}",
+            event_type
+        )));
+    }
+
+    let fields: Vec<&str> = policy.split(':').map(str::trim).collect();
+    let [max_attempts, initial_backoff_ms, backoff_multiplier, max_backoff_ms, dead_letter] =
+        fields[..]
+    else {
+        return Err(invalid());
+    };
+    let policy = RetryPolicy::new(
+        max_attempts.parse().map_err(|_| invalid())?,
+        initial_backoff_ms.parse().map_err(|_| invalid())?,
+        backoff_multiplier.parse().map_err(|_| invalid())?,
+        max_backoff_ms.parse().map_err(|_| invalid())?,
+        parse_dead_letter_routing("EVENT_RETRY_POLICY_OVERRIDES", dead_letter)?,
+    )
+    .map_err(|_| invalid())?;
+
+    Ok((event_type.to_string(), policy))
+}
+
+/// ワーカーレーンへの送信側（イベントと発行元のスパン）
+type LaneSender = mpsc::UnboundedSender<(DomainEvent, tracing::Span)>;
+
+/// 集約IDからワーカーレーンを決定する
+fn lane_for(aggregate_id: &str, lanes: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    aggregate_id.hash(&mut hasher);
+    (hasher.finish() % lanes as u64) as usize
+}
+
+/// 購読しているハンドラーと、購読時に指定した配信保証
+#[derive(Clone)]
+struct Subscription {
+    /// 購読の名前（ハンドラーの型名）
+    name: String,
+    event_type: &'static str,
+    handler: Arc<dyn DynEventHandler>,
+    delivery_guarantee: DeliveryGuarantee,
+    /// 購読時に指定した重要度
+    criticality: HandlerCriticality,
+    /// 購読時に指定した絞り込み条件
+    filter: Option<EventFilter>,
+    /// 一時停止の状態（配信中のイベントとも共有するためArcで持つ）
+    control: Arc<std::sync::Mutex<SubscriptionControl>>,
+}
+
+/// 購読の一時停止の状態
+#[derive(Default)]
+struct SubscriptionControl {
+    paused: Option<PausedEventHandling>,
+    /// 一時停止中にためているイベント（発行順）
+    buffered_events: VecDeque<DomainEvent>,
+    skipped_events: u64,
+    /// 負荷制限中に配信を見送ったイベント数
+    shed_events: u64,
+    /// 直近の配信結果（自動一時停止の判定用）
+    health: HandlerHealth,
+    /// 自動で一時停止している場合の、再開を試みる日時
+    auto_paused_until: Option<DateTime<Utc>>,
+}
+
+impl Subscription {
+    fn lock_control(&self) -> std::sync::MutexGuard<'_, SubscriptionControl> {
+        self.control.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// ハンドラーが扱うイベントで、絞り込み条件を満たすか
+    fn accepts(&self, event: &DomainEvent) -> bool {
+        self.handler.can_handle(event)
+            && self
+                .filter
+                .as_ref()
+                .is_none_or(|filter| filter.matches(event))
+    }
+
+    fn status(&self) -> SubscriptionStatus {
+        let control = self.lock_control();
+        SubscriptionStatus {
+            handler_name: self.name.clone(),
+            event_type: self.event_type.to_string(),
+            delivery_guarantee: self.delivery_guarantee,
+            criticality: self.criticality,
+            filter: self
+                .filter
+                .as_ref()
+                .map(|filter| filter.description().to_string()),
+            state: match control.paused {
+                Some(paused_events) => SubscriptionState::Paused(paused_events),
+                None => SubscriptionState::Active,
+            },
+            buffered_events: control.buffered_events.len(),
+            skipped_events: control.skipped_events,
+            shed_events: control.shed_events,
+            auto_paused_until: control.auto_paused_until,
         }
     }
 }
 
+/// 購読の名前（ハンドラーの型名からモジュールパスとジェネリクスを除いたもの）
+fn subscription_name<H>() -> String {
+    let type_name = std::any::type_name::<H>();
+    let without_generics = type_name.split('<').next().unwrap_or(type_name);
+    without_generics
+        .rsplit("::")
+        .next()
+        .unwrap_or(without_generics)
+        .to_string()
+}
+
 /// インメモリイベントバス実装
 /// 開発・テスト用の高度な機能を持つ実装
 pub struct InMemoryEventBus {
-    handlers: Arc<RwLock<Vec<Box<dyn DynEventHandler>>>>,
+    handlers: Arc<RwLock<Vec<Subscription>>>,
     dead_letter_queue: Arc<Mutex<VecDeque<DeadLetterEntry>>>,
+    dead_lettered_total: Arc<AtomicU64>,
+    /// 最後に発行されたイベント（プロジェクションの遅延監視用）
+    stream_head: Arc<std::sync::Mutex<EventStreamHead>>,
     config: EventBusConfig,
     serializer: EventSerializer,
+    /// バッファ付き配信時のレーンごとの送信側（同期配信時はNone）
+    lanes: Option<Arc<Vec<LaneSender>>>,
+    /// レーンに積まれて配信を待っている（配信中を含む）イベント数
+    queue_depth: Arc<AtomicUsize>,
+    /// 負荷制限の状態
+    load_shedder: Arc<std::sync::Mutex<LoadShedder>>,
+    /// subscribe_*で登録するハンドラーの配信保証
+    delivery_guarantee: DeliveryGuarantee,
+    /// subscribe_*で登録するハンドラーの重要度
+    criticality: HandlerCriticality,
+    /// subscribe_*で登録するハンドラーの絞り込み条件
+    filter: Option<EventFilter>,
+    /// 発行したイベントを記録するジャーナル（イベントのエクスポート用、未設定の場合は記録しない）
+    journal: Option<Arc<dyn EventJournal>>,
+    /// ハンドラーを自動で一時停止したときのアラートの送信先（未設定の場合は警告ログのみ）
+    alerting: Option<Arc<dyn AlertingPort>>,
+    /// 直近のイベントの配信のトレース（トレースモードが無効の場合はNone）
+    traces: Option<Arc<std::sync::Mutex<EventTraceBuffer>>>,
+    /// 発行したイベントをその場で受け取る受信側への送信側（注文ごとのイベントストリーム用）
+    live_events: broadcast::Sender<DomainEvent>,
+    /// 配信全体の一時停止（デプロイ時）と処理中のイベント数
+    dispatch_gate: Arc<DispatchGate>,
+    /// 配信全体を一時停止している間にためたイベント（再開時に発行順に配信する）
+    paused_events: Arc<std::sync::Mutex<VecDeque<DomainEvent>>>,
 }
 
 impl InMemoryEventBus {
@@ -75,47 +423,158 @@ impl InMemoryEventBus {
     /// # 例
     /// ```
     /// use bookstore_order_management::adapter::driven::{InMemoryEventBus, EventBusConfig};
+    /// use bookstore_order_management::domain::retry_policy::{RetryPolicies, RetryPolicy};
     /// 
     /// // デフォルト設定で作成
     /// let event_bus = InMemoryEventBus::new(EventBusConfig::default());
     /// 
     /// // カスタム設定で作成
     /// let config = EventBusConfig {
-    ///     max_retry_attempts: 5,
-    ///     retry_delay: std::time::Duration::from_millis(100),
+    ///     retry_policies: RetryPolicies {
+    ///         default_policy: RetryPolicy {
+    ///             max_attempts: 5,
+    ///             initial_backoff_ms: 100,
+    ///             ..RetryPolicy::default()
+    ///         },
+    ///         ..RetryPolicies::default()
+    ///     },
     ///     ..EventBusConfig::default()
     /// };
     /// let event_bus = InMemoryEventBus::new(config);
     /// ```
+    ///
+    /// `DispatchMode::Buffered`を指定した場合はレーンごとのワーカーを起動するため、
+    /// Tokioランタイム内で呼び出す必要がある
     pub fn new(config: EventBusConfig) -> Self {
-        Self {
+        let traces = (config.trace_capacity > 0).then(|| {
+            Arc::new(std::sync::Mutex::new(EventTraceBuffer::new(
+                config.trace_capacity,
+            )))
+        });
+        let mut event_bus = Self {
             handlers: Arc::new(RwLock::new(Vec::new())),
             dead_letter_queue: Arc::new(Mutex::new(VecDeque::new())),
+            dead_lettered_total: Arc::new(AtomicU64::new(0)),
+            stream_head: Arc::new(std::sync::Mutex::new(EventStreamHead::default())),
             config,
             serializer: EventSerializer::new(),
+            lanes: None,
+            queue_depth: Arc::new(AtomicUsize::new(0)),
+            load_shedder: Arc::new(std::sync::Mutex::new(LoadShedder::default())),
+            delivery_guarantee: DeliveryGuarantee::AtLeastOnce,
+            criticality: HandlerCriticality::Critical,
+            filter: None,
+            journal: None,
+            alerting: None,
+            traces,
+            live_events: broadcast::channel(LIVE_EVENT_CAPACITY).0,
+            dispatch_gate: Arc::new(DispatchGate::new()),
+            paused_events: Arc::new(std::sync::Mutex::new(VecDeque::new())),
+        };
+
+        if let DispatchMode::Buffered { lanes } = event_bus.config.dispatch_mode {
+            // ワーカーは同期配信のバスでハンドラーを実行する
+            // ハンドラー内で発行されたイベントはレーンに積まれるだけなので、同じレーンでも待ち合わせは発生しない
+            let senders = (0..lanes.max(1))
+                .map(|_| {
+                    let (sender, mut receiver) =
+                        mpsc::unbounded_channel::<(DomainEvent, tracing::Span)>();
+                    let dispatcher = event_bus.clone();
+                    tokio::spawn(async move {
+                        while let Some((event, span)) = receiver.recv().await {
+                            dispatcher.dispatch(event).instrument(span).await;
+                            dispatcher.record_queue_depth(
+                                dispatcher.queue_depth.fetch_sub(1, Ordering::SeqCst) - 1,
+                            );
+                        }
+                    });
+                    sender
+                })
+                .collect();
+            event_bus.lanes = Some(Arc::new(senders));
         }
+
+        event_bus
     }
 
-    /// ハンドラーの実行（エラー処理とリトライ機能付き）
+    /// 発行したイベントを記録するジャーナルを設定する（イベントのエクスポート用）
+    pub fn with_journal(mut self, journal: Arc<dyn EventJournal>) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// ハンドラーを自動で一時停止したときのアラートの送信先を設定する
+    pub fn with_alerting(mut self, alerting: Arc<dyn AlertingPort>) -> Self {
+        self.alerting = Some(alerting);
+        self
+    }
+
+    /// ハンドラーの実行（トレーススパンとメトリクス記録付き）
+    #[tracing::instrument(
+        name = "event.handle",
+        skip_all,
+        fields(
+            handler.name = handler.handler_name(),
+            event.type = event.event_type(),
+            correlation_id = %event.metadata().correlation_id,
+            causation_id = %event.metadata().event_id,
+            otel.status_code = tracing::field::Empty,
+        )
+    )]
     async fn execute_handler_with_retry(
         &self,
         handler: &dyn DynEventHandler,
         event: &DomainEvent,
-    ) -> Result<(), HandlerError> {
+        delivery_guarantee: DeliveryGuarantee,
+    ) -> Result<u32, (HandlerError, u32)> {
+        let started_at = Instant::now();
+        let result = self
+            .run_handler_with_retry(handler, event, delivery_guarantee)
+            .await;
+
+        if let Err((handler_error, _)) = &result {
+            tracing::Span::current().record("otel.status_code", "ERROR");
+            tracing::warn!(error = %handler_error, "event handler failed");
+        }
+        telemetry::record_handler_execution(
+            handler.handler_name(),
+            event.event_type(),
+            started_at.elapsed(),
+            result.is_ok(),
+        );
+
+        result
+    }
+
+    /// ハンドラーの実行（エラー処理とリトライ機能付き）
+    /// イベントタイプのリトライポリシーに従ってリトライし、成功した場合は試行回数、失敗した場合は最後のエラーと試行回数を返す
+    /// at-most-onceで購読したハンドラーはリトライせず、1回だけ実行する
+    async fn run_handler_with_retry(
+        &self,
+        handler: &dyn DynEventHandler,
+        event: &DomainEvent,
+        delivery_guarantee: DeliveryGuarantee,
+    ) -> Result<u32, (HandlerError, u32)> {
+        let policy = self.config.retry_policies.policy_for(event.event_type());
+        let max_attempts = match delivery_guarantee {
+            DeliveryGuarantee::AtLeastOnce => policy.max_attempts,
+            DeliveryGuarantee::AtMostOnce => 1,
+        };
         let mut attempts = 0;
         let mut last_error = None;
 
-        while attempts < self.config.max_retry_attempts {
+        while attempts < max_attempts {
             attempts += 1;
 
             // スキーマバージョンの互換性チェック
             let event_version = event.metadata().event_version;
             if !handler.supports_schema_version(event_version) {
-                return Err(HandlerError::PermanentError(format!(
+                let error = HandlerError::PermanentError(format!(
                     "Handler {} does not support schema version {}",
                     handler.handler_name(),
                     event_version
-                )));
+                ));
+                return Err((error, attempts));
             }
 
             // タイムアウト付きでハンドラーを実行
@@ -124,32 +583,34 @@ impl InMemoryEventBus {
                     .await;
 
             match result {
-                Ok(Ok(())) => return Ok(()),
+                Ok(Ok(())) => return Ok(attempts),
                 Ok(Err(handler_error)) => {
                     last_error = Some(handler_error.clone());
 
                     // 永続的エラーの場合はリトライしない
-                    if matches!(handler_error, HandlerError::PermanentError(_)) {
+                    if handler_error.is_permanent() {
                         break;
                     }
 
                     // 最後の試行でない場合は待機
-                    if attempts < self.config.max_retry_attempts {
-                        tokio::time::sleep(self.config.retry_delay).await;
+                    if attempts < max_attempts {
+                        tokio::time::sleep(policy.backoff_after(attempts)).await;
                     }
                 }
                 Err(_timeout_error) => {
                     last_error = Some(HandlerError::TransientError("Handler timeout".to_string()));
 
                     // 最後の試行でない場合は待機
-                    if attempts < self.config.max_retry_attempts {
-                        tokio::time::sleep(self.config.retry_delay).await;
+                    if attempts < max_attempts {
+                        tokio::time::sleep(policy.backoff_after(attempts)).await;
                     }
                 }
             }
         }
 
-        Err(last_error.unwrap_or(HandlerError::ProcessingFailed("Unknown error".to_string())))
+        let error =
+            last_error.unwrap_or(HandlerError::ProcessingFailed("Unknown error".to_string()));
+        Err((error, attempts))
     }
 
     /// 失敗したイベントをデッドレターキューに追加
@@ -158,6 +619,7 @@ impl InMemoryEventBus {
         event: DomainEvent,
         handler_name: String,
         error: &HandlerError,
+        attempt_count: u32,
     ) -> Result<(), EventBusError> {
         let mut dlq = self.dead_letter_queue.lock().await;
 
@@ -166,14 +628,22 @@ impl InMemoryEventBus {
             dlq.pop_front(); // 古いエントリを削除
         }
 
-        let is_retryable = matches!(error, HandlerError::TransientError(_));
+        let is_retryable = error.is_transient();
         let now = SystemTime::now();
+        // ハンドラーが付加したステップに、イベントと集約の情報を補う
+        let context = error
+            .clone()
+            .for_event(&event)
+            .context()
+            .cloned()
+            .unwrap_or_default();
 
         let failed_processing = FailedEventProcessing {
             event: event.clone(),
             handler_name: handler_name.clone(),
             error: error.to_string(),
-            attempt_count: self.config.max_retry_attempts,
+            context,
+            attempt_count,
             first_failed_at: now,
             last_failed_at: now,
             is_retryable,
@@ -185,6 +655,7 @@ impl InMemoryEventBus {
         };
 
         dlq.push_back(entry);
+        self.dead_lettered_total.fetch_add(1, Ordering::Relaxed);
         Ok(())
     }
 
@@ -226,104 +697,760 @@ impl Default for InMemoryEventBus {
 
 #[async_trait]
 impl EventBus for InMemoryEventBus {
+    #[tracing::instrument(
+        name = "event.publish",
+        skip_all,
+        fields(
+            event.type = event.event_type(),
+            event.id = %event.metadata().event_id,
+            correlation_id = %event.metadata().correlation_id,
+        )
+    )]
     async fn publish(&self, event: DomainEvent) -> Result<(), EventBusError> {
         // シリアライゼーション検証
         self.validate_event_serialization(&event)?;
+        self.record_stream_head(&event);
+        self.record_in_journal(&event).await;
+        // 受信側がいない場合の送信エラーは無視する
+        let _ = self.live_events.send(event.clone());
+
+        // バッファ付き配信: 集約IDで決まるレーンに積み、ワーカーが発行順に処理する
+        if let Some(lanes) = &self.lanes {
+            let lane = lane_for(&event.aggregate_id(), lanes.len());
+            self.record_queue_depth(self.queue_depth.fetch_add(1, Ordering::SeqCst) + 1);
+            return lanes[lane]
+                .send((event, tracing::Span::current()))
+                .map_err(|_| {
+                    self.queue_depth.fetch_sub(1, Ordering::SeqCst);
+                    EventBusError::PublishingFailed(format!("Dispatch lane {} is closed", lane))
+                });
+        }
+
+        self.dispatch(event).await;
+        Ok(())
+    }
+}
+
+impl InMemoryEventBus {
+    /// 発行したイベントをジャーナルに記録する
+    /// 記録に失敗してもイベントの配信は止めない（エクスポートから漏れたイベントは警告ログで追跡する）
+    async fn record_in_journal(&self, event: &DomainEvent) {
+        let Some(journal) = &self.journal else {
+            return;
+        };
+        if let Err(e) = journal.append(event).await {
+            tracing::warn!(
+                event.type = event.event_type(),
+                event.id = %event.metadata().event_id,
+                error = %e,
+                "failed to record event in journal"
+            );
+        }
+    }
+
+    /// 発行されたイベントをイベントストリームの先頭として記録する
+    fn record_stream_head(&self, event: &DomainEvent) {
+        let metadata = event.metadata();
+        let mut head = self
+            .stream_head
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        head.published_events += 1;
+        head.last_event_id = Some(metadata.event_id);
+        head.last_event_type = Some(event.event_type().to_string());
+        head.last_occurred_at = Some(metadata.occurred_at);
+    }
+
+    /// 配信待ちのイベント数を反映し、負荷制限のポリシーに従って負荷制限を切り替える
+    fn record_queue_depth(&self, queue_depth: usize) {
+        let Some(policy) = &self.config.load_shedding else {
+            return;
+        };
+        let transition = self
+            .load_shedder
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .observe(queue_depth, Utc::now(), policy);
+        match transition {
+            Some(LoadSheddingTransition::Started) => {
+                telemetry::record_load_shedding_activation();
+                tracing::warn!(
+                    queue_depth,
+                    shed_at_depth = policy.shed_at_depth,
+                    "event backlog is growing, shedding non-critical handlers"
+                );
+            }
+            Some(LoadSheddingTransition::Stopped) => {
+                tracing::info!(
+                    queue_depth,
+                    resume_at_depth = policy.resume_at_depth,
+                    "event backlog drained, resuming non-critical handlers"
+                );
+            }
+            None => {}
+        }
+    }
+
+    /// 負荷制限中の場合は、重要でない購読への配信を見送ったことを記録してtrueを返す
+    fn shed(&self, subscription: &Subscription, event: &DomainEvent) -> bool {
+        if subscription.criticality != HandlerCriticality::NonCritical {
+            return false;
+        }
+        {
+            let mut load_shedder = self
+                .load_shedder
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if !load_shedder.is_shedding() {
+                return false;
+            }
+            load_shedder.record_shed();
+        }
+        subscription.lock_control().shed_events += 1;
+        telemetry::record_shed_event(&subscription.name, event.event_type());
+        true
+    }
 
+    /// イベントを購読しているハンドラーに配信する
+    /// 配信全体を一時停止している場合はためておき、再開時に発行順に配信する
+    async fn dispatch(&self, event: DomainEvent) {
+        // 再開時にためたイベントを配信し終えてから一時停止を解除するため、判定とためる操作は同じロックで行う
+        let in_flight = {
+            let mut paused_events = self
+                .paused_events
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            match self.dispatch_gate.try_enter() {
+                Some(in_flight) => in_flight,
+                None => {
+                    paused_events.push_back(event);
+                    return;
+                }
+            }
+        };
+        self.dispatch_to_subscribers(event).await;
+        drop(in_flight);
+    }
+
+    /// イベントを購読しているハンドラーに順次配信する
+    /// 失敗したハンドラーのイベントはデッドレターキューに追加する
+    async fn dispatch_to_subscribers(&self, event: DomainEvent) {
         // イベント発行ログ
         // Note: Logger trait is not available in this context as it would create circular dependency
         // Individual handlers log their own processing
 
-        // ハンドラー情報を収集
-        let handlers = {
+        // 対象ハンドラーを収集（ロックを保持したままハンドラーを実行しないように複製する）
+        let subscriptions: Vec<Subscription> = {
             let handlers_guard = self.handlers.read().await;
-            let mut applicable_handlers = Vec::new();
-
-            for handler in handlers_guard.iter() {
-                if handler.can_handle(&event) {
-                    applicable_handlers.push((
-                        handler.handler_name().to_string(),
-                        handler.supports_schema_version(event.metadata().event_version),
-                    ));
+            handlers_guard
+                .iter()
+                .filter(|subscription| subscription.accepts(&event))
+                .cloned()
+                .collect()
+        };
+
+        // 各ハンドラーを順次処理（一時停止中の購読にはためるか読み飛ばし、負荷制限中は重要でない購読への配信を見送る）
+        let mut handler_traces = Vec::new();
+        for subscription in subscriptions {
+            let started_at = Instant::now();
+            let paused = {
+                let mut control = subscription.lock_control();
+                match control.paused {
+                    Some(PausedEventHandling::Buffer) => {
+                        control.buffered_events.push_back(event.clone());
+                        Some(HandlerOutcome::Buffered)
+                    }
+                    Some(PausedEventHandling::Skip) => {
+                        control.skipped_events += 1;
+                        Some(HandlerOutcome::Skipped)
+                    }
+                    None => None,
                 }
+            };
+            let withheld = paused.or_else(|| {
+                self.shed(&subscription, &event)
+                    .then_some(HandlerOutcome::Shed)
+            });
+            let (outcome, attempts) = match withheld {
+                Some(outcome) => (outcome, 0),
+                None => self.deliver(&subscription, &event).await,
+            };
+            if self.traces.is_some() {
+                handler_traces.push(HandlerTrace {
+                    handler_name: subscription.name.clone(),
+                    outcome,
+                    duration_ms: started_at.elapsed().as_millis() as u64,
+                    retries: attempts.saturating_sub(1),
+                });
             }
-            applicable_handlers
+        }
+        self.record_trace(&event, handler_traces);
+    }
+
+    /// トレースモードの場合は、イベントの配信のトレースを記録する
+    fn record_trace(&self, event: &DomainEvent, handlers: Vec<HandlerTrace>) {
+        let Some(traces) = &self.traces else {
+            return;
         };
+        let metadata = event.metadata();
+        traces
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .record(EventTrace {
+                event_id: metadata.event_id,
+                event_type: event.event_type().to_string(),
+                aggregate_id: event.aggregate_id(),
+                correlation_id: metadata.correlation_id,
+                occurred_at: metadata.occurred_at,
+                handlers,
+            });
+    }
 
-        // 各ハンドラーを順次処理
-        for (handler_name, supports_version) in handlers {
-            if !supports_version {
-                let error = HandlerError::PermanentError(format!(
-                    "Handler {} does not support schema version {}",
-                    handler_name,
-                    event.metadata().event_version
-                ));
+    /// 購読しているハンドラーにイベントを配信し、配信結果と試行回数を返す
+    /// 失敗したイベントは配信保証とリトライポリシーに従ってデッドレターキューに追加する
+    async fn deliver(
+        &self,
+        subscription: &Subscription,
+        event: &DomainEvent,
+    ) -> (HandlerOutcome, u32) {
+        let handler = &subscription.handler;
+        let delivery_guarantee = subscription.delivery_guarantee;
+        let handler_name = handler.handler_name().to_string();
+
+        if !handler.supports_schema_version(event.metadata().event_version) {
+            let error = HandlerError::PermanentError(format!(
+                "Handler {} does not support schema version {}",
+                handler_name,
+                event.metadata().event_version
+            ));
+
+            // エラーログ
+            // Note: Logger trait is not available in this context as it would create circular dependency
+            // Individual handlers should log their own failures
+
+            let outcome = HandlerOutcome::Failed(error.to_string());
+            if let Err(dlq_error) = self
+                .add_to_dead_letter_queue(event.clone(), handler_name, &error, 0)
+                .await
+            {
+                // Note: Logger trait is not available in this context as it would create circular dependency
+                // DLQ errors are handled silently to prevent infinite loops
+                let _ = dlq_error; // Acknowledge the error without logging
+            }
+            return (outcome, 0);
+        }
 
-                // エラーログ
+        // ハンドラーを実行
+        let result = self
+            .execute_handler_with_retry(handler.as_ref(), event, delivery_guarantee)
+            .await;
+        self.record_health(subscription, result.is_ok()).await;
+        match result {
+            Ok(attempts) => {
+                // 成功ログは個別のハンドラー内で出力される
+                (HandlerOutcome::Succeeded, attempts)
+            }
+            Err((handler_error, attempts)) => {
                 // Note: Logger trait is not available in this context as it would create circular dependency
                 // Individual handlers should log their own failures
+                let traced = (HandlerOutcome::Failed(handler_error.to_string()), attempts);
+
+                // at-most-onceで購読したハンドラーの失敗は、再処理の対象にしない
+                if delivery_guarantee == DeliveryGuarantee::AtMostOnce {
+                    tracing::warn!(
+                        handler.name = %handler_name,
+                        event.type = event.event_type(),
+                        error = %handler_error,
+                        "dropping failed event for at-most-once subscription"
+                    );
+                    return traced;
+                }
+
+                // リトライポリシーで破棄を指定したイベントはデッドレターキューに送らない
+                let policy = self.config.retry_policies.policy_for(event.event_type());
+                if policy.dead_letter == DeadLetterRouting::Discard {
+                    tracing::warn!(
+                        handler.name = %handler_name,
+                        event.type = event.event_type(),
+                        error = %handler_error,
+                        "discarding failed event per retry policy"
+                    );
+                    return traced;
+                }
 
                 if let Err(dlq_error) = self
-                    .add_to_dead_letter_queue(event.clone(), handler_name.clone(), &error)
+                    .add_to_dead_letter_queue(event.clone(), handler_name, &handler_error, attempts)
                     .await
                 {
                     // Note: Logger trait is not available in this context as it would create circular dependency
                     // DLQ errors are handled silently to prevent infinite loops
                     let _ = dlq_error; // Acknowledge the error without logging
                 }
-                continue;
+                traced
             }
+        }
+    }
 
-            // ハンドラーを名前で実行
-            match self.execute_handler_by_name(&handler_name, &event).await {
-                Ok(()) => {
-                    // 成功ログは個別のハンドラー内で出力される
-                }
-                Err(handler_error) => {
-                    // Note: Logger trait is not available in this context as it would create circular dependency
-                    // Individual handlers should log their own failures
-
-                    if let Err(dlq_error) = self
-                        .add_to_dead_letter_queue(
-                            event.clone(),
-                            handler_name.clone(),
-                            &handler_error,
-                        )
-                        .await
-                    {
-                        // Note: Logger trait is not available in this context as it would create circular dependency
-                        // DLQ errors are handled silently to prevent infinite loops
-                        let _ = dlq_error; // Acknowledge the error without logging
+    /// 配信結果を記録し、失敗率が閾値以上になったハンドラーを自動で一時停止する
+    /// 一時停止した後に届いたイベントはためておき、再開時に発行順に配信する
+    async fn record_health(&self, subscription: &Subscription, succeeded: bool) {
+        let Some(policy) = &self.config.handler_health else {
+            return;
+        };
+        let (failure_rate, retry_at) = {
+            let mut control = subscription.lock_control();
+            if control.auto_paused_until.is_some() {
+                return;
+            }
+            let Some(failure_rate) = control.health.record(succeeded, Instant::now(), policy)
+            else {
+                return;
+            };
+            let retry_at = Utc::now()
+                + chrono::Duration::from_std(policy.cooldown).unwrap_or(chrono::Duration::MAX);
+            control.paused = Some(PausedEventHandling::Buffer);
+            control.auto_paused_until = Some(retry_at);
+            (failure_rate, retry_at)
+        };
+
+        tracing::warn!(
+            handler.name = %subscription.name,
+            event.type = subscription.event_type,
+            failure_rate,
+            retry_at = %retry_at,
+            "pausing unhealthy event handler"
+        );
+        let Some(alerting) = &self.alerting else {
+            return;
+        };
+        let alert = Alert {
+            kind: AnomalyKind::HandlerUnhealthy,
+            severity: AlertSeverity::Critical,
+            message: format!(
+                "イベントハンドラー {}（{}）の失敗率が {:.1}% に達したため一時停止しました。{} に再開を試みます",
+                subscription.name,
+                subscription.event_type,
+                failure_rate * 100.0,
+                retry_at.to_rfc3339()
+            ),
+            observed_value: failure_rate,
+            threshold: policy.failure_rate,
+            window_seconds: policy.window.as_secs(),
+            detected_at: Utc::now(),
+        };
+        if let Err(e) = alerting.send_alert(&alert).await {
+            tracing::warn!(
+                handler.name = %subscription.name,
+                error = %e,
+                "failed to send handler pause alert"
+            );
+        }
+    }
+
+    /// 一時停止中にためたイベントを発行順に配信し、配信した件数を返す
+    /// ためたイベントを配信し終えるまでは一時停止のままにして、新しいイベントを後ろに積む
+    /// 配信中に再び自動で一時停止した場合は、残りのイベントをためたまま中断する
+    async fn replay_buffered(&self, subscription: &Subscription) -> usize {
+        let mut replayed = 0;
+        loop {
+            let next = {
+                let mut control = subscription.lock_control();
+                if control.auto_paused_until.is_some() {
+                    None
+                } else {
+                    let next = control.buffered_events.pop_front();
+                    if next.is_none() {
+                        control.paused = None;
                     }
+                    next
                 }
+            };
+            let Some(event) = next else {
+                break;
+            };
+            self.deliver(subscription, &event).await;
+            replayed += 1;
+        }
+        replayed
+    }
+
+    /// クールダウンを過ぎた自動一時停止中のハンドラーの再開を試みる
+    /// ためている最初のイベントを配信して成功した場合は再開して残りのイベントを配信し、
+    /// 失敗した場合はイベントを戻して再びクールダウンの間一時停止する
+    ///
+    /// # Returns
+    /// * 再開した購読の数
+    pub async fn resume_recovered_handlers(&self, now: DateTime<Utc>) -> usize {
+        let Some(policy) = &self.config.handler_health else {
+            return 0;
+        };
+        let due: Vec<Subscription> = self
+            .handlers
+            .read()
+            .await
+            .iter()
+            .filter(|subscription| {
+                subscription
+                    .lock_control()
+                    .auto_paused_until
+                    .is_some_and(|retry_at| retry_at <= now)
+            })
+            .cloned()
+            .collect();
+
+        let mut resumed = 0;
+        for subscription in &due {
+            let trial = subscription.lock_control().buffered_events.pop_front();
+            if let Some(event) = trial {
+                let result = self
+                    .execute_handler_with_retry(
+                        subscription.handler.as_ref(),
+                        &event,
+                        subscription.delivery_guarantee,
+                    )
+                    .await;
+                if let Err((error, _)) = result {
+                    let retry_at = now
+                        + chrono::Duration::from_std(policy.cooldown)
+                            .unwrap_or(chrono::Duration::MAX);
+                    let mut control = subscription.lock_control();
+                    control.buffered_events.push_front(event);
+                    control.auto_paused_until = Some(retry_at);
+                    tracing::warn!(
+                        handler.name = %subscription.name,
+                        event.type = subscription.event_type,
+                        error = %error,
+                        retry_at = %retry_at,
+                        "event handler is still failing, keeping it paused"
+                    );
+                    continue;
+                }
+            }
+
+            {
+                let mut control = subscription.lock_control();
+                control.auto_paused_until = None;
+                control.health.reset();
             }
+            tracing::info!(
+                handler.name = %subscription.name,
+                event.type = subscription.event_type,
+                "resuming recovered event handler"
+            );
+            self.replay_buffered(subscription).await;
+            resumed += 1;
         }
+        resumed
+    }
 
-        Ok(())
+    /// 自動一時停止中のハンドラーの再開を定期的に試みるタスクをバックグラウンドで起動
+    pub fn spawn_health_probe(&self, check_interval: Duration) -> JoinHandle<()> {
+        let event_bus = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(check_interval);
+            loop {
+                interval.tick().await;
+                event_bus.resume_recovered_handlers(Utc::now()).await;
+            }
+        })
     }
 }
 
-impl InMemoryEventBus {
-    /// 名前でハンドラーを実行
-    async fn execute_handler_by_name(
+#[async_trait]
+impl DeadLetterMonitor for InMemoryEventBus {
+    fn dead_lettered_total(&self) -> u64 {
+        // キューサイズ制限で削除されたエントリも含めた累計数
+        self.dead_lettered_total.load(Ordering::Relaxed)
+    }
+
+    async fn dead_letters(&self) -> Vec<DeadLetter> {
+        let dlq = self.dead_letter_queue.lock().await;
+        dlq.iter()
+            .map(|entry| {
+                let failed = &entry.failed_processing;
+                DeadLetter {
+                    handler_name: failed.handler_name.clone(),
+                    event_type: failed.event.event_type().to_string(),
+                    error: failed.error.clone(),
+                    context: failed.context.clone(),
+                    attempt_count: failed.attempt_count,
+                    retryable: failed.is_retryable,
+                    dead_lettered_at: entry.added_at.into(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl SubscriptionManager for InMemoryEventBus {
+    async fn subscriptions(&self) -> Vec<SubscriptionStatus> {
+        let handlers = self.handlers.read().await;
+        handlers.iter().map(Subscription::status).collect()
+    }
+
+    async fn unsubscribe(&self, handler_name: &str) -> Result<usize, EventBusError> {
+        let mut handlers = self.handlers.write().await;
+        let before = handlers.len();
+        handlers.retain(|subscription| subscription.name != handler_name);
+        let removed = before - handlers.len();
+        if removed == 0 {
+            return Err(EventBusError::SubscriptionNotFound(
+                handler_name.to_string(),
+            ));
+        }
+        Ok(removed)
+    }
+
+    async fn pause(
         &self,
         handler_name: &str,
-        event: &DomainEvent,
-    ) -> Result<(), HandlerError> {
-        let handlers = self.handlers.read().await;
+        paused_events: PausedEventHandling,
+    ) -> Result<usize, EventBusError> {
+        let subscriptions = self.find_subscriptions(handler_name).await?;
+        for subscription in &subscriptions {
+            // 手動の一時停止は自動の再開の対象にしない
+            let mut control = subscription.lock_control();
+            control.paused = Some(paused_events);
+            control.auto_paused_until = None;
+        }
+        Ok(subscriptions.len())
+    }
 
-        for handler in handlers.iter() {
-            if handler.handler_name() == handler_name && handler.can_handle(event) {
-                return self
-                    .execute_handler_with_retry(handler.as_ref(), event)
-                    .await;
+    async fn resume(&self, handler_name: &str) -> Result<usize, EventBusError> {
+        let subscriptions = self.find_subscriptions(handler_name).await?;
+        let mut replayed = 0;
+        for subscription in &subscriptions {
+            {
+                let mut control = subscription.lock_control();
+                control.auto_paused_until = None;
+                control.health.reset();
             }
+            replayed += self.replay_buffered(subscription).await;
+        }
+        Ok(replayed)
+    }
+
+    fn load_shedding(&self) -> LoadSheddingStatus {
+        let queue_depth = self.queue_depth.load(Ordering::SeqCst);
+        match &self.config.load_shedding {
+            Some(policy) => self
+                .load_shedder
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .status(queue_depth, policy),
+            None => LoadSheddingStatus::disabled(queue_depth),
+        }
+    }
+}
+
+impl EventTraceMonitor for InMemoryEventBus {
+    fn tracing_enabled(&self) -> bool {
+        self.traces.is_some()
+    }
+
+    fn event_traces(&self, correlation_id: Option<Uuid>) -> Vec<EventTrace> {
+        self.traces.as_ref().map_or_else(Vec::new, |traces| {
+            traces
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .traces(correlation_id)
+        })
+    }
+}
+
+#[async_trait]
+impl EventDispatchControl for InMemoryEventBus {
+    async fn pause_dispatch(&self, drain_timeout: Duration) -> EventDispatchStatus {
+        let drained = self.dispatch_gate.pause(Utc::now(), drain_timeout).await;
+        let status = self.dispatch_status();
+        if drained {
+            tracing::info!("event dispatch paused");
+        } else {
+            tracing::warn!(
+                in_flight_events = status.in_flight_events,
+                "event dispatch paused, but handlers are still running"
+            );
         }
+        status
+    }
+
+    async fn resume_dispatch(&self) -> usize {
+        // ためたイベントを配信し終えるまでは一時停止のままにして、新しいイベントを後ろに積む
+        let mut replayed = 0;
+        loop {
+            let (event, in_flight) = {
+                let mut paused_events = self
+                    .paused_events
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                let Some(event) = paused_events.pop_front() else {
+                    self.dispatch_gate.resume();
+                    break;
+                };
+                (event, self.dispatch_gate.enter_while_paused())
+            };
+            self.dispatch_to_subscribers(event).await;
+            drop(in_flight);
+            replayed += 1;
+        }
+        tracing::info!(replayed_events = replayed, "event dispatch resumed");
+        replayed
+    }
+
+    fn dispatch_status(&self) -> EventDispatchStatus {
+        let buffered_events = self
+            .paused_events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len();
+        self.dispatch_gate.status(buffered_events)
+    }
+}
+
+impl LiveEventSource for InMemoryEventBus {
+    fn subscribe_all(&self) -> broadcast::Receiver<DomainEvent> {
+        self.live_events.subscribe()
+    }
+}
+
+impl EventStreamMonitor for InMemoryEventBus {
+    fn stream_head(&self) -> EventStreamHead {
+        self.stream_head
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl InMemoryEventBus {
+    /// 配信保証を指定してハンドラーを登録するためのイベントバスを取得
+    /// 返したイベントバスはハンドラー・キューを共有し、subscribe_*で登録するハンドラーの配信保証だけが異なる
+    ///
+    /// # 例
+    /// ```
+    /// use bookstore_order_management::adapter::driven::{EventBusConfig, InMemoryEventBus};
+    /// use bookstore_order_management::domain::retry_policy::DeliveryGuarantee;
+    ///
+    /// # async fn example<H>(sms_handler: H)
+    /// # where
+    /// #     H: bookstore_order_management::domain::event_bus::EventHandler<
+    /// #             bookstore_order_management::domain::event::OrderShipped,
+    /// #         > + 'static,
+    /// # {
+    /// let event_bus = InMemoryEventBus::new(EventBusConfig::default());
+    /// // SMSは重複して送るより送らない方がよいため、失敗してもリトライしない
+    /// event_bus
+    ///     .with_delivery_guarantee(DeliveryGuarantee::AtMostOnce)
+    ///     .subscribe_order_shipped(sms_handler)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn with_delivery_guarantee(&self, delivery_guarantee: DeliveryGuarantee) -> Self {
+        let mut event_bus = self.clone();
+        event_bus.delivery_guarantee = delivery_guarantee;
+        event_bus
+    }
+
+    /// 絞り込み条件を指定してハンドラーを登録するためのイベントバスを取得
+    /// 返したイベントバスはハンドラー・キューを共有し、subscribe_*で登録するハンドラーには条件を満たすイベントだけを配信する
+    ///
+    /// # 例
+    /// ```
+    /// use bookstore_order_management::adapter::driven::{EventBusConfig, InMemoryEventBus};
+    /// use bookstore_order_management::domain::event_bus::EventFilter;
+    /// use bookstore_order_management::domain::model::Money;
+    ///
+    /// # async fn example<H>(vip_handler: H)
+    /// # where
+    /// #     H: bookstore_order_management::domain::event_bus::EventHandler<
+    /// #             bookstore_order_management::domain::event::OrderConfirmed,
+    /// #         > + 'static,
+    /// # {
+    /// let event_bus = InMemoryEventBus::new(EventBusConfig::default());
+    /// // 合計10,000円以上の注文確定のみVIP向けハンドラーに配信する
+    /// event_bus
+    ///     .with_filter(EventFilter::order_total_at_least(Money::jpy(10_000)))
+    ///     .subscribe_order_confirmed(vip_handler)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn with_filter(&self, filter: EventFilter) -> Self {
+        let mut event_bus = self.clone();
+        event_bus.filter = Some(filter);
+        event_bus
+    }
 
-        Err(HandlerError::ProcessingFailed(format!(
-            "Handler {} not found",
-            handler_name
-        )))
+    /// 重要度を指定してハンドラーを登録するためのイベントバスを取得
+    /// 返したイベントバスはハンドラー・キューを共有し、subscribe_*で登録するハンドラーの重要度だけが異なる
+    /// 重要でないハンドラーには、負荷制限中（配信待ちのイベントがたまっている間）はイベントを配信しない
+    ///
+    /// # 例
+    /// ```
+    /// use bookstore_order_management::adapter::driven::{EventBusConfig, InMemoryEventBus};
+    /// use bookstore_order_management::domain::load_shedding::HandlerCriticality;
+    ///
+    /// # async fn example<H>(analytics_handler: H)
+    /// # where
+    /// #     H: bookstore_order_management::domain::event_bus::EventHandler<
+    /// #             bookstore_order_management::domain::event::OrderConfirmed,
+    /// #         > + 'static,
+    /// # {
+    /// let event_bus = InMemoryEventBus::new(EventBusConfig::default());
+    /// // 分析用の集計は欠けても注文の処理に影響しないため、負荷制限の対象にする
+    /// event_bus
+    ///     .with_criticality(HandlerCriticality::NonCritical)
+    ///     .subscribe_order_confirmed(analytics_handler)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn with_criticality(&self, criticality: HandlerCriticality) -> Self {
+        let mut event_bus = self.clone();
+        event_bus.criticality = criticality;
+        event_bus
+    }
+
+    /// ハンドラーを現在の配信保証・重要度・絞り込み条件で登録
+    async fn register(
+        &self,
+        event_type: &'static str,
+        name: String,
+        handler: impl DynEventHandler + 'static,
+    ) {
+        let mut handlers = self.handlers.write().await;
+        handlers.push(Subscription {
+            name,
+            event_type,
+            handler: Arc::new(handler),
+            delivery_guarantee: self.delivery_guarantee,
+            criticality: self.criticality,
+            filter: self.filter.clone(),
+            control: Arc::default(),
+        });
+    }
+
+    /// ハンドラー名に一致する購読を複製して取得（ロックを保持したまま配信しないように複製する）
+    async fn find_subscriptions(
+        &self,
+        handler_name: &str,
+    ) -> Result<Vec<Subscription>, EventBusError> {
+        let subscriptions: Vec<Subscription> = self
+            .handlers
+            .read()
+            .await
+            .iter()
+            .filter(|subscription| subscription.name == handler_name)
+            .cloned()
+            .collect();
+        if subscriptions.is_empty() {
+            return Err(EventBusError::SubscriptionNotFound(
+                handler_name.to_string(),
+            ));
+        }
+        Ok(subscriptions)
     }
 
     /// OrderConfirmedハンドラーを登録
@@ -332,21 +1459,19 @@ impl InMemoryEventBus {
         H: EventHandler<crate::domain::event::OrderConfirmed> + Send + Sync + 'static,
     {
         let wrapped_handler = OrderConfirmedHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register("OrderConfirmed", subscription_name::<H>(), wrapped_handler)
+            .await;
         Ok(())
     }
 
-
-
     /// OrderCancelledハンドラーを登録
     pub async fn subscribe_order_cancelled<H>(&self, handler: H) -> Result<(), EventBusError>
     where
         H: EventHandler<crate::domain::event::OrderCancelled> + Send + Sync + 'static,
     {
         let wrapped_handler = OrderCancelledHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register("OrderCancelled", subscription_name::<H>(), wrapped_handler)
+            .await;
         Ok(())
     }
 
@@ -356,8 +1481,8 @@ impl InMemoryEventBus {
         H: EventHandler<crate::domain::event::OrderShipped> + Send + Sync + 'static,
     {
         let wrapped_handler = OrderShippedHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register("OrderShipped", subscription_name::<H>(), wrapped_handler)
+            .await;
         Ok(())
     }
 
@@ -367,8 +1492,269 @@ impl InMemoryEventBus {
         H: EventHandler<crate::domain::event::OrderDelivered> + Send + Sync + 'static,
     {
         let wrapped_handler = OrderDeliveredHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register("OrderDelivered", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// PreOrderActivatedハンドラーを登録
+    pub async fn subscribe_pre_order_activated<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::PreOrderActivated> + Send + Sync + 'static,
+    {
+        let wrapped_handler = PreOrderActivatedHandlerWrapper::new(handler);
+        self.register(
+            "PreOrderActivated",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// WaitlistJoinedハンドラーを登録
+    pub async fn subscribe_waitlist_joined<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::WaitlistJoined> + Send + Sync + 'static,
+    {
+        let wrapped_handler = WaitlistJoinedHandlerWrapper::new(handler);
+        self.register("WaitlistJoined", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// WaitlistPromotedハンドラーを登録
+    pub async fn subscribe_waitlist_promoted<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::WaitlistPromoted> + Send + Sync + 'static,
+    {
+        let wrapped_handler = WaitlistPromotedHandlerWrapper::new(handler);
+        self.register(
+            "WaitlistPromoted",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// DigitalItemsFulfilledハンドラーを登録
+    pub async fn subscribe_digital_items_fulfilled<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::DigitalItemsFulfilled> + Send + Sync + 'static,
+    {
+        let wrapped_handler = DigitalItemsFulfilledHandlerWrapper::new(handler);
+        self.register(
+            "DigitalItemsFulfilled",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// FulfillmentSlaBreachedハンドラーを登録
+    pub async fn subscribe_fulfillment_sla_breached<H>(
+        &self,
+        handler: H,
+    ) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::FulfillmentSlaBreached> + Send + Sync + 'static,
+    {
+        let wrapped_handler = FulfillmentSlaBreachedHandlerWrapper::new(handler);
+        self.register(
+            "FulfillmentSlaBreached",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// ShippingAddressChangedハンドラーを登録
+    pub async fn subscribe_shipping_address_changed<H>(
+        &self,
+        handler: H,
+    ) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::ShippingAddressChanged> + Send + Sync + 'static,
+    {
+        let wrapped_handler = ShippingAddressChangedHandlerWrapper::new(handler);
+        self.register(
+            "ShippingAddressChanged",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// OrderHeldハンドラーを登録
+    pub async fn subscribe_order_held<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::OrderHeld> + Send + Sync + 'static,
+    {
+        let wrapped_handler = OrderHeldHandlerWrapper::new(handler);
+        self.register("OrderHeld", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// OrderReleasedハンドラーを登録
+    pub async fn subscribe_order_released<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::OrderReleased> + Send + Sync + 'static,
+    {
+        let wrapped_handler = OrderReleasedHandlerWrapper::new(handler);
+        self.register("OrderReleased", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// ReturnRequestedハンドラーを登録
+    pub async fn subscribe_return_requested<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::ReturnRequested> + Send + Sync + 'static,
+    {
+        let wrapped_handler = ReturnRequestedHandlerWrapper::new(handler);
+        self.register("ReturnRequested", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// ReturnApprovedハンドラーを登録
+    pub async fn subscribe_return_approved<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::ReturnApproved> + Send + Sync + 'static,
+    {
+        let wrapped_handler = ReturnApprovedHandlerWrapper::new(handler);
+        self.register("ReturnApproved", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// OrderStatusForcefullyChangedハンドラーを登録
+    pub async fn subscribe_order_status_forcefully_changed<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::OrderStatusForcefullyChanged> + Send + Sync + 'static,
+    {
+        let wrapped_handler = OrderStatusForcefullyChangedHandlerWrapper::new(handler);
+        self.register("OrderStatusForcefullyChanged", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// CarrierTrackingUpdatedハンドラーを登録
+    pub async fn subscribe_carrier_tracking_updated<H>(
+        &self,
+        handler: H,
+    ) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::CarrierTrackingUpdated> + Send + Sync + 'static,
+    {
+        let wrapped_handler = CarrierTrackingUpdatedHandlerWrapper::new(handler);
+        self.register(
+            "CarrierTrackingUpdated",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// InventoryReservedハンドラーを登録
+    pub async fn subscribe_inventory_reserved<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::InventoryReserved> + Send + Sync + 'static,
+    {
+        let wrapped_handler = InventoryReservedHandlerWrapper::new(handler);
+        self.register(
+            "InventoryReserved",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// InventoryReleasedハンドラーを登録
+    pub async fn subscribe_inventory_released<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::InventoryReleased> + Send + Sync + 'static,
+    {
+        let wrapped_handler = InventoryReleasedHandlerWrapper::new(handler);
+        self.register(
+            "InventoryReleased",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// CheckoutHoldExpiredハンドラーを登録
+    pub async fn subscribe_checkout_hold_expired<H>(
+        &self,
+        handler: H,
+    ) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::CheckoutHoldExpired> + Send + Sync + 'static,
+    {
+        let wrapped_handler = CheckoutHoldExpiredHandlerWrapper::new(handler);
+        self.register(
+            "CheckoutHoldExpired",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// ItemsRestockedハンドラーを登録
+    pub async fn subscribe_items_restocked<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::ItemsRestocked> + Send + Sync + 'static,
+    {
+        let wrapped_handler = ItemsRestockedHandlerWrapper::new(handler);
+        self.register("ItemsRestocked", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// InventoryAdjustedハンドラーを登録
+    pub async fn subscribe_inventory_adjusted<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::InventoryAdjusted> + Send + Sync + 'static,
+    {
+        let wrapped_handler = InventoryAdjustedHandlerWrapper::new(handler);
+        self.register(
+            "InventoryAdjusted",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// HighValueOrderPlacedハンドラーを登録
+    pub async fn subscribe_high_value_order_placed<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::HighValueOrderPlaced> + Send + Sync + 'static,
+    {
+        let wrapped_handler = HighValueOrderPlacedHandlerWrapper::new(handler);
+        self.register("HighValueOrderPlaced", subscription_name::<H>(), wrapped_handler)
+            .await;
+        Ok(())
+    }
+
+    /// CustomerBecameRepeatBuyerハンドラーを登録
+    pub async fn subscribe_customer_became_repeat_buyer<H>(&self, handler: H) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::CustomerBecameRepeatBuyer> + Send + Sync + 'static,
+    {
+        let wrapped_handler = CustomerBecameRepeatBuyerHandlerWrapper::new(handler);
+        self.register("CustomerBecameRepeatBuyer", subscription_name::<H>(), wrapped_handler)
+            .await;
         Ok(())
     }
 
@@ -383,8 +1769,12 @@ impl InMemoryEventBus {
         H: EventHandler<crate::domain::event::InventoryReservationFailed> + Send + Sync + 'static,
     {
         let wrapped_handler = InventoryReservationFailedHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register(
+            "InventoryReservationFailed",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
         Ok(())
     }
 
@@ -394,8 +1784,8 @@ impl InMemoryEventBus {
         H: EventHandler<crate::domain::event::ShippingFailed> + Send + Sync + 'static,
     {
         let wrapped_handler = ShippingFailedHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register("ShippingFailed", subscription_name::<H>(), wrapped_handler)
+            .await;
         Ok(())
     }
 
@@ -405,8 +1795,8 @@ impl InMemoryEventBus {
         H: EventHandler<crate::domain::event::DeliveryFailed> + Send + Sync + 'static,
     {
         let wrapped_handler = DeliveryFailedHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register("DeliveryFailed", subscription_name::<H>(), wrapped_handler)
+            .await;
         Ok(())
     }
 
@@ -419,8 +1809,12 @@ impl InMemoryEventBus {
         H: EventHandler<crate::domain::event::SagaCompensationStarted> + Send + Sync + 'static,
     {
         let wrapped_handler = SagaCompensationStartedHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register(
+            "SagaCompensationStarted",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
         Ok(())
     }
 
@@ -433,8 +1827,30 @@ impl InMemoryEventBus {
         H: EventHandler<crate::domain::event::SagaCompensationCompleted> + Send + Sync + 'static,
     {
         let wrapped_handler = SagaCompensationCompletedHandlerWrapper::new(handler);
-        let mut handlers = self.handlers.write().await;
-        handlers.push(Box::new(wrapped_handler));
+        self.register(
+            "SagaCompensationCompleted",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// SagaTimedOutハンドラーを登録
+    pub async fn subscribe_saga_timed_out<H>(
+        &self,
+        handler: H,
+    ) -> Result<(), EventBusError>
+    where
+        H: EventHandler<crate::domain::event::SagaTimedOut> + Send + Sync + 'static,
+    {
+        let wrapped_handler = SagaTimedOutHandlerWrapper::new(handler);
+        self.register(
+            "SagaTimedOut",
+            subscription_name::<H>(),
+            wrapped_handler,
+        )
+        .await;
         Ok(())
     }
 }
@@ -445,8 +1861,706 @@ impl Clone for InMemoryEventBus {
         Self {
             handlers: self.handlers.clone(),
             dead_letter_queue: self.dead_letter_queue.clone(),
+            dead_lettered_total: self.dead_lettered_total.clone(),
+            stream_head: self.stream_head.clone(),
             config: self.config.clone(),
             serializer: EventSerializer::new(), // 新しいシリアライザーインスタンスを作成
+            lanes: self.lanes.clone(),
+            queue_depth: self.queue_depth.clone(),
+            load_shedder: self.load_shedder.clone(),
+            delivery_guarantee: self.delivery_guarantee,
+            criticality: self.criticality,
+            filter: self.filter.clone(),
+            journal: self.journal.clone(),
+            alerting: self.alerting.clone(),
+            traces: self.traces.clone(),
+            live_events: self.live_events.clone(),
+            dispatch_gate: self.dispatch_gate.clone(),
+            paused_events: self.paused_events.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event::{OrderConfirmed, OrderDelivered};
+    use crate::domain::model::{CustomerId, Money, OrderId};
+    use tokio::sync::Notify;
+    use uuid::Uuid;
+
+    /// 処理したイベントを記録するハンドラー（イベントごとに処理時間を変える）
+    struct RecordingHandler {
+        processed: Arc<Mutex<Vec<(OrderId, Uuid)>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<OrderDelivered> for RecordingHandler {
+        async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+            let delay = event.metadata.event_id.as_bytes()[0] % 4;
+            tokio::time::sleep(Duration::from_millis(delay as u64)).await;
+            self.processed
+                .lock()
+                .await
+                .push((event.order_id, event.metadata.event_id));
+            Ok(())
+        }
+    }
+
+    /// 指定した注文のイベントを、他の注文のイベントが処理されるまで待たせるハンドラー
+    struct BlockingHandler {
+        blocked_order: OrderId,
+        released: Arc<Notify>,
+        processed: Arc<Mutex<Vec<OrderId>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<OrderDelivered> for BlockingHandler {
+        async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+            if event.order_id == self.blocked_order {
+                self.released.notified().await;
+            } else {
+                self.released.notify_one();
+            }
+            self.processed.lock().await.push(event.order_id);
+            Ok(())
+        }
+    }
+
+    /// 処理ステップを付加した永続的エラーを返すハンドラー
+    struct FailingHandler;
+
+    #[async_trait]
+    impl EventHandler<OrderDelivered> for FailingHandler {
+        async fn handle(&self, _event: OrderDelivered) -> Result<(), HandlerError> {
+            Err(
+                HandlerError::PermanentError("注文取得エラー: timeout".to_string())
+                    .at_step("load_order"),
+            )
+        }
+    }
+
+    /// 常に一時的エラーを返し、呼び出し回数を記録するハンドラー
+    struct FlakyHandler {
+        calls: Arc<Mutex<u32>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<OrderDelivered> for FlakyHandler {
+        async fn handle(&self, _event: OrderDelivered) -> Result<(), HandlerError> {
+            *self.calls.lock().await += 1;
+            Err(HandlerError::TransientError("通知先に接続できません".to_string()))
+        }
+    }
+
+    fn buffered_config(lanes: usize) -> EventBusConfig {
+        EventBusConfig {
+            dispatch_mode: DispatchMode::Buffered { lanes },
+            ..EventBusConfig::default()
+        }
+    }
+
+    async fn wait_until_processed<T>(processed: &Mutex<Vec<T>>, expected: usize) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while processed.lock().await.len() < expected {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("events were not processed in time");
+    }
+
+    #[tokio::test]
+    async fn test_buffered_dispatch_preserves_order_per_aggregate() {
+        let event_bus = InMemoryEventBus::new(buffered_config(4));
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        event_bus
+            .subscribe_order_delivered(RecordingHandler {
+                processed: processed.clone(),
+            })
+            .await
+            .unwrap();
+
+        // 複数の注文のイベントを交互に発行する
+        let order_ids: Vec<OrderId> = (0..3).map(|_| OrderId::new()).collect();
+        let mut published = Vec::new();
+        for _ in 0..10 {
+            for &order_id in &order_ids {
+                let event = OrderDelivered::new(order_id);
+                published.push((order_id, event.metadata.event_id));
+                event_bus
+                    .publish(DomainEvent::OrderDelivered(event))
+                    .await
+                    .unwrap();
+            }
+        }
+
+        wait_until_processed(&processed, published.len()).await;
+
+        let processed = processed.lock().await;
+        for order_id in &order_ids {
+            let expected: Vec<Uuid> = published
+                .iter()
+                .filter(|(id, _)| id == order_id)
+                .map(|(_, event_id)| *event_id)
+                .collect();
+            let actual: Vec<Uuid> = processed
+                .iter()
+                .filter(|(id, _)| id == order_id)
+                .map(|(_, event_id)| *event_id)
+                .collect();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_buffered_dispatch_processes_different_lanes_concurrently() {
+        let blocked_order = OrderId::new();
+        let other_order = loop {
+            let candidate = OrderId::new();
+            if lane_for(&candidate.to_string(), 2) != lane_for(&blocked_order.to_string(), 2) {
+                break candidate;
+            }
+        };
+
+        let event_bus = InMemoryEventBus::new(buffered_config(2));
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        event_bus
+            .subscribe_order_delivered(BlockingHandler {
+                blocked_order,
+                released: Arc::new(Notify::new()),
+                processed: processed.clone(),
+            })
+            .await
+            .unwrap();
+
+        // 先に発行したイベントの処理中でも、別レーンのイベントは処理される
+        for order_id in [blocked_order, other_order] {
+            event_bus
+                .publish(DomainEvent::OrderDelivered(OrderDelivered::new(order_id)))
+                .await
+                .unwrap();
+        }
+
+        wait_until_processed(&processed, 2).await;
+
+        assert_eq!(*processed.lock().await, vec![other_order, blocked_order]);
+    }
+
+    #[tokio::test]
+    async fn test_dead_letter_carries_handler_error_context() {
+        let event_bus = InMemoryEventBus::new(EventBusConfig::default());
+        event_bus
+            .subscribe_order_delivered(FailingHandler)
+            .await
+            .unwrap();
+
+        let order_id = OrderId::new();
+        let event = OrderDelivered::new(order_id);
+        let event_id = event.metadata.event_id;
+        event_bus
+            .publish(DomainEvent::OrderDelivered(event))
+            .await
+            .unwrap();
+
+        let dead_letters = event_bus.dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        let dead_letter = &dead_letters[0];
+        // 表示はコンテキストの付加前と変わらない
+        assert_eq!(
+            dead_letter.error,
+            "Permanent error (not retryable): 注文取得エラー: timeout"
+        );
+        assert!(!dead_letter.retryable);
+        assert_eq!(
+            dead_letter.context,
+            HandlerErrorContext {
+                event_id: Some(event_id),
+                event_type: Some("OrderDelivered".to_string()),
+                aggregate_id: Some(order_id.to_string()),
+                step: Some("load_order".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_is_applied_per_event_type() {
+        let calls = Arc::new(Mutex::new(0));
+        let config = EventBusConfig {
+            retry_policies: RetryPolicies {
+                default_policy: RetryPolicy::new(5, 0, 1, 0, DeadLetterRouting::DeadLetter)
+                    .unwrap(),
+                overrides: BTreeMap::from([(
+                    "OrderDelivered".to_string(),
+                    RetryPolicy::new(2, 0, 1, 0, DeadLetterRouting::Discard).unwrap(),
+                )]),
+            },
+            ..EventBusConfig::default()
+        };
+        let event_bus = InMemoryEventBus::new(config);
+        event_bus
+            .subscribe_order_delivered(FlakyHandler {
+                calls: calls.clone(),
+            })
+            .await
+            .unwrap();
+
+        event_bus
+            .publish(DomainEvent::OrderDelivered(OrderDelivered::new(OrderId::new())))
+            .await
+            .unwrap();
+
+        // OrderDeliveredの個別ポリシーに従って2回で打ち切り、デッドレターキューには送らない
+        assert_eq!(*calls.lock().await, 2);
+        assert!(event_bus.dead_letters().await.is_empty());
+    }
+
+    fn no_backoff_config(max_attempts: u32) -> EventBusConfig {
+        EventBusConfig {
+            retry_policies: RetryPolicies {
+                default_policy: RetryPolicy::new(
+                    max_attempts,
+                    0,
+                    1,
+                    0,
+                    DeadLetterRouting::DeadLetter,
+                )
+                .unwrap(),
+                ..RetryPolicies::default()
+            },
+            ..EventBusConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_at_least_once_subscription_retries_and_dead_letters() {
+        let calls = Arc::new(Mutex::new(0));
+        let event_bus = InMemoryEventBus::new(no_backoff_config(3));
+        event_bus
+            .with_delivery_guarantee(DeliveryGuarantee::AtLeastOnce)
+            .subscribe_order_delivered(FlakyHandler {
+                calls: calls.clone(),
+            })
+            .await
+            .unwrap();
+
+        event_bus
+            .publish(DomainEvent::OrderDelivered(OrderDelivered::new(
+                OrderId::new(),
+            )))
+            .await
+            .unwrap();
+
+        assert_eq!(*calls.lock().await, 3);
+        let dead_letters = event_bus.dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].attempt_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_at_most_once_subscription_neither_retries_nor_dead_letters() {
+        let at_most_once_calls = Arc::new(Mutex::new(0));
+        let default_calls = Arc::new(Mutex::new(0));
+        let event_bus = InMemoryEventBus::new(no_backoff_config(3));
+        event_bus
+            .with_delivery_guarantee(DeliveryGuarantee::AtMostOnce)
+            .subscribe_order_delivered(FlakyHandler {
+                calls: at_most_once_calls.clone(),
+            })
+            .await
+            .unwrap();
+        // 元のイベントバスで登録したハンドラーは、これまでどおりat-least-once
+        event_bus
+            .subscribe_order_delivered(FlakyHandler {
+                calls: default_calls.clone(),
+            })
+            .await
+            .unwrap();
+
+        event_bus
+            .publish(DomainEvent::OrderDelivered(OrderDelivered::new(
+                OrderId::new(),
+            )))
+            .await
+            .unwrap();
+
+        assert_eq!(*at_most_once_calls.lock().await, 1);
+        assert_eq!(*default_calls.lock().await, 3);
+        // デッドレターキューに送られるのはat-least-onceのハンドラーの失敗のみ
+        assert_eq!(event_bus.dead_letters().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_paused_subscription_buffers_events_and_replays_them_in_order_on_resume() {
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let event_bus = InMemoryEventBus::default();
+        event_bus
+            .subscribe_order_delivered(RecordingHandler {
+                processed: processed.clone(),
+            })
+            .await
+            .unwrap();
+
+        event_bus
+            .pause("RecordingHandler", PausedEventHandling::Buffer)
+            .await
+            .unwrap();
+        let order_ids = [OrderId::new(), OrderId::new()];
+        for order_id in order_ids {
+            event_bus
+                .publish(DomainEvent::OrderDelivered(OrderDelivered::new(order_id)))
+                .await
+                .unwrap();
+        }
+        assert!(processed.lock().await.is_empty());
+        let subscriptions = event_bus.subscriptions().await;
+        assert_eq!(subscriptions[0].event_type, "OrderDelivered");
+        assert_eq!(
+            subscriptions[0].state,
+            SubscriptionState::Paused(PausedEventHandling::Buffer)
+        );
+        assert_eq!(subscriptions[0].buffered_events, 2);
+
+        assert_eq!(event_bus.resume("RecordingHandler").await.unwrap(), 2);
+        let replayed: Vec<OrderId> = processed.lock().await.iter().map(|(id, _)| *id).collect();
+        assert_eq!(replayed, order_ids);
+        let subscriptions = event_bus.subscriptions().await;
+        assert_eq!(subscriptions[0].state, SubscriptionState::Active);
+        assert_eq!(subscriptions[0].buffered_events, 0);
+    }
+
+    #[tokio::test]
+    async fn test_paused_dispatch_buffers_events_and_replays_them_in_order_on_resume() {
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        let event_bus = InMemoryEventBus::default();
+        event_bus
+            .subscribe_order_delivered(RecordingHandler {
+                processed: processed.clone(),
+            })
+            .await
+            .unwrap();
+
+        let status = event_bus.pause_dispatch(Duration::from_secs(1)).await;
+        assert!(status.paused);
+        assert_eq!(status.in_flight_events, 0);
+        let order_ids = [OrderId::new(), OrderId::new()];
+        for order_id in order_ids {
+            event_bus
+                .publish(DomainEvent::OrderDelivered(OrderDelivered::new(order_id)))
+                .await
+                .unwrap();
+        }
+        assert!(processed.lock().await.is_empty());
+        assert_eq!(event_bus.dispatch_status().buffered_events, 2);
+
+        assert_eq!(event_bus.resume_dispatch().await, 2);
+        let replayed: Vec<OrderId> = processed.lock().await.iter().map(|(id, _)| *id).collect();
+        assert_eq!(replayed, order_ids);
+        let status = event_bus.dispatch_status();
+        assert!(!status.paused);
+        assert_eq!(status.buffered_events, 0);
+    }
+
+    #[tokio::test]
+    async fn test_live_subscribers_receive_events_published_after_subscribing() {
+        let event_bus = InMemoryEventBus::default();
+        event_bus
+            .publish(DomainEvent::OrderDelivered(OrderDelivered::new(OrderId::new())))
+            .await
+            .unwrap();
+
+        // ハンドラーを登録していなくても、購読した後に発行されたイベントを受け取る
+        let mut receiver = event_bus.subscribe_all();
+        let order_id = OrderId::new();
+        event_bus
+            .publish(DomainEvent::OrderDelivered(OrderDelivered::new(order_id)))
+            .await
+            .unwrap();
+
+        let received = receiver.recv().await.unwrap();
+        assert_eq!(received.aggregate_id(), order_id.to_string());
+        assert!(receiver.try_recv().is_err());
+    }
+
+    /// 指定した注文のイベントを、ゲートが開くまで待たせるハンドラー
+    struct GatedHandler {
+        gated_order: OrderId,
+        gate: Arc<Notify>,
+    }
+
+    #[async_trait]
+    impl EventHandler<OrderDelivered> for GatedHandler {
+        async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+            if event.order_id == self.gated_order {
+                self.gate.notified().await;
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_non_critical_handlers_are_shed_until_backlog_drains() {
+        let event_bus = InMemoryEventBus::new(EventBusConfig {
+            load_shedding: Some(LoadSheddingPolicy {
+                shed_at_depth: 3,
+                resume_at_depth: 1,
+            }),
+            ..buffered_config(1)
+        });
+        let order_ids = [OrderId::new(), OrderId::new(), OrderId::new(), OrderId::new()];
+        let gate = Arc::new(Notify::new());
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        event_bus
+            .subscribe_order_delivered(GatedHandler {
+                gated_order: order_ids[0],
+                gate: gate.clone(),
+            })
+            .await
+            .unwrap();
+        event_bus
+            .with_criticality(HandlerCriticality::NonCritical)
+            .subscribe_order_delivered(RecordingHandler {
+                processed: processed.clone(),
+            })
+            .await
+            .unwrap();
+
+        // 最初のイベントの処理が止まっている間に、配信待ちのイベントがたまる
+        for order_id in order_ids {
+            event_bus
+                .publish(DomainEvent::OrderDelivered(OrderDelivered::new(order_id)))
+                .await
+                .unwrap();
+        }
+        let status = event_bus.load_shedding();
+        assert!(status.shedding);
+        assert_eq!(status.queue_depth, 4);
+        gate.notify_one();
+
+        // 配信待ちが再開の閾値まで減るまでは重要でないハンドラーに配信せず、その後の配信は再開する
+        wait_until_processed(&processed, 1).await;
+        let delivered: Vec<OrderId> = processed.lock().await.iter().map(|(id, _)| *id).collect();
+        assert_eq!(delivered, vec![order_ids[3]]);
+        let subscriptions = event_bus.subscriptions().await;
+        assert_eq!(subscriptions[0].criticality, HandlerCriticality::Critical);
+        assert_eq!(subscriptions[0].shed_events, 0);
+        assert_eq!(subscriptions[1].criticality, HandlerCriticality::NonCritical);
+        assert_eq!(subscriptions[1].shed_events, 3);
+        let status = event_bus.load_shedding();
+        assert!(!status.shedding);
+        assert_eq!(status.activations_total, 1);
+        assert_eq!(status.shed_events_total, 3);
+    }
+
+    #[tokio::test]
+    async fn test_skipping_and_unsubscribed_handlers_do_not_receive_events() {
+        let skipped = Arc::new(Mutex::new(Vec::new()));
+        let unsubscribed_calls = Arc::new(Mutex::new(0));
+        let event_bus = InMemoryEventBus::new(no_backoff_config(1));
+        event_bus
+            .subscribe_order_delivered(RecordingHandler {
+                processed: skipped.clone(),
+            })
+            .await
+            .unwrap();
+        event_bus
+            .subscribe_order_delivered(FlakyHandler {
+                calls: unsubscribed_calls.clone(),
+            })
+            .await
+            .unwrap();
+
+        event_bus
+            .pause("RecordingHandler", PausedEventHandling::Skip)
+            .await
+            .unwrap();
+        assert_eq!(event_bus.unsubscribe("FlakyHandler").await.unwrap(), 1);
+        assert!(matches!(
+            event_bus.unsubscribe("FlakyHandler").await,
+            Err(EventBusError::SubscriptionNotFound(_))
+        ));
+        event_bus
+            .publish(DomainEvent::OrderDelivered(OrderDelivered::new(
+                OrderId::new(),
+            )))
+            .await
+            .unwrap();
+
+        let subscriptions = event_bus.subscriptions().await;
+        assert_eq!(subscriptions.len(), 1);
+        assert_eq!(subscriptions[0].skipped_events, 1);
+        // 読み飛ばしたイベントは再開しても配信しない
+        assert_eq!(event_bus.resume("RecordingHandler").await.unwrap(), 0);
+        assert!(skipped.lock().await.is_empty());
+        assert_eq!(*unsubscribed_calls.lock().await, 0);
+    }
+
+    /// 確定した注文を記録するハンドラー
+    struct ConfirmedOrderRecorder {
+        processed: Arc<Mutex<Vec<OrderId>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<OrderConfirmed> for ConfirmedOrderRecorder {
+        async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+            self.processed.lock().await.push(event.order_id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_filtered_subscription_receives_only_matching_events() {
+        let vip_orders = Arc::new(Mutex::new(Vec::new()));
+        let all_orders = Arc::new(Mutex::new(Vec::new()));
+        let event_bus = InMemoryEventBus::default();
+        event_bus
+            .with_filter(EventFilter::order_total_at_least(Money::jpy(10_000)))
+            .subscribe_order_confirmed(ConfirmedOrderRecorder {
+                processed: vip_orders.clone(),
+            })
+            .await
+            .unwrap();
+        event_bus
+            .subscribe_order_confirmed(ConfirmedOrderRecorder {
+                processed: all_orders.clone(),
+            })
+            .await
+            .unwrap();
+
+        let small_order = OrderId::new();
+        let large_order = OrderId::new();
+        for (order_id, total) in [(small_order, 9_999), (large_order, 10_000)] {
+            event_bus
+                .publish(DomainEvent::OrderConfirmed(OrderConfirmed::new(
+                    order_id,
+                    CustomerId::new(),
+                    Vec::new(),
+                    Money::jpy(total),
+                )))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(*vip_orders.lock().await, vec![large_order]);
+        assert_eq!(*all_orders.lock().await, vec![small_order, large_order]);
+        let filters: Vec<Option<String>> = event_bus
+            .subscriptions()
+            .await
+            .into_iter()
+            .map(|subscription| subscription.filter)
+            .collect();
+        assert_eq!(
+            filters,
+            vec![
+                Some("OrderConfirmed.total_amount >= 10000 JPY".to_string()),
+                None
+            ]
+        );
+    }
+
+    /// healthyがfalseの間は一時的エラーを返し、処理した注文を記録するハンドラー
+    struct SwitchableHandler {
+        healthy: Arc<std::sync::atomic::AtomicBool>,
+        processed: Arc<Mutex<Vec<OrderId>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<OrderDelivered> for SwitchableHandler {
+        async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+            if !self.healthy.load(Ordering::SeqCst) {
+                return Err(HandlerError::TransientError("配送業者APIが応答しません".to_string()));
+            }
+            self.processed.lock().await.push(event.order_id);
+            Ok(())
+        }
+    }
+
+    /// 送信したアラートを記録する
+    #[derive(Default)]
+    struct RecordingAlerting {
+        alerts: Mutex<Vec<Alert>>,
+    }
+
+    #[async_trait]
+    impl AlertingPort for RecordingAlerting {
+        async fn send_alert(
+            &self,
+            alert: &Alert,
+        ) -> Result<(), crate::domain::port::AlertingError> {
+            self.alerts.lock().await.push(alert.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failing_handler_is_paused_and_resumed_after_cooldown_once_recovered() {
+        let cooldown = Duration::from_secs(30);
+        let alerting = Arc::new(RecordingAlerting::default());
+        let event_bus = InMemoryEventBus::new(EventBusConfig {
+            handler_health: Some(HandlerHealthPolicy {
+                failure_rate: 0.5,
+                window: Duration::from_secs(60),
+                min_deliveries: 2,
+                cooldown,
+            }),
+            ..no_backoff_config(1)
+        })
+        .with_alerting(alerting.clone());
+        let healthy = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        event_bus
+            .subscribe_order_delivered(SwitchableHandler {
+                healthy: healthy.clone(),
+                processed: processed.clone(),
+            })
+            .await
+            .unwrap();
+        let publish = |order_id: OrderId| {
+            let event_bus = event_bus.clone();
+            async move {
+                event_bus
+                    .publish(DomainEvent::OrderDelivered(OrderDelivered::new(order_id)))
+                    .await
+                    .unwrap();
+            }
+        };
+
+        // 失敗率が閾値に達すると一時停止し、以降のイベントはためておく
+        publish(OrderId::new()).await;
+        publish(OrderId::new()).await;
+        let buffered = [OrderId::new(), OrderId::new()];
+        for order_id in buffered {
+            publish(order_id).await;
+        }
+        let subscription = &event_bus.subscriptions().await[0];
+        assert_eq!(
+            subscription.state,
+            SubscriptionState::Paused(PausedEventHandling::Buffer)
+        );
+        assert_eq!(subscription.buffered_events, 2);
+        let paused_until = subscription.auto_paused_until.unwrap();
+        assert_eq!(event_bus.dead_letters().await.len(), 2);
+        let alerts = alerting.alerts.lock().await;
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AnomalyKind::HandlerUnhealthy);
+        assert_eq!(alerts[0].observed_value, 1.0);
+        drop(alerts);
+
+        // クールダウン中は再開しない。クールダウン後も失敗する場合はイベントを戻して一時停止を続ける
+        assert_eq!(event_bus.resume_recovered_handlers(Utc::now()).await, 0);
+        assert_eq!(event_bus.resume_recovered_handlers(paused_until).await, 0);
+        let subscription = &event_bus.subscriptions().await[0];
+        assert_eq!(subscription.buffered_events, 2);
+        assert!(subscription.auto_paused_until.unwrap() > paused_until);
+
+        // 回復したハンドラーは再開し、ためていたイベントを発行順に配信する
+        healthy.store(true, Ordering::SeqCst);
+        let later = paused_until + chrono::Duration::from_std(cooldown * 2).unwrap();
+        assert_eq!(event_bus.resume_recovered_handlers(later).await, 1);
+        assert_eq!(*processed.lock().await, buffered);
+        let subscription = &event_bus.subscriptions().await[0];
+        assert_eq!(subscription.state, SubscriptionState::Active);
+        assert_eq!(subscription.auto_paused_until, None);
+        assert_eq!(event_bus.dead_letters().await.len(), 2);
+    }
+}
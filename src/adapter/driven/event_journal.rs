@@ -0,0 +1,128 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::event::DomainEvent;
+use crate::domain::event_export::JournaledEvent;
+use crate::domain::port::{EventJournal, RepositoryError};
+use crate::domain::serialization::EventSerializer;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+/// MySQLイベントジャーナル
+/// MySQLデータベース（event_journalテーブル）に発行されたイベントを記録順に保存する
+/// 記録順の位置は自動採番のpositionとする
+#[derive(Clone)]
+pub struct MySqlEventJournal {
+    pool: Pool<MySql>,
+}
+
+impl MySqlEventJournal {
+    /// 新しいMySQLイベントジャーナルを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlEventJournalのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EventJournal for MySqlEventJournal {
+    #[tracing::instrument(name = "db.event_journal.append", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "event_journal", event.type = event.event_type()), err)]
+    async fn append(&self, event: &DomainEvent) -> Result<bool, RepositoryError> {
+        let payload = EventSerializer::new()
+            .serialize_event(event)
+            .map_err(|e| RepositoryError::OperationFailed(e.to_string()))?;
+        let metadata = event.metadata();
+
+        // event_idの一意制約により、同じイベントの再記録は無視される
+        let result = sqlx::query(
+            r#"
+            INSERT IGNORE INTO event_journal (event_id, event_type, aggregate_id, occurred_at, payload)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(metadata.event_id.to_string())
+        .bind(event.event_type())
+        .bind(event.aggregate_id())
+        .bind(metadata.occurred_at)
+        .bind(payload)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("イベントの記録に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(name = "db.event_journal.read_range", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "event_journal", after_position = after_position, limit = limit), err)]
+    async fn read_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after_position: u64,
+        limit: usize,
+    ) -> Result<Vec<JournaledEvent>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT position, event_id, event_type, occurred_at, payload
+            FROM event_journal
+            WHERE occurred_at >= ? AND occurred_at < ? AND position > ?
+            ORDER BY position ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(after_position)
+        .bind(limit as u64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("イベントの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(journaled_event_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.event_journal.find_by_aggregate", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "event_journal", aggregate_id = aggregate_id), err)]
+    async fn find_by_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Vec<JournaledEvent>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT position, event_id, event_type, occurred_at, payload
+            FROM event_journal
+            WHERE aggregate_id = ?
+            ORDER BY position ASC
+            "#,
+        )
+        .bind(aggregate_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("イベントの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(journaled_event_from_row).collect()
+    }
+}
+
+/// event_journalテーブルの行をジャーナルのイベントに変換する
+fn journaled_event_from_row(row: &MySqlRow) -> Result<JournaledEvent, RepositoryError> {
+    let event_id = Uuid::parse_str(row.get("event_id")).map_err(|e| {
+        RepositoryError::FetchFailed(format!("イベントIDの解析に失敗しました: {}", e))
+    })?;
+    Ok(JournaledEvent {
+        position: row.get("position"),
+        event_id,
+        event_type: row.get("event_type"),
+        occurred_at: row.get::<DateTime<Utc>, _>("occurred_at"),
+        payload: row.get("payload"),
+    })
+}
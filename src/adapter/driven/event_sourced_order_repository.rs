@@ -0,0 +1,295 @@
+use crate::domain::event::DomainEvent;
+use crate::domain::event_sourcing::{events_since, order_stream_id, replay_order};
+use crate::domain::model::{
+    BookId, CustomerId, Order, OrderId, OrderNumber, OrderStatus, TrackingToken,
+};
+use crate::domain::port::{EventStore, OrderRepository, RepositoryError};
+use crate::domain::purchase_policy::{PurchaseCheck, PurchasePolicy};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// イベントソーシングの注文リポジトリ
+/// 注文の状態をイベントストアのイベントの履歴から再構築する（状態テーブルの代わりにイベントを正とする）
+///
+/// - 保存時は、保存前の注文からの変化をドメインイベントとして導出し、読み込んだときのバージョンを期待してストリームに追記する
+///   （読み込んだ後に他の操作が追記していた場合は `RepositoryError::ConcurrencyConflict`）
+/// - 状態テーブル（`projection`）は一覧・集計の問い合わせ用の投影として、追記の後に更新する
+///   （追記した時点で保存は完了しているため、投影の更新に失敗しても保存の失敗にはせず、後の保存時に反映し直す）
+/// - 確定前の注文（イベントがない注文）と、イベントに含まれない属性は投影から読み込む
+pub struct EventSourcedOrderRepository<P>
+where
+    P: OrderRepository,
+{
+    event_store: Arc<dyn EventStore>,
+    projection: P,
+    /// イベントの追記後に投影の更新に失敗した注文（投影に反映し直すまで保持する）
+    stale_projections: Mutex<HashMap<OrderId, Order>>,
+}
+
+impl<P> EventSourcedOrderRepository<P>
+where
+    P: OrderRepository,
+{
+    /// 新しいイベントソーシングの注文リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `event_store` - 注文のイベントストリームを保存するイベントストア
+    /// * `projection` - 問い合わせ用の状態テーブルの注文リポジトリ
+    pub fn new(event_store: Arc<dyn EventStore>, projection: P) -> Self {
+        Self {
+            event_store,
+            projection,
+            stale_projections: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 投影の更新に失敗していた注文を投影に反映し直す
+    /// 保存しようとしている注文は、その保存で投影を更新するため対象外にする
+    async fn rebuild_stale_projections(&self, except: OrderId) {
+        let mut stale_projections = self.stale_projections.lock().await;
+        let order_ids: Vec<OrderId> = stale_projections
+            .keys()
+            .copied()
+            .filter(|order_id| *order_id != except)
+            .collect();
+        for order_id in order_ids {
+            let Some(order) = stale_projections.get(&order_id) else {
+                continue;
+            };
+            // その後に追記された注文は、追記した保存が投影を更新している
+            let Ok(stream) = self
+                .event_store
+                .load_stream(&order_stream_id(order_id))
+                .await
+            else {
+                continue;
+            };
+            if stream.version != order.stream_version() {
+                stale_projections.remove(&order_id);
+                continue;
+            }
+            // ストリームに追記した内容が正のため、投影に保存されているバージョンを引き継いで上書きする
+            let Ok(snapshot) = self.projection.find_by_id(order_id).await else {
+                continue;
+            };
+            let mut order = order
+                .clone()
+                .with_version(snapshot.as_ref().map_or(0, Order::version));
+            if self.projection.save(&mut order).await.is_ok() {
+                stale_projections.remove(&order_id);
+            }
+        }
+    }
+
+    /// 保存する注文をストリームに追記するイベントを導出する
+    /// 読み込んだときのストリームのバージョンと一致しない場合は競合エラーを返す
+    async fn events_to_append(&self, order: &Order) -> Result<Vec<DomainEvent>, RepositoryError> {
+        let stream = self
+            .event_store
+            .load_stream(&order_stream_id(order.id()))
+            .await?;
+        if stream.version != order.stream_version() {
+            return Err(RepositoryError::ConcurrencyConflict(format!(
+                "注文{}は読み込んだ後に更新されています（バージョン{}→{}）",
+                order.id(),
+                order.stream_version(),
+                stream.version
+            )));
+        }
+
+        let snapshot = self.projection.find_by_id(order.id()).await?;
+        let previous = if stream.is_empty() {
+            snapshot
+        } else {
+            replay_order(&stream, snapshot.as_ref())
+                .map_err(|e| RepositoryError::FetchFailed(e.to_string()))?
+        };
+        Ok(events_since(previous.as_ref(), order))
+    }
+}
+
+#[async_trait]
+impl<P> OrderRepository for EventSourcedOrderRepository<P>
+where
+    P: OrderRepository,
+{
+    async fn save(&self, order: &mut Order) -> Result<(), RepositoryError> {
+        self.rebuild_stale_projections(order.id()).await;
+
+        let events = self.events_to_append(order).await?;
+        if events.is_empty() {
+            self.projection.save(order).await?;
+        } else {
+            let version = self
+                .event_store
+                .append(
+                    &order_stream_id(order.id()),
+                    &events,
+                    order.stream_version(),
+                )
+                .await?;
+            order.mark_stream_appended(version);
+            // 追記した時点で保存は完了している。投影の更新に失敗した注文は後の保存時に反映し直す
+            if let Err(e) = self.projection.save(order).await {
+                tracing::warn!(
+                    order_id = %order.id(),
+                    error = %e,
+                    "failed to update order projection after appending events"
+                );
+                self.stale_projections
+                    .lock()
+                    .await
+                    .insert(order.id(), order.clone());
+                return Ok(());
+            }
+        }
+        self.stale_projections.lock().await.remove(&order.id());
+        Ok(())
+    }
+
+    async fn save_within_purchase_limits(
+        &self,
+        order: &mut Order,
+        policy: &PurchasePolicy,
+        check: PurchaseCheck,
+    ) -> Result<(), RepositoryError> {
+        // 購入制限を確認するのは確定前の注文（イベントを導出しない）の保存だけなので、判定と保存は投影に任せる
+        // （制限を超えて保存しなかった場合に、追記済みのイベントが残らないようにする）
+        if !self.events_to_append(order).await?.is_empty() {
+            return Err(RepositoryError::OperationFailed(format!(
+                "注文{}は確定済みのため、購入制限を確認して保存できません",
+                order.id()
+            )));
+        }
+        self.projection
+            .save_within_purchase_limits(order, policy, check)
+            .await
+    }
+
+    async fn find_by_id(&self, order_id: OrderId) -> Result<Option<Order>, RepositoryError> {
+        let snapshot = self.projection.find_by_id(order_id).await?;
+        let stream = self
+            .event_store
+            .load_stream(&order_stream_id(order_id))
+            .await?;
+        if stream.is_empty() {
+            return Ok(snapshot);
+        }
+        // 投影に反映できていない注文は、イベントに含まれない属性も保存したときの状態で返す
+        if let Some(order) = self.stale_projections.lock().await.get(&order_id) {
+            if order.stream_version() == stream.version {
+                return Ok(Some(order.clone()));
+            }
+        }
+        replay_order(&stream, snapshot.as_ref())
+            .map_err(|e| RepositoryError::FetchFailed(e.to_string()))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Order>, RepositoryError> {
+        self.projection.find_all().await
+    }
+
+    async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, RepositoryError> {
+        self.projection.find_by_status(status).await
+    }
+
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        self.projection.find_by_customer(customer_id).await
+    }
+
+    async fn find_by_customer_page(
+        &self,
+        customer_id: CustomerId,
+        after: Option<OrderId>,
+        limit: u32,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        self.projection
+            .find_by_customer_page(customer_id, after, limit)
+            .await
+    }
+
+    async fn find_by_status_created_before(
+        &self,
+        status: OrderStatus,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        self.projection
+            .find_by_status_created_before(status, created_before)
+            .await
+    }
+
+    async fn count_open_orders_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<u32, RepositoryError> {
+        self.projection
+            .count_open_orders_by_customer(customer_id)
+            .await
+    }
+
+    async fn count_orders_by_customer_and_status(
+        &self,
+        customer_id: CustomerId,
+        status: OrderStatus,
+    ) -> Result<u32, RepositoryError> {
+        self.projection
+            .count_orders_by_customer_and_status(customer_id, status)
+            .await
+    }
+
+    async fn sum_book_quantity_by_customer_since(
+        &self,
+        customer_id: CustomerId,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, RepositoryError> {
+        self.projection
+            .sum_book_quantity_by_customer_since(customer_id, book_id, since)
+            .await
+    }
+
+    async fn find_by_tracking_token(
+        &self,
+        token: &TrackingToken,
+    ) -> Result<Option<Order>, RepositoryError> {
+        self.projection.find_by_tracking_token(token).await
+    }
+
+    async fn find_by_order_number(
+        &self,
+        order_number: &OrderNumber,
+    ) -> Result<Option<Order>, RepositoryError> {
+        match self.projection.find_by_order_number(order_number).await? {
+            Some(order) => self.find_by_id(order.id()).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn find_similar_orders(
+        &self,
+        order_id: OrderId,
+        within: TimeDelta,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        self.projection.find_similar_orders(order_id, within).await
+    }
+
+    async fn sum_daily_book_demand_since(
+        &self,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<BTreeMap<NaiveDate, u32>, RepositoryError> {
+        self.projection
+            .sum_daily_book_demand_since(book_id, since)
+            .await
+    }
+
+    fn next_identity(&self) -> OrderId {
+        self.projection.next_identity()
+    }
+}
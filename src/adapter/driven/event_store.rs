@@ -0,0 +1,153 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::event::DomainEvent;
+use crate::domain::event_sourcing::EventStream;
+use crate::domain::port::{EventStore, RepositoryError};
+use crate::domain::serialization::EventSerializer;
+use async_trait::async_trait;
+use sqlx::{MySql, Pool, QueryBuilder, Row};
+
+/// MySQLイベントストア
+/// MySQLデータベース（event_storeテーブル）にストリームごとのイベントをバージョン順に保存する
+/// （ストリームIDとバージョンの主キーにより、同じバージョンへの同時の追記は一方だけが成功する）
+#[derive(Clone)]
+pub struct MySqlEventStore {
+    pool: Pool<MySql>,
+}
+
+impl MySqlEventStore {
+    /// 新しいMySQLイベントストアを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlEventStoreのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EventStore for MySqlEventStore {
+    #[tracing::instrument(name = "db.event_store.append", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "event_store", stream_id = stream_id, expected_version = expected_version, event_count = events.len()), err)]
+    async fn append(
+        &self,
+        stream_id: &str,
+        events: &[DomainEvent],
+        expected_version: u64,
+    ) -> Result<u64, RepositoryError> {
+        let serializer = EventSerializer::new();
+        let mut rows = Vec::with_capacity(events.len());
+        for (offset, event) in events.iter().enumerate() {
+            let payload = serializer
+                .serialize_event(event)
+                .map_err(|e| RepositoryError::OperationFailed(e.to_string()))?;
+            rows.push((expected_version + offset as u64 + 1, event, payload));
+        }
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // ストリームの現在のバージョンをロックして確認する
+        let current_version: u64 = sqlx::query(
+            "SELECT COALESCE(MAX(version), 0) AS version FROM event_store WHERE stream_id = ? FOR UPDATE",
+        )
+        .bind(stream_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("ストリームのバージョンの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?
+        .get("version");
+
+        if current_version != expected_version {
+            return Err(concurrency_conflict(
+                stream_id,
+                expected_version,
+                current_version,
+            ));
+        }
+        if rows.is_empty() {
+            return Ok(current_version);
+        }
+
+        let mut query = QueryBuilder::<MySql>::new(
+            "INSERT INTO event_store (stream_id, version, event_id, event_type, occurred_at, payload) ",
+        );
+        query.push_values(&rows, |mut row, (version, event, payload)| {
+            row.push_bind(stream_id)
+                .push_bind(*version)
+                .push_bind(event.metadata().event_id.to_string())
+                .push_bind(event.event_type())
+                .push_bind(event.metadata().occurred_at)
+                .push_bind(payload.as_str());
+        });
+        query.build().execute(&mut *tx).await.map_err(|e| {
+            // 同時に追記した他の操作が先に同じバージョンを保存していた
+            if e.as_database_error()
+                .is_some_and(|db_error| db_error.is_unique_violation())
+            {
+                concurrency_conflict(stream_id, expected_version, expected_version + 1)
+            } else {
+                RepositoryError::from(DatabaseError::QueryError(format!(
+                    "イベントの追記に失敗しました: {}",
+                    e
+                )))
+            }
+        })?;
+
+        // トランザクションをコミット
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(expected_version + rows.len() as u64)
+    }
+
+    #[tracing::instrument(name = "db.event_store.load_stream", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "event_store", stream_id = stream_id), err)]
+    async fn load_stream(&self, stream_id: &str) -> Result<EventStream, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT version, payload
+            FROM event_store
+            WHERE stream_id = ?
+            ORDER BY version ASC
+            "#,
+        )
+        .bind(stream_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("イベントの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let serializer = EventSerializer::new();
+        let mut stream = EventStream::empty(stream_id);
+        for row in rows {
+            let event = serializer
+                .deserialize_event(row.get("payload"))
+                .map_err(|e| RepositoryError::FetchFailed(e.to_string()))?;
+            stream.version = row.get("version");
+            stream.events.push(event);
+        }
+        Ok(stream)
+    }
+}
+
+/// 楽観的排他制御の競合エラーを作成
+fn concurrency_conflict(stream_id: &str, expected: u64, actual: u64) -> RepositoryError {
+    RepositoryError::ConcurrencyConflict(format!(
+        "ストリーム{}のバージョンが{}ではなく{}です（他の操作が先に更新しました）",
+        stream_id, expected, actual
+    ))
+}
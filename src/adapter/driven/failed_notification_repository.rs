@@ -0,0 +1,189 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::OrderId;
+use crate::domain::notification_retry::{
+    FailedNotification, FailedNotificationStatus, Notification, NotificationChannel,
+};
+use crate::domain::port::{FailedNotificationRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+const SELECT_COLUMNS: &str = "SELECT id, channel, order_id, phone, event_type, message, correlation_id, status, attempts, last_error, next_attempt_at, created_at, updated_at, delivered_at FROM failed_notifications";
+
+/// MySQL送信失敗通知リポジトリ
+/// MySQLデータベース（failed_notificationsテーブル）に送信に失敗した通知を保存する
+#[derive(Clone)]
+pub struct MySqlFailedNotificationRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlFailedNotificationRepository {
+    /// 新しいMySQL送信失敗通知リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlFailedNotificationRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から通知を構築する
+    fn notification_from_row(row: &MySqlRow) -> Result<FailedNotification, RepositoryError> {
+        let parse_error =
+            |e: uuid::Error| RepositoryError::FetchFailed(format!("IDの解析に失敗しました: {}", e));
+        Ok(FailedNotification {
+            id: Uuid::parse_str(row.get("id")).map_err(parse_error)?,
+            notification: Notification {
+                channel: NotificationChannel::from_string(row.get("channel")).map_err(|e| {
+                    RepositoryError::FetchFailed(format!(
+                        "通知のチャネルの解析に失敗しました: {}",
+                        e
+                    ))
+                })?,
+                order_id: OrderId::from_string(&row.get::<String, _>("order_id"))
+                    .map_err(parse_error)?,
+                phone: row.get("phone"),
+                event_type: row.get("event_type"),
+                message: row.get("message"),
+                correlation_id: Uuid::parse_str(row.get("correlation_id")).map_err(parse_error)?,
+            },
+            status: FailedNotificationStatus::from_string(row.get("status")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("通知の状態の解析に失敗しました: {}", e))
+            })?,
+            attempts: row.get("attempts"),
+            last_error: row.get("last_error"),
+            next_attempt_at: row.get::<Option<DateTime<Utc>>, _>("next_attempt_at"),
+            created_at: row.get::<DateTime<Utc>, _>("created_at"),
+            updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+            delivered_at: row.get::<Option<DateTime<Utc>>, _>("delivered_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl FailedNotificationRepository for MySqlFailedNotificationRepository {
+    #[tracing::instrument(name = "db.failed_notifications.save", skip_all, fields(db.system = "mysql", db.operation = "UPSERT", db.sql.table = "failed_notifications", notification_id = %notification.id, status = %notification.status), err)]
+    async fn save(&self, notification: &FailedNotification) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO failed_notifications
+                (id, channel, order_id, phone, event_type, message, correlation_id, status, attempts, last_error, next_attempt_at, created_at, updated_at, delivered_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                attempts = VALUES(attempts),
+                last_error = VALUES(last_error),
+                next_attempt_at = VALUES(next_attempt_at),
+                updated_at = VALUES(updated_at),
+                delivered_at = VALUES(delivered_at)
+            "#,
+        )
+        .bind(notification.id.to_string())
+        .bind(notification.notification.channel.to_string())
+        .bind(notification.notification.order_id.to_string())
+        .bind(&notification.notification.phone)
+        .bind(&notification.notification.event_type)
+        .bind(&notification.notification.message)
+        .bind(notification.notification.correlation_id.to_string())
+        .bind(notification.status.to_string())
+        .bind(notification.attempts)
+        .bind(&notification.last_error)
+        .bind(notification.next_attempt_at)
+        .bind(notification.created_at)
+        .bind(notification.updated_at)
+        .bind(notification.delivered_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("送信に失敗した通知の保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.failed_notifications.find_by_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "failed_notifications", notification_id = %id), err)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<FailedNotification>, RepositoryError> {
+        let row = sqlx::query(&format!("{} WHERE id = ?", SELECT_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("送信に失敗した通知の取得に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        row.as_ref().map(Self::notification_from_row).transpose()
+    }
+
+    #[tracing::instrument(name = "db.failed_notifications.find_due", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "failed_notifications", limit = limit), err)]
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE status = ? AND next_attempt_at <= ? ORDER BY next_attempt_at ASC, id ASC LIMIT ?",
+            SELECT_COLUMNS
+        ))
+        .bind(FailedNotificationStatus::Pending.to_string())
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("再送する通知の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::notification_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.failed_notifications.find_by_status", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "failed_notifications", status = %status, limit = limit), err)]
+    async fn find_by_status(
+        &self,
+        status: FailedNotificationStatus,
+        limit: u32,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE status = ? ORDER BY updated_at DESC, id ASC LIMIT ?",
+            SELECT_COLUMNS
+        ))
+        .bind(status.to_string())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("送信に失敗した通知の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::notification_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.failed_notifications.find_by_order", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "failed_notifications", order_id = %order_id), err)]
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE order_id = ? ORDER BY created_at ASC, id ASC",
+            SELECT_COLUMNS
+        ))
+        .bind(order_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("注文の通知の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::notification_from_row).collect()
+    }
+}
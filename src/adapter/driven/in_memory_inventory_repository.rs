@@ -0,0 +1,206 @@
+use crate::domain::inventory_snapshot::{InventorySnapshot, InventorySnapshotEntry, StockMovement};
+use crate::domain::inventory_valuation::StockValuation;
+use crate::domain::model::{BookId, Inventory, Money};
+use crate::domain::port::{InventoryRepository, RepositoryError};
+use crate::domain::reconciliation::LedgerDrift;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// インメモリ在庫リポジトリ（テスト・サンプル用、`test-support` フィーチャーで有効）
+/// 複製したリポジトリは同じ在庫を共有する
+/// 在庫変動ログの記録はMySqlInventoryRepositoryと同じく、在庫数が変わる保存のたびに行う
+#[derive(Clone, Default)]
+pub struct InMemoryInventoryRepository {
+    state: Arc<Mutex<InMemoryInventoryState>>,
+}
+
+#[derive(Default)]
+struct InMemoryInventoryState {
+    inventories: HashMap<BookId, Inventory>,
+    /// 書籍カタログの販売価格（在庫評価で在庫と結合する）
+    prices: HashMap<BookId, Money>,
+    /// 在庫変動ログ
+    movements: Vec<StockMovement>,
+}
+
+impl InMemoryInventoryState {
+    /// 在庫変動ログに記録する
+    fn record_movement(&mut self, book_id: BookId, quantity_delta: i64, quantity_on_hand: u32) {
+        let version = self.movements.len() as u64 + 1;
+        self.movements.push(StockMovement {
+            version,
+            book_id,
+            quantity_delta,
+            quantity_on_hand,
+            recorded_at: Utc::now(),
+        });
+    }
+
+    /// 書籍の在庫変動ログの残高（変動がない場合はNone）
+    fn ledger_quantity(&self, book_id: BookId) -> Option<i64> {
+        self.movements
+            .iter()
+            .filter(|movement| movement.book_id == book_id)
+            .map(|movement| movement.quantity_delta)
+            .reduce(|total, delta| total + delta)
+    }
+}
+
+impl InMemoryInventoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 在庫を在庫変動ログに記録せずに登録する（テストの前提データの用意や、ログを経由しない変更の模擬に使う）
+    pub async fn add_inventory(&self, inventory: Inventory) {
+        let mut state = self.state.lock().await;
+        state.inventories.insert(inventory.book_id(), inventory);
+    }
+
+    /// 在庫評価で結合する書籍の販売価格を設定する
+    pub async fn set_price(&self, book_id: BookId, price: Money) {
+        self.state.lock().await.prices.insert(book_id, price);
+    }
+}
+
+#[async_trait]
+impl InventoryRepository for InMemoryInventoryRepository {
+    async fn save(&self, inventory: &Inventory) -> Result<(), RepositoryError> {
+        let mut state = self.state.lock().await;
+        let previous = state
+            .inventories
+            .insert(inventory.book_id(), inventory.clone())
+            .map(|previous| i64::from(previous.quantity_on_hand()));
+        let quantity_delta = i64::from(inventory.quantity_on_hand()) - previous.unwrap_or(0);
+        if previous.is_none() || quantity_delta != 0 {
+            state.record_movement(
+                inventory.book_id(),
+                quantity_delta,
+                inventory.quantity_on_hand(),
+            );
+        }
+        Ok(())
+    }
+
+    async fn find_by_book_id(&self, book_id: BookId) -> Result<Option<Inventory>, RepositoryError> {
+        let state = self.state.lock().await;
+        Ok(state.inventories.get(&book_id).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Inventory>, RepositoryError> {
+        let state = self.state.lock().await;
+        Ok(state.inventories.values().cloned().collect())
+    }
+
+    async fn find_by_max_quantity(
+        &self,
+        max_quantity: u32,
+    ) -> Result<Vec<Inventory>, RepositoryError> {
+        let state = self.state.lock().await;
+        Ok(state
+            .inventories
+            .values()
+            .filter(|inventory| inventory.quantity_on_hand() <= max_quantity)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_ledger_drifts(&self) -> Result<Vec<LedgerDrift>, RepositoryError> {
+        let state = self.state.lock().await;
+        let mut drifts: Vec<LedgerDrift> = state
+            .inventories
+            .values()
+            .filter_map(|inventory| {
+                let ledger_quantity = state.ledger_quantity(inventory.book_id())?;
+                let drift = LedgerDrift {
+                    book_id: inventory.book_id(),
+                    quantity_on_hand: inventory.quantity_on_hand(),
+                    ledger_quantity,
+                };
+                (drift.difference() != 0).then_some(drift)
+            })
+            .collect();
+        drifts.sort_by_key(|drift| drift.book_id.to_string());
+        Ok(drifts)
+    }
+
+    async fn freeze_ledger_drift(&self, book_id: BookId) -> Result<bool, RepositoryError> {
+        let mut state = self.state.lock().await;
+        let Some(quantity_on_hand) = state
+            .inventories
+            .get(&book_id)
+            .map(Inventory::quantity_on_hand)
+        else {
+            return Ok(false);
+        };
+        let Some(ledger_quantity) = state.ledger_quantity(book_id) else {
+            return Ok(false);
+        };
+        if i64::from(quantity_on_hand) == ledger_quantity {
+            return Ok(false);
+        }
+        if let Some(inventory) = state.inventories.get_mut(&book_id) {
+            inventory.freeze();
+        }
+        Ok(true)
+    }
+
+    async fn find_stock_valuations(&self) -> Result<Vec<StockValuation>, RepositoryError> {
+        let state = self.state.lock().await;
+        let mut valuations: Vec<StockValuation> = state
+            .inventories
+            .values()
+            .map(|inventory| StockValuation {
+                book_id: inventory.book_id(),
+                quantity_on_hand: inventory.quantity_on_hand(),
+                unit_price: state.prices.get(&inventory.book_id()).copied(),
+            })
+            .collect();
+        valuations.sort_by_key(|valuation| valuation.book_id.to_string());
+        Ok(valuations)
+    }
+
+    async fn take_snapshot(
+        &self,
+        taken_at: DateTime<Utc>,
+    ) -> Result<InventorySnapshot, RepositoryError> {
+        // 1つのロックの中で在庫とversionを読むため、同じ時点の状態になる
+        let state = self.state.lock().await;
+        let mut entries: Vec<InventorySnapshotEntry> = state
+            .inventories
+            .values()
+            .map(|inventory| InventorySnapshotEntry {
+                book_id: inventory.book_id(),
+                quantity_on_hand: inventory.quantity_on_hand(),
+                frozen: inventory.is_frozen(),
+            })
+            .collect();
+        entries.sort_by_key(|entry| entry.book_id.to_string());
+        Ok(InventorySnapshot {
+            version: state
+                .movements
+                .last()
+                .map_or(0, |movement| movement.version),
+            taken_at,
+            entries,
+        })
+    }
+
+    async fn find_movements_since(
+        &self,
+        since_version: u64,
+        limit: usize,
+    ) -> Result<Vec<StockMovement>, RepositoryError> {
+        let state = self.state.lock().await;
+        Ok(state
+            .movements
+            .iter()
+            .filter(|movement| movement.version > since_version)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
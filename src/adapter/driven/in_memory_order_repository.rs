@@ -0,0 +1,314 @@
+use crate::domain::model::{
+    BookId, CustomerId, Order, OrderId, OrderNumber, OrderStatus, TrackingToken,
+};
+use crate::domain::port::{OrderRepository, RepositoryError};
+use crate::domain::purchase_policy::{PurchaseCheck, PurchasePolicy};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// インメモリ注文リポジトリ（テスト・サンプル用、`test-support` フィーチャーで有効）
+/// 複製したリポジトリは同じ注文を共有するため、アプリケーションサービスとハンドラーに同じ状態を渡せる
+/// 作成日時は初めて保存・登録した日時とし、作成日時による絞り込み（`created_before`・`since`・`within`）に使う
+#[derive(Clone, Default)]
+pub struct InMemoryOrderRepository {
+    orders: Arc<Mutex<HashMap<OrderId, Order>>>,
+    /// 注文ごとの作成日時（`orders`と同時にロックする場合は`orders`を先にロックする）
+    created_at: Arc<Mutex<HashMap<OrderId, DateTime<Utc>>>>,
+}
+
+impl InMemoryOrderRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注文をバージョンを検証せずにそのまま登録する（テストの前提データの用意に使う）
+    pub async fn add_order(&self, order: Order) {
+        let mut orders = self.orders.lock().await;
+        self.created_at
+            .lock()
+            .await
+            .entry(order.id())
+            .or_insert_with(Utc::now);
+        orders.insert(order.id(), order);
+    }
+
+    /// 登録済みの注文の作成日時を変更する（期間による絞り込みのテストに使う）
+    pub async fn set_created_at(&self, order_id: OrderId, created_at: DateTime<Utc>) {
+        self.created_at.lock().await.insert(order_id, created_at);
+    }
+
+    /// ロックを保持したまま、バージョンを検証して注文を保存する
+    fn save_locked(
+        orders: &mut HashMap<OrderId, Order>,
+        created_at: &mut HashMap<OrderId, DateTime<Utc>>,
+        order: &mut Order,
+    ) -> Result<(), RepositoryError> {
+        // MySQLのリポジトリと同じく、読み込んだ後に他の操作が保存していた場合は競合にする
+        let stored_version = orders.get(&order.id()).map_or(0, Order::version);
+        if stored_version != order.version() {
+            return Err(RepositoryError::ConcurrencyConflict(format!(
+                "注文{}のバージョンが一致しません",
+                order.id()
+            )));
+        }
+        created_at.entry(order.id()).or_insert_with(Utc::now);
+        order.mark_saved(stored_version + 1);
+        orders.insert(order.id(), order.clone());
+        Ok(())
+    }
+}
+
+/// 顧客の未完了の注文数を数える
+fn count_open_orders(orders: &HashMap<OrderId, Order>, customer_id: CustomerId) -> u32 {
+    orders
+        .values()
+        .filter(|order| order.customer_id() == customer_id)
+        .filter(|order| {
+            matches!(
+                order.status(),
+                OrderStatus::Pending
+                    | OrderStatus::Confirmed
+                    | OrderStatus::OnHold
+                    | OrderStatus::AwaitingRelease
+                    | OrderStatus::Waitlisted
+            )
+        })
+        .count() as u32
+}
+
+/// 指定日時以降に作成された顧客の注文に含まれる書籍の合計数量を集計する
+fn sum_book_quantity_since(
+    orders: &HashMap<OrderId, Order>,
+    created_at: &HashMap<OrderId, DateTime<Utc>>,
+    customer_id: CustomerId,
+    book_id: BookId,
+    since: DateTime<Utc>,
+) -> u32 {
+    orders
+        .values()
+        .filter(|order| order.customer_id() == customer_id)
+        .filter(|order| order.status() != OrderStatus::Cancelled)
+        .filter(|order| created_at.get(&order.id()).is_some_and(|at| *at >= since))
+        .flat_map(|order| order.order_lines())
+        .filter(|line| line.book_id() == book_id)
+        .map(|line| line.quantity())
+        .sum()
+}
+
+#[async_trait]
+impl OrderRepository for InMemoryOrderRepository {
+    async fn save(&self, order: &mut Order) -> Result<(), RepositoryError> {
+        let mut orders = self.orders.lock().await;
+        let mut created_at = self.created_at.lock().await;
+        Self::save_locked(&mut orders, &mut created_at, order)
+    }
+
+    async fn save_within_purchase_limits(
+        &self,
+        order: &mut Order,
+        policy: &PurchasePolicy,
+        check: PurchaseCheck,
+    ) -> Result<(), RepositoryError> {
+        // 集計から保存までロックを保持し、同時の保存が集計をすり抜けないようにする
+        let mut orders = self.orders.lock().await;
+        let mut created_at = self.created_at.lock().await;
+        let counted = match &check {
+            PurchaseCheck::OpenOrder => count_open_orders(&orders, order.customer_id()),
+            PurchaseCheck::BookQuantity { book_id, since, .. } => sum_book_quantity_since(
+                &orders,
+                &created_at,
+                order.customer_id(),
+                *book_id,
+                *since,
+            ),
+        };
+        policy
+            .ensure(&check, counted)
+            .map_err(RepositoryError::PurchaseLimitExceeded)?;
+        Self::save_locked(&mut orders, &mut created_at, order)
+    }
+
+    async fn find_by_id(&self, order_id: OrderId) -> Result<Option<Order>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        Ok(orders.get(&order_id).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Order>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        Ok(orders.values().cloned().collect())
+    }
+
+    async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        Ok(orders
+            .values()
+            .filter(|order| order.status() == status)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        Ok(orders
+            .values()
+            .filter(|order| order.customer_id() == customer_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_customer_page(
+        &self,
+        customer_id: CustomerId,
+        after: Option<OrderId>,
+        limit: u32,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let after = after.map(|id| id.to_string()).unwrap_or_default();
+        let mut orders: Vec<Order> = self
+            .find_by_customer(customer_id)
+            .await?
+            .into_iter()
+            .filter(|order| order.id().to_string() > after)
+            .collect();
+        orders.sort_by_key(|order| order.id().to_string());
+        orders.truncate(limit as usize);
+        Ok(orders)
+    }
+
+    async fn find_by_status_created_before(
+        &self,
+        status: OrderStatus,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        let created_at = self.created_at.lock().await;
+        Ok(orders
+            .values()
+            .filter(|order| order.status() == status)
+            .filter(|order| {
+                created_at
+                    .get(&order.id())
+                    .is_some_and(|at| *at < created_before)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn count_open_orders_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<u32, RepositoryError> {
+        let orders = self.orders.lock().await;
+        Ok(count_open_orders(&orders, customer_id))
+    }
+
+    async fn count_orders_by_customer_and_status(
+        &self,
+        customer_id: CustomerId,
+        status: OrderStatus,
+    ) -> Result<u32, RepositoryError> {
+        let orders = self.orders.lock().await;
+        Ok(orders
+            .values()
+            .filter(|order| order.customer_id() == customer_id && order.status() == status)
+            .count() as u32)
+    }
+
+    async fn sum_book_quantity_by_customer_since(
+        &self,
+        customer_id: CustomerId,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, RepositoryError> {
+        let orders = self.orders.lock().await;
+        let created_at = self.created_at.lock().await;
+        Ok(sum_book_quantity_since(
+            &orders,
+            &created_at,
+            customer_id,
+            book_id,
+            since,
+        ))
+    }
+
+    async fn find_by_tracking_token(
+        &self,
+        token: &TrackingToken,
+    ) -> Result<Option<Order>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        Ok(orders
+            .values()
+            .find(|order| order.tracking_token() == Some(token))
+            .cloned())
+    }
+
+    async fn find_by_order_number(
+        &self,
+        order_number: &OrderNumber,
+    ) -> Result<Option<Order>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        Ok(orders
+            .values()
+            .find(|order| order.order_number() == Some(order_number))
+            .cloned())
+    }
+
+    async fn find_similar_orders(
+        &self,
+        order_id: OrderId,
+        within: TimeDelta,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        let created_at = self.created_at.lock().await;
+        let (Some(target), Some(target_created_at)) =
+            (orders.get(&order_id), created_at.get(&order_id))
+        else {
+            return Ok(Vec::new());
+        };
+        let mut similar: Vec<(DateTime<Utc>, Order)> = orders
+            .values()
+            .filter(|order| order.id() != order_id)
+            .filter(|order| order.customer_id() == target.customer_id())
+            .filter(|order| order.status() != OrderStatus::Cancelled)
+            .filter(|order| order.has_same_lines(target))
+            .filter_map(|order| {
+                let at = *created_at.get(&order.id())?;
+                ((at - *target_created_at).abs() <= within).then(|| (at, order.clone()))
+            })
+            .collect();
+        similar.sort_by_key(|(at, _)| *at);
+        Ok(similar.into_iter().map(|(_, order)| order).collect())
+    }
+
+    async fn sum_daily_book_demand_since(
+        &self,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<BTreeMap<NaiveDate, u32>, RepositoryError> {
+        let orders = self.orders.lock().await;
+        let mut demand = BTreeMap::new();
+        for order in orders.values() {
+            let Some(confirmed_at) = order.confirmed_at() else {
+                continue;
+            };
+            if order.status() == OrderStatus::Cancelled || confirmed_at < since {
+                continue;
+            }
+            for line in order.order_lines() {
+                if line.book_id() == book_id {
+                    *demand.entry(confirmed_at.date_naive()).or_insert(0) += line.quantity();
+                }
+            }
+        }
+        Ok(demand)
+    }
+
+    fn next_identity(&self) -> OrderId {
+        OrderId::new()
+    }
+}
@@ -0,0 +1,1102 @@
+//! インメモリの補助ストア
+//!
+//! SQLiteモード（`--sqlite`）で、注文・在庫・書籍カタログ以外のポートをプロセス内に保持する実装。
+//! 外部のデータベースを用意せずにサンプル全体を動かすためのもので、プロセスを終了すると内容は失われる。
+//! 並び順・重複の扱いは対応するMySQLリポジトリに合わせている。
+
+use crate::domain::action_link::ActionLinkUse;
+use crate::domain::checkout_hold::CheckoutHold;
+use crate::domain::customer_webhook::{WebhookDelivery, WebhookSubscription};
+use crate::domain::event::DomainEvent;
+use crate::domain::event_export::JournaledEvent;
+use crate::domain::inbox::{InboxMessage, InboxMessageStatus};
+use crate::domain::late_event::ParkedEvent;
+use crate::domain::legacy_import::{LegacyImportOutcome, LegacyImportRecord};
+use crate::domain::model::{
+    AllocationQuota, BookId, Customer, CustomerId, CycleCount, CycleCountId, DeviceRegistration,
+    DeviceToken, EmailAddress, OrderId, OrderNumber,
+};
+use crate::domain::notification_retry::{FailedNotification, FailedNotificationStatus};
+use crate::domain::order_lock::OrderLock;
+use crate::domain::order_note::OrderNote;
+use crate::domain::pending_operation::{PendingOperation, PendingOperationStatus};
+use crate::domain::port::{
+    ActionLinkAuditRepository, AllocationQuotaRepository, CheckoutHoldRepository,
+    CustomerRepository, CycleCountRepository, DeviceRegistrationRepository, EventJournal,
+    FailedNotificationRepository, InboxRepository, LegacyImportRepository, OrderLockRepository,
+    OrderNoteRepository, OrderNumberGenerator, ParkedEventRepository, PendingOperationRepository,
+    ProcessedEventRepository, RepositoryError, SagaCompensationRepository,
+    StatusOverrideAuditRepository, SubscriptionRegistryRepository, TrackingEventRepository,
+    WaitlistRepository, WebhookDeliveryRepository, WebhookSubscriptionRepository,
+};
+use crate::domain::projection::ProjectionCheckpoint;
+use crate::domain::saga_metrics::SagaCompensation;
+use crate::domain::serialization::EventSerializer;
+use crate::domain::status_override::StatusOverrideAudit;
+use crate::domain::subscription_registry::SubscriptionRegistration;
+use crate::domain::tracking::TrackingEvent;
+use crate::domain::waitlist::WaitlistEntry;
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Utc};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// インメモリ棚卸しリポジトリ
+#[derive(Default)]
+pub struct InMemoryCycleCountRepository {
+    cycle_counts: Mutex<HashMap<CycleCountId, CycleCount>>,
+}
+
+impl InMemoryCycleCountRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CycleCountRepository for InMemoryCycleCountRepository {
+    async fn save(&self, cycle_count: &CycleCount) -> Result<(), RepositoryError> {
+        self.cycle_counts
+            .lock()
+            .await
+            .insert(cycle_count.id(), cycle_count.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        cycle_count_id: CycleCountId,
+    ) -> Result<Option<CycleCount>, RepositoryError> {
+        Ok(self.cycle_counts.lock().await.get(&cycle_count_id).cloned())
+    }
+}
+
+/// インメモリ配送追跡イベントリポジトリ
+#[derive(Default)]
+pub struct InMemoryTrackingEventRepository {
+    state: Mutex<TrackingState>,
+}
+
+#[derive(Default)]
+struct TrackingState {
+    events: Vec<TrackingEvent>,
+    /// プロジェクション名・集約IDごとの適用済みの連番
+    checkpoints: HashMap<(String, String), u64>,
+}
+
+impl TrackingState {
+    fn append(&mut self, event: &TrackingEvent) {
+        if !self.events.iter().any(|e| e.event_id == event.event_id) {
+            self.events.push(event.clone());
+        }
+    }
+}
+
+impl InMemoryTrackingEventRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TrackingEventRepository for InMemoryTrackingEventRepository {
+    async fn append(&self, event: &TrackingEvent) -> Result<(), RepositoryError> {
+        self.state.lock().await.append(event);
+        Ok(())
+    }
+
+    async fn find_by_order(&self, order_id: OrderId) -> Result<Vec<TrackingEvent>, RepositoryError> {
+        let state = self.state.lock().await;
+        let mut events: Vec<TrackingEvent> = state
+            .events
+            .iter()
+            .filter(|e| e.order_id == order_id)
+            .cloned()
+            .collect();
+        events.sort_by_key(|e| e.occurred_at);
+        Ok(events)
+    }
+
+    async fn apply_checkpointed(
+        &self,
+        checkpoint: &ProjectionCheckpoint,
+        event: Option<&TrackingEvent>,
+    ) -> Result<bool, RepositoryError> {
+        let mut state = self.state.lock().await;
+        let key = (checkpoint.projection.clone(), checkpoint.aggregate_id.clone());
+        if state
+            .checkpoints
+            .get(&key)
+            .is_some_and(|&last| last >= checkpoint.sequence_number)
+        {
+            return Ok(false);
+        }
+        if let Some(event) = event {
+            state.append(event);
+        }
+        state.checkpoints.insert(key, checkpoint.sequence_number);
+        Ok(true)
+    }
+
+    async fn load_checkpoints(
+        &self,
+        projection: &str,
+    ) -> Result<Vec<ProjectionCheckpoint>, RepositoryError> {
+        let state = self.state.lock().await;
+        Ok(state
+            .checkpoints
+            .iter()
+            .filter(|((name, _), _)| name == projection)
+            .map(|((name, aggregate_id), &last)| ProjectionCheckpoint::new(name, aggregate_id, last))
+            .collect())
+    }
+}
+
+/// インメモリ保留イベントリポジトリ
+#[derive(Default)]
+pub struct InMemoryParkedEventRepository {
+    events: Mutex<Vec<ParkedEvent>>,
+}
+
+impl InMemoryParkedEventRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ParkedEventRepository for InMemoryParkedEventRepository {
+    async fn park(&self, event: &ParkedEvent) -> Result<(), RepositoryError> {
+        let mut events = self.events.lock().await;
+        if !events
+            .iter()
+            .any(|parked| parked.event_id == event.event_id && parked.handler == event.handler)
+        {
+            events.push(event.clone());
+        }
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<ParkedEvent>, RepositoryError> {
+        Ok(self.events.lock().await.clone())
+    }
+}
+
+/// インメモリ再試行待ちの操作リポジトリ
+#[derive(Default)]
+pub struct InMemoryPendingOperationRepository {
+    operations: Mutex<Vec<PendingOperation>>,
+}
+
+impl InMemoryPendingOperationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PendingOperationRepository for InMemoryPendingOperationRepository {
+    async fn save(&self, operation: &PendingOperation) -> Result<(), RepositoryError> {
+        let mut operations = self.operations.lock().await;
+        match operations.iter_mut().find(|o| o.id == operation.id) {
+            Some(existing) => *existing = operation.clone(),
+            None => operations.push(operation.clone()),
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PendingOperation>, RepositoryError> {
+        let operations = self.operations.lock().await;
+        Ok(operations.iter().find(|o| o.id == id).cloned())
+    }
+
+    async fn find_pending(&self, limit: u32) -> Result<Vec<PendingOperation>, RepositoryError> {
+        let operations = self.operations.lock().await;
+        Ok(operations
+            .iter()
+            .filter(|o| o.status == PendingOperationStatus::Pending)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+/// インメモリ送信に失敗した通知のリポジトリ
+#[derive(Default)]
+pub struct InMemoryFailedNotificationRepository {
+    notifications: Mutex<Vec<FailedNotification>>,
+}
+
+impl InMemoryFailedNotificationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl FailedNotificationRepository for InMemoryFailedNotificationRepository {
+    async fn save(&self, notification: &FailedNotification) -> Result<(), RepositoryError> {
+        let mut notifications = self.notifications.lock().await;
+        match notifications.iter_mut().find(|n| n.id == notification.id) {
+            Some(existing) => *existing = notification.clone(),
+            None => notifications.push(notification.clone()),
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<FailedNotification>, RepositoryError> {
+        let notifications = self.notifications.lock().await;
+        Ok(notifications.iter().find(|n| n.id == id).cloned())
+    }
+
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        let notifications = self.notifications.lock().await;
+        let mut due: Vec<FailedNotification> = notifications
+            .iter()
+            .filter(|n| {
+                n.status == FailedNotificationStatus::Pending
+                    && n.next_attempt_at.is_some_and(|at| at <= now)
+            })
+            .cloned()
+            .collect();
+        due.sort_by_key(|n| n.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+
+    async fn find_by_status(
+        &self,
+        status: FailedNotificationStatus,
+        limit: u32,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        let notifications = self.notifications.lock().await;
+        let mut found: Vec<FailedNotification> = notifications
+            .iter()
+            .filter(|n| n.status == status)
+            .cloned()
+            .collect();
+        found.sort_by_key(|n| Reverse(n.updated_at));
+        found.truncate(limit as usize);
+        Ok(found)
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        let notifications = self.notifications.lock().await;
+        let mut found: Vec<FailedNotification> = notifications
+            .iter()
+            .filter(|n| n.notification.order_id == order_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|n| n.created_at);
+        Ok(found)
+    }
+}
+
+/// インメモリアクションリンクの使用記録リポジトリ
+#[derive(Default)]
+pub struct InMemoryActionLinkAuditRepository {
+    records: Mutex<Vec<ActionLinkUse>>,
+}
+
+impl InMemoryActionLinkAuditRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ActionLinkAuditRepository for InMemoryActionLinkAuditRepository {
+    async fn save(&self, record: &ActionLinkUse) -> Result<(), RepositoryError> {
+        self.records.lock().await.push(record.clone());
+        Ok(())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<ActionLinkUse>, RepositoryError> {
+        let records = self.records.lock().await;
+        Ok(records
+            .iter()
+            .filter(|record| record.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+}
+
+/// インメモリ顧客リポジトリ
+#[derive(Default)]
+pub struct InMemoryCustomerRepository {
+    customers: Mutex<HashMap<CustomerId, Customer>>,
+}
+
+impl InMemoryCustomerRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CustomerRepository for InMemoryCustomerRepository {
+    async fn save(&self, customer: &Customer) -> Result<(), RepositoryError> {
+        self.customers
+            .lock()
+            .await
+            .insert(customer.id(), customer.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Option<Customer>, RepositoryError> {
+        Ok(self.customers.lock().await.get(&customer_id).cloned())
+    }
+
+    async fn find_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Option<Customer>, RepositoryError> {
+        let customers = self.customers.lock().await;
+        Ok(customers
+            .values()
+            .find(|customer| customer.email() == email)
+            .cloned())
+    }
+}
+
+/// インメモリデバイス登録リポジトリ
+#[derive(Default)]
+pub struct InMemoryDeviceRegistrationRepository {
+    registrations: Mutex<Vec<DeviceRegistration>>,
+}
+
+impl InMemoryDeviceRegistrationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DeviceRegistrationRepository for InMemoryDeviceRegistrationRepository {
+    async fn save(&self, registration: &DeviceRegistration) -> Result<(), RepositoryError> {
+        let mut registrations = self.registrations.lock().await;
+        match registrations.iter_mut().find(|r| {
+            r.customer_id() == registration.customer_id() && r.token() == registration.token()
+        }) {
+            Some(existing) => *existing = registration.clone(),
+            None => registrations.push(registration.clone()),
+        }
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        customer_id: CustomerId,
+        token: &DeviceToken,
+    ) -> Result<bool, RepositoryError> {
+        let mut registrations = self.registrations.lock().await;
+        let before = registrations.len();
+        registrations.retain(|r| !(r.customer_id() == customer_id && r.token() == token));
+        Ok(registrations.len() < before)
+    }
+
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<DeviceRegistration>, RepositoryError> {
+        let registrations = self.registrations.lock().await;
+        Ok(registrations
+            .iter()
+            .filter(|r| r.customer_id() == customer_id)
+            .cloned()
+            .collect())
+    }
+}
+
+/// インメモリWebhookの購読リポジトリ
+/// 購読を削除すると、その購読の配信ログも削除する（MySQLの外部キーのカスケード削除に合わせる）
+#[derive(Default)]
+pub struct InMemoryWebhookSubscriptionRepository {
+    subscriptions: Mutex<Vec<WebhookSubscription>>,
+}
+
+impl InMemoryWebhookSubscriptionRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepository for InMemoryWebhookSubscriptionRepository {
+    async fn save(&self, subscription: &WebhookSubscription) -> Result<(), RepositoryError> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        match subscriptions.iter_mut().find(|s| s.id == subscription.id) {
+            Some(existing) => *existing = subscription.clone(),
+            None => subscriptions.push(subscription.clone()),
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        let subscriptions = self.subscriptions.lock().await;
+        Ok(subscriptions.iter().find(|s| s.id == id).cloned())
+    }
+
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        let subscriptions = self.subscriptions.lock().await;
+        Ok(subscriptions
+            .iter()
+            .filter(|s| s.customer_id == customer_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, customer_id: CustomerId, id: Uuid) -> Result<bool, RepositoryError> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        let before = subscriptions.len();
+        subscriptions.retain(|s| !(s.customer_id == customer_id && s.id == id));
+        Ok(subscriptions.len() < before)
+    }
+}
+
+/// インメモリWebhookの配信ログリポジトリ
+#[derive(Default)]
+pub struct InMemoryWebhookDeliveryRepository {
+    deliveries: Mutex<Vec<WebhookDelivery>>,
+}
+
+impl InMemoryWebhookDeliveryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for InMemoryWebhookDeliveryRepository {
+    async fn record(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        self.deliveries.lock().await.push(delivery.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let deliveries = self.deliveries.lock().await;
+        Ok(deliveries.iter().find(|d| d.id == id).cloned())
+    }
+
+    async fn find_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let deliveries = self.deliveries.lock().await;
+        Ok(deliveries
+            .iter()
+            .rev()
+            .filter(|d| d.subscription_id == subscription_id)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+/// インメモリ順番待ちリポジトリ（登録順が先着順）
+#[derive(Default)]
+pub struct InMemoryWaitlistRepository {
+    entries: Mutex<Vec<WaitlistEntry>>,
+}
+
+impl InMemoryWaitlistRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WaitlistRepository for InMemoryWaitlistRepository {
+    async fn join(&self, entries: &[WaitlistEntry]) -> Result<(), RepositoryError> {
+        let mut stored = self.entries.lock().await;
+        for entry in entries {
+            if !stored
+                .iter()
+                .any(|e| e.order_id == entry.order_id && e.book_id == entry.book_id)
+            {
+                stored.push(entry.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_by_book(&self, book_id: BookId) -> Result<Vec<WaitlistEntry>, RepositoryError> {
+        let stored = self.entries.lock().await;
+        Ok(stored
+            .iter()
+            .filter(|e| e.book_id == book_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<WaitlistEntry>, RepositoryError> {
+        let stored = self.entries.lock().await;
+        Ok(stored
+            .iter()
+            .filter(|e| e.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn remove_order(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        self.entries.lock().await.retain(|e| e.order_id != order_id);
+        Ok(())
+    }
+}
+
+/// インメモリ割当枠リポジトリ
+#[derive(Default)]
+pub struct InMemoryAllocationQuotaRepository {
+    quotas: Mutex<HashMap<BookId, AllocationQuota>>,
+}
+
+impl InMemoryAllocationQuotaRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AllocationQuotaRepository for InMemoryAllocationQuotaRepository {
+    async fn find_by_book_id(
+        &self,
+        book_id: BookId,
+    ) -> Result<Option<AllocationQuota>, RepositoryError> {
+        Ok(self.quotas.lock().await.get(&book_id).cloned())
+    }
+
+    async fn save(&self, quota: &AllocationQuota) -> Result<(), RepositoryError> {
+        self.quotas.lock().await.insert(quota.book_id(), quota.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, book_id: BookId) -> Result<bool, RepositoryError> {
+        Ok(self.quotas.lock().await.remove(&book_id).is_some())
+    }
+}
+
+/// インメモリ在庫の仮押さえリポジトリ
+#[derive(Default)]
+pub struct InMemoryCheckoutHoldRepository {
+    holds: Mutex<Vec<CheckoutHold>>,
+}
+
+impl InMemoryCheckoutHoldRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CheckoutHoldRepository for InMemoryCheckoutHoldRepository {
+    async fn replace_for_order(
+        &self,
+        order_id: OrderId,
+        holds: &[CheckoutHold],
+    ) -> Result<(), RepositoryError> {
+        let mut stored = self.holds.lock().await;
+        stored.retain(|hold| hold.order_id != order_id);
+        stored.extend_from_slice(holds);
+        Ok(())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<CheckoutHold>, RepositoryError> {
+        let stored = self.holds.lock().await;
+        Ok(stored
+            .iter()
+            .filter(|hold| hold.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<CheckoutHold>, RepositoryError> {
+        let stored = self.holds.lock().await;
+        let mut expired: Vec<CheckoutHold> = stored
+            .iter()
+            .filter(|hold| hold.is_expired(now))
+            .cloned()
+            .collect();
+        expired.sort_by_key(|hold| hold.expires_at);
+        Ok(expired)
+    }
+
+    async fn remove_order(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        self.holds.lock().await.retain(|hold| hold.order_id != order_id);
+        Ok(())
+    }
+}
+
+/// インメモリ注文の編集ロックリポジトリ
+#[derive(Default)]
+pub struct InMemoryOrderLockRepository {
+    locks: Mutex<HashMap<OrderId, OrderLock>>,
+}
+
+impl InMemoryOrderLockRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OrderLockRepository for InMemoryOrderLockRepository {
+    async fn try_acquire(&self, lock: &OrderLock) -> Result<OrderLock, RepositoryError> {
+        let mut locks = self.locks.lock().await;
+        let current = locks
+            .entry(lock.order_id)
+            .and_modify(|current| {
+                if current.is_expired(lock.locked_at) || current.locked_by == lock.locked_by {
+                    *current = lock.clone();
+                }
+            })
+            .or_insert_with(|| lock.clone());
+        Ok(current.clone())
+    }
+
+    async fn find_by_order_id(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Option<OrderLock>, RepositoryError> {
+        Ok(self.locks.lock().await.get(&order_id).cloned())
+    }
+
+    async fn find_active(&self, now: DateTime<Utc>) -> Result<Vec<OrderLock>, RepositoryError> {
+        let locks = self.locks.lock().await;
+        Ok(locks
+            .values()
+            .filter(|lock| !lock.is_expired(now))
+            .cloned()
+            .collect())
+    }
+
+    async fn remove(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        self.locks.lock().await.remove(&order_id);
+        Ok(())
+    }
+}
+
+/// インメモリCSメモリポジトリ
+#[derive(Default)]
+pub struct InMemoryOrderNoteRepository {
+    notes: Mutex<Vec<OrderNote>>,
+}
+
+impl InMemoryOrderNoteRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OrderNoteRepository for InMemoryOrderNoteRepository {
+    async fn add(&self, note: &OrderNote) -> Result<(), RepositoryError> {
+        self.notes.lock().await.push(note.clone());
+        Ok(())
+    }
+
+    async fn find_by_order(&self, order_id: OrderId) -> Result<Vec<OrderNote>, RepositoryError> {
+        let notes = self.notes.lock().await;
+        Ok(notes
+            .iter()
+            .filter(|note| note.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+}
+
+/// インメモリ強制遷移の監査記録リポジトリ
+#[derive(Default)]
+pub struct InMemoryStatusOverrideAuditRepository {
+    audits: Mutex<Vec<StatusOverrideAudit>>,
+}
+
+impl InMemoryStatusOverrideAuditRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl StatusOverrideAuditRepository for InMemoryStatusOverrideAuditRepository {
+    async fn add(&self, audit: &StatusOverrideAudit) -> Result<(), RepositoryError> {
+        self.audits.lock().await.push(audit.clone());
+        Ok(())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<StatusOverrideAudit>, RepositoryError> {
+        let audits = self.audits.lock().await;
+        Ok(audits
+            .iter()
+            .filter(|audit| audit.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+}
+
+/// インメモリ処理済みイベントリポジトリ
+#[derive(Default)]
+pub struct InMemoryProcessedEventRepository {
+    processed: Mutex<HashSet<(Uuid, String)>>,
+}
+
+impl InMemoryProcessedEventRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ProcessedEventRepository for InMemoryProcessedEventRepository {
+    async fn is_processed(
+        &self,
+        event_id: Uuid,
+        handler_name: &str,
+    ) -> Result<bool, RepositoryError> {
+        let processed = self.processed.lock().await;
+        Ok(processed.contains(&(event_id, handler_name.to_string())))
+    }
+
+    async fn mark_processed(
+        &self,
+        event_id: Uuid,
+        handler_name: &str,
+        _processed_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        self.processed
+            .lock()
+            .await
+            .insert((event_id, handler_name.to_string()));
+        Ok(())
+    }
+}
+
+/// インメモリ補償の記録リポジトリ
+#[derive(Default)]
+pub struct InMemorySagaCompensationRepository {
+    compensations: Mutex<Vec<SagaCompensation>>,
+}
+
+impl InMemorySagaCompensationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SagaCompensationRepository for InMemorySagaCompensationRepository {
+    async fn record(&self, compensation: &SagaCompensation) -> Result<(), RepositoryError> {
+        let mut compensations = self.compensations.lock().await;
+        if !compensations
+            .iter()
+            .any(|recorded| recorded.event_id == compensation.event_id)
+        {
+            compensations.push(compensation.clone());
+        }
+        Ok(())
+    }
+
+    async fn count_by_failed_step(&self) -> Result<BTreeMap<String, u64>, RepositoryError> {
+        let compensations = self.compensations.lock().await;
+        let mut counts = BTreeMap::new();
+        for compensation in compensations.iter() {
+            *counts.entry(compensation.failed_step.clone()).or_insert(0) += 1;
+        }
+        Ok(counts)
+    }
+}
+
+/// インメモリ受信メッセージリポジトリ（受信順に処理する）
+#[derive(Default)]
+pub struct InMemoryInboxRepository {
+    messages: Mutex<Vec<InboxMessage>>,
+}
+
+impl InMemoryInboxRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl InboxRepository for InMemoryInboxRepository {
+    async fn insert(&self, message: &InboxMessage) -> Result<bool, RepositoryError> {
+        let mut messages = self.messages.lock().await;
+        if messages
+            .iter()
+            .any(|m| m.source == message.source && m.external_id == message.external_id)
+        {
+            return Ok(false);
+        }
+        messages.push(message.clone());
+        Ok(true)
+    }
+
+    async fn find_pending(&self, limit: usize) -> Result<Vec<InboxMessage>, RepositoryError> {
+        let messages = self.messages.lock().await;
+        Ok(messages
+            .iter()
+            .filter(|m| m.status == InboxMessageStatus::Pending)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn mark_processed(
+        &self,
+        source: &str,
+        external_id: &str,
+        processed_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut messages = self.messages.lock().await;
+        if let Some(message) = messages
+            .iter_mut()
+            .find(|m| m.source == source && m.external_id == external_id)
+        {
+            message.status = InboxMessageStatus::Processed;
+            message.attempts += 1;
+            message.processed_at = Some(processed_at);
+        }
+        Ok(())
+    }
+
+    async fn record_failure(
+        &self,
+        source: &str,
+        external_id: &str,
+        error: &str,
+        give_up: bool,
+    ) -> Result<(), RepositoryError> {
+        let mut messages = self.messages.lock().await;
+        if let Some(message) = messages
+            .iter_mut()
+            .find(|m| m.source == source && m.external_id == external_id)
+        {
+            if give_up {
+                message.status = InboxMessageStatus::Failed;
+            }
+            message.attempts += 1;
+            message.last_error = Some(error.to_string());
+        }
+        Ok(())
+    }
+}
+
+/// インメモリ旧システムの注文の取り込み記録リポジトリ
+#[derive(Default)]
+pub struct InMemoryLegacyImportRepository {
+    records: Mutex<Vec<LegacyImportRecord>>,
+}
+
+impl InMemoryLegacyImportRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LegacyImportRepository for InMemoryLegacyImportRepository {
+    async fn save(&self, record: &LegacyImportRecord) -> Result<(), RepositoryError> {
+        let mut records = self.records.lock().await;
+        records.retain(|r| r.legacy_order_no != record.legacy_order_no);
+        records.push(record.clone());
+        Ok(())
+    }
+
+    async fn find_by_legacy_order_no(
+        &self,
+        legacy_order_no: &str,
+    ) -> Result<Option<LegacyImportRecord>, RepositoryError> {
+        let records = self.records.lock().await;
+        Ok(records
+            .iter()
+            .find(|r| r.legacy_order_no == legacy_order_no)
+            .cloned())
+    }
+
+    async fn find_quarantined(&self) -> Result<Vec<LegacyImportRecord>, RepositoryError> {
+        let records = self.records.lock().await;
+        let mut quarantined: Vec<LegacyImportRecord> = records
+            .iter()
+            .filter(|r| r.outcome == LegacyImportOutcome::Quarantined)
+            .cloned()
+            .collect();
+        quarantined.sort_by_key(|r| r.recorded_at);
+        Ok(quarantined)
+    }
+}
+
+/// インメモリ購読の登録内容リポジトリ（ハンドラー名の順に返す）
+#[derive(Default)]
+pub struct InMemorySubscriptionRegistryRepository {
+    registrations: Mutex<BTreeMap<String, SubscriptionRegistration>>,
+}
+
+impl InMemorySubscriptionRegistryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SubscriptionRegistryRepository for InMemorySubscriptionRegistryRepository {
+    async fn save(&self, registration: &SubscriptionRegistration) -> Result<(), RepositoryError> {
+        self.registrations
+            .lock()
+            .await
+            .insert(registration.handler_name.clone(), registration.clone());
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<SubscriptionRegistration>, RepositoryError> {
+        Ok(self.registrations.lock().await.values().cloned().collect())
+    }
+
+    async fn delete(&self, handler_name: &str) -> Result<bool, RepositoryError> {
+        Ok(self
+            .registrations
+            .lock()
+            .await
+            .remove(handler_name)
+            .is_some())
+    }
+}
+
+/// インメモリイベントジャーナル（記録順の位置は1から始まる）
+#[derive(Default)]
+pub struct InMemoryEventJournal {
+    /// 記録したイベントと集約ID
+    events: Mutex<Vec<(JournaledEvent, String)>>,
+}
+
+impl InMemoryEventJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EventJournal for InMemoryEventJournal {
+    async fn append(&self, event: &DomainEvent) -> Result<bool, RepositoryError> {
+        let payload = EventSerializer::new()
+            .serialize_event(event)
+            .map_err(|e| {
+                RepositoryError::OperationFailed(format!(
+                    "イベントのシリアライズに失敗しました: {}",
+                    e
+                ))
+            })?;
+        let metadata = event.metadata();
+        let mut events = self.events.lock().await;
+        if events
+            .iter()
+            .any(|(journaled, _)| journaled.event_id == metadata.event_id)
+        {
+            return Ok(false);
+        }
+        let position = events.len() as u64 + 1;
+        events.push((
+            JournaledEvent {
+                position,
+                event_id: metadata.event_id,
+                event_type: event.event_type().to_string(),
+                occurred_at: metadata.occurred_at,
+                payload,
+            },
+            event.aggregate_id(),
+        ));
+        Ok(true)
+    }
+
+    async fn read_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after_position: u64,
+        limit: usize,
+    ) -> Result<Vec<JournaledEvent>, RepositoryError> {
+        let events = self.events.lock().await;
+        Ok(events
+            .iter()
+            .map(|(journaled, _)| journaled)
+            .filter(|e| e.occurred_at >= from && e.occurred_at < to && e.position > after_position)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Vec<JournaledEvent>, RepositoryError> {
+        let events = self.events.lock().await;
+        Ok(events
+            .iter()
+            .filter(|(_, id)| id == aggregate_id)
+            .map(|(journaled, _)| journaled.clone())
+            .collect())
+    }
+}
+
+/// インメモリ注文番号の採番（作成年ごとの連番）
+pub struct InMemoryOrderNumberGenerator {
+    prefix: String,
+    digits: usize,
+    sequences: Mutex<HashMap<i32, u64>>,
+}
+
+impl InMemoryOrderNumberGenerator {
+    /// 新しい採番を作成
+    ///
+    /// # Arguments
+    /// * `prefix` - 注文番号の接頭辞
+    /// * `digits` - 連番の桁数
+    pub fn new(prefix: String, digits: usize) -> Self {
+        Self {
+            prefix,
+            digits,
+            sequences: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl OrderNumberGenerator for InMemoryOrderNumberGenerator {
+    async fn next_order_number(
+        &self,
+        created_at: DateTime<Utc>,
+    ) -> Result<OrderNumber, RepositoryError> {
+        let mut sequences = self.sequences.lock().await;
+        let sequence = sequences.entry(created_at.year()).or_insert(0);
+        *sequence += 1;
+        OrderNumber::new(&self.prefix, created_at.year(), *sequence, self.digits)
+            .map_err(|e| RepositoryError::OperationFailed(e.to_string()))
+    }
+}
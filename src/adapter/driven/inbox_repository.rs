@@ -0,0 +1,145 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::inbox::{InboxMessage, InboxMessageStatus};
+use crate::domain::port::{InboxRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+// MySQL関連のインポート
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL受信メッセージリポジトリ
+/// MySQLデータベース（inbox_messagesテーブル）に外部システムから受信したメッセージを保存する
+#[derive(Clone)]
+pub struct MySqlInboxRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlInboxRepository {
+    /// 新しいMySQL受信メッセージリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlInboxRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InboxRepository for MySqlInboxRepository {
+    #[tracing::instrument(name = "db.inbox_messages.insert", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "inbox_messages", source = %message.source, external_id = %message.external_id), err)]
+    async fn insert(&self, message: &InboxMessage) -> Result<bool, RepositoryError> {
+        // (source, external_id)の一意制約により、再送されたメッセージは無視される
+        let result = sqlx::query(
+            r#"
+            INSERT IGNORE INTO inbox_messages
+                (source, external_id, payload, received_at, status, attempts, last_error, processed_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&message.source)
+        .bind(&message.external_id)
+        .bind(&message.payload)
+        .bind(message.received_at)
+        .bind(message.status.as_str())
+        .bind(message.attempts)
+        .bind(&message.last_error)
+        .bind(message.processed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("受信メッセージの保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    #[tracing::instrument(name = "db.inbox_messages.find_pending", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "inbox_messages"), err)]
+    async fn find_pending(&self, limit: usize) -> Result<Vec<InboxMessage>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT source, external_id, payload, received_at, status, attempts, last_error, processed_at
+            FROM inbox_messages
+            WHERE status = ?
+            ORDER BY id ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(InboxMessageStatus::Pending.as_str())
+        .bind(limit as u64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("受信メッセージの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut messages = Vec::new();
+        for row in rows {
+            let status = InboxMessageStatus::from_string(row.get("status")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("処理状態の解析に失敗しました: {}", e))
+            })?;
+            messages.push(InboxMessage {
+                source: row.get("source"),
+                external_id: row.get("external_id"),
+                payload: row.get("payload"),
+                received_at: row.get::<DateTime<Utc>, _>("received_at"),
+                status,
+                attempts: row.get("attempts"),
+                last_error: row.get("last_error"),
+                processed_at: row.get::<Option<DateTime<Utc>>, _>("processed_at"),
+            });
+        }
+
+        Ok(messages)
+    }
+
+    #[tracing::instrument(name = "db.inbox_messages.mark_processed", skip_all, fields(db.system = "mysql", db.operation = "UPDATE", db.sql.table = "inbox_messages", source = %source, external_id = %external_id), err)]
+    async fn mark_processed(
+        &self,
+        source: &str,
+        external_id: &str,
+        processed_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "UPDATE inbox_messages SET status = ?, attempts = attempts + 1, processed_at = ? WHERE source = ? AND external_id = ?",
+        )
+        .bind(InboxMessageStatus::Processed.as_str())
+        .bind(processed_at)
+        .bind(source)
+        .bind(external_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("受信メッセージの更新に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.inbox_messages.record_failure", skip_all, fields(db.system = "mysql", db.operation = "UPDATE", db.sql.table = "inbox_messages", source = %source, external_id = %external_id), err)]
+    async fn record_failure(
+        &self,
+        source: &str,
+        external_id: &str,
+        error: &str,
+        give_up: bool,
+    ) -> Result<(), RepositoryError> {
+        let status = if give_up {
+            InboxMessageStatus::Failed
+        } else {
+            InboxMessageStatus::Pending
+        };
+        sqlx::query(
+            "UPDATE inbox_messages SET status = ?, attempts = attempts + 1, last_error = ? WHERE source = ? AND external_id = ?",
+        )
+        .bind(status.as_str())
+        .bind(error)
+        .bind(source)
+        .bind(external_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("受信メッセージの更新に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+}
@@ -1,16 +1,23 @@
 use crate::adapter::database_error::DatabaseError;
-use crate::domain::model::{BookId, Inventory};
+use crate::domain::invariant::describe_violations;
+use crate::domain::inventory_snapshot::{InventorySnapshot, InventorySnapshotEntry, StockMovement};
+use crate::domain::inventory_valuation::StockValuation;
+use crate::domain::model::{BookId, Inventory, Money};
 use crate::domain::port::{InventoryRepository, RepositoryError};
+use crate::domain::reconciliation::LedgerDrift;
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 
 // MySQL関連のインポート
-use sqlx::{MySql, Pool, Row};
+use sqlx::{Acquire, Executor, MySql, Pool, Row};
 
 /// MySQL在庫リポジトリ
 /// MySQLデータベースを使用して在庫を永続化する
 #[derive(Clone)]
 pub struct MySqlInventoryRepository {
     pool: Pool<MySql>,
+    /// 保存・読み込み時に在庫の不変条件を検証するかどうか
+    invariant_checks: bool,
 }
 
 impl MySqlInventoryRepository {
@@ -22,41 +29,161 @@ impl MySqlInventoryRepository {
     /// # Returns
     /// * MySqlInventoryRepositoryのインスタンス
     pub fn new(pool: Pool<MySql>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            invariant_checks: false,
+        }
+    }
+
+    /// 不変条件の検証の有効・無効を設定したリポジトリを作成
+    /// 有効な場合は違反する在庫の保存を拒否し、違反する在庫の読み込みを警告ログに残す
+    ///
+    /// # Arguments
+    /// * `enabled` - 不変条件を検証するかどうか
+    pub fn with_invariant_checks(mut self, enabled: bool) -> Self {
+        self.invariant_checks = enabled;
+        self
+    }
+
+    /// 保存する在庫の不変条件を検証
+    fn ensure_invariants(&self, inventory: &Inventory) -> Result<(), RepositoryError> {
+        if !self.invariant_checks {
+            return Ok(());
+        }
+        let violations = inventory.check_invariants();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(RepositoryError::OperationFailed(format!(
+                "不変条件に違反する在庫は保存できません: {}",
+                describe_violations(&violations)
+            )))
+        }
+    }
+
+    /// 在庫変動ログに記録する（在庫の更新と同じトランザクションで実行）
+    async fn record_movement<'e, E>(
+        executor: E,
+        book_id: BookId,
+        quantity_delta: i64,
+        quantity_on_hand: u32,
+    ) -> Result<(), RepositoryError>
+    where
+        E: Executor<'e, Database = MySql>,
+    {
+        sqlx::query(
+            "INSERT INTO inventory_movements (book_id, quantity_delta, quantity_on_hand) VALUES (?, ?, ?)",
+        )
+        .bind(book_id.to_string())
+        .bind(quantity_delta)
+        .bind(quantity_on_hand)
+        .execute(executor)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫変動の記録に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    /// 読み込んだ在庫の不変条件を検証（違反があっても読み込みは続ける）
+    fn warn_invariant_violations(&self, inventory: Inventory) -> Inventory {
+        if self.invariant_checks {
+            let violations = inventory.check_invariants();
+            if !violations.is_empty() {
+                tracing::warn!(
+                    book_id = %inventory.book_id(),
+                    violations = %describe_violations(&violations),
+                    "loaded inventory violates invariants"
+                );
+            }
+        }
+        inventory
     }
 }
 
 #[async_trait]
 impl InventoryRepository for MySqlInventoryRepository {
+    #[tracing::instrument(name = "db.inventories.save", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "inventories", book_id = %inventory.book_id()), err)]
     async fn save(&self, inventory: &Inventory) -> Result<(), RepositoryError> {
+        self.ensure_invariants(inventory)?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 在庫変動を算出するため、更新前の在庫数を行ロックを取って読む
+        let previous: Option<i64> = sqlx::query_scalar(
+            "SELECT CAST(quantity_on_hand AS SIGNED) FROM inventories WHERE book_id = ? FOR UPDATE",
+        )
+        .bind(inventory.book_id().to_string())
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
         // 在庫データをinventoriesテーブルにUPSERT
         sqlx::query(
             r#"
-            INSERT INTO inventories (book_id, quantity_on_hand)
-            VALUES (?, ?)
+            INSERT INTO inventories (book_id, quantity_on_hand, release_date, frozen, waitlist_enabled)
+            VALUES (?, ?, ?, ?, ?)
             ON DUPLICATE KEY UPDATE
-                quantity_on_hand = VALUES(quantity_on_hand)
+                quantity_on_hand = VALUES(quantity_on_hand),
+                release_date = VALUES(release_date),
+                frozen = VALUES(frozen),
+                waitlist_enabled = VALUES(waitlist_enabled)
             "#,
         )
         .bind(inventory.book_id().to_string())
         .bind(inventory.quantity_on_hand())
-        .execute(&self.pool)
+        .bind(inventory.release_date())
+        .bind(inventory.is_frozen())
+        .bind(inventory.is_waitlist_enabled())
+        .execute(&mut *tx)
         .await
         .map_err(|e| DatabaseError::QueryError(format!("在庫の保存に失敗しました: {}", e)))
         .map_err(RepositoryError::from)?;
 
+        // 在庫数が変わった場合（新規登録を含む）は在庫変動ログに記録する
+        let quantity_delta = i64::from(inventory.quantity_on_hand()) - previous.unwrap_or(0);
+        if previous.is_none() || quantity_delta != 0 {
+            Self::record_movement(
+                &mut *tx,
+                inventory.book_id(),
+                quantity_delta,
+                inventory.quantity_on_hand(),
+            )
+            .await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
         Ok(())
     }
 
+    #[tracing::instrument(name = "db.inventories.find_by_book_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "inventories", book_id = %book_id), err)]
     async fn find_by_book_id(&self, book_id: BookId) -> Result<Option<Inventory>, RepositoryError> {
         // inventoriesテーブルから在庫を取得
-        let row =
-            sqlx::query("SELECT book_id, quantity_on_hand FROM inventories WHERE book_id = ?")
-                .bind(book_id.to_string())
-                .fetch_optional(&self.pool)
-                .await
-                .map_err(|e| DatabaseError::QueryError(format!("在庫の取得に失敗しました: {}", e)))
-                .map_err(RepositoryError::from)?;
+        let row = sqlx::query(
+            "SELECT book_id, quantity_on_hand, release_date, frozen, waitlist_enabled FROM inventories WHERE book_id = ?",
+        )
+        .bind(book_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
 
         match row {
             Some(row) => {
@@ -64,24 +191,27 @@ impl InventoryRepository for MySqlInventoryRepository {
                     RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
                 })?;
 
-                let inventory = Inventory::new(book_id, row.get::<u32, _>("quantity_on_hand"));
-                Ok(Some(inventory))
+                let inventory = Inventory::new(book_id, row.get::<u32, _>("quantity_on_hand"))
+                    .with_release_date(row.get::<Option<NaiveDate>, _>("release_date"))
+                    .with_frozen(row.get::<bool, _>("frozen"))
+                    .with_waitlist_enabled(row.get::<bool, _>("waitlist_enabled"));
+                Ok(Some(self.warn_invariant_violations(inventory)))
             }
             None => Ok(None),
         }
     }
 
+    #[tracing::instrument(name = "db.inventories.find_all", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "inventories"), err)]
     async fn find_all(&self) -> Result<Vec<Inventory>, RepositoryError> {
         // inventoriesテーブルからすべての在庫を取得
         // 書籍IDの昇順で並べる
-        let rows =
-            sqlx::query("SELECT book_id, quantity_on_hand FROM inventories ORDER BY book_id ASC")
-                .fetch_all(&self.pool)
-                .await
-                .map_err(|e| {
-                    DatabaseError::QueryError(format!("在庫一覧の取得に失敗しました: {}", e))
-                })
-                .map_err(RepositoryError::from)?;
+        let rows = sqlx::query(
+            "SELECT book_id, quantity_on_hand, release_date, frozen, waitlist_enabled FROM inventories ORDER BY book_id ASC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫一覧の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
 
         let mut inventories = Vec::new();
         for row in rows {
@@ -89,13 +219,17 @@ impl InventoryRepository for MySqlInventoryRepository {
                 RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
             })?;
 
-            let inventory = Inventory::new(book_id, row.get::<u32, _>("quantity_on_hand"));
-            inventories.push(inventory);
+            let inventory = Inventory::new(book_id, row.get::<u32, _>("quantity_on_hand"))
+                .with_release_date(row.get::<Option<NaiveDate>, _>("release_date"))
+                .with_frozen(row.get::<bool, _>("frozen"))
+                .with_waitlist_enabled(row.get::<bool, _>("waitlist_enabled"));
+            inventories.push(self.warn_invariant_violations(inventory));
         }
 
         Ok(inventories)
     }
 
+    #[tracing::instrument(name = "db.inventories.find_by_max_quantity", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "inventories", max_quantity = max_quantity), err)]
     async fn find_by_max_quantity(
         &self,
         max_quantity: u32,
@@ -103,7 +237,7 @@ impl InventoryRepository for MySqlInventoryRepository {
         // 指定された最大在庫数以下の在庫を取得
         // 書籍IDの昇順で並べる
         let rows = sqlx::query(
-            "SELECT book_id, quantity_on_hand FROM inventories WHERE quantity_on_hand <= ? ORDER BY book_id ASC"
+            "SELECT book_id, quantity_on_hand, release_date, frozen, waitlist_enabled FROM inventories WHERE quantity_on_hand <= ? ORDER BY book_id ASC"
         )
         .bind(max_quantity)
         .fetch_all(&self.pool)
@@ -117,10 +251,268 @@ impl InventoryRepository for MySqlInventoryRepository {
                 RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
             })?;
 
-            let inventory = Inventory::new(book_id, row.get::<u32, _>("quantity_on_hand"));
-            inventories.push(inventory);
+            let inventory = Inventory::new(book_id, row.get::<u32, _>("quantity_on_hand"))
+                .with_release_date(row.get::<Option<NaiveDate>, _>("release_date"))
+                .with_frozen(row.get::<bool, _>("frozen"))
+                .with_waitlist_enabled(row.get::<bool, _>("waitlist_enabled"));
+            inventories.push(self.warn_invariant_violations(inventory));
         }
 
         Ok(inventories)
     }
+
+    #[tracing::instrument(name = "db.inventories.find_ledger_drifts", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "inventories"), err)]
+    async fn find_ledger_drifts(&self) -> Result<Vec<LedgerDrift>, RepositoryError> {
+        // 変動が1件もない書籍（在庫変動ログの導入前の在庫）は内部結合で対象外にする
+        let rows = sqlx::query(
+            r#"
+            SELECT i.book_id, i.quantity_on_hand, CAST(SUM(m.quantity_delta) AS SIGNED) AS ledger_quantity
+            FROM inventories i
+            INNER JOIN inventory_movements m ON m.book_id = i.book_id
+            GROUP BY i.book_id, i.quantity_on_hand
+            HAVING SUM(m.quantity_delta) <> i.quantity_on_hand
+            ORDER BY i.book_id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("在庫変動ログとの照合に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        let mut drifts = Vec::with_capacity(rows.len());
+        for row in rows {
+            let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+
+            drifts.push(LedgerDrift {
+                book_id,
+                quantity_on_hand: row.get::<u32, _>("quantity_on_hand"),
+                ledger_quantity: row.get::<i64, _>("ledger_quantity"),
+            });
+        }
+
+        Ok(drifts)
+    }
+
+    #[tracing::instrument(name = "db.inventories.freeze_ledger_drift", skip_all, fields(db.system = "mysql", db.operation = "UPDATE", db.sql.table = "inventories", book_id = %book_id), err)]
+    async fn freeze_ledger_drift(&self, book_id: BookId) -> Result<bool, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 在庫の行をロックしてから残高を読む（在庫の保存も同じ行をロックしてから変動を記録するため、
+        // 確認から凍結までの間に変動が記録されることはない）
+        let quantity_on_hand: Option<u32> = sqlx::query_scalar(
+            "SELECT quantity_on_hand FROM inventories WHERE book_id = ? FOR UPDATE",
+        )
+        .bind(book_id.to_string())
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+        let Some(quantity_on_hand) = quantity_on_hand else {
+            return Ok(false);
+        };
+
+        let ledger_quantity: Option<i64> = sqlx::query_scalar(
+            "SELECT CAST(SUM(quantity_delta) AS SIGNED) FROM inventory_movements WHERE book_id = ?",
+        )
+        .bind(book_id.to_string())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("在庫変動ログの集計に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+        let drift = match ledger_quantity {
+            Some(ledger_quantity) => i64::from(quantity_on_hand) - ledger_quantity,
+            None => 0,
+        };
+        if drift == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query("UPDATE inventories SET frozen = TRUE WHERE book_id = ?")
+            .bind(book_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("在庫の凍結に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(true)
+    }
+
+    #[tracing::instrument(name = "db.inventories.find_stock_valuations", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "inventories"), err)]
+    async fn find_stock_valuations(&self) -> Result<Vec<StockValuation>, RepositoryError> {
+        // 価格が登録されていない書籍も在庫数を集計できるよう、書籍カタログの価格を外部結合する
+        let rows = sqlx::query(
+            r#"
+            SELECT i.book_id, i.quantity_on_hand, p.unit_price_amount, p.unit_price_currency
+            FROM inventories i
+            LEFT JOIN book_prices p ON p.book_id = i.book_id
+            ORDER BY i.book_id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫評価の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut valuations = Vec::with_capacity(rows.len());
+        for row in rows {
+            let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            let unit_price = match (
+                row.get::<Option<i64>, _>("unit_price_amount"),
+                row.get::<Option<String>, _>("unit_price_currency"),
+            ) {
+                (Some(amount), Some(currency)) => Some(Money::new(amount, currency).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("金額の構築に失敗しました: {}", e))
+                })?),
+                _ => None,
+            };
+
+            valuations.push(StockValuation {
+                book_id,
+                quantity_on_hand: row.get::<u32, _>("quantity_on_hand"),
+                unit_price,
+            });
+        }
+
+        Ok(valuations)
+    }
+
+    #[tracing::instrument(name = "db.inventories.take_snapshot", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "inventories"), err)]
+    async fn take_snapshot(
+        &self,
+        taken_at: DateTime<Utc>,
+    ) -> Result<InventorySnapshot, RepositoryError> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| DatabaseError::ConnectionError(format!("接続の取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        // 次に開始するトランザクションだけに適用される（セッションの分離レベルは変えない）
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("分離レベルの設定に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 同じトランザクション内の読み取りは最初の読み取り時点のスナップショットを見るため、
+        // versionと在庫数は同じ時点の値になる
+        let version: u64 = sqlx::query_scalar(
+            "SELECT CAST(COALESCE(MAX(version), 0) AS UNSIGNED) FROM inventory_movements",
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫変動のversionの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let rows = sqlx::query(
+            "SELECT book_id, quantity_on_hand, frozen FROM inventories ORDER BY book_id ASC",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫スナップショットの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in rows {
+            let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            entries.push(InventorySnapshotEntry {
+                book_id,
+                quantity_on_hand: row.get::<u32, _>("quantity_on_hand"),
+                frozen: row.get::<bool, _>("frozen"),
+            });
+        }
+
+        Ok(InventorySnapshot {
+            version,
+            taken_at,
+            entries,
+        })
+    }
+
+    #[tracing::instrument(name = "db.inventory_movements.find_since", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "inventory_movements", since_version = since_version), err)]
+    async fn find_movements_since(
+        &self,
+        since_version: u64,
+        limit: usize,
+    ) -> Result<Vec<StockMovement>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT version, book_id, quantity_delta, quantity_on_hand, recorded_at
+            FROM inventory_movements
+            WHERE version > ?
+            ORDER BY version ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(since_version)
+        .bind(limit as u64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫変動の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut movements = Vec::with_capacity(rows.len());
+        for row in rows {
+            let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            movements.push(StockMovement {
+                version: row.get::<u64, _>("version"),
+                book_id,
+                quantity_delta: row.get::<i64, _>("quantity_delta"),
+                quantity_on_hand: row.get::<u32, _>("quantity_on_hand"),
+                recorded_at: row.get::<DateTime<Utc>, _>("recorded_at"),
+            });
+        }
+
+        Ok(movements)
+    }
 }
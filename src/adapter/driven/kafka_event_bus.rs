@@ -0,0 +1,711 @@
+use crate::adapter::driven::dispatch_gate::DispatchGate;
+use crate::adapter::driven::event_bus::EventBusConfig;
+use crate::adapter::driven::schema_registry::HttpSchemaRegistry;
+use crate::domain::event::{DomainEvent, EVENT_TYPES};
+use crate::domain::event_bus::{DynEventHandler, HandlerError};
+use crate::domain::event_schema::{event_schemas, EventSchema};
+use crate::domain::port::{EventBus, EventBusError, SchemaRegistry};
+use crate::domain::retry_policy::{DeadLetterRouting, RetryPolicies};
+use crate::domain::serialization::EventSerializer;
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::error::KafkaError;
+use rdkafka::message::{Header, Message, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+
+/// イベントを発行するトピックのデフォルト名
+pub const DEFAULT_KAFKA_TOPIC: &str = "bookstore.events";
+
+/// ブローカーが受け取るまで発行を待つ時間
+const PUBLISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Kafkaの接続設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KafkaConfig {
+    /// ブートストラップサーバー（例: localhost:9092。カンマ区切りで複数指定できる）
+    pub brokers: String,
+    /// イベントを発行するトピック
+    pub topic: String,
+    /// リトライを使い切ったイベントの転送先のトピック（Noneの場合はログを出力して破棄する）
+    pub dead_letter_topic: Option<String>,
+    /// スキーマレジストリのURL（Noneの場合はペイロードのスキーマを検証しない）
+    pub schema_registry_url: Option<String>,
+}
+
+impl KafkaConfig {
+    /// 環境変数から設定を読み取る（KAFKA_BROKERSが未設定の場合はNone）
+    /// - KAFKA_BROKERS: ブートストラップサーバー
+    /// - KAFKA_TOPIC: トピック名（デフォルト: bookstore.events）
+    /// - KAFKA_DEAD_LETTER_TOPIC: リトライを使い切ったイベントの転送先（デフォルト: なし）
+    /// - KAFKA_SCHEMA_REGISTRY_URL: Confluent Schema Registry互換のレジストリのURL（デフォルト: なし）
+    pub fn from_env() -> Option<Self> {
+        let brokers = std::env::var("KAFKA_BROKERS").ok()?;
+        Some(Self {
+            brokers,
+            topic: std::env::var("KAFKA_TOPIC").unwrap_or_else(|_| DEFAULT_KAFKA_TOPIC.to_string()),
+            dead_letter_topic: std::env::var("KAFKA_DEAD_LETTER_TOPIC").ok(),
+            schema_registry_url: std::env::var("KAFKA_SCHEMA_REGISTRY_URL").ok(),
+        })
+    }
+
+    /// 発行の重複と欠落を防ぐ設定のプロデューサーを作成する
+    fn producer(&self) -> Result<FutureProducer, KafkaError> {
+        ClientConfig::new()
+            .set("bootstrap.servers", &self.brokers)
+            .set("enable.idempotence", "true")
+            .set("acks", "all")
+            .create()
+    }
+}
+
+/// イベントのメッセージキー
+/// 同じ注文のイベントが同じパーティションに入り、発行順に処理されるよう注文IDを使う
+/// 注文IDを持たないイベント（在庫調整・サーガの補償）は相関ID（サーガID）を使う
+pub fn partition_key(event: &DomainEvent) -> String {
+    match event {
+        DomainEvent::CustomerBecameRepeatBuyer(event) => event.order_id.to_string(),
+        DomainEvent::InventoryAdjusted(_)
+        | DomainEvent::SagaCompensationStarted(_)
+        | DomainEvent::SagaCompensationCompleted(_) => event.metadata().correlation_id.to_string(),
+        _ => event.aggregate_id(),
+    }
+}
+
+/// Kafkaイベントバス
+/// イベントをEventSerializerの形式のJSONでトピックに発行する
+/// ハンドラーは別のプロセスでKafkaEventConsumerに登録して実行する
+///
+/// スキーマレジストリを設定した場合は、起動時にregister_schemasでイベントタイプごとのスキーマを登録し、
+/// 発行するペイロードを登録したスキーマで検証する（登録していない・適合しないイベントは発行しない）
+pub struct KafkaEventBus {
+    producer: FutureProducer,
+    topic: String,
+    serializer: EventSerializer,
+    schema_registry: Option<Arc<dyn SchemaRegistry>>,
+    /// 登録したスキーマ（イベントタイプ・バージョンごとのスキーマIDとスキーマ）
+    registered_schemas: RwLock<HashMap<(String, u32), (u32, EventSchema)>>,
+}
+
+impl KafkaEventBus {
+    /// Kafkaのイベントバスを作成
+    ///
+    /// # Arguments
+    /// * `config` - Kafkaの接続設定
+    ///
+    /// # Returns
+    /// * `Ok(KafkaEventBus)` - イベントバス
+    /// * `Err(EventBusError)` - プロデューサーの作成に失敗した場合
+    pub fn new(config: &KafkaConfig) -> Result<Self, EventBusError> {
+        Ok(Self {
+            producer: config.producer().map_err(connection_error)?,
+            topic: config.topic.clone(),
+            serializer: EventSerializer::new(),
+            schema_registry: config.schema_registry_url.as_ref().map(|url| {
+                Arc::new(HttpSchemaRegistry::new(url.clone())) as Arc<dyn SchemaRegistry>
+            }),
+            registered_schemas: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// スキーマレジストリを設定（KAFKA_SCHEMA_REGISTRY_URLの設定より優先する）
+    ///
+    /// # Arguments
+    /// * `schema_registry` - スキーマレジストリ（例: テスト用のInMemorySchemaRegistry）
+    pub fn with_schema_registry(mut self, schema_registry: Arc<dyn SchemaRegistry>) -> Self {
+        self.schema_registry = Some(schema_registry);
+        self
+    }
+
+    /// 現在のバージョンのすべてのイベントタイプのスキーマをレジストリに登録する
+    /// 発行を始める前の起動時に呼び出す
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 登録したスキーマの数（スキーマレジストリを設定していない場合は0）
+    /// * `Err(EventBusError)` - 登録済みのスキーマと互換性がない、またはレジストリに接続できない
+    pub async fn register_schemas(&self) -> Result<usize, EventBusError> {
+        let Some(schema_registry) = &self.schema_registry else {
+            return Ok(0);
+        };
+        let mut registered = HashMap::new();
+        for schema in event_schemas() {
+            let schema_id = schema_registry
+                .register(&schema.subject(&self.topic), &schema)
+                .await
+                .map_err(|e| EventBusError::PublishingFailed(e.to_string()))?;
+            registered.insert((schema.event_type.clone(), schema.version), (schema_id, schema));
+        }
+        let count = registered.len();
+        *self.registered_schemas.write().await = registered;
+        Ok(count)
+    }
+
+    /// 発行するペイロードを登録したスキーマで検証する
+    ///
+    /// # Returns
+    /// * `Ok(Some(u32))` - 適合したスキーマのID
+    /// * `Ok(None)` - スキーマレジストリを設定していない
+    /// * `Err(EventBusError)` - スキーマを登録していない、または適合しない
+    async fn validate_payload(
+        &self,
+        event_type: &str,
+        event_version: u32,
+        payload: &str,
+    ) -> Result<Option<u32>, EventBusError> {
+        if self.schema_registry.is_none() {
+            return Ok(None);
+        }
+        let registered_schemas = self.registered_schemas.read().await;
+        let Some((schema_id, schema)) =
+            registered_schemas.get(&(event_type.to_string(), event_version))
+        else {
+            return Err(EventBusError::PublishingFailed(format!(
+                "No schema registered for {} v{}",
+                event_type, event_version
+            )));
+        };
+        let message: serde_json::Value = serde_json::from_str(payload).map_err(|e| {
+            EventBusError::PublishingFailed(format!("Event serialization failed: {}", e))
+        })?;
+        schema.validate(&message).map_err(|violations| {
+            EventBusError::PublishingFailed(format!(
+                "Payload does not match schema {} for {} v{}: {}",
+                schema_id,
+                event_type,
+                event_version,
+                violations.join("; ")
+            ))
+        })?;
+        Ok(Some(*schema_id))
+    }
+}
+
+#[async_trait]
+impl EventBus for KafkaEventBus {
+    #[tracing::instrument(
+        name = "event.publish",
+        skip_all,
+        fields(
+            messaging.system = "kafka",
+            event.type = event.event_type(),
+            event.id = %event.metadata().event_id,
+            correlation_id = %event.metadata().correlation_id,
+        )
+    )]
+    async fn publish(&self, event: DomainEvent) -> Result<(), EventBusError> {
+        let payload = self.serializer.serialize_event(&event).map_err(|e| {
+            EventBusError::PublishingFailed(format!("Event serialization failed: {}", e))
+        })?;
+        let schema_id = self
+            .validate_payload(event.event_type(), event.metadata().event_version, &payload)
+            .await?;
+        let key = partition_key(&event);
+        let event_id = event.metadata().event_id.to_string();
+        let correlation_id = event.metadata().correlation_id.to_string();
+        let mut headers = OwnedHeaders::new()
+            .insert(Header {
+                key: "event_type",
+                value: Some(event.event_type()),
+            })
+            .insert(Header {
+                key: "event_id",
+                value: Some(&event_id),
+            })
+            .insert(Header {
+                key: "correlation_id",
+                value: Some(&correlation_id),
+            });
+        if let Some(schema_id) = schema_id {
+            headers = headers.insert(Header {
+                key: "schema_id",
+                value: Some(&schema_id.to_string()),
+            });
+        }
+
+        self.producer
+            .send(
+                FutureRecord::to(&self.topic)
+                    .key(&key)
+                    .payload(&payload)
+                    .headers(headers),
+                PUBLISH_TIMEOUT,
+            )
+            .await
+            .map_err(|(error, _)| {
+                EventBusError::PublishingFailed(format!("Kafka publish failed: {}", error))
+            })?;
+        Ok(())
+    }
+}
+
+/// リトライを使い切ったイベント処理
+#[derive(Debug, Clone)]
+pub struct KafkaDeadLetter {
+    pub handler_name: String,
+    pub error: HandlerError,
+    /// 試行回数（実行前に処理できないと判断した場合は0）
+    pub attempts: u32,
+}
+
+/// Kafkaのイベントの受信
+/// コンシューマーグループでトピックを購読し、受信したイベントを既存のハンドラーラッパー
+/// （OrderConfirmedHandlerWrapperなど）経由でEventHandlerに渡す
+///
+/// リトライはInMemoryEventBusと同じくイベントタイプのリトライポリシーに従い、
+/// 使い切った場合はデッドレターの扱いに従ってデッドレタートピックに転送するか破棄する
+/// オフセットはメッセージの処理を終えてからコミットする（at-least-once）
+pub struct KafkaEventConsumer {
+    config: KafkaConfig,
+    group_id: String,
+    handlers: Vec<(&'static str, Arc<dyn DynEventHandler>)>,
+    retry_policies: RetryPolicies,
+    handler_timeout: Duration,
+    serializer: EventSerializer,
+    /// デプロイ時の受信の一時停止
+    dispatch_gate: Arc<DispatchGate>,
+}
+
+impl KafkaEventConsumer {
+    /// 新しいイベントの受信を作成
+    ///
+    /// # Arguments
+    /// * `config` - Kafkaの接続設定
+    /// * `group_id` - コンシューマーグループ（同じグループのプロセスでパーティションを分け合う）
+    /// * `event_bus_config` - リトライポリシーとハンドラーのタイムアウト
+    pub fn new(
+        config: KafkaConfig,
+        group_id: impl Into<String>,
+        event_bus_config: &EventBusConfig,
+    ) -> Self {
+        Self {
+            config,
+            group_id: group_id.into(),
+            handlers: Vec::new(),
+            retry_policies: event_bus_config.retry_policies.clone(),
+            handler_timeout: event_bus_config.handler_timeout,
+            serializer: EventSerializer::new(),
+            dispatch_gate: Arc::new(DispatchGate::new()),
+        }
+    }
+
+    /// 受信の一時停止に使う配信ゲートを設定する
+    /// 管理APIからゲートを一時停止すると、処理中のメッセージを終えた後は受信せず、メッセージをトピックに残す
+    ///
+    /// # Arguments
+    /// * `dispatch_gate` - 配信ゲート（EventDispatchControlとして管理APIに渡す）
+    pub fn with_dispatch_gate(mut self, dispatch_gate: Arc<DispatchGate>) -> Self {
+        self.dispatch_gate = dispatch_gate;
+        self
+    }
+
+    /// イベントタイプのハンドラーを登録する
+    ///
+    /// # Arguments
+    /// * `event_type` - 購読するイベントタイプ（例: "OrderConfirmed"）
+    /// * `handler` - ハンドラーラッパー（例: `OrderConfirmedHandlerWrapper::new(handler)`）
+    ///
+    /// # Returns
+    /// * `Err(EventBusError::SubscriptionNotFound)` - 存在しないイベントタイプを指定した場合
+    pub fn subscribe<W>(
+        mut self,
+        event_type: &'static str,
+        handler: W,
+    ) -> Result<Self, EventBusError>
+    where
+        W: DynEventHandler + 'static,
+    {
+        if !EVENT_TYPES.contains(&event_type) {
+            return Err(EventBusError::SubscriptionNotFound(format!(
+                "Unknown event type: {}",
+                event_type
+            )));
+        }
+        self.handlers.push((event_type, Arc::new(handler)));
+        Ok(self)
+    }
+
+    /// トピックを購読し、受信を開始する
+    ///
+    /// # Returns
+    /// * `Ok(JoinHandle)` - 受信を続けるタスク
+    /// * `Err(EventBusError)` - コンシューマーの作成・購読に失敗した場合
+    pub fn start(self) -> Result<JoinHandle<()>, EventBusError> {
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", &self.config.brokers)
+            .set("group.id", &self.group_id)
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "earliest")
+            .create()
+            .map_err(connection_error)?;
+        consumer
+            .subscribe(&[self.config.topic.as_str()])
+            .map_err(connection_error)?;
+        let dead_letter_producer = match &self.config.dead_letter_topic {
+            Some(_) => Some(self.config.producer().map_err(connection_error)?),
+            None => None,
+        };
+
+        Ok(tokio::spawn(async move {
+            loop {
+                // 一時停止中は受信せず、メッセージをブローカーに残す
+                self.dispatch_gate.wait_until_resumed().await;
+                let message = match consumer.recv().await {
+                    Ok(message) => message,
+                    Err(error) => {
+                        tracing::error!(error = %error, "kafka consumer failed");
+                        continue;
+                    }
+                };
+                // 受信した後に一時停止した場合は、オフセットをコミットせずに再開を待つ（停止しても再び受信する）
+                let _in_flight = self.dispatch_gate.enter().await;
+                let payload = message.payload().unwrap_or_default();
+                for dead_letter in self.handle(payload).await {
+                    self.forward_dead_letter(
+                        dead_letter_producer.as_ref(),
+                        message.key(),
+                        payload,
+                        &dead_letter,
+                    )
+                    .await;
+                }
+                if let Err(error) = consumer.commit_message(&message, CommitMode::Async) {
+                    tracing::error!(error = %error, "failed to commit kafka offset");
+                }
+            }
+        }))
+    }
+
+    /// 受信したメッセージを登録されたハンドラーに渡す
+    /// ハンドラーごとにリトライポリシーに従ってリトライし、デッドレタートピックに転送するものを返す
+    ///
+    /// # Arguments
+    /// * `payload` - メッセージの本文（EventSerializerの形式のJSON）
+    ///
+    /// # Returns
+    /// * デッドレタートピックに転送するイベント処理（すべて成功した、または破棄した場合は空）
+    pub async fn handle(&self, payload: &[u8]) -> Vec<KafkaDeadLetter> {
+        let event = match std::str::from_utf8(payload)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                self.serializer
+                    .deserialize_event(json)
+                    .map_err(|e| e.to_string())
+            }) {
+            Ok(event) => event,
+            Err(error) => {
+                tracing::error!(error = %error, "undecodable kafka message");
+                return vec![KafkaDeadLetter {
+                    handler_name: "KafkaEventConsumer".to_string(),
+                    error: HandlerError::PermanentError(format!("Undecodable message: {}", error)),
+                    attempts: 0,
+                }];
+            }
+        };
+
+        let policy = self.retry_policies.policy_for(event.event_type());
+        let mut dead_letters = Vec::new();
+        for (event_type, handler) in &self.handlers {
+            if *event_type != event.event_type() || !handler.can_handle(&event) {
+                continue;
+            }
+            if !handler.supports_schema_version(event.metadata().event_version) {
+                dead_letters.push(KafkaDeadLetter {
+                    handler_name: handler.handler_name().to_string(),
+                    error: HandlerError::PermanentError(format!(
+                        "Handler {} does not support schema version {}",
+                        handler.handler_name(),
+                        event.metadata().event_version
+                    )),
+                    attempts: 0,
+                });
+                continue;
+            }
+
+            let mut attempts = 0;
+            let error = loop {
+                attempts += 1;
+                let error =
+                    match tokio::time::timeout(self.handler_timeout, handler.handle_event(&event))
+                        .await
+                    {
+                        Ok(Ok(())) => break None,
+                        Ok(Err(error)) => error,
+                        Err(_) => HandlerError::TransientError("Handler timeout".to_string()),
+                    };
+                // 永続的エラーの場合はリトライしない
+                if error.is_permanent() || attempts >= policy.max_attempts {
+                    break Some(error);
+                }
+                tokio::time::sleep(policy.backoff_after(attempts)).await;
+            };
+            let Some(error) = error else {
+                continue;
+            };
+
+            tracing::warn!(
+                handler.name = handler.handler_name(),
+                event.type = event.event_type(),
+                event.id = %event.metadata().event_id,
+                attempts,
+                error = %error,
+                "kafka event handler failed"
+            );
+            // リトライポリシーで破棄を指定したイベントはデッドレタートピックに送らない
+            if policy.dead_letter == DeadLetterRouting::Discard {
+                continue;
+            }
+            dead_letters.push(KafkaDeadLetter {
+                handler_name: handler.handler_name().to_string(),
+                error: error.for_event(&event),
+                attempts,
+            });
+        }
+        dead_letters
+    }
+
+    /// 元のメッセージと同じキー・本文で、失敗したハンドラーとエラーをヘッダーに付けてデッドレタートピックに転送する
+    async fn forward_dead_letter(
+        &self,
+        producer: Option<&FutureProducer>,
+        key: Option<&[u8]>,
+        payload: &[u8],
+        dead_letter: &KafkaDeadLetter,
+    ) {
+        let (Some(producer), Some(topic)) = (producer, &self.config.dead_letter_topic) else {
+            tracing::error!(
+                handler.name = %dead_letter.handler_name,
+                error = %dead_letter.error,
+                "discarding failed kafka message without dead letter topic"
+            );
+            return;
+        };
+        let error = dead_letter.error.to_string();
+        let attempts = dead_letter.attempts.to_string();
+        let headers = OwnedHeaders::new()
+            .insert(Header {
+                key: "dead_letter.handler",
+                value: Some(&dead_letter.handler_name),
+            })
+            .insert(Header {
+                key: "dead_letter.error",
+                value: Some(&error),
+            })
+            .insert(Header {
+                key: "dead_letter.attempts",
+                value: Some(&attempts),
+            });
+        let mut record = FutureRecord::<[u8], [u8]>::to(topic)
+            .payload(payload)
+            .headers(headers);
+        if let Some(key) = key {
+            record = record.key(key);
+        }
+        if let Err((error, _)) = producer.send(record, PUBLISH_TIMEOUT).await {
+            tracing::error!(error = %error, "failed to forward kafka dead letter");
+        }
+    }
+}
+
+fn connection_error(error: KafkaError) -> EventBusError {
+    EventBusError::PublishingFailed(format!("Kafka connection failed: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event::{OrderConfirmed, SagaCompensationStarted};
+    use crate::domain::event_bus::{EventHandler, OrderConfirmedHandlerWrapper};
+    use crate::domain::model::{CustomerId, Money, OrderId};
+    use crate::adapter::driven::InMemorySchemaRegistry;
+    use crate::domain::retry_policy::RetryPolicy;
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct FlakyHandler {
+        calls: Arc<AtomicUsize>,
+        error: Option<fn() -> HandlerError>,
+    }
+
+    #[async_trait]
+    impl EventHandler<OrderConfirmed> for FlakyHandler {
+        async fn handle(&self, _event: OrderConfirmed) -> Result<(), HandlerError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            match self.error {
+                Some(error) => Err(error()),
+                None => Ok(()),
+            }
+        }
+    }
+
+    fn consumer(
+        error: Option<fn() -> HandlerError>,
+        dead_letter: DeadLetterRouting,
+    ) -> (KafkaEventConsumer, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let event_bus_config = EventBusConfig {
+            retry_policies: RetryPolicies {
+                default_policy: RetryPolicy::new(3, 0, 1, 0, DeadLetterRouting::DeadLetter)
+                    .unwrap(),
+                overrides: BTreeMap::from([(
+                    "OrderConfirmed".to_string(),
+                    RetryPolicy::new(2, 0, 1, 0, dead_letter).unwrap(),
+                )]),
+            },
+            ..EventBusConfig::default()
+        };
+        let consumer = KafkaEventConsumer::new(
+            KafkaConfig {
+                brokers: "localhost:9092".to_string(),
+                topic: DEFAULT_KAFKA_TOPIC.to_string(),
+                dead_letter_topic: None,
+                schema_registry_url: None,
+            },
+            "order-notifications",
+            &event_bus_config,
+        )
+        .subscribe(
+            "OrderConfirmed",
+            OrderConfirmedHandlerWrapper::new(FlakyHandler {
+                calls: calls.clone(),
+                error,
+            }),
+        )
+        .unwrap();
+        (consumer, calls)
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_keyed_by_order_and_retried_per_policy() {
+        let order_id = OrderId::new();
+        let event = DomainEvent::OrderConfirmed(OrderConfirmed::new(
+            order_id,
+            CustomerId::new(),
+            Vec::new(),
+            Money::jpy(0),
+        ));
+        assert_eq!(partition_key(&event), order_id.to_string());
+        let saga_id = uuid::Uuid::new_v4();
+        let saga_event = DomainEvent::SagaCompensationStarted(SagaCompensationStarted::new(
+            saga_id,
+            "ReserveInventory".to_string(),
+            "out of stock".to_string(),
+            Vec::new(),
+        ));
+        assert_eq!(partition_key(&saga_event), saga_id.to_string());
+        let payload = EventSerializer::new().serialize_event(&event).unwrap();
+
+        let (ok, calls) = consumer(None, DeadLetterRouting::DeadLetter);
+        assert!(ok.handle(payload.as_bytes()).await.is_empty());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        let undecodable = ok.handle(b"not-json").await;
+        assert_eq!(undecodable.len(), 1);
+        assert!(undecodable[0].error.is_permanent());
+
+        // 一時的なエラーはイベントタイプのポリシーの回数だけ試行してからデッドレターにする
+        let (transient, calls) = consumer(
+            Some(|| HandlerError::TransientError("timeout".to_string())),
+            DeadLetterRouting::DeadLetter,
+        );
+        let dead_letters = transient.handle(payload.as_bytes()).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].attempts, 2);
+
+        let (permanent, calls) = consumer(
+            Some(|| HandlerError::PermanentError("invalid".to_string())),
+            DeadLetterRouting::DeadLetter,
+        );
+        assert_eq!(permanent.handle(payload.as_bytes()).await[0].attempts, 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let (discard, _) = consumer(
+            Some(|| HandlerError::TransientError("timeout".to_string())),
+            DeadLetterRouting::Discard,
+        );
+        assert!(discard.handle(payload.as_bytes()).await.is_empty());
+
+        assert!(matches!(
+            KafkaEventConsumer::new(ok.config.clone(), "g", &EventBusConfig::default()).subscribe(
+                "NoSuchEvent",
+                OrderConfirmedHandlerWrapper::new(FlakyHandler { calls, error: None })
+            ),
+            Err(EventBusError::SubscriptionNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_payloads_are_validated_against_registered_schemas() {
+        let config = KafkaConfig {
+            brokers: "localhost:9092".to_string(),
+            topic: DEFAULT_KAFKA_TOPIC.to_string(),
+            dead_letter_topic: None,
+            schema_registry_url: None,
+        };
+        let registry = Arc::new(InMemorySchemaRegistry::new());
+        let event_bus = KafkaEventBus::new(&config)
+            .unwrap()
+            .with_schema_registry(registry.clone());
+        let event = DomainEvent::OrderConfirmed(OrderConfirmed::new(
+            OrderId::new(),
+            CustomerId::new(),
+            Vec::new(),
+            Money::jpy(0),
+        ));
+        let payload = EventSerializer::new().serialize_event(&event).unwrap();
+
+        // 起動時に登録するまでは発行しない
+        assert!(event_bus
+            .validate_payload("OrderConfirmed", 1, &payload)
+            .await
+            .is_err());
+        assert_eq!(
+            event_bus.register_schemas().await.unwrap(),
+            EVENT_TYPES.len()
+        );
+        let schema_id = event_bus
+            .validate_payload("OrderConfirmed", 1, &payload)
+            .await
+            .unwrap();
+        assert!(schema_id.is_some());
+        // 再起動して同じスキーマを登録しても、同じIDを使う
+        event_bus.register_schemas().await.unwrap();
+        assert_eq!(
+            event_bus
+                .validate_payload("OrderConfirmed", 1, &payload)
+                .await
+                .unwrap(),
+            schema_id
+        );
+
+        let broken = payload.replace("\"total_amount\"", "\"total\"");
+        assert!(event_bus
+            .validate_payload("OrderConfirmed", 1, &broken)
+            .await
+            .is_err());
+        assert!(event_bus
+            .validate_payload("OrderConfirmed", 2, &payload)
+            .await
+            .is_err());
+
+        // 登録済みのスキーマと互換性のない変更は登録を拒否する
+        let mut previous = event_schemas().remove(0);
+        previous.schema["properties"]["event_data"]["required"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!("order_number"));
+        let other_registry = Arc::new(InMemorySchemaRegistry::new());
+        other_registry
+            .register(&previous.subject(DEFAULT_KAFKA_TOPIC), &previous)
+            .await
+            .unwrap();
+        let event_bus = KafkaEventBus::new(&config)
+            .unwrap()
+            .with_schema_registry(other_registry);
+        assert!(event_bus.register_schemas().await.is_err());
+    }
+}
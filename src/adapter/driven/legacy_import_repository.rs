@@ -0,0 +1,123 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::legacy_import::{LegacyImportOutcome, LegacyImportRecord};
+use crate::domain::model::OrderId;
+use crate::domain::port::{LegacyImportRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL旧システム注文取り込み記録リポジトリ
+/// MySQLデータベース（legacy_order_importsテーブル）に旧システムの注文の取り込み結果を保存する
+#[derive(Clone)]
+pub struct MySqlLegacyImportRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlLegacyImportRepository {
+    /// 新しいMySQL旧システム注文取り込み記録リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlLegacyImportRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から取り込みの記録を復元
+    fn row_to_record(row: &MySqlRow) -> Result<LegacyImportRecord, RepositoryError> {
+        let outcome = LegacyImportOutcome::from_string(row.get("outcome")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("取り込み結果の解析に失敗しました: {}", e))
+        })?;
+        let order_id = row
+            .get::<Option<String>, _>("order_id")
+            .map(|id| {
+                OrderId::from_string(&id).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+                })
+            })
+            .transpose()?;
+        let reasons: Vec<String> = serde_json::from_str(row.get("reasons")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("隔離理由の解析に失敗しました: {}", e))
+        })?;
+
+        Ok(LegacyImportRecord {
+            legacy_order_no: row.get("legacy_order_no"),
+            outcome,
+            order_id,
+            payload: row.get("payload"),
+            reasons,
+            recorded_at: row.get::<DateTime<Utc>, _>("recorded_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl LegacyImportRepository for MySqlLegacyImportRepository {
+    #[tracing::instrument(name = "db.legacy_order_imports.save", skip_all, fields(db.system = "mysql", db.operation = "UPSERT", db.sql.table = "legacy_order_imports", legacy_order_no = %record.legacy_order_no), err)]
+    async fn save(&self, record: &LegacyImportRecord) -> Result<(), RepositoryError> {
+        let reasons = serde_json::to_string(&record.reasons).map_err(|e| {
+            RepositoryError::OperationFailed(format!("隔離理由のシリアライズに失敗しました: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO legacy_order_imports (legacy_order_no, outcome, order_id, payload, reasons, recorded_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                outcome = VALUES(outcome),
+                order_id = VALUES(order_id),
+                payload = VALUES(payload),
+                reasons = VALUES(reasons),
+                recorded_at = VALUES(recorded_at)
+            "#,
+        )
+        .bind(&record.legacy_order_no)
+        .bind(record.outcome.as_str())
+        .bind(record.order_id.map(|id| id.to_string()))
+        .bind(&record.payload)
+        .bind(reasons)
+        .bind(record.recorded_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("取り込みの記録の保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.legacy_order_imports.find_by_legacy_order_no", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "legacy_order_imports", legacy_order_no = %legacy_order_no), err)]
+    async fn find_by_legacy_order_no(
+        &self,
+        legacy_order_no: &str,
+    ) -> Result<Option<LegacyImportRecord>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT legacy_order_no, outcome, order_id, payload, reasons, recorded_at FROM legacy_order_imports WHERE legacy_order_no = ?",
+        )
+        .bind(legacy_order_no)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("取り込みの記録の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        row.as_ref().map(Self::row_to_record).transpose()
+    }
+
+    #[tracing::instrument(name = "db.legacy_order_imports.find_quarantined", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "legacy_order_imports"), err)]
+    async fn find_quarantined(&self) -> Result<Vec<LegacyImportRecord>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT legacy_order_no, outcome, order_id, payload, reasons, recorded_at FROM legacy_order_imports WHERE outcome = ? ORDER BY recorded_at ASC",
+        )
+        .bind(LegacyImportOutcome::Quarantined.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("隔離中のレコードの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::row_to_record).collect()
+    }
+}
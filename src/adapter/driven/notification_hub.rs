@@ -0,0 +1,117 @@
+use crate::domain::customer_notification::CustomerNotification;
+use crate::domain::model::CustomerId;
+use crate::domain::port::{CustomerNotificationSource, NotificationChannelPort};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use tokio::sync::mpsc;
+
+/// 接続ごとにためておける未送信の通知の上限
+/// 受信が追いつかない接続には、あふれた通知を届けない（メール・SMSでも届くため）
+const CONNECTION_BUFFER_CAPACITY: usize = 32;
+
+/// 接続中の顧客への通知の配信先（プロセス内）
+/// 顧客ごとに接続中の受信側を持ち、配信された通知をすべての接続に届ける
+/// 接続はこのプロセスのものだけのため、複数のインスタンスで動かす場合は、
+/// 顧客の接続先のインスタンスで通知ハンドラーが動くとは限らない
+#[derive(Debug, Default)]
+pub struct InMemoryNotificationHub {
+    connections: Mutex<HashMap<CustomerId, Vec<mpsc::Sender<CustomerNotification>>>>,
+}
+
+impl InMemoryNotificationHub {
+    /// 接続のない配信先を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 顧客が接続している数
+    pub fn connection_count(&self, customer_id: CustomerId) -> usize {
+        self.connections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&customer_id)
+            .map_or(0, |senders| {
+                senders.iter().filter(|sender| !sender.is_closed()).count()
+            })
+    }
+}
+
+#[async_trait]
+impl NotificationChannelPort for InMemoryNotificationHub {
+    async fn push(&self, notification: &CustomerNotification) -> usize {
+        let mut connections = self
+            .connections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let Some(senders) = connections.get_mut(&notification.customer_id) else {
+            return 0;
+        };
+
+        // 切断した接続はここで取り除く
+        senders.retain(|sender| !sender.is_closed());
+        let delivered = senders
+            .iter()
+            .filter(|sender| sender.try_send(notification.clone()).is_ok())
+            .count();
+        if senders.is_empty() {
+            connections.remove(&notification.customer_id);
+        }
+        delivered
+    }
+}
+
+impl CustomerNotificationSource for InMemoryNotificationHub {
+    fn connect(&self, customer_id: CustomerId) -> mpsc::Receiver<CustomerNotification> {
+        let (sender, receiver) = mpsc::channel(CONNECTION_BUFFER_CAPACITY);
+        self.connections
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(customer_id)
+            .or_default()
+            .push(sender);
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::OrderId;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn notification(customer_id: CustomerId) -> CustomerNotification {
+        CustomerNotification {
+            customer_id,
+            order_id: OrderId::new(),
+            event_type: "OrderShipped".to_string(),
+            message: "ご注文が発送されました。".to_string(),
+            correlation_id: Uuid::new_v4(),
+            sent_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_push_fans_out_to_connections_of_the_customer_only() {
+        let hub = InMemoryNotificationHub::new();
+        let customer_id = CustomerId::new();
+        let mut phone = hub.connect(customer_id);
+        let mut browser = hub.connect(customer_id);
+        let mut other = hub.connect(CustomerId::new());
+
+        let sent = notification(customer_id);
+        assert_eq!(hub.push(&sent).await, 2);
+        assert_eq!(phone.recv().await.unwrap(), sent);
+        assert_eq!(browser.recv().await.unwrap(), sent);
+        assert!(other.try_recv().is_err());
+
+        // 切断した接続には配信しない
+        drop(browser);
+        assert_eq!(hub.push(&notification(customer_id)).await, 1);
+        assert_eq!(hub.connection_count(customer_id), 1);
+        drop(phone);
+        assert_eq!(hub.push(&notification(customer_id)).await, 0);
+        assert_eq!(hub.connection_count(customer_id), 0);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::domain::notification_retry::{Notification, NotificationChannel};
+use crate::domain::port::{Logger, NotificationError, NotificationSender};
+use async_trait::async_trait;
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// メール・SMSの送信のシミュレーター（スタブ実装）
+/// 送信する内容をログに出力し、設定した確率で送信失敗として返す
+/// 実際の配信ではメール・SMSの送信サービスのAPIの呼び出しに置き換える想定
+pub struct SimulatedNotificationSender {
+    logger: Arc<dyn Logger>,
+    failure_rate: f64,
+}
+
+impl SimulatedNotificationSender {
+    /// 新しい通知の送信のシミュレーターを作成
+    ///
+    /// # Arguments
+    /// * `logger` - 送信内容を出力するロガー
+    /// * `failure_rate` - 送信失敗として返す確率（0.0〜1.0）
+    pub fn new(logger: Arc<dyn Logger>, failure_rate: f64) -> Self {
+        Self {
+            logger,
+            failure_rate: failure_rate.clamp(0.0, 1.0),
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationSender for SimulatedNotificationSender {
+    async fn send(&self, notification: &Notification) -> Result<(), NotificationError> {
+        if rand::thread_rng().gen_bool(self.failure_rate) {
+            return Err(NotificationError::DeliveryFailed(format!(
+                "{}の送信サービスが応答しませんでした（シミュレーション）",
+                notification.channel
+            )));
+        }
+
+        let mut context = HashMap::new();
+        context.insert("channel".to_string(), notification.channel.to_string());
+        context.insert("order_id".to_string(), notification.order_id.to_string());
+        context.insert("event_type".to_string(), notification.event_type.clone());
+        if notification.channel == NotificationChannel::Sms {
+            context.insert(
+                "phone".to_string(),
+                notification.phone.clone().unwrap_or_default(),
+            );
+        }
+        context.insert("message".to_string(), notification.message.clone());
+        self.logger.info(
+            "SimulatedNotificationSender",
+            "Notification sent",
+            Some(notification.correlation_id),
+            Some(context),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::driven::ConsoleLogger;
+    use crate::domain::model::OrderId;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_send_fails_at_configured_rate() {
+        let notification = Notification {
+            channel: NotificationChannel::Sms,
+            order_id: OrderId::new(),
+            phone: Some("09012345678".to_string()),
+            event_type: "OrderShipped".to_string(),
+            message: "ギフトが発送されました".to_string(),
+            correlation_id: Uuid::new_v4(),
+        };
+        let logger = Arc::new(ConsoleLogger::new());
+
+        let sender = SimulatedNotificationSender::new(logger.clone(), 0.0);
+        assert!(sender.send(&notification).await.is_ok());
+
+        let failing = SimulatedNotificationSender::new(logger, 1.0);
+        assert!(matches!(
+            failing.send(&notification).await,
+            Err(NotificationError::DeliveryFailed(_))
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::domain::port::{ObjectStorageError, ObjectStoragePort};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// ローカルファイルシステムのオブジェクトストレージの実装
+/// ルートディレクトリの下に、キーをパスとしてオブジェクトを保存する
+pub struct LocalFileObjectStorage {
+    root: PathBuf,
+}
+
+impl LocalFileObjectStorage {
+    /// 新しいローカルファイルシステムのオブジェクトストレージを作成
+    ///
+    /// # Arguments
+    /// * `root` - オブジェクトを保存するディレクトリ
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// キーに対応するパス（ルートディレクトリの外を指すキーは拒否する）
+    fn path_for(&self, key: &str) -> Option<PathBuf> {
+        let relative = Path::new(key);
+        let is_safe = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        (is_safe && !key.is_empty()).then(|| self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl ObjectStoragePort for LocalFileObjectStorage {
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> Result<(), ObjectStorageError> {
+        let path = self
+            .path_for(key)
+            .ok_or_else(|| ObjectStorageError::WriteFailed(format!("不正なキーです: {}", key)))?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| ObjectStorageError::WriteFailed(format!("{}: {}", key, e)))?;
+        }
+
+        // 書き込み途中のファイルを読まれないよう、一時ファイルに書いてから置き換える
+        let temporary = path.with_extension("partial");
+        tokio::fs::write(&temporary, body)
+            .await
+            .map_err(|e| ObjectStorageError::WriteFailed(format!("{}: {}", key, e)))?;
+        tokio::fs::rename(&temporary, &path)
+            .await
+            .map_err(|e| ObjectStorageError::WriteFailed(format!("{}: {}", key, e)))
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, ObjectStorageError> {
+        let path = self
+            .path_for(key)
+            .ok_or_else(|| ObjectStorageError::ReadFailed(format!("不正なキーです: {}", key)))?;
+        match tokio::fs::read(&path).await {
+            Ok(body) => Ok(Some(body)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(ObjectStorageError::ReadFailed(format!("{}: {}", key, e))),
+        }
+    }
+}
+
+/// S3互換オブジェクトストレージのスタブ
+/// エンドポイントとバケットの設定だけを持ち、オブジェクトはメモリに保持する
+/// 実際のアップロードはS3クライアント（署名付きリクエスト）の実装に置き換える想定
+#[derive(Clone)]
+pub struct S3CompatibleObjectStorage {
+    endpoint: String,
+    bucket: String,
+    objects: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl S3CompatibleObjectStorage {
+    /// 新しいS3互換オブジェクトストレージのスタブを作成
+    ///
+    /// # Arguments
+    /// * `endpoint` - S3互換APIのエンドポイント（例: https://s3.ap-northeast-1.amazonaws.com）
+    /// * `bucket` - バケット名
+    pub fn new(endpoint: String, bucket: String) -> Self {
+        Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            bucket,
+            objects: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// オブジェクトのURL（ログ用）
+    fn object_url(&self, key: &str) -> String {
+        format!("{}/{}/{}", self.endpoint, self.bucket, key)
+    }
+}
+
+#[async_trait]
+impl ObjectStoragePort for S3CompatibleObjectStorage {
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> Result<(), ObjectStorageError> {
+        tracing::info!(
+            object.url = %self.object_url(key),
+            object.size = body.len(),
+            "storing object in S3-compatible storage stub"
+        );
+        self.objects.lock().await.insert(key.to_string(), body);
+        Ok(())
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, ObjectStorageError> {
+        Ok(self.objects.lock().await.get(key).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_storage_round_trip_and_rejects_escaping_keys() {
+        let root = std::env::temp_dir().join(format!("object-storage-{}", uuid::Uuid::new_v4()));
+        let storage = LocalFileObjectStorage::new(&root);
+
+        assert!(storage.get_object("events/manifest.json").await.unwrap().is_none());
+        storage
+            .put_object("events/manifest.json", b"{}".to_vec())
+            .await
+            .unwrap();
+        storage
+            .put_object("events/manifest.json", b"{\"completed\":true}".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            storage.get_object("events/manifest.json").await.unwrap(),
+            Some(b"{\"completed\":true}".to_vec())
+        );
+
+        assert!(storage.put_object("../outside", Vec::new()).await.is_err());
+        assert!(storage.put_object("/absolute", Vec::new()).await.is_err());
+
+        tokio::fs::remove_dir_all(&root).await.unwrap();
+    }
+}
@@ -0,0 +1,125 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::OrderId;
+use crate::domain::order_lock::OrderLock;
+use crate::domain::port::{OrderLockRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL注文編集ロックリポジトリ
+/// MySQLデータベース（order_locksテーブル）でオペレーターの注文の編集ロックを管理する
+#[derive(Clone)]
+pub struct MySqlOrderLockRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlOrderLockRepository {
+    /// 新しいMySQL注文編集ロックリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlOrderLockRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行からロックを構築する
+    fn lock_from_row(row: &MySqlRow) -> Result<OrderLock, RepositoryError> {
+        Ok(OrderLock {
+            order_id: OrderId::from_string(&row.get::<String, _>("order_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+            })?,
+            locked_by: row.get("locked_by"),
+            locked_at: row.get::<DateTime<Utc>, _>("locked_at"),
+            expires_at: row.get::<DateTime<Utc>, _>("expires_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl OrderLockRepository for MySqlOrderLockRepository {
+    #[tracing::instrument(name = "db.order_locks.try_acquire", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "order_locks", order_id = %lock.order_id), err)]
+    async fn try_acquire(&self, lock: &OrderLock) -> Result<OrderLock, RepositoryError> {
+        // 期限切れのロックと、同じオペレーターのロック（延長）だけを削除する
+        sqlx::query(
+            "DELETE FROM order_locks WHERE order_id = ? AND (expires_at <= ? OR locked_by = ?)",
+        )
+        .bind(lock.order_id.to_string())
+        .bind(lock.locked_at)
+        .bind(&lock.locked_by)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("期限切れのロックの削除に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        // 他のオペレーターのロックが残っている場合は挿入しない
+        sqlx::query(
+            "INSERT IGNORE INTO order_locks (order_id, locked_by, locked_at, expires_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(lock.order_id.to_string())
+        .bind(&lock.locked_by)
+        .bind(lock.locked_at)
+        .bind(lock.expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("ロックの保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        self.find_by_order_id(lock.order_id).await?.ok_or_else(|| {
+            RepositoryError::FetchFailed(format!(
+                "保存したロックが見つかりません: {}",
+                lock.order_id
+            ))
+        })
+    }
+
+    #[tracing::instrument(name = "db.order_locks.find_by_order_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "order_locks", order_id = %order_id), err)]
+    async fn find_by_order_id(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Option<OrderLock>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT order_id, locked_by, locked_at, expires_at FROM order_locks WHERE order_id = ?",
+        )
+        .bind(order_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("ロックの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        row.as_ref().map(Self::lock_from_row).transpose()
+    }
+
+    #[tracing::instrument(name = "db.order_locks.find_active", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "order_locks"), err)]
+    async fn find_active(&self, now: DateTime<Utc>) -> Result<Vec<OrderLock>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT order_id, locked_by, locked_at, expires_at FROM order_locks WHERE expires_at > ? ORDER BY locked_at",
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("ロックの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::lock_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.order_locks.remove", skip_all, fields(db.system = "mysql", db.operation = "DELETE", db.sql.table = "order_locks", order_id = %order_id), err)]
+    async fn remove(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM order_locks WHERE order_id = ?")
+            .bind(order_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("ロックの削除に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+}
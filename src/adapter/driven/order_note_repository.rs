@@ -0,0 +1,81 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::OrderId;
+use crate::domain::order_note::OrderNote;
+use crate::domain::port::{OrderNoteRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL CSメモリポジトリ
+/// MySQLデータベース（order_notesテーブル）で注文のCSメモを管理する
+#[derive(Clone)]
+pub struct MySqlOrderNoteRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlOrderNoteRepository {
+    /// 新しいMySQL CSメモリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlOrderNoteRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行からメモを構築する
+    fn note_from_row(row: &MySqlRow) -> Result<OrderNote, RepositoryError> {
+        Ok(OrderNote {
+            note_id: Uuid::parse_str(&row.get::<String, _>("note_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("メモIDの解析に失敗しました: {}", e))
+            })?,
+            order_id: OrderId::from_string(&row.get::<String, _>("order_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+            })?,
+            author: row.get("author"),
+            body: row.get("body"),
+            created_at: row.get::<DateTime<Utc>, _>("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl OrderNoteRepository for MySqlOrderNoteRepository {
+    #[tracing::instrument(name = "db.order_notes.add", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "order_notes", order_id = %note.order_id), err)]
+    async fn add(&self, note: &OrderNote) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO order_notes (note_id, order_id, author, body, created_at) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(note.note_id.to_string())
+        .bind(note.order_id.to_string())
+        .bind(&note.author)
+        .bind(&note.body)
+        .bind(note.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("メモの保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.order_notes.find_by_order", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "order_notes", order_id = %order_id), err)]
+    async fn find_by_order(&self, order_id: OrderId) -> Result<Vec<OrderNote>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT note_id, order_id, author, body, created_at FROM order_notes WHERE order_id = ? ORDER BY created_at, note_id",
+        )
+        .bind(order_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("メモの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::note_from_row).collect()
+    }
+}
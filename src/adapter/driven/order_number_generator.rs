@@ -0,0 +1,81 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::OrderNumber;
+use crate::domain::port::{OrderNumberGenerator, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Utc};
+
+// MySQL関連のインポート
+use sqlx::{MySql, Pool};
+
+/// MySQL注文番号採番
+/// MySQLデータベース（order_number_sequencesテーブル）の作成年ごとの連番から注文番号を採番する
+///
+/// 連番の加算と取得を1つの文で行うため、複数のインスタンスから同時に採番しても番号は重複しない
+/// （採番した注文の保存に失敗した場合、その番号は欠番になる）
+#[derive(Clone)]
+pub struct MySqlOrderNumberGenerator {
+    pool: Pool<MySql>,
+    prefix: String,
+    digits: usize,
+}
+
+impl MySqlOrderNumberGenerator {
+    /// 新しいMySQL注文番号採番を作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    /// * `prefix` - 注文番号の接頭辞（例: BK）
+    /// * `digits` - 連番の最小桁数（0で埋める）
+    ///
+    /// # Returns
+    /// * MySqlOrderNumberGeneratorのインスタンス
+    pub fn new(pool: Pool<MySql>, prefix: String, digits: usize) -> Self {
+        Self {
+            pool,
+            prefix,
+            digits,
+        }
+    }
+}
+
+#[async_trait]
+impl OrderNumberGenerator for MySqlOrderNumberGenerator {
+    #[tracing::instrument(name = "db.order_number_sequences.next", skip_all, fields(db.system = "mysql", db.operation = "UPSERT", db.sql.table = "order_number_sequences"), err)]
+    async fn next_order_number(
+        &self,
+        created_at: DateTime<Utc>,
+    ) -> Result<OrderNumber, RepositoryError> {
+        let year = created_at.year();
+        // LAST_INSERT_IDは接続ごとの値のため、更新と取得を同じ接続で行う
+        let mut connection = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| DatabaseError::ConnectionError(format!("接続の取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO order_number_sequences (year, last_value)
+            VALUES (?, LAST_INSERT_ID(1))
+            ON DUPLICATE KEY UPDATE last_value = LAST_INSERT_ID(last_value + 1)
+            "#,
+        )
+        .bind(year)
+        .execute(&mut *connection)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("注文番号の採番に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let sequence: u64 = sqlx::query_scalar("SELECT LAST_INSERT_ID()")
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("採番した連番の取得に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        OrderNumber::new(&self.prefix, year, sequence, self.digits)
+            .map_err(|e| RepositoryError::OperationFailed(e.to_string()))
+    }
+}
@@ -1,16 +1,32 @@
 use crate::adapter::database_error::DatabaseError;
+use crate::domain::address_normalizer::AddressInput;
+use crate::domain::invariant::describe_violations;
 use crate::domain::model::{Order, OrderId};
+use crate::domain::order_totals::OrderTotals;
 use crate::domain::port::{OrderRepository, RepositoryError};
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
 
 // MySQL関連のインポート
-use crate::domain::model::{BookId, CustomerId, Money, OrderLine, OrderStatus, ShippingAddress};
-use sqlx::{MySql, Pool, Row};
+use crate::domain::model::{
+    BookId, BookSpec, CustomerId, FulfillmentType, HoldReason, LineAttribute, Money, OrderLine,
+    OrderNumber, OrderStatus, Recipient, SalesChannel, ShippingAddress, TrackingToken,
+};
+use crate::domain::purchase_policy::{PurchaseCheck, PurchasePolicy};
+use sqlx::mysql::{MySqlConnection, MySqlRow};
+use sqlx::{Executor, MySql, Pool, QueryBuilder, Row};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// 注文IDと書籍IDごとの明細属性
+type LineAttributesByLine = HashMap<(String, String), Vec<LineAttribute>>;
 
 /// MySQL注文リポジトリ
 /// MySQLデータベースを使用して注文を永続化する
 pub struct MySqlOrderRepository {
     pool: Pool<MySql>,
+    /// 保存・読み込み時に注文の不変条件を検証するかどうか
+    invariant_checks: bool,
 }
 
 impl MySqlOrderRepository {
@@ -22,7 +38,238 @@ impl MySqlOrderRepository {
     /// # Returns
     /// * MySqlOrderRepositoryのインスタンス
     pub fn new(pool: Pool<MySql>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            invariant_checks: false,
+        }
+    }
+
+    /// 不変条件の検証の有効・無効を設定したリポジトリを作成
+    /// 有効な場合は違反する注文の保存を拒否し、違反する注文の読み込みを警告ログに残す
+    ///
+    /// # Arguments
+    /// * `enabled` - 不変条件を検証するかどうか
+    pub fn with_invariant_checks(mut self, enabled: bool) -> Self {
+        self.invariant_checks = enabled;
+        self
+    }
+
+    /// 保存する注文の不変条件を検証
+    fn ensure_invariants(&self, order: &Order) -> Result<(), RepositoryError> {
+        if !self.invariant_checks {
+            return Ok(());
+        }
+        let violations = order.check_invariants();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(RepositoryError::OperationFailed(format!(
+                "不変条件に違反する注文は保存できません: {}",
+                describe_violations(&violations)
+            )))
+        }
+    }
+
+    /// 読み込んだ注文の不変条件を検証（違反があっても読み込みは続ける）
+    fn warn_invariant_violations(&self, order: Order) -> Order {
+        if self.invariant_checks {
+            let violations = order.check_invariants();
+            if !violations.is_empty() {
+                tracing::warn!(
+                    order_id = %order.id(),
+                    violations = %describe_violations(&violations),
+                    "loaded order violates invariants"
+                );
+            }
+        }
+        order
+    }
+
+    /// 注文明細の行からフルフィルメント種別を取得する
+    /// 注文明細がない行（LEFT JOIN）はNULLのため物理書籍として扱う
+    fn fulfillment_type_from_row(row: &MySqlRow) -> Result<FulfillmentType, RepositoryError> {
+        row.get::<Option<String>, _>("fulfillment_type")
+            .map(|value| FulfillmentType::from_string(&value))
+            .transpose()
+            .map(Option::unwrap_or_default)
+            .map_err(|e| {
+                RepositoryError::FetchFailed(format!(
+                    "フルフィルメント種別の解析に失敗しました: {}",
+                    e
+                ))
+            })
+    }
+
+    /// 注文明細の行から書籍の重量と寸法を取得する
+    /// 重量・寸法が未登録の明細（マイグレーション前に保存した明細を含む）はNULLのためNone
+    fn book_spec_from_row(row: &MySqlRow) -> Result<Option<BookSpec>, RepositoryError> {
+        match (
+            row.get::<Option<u32>, _>("weight_grams"),
+            row.get::<Option<u32>, _>("width_mm"),
+            row.get::<Option<u32>, _>("height_mm"),
+            row.get::<Option<u32>, _>("thickness_mm"),
+        ) {
+            (Some(weight), Some(width), Some(height), Some(thickness)) => {
+                BookSpec::new(weight, width, height, thickness)
+                    .map(Some)
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!(
+                            "書籍の重量・寸法の解析に失敗しました: {}",
+                            e
+                        ))
+                    })
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// 注文の行からギフト注文の受取人を取得する
+    /// 受取人の住所は配送先住所と同じため、配送先住所と受取人の氏名・電話番号から再構築する
+    fn recipient_from_row(
+        row: &MySqlRow,
+        shipping_address: Option<&ShippingAddress>,
+    ) -> Result<Option<Recipient>, RepositoryError> {
+        match (
+            row.get::<Option<String>, _>("recipient_name"),
+            row.get::<Option<String>, _>("recipient_phone"),
+            shipping_address,
+        ) {
+            (Some(name), Some(phone), Some(address)) => {
+                Recipient::new(name, phone, address.clone())
+                    .map(Some)
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!("受取人の解析に失敗しました: {}", e))
+                    })
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// 注文の行から正規化前の配送先住所を取得する（JSONで保存している）
+    fn original_shipping_address_from_row(
+        row: &MySqlRow,
+    ) -> Result<Option<AddressInput>, RepositoryError> {
+        row.get::<Option<String>, _>("original_shipping_address")
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    RepositoryError::FetchFailed(format!(
+                        "正規化前の配送先住所の解析に失敗しました: {}",
+                        e
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    /// 注文の行から確定時の金額を取得する（JSONで保存している。確定前の注文とマイグレーション前に確定した注文はNULL）
+    fn confirmed_totals_from_row(row: &MySqlRow) -> Result<Option<OrderTotals>, RepositoryError> {
+        row.get::<Option<String>, _>("confirmed_totals")
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("確定時の金額の解析に失敗しました: {}", e))
+                })
+            })
+            .transpose()
+    }
+
+    /// 注文の行から追跡トークンを取得する（確定前・キャンセル済みの注文とマイグレーション前に確定した注文はNULL）
+    fn tracking_token_from_row(row: &MySqlRow) -> Result<Option<TrackingToken>, RepositoryError> {
+        row.get::<Option<String>, _>("tracking_token")
+            .map(|token| {
+                TrackingToken::parse(&token).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("追跡トークンの解析に失敗しました: {}", e))
+                })
+            })
+            .transpose()
+    }
+
+    /// 注文の行から注文番号を取得する（採番を導入する前に作成した注文はNULL）
+    fn order_number_from_row(row: &MySqlRow) -> Result<Option<OrderNumber>, RepositoryError> {
+        row.get::<Option<String>, _>("order_number")
+            .map(|number| {
+                OrderNumber::parse(&number).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("注文番号の解析に失敗しました: {}", e))
+                })
+            })
+            .transpose()
+    }
+
+    /// 注文の行から販売チャネルを取得する
+    fn sales_channel_from_row(row: &MySqlRow) -> Result<SalesChannel, RepositoryError> {
+        SalesChannel::from_string(&row.get::<String, _>("sales_channel")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("販売チャネルの解析に失敗しました: {}", e))
+        })
+    }
+
+    /// 注文の行から保留の理由を取得する（保留中でない注文はNULL）
+    fn hold_reason_from_row(row: &MySqlRow) -> Result<Option<HoldReason>, RepositoryError> {
+        row.get::<Option<String>, _>("hold_reason")
+            .map(|reason| {
+                HoldReason::from_string(&reason).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("保留の理由の解析に失敗しました: {}", e))
+                })
+            })
+            .transpose()
+    }
+
+    /// 注文の行からサーガの相関IDを取得する（確定前の注文とマイグレーション前に確定した注文はNULL）
+    fn saga_correlation_id_from_row(row: &MySqlRow) -> Result<Option<Uuid>, RepositoryError> {
+        row.get::<Option<String>, _>("saga_correlation_id")
+            .map(|id| {
+                Uuid::parse_str(&id).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("相関IDの解析に失敗しました: {}", e))
+                })
+            })
+            .transpose()
+    }
+
+    /// 注文の行からステータス遷移日時を取得して設定する
+    fn with_transition_times_from_row(order: Order, row: &MySqlRow) -> Order {
+        order.with_transition_times(
+            row.get::<Option<DateTime<Utc>>, _>("confirmed_at"),
+            row.get::<Option<DateTime<Utc>>, _>("shipped_at"),
+            row.get::<Option<DateTime<Utc>>, _>("delivered_at"),
+        )
+    }
+
+    /// 注文の明細属性をorder_line_attributesテーブルから取得する
+    async fn load_line_attributes(
+        &self,
+        order_ids: &[&str],
+    ) -> Result<LineAttributesByLine, RepositoryError> {
+        let mut attributes = LineAttributesByLine::new();
+        if order_ids.is_empty() {
+            return Ok(attributes);
+        }
+
+        let mut query = QueryBuilder::<MySql>::new(
+            "SELECT order_id, book_id, attribute_key, attribute_value FROM order_line_attributes WHERE order_id IN (",
+        );
+        let mut separated = query.separated(", ");
+        for order_id in order_ids {
+            separated.push_bind(*order_id);
+        }
+        separated.push_unseparated(") ORDER BY id");
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("明細属性の取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+        for row in rows {
+            let attribute =
+                LineAttribute::new(row.get("attribute_key"), row.get("attribute_value")).map_err(
+                    |e| {
+                        RepositoryError::FetchFailed(format!("明細属性の構築に失敗しました: {}", e))
+                    },
+                )?;
+            attributes
+                .entry((row.get("order_id"), row.get("book_id")))
+                .or_default()
+                .push(attribute);
+        }
+        Ok(attributes)
     }
 
     /// データベースの行から注文オブジェクトのリストを構築する
@@ -31,8 +278,6 @@ impl MySqlOrderRepository {
         &self,
         rows: Vec<sqlx::mysql::MySqlRow>,
     ) -> Result<Vec<Order>, RepositoryError> {
-        use std::collections::HashMap;
-
         // 注文IDごとにグループ化
         let mut order_groups: HashMap<String, Vec<&sqlx::mysql::MySqlRow>> = HashMap::new();
         for row in &rows {
@@ -43,9 +288,12 @@ impl MySqlOrderRepository {
                 .push(row);
         }
 
+        let order_ids: Vec<&str> = order_groups.keys().map(String::as_str).collect();
+        let mut line_attributes = self.load_line_attributes(&order_ids).await?;
+
         let mut orders = Vec::new();
 
-        for (order_id_str, order_rows) in order_groups {
+        for (order_id_str, order_rows) in &order_groups {
             if order_rows.is_empty() {
                 continue;
             }
@@ -53,7 +301,7 @@ impl MySqlOrderRepository {
             // 最初の行から注文の基本情報を取得
             let first_row = order_rows[0];
 
-            let order_id = OrderId::from_string(&order_id_str).map_err(|e| {
+            let order_id = OrderId::from_string(order_id_str).map_err(|e| {
                 RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
             })?;
 
@@ -95,7 +343,7 @@ impl MySqlOrderRepository {
 
             // 注文明細を再構築
             let mut order_lines = Vec::new();
-            for row in &order_rows {
+            for row in order_rows {
                 if let (Some(book_id_str), Some(quantity), Some(amount), Some(currency)) = (
                     row.get::<Option<String>, _>("book_id"),
                     row.get::<Option<u32>, _>("quantity"),
@@ -110,13 +358,20 @@ impl MySqlOrderRepository {
                         RepositoryError::FetchFailed(format!("金額の構築に失敗しました: {}", e))
                     })?;
 
-                    let order_line =
-                        OrderLine::new(book_id, quantity, unit_price).map_err(|e| {
+                    let order_line = OrderLine::new(book_id, quantity, unit_price)
+                        .map_err(|e| {
                             RepositoryError::FetchFailed(format!(
                                 "注文明細の構築に失敗しました: {}",
                                 e
                             ))
-                        })?;
+                        })?
+                        .with_fulfillment_type(Self::fulfillment_type_from_row(row)?)
+                        .with_spec(Self::book_spec_from_row(row)?)
+                        .with_attributes(
+                            line_attributes
+                                .remove(&(order_id_str.clone(), book_id_str))
+                                .unwrap_or_default(),
+                        );
 
                     order_lines.push(order_line);
                 }
@@ -131,26 +386,31 @@ impl MySqlOrderRepository {
                             e
                         ))
                     })?;
+            let recipient = Self::recipient_from_row(first_row, order.shipping_address())?;
+            let original_shipping_address = Self::original_shipping_address_from_row(first_row)?;
+            let order = Self::with_transition_times_from_row(order, first_row)
+                .with_order_number(Self::order_number_from_row(first_row)?)
+                .with_sales_channel(Self::sales_channel_from_row(first_row)?)
+                .with_event_sequence(first_row.get::<u64, _>("event_sequence"))
+                .with_saga_correlation_id(Self::saga_correlation_id_from_row(first_row)?)
+                .with_confirmed_totals(Self::confirmed_totals_from_row(first_row)?)
+                .with_tracking_token(Self::tracking_token_from_row(first_row)?)
+                .with_estimated_delivery_date(
+                    first_row.get::<Option<NaiveDate>, _>("estimated_delivery_date"),
+                )
+                .with_hold_reason(Self::hold_reason_from_row(first_row)?)
+                .with_version(first_row.get::<u64, _>("version"))
+                .with_recipient(recipient)
+                .with_original_shipping_address(original_shipping_address);
 
-            orders.push(order);
+            orders.push(self.warn_invariant_violations(order));
         }
 
         Ok(orders)
     }
-}
-
-#[async_trait]
-impl OrderRepository for MySqlOrderRepository {
-    async fn save(&self, order: &Order) -> Result<(), RepositoryError> {
-        let mut tx = self
-            .pool
-            .begin()
-            .await
-            .map_err(|e| {
-                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
-            })
-            .map_err(RepositoryError::from)?;
 
+    /// 注文と明細・明細属性をトランザクション内で書き込む（コミットは呼び出し側で行う）
+    async fn write_order(conn: &mut MySqlConnection, order: &Order) -> Result<(), RepositoryError> {
         // 注文データをordersテーブルにUPSERT
         let shipping_address = order.shipping_address();
         let (postal_code, prefecture, city, street, building) = match shipping_address {
@@ -163,37 +423,111 @@ impl OrderRepository for MySqlOrderRepository {
             ),
             None => (None, None, None, None, None),
         };
+        let recipient = order.recipient();
+        let original_shipping_address = order
+            .original_shipping_address()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| {
+                RepositoryError::OperationFailed(format!(
+                    "正規化前の配送先住所のシリアライズに失敗しました: {}",
+                    e
+                ))
+            })?;
+        let confirmed_totals = order
+            .confirmed_totals()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| {
+                RepositoryError::OperationFailed(format!(
+                    "確定時の金額のシリアライズに失敗しました: {}",
+                    e
+                ))
+            })?;
 
-        sqlx::query(
-            r#"
-            INSERT INTO orders (id, customer_id, status, postal_code, prefecture, city, street, building)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
-            ON DUPLICATE KEY UPDATE
-                status = VALUES(status),
-                postal_code = VALUES(postal_code),
-                prefecture = VALUES(prefecture),
-                city = VALUES(city),
-                street = VALUES(street),
-                building = VALUES(building)
-            "#
-        )
-        .bind(order.id().to_string())
-        .bind(order.customer_id().to_string())
-        .bind(order.status().to_string())
-        .bind(postal_code)
-        .bind(prefecture)
-        .bind(city)
-        .bind(street)
-        .bind(building)
-        .execute(&mut *tx)
-        .await
-        .map_err(|e| DatabaseError::QueryError(format!("注文の保存に失敗しました: {}", e)))
-        .map_err(RepositoryError::from)?;
+        // 未保存の注文（バージョン0）は追加し、保存済みの注文は読み込んだときのバージョンの場合のみ更新する
+        // （他の操作が先に保存していた場合は、追加は主キーの重複、更新は0件になる）
+        let is_new = order.version() == 0;
+        let query = if is_new {
+            sqlx::query(
+                r#"
+                INSERT INTO orders (id, order_number, customer_id, sales_channel, status, confirmed_at, shipped_at, delivered_at, estimated_delivery_date, hold_reason, event_sequence, saga_correlation_id, confirmed_totals, tracking_token, postal_code, prefecture, city, street, building, original_shipping_address, recipient_name, recipient_phone, version)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 1)
+                "#,
+            )
+            .bind(order.id().to_string())
+            .bind(order.order_number().map(OrderNumber::as_str))
+            .bind(order.customer_id().to_string())
+            .bind(order.sales_channel().to_string())
+        } else {
+            sqlx::query(
+                r#"
+                UPDATE orders SET
+                    status = ?,
+                    confirmed_at = ?,
+                    shipped_at = ?,
+                    delivered_at = ?,
+                    estimated_delivery_date = ?,
+                    hold_reason = ?,
+                    event_sequence = ?,
+                    saga_correlation_id = ?,
+                    confirmed_totals = ?,
+                    tracking_token = ?,
+                    postal_code = ?,
+                    prefecture = ?,
+                    city = ?,
+                    street = ?,
+                    building = ?,
+                    original_shipping_address = ?,
+                    recipient_name = ?,
+                    recipient_phone = ?,
+                    version = version + 1
+                WHERE id = ? AND version = ?
+                "#,
+            )
+        };
+        let query = query
+            .bind(order.status().to_string())
+            .bind(order.confirmed_at())
+            .bind(order.shipped_at())
+            .bind(order.delivered_at())
+            .bind(order.estimated_delivery_date())
+            .bind(order.hold_reason().map(|reason| reason.to_string()))
+            .bind(order.event_sequence())
+            .bind(order.saga_correlation_id().map(|id| id.to_string()))
+            .bind(confirmed_totals)
+            .bind(order.tracking_token().map(TrackingToken::as_str))
+            .bind(postal_code)
+            .bind(prefecture)
+            .bind(city)
+            .bind(street)
+            .bind(building)
+            .bind(original_shipping_address)
+            .bind(recipient.map(Recipient::name))
+            .bind(recipient.map(Recipient::phone));
+        let query = if is_new {
+            query
+        } else {
+            query.bind(order.id().to_string()).bind(order.version())
+        };
+        let result = query.execute(&mut *conn).await.map_err(|e| {
+            if is_primary_key_violation(&e) {
+                concurrency_conflict(order)
+            } else {
+                RepositoryError::from(DatabaseError::QueryError(format!(
+                    "注文の保存に失敗しました: {}",
+                    e
+                )))
+            }
+        })?;
+        if result.rows_affected() == 0 {
+            return Err(concurrency_conflict(order));
+        }
 
         // 既存の注文明細を削除
         sqlx::query("DELETE FROM order_lines WHERE order_id = ?")
             .bind(order.id().to_string())
-            .execute(&mut *tx)
+            .execute(&mut *conn)
             .await
             .map_err(|e| DatabaseError::QueryError(format!("注文明細の削除に失敗しました: {}", e)))
             .map_err(RepositoryError::from)?;
@@ -202,8 +536,9 @@ impl OrderRepository for MySqlOrderRepository {
         for order_line in order.order_lines() {
             sqlx::query(
                 r#"
-                INSERT INTO order_lines (order_id, book_id, quantity, unit_price_amount, unit_price_currency)
-                VALUES (?, ?, ?, ?, ?)
+                INSERT INTO order_lines (order_id, book_id, quantity, unit_price_amount, unit_price_currency, fulfillment_type,
+                    weight_grams, width_mm, height_mm, thickness_mm)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#
             )
             .bind(order.id().to_string())
@@ -211,12 +546,61 @@ impl OrderRepository for MySqlOrderRepository {
             .bind(order_line.quantity())
             .bind(order_line.unit_price().amount())
             .bind(order_line.unit_price().currency())
-            .execute(&mut *tx)
+            .bind(order_line.fulfillment_type().to_string())
+            .bind(order_line.spec().map(|spec| spec.weight_grams()))
+            .bind(order_line.spec().map(|spec| spec.width_mm()))
+            .bind(order_line.spec().map(|spec| spec.height_mm()))
+            .bind(order_line.spec().map(|spec| spec.thickness_mm()))
+            .execute(&mut *conn)
             .await
             .map_err(|e| DatabaseError::QueryError(format!("注文明細の保存に失敗しました: {}", e)))
             .map_err(RepositoryError::from)?;
         }
 
+        // 明細属性を置き換え
+        sqlx::query("DELETE FROM order_line_attributes WHERE order_id = ?")
+            .bind(order.id().to_string())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("明細属性の削除に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+        for order_line in order.order_lines() {
+            for attribute in order_line.attributes() {
+                sqlx::query(
+                    "INSERT INTO order_line_attributes (order_id, book_id, attribute_key, attribute_value) VALUES (?, ?, ?, ?)",
+                )
+                .bind(order.id().to_string())
+                .bind(order_line.book_id().to_string())
+                .bind(attribute.key())
+                .bind(attribute.value())
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("明細属性の保存に失敗しました: {}", e)))
+                .map_err(RepositoryError::from)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrderRepository for MySqlOrderRepository {
+    #[tracing::instrument(name = "db.orders.save", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "orders", order_id = %order.id()), err)]
+    async fn save(&self, order: &mut Order) -> Result<(), RepositoryError> {
+        self.ensure_invariants(order)?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Self::write_order(&mut tx, order).await?;
+
         // トランザクションをコミット
         tx.commit()
             .await
@@ -228,17 +612,75 @@ impl OrderRepository for MySqlOrderRepository {
             })
             .map_err(RepositoryError::from)?;
 
+        order.mark_saved(order.version() + 1);
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.orders.save_within_purchase_limits", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "orders", order_id = %order.id()), err)]
+    async fn save_within_purchase_limits(
+        &self,
+        order: &mut Order,
+        policy: &PurchasePolicy,
+        check: PurchaseCheck,
+    ) -> Result<(), RepositoryError> {
+        self.ensure_invariants(order)?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 顧客ごとのロック行を排他ロックし、同じ顧客の集計から保存までを直列化する
+        // （ロック行がなければ作成する。INSERTでもUPDATEでも行ロックはコミットまで保持される）
+        sqlx::query(
+            "INSERT INTO customer_purchase_locks (customer_id) VALUES (?) ON DUPLICATE KEY UPDATE customer_id = customer_id",
+        )
+        .bind(order.customer_id().to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("購入制限のロックに失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let counted = match &check {
+            PurchaseCheck::OpenOrder => count_open_orders(&mut *tx, order.customer_id()).await?,
+            PurchaseCheck::BookQuantity { book_id, since, .. } => {
+                sum_book_quantity_since(&mut *tx, order.customer_id(), *book_id, *since).await?
+            }
+        };
+        policy
+            .ensure(&check, counted)
+            .map_err(RepositoryError::PurchaseLimitExceeded)?;
+
+        Self::write_order(&mut tx, order).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        order.mark_saved(order.version() + 1);
         Ok(())
     }
 
+    #[tracing::instrument(name = "db.orders.find_by_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", order_id = %order_id), err)]
     async fn find_by_id(&self, order_id: OrderId) -> Result<Option<Order>, RepositoryError> {
         // ordersテーブルとorder_linesテーブルをJOINして取得
         let rows = sqlx::query(
             r#"
             SELECT 
-                o.id, o.customer_id, o.status,
-                o.postal_code, o.prefecture, o.city, o.street, o.building,
-                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency
+                o.id, o.customer_id, o.sales_channel, o.status, o.confirmed_at, o.shipped_at, o.delivered_at, o.estimated_delivery_date, o.hold_reason, o.event_sequence, o.saga_correlation_id, o.confirmed_totals, o.tracking_token, o.order_number, o.version,
+                o.postal_code, o.prefecture, o.city, o.street, o.building, o.original_shipping_address, o.recipient_name, o.recipient_phone,
+                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency, ol.fulfillment_type,
+                ol.weight_grams, ol.width_mm, ol.height_mm, ol.thickness_mm
             FROM orders o
             LEFT JOIN order_lines ol ON o.id = ol.order_id
             WHERE o.id = ?
@@ -292,6 +734,8 @@ impl OrderRepository for MySqlOrderRepository {
             };
 
         // 注文明細を再構築
+        let order_id_str = order_id.to_string();
+        let mut line_attributes = self.load_line_attributes(&[&order_id_str]).await?;
         let mut order_lines = Vec::new();
         for row in &rows {
             if let (Some(book_id_str), Some(quantity), Some(amount), Some(currency)) = (
@@ -308,10 +752,17 @@ impl OrderRepository for MySqlOrderRepository {
                     RepositoryError::FetchFailed(format!("金額の構築に失敗しました: {}", e))
                 })?;
 
-                let order_line =
-                    OrderLine::new(book_id, quantity, unit_price).map_err(|e| {
+                let order_line = OrderLine::new(book_id, quantity, unit_price)
+                    .map_err(|e| {
                         RepositoryError::FetchFailed(format!("注文明細の構築に失敗しました: {}", e))
-                    })?;
+                    })?
+                    .with_fulfillment_type(Self::fulfillment_type_from_row(row)?)
+                    .with_spec(Self::book_spec_from_row(row)?)
+                    .with_attributes(
+                        line_attributes
+                            .remove(&(order_id_str.clone(), book_id_str))
+                            .unwrap_or_default(),
+                    );
 
                 order_lines.push(order_line);
             }
@@ -323,19 +774,37 @@ impl OrderRepository for MySqlOrderRepository {
                 .map_err(|e| {
                     RepositoryError::FetchFailed(format!("注文集約の再構築に失敗しました: {}", e))
                 })?;
+        let recipient = Self::recipient_from_row(first_row, order.shipping_address())?;
+        let original_shipping_address = Self::original_shipping_address_from_row(first_row)?;
+        let order = Self::with_transition_times_from_row(order, first_row)
+            .with_order_number(Self::order_number_from_row(first_row)?)
+            .with_sales_channel(Self::sales_channel_from_row(first_row)?)
+            .with_event_sequence(first_row.get::<u64, _>("event_sequence"))
+            .with_saga_correlation_id(Self::saga_correlation_id_from_row(first_row)?)
+            .with_confirmed_totals(Self::confirmed_totals_from_row(first_row)?)
+            .with_tracking_token(Self::tracking_token_from_row(first_row)?)
+            .with_estimated_delivery_date(
+                first_row.get::<Option<NaiveDate>, _>("estimated_delivery_date"),
+            )
+            .with_hold_reason(Self::hold_reason_from_row(first_row)?)
+            .with_version(first_row.get::<u64, _>("version"))
+            .with_recipient(recipient)
+            .with_original_shipping_address(original_shipping_address);
 
-        Ok(Some(order))
+        Ok(Some(self.warn_invariant_violations(order)))
     }
 
+    #[tracing::instrument(name = "db.orders.find_all", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders"), err)]
     async fn find_all(&self) -> Result<Vec<Order>, RepositoryError> {
         // ordersテーブルとorder_linesテーブルをJOINして全注文を取得
         // 作成日時の降順で並べる
         let rows = sqlx::query(
             r#"
             SELECT 
-                o.id, o.customer_id, o.status,
-                o.postal_code, o.prefecture, o.city, o.street, o.building,
-                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency
+                o.id, o.customer_id, o.sales_channel, o.status, o.confirmed_at, o.shipped_at, o.delivered_at, o.estimated_delivery_date, o.hold_reason, o.event_sequence, o.saga_correlation_id, o.confirmed_totals, o.tracking_token, o.order_number, o.version,
+                o.postal_code, o.prefecture, o.city, o.street, o.building, o.original_shipping_address, o.recipient_name, o.recipient_phone,
+                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency, ol.fulfillment_type,
+                ol.weight_grams, ol.width_mm, ol.height_mm, ol.thickness_mm
             FROM orders o
             LEFT JOIN order_lines ol ON o.id = ol.order_id
             ORDER BY o.created_at DESC
@@ -349,15 +818,17 @@ impl OrderRepository for MySqlOrderRepository {
         self.build_orders_from_rows(rows).await
     }
 
+    #[tracing::instrument(name = "db.orders.find_by_status", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", status = ?status), err)]
     async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, RepositoryError> {
         // 指定されたステータスの注文を取得
         // 作成日時の降順で並べる
         let rows = sqlx::query(
             r#"
             SELECT 
-                o.id, o.customer_id, o.status,
-                o.postal_code, o.prefecture, o.city, o.street, o.building,
-                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency
+                o.id, o.customer_id, o.sales_channel, o.status, o.confirmed_at, o.shipped_at, o.delivered_at, o.estimated_delivery_date, o.hold_reason, o.event_sequence, o.saga_correlation_id, o.confirmed_totals, o.tracking_token, o.order_number, o.version,
+                o.postal_code, o.prefecture, o.city, o.street, o.building, o.original_shipping_address, o.recipient_name, o.recipient_phone,
+                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency, ol.fulfillment_type,
+                ol.weight_grams, ol.width_mm, ol.height_mm, ol.thickness_mm
             FROM orders o
             LEFT JOIN order_lines ol ON o.id = ol.order_id
             WHERE o.status = ?
@@ -375,7 +846,377 @@ impl OrderRepository for MySqlOrderRepository {
         self.build_orders_from_rows(rows).await
     }
 
+    #[tracing::instrument(name = "db.orders.find_by_customer", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", customer_id = %customer_id), err)]
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        // 顧客の注文を取得
+        // 作成日時の降順で並べる
+        let rows = sqlx::query(
+            r#"
+            SELECT 
+                o.id, o.customer_id, o.sales_channel, o.status, o.confirmed_at, o.shipped_at, o.delivered_at, o.estimated_delivery_date, o.hold_reason, o.event_sequence, o.saga_correlation_id, o.confirmed_totals, o.tracking_token, o.order_number, o.version,
+                o.postal_code, o.prefecture, o.city, o.street, o.building, o.original_shipping_address, o.recipient_name, o.recipient_phone,
+                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency, ol.fulfillment_type,
+                ol.weight_grams, ol.width_mm, ol.height_mm, ol.thickness_mm
+            FROM orders o
+            LEFT JOIN order_lines ol ON o.id = ol.order_id
+            WHERE o.customer_id = ?
+            ORDER BY o.created_at DESC
+            "#,
+        )
+        .bind(customer_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("顧客の注文一覧の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        self.build_orders_from_rows(rows).await
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_customer_page", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", customer_id = %customer_id, limit = limit), err)]
+    async fn find_by_customer_page(
+        &self,
+        customer_id: CustomerId,
+        after: Option<OrderId>,
+        limit: u32,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        // 注文明細と結合すると行数が増えるため、先にページの注文IDを絞り込んでから結合する
+        let rows = sqlx::query(
+            r#"
+            SELECT 
+                o.id, o.customer_id, o.sales_channel, o.status, o.confirmed_at, o.shipped_at, o.delivered_at, o.estimated_delivery_date, o.hold_reason, o.event_sequence, o.saga_correlation_id, o.confirmed_totals, o.tracking_token, o.order_number, o.version,
+                o.postal_code, o.prefecture, o.city, o.street, o.building, o.original_shipping_address, o.recipient_name, o.recipient_phone,
+                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency, ol.fulfillment_type,
+                ol.weight_grams, ol.width_mm, ol.height_mm, ol.thickness_mm
+            FROM (
+                SELECT id FROM orders
+                WHERE customer_id = ? AND id > ?
+                ORDER BY id ASC
+                LIMIT ?
+            ) page
+            JOIN orders o ON o.id = page.id
+            LEFT JOIN order_lines ol ON o.id = ol.order_id
+            "#,
+        )
+        .bind(customer_id.to_string())
+        .bind(after.map(|id| id.to_string()).unwrap_or_default())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("顧客の注文の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        let mut orders = self.build_orders_from_rows(rows).await?;
+        orders.sort_by_key(|order| order.id().to_string());
+        Ok(orders)
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_status_created_before", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", status = ?status), err)]
+    async fn find_by_status_created_before(
+        &self,
+        status: OrderStatus,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        // 指定されたステータスで、指定日時より前に作成された注文を取得
+        let rows = sqlx::query(
+            r#"
+            SELECT 
+                o.id, o.customer_id, o.sales_channel, o.status, o.confirmed_at, o.shipped_at, o.delivered_at, o.estimated_delivery_date, o.hold_reason, o.event_sequence, o.saga_correlation_id, o.confirmed_totals, o.tracking_token, o.order_number, o.version,
+                o.postal_code, o.prefecture, o.city, o.street, o.building, o.original_shipping_address, o.recipient_name, o.recipient_phone,
+                ol.book_id, ol.quantity, ol.unit_price_amount, ol.unit_price_currency, ol.fulfillment_type,
+                ol.weight_grams, ol.width_mm, ol.height_mm, ol.thickness_mm
+            FROM orders o
+            LEFT JOIN order_lines ol ON o.id = ol.order_id
+            WHERE o.status = ? AND o.created_at < ?
+            "#,
+        )
+        .bind(status.to_string())
+        .bind(created_before)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("作成日時による注文一覧の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        self.build_orders_from_rows(rows).await
+    }
+
+    #[tracing::instrument(name = "db.orders.count_open_orders_by_customer", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", customer_id = %customer_id), err)]
+    async fn count_open_orders_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<u32, RepositoryError> {
+        count_open_orders(&self.pool, customer_id).await
+    }
+
+    #[tracing::instrument(name = "db.orders.count_orders_by_customer_and_status", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", customer_id = %customer_id, status = %status), err)]
+    async fn count_orders_by_customer_and_status(
+        &self,
+        customer_id: CustomerId,
+        status: OrderStatus,
+    ) -> Result<u32, RepositoryError> {
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM orders WHERE customer_id = ? AND status = ?")
+                .bind(customer_id.to_string())
+                .bind(status.to_string())
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!("注文数の取得に失敗しました: {}", e))
+                })
+                .map_err(RepositoryError::from)?;
+
+        Ok(count as u32)
+    }
+
+    #[tracing::instrument(name = "db.orders.sum_book_quantity_by_customer_since", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "order_lines", customer_id = %customer_id, book_id = %book_id), err)]
+    async fn sum_book_quantity_by_customer_since(
+        &self,
+        customer_id: CustomerId,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, RepositoryError> {
+        sum_book_quantity_since(&self.pool, customer_id, book_id, since).await
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_tracking_token", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders"), err)]
+    async fn find_by_tracking_token(
+        &self,
+        token: &TrackingToken,
+    ) -> Result<Option<Order>, RepositoryError> {
+        let order_id: Option<String> =
+            sqlx::query_scalar("SELECT id FROM orders WHERE tracking_token = ?")
+                .bind(token.as_str())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!(
+                        "追跡トークンによる注文の取得に失敗しました: {}",
+                        e
+                    ))
+                })
+                .map_err(RepositoryError::from)?;
+
+        match order_id {
+            Some(id) => {
+                let order_id = OrderId::from_string(&id).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+                })?;
+                self.find_by_id(order_id).await
+            }
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_order_number", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", order_number = %order_number), err)]
+    async fn find_by_order_number(
+        &self,
+        order_number: &OrderNumber,
+    ) -> Result<Option<Order>, RepositoryError> {
+        let order_id: Option<String> =
+            sqlx::query_scalar("SELECT id FROM orders WHERE order_number = ?")
+                .bind(order_number.as_str())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!(
+                        "注文番号による注文の取得に失敗しました: {}",
+                        e
+                    ))
+                })
+                .map_err(RepositoryError::from)?;
+
+        match order_id {
+            Some(id) => {
+                let order_id = OrderId::from_string(&id).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+                })?;
+                self.find_by_id(order_id).await
+            }
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "db.orders.find_similar_orders", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", order_id = %order_id), err)]
+    async fn find_similar_orders(
+        &self,
+        order_id: OrderId,
+        within: TimeDelta,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        // 明細を「書籍ID:数量」の並びにまとめて比較する（明細のない注文はNULLになり一致しない）
+        let similar_ids: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT candidate.id
+            FROM orders target
+            INNER JOIN orders candidate
+                ON candidate.customer_id = target.customer_id
+                AND candidate.id <> target.id
+                AND candidate.created_at BETWEEN target.created_at - INTERVAL ? SECOND
+                    AND target.created_at + INTERVAL ? SECOND
+            WHERE target.id = ?
+                AND candidate.status <> ?
+                AND (
+                    SELECT GROUP_CONCAT(CONCAT(book_id, ':', quantity) ORDER BY book_id)
+                    FROM order_lines
+                    WHERE order_id = candidate.id
+                ) = (
+                    SELECT GROUP_CONCAT(CONCAT(book_id, ':', quantity) ORDER BY book_id)
+                    FROM order_lines
+                    WHERE order_id = target.id
+                )
+            ORDER BY candidate.created_at
+            "#,
+        )
+        .bind(within.num_seconds())
+        .bind(within.num_seconds())
+        .bind(order_id.to_string())
+        .bind(OrderStatus::Cancelled.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("重複の可能性がある注文の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        let mut orders = Vec::with_capacity(similar_ids.len());
+        for id in similar_ids {
+            let similar_id = OrderId::from_string(&id).map_err(|e| {
+                RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+            })?;
+            if let Some(order) = self.find_by_id(similar_id).await? {
+                orders.push(order);
+            }
+        }
+        Ok(orders)
+    }
+
+    #[tracing::instrument(name = "db.orders.sum_daily_book_demand_since", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "orders", book_id = %book_id), err)]
+    async fn sum_daily_book_demand_since(
+        &self,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<BTreeMap<NaiveDate, u32>, RepositoryError> {
+        // 日付の区切りをセッションのタイムゾーンに依存させないよう、確定日時ごとに取得してUTCの日付で集計する
+        let rows = sqlx::query(
+            r#"
+            SELECT o.confirmed_at, CAST(SUM(ol.quantity) AS UNSIGNED) AS quantity
+            FROM orders o
+            INNER JOIN order_lines ol ON o.id = ol.order_id
+            WHERE ol.book_id = ?
+                AND o.status <> ?
+                AND o.confirmed_at >= ?
+            GROUP BY o.confirmed_at
+            "#,
+        )
+        .bind(book_id.to_string())
+        .bind(OrderStatus::Cancelled.to_string())
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("書籍の需要の集計に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut demand = BTreeMap::new();
+        for row in rows {
+            let confirmed_at: DateTime<Utc> = row.get("confirmed_at");
+            let quantity: u64 = row.get("quantity");
+            *demand.entry(confirmed_at.date_naive()).or_insert(0) += quantity as u32;
+        }
+        Ok(demand)
+    }
+
     fn next_identity(&self) -> OrderId {
         OrderId::new()
     }
 }
+
+/// 顧客の未完了の注文数を数える（プールとトランザクションの両方から使う）
+async fn count_open_orders<'e, E>(executor: E, customer_id: CustomerId) -> Result<u32, RepositoryError>
+where
+    E: Executor<'e, Database = MySql>,
+{
+    let count: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*)
+        FROM orders
+        WHERE customer_id = ? AND status IN (?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(customer_id.to_string())
+    .bind(OrderStatus::Pending.to_string())
+    .bind(OrderStatus::Confirmed.to_string())
+    .bind(OrderStatus::OnHold.to_string())
+    .bind(OrderStatus::AwaitingRelease.to_string())
+    .bind(OrderStatus::Waitlisted.to_string())
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        DatabaseError::QueryError(format!("未完了の注文数の取得に失敗しました: {}", e))
+    })
+    .map_err(RepositoryError::from)?;
+
+    Ok(count as u32)
+}
+
+/// 指定日時以降に作成された顧客の注文に含まれる書籍の合計数量を集計する（プールとトランザクションの両方から使う）
+async fn sum_book_quantity_since<'e, E>(
+    executor: E,
+    customer_id: CustomerId,
+    book_id: BookId,
+    since: DateTime<Utc>,
+) -> Result<u32, RepositoryError>
+where
+    E: Executor<'e, Database = MySql>,
+{
+    // SUMはDECIMALを返すため、符号なし整数にキャストして取得する
+    let quantity: u64 = sqlx::query_scalar(
+        r#"
+        SELECT CAST(COALESCE(SUM(ol.quantity), 0) AS UNSIGNED)
+        FROM orders o
+        INNER JOIN order_lines ol ON o.id = ol.order_id
+        WHERE o.customer_id = ?
+            AND ol.book_id = ?
+            AND o.status <> ?
+            AND o.created_at >= ?
+        "#,
+    )
+    .bind(customer_id.to_string())
+    .bind(book_id.to_string())
+    .bind(OrderStatus::Cancelled.to_string())
+    .bind(since)
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        DatabaseError::QueryError(format!("書籍の購入数量の集計に失敗しました: {}", e))
+    })
+    .map_err(RepositoryError::from)?;
+
+    Ok(quantity as u32)
+}
+
+/// 注文IDの主キーの重複か（他の操作が同じ注文を先に追加していた場合）
+/// 注文番号などの他の一意制約の重複は競合ではなく保存の失敗として扱う。
+/// MySQLのエラーは制約名を持たないため、メッセージのキー名（`for key 'orders.PRIMARY'`）で判定する
+fn is_primary_key_violation(error: &sqlx::Error) -> bool {
+    error.as_database_error().is_some_and(|db_error| {
+        db_error.is_unique_violation()
+            && (db_error.message().ends_with(".PRIMARY'")
+                || db_error.message().ends_with("'PRIMARY'"))
+    })
+}
+
+/// 楽観的排他制御の競合エラーを作成
+fn concurrency_conflict(order: &Order) -> RepositoryError {
+    RepositoryError::ConcurrencyConflict(format!(
+        "注文{}は読み込んだ後に他の操作が保存しています（読み込んだバージョン: {}）",
+        order.id(),
+        order.version()
+    ))
+}
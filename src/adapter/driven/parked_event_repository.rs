@@ -0,0 +1,104 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::late_event::ParkedEvent;
+use crate::domain::model::OrderId;
+use crate::domain::port::{ParkedEventRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL保留イベントリポジトリ
+/// MySQLデータベースを使用して遅延イベントポリシーで保留されたイベントを永続化する
+#[derive(Clone)]
+pub struct MySqlParkedEventRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlParkedEventRepository {
+    /// 新しいMySQL保留イベントリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlParkedEventRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ParkedEventRepository for MySqlParkedEventRepository {
+    #[tracing::instrument(name = "db.parked_events.park", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "parked_events", order_id = %event.order_id), err)]
+    async fn park(&self, event: &ParkedEvent) -> Result<(), RepositoryError> {
+        // (event_id, handler)の一意制約により、同じイベントの再保留は無視される
+        sqlx::query(
+            r#"
+            INSERT IGNORE INTO parked_events
+                (event_id, event_type, handler, order_id, correlation_id, order_status, expected_status, payload, parked_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(event.event_id.to_string())
+        .bind(&event.event_type)
+        .bind(&event.handler)
+        .bind(event.order_id.to_string())
+        .bind(event.correlation_id.to_string())
+        .bind(&event.order_status)
+        .bind(&event.expected_status)
+        .bind(event.payload.to_string())
+        .bind(event.parked_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("保留イベントの保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.parked_events.find_all", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "parked_events"), err)]
+    async fn find_all(&self) -> Result<Vec<ParkedEvent>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT event_id, event_type, handler, order_id, correlation_id, order_status, expected_status, payload, parked_at
+            FROM parked_events
+            ORDER BY parked_at ASC, id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("保留イベントの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            let event_id = Uuid::parse_str(row.get("event_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("イベントIDの解析に失敗しました: {}", e))
+            })?;
+            let order_id = OrderId::from_string(row.get("order_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+            })?;
+            let correlation_id = Uuid::parse_str(row.get("correlation_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("相関IDの解析に失敗しました: {}", e))
+            })?;
+            let payload = serde_json::from_str(row.get("payload")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("イベント本体の解析に失敗しました: {}", e))
+            })?;
+            events.push(ParkedEvent {
+                event_id,
+                event_type: row.get("event_type"),
+                handler: row.get("handler"),
+                order_id,
+                correlation_id,
+                order_status: row.get("order_status"),
+                expected_status: row.get("expected_status"),
+                payload,
+                parked_at: row.get::<DateTime<Utc>, _>("parked_at"),
+            });
+        }
+
+        Ok(events)
+    }
+}
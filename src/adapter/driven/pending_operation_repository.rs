@@ -0,0 +1,124 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::pending_operation::{PendingOperation, PendingOperationStatus};
+use crate::domain::port::{PendingOperationRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+const SELECT_COLUMNS: &str = "SELECT id, operation_type, aggregate_id, event_type, payload, status, attempts, last_error, created_at, updated_at, completed_at FROM pending_operations";
+
+/// MySQL再試行待ち操作リポジトリ
+/// MySQLデータベース（pending_operationsテーブル）に発行に失敗したイベントの再試行待ちの操作を保存する
+#[derive(Clone)]
+pub struct MySqlPendingOperationRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlPendingOperationRepository {
+    /// 新しいMySQL再試行待ち操作リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlPendingOperationRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から操作を構築する
+    fn operation_from_row(row: &MySqlRow) -> Result<PendingOperation, RepositoryError> {
+        Ok(PendingOperation {
+            id: Uuid::parse_str(row.get("id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("IDの解析に失敗しました: {}", e))
+            })?,
+            operation_type: row.get("operation_type"),
+            aggregate_id: row.get("aggregate_id"),
+            event_type: row.get("event_type"),
+            payload: row.get("payload"),
+            status: PendingOperationStatus::from_string(row.get("status")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("操作の状態の解析に失敗しました: {}", e))
+            })?,
+            attempts: row.get("attempts"),
+            last_error: row.get("last_error"),
+            created_at: row.get::<DateTime<Utc>, _>("created_at"),
+            updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+            completed_at: row.get::<Option<DateTime<Utc>>, _>("completed_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl PendingOperationRepository for MySqlPendingOperationRepository {
+    #[tracing::instrument(name = "db.pending_operations.save", skip_all, fields(db.system = "mysql", db.operation = "UPSERT", db.sql.table = "pending_operations", operation_id = %operation.id, status = %operation.status), err)]
+    async fn save(&self, operation: &PendingOperation) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO pending_operations
+                (id, operation_type, aggregate_id, event_type, payload, status, attempts, last_error, created_at, updated_at, completed_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                status = VALUES(status),
+                attempts = VALUES(attempts),
+                last_error = VALUES(last_error),
+                updated_at = VALUES(updated_at),
+                completed_at = VALUES(completed_at)
+            "#,
+        )
+        .bind(operation.id.to_string())
+        .bind(&operation.operation_type)
+        .bind(&operation.aggregate_id)
+        .bind(&operation.event_type)
+        .bind(&operation.payload)
+        .bind(operation.status.to_string())
+        .bind(operation.attempts)
+        .bind(&operation.last_error)
+        .bind(operation.created_at)
+        .bind(operation.updated_at)
+        .bind(operation.completed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("再試行待ちの操作の保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.pending_operations.find_by_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "pending_operations", operation_id = %id), err)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PendingOperation>, RepositoryError> {
+        let row = sqlx::query(&format!("{} WHERE id = ?", SELECT_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("再試行待ちの操作の取得に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        row.as_ref().map(Self::operation_from_row).transpose()
+    }
+
+    #[tracing::instrument(name = "db.pending_operations.find_pending", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "pending_operations", limit = limit), err)]
+    async fn find_pending(&self, limit: u32) -> Result<Vec<PendingOperation>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE status = ? ORDER BY created_at ASC, id ASC LIMIT ?",
+            SELECT_COLUMNS
+        ))
+        .bind(PendingOperationStatus::Pending.to_string())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("再試行待ちの操作の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::operation_from_row).collect()
+    }
+}
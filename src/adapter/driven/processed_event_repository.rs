@@ -0,0 +1,78 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::port::{ProcessedEventRepository, RepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::{MySql, Pool};
+
+/// MySQL処理済みイベントリポジトリ
+/// MySQLデータベース（processed_eventsテーブル）でハンドラーごとの処理済みイベントを管理する
+#[derive(Clone)]
+pub struct MySqlProcessedEventRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlProcessedEventRepository {
+    /// 新しいMySQL処理済みイベントリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlProcessedEventRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProcessedEventRepository for MySqlProcessedEventRepository {
+    #[tracing::instrument(name = "db.processed_events.is_processed", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "processed_events", event_id = %event_id, handler_name = %handler_name), err)]
+    async fn is_processed(
+        &self,
+        event_id: Uuid,
+        handler_name: &str,
+    ) -> Result<bool, RepositoryError> {
+        let row =
+            sqlx::query("SELECT 1 FROM processed_events WHERE event_id = ? AND handler_name = ?")
+                .bind(event_id.to_string())
+                .bind(handler_name)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!(
+                        "処理済みイベントの取得に失敗しました: {}",
+                        e
+                    ))
+                })
+                .map_err(RepositoryError::from)?;
+
+        Ok(row.is_some())
+    }
+
+    #[tracing::instrument(name = "db.processed_events.mark_processed", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "processed_events", event_id = %event_id, handler_name = %handler_name), err)]
+    async fn mark_processed(
+        &self,
+        event_id: Uuid,
+        handler_name: &str,
+        processed_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        // 再配信で同じイベントを記録しようとした場合は最初の記録を残す
+        sqlx::query(
+            "INSERT IGNORE INTO processed_events (event_id, handler_name, processed_at) VALUES (?, ?, ?)",
+        )
+        .bind(event_id.to_string())
+        .bind(handler_name)
+        .bind(processed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("処理済みイベントの保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+}
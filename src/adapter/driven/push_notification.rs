@@ -0,0 +1,104 @@
+use crate::domain::model::DeviceToken;
+use crate::domain::port::{Logger, PushNotificationError, PushNotificationPort};
+use async_trait::async_trait;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// FCM形式のプッシュ通知アダプター（スタブ実装）
+/// FCM HTTP v1 APIと同じ形式のメッセージを組み立ててログに出力する
+/// 実際の配信ではFirebase Admin SDKやHTTP v1 APIの呼び出しに置き換える想定
+pub struct FcmPushNotificationAdapter {
+    logger: Arc<dyn Logger>,
+}
+
+impl FcmPushNotificationAdapter {
+    pub fn new(logger: Arc<dyn Logger>) -> Self {
+        Self { logger }
+    }
+}
+
+/// FCM HTTP v1 API形式のトピック宛てメッセージを組み立てる
+/// FCMのdataフィールドは文字列値のみを受け付けるため、ペイロードはJSON文字列のまま格納する
+fn build_topic_message(topic: &str, payload: &str) -> serde_json::Value {
+    json!({
+        "message": {
+            "topic": topic,
+            "data": {
+                "payload": payload,
+            },
+        },
+    })
+}
+
+#[async_trait]
+impl PushNotificationPort for FcmPushNotificationAdapter {
+    async fn subscribe_to_topic(
+        &self,
+        token: &DeviceToken,
+        topic: &str,
+    ) -> Result<(), PushNotificationError> {
+        let mut context = HashMap::new();
+        context.insert("topic".to_string(), topic.to_string());
+        context.insert("token".to_string(), token.to_string());
+        self.logger.info(
+            "FcmPushNotificationAdapter",
+            "Subscribed device to topic",
+            None,
+            Some(context),
+        );
+        Ok(())
+    }
+
+    async fn unsubscribe_from_topic(
+        &self,
+        token: &DeviceToken,
+        topic: &str,
+    ) -> Result<(), PushNotificationError> {
+        let mut context = HashMap::new();
+        context.insert("topic".to_string(), topic.to_string());
+        context.insert("token".to_string(), token.to_string());
+        self.logger.info(
+            "FcmPushNotificationAdapter",
+            "Unsubscribed device from topic",
+            None,
+            Some(context),
+        );
+        Ok(())
+    }
+
+    async fn publish_to_topic(
+        &self,
+        topic: &str,
+        payload: &str,
+    ) -> Result<(), PushNotificationError> {
+        let mut context = HashMap::new();
+        context.insert(
+            "message".to_string(),
+            build_topic_message(topic, payload).to_string(),
+        );
+        self.logger.info(
+            "FcmPushNotificationAdapter",
+            "Published push notification to topic",
+            None,
+            Some(context),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_topic_message() {
+        let message = build_topic_message("customer_123", r#"{"status":"Shipped"}"#);
+
+        assert_eq!(message["message"]["topic"], "customer_123");
+        assert_eq!(
+            message["message"]["data"]["payload"],
+            r#"{"status":"Shipped"}"#
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::port::{RepositoryError, SagaCompensationRepository};
+use crate::domain::saga_metrics::SagaCompensation;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+// MySQL関連のインポート
+use sqlx::{MySql, Pool, Row};
+
+/// MySQLサーガ補償リポジトリ
+/// MySQLデータベース（saga_compensationsテーブル）に補償の記録を保存する
+#[derive(Clone)]
+pub struct MySqlSagaCompensationRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlSagaCompensationRepository {
+    /// 新しいMySQLサーガ補償リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlSagaCompensationRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SagaCompensationRepository for MySqlSagaCompensationRepository {
+    #[tracing::instrument(name = "db.saga_compensations.record", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "saga_compensations", order_id = %compensation.order_id, failed_step = %compensation.failed_step), err)]
+    async fn record(&self, compensation: &SagaCompensation) -> Result<(), RepositoryError> {
+        // 同じ失敗イベントの記録は無視する
+        sqlx::query(
+            "INSERT IGNORE INTO saga_compensations (event_id, order_id, failed_step, failure_reason, occurred_at) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(compensation.event_id.to_string())
+        .bind(compensation.order_id.to_string())
+        .bind(&compensation.failed_step)
+        .bind(&compensation.failure_reason)
+        .bind(compensation.occurred_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("補償の記録に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.saga_compensations.count_by_failed_step", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "saga_compensations"), err)]
+    async fn count_by_failed_step(&self) -> Result<BTreeMap<String, u64>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT failed_step, COUNT(*) AS compensation_count FROM saga_compensations GROUP BY failed_step",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("補償の件数の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                (
+                    row.get::<String, _>("failed_step"),
+                    row.get::<i64, _>("compensation_count") as u64,
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,198 @@
+use crate::domain::event_schema::EventSchema;
+use crate::domain::port::{SchemaRegistry, SchemaRegistryError};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// レジストリの応答を待つ時間
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Confluent Schema RegistryのREST APIのメディアタイプ
+const SCHEMA_REGISTRY_CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+/// Confluent Schema Registry互換のスキーマレジストリ
+/// サブジェクトにJSONスキーマ（schemaType: JSON）を登録する
+/// 互換性はレジストリに設定した互換性レベルで検証し、互換性がない場合は登録前に拒否する
+pub struct HttpSchemaRegistry {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+/// 互換性の検証結果（verbose=trueの場合は理由を含む）
+#[derive(Deserialize)]
+struct CompatibilityResponse {
+    is_compatible: bool,
+    #[serde(default)]
+    messages: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct RegisterResponse {
+    id: u32,
+}
+
+impl HttpSchemaRegistry {
+    /// 新しいスキーマレジストリのクライアントを作成
+    ///
+    /// # Arguments
+    /// * `base_url` - レジストリのURL（例: http://localhost:8081）
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn request_body(schema: &EventSchema) -> serde_json::Value {
+        serde_json::json!({
+            "schemaType": "JSON",
+            "schema": schema.schema.to_string(),
+        })
+    }
+}
+
+fn unavailable(error: reqwest::Error) -> SchemaRegistryError {
+    SchemaRegistryError::Unavailable(error.to_string())
+}
+
+#[async_trait]
+impl SchemaRegistry for HttpSchemaRegistry {
+    #[tracing::instrument(name = "schema_registry.register", skip_all, fields(subject = %subject, event.version = schema.version), err)]
+    async fn register(&self, subject: &str, schema: &EventSchema) -> Result<u32, SchemaRegistryError> {
+        let compatibility = self
+            .client
+            .post(format!(
+                "{}/compatibility/subjects/{}/versions/latest?verbose=true",
+                self.base_url, subject
+            ))
+            .header(reqwest::header::CONTENT_TYPE, SCHEMA_REGISTRY_CONTENT_TYPE)
+            .json(&Self::request_body(schema))
+            .send()
+            .await
+            .map_err(unavailable)?;
+        // サブジェクトが未登録の場合（404）は最初のバージョンとして登録する
+        if compatibility.status() != reqwest::StatusCode::NOT_FOUND {
+            let compatibility: CompatibilityResponse = compatibility
+                .error_for_status()
+                .map_err(unavailable)?
+                .json()
+                .await
+                .map_err(unavailable)?;
+            if !compatibility.is_compatible {
+                return Err(SchemaRegistryError::Incompatible {
+                    subject: subject.to_string(),
+                    violations: compatibility.messages,
+                });
+            }
+        }
+
+        let registered: RegisterResponse = self
+            .client
+            .post(format!("{}/subjects/{}/versions", self.base_url, subject))
+            .header(reqwest::header::CONTENT_TYPE, SCHEMA_REGISTRY_CONTENT_TYPE)
+            .json(&Self::request_body(schema))
+            .send()
+            .await
+            .map_err(unavailable)?
+            .error_for_status()
+            .map_err(unavailable)?
+            .json()
+            .await
+            .map_err(unavailable)?;
+        Ok(registered.id)
+    }
+}
+
+/// メモリ上のスキーマレジストリ（テスト・ローカル開発用）
+/// 互換性はEventSchema::check_compatible_withで、登録済みの最新のスキーマに対して検証する
+#[derive(Default)]
+pub struct InMemorySchemaRegistry {
+    state: Mutex<InMemorySchemaRegistryState>,
+}
+
+#[derive(Default)]
+struct InMemorySchemaRegistryState {
+    next_id: u32,
+    /// サブジェクトごとの登録済みのスキーマ（登録順）
+    subjects: HashMap<String, Vec<(u32, EventSchema)>>,
+}
+
+impl InMemorySchemaRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// サブジェクトに登録済みのスキーマを登録順に取得
+    pub async fn versions(&self, subject: &str) -> Vec<EventSchema> {
+        self.state
+            .lock()
+            .await
+            .subjects
+            .get(subject)
+            .map(|versions| versions.iter().map(|(_, schema)| schema.clone()).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl SchemaRegistry for InMemorySchemaRegistry {
+    async fn register(&self, subject: &str, schema: &EventSchema) -> Result<u32, SchemaRegistryError> {
+        let mut state = self.state.lock().await;
+        let versions = state.subjects.entry(subject.to_string()).or_default();
+        if let Some((id, _)) = versions
+            .iter()
+            .find(|(_, registered)| registered.schema == schema.schema)
+        {
+            return Ok(*id);
+        }
+        if let Some((_, latest)) = versions.last() {
+            schema
+                .check_compatible_with(latest)
+                .map_err(|violations| SchemaRegistryError::Incompatible {
+                    subject: subject.to_string(),
+                    violations,
+                })?;
+        }
+
+        state.next_id += 1;
+        let id = state.next_id;
+        state
+            .subjects
+            .entry(subject.to_string())
+            .or_default()
+            .push((id, schema.clone()));
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event_schema::event_schemas;
+
+    #[tokio::test]
+    async fn test_in_memory_registry_reuses_ids_and_rejects_incompatible_versions() {
+        let registry = InMemorySchemaRegistry::new();
+        let schema = event_schemas().remove(0);
+        let subject = schema.subject("bookstore.events");
+        assert_eq!(subject, "bookstore.events-OrderConfirmed");
+
+        let id = registry.register(&subject, &schema).await.unwrap();
+        assert_eq!(registry.register(&subject, &schema).await.unwrap(), id);
+
+        let mut incompatible = schema.clone();
+        incompatible.version = 2;
+        incompatible.schema["properties"]["event_data"]["properties"]["order_id"] =
+            serde_json::json!({ "type": "integer" });
+        assert!(matches!(
+            registry.register(&subject, &incompatible).await,
+            Err(SchemaRegistryError::Incompatible { .. })
+        ));
+        assert_eq!(registry.versions(&subject).await, vec![schema]);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::port::{
+    CarrierDeliveryResult, CarrierError, DeliveryResultCallback, Shipment, ShippingCarrierPort,
+};
+use async_trait::async_trait;
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 配送業者のシミュレーター
+/// 引き渡された荷物を設定した時間だけ待ってから、設定した確率で配達失敗・それ以外は配達完了として通知する
+/// 実際の配送業者と連携せずに、配達失敗の補償まで含めたサーガをデモするために使う
+pub struct SimulatedCarrierAdapter {
+    delivery_delay: Duration,
+    failure_rate: f64,
+}
+
+impl SimulatedCarrierAdapter {
+    /// 新しい配送業者のシミュレーターを作成
+    ///
+    /// # Arguments
+    /// * `delivery_delay` - 引き渡しから配達の結果を通知するまでの時間
+    /// * `failure_rate` - 配達失敗として通知する確率（0.0〜1.0）
+    pub fn new(delivery_delay: Duration, failure_rate: f64) -> Self {
+        Self {
+            delivery_delay,
+            failure_rate: failure_rate.clamp(0.0, 1.0),
+        }
+    }
+}
+
+#[async_trait]
+impl ShippingCarrierPort for SimulatedCarrierAdapter {
+    async fn hand_over(
+        &self,
+        shipment: Shipment,
+        callback: Arc<dyn DeliveryResultCallback>,
+    ) -> Result<(), CarrierError> {
+        let delivery_delay = self.delivery_delay;
+        let failed = rand::thread_rng().gen_bool(self.failure_rate);
+        tokio::spawn(async move {
+            tokio::time::sleep(delivery_delay).await;
+            let result = if failed {
+                CarrierDeliveryResult::Failed(
+                    "配送業者が配達できませんでした（シミュレーション）".to_string(),
+                )
+            } else {
+                CarrierDeliveryResult::Delivered
+            };
+            callback.on_delivery_result(shipment, result).await;
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{OrderId, ShippingAddress};
+    use tokio::sync::Mutex;
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct RecordingCallback {
+        results: Mutex<Vec<CarrierDeliveryResult>>,
+    }
+
+    #[async_trait]
+    impl DeliveryResultCallback for RecordingCallback {
+        async fn on_delivery_result(&self, _shipment: Shipment, result: CarrierDeliveryResult) {
+            self.results.lock().await.push(result);
+        }
+    }
+
+    fn shipment() -> Shipment {
+        Shipment {
+            order_id: OrderId::new(),
+            shipping_address: ShippingAddress::new(
+                "1500001".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "神宮前1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+            shipped_event_id: Uuid::new_v4(),
+            correlation_id: Uuid::new_v4(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_result_after_delay_by_failure_rate() {
+        let callback = Arc::new(RecordingCallback::default());
+
+        let carrier = SimulatedCarrierAdapter::new(Duration::from_millis(50), 0.0);
+        carrier.hand_over(shipment(), callback.clone()).await.unwrap();
+        assert!(callback.results.lock().await.is_empty());
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert_eq!(
+            *callback.results.lock().await,
+            vec![CarrierDeliveryResult::Delivered]
+        );
+
+        let carrier = SimulatedCarrierAdapter::new(Duration::ZERO, 1.0);
+        carrier.hand_over(shipment(), callback.clone()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(matches!(
+            callback.results.lock().await[1],
+            CarrierDeliveryResult::Failed(_)
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::domain::model::{Money, OrderId};
+use crate::domain::port::{PaymentError, PaymentPort};
+use async_trait::async_trait;
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+/// 決済代行のシミュレーター
+/// 設定した時間だけ待ってから、設定した確率で請求を拒否・それ以外は請求として記録する
+/// 実際の決済代行と連携せずに、決済の失敗・タイムアウトの補償まで含めたサーガをデモするために使う
+pub struct SimulatedPaymentGateway {
+    latency: Duration,
+    failure_rate: f64,
+    /// 請求済みの注文と請求額
+    charges: Mutex<HashMap<OrderId, i64>>,
+}
+
+impl SimulatedPaymentGateway {
+    /// 新しい決済代行のシミュレーターを作成
+    ///
+    /// # Arguments
+    /// * `latency` - 請求の応答までの時間
+    /// * `failure_rate` - 請求を拒否する確率（0.0〜1.0）
+    pub fn new(latency: Duration, failure_rate: f64) -> Self {
+        Self {
+            latency,
+            failure_rate: failure_rate.clamp(0.0, 1.0),
+            charges: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 注文に請求した金額（未請求・返金済みの場合はNone）
+    pub fn charged_amount(&self, order_id: OrderId) -> Option<i64> {
+        self.charges
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&order_id)
+            .copied()
+    }
+}
+
+#[async_trait]
+impl PaymentPort for SimulatedPaymentGateway {
+    async fn charge(&self, order_id: OrderId, amount: &Money) -> Result<(), PaymentError> {
+        tokio::time::sleep(self.latency).await;
+        if rand::thread_rng().gen_bool(self.failure_rate) {
+            return Err(PaymentError::Declined(
+                "カード会社が請求を拒否しました（シミュレーション）".to_string(),
+            ));
+        }
+        self.charges
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(order_id)
+            .or_insert(amount.amount());
+        Ok(())
+    }
+
+    async fn refund(&self, order_id: OrderId) -> Result<(), PaymentError> {
+        self.charges
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&order_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_charges_once_per_order_and_refunds_by_failure_rate() {
+        let gateway = SimulatedPaymentGateway::new(Duration::ZERO, 0.0);
+        let order_id = OrderId::new();
+        gateway.charge(order_id, &Money::jpy(1200)).await.unwrap();
+        // 同じ注文への再請求は最初の請求のまま
+        gateway.charge(order_id, &Money::jpy(3000)).await.unwrap();
+        assert_eq!(gateway.charged_amount(order_id), Some(1200));
+
+        gateway.refund(order_id).await.unwrap();
+        assert_eq!(gateway.charged_amount(order_id), None);
+        // 請求のない注文の返金は何もしない
+        gateway.refund(OrderId::new()).await.unwrap();
+
+        let gateway = SimulatedPaymentGateway::new(Duration::ZERO, 1.0);
+        let result = gateway.charge(order_id, &Money::jpy(1200)).await;
+        assert!(matches!(result, Err(PaymentError::Declined(_))));
+        assert_eq!(gateway.charged_amount(order_id), None);
+    }
+}
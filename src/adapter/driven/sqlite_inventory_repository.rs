@@ -0,0 +1,487 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::invariant::describe_violations;
+use crate::domain::inventory_snapshot::{InventorySnapshot, InventorySnapshotEntry, StockMovement};
+use crate::domain::inventory_valuation::StockValuation;
+use crate::domain::model::{BookId, Inventory, Money};
+use crate::domain::port::{InventoryRepository, RepositoryError};
+use crate::domain::reconciliation::LedgerDrift;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Executor, Pool, Row, Sqlite};
+
+/// inventoriesテーブルから読み込む列
+const INVENTORY_COLUMNS: &str = "book_id, quantity_on_hand, release_date, frozen, waitlist_enabled";
+
+/// SQLite在庫リポジトリ
+/// 外部のデータベースを用意せずに動かすデモ・テスト用に、SQLiteを使用して在庫を永続化する
+/// 振る舞い（在庫変動ログの記録・並び順）はMySqlInventoryRepositoryと同じ
+#[derive(Clone)]
+pub struct SqliteInventoryRepository {
+    pool: Pool<Sqlite>,
+    /// 保存・読み込み時に在庫の不変条件を検証するかどうか
+    invariant_checks: bool,
+}
+
+impl SqliteInventoryRepository {
+    /// 新しいSQLite在庫リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - SqliteDatabaseで作成したSQLiteの接続プール
+    ///
+    /// # Returns
+    /// * SqliteInventoryRepositoryのインスタンス
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self {
+            pool,
+            invariant_checks: false,
+        }
+    }
+
+    /// 不変条件の検証の有効・無効を設定したリポジトリを作成
+    /// 有効な場合は違反する在庫の保存を拒否し、違反する在庫の読み込みを警告ログに残す
+    ///
+    /// # Arguments
+    /// * `enabled` - 不変条件を検証するかどうか
+    pub fn with_invariant_checks(mut self, enabled: bool) -> Self {
+        self.invariant_checks = enabled;
+        self
+    }
+
+    /// 保存する在庫の不変条件を検証
+    fn ensure_invariants(&self, inventory: &Inventory) -> Result<(), RepositoryError> {
+        if !self.invariant_checks {
+            return Ok(());
+        }
+        let violations = inventory.check_invariants();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(RepositoryError::OperationFailed(format!(
+                "不変条件に違反する在庫は保存できません: {}",
+                describe_violations(&violations)
+            )))
+        }
+    }
+
+    /// 在庫変動ログに記録する（在庫の更新と同じトランザクションで実行）
+    async fn record_movement<'e, E>(
+        executor: E,
+        book_id: BookId,
+        quantity_delta: i64,
+        quantity_on_hand: u32,
+    ) -> Result<(), RepositoryError>
+    where
+        E: Executor<'e, Database = Sqlite>,
+    {
+        sqlx::query(
+            "INSERT INTO inventory_movements (book_id, quantity_delta, quantity_on_hand, recorded_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(book_id.to_string())
+        .bind(quantity_delta)
+        .bind(quantity_on_hand)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫変動の記録に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    /// inventoriesテーブルの行から在庫を再構築する
+    fn inventory_from_row(&self, row: &SqliteRow) -> Result<Inventory, RepositoryError> {
+        let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+        })?;
+
+        let inventory = Inventory::new(book_id, row.get::<u32, _>("quantity_on_hand"))
+            .with_release_date(row.get::<Option<NaiveDate>, _>("release_date"))
+            .with_frozen(row.get::<bool, _>("frozen"))
+            .with_waitlist_enabled(row.get::<bool, _>("waitlist_enabled"));
+        Ok(self.warn_invariant_violations(inventory))
+    }
+
+    /// 読み込んだ在庫の不変条件を検証（違反があっても読み込みは続ける）
+    fn warn_invariant_violations(&self, inventory: Inventory) -> Inventory {
+        if self.invariant_checks {
+            let violations = inventory.check_invariants();
+            if !violations.is_empty() {
+                tracing::warn!(
+                    book_id = %inventory.book_id(),
+                    violations = %describe_violations(&violations),
+                    "loaded inventory violates invariants"
+                );
+            }
+        }
+        inventory
+    }
+}
+
+#[async_trait]
+impl InventoryRepository for SqliteInventoryRepository {
+    #[tracing::instrument(name = "db.inventories.save", skip_all, fields(db.system = "sqlite", db.operation = "INSERT", db.sql.table = "inventories", book_id = %inventory.book_id()), err)]
+    async fn save(&self, inventory: &Inventory) -> Result<(), RepositoryError> {
+        self.ensure_invariants(inventory)?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 在庫変動を算出するため、更新前の在庫数を読む（接続は1つのため、他の書き込みとは重ならない）
+        let previous: Option<i64> =
+            sqlx::query_scalar("SELECT quantity_on_hand FROM inventories WHERE book_id = ?")
+                .bind(inventory.book_id().to_string())
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("在庫の取得に失敗しました: {}", e)))
+                .map_err(RepositoryError::from)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO inventories (book_id, quantity_on_hand, release_date, frozen, waitlist_enabled)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT (book_id) DO UPDATE SET
+                quantity_on_hand = excluded.quantity_on_hand,
+                release_date = excluded.release_date,
+                frozen = excluded.frozen,
+                waitlist_enabled = excluded.waitlist_enabled
+            "#,
+        )
+        .bind(inventory.book_id().to_string())
+        .bind(inventory.quantity_on_hand())
+        .bind(inventory.release_date())
+        .bind(inventory.is_frozen())
+        .bind(inventory.is_waitlist_enabled())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫の保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        // 在庫数が変わった場合（新規登録を含む）は在庫変動ログに記録する
+        let quantity_delta = i64::from(inventory.quantity_on_hand()) - previous.unwrap_or(0);
+        if previous.is_none() || quantity_delta != 0 {
+            Self::record_movement(
+                &mut *tx,
+                inventory.book_id(),
+                quantity_delta,
+                inventory.quantity_on_hand(),
+            )
+            .await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.inventories.find_by_book_id", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "inventories", book_id = %book_id), err)]
+    async fn find_by_book_id(&self, book_id: BookId) -> Result<Option<Inventory>, RepositoryError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM inventories WHERE book_id = ?",
+            INVENTORY_COLUMNS
+        ))
+        .bind(book_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        row.map(|row| self.inventory_from_row(&row)).transpose()
+    }
+
+    #[tracing::instrument(name = "db.inventories.find_all", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "inventories"), err)]
+    async fn find_all(&self) -> Result<Vec<Inventory>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM inventories ORDER BY book_id ASC",
+            INVENTORY_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫一覧の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter()
+            .map(|row| self.inventory_from_row(row))
+            .collect()
+    }
+
+    #[tracing::instrument(name = "db.inventories.find_by_max_quantity", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "inventories", max_quantity = max_quantity), err)]
+    async fn find_by_max_quantity(
+        &self,
+        max_quantity: u32,
+    ) -> Result<Vec<Inventory>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM inventories WHERE quantity_on_hand <= ? ORDER BY book_id ASC",
+            INVENTORY_COLUMNS
+        ))
+        .bind(max_quantity)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("在庫フィルタリングの取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter()
+            .map(|row| self.inventory_from_row(row))
+            .collect()
+    }
+
+    #[tracing::instrument(name = "db.inventories.find_ledger_drifts", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "inventories"), err)]
+    async fn find_ledger_drifts(&self) -> Result<Vec<LedgerDrift>, RepositoryError> {
+        // MySQL版と同じく、変動が1件もない書籍は内部結合で対象外にする
+        let rows = sqlx::query(
+            r#"
+            SELECT i.book_id, i.quantity_on_hand, SUM(m.quantity_delta) AS ledger_quantity
+            FROM inventories i
+            INNER JOIN inventory_movements m ON m.book_id = i.book_id
+            GROUP BY i.book_id, i.quantity_on_hand
+            HAVING SUM(m.quantity_delta) <> i.quantity_on_hand
+            ORDER BY i.book_id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("在庫変動ログとの照合に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        let mut drifts = Vec::with_capacity(rows.len());
+        for row in rows {
+            let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+
+            drifts.push(LedgerDrift {
+                book_id,
+                quantity_on_hand: row.get::<u32, _>("quantity_on_hand"),
+                ledger_quantity: row.get::<i64, _>("ledger_quantity"),
+            });
+        }
+
+        Ok(drifts)
+    }
+
+    #[tracing::instrument(name = "db.inventories.freeze_ledger_drift", skip_all, fields(db.system = "sqlite", db.operation = "UPDATE", db.sql.table = "inventories", book_id = %book_id), err)]
+    async fn freeze_ledger_drift(&self, book_id: BookId) -> Result<bool, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        let quantity_on_hand: Option<u32> =
+            sqlx::query_scalar("SELECT quantity_on_hand FROM inventories WHERE book_id = ?")
+                .bind(book_id.to_string())
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("在庫の取得に失敗しました: {}", e)))
+                .map_err(RepositoryError::from)?;
+        let Some(quantity_on_hand) = quantity_on_hand else {
+            return Ok(false);
+        };
+
+        let ledger_quantity: Option<i64> = sqlx::query_scalar(
+            "SELECT SUM(quantity_delta) FROM inventory_movements WHERE book_id = ?",
+        )
+        .bind(book_id.to_string())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("在庫変動ログの集計に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+        let drift = match ledger_quantity {
+            Some(ledger_quantity) => i64::from(quantity_on_hand) - ledger_quantity,
+            None => 0,
+        };
+        if drift == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query("UPDATE inventories SET frozen = TRUE WHERE book_id = ?")
+            .bind(book_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("在庫の凍結に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(true)
+    }
+
+    #[tracing::instrument(name = "db.inventories.find_stock_valuations", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "inventories"), err)]
+    async fn find_stock_valuations(&self) -> Result<Vec<StockValuation>, RepositoryError> {
+        // 価格が登録されていない書籍も在庫数を集計できるよう、書籍カタログの価格を外部結合する
+        let rows = sqlx::query(
+            r#"
+            SELECT i.book_id, i.quantity_on_hand, p.unit_price_amount, p.unit_price_currency
+            FROM inventories i
+            LEFT JOIN book_prices p ON p.book_id = i.book_id
+            ORDER BY i.book_id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫評価の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut valuations = Vec::with_capacity(rows.len());
+        for row in rows {
+            let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            let unit_price = match (
+                row.get::<Option<i64>, _>("unit_price_amount"),
+                row.get::<Option<String>, _>("unit_price_currency"),
+            ) {
+                (Some(amount), Some(currency)) => {
+                    Some(Money::new(amount, currency).map_err(|e| {
+                        RepositoryError::FetchFailed(format!("金額の構築に失敗しました: {}", e))
+                    })?)
+                }
+                _ => None,
+            };
+
+            valuations.push(StockValuation {
+                book_id,
+                quantity_on_hand: row.get::<u32, _>("quantity_on_hand"),
+                unit_price,
+            });
+        }
+
+        Ok(valuations)
+    }
+
+    #[tracing::instrument(name = "db.inventories.take_snapshot", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "inventories"), err)]
+    async fn take_snapshot(
+        &self,
+        taken_at: DateTime<Utc>,
+    ) -> Result<InventorySnapshot, RepositoryError> {
+        // SQLiteのトランザクションは直列化されるため、versionと在庫数は同じ時点の値になる
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        let version: i64 =
+            sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM inventory_movements")
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!(
+                        "在庫変動のversionの取得に失敗しました: {}",
+                        e
+                    ))
+                })
+                .map_err(RepositoryError::from)?;
+
+        let rows = sqlx::query(
+            "SELECT book_id, quantity_on_hand, frozen FROM inventories ORDER BY book_id ASC",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("在庫スナップショットの取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in rows {
+            let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            entries.push(InventorySnapshotEntry {
+                book_id,
+                quantity_on_hand: row.get::<u32, _>("quantity_on_hand"),
+                frozen: row.get::<bool, _>("frozen"),
+            });
+        }
+
+        Ok(InventorySnapshot {
+            version: version as u64,
+            taken_at,
+            entries,
+        })
+    }
+
+    #[tracing::instrument(name = "db.inventory_movements.find_since", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "inventory_movements", since_version = since_version), err)]
+    async fn find_movements_since(
+        &self,
+        since_version: u64,
+        limit: usize,
+    ) -> Result<Vec<StockMovement>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT version, book_id, quantity_delta, quantity_on_hand, recorded_at
+            FROM inventory_movements
+            WHERE version > ?
+            ORDER BY version ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(since_version as i64)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("在庫変動の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut movements = Vec::with_capacity(rows.len());
+        for row in rows {
+            let book_id = BookId::from_string(row.get("book_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            movements.push(StockMovement {
+                version: row.get::<i64, _>("version") as u64,
+                book_id,
+                quantity_delta: row.get::<i64, _>("quantity_delta"),
+                quantity_on_hand: row.get::<u32, _>("quantity_on_hand"),
+                recorded_at: row.get::<DateTime<Utc>, _>("recorded_at"),
+            });
+        }
+
+        Ok(movements)
+    }
+}
@@ -0,0 +1,995 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::address_normalizer::AddressInput;
+use crate::domain::invariant::describe_violations;
+use crate::domain::model::{
+    BookId, BookSpec, CustomerId, FulfillmentType, HoldReason, LineAttribute, Money, Order,
+    OrderId, OrderLine, OrderNumber, OrderStatus, Recipient, SalesChannel, ShippingAddress,
+    TrackingToken,
+};
+use crate::domain::order_totals::OrderTotals;
+use crate::domain::port::{OrderRepository, RepositoryError};
+use crate::domain::purchase_policy::{PurchaseCheck, PurchasePolicy};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use sqlx::sqlite::{SqliteConnection, SqliteRow};
+use sqlx::{Executor, Pool, QueryBuilder, Row, Sqlite};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// ordersテーブルから読み込む列
+const ORDER_COLUMNS: &str = "id, order_number, customer_id, sales_channel, status, confirmed_at, shipped_at, delivered_at, estimated_delivery_date, hold_reason, event_sequence, saga_correlation_id, confirmed_totals, tracking_token, version, postal_code, prefecture, city, street, building, original_shipping_address, recipient_name, recipient_phone";
+
+/// SQLite注文リポジトリ
+/// 外部のデータベースを用意せずに動かすデモ・テスト用に、SQLiteを使用して注文を永続化する
+/// 振る舞い（楽観的排他制御・並び順・集計）はMySqlOrderRepositoryと同じ
+#[derive(Clone)]
+pub struct SqliteOrderRepository {
+    pool: Pool<Sqlite>,
+    /// 保存・読み込み時に注文の不変条件を検証するかどうか
+    invariant_checks: bool,
+}
+
+impl SqliteOrderRepository {
+    /// 新しいSQLite注文リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - SqliteDatabaseで作成したSQLiteの接続プール
+    ///
+    /// # Returns
+    /// * SqliteOrderRepositoryのインスタンス
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self {
+            pool,
+            invariant_checks: false,
+        }
+    }
+
+    /// 不変条件の検証の有効・無効を設定したリポジトリを作成
+    /// 有効な場合は違反する注文の保存を拒否し、違反する注文の読み込みを警告ログに残す
+    ///
+    /// # Arguments
+    /// * `enabled` - 不変条件を検証するかどうか
+    pub fn with_invariant_checks(mut self, enabled: bool) -> Self {
+        self.invariant_checks = enabled;
+        self
+    }
+
+    /// 保存する注文の不変条件を検証
+    fn ensure_invariants(&self, order: &Order) -> Result<(), RepositoryError> {
+        if !self.invariant_checks {
+            return Ok(());
+        }
+        let violations = order.check_invariants();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(RepositoryError::OperationFailed(format!(
+                "不変条件に違反する注文は保存できません: {}",
+                describe_violations(&violations)
+            )))
+        }
+    }
+
+    /// 読み込んだ注文の不変条件を検証（違反があっても読み込みは続ける）
+    fn warn_invariant_violations(&self, order: Order) -> Order {
+        if self.invariant_checks {
+            let violations = order.check_invariants();
+            if !violations.is_empty() {
+                tracing::warn!(
+                    order_id = %order.id(),
+                    violations = %describe_violations(&violations),
+                    "loaded order violates invariants"
+                );
+            }
+        }
+        order
+    }
+
+    /// 注文IDを指定して注文を取得する
+    async fn find_by_ids(&self, order_ids: &[String]) -> Result<Vec<Order>, RepositoryError> {
+        if order_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT {} FROM orders WHERE id IN (",
+            ORDER_COLUMNS
+        ));
+        let mut separated = query.separated(", ");
+        for order_id in order_ids {
+            separated.push_bind(order_id);
+        }
+        separated.push_unseparated(")");
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("注文の取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+        self.build_orders_from_rows(rows).await
+    }
+
+    /// 注文の明細をorder_linesテーブルとorder_line_attributesテーブルから取得する
+    async fn load_order_lines(
+        &self,
+        order_ids: &[String],
+    ) -> Result<HashMap<String, Vec<OrderLine>>, RepositoryError> {
+        let mut lines: HashMap<String, Vec<OrderLine>> = HashMap::new();
+        if order_ids.is_empty() {
+            return Ok(lines);
+        }
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT order_id, book_id, attribute_key, attribute_value FROM order_line_attributes WHERE order_id IN (",
+        );
+        let mut separated = query.separated(", ");
+        for order_id in order_ids {
+            separated.push_bind(order_id);
+        }
+        separated.push_unseparated(") ORDER BY id");
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("明細属性の取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+        let mut attributes: HashMap<(String, String), Vec<LineAttribute>> = HashMap::new();
+        for row in rows {
+            let attribute =
+                LineAttribute::new(row.get("attribute_key"), row.get("attribute_value")).map_err(
+                    |e| {
+                        RepositoryError::FetchFailed(format!("明細属性の構築に失敗しました: {}", e))
+                    },
+                )?;
+            attributes
+                .entry((row.get("order_id"), row.get("book_id")))
+                .or_default()
+                .push(attribute);
+        }
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT order_id, book_id, quantity, unit_price_amount, unit_price_currency, fulfillment_type, weight_grams, width_mm, height_mm, thickness_mm FROM order_lines WHERE order_id IN (",
+        );
+        let mut separated = query.separated(", ");
+        for order_id in order_ids {
+            separated.push_bind(order_id);
+        }
+        separated.push_unseparated(") ORDER BY id");
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("注文明細の取得に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+        for row in rows {
+            let order_id: String = row.get("order_id");
+            let book_id_str: String = row.get("book_id");
+            let book_id = BookId::from_string(&book_id_str).map_err(|e| {
+                RepositoryError::FetchFailed(format!("書籍IDの解析に失敗しました: {}", e))
+            })?;
+            let unit_price = Money::new(
+                row.get::<i64, _>("unit_price_amount"),
+                row.get::<String, _>("unit_price_currency"),
+            )
+            .map_err(|e| {
+                RepositoryError::FetchFailed(format!("金額の構築に失敗しました: {}", e))
+            })?;
+            let fulfillment_type = FulfillmentType::from_string(
+                &row.get::<String, _>("fulfillment_type"),
+            )
+            .map_err(|e| {
+                RepositoryError::FetchFailed(format!(
+                    "フルフィルメント種別の解析に失敗しました: {}",
+                    e
+                ))
+            })?;
+
+            let order_line = OrderLine::new(book_id, row.get::<u32, _>("quantity"), unit_price)
+                .map_err(|e| {
+                    RepositoryError::FetchFailed(format!("注文明細の構築に失敗しました: {}", e))
+                })?
+                .with_fulfillment_type(fulfillment_type)
+                .with_spec(Self::book_spec_from_row(&row)?)
+                .with_attributes(
+                    attributes
+                        .remove(&(order_id.clone(), book_id_str))
+                        .unwrap_or_default(),
+                );
+            lines.entry(order_id).or_default().push(order_line);
+        }
+
+        Ok(lines)
+    }
+
+    /// 注文明細の行から書籍の重量と寸法を取得する（未登録の明細はNULLのためNone）
+    fn book_spec_from_row(row: &SqliteRow) -> Result<Option<BookSpec>, RepositoryError> {
+        match (
+            row.get::<Option<u32>, _>("weight_grams"),
+            row.get::<Option<u32>, _>("width_mm"),
+            row.get::<Option<u32>, _>("height_mm"),
+            row.get::<Option<u32>, _>("thickness_mm"),
+        ) {
+            (Some(weight), Some(width), Some(height), Some(thickness)) => {
+                BookSpec::new(weight, width, height, thickness)
+                    .map(Some)
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!(
+                            "書籍の重量・寸法の解析に失敗しました: {}",
+                            e
+                        ))
+                    })
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// 注文の行から配送先住所を取得する
+    fn shipping_address_from_row(
+        row: &SqliteRow,
+    ) -> Result<Option<ShippingAddress>, RepositoryError> {
+        match (
+            row.get::<Option<String>, _>("postal_code"),
+            row.get::<Option<String>, _>("prefecture"),
+            row.get::<Option<String>, _>("city"),
+            row.get::<Option<String>, _>("street"),
+        ) {
+            (Some(postal_code), Some(prefecture), Some(city), Some(street)) => {
+                ShippingAddress::new(postal_code, prefecture, city, street, row.get("building"))
+                    .map(Some)
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!(
+                            "配送先住所の構築に失敗しました: {}",
+                            e
+                        ))
+                    })
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// 注文の行からギフト注文の受取人を取得する
+    /// 受取人の住所は配送先住所と同じため、配送先住所と受取人の氏名・電話番号から再構築する
+    fn recipient_from_row(
+        row: &SqliteRow,
+        shipping_address: Option<&ShippingAddress>,
+    ) -> Result<Option<Recipient>, RepositoryError> {
+        match (
+            row.get::<Option<String>, _>("recipient_name"),
+            row.get::<Option<String>, _>("recipient_phone"),
+            shipping_address,
+        ) {
+            (Some(name), Some(phone), Some(address)) => {
+                Recipient::new(name, phone, address.clone())
+                    .map(Some)
+                    .map_err(|e| {
+                        RepositoryError::FetchFailed(format!("受取人の解析に失敗しました: {}", e))
+                    })
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// 注文の行の文字列の列を解析する（NULLはNone）
+    fn parse_optional<T, E: std::fmt::Display>(
+        row: &SqliteRow,
+        column: &str,
+        label: &str,
+        parse: impl FnOnce(&str) -> Result<T, E>,
+    ) -> Result<Option<T>, RepositoryError> {
+        row.get::<Option<String>, _>(column)
+            .map(|value| {
+                parse(&value).map_err(|e| {
+                    RepositoryError::FetchFailed(format!("{}の解析に失敗しました: {}", label, e))
+                })
+            })
+            .transpose()
+    }
+
+    /// ordersテーブルの行と明細から注文を再構築する
+    fn order_from_row(
+        &self,
+        row: &SqliteRow,
+        order_lines: Vec<OrderLine>,
+    ) -> Result<Order, RepositoryError> {
+        let order_id = OrderId::from_string(row.get("id")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+        })?;
+        let customer_id = CustomerId::from_string(row.get("customer_id")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("顧客IDの解析に失敗しました: {}", e))
+        })?;
+        let status = OrderStatus::from_string(row.get("status")).map_err(|e| {
+            RepositoryError::FetchFailed(format!("注文ステータスの解析に失敗しました: {}", e))
+        })?;
+        let sales_channel = SalesChannel::from_string(&row.get::<String, _>("sales_channel"))
+            .map_err(|e| {
+                RepositoryError::FetchFailed(format!("販売チャネルの解析に失敗しました: {}", e))
+            })?;
+
+        let order = Order::reconstruct(
+            order_id,
+            customer_id,
+            order_lines,
+            Self::shipping_address_from_row(row)?,
+            status,
+        )
+        .map_err(|e| {
+            RepositoryError::FetchFailed(format!("注文集約の再構築に失敗しました: {}", e))
+        })?;
+        let recipient = Self::recipient_from_row(row, order.shipping_address())?;
+        let original_shipping_address = Self::parse_optional(
+            row,
+            "original_shipping_address",
+            "正規化前の配送先住所",
+            |json: &str| serde_json::from_str::<AddressInput>(json),
+        )?;
+
+        let order = order
+            .with_transition_times(
+                row.get::<Option<DateTime<Utc>>, _>("confirmed_at"),
+                row.get::<Option<DateTime<Utc>>, _>("shipped_at"),
+                row.get::<Option<DateTime<Utc>>, _>("delivered_at"),
+            )
+            .with_order_number(Self::parse_optional(
+                row,
+                "order_number",
+                "注文番号",
+                OrderNumber::parse,
+            )?)
+            .with_sales_channel(sales_channel)
+            .with_event_sequence(row.get::<i64, _>("event_sequence") as u64)
+            .with_saga_correlation_id(Self::parse_optional(
+                row,
+                "saga_correlation_id",
+                "相関ID",
+                Uuid::parse_str,
+            )?)
+            .with_confirmed_totals(Self::parse_optional(
+                row,
+                "confirmed_totals",
+                "確定時の金額",
+                |json: &str| serde_json::from_str::<OrderTotals>(json),
+            )?)
+            .with_tracking_token(Self::parse_optional(
+                row,
+                "tracking_token",
+                "追跡トークン",
+                TrackingToken::parse,
+            )?)
+            .with_estimated_delivery_date(
+                row.get::<Option<NaiveDate>, _>("estimated_delivery_date"),
+            )
+            .with_hold_reason(Self::parse_optional(
+                row,
+                "hold_reason",
+                "保留の理由",
+                HoldReason::from_string,
+            )?)
+            .with_version(row.get::<i64, _>("version") as u64)
+            .with_recipient(recipient)
+            .with_original_shipping_address(original_shipping_address);
+
+        Ok(self.warn_invariant_violations(order))
+    }
+
+    /// ordersテーブルの行から注文のリストを構築する（行の順番を保つ）
+    async fn build_orders_from_rows(
+        &self,
+        rows: Vec<SqliteRow>,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let order_ids: Vec<String> = rows.iter().map(|row| row.get("id")).collect();
+        let mut order_lines = self.load_order_lines(&order_ids).await?;
+
+        rows.iter()
+            .zip(&order_ids)
+            .map(|(row, order_id)| {
+                self.order_from_row(row, order_lines.remove(order_id).unwrap_or_default())
+            })
+            .collect()
+    }
+
+    /// 注文と明細・明細属性をトランザクション内で書き込む（コミットは呼び出し側で行う）
+    async fn write_order(conn: &mut SqliteConnection, order: &Order) -> Result<(), RepositoryError> {
+        let shipping_address = order.shipping_address();
+        let recipient = order.recipient();
+        let original_shipping_address = order
+            .original_shipping_address()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| {
+                RepositoryError::OperationFailed(format!(
+                    "正規化前の配送先住所のシリアライズに失敗しました: {}",
+                    e
+                ))
+            })?;
+        let confirmed_totals = order
+            .confirmed_totals()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| {
+                RepositoryError::OperationFailed(format!(
+                    "確定時の金額のシリアライズに失敗しました: {}",
+                    e
+                ))
+            })?;
+
+        // 未保存の注文（バージョン0）は追加し、保存済みの注文は読み込んだときのバージョンの場合のみ更新する
+        // （他の操作が先に保存していた場合は、追加は主キーの重複、更新は0件になる）
+        // 作成日時は並び順の比較に使うため、他の日時と同じRFC3339の文字列で保存する
+        let is_new = order.version() == 0;
+        let query = if is_new {
+            sqlx::query(
+                r#"
+                INSERT INTO orders (id, order_number, customer_id, sales_channel, created_at, status, confirmed_at, shipped_at, delivered_at, estimated_delivery_date, hold_reason, event_sequence, saga_correlation_id, confirmed_totals, tracking_token, postal_code, prefecture, city, street, building, original_shipping_address, recipient_name, recipient_phone, version)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 1)
+                "#,
+            )
+            .bind(order.id().to_string())
+            .bind(order.order_number().map(OrderNumber::as_str))
+            .bind(order.customer_id().to_string())
+            .bind(order.sales_channel().to_string())
+            .bind(Utc::now())
+        } else {
+            sqlx::query(
+                r#"
+                UPDATE orders SET
+                    status = ?,
+                    confirmed_at = ?,
+                    shipped_at = ?,
+                    delivered_at = ?,
+                    estimated_delivery_date = ?,
+                    hold_reason = ?,
+                    event_sequence = ?,
+                    saga_correlation_id = ?,
+                    confirmed_totals = ?,
+                    tracking_token = ?,
+                    postal_code = ?,
+                    prefecture = ?,
+                    city = ?,
+                    street = ?,
+                    building = ?,
+                    original_shipping_address = ?,
+                    recipient_name = ?,
+                    recipient_phone = ?,
+                    version = version + 1
+                WHERE id = ? AND version = ?
+                "#,
+            )
+        };
+        let query = query
+            .bind(order.status().to_string())
+            .bind(order.confirmed_at())
+            .bind(order.shipped_at())
+            .bind(order.delivered_at())
+            .bind(order.estimated_delivery_date())
+            .bind(order.hold_reason().map(|reason| reason.to_string()))
+            .bind(order.event_sequence() as i64)
+            .bind(order.saga_correlation_id().map(|id| id.to_string()))
+            .bind(confirmed_totals)
+            .bind(order.tracking_token().map(TrackingToken::as_str))
+            .bind(shipping_address.map(ShippingAddress::postal_code))
+            .bind(shipping_address.map(ShippingAddress::prefecture))
+            .bind(shipping_address.map(ShippingAddress::city))
+            .bind(shipping_address.map(ShippingAddress::street))
+            .bind(shipping_address.and_then(ShippingAddress::building))
+            .bind(original_shipping_address)
+            .bind(recipient.map(Recipient::name))
+            .bind(recipient.map(Recipient::phone));
+        let query = if is_new {
+            query
+        } else {
+            query
+                .bind(order.id().to_string())
+                .bind(order.version() as i64)
+        };
+        let result = query.execute(&mut *conn).await.map_err(|e| {
+            if is_primary_key_violation(&e) {
+                concurrency_conflict(order)
+            } else {
+                RepositoryError::from(DatabaseError::QueryError(format!(
+                    "注文の保存に失敗しました: {}",
+                    e
+                )))
+            }
+        })?;
+        if result.rows_affected() == 0 {
+            return Err(concurrency_conflict(order));
+        }
+
+        // 注文明細と明細属性を置き換え
+        sqlx::query("DELETE FROM order_lines WHERE order_id = ?")
+            .bind(order.id().to_string())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("注文明細の削除に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+        sqlx::query("DELETE FROM order_line_attributes WHERE order_id = ?")
+            .bind(order.id().to_string())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("明細属性の削除に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+        for order_line in order.order_lines() {
+            sqlx::query(
+                r#"
+                INSERT INTO order_lines (order_id, book_id, quantity, unit_price_amount, unit_price_currency, fulfillment_type,
+                    weight_grams, width_mm, height_mm, thickness_mm)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(order.id().to_string())
+            .bind(order_line.book_id().to_string())
+            .bind(order_line.quantity())
+            .bind(order_line.unit_price().amount())
+            .bind(order_line.unit_price().currency())
+            .bind(order_line.fulfillment_type().to_string())
+            .bind(order_line.spec().map(|spec| spec.weight_grams()))
+            .bind(order_line.spec().map(|spec| spec.width_mm()))
+            .bind(order_line.spec().map(|spec| spec.height_mm()))
+            .bind(order_line.spec().map(|spec| spec.thickness_mm()))
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("注文明細の保存に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+            for attribute in order_line.attributes() {
+                sqlx::query(
+                    "INSERT INTO order_line_attributes (order_id, book_id, attribute_key, attribute_value) VALUES (?, ?, ?, ?)",
+                )
+                .bind(order.id().to_string())
+                .bind(order_line.book_id().to_string())
+                .bind(attribute.key())
+                .bind(attribute.value())
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("明細属性の保存に失敗しました: {}", e)))
+                .map_err(RepositoryError::from)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrderRepository for SqliteOrderRepository {
+    #[tracing::instrument(name = "db.orders.save", skip_all, fields(db.system = "sqlite", db.operation = "INSERT", db.sql.table = "orders", order_id = %order.id()), err)]
+    async fn save(&self, order: &mut Order) -> Result<(), RepositoryError> {
+        self.ensure_invariants(order)?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Self::write_order(&mut tx, order).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        order.mark_saved(order.version() + 1);
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.orders.save_within_purchase_limits", skip_all, fields(db.system = "sqlite", db.operation = "INSERT", db.sql.table = "orders", order_id = %order.id()), err)]
+    async fn save_within_purchase_limits(
+        &self,
+        order: &mut Order,
+        policy: &PurchasePolicy,
+        check: PurchaseCheck,
+    ) -> Result<(), RepositoryError> {
+        self.ensure_invariants(order)?;
+
+        // 接続は1つだけなので、トランザクションの間は他の書き込みが割り込まず、集計から保存までが直列化される
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        let counted = match &check {
+            PurchaseCheck::OpenOrder => count_open_orders(&mut *tx, order.customer_id()).await?,
+            PurchaseCheck::BookQuantity { book_id, since, .. } => {
+                sum_book_quantity_since(&mut *tx, order.customer_id(), *book_id, *since).await?
+            }
+        };
+        policy
+            .ensure(&check, counted)
+            .map_err(RepositoryError::PurchaseLimitExceeded)?;
+
+        Self::write_order(&mut tx, order).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        order.mark_saved(order.version() + 1);
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_id", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", order_id = %order_id), err)]
+    async fn find_by_id(&self, order_id: OrderId) -> Result<Option<Order>, RepositoryError> {
+        Ok(self
+            .find_by_ids(&[order_id.to_string()])
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    #[tracing::instrument(name = "db.orders.find_all", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders"), err)]
+    async fn find_all(&self) -> Result<Vec<Order>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM orders ORDER BY created_at DESC",
+            ORDER_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("注文一覧の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        self.build_orders_from_rows(rows).await
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_status", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", status = ?status), err)]
+    async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM orders WHERE status = ? ORDER BY created_at DESC",
+            ORDER_COLUMNS
+        ))
+        .bind(status.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("ステータス別注文一覧の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        self.build_orders_from_rows(rows).await
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_customer", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", customer_id = %customer_id), err)]
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM orders WHERE customer_id = ? ORDER BY created_at DESC",
+            ORDER_COLUMNS
+        ))
+        .bind(customer_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("顧客の注文一覧の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        self.build_orders_from_rows(rows).await
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_customer_page", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", customer_id = %customer_id, limit = limit), err)]
+    async fn find_by_customer_page(
+        &self,
+        customer_id: CustomerId,
+        after: Option<OrderId>,
+        limit: u32,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM orders WHERE customer_id = ? AND id > ? ORDER BY id ASC LIMIT ?",
+            ORDER_COLUMNS
+        ))
+        .bind(customer_id.to_string())
+        .bind(after.map(|id| id.to_string()).unwrap_or_default())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("顧客の注文の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        self.build_orders_from_rows(rows).await
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_status_created_before", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", status = ?status), err)]
+    async fn find_by_status_created_before(
+        &self,
+        status: OrderStatus,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM orders WHERE status = ? AND created_at < ?",
+            ORDER_COLUMNS
+        ))
+        .bind(status.to_string())
+        .bind(created_before)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("作成日時による注文一覧の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        self.build_orders_from_rows(rows).await
+    }
+
+    #[tracing::instrument(name = "db.orders.count_open_orders_by_customer", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", customer_id = %customer_id), err)]
+    async fn count_open_orders_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<u32, RepositoryError> {
+        count_open_orders(&self.pool, customer_id).await
+    }
+
+    #[tracing::instrument(name = "db.orders.count_orders_by_customer_and_status", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", customer_id = %customer_id, status = %status), err)]
+    async fn count_orders_by_customer_and_status(
+        &self,
+        customer_id: CustomerId,
+        status: OrderStatus,
+    ) -> Result<u32, RepositoryError> {
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM orders WHERE customer_id = ? AND status = ?")
+                .bind(customer_id.to_string())
+                .bind(status.to_string())
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!("注文数の取得に失敗しました: {}", e))
+                })
+                .map_err(RepositoryError::from)?;
+
+        Ok(count as u32)
+    }
+
+    #[tracing::instrument(name = "db.orders.sum_book_quantity_by_customer_since", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "order_lines", customer_id = %customer_id, book_id = %book_id), err)]
+    async fn sum_book_quantity_by_customer_since(
+        &self,
+        customer_id: CustomerId,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, RepositoryError> {
+        sum_book_quantity_since(&self.pool, customer_id, book_id, since).await
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_tracking_token", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders"), err)]
+    async fn find_by_tracking_token(
+        &self,
+        token: &TrackingToken,
+    ) -> Result<Option<Order>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM orders WHERE tracking_token = ?",
+            ORDER_COLUMNS
+        ))
+        .bind(token.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("追跡トークンによる注文の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(self.build_orders_from_rows(rows).await?.into_iter().next())
+    }
+
+    #[tracing::instrument(name = "db.orders.find_by_order_number", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", order_number = %order_number), err)]
+    async fn find_by_order_number(
+        &self,
+        order_number: &OrderNumber,
+    ) -> Result<Option<Order>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM orders WHERE order_number = ?",
+            ORDER_COLUMNS
+        ))
+        .bind(order_number.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("注文番号による注文の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(self.build_orders_from_rows(rows).await?.into_iter().next())
+    }
+
+    #[tracing::instrument(name = "db.orders.find_similar_orders", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", order_id = %order_id), err)]
+    async fn find_similar_orders(
+        &self,
+        order_id: OrderId,
+        within: TimeDelta,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        let target: Option<(String, DateTime<Utc>)> =
+            sqlx::query_as("SELECT customer_id, created_at FROM orders WHERE id = ?")
+                .bind(order_id.to_string())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("注文の取得に失敗しました: {}", e)))
+                .map_err(RepositoryError::from)?;
+        let Some((customer_id, created_at)) = target else {
+            return Ok(Vec::new());
+        };
+
+        // 作成日時が近い同じ顧客の注文を候補にし、明細（書籍と数量）の比較は集約で行う
+        let candidate_ids: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT id FROM orders
+            WHERE customer_id = ?
+                AND id <> ?
+                AND status <> ?
+                AND created_at BETWEEN ? AND ?
+            ORDER BY created_at
+            "#,
+        )
+        .bind(customer_id)
+        .bind(order_id.to_string())
+        .bind(OrderStatus::Cancelled.to_string())
+        .bind(created_at - within)
+        .bind(created_at + within)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("重複の可能性がある注文の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+        if candidate_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let Some(target) = self.find_by_id(order_id).await? else {
+            return Ok(Vec::new());
+        };
+        let mut candidates: HashMap<OrderId, Order> = self
+            .find_by_ids(&candidate_ids)
+            .await?
+            .into_iter()
+            .map(|order| (order.id(), order))
+            .collect();
+
+        let mut orders = Vec::new();
+        for id in candidate_ids {
+            let candidate_id = OrderId::from_string(&id).map_err(|e| {
+                RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+            })?;
+            if let Some(candidate) = candidates.remove(&candidate_id) {
+                if candidate.has_same_lines(&target) {
+                    orders.push(candidate);
+                }
+            }
+        }
+        Ok(orders)
+    }
+
+    #[tracing::instrument(name = "db.orders.sum_daily_book_demand_since", skip_all, fields(db.system = "sqlite", db.operation = "SELECT", db.sql.table = "orders", book_id = %book_id), err)]
+    async fn sum_daily_book_demand_since(
+        &self,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<BTreeMap<NaiveDate, u32>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT o.confirmed_at, SUM(ol.quantity) AS quantity
+            FROM orders o
+            INNER JOIN order_lines ol ON o.id = ol.order_id
+            WHERE ol.book_id = ?
+                AND o.status <> ?
+                AND o.confirmed_at >= ?
+            GROUP BY o.confirmed_at
+            "#,
+        )
+        .bind(book_id.to_string())
+        .bind(OrderStatus::Cancelled.to_string())
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("書籍の需要の集計に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut demand = BTreeMap::new();
+        for row in rows {
+            let confirmed_at: DateTime<Utc> = row.get("confirmed_at");
+            let quantity: i64 = row.get("quantity");
+            *demand.entry(confirmed_at.date_naive()).or_insert(0) += quantity as u32;
+        }
+        Ok(demand)
+    }
+
+    fn next_identity(&self) -> OrderId {
+        OrderId::new()
+    }
+}
+
+/// 顧客の未完了の注文数を数える（プールとトランザクションの両方から使う）
+async fn count_open_orders<'e, E>(executor: E, customer_id: CustomerId) -> Result<u32, RepositoryError>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM orders WHERE customer_id = ? AND status IN (?, ?, ?, ?, ?)",
+    )
+    .bind(customer_id.to_string())
+    .bind(OrderStatus::Pending.to_string())
+    .bind(OrderStatus::Confirmed.to_string())
+    .bind(OrderStatus::OnHold.to_string())
+    .bind(OrderStatus::AwaitingRelease.to_string())
+    .bind(OrderStatus::Waitlisted.to_string())
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        DatabaseError::QueryError(format!("未完了の注文数の取得に失敗しました: {}", e))
+    })
+    .map_err(RepositoryError::from)?;
+
+    Ok(count as u32)
+}
+
+/// 指定日時以降に作成された顧客の注文に含まれる書籍の合計数量を集計する（プールとトランザクションの両方から使う）
+async fn sum_book_quantity_since<'e, E>(
+    executor: E,
+    customer_id: CustomerId,
+    book_id: BookId,
+    since: DateTime<Utc>,
+) -> Result<u32, RepositoryError>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let quantity: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(SUM(ol.quantity), 0)
+        FROM orders o
+        INNER JOIN order_lines ol ON o.id = ol.order_id
+        WHERE o.customer_id = ?
+            AND ol.book_id = ?
+            AND o.status <> ?
+            AND o.created_at >= ?
+        "#,
+    )
+    .bind(customer_id.to_string())
+    .bind(book_id.to_string())
+    .bind(OrderStatus::Cancelled.to_string())
+    .bind(since)
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        DatabaseError::QueryError(format!("書籍の購入数量の集計に失敗しました: {}", e))
+    })
+    .map_err(RepositoryError::from)?;
+
+    Ok(quantity as u32)
+}
+
+/// SQLiteの主キー制約違反の拡張エラーコード（SQLITE_CONSTRAINT_PRIMARYKEY）
+const SQLITE_CONSTRAINT_PRIMARYKEY: &str = "1555";
+
+/// 注文IDの主キーの重複か（他の操作が同じ注文を先に追加していた場合）
+/// 注文番号などの他の一意制約の重複は競合ではなく保存の失敗として扱う
+fn is_primary_key_violation(error: &sqlx::Error) -> bool {
+    error
+        .as_database_error()
+        .is_some_and(|db_error| db_error.code().as_deref() == Some(SQLITE_CONSTRAINT_PRIMARYKEY))
+}
+
+/// 楽観的排他制御の競合エラーを作成
+fn concurrency_conflict(order: &Order) -> RepositoryError {
+    RepositoryError::ConcurrencyConflict(format!(
+        "注文{}は読み込んだ後に他の操作が保存しています（読み込んだバージョン: {}）",
+        order.id(),
+        order.version()
+    ))
+}
@@ -0,0 +1,93 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::OrderId;
+use crate::domain::port::{RepositoryError, StatusOverrideAuditRepository};
+use crate::domain::status_override::StatusOverrideAudit;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL 強制遷移の監査記録リポジトリ
+/// MySQLデータベース（status_override_auditsテーブル）で注文の強制遷移の記録を管理する
+#[derive(Clone)]
+pub struct MySqlStatusOverrideAuditRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlStatusOverrideAuditRepository {
+    /// 新しいMySQL 強制遷移の監査記録リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlStatusOverrideAuditRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から監査記録を構築する
+    fn audit_from_row(row: &MySqlRow) -> Result<StatusOverrideAudit, RepositoryError> {
+        let parse_uuid = |column: &str, label: &str| {
+            Uuid::parse_str(&row.get::<String, _>(column)).map_err(|e| {
+                RepositoryError::FetchFailed(format!("{}の解析に失敗しました: {}", label, e))
+            })
+        };
+        Ok(StatusOverrideAudit {
+            audit_id: parse_uuid("audit_id", "監査記録ID")?,
+            order_id: OrderId::from_string(&row.get::<String, _>("order_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("注文IDの解析に失敗しました: {}", e))
+            })?,
+            from_status: row.get("from_status"),
+            to_status: row.get("to_status"),
+            reason: row.get("reason"),
+            operator: row.get("operator"),
+            event_id: parse_uuid("event_id", "イベントID")?,
+            created_at: row.get::<DateTime<Utc>, _>("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl StatusOverrideAuditRepository for MySqlStatusOverrideAuditRepository {
+    #[tracing::instrument(name = "db.status_override_audits.add", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "status_override_audits", order_id = %audit.order_id), err)]
+    async fn add(&self, audit: &StatusOverrideAudit) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO status_override_audits (audit_id, order_id, from_status, to_status, reason, operator, event_id, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(audit.audit_id.to_string())
+        .bind(audit.order_id.to_string())
+        .bind(&audit.from_status)
+        .bind(&audit.to_status)
+        .bind(&audit.reason)
+        .bind(&audit.operator)
+        .bind(audit.event_id.to_string())
+        .bind(audit.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("強制遷移の記録の保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.status_override_audits.find_by_order", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "status_override_audits", order_id = %order_id), err)]
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<StatusOverrideAudit>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT audit_id, order_id, from_status, to_status, reason, operator, event_id, created_at FROM status_override_audits WHERE order_id = ? ORDER BY created_at, audit_id",
+        )
+        .bind(order_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("強制遷移の記録の取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::audit_from_row).collect()
+    }
+}
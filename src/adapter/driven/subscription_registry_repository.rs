@@ -0,0 +1,146 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::event_bus::PausedEventHandling;
+use crate::domain::port::{RepositoryError, SubscriptionRegistryRepository};
+use crate::domain::retry_policy::DeliveryGuarantee;
+use crate::domain::subscription_registry::{RegisteredSubscriptionState, SubscriptionRegistration};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL 購読の登録内容リポジトリ
+/// MySQLデータベース（subscription_registryテーブル）でハンドラーごとの購読の状態を管理する
+#[derive(Clone)]
+pub struct MySqlSubscriptionRegistryRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlSubscriptionRegistryRepository {
+    /// 新しいMySQL 購読の登録内容リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlSubscriptionRegistryRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から登録内容を構築する
+    fn registration_from_row(row: &MySqlRow) -> Result<SubscriptionRegistration, RepositoryError> {
+        let state: String = row.get("state");
+        let paused_events: Option<String> = row.get("paused_events");
+        let state = match (state.as_str(), paused_events.as_deref()) {
+            ("active", _) => RegisteredSubscriptionState::Active,
+            ("paused", Some("skip")) => RegisteredSubscriptionState::Paused(PausedEventHandling::Skip),
+            ("paused", _) => RegisteredSubscriptionState::Paused(PausedEventHandling::Buffer),
+            ("unsubscribed", _) => RegisteredSubscriptionState::Unsubscribed,
+            (other, _) => {
+                return Err(RepositoryError::FetchFailed(format!(
+                    "購読の状態の解析に失敗しました: {}",
+                    other
+                )))
+            }
+        };
+        let delivery_guarantee = match row.get::<String, _>("delivery_guarantee").as_str() {
+            "at_most_once" => DeliveryGuarantee::AtMostOnce,
+            _ => DeliveryGuarantee::AtLeastOnce,
+        };
+
+        Ok(SubscriptionRegistration {
+            handler_name: row.get("handler_name"),
+            event_types: row
+                .get::<String, _>("event_types")
+                .split(',')
+                .filter(|event_type| !event_type.is_empty())
+                .map(str::to_string)
+                .collect(),
+            state,
+            filter: row.get("filter_description"),
+            delivery_guarantee,
+            updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl SubscriptionRegistryRepository for MySqlSubscriptionRegistryRepository {
+    #[tracing::instrument(name = "db.subscription_registry.save", skip_all, fields(db.system = "mysql", db.operation = "UPSERT", db.sql.table = "subscription_registry", handler_name = %registration.handler_name), err)]
+    async fn save(&self, registration: &SubscriptionRegistration) -> Result<(), RepositoryError> {
+        let (state, paused_events) = match registration.state {
+            RegisteredSubscriptionState::Active => ("active", None),
+            RegisteredSubscriptionState::Paused(PausedEventHandling::Buffer) => {
+                ("paused", Some("buffer"))
+            }
+            RegisteredSubscriptionState::Paused(PausedEventHandling::Skip) => {
+                ("paused", Some("skip"))
+            }
+            RegisteredSubscriptionState::Unsubscribed => ("unsubscribed", None),
+        };
+        let delivery_guarantee = match registration.delivery_guarantee {
+            DeliveryGuarantee::AtLeastOnce => "at_least_once",
+            DeliveryGuarantee::AtMostOnce => "at_most_once",
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO subscription_registry (handler_name, event_types, state, paused_events, filter_description, delivery_guarantee, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                event_types = VALUES(event_types),
+                state = VALUES(state),
+                paused_events = VALUES(paused_events),
+                filter_description = VALUES(filter_description),
+                delivery_guarantee = VALUES(delivery_guarantee),
+                updated_at = VALUES(updated_at)
+            "#,
+        )
+        .bind(&registration.handler_name)
+        .bind(registration.event_types.join(","))
+        .bind(state)
+        .bind(paused_events)
+        .bind(&registration.filter)
+        .bind(delivery_guarantee)
+        .bind(registration.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("購読の登録内容の保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.subscription_registry.find_all", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "subscription_registry"), err)]
+    async fn find_all(&self) -> Result<Vec<SubscriptionRegistration>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT handler_name, event_types, state, paused_events, filter_description, delivery_guarantee, updated_at FROM subscription_registry ORDER BY handler_name",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("購読の登録内容の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::registration_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.subscription_registry.delete", skip_all, fields(db.system = "mysql", db.operation = "DELETE", db.sql.table = "subscription_registry", handler_name = %handler_name), err)]
+    async fn delete(&self, handler_name: &str) -> Result<bool, RepositoryError> {
+        let result = sqlx::query("DELETE FROM subscription_registry WHERE handler_name = ?")
+            .bind(handler_name)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("購読の登録内容の削除に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
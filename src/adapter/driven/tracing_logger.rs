@@ -0,0 +1,178 @@
+use crate::domain::port::{LogLevel, Logger};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// tracingによるログ実装
+/// ログを`tracing`のイベントとして出力するため、実行中のスパン（HTTPリクエスト・イベントの発行・ハンドラーの実行）に
+/// ひも付いて記録される（出力先・書式はinit_telemetryで構成したサブスクライバーが決める）
+pub struct TracingLogger;
+
+impl TracingLogger {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn log(
+        &self,
+        level: LogLevel,
+        component: &str,
+        message: &str,
+        correlation_id: Option<Uuid>,
+        context: Option<HashMap<String, String>>,
+    ) {
+        let correlation_id = correlation_id.map(|id| id.to_string());
+        let context = format_context(context);
+
+        macro_rules! emit {
+            ($level:expr) => {
+                tracing::event!(
+                    $level,
+                    component,
+                    correlation_id = correlation_id.as_deref(),
+                    context = context.as_deref(),
+                    "{}",
+                    message
+                )
+            };
+        }
+
+        match level {
+            LogLevel::Debug => emit!(tracing::Level::DEBUG),
+            LogLevel::Info => emit!(tracing::Level::INFO),
+            LogLevel::Warning => emit!(tracing::Level::WARN),
+            LogLevel::Error => emit!(tracing::Level::ERROR),
+        }
+    }
+}
+
+impl Default for TracingLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 追加コンテキストをキー順の「key=value」の並びにする（空の場合はNone）
+fn format_context(context: Option<HashMap<String, String>>) -> Option<String> {
+    let context: BTreeMap<_, _> = context?.into_iter().collect();
+    if context.is_empty() {
+        return None;
+    }
+    Some(
+        context
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+impl Logger for TracingLogger {
+    fn debug(
+        &self,
+        component: &str,
+        message: &str,
+        correlation_id: Option<Uuid>,
+        context: Option<HashMap<String, String>>,
+    ) {
+        self.log(LogLevel::Debug, component, message, correlation_id, context);
+    }
+
+    fn info(
+        &self,
+        component: &str,
+        message: &str,
+        correlation_id: Option<Uuid>,
+        context: Option<HashMap<String, String>>,
+    ) {
+        self.log(LogLevel::Info, component, message, correlation_id, context);
+    }
+
+    fn warn(
+        &self,
+        component: &str,
+        message: &str,
+        correlation_id: Option<Uuid>,
+        context: Option<HashMap<String, String>>,
+    ) {
+        self.log(LogLevel::Warning, component, message, correlation_id, context);
+    }
+
+    fn error(
+        &self,
+        component: &str,
+        message: &str,
+        correlation_id: Option<Uuid>,
+        context: Option<HashMap<String, String>>,
+    ) {
+        self.log(LogLevel::Error, component, message, correlation_id, context);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::MakeWriter;
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Captured {
+        type Writer = Captured;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_logs_are_recorded_within_current_span() {
+        let captured = Captured::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(captured.clone())
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .finish();
+        let correlation_id = Uuid::new_v4();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("event.handle", handler.name = "ShippingHandler");
+            let _entered = span.enter();
+            let mut context = HashMap::new();
+            context.insert("order_id".to_string(), "order-1".to_string());
+            context.insert("attempt".to_string(), "2".to_string());
+            TracingLogger::new().warn(
+                "ShippingHandler",
+                "Shipping failed",
+                Some(correlation_id),
+                Some(context),
+            );
+        });
+
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("WARN"));
+        assert!(output.contains("event.handle{handler.name=\"ShippingHandler\"}"));
+        assert!(output.contains("Shipping failed"));
+        assert!(output.contains("component=\"ShippingHandler\""));
+        assert!(output.contains(&format!("correlation_id=\"{}\"", correlation_id)));
+        assert!(output.contains("context=\"attempt=2, order_id=order-1\""));
+    }
+
+    #[test]
+    fn test_empty_context_is_omitted() {
+        assert_eq!(format_context(None), None);
+        assert_eq!(format_context(Some(HashMap::new())), None);
+    }
+}
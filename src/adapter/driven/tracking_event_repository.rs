@@ -0,0 +1,208 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::OrderId;
+use crate::domain::port::{RepositoryError, TrackingEventRepository};
+use crate::domain::projection::ProjectionCheckpoint;
+use crate::domain::tracking::{TrackingEvent, TrackingStage};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::{Executor, MySql, Pool, Row};
+
+/// MySQL配送追跡イベントリポジトリ
+/// MySQLデータベースを使用して配送追跡タイムラインの投影を永続化する
+#[derive(Clone)]
+pub struct MySqlTrackingEventRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlTrackingEventRepository {
+    /// 新しいMySQL配送追跡イベントリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlTrackingEventRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// 追跡イベントを追加する（コネクションプールまたはトランザクションで実行）
+    async fn insert_event<'e, E>(executor: E, event: &TrackingEvent) -> Result<(), RepositoryError>
+    where
+        E: Executor<'e, Database = MySql>,
+    {
+        // event_idの一意制約により、同じイベントの再投影は無視される
+        sqlx::query(
+            r#"
+            INSERT IGNORE INTO tracking_events (event_id, order_id, stage, occurred_at, location, description)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(event.event_id.to_string())
+        .bind(event.order_id.to_string())
+        .bind(event.stage.to_string())
+        .bind(event.occurred_at)
+        .bind(event.location.as_deref())
+        .bind(event.description.as_deref())
+        .execute(executor)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("追跡イベントの保存に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TrackingEventRepository for MySqlTrackingEventRepository {
+    #[tracing::instrument(name = "db.tracking_events.append", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "tracking_events", order_id = %event.order_id), err)]
+    async fn append(&self, event: &TrackingEvent) -> Result<(), RepositoryError> {
+        Self::insert_event(&self.pool, event).await
+    }
+
+    #[tracing::instrument(name = "db.tracking_events.find_by_order", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "tracking_events", order_id = %order_id), err)]
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<TrackingEvent>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT event_id, stage, occurred_at, location, description
+            FROM tracking_events
+            WHERE order_id = ?
+            ORDER BY occurred_at ASC, id ASC
+            "#,
+        )
+        .bind(order_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("追跡イベントの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            let event_id = Uuid::parse_str(row.get("event_id")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("イベントIDの解析に失敗しました: {}", e))
+            })?;
+            let stage = TrackingStage::from_string(row.get("stage")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("追跡段階の解析に失敗しました: {}", e))
+            })?;
+            events.push(TrackingEvent {
+                event_id,
+                order_id,
+                stage,
+                occurred_at: row.get::<DateTime<Utc>, _>("occurred_at"),
+                location: row.get("location"),
+                description: row.get("description"),
+            });
+        }
+
+        Ok(events)
+    }
+
+    #[tracing::instrument(name = "db.projection_checkpoints.apply", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "projection_checkpoints", projection = %checkpoint.projection, aggregate_id = %checkpoint.aggregate_id, sequence_number = checkpoint.sequence_number), err)]
+    async fn apply_checkpointed(
+        &self,
+        checkpoint: &ProjectionCheckpoint,
+        event: Option<&TrackingEvent>,
+    ) -> Result<bool, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("トランザクション開始に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        // 同じ集約のチェックポイントを同時に更新しないよう行ロックを取る
+        let current: Option<u64> = sqlx::query_scalar(
+            r#"
+            SELECT last_sequence_number FROM projection_checkpoints
+            WHERE projection_name = ? AND aggregate_id = ?
+            FOR UPDATE
+            "#,
+        )
+        .bind(&checkpoint.projection)
+        .bind(&checkpoint.aggregate_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("チェックポイントの取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        // 適用済みの連番は何もしない（ロールバックして終了）
+        if current.is_some_and(|last| last >= checkpoint.sequence_number) {
+            return Ok(false);
+        }
+
+        if let Some(event) = event {
+            Self::insert_event(&mut *tx, event).await?;
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO projection_checkpoints (projection_name, aggregate_id, last_sequence_number)
+            VALUES (?, ?, ?)
+            ON DUPLICATE KEY UPDATE last_sequence_number = VALUES(last_sequence_number)
+            "#,
+        )
+        .bind(&checkpoint.projection)
+        .bind(&checkpoint.aggregate_id)
+        .bind(checkpoint.sequence_number)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("チェックポイントの保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!(
+                    "トランザクションのコミットに失敗しました: {}",
+                    e
+                ))
+            })
+            .map_err(RepositoryError::from)?;
+
+        Ok(true)
+    }
+
+    #[tracing::instrument(name = "db.projection_checkpoints.load", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "projection_checkpoints", projection = %projection), err)]
+    async fn load_checkpoints(
+        &self,
+        projection: &str,
+    ) -> Result<Vec<ProjectionCheckpoint>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT aggregate_id, last_sequence_number
+            FROM projection_checkpoints
+            WHERE projection_name = ?
+            "#,
+        )
+        .bind(projection)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("チェックポイントの取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                ProjectionCheckpoint::new(
+                    projection,
+                    row.get("aggregate_id"),
+                    row.get::<u64, _>("last_sequence_number"),
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,120 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::model::{BookId, CustomerId, OrderId};
+use crate::domain::port::{RepositoryError, WaitlistRepository};
+use crate::domain::waitlist::WaitlistEntry;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, QueryBuilder, Row};
+
+/// MySQL順番待ちリポジトリ
+/// MySQLデータベース（waitlist_entriesテーブル）で書籍ごとの順番待ちを管理する
+/// 先着順は自動採番のidの順とする
+#[derive(Clone)]
+pub struct MySqlWaitlistRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlWaitlistRepository {
+    /// 新しいMySQL順番待ちリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlWaitlistRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から順番待ちを構築する
+    fn entry_from_row(row: &MySqlRow) -> Result<WaitlistEntry, RepositoryError> {
+        let parse_error =
+            |e: uuid::Error| RepositoryError::FetchFailed(format!("IDの解析に失敗しました: {}", e));
+        Ok(WaitlistEntry {
+            order_id: OrderId::from_string(&row.get::<String, _>("order_id"))
+                .map_err(parse_error)?,
+            customer_id: CustomerId::from_string(&row.get::<String, _>("customer_id"))
+                .map_err(parse_error)?,
+            book_id: BookId::from_string(&row.get::<String, _>("book_id")).map_err(parse_error)?,
+            quantity: row.get("quantity"),
+            joined_at: row.get::<DateTime<Utc>, _>("joined_at"),
+        })
+    }
+
+    async fn fetch_entries(
+        &self,
+        column: &str,
+        id: String,
+    ) -> Result<Vec<WaitlistEntry>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT order_id, customer_id, book_id, quantity, joined_at FROM waitlist_entries WHERE {} = ? ORDER BY id",
+            column
+        ))
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("順番待ちの取得に失敗しました: {}", e)))
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::entry_from_row).collect()
+    }
+}
+
+#[async_trait]
+impl WaitlistRepository for MySqlWaitlistRepository {
+    #[tracing::instrument(name = "db.waitlist_entries.join", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "waitlist_entries", entry_count = entries.len()), err)]
+    async fn join(&self, entries: &[WaitlistEntry]) -> Result<(), RepositoryError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        // 同じ注文・書籍の登録は無視し、最初に並んだ位置を保つ
+        let mut query = QueryBuilder::<MySql>::new(
+            "INSERT IGNORE INTO waitlist_entries (order_id, customer_id, book_id, quantity, joined_at) ",
+        );
+        query.push_values(entries, |mut row, entry| {
+            row.push_bind(entry.order_id.to_string())
+                .push_bind(entry.customer_id.to_string())
+                .push_bind(entry.book_id.to_string())
+                .push_bind(entry.quantity)
+                .push_bind(entry.joined_at);
+        });
+
+        query
+            .build()
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("順番待ちの登録に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.waitlist_entries.find_by_book", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "waitlist_entries", book_id = %book_id), err)]
+    async fn find_by_book(&self, book_id: BookId) -> Result<Vec<WaitlistEntry>, RepositoryError> {
+        self.fetch_entries("book_id", book_id.to_string()).await
+    }
+
+    #[tracing::instrument(name = "db.waitlist_entries.find_by_order", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "waitlist_entries", order_id = %order_id), err)]
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<WaitlistEntry>, RepositoryError> {
+        self.fetch_entries("order_id", order_id.to_string()).await
+    }
+
+    #[tracing::instrument(name = "db.waitlist_entries.remove_order", skip_all, fields(db.system = "mysql", db.operation = "DELETE", db.sql.table = "waitlist_entries", order_id = %order_id), err)]
+    async fn remove_order(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM waitlist_entries WHERE order_id = ?")
+            .bind(order_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("順番待ちの削除に失敗しました: {}", e)))
+            .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+}
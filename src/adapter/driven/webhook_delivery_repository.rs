@@ -0,0 +1,128 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::customer_webhook::{WebhookDelivery, WebhookDeliveryStatus};
+use crate::domain::model::OrderId;
+use crate::domain::port::{RepositoryError, WebhookDeliveryRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+const SELECT_COLUMNS: &str = "SELECT id, subscription_id, event_id, event_type, order_id, payload, status, response_status, error, redelivery_of, attempted_at FROM webhook_deliveries";
+
+/// MySQL Webhook配信ログリポジトリ
+/// MySQLデータベース（webhook_deliveriesテーブル）に購読ごとのWebhookの配信記録を保存する
+#[derive(Clone)]
+pub struct MySqlWebhookDeliveryRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlWebhookDeliveryRepository {
+    /// 新しいMySQL Webhook配信ログリポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlWebhookDeliveryRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から配信記録を構築する
+    fn delivery_from_row(row: &MySqlRow) -> Result<WebhookDelivery, RepositoryError> {
+        let parse_error =
+            |e: uuid::Error| RepositoryError::FetchFailed(format!("IDの解析に失敗しました: {}", e));
+        Ok(WebhookDelivery {
+            id: Uuid::parse_str(row.get("id")).map_err(parse_error)?,
+            subscription_id: Uuid::parse_str(row.get("subscription_id")).map_err(parse_error)?,
+            event_id: Uuid::parse_str(row.get("event_id")).map_err(parse_error)?,
+            event_type: row.get("event_type"),
+            order_id: OrderId::from_string(&row.get::<String, _>("order_id"))
+                .map_err(parse_error)?,
+            payload: row.get("payload"),
+            status: WebhookDeliveryStatus::from_string(row.get("status")).map_err(|e| {
+                RepositoryError::FetchFailed(format!("配信結果の解析に失敗しました: {}", e))
+            })?,
+            response_status: row.get("response_status"),
+            error: row.get("error"),
+            redelivery_of: row
+                .get::<Option<String>, _>("redelivery_of")
+                .map(|id| Uuid::parse_str(&id))
+                .transpose()
+                .map_err(parse_error)?,
+            attempted_at: row.get::<DateTime<Utc>, _>("attempted_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for MySqlWebhookDeliveryRepository {
+    #[tracing::instrument(name = "db.webhook_deliveries.record", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "webhook_deliveries", subscription_id = %delivery.subscription_id, event_type = %delivery.event_type), err)]
+    async fn record(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries
+                (id, subscription_id, event_id, event_type, order_id, payload, status, response_status, error, redelivery_of, attempted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(delivery.id.to_string())
+        .bind(delivery.subscription_id.to_string())
+        .bind(delivery.event_id.to_string())
+        .bind(&delivery.event_type)
+        .bind(delivery.order_id.to_string())
+        .bind(&delivery.payload)
+        .bind(delivery.status.to_string())
+        .bind(delivery.response_status)
+        .bind(&delivery.error)
+        .bind(delivery.redelivery_of.map(|id| id.to_string()))
+        .bind(delivery.attempted_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("Webhookの配信記録の保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.webhook_deliveries.find_by_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "webhook_deliveries", delivery_id = %id), err)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let row = sqlx::query(&format!("{} WHERE id = ?", SELECT_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                DatabaseError::QueryError(format!("Webhookの配信記録の取得に失敗しました: {}", e))
+            })
+            .map_err(RepositoryError::from)?;
+
+        row.as_ref().map(Self::delivery_from_row).transpose()
+    }
+
+    #[tracing::instrument(name = "db.webhook_deliveries.find_by_subscription", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "webhook_deliveries", subscription_id = %subscription_id), err)]
+    async fn find_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE subscription_id = ? ORDER BY attempted_at DESC, id DESC LIMIT ?",
+            SELECT_COLUMNS
+        ))
+        .bind(subscription_id.to_string())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("Webhookの配信記録の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::delivery_from_row).collect()
+    }
+}
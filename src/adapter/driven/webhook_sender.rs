@@ -0,0 +1,105 @@
+use crate::domain::customer_webhook::{WebhookOutcome, WebhookSubscription};
+use crate::domain::port::WebhookSender;
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// 配信先の応答を待つ時間
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// ペイロードの署名を作成する
+/// "{タイムスタンプ}.{ペイロード}"をシークレットでHMAC-SHA256し、16進数で返す
+/// タイムスタンプを署名に含めることで、受信側が古い配信の再送（リプレイ）を検出できる
+fn sign(secret: &str, timestamp: i64, payload: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMACは任意の長さの鍵を受け付ける");
+    mac.update(format!("{}.{}", timestamp, payload).as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// HTTP Webhook送信の実装
+/// ペイロードを購読のURLにPOSTし、次のヘッダーを付ける
+/// - X-Webhook-Id: 配信ID
+/// - X-Webhook-Event: イベントの種類
+/// - X-Webhook-Timestamp: 送信時刻（UNIX秒）
+/// - X-Webhook-Signature: sha256=<署名>
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Default for HttpWebhookSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(
+        &self,
+        subscription: &WebhookSubscription,
+        delivery_id: Uuid,
+        event_type: &str,
+        payload: &str,
+    ) -> WebhookOutcome {
+        let timestamp = Utc::now().timestamp();
+        let result = self
+            .client
+            .post(&subscription.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("X-Webhook-Id", delivery_id.to_string())
+            .header("X-Webhook-Event", event_type)
+            .header("X-Webhook-Timestamp", timestamp.to_string())
+            .header(
+                "X-Webhook-Signature",
+                format!("sha256={}", sign(&subscription.secret, timestamp, payload)),
+            )
+            .body(payload.to_string())
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => WebhookOutcome::Delivered {
+                status: response.status().as_u16(),
+            },
+            Ok(response) => WebhookOutcome::Rejected {
+                status: response.status().as_u16(),
+            },
+            Err(e) => WebhookOutcome::Unreachable {
+                reason: e.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_is_hmac_sha256_of_timestamp_and_payload() {
+        // echo -n '1700000000.{"a":1}' | openssl dgst -sha256 -hmac 'whsec_0123456789abcdef'
+        assert_eq!(
+            sign("whsec_0123456789abcdef", 1_700_000_000, r#"{"a":1}"#),
+            "9f5e37cdc7ea587c7c02062d93283cad000d66a831b2a1676418696db47ed6c2"
+        );
+        assert_ne!(
+            sign("whsec_0123456789abcdef", 1_700_000_001, r#"{"a":1}"#),
+            sign("whsec_0123456789abcdef", 1_700_000_000, r#"{"a":1}"#)
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::adapter::database_error::DatabaseError;
+use crate::domain::customer_webhook::WebhookSubscription;
+use crate::domain::model::CustomerId;
+use crate::domain::port::{RepositoryError, WebhookSubscriptionRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// MySQL関連のインポート
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySql, Pool, Row};
+
+/// MySQL Webhook購読リポジトリ
+/// MySQLデータベース（webhook_subscriptionsテーブル）で顧客ごとのWebhookの購読を管理する
+/// イベントの種類はカンマ区切りで保存する
+#[derive(Clone)]
+pub struct MySqlWebhookSubscriptionRepository {
+    pool: Pool<MySql>,
+}
+
+impl MySqlWebhookSubscriptionRepository {
+    /// 新しいMySQL Webhook購読リポジトリを作成
+    ///
+    /// # Arguments
+    /// * `pool` - MySQLコネクションプール
+    ///
+    /// # Returns
+    /// * MySqlWebhookSubscriptionRepositoryのインスタンス
+    pub fn new(pool: Pool<MySql>) -> Self {
+        Self { pool }
+    }
+
+    /// データベースの行から購読を構築する
+    fn subscription_from_row(row: &MySqlRow) -> Result<WebhookSubscription, RepositoryError> {
+        let parse_error =
+            |e: uuid::Error| RepositoryError::FetchFailed(format!("IDの解析に失敗しました: {}", e));
+        Ok(WebhookSubscription {
+            id: Uuid::parse_str(row.get("id")).map_err(parse_error)?,
+            customer_id: CustomerId::from_string(&row.get::<String, _>("customer_id"))
+                .map_err(parse_error)?,
+            url: row.get("url"),
+            secret: row.get("secret"),
+            event_types: row
+                .get::<String, _>("event_types")
+                .split(',')
+                .map(str::to_string)
+                .collect(),
+            created_at: row.get::<DateTime<Utc>, _>("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepository for MySqlWebhookSubscriptionRepository {
+    #[tracing::instrument(name = "db.webhook_subscriptions.save", skip_all, fields(db.system = "mysql", db.operation = "INSERT", db.sql.table = "webhook_subscriptions", customer_id = %subscription.customer_id), err)]
+    async fn save(&self, subscription: &WebhookSubscription) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_subscriptions (id, customer_id, url, secret, event_types, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                url = VALUES(url),
+                secret = VALUES(secret),
+                event_types = VALUES(event_types)
+            "#,
+        )
+        .bind(subscription.id.to_string())
+        .bind(subscription.customer_id.to_string())
+        .bind(&subscription.url)
+        .bind(&subscription.secret)
+        .bind(subscription.event_types.join(","))
+        .bind(subscription.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("Webhookの購読の保存に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.webhook_subscriptions.find_by_id", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "webhook_subscriptions", subscription_id = %id), err)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT id, customer_id, url, secret, event_types, created_at FROM webhook_subscriptions WHERE id = ?",
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("Webhookの購読の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        row.as_ref().map(Self::subscription_from_row).transpose()
+    }
+
+    #[tracing::instrument(name = "db.webhook_subscriptions.find_by_customer", skip_all, fields(db.system = "mysql", db.operation = "SELECT", db.sql.table = "webhook_subscriptions", customer_id = %customer_id), err)]
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT id, customer_id, url, secret, event_types, created_at FROM webhook_subscriptions WHERE customer_id = ? ORDER BY created_at, id",
+        )
+        .bind(customer_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DatabaseError::QueryError(format!("Webhookの購読の取得に失敗しました: {}", e))
+        })
+        .map_err(RepositoryError::from)?;
+
+        rows.iter().map(Self::subscription_from_row).collect()
+    }
+
+    #[tracing::instrument(name = "db.webhook_subscriptions.delete", skip_all, fields(db.system = "mysql", db.operation = "DELETE", db.sql.table = "webhook_subscriptions", customer_id = %customer_id, subscription_id = %id), err)]
+    async fn delete(&self, customer_id: CustomerId, id: Uuid) -> Result<bool, RepositoryError> {
+        // 配信ログは外部キーのON DELETE CASCADEで削除される
+        let result =
+            sqlx::query("DELETE FROM webhook_subscriptions WHERE id = ? AND customer_id = ?")
+                .bind(id.to_string())
+                .bind(customer_id.to_string())
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    DatabaseError::QueryError(format!("Webhookの購読の削除に失敗しました: {}", e))
+                })
+                .map_err(RepositoryError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -1,5 +1,12 @@
 // 駆動側アダプター（APIなど）
 
+pub mod admin_ui;
+#[cfg(feature = "e2e")]
+pub mod api_client;
+pub mod auth;
+pub mod grpc;
+pub mod legacy_order_import;
+pub mod openapi;
 pub mod request_dto;
 pub mod response_dto;
 pub mod rest_api;
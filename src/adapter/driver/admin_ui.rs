@@ -0,0 +1,76 @@
+// 管理画面（静的なHTML/JS）の配信
+// static/admin-ui 以下のファイルをバイナリに埋め込み、/admin/ui で配信する
+// （ビルド手順のない手書きのHTML/JSで、画面からは既存の管理用APIを呼び出す）
+
+use axum::{
+    extract::Path,
+    http::{header, StatusCode},
+    response::{IntoResponse, Redirect, Response},
+};
+use rust_embed::RustEmbed;
+
+/// 管理画面の静的ファイル
+/// リリースビルドではバイナリに埋め込み、デバッグビルドではディスクから読み込む（再ビルドせずに画面を編集できる）
+#[derive(RustEmbed)]
+#[folder = "static/admin-ui/"]
+struct AdminUiAssets;
+
+/// 管理画面のトップページのファイル名
+const INDEX_FILE: &str = "index.html";
+
+// 管理画面のトップページ（/admin/ui）
+// 相対パスで静的ファイルを読み込めるように、末尾にスラッシュを付けたパスへリダイレクトする
+pub async fn admin_ui_index() -> Redirect {
+    Redirect::permanent("/admin/ui/")
+}
+
+// 管理画面の静的ファイル（/admin/ui/*path、空のパスはトップページ）
+pub async fn admin_ui_asset(path: Option<Path<String>>) -> Response {
+    let path = path.map(|Path(path)| path).unwrap_or_default();
+    serve_asset(if path.is_empty() { INDEX_FILE } else { &path })
+}
+
+/// 埋め込んだ静的ファイルを拡張子に応じたContent-Typeで返す
+///
+/// # Arguments
+/// * `path` - static/admin-ui からの相対パス
+///
+/// # Returns
+/// * ファイルの内容（存在しない場合は404）
+fn serve_asset(path: &str) -> Response {
+    match AdminUiAssets::get(path) {
+        Some(file) => (
+            [
+                (header::CONTENT_TYPE, file.metadata.mimetype().to_string()),
+                // 画面の更新がすぐに反映されるように、ブラウザーにキャッシュさせない
+                (header::CACHE_CONTROL, "no-cache".to_string()),
+            ],
+            file.data,
+        )
+            .into_response(),
+        None => (StatusCode::NOT_FOUND, "Not Found").into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content_type(response: &Response) -> &str {
+        response.headers()[header::CONTENT_TYPE].to_str().unwrap()
+    }
+
+    #[test]
+    fn test_serve_asset_returns_embedded_files_with_content_type() {
+        let index = serve_asset(INDEX_FILE);
+        assert_eq!(index.status(), StatusCode::OK);
+        assert!(content_type(&index).starts_with("text/html"));
+
+        let script = serve_asset("app.js");
+        assert_eq!(script.status(), StatusCode::OK);
+        assert!(content_type(&script).contains("javascript"));
+
+        assert_eq!(serve_asset("missing.js").status(), StatusCode::NOT_FOUND);
+        assert_eq!(serve_asset("../Cargo.toml").status(), StatusCode::NOT_FOUND);
+    }
+}
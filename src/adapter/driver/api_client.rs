@@ -0,0 +1,217 @@
+use crate::adapter::driver::request_dto::{
+    AddBookRequest, CreateInventoryRequest, CreateOrderRequest, RegisterCustomerRequest,
+    SetShippingAddressRequest,
+};
+use crate::adapter::driver::response_dto::{
+    CustomerResponse, InventoryResponse, OrderDetailResponse,
+};
+use crate::adapter::driver::rest_api::{ApiError, CommandResponse, CreateOrderResponse};
+use reqwest::{Client, RequestBuilder};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// APIクライアントのエラー
+#[derive(Debug, Error)]
+pub enum ApiClientError {
+    /// 接続失敗やレスポンスの解析失敗
+    #[error("リクエストに失敗しました: {0}")]
+    Transport(#[from] reqwest::Error),
+    /// APIがエラーを返した（レスポンスがApiError形式でない場合はcodeが空）
+    #[error("APIエラー（{status}）: {code} {message}")]
+    Api {
+        status: u16,
+        code: String,
+        message: String,
+    },
+}
+
+/// REST APIの型付きクライアント
+/// リクエスト・レスポンスにREST APIと同じDTOを使う（E2Eスモークテスト用）
+#[derive(Clone)]
+pub struct ApiClient {
+    base_url: String,
+    client: Client,
+}
+
+impl ApiClient {
+    /// ベースURL（例: "http://localhost:3000"）を指定してクライアントを作成
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            client: Client::new(),
+        }
+    }
+
+    /// ヘルスチェック
+    pub async fn health(&self) -> Result<serde_json::Value, ApiClientError> {
+        self.send_json(self.client.get(self.url("/health"))).await
+    }
+
+    /// 在庫を作成
+    pub async fn create_inventory(
+        &self,
+        request: &CreateInventoryRequest,
+    ) -> Result<(), ApiClientError> {
+        self.send(self.client.post(self.url("/inventory")).json(request))
+            .await
+    }
+
+    /// 書籍の在庫を取得
+    pub async fn get_inventory(&self, book_id: Uuid) -> Result<InventoryResponse, ApiClientError> {
+        self.send_json(
+            self.client
+                .get(self.url(&format!("/inventory/{}", book_id))),
+        )
+        .await
+    }
+
+    /// 顧客を登録
+    pub async fn register_customer(
+        &self,
+        request: &RegisterCustomerRequest,
+    ) -> Result<CustomerResponse, ApiClientError> {
+        self.send_json(self.client.post(self.url("/customers")).json(request))
+            .await
+    }
+
+    /// 顧客を取得
+    pub async fn get_customer(
+        &self,
+        customer_id: Uuid,
+    ) -> Result<CustomerResponse, ApiClientError> {
+        self.send_json(
+            self.client
+                .get(self.url(&format!("/customers/{}", customer_id))),
+        )
+        .await
+    }
+
+    /// 注文を作成
+    pub async fn create_order(
+        &self,
+        request: &CreateOrderRequest,
+    ) -> Result<CreateOrderResponse, ApiClientError> {
+        self.send_json(self.client.post(self.url("/orders")).json(request))
+            .await
+    }
+
+    /// 注文に書籍を追加
+    pub async fn add_book(
+        &self,
+        order_id: Uuid,
+        request: &AddBookRequest,
+    ) -> Result<CommandResponse, ApiClientError> {
+        self.send_json(
+            self.client
+                .post(self.url(&format!("/orders/{}/books", order_id)))
+                .json(request),
+        )
+        .await
+    }
+
+    /// 配送先住所を設定
+    pub async fn set_shipping_address(
+        &self,
+        order_id: Uuid,
+        request: &SetShippingAddressRequest,
+    ) -> Result<CommandResponse, ApiClientError> {
+        self.send_json(
+            self.client
+                .put(self.url(&format!("/orders/{}/shipping-address", order_id)))
+                .json(request),
+        )
+        .await
+    }
+
+    /// 注文を確定
+    pub async fn confirm_order(&self, order_id: Uuid) -> Result<CommandResponse, ApiClientError> {
+        self.send_json(
+            self.client
+                .post(self.url(&format!("/orders/{}/confirm", order_id))),
+        )
+        .await
+    }
+
+    /// 注文をキャンセル
+    pub async fn cancel_order(
+        &self,
+        order_id: Uuid,
+    ) -> Result<CommandResponse, ApiClientError> {
+        self.post_order_command(order_id, "cancel").await
+    }
+
+    /// 注文を発送済みにする
+    pub async fn mark_order_as_shipped(
+        &self,
+        order_id: Uuid,
+    ) -> Result<CommandResponse, ApiClientError> {
+        self.post_order_command(order_id, "ship").await
+    }
+
+    /// 注文を配達完了にする
+    pub async fn mark_order_as_delivered(
+        &self,
+        order_id: Uuid,
+    ) -> Result<CommandResponse, ApiClientError> {
+        self.post_order_command(order_id, "deliver").await
+    }
+
+    /// 注文詳細を取得
+    pub async fn get_order(&self, order_id: Uuid) -> Result<OrderDetailResponse, ApiClientError> {
+        self.send_json(self.client.get(self.url(&format!("/orders/{}", order_id))))
+            .await
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    async fn post_order_command(
+        &self,
+        order_id: Uuid,
+        command: &str,
+    ) -> Result<CommandResponse, ApiClientError> {
+        self.send_json(
+            self.client
+                .post(self.url(&format!("/orders/{}/{}", order_id, command))),
+        )
+        .await
+    }
+
+    /// リクエストを送信し、ボディのないレスポンスを受け取る
+    async fn send(&self, request: RequestBuilder) -> Result<(), ApiClientError> {
+        Self::ensure_success(request.send().await?).await?;
+        Ok(())
+    }
+
+    /// リクエストを送信し、JSONのレスポンスを受け取る
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<T, ApiClientError> {
+        let response = Self::ensure_success(request.send().await?).await?;
+        Ok(response.json().await?)
+    }
+
+    /// エラーのステータスコードをApiClientError::Apiに変換する
+    async fn ensure_success(
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, ApiClientError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let body = response.text().await?;
+        let (code, message) = match serde_json::from_str::<ApiError>(&body) {
+            Ok(error) => (error.code, error.error),
+            Err(_) => (String::new(), body),
+        };
+        Err(ApiClientError::Api {
+            status: status.as_u16(),
+            code,
+            message,
+        })
+    }
+}
@@ -0,0 +1,600 @@
+// APIの認証と認可
+// Bearerトークン（HS256で署名したJWT）から利用者の役割を取り出し、エンドポイントごとにアクセスを制限する
+// エンドポイントが要求する権限は AUTHZ_POLICIES の表で定義し、役割に付与した権限と照合する
+// （顧客は自分の注文と顧客情報だけ、発送・配達・在庫の変更・管理用APIはスタッフだけが操作できる）
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, Method, StatusCode, Uri},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use jsonwebtoken::{DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::adapter::auth_config::AuthConfig;
+use crate::adapter::driver::rest_api::ApiError;
+use crate::application::service::OrderApplicationService;
+use crate::domain::access_control::{Permission, Principal, Role};
+use crate::domain::model::{CustomerId, OrderId};
+use crate::domain::port::OrderRepository;
+
+/// トークンに含めるクレーム
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JwtClaims {
+    /// 利用者の識別子
+    pub sub: String,
+    pub role: Role,
+    /// 顧客の場合は顧客ID（顧客のトークンでは必須）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<Uuid>,
+    /// 有効期限（UNIX秒）
+    pub exp: i64,
+}
+
+/// 認証エラー
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("Bearer token is missing")]
+    MissingToken,
+    #[error("Invalid token: {0}")]
+    InvalidToken(String),
+    #[error("Customer token has no customer_id claim")]
+    MissingCustomerId,
+}
+
+/// JWTの検証
+pub struct JwtAuthenticator {
+    decoding_key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtAuthenticator {
+    /// 新しいJWTの検証を作成
+    ///
+    /// # Arguments
+    /// * `secret` - 署名（HS256）の検証に使うシークレット
+    /// * `issuer` - 受け付ける発行者（Noneの場合は検証しない）
+    /// * `audience` - 受け付ける対象者（Noneの場合は検証しない）
+    pub fn new(secret: &str, issuer: Option<&str>, audience: Option<&str>) -> Self {
+        let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
+        if let Some(issuer) = issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        match audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+        Self {
+            decoding_key: DecodingKey::from_secret(secret.as_bytes()),
+            validation,
+        }
+    }
+
+    /// 設定から作成する（シークレットが未設定の場合は認証を行わないためNone）
+    pub fn from_config(config: &AuthConfig) -> Option<Self> {
+        config.jwt_secret.as_deref().map(|secret| {
+            Self::new(secret, config.issuer.as_deref(), config.audience.as_deref())
+        })
+    }
+
+    /// トークンを検証して利用者を取り出す
+    ///
+    /// # Returns
+    /// * `Ok(Principal)` - 署名と有効期限が正しいトークンの利用者
+    /// * `Err(AuthError)` - 署名・有効期限・クレームが正しくない
+    pub fn authenticate(&self, token: &str) -> Result<Principal, AuthError> {
+        let claims = jsonwebtoken::decode::<JwtClaims>(token, &self.decoding_key, &self.validation)
+            .map_err(|e| AuthError::InvalidToken(e.to_string()))?
+            .claims;
+        match claims.role {
+            Role::Staff => Ok(Principal::staff(claims.sub)),
+            Role::Customer => {
+                let customer_id = claims.customer_id.ok_or(AuthError::MissingCustomerId)?;
+                Ok(Principal::customer(
+                    claims.sub,
+                    CustomerId::from_uuid(customer_id),
+                ))
+            }
+        }
+    }
+}
+
+/// ポリシーの対象のメソッド
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MethodScope {
+    Any,
+    /// GET・HEAD・OPTIONS
+    Read,
+    /// 参照以外のメソッド
+    Write,
+}
+
+impl MethodScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MethodScope::Any => "any",
+            MethodScope::Read => "read",
+            MethodScope::Write => "write",
+        }
+    }
+
+    fn matches(&self, method: &Method) -> bool {
+        let is_read = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+        match self {
+            MethodScope::Any => true,
+            MethodScope::Read => is_read,
+            MethodScope::Write => !is_read,
+        }
+    }
+}
+
+/// ポリシーが要求する条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement {
+    /// 認証なしで利用できる（ヘルスチェック・API仕様・署名付きのリンク・配送業者のWebhookなど）
+    Public,
+    /// 認証済みであれば利用できる
+    Authenticated,
+    /// 役割に権限が付与された利用者だけが利用できる
+    Permission(Permission),
+}
+
+impl Requirement {
+    /// 条件の名前（権限の場合は権限の名前）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Requirement::Public => "public",
+            Requirement::Authenticated => "authenticated",
+            Requirement::Permission(permission) => permission.as_str(),
+        }
+    }
+}
+
+/// 利用者を本人に限定するパスパラメータ（スタッフは限定しない）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnerScope {
+    None,
+    /// `:order_id`の注文を注文した顧客本人
+    Order,
+    /// `:customer_id`の顧客本人
+    Customer,
+}
+
+impl OwnerScope {
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            OwnerScope::None => None,
+            OwnerScope::Order => Some("order"),
+            OwnerScope::Customer => Some("customer"),
+        }
+    }
+}
+
+/// エンドポイントの認可ポリシー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndpointPolicy {
+    pub methods: MethodScope,
+    /// パスのパターン（`:name`は1つのセグメント、末尾の`*`は0個以上のセグメントに一致する）
+    pub path: &'static str,
+    pub requirement: Requirement,
+    pub owner: OwnerScope,
+}
+
+const fn public(path: &'static str) -> EndpointPolicy {
+    EndpointPolicy {
+        methods: MethodScope::Any,
+        path,
+        requirement: Requirement::Public,
+        owner: OwnerScope::None,
+    }
+}
+
+const fn permit(methods: MethodScope, path: &'static str, permission: Permission) -> EndpointPolicy {
+    EndpointPolicy {
+        methods,
+        path,
+        requirement: Requirement::Permission(permission),
+        owner: OwnerScope::None,
+    }
+}
+
+const fn owned(
+    methods: MethodScope,
+    path: &'static str,
+    permission: Permission,
+    owner: OwnerScope,
+) -> EndpointPolicy {
+    EndpointPolicy {
+        methods,
+        path,
+        requirement: Requirement::Permission(permission),
+        owner,
+    }
+}
+
+/// エンドポイントの認可ポリシーの一覧
+/// 上から順に照合して最初に一致したポリシーを適用し、一致しないエンドポイントは認証だけを求める
+/// （エンドポイントの権限はここでだけ定義し、GET /admin/authz-policies で参照できる）
+pub const AUTHZ_POLICIES: &[EndpointPolicy] = &[
+    public("/health"),
+    public("/health/ready"),
+    public("/openapi.json"),
+    public("/docs"),
+    public("/meta/*"),
+    public("/track/:token"),
+    public("/actions/:token"),
+    public("/webhooks/carrier"),
+    // 管理画面の静的ファイル（画面から呼び出す管理用APIにはスタッフのトークンが必要）
+    public("/admin/ui/*"),
+    permit(MethodScope::Any, "/admin/inventory/*", Permission::InventoryAdmin),
+    permit(MethodScope::Any, "/admin/orders/*", Permission::OrdersManage),
+    permit(MethodScope::Any, "/admin/parked-events", Permission::DlqManage),
+    permit(MethodScope::Any, "/admin/dead-letters/*", Permission::DlqManage),
+    permit(MethodScope::Any, "/admin/retry-policies", Permission::DlqManage),
+    permit(MethodScope::Any, "/admin/notifications/*", Permission::DlqManage),
+    permit(MethodScope::Any, "/admin/*", Permission::AdminAccess),
+    permit(MethodScope::Any, "/metrics", Permission::AdminAccess),
+    permit(MethodScope::Any, "/reports/*", Permission::AdminAccess),
+    // 注文の一覧は全顧客の注文を含むため、顧客は /customers/:customer_id/orders を使う
+    permit(MethodScope::Read, "/orders", Permission::OrdersManage),
+    // 注文する顧客の確認はハンドラーで行う
+    permit(MethodScope::Write, "/orders", Permission::OrdersWrite),
+    permit(MethodScope::Any, "/orders/bulk/*", Permission::OrdersManage),
+    permit(MethodScope::Any, "/orders/:order_id/ship", Permission::OrdersManage),
+    permit(MethodScope::Any, "/orders/:order_id/deliver", Permission::OrdersManage),
+    permit(MethodScope::Any, "/orders/:order_id/hold", Permission::OrdersManage),
+    permit(MethodScope::Any, "/orders/:order_id/release", Permission::OrdersManage),
+    permit(MethodScope::Any, "/orders/:order_id/notes", Permission::OrdersManage),
+    permit(MethodScope::Any, "/orders/:order_id/timeline", Permission::OrdersManage),
+    permit(MethodScope::Any, "/orders/:order_id/return/approve", Permission::OrdersManage),
+    owned(MethodScope::Read, "/orders/:order_id/*", Permission::OrdersRead, OwnerScope::Order),
+    owned(MethodScope::Write, "/orders/:order_id/*", Permission::OrdersWrite, OwnerScope::Order),
+    permit(MethodScope::Write, "/inventory/*", Permission::InventoryAdmin),
+    permit(MethodScope::Write, "/catalog/*", Permission::InventoryAdmin),
+    owned(MethodScope::Read, "/customers/:customer_id/*", Permission::CustomersRead, OwnerScope::Customer),
+    owned(MethodScope::Write, "/customers/:customer_id/*", Permission::CustomersWrite, OwnerScope::Customer),
+];
+
+/// リクエストの利用者を限定する注文・顧客
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Owner {
+    Order(OrderId),
+    Customer(CustomerId),
+}
+
+/// リクエストに適用するアクセスの条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessRule {
+    pub requirement: Requirement,
+    pub owner: Option<Owner>,
+}
+
+/// 一致するポリシーがないエンドポイントの条件
+/// （再試行待ちの操作の参照など、対象の確認をハンドラーで行うエンドポイントも含む）
+pub const DEFAULT_RULE: AccessRule = AccessRule {
+    requirement: Requirement::Authenticated,
+    owner: None,
+};
+
+/// リクエストのメソッドとパスに一致するポリシーからアクセスの条件を決める
+/// パスのIDがUUIDでない場合は利用者を限定せず、IDの検証はハンドラーに任せる
+pub fn access_rule(method: &Method, path: &str) -> AccessRule {
+    AUTHZ_POLICIES
+        .iter()
+        .filter(|policy| policy.methods.matches(method))
+        .find_map(|policy| {
+            let params = match_path(policy.path, path)?;
+            let param = |name: &str| {
+                params
+                    .iter()
+                    .find(|(param, _)| *param == name)
+                    .and_then(|(_, value)| Uuid::parse_str(value).ok())
+            };
+            let owner = match policy.owner {
+                OwnerScope::None => None,
+                OwnerScope::Order => param("order_id").map(|id| Owner::Order(OrderId::from_uuid(id))),
+                OwnerScope::Customer => {
+                    param("customer_id").map(|id| Owner::Customer(CustomerId::from_uuid(id)))
+                }
+            };
+            Some(AccessRule {
+                requirement: policy.requirement,
+                owner,
+            })
+        })
+        .unwrap_or(DEFAULT_RULE)
+}
+
+/// パスをポリシーのパターンと照合し、一致した場合はパスパラメータを返す
+fn match_path<'a>(pattern: &'static str, path: &'a str) -> Option<Vec<(&'static str, &'a str)>> {
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+    let mut params = Vec::new();
+    for expected in pattern.split('/').filter(|segment| !segment.is_empty()) {
+        if expected == "*" {
+            return Some(params);
+        }
+        let segment = segments.next()?;
+        match expected.strip_prefix(':') {
+            Some(name) => params.push((name, segment)),
+            None if expected == segment => {}
+            None => return None,
+        }
+    }
+    segments.next().is_none().then_some(params)
+}
+
+/// 認証のミドルウェアの状態
+#[derive(Clone)]
+pub struct AuthState {
+    pub authenticator: Arc<JwtAuthenticator>,
+    /// 注文した顧客の確認に使う
+    pub order_service: Arc<OrderApplicationService<Arc<dyn OrderRepository>>>,
+}
+
+/// 認証と認可のミドルウェア
+/// トークンがない・不正な場合は401、役割や顧客が一致しない場合は403で拒否する
+/// 認証した利用者はリクエストの拡張に入れ、ハンドラーから参照できるようにする
+pub async fn require_authorization(
+    State(state): State<AuthState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let rule = access_rule(request.method(), request.uri().path());
+    if rule.requirement == Requirement::Public {
+        return next.run(request).await;
+    }
+
+    let principal = match bearer_token(request.headers())
+        .or_else(|| websocket_access_token(request.uri()))
+        .ok_or(AuthError::MissingToken)
+        .and_then(|token| state.authenticator.authenticate(token))
+    {
+        Ok(principal) => principal,
+        Err(err) => {
+            tracing::debug!(error = %err, "authentication failed");
+            return unauthorized().into_response();
+        }
+    };
+
+    let permitted = match rule.requirement {
+        Requirement::Public | Requirement::Authenticated => true,
+        Requirement::Permission(permission) => principal.has_permission(permission),
+    };
+    let allowed = permitted && match rule.owner {
+        None => true,
+        Some(Owner::Customer(customer_id)) => principal.can_act_for(customer_id),
+        Some(Owner::Order(_)) if principal.is_staff() => true,
+        Some(Owner::Order(order_id)) => {
+            match state.order_service.get_order_by_id(order_id).await {
+                Ok(Some(order)) => principal.can_act_for(order.customer_id()),
+                // 存在しない注文はハンドラーで404にする
+                Ok(None) => true,
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to load order for authorization");
+                    false
+                }
+            }
+        }
+    };
+    if !allowed {
+        return forbidden().into_response();
+    }
+
+    request.extensions_mut().insert(principal);
+    next.run(request).await
+}
+
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+/// WebSocketの接続（/ws/*）のクエリのaccess_token
+/// ブラウザのWebSocketはAuthorizationヘッダーを付けられないため、WebSocketに限ってクエリのトークンを受け付ける
+/// （JWTはURLで使える文字だけのため、デコードせずに使う）
+fn websocket_access_token(uri: &Uri) -> Option<&str> {
+    if !uri.path().starts_with("/ws/") {
+        return None;
+    }
+    uri.query()?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("access_token="))
+        .filter(|token| !token.is_empty())
+}
+
+fn unauthorized() -> (StatusCode, [(header::HeaderName, &'static str); 1], Json<ApiError>) {
+    (
+        StatusCode::UNAUTHORIZED,
+        [(header::WWW_AUTHENTICATE, "Bearer")],
+        Json(ApiError {
+            error: "有効なBearerトークンを指定してください".to_string(),
+            code: "UNAUTHORIZED".to_string(),
+            violations: Vec::new(),
+        }),
+    )
+}
+
+/// 認可エラーのレスポンス（ハンドラーで本文の内容を確認する場合にも使う）
+pub fn forbidden() -> (StatusCode, Json<ApiError>) {
+    (
+        StatusCode::FORBIDDEN,
+        Json(ApiError {
+            error: "この操作を行う権限がありません".to_string(),
+            code: "FORBIDDEN".to_string(),
+            violations: Vec::new(),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{EncodingKey, Header};
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    fn token(claims: &JwtClaims, secret: &str) -> String {
+        jsonwebtoken::encode(
+            &Header::default(),
+            claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    fn claims(role: Role, customer_id: Option<Uuid>) -> JwtClaims {
+        JwtClaims {
+            sub: "user-1".to_string(),
+            role,
+            customer_id,
+            exp: chrono::Utc::now().timestamp() + 3600,
+        }
+    }
+
+    #[test]
+    fn test_authenticate_accepts_only_valid_tokens() {
+        let authenticator = JwtAuthenticator::new(SECRET, None, None);
+        let customer_id = Uuid::new_v4();
+
+        let principal = authenticator
+            .authenticate(&token(&claims(Role::Customer, Some(customer_id)), SECRET))
+            .unwrap();
+        assert_eq!(
+            principal,
+            Principal::customer("user-1", CustomerId::from_uuid(customer_id))
+        );
+        assert!(authenticator
+            .authenticate(&token(&claims(Role::Staff, None), SECRET))
+            .unwrap()
+            .is_staff());
+
+        // 別のシークレットで署名したトークン・期限切れのトークン・顧客IDのない顧客のトークンは拒否する
+        let other_secret = "fedcba9876543210fedcba9876543210";
+        assert!(matches!(
+            authenticator.authenticate(&token(&claims(Role::Staff, None), other_secret)),
+            Err(AuthError::InvalidToken(_))
+        ));
+        let mut expired = claims(Role::Staff, None);
+        expired.exp = chrono::Utc::now().timestamp() - 3600;
+        assert!(matches!(
+            authenticator.authenticate(&token(&expired, SECRET)),
+            Err(AuthError::InvalidToken(_))
+        ));
+        assert!(matches!(
+            authenticator.authenticate(&token(&claims(Role::Customer, None), SECRET)),
+            Err(AuthError::MissingCustomerId)
+        ));
+    }
+
+    fn permission(permission: Permission) -> AccessRule {
+        AccessRule {
+            requirement: Requirement::Permission(permission),
+            owner: None,
+        }
+    }
+
+    fn owned_by(permission: Permission, owner: Owner) -> AccessRule {
+        AccessRule {
+            requirement: Requirement::Permission(permission),
+            owner: Some(owner),
+        }
+    }
+
+    #[test]
+    fn test_access_rule_restricts_fulfillment_and_inventory_to_staff() {
+        let order_id = Uuid::new_v4();
+        let order_path = |suffix: &str| format!("/orders/{}{}", order_id, suffix);
+        let manage = permission(Permission::OrdersManage);
+        let inventory_admin = permission(Permission::InventoryAdmin);
+
+        assert_eq!(access_rule(&Method::POST, &order_path("/ship")), manage);
+        assert_eq!(access_rule(&Method::POST, &order_path("/deliver")), manage);
+        assert_eq!(access_rule(&Method::POST, &order_path("/return/approve")), manage);
+        assert_eq!(access_rule(&Method::POST, "/orders/bulk/ship"), manage);
+        assert_eq!(access_rule(&Method::GET, "/orders"), manage);
+        assert_eq!(access_rule(&Method::POST, "/inventory"), inventory_admin);
+        assert_eq!(
+            access_rule(&Method::PUT, "/inventory/counts/abc/entries"),
+            inventory_admin
+        );
+        assert_eq!(access_rule(&Method::POST, "/admin/inventory/repair"), inventory_admin);
+        assert_eq!(access_rule(&Method::GET, "/inventory"), DEFAULT_RULE);
+        assert_eq!(
+            access_rule(&Method::POST, "/admin/dead-letters"),
+            permission(Permission::DlqManage)
+        );
+        assert_eq!(
+            access_rule(&Method::GET, "/admin/authz-policies"),
+            permission(Permission::AdminAccess)
+        );
+    }
+
+    #[test]
+    fn test_access_rule_scopes_orders_and_customers_to_their_owner() {
+        let order_id = Uuid::new_v4();
+        let customer_id = Uuid::new_v4();
+        let order = Owner::Order(OrderId::from_uuid(order_id));
+
+        assert_eq!(
+            access_rule(&Method::POST, &format!("/orders/{}/cancel", order_id)),
+            owned_by(Permission::OrdersWrite, order)
+        );
+        assert_eq!(
+            access_rule(&Method::GET, &format!("/orders/{}", order_id)),
+            owned_by(Permission::OrdersRead, order)
+        );
+        assert_eq!(
+            access_rule(&Method::GET, &format!("/customers/{}/orders", customer_id)),
+            owned_by(
+                Permission::CustomersRead,
+                Owner::Customer(CustomerId::from_uuid(customer_id))
+            )
+        );
+        assert_eq!(access_rule(&Method::POST, "/orders"), permission(Permission::OrdersWrite));
+        assert_eq!(
+            access_rule(&Method::GET, &format!("/operations/{}", Uuid::new_v4())),
+            DEFAULT_RULE
+        );
+        assert_eq!(
+            access_rule(&Method::GET, "/orders/not-a-uuid"),
+            permission(Permission::OrdersRead)
+        );
+        let public = |path: &str| access_rule(&Method::GET, path).requirement == Requirement::Public;
+        assert!(public("/health"));
+        assert!(public("/health/ready"));
+        assert!(public("/admin/ui"));
+        assert!(public("/admin/ui/app.js"));
+        assert!(access_rule(&Method::POST, "/actions/token").requirement == Requirement::Public);
+        assert!(!public("/healthz"));
+    }
+
+    #[test]
+    fn test_match_path_binds_parameters_and_trailing_wildcards() {
+        assert_eq!(
+            match_path("/orders/:order_id/*", "/orders/abc/lines/1"),
+            Some(vec![("order_id", "abc")])
+        );
+        assert_eq!(match_path("/orders/:order_id/*", "/orders/abc/"), Some(vec![("order_id", "abc")]));
+        assert_eq!(match_path("/orders/:order_id/*", "/orders"), None);
+        assert_eq!(match_path("/orders/:order_id/ship", "/orders/abc/ship/now"), None);
+        assert_eq!(match_path("/metrics", "/metrics"), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_websocket_access_token_is_read_from_query_only_for_websockets() {
+        let uri: Uri = "/ws/notifications?customer_id=c1&access_token=abc.def".parse().unwrap();
+        assert_eq!(websocket_access_token(&uri), Some("abc.def"));
+        let uri: Uri = "/ws/notifications?access_token=".parse().unwrap();
+        assert_eq!(websocket_access_token(&uri), None);
+        let uri: Uri = "/orders?access_token=abc.def".parse().unwrap();
+        assert_eq!(websocket_access_token(&uri), None);
+    }
+}
@@ -0,0 +1,564 @@
+// gRPC API（tonic）
+// REST APIと同じアプリケーションサービスを共有し、注文と在庫の主な操作を公開する
+// tonic::Statusはサービスのトレイトが返す型のため、Resultのサイズの警告は抑止する
+#![allow(clippy::result_large_err)]
+
+use crate::adapter::driver::auth::JwtAuthenticator;
+use crate::adapter::driver::rest_api::map_application_error;
+use crate::application::error::ApplicationError;
+use crate::application::service::{
+    CommandAcknowledgement, InventoryApplicationService, OrderApplicationService,
+};
+use crate::domain::model::{
+    BookId, BookSpec, CustomerId, FulfillmentType, Inventory, Money, Order, OrderId,
+};
+use crate::domain::port::OrderRepository;
+use crate::domain::warning::DomainWarning;
+use axum::http::StatusCode;
+use chrono::NaiveDate;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tonic::metadata::MetadataValue;
+use tonic::service::Interceptor;
+use tonic::{Code, Request, Response, Status};
+use uuid::Uuid;
+
+/// proto/bookstore/v1/order_service.proto から生成したメッセージとサービス
+pub mod proto {
+    tonic::include_proto!("bookstore.v1");
+}
+
+use proto::inventory_service_server::{InventoryService, InventoryServiceServer};
+use proto::order_service_server::{OrderService, OrderServiceServer};
+
+/// エラーの種類（REST APIのApiError.codeと同じ値）を返すメタデータのキー
+pub const ERROR_CODE_METADATA_KEY: &str = "x-error-code";
+
+// アプリケーションエラーをgRPCのステータスにマッピング
+// エラーコードとメッセージはREST APIと同じものを使い、HTTPステータスを対応するgRPCのコードに置き換える
+fn map_application_status(err: ApplicationError) -> Status {
+    let (status, error) = map_application_error(err);
+    error_status(status, &error.0.code, error.0.error)
+}
+
+fn error_status(status: StatusCode, error_code: &str, message: String) -> Status {
+    let code = match status {
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Code::InvalidArgument,
+        StatusCode::UNAUTHORIZED => Code::Unauthenticated,
+        StatusCode::FORBIDDEN => Code::PermissionDenied,
+        StatusCode::NOT_FOUND => Code::NotFound,
+        StatusCode::CONFLICT => Code::Aborted,
+        StatusCode::TOO_MANY_REQUESTS => Code::ResourceExhausted,
+        StatusCode::NOT_IMPLEMENTED => Code::Unimplemented,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE => Code::Unavailable,
+        _ => Code::Internal,
+    };
+    let mut status = Status::new(code, message);
+    if let Ok(value) = MetadataValue::try_from(error_code) {
+        status.metadata_mut().insert(ERROR_CODE_METADATA_KEY, value);
+    }
+    status
+}
+
+fn not_found(error_code: &str, message: &str) -> Status {
+    error_status(StatusCode::NOT_FOUND, error_code, message.to_string())
+}
+
+/// UUIDの項目を読み取る
+fn parse_uuid(field: &str, value: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(value.trim()).map_err(|_| {
+        error_status(
+            StatusCode::BAD_REQUEST,
+            "INVALID_VALUE",
+            format!("{}はUUIDで指定してください", field),
+        )
+    })
+}
+
+fn command_response(acknowledgement: CommandAcknowledgement) -> proto::CommandResponse {
+    proto::CommandResponse {
+        warnings: warnings(acknowledgement.warnings),
+        event_type: Some(acknowledgement.event.event_type().to_string()),
+        event_id: Some(acknowledgement.event.metadata().event_id.to_string()),
+        operation_id: acknowledgement
+            .pending_operation_id
+            .map(|operation_id| operation_id.to_string()),
+    }
+}
+
+fn warnings(warnings: Vec<DomainWarning>) -> Vec<proto::Warning> {
+    warnings
+        .into_iter()
+        .map(|warning| proto::Warning {
+            code: warning.code,
+            message: warning.message,
+        })
+        .collect()
+}
+
+fn order_message(order: &Order) -> proto::Order {
+    proto::Order {
+        order_id: order.id().to_string(),
+        customer_id: order.customer_id().to_string(),
+        status: order.status().to_string(),
+        lines: order
+            .order_lines()
+            .iter()
+            .map(|line| proto::OrderLine {
+                book_id: line.book_id().to_string(),
+                quantity: line.quantity(),
+                unit_price: line.unit_price().amount(),
+            })
+            .collect(),
+        total_amount: order.calculate_total().amount(),
+    }
+}
+
+fn inventory_message(inventory: &Inventory) -> proto::Inventory {
+    proto::Inventory {
+        book_id: inventory.book_id().to_string(),
+        quantity_on_hand: inventory.quantity_on_hand(),
+        release_date: inventory
+            .release_date()
+            .map(|date| date.format("%Y-%m-%d").to_string()),
+        frozen: inventory.is_frozen(),
+        waitlist_enabled: inventory.is_waitlist_enabled(),
+    }
+}
+
+/// 注文のgRPCサービス
+pub struct GrpcOrderService<OR>
+where
+    OR: OrderRepository,
+{
+    order_service: Arc<OrderApplicationService<OR>>,
+}
+
+impl<OR> GrpcOrderService<OR>
+where
+    OR: OrderRepository,
+{
+    /// 新しい注文のgRPCサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_service` - REST APIと共有する注文アプリケーションサービス
+    pub fn new(order_service: Arc<OrderApplicationService<OR>>) -> Self {
+        Self { order_service }
+    }
+}
+
+#[tonic::async_trait]
+impl<OR> OrderService for GrpcOrderService<OR>
+where
+    OR: OrderRepository + 'static,
+{
+    async fn create_order(
+        &self,
+        request: Request<proto::CreateOrderRequest>,
+    ) -> Result<Response<proto::CreateOrderResponse>, Status> {
+        let request = request.into_inner();
+        let customer_id = CustomerId::from_uuid(parse_uuid("customer_id", &request.customer_id)?);
+
+        let order_id = self
+            .order_service
+            .create_order(customer_id)
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(proto::CreateOrderResponse {
+            order_id: order_id.to_string(),
+            customer_id: customer_id.to_string(),
+        }))
+    }
+
+    async fn add_book(
+        &self,
+        request: Request<proto::AddBookRequest>,
+    ) -> Result<Response<proto::CommandResponse>, Status> {
+        let request = request.into_inner();
+        let order_id = OrderId::from_uuid(parse_uuid("order_id", &request.order_id)?);
+        let book_id = BookId::from_uuid(parse_uuid("book_id", &request.book_id)?);
+        let fulfillment_type = match request.fulfillment_type() {
+            proto::FulfillmentType::Physical => FulfillmentType::Physical,
+            proto::FulfillmentType::Digital => FulfillmentType::Digital,
+        };
+        let spec = request
+            .spec
+            .map(|spec| {
+                BookSpec::new(
+                    spec.weight_grams,
+                    spec.width_mm,
+                    spec.height_mm,
+                    spec.thickness_mm,
+                )
+            })
+            .transpose()
+            .map_err(|err| map_application_status(err.into()))?;
+
+        let warnings = self
+            .order_service
+            .add_book_to_order_with_spec(
+                order_id,
+                book_id,
+                request.quantity,
+                Money::jpy(request.unit_price),
+                fulfillment_type,
+                spec,
+            )
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(proto::CommandResponse {
+            warnings: self::warnings(warnings),
+            ..Default::default()
+        }))
+    }
+
+    async fn set_shipping_address(
+        &self,
+        request: Request<proto::SetShippingAddressRequest>,
+    ) -> Result<Response<proto::CommandResponse>, Status> {
+        let request = request.into_inner();
+        let order_id = OrderId::from_uuid(parse_uuid("order_id", &request.order_id)?);
+
+        let warnings = self
+            .order_service
+            .set_shipping_address_from_request(
+                order_id,
+                request.postal_code,
+                request.prefecture,
+                request.city,
+                request.address_line1,
+                request.address_line2,
+            )
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(proto::CommandResponse {
+            warnings: self::warnings(warnings),
+            ..Default::default()
+        }))
+    }
+
+    async fn confirm_order(
+        &self,
+        request: Request<proto::OrderCommandRequest>,
+    ) -> Result<Response<proto::CommandResponse>, Status> {
+        let order_id = OrderId::from_uuid(parse_uuid("order_id", &request.get_ref().order_id)?);
+
+        let acknowledgement = self
+            .order_service
+            .confirm_order(order_id)
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(command_response(acknowledgement)))
+    }
+
+    async fn cancel_order(
+        &self,
+        request: Request<proto::OrderCommandRequest>,
+    ) -> Result<Response<proto::CommandResponse>, Status> {
+        let order_id = OrderId::from_uuid(parse_uuid("order_id", &request.get_ref().order_id)?);
+
+        let acknowledgement = self
+            .order_service
+            .cancel_order(order_id)
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(command_response(acknowledgement)))
+    }
+
+    async fn ship_order(
+        &self,
+        request: Request<proto::OrderCommandRequest>,
+    ) -> Result<Response<proto::CommandResponse>, Status> {
+        let request = request.into_inner();
+        let order_id = OrderId::from_uuid(parse_uuid("order_id", &request.order_id)?);
+        let correlation_id = request
+            .correlation_id
+            .map(|correlation_id| parse_uuid("correlation_id", &correlation_id))
+            .transpose()?;
+
+        let acknowledgement = self
+            .order_service
+            .mark_order_as_shipped(order_id, correlation_id)
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(command_response(acknowledgement)))
+    }
+
+    async fn deliver_order(
+        &self,
+        request: Request<proto::OrderCommandRequest>,
+    ) -> Result<Response<proto::CommandResponse>, Status> {
+        let request = request.into_inner();
+        let order_id = OrderId::from_uuid(parse_uuid("order_id", &request.order_id)?);
+        let correlation_id = request
+            .correlation_id
+            .map(|correlation_id| parse_uuid("correlation_id", &correlation_id))
+            .transpose()?;
+
+        let acknowledgement = self
+            .order_service
+            .mark_order_as_delivered(order_id, correlation_id)
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(command_response(acknowledgement)))
+    }
+
+    async fn get_order(
+        &self,
+        request: Request<proto::GetOrderRequest>,
+    ) -> Result<Response<proto::Order>, Status> {
+        let order_id = OrderId::from_uuid(parse_uuid("order_id", &request.get_ref().order_id)?);
+
+        match self
+            .order_service
+            .get_order_by_id(order_id)
+            .await
+            .map_err(map_application_status)?
+        {
+            Some(order) => Ok(Response::new(order_message(&order))),
+            None => Err(not_found(
+                "ORDER_NOT_FOUND",
+                "指定された注文が見つかりません",
+            )),
+        }
+    }
+}
+
+/// 在庫のgRPCサービス
+pub struct GrpcInventoryService {
+    inventory_service: Arc<InventoryApplicationService>,
+}
+
+impl GrpcInventoryService {
+    /// 新しい在庫のgRPCサービスを作成
+    ///
+    /// # Arguments
+    /// * `inventory_service` - REST APIと共有する在庫アプリケーションサービス
+    pub fn new(inventory_service: Arc<InventoryApplicationService>) -> Self {
+        Self { inventory_service }
+    }
+
+    async fn find_inventory(&self, book_id: BookId) -> Result<proto::Inventory, Status> {
+        match self
+            .inventory_service
+            .get_inventory_by_book_id(book_id)
+            .await
+            .map_err(map_application_status)?
+        {
+            Some(inventory) => Ok(inventory_message(&inventory)),
+            None => Err(not_found(
+                "INVENTORY_NOT_FOUND",
+                "指定された書籍の在庫が見つかりません",
+            )),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl InventoryService for GrpcInventoryService {
+    async fn create_inventory(
+        &self,
+        request: Request<proto::CreateInventoryRequest>,
+    ) -> Result<Response<proto::Inventory>, Status> {
+        let request = request.into_inner();
+        let book_id = BookId::from_uuid(parse_uuid("book_id", &request.book_id)?);
+        let release_date = request
+            .release_date
+            .map(|date| {
+                NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|_| {
+                    error_status(
+                        StatusCode::BAD_REQUEST,
+                        "INVALID_VALUE",
+                        "release_dateはYYYY-MM-DDで指定してください".to_string(),
+                    )
+                })
+            })
+            .transpose()?;
+
+        self.inventory_service
+            .create_inventory(
+                book_id,
+                request.quantity,
+                release_date,
+                request.waitlist_enabled,
+            )
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(self.find_inventory(book_id).await?))
+    }
+
+    async fn get_inventory(
+        &self,
+        request: Request<proto::GetInventoryRequest>,
+    ) -> Result<Response<proto::Inventory>, Status> {
+        let book_id = BookId::from_uuid(parse_uuid("book_id", &request.get_ref().book_id)?);
+        Ok(Response::new(self.find_inventory(book_id).await?))
+    }
+
+    async fn restock_inventory(
+        &self,
+        request: Request<proto::RestockInventoryRequest>,
+    ) -> Result<Response<proto::Inventory>, Status> {
+        let request = request.into_inner();
+        let book_id = BookId::from_uuid(parse_uuid("book_id", &request.book_id)?);
+
+        let inventory = self
+            .inventory_service
+            .restock_inventory(book_id, request.quantity)
+            .await
+            .map_err(map_application_status)?;
+        Ok(Response::new(inventory_message(&inventory)))
+    }
+}
+
+/// gRPC APIの認証
+/// 認証を有効にしている場合（AUTH_JWT_SECRET）、社内のサービス向けのAPIとしてスタッフのトークンだけを受け付ける
+#[derive(Clone)]
+pub struct StaffAuthInterceptor {
+    authenticator: Option<Arc<JwtAuthenticator>>,
+}
+
+impl StaffAuthInterceptor {
+    /// 新しい認証を作成（authenticatorがNoneの場合はすべてのリクエストを受け付ける）
+    pub fn new(authenticator: Option<Arc<JwtAuthenticator>>) -> Self {
+        Self { authenticator }
+    }
+}
+
+impl Interceptor for StaffAuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let Some(authenticator) = &self.authenticator else {
+            return Ok(request);
+        };
+        let principal = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or_else(|| {
+                error_status(
+                    StatusCode::UNAUTHORIZED,
+                    "UNAUTHORIZED",
+                    "Bearerトークンを指定してください".to_string(),
+                )
+            })
+            .and_then(|token| {
+                authenticator.authenticate(token.trim()).map_err(|err| {
+                    error_status(StatusCode::UNAUTHORIZED, "UNAUTHORIZED", err.to_string())
+                })
+            })?;
+        if !principal.is_staff() {
+            return Err(error_status(
+                StatusCode::FORBIDDEN,
+                "FORBIDDEN",
+                "この操作を行う権限がありません".to_string(),
+            ));
+        }
+        request.extensions_mut().insert(principal);
+        Ok(request)
+    }
+}
+
+/// gRPCサーバーを起動し、終了するまで待つ
+///
+/// # Arguments
+/// * `addr` - 待ち受けるアドレス
+/// * `order_service` - REST APIと共有する注文アプリケーションサービス
+/// * `inventory_service` - REST APIと共有する在庫アプリケーションサービス
+/// * `authenticator` - トークンの検証（Noneの場合は認証を行わない）
+pub async fn serve<OR>(
+    addr: SocketAddr,
+    order_service: Arc<OrderApplicationService<OR>>,
+    inventory_service: Arc<InventoryApplicationService>,
+    authenticator: Option<Arc<JwtAuthenticator>>,
+) -> Result<(), tonic::transport::Error>
+where
+    OR: OrderRepository + 'static,
+{
+    let interceptor = StaffAuthInterceptor::new(authenticator);
+    tonic::transport::Server::builder()
+        .add_service(OrderServiceServer::with_interceptor(
+            GrpcOrderService::new(order_service),
+            interceptor.clone(),
+        ))
+        .add_service(InventoryServiceServer::with_interceptor(
+            GrpcInventoryService::new(inventory_service),
+            interceptor,
+        ))
+        .serve(addr)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::error::DomainError;
+
+    #[test]
+    fn test_application_errors_keep_rest_error_codes() {
+        let status = map_application_status(ApplicationError::NotFound(
+            "注文が見つかりません".to_string(),
+        ));
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(
+            status.metadata().get(ERROR_CODE_METADATA_KEY).unwrap(),
+            "NOT_FOUND"
+        );
+
+        let status = map_application_status(DomainError::InsufficientInventory.into());
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(
+            status.metadata().get(ERROR_CODE_METADATA_KEY).unwrap(),
+            "INSUFFICIENT_INVENTORY"
+        );
+
+        assert_eq!(
+            parse_uuid("order_id", "not-a-uuid").unwrap_err().code(),
+            Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn test_only_staff_tokens_are_accepted_when_authentication_is_enabled() {
+        let secret = "0123456789abcdef0123456789abcdef";
+        let token = |role: &str| {
+            jsonwebtoken::encode(
+                &jsonwebtoken::Header::default(),
+                &serde_json::json!({
+                    "sub": "user-1",
+                    "role": role,
+                    "customer_id": Uuid::new_v4(),
+                    "exp": chrono::Utc::now().timestamp() + 600,
+                }),
+                &jsonwebtoken::EncodingKey::from_secret(secret.as_bytes()),
+            )
+            .unwrap()
+        };
+        let request = |token: Option<String>| {
+            let mut request = Request::new(());
+            if let Some(token) = token {
+                request
+                    .metadata_mut()
+                    .insert("authorization", format!("Bearer {}", token).parse().unwrap());
+            }
+            request
+        };
+        let mut interceptor = StaffAuthInterceptor::new(Some(Arc::new(JwtAuthenticator::new(
+            secret, None, None,
+        ))));
+
+        assert!(interceptor.call(request(Some(token("staff")))).is_ok());
+        assert_eq!(
+            interceptor
+                .call(request(Some(token("customer"))))
+                .unwrap_err()
+                .code(),
+            Code::PermissionDenied
+        );
+        assert_eq!(
+            interceptor.call(request(None)).unwrap_err().code(),
+            Code::Unauthenticated
+        );
+        assert!(StaffAuthInterceptor::new(None).call(request(None)).is_ok());
+    }
+}
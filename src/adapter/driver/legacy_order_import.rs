@@ -0,0 +1,420 @@
+// 旧システムの注文の取り込み用の腐敗防止層（Anti-Corruption Layer）
+// 旧システムのスキーマ（コード値・日時の書式・郵便番号の表記など）をこのモジュールの中だけで扱い、
+// ドメインにはこのシステムの値オブジェクトに変換したコマンドだけを渡す
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::service::{
+    ImportLegacyOrderCommand, LegacyImportApplicationService, LegacyImportResult,
+};
+use crate::application::ApplicationError;
+use crate::domain::model::{BookId, CustomerId, Money, OrderLine, OrderStatus, ShippingAddress};
+
+/// 旧システムの日時の書式（日本時間）
+const LEGACY_DATETIME_FORMAT: &str = "%Y/%m/%d %H:%M:%S";
+
+/// 旧システムの日時のタイムゾーン（日本時間: UTC+9）
+const LEGACY_UTC_OFFSET_SECONDS: i32 = 9 * 60 * 60;
+
+/// 1回の取り込みで受け付けるレコード数の上限
+pub const MAX_LEGACY_IMPORT_BATCH_SIZE: usize = 500;
+
+/// 旧システムの注文レコード
+/// 旧システムのエクスポート形式のまま受け取り、値の検証は変換時に行う
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct LegacyOrderImport {
+    /// 旧システムの注文番号
+    pub order_no: String,
+    /// 旧システムの顧客コード
+    pub cust_cd: Option<String>,
+    /// 旧システムの注文ステータスコード（例: "01" 受付、"03" 出荷済）
+    pub order_sts: Option<String>,
+    /// 注文日時（"YYYY/MM/DD HH:MM:SS"、日本時間）
+    pub order_dt: Option<String>,
+    /// 出荷日時
+    pub ship_dt: Option<String>,
+    /// 配達日時
+    pub deliv_dt: Option<String>,
+    /// 郵便番号（ハイフンあり・なしのどちらも可）
+    pub zip: Option<String>,
+    pub addr_pref: Option<String>,
+    pub addr_city: Option<String>,
+    pub addr_line: Option<String>,
+    pub addr_bldg: Option<String>,
+    #[serde(default)]
+    pub items: Vec<LegacyOrderItem>,
+}
+
+/// 旧システムの注文明細レコード
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct LegacyOrderItem {
+    /// 旧システムの商品コード
+    pub item_cd: String,
+    pub qty: i64,
+    /// 単価（円）
+    pub unit_price: i64,
+}
+
+/// 旧システムの注文の取り込みリクエスト
+/// 顧客コード・商品コードの対応表は移行作業ごとに用意してレコードと一緒に渡す
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LegacyOrderImportBatch {
+    /// 旧システムの顧客コード → 顧客ID
+    #[serde(default)]
+    pub customer_codes: HashMap<String, Uuid>,
+    /// 旧システムの商品コード → 書籍ID
+    #[serde(default)]
+    pub book_codes: HashMap<String, Uuid>,
+    pub orders: Vec<LegacyOrderImport>,
+}
+
+/// 旧システムのコード値とこのシステムの値の対応表
+#[derive(Debug, Clone)]
+pub struct LegacyMappingTables {
+    statuses: HashMap<String, OrderStatus>,
+    customers: HashMap<String, CustomerId>,
+    books: HashMap<String, BookId>,
+}
+
+impl LegacyMappingTables {
+    /// 旧システムの注文ステータスコードの対応表
+    /// 返品（"05"）など、このシステムに対応するステータスがないコードは含めない
+    pub const STATUS_CODES: [(&'static str, OrderStatus); 5] = [
+        ("01", OrderStatus::Pending),
+        ("02", OrderStatus::Confirmed),
+        ("03", OrderStatus::Shipped),
+        ("04", OrderStatus::Delivered),
+        ("09", OrderStatus::Cancelled),
+    ];
+
+    /// 取り込みリクエストの顧客コード・商品コードの対応表から作成
+    pub fn from_batch(batch: &LegacyOrderImportBatch) -> Self {
+        Self {
+            statuses: Self::STATUS_CODES
+                .iter()
+                .map(|(code, status)| (code.to_string(), *status))
+                .collect(),
+            customers: batch
+                .customer_codes
+                .iter()
+                .map(|(code, id)| (code.clone(), CustomerId::from_uuid(*id)))
+                .collect(),
+            books: batch
+                .book_codes
+                .iter()
+                .map(|(code, id)| (code.clone(), BookId::from_uuid(*id)))
+                .collect(),
+        }
+    }
+
+    /// 旧システムの注文レコードを取り込みコマンドに変換
+    ///
+    /// # Returns
+    /// * `Ok(ImportLegacyOrderCommand)` - 変換成功
+    /// * `Err(Vec<String>)` - 変換できなかった理由（見つかったものをすべて返す）
+    pub fn translate(
+        &self,
+        record: &LegacyOrderImport,
+    ) -> Result<ImportLegacyOrderCommand, Vec<String>> {
+        let mut reasons = Vec::new();
+
+        let customer_id = match record.cust_cd.as_deref() {
+            Some(code) => self.customers.get(code).copied().or_else(|| {
+                reasons.push(format!("対応表にない顧客コード: {}", code));
+                None
+            }),
+            None => {
+                reasons.push("顧客コードがありません".to_string());
+                None
+            }
+        };
+
+        let status = match record.order_sts.as_deref() {
+            Some(code) => self.statuses.get(code).copied().or_else(|| {
+                reasons.push(format!("対応していない注文ステータスコード: {}", code));
+                None
+            }),
+            None => {
+                reasons.push("注文ステータスコードがありません".to_string());
+                None
+            }
+        };
+
+        let mut order_lines = Vec::new();
+        for item in &record.items {
+            match self.translate_item(item) {
+                Ok(line) => order_lines.push(line),
+                Err(reason) => reasons.push(reason),
+            }
+        }
+
+        let shipping_address = translate_address(record).unwrap_or_else(|reason| {
+            reasons.push(reason);
+            None
+        });
+
+        let mut transition_time = |label: &str, value: &Option<String>, required: bool| {
+            match (value.as_deref(), required) {
+                (Some(value), _) => parse_legacy_datetime(value).map(Some).unwrap_or_else(|| {
+                    reasons.push(format!("{}の書式が不正です: {}", label, value));
+                    None
+                }),
+                (None, true) => {
+                    reasons.push(format!("{}がありません", label));
+                    None
+                }
+                (None, false) => None,
+            }
+        };
+        let reached = |statuses: &[OrderStatus]| status.is_some_and(|s| statuses.contains(&s));
+        let confirmed_at = transition_time(
+            "注文日時",
+            &record.order_dt,
+            reached(&[
+                OrderStatus::Confirmed,
+                OrderStatus::Shipped,
+                OrderStatus::Delivered,
+            ]),
+        );
+        let shipped_at = transition_time(
+            "出荷日時",
+            &record.ship_dt,
+            reached(&[OrderStatus::Shipped, OrderStatus::Delivered]),
+        );
+        let delivered_at = transition_time(
+            "配達日時",
+            &record.deliv_dt,
+            reached(&[OrderStatus::Delivered]),
+        );
+
+        match (customer_id, status) {
+            (Some(customer_id), Some(status)) if reasons.is_empty() => {
+                Ok(ImportLegacyOrderCommand {
+                    legacy_order_no: record.order_no.clone(),
+                    customer_id,
+                    order_lines,
+                    shipping_address,
+                    status,
+                    // 確定前の注文・キャンセルされた注文の注文日時は確定日時として扱わない
+                    confirmed_at: confirmed_at.filter(|_| {
+                        !matches!(status, OrderStatus::Pending | OrderStatus::Cancelled)
+                    }),
+                    shipped_at,
+                    delivered_at,
+                })
+            }
+            _ => Err(reasons),
+        }
+    }
+
+    fn translate_item(&self, item: &LegacyOrderItem) -> Result<OrderLine, String> {
+        let book_id = self
+            .books
+            .get(&item.item_cd)
+            .copied()
+            .ok_or_else(|| format!("対応表にない商品コード: {}", item.item_cd))?;
+        let quantity = u32::try_from(item.qty)
+            .map_err(|_| format!("商品コード{}の数量が不正です: {}", item.item_cd, item.qty))?;
+        if item.unit_price < 0 {
+            return Err(format!(
+                "商品コード{}の単価が負の値です: {}",
+                item.item_cd, item.unit_price
+            ));
+        }
+
+        OrderLine::new(book_id, quantity, Money::jpy(item.unit_price))
+            .map_err(|e| format!("商品コード{}の明細が不正です: {}", item.item_cd, e))
+    }
+}
+
+/// 旧システムの住所を配送先住所に変換（住所の項目がすべて空の場合はNone）
+fn translate_address(record: &LegacyOrderImport) -> Result<Option<ShippingAddress>, String> {
+    let fields = [
+        &record.zip,
+        &record.addr_pref,
+        &record.addr_city,
+        &record.addr_line,
+    ];
+    if fields.iter().all(|field| field.is_none()) {
+        return Ok(None);
+    }
+
+    let field = |value: &Option<String>| value.clone().unwrap_or_default();
+    ShippingAddress::new(
+        field(&record.zip).replace('-', ""),
+        field(&record.addr_pref),
+        field(&record.addr_city),
+        field(&record.addr_line),
+        record
+            .addr_bldg
+            .clone()
+            .filter(|building| !building.trim().is_empty()),
+    )
+    .map(Some)
+    .map_err(|e| format!("住所が不正です: {}", e))
+}
+
+/// 旧システムの日時（日本時間）をUTCに変換
+fn parse_legacy_datetime(value: &str) -> Option<DateTime<Utc>> {
+    let naive = NaiveDateTime::parse_from_str(value.trim(), LEGACY_DATETIME_FORMAT).ok()?;
+    let offset = FixedOffset::east_opt(LEGACY_UTC_OFFSET_SECONDS)?;
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .map(|datetime| datetime.with_timezone(&Utc))
+}
+
+/// 取り込んだ注文
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportedLegacyOrder {
+    pub legacy_order_no: String,
+    pub order_id: Uuid,
+}
+
+/// 隔離したレコード
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QuarantinedLegacyOrder {
+    pub legacy_order_no: String,
+    pub reasons: Vec<String>,
+}
+
+/// 旧システムの注文の取り込み結果
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct LegacyImportReport {
+    pub imported: Vec<ImportedLegacyOrder>,
+    /// 取り込み済みのため読み飛ばした注文
+    pub skipped: Vec<ImportedLegacyOrder>,
+    pub quarantined: Vec<QuarantinedLegacyOrder>,
+}
+
+/// 旧システムの注文をまとめて取り込む
+/// 1件ずつ変換・取り込みを行い、変換できないレコードは隔離して残りの取り込みを続ける
+///
+/// # Returns
+/// * `Ok(LegacyImportReport)` - 注文番号ごとの取り込み結果
+/// * `Err(ApplicationError)` - 保存失敗（それまでに取り込んだ注文は取り込み済みのまま）
+pub async fn import_legacy_orders(
+    service: &LegacyImportApplicationService,
+    batch: LegacyOrderImportBatch,
+    now: DateTime<Utc>,
+) -> Result<LegacyImportReport, ApplicationError> {
+    let tables = LegacyMappingTables::from_batch(&batch);
+
+    let mut report = LegacyImportReport::default();
+    for record in batch.orders {
+        let payload = serde_json::to_string(&record).unwrap_or_default();
+        let result = match tables.translate(&record) {
+            Ok(command) => service.import_order(command, payload, now).await?,
+            Err(reasons) => {
+                service
+                    .quarantine(record.order_no.clone(), payload, reasons, now)
+                    .await?
+            }
+        };
+
+        let legacy_order_no = record.order_no;
+        match result {
+            LegacyImportResult::Imported(order_id) => report.imported.push(ImportedLegacyOrder {
+                legacy_order_no,
+                order_id: order_id.as_uuid(),
+            }),
+            LegacyImportResult::AlreadyImported(order_id) => {
+                report.skipped.push(ImportedLegacyOrder {
+                    legacy_order_no,
+                    order_id: order_id.as_uuid(),
+                })
+            }
+            LegacyImportResult::Quarantined(reasons) => {
+                report.quarantined.push(QuarantinedLegacyOrder {
+                    legacy_order_no,
+                    reasons,
+                })
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(order: LegacyOrderImport, book_id: Uuid, customer_id: Uuid) -> LegacyOrderImportBatch {
+        LegacyOrderImportBatch {
+            customer_codes: HashMap::from([("C0001".to_string(), customer_id)]),
+            book_codes: HashMap::from([("ISBN-001".to_string(), book_id)]),
+            orders: vec![order],
+        }
+    }
+
+    fn shipped_record() -> LegacyOrderImport {
+        serde_json::from_value(serde_json::json!({
+            "ORDER_NO": "L-2019-0001",
+            "CUST_CD": "C0001",
+            "ORDER_STS": "03",
+            "ORDER_DT": "2019/04/01 10:00:00",
+            "SHIP_DT": "2019/04/02 09:30:00",
+            "ZIP": "150-0043",
+            "ADDR_PREF": "東京都",
+            "ADDR_CITY": "渋谷区",
+            "ADDR_LINE": "道玄坂1-1-1",
+            "ADDR_BLDG": "",
+            "ITEMS": [{ "ITEM_CD": "ISBN-001", "QTY": 2, "UNIT_PRICE": 1500 }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_translate_maps_legacy_codes_and_converts_jst_to_utc() {
+        let book_id = Uuid::new_v4();
+        let customer_id = Uuid::new_v4();
+        let batch = batch(shipped_record(), book_id, customer_id);
+        let tables = LegacyMappingTables::from_batch(&batch);
+
+        let command = tables.translate(&batch.orders[0]).unwrap();
+
+        assert_eq!(command.legacy_order_no, "L-2019-0001");
+        assert_eq!(command.customer_id, CustomerId::from_uuid(customer_id));
+        assert_eq!(command.status, OrderStatus::Shipped);
+        assert_eq!(command.order_lines[0].book_id(), BookId::from_uuid(book_id));
+        assert_eq!(command.order_lines[0].quantity(), 2);
+        assert_eq!(
+            command.shipping_address.unwrap().postal_code(),
+            "1500043"
+        );
+        assert_eq!(
+            command.shipped_at.unwrap().to_rfc3339(),
+            "2019-04-02T00:30:00+00:00"
+        );
+        assert!(command.confirmed_at.is_some());
+        assert!(command.delivered_at.is_none());
+    }
+
+    #[test]
+    fn test_translate_collects_all_reasons_for_unmappable_record() {
+        let mut record = shipped_record();
+        record.order_sts = Some("05".to_string());
+        record.ship_dt = Some("2019-04-02".to_string());
+        record.items.push(LegacyOrderItem {
+            item_cd: "ISBN-999".to_string(),
+            qty: -1,
+            unit_price: 1000,
+        });
+        let batch = batch(record, Uuid::new_v4(), Uuid::new_v4());
+        let tables = LegacyMappingTables::from_batch(&batch);
+
+        let reasons = tables.translate(&batch.orders[0]).unwrap_err();
+
+        assert_eq!(reasons.len(), 3);
+        assert!(reasons[0].contains("注文ステータスコード: 05"));
+        assert!(reasons[1].contains("ISBN-999"));
+        assert!(reasons[2].contains("出荷日時"));
+    }
+}
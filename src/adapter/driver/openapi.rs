@@ -0,0 +1,405 @@
+// OpenAPI仕様の生成とSwagger UIの配信
+// 各エンドポイントの #[utoipa::path] とDTOの ToSchema から仕様を組み立て、/openapi.json と /docs で配信する
+// （ドメインの型は仕様に載せず、DTOに埋め込んでいるものは汎用のオブジェクトとして扱う）
+
+use axum::{response::Html, Json};
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::{self, RefOr, Schema};
+use utoipa::{Modify, OpenApi};
+
+use crate::adapter::driver::legacy_order_import::{
+    ImportedLegacyOrder, LegacyImportReport, LegacyOrderImport, LegacyOrderImportBatch,
+    LegacyOrderItem, QuarantinedLegacyOrder,
+};
+use crate::adapter::driver::request_dto::{
+    AddBookRequest, AddOrderNoteRequest, AllocationQuotaRequest, ApproveCycleCountRequest, BookSpecRequest,
+    BulkCancelRequest, BulkTransitionRequest, CarrierTrackingWebhookRequest,
+    CreateCycleCountRequest, CreateInventoryRequest, CreateOrderRequest,
+    CreateWebhookSubscriptionRequest, CycleCountEntryRequest, ExportEventsRequest,
+    ForceOrderStatusRequest, HoldOrderRequest, ImportEventsRequest, LineAttributeRequest, LockOrderRequest,
+    PauseSubscriptionRequest, RecordCycleCountRequest, RegisterCustomerRequest, SetPreferredLanguageRequest,
+    RegisterDeviceRequest, ReportFormat, RestockRequest, ReturnOrderRequest, SetBookPriceRequest,
+    SetBookTitleRequest, SetLineAttributesRequest, SetRecipientRequest, SetShippingAddressRequest,
+};
+use crate::adapter::driver::response_dto::{
+    ActionLinkResponse, AllocationQuotaResponse, AuthzPoliciesResponse, AuthzPolicyResponse,
+    BookPriceResponse, BookTitlesResponse,
+    BulkCancellationPreviewResponse, BulkTransitionResponse, BulkTransitionResultResponse,
+    ChannelQuotaResponse, CheckoutHoldLineResponse, CheckoutHoldResponse, ConfirmedTotalsResponse, CustomerResponse, CustomerExportResponse, CycleCountLineResponse,
+    CycleCountResponse, DeviceResponse, EventEchoResponse, FailedNotificationResponse,
+    InventoryResponse, LineAttributeResponse, OrderDetailResponse, OrderLineResponse,
+    OrderLockResponse, OrderSummaryResponse, PendingOperationResponse, PublicTrackingResponse,
+    RecipientResponse, RolePermissionsResponse, ShippingAddressResponse, SimilarOrdersResponse, WaitlistPositionResponse,
+    WaitlistStatusResponse, WebhookDeliveryResponse, WebhookSubscriptionResponse,
+};
+use crate::adapter::driver::rest_api::{
+    self, ApiError, CommandResponse, CreateCycleCountResponse, CreateOrderResponse,
+    ResumeEventBusResponse, ResumeSubscriptionResponse,
+};
+
+/// エラーレスポンス（ApiError）の code に入りうる値
+/// map_domain_error・map_application_error とハンドラーが返すエラーコードを追加・変更した場合はここも更新する
+pub const ERROR_CODES: &[&str] = &[
+    // ドメインエラー
+    "VALIDATION_FAILED",
+    "INVALID_QUANTITY",
+    "INVALID_VALUE",
+    "INVALID_ORDER_STATE",
+    "INSUFFICIENT_INVENTORY",
+    "CURRENCY_MISMATCH",
+    "PURCHASE_QUANTITY_LIMIT_EXCEEDED",
+    "OPEN_ORDER_LIMIT_EXCEEDED",
+    "INVALID_CYCLE_COUNT_STATE",
+    "INVENTORY_FROZEN",
+    "CARRIER_LIMIT_EXCEEDED",
+    "PRICE_CHANGED",
+    "CANCELLATION_WINDOW_EXPIRED",
+    "ORDER_LOCKED",
+    "CONFIRMATION_TOKEN_MISMATCH",
+    "QUOTA_EXCEEDED",
+    // アプリケーションエラー
+    "CONCURRENCY_CONFLICT",
+    "REPOSITORY_ERROR",
+    "EVENT_PUBLISHING_ERROR",
+    "NOT_FOUND",
+    "EXTERNAL_SERVICE_ERROR",
+    "UNSUPPORTED",
+    // リクエストの検証・参照先が見つからない場合のエラー（ハンドラーが直接返す）
+    "INVALID_PARAMETER",
+    "INVALID_CORRELATION_ID",
+    "UNSUPPORTED_LANGUAGE",
+    "ORDER_NOT_FOUND",
+    "INVENTORY_NOT_FOUND",
+    "CYCLE_COUNT_NOT_FOUND",
+    "BOOK_PRICE_NOT_FOUND",
+    "BOOK_TITLE_NOT_FOUND",
+    // 認証・認可のエラー（認証のミドルウェアが返す）
+    "UNAUTHORIZED",
+    "FORBIDDEN",
+];
+
+/// REST APIのOpenAPI仕様
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Bookstore Order Management API"),
+    paths(
+        rest_api::health_check,
+        rest_api::readiness_check,
+        rest_api::get_domain_model,
+        rest_api::create_order,
+        rest_api::add_book_to_order,
+        rest_api::set_shipping_address,
+        rest_api::set_line_attributes,
+        rest_api::set_gift_recipient,
+        rest_api::get_packing_slip,
+        rest_api::place_checkout_hold,
+        rest_api::confirm_order,
+        rest_api::reprice_order,
+        rest_api::cancel_order,
+        rest_api::hold_order,
+        rest_api::release_order_hold,
+        rest_api::mark_order_as_shipped,
+        rest_api::mark_order_as_delivered,
+        rest_api::request_order_return,
+        rest_api::approve_order_return,
+        rest_api::bulk_mark_orders_as_shipped,
+        rest_api::bulk_mark_orders_as_delivered,
+        rest_api::get_order_tracking,
+        rest_api::get_order_timeline,
+        rest_api::stream_order_events,
+        rest_api::customer_notifications_socket,
+        rest_api::request_customer_export,
+        rest_api::get_customer_export,
+        rest_api::download_customer_export,
+        rest_api::add_order_note,
+        rest_api::get_order_waitlist,
+        rest_api::get_public_tracking,
+        rest_api::get_action_link,
+        rest_api::execute_action_link,
+        rest_api::get_operation,
+        rest_api::receive_carrier_tracking,
+        rest_api::create_inventory,
+        rest_api::set_book_price,
+        rest_api::get_book_price,
+        rest_api::get_book_titles,
+        rest_api::set_book_title,
+        rest_api::get_orders,
+        rest_api::get_order_by_id,
+        rest_api::get_inventories,
+        rest_api::get_inventory_snapshot,
+        rest_api::get_inventory_changes,
+        rest_api::get_inventory_by_book_id,
+        rest_api::restock_inventory,
+        rest_api::create_cycle_count,
+        rest_api::get_cycle_count,
+        rest_api::record_cycle_count,
+        rest_api::submit_cycle_count,
+        rest_api::approve_cycle_count,
+        rest_api::reject_cycle_count,
+        rest_api::repair_inventory,
+        rest_api::unfreeze_inventory,
+        rest_api::get_allocation_quota,
+        rest_api::set_allocation_quota,
+        rest_api::remove_allocation_quota,
+        rest_api::bulk_cancel_orders,
+        rest_api::get_similar_orders,
+        rest_api::repair_order,
+        rest_api::force_order_status,
+        rest_api::get_status_overrides,
+        rest_api::lock_order,
+        rest_api::unlock_order,
+        rest_api::get_parked_events,
+        rest_api::get_dead_letters,
+        rest_api::get_dead_letter_summary,
+        rest_api::get_retry_policies,
+        rest_api::get_authz_policies,
+        rest_api::get_failed_notifications,
+        rest_api::resend_notification,
+        rest_api::get_event_traces,
+        rest_api::get_projections,
+        rest_api::rebuild_projection,
+        rest_api::get_saga_metrics,
+        rest_api::get_diagnostics,
+        rest_api::export_events,
+        rest_api::import_events,
+        rest_api::import_legacy_order_batch,
+        rest_api::get_quarantined_legacy_orders,
+        rest_api::get_subscriptions,
+        rest_api::get_load_shedding,
+        rest_api::get_subscription_registry,
+        rest_api::forget_subscription_registration,
+        rest_api::unsubscribe,
+        rest_api::pause_subscription,
+        rest_api::resume_subscription,
+        rest_api::get_event_bus_status,
+        rest_api::pause_event_bus,
+        rest_api::resume_event_bus,
+        rest_api::register_customer,
+        rest_api::get_customer,
+        rest_api::set_customer_preferred_language,
+        rest_api::get_customer_orders,
+        rest_api::register_device,
+        rest_api::get_devices,
+        rest_api::unregister_device,
+        rest_api::create_webhook_subscription,
+        rest_api::get_webhook_subscriptions,
+        rest_api::delete_webhook_subscription,
+        rest_api::get_webhook_deliveries,
+        rest_api::redeliver_webhook,
+        rest_api::get_business_metrics,
+        rest_api::get_sla_report,
+        rest_api::get_inventory_valuation_report,
+        rest_api::get_demand_forecast
+    ),
+    components(
+        schemas(
+            CreateOrderRequest,
+            AddBookRequest,
+            BookSpecRequest,
+            SetShippingAddressRequest,
+            SetRecipientRequest,
+            SetBookPriceRequest,
+            SetBookTitleRequest,
+            SetLineAttributesRequest,
+            LineAttributeRequest,
+            CreateInventoryRequest,
+            RestockRequest,
+            AllocationQuotaRequest,
+            CreateCycleCountRequest,
+            RecordCycleCountRequest,
+            CycleCountEntryRequest,
+            ApproveCycleCountRequest,
+            RegisterCustomerRequest,
+            SetPreferredLanguageRequest,
+            RegisterDeviceRequest,
+            CreateWebhookSubscriptionRequest,
+            CarrierTrackingWebhookRequest,
+            HoldOrderRequest,
+            ForceOrderStatusRequest,
+            ReturnOrderRequest,
+            BulkTransitionRequest,
+            BulkCancelRequest,
+            ReportFormat,
+            LockOrderRequest,
+            AddOrderNoteRequest,
+            PauseSubscriptionRequest,
+            ExportEventsRequest,
+            ImportEventsRequest,
+            OrderSummaryResponse,
+            OrderLockResponse,
+            SimilarOrdersResponse,
+            OrderDetailResponse,
+            ConfirmedTotalsResponse,
+            OrderLineResponse,
+            LineAttributeResponse,
+            ShippingAddressResponse,
+            RecipientResponse,
+            InventoryResponse,
+            AllocationQuotaResponse,
+            ChannelQuotaResponse,
+            CycleCountResponse,
+            CustomerResponse,
+            CycleCountLineResponse,
+            DeviceResponse,
+            WebhookSubscriptionResponse,
+            CustomerExportResponse,
+            WebhookDeliveryResponse,
+            PendingOperationResponse,
+            FailedNotificationResponse,
+            ActionLinkResponse,
+            WaitlistStatusResponse,
+            WaitlistPositionResponse,
+            CheckoutHoldResponse,
+            CheckoutHoldLineResponse,
+            BulkTransitionResponse,
+            BulkCancellationPreviewResponse,
+            BulkTransitionResultResponse,
+            EventEchoResponse,
+            BookPriceResponse,
+            BookTitlesResponse,
+            PublicTrackingResponse,
+            AuthzPoliciesResponse,
+            AuthzPolicyResponse,
+            RolePermissionsResponse,
+            LegacyOrderImport,
+            LegacyOrderItem,
+            LegacyOrderImportBatch,
+            ImportedLegacyOrder,
+            QuarantinedLegacyOrder,
+            LegacyImportReport,
+            CreateOrderResponse,
+            CommandResponse,
+            CreateCycleCountResponse,
+            ResumeSubscriptionResponse,
+            ResumeEventBusResponse,
+            ApiError
+        )
+    ),
+    modifiers(&ErrorCodes, &BearerAuth),
+    security(("bearer_auth" = []))
+)]
+pub struct ApiDoc;
+
+/// ApiError の code を ERROR_CODES の列挙にする
+struct ErrorCodes;
+
+impl Modify for ErrorCodes {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        let Some(components) = openapi.components.as_mut() else {
+            return;
+        };
+        let Some(RefOr::T(Schema::Object(api_error))) = components.schemas.get_mut("ApiError")
+        else {
+            return;
+        };
+        if let Some(RefOr::T(Schema::Object(code))) = api_error.properties.get_mut("code") {
+            code.enum_values = Some(ERROR_CODES.iter().map(|code| (*code).into()).collect());
+        }
+    }
+}
+
+/// Bearerトークン（JWT）による認証を仕様に載せる（AUTH_JWT_SECRETを設定した場合に必要）
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                SecurityScheme::Http(
+                    HttpBuilder::new()
+                        .scheme(HttpAuthScheme::Bearer)
+                        .bearer_format("JWT")
+                        .build(),
+                ),
+            );
+        }
+    }
+}
+
+// OpenAPI仕様の取得エンドポイント
+pub async fn openapi_json() -> Json<openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+/// Swagger UIのページ（スクリプトとスタイルはCDNから読み込む）
+const SWAGGER_UI_HTML: &str = r##"<!DOCTYPE html>
+<html lang="ja">
+<head>
+  <meta charset="utf-8" />
+  <title>Bookstore Order Management API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+  <script>
+    window.onload = () => {
+      window.ui = SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+    };
+  </script>
+</body>
+</html>
+"##;
+
+// Swagger UI（/docs）
+pub async fn swagger_ui() -> Html<&'static str> {
+    Html(SWAGGER_UI_HTML)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_refs(value: &serde_json::Value, refs: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    match (key.as_str(), value) {
+                        ("$ref", serde_json::Value::String(reference)) => {
+                            refs.push(reference.clone())
+                        }
+                        _ => collect_refs(value, refs),
+                    }
+                }
+            }
+            serde_json::Value::Array(values) => {
+                values.iter().for_each(|value| collect_refs(value, refs))
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_openapi_spec_references_only_registered_schemas() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        assert!(spec["paths"]["/orders/{order_id}/confirm"]["post"].is_object());
+        assert!(spec["paths"]["/inventory/{book_id}"]["get"].is_object());
+
+        // ドメインの型を直接参照すると、仕様に定義のないスキーマを参照してしまう
+        let mut refs = Vec::new();
+        collect_refs(&spec, &mut refs);
+        let schemas = spec["components"]["schemas"].as_object().unwrap();
+        for reference in refs {
+            let name = reference.trim_start_matches("#/components/schemas/");
+            assert!(
+                schemas.contains_key(name),
+                "未登録のスキーマ: {}",
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn test_api_error_code_lists_error_codes() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        let codes = &spec["components"]["schemas"]["ApiError"]["properties"]["code"]["enum"];
+        assert_eq!(codes.as_array().unwrap().len(), ERROR_CODES.len());
+        assert!(codes
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("INSUFFICIENT_INVENTORY")));
+    }
+}
@@ -1,22 +1,70 @@
+use crate::domain::address_normalizer::{AddressInput, AddressNormalizer};
+use crate::domain::error::DomainError;
+use crate::domain::event_bus::PausedEventHandling;
+use crate::domain::model::{
+    BookSpec, FulfillmentType, LineAttribute, Recipient, SalesChannel, ShippingAddress,
+};
+use crate::domain::tracking::TrackingStage;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 /// 注文作成用のリクエストDTO
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct CreateOrderRequest {
+    /// 登録済みの顧客のID（未指定の場合は検証エラーとする）
     pub customer_id: Option<Uuid>,
+    /// 販売チャネル（"Online" または "InStore"、省略時はオンライン）
+    #[serde(default)]
+    #[schema(value_type = String)]
+    pub sales_channel: SalesChannel,
 }
 
 /// 書籍追加用のリクエストDTO
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct AddBookRequest {
     pub book_id: Uuid,
     pub quantity: u32,
     pub unit_price: i64, // JPY in cents
+    /// フルフィルメント種別（"Physical" または "Digital"、省略時は物理書籍）
+    #[serde(default)]
+    #[schema(value_type = String)]
+    pub fulfillment_type: FulfillmentType,
+    /// 書籍の重量と寸法（配送料の算出に使用、省略時は重量0として扱う）
+    #[serde(default)]
+    pub spec: Option<BookSpecRequest>,
+}
+
+/// 書籍の重量と寸法
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BookSpecRequest {
+    pub weight_grams: u32,
+    pub width_mm: u32,
+    pub height_mm: u32,
+    pub thickness_mm: u32,
+}
+
+impl AddBookRequest {
+    /// 書籍の重量と寸法を値オブジェクトに変換
+    pub fn book_spec(&self) -> Result<Option<BookSpec>, DomainError> {
+        self.spec
+            .as_ref()
+            .map(|spec| {
+                BookSpec::new(
+                    spec.weight_grams,
+                    spec.width_mm,
+                    spec.height_mm,
+                    spec.thickness_mm,
+                )
+            })
+            .transpose()
+    }
 }
 
 /// 配送先住所設定用のリクエストDTO
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct SetShippingAddressRequest {
     pub postal_code: String,
     pub prefecture: String,
@@ -25,25 +73,398 @@ pub struct SetShippingAddressRequest {
     pub address_line2: Option<String>,
 }
 
+/// ギフト注文の受取人設定用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SetRecipientRequest {
+    pub name: String,
+    pub phone: String,
+    pub postal_code: String,
+    pub prefecture: String,
+    pub city: String,
+    pub address_line1: String,
+    pub address_line2: Option<String>,
+}
+
+impl SetRecipientRequest {
+    /// 受取人の値オブジェクトに変換
+    pub fn recipient(self) -> Result<Recipient, DomainError> {
+        let address = ShippingAddress::new(
+            self.postal_code,
+            self.prefecture,
+            self.city,
+            self.address_line1,
+            self.address_line2,
+        )?;
+        Recipient::new(self.name, self.phone, address)
+    }
+}
+
+/// 書籍の販売価格設定用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SetBookPriceRequest {
+    pub unit_price: i64,
+}
+
+/// 書籍のタイトル設定用のリクエストDTO（言語はパスで指定する）
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SetBookTitleRequest {
+    pub title: String,
+}
+
+/// 顧客の通知の言語設定用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SetPreferredLanguageRequest {
+    /// 言語コード（ja・en）
+    pub language: String,
+}
+
+/// 注文明細の属性設定用のリクエストDTO（既存の属性はすべて置き換える）
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SetLineAttributesRequest {
+    pub attributes: Vec<LineAttributeRequest>,
+}
+
+/// 注文明細の属性（例: key = "signed_copy", value = "requested"）
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct LineAttributeRequest {
+    pub key: String,
+    pub value: String,
+}
+
+impl SetLineAttributesRequest {
+    /// 明細属性の値オブジェクトに変換
+    pub fn line_attributes(self) -> Result<Vec<LineAttribute>, DomainError> {
+        self.attributes
+            .into_iter()
+            .map(|attribute| LineAttribute::new(attribute.key, attribute.value))
+            .collect()
+    }
+}
+
 /// 在庫作成用のリクエストDTO
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct CreateInventoryRequest {
     pub book_id: Uuid,
     pub quantity: u32,
+    /// 発売日（未発売の書籍の場合のみ指定、YYYY-MM-DD）
+    #[serde(default)]
+    pub release_date: Option<NaiveDate>,
+    /// 在庫を超える注文を先着順の順番待ちにするかどうか（人気の新刊など）
+    #[serde(default)]
+    pub waitlist_enabled: bool,
+}
+
+/// 入荷用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RestockRequest {
+    pub quantity: u32,
+}
+
+/// 割当枠の設定用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AllocationQuotaRequest {
+    /// 1顧客が購入できる最大数量（省略時は顧客ごとに制限しない）
+    #[serde(default)]
+    pub per_customer_cap: Option<u32>,
+    /// 販売チャネル（"Online"・"InStore"）ごとの割当比率（%、合計100%以内。省略時はチャネルで制限しない）
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub channel_shares: BTreeMap<SalesChannel, u32>,
+    /// 割当比率をかける数量（省略時は現在の在庫数）
+    #[serde(default)]
+    pub allocation_pool: Option<u32>,
+}
+
+/// 棚卸し開始用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateCycleCountRequest {
+    /// 棚卸し対象の書籍ID（省略時は登録済みのすべての在庫が対象）
+    #[serde(default)]
+    pub book_ids: Vec<Uuid>,
+}
+
+/// 棚卸しの実数記録用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RecordCycleCountRequest {
+    pub entries: Vec<CycleCountEntryRequest>,
+}
+
+/// 棚卸しの実数（書籍ごと）
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CycleCountEntryRequest {
+    pub book_id: Uuid,
+    pub counted_quantity: u32,
+}
+
+/// 棚卸し承認用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ApproveCycleCountRequest {
+    pub approved_by: String,
+}
+
+/// 顧客登録用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RegisterCustomerRequest {
+    pub name: String,
+    pub email: String,
+    /// 注文の作成時に引き継ぐ既定の配送先住所（任意）
+    pub default_shipping_address: Option<SetShippingAddressRequest>,
+}
+
+impl RegisterCustomerRequest {
+    /// 既定の配送先住所を正規化して値オブジェクトに変換
+    pub fn default_shipping_address(&self) -> Result<Option<ShippingAddress>, DomainError> {
+        self.default_shipping_address
+            .as_ref()
+            .map(|address| {
+                AddressNormalizer.normalize(&AddressInput {
+                    postal_code: address.postal_code.clone(),
+                    prefecture: address.prefecture.clone(),
+                    city: address.city.clone(),
+                    street: address.address_line1.clone(),
+                    building: address.address_line2.clone(),
+                })
+            })
+            .transpose()
+    }
+}
+
+/// デバイス登録用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RegisterDeviceRequest {
+    /// プッシュ通知サービスが発行したデバイストークン
+    pub token: String,
+    /// プラットフォーム（Ios / Android / Web）
+    pub platform: String,
+}
+
+/// Webhookの購読登録用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateWebhookSubscriptionRequest {
+    /// 配信先のURL（http・https）
+    pub url: String,
+    /// ペイロードの署名に使うシークレット（16文字以上）
+    pub secret: String,
+    /// 配信するイベントの種類（例: OrderConfirmed, OrderShipped）
+    pub event_types: Vec<String>,
+}
+
+/// 配送業者の追跡Webhook用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CarrierTrackingWebhookRequest {
+    /// 配送業者が付与したメッセージID（再送されたメッセージの判定に使う）
+    pub message_id: String,
+    pub order_id: Uuid,
+    /// 配送業者のステータスコード（例: packed, in_transit, out_for_delivery, delivered）
+    pub status: String,
+    /// 配送業者側の発生日時（省略時は受信日時）
+    pub occurred_at: Option<DateTime<Utc>>,
+    pub location: Option<String>,
+    pub description: Option<String>,
+}
+
+impl CarrierTrackingWebhookRequest {
+    /// 配送業者のステータスコードを配送追跡の段階に変換
+    pub fn tracking_stage(&self) -> Result<TrackingStage, DomainError> {
+        TrackingStage::from_carrier_status(&self.status)
+    }
+}
+
+/// 注文の保留用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct HoldOrderRequest {
+    /// 保留の理由（SuspectedFraud / PaymentVerification / AddressVerification / CustomerRequest）
+    pub reason: String,
+    /// 保留の経緯を残すメモ
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// 注文のステータスの強制変更用のリクエストDTO（変更したオペレーターは認証した利用者として記録する）
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ForceOrderStatusRequest {
+    /// 変更後のステータス（Confirmed / Shipped / Delivered / Fulfilled / Cancelled）
+    pub status: String,
+    /// 変更の理由（監査記録に残す）
+    pub reason: String,
+}
+
+/// 返品の依頼用のリクエストDTO（本文は省略できる）
+#[derive(Serialize, Deserialize, Default, ToSchema)]
+pub struct ReturnOrderRequest {
+    /// 返品の理由（顧客の申告）
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// 一括のステータス遷移（まとめての発送・配達）用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BulkTransitionRequest {
+    pub order_ids: Vec<Uuid>,
+}
+
+/// 注文の一括キャンセル用のリクエストDTO
+/// confirmation_tokenを省略するとプレビューだけを返し、プレビューで返したトークンを指定すると実行する
+#[derive(Serialize, Deserialize, Default, ToSchema)]
+pub struct BulkCancelRequest {
+    /// 対象にする注文ステータス（省略時はPending）
+    #[serde(default)]
+    pub status: Option<String>,
+    /// 作成から指定した日数以上経過した注文だけを対象にする
+    #[serde(default)]
+    pub older_than_days: Option<u32>,
+    #[serde(default)]
+    pub confirmation_token: Option<String>,
 }
 
 /// 注文一覧取得用のクエリパラメータ
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct OrdersQueryParams {
     pub status: Option<String>,
+    /// 注文番号で検索する（指定した場合はstatusより優先し、見つかった注文だけを返す）
+    pub order_number: Option<String>,
 }
 
 /// 在庫一覧取得用のクエリパラメータ
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct InventoryQueryParams {
     pub max_quantity: Option<u32>,
 }
 
+/// 在庫の差分同期用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct InventoryChangesQueryParams {
+    /// 同期済みの最後のversion（スナップショットまたは前回の差分のversion）
+    pub since_version: u64,
+}
+
+/// レポートの出力形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+/// レポート取得用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReportQueryParams {
+    /// 出力形式（"json" または "csv"、省略時は "json"）
+    #[serde(default)]
+    pub format: ReportFormat,
+}
+
+/// 重複の可能性がある注文の検索用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SimilarOrdersQueryParams {
+    /// 作成日時の差の上限（分、省略時は30分）
+    pub within_minutes: Option<u32>,
+}
+
+/// 停滞した注文の修復用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RepairOrderQueryParams {
+    /// trueの場合は診断と修復手順の確認だけを行う（省略時はfalse）
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Webhookの配信ログ取得用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct WebhookDeliveriesQueryParams {
+    /// 取得する最大件数（省略時は50件、最大100件）
+    pub limit: Option<u32>,
+}
+
+/// デッドレターキュー取得用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeadLettersQueryParams {
+    /// 集約ID（注文IDなど）で絞り込む
+    pub aggregate_id: Option<String>,
+}
+
+/// 送信に失敗した通知の取得用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FailedNotificationsQueryParams {
+    /// 通知の状態（Pending / Delivered / Undeliverable、省略時はUndeliverable）
+    pub status: Option<String>,
+}
+
+/// イベントの配信のトレース取得用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventTracesQueryParams {
+    /// 相関ID（サーガ）で絞り込む
+    pub correlation_id: Option<Uuid>,
+}
+
+/// 注文の編集ロック用のリクエストDTO（ロックを保持するのは認証した利用者）
+#[derive(Serialize, Deserialize, Default, ToSchema)]
+pub struct LockOrderRequest {
+    /// ロックの期間（分、省略時は30分）
+    #[serde(default)]
+    pub ttl_minutes: Option<i64>,
+}
+
+/// CSメモ追加用のリクエストDTO（メモを書いたオペレーターは認証した利用者として記録する）
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AddOrderNoteRequest {
+    pub body: String,
+}
+
+/// 購読の一時停止用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PauseSubscriptionRequest {
+    /// 一時停止中のイベントの扱い（"buffer" または "skip"、省略時は "buffer"）
+    #[serde(default)]
+    #[schema(value_type = String)]
+    pub paused_events: PausedEventHandling,
+}
+
+/// 顧客への通知のWebSocketの接続用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CustomerNotificationsQueryParams {
+    /// 通知を受け取る顧客（顧客のトークンで接続する場合は省略できる）
+    pub customer_id: Option<Uuid>,
+    /// Bearerトークン（ブラウザのWebSocketはAuthorizationヘッダーを付けられないため、代わりに指定できる）
+    pub access_token: Option<String>,
+}
+
+/// イベントの配信全体の一時停止用のクエリパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PauseEventBusQueryParams {
+    /// 処理中のハンドラーが終わるのを待つ上限（秒、省略時は30秒）
+    pub drain_timeout_seconds: Option<u64>,
+}
+
+/// イベントのエクスポート用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ExportEventsRequest {
+    /// 期間の開始（この日時を含む）
+    pub from: DateTime<Utc>,
+    /// 期間の終了（この日時を含まない）
+    pub to: DateTime<Utc>,
+}
+
+/// エクスポートしたイベントの取り込み用のリクエストDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ImportEventsRequest {
+    /// アーカイブのキーの接頭辞（例: events/20240114T000000Z_20240115T000000Z）
+    pub archive: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,6 +473,7 @@ mod tests {
     fn test_create_order_request_serialization() {
         let request = CreateOrderRequest {
             customer_id: Some(Uuid::new_v4()),
+            sales_channel: SalesChannel::InStore,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -63,7 +485,10 @@ mod tests {
 
     #[test]
     fn test_create_order_request_without_customer_id() {
-        let request = CreateOrderRequest { customer_id: None };
+        let request = CreateOrderRequest {
+            customer_id: None,
+            sales_channel: SalesChannel::default(),
+        };
 
         let json = serde_json::to_string(&request).unwrap();
         let _deserialized: CreateOrderRequest = serde_json::from_str(&json).unwrap();
@@ -72,6 +497,25 @@ mod tests {
         assert!(json.contains("null"));
     }
 
+    #[test]
+    fn test_carrier_tracking_webhook_status_mapping() {
+        let request = |status: &str| CarrierTrackingWebhookRequest {
+            message_id: "carrier-evt-0001".to_string(),
+            order_id: Uuid::new_v4(),
+            status: status.to_string(),
+            occurred_at: None,
+            location: None,
+            description: None,
+        };
+
+        assert_eq!(request("in_transit").tracking_stage().unwrap(), TrackingStage::Shipped);
+        assert_eq!(
+            request("OUT_FOR_DELIVERY").tracking_stage().unwrap(),
+            TrackingStage::OutForDelivery
+        );
+        assert!(request("lost").tracking_stage().is_err());
+    }
+
     #[test]
     fn test_add_book_request_serialization() {
         let book_id = Uuid::new_v4();
@@ -79,6 +523,8 @@ mod tests {
             book_id,
             quantity: 2,
             unit_price: 1500,
+            fulfillment_type: FulfillmentType::Physical,
+            spec: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -90,6 +536,23 @@ mod tests {
         assert!(json.contains("unit_price"));
     }
 
+    #[test]
+    fn test_add_book_request_fulfillment_type_defaults_to_physical() {
+        let json =
+            r#"{"book_id":"550e8400-e29b-41d4-a716-446655440000","quantity":1,"unit_price":1500}"#;
+        let request: AddBookRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.fulfillment_type, FulfillmentType::Physical);
+
+        let json = r#"{"book_id":"550e8400-e29b-41d4-a716-446655440000","quantity":1,"unit_price":1500,"fulfillment_type":"Digital"}"#;
+        let request: AddBookRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.fulfillment_type, FulfillmentType::Digital);
+        assert!(request.book_spec().unwrap().is_none());
+
+        let json = r#"{"book_id":"550e8400-e29b-41d4-a716-446655440000","quantity":1,"unit_price":1500,"spec":{"weight_grams":0,"width_mm":182,"height_mm":257,"thickness_mm":20}}"#;
+        let request: AddBookRequest = serde_json::from_str(json).unwrap();
+        assert!(request.book_spec().is_err());
+    }
+
     #[test]
     fn test_set_shipping_address_request_with_building() {
         let request = SetShippingAddressRequest {
@@ -134,6 +597,8 @@ mod tests {
         let request = CreateInventoryRequest {
             book_id,
             quantity: 50,
+            release_date: None,
+            waitlist_enabled: false,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -144,16 +609,31 @@ mod tests {
         assert!(json.contains("quantity"));
     }
 
+    #[test]
+    fn test_create_inventory_request_with_release_date() {
+        let json = r#"{"book_id":"550e8400-e29b-41d4-a716-446655440000","quantity":5,"release_date":"2024-04-01"}"#;
+        let request: CreateInventoryRequest = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request.release_date, NaiveDate::from_ymd_opt(2024, 4, 1));
+        // 順番待ちは省略時に無効
+        assert!(!request.waitlist_enabled);
+    }
+
     #[test]
     fn test_query_params_deserialization() {
         // OrdersQueryParams のテスト
         let params = OrdersQueryParams {
             status: Some("Pending".to_string()),
+            order_number: None,
         };
         assert_eq!(params.status, Some("Pending".to_string()));
 
-        let params = OrdersQueryParams { status: None };
+        let params = OrdersQueryParams {
+            status: None,
+            order_number: Some("BK-2024-000123".to_string()),
+        };
         assert_eq!(params.status, None);
+        assert_eq!(params.order_number, Some("BK-2024-000123".to_string()));
 
         // InventoryQueryParams のテスト
         let params = InventoryQueryParams {
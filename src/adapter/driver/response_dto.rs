@@ -1,46 +1,154 @@
-use crate::domain::model::{Inventory, Money, Order, OrderLine, ShippingAddress};
-use serde::Serialize;
+use crate::adapter::driver::auth::{EndpointPolicy, Requirement};
+use crate::domain::access_control::Role;
+use crate::domain::action_link::ActionLinkClaims;
+use crate::domain::book_translation::{BookTitles, LocalizedTitle};
+use crate::domain::cancellation_policy::CancellationWindow;
+use crate::domain::checkout_hold::CheckoutHold;
+use crate::domain::customer_export::{CustomerExportJob, CustomerExportStatus};
+use crate::domain::customer_webhook::{WebhookDelivery, WebhookSubscription};
+use crate::domain::event::DomainEvent;
+use crate::domain::model::{
+    AllocationQuota, BookId, Customer, CycleCount, CycleCountLine, DeviceRegistration, Inventory, Money, Order,
+    OrderId, OrderLine, OrderStatus, ShippingAddress,
+};
+use crate::domain::notification_retry::FailedNotification;
+use crate::domain::order_lock::OrderLock;
+use crate::domain::order_totals::OrderTotals;
+use crate::domain::pending_operation::PendingOperation;
+use crate::domain::shipping_fee::{ShippingFeeBreakdown, ShippingFeePolicy};
+use crate::domain::tracking::{MaskedAddress, PublicTrackingView};
+use crate::domain::waitlist::WaitlistPosition;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use utoipa::ToSchema;
+use uuid::Uuid;
 
 /// 注文一覧用のレスポンスDTO
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct OrderSummaryResponse {
     pub order_id: String,
+    /// 顧客に伝える注文番号（採番を導入する前に作成した注文はNone）
+    pub order_number: Option<String>,
     pub customer_id: String,
     pub status: String,
     pub total_amount: i64,
     pub total_currency: String,
     pub created_at: String,
+    /// オペレーターの編集ロック（ロック中の注文のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock: Option<OrderLockResponse>,
+}
+
+/// 注文の編集ロック用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct OrderLockResponse {
+    pub order_id: String,
+    pub locked_by: String,
+    pub locked_at: String,
+    /// ロックの期限（これを過ぎると他のオペレーターも注文を変更できる）
+    pub expires_at: String,
+}
+
+/// 重複の可能性がある注文の検索結果（GET /admin/orders/:order_id/similar のレスポンス）
+#[derive(Serialize, ToSchema)]
+pub struct SimilarOrdersResponse {
+    pub order_id: String,
+    /// 作成日時の差の上限（分）
+    pub within_minutes: u32,
+    /// 同じ顧客・同じ明細の注文（作成日時の昇順）
+    pub similar_orders: Vec<OrderSummaryResponse>,
 }
 
 /// 注文詳細用のレスポンスDTO
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct OrderDetailResponse {
     pub order_id: String,
+    /// 顧客に伝える注文番号（採番を導入する前に作成した注文はNone）
+    #[serde(default)]
+    pub order_number: Option<String>,
     pub customer_id: String,
+    /// 注文を受け付けた販売チャネル（"Online" または "InStore"）
+    #[serde(default)]
+    pub sales_channel: String,
     pub status: String,
     pub order_lines: Vec<OrderLineResponse>,
     pub shipping_address: Option<ShippingAddressResponse>,
+    /// 正規化前の入力されたままの配送先住所（監査用、住所を正規化して設定した場合のみ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_shipping_address: Option<ShippingAddressResponse>,
+    /// ギフト注文かどうか
+    pub gift: bool,
+    /// ギフト注文の受取人（住所はshipping_address）
+    pub recipient: Option<RecipientResponse>,
     pub subtotal_amount: i64,
     pub subtotal_currency: String,
     pub shipping_fee_amount: i64,
     pub shipping_fee_currency: String,
+    /// 配送料の内訳（総重量・重量帯の基本料金・都道府県の追加料金）
+    #[schema(value_type = Object)]
+    pub shipping_fee_breakdown: ShippingFeeBreakdown,
     pub total_amount: i64,
     pub total_currency: String,
+    /// 確定時の金額（確定済み以降の注文のみ。金額の各項目はこの値を返し、確定後に配送料ポリシーや税率が変わっても変わらない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmed_totals: Option<ConfirmedTotalsResponse>,
+    /// 配送状況の公開確認用のトークン（GET /track/:token、確定前・キャンセル済みの注文はNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_token: Option<String>,
+    /// 配達予定日（YYYY-MM-DD、確定前・電子書籍のみ・キャンセル済みなど発送の予定が立たない注文はNone）
+    #[serde(default)]
+    pub estimated_delivery_date: Option<String>,
+    /// キャンセルの受付期限（RFC 3339、期限のない注文はNone）
+    #[serde(default)]
+    pub cancellable_until: Option<String>,
+    /// キャンセルの受付期限までの残り秒数（期限を過ぎている場合は0）
+    #[serde(default)]
+    pub cancellation_window_remaining_seconds: Option<i64>,
+}
+
+/// 確定時の金額のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ConfirmedTotalsResponse {
+    pub subtotal_amount: i64,
+    pub shipping_fee_amount: i64,
+    /// 合計金額に含まれる消費税額（内税）
+    pub tax_amount: i64,
+    pub total_amount: i64,
+    pub currency: String,
 }
 
 /// 注文明細用のレスポンスDTO
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct OrderLineResponse {
     pub book_id: String,
+    /// 書籍のタイトル（表示言語のタイトルがない場合は他の言語、タイトルが未登録の場合はNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// titleの言語（"ja"、"en"）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_language: Option<String>,
     pub quantity: u32,
     pub unit_price_amount: i64,
     pub unit_price_currency: String,
     pub subtotal_amount: i64,
     pub subtotal_currency: String,
+    pub fulfillment_type: String,
+    /// 書籍の重量（グラム、未登録の場合はNone）
+    pub weight_grams: Option<u32>,
+    /// 明細ごとの要望（「サイン本希望」など）
+    #[serde(default)]
+    pub attributes: Vec<LineAttributeResponse>,
+}
+
+/// 注文明細の属性用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct LineAttributeResponse {
+    pub key: String,
+    pub value: String,
 }
 
 /// 配送先住所用のレスポンスDTO
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct ShippingAddressResponse {
     pub postal_code: String,
     pub prefecture: String,
@@ -49,32 +157,390 @@ pub struct ShippingAddressResponse {
     pub building: Option<String>,
 }
 
+/// ギフト注文の受取人用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RecipientResponse {
+    pub name: String,
+    pub phone: String,
+}
+
 /// 在庫用のレスポンスDTO
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct InventoryResponse {
     pub book_id: String,
     pub quantity_on_hand: u32,
+    pub release_date: Option<String>,
+    /// 凍結中（在庫変動ログとの照合による修復後、棚卸しが終わるまで予約を受け付けない）
+    pub frozen: bool,
+    /// 在庫を超える注文を先着順の順番待ちにする
+    #[serde(default)]
+    pub waitlist_enabled: bool,
+}
+
+/// 割当枠用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AllocationQuotaResponse {
+    pub book_id: String,
+    /// 1顧客が購入できる最大数量（Noneの場合は顧客ごとに制限しない）
+    pub per_customer_cap: Option<u32>,
+    /// 割当比率をかける数量
+    pub allocation_pool: u32,
+    /// 販売チャネルごとの割当枠（チャネルで制限しない場合は空）
+    pub channels: Vec<ChannelQuotaResponse>,
+    /// 購入した顧客の数
+    pub customer_count: usize,
+}
+
+/// 販売チャネルごとの割当枠用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ChannelQuotaResponse {
+    pub channel: String,
+    /// 割当比率（%）
+    pub share_percent: u32,
+    /// 割り当てられる数量
+    pub limit: u32,
+    /// 割当済みの数量
+    pub allocated: u32,
+}
+
+/// 棚卸し用のレスポンスDTO
+#[derive(Serialize, ToSchema)]
+pub struct CycleCountResponse {
+    pub cycle_count_id: String,
+    pub status: String,
+    pub approved_by: Option<String>,
+    pub lines: Vec<CycleCountLineResponse>,
+}
+
+/// 顧客用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CustomerResponse {
+    pub customer_id: String,
+    pub name: String,
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_shipping_address: Option<ShippingAddressResponse>,
+    /// 通知を送る言語（ja・en）
+    pub preferred_language: String,
+    pub registered_at: String,
+}
+
+/// 棚卸し明細用のレスポンスDTO
+#[derive(Serialize, ToSchema)]
+pub struct CycleCountLineResponse {
+    pub book_id: String,
+    pub counted_quantity: Option<u32>,
+    pub system_quantity: Option<u32>,
+    pub variance: Option<i64>,
+}
+
+/// デバイス登録用のレスポンスDTO
+#[derive(Serialize, ToSchema)]
+pub struct DeviceResponse {
+    pub token: String,
+    pub platform: String,
+    /// 購読しているプッシュ通知トピック
+    pub topic: String,
+}
+
+/// Webhookの購読用のレスポンスDTO
+/// 署名用のシークレットは登録時にも返さない
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionResponse {
+    pub subscription_id: String,
+    pub customer_id: String,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub created_at: String,
+}
+
+/// 顧客のデータのエクスポート用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CustomerExportResponse {
+    pub job_id: String,
+    pub customer_id: String,
+    /// ジョブの状態（pending / completed / failed）
+    pub status: String,
+    pub requested_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// アーカイブに含めた注文数（完了するまでは0）
+    pub order_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// ジョブの状態の確認先
+    pub status_url: String,
+    /// アーカイブ（ZIP）のダウンロード先（完了した場合のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
+}
+
+/// Webhookの配信記録用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    pub delivery_id: String,
+    pub subscription_id: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub order_id: String,
+    /// 配信結果（Succeeded / Failed）
+    pub status: String,
+    /// 配信先が返したHTTPステータス（接続できなかった場合は省略）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_status: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 再配信の場合は元の配信ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redelivery_of: Option<String>,
+    pub attempted_at: String,
+    /// 配信したJSONペイロード
+    pub payload: String,
+}
+
+/// 再試行待ちの操作用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PendingOperationResponse {
+    pub operation_id: String,
+    pub operation_type: String,
+    /// 操作の状態（Pending / Completed / Failed）
+    pub status: String,
+    pub aggregate_id: String,
+    pub event_type: String,
+    /// 試行回数（最初の発行の失敗を含む）
+    pub attempts: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+}
+
+/// 送信に失敗した通知用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct FailedNotificationResponse {
+    pub notification_id: String,
+    /// 通知のチャネル（Email / Sms）
+    pub channel: String,
+    pub order_id: String,
+    pub event_type: String,
+    pub message: String,
+    /// 通知の状態（Pending / Delivered / Undeliverable）
+    pub status: String,
+    /// 試行回数（最初の送信の失敗を含む）
+    pub attempts: u32,
+    pub last_error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_attempt_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered_at: Option<String>,
+}
+
+/// アクションリンクの確認用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ActionLinkResponse {
+    /// 実行する操作（cancel_order / confirm_delivery）
+    pub action: String,
+    pub order_id: String,
+    pub expires_at: String,
+}
+
+/// 注文の順番待ち照会用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct WaitlistStatusResponse {
+    pub order_id: String,
+    pub status: String,
+    /// 書籍ごとの順番（順番待ちでない場合は空）
+    pub positions: Vec<WaitlistPositionResponse>,
+}
+
+/// 書籍ごとの順番待ちの位置
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct WaitlistPositionResponse {
+    pub book_id: String,
+    /// 先頭を1とする順番
+    pub position: u32,
+    pub quantity: u32,
+    /// 前に並んでいる注文が待っている数量の合計
+    pub quantity_ahead: u32,
+}
+
+/// チェックアウト中の在庫の仮押さえ用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CheckoutHoldResponse {
+    pub order_id: String,
+    /// 仮押さえの期限（これを過ぎると在庫が解放される）
+    pub expires_at: Option<String>,
+    /// 書籍ごとの仮押さえ（電子書籍のみの注文の場合は空）
+    pub holds: Vec<CheckoutHoldLineResponse>,
+}
+
+/// 書籍ごとの仮押さえ
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CheckoutHoldLineResponse {
+    pub book_id: String,
+    pub quantity: u32,
+}
+
+/// 一括のステータス遷移用のレスポンスDTO
+/// 注文ごとに独立して処理するため、一部の注文が失敗しても成功した注文の遷移は取り消されない
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BulkTransitionResponse {
+    pub succeeded: usize,
+    pub failed: usize,
+    /// 指定された順の注文ごとの結果（重複して指定された注文は1件にまとめる）
+    pub results: Vec<BulkTransitionResultResponse>,
+}
+
+/// 一括キャンセルのプレビュー（POST /admin/orders/bulk-cancel で確認トークンを省略した場合のレスポンス）
+#[derive(Serialize, ToSchema)]
+pub struct BulkCancellationPreviewResponse {
+    /// キャンセルの対象になる注文数
+    pub count: usize,
+    pub orders: Vec<OrderSummaryResponse>,
+    /// 実行時にリクエストに指定するトークン（プレビュー後に対象が変わると無効になる）
+    pub confirmation_token: String,
+}
+
+/// 一括のステータス遷移の注文1件ごとの結果
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BulkTransitionResultResponse {
+    pub order_id: String,
+    pub success: bool,
+    /// 成功した場合に発行したイベント
+    pub event: Option<EventEchoResponse>,
+    /// 失敗した場合のエラーコード（1件ずつのエンドポイントと同じコード）
+    pub code: Option<String>,
+    pub error: Option<String>,
+}
+
+/// コマンドが発行したドメインイベントのレスポンスDTO
+/// クライアントが楽観的に更新した画面を、イベントの配信と突き合わせるために使う
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct EventEchoResponse {
+    pub event_id: Uuid,
+    pub correlation_id: Uuid,
+    pub event_type: String,
+    /// シリアライズしたイベント（メタデータを含む）
+    #[schema(value_type = Object)]
+    pub payload: DomainEvent,
+}
+
+impl EventEchoResponse {
+    /// 発行したイベントからEventEchoResponseを作成
+    pub fn from_event(event: DomainEvent) -> Self {
+        Self {
+            event_id: event.metadata().event_id,
+            correlation_id: event.metadata().correlation_id,
+            event_type: event.event_type().to_string(),
+            payload: event,
+        }
+    }
+}
+
+/// 書籍の販売価格用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BookPriceResponse {
+    pub book_id: String,
+    pub unit_price_amount: i64,
+    pub unit_price_currency: String,
+}
+
+/// 書籍の言語ごとのタイトル用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct BookTitlesResponse {
+    pub book_id: String,
+    /// 言語コード（"ja"、"en"）ごとのタイトル
+    pub titles: BTreeMap<String, String>,
+}
+
+/// 追跡トークンで公開する配送状況用のレスポンスDTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicTrackingResponse {
+    pub status: String,
+    /// 配達予定日時（RFC 3339、見積もれない注文はNone）
+    pub estimated_delivery_at: Option<String>,
+    /// 発送日時（RFC 3339）
+    pub shipped_at: Option<String>,
+    /// 配達完了日時（RFC 3339）
+    pub delivered_at: Option<String>,
+    /// 一部を伏せた配送先住所（郵便番号の上3桁・都道府県・市区町村）
+    #[schema(value_type = Option<Object>)]
+    pub shipping_address: Option<MaskedAddress>,
+}
+
+/// エンドポイントの認可ポリシー一覧用のレスポンスDTO
+#[derive(Serialize, ToSchema)]
+pub struct AuthzPoliciesResponse {
+    /// 上から順に照合し、最初に一致したポリシーを適用する
+    pub policies: Vec<AuthzPolicyResponse>,
+    /// 一致するポリシーがないエンドポイントの条件
+    pub default_requirement: String,
+    /// 役割ごとに付与する権限
+    pub roles: Vec<RolePermissionsResponse>,
+}
+
+/// エンドポイントの認可ポリシー用のレスポンスDTO
+#[derive(Serialize, ToSchema)]
+pub struct AuthzPolicyResponse {
+    /// 対象のメソッド（any / read / write）
+    pub methods: String,
+    pub path: String,
+    /// 要求する条件（public / authenticated / 権限の名前）
+    pub requirement: String,
+    /// 利用者を本人に限定する対象（order / customer）
+    pub owner: Option<String>,
+}
+
+/// 役割の権限用のレスポンスDTO
+#[derive(Serialize, ToSchema)]
+pub struct RolePermissionsResponse {
+    pub role: String,
+    pub permissions: Vec<String>,
 }
 
 impl OrderSummaryResponse {
     /// ドメインオブジェクトからOrderSummaryResponseを作成
     /// 注意: created_atは現在のシステムでは利用できないため、固定値を使用
-    pub fn from_order(order: &Order) -> Self {
-        let total = order.calculate_total();
+    pub fn from_order(order: &Order, shipping_fee_policy: &ShippingFeePolicy) -> Self {
+        let total = OrderTotals::for_order(order, shipping_fee_policy).total;
         Self {
             order_id: order.id().to_string(),
+            order_number: order.order_number().map(ToString::to_string),
             customer_id: order.customer_id().to_string(),
             status: order.status().to_string(),
             total_amount: total.amount(),
             total_currency: total.currency(),
             created_at: "2024-01-01T00:00:00Z".to_string(), // TODO: 実際の作成日時を使用
+            lock: None,
+        }
+    }
+
+    /// 注文の編集ロックを設定する
+    pub fn with_lock(mut self, lock: Option<&OrderLock>) -> Self {
+        self.lock = lock.map(OrderLockResponse::from_lock);
+        self
+    }
+}
+
+impl OrderLockResponse {
+    /// ドメインオブジェクトからOrderLockResponseを作成
+    pub fn from_lock(lock: &OrderLock) -> Self {
+        Self {
+            order_id: lock.order_id.to_string(),
+            locked_by: lock.locked_by.clone(),
+            locked_at: lock.locked_at.to_rfc3339(),
+            expires_at: lock.expires_at.to_rfc3339(),
         }
     }
 }
 
 impl OrderDetailResponse {
     /// ドメインオブジェクトからOrderDetailResponseを作成
-    pub fn from_order(order: &Order) -> Self {
+    pub fn from_order(order: &Order, shipping_fee_policy: &ShippingFeePolicy) -> Self {
         let order_lines: Vec<OrderLineResponse> = order
             .order_lines()
             .iter()
@@ -85,35 +551,77 @@ impl OrderDetailResponse {
             .shipping_address()
             .map(ShippingAddressResponse::from_shipping_address);
 
-        // 小計を計算（配送料を除く）
-        let subtotal = order
-            .order_lines()
-            .iter()
-            .map(|line| line.subtotal())
-            .fold(Money::jpy(0), |acc, amount| acc.add(&amount).unwrap_or(acc));
-
-        // 配送料を計算
-        let shipping_fee = if subtotal.amount() >= 10_000 {
-            Money::jpy(0)
-        } else {
-            Money::jpy(500)
-        };
-
-        let total = order.calculate_total();
+        // 小計（配送料を除く）と配送料（確定済みの注文は確定時の金額、Pendingの注文は現在のポリシーで再計算）
+        let totals = OrderTotals::for_order(order, shipping_fee_policy);
+        let subtotal = totals.subtotal;
+        let shipping_fee = totals.shipping_fee();
+        let total = totals.total;
 
         Self {
             order_id: order.id().to_string(),
+            order_number: order.order_number().map(ToString::to_string),
             customer_id: order.customer_id().to_string(),
+            sales_channel: order.sales_channel().to_string(),
             status: order.status().to_string(),
             order_lines,
             shipping_address,
+            original_shipping_address: order.original_shipping_address().map(|original| {
+                ShippingAddressResponse {
+                    postal_code: original.postal_code.clone(),
+                    prefecture: original.prefecture.clone(),
+                    city: original.city.clone(),
+                    street: original.street.clone(),
+                    building: original.building.clone(),
+                }
+            }),
+            gift: order.is_gift(),
+            recipient: order.recipient().map(|recipient| RecipientResponse {
+                name: recipient.name().to_string(),
+                phone: recipient.phone().to_string(),
+            }),
             subtotal_amount: subtotal.amount(),
             subtotal_currency: subtotal.currency(),
             shipping_fee_amount: shipping_fee.amount(),
             shipping_fee_currency: shipping_fee.currency(),
+            shipping_fee_breakdown: totals.shipping,
             total_amount: total.amount(),
             total_currency: total.currency(),
+            confirmed_totals: order
+                .confirmed_totals()
+                .map(|confirmed| ConfirmedTotalsResponse {
+                    subtotal_amount: confirmed.subtotal.amount(),
+                    shipping_fee_amount: confirmed.shipping.fee,
+                    tax_amount: confirmed.tax.amount(),
+                    total_amount: confirmed.total.amount(),
+                    currency: confirmed.total.currency(),
+                }),
+            tracking_token: order.tracking_token().map(ToString::to_string),
+            estimated_delivery_date: order
+                .estimated_delivery_date()
+                .map(|date| date.format("%Y-%m-%d").to_string()),
+            cancellable_until: None,
+            cancellation_window_remaining_seconds: None,
+        }
+    }
+
+    /// 明細の書籍のタイトルを設定（表示言語で選んだタイトル）
+    pub fn with_line_titles(mut self, titles: &HashMap<BookId, LocalizedTitle>) -> Self {
+        for line in &mut self.order_lines {
+            let title = BookId::from_string(&line.book_id)
+                .ok()
+                .and_then(|book_id| titles.get(&book_id));
+            line.title = title.map(|title| title.title.clone());
+            line.title_language = title.map(|title| title.language.code().to_string());
         }
+        self
+    }
+
+    /// キャンセルの受付期限を設定
+    pub fn with_cancellation_window(mut self, window: Option<CancellationWindow>) -> Self {
+        self.cancellable_until = window.map(|window| window.deadline.to_rfc3339());
+        self.cancellation_window_remaining_seconds =
+            window.map(|window| window.remaining.num_seconds());
+        self
     }
 }
 
@@ -125,11 +633,23 @@ impl OrderLineResponse {
 
         Self {
             book_id: order_line.book_id().to_string(),
+            title: None,
+            title_language: None,
             quantity: order_line.quantity(),
             unit_price_amount: unit_price.amount(),
             unit_price_currency: unit_price.currency(),
             subtotal_amount: subtotal.amount(),
             subtotal_currency: subtotal.currency(),
+            fulfillment_type: order_line.fulfillment_type().to_string(),
+            weight_grams: order_line.spec().map(|spec| spec.weight_grams()),
+            attributes: order_line
+                .attributes()
+                .iter()
+                .map(|attribute| LineAttributeResponse {
+                    key: attribute.key().to_string(),
+                    value: attribute.value().to_string(),
+                })
+                .collect(),
         }
     }
 }
@@ -153,6 +673,295 @@ impl InventoryResponse {
         Self {
             book_id: inventory.book_id().to_string(),
             quantity_on_hand: inventory.quantity_on_hand(),
+            release_date: inventory
+                .release_date()
+                .map(|date| date.format("%Y-%m-%d").to_string()),
+            frozen: inventory.is_frozen(),
+            waitlist_enabled: inventory.is_waitlist_enabled(),
+        }
+    }
+}
+
+impl AllocationQuotaResponse {
+    /// ドメインオブジェクトからAllocationQuotaResponseを作成
+    pub fn from_quota(quota: &AllocationQuota) -> Self {
+        Self {
+            book_id: quota.book_id().to_string(),
+            per_customer_cap: quota.per_customer_cap(),
+            allocation_pool: quota.allocation_pool(),
+            channels: quota
+                .channel_shares()
+                .iter()
+                .map(|(channel, share)| ChannelQuotaResponse {
+                    channel: channel.to_string(),
+                    share_percent: *share,
+                    limit: quota.channel_limit(*channel).unwrap_or(0),
+                    allocated: quota.channel_allocated().get(channel).copied().unwrap_or(0),
+                })
+                .collect(),
+            customer_count: quota.customer_allocated().len(),
+        }
+    }
+}
+
+impl CycleCountResponse {
+    /// ドメインオブジェクトからCycleCountResponseを作成
+    pub fn from_cycle_count(cycle_count: &CycleCount) -> Self {
+        Self {
+            cycle_count_id: cycle_count.id().to_string(),
+            status: cycle_count.status().to_string(),
+            approved_by: cycle_count.approved_by().map(str::to_string),
+            lines: cycle_count
+                .lines()
+                .iter()
+                .map(CycleCountLineResponse::from_cycle_count_line)
+                .collect(),
+        }
+    }
+}
+
+impl CycleCountLineResponse {
+    /// ドメインオブジェクトからCycleCountLineResponseを作成
+    pub fn from_cycle_count_line(line: &CycleCountLine) -> Self {
+        Self {
+            book_id: line.book_id().to_string(),
+            counted_quantity: line.counted_quantity(),
+            system_quantity: line.system_quantity(),
+            variance: line.variance(),
+        }
+    }
+}
+
+impl CustomerResponse {
+    /// ドメインオブジェクトからCustomerResponseを作成
+    pub fn from_customer(customer: &Customer) -> Self {
+        Self {
+            customer_id: customer.id().to_string(),
+            name: customer.name().to_string(),
+            email: customer.email().to_string(),
+            default_shipping_address: customer
+                .default_shipping_address()
+                .map(ShippingAddressResponse::from_shipping_address),
+            preferred_language: customer.preferred_language().code().to_string(),
+            registered_at: customer.registered_at().to_rfc3339(),
+        }
+    }
+}
+
+impl DeviceResponse {
+    /// ドメインオブジェクトからDeviceResponseを作成
+    pub fn from_registration(registration: &DeviceRegistration) -> Self {
+        Self {
+            token: registration.token().to_string(),
+            platform: registration.platform().to_string(),
+            topic: registration.topic(),
+        }
+    }
+}
+
+impl CustomerExportResponse {
+    /// ドメインオブジェクトからCustomerExportResponseを作成
+    pub fn from_job(job: &CustomerExportJob) -> Self {
+        let status_url = format!("/customers/{}/export/{}", job.customer_id, job.job_id);
+        let download_url = (job.status == CustomerExportStatus::Completed)
+            .then(|| format!("{}/download", status_url));
+        Self {
+            job_id: job.job_id.to_string(),
+            customer_id: job.customer_id.to_string(),
+            status: job.status.as_str().to_string(),
+            requested_at: job.requested_at.to_rfc3339(),
+            completed_at: job.completed_at.map(|at| at.to_rfc3339()),
+            order_count: job.order_count,
+            error: job.error.clone(),
+            status_url,
+            download_url,
+        }
+    }
+}
+
+impl WebhookSubscriptionResponse {
+    /// ドメインオブジェクトからWebhookSubscriptionResponseを作成
+    pub fn from_subscription(subscription: &WebhookSubscription) -> Self {
+        Self {
+            subscription_id: subscription.id.to_string(),
+            customer_id: subscription.customer_id.to_string(),
+            url: subscription.url.clone(),
+            event_types: subscription.event_types.clone(),
+            created_at: subscription.created_at.to_rfc3339(),
+        }
+    }
+}
+
+impl WebhookDeliveryResponse {
+    /// ドメインオブジェクトからWebhookDeliveryResponseを作成
+    pub fn from_delivery(delivery: &WebhookDelivery) -> Self {
+        Self {
+            delivery_id: delivery.id.to_string(),
+            subscription_id: delivery.subscription_id.to_string(),
+            event_id: delivery.event_id.to_string(),
+            event_type: delivery.event_type.clone(),
+            order_id: delivery.order_id.to_string(),
+            status: delivery.status.to_string(),
+            response_status: delivery.response_status,
+            error: delivery.error.clone(),
+            redelivery_of: delivery.redelivery_of.map(|id| id.to_string()),
+            attempted_at: delivery.attempted_at.to_rfc3339(),
+            payload: delivery.payload.clone(),
+        }
+    }
+}
+
+impl PendingOperationResponse {
+    /// ドメインオブジェクトからPendingOperationResponseを作成
+    pub fn from_operation(operation: &PendingOperation) -> Self {
+        Self {
+            operation_id: operation.id.to_string(),
+            operation_type: operation.operation_type.clone(),
+            status: operation.status.to_string(),
+            aggregate_id: operation.aggregate_id.clone(),
+            event_type: operation.event_type.clone(),
+            attempts: operation.attempts,
+            last_error: operation.last_error.clone(),
+            created_at: operation.created_at.to_rfc3339(),
+            updated_at: operation.updated_at.to_rfc3339(),
+            completed_at: operation.completed_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+impl FailedNotificationResponse {
+    /// ドメインオブジェクトからFailedNotificationResponseを作成
+    pub fn from_notification(failed: &FailedNotification) -> Self {
+        Self {
+            notification_id: failed.id.to_string(),
+            channel: failed.notification.channel.to_string(),
+            order_id: failed.notification.order_id.to_string(),
+            event_type: failed.notification.event_type.clone(),
+            message: failed.notification.message.clone(),
+            status: failed.status.to_string(),
+            attempts: failed.attempts,
+            last_error: failed.last_error.clone(),
+            next_attempt_at: failed.next_attempt_at.map(|at| at.to_rfc3339()),
+            created_at: failed.created_at.to_rfc3339(),
+            updated_at: failed.updated_at.to_rfc3339(),
+            delivered_at: failed.delivered_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+impl ActionLinkResponse {
+    /// リンクの内容からActionLinkResponseを作成
+    pub fn from_claims(claims: &ActionLinkClaims) -> Self {
+        Self {
+            action: claims.action.to_string(),
+            order_id: claims.order_id.to_string(),
+            expires_at: claims.expires_at.to_rfc3339(),
+        }
+    }
+}
+
+impl CheckoutHoldResponse {
+    /// 注文の仮押さえからCheckoutHoldResponseを作成
+    pub fn from_holds(order_id: OrderId, holds: &[CheckoutHold]) -> Self {
+        Self {
+            order_id: order_id.to_string(),
+            expires_at: holds.first().map(|hold| hold.expires_at.to_rfc3339()),
+            holds: holds
+                .iter()
+                .map(|hold| CheckoutHoldLineResponse {
+                    book_id: hold.book_id.to_string(),
+                    quantity: hold.quantity,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl WaitlistStatusResponse {
+    /// 注文のステータスと順番待ちの位置からWaitlistStatusResponseを作成
+    pub fn from_positions(
+        order_id: OrderId,
+        status: OrderStatus,
+        positions: &[WaitlistPosition],
+    ) -> Self {
+        Self {
+            order_id: order_id.to_string(),
+            status: status.to_string(),
+            positions: positions
+                .iter()
+                .map(|position| WaitlistPositionResponse {
+                    book_id: position.book_id.to_string(),
+                    position: position.position,
+                    quantity: position.quantity,
+                    quantity_ahead: position.quantity_ahead,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl BookTitlesResponse {
+    /// 書籍IDと言語ごとのタイトルからBookTitlesResponseを作成
+    pub fn from_titles(book_id: BookId, titles: &BookTitles) -> Self {
+        Self {
+            book_id: book_id.to_string(),
+            titles: titles
+                .iter()
+                .map(|(language, title)| (language.code().to_string(), title.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl PublicTrackingResponse {
+    /// 公開用の配送状況からPublicTrackingResponseを作成
+    pub fn from_view(view: PublicTrackingView) -> Self {
+        Self {
+            status: view.status.to_string(),
+            estimated_delivery_at: view.estimated_delivery_at.map(|at| at.to_rfc3339()),
+            shipped_at: view.shipped_at.map(|at| at.to_rfc3339()),
+            delivered_at: view.delivered_at.map(|at| at.to_rfc3339()),
+            shipping_address: view.shipping_address,
+        }
+    }
+}
+
+impl AuthzPoliciesResponse {
+    /// 認可ポリシーの一覧からAuthzPoliciesResponseを作成
+    pub fn from_policies(policies: &[EndpointPolicy], default_requirement: Requirement) -> Self {
+        Self {
+            policies: policies
+                .iter()
+                .map(|policy| AuthzPolicyResponse {
+                    methods: policy.methods.as_str().to_string(),
+                    path: policy.path.to_string(),
+                    requirement: policy.requirement.as_str().to_string(),
+                    owner: policy.owner.as_str().map(str::to_string),
+                })
+                .collect(),
+            default_requirement: default_requirement.as_str().to_string(),
+            roles: [Role::Customer, Role::Staff]
+                .into_iter()
+                .map(|role| RolePermissionsResponse {
+                    role: role.as_str().to_string(),
+                    permissions: role
+                        .permissions()
+                        .iter()
+                        .map(|permission| permission.as_str().to_string())
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl BookPriceResponse {
+    /// 書籍IDと価格からBookPriceResponseを作成
+    pub fn from_price(book_id: BookId, price: Money) -> Self {
+        Self {
+            book_id: book_id.to_string(),
+            unit_price_amount: price.amount(),
+            unit_price_currency: price.currency(),
         }
     }
 }
@@ -175,7 +984,7 @@ mod tests {
         let price = Money::jpy(1000);
         order.add_book(book_id, 2, price).unwrap();
 
-        let response = OrderSummaryResponse::from_order(&order);
+        let response = OrderSummaryResponse::from_order(&order, &ShippingFeePolicy::default());
 
         assert_eq!(response.order_id, order_id.to_string());
         assert_eq!(response.customer_id, customer_id.to_string());
@@ -204,9 +1013,9 @@ mod tests {
             Some("ビル名".to_string()),
         )
         .unwrap();
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
 
-        let response = OrderDetailResponse::from_order(&order);
+        let response = OrderDetailResponse::from_order(&order, &ShippingFeePolicy::default());
 
         assert_eq!(response.order_id, order_id.to_string());
         assert_eq!(response.customer_id, customer_id.to_string());
@@ -214,6 +1023,8 @@ mod tests {
         assert_eq!(response.order_lines.len(), 1);
         assert_eq!(response.subtotal_amount, 2000);
         assert_eq!(response.shipping_fee_amount, 500);
+        assert_eq!(response.shipping_fee_breakdown.base_fee, 500);
+        assert_eq!(response.shipping_fee_breakdown.prefecture_surcharge, 0);
         assert_eq!(response.total_amount, 2500);
         assert!(response.shipping_address.is_some());
     }
@@ -279,6 +1090,8 @@ mod tests {
 
         assert_eq!(response.book_id, book_id.to_string());
         assert_eq!(response.quantity_on_hand, 50);
+        assert_eq!(response.release_date, None);
+        assert!(!response.frozen);
     }
 
     #[test]
@@ -292,7 +1105,7 @@ mod tests {
         let price = Money::jpy(12000);
         order.add_book(book_id, 1, price).unwrap();
 
-        let response = OrderDetailResponse::from_order(&order);
+        let response = OrderDetailResponse::from_order(&order, &ShippingFeePolicy::default());
 
         assert_eq!(response.subtotal_amount, 12000);
         assert_eq!(response.shipping_fee_amount, 0); // 配送料無料
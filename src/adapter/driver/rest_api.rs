@@ -1,38 +1,183 @@
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    response::Json,
-    routing::{get, post, put},
-    Router,
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        MatchedPath, Path, Query, Request, State,
+    },
+    http::{header, HeaderMap, Method, StatusCode},
+    middleware::Next,
+    response::{
+        sse::{Event as SseEvent, KeepAlive, Sse},
+        IntoResponse, Json, Response,
+    },
+    routing::{delete, get, post, put},
+    Extension, Router,
 };
+use chrono::{TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
 use std::sync::Arc;
-
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::adapter::driven::MySqlOrderRepository;
+use crate::adapter::driver::admin_ui::{admin_ui_asset, admin_ui_index};
+use crate::adapter::driver::auth::{forbidden, AUTHZ_POLICIES, DEFAULT_RULE};
+use crate::adapter::driver::openapi::{openapi_json, swagger_ui};
+use crate::adapter::driver::legacy_order_import::{
+    import_legacy_orders, LegacyImportReport, LegacyOrderImportBatch,
+    MAX_LEGACY_IMPORT_BATCH_SIZE,
+};
 use crate::adapter::driver::request_dto::{
-    AddBookRequest, CreateInventoryRequest, CreateOrderRequest, InventoryQueryParams,
-    OrdersQueryParams, SetShippingAddressRequest,
+    AddBookRequest, AllocationQuotaRequest, ApproveCycleCountRequest, BulkCancelRequest, BulkTransitionRequest, CarrierTrackingWebhookRequest,
+    CreateCycleCountRequest, CreateInventoryRequest, CreateOrderRequest, CreateWebhookSubscriptionRequest, DeadLettersQueryParams, EventTracesQueryParams,
+    ExportEventsRequest, ImportEventsRequest,
+    FailedNotificationsQueryParams, ForceOrderStatusRequest, HoldOrderRequest, InventoryChangesQueryParams, InventoryQueryParams, LockOrderRequest, AddOrderNoteRequest, OrdersQueryParams, CustomerNotificationsQueryParams, PauseEventBusQueryParams, PauseSubscriptionRequest, RecordCycleCountRequest,
+    RegisterCustomerRequest, RegisterDeviceRequest, RepairOrderQueryParams, ReportFormat, ReportQueryParams, RestockRequest, ReturnOrderRequest, SetBookPriceRequest, SetBookTitleRequest, SetPreferredLanguageRequest, SetLineAttributesRequest,
+    SetRecipientRequest, SetShippingAddressRequest, SimilarOrdersQueryParams, WebhookDeliveriesQueryParams,
 };
 use crate::adapter::driver::response_dto::{
-    InventoryResponse, OrderDetailResponse, OrderSummaryResponse,
+    ActionLinkResponse, AllocationQuotaResponse, AuthzPoliciesResponse, BookPriceResponse, BookTitlesResponse, BulkCancellationPreviewResponse, BulkTransitionResponse, BulkTransitionResultResponse, CheckoutHoldResponse,
+    CustomerExportResponse, CustomerResponse, CycleCountResponse,
+    DeviceResponse, EventEchoResponse, FailedNotificationResponse, InventoryResponse, OrderDetailResponse, OrderLockResponse,
+    OrderSummaryResponse, PendingOperationResponse, PublicTrackingResponse, SimilarOrdersResponse, WaitlistStatusResponse,
+    WebhookDeliveryResponse, WebhookSubscriptionResponse,
+};
+use crate::application::service::{
+    ActionLinkApplicationService, BulkTransition, BulkTransitionOutcome, CatalogApplicationService, CheckoutHoldApplicationService, CommandAcknowledgement, CycleCountApplicationService, OrderEventSubscription, DeadLetterApplicationService, DiagnosticsApplicationService, EventTraceApplicationService,
+    CustomerApplicationService, CustomerExportApplicationService, DeviceApplicationService, EventExportApplicationService, ForecastApplicationService, InboxApplicationService, InventoryApplicationService,
+    LegacyImportApplicationService, NotificationApplicationService, OrderApplicationService, OrderLockApplicationService, OrderRepairApplicationService,
+    ParkedEventApplicationService, PendingOperationApplicationService, ProjectionApplicationService, SagaMetricsApplicationService,
+    SubscriptionApplicationService, TimelineApplicationService, TrackingApplicationService, WaitlistApplicationService,
+    WebhookApplicationService,
+    DEFAULT_EVENT_DISPATCH_DRAIN_TIMEOUT_SECONDS, DEFAULT_SIMILAR_ORDER_WINDOW_MINUTES,
 };
-use crate::application::service::{InventoryApplicationService, OrderApplicationService};
 use crate::application::ApplicationError;
-use crate::domain::model::{BookId, CustomerId, Money, OrderId};
+use crate::domain::access_control::Principal;
+use crate::domain::customer_notification::CustomerNotification;
+use crate::domain::error::DomainError;
+use crate::domain::event::DomainEvent;
+use crate::domain::book_translation::Language;
+use crate::domain::bulk_cancellation::BulkCancellationFilter;
+use crate::domain::dead_letter::DeadLetterSummary;
+use crate::domain::diagnostics::DiagnosticsReport;
+use crate::domain::event_bus::{DeadLetter, EventDispatchStatus, SubscriptionStatus};
+use crate::domain::event_export::{EventImportSummary, ExportManifest};
+use crate::domain::event_trace::EventTraceReport;
+use crate::domain::forecast::DemandForecast;
+use crate::domain::glossary::{DomainModelDescription, DomainModelRegistry};
+use crate::domain::inbox::INBOX_SOURCE_CARRIER;
+use crate::domain::inventory_snapshot::{InventoryChanges, InventorySnapshot};
+use crate::domain::late_event::ParkedEvent;
+use crate::domain::legacy_import::LegacyImportRecord;
+use crate::domain::load_shedding::LoadSheddingStatus;
+use crate::domain::metrics::{BusinessMetrics, BusinessMetricsSnapshot};
+use crate::domain::notification_retry::FailedNotificationStatus;
+use crate::domain::order_lock::DEFAULT_ORDER_LOCK_TTL_MINUTES;
+use crate::domain::order_note::OrderNote;
+use crate::domain::order_timeline::OrderTimeline;
+use crate::domain::order_repair::OrderRepairReport;
+use crate::domain::packing_slip::PackingSlip;
+use crate::domain::pending_operation::PendingOperation;
+use crate::domain::model::{
+    BookId, CustomerId, CycleCountId, DevicePlatform, DeviceToken, HoldReason, Money, OrderId,
+    OrderStatus, TrackingToken,
+};
+use crate::domain::port::{OrderRepository, RepositoryError};
+use crate::domain::projection::ProjectionStatus;
+use crate::domain::reconciliation::InventoryReconciliationReport;
+use crate::domain::retry_policy::EventRetryPolicy;
+use crate::domain::saga_metrics::SagaMetricsReport;
+use crate::domain::sla::SlaReport;
+use crate::domain::status_override::StatusOverrideAudit;
+use crate::domain::subscription_registry::SubscriptionRegistration;
+use crate::domain::tracking::TrackingTimeline;
+use crate::domain::validation::{Constraint, FieldViolation};
+use crate::domain::warning::DomainWarning;
 
 // REST API用のレスポンスDTO
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct CreateOrderResponse {
     pub order_id: Uuid,
     pub customer_id: Uuid,
 }
 
-#[derive(Serialize, Deserialize)]
+/// 更新系エンドポイントのレスポンス（成功した上で確認してほしい警告）
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CommandResponse {
+    #[schema(value_type = Vec<Object>)]
+    pub warnings: Vec<DomainWarning>,
+    /// コマンドが発行したドメインイベント（イベントを発行しないコマンドでは省略）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<EventEchoResponse>,
+    /// イベントの発行を再試行待ちにした場合の操作ID（GET /operations/:operation_id で完了を確認する）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<Uuid>,
+}
+
+impl CommandResponse {
+    /// イベントを発行しないコマンドのレスポンスを作成
+    pub fn from_warnings(warnings: Vec<DomainWarning>) -> Self {
+        Self {
+            warnings,
+            event: None,
+            operation_id: None,
+        }
+    }
+}
+
+impl From<CommandAcknowledgement> for CommandResponse {
+    fn from(acknowledgement: CommandAcknowledgement) -> Self {
+        Self {
+            warnings: acknowledgement.warnings,
+            event: Some(EventEchoResponse::from_event(acknowledgement.event)),
+            operation_id: acknowledgement.pending_operation_id,
+        }
+    }
+}
+
+// イベントを発行するコマンドのレスポンスを作成
+// イベントの発行を再試行待ちにした場合は、状態の変更は保存済みのため202で応答する
+fn command_response(acknowledgement: CommandAcknowledgement) -> (StatusCode, Json<CommandResponse>) {
+    let status = if acknowledgement.pending_operation_id.is_some() {
+        StatusCode::ACCEPTED
+    } else {
+        StatusCode::OK
+    };
+    (status, Json(acknowledgement.into()))
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateCycleCountResponse {
+    pub cycle_count_id: Uuid,
+}
+
+/// 購読の再開エンドポイントのレスポンス
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ResumeSubscriptionResponse {
+    /// 一時停止中にためていて、再開時に配信したイベント数
+    pub replayed_events: usize,
+}
+
+/// イベントの配信全体の再開エンドポイントのレスポンス
+#[derive(Serialize, ToSchema)]
+pub struct ResumeEventBusResponse {
+    /// 一時停止中にためていて、再開時に配信したイベント数（ブローカーから受信する場合は常に0）
+    pub replayed_events: usize,
+    #[schema(value_type = Object)]
+    pub status: EventDispatchStatus,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct ApiError {
     pub error: String,
     pub code: String,
+    /// 検証に失敗した項目（検証エラーの場合のみ）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(value_type = Vec<Object>)]
+    pub violations: Vec<FieldViolation>,
 }
 
 // アプリケーションサービスを含む状態
@@ -40,33 +185,261 @@ pub type AppState = AppStateInner;
 
 #[derive(Clone)]
 pub struct AppStateInner {
-    pub order_service: Arc<OrderApplicationService<MySqlOrderRepository>>,
+    pub order_service: Arc<OrderApplicationService<Arc<dyn OrderRepository>>>,
     pub inventory_service: Arc<InventoryApplicationService>,
+    pub catalog_service: Arc<CatalogApplicationService>,
+    pub waitlist_service: Arc<WaitlistApplicationService>,
+    pub checkout_hold_service: Arc<CheckoutHoldApplicationService>,
+    pub cycle_count_service: Arc<CycleCountApplicationService>,
+    pub customer_service: Arc<CustomerApplicationService>,
+    pub customer_export_service: Arc<CustomerExportApplicationService>,
+    pub device_service: Arc<DeviceApplicationService>,
+    pub tracking_service: Arc<TrackingApplicationService>,
+    pub timeline_service: Arc<TimelineApplicationService>,
+    pub inbox_service: Arc<InboxApplicationService>,
+    pub legacy_import_service: Arc<LegacyImportApplicationService>,
+    pub parked_event_service: Arc<ParkedEventApplicationService>,
+    pub dead_letter_service: Arc<DeadLetterApplicationService>,
+    pub event_trace_service: Arc<EventTraceApplicationService>,
+    pub projection_service: Arc<ProjectionApplicationService>,
+    pub saga_metrics_service: Arc<SagaMetricsApplicationService>,
+    pub order_repair_service: Arc<OrderRepairApplicationService>,
+    pub order_lock_service: Arc<OrderLockApplicationService>,
+    pub pending_operation_service: Arc<PendingOperationApplicationService>,
+    pub action_link_service: Arc<ActionLinkApplicationService<Arc<dyn OrderRepository>>>,
+    pub notification_service: Arc<NotificationApplicationService>,
+    pub subscription_service: Arc<SubscriptionApplicationService>,
+    pub event_export_service: Arc<EventExportApplicationService>,
+    pub webhook_service: Arc<WebhookApplicationService>,
+    pub forecast_service: Arc<ForecastApplicationService>,
+    pub diagnostics_service: Arc<DiagnosticsApplicationService>,
+    pub business_metrics: BusinessMetrics,
 }
 
 // REST APIルーターを作成
 pub fn create_router() -> Router<AppState> {
     Router::new()
         .route("/health", get(health_check))
+        // 準備状態の確認（バックグラウンドタスクが止まっている場合は503）
+        .route("/health/ready", get(readiness_check))
+        // ドメインモデルの説明（集約・値オブジェクト・イベント・サーガ）
+        .route("/meta/domain-model", get(get_domain_model))
+        // OpenAPI仕様とSwagger UI
+        .route("/openapi.json", get(openapi_json))
+        .route("/docs", get(swagger_ui))
         .route("/orders", post(create_order))
         .route("/orders/:order_id/books", post(add_book_to_order))
         .route(
             "/orders/:order_id/shipping-address",
             put(set_shipping_address),
         )
+        .route(
+            "/orders/:order_id/books/:book_id/attributes",
+            put(set_line_attributes),
+        )
+        .route("/orders/:order_id/recipient", put(set_gift_recipient))
+        .route("/orders/:order_id/packing-slip", get(get_packing_slip))
+        .route("/orders/:order_id/checkout-hold", post(place_checkout_hold))
         .route("/orders/:order_id/confirm", post(confirm_order))
+        .route("/orders/:order_id/reprice", post(reprice_order))
         .route("/orders/:order_id/cancel", post(cancel_order))
+        .route("/orders/:order_id/hold", post(hold_order))
+        .route("/orders/:order_id/release", post(release_order_hold))
         .route("/orders/:order_id/ship", post(mark_order_as_shipped))
         .route("/orders/:order_id/deliver", post(mark_order_as_delivered))
+        .route("/orders/:order_id/return", post(request_order_return))
+        .route(
+            "/orders/:order_id/return/approve",
+            post(approve_order_return),
+        )
+        .route("/orders/bulk/ship", post(bulk_mark_orders_as_shipped))
+        .route("/orders/bulk/deliver", post(bulk_mark_orders_as_delivered))
+        .route("/orders/:order_id/tracking", get(get_order_tracking))
+        // サポート向けの注文タイムライン（イベント・ステータス・CSメモ・追跡情報）とCSメモの追加
+        .route("/orders/:order_id/timeline", get(get_order_timeline))
+        .route("/orders/:order_id/notes", post(add_order_note))
+        // 注文に関して発行されたドメインイベントのライブストリーム（Server-Sent Events）
+        .route("/orders/:order_id/events", get(stream_order_events))
+        .route("/orders/:order_id/waitlist", get(get_order_waitlist))
+        // アカウントなしで配送状況を確認する公開エンドポイント（確定時に発行した追跡トークンで照会）
+        .route("/track/:token", get(get_public_tracking))
+        // 通知に載せた署名付きリンクからの顧客の操作（GETで内容の確認、POSTで実行）
+        .route(
+            "/actions/:token",
+            get(get_action_link).post(execute_action_link),
+        )
+        // イベントの発行を再試行待ちにしたコマンドの完了の確認
+        .route("/operations/:operation_id", get(get_operation))
+        .route("/webhooks/carrier", post(receive_carrier_tracking))
         .route("/inventory", post(create_inventory))
+        // 書籍の販売価格（注文の確定時に明細の単価と照合する）
+        .route(
+            "/catalog/books/:book_id/price",
+            put(set_book_price).get(get_book_price),
+        )
+        // 書籍の言語ごとのタイトル（注文詳細の明細にAccept-Languageの言語で表示する）
+        .route("/catalog/books/:book_id/titles", get(get_book_titles))
+        .route(
+            "/catalog/books/:book_id/titles/:language",
+            put(set_book_title),
+        )
         // 新しいGETエンドポイント
         .route("/orders", get(get_orders))
         .route("/orders/:order_id", get(get_order_by_id))
         .route("/inventory", get(get_inventories))
+        // 店舗のPOS向けの在庫同期（全件のスナップショットとversion以降の差分）
+        .route("/inventory/snapshot", get(get_inventory_snapshot))
+        .route("/inventory/changes", get(get_inventory_changes))
         .route("/inventory/:book_id", get(get_inventory_by_book_id))
+        .route("/inventory/:book_id/restock", post(restock_inventory))
+        // 棚卸し
+        .route("/inventory/counts", post(create_cycle_count))
+        .route("/inventory/counts/:count_id", get(get_cycle_count))
+        .route("/inventory/counts/:count_id/entries", put(record_cycle_count))
+        .route("/inventory/counts/:count_id/submit", post(submit_cycle_count))
+        .route("/inventory/counts/:count_id/approve", post(approve_cycle_count))
+        .route("/inventory/counts/:count_id/reject", post(reject_cycle_count))
+        // 在庫の修復（管理者向け）
+        .route("/admin/inventory/repair", post(repair_inventory))
+        .route("/admin/inventory/:book_id/unfreeze", post(unfreeze_inventory))
+        .route(
+            "/admin/inventory/:book_id/quota",
+            get(get_allocation_quota)
+                .put(set_allocation_quota)
+                .delete(remove_allocation_quota),
+        )
+        // 重複の可能性がある注文（管理者向け）
+        .route("/admin/orders/bulk-cancel", post(bulk_cancel_orders))
+        .route("/admin/orders/:order_id/similar", get(get_similar_orders))
+        // 停滞した注文の診断と修復（管理者向け、dry_run=trueで確認のみ）
+        .route("/admin/orders/:order_id/repair", post(repair_order))
+        // 誤った状態で止まった注文のステータスの強制変更（管理者向け、監査記録を残す）
+        .route("/admin/orders/:order_id/force-status", post(force_order_status))
+        .route(
+            "/admin/orders/:order_id/status-overrides",
+            get(get_status_overrides),
+        )
+        // 注文の編集ロック
+        .route(
+            "/admin/orders/:order_id/lock",
+            post(lock_order).delete(unlock_order),
+        )
+        // 遅延イベントポリシーで保留されたイベント（管理者向け）
+        .route("/admin/parked-events", get(get_parked_events))
+        // 処理に失敗したイベント（管理者向け）
+        .route("/admin/dead-letters", get(get_dead_letters))
+        .route("/admin/dead-letters/summary", get(get_dead_letter_summary))
+        .route("/admin/retry-policies", get(get_retry_policies))
+        .route("/admin/authz-policies", get(get_authz_policies))
+        // 送信に失敗した通知（管理者向け、イベントのデッドレターキューとは別に管理する）
+        .route("/admin/notifications", get(get_failed_notifications))
+        .route(
+            "/admin/notifications/:notification_id/resend",
+            post(resend_notification),
+        )
+        // イベントの配信のトレース（管理者向け、トレースモードのみ）
+        .route("/admin/event-traces", get(get_event_traces))
+        // プロジェクションの遅延監視（管理者向け）
+        .route("/admin/projections", get(get_projections))
+        .route("/admin/projections/:name/rebuild", post(rebuild_projection))
+        // サーガの進行状況の集計（管理者向け）
+        .route("/admin/sagas/metrics", get(get_saga_metrics))
+        .route("/admin/diagnostics", get(get_diagnostics))
+        // 管理画面（静的なHTML/JS、管理用APIを呼び出す）
+        .route("/admin/ui", get(admin_ui_index))
+        .route("/admin/ui/", get(admin_ui_asset))
+        .route("/admin/ui/*path", get(admin_ui_asset))
+        // 発行したイベントのオブジェクトストレージへのエクスポート（管理者向け）
+        .route("/admin/exports/events", post(export_events))
+        // エクスポートしたイベントのアーカイブの取り込み（管理者向け、環境の複製用）
+        .route("/admin/imports/events", post(import_events))
+        // 旧システムの注文の取り込み（管理者向け）
+        .route("/admin/legacy-orders/import", post(import_legacy_order_batch))
+        .route(
+            "/admin/legacy-orders/quarantine",
+            get(get_quarantined_legacy_orders),
+        )
+        // イベントバスの購読の管理（管理者向け）
+        .route("/admin/subscriptions", get(get_subscriptions))
+        // イベントの配信全体の一時停止・再開（ローリングデプロイの間、イベントを失わずに処理を止める）
+        .route("/admin/event-bus", get(get_event_bus_status))
+        .route("/admin/event-bus/pause", post(pause_event_bus))
+        .route("/admin/event-bus/resume", post(resume_event_bus))
+        .route("/admin/subscriptions/load-shedding", get(get_load_shedding))
+        .route("/admin/subscriptions/registry", get(get_subscription_registry))
+        .route(
+            "/admin/subscriptions/registry/:name",
+            delete(forget_subscription_registration),
+        )
+        .route("/admin/subscriptions/:name", delete(unsubscribe))
+        .route("/admin/subscriptions/:name/pause", post(pause_subscription))
+        .route(
+            "/admin/subscriptions/:name/resume",
+            post(resume_subscription),
+        )
+        .route("/customers", post(register_customer))
+        .route("/customers/:customer_id", get(get_customer))
+        .route("/customers/:customer_id/orders", get(get_customer_orders))
+        // 通知を送る言語（ja・en）
+        .route(
+            "/customers/:customer_id/preferred-language",
+            put(set_customer_preferred_language),
+        )
+        // 顧客のデータのエクスポート（ZIPのアーカイブをバックグラウンドで作成し、ジョブIDで確認・ダウンロードする）
+        .route("/customers/:customer_id/export", get(request_customer_export))
+        .route(
+            "/customers/:customer_id/export/:job_id",
+            get(get_customer_export),
+        )
+        .route(
+            "/customers/:customer_id/export/:job_id/download",
+            get(download_customer_export),
+        )
+        .route(
+            "/customers/:customer_id/devices",
+            post(register_device).get(get_devices),
+        )
+        .route(
+            "/customers/:customer_id/devices/:token",
+            delete(unregister_device),
+        )
+        // 顧客（B2Bの取引先）ごとの注文イベントのWebhook
+        .route(
+            "/customers/:customer_id/webhooks",
+            post(create_webhook_subscription).get(get_webhook_subscriptions),
+        )
+        .route(
+            "/customers/:customer_id/webhooks/:subscription_id",
+            delete(delete_webhook_subscription),
+        )
+        .route(
+            "/customers/:customer_id/webhooks/:subscription_id/deliveries",
+            get(get_webhook_deliveries),
+        )
+        .route(
+            "/customers/:customer_id/webhooks/:subscription_id/deliveries/:delivery_id/redeliver",
+            post(redeliver_webhook),
+        )
+        // 接続中の顧客への通知（WebSocket）
+        .route("/ws/notifications", get(customer_notifications_socket))
+        .route("/metrics", get(get_business_metrics))
+        .route("/reports/sla", get(get_sla_report))
+        .route(
+            "/reports/inventory-valuation",
+            get(get_inventory_valuation_report),
+        )
+        .route("/reports/forecast/:book_id", get(get_demand_forecast))
 }
 
 // ヘルスチェックエンドポイント
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses(
+        (status = 200, body = Object)
+    )
+)]
 async fn health_check() -> Json<serde_json::Value> {
     Json(serde_json::json!({
         "status": "healthy",
@@ -75,17 +448,89 @@ async fn health_check() -> Json<serde_json::Value> {
     }))
 }
 
+// 準備状態の確認エンドポイント
+// 監督中のバックグラウンドタスクのいずれかが止まっている（再起動待ち・再起動をあきらめた）場合は503で応答する
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, body = Object),
+        (status = 503, body = Object)
+    )
+)]
+async fn readiness_check(State(state): State<AppState>) -> (StatusCode, Json<serde_json::Value>) {
+    let background_tasks = state.diagnostics_service.background_tasks();
+    let ready = background_tasks.iter().all(|task| task.healthy);
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status,
+        Json(serde_json::json!({
+            "status": if ready { "ready" } else { "not_ready" },
+            "background_tasks": background_tasks,
+        })),
+    )
+}
+
+// ドメインモデルの説明取得エンドポイント（コンテキストマップやドキュメントの生成用）
+#[utoipa::path(
+    get,
+    path = "/meta/domain-model",
+    tag = "meta",
+    responses(
+        (status = 200, body = Object)
+    )
+)]
+async fn get_domain_model() -> Json<DomainModelDescription> {
+    Json(DomainModelRegistry::bookstore().describe())
+}
+
 // 注文作成エンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders",
+    tag = "orders",
+    request_body = CreateOrderRequest,
+    responses(
+        (status = 200, body = CreateOrderResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn create_order(
     State(state): State<AppState>,
+    principal: Option<Extension<Principal>>,
     Json(request): Json<CreateOrderRequest>,
 ) -> Result<Json<CreateOrderResponse>, (StatusCode, Json<ApiError>)> {
+    // 注文は登録済みの顧客を参照する必要がある
     let customer_id = request
         .customer_id
         .map(CustomerId::from_uuid)
-        .unwrap_or_else(CustomerId::new);
+        .ok_or_else(|| {
+            map_domain_error(
+                FieldViolation::new(
+                    "customer_id",
+                    Constraint::Required,
+                    None,
+                    "顧客IDを指定してください",
+                )
+                .into(),
+            )
+        })?;
+    // 認証を有効にしている場合、顧客は自分の注文だけを作成できる
+    if principal.is_some_and(|Extension(principal)| !principal.can_act_for(customer_id)) {
+        return Err(forbidden());
+    }
 
-    match state.order_service.create_order(customer_id).await {
+    match state
+        .order_service
+        .create_order_in_channel(customer_id, request.sales_channel)
+        .await
+    {
         Ok(order_id) => Ok(Json(CreateOrderResponse {
             order_id: order_id.as_uuid(),
             customer_id: customer_id.as_uuid(),
@@ -95,18 +540,70 @@ async fn create_order(
 }
 
 // 本を注文に追加するエンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/books",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    request_body = AddBookRequest,
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn add_book_to_order(
     State(state): State<AppState>,
     Path(order_id): Path<Uuid>,
     Json(request): Json<AddBookRequest>,
-) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+) -> Result<Json<CommandResponse>, (StatusCode, Json<ApiError>)> {
     let order_id = OrderId::from_uuid(order_id);
     let book_id = BookId::from_uuid(request.book_id);
     let unit_price = Money::jpy(request.unit_price);
+    let spec = request.book_spec().map_err(map_domain_error)?;
+
+    match state
+        .order_service
+        .add_book_to_order_with_spec(
+            order_id,
+            book_id,
+            request.quantity,
+            unit_price,
+            request.fulfillment_type,
+            spec,
+        )
+        .await
+    {
+        Ok(warnings) => Ok(Json(CommandResponse::from_warnings(warnings))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 注文明細の属性設定エンドポイント
+#[utoipa::path(
+    put,
+    path = "/orders/{order_id}/books/{book_id}/attributes",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID"), ("book_id" = Uuid, Path, description = "書籍ID")),
+    request_body = SetLineAttributesRequest,
+    responses(
+        (status = 200),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn set_line_attributes(
+    State(state): State<AppState>,
+    Path((order_id, book_id)): Path<(Uuid, Uuid)>,
+    Json(request): Json<SetLineAttributesRequest>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+    let book_id = BookId::from_uuid(book_id);
+    let attributes = request.line_attributes().map_err(map_domain_error)?;
 
     match state
         .order_service
-        .add_book_to_order(order_id, book_id, request.quantity, unit_price)
+        .set_line_attributes(order_id, book_id, attributes)
         .await
     {
         Ok(()) => Ok(StatusCode::OK),
@@ -115,11 +612,23 @@ async fn add_book_to_order(
 }
 
 // 配送先住所設定エンドポイント
+#[utoipa::path(
+    put,
+    path = "/orders/{order_id}/shipping-address",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    request_body = SetShippingAddressRequest,
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn set_shipping_address(
     State(state): State<AppState>,
     Path(order_id): Path<Uuid>,
     Json(request): Json<SetShippingAddressRequest>,
-) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+) -> Result<Json<CommandResponse>, (StatusCode, Json<ApiError>)> {
     let order_id = OrderId::from_uuid(order_id);
 
     match state
@@ -134,64 +643,643 @@ async fn set_shipping_address(
         )
         .await
     {
-        Ok(()) => Ok(StatusCode::OK),
+        Ok(warnings) => Ok(Json(CommandResponse::from_warnings(warnings))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// ギフト注文の受取人設定エンドポイント
+#[utoipa::path(
+    put,
+    path = "/orders/{order_id}/recipient",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    request_body = SetRecipientRequest,
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn set_gift_recipient(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    Json(request): Json<SetRecipientRequest>,
+) -> Result<Json<CommandResponse>, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+    let recipient = request.recipient().map_err(map_domain_error)?;
+
+    match state
+        .order_service
+        .set_gift_recipient(order_id, recipient)
+        .await
+    {
+        Ok(warnings) => Ok(Json(CommandResponse::from_warnings(warnings))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 納品書取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/orders/{order_id}/packing-slip",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_packing_slip(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<Json<PackingSlip>, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+
+    match state.order_service.get_packing_slip(order_id).await {
+        Ok(packing_slip) => Ok(Json(packing_slip)),
         Err(err) => Err(map_application_error(err)),
     }
 }
 
 // 注文確定エンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/confirm",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn confirm_order(
     State(state): State<AppState>,
     Path(order_id): Path<Uuid>,
-) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
     let order_id = OrderId::from_uuid(order_id);
 
     match state.order_service.confirm_order(order_id).await {
-        Ok(()) => Ok(StatusCode::OK),
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 再試行待ちの操作の取得エンドポイント
+// 認証を有効にしている場合、顧客は自分の注文（または自分自身）の操作だけを参照できる
+#[utoipa::path(
+    get,
+    path = "/operations/{operation_id}",
+    tag = "operations",
+    params(("operation_id" = Uuid, Path, description = "操作ID")),
+    responses(
+        (status = 200, body = PendingOperationResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_operation(
+    State(state): State<AppState>,
+    Path(operation_id): Path<Uuid>,
+    principal: Option<Extension<Principal>>,
+) -> Result<Json<PendingOperationResponse>, (StatusCode, Json<ApiError>)> {
+    let operation = state
+        .pending_operation_service
+        .get_operation(operation_id)
+        .await
+        .map_err(map_application_error)?;
+    if let Some(Extension(principal)) = principal {
+        if !principal.is_staff() && !owns_operation(&state, &principal, &operation).await? {
+            return Err(forbidden());
+        }
+    }
+    Ok(Json(PendingOperationResponse::from_operation(&operation)))
+}
+
+// 操作の集約が顧客の注文、または顧客自身かどうか
+async fn owns_operation(
+    state: &AppState,
+    principal: &Principal,
+    operation: &PendingOperation,
+) -> Result<bool, (StatusCode, Json<ApiError>)> {
+    let Ok(aggregate_id) = Uuid::parse_str(&operation.aggregate_id) else {
+        return Ok(false);
+    };
+    if principal.customer_id == Some(CustomerId::from_uuid(aggregate_id)) {
+        return Ok(true);
+    }
+    let order = state
+        .order_service
+        .get_order_by_id(OrderId::from_uuid(aggregate_id))
+        .await
+        .map_err(map_application_error)?;
+    Ok(order.is_some_and(|order| principal.can_act_for(order.customer_id())))
+}
+
+// 注文の再価格付けエンドポイント（明細の単価をカタログの現在の価格に更新）
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/reprice",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn reprice_order(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<Json<CommandResponse>, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+
+    match state.order_service.reprice_order(order_id).await {
+        Ok(warnings) => Ok(Json(CommandResponse::from_warnings(warnings))),
         Err(err) => Err(map_application_error(err)),
     }
 }
 
 // 注文キャンセルエンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/cancel",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn cancel_order(
     State(state): State<AppState>,
     Path(order_id): Path<Uuid>,
-) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
     let order_id = OrderId::from_uuid(order_id);
 
     match state.order_service.cancel_order(order_id).await {
-        Ok(()) => Ok(StatusCode::OK),
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 注文保留エンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/hold",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    request_body = HoldOrderRequest,
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn hold_order(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    Json(request): Json<HoldOrderRequest>,
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+    let reason = HoldReason::from_string(&request.reason).map_err(map_domain_error)?;
+
+    match state
+        .order_service
+        .place_order_on_hold(order_id, reason, request.note)
+        .await
+    {
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 注文保留解除エンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/release",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn release_order_hold(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+
+    match state.order_service.release_order_hold(order_id).await {
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
         Err(err) => Err(map_application_error(err)),
     }
 }
 
+/// 発行するイベントの相関IDを指定するリクエストヘッダー
+const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+// リクエストヘッダーから相関IDを取得（ヘッダーがない場合はNone）
+fn correlation_id_from_headers(
+    headers: &HeaderMap,
+) -> Result<Option<Uuid>, (StatusCode, Json<ApiError>)> {
+    let Some(value) = headers.get(CORRELATION_ID_HEADER) else {
+        return Ok(None);
+    };
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| Uuid::parse_str(value.trim()).ok())
+        .map(Some)
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError {
+                    error: format!("{}ヘッダーはUUIDで指定してください", CORRELATION_ID_HEADER),
+                    code: "INVALID_CORRELATION_ID".to_string(),
+                    violations: Vec::new(),
+                }),
+            )
+        })
+}
+
+/// HTTPリクエストごとのトレーススパンを作成（TraceLayerのmake_span_withに渡す）
+/// X-Correlation-Idヘッダーの相関IDを記録し、同じ相関IDで発行したイベント・ハンドラーのスパンと突き合わせられるようにする
+pub fn http_request_span(request: &Request) -> tracing::Span {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| request.uri().path(), MatchedPath::as_str);
+    let span = tracing::info_span!(
+        "http.request",
+        otel.kind = "server",
+        http.request.method = %request.method(),
+        http.route = route,
+        correlation_id = tracing::field::Empty,
+    );
+    if let Some(correlation_id) = request
+        .headers()
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| Uuid::parse_str(value.trim()).ok())
+    {
+        span.record("correlation_id", tracing::field::display(correlation_id));
+    }
+    span
+}
+
 // 注文発送エンドポイント
+// X-Correlation-Idヘッダーがない場合は確定時に始まったサーガの相関IDを引き継ぐ
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/ship",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn mark_order_as_shipped(
     State(state): State<AppState>,
     Path(order_id): Path<Uuid>,
-) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    headers: HeaderMap,
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
     let order_id = OrderId::from_uuid(order_id);
+    let correlation_id = correlation_id_from_headers(&headers)?;
 
-    match state.order_service.mark_order_as_shipped(order_id).await {
-        Ok(()) => Ok(StatusCode::OK),
+    match state
+        .order_service
+        .mark_order_as_shipped(order_id, correlation_id)
+        .await
+    {
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
         Err(err) => Err(map_application_error(err)),
     }
 }
 
 // 注文配達完了エンドポイント
+// X-Correlation-Idヘッダーがない場合は確定時に始まったサーガの相関IDを引き継ぐ
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/deliver",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn mark_order_as_delivered(
     State(state): State<AppState>,
     Path(order_id): Path<Uuid>,
-) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    headers: HeaderMap,
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
     let order_id = OrderId::from_uuid(order_id);
+    let correlation_id = correlation_id_from_headers(&headers)?;
 
-    match state.order_service.mark_order_as_delivered(order_id).await {
-        Ok(()) => Ok(StatusCode::OK),
+    match state
+        .order_service
+        .mark_order_as_delivered(order_id, correlation_id)
+        .await
+    {
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 返品依頼エンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/return",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    request_body = Option<ReturnOrderRequest>,
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn request_order_return(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    request: Option<Json<ReturnOrderRequest>>,
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+    let Json(request) = request.unwrap_or_default();
+
+    match state
+        .order_service
+        .request_order_return(order_id, request.reason)
+        .await
+    {
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 返品承認エンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/return/approve",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn approve_order_return(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+
+    match state.order_service.approve_order_return(order_id).await {
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
         Err(err) => Err(map_application_error(err)),
     }
 }
 
+// 注文一括発送エンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/bulk/ship",
+    tag = "orders",
+    request_body = BulkTransitionRequest,
+    responses(
+        (status = 200, body = BulkTransitionResponse),
+        (status = 207, body = BulkTransitionResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn bulk_mark_orders_as_shipped(
+    State(state): State<AppState>,
+    principal: Option<Extension<Principal>>,
+    headers: HeaderMap,
+    Json(request): Json<BulkTransitionRequest>,
+) -> Result<(StatusCode, Json<BulkTransitionResponse>), (StatusCode, Json<ApiError>)> {
+    let order_ids: Vec<OrderId> = request.order_ids.into_iter().map(OrderId::from_uuid).collect();
+    let operator = operator(principal.as_deref(), &headers);
+    bulk_transition(&state, BulkTransition::Ship, operator, order_ids).await
+}
+
+// 注文一括配達完了エンドポイント
+#[utoipa::path(
+    post,
+    path = "/orders/bulk/deliver",
+    tag = "orders",
+    request_body = BulkTransitionRequest,
+    responses(
+        (status = 200, body = BulkTransitionResponse),
+        (status = 207, body = BulkTransitionResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn bulk_mark_orders_as_delivered(
+    State(state): State<AppState>,
+    principal: Option<Extension<Principal>>,
+    headers: HeaderMap,
+    Json(request): Json<BulkTransitionRequest>,
+) -> Result<(StatusCode, Json<BulkTransitionResponse>), (StatusCode, Json<ApiError>)> {
+    let order_ids: Vec<OrderId> = request.order_ids.into_iter().map(OrderId::from_uuid).collect();
+    let operator = operator(principal.as_deref(), &headers);
+    bulk_transition(&state, BulkTransition::Deliver, operator, order_ids).await
+}
+
+// 注文一括キャンセルエンドポイント
+// 確認トークンを省略した場合は条件に一致する注文のプレビューだけを返し、
+// プレビューで返したトークンを指定した場合に、注文ごとにキャンセルして結果を返す
+#[utoipa::path(
+    post,
+    path = "/admin/orders/bulk-cancel",
+    tag = "admin",
+    request_body = BulkCancelRequest,
+    responses(
+        (status = 200, description = "確認トークンを省略した場合はプレビュー、指定した場合は注文ごとの結果", body = BulkCancellationPreviewResponse),
+        (status = 207, body = BulkTransitionResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn bulk_cancel_orders(
+    State(state): State<AppState>,
+    principal: Option<Extension<Principal>>,
+    headers: HeaderMap,
+    Json(request): Json<BulkCancelRequest>,
+) -> Result<Response, (StatusCode, Json<ApiError>)> {
+    let status = match request.status {
+        Some(status) => OrderStatus::from_string(&status)
+            .map_err(|e| map_application_error(ApplicationError::DomainError(e)))?,
+        None => OrderStatus::Pending,
+    };
+    let filter = BulkCancellationFilter::new(status, request.older_than_days)
+        .map_err(|e| map_application_error(ApplicationError::DomainError(e)))?;
+    let now = Utc::now();
+
+    let Some(confirmation_token) = request.confirmation_token else {
+        let preview = state
+            .order_service
+            .preview_bulk_cancellation(&filter, now)
+            .await
+            .map_err(map_application_error)?;
+        let locks = state
+            .order_lock_service
+            .active_locks(now)
+            .await
+            .map_err(map_application_error)?;
+        let shipping_fee_policy = state.order_service.shipping_fee_policy();
+        let orders: Vec<OrderSummaryResponse> = preview
+            .orders
+            .iter()
+            .map(|order| {
+                OrderSummaryResponse::from_order(order, shipping_fee_policy)
+                    .with_lock(locks.get(&order.id()))
+            })
+            .collect();
+        return Ok(Json(BulkCancellationPreviewResponse {
+            count: orders.len(),
+            orders,
+            confirmation_token: preview.confirmation_token,
+        })
+        .into_response());
+    };
+
+    let order_ids = state
+        .order_service
+        .confirm_bulk_cancellation(&filter, &confirmation_token, now)
+        .await
+        .map_err(map_application_error)?;
+    // 条件に一致する注文がない場合はキャンセルを呼び出さない（空のリストは検証エラーになるため）
+    if order_ids.is_empty() {
+        return Ok(Json(BulkTransitionResponse {
+            succeeded: 0,
+            failed: 0,
+            results: Vec::new(),
+        })
+        .into_response());
+    }
+    let operator = operator(principal.as_deref(), &headers);
+    let response = bulk_transition(&state, BulkTransition::Cancel, operator, order_ids).await?;
+    Ok(response.into_response())
+}
+
+// 注文ごとの結果をまとめる
+// すべて成功した場合は200、一部でも失敗した場合は207（Multi-Status）で注文ごとの結果を返す
+// 他のオペレーターが編集ロック中の注文は遷移させず、その注文の結果をORDER_LOCKEDにする
+async fn bulk_transition(
+    state: &AppState,
+    transition: BulkTransition,
+    operator: Option<&str>,
+    order_ids: Vec<OrderId>,
+) -> Result<(StatusCode, Json<BulkTransitionResponse>), (StatusCode, Json<ApiError>)> {
+    let now = Utc::now();
+    let locks = state
+        .order_lock_service
+        .active_locks(now)
+        .await
+        .map_err(map_application_error)?;
+    let (locked, unlocked): (Vec<OrderId>, Vec<OrderId>) = order_ids.iter().partition(|order_id| {
+        locks
+            .get(order_id)
+            .is_some_and(|lock| lock.blocks(operator, now))
+    });
+
+    // すべての注文がロック中の場合は遷移を呼び出さない（空のリストは検証エラーになるため）
+    let mut outcomes = if unlocked.is_empty() && !locked.is_empty() {
+        Vec::new()
+    } else {
+        state
+            .order_service
+            .bulk_transition(transition, &unlocked)
+            .await
+            .map_err(map_application_error)?
+    };
+    for order_id in locked {
+        if outcomes.iter().any(|outcome| outcome.order_id == order_id) {
+            continue;
+        }
+        outcomes.push(BulkTransitionOutcome {
+            order_id,
+            result: Err(locks[&order_id].locked_error().into()),
+        });
+    }
+    outcomes.sort_by_key(|outcome| order_ids.iter().position(|id| *id == outcome.order_id));
+
+    let results: Vec<BulkTransitionResultResponse> = outcomes
+        .into_iter()
+        .map(|outcome| {
+            let order_id = outcome.order_id.to_string();
+            match outcome.result {
+                Ok(event) => BulkTransitionResultResponse {
+                    order_id,
+                    success: true,
+                    event: Some(EventEchoResponse::from_event(event)),
+                    code: None,
+                    error: None,
+                },
+                Err(err) => {
+                    let (_, Json(api_error)) = map_application_error(err);
+                    BulkTransitionResultResponse {
+                        order_id,
+                        success: false,
+                        event: None,
+                        code: Some(api_error.code),
+                        error: Some(api_error.error),
+                    }
+                }
+            }
+        })
+        .collect();
+    let succeeded = results.iter().filter(|result| result.success).count();
+    let failed = results.len() - succeeded;
+
+    let status = if failed == 0 {
+        StatusCode::OK
+    } else {
+        StatusCode::MULTI_STATUS
+    };
+    Ok((
+        status,
+        Json(BulkTransitionResponse {
+            succeeded,
+            failed,
+            results,
+        }),
+    ))
+}
+
 // 在庫作成エンドポイント（テスト用）
+#[utoipa::path(
+    post,
+    path = "/inventory",
+    tag = "inventory",
+    request_body = CreateInventoryRequest,
+    responses(
+        (status = 201),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn create_inventory(
     State(state): State<AppState>,
     Json(request): Json<CreateInventoryRequest>,
@@ -200,7 +1288,12 @@ async fn create_inventory(
 
     match state
         .inventory_service
-        .create_inventory(book_id, request.quantity)
+        .create_inventory(
+            book_id,
+            request.quantity,
+            request.release_date,
+            request.waitlist_enabled,
+        )
         .await
     {
         Ok(()) => Ok(StatusCode::CREATED),
@@ -208,7 +1301,46 @@ async fn create_inventory(
     }
 }
 
+// 入荷エンドポイント（入荷数を在庫に加え、順番待ちの注文を繰り上げる）
+#[utoipa::path(
+    post,
+    path = "/inventory/{book_id}/restock",
+    tag = "inventory",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    request_body = RestockRequest,
+    responses(
+        (status = 200, body = InventoryResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn restock_inventory(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+    Json(request): Json<RestockRequest>,
+) -> Result<Json<InventoryResponse>, (StatusCode, Json<ApiError>)> {
+    match state
+        .inventory_service
+        .restock_inventory(BookId::from_uuid(book_id), request.quantity)
+        .await
+    {
+        Ok(inventory) => Ok(Json(InventoryResponse::from_inventory(&inventory))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
 // 注文一覧取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/orders",
+    tag = "orders",
+    params(OrdersQueryParams),
+    responses(
+        (status = 200, body = [OrderSummaryResponse]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn get_orders(
     State(state): State<AppState>,
     query: Result<Query<OrdersQueryParams>, axum::extract::rejection::QueryRejection>,
@@ -219,11 +1351,17 @@ async fn get_orders(
             Json(ApiError {
                 error: "無効なクエリパラメータです".to_string(),
                 code: "INVALID_PARAMETER".to_string(),
+                violations: Vec::new(),
             }),
         )
     })?;
 
-    let orders = if let Some(status_str) = params.status {
+    let orders = if let Some(order_number) = params.order_number {
+        match state.order_service.get_order_by_number(&order_number).await {
+            Ok(order) => order.into_iter().collect(),
+            Err(err) => return Err(map_application_error(err)),
+        }
+    } else if let Some(status_str) = params.status {
         match state
             .order_service
             .get_orders_by_status_string(status_str)
@@ -239,39 +1377,380 @@ async fn get_orders(
         }
     };
 
+    let locks = state
+        .order_lock_service
+        .active_locks(Utc::now())
+        .await
+        .map_err(map_application_error)?;
+
+    let shipping_fee_policy = state.order_service.shipping_fee_policy();
     let response: Vec<OrderSummaryResponse> = orders
         .iter()
-        .map(OrderSummaryResponse::from_order)
+        .map(|order| {
+            OrderSummaryResponse::from_order(order, shipping_fee_policy)
+                .with_lock(locks.get(&order.id()))
+        })
         .collect();
 
     Ok(Json(response))
 }
 
 // 注文詳細取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/orders/{order_id}",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = OrderDetailResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
 async fn get_order_by_id(
     State(state): State<AppState>,
     Path(order_id): Path<Uuid>,
-) -> Result<Json<OrderDetailResponse>, (StatusCode, Json<ApiError>)> {
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ApiError>)> {
     let order_id = OrderId::from_uuid(order_id);
+    let language = language_from_headers(&headers);
 
     match state.order_service.get_order_by_id(order_id).await {
         Ok(Some(order)) => {
-            let response = OrderDetailResponse::from_order(&order);
-            Ok(Json(response))
+            let book_ids: Vec<BookId> = order
+                .order_lines()
+                .iter()
+                .map(|line| line.book_id())
+                .collect();
+            let titles = state
+                .catalog_service
+                .localized_titles(&book_ids, language)
+                .await
+                .map_err(map_application_error)?;
+            let response =
+                OrderDetailResponse::from_order(&order, state.order_service.shipping_fee_policy())
+                    .with_line_titles(&titles)
+                    .with_cancellation_window(
+                        state
+                            .order_service
+                            .cancellation_policy()
+                            .window_for(&order, Utc::now()),
+                    );
+            Ok((
+                [
+                    (header::CONTENT_LANGUAGE, language.code()),
+                    (header::VARY, "Accept-Language"),
+                ],
+                Json(response),
+            )
+                .into_response())
         }
         Ok(None) => Err((
             StatusCode::NOT_FOUND,
             Json(ApiError {
                 error: "指定された注文が見つかりません".to_string(),
                 code: "ORDER_NOT_FOUND".to_string(),
+                violations: Vec::new(),
             }),
         )),
         Err(err) => Err(map_application_error(err)),
     }
 }
 
-// 在庫一覧取得エンドポイント
-async fn get_inventories(
+// 重複の可能性がある注文の検索エンドポイント（同じ顧客・同じ明細で作成日時が近い注文）
+#[utoipa::path(
+    get,
+    path = "/admin/orders/{order_id}/similar",
+    tag = "admin",
+    params(("order_id" = Uuid, Path, description = "注文ID"), SimilarOrdersQueryParams),
+    responses(
+        (status = 200, body = SimilarOrdersResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_similar_orders(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    query: Result<Query<SimilarOrdersQueryParams>, axum::extract::rejection::QueryRejection>,
+) -> Result<Json<SimilarOrdersResponse>, (StatusCode, Json<ApiError>)> {
+    let Query(params) = query.map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: "無効なクエリパラメータです".to_string(),
+                code: "INVALID_PARAMETER".to_string(),
+                violations: Vec::new(),
+            }),
+        )
+    })?;
+    let order_id = OrderId::from_uuid(order_id);
+    let within_minutes = params
+        .within_minutes
+        .unwrap_or(DEFAULT_SIMILAR_ORDER_WINDOW_MINUTES);
+
+    let orders = state
+        .order_service
+        .find_similar_orders(order_id, TimeDelta::minutes(i64::from(within_minutes)))
+        .await
+        .map_err(map_application_error)?;
+
+    let shipping_fee_policy = state.order_service.shipping_fee_policy();
+    Ok(Json(SimilarOrdersResponse {
+        order_id: order_id.to_string(),
+        within_minutes,
+        similar_orders: orders
+            .iter()
+            .map(|order| OrderSummaryResponse::from_order(order, shipping_fee_policy))
+            .collect(),
+    }))
+}
+
+// 停滞した注文の修復エンドポイント
+#[utoipa::path(
+    post,
+    path = "/admin/orders/{order_id}/repair",
+    tag = "admin",
+    params(("order_id" = Uuid, Path, description = "注文ID"), RepairOrderQueryParams),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn repair_order(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    query: Result<Query<RepairOrderQueryParams>, axum::extract::rejection::QueryRejection>,
+) -> Result<Json<OrderRepairReport>, (StatusCode, Json<ApiError>)> {
+    let Query(params) = query.map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: "無効なクエリパラメータです".to_string(),
+                code: "INVALID_PARAMETER".to_string(),
+                violations: Vec::new(),
+            }),
+        )
+    })?;
+
+    match state
+        .order_repair_service
+        .repair_order(OrderId::from_uuid(order_id), params.dry_run, Utc::now())
+        .await
+    {
+        Ok(report) => Ok(Json(report)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 注文のステータスの強制変更エンドポイント（変更したオペレーターは認証した利用者として記録する）
+#[utoipa::path(
+    post,
+    path = "/admin/orders/{order_id}/force-status",
+    tag = "admin",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    request_body = ForceOrderStatusRequest,
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn force_order_status(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    principal: Option<Extension<Principal>>,
+    headers: HeaderMap,
+    Json(request): Json<ForceOrderStatusRequest>,
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
+    let status = OrderStatus::from_string(request.status.trim())
+        .map_err(|err| map_application_error(err.into()))?;
+
+    match state
+        .order_service
+        .force_order_status(
+            OrderId::from_uuid(order_id),
+            status,
+            &request.reason,
+            operator(principal.as_deref(), &headers).unwrap_or_default(),
+            Utc::now(),
+        )
+        .await
+    {
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 注文のステータスの強制変更の監査記録取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/orders/{order_id}/status-overrides",
+    tag = "admin",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_status_overrides(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<Json<Vec<StatusOverrideAudit>>, (StatusCode, Json<ApiError>)> {
+    match state
+        .order_service
+        .list_status_overrides(OrderId::from_uuid(order_id))
+        .await
+    {
+        Ok(audits) => Ok(Json(audits)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+/// 認証を無効にしている場合に、注文を編集するオペレーターを名乗るリクエストヘッダー
+const OPERATOR_HEADER: &str = "x-operator";
+
+// 操作したオペレーターを取得（編集ロックの保持者と監査記録に使う）
+// 認証を有効にしている場合は認証した利用者の識別子（トークンのsubクレーム）を使い、クライアントが指定するX-Operatorヘッダーは使わない
+// 認証を無効にしている場合だけX-Operatorヘッダーで名乗る（ヘッダーがない、または空の場合はNone）
+fn operator<'a>(principal: Option<&'a Principal>, headers: &'a HeaderMap) -> Option<&'a str> {
+    if let Some(principal) = principal {
+        return Some(principal.subject.as_str());
+    }
+    headers
+        .get(OPERATOR_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+// 注文の編集ロックエンドポイント
+// 同じオペレーターが既にロックしている場合は期限を延長する
+#[utoipa::path(
+    post,
+    path = "/admin/orders/{order_id}/lock",
+    tag = "admin",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    request_body = Option<LockOrderRequest>,
+    responses(
+        (status = 200, body = OrderLockResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn lock_order(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    principal: Option<Extension<Principal>>,
+    headers: HeaderMap,
+    request: Option<Json<LockOrderRequest>>,
+) -> Result<Json<OrderLockResponse>, (StatusCode, Json<ApiError>)> {
+    let Json(request) = request.unwrap_or_default();
+    let ttl_minutes = request
+        .ttl_minutes
+        .unwrap_or(DEFAULT_ORDER_LOCK_TTL_MINUTES);
+
+    match state
+        .order_lock_service
+        .lock(
+            OrderId::from_uuid(order_id),
+            operator(principal.as_deref(), &headers).unwrap_or_default(),
+            TimeDelta::minutes(ttl_minutes),
+            Utc::now(),
+        )
+        .await
+    {
+        Ok(lock) => Ok(Json(OrderLockResponse::from_lock(&lock))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 注文の編集ロックの解除エンドポイント（ロックしたオペレーターのみ解除できる）
+#[utoipa::path(
+    delete,
+    path = "/admin/orders/{order_id}/lock",
+    tag = "admin",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 204),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn unlock_order(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    principal: Option<Extension<Principal>>,
+    headers: HeaderMap,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    match state
+        .order_lock_service
+        .unlock(
+            OrderId::from_uuid(order_id),
+            operator(principal.as_deref(), &headers),
+            Utc::now(),
+        )
+        .await
+    {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// パスで注文を指定する更新系のリクエストの対象の注文ID（ロック自体の操作と、注文を変更しないCSメモの追加はNone）
+fn lockable_order_id(method: &Method, path: &str) -> Option<OrderId> {
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return None;
+    }
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (order_id, rest) = match segments.as_slice() {
+        ["orders", order_id, rest @ ..] => (*order_id, rest),
+        ["admin", "orders", order_id, rest @ ..] => (*order_id, rest),
+        _ => return None,
+    };
+    if rest == ["lock"] || rest == ["notes"] {
+        return None;
+    }
+    Uuid::parse_str(order_id).ok().map(OrderId::from_uuid)
+}
+
+/// 注文の編集ロックのミドルウェア
+/// 他のオペレーターが編集ロック中の注文を変更するリクエストを423 Lockedで拒否する
+/// （一括のステータス遷移は注文ごとの結果で拒否する）
+pub async fn order_lock_guard(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(order_id) = lockable_order_id(request.method(), request.uri().path()) {
+        let operator = operator(request.extensions().get::<Principal>(), request.headers());
+        if let Err(err) = state
+            .order_lock_service
+            .ensure_unlocked(order_id, operator, Utc::now())
+            .await
+        {
+            return map_application_error(err).into_response();
+        }
+    }
+    next.run(request).await
+}
+
+// 在庫一覧取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/inventory",
+    tag = "inventory",
+    params(InventoryQueryParams),
+    responses(
+        (status = 200, body = [InventoryResponse]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_inventories(
     State(state): State<AppState>,
     query: Result<Query<InventoryQueryParams>, axum::extract::rejection::QueryRejection>,
 ) -> Result<Json<Vec<InventoryResponse>>, (StatusCode, Json<ApiError>)> {
@@ -281,70 +1760,2143 @@ async fn get_inventories(
             Json(ApiError {
                 error: "無効なクエリパラメータです".to_string(),
                 code: "INVALID_PARAMETER".to_string(),
+                violations: Vec::new(),
+            }),
+        )
+    })?;
+
+    let inventories = if let Some(max_quantity) = params.max_quantity {
+        match state
+            .inventory_service
+            .get_low_stock_inventories(max_quantity)
+            .await
+        {
+            Ok(inventories) => inventories,
+            Err(err) => return Err(map_application_error(err)),
+        }
+    } else {
+        match state.inventory_service.get_all_inventories().await {
+            Ok(inventories) => inventories,
+            Err(err) => return Err(map_application_error(err)),
+        }
+    };
+
+    let response: Vec<InventoryResponse> = inventories
+        .iter()
+        .map(InventoryResponse::from_inventory)
+        .collect();
+
+    Ok(Json(response))
+}
+
+// 在庫スナップショット取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/inventory/snapshot",
+    tag = "inventory",
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_inventory_snapshot(
+    State(state): State<AppState>,
+) -> Result<Json<InventorySnapshot>, (StatusCode, Json<ApiError>)> {
+    match state
+        .inventory_service
+        .get_inventory_snapshot(Utc::now())
+        .await
+    {
+        Ok(snapshot) => Ok(Json(snapshot)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 在庫差分取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/inventory/changes",
+    tag = "inventory",
+    params(InventoryChangesQueryParams),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_inventory_changes(
+    State(state): State<AppState>,
+    query: Result<Query<InventoryChangesQueryParams>, axum::extract::rejection::QueryRejection>,
+) -> Result<Json<InventoryChanges>, (StatusCode, Json<ApiError>)> {
+    let Query(params) = query.map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: "since_versionには0以上の整数を指定してください".to_string(),
+                code: "INVALID_PARAMETER".to_string(),
+                violations: Vec::new(),
+            }),
+        )
+    })?;
+
+    match state
+        .inventory_service
+        .get_inventory_changes(params.since_version)
+        .await
+    {
+        Ok(changes) => Ok(Json(changes)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 在庫詳細取得エンドポイント
+// 書籍の販売価格設定エンドポイント
+#[utoipa::path(
+    put,
+    path = "/catalog/books/{book_id}/price",
+    tag = "catalog",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    request_body = SetBookPriceRequest,
+    responses(
+        (status = 200, body = BookPriceResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn set_book_price(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+    Json(request): Json<SetBookPriceRequest>,
+) -> Result<Json<BookPriceResponse>, (StatusCode, Json<ApiError>)> {
+    let book_id = BookId::from_uuid(book_id);
+    let price = Money::jpy(request.unit_price);
+
+    match state.catalog_service.set_price(book_id, price).await {
+        Ok(()) => Ok(Json(BookPriceResponse::from_price(book_id, price))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// Accept-Languageヘッダーから表示言語を選ぶ（指定がない場合・対応していない言語のみの場合は既定の言語）
+fn language_from_headers(headers: &HeaderMap) -> Language {
+    headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Language::negotiate)
+        .unwrap_or(Language::DEFAULT)
+}
+
+// 言語コードから言語を取得（対応していない言語の場合は400）
+fn parse_language(tag: &str) -> Result<Language, (StatusCode, Json<ApiError>)> {
+    Language::from_tag(tag).ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: format!("対応していない言語です（ja・enのいずれか）: {}", tag),
+                code: "UNSUPPORTED_LANGUAGE".to_string(),
+                violations: Vec::new(),
+            }),
+        )
+    })
+}
+
+// 書籍のタイトル設定エンドポイント（言語ごと）
+#[utoipa::path(
+    put,
+    path = "/catalog/books/{book_id}/titles/{language}",
+    tag = "catalog",
+    params(("book_id" = Uuid, Path, description = "書籍ID"), ("language" = String, Path, description = "言語コード（例: ja, en）")),
+    request_body = SetBookTitleRequest,
+    responses(
+        (status = 200, body = BookTitlesResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn set_book_title(
+    State(state): State<AppState>,
+    Path((book_id, language)): Path<(Uuid, String)>,
+    Json(request): Json<SetBookTitleRequest>,
+) -> Result<Json<BookTitlesResponse>, (StatusCode, Json<ApiError>)> {
+    let book_id = BookId::from_uuid(book_id);
+    let language = parse_language(&language)?;
+
+    match state
+        .catalog_service
+        .set_title(book_id, language, &request.title)
+        .await
+    {
+        Ok(titles) => Ok(Json(BookTitlesResponse::from_titles(book_id, &titles))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 書籍のタイトル取得エンドポイント（登録されているすべての言語）
+#[utoipa::path(
+    get,
+    path = "/catalog/books/{book_id}/titles",
+    tag = "catalog",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    responses(
+        (status = 200, body = BookTitlesResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_book_titles(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+) -> Result<Json<BookTitlesResponse>, (StatusCode, Json<ApiError>)> {
+    let book_id = BookId::from_uuid(book_id);
+
+    match state.catalog_service.get_titles(book_id).await {
+        Ok(titles) if titles.is_empty() => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError {
+                error: "指定された書籍のタイトルが登録されていません".to_string(),
+                code: "BOOK_TITLE_NOT_FOUND".to_string(),
+                violations: Vec::new(),
+            }),
+        )),
+        Ok(titles) => Ok(Json(BookTitlesResponse::from_titles(book_id, &titles))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 書籍の販売価格取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/catalog/books/{book_id}/price",
+    tag = "catalog",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    responses(
+        (status = 200, body = BookPriceResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_book_price(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+) -> Result<Json<BookPriceResponse>, (StatusCode, Json<ApiError>)> {
+    let book_id = BookId::from_uuid(book_id);
+
+    match state.catalog_service.get_price(book_id).await {
+        Ok(Some(price)) => Ok(Json(BookPriceResponse::from_price(book_id, price))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError {
+                error: "指定された書籍の価格が登録されていません".to_string(),
+                code: "BOOK_PRICE_NOT_FOUND".to_string(),
+                violations: Vec::new(),
+            }),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/inventory/{book_id}",
+    tag = "inventory",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    responses(
+        (status = 200, body = InventoryResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_inventory_by_book_id(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+) -> Result<Json<InventoryResponse>, (StatusCode, Json<ApiError>)> {
+    let book_id = BookId::from_uuid(book_id);
+
+    match state
+        .inventory_service
+        .get_inventory_by_book_id(book_id)
+        .await
+    {
+        Ok(Some(inventory)) => {
+            let response = InventoryResponse::from_inventory(&inventory);
+            Ok(Json(response))
+        }
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError {
+                error: "指定された書籍の在庫が見つかりません".to_string(),
+                code: "INVENTORY_NOT_FOUND".to_string(),
+                violations: Vec::new(),
             }),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 在庫修復エンドポイント（在庫変動ログと食い違う在庫を検出・凍結し、照合レポートを返す）
+#[utoipa::path(
+    post,
+    path = "/admin/inventory/repair",
+    tag = "admin",
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn repair_inventory(
+    State(state): State<AppState>,
+) -> Result<Json<InventoryReconciliationReport>, (StatusCode, Json<ApiError>)> {
+    match state
+        .inventory_service
+        .repair_ledger_drifts(Utc::now())
+        .await
+    {
+        Ok(report) => Ok(Json(report)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 在庫の凍結解除エンドポイント
+#[utoipa::path(
+    post,
+    path = "/admin/inventory/{book_id}/unfreeze",
+    tag = "admin",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    responses(
+        (status = 200, body = InventoryResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn unfreeze_inventory(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+) -> Result<Json<InventoryResponse>, (StatusCode, Json<ApiError>)> {
+    match state
+        .inventory_service
+        .unfreeze_inventory(BookId::from_uuid(book_id))
+        .await
+    {
+        Ok(inventory) => Ok(Json(InventoryResponse::from_inventory(&inventory))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 割当枠の取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/inventory/{book_id}/quota",
+    tag = "admin",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    responses(
+        (status = 200, body = AllocationQuotaResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_allocation_quota(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+) -> Result<Json<AllocationQuotaResponse>, (StatusCode, Json<ApiError>)> {
+    match state
+        .inventory_service
+        .get_allocation_quota(BookId::from_uuid(book_id))
+        .await
+    {
+        Ok(Some(quota)) => Ok(Json(AllocationQuotaResponse::from_quota(&quota))),
+        Ok(None) => Err(map_application_error(ApplicationError::NotFound(
+            "指定された書籍の割当枠が設定されていません".to_string(),
+        ))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 割当枠の設定エンドポイント（限定版の顧客ごとの購入上限・販売チャネル別の割当比率）
+#[utoipa::path(
+    put,
+    path = "/admin/inventory/{book_id}/quota",
+    tag = "admin",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    request_body = AllocationQuotaRequest,
+    responses(
+        (status = 200, body = AllocationQuotaResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn set_allocation_quota(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+    Json(request): Json<AllocationQuotaRequest>,
+) -> Result<Json<AllocationQuotaResponse>, (StatusCode, Json<ApiError>)> {
+    match state
+        .inventory_service
+        .set_allocation_quota(
+            BookId::from_uuid(book_id),
+            request.per_customer_cap,
+            request.channel_shares,
+            request.allocation_pool,
+        )
+        .await
+    {
+        Ok(quota) => Ok(Json(AllocationQuotaResponse::from_quota(&quota))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 割当枠の解除エンドポイント
+#[utoipa::path(
+    delete,
+    path = "/admin/inventory/{book_id}/quota",
+    tag = "admin",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    responses(
+        (status = 204),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn remove_allocation_quota(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    match state
+        .inventory_service
+        .remove_allocation_quota(BookId::from_uuid(book_id))
+        .await
+    {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 保留イベント一覧取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/parked-events",
+    tag = "admin",
+    responses(
+        (status = 200, body = [Object]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_parked_events(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ParkedEvent>>, (StatusCode, Json<ApiError>)> {
+    match state.parked_event_service.list_parked_events().await {
+        Ok(events) => Ok(Json(events)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// デッドレターキュー取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/dead-letters",
+    tag = "admin",
+    params(DeadLettersQueryParams),
+    responses(
+        (status = 200, body = [Object])
+    )
+)]
+async fn get_dead_letters(
+    State(state): State<AppState>,
+    Query(params): Query<DeadLettersQueryParams>,
+) -> Json<Vec<DeadLetter>> {
+    Json(
+        state
+            .dead_letter_service
+            .list_dead_letters(params.aggregate_id.as_deref())
+            .await,
+    )
+}
+
+// デッドレターキューの集計エンドポイント（ハンドラーとエラーの指紋ごと）
+#[utoipa::path(
+    get,
+    path = "/admin/dead-letters/summary",
+    tag = "admin",
+    responses(
+        (status = 200, body = Object)
+    )
+)]
+async fn get_dead_letter_summary(State(state): State<AppState>) -> Json<DeadLetterSummary> {
+    Json(state.dead_letter_service.summarize_dead_letters().await)
+}
+
+// イベントタイプごとのリトライポリシー取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/retry-policies",
+    tag = "admin",
+    responses(
+        (status = 200, body = [Object])
+    )
+)]
+async fn get_retry_policies(State(state): State<AppState>) -> Json<Vec<EventRetryPolicy>> {
+    Json(state.dead_letter_service.list_retry_policies())
+}
+
+// エンドポイントの認可ポリシーと役割の権限の取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/authz-policies",
+    tag = "admin",
+    responses(
+        (status = 200, body = AuthzPoliciesResponse)
+    )
+)]
+async fn get_authz_policies() -> Json<AuthzPoliciesResponse> {
+    Json(AuthzPoliciesResponse::from_policies(
+        AUTHZ_POLICIES,
+        DEFAULT_RULE.requirement,
+    ))
+}
+
+// 送信に失敗した通知の取得エンドポイント（省略時は再送をあきらめた通知）
+#[utoipa::path(
+    get,
+    path = "/admin/notifications",
+    tag = "admin",
+    params(FailedNotificationsQueryParams),
+    responses(
+        (status = 200, body = [FailedNotificationResponse]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_failed_notifications(
+    State(state): State<AppState>,
+    Query(params): Query<FailedNotificationsQueryParams>,
+) -> Result<Json<Vec<FailedNotificationResponse>>, (StatusCode, Json<ApiError>)> {
+    let status = match params.status.as_deref() {
+        Some(status) => FailedNotificationStatus::from_string(status).map_err(map_domain_error)?,
+        None => FailedNotificationStatus::Undeliverable,
+    };
+
+    state
+        .notification_service
+        .list_failed_notifications(status)
+        .await
+        .map(|notifications| {
+            Json(
+                notifications
+                    .iter()
+                    .map(FailedNotificationResponse::from_notification)
+                    .collect(),
+            )
+        })
+        .map_err(map_application_error)
+}
+
+// 送信に失敗した通知の手動の再送エンドポイント
+#[utoipa::path(
+    post,
+    path = "/admin/notifications/{notification_id}/resend",
+    tag = "admin",
+    params(("notification_id" = Uuid, Path, description = "通知ID")),
+    responses(
+        (status = 200, body = FailedNotificationResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn resend_notification(
+    State(state): State<AppState>,
+    Path(notification_id): Path<Uuid>,
+) -> Result<Json<FailedNotificationResponse>, (StatusCode, Json<ApiError>)> {
+    match state.notification_service.resend(notification_id).await {
+        Ok(notification) => Ok(Json(FailedNotificationResponse::from_notification(
+            &notification,
+        ))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// イベントの配信のトレース取得エンドポイント（相関IDでサーガを指定できる）
+#[utoipa::path(
+    get,
+    path = "/admin/event-traces",
+    tag = "admin",
+    params(EventTracesQueryParams),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_event_traces(
+    State(state): State<AppState>,
+    Query(params): Query<EventTracesQueryParams>,
+) -> Result<Json<EventTraceReport>, (StatusCode, Json<ApiError>)> {
+    state
+        .event_trace_service
+        .trace_report(params.correlation_id)
+        .map(Json)
+        .map_err(map_application_error)
+}
+
+// プロジェクション状態取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/projections",
+    tag = "admin",
+    responses(
+        (status = 200, body = [Object])
+    )
+)]
+async fn get_projections(State(state): State<AppState>) -> Json<Vec<ProjectionStatus>> {
+    Json(state.projection_service.list_projections().await)
+}
+
+// プロジェクション再構築エンドポイント
+#[utoipa::path(
+    post,
+    path = "/admin/projections/{name}/rebuild",
+    tag = "admin",
+    params(("name" = String, Path, description = "プロジェクション名")),
+    responses(
+        (status = 202),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn rebuild_projection(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    match state.projection_service.rebuild_projection(&name).await {
+        Ok(()) => Ok(StatusCode::ACCEPTED),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// サーガ集計取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/sagas/metrics",
+    tag = "admin",
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_saga_metrics(
+    State(state): State<AppState>,
+) -> Result<Json<SagaMetricsReport>, (StatusCode, Json<ApiError>)> {
+    match state.saga_metrics_service.get_metrics(Utc::now()).await {
+        Ok(report) => Ok(Json(report)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 起動時の診断レポート取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/diagnostics",
+    tag = "admin",
+    responses(
+        (status = 200, body = Object)
+    )
+)]
+async fn get_diagnostics(State(state): State<AppState>) -> Json<DiagnosticsReport> {
+    Json(state.diagnostics_service.latest_report())
+}
+
+// イベントのエクスポートエンドポイント
+// 同じ期間を再度指定すると、前回書き出した位置から再開する
+#[utoipa::path(
+    post,
+    path = "/admin/exports/events",
+    tag = "admin",
+    request_body = ExportEventsRequest,
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn export_events(
+    State(state): State<AppState>,
+    Json(request): Json<ExportEventsRequest>,
+) -> Result<Json<ExportManifest>, (StatusCode, Json<ApiError>)> {
+    match state
+        .event_export_service
+        .export_events(request.from, request.to, Utc::now())
+        .await
+    {
+        Ok(manifest) => Ok(Json(manifest)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// エクスポートしたイベントの取り込みエンドポイント
+// 既に記録されているイベントは読み飛ばす
+#[utoipa::path(
+    post,
+    path = "/admin/imports/events",
+    tag = "admin",
+    request_body = ImportEventsRequest,
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn import_events(
+    State(state): State<AppState>,
+    Json(request): Json<ImportEventsRequest>,
+) -> Result<Json<EventImportSummary>, (StatusCode, Json<ApiError>)> {
+    match state
+        .event_export_service
+        .import_events(&request.archive)
+        .await
+    {
+        Ok(summary) => Ok(Json(summary)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 旧システムの注文の取り込みエンドポイント
+// 変換できないレコードは隔離して残りの取り込みを続ける（部分的な成功を許す）
+#[utoipa::path(
+    post,
+    path = "/admin/legacy-orders/import",
+    tag = "admin",
+    request_body = LegacyOrderImportBatch,
+    responses(
+        (status = 200, body = LegacyImportReport),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn import_legacy_order_batch(
+    State(state): State<AppState>,
+    Json(batch): Json<LegacyOrderImportBatch>,
+) -> Result<Json<LegacyImportReport>, (StatusCode, Json<ApiError>)> {
+    if batch.orders.len() > MAX_LEGACY_IMPORT_BATCH_SIZE {
+        return Err(map_domain_error(DomainError::InvalidValue(format!(
+            "1回に取り込めるのは{}件までです",
+            MAX_LEGACY_IMPORT_BATCH_SIZE
+        ))));
+    }
+
+    match import_legacy_orders(&state.legacy_import_service, batch, Utc::now()).await {
+        Ok(report) => Ok(Json(report)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 隔離中の旧システムの注文の一覧取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/legacy-orders/quarantine",
+    tag = "admin",
+    responses(
+        (status = 200, body = [Object]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_quarantined_legacy_orders(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<LegacyImportRecord>>, (StatusCode, Json<ApiError>)> {
+    match state.legacy_import_service.list_quarantined().await {
+        Ok(records) => Ok(Json(records)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 購読一覧取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/subscriptions",
+    tag = "admin",
+    responses(
+        (status = 200, body = [Object])
+    )
+)]
+async fn get_subscriptions(State(state): State<AppState>) -> Json<Vec<SubscriptionStatus>> {
+    Json(state.subscription_service.list_subscriptions().await)
+}
+
+// 配信待ちのイベント数と負荷制限の状態の取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/subscriptions/load-shedding",
+    tag = "admin",
+    responses(
+        (status = 200, body = Object)
+    )
+)]
+async fn get_load_shedding(State(state): State<AppState>) -> Json<LoadSheddingStatus> {
+    Json(state.subscription_service.load_shedding_status())
+}
+
+// 保存した購読の登録内容の一覧取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/subscriptions/registry",
+    tag = "admin",
+    responses(
+        (status = 200, body = [Object]),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_subscription_registry(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<SubscriptionRegistration>>, (StatusCode, Json<ApiError>)> {
+    match state.subscription_service.list_registrations().await {
+        Ok(registrations) => Ok(Json(registrations)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 保存した購読の登録内容の削除エンドポイント（次の起動時は購読時の状態で始まる）
+#[utoipa::path(
+    delete,
+    path = "/admin/subscriptions/registry/{name}",
+    tag = "admin",
+    params(("name" = String, Path, description = "購読名")),
+    responses(
+        (status = 204),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn forget_subscription_registration(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    match state.subscription_service.forget_registration(&name).await {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 購読解除エンドポイント
+#[utoipa::path(
+    delete,
+    path = "/admin/subscriptions/{name}",
+    tag = "admin",
+    params(("name" = String, Path, description = "購読名")),
+    responses(
+        (status = 204),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn unsubscribe(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    match state.subscription_service.unsubscribe(&name).await {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 購読一時停止エンドポイント
+#[utoipa::path(
+    post,
+    path = "/admin/subscriptions/{name}/pause",
+    tag = "admin",
+    params(("name" = String, Path, description = "購読名")),
+    request_body = PauseSubscriptionRequest,
+    responses(
+        (status = 204),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn pause_subscription(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Json(request): Json<PauseSubscriptionRequest>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    match state
+        .subscription_service
+        .pause(&name, request.paused_events)
+        .await
+    {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 購読再開エンドポイント
+#[utoipa::path(
+    post,
+    path = "/admin/subscriptions/{name}/resume",
+    tag = "admin",
+    params(("name" = String, Path, description = "購読名")),
+    responses(
+        (status = 200, body = ResumeSubscriptionResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn resume_subscription(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<ResumeSubscriptionResponse>, (StatusCode, Json<ApiError>)> {
+    match state.subscription_service.resume(&name).await {
+        Ok(replayed_events) => Ok(Json(ResumeSubscriptionResponse { replayed_events })),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// イベントの配信全体の状態の取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/admin/event-bus",
+    tag = "admin",
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError)
+    )
+)]
+async fn get_event_bus_status(
+    State(state): State<AppState>,
+) -> Result<Json<EventDispatchStatus>, (StatusCode, Json<ApiError>)> {
+    match state.subscription_service.event_dispatch_status() {
+        Ok(status) => Ok(Json(status)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// イベントの配信全体の一時停止エンドポイント
+// 処理中のハンドラーが終わるまで（drain_timeout_secondsまで）待ってから応答する
+// in_flight_eventsが0でない場合は、時間をおいてGET /admin/event-busで止め終えたことを確認する
+#[utoipa::path(
+    post,
+    path = "/admin/event-bus/pause",
+    tag = "admin",
+    params(PauseEventBusQueryParams),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError)
+    )
+)]
+async fn pause_event_bus(
+    State(state): State<AppState>,
+    Query(params): Query<PauseEventBusQueryParams>,
+) -> Result<Json<EventDispatchStatus>, (StatusCode, Json<ApiError>)> {
+    let drain_timeout = std::time::Duration::from_secs(
+        params
+            .drain_timeout_seconds
+            .unwrap_or(DEFAULT_EVENT_DISPATCH_DRAIN_TIMEOUT_SECONDS),
+    );
+
+    match state
+        .subscription_service
+        .pause_event_dispatch(drain_timeout)
+        .await
+    {
+        Ok(status) => Ok(Json(status)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// イベントの配信全体の再開エンドポイント（一時停止中にためていたイベントを発行順に配信する）
+#[utoipa::path(
+    post,
+    path = "/admin/event-bus/resume",
+    tag = "admin",
+    responses(
+        (status = 200, body = ResumeEventBusResponse),
+        (status = "4XX", body = ApiError)
+    )
+)]
+async fn resume_event_bus(
+    State(state): State<AppState>,
+) -> Result<Json<ResumeEventBusResponse>, (StatusCode, Json<ApiError>)> {
+    let replayed_events = state
+        .subscription_service
+        .resume_event_dispatch()
+        .await
+        .map_err(map_application_error)?;
+    let status = state
+        .subscription_service
+        .event_dispatch_status()
+        .map_err(map_application_error)?;
+    Ok(Json(ResumeEventBusResponse {
+        replayed_events,
+        status,
+    }))
+}
+
+// 棚卸し開始エンドポイント
+#[utoipa::path(
+    post,
+    path = "/inventory/counts",
+    tag = "inventory",
+    request_body = CreateCycleCountRequest,
+    responses(
+        (status = 201, body = CreateCycleCountResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn create_cycle_count(
+    State(state): State<AppState>,
+    Json(request): Json<CreateCycleCountRequest>,
+) -> Result<(StatusCode, Json<CreateCycleCountResponse>), (StatusCode, Json<ApiError>)> {
+    let book_ids = request.book_ids.into_iter().map(BookId::from_uuid).collect();
+
+    match state.cycle_count_service.start_cycle_count(book_ids).await {
+        Ok(cycle_count_id) => Ok((
+            StatusCode::CREATED,
+            Json(CreateCycleCountResponse {
+                cycle_count_id: cycle_count_id.as_uuid(),
+            }),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 棚卸し詳細取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/inventory/counts/{count_id}",
+    tag = "inventory",
+    params(("count_id" = Uuid, Path, description = "棚卸しID")),
+    responses(
+        (status = 200, body = CycleCountResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_cycle_count(
+    State(state): State<AppState>,
+    Path(count_id): Path<Uuid>,
+) -> Result<Json<CycleCountResponse>, (StatusCode, Json<ApiError>)> {
+    let cycle_count_id = CycleCountId::from_uuid(count_id);
+
+    match state.cycle_count_service.get_cycle_count(cycle_count_id).await {
+        Ok(Some(cycle_count)) => Ok(Json(CycleCountResponse::from_cycle_count(&cycle_count))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError {
+                error: "指定された棚卸しが見つかりません".to_string(),
+                code: "CYCLE_COUNT_NOT_FOUND".to_string(),
+                violations: Vec::new(),
+            }),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 棚卸しの実数記録エンドポイント
+#[utoipa::path(
+    put,
+    path = "/inventory/counts/{count_id}/entries",
+    tag = "inventory",
+    params(("count_id" = Uuid, Path, description = "棚卸しID")),
+    request_body = RecordCycleCountRequest,
+    responses(
+        (status = 200),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn record_cycle_count(
+    State(state): State<AppState>,
+    Path(count_id): Path<Uuid>,
+    Json(request): Json<RecordCycleCountRequest>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let cycle_count_id = CycleCountId::from_uuid(count_id);
+    let counts = request
+        .entries
+        .into_iter()
+        .map(|entry| (BookId::from_uuid(entry.book_id), entry.counted_quantity))
+        .collect();
+
+    match state
+        .cycle_count_service
+        .record_counts(cycle_count_id, counts)
+        .await
+    {
+        Ok(()) => Ok(StatusCode::OK),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 棚卸し提出エンドポイント
+#[utoipa::path(
+    post,
+    path = "/inventory/counts/{count_id}/submit",
+    tag = "inventory",
+    params(("count_id" = Uuid, Path, description = "棚卸しID")),
+    responses(
+        (status = 200),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn submit_cycle_count(
+    State(state): State<AppState>,
+    Path(count_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let cycle_count_id = CycleCountId::from_uuid(count_id);
+
+    match state
+        .cycle_count_service
+        .submit_cycle_count(cycle_count_id)
+        .await
+    {
+        Ok(()) => Ok(StatusCode::OK),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 棚卸し承認エンドポイント（差異を在庫に反映）
+#[utoipa::path(
+    post,
+    path = "/inventory/counts/{count_id}/approve",
+    tag = "inventory",
+    params(("count_id" = Uuid, Path, description = "棚卸しID")),
+    request_body = ApproveCycleCountRequest,
+    responses(
+        (status = 200),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn approve_cycle_count(
+    State(state): State<AppState>,
+    Path(count_id): Path<Uuid>,
+    Json(request): Json<ApproveCycleCountRequest>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let cycle_count_id = CycleCountId::from_uuid(count_id);
+
+    match state
+        .cycle_count_service
+        .approve_cycle_count(cycle_count_id, request.approved_by)
+        .await
+    {
+        Ok(()) => Ok(StatusCode::OK),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 棚卸し却下エンドポイント
+#[utoipa::path(
+    post,
+    path = "/inventory/counts/{count_id}/reject",
+    tag = "inventory",
+    params(("count_id" = Uuid, Path, description = "棚卸しID")),
+    responses(
+        (status = 200),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn reject_cycle_count(
+    State(state): State<AppState>,
+    Path(count_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let cycle_count_id = CycleCountId::from_uuid(count_id);
+
+    match state
+        .cycle_count_service
+        .reject_cycle_count(cycle_count_id)
+        .await
+    {
+        Ok(()) => Ok(StatusCode::OK),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 配送追跡タイムライン取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/orders/{order_id}/tracking",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_order_tracking(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<Json<TrackingTimeline>, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+
+    match state.tracking_service.get_tracking_timeline(order_id).await {
+        Ok(timeline) => Ok(Json(timeline)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 注文タイムライン取得エンドポイント（サポート向け）
+#[utoipa::path(
+    get,
+    path = "/orders/{order_id}/timeline",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_order_timeline(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<Json<OrderTimeline>, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+
+    match state.timeline_service.get_order_timeline(order_id).await {
+        Ok(timeline) => Ok(Json(timeline)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 注文イベントストリームエンドポイント（Server-Sent Events）
+// 接続した後に発行された注文のイベントを、イベントタイプをevent、イベントIDをid、イベントのJSONをdataとして送る
+// 受信が遅れて取りこぼした場合は、取りこぼした件数をdataとしたlaggedイベントを送る（クライアントは注文を取得し直す）
+#[utoipa::path(
+    get,
+    path = "/orders/{order_id}/events",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, content_type = "text/event-stream", body = String),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn stream_order_events(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<Sse<impl Stream<Item = Result<SseEvent, Infallible>>>, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+    let OrderEventSubscription { events, mut filter } = state
+        .order_service
+        .subscribe_order_events(order_id)
+        .await
+        .map_err(map_application_error)?;
+
+    let stream = BroadcastStream::new(events).filter_map(move |received| match received {
+        Ok(event) if filter.matches(&event) => Some(Ok(order_event_to_sse(&event))),
+        Ok(_) => None,
+        Err(BroadcastStreamRecvError::Lagged(missed)) => {
+            tracing::warn!(order_id = %order_id, missed, "order event stream lagged");
+            Some(Ok(SseEvent::default()
+                .event("lagged")
+                .data(missed.to_string())))
+        }
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// ドメインイベントをServer-Sent Eventsのイベントにする
+fn order_event_to_sse(event: &DomainEvent) -> SseEvent {
+    let sse_event = SseEvent::default()
+        .event(event.event_type())
+        .id(event.metadata().event_id.to_string());
+    match sse_event.clone().json_data(event) {
+        Ok(sse_event) => sse_event,
+        Err(e) => {
+            tracing::warn!(event.type = event.event_type(), error = %e, "failed to serialize order event");
+            sse_event.comment("serialization failed")
+        }
+    }
+}
+
+// 顧客への通知のWebSocketエンドポイント
+// 接続した後に通知ハンドラーが送った顧客への通知を、JSONのテキストメッセージで送る
+// 顧客は自分の通知だけを受け取れる（スタッフと認証を無効にしている場合はcustomer_idで顧客を指定する）
+#[utoipa::path(
+    get,
+    path = "/ws/notifications",
+    tag = "customers",
+    params(CustomerNotificationsQueryParams),
+    responses(
+        (status = 101, description = "WebSocketに切り替え、顧客への通知をJSONのテキストメッセージで送る"),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn customer_notifications_socket(
+    State(state): State<AppState>,
+    principal: Option<Extension<Principal>>,
+    Query(params): Query<CustomerNotificationsQueryParams>,
+    upgrade: WebSocketUpgrade,
+) -> Result<Response, (StatusCode, Json<ApiError>)> {
+    let customer_id = params
+        .customer_id
+        .map(CustomerId::from_uuid)
+        .or_else(|| principal.as_ref().and_then(|Extension(principal)| principal.customer_id))
+        .ok_or_else(|| {
+            map_domain_error(
+                FieldViolation::new(
+                    "customer_id",
+                    Constraint::Required,
+                    None,
+                    "顧客IDを指定してください",
+                )
+                .into(),
+            )
+        })?;
+    if principal.is_some_and(|Extension(principal)| !principal.can_act_for(customer_id)) {
+        return Err(forbidden());
+    }
+
+    let notifications = state
+        .notification_service
+        .connect_customer_notifications(customer_id)
+        .map_err(map_application_error)?;
+    Ok(upgrade.on_upgrade(move |socket| forward_customer_notifications(socket, notifications)))
+}
+
+/// 顧客への通知をWebSocketに送る（クライアントが切断するか、通知の受信元がなくなるまで）
+async fn forward_customer_notifications(
+    mut socket: WebSocket,
+    mut notifications: mpsc::Receiver<CustomerNotification>,
+) {
+    loop {
+        tokio::select! {
+            notification = notifications.recv() => {
+                let Some(notification) = notification else {
+                    break;
+                };
+                let json = match serde_json::to_string(&notification) {
+                    Ok(json) => json,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "failed to serialize customer notification");
+                        continue;
+                    }
+                };
+                if socket.send(Message::Text(json)).await.is_err() {
+                    break;
+                }
+            }
+            // クライアントからのメッセージは読み捨て、切断だけを検知する
+            received = socket.recv() => {
+                if matches!(received, None | Some(Err(_)) | Some(Ok(Message::Close(_)))) {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+// CSメモ追加エンドポイント（メモを書いたオペレーターは認証した利用者として記録する）
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/notes",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    request_body = AddOrderNoteRequest,
+    responses(
+        (status = 201, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn add_order_note(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    principal: Option<Extension<Principal>>,
+    headers: HeaderMap,
+    Json(request): Json<AddOrderNoteRequest>,
+) -> Result<(StatusCode, Json<OrderNote>), (StatusCode, Json<ApiError>)> {
+    match state
+        .timeline_service
+        .add_note(
+            OrderId::from_uuid(order_id),
+            operator(principal.as_deref(), &headers).unwrap_or_default(),
+            &request.body,
+            Utc::now(),
+        )
+        .await
+    {
+        Ok(note) => Ok((StatusCode::CREATED, Json(note))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 追跡トークンによる公開の配送状況取得エンドポイント
+// 形式が正しくないトークンも存在しないトークンと同じく404を返し、トークンの有無を推測させない
+#[utoipa::path(
+    get,
+    path = "/track/{token}",
+    tag = "track",
+    params(("token" = String, Path, description = "追跡トークン")),
+    responses(
+        (status = 200, body = PublicTrackingResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_public_tracking(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<PublicTrackingResponse>, (StatusCode, Json<ApiError>)> {
+    let token = TrackingToken::parse(&token).map_err(|_| {
+        map_application_error(ApplicationError::NotFound(
+            "追跡情報が見つかりません".to_string(),
+        ))
+    })?;
+
+    match state.order_service.get_public_tracking(&token).await {
+        Ok(view) => Ok(Json(PublicTrackingResponse::from_view(view))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// アクションリンクの確認エンドポイント
+// メールクライアントなどのリンクの先読みで操作が実行されないよう、GETでは内容を返すだけにする
+#[utoipa::path(
+    get,
+    path = "/actions/{token}",
+    tag = "actions",
+    params(("token" = String, Path, description = "アクションリンクのトークン")),
+    responses(
+        (status = 200, body = ActionLinkResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_action_link(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<ActionLinkResponse>, (StatusCode, Json<ApiError>)> {
+    match state.action_link_service.describe(&token, Utc::now()) {
+        Ok(claims) => Ok(Json(ActionLinkResponse::from_claims(&claims))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// アクションリンクの実行エンドポイント
+#[utoipa::path(
+    post,
+    path = "/actions/{token}",
+    tag = "actions",
+    params(("token" = String, Path, description = "アクションリンクのトークン")),
+    responses(
+        (status = 200, body = CommandResponse),
+        (status = 202, body = CommandResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn execute_action_link(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<(StatusCode, Json<CommandResponse>), (StatusCode, Json<ApiError>)> {
+    match state.action_link_service.execute(&token, Utc::now()).await {
+        Ok(acknowledgement) => Ok(command_response(acknowledgement)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// チェックアウト中の在庫の仮押さえエンドポイント
+// 再度呼び出すと現在の注文明細で仮押さえを更新し、期限を延長する
+#[utoipa::path(
+    post,
+    path = "/orders/{order_id}/checkout-hold",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = CheckoutHoldResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn place_checkout_hold(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<Json<CheckoutHoldResponse>, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+
+    match state
+        .checkout_hold_service
+        .place_hold(order_id, Utc::now())
+        .await
+    {
+        Ok(holds) => Ok(Json(CheckoutHoldResponse::from_holds(order_id, &holds))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 順番待ちの位置照会エンドポイント（顧客向け）
+#[utoipa::path(
+    get,
+    path = "/orders/{order_id}/waitlist",
+    tag = "orders",
+    params(("order_id" = Uuid, Path, description = "注文ID")),
+    responses(
+        (status = 200, body = WaitlistStatusResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_order_waitlist(
+    State(state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+) -> Result<Json<WaitlistStatusResponse>, (StatusCode, Json<ApiError>)> {
+    let order_id = OrderId::from_uuid(order_id);
+
+    match state.waitlist_service.get_positions(order_id).await {
+        Ok((status, positions)) => Ok(Json(WaitlistStatusResponse::from_positions(
+            order_id, status, &positions,
+        ))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 配送業者の追跡Webhook受信エンドポイント
+// 受信したメッセージはinboxに記録して受け付け、InboxProcessorが非同期に取り込む
+#[utoipa::path(
+    post,
+    path = "/webhooks/carrier",
+    tag = "webhooks",
+    request_body = CarrierTrackingWebhookRequest,
+    responses(
+        (status = 202),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn receive_carrier_tracking(
+    State(state): State<AppState>,
+    body: String,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let request: CarrierTrackingWebhookRequest = serde_json::from_str(&body).map_err(|e| {
+        map_domain_error(DomainError::InvalidValue(format!(
+            "Webhookの本文が不正です: {}",
+            e
+        )))
+    })?;
+    request.tracking_stage().map_err(map_domain_error)?;
+
+    match state
+        .inbox_service
+        .receive(INBOX_SOURCE_CARRIER, request.message_id, body, Utc::now())
+        .await
+    {
+        Ok(true) => Ok(StatusCode::ACCEPTED),
+        // 再送されたメッセージは記録済みのため取り込まない
+        Ok(false) => Ok(StatusCode::OK),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 顧客登録エンドポイント
+#[utoipa::path(
+    post,
+    path = "/customers",
+    tag = "customers",
+    request_body = RegisterCustomerRequest,
+    responses(
+        (status = 201, body = CustomerResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn register_customer(
+    State(state): State<AppState>,
+    Json(request): Json<RegisterCustomerRequest>,
+) -> Result<(StatusCode, Json<CustomerResponse>), (StatusCode, Json<ApiError>)> {
+    let default_shipping_address = request
+        .default_shipping_address()
+        .map_err(map_domain_error)?;
+
+    match state
+        .customer_service
+        .register_customer(request.name, request.email, default_shipping_address)
+        .await
+    {
+        Ok(customer) => Ok((
+            StatusCode::CREATED,
+            Json(CustomerResponse::from_customer(&customer)),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 顧客取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID")),
+    responses(
+        (status = 200, body = CustomerResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_customer(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+) -> Result<Json<CustomerResponse>, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+
+    match state.customer_service.get_customer(customer_id).await {
+        Ok(customer) => Ok(Json(CustomerResponse::from_customer(&customer))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 顧客の通知の言語設定エンドポイント
+#[utoipa::path(
+    put,
+    path = "/customers/{customer_id}/preferred-language",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID")),
+    request_body = SetPreferredLanguageRequest,
+    responses(
+        (status = 200, body = CustomerResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn set_customer_preferred_language(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+    Json(request): Json<SetPreferredLanguageRequest>,
+) -> Result<Json<CustomerResponse>, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+    let language = parse_language(&request.language)?;
+
+    match state
+        .customer_service
+        .set_preferred_language(customer_id, language)
+        .await
+    {
+        Ok(customer) => Ok(Json(CustomerResponse::from_customer(&customer))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 顧客の注文一覧取得エンドポイント（作成日時の新しい順）
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/orders",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID")),
+    responses(
+        (status = 200, body = [OrderSummaryResponse]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_customer_orders(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+) -> Result<Json<Vec<OrderSummaryResponse>>, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+
+    match state
+        .customer_service
+        .list_customer_orders(customer_id)
+        .await
+    {
+        Ok(orders) => {
+            let shipping_fee_policy = state.order_service.shipping_fee_policy();
+            Ok(Json(
+                orders
+                    .iter()
+                    .map(|order| OrderSummaryResponse::from_order(order, shipping_fee_policy))
+                    .collect(),
+            ))
+        }
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// デバイス登録エンドポイント
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/devices",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID")),
+    request_body = RegisterDeviceRequest,
+    responses(
+        (status = 201, body = DeviceResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn register_device(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+    Json(request): Json<RegisterDeviceRequest>,
+) -> Result<(StatusCode, Json<DeviceResponse>), (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+    let token = DeviceToken::new(request.token).map_err(map_domain_error)?;
+    let platform = DevicePlatform::from_string(&request.platform).map_err(map_domain_error)?;
+
+    match state
+        .device_service
+        .register_device(customer_id, token, platform)
+        .await
+    {
+        Ok(registration) => Ok((
+            StatusCode::CREATED,
+            Json(DeviceResponse::from_registration(&registration)),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 登録済みデバイス一覧取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/devices",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID")),
+    responses(
+        (status = 200, body = [DeviceResponse]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_devices(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+) -> Result<Json<Vec<DeviceResponse>>, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+
+    match state.device_service.list_devices(customer_id).await {
+        Ok(registrations) => Ok(Json(
+            registrations
+                .iter()
+                .map(DeviceResponse::from_registration)
+                .collect(),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// デバイス登録解除エンドポイント
+#[utoipa::path(
+    delete,
+    path = "/customers/{customer_id}/devices/{token}",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID"), ("token" = String, Path, description = "アクションリンクのトークン")),
+    responses(
+        (status = 204),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn unregister_device(
+    State(state): State<AppState>,
+    Path((customer_id, token)): Path<(Uuid, String)>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+    let token = DeviceToken::new(token).map_err(map_domain_error)?;
+
+    match state
+        .device_service
+        .unregister_device(customer_id, token)
+        .await
+    {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+/// Webhookの配信ログの既定の取得件数
+const DEFAULT_WEBHOOK_DELIVERIES: u32 = 50;
+
+// Webhookの購読登録エンドポイント
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/webhooks",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID")),
+    request_body = CreateWebhookSubscriptionRequest,
+    responses(
+        (status = 201, body = WebhookSubscriptionResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn create_webhook_subscription(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+    Json(request): Json<CreateWebhookSubscriptionRequest>,
+) -> Result<(StatusCode, Json<WebhookSubscriptionResponse>), (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+
+    match state
+        .webhook_service
+        .create_subscription(
+            customer_id,
+            request.url,
+            request.secret,
+            request.event_types,
         )
-    })?;
+        .await
+    {
+        Ok(subscription) => Ok((
+            StatusCode::CREATED,
+            Json(WebhookSubscriptionResponse::from_subscription(
+                &subscription,
+            )),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
 
-    let inventories = if let Some(max_quantity) = params.max_quantity {
-        match state
-            .inventory_service
-            .get_low_stock_inventories(max_quantity)
-            .await
-        {
-            Ok(inventories) => inventories,
-            Err(err) => return Err(map_application_error(err)),
-        }
-    } else {
-        match state.inventory_service.get_all_inventories().await {
-            Ok(inventories) => inventories,
-            Err(err) => return Err(map_application_error(err)),
-        }
-    };
+// Webhookの購読一覧取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/webhooks",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID")),
+    responses(
+        (status = 200, body = [WebhookSubscriptionResponse]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_webhook_subscriptions(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+) -> Result<Json<Vec<WebhookSubscriptionResponse>>, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
 
-    let response: Vec<InventoryResponse> = inventories
-        .iter()
-        .map(InventoryResponse::from_inventory)
-        .collect();
+    match state.webhook_service.list_subscriptions(customer_id).await {
+        Ok(subscriptions) => Ok(Json(
+            subscriptions
+                .iter()
+                .map(WebhookSubscriptionResponse::from_subscription)
+                .collect(),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
 
-    Ok(Json(response))
+// 顧客のデータのエクスポートの依頼エンドポイント
+// アーカイブ（顧客情報・注文・通知の履歴のJSONファイルのZIP）はバックグラウンドで作成するため、
+// 返したstatus_urlで完了を確認してからdownload_urlでダウンロードする
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/export",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID")),
+    responses(
+        (status = 202, body = CustomerExportResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn request_customer_export(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<CustomerExportResponse>), (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+
+    match state.customer_export_service.request_export(customer_id).await {
+        Ok(job) => Ok((
+            StatusCode::ACCEPTED,
+            Json(CustomerExportResponse::from_job(&job)),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
 }
 
-// 在庫詳細取得エンドポイント
-async fn get_inventory_by_book_id(
+// 顧客のデータのエクスポートの状態の取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/export/{job_id}",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID"), ("job_id" = Uuid, Path, description = "エクスポートのジョブID")),
+    responses(
+        (status = 200, body = CustomerExportResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_customer_export(
     State(state): State<AppState>,
-    Path(book_id): Path<Uuid>,
-) -> Result<Json<InventoryResponse>, (StatusCode, Json<ApiError>)> {
-    let book_id = BookId::from_uuid(book_id);
+    Path((customer_id, job_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<CustomerExportResponse>, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
 
     match state
-        .inventory_service
-        .get_inventory_by_book_id(book_id)
+        .customer_export_service
+        .get_export(customer_id, job_id)
         .await
     {
-        Ok(Some(inventory)) => {
-            let response = InventoryResponse::from_inventory(&inventory);
-            Ok(Json(response))
-        }
-        Ok(None) => Err((
-            StatusCode::NOT_FOUND,
-            Json(ApiError {
-                error: "指定された書籍の在庫が見つかりません".to_string(),
-                code: "INVENTORY_NOT_FOUND".to_string(),
-            }),
+        Ok(job) => Ok(Json(CustomerExportResponse::from_job(&job))),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 顧客のデータのエクスポートのダウンロードエンドポイント（ZIP）
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/export/{job_id}/download",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID"), ("job_id" = Uuid, Path, description = "エクスポートのジョブID")),
+    responses(
+        (status = 200, content_type = "application/zip", body = Vec<u8>),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn download_customer_export(
+    State(state): State<AppState>,
+    Path((customer_id, job_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+
+    match state
+        .customer_export_service
+        .download_export(customer_id, job_id)
+        .await
+    {
+        Ok(archive) => Ok((
+            [
+                (header::CONTENT_TYPE, "application/zip".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"customer-export-{}.zip\"", job_id),
+                ),
+            ],
+            archive,
+        )
+            .into_response()),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// Webhookの購読削除エンドポイント
+#[utoipa::path(
+    delete,
+    path = "/customers/{customer_id}/webhooks/{subscription_id}",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID"), ("subscription_id" = Uuid, Path, description = "Webhookの購読ID")),
+    responses(
+        (status = 204),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn delete_webhook_subscription(
+    State(state): State<AppState>,
+    Path((customer_id, subscription_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+
+    match state
+        .webhook_service
+        .delete_subscription(customer_id, subscription_id)
+        .await
+    {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// Webhookの配信ログ取得エンドポイント（新しい順）
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/webhooks/{subscription_id}/deliveries",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID"), ("subscription_id" = Uuid, Path, description = "Webhookの購読ID"), WebhookDeliveriesQueryParams),
+    responses(
+        (status = 200, body = [WebhookDeliveryResponse]),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_webhook_deliveries(
+    State(state): State<AppState>,
+    Path((customer_id, subscription_id)): Path<(Uuid, Uuid)>,
+    Query(params): Query<WebhookDeliveriesQueryParams>,
+) -> Result<Json<Vec<WebhookDeliveryResponse>>, (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+    let limit = params.limit.unwrap_or(DEFAULT_WEBHOOK_DELIVERIES);
+
+    match state
+        .webhook_service
+        .list_deliveries(customer_id, subscription_id, limit)
+        .await
+    {
+        Ok(deliveries) => Ok(Json(
+            deliveries
+                .iter()
+                .map(WebhookDeliveryResponse::from_delivery)
+                .collect(),
+        )),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// Webhookの再配信エンドポイント
+// 再配信の結果は配信先が失敗した場合も新しい配信記録として返す
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/webhooks/{subscription_id}/deliveries/{delivery_id}/redeliver",
+    tag = "customers",
+    params(("customer_id" = Uuid, Path, description = "顧客ID"), ("subscription_id" = Uuid, Path, description = "Webhookの購読ID"), ("delivery_id" = Uuid, Path, description = "Webhookの配信ID")),
+    responses(
+        (status = 201, body = WebhookDeliveryResponse),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn redeliver_webhook(
+    State(state): State<AppState>,
+    Path((customer_id, subscription_id, delivery_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<(StatusCode, Json<WebhookDeliveryResponse>), (StatusCode, Json<ApiError>)> {
+    let customer_id = CustomerId::from_uuid(customer_id);
+
+    match state
+        .webhook_service
+        .redeliver(customer_id, subscription_id, delivery_id)
+        .await
+    {
+        Ok(delivery) => Ok((
+            StatusCode::CREATED,
+            Json(WebhookDeliveryResponse::from_delivery(&delivery)),
         )),
         Err(err) => Err(map_application_error(err)),
     }
 }
 
+// ビジネスメトリクス取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "metrics",
+    responses(
+        (status = 200, body = Object)
+    )
+)]
+async fn get_business_metrics(State(state): State<AppState>) -> Json<BusinessMetricsSnapshot> {
+    Json(state.business_metrics.snapshot().await)
+}
+
+// フルフィルメントSLAレポート取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/reports/sla",
+    tag = "reports",
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_sla_report(
+    State(state): State<AppState>,
+) -> Result<Json<SlaReport>, (StatusCode, Json<ApiError>)> {
+    match state.order_service.get_sla_report(Utc::now()).await {
+        Ok(report) => Ok(Json(report)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 書籍の需要予測取得エンドポイント
+#[utoipa::path(
+    get,
+    path = "/reports/forecast/{book_id}",
+    tag = "reports",
+    params(("book_id" = Uuid, Path, description = "書籍ID")),
+    responses(
+        (status = 200, body = Object),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_demand_forecast(
+    State(state): State<AppState>,
+    Path(book_id): Path<Uuid>,
+) -> Result<Json<DemandForecast>, (StatusCode, Json<ApiError>)> {
+    match state
+        .forecast_service
+        .get_forecast(BookId::from_uuid(book_id), Utc::now())
+        .await
+    {
+        Ok(forecast) => Ok(Json(forecast)),
+        Err(err) => Err(map_application_error(err)),
+    }
+}
+
+// 在庫評価レポート取得エンドポイント（format=csvでCSVをダウンロード）
+#[utoipa::path(
+    get,
+    path = "/reports/inventory-valuation",
+    tag = "reports",
+    params(ReportQueryParams),
+    responses(
+        (status = 200, content(("application/json" = Object), ("text/csv" = String))),
+        (status = "4XX", body = ApiError),
+        (status = "5XX", body = ApiError)
+    )
+)]
+async fn get_inventory_valuation_report(
+    State(state): State<AppState>,
+    query: Result<Query<ReportQueryParams>, axum::extract::rejection::QueryRejection>,
+) -> Result<Response, (StatusCode, Json<ApiError>)> {
+    let Query(params) = query.map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: "無効なクエリパラメータです（formatはjsonまたはcsv）".to_string(),
+                code: "INVALID_PARAMETER".to_string(),
+                violations: Vec::new(),
+            }),
+        )
+    })?;
+
+    let report = state
+        .inventory_service
+        .get_valuation_report(Utc::now())
+        .await
+        .map_err(map_application_error)?;
+
+    Ok(match params.format {
+        ReportFormat::Json => Json(report).into_response(),
+        ReportFormat::Csv => (
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"inventory-valuation.csv\"",
+                ),
+            ],
+            report.to_csv(),
+        )
+            .into_response(),
+    })
+}
+
 // アプリケーションエラーをHTTPエラーにマッピング
-fn map_application_error(err: ApplicationError) -> (StatusCode, Json<ApiError>) {
+pub(crate) fn map_application_error(err: ApplicationError) -> (StatusCode, Json<ApiError>) {
     match err {
         ApplicationError::DomainError(domain_err) => map_domain_error(domain_err),
+        ApplicationError::RepositoryError(RepositoryError::ConcurrencyConflict(msg)) => (
+            StatusCode::CONFLICT,
+            Json(ApiError {
+                error: msg,
+                code: "CONCURRENCY_CONFLICT".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
         ApplicationError::RepositoryError(repo_err) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ApiError {
                 error: format!("{}", repo_err),
                 code: "REPOSITORY_ERROR".to_string(),
+                violations: Vec::new(),
             }),
         ),
         ApplicationError::EventPublishingFailed(msg) => (
@@ -352,6 +3904,7 @@ fn map_application_error(err: ApplicationError) -> (StatusCode, Json<ApiError>)
             Json(ApiError {
                 error: msg,
                 code: "EVENT_PUBLISHING_ERROR".to_string(),
+                violations: Vec::new(),
             }),
         ),
         ApplicationError::NotFound(msg) => (
@@ -359,6 +3912,23 @@ fn map_application_error(err: ApplicationError) -> (StatusCode, Json<ApiError>)
             Json(ApiError {
                 error: msg,
                 code: "NOT_FOUND".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        ApplicationError::ExternalServiceFailed(msg) => (
+            StatusCode::BAD_GATEWAY,
+            Json(ApiError {
+                error: msg,
+                code: "EXTERNAL_SERVICE_ERROR".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        ApplicationError::Unsupported(msg) => (
+            StatusCode::NOT_IMPLEMENTED,
+            Json(ApiError {
+                error: msg,
+                code: "UNSUPPORTED".to_string(),
+                violations: Vec::new(),
             }),
         ),
     }
@@ -369,11 +3939,12 @@ fn map_domain_error(domain_err: crate::domain::error::DomainError) -> (StatusCod
     use crate::domain::error::DomainError;
 
     match domain_err {
-        DomainError::InvalidAddress(msg) => (
-            StatusCode::BAD_REQUEST,
+        DomainError::Validation(errors) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
             Json(ApiError {
-                error: msg,
-                code: "INVALID_ADDRESS".to_string(),
+                error: errors.to_string(),
+                code: "VALIDATION_FAILED".to_string(),
+                violations: errors.violations().to_vec(),
             }),
         ),
         DomainError::InvalidQuantity => (
@@ -381,6 +3952,7 @@ fn map_domain_error(domain_err: crate::domain::error::DomainError) -> (StatusCod
             Json(ApiError {
                 error: "無効な数量です".to_string(),
                 code: "INVALID_QUANTITY".to_string(),
+                violations: Vec::new(),
             }),
         ),
         DomainError::InvalidValue(msg) => (
@@ -388,6 +3960,7 @@ fn map_domain_error(domain_err: crate::domain::error::DomainError) -> (StatusCod
             Json(ApiError {
                 error: msg,
                 code: "INVALID_VALUE".to_string(),
+                violations: Vec::new(),
             }),
         ),
         DomainError::InvalidOrderState(msg) => (
@@ -395,6 +3968,7 @@ fn map_domain_error(domain_err: crate::domain::error::DomainError) -> (StatusCod
             Json(ApiError {
                 error: msg,
                 code: "INVALID_ORDER_STATE".to_string(),
+                violations: Vec::new(),
             }),
         ),
         DomainError::InsufficientInventory => (
@@ -402,20 +3976,95 @@ fn map_domain_error(domain_err: crate::domain::error::DomainError) -> (StatusCod
             Json(ApiError {
                 error: "在庫不足です".to_string(),
                 code: "INSUFFICIENT_INVENTORY".to_string(),
+                violations: Vec::new(),
             }),
         ),
-        DomainError::OrderValidation(msg) => (
+        DomainError::CurrencyMismatch => (
             StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: "通貨が一致しません".to_string(),
+                code: "CURRENCY_MISMATCH".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::PurchaseQuantityLimitExceeded(msg) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
             Json(ApiError {
                 error: msg,
-                code: "ORDER_VALIDATION".to_string(),
+                code: "PURCHASE_QUANTITY_LIMIT_EXCEEDED".to_string(),
+                violations: Vec::new(),
             }),
         ),
-        DomainError::CurrencyMismatch => (
+        DomainError::OpenOrderLimitExceeded(msg) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            Json(ApiError {
+                error: msg,
+                code: "OPEN_ORDER_LIMIT_EXCEEDED".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::InvalidCycleCountState(msg) => (
             StatusCode::BAD_REQUEST,
             Json(ApiError {
-                error: "通貨が一致しません".to_string(),
-                code: "CURRENCY_MISMATCH".to_string(),
+                error: msg,
+                code: "INVALID_CYCLE_COUNT_STATE".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::InventoryFrozen(book_id) => (
+            StatusCode::CONFLICT,
+            Json(ApiError {
+                error: format!("在庫が凍結中のため処理できません: {}", book_id),
+                code: "INVENTORY_FROZEN".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::CarrierLimitExceeded(msg) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiError {
+                error: msg,
+                code: "CARRIER_LIMIT_EXCEEDED".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::PriceChanged(msg) => (
+            StatusCode::CONFLICT,
+            Json(ApiError {
+                error: msg,
+                code: "PRICE_CHANGED".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::CancellationWindowExpired(msg) => (
+            StatusCode::CONFLICT,
+            Json(ApiError {
+                error: msg,
+                code: "CANCELLATION_WINDOW_EXPIRED".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::OrderLocked(msg) => (
+            StatusCode::LOCKED,
+            Json(ApiError {
+                error: msg,
+                code: "ORDER_LOCKED".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::ConfirmationTokenMismatch(msg) => (
+            StatusCode::CONFLICT,
+            Json(ApiError {
+                error: msg,
+                code: "CONFIRMATION_TOKEN_MISMATCH".to_string(),
+                violations: Vec::new(),
+            }),
+        ),
+        DomainError::QuotaExceeded(msg) => (
+            StatusCode::CONFLICT,
+            Json(ApiError {
+                error: msg,
+                code: "QUOTA_EXCEEDED".to_string(),
+                violations: Vec::new(),
             }),
         ),
     }
@@ -425,11 +4074,64 @@ fn map_domain_error(domain_err: crate::domain::error::DomainError) -> (StatusCod
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_create_router_accepts_cycle_count_routes() {
+        // 静的セグメント（/inventory/counts・/inventory/snapshot）とパスパラメータ（/inventory/:book_id）が共存できること
+        let _router = create_router();
+    }
+
+    #[test]
+    fn test_order_lock_guard_applies_to_order_mutations_except_lock() {
+        let order_id = Uuid::new_v4();
+        let path = |suffix: &str| format!("/orders/{}{}", order_id, suffix);
+        assert_eq!(
+            lockable_order_id(&Method::POST, &path("/ship")),
+            Some(OrderId::from_uuid(order_id))
+        );
+        assert_eq!(
+            lockable_order_id(&Method::POST, &format!("/admin/orders/{}/repair", order_id)),
+            Some(OrderId::from_uuid(order_id))
+        );
+        assert_eq!(lockable_order_id(&Method::GET, &path("")), None);
+        assert_eq!(
+            lockable_order_id(&Method::POST, &format!("/admin/orders/{}/lock", order_id)),
+            None
+        );
+        assert_eq!(lockable_order_id(&Method::POST, &path("/notes")), None);
+        assert_eq!(lockable_order_id(&Method::POST, "/orders/bulk/ship"), None);
+        assert_eq!(lockable_order_id(&Method::POST, "/admin/orders/bulk-cancel"), None);
+        assert_eq!(lockable_order_id(&Method::POST, "/orders"), None);
+    }
+
+    #[test]
+    fn test_operator_is_the_authenticated_subject_rather_than_the_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(OPERATOR_HEADER, "mallory".parse().unwrap());
+        let principal = Principal::staff("alice");
+
+        // 認証した利用者がいる場合はX-Operatorヘッダーでなりすませない
+        assert_eq!(operator(Some(&principal), &headers), Some("alice"));
+        assert_eq!(operator(Some(&principal), &HeaderMap::new()), Some("alice"));
+        // 認証を無効にしている場合だけヘッダーで名乗る
+        assert_eq!(operator(None, &headers), Some("mallory"));
+        assert_eq!(operator(None, &HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_concurrency_conflict_is_mapped_to_conflict() {
+        let (status, Json(error)) = map_application_error(ApplicationError::RepositoryError(
+            RepositoryError::ConcurrencyConflict("注文は他の操作が保存しています".to_string()),
+        ));
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(error.code, "CONCURRENCY_CONFLICT");
+    }
+
     #[test]
     fn test_api_error_structure() {
         let api_error = ApiError {
             error: "テストエラー".to_string(),
             code: "TEST_ERROR".to_string(),
+            violations: Vec::new(),
         };
 
         // JSON シリアライゼーションのテスト
@@ -442,12 +4144,46 @@ mod tests {
         assert_eq!(deserialized.error, "テストエラー");
         assert_eq!(deserialized.code, "TEST_ERROR");
     }
+
+    #[test]
+    fn test_correlation_id_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(correlation_id_from_headers(&headers).ok(), Some(None));
+
+        let correlation_id = Uuid::new_v4();
+        headers.insert(
+            "X-Correlation-Id",
+            correlation_id.to_string().parse().unwrap(),
+        );
+        assert_eq!(
+            correlation_id_from_headers(&headers).ok(),
+            Some(Some(correlation_id))
+        );
+
+        headers.insert("X-Correlation-Id", "not-a-uuid".parse().unwrap());
+        let (status, Json(api_error)) = correlation_id_from_headers(&headers).unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(api_error.code, "INVALID_CORRELATION_ID");
+    }
+
+    #[test]
+    fn test_language_from_headers_falls_back_to_default() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(language_from_headers(&headers), Language::Ja);
+
+        headers.insert(header::ACCEPT_LANGUAGE, "en-GB,en;q=0.9".parse().unwrap());
+        assert_eq!(language_from_headers(&headers), Language::En);
+
+        headers.insert(header::ACCEPT_LANGUAGE, "fr-FR".parse().unwrap());
+        assert_eq!(language_from_headers(&headers), Language::Ja);
+    }
 }
 
 #[cfg(test)]
 mod error_handling_tests {
     use super::*;
     use crate::application::ApplicationError;
+    use crate::domain::error::DomainError;
 
     #[test]
     fn test_map_application_error_not_found() {
@@ -458,4 +4194,106 @@ mod error_handling_tests {
         assert_eq!(api_error.code, "NOT_FOUND");
         assert_eq!(api_error.error, "リソースが見つかりません");
     }
+
+    #[test]
+    fn test_map_application_error_external_service_failed() {
+        let app_error = ApplicationError::ExternalServiceFailed("FCM unavailable".to_string());
+        let (status, Json(api_error)) = map_application_error(app_error);
+
+        assert_eq!(status, StatusCode::BAD_GATEWAY);
+        assert_eq!(api_error.code, "EXTERNAL_SERVICE_ERROR");
+    }
+
+    #[test]
+    fn test_map_purchase_limit_errors() {
+        let (status, Json(api_error)) = map_application_error(ApplicationError::DomainError(
+            DomainError::OpenOrderLimitExceeded("上限".to_string()),
+        ));
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(api_error.code, "OPEN_ORDER_LIMIT_EXCEEDED");
+
+        let (status, Json(api_error)) = map_application_error(ApplicationError::DomainError(
+            DomainError::PurchaseQuantityLimitExceeded("上限".to_string()),
+        ));
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(api_error.code, "PURCHASE_QUANTITY_LIMIT_EXCEEDED");
+    }
+
+    #[test]
+    fn test_map_validation_error_lists_violations() {
+        use crate::domain::validation::{Constraint, ValidationErrors};
+
+        let mut errors = ValidationErrors::new();
+        errors.add(FieldViolation::new(
+            "postal_code",
+            Constraint::Pattern,
+            Some("123".to_string()),
+            "郵便番号は7桁の数字である必要があります",
+        ));
+        errors.add(FieldViolation::new(
+            "city",
+            Constraint::Required,
+            None,
+            "市区町村は空にできません",
+        ));
+        let (status, Json(api_error)) =
+            map_application_error(ApplicationError::DomainError(errors.into()));
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(api_error.code, "VALIDATION_FAILED");
+        let body = serde_json::to_value(&api_error).unwrap();
+        assert_eq!(body["violations"][0]["field"], "postal_code");
+        assert_eq!(body["violations"][0]["constraint"], "pattern");
+        assert_eq!(body["violations"][0]["actual"], "123");
+        assert_eq!(body["violations"][1]["constraint"], "required");
+
+        // 検証エラー以外ではviolationsを出力しない
+        let (_, Json(api_error)) = map_application_error(ApplicationError::NotFound(
+            "リソースが見つかりません".to_string(),
+        ));
+        assert!(serde_json::to_value(&api_error).unwrap().get("violations").is_none());
+    }
+
+    #[test]
+    fn test_mapped_error_codes_are_listed_in_openapi_spec() {
+        use crate::adapter::driver::openapi::ERROR_CODES;
+        use crate::domain::validation::ValidationErrors;
+
+        let message = || "error".to_string();
+        let domain_errors = vec![
+            DomainError::Validation(ValidationErrors::new()),
+            DomainError::InvalidQuantity,
+            DomainError::InvalidValue(message()),
+            DomainError::InvalidOrderState(message()),
+            DomainError::InsufficientInventory,
+            DomainError::CurrencyMismatch,
+            DomainError::PurchaseQuantityLimitExceeded(message()),
+            DomainError::OpenOrderLimitExceeded(message()),
+            DomainError::InvalidCycleCountState(message()),
+            DomainError::InventoryFrozen(message()),
+            DomainError::CarrierLimitExceeded(message()),
+            DomainError::PriceChanged(message()),
+            DomainError::CancellationWindowExpired(message()),
+            DomainError::OrderLocked(message()),
+            DomainError::ConfirmationTokenMismatch(message()),
+            DomainError::QuotaExceeded(message()),
+        ];
+        let application_errors = vec![
+            ApplicationError::RepositoryError(RepositoryError::ConcurrencyConflict(message())),
+            ApplicationError::RepositoryError(RepositoryError::OperationFailed(message())),
+            ApplicationError::EventPublishingFailed(message()),
+            ApplicationError::NotFound(message()),
+            ApplicationError::ExternalServiceFailed(message()),
+            ApplicationError::Unsupported(message()),
+        ];
+
+        let codes = domain_errors
+            .into_iter()
+            .map(map_domain_error)
+            .chain(application_errors.into_iter().map(map_application_error))
+            .map(|(_, Json(api_error))| api_error.code);
+        for code in codes {
+            assert!(ERROR_CODES.contains(&code.as_str()), "未掲載のエラーコード: {}", code);
+        }
+    }
 }
@@ -0,0 +1,121 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::event_export::DEFAULT_EXPORT_CHUNK_SIZE;
+use std::env;
+use std::path::PathBuf;
+
+/// イベントのエクスポート先
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportStorage {
+    /// ローカルファイルシステムのディレクトリ
+    Local(PathBuf),
+    /// S3互換オブジェクトストレージ（スタブ）
+    S3 { endpoint: String, bucket: String },
+}
+
+/// イベントのエクスポート設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct EventExportConfig {
+    pub storage: ExportStorage,
+    /// 1つのチャンク（オブジェクト）に書き出すイベント数
+    pub chunk_size: usize,
+    /// 前日分のイベントを夜間に自動で書き出すか
+    pub nightly: bool,
+}
+
+/// 必須の環境変数を読み取る
+fn required(name: &str) -> Result<String, ConfigError> {
+    env::var(name).map_err(|_| {
+        ConfigError::InvalidValue(format!("{} is required for this EVENT_EXPORT_STORAGE", name))
+    })
+}
+
+impl EventExportConfig {
+    /// 環境変数から設定を読み取る
+    /// - EVENT_EXPORT_STORAGE: local（デフォルト）/ s3
+    /// - EVENT_EXPORT_DIR: localの場合の書き出し先ディレクトリ（デフォルト: exports）
+    /// - EVENT_EXPORT_S3_ENDPOINT / EVENT_EXPORT_S3_BUCKET: s3の場合のエンドポイントとバケット
+    /// - EVENT_EXPORT_CHUNK_SIZE: 1つのチャンクに書き出すイベント数（デフォルト: 1000）
+    /// - EVENT_EXPORT_NIGHTLY: 前日分を夜間に自動で書き出すか（デフォルト: true）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_env_with_defaults(DEFAULT_EXPORT_CHUNK_SIZE, true)
+    }
+
+    /// 環境変数から設定を読み取る
+    /// EVENT_EXPORT_CHUNK_SIZE・EVENT_EXPORT_NIGHTLYが設定されていない場合は指定した値を使用（設定ファイルの値を環境変数で上書きする）
+    pub fn from_env_with_defaults(chunk_size: usize, nightly: bool) -> Result<Self, ConfigError> {
+        let storage = match env::var("EVENT_EXPORT_STORAGE")
+            .unwrap_or_else(|_| "local".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "local" => ExportStorage::Local(PathBuf::from(
+                env::var("EVENT_EXPORT_DIR").unwrap_or_else(|_| "exports".to_string()),
+            )),
+            "s3" => ExportStorage::S3 {
+                endpoint: required("EVENT_EXPORT_S3_ENDPOINT")?,
+                bucket: required("EVENT_EXPORT_S3_BUCKET")?,
+            },
+            other => {
+                return Err(ConfigError::InvalidValue(format!(
+                    "Invalid EVENT_EXPORT_STORAGE: {}",
+                    other
+                )))
+            }
+        };
+
+        let chunk_size = parse_env("EVENT_EXPORT_CHUNK_SIZE", chunk_size)?;
+        if chunk_size == 0 {
+            return Err(ConfigError::InvalidValue(
+                "EVENT_EXPORT_CHUNK_SIZE must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            storage,
+            chunk_size,
+            nightly: parse_env("EVENT_EXPORT_NIGHTLY", nightly)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::database_config::tests::ENV_LOCK;
+
+    #[test]
+    fn test_from_env_selects_storage() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::set_var("EVENT_EXPORT_STORAGE", "s3");
+        env::set_var("EVENT_EXPORT_S3_ENDPOINT", "http://localhost:9000");
+        env::set_var("EVENT_EXPORT_S3_BUCKET", "bookstore-events");
+        env::set_var("EVENT_EXPORT_CHUNK_SIZE", "500");
+        env::set_var("EVENT_EXPORT_NIGHTLY", "false");
+        let config = EventExportConfig::from_env().unwrap();
+        assert_eq!(
+            config.storage,
+            ExportStorage::S3 {
+                endpoint: "http://localhost:9000".to_string(),
+                bucket: "bookstore-events".to_string(),
+            }
+        );
+        assert_eq!(config.chunk_size, 500);
+        assert!(!config.nightly);
+
+        env::remove_var("EVENT_EXPORT_S3_BUCKET");
+        assert!(EventExportConfig::from_env().is_err());
+
+        env::set_var("EVENT_EXPORT_STORAGE", "ftp");
+        assert!(EventExportConfig::from_env().is_err());
+
+        env::remove_var("EVENT_EXPORT_STORAGE");
+        env::remove_var("EVENT_EXPORT_S3_ENDPOINT");
+        env::remove_var("EVENT_EXPORT_CHUNK_SIZE");
+        env::remove_var("EVENT_EXPORT_NIGHTLY");
+        let config = EventExportConfig::from_env().unwrap();
+        assert_eq!(config.storage, ExportStorage::Local(PathBuf::from("exports")));
+        assert_eq!(config.chunk_size, DEFAULT_EXPORT_CHUNK_SIZE);
+        assert!(config.nightly);
+    }
+}
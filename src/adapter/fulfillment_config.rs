@@ -0,0 +1,82 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::fulfillment_mode::FulfillmentMode;
+use std::env;
+use std::time::Duration;
+
+/// 配送業者のシミュレーターが配達の結果を通知するまでの時間のデフォルト値（ミリ秒）
+const DEFAULT_CARRIER_DELIVERY_DELAY_MS: u64 = 5000;
+
+/// 発送・配達の進め方の設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct FulfillmentConfig {
+    pub mode: FulfillmentMode,
+    /// auto / hybridで配送業者のシミュレーターが配達の結果を通知するまでの時間
+    pub carrier_delivery_delay: Duration,
+    /// auto / hybridで配送業者のシミュレーターが配達失敗を通知する確率（0.0〜1.0）
+    pub carrier_failure_rate: f64,
+}
+
+impl FulfillmentConfig {
+    /// 環境変数から設定を読み取る
+    /// - FULFILLMENT_MODE: 発送・配達の進め方（manual / auto / hybrid、デフォルト: manual）
+    /// - CARRIER_SIMULATION_DELAY_MS: 配送業者のシミュレーターが配達の結果を通知するまでの時間（デフォルト: 5000）
+    /// - CARRIER_SIMULATION_FAILURE_RATE: 配送業者のシミュレーターが配達失敗を通知する確率（デフォルト: 0.0）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let mode = match env::var("FULFILLMENT_MODE") {
+            Ok(value) => FulfillmentMode::from_string(value.trim()).map_err(|_| {
+                ConfigError::InvalidValue(format!("Invalid FULFILLMENT_MODE: {}", value))
+            })?,
+            Err(_) => FulfillmentMode::default(),
+        };
+        let carrier_delivery_delay = Duration::from_millis(parse_env(
+            "CARRIER_SIMULATION_DELAY_MS",
+            DEFAULT_CARRIER_DELIVERY_DELAY_MS,
+        )?);
+        let carrier_failure_rate: f64 = parse_env("CARRIER_SIMULATION_FAILURE_RATE", 0.0)?;
+        if !(0.0..=1.0).contains(&carrier_failure_rate) {
+            return Err(ConfigError::InvalidValue(
+                "CARRIER_SIMULATION_FAILURE_RATE must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            mode,
+            carrier_delivery_delay,
+            carrier_failure_rate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env_parses_fulfillment_mode_and_carrier_simulation() {
+        env::set_var("FULFILLMENT_MODE", "hybrid");
+        env::set_var("CARRIER_SIMULATION_DELAY_MS", "250");
+        env::set_var("CARRIER_SIMULATION_FAILURE_RATE", "0.2");
+        let config = FulfillmentConfig::from_env().unwrap();
+        assert_eq!(config.mode, FulfillmentMode::Hybrid);
+        assert_eq!(config.carrier_delivery_delay, Duration::from_millis(250));
+        assert_eq!(config.carrier_failure_rate, 0.2);
+
+        env::set_var("CARRIER_SIMULATION_FAILURE_RATE", "1.5");
+        assert!(FulfillmentConfig::from_env().is_err());
+
+        env::set_var("FULFILLMENT_MODE", "automatic");
+        env::remove_var("CARRIER_SIMULATION_FAILURE_RATE");
+        assert!(FulfillmentConfig::from_env().is_err());
+
+        env::remove_var("FULFILLMENT_MODE");
+        env::remove_var("CARRIER_SIMULATION_DELAY_MS");
+        let config = FulfillmentConfig::from_env().unwrap();
+        assert_eq!(config.mode, FulfillmentMode::Manual);
+        assert_eq!(
+            config.carrier_delivery_delay,
+            Duration::from_millis(DEFAULT_CARRIER_DELIVERY_DELAY_MS)
+        );
+        assert_eq!(config.carrier_failure_rate, 0.0);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+
+/// gRPC APIの既定のポート
+const DEFAULT_GRPC_PORT: u16 = 50051;
+
+/// gRPC APIの設定を管理する構造体
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrpcConfig {
+    /// REST APIと並行してgRPCサーバーを起動するか
+    pub enabled: bool,
+    /// 待ち受けるポート
+    pub port: u16,
+}
+
+impl Default for GrpcConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_GRPC_PORT,
+        }
+    }
+}
+
+impl GrpcConfig {
+    /// 環境変数から設定を読み取る
+    /// - GRPC_ENABLED: gRPCサーバーを起動するか（デフォルト: false）
+    /// - GRPC_PORT: 待ち受けるポート（デフォルト: 50051）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            enabled: parse_env("GRPC_ENABLED", false)?,
+            port: parse_env("GRPC_PORT", DEFAULT_GRPC_PORT)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_env_reads_flag_and_port() {
+        env::set_var("GRPC_ENABLED", "true");
+        env::set_var("GRPC_PORT", "50061");
+        assert_eq!(
+            GrpcConfig::from_env().unwrap(),
+            GrpcConfig {
+                enabled: true,
+                port: 50061
+            }
+        );
+
+        env::set_var("GRPC_PORT", "not-a-port");
+        assert!(GrpcConfig::from_env().is_err());
+
+        env::remove_var("GRPC_ENABLED");
+        env::remove_var("GRPC_PORT");
+        assert_eq!(GrpcConfig::from_env().unwrap(), GrpcConfig::default());
+    }
+}
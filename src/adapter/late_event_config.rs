@@ -0,0 +1,94 @@
+use crate::adapter::database_config::ConfigError;
+use crate::domain::late_event::{LateEventPolicies, LateEventPolicy, LATE_EVENT_HANDLERS};
+use std::collections::HashMap;
+use std::env;
+
+/// 遅延イベントポリシーの設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct LateEventConfig {
+    pub policies: LateEventPolicies,
+}
+
+/// ポリシー名を解析する
+fn parse_policy(name: &str, value: &str) -> Result<LateEventPolicy, ConfigError> {
+    LateEventPolicy::from_string(value.trim())
+        .map_err(|_| ConfigError::InvalidValue(format!("Invalid {}: {}", name, value)))
+}
+
+impl LateEventConfig {
+    /// 環境変数から設定を読み取る
+    /// - LATE_EVENT_POLICY: 既定のポリシー（skip / park / apply_if_compatible、デフォルト: skip）
+    /// - LATE_EVENT_POLICY_OVERRIDES: ハンドラーごとのポリシー（例: "FulfillmentRouter=apply_if_compatible,InventoryReservationHandler=park"）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let default_policy = match env::var("LATE_EVENT_POLICY") {
+            Ok(value) => parse_policy("LATE_EVENT_POLICY", &value)?,
+            Err(_) => LateEventPolicy::default(),
+        };
+
+        let mut overrides = HashMap::new();
+        if let Ok(value) = env::var("LATE_EVENT_POLICY_OVERRIDES") {
+            for entry in value.split(',').filter(|entry| !entry.trim().is_empty()) {
+                let (handler, policy) = entry.split_once('=').ok_or_else(|| {
+                    ConfigError::InvalidValue(format!(
+                        "Invalid LATE_EVENT_POLICY_OVERRIDES entry: {}",
+                        entry
+                    ))
+                })?;
+                let handler = handler.trim();
+                if !LATE_EVENT_HANDLERS.contains(&handler) {
+                    return Err(ConfigError::InvalidValue(format!(
+                        "Unknown handler in LATE_EVENT_POLICY_OVERRIDES: {}",
+                        handler
+                    )));
+                }
+                overrides.insert(
+                    handler.to_string(),
+                    parse_policy("LATE_EVENT_POLICY_OVERRIDES", policy)?,
+                );
+            }
+        }
+
+        Ok(Self {
+            policies: LateEventPolicies {
+                default_policy,
+                overrides,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env_overrides_and_validation() {
+        env::set_var("LATE_EVENT_POLICY", "park");
+        env::set_var(
+            "LATE_EVENT_POLICY_OVERRIDES",
+            "FulfillmentRouter=apply_if_compatible, ShippingHandler=skip",
+        );
+        let config = LateEventConfig::from_env().unwrap();
+        assert_eq!(
+            config.policies.policy_for("InventoryReservationHandler"),
+            LateEventPolicy::Park
+        );
+        assert_eq!(
+            config.policies.policy_for("FulfillmentRouter"),
+            LateEventPolicy::ApplyIfCompatible
+        );
+        assert_eq!(
+            config.policies.policy_for("ShippingHandler"),
+            LateEventPolicy::Skip
+        );
+
+        env::set_var("LATE_EVENT_POLICY_OVERRIDES", "UnknownHandler=park");
+        assert!(LateEventConfig::from_env().is_err());
+
+        env::set_var("LATE_EVENT_POLICY_OVERRIDES", "ShippingHandler=drop");
+        assert!(LateEventConfig::from_env().is_err());
+
+        env::remove_var("LATE_EVENT_POLICY");
+        env::remove_var("LATE_EVENT_POLICY_OVERRIDES");
+    }
+}
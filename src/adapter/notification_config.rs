@@ -0,0 +1,124 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::notification_retry::{ChannelRetryPolicy, NotificationRetryPolicy};
+use chrono::TimeDelta;
+use std::time::Duration;
+
+/// 再送待ちの通知を確認する間隔のデフォルト値（秒）
+const DEFAULT_RETRY_INTERVAL_SECONDS: u64 = 30;
+
+/// 通知の送信と再送の設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct NotificationConfig {
+    /// チャネルごとの再送ポリシー
+    pub retry_policy: NotificationRetryPolicy,
+    /// 再送待ちの通知を確認するジョブの実行間隔
+    pub retry_interval: Duration,
+    /// 通知の送信のシミュレーターが送信失敗を返す確率（0.0〜1.0）
+    pub failure_rate: f64,
+}
+
+/// チャネルの再送の設定を環境変数から読み取る（NOTIFICATION_{prefix}_MAX_ATTEMPTS・NOTIFICATION_{prefix}_BACKOFF_SECONDS）
+fn channel_policy(
+    prefix: &str,
+    defaults: &ChannelRetryPolicy,
+    max_backoff: TimeDelta,
+) -> Result<ChannelRetryPolicy, ConfigError> {
+    let max_attempts_name = format!("NOTIFICATION_{}_MAX_ATTEMPTS", prefix);
+    let backoff_name = format!("NOTIFICATION_{}_BACKOFF_SECONDS", prefix);
+    let max_attempts: u32 = parse_env(&max_attempts_name, defaults.max_attempts)?;
+    let backoff_seconds: i64 = parse_env(&backoff_name, defaults.initial_backoff.num_seconds())?;
+    if max_attempts == 0 {
+        return Err(ConfigError::InvalidValue(format!(
+            "{} must be greater than 0",
+            max_attempts_name
+        )));
+    }
+    if backoff_seconds <= 0 {
+        return Err(ConfigError::InvalidValue(format!(
+            "{} must be greater than 0",
+            backoff_name
+        )));
+    }
+
+    Ok(ChannelRetryPolicy {
+        max_attempts,
+        initial_backoff: TimeDelta::seconds(backoff_seconds),
+        max_backoff,
+    })
+}
+
+impl NotificationConfig {
+    /// 環境変数から設定を読み取る
+    /// - NOTIFICATION_EMAIL_MAX_ATTEMPTS / NOTIFICATION_SMS_MAX_ATTEMPTS: 送信をあきらめるまでの試行回数（デフォルト: 5 / 3）
+    /// - NOTIFICATION_EMAIL_BACKOFF_SECONDS / NOTIFICATION_SMS_BACKOFF_SECONDS: 最初の再送までの待ち時間（秒、失敗するたびに倍にする。デフォルト: 60 / 300）
+    /// - NOTIFICATION_MAX_BACKOFF_SECONDS: 再送までの待ち時間の上限（秒、デフォルト: 3600）
+    /// - NOTIFICATION_RETRY_INTERVAL_SECONDS: 再送待ちの通知を確認する間隔（秒、デフォルト: 30）
+    /// - NOTIFICATION_SIMULATION_FAILURE_RATE: 通知の送信のシミュレーターが送信失敗を返す確率（デフォルト: 0.0）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let defaults = NotificationRetryPolicy::default();
+
+        let max_backoff_seconds: i64 = parse_env(
+            "NOTIFICATION_MAX_BACKOFF_SECONDS",
+            defaults.email.max_backoff.num_seconds(),
+        )?;
+        if max_backoff_seconds <= 0 {
+            return Err(ConfigError::InvalidValue(
+                "NOTIFICATION_MAX_BACKOFF_SECONDS must be greater than 0".to_string(),
+            ));
+        }
+        let max_backoff = TimeDelta::seconds(max_backoff_seconds);
+
+        let retry_interval = Duration::from_secs(parse_env(
+            "NOTIFICATION_RETRY_INTERVAL_SECONDS",
+            DEFAULT_RETRY_INTERVAL_SECONDS,
+        )?);
+        if retry_interval.is_zero() {
+            return Err(ConfigError::InvalidValue(
+                "NOTIFICATION_RETRY_INTERVAL_SECONDS must be greater than 0".to_string(),
+            ));
+        }
+
+        let failure_rate: f64 = parse_env("NOTIFICATION_SIMULATION_FAILURE_RATE", 0.0)?;
+        if !(0.0..=1.0).contains(&failure_rate) {
+            return Err(ConfigError::InvalidValue(
+                "NOTIFICATION_SIMULATION_FAILURE_RATE must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            retry_policy: NotificationRetryPolicy {
+                email: channel_policy("EMAIL", &defaults.email, max_backoff)?,
+                sms: channel_policy("SMS", &defaults.sms, max_backoff)?,
+            },
+            retry_interval,
+            failure_rate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_env_parses_channel_policies() {
+        env::set_var("NOTIFICATION_SMS_MAX_ATTEMPTS", "2");
+        env::set_var("NOTIFICATION_EMAIL_BACKOFF_SECONDS", "10");
+        let config = NotificationConfig::from_env().unwrap();
+        assert_eq!(config.retry_policy.sms.max_attempts, 2);
+        assert_eq!(config.retry_policy.email.max_attempts, 5);
+        assert_eq!(
+            config.retry_policy.email.initial_backoff,
+            TimeDelta::seconds(10)
+        );
+        assert_eq!(config.retry_interval, Duration::from_secs(30));
+
+        env::set_var("NOTIFICATION_SMS_MAX_ATTEMPTS", "0");
+        assert!(NotificationConfig::from_env().is_err());
+
+        env::remove_var("NOTIFICATION_SMS_MAX_ATTEMPTS");
+        env::remove_var("NOTIFICATION_EMAIL_BACKOFF_SECONDS");
+    }
+}
@@ -0,0 +1,71 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::model::OrderNumber;
+use std::env;
+
+/// 連番の最小桁数の範囲
+const DIGITS_RANGE: std::ops::RangeInclusive<usize> = 4..=10;
+
+/// 注文番号の採番設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct OrderNumberConfig {
+    /// 注文番号の接頭辞
+    pub prefix: String,
+    /// 連番の最小桁数（0で埋める）
+    pub digits: usize,
+}
+
+impl OrderNumberConfig {
+    /// 環境変数から設定を読み取る
+    /// - ORDER_NUMBER_PREFIX: 注文番号の接頭辞（英大文字1〜8文字、デフォルト: BK）
+    /// - ORDER_NUMBER_DIGITS: 連番の最小桁数（4〜10、デフォルト: 6）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let prefix = env::var("ORDER_NUMBER_PREFIX").unwrap_or_else(|_| "BK".to_string());
+        if !OrderNumber::is_valid_prefix(&prefix) {
+            return Err(ConfigError::InvalidValue(format!(
+                "ORDER_NUMBER_PREFIX must be 1 to {} uppercase letters: {}",
+                OrderNumber::MAX_PREFIX_LENGTH,
+                prefix
+            )));
+        }
+
+        let digits: usize = parse_env("ORDER_NUMBER_DIGITS", 6)?;
+        if !DIGITS_RANGE.contains(&digits) {
+            return Err(ConfigError::InvalidValue(format!(
+                "ORDER_NUMBER_DIGITS must be between {} and {}",
+                DIGITS_RANGE.start(),
+                DIGITS_RANGE.end()
+            )));
+        }
+
+        Ok(Self { prefix, digits })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env_validates_prefix_and_digits() {
+        env::remove_var("ORDER_NUMBER_PREFIX");
+        env::remove_var("ORDER_NUMBER_DIGITS");
+        let config = OrderNumberConfig::from_env().unwrap();
+        assert_eq!(config.prefix, "BK");
+        assert_eq!(config.digits, 6);
+
+        env::set_var("ORDER_NUMBER_PREFIX", "bk");
+        assert!(OrderNumberConfig::from_env().is_err());
+
+        env::set_var("ORDER_NUMBER_PREFIX", "SHOP");
+        env::set_var("ORDER_NUMBER_DIGITS", "3");
+        assert!(OrderNumberConfig::from_env().is_err());
+
+        env::set_var("ORDER_NUMBER_DIGITS", "8");
+        let config = OrderNumberConfig::from_env().unwrap();
+        assert_eq!((config.prefix.as_str(), config.digits), ("SHOP", 8));
+
+        env::remove_var("ORDER_NUMBER_PREFIX");
+        env::remove_var("ORDER_NUMBER_DIGITS");
+    }
+}
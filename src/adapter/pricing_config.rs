@@ -0,0 +1,49 @@
+use crate::adapter::database_config::ConfigError;
+use crate::domain::pricing::PriceChangePolicy;
+use std::env;
+
+/// 確定時の価格チェックの設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct PricingConfig {
+    pub price_change_policy: PriceChangePolicy,
+}
+
+impl PricingConfig {
+    /// 環境変数から設定を読み取る
+    /// - PRICE_CHANGE_POLICY: 確定時にカタログの価格が変更されていた場合の扱い（reject / reprice、デフォルト: reject）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let price_change_policy = match env::var("PRICE_CHANGE_POLICY") {
+            Ok(value) => PriceChangePolicy::from_string(value.trim()).map_err(|_| {
+                ConfigError::InvalidValue(format!("Invalid PRICE_CHANGE_POLICY: {}", value))
+            })?,
+            Err(_) => PriceChangePolicy::default(),
+        };
+
+        Ok(Self {
+            price_change_policy,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env_parses_price_change_policy() {
+        env::set_var("PRICE_CHANGE_POLICY", "reprice");
+        assert_eq!(
+            PricingConfig::from_env().unwrap().price_change_policy,
+            PriceChangePolicy::Reprice
+        );
+
+        env::set_var("PRICE_CHANGE_POLICY", "ignore");
+        assert!(PricingConfig::from_env().is_err());
+
+        env::remove_var("PRICE_CHANGE_POLICY");
+        assert_eq!(
+            PricingConfig::from_env().unwrap().price_change_policy,
+            PriceChangePolicy::Reject
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::purchase_policy::{PurchaseLimits, PurchasePolicy};
+
+/// 転売目的の大量購入を防ぐ購入制限の設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct PurchaseLimitConfig {
+    pub policy: PurchasePolicy,
+}
+
+impl PurchaseLimitConfig {
+    /// 環境変数から設定を読み取る
+    /// - PURCHASE_MAX_QUANTITY_PER_BOOK_PER_DAY: 1顧客が同じ書籍を直近24時間に購入できる最大数量（デフォルト: 0 = 制限しない）
+    /// - PURCHASE_MAX_OPEN_ORDERS_PER_CUSTOMER: 1顧客が同時に保持できる未完了の注文数（デフォルト: 0 = 制限しない）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let max_quantity: u32 = parse_env("PURCHASE_MAX_QUANTITY_PER_BOOK_PER_DAY", 0)?;
+        let max_open_orders: u32 = parse_env("PURCHASE_MAX_OPEN_ORDERS_PER_CUSTOMER", 0)?;
+
+        Ok(Self {
+            policy: PurchasePolicy::new(PurchaseLimits {
+                max_quantity_per_book_per_day: (max_quantity > 0).then_some(max_quantity),
+                max_open_orders_per_customer: (max_open_orders > 0).then_some(max_open_orders),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_env_parses_purchase_limits() {
+        env::set_var("PURCHASE_MAX_QUANTITY_PER_BOOK_PER_DAY", "10");
+        env::set_var("PURCHASE_MAX_OPEN_ORDERS_PER_CUSTOMER", "5");
+        assert_eq!(
+            PurchaseLimitConfig::from_env().unwrap().policy.limits(),
+            PurchaseLimits {
+                max_quantity_per_book_per_day: Some(10),
+                max_open_orders_per_customer: Some(5),
+            }
+        );
+
+        env::set_var("PURCHASE_MAX_OPEN_ORDERS_PER_CUSTOMER", "-1");
+        assert!(PurchaseLimitConfig::from_env().is_err());
+
+        env::remove_var("PURCHASE_MAX_QUANTITY_PER_BOOK_PER_DAY");
+        env::remove_var("PURCHASE_MAX_OPEN_ORDERS_PER_CUSTOMER");
+        assert_eq!(
+            PurchaseLimitConfig::from_env().unwrap().policy.limits(),
+            PurchaseLimits::default()
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use crate::adapter::driven::{
+    InMemoryActionLinkAuditRepository, InMemoryAllocationQuotaRepository,
+    InMemoryCheckoutHoldRepository, InMemoryCustomerRepository, InMemoryCycleCountRepository,
+    InMemoryDeviceRegistrationRepository, InMemoryEventJournal,
+    InMemoryFailedNotificationRepository, InMemoryInboxRepository,
+    InMemoryLegacyImportRepository, InMemoryOrderLockRepository, InMemoryOrderNoteRepository,
+    InMemoryOrderNumberGenerator, InMemoryParkedEventRepository,
+    InMemoryPendingOperationRepository, InMemoryProcessedEventRepository,
+    InMemorySagaCompensationRepository, InMemoryStatusOverrideAuditRepository,
+    InMemorySubscriptionRegistryRepository, InMemoryTrackingEventRepository,
+    InMemoryWaitlistRepository, InMemoryWebhookDeliveryRepository,
+    InMemoryWebhookSubscriptionRepository, MySqlActionLinkAuditRepository,
+    MySqlAllocationQuotaRepository, MySqlBookCatalog, MySqlCheckoutHoldRepository,
+    MySqlCustomerRepository, MySqlCycleCountRepository, MySqlDeviceRegistrationRepository,
+    MySqlEventJournal, MySqlFailedNotificationRepository, MySqlInboxRepository,
+    MySqlInventoryRepository, MySqlLegacyImportRepository, MySqlOrderLockRepository,
+    MySqlOrderNoteRepository, MySqlOrderNumberGenerator, MySqlOrderRepository,
+    MySqlParkedEventRepository, MySqlPendingOperationRepository, MySqlProcessedEventRepository,
+    MySqlSagaCompensationRepository, MySqlStatusOverrideAuditRepository,
+    MySqlSubscriptionRegistryRepository, MySqlTrackingEventRepository, MySqlWaitlistRepository,
+    MySqlWebhookDeliveryRepository, MySqlWebhookSubscriptionRepository, SqliteBookCatalog,
+    SqliteInventoryRepository, SqliteOrderRepository,
+};
+use crate::adapter::database_config::ConfigError;
+use crate::adapter::OrderNumberConfig;
+use crate::domain::port::{
+    ActionLinkAuditRepository, AllocationQuotaRepository, BookCatalog, CheckoutHoldRepository,
+    CustomerRepository, CycleCountRepository, DeviceRegistrationRepository, EventJournal,
+    FailedNotificationRepository, InboxRepository, InventoryRepository, LegacyImportRepository,
+    OrderLockRepository, OrderNoteRepository, OrderNumberGenerator, OrderRepository,
+    ParkedEventRepository, PendingOperationRepository, ProcessedEventRepository,
+    SagaCompensationRepository, StatusOverrideAuditRepository, SubscriptionRegistryRepository,
+    TrackingEventRepository, WaitlistRepository, WebhookDeliveryRepository,
+    WebhookSubscriptionRepository,
+};
+use sqlx::{MySql, Pool, Sqlite};
+use std::sync::Arc;
+
+/// SQLiteモードのデフォルトの接続先（プロセスを終了するとデータも消える）
+pub const DEFAULT_SQLITE_URL: &str = "sqlite::memory:";
+
+/// 起動時に選ぶ永続化先
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageBackend {
+    /// DATABASE_*で指定したMySQL（デフォルト）
+    MySql,
+    /// 外部のデータベースを用意せずに動かすSQLite（`--sqlite` または `--sqlite=<url>`）
+    Sqlite { url: String },
+}
+
+impl StorageBackend {
+    /// コマンドライン引数から永続化先を決める
+    ///
+    /// # Arguments
+    /// * `args` - プログラム名を除いたコマンドライン引数
+    ///
+    /// # Returns
+    /// * `Ok(StorageBackend)` - 選んだ永続化先
+    /// * `Err(ConfigError)` - 知らない引数、または空の接続先が指定された
+    pub fn from_args<I>(args: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut backend = StorageBackend::MySql;
+        for arg in args {
+            backend = match arg.as_str() {
+                "--sqlite" => StorageBackend::Sqlite {
+                    url: DEFAULT_SQLITE_URL.to_string(),
+                },
+                _ => match arg.strip_prefix("--sqlite=") {
+                    Some(url) if !url.is_empty() => StorageBackend::Sqlite {
+                        url: url.to_string(),
+                    },
+                    _ => {
+                        return Err(ConfigError::InvalidValue(format!(
+                            "unknown argument: {} (usage: [--sqlite[=<url>]])",
+                            arg
+                        )))
+                    }
+                },
+            };
+        }
+        Ok(backend)
+    }
+}
+
+/// アプリケーションが使うリポジトリ一式
+/// 起動時に選んだ永続化先（MySQL・SQLite）の実装をポートとして束ね、サービス・ハンドラーの組み立てに渡す
+#[derive(Clone)]
+pub struct Repositories {
+    pub orders: Arc<dyn OrderRepository>,
+    pub inventory: Arc<dyn InventoryRepository>,
+    pub book_catalog: Arc<dyn BookCatalog>,
+    pub order_numbers: Arc<dyn OrderNumberGenerator>,
+    pub customers: Arc<dyn CustomerRepository>,
+    pub tracking_events: Arc<dyn TrackingEventRepository>,
+    pub parked_events: Arc<dyn ParkedEventRepository>,
+    pub event_journal: Arc<dyn EventJournal>,
+    pub waitlist: Arc<dyn WaitlistRepository>,
+    pub checkout_holds: Arc<dyn CheckoutHoldRepository>,
+    pub allocation_quotas: Arc<dyn AllocationQuotaRepository>,
+    pub processed_events: Arc<dyn ProcessedEventRepository>,
+    pub failed_notifications: Arc<dyn FailedNotificationRepository>,
+    pub webhook_subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+    pub webhook_deliveries: Arc<dyn WebhookDeliveryRepository>,
+    pub saga_compensations: Arc<dyn SagaCompensationRepository>,
+    pub pending_operations: Arc<dyn PendingOperationRepository>,
+    pub status_override_audits: Arc<dyn StatusOverrideAuditRepository>,
+    pub action_link_audits: Arc<dyn ActionLinkAuditRepository>,
+    pub cycle_counts: Arc<dyn CycleCountRepository>,
+    pub device_registrations: Arc<dyn DeviceRegistrationRepository>,
+    pub inbox: Arc<dyn InboxRepository>,
+    pub legacy_imports: Arc<dyn LegacyImportRepository>,
+    pub order_notes: Arc<dyn OrderNoteRepository>,
+    pub order_locks: Arc<dyn OrderLockRepository>,
+    pub subscription_registry: Arc<dyn SubscriptionRegistryRepository>,
+}
+
+impl Repositories {
+    /// すべてのリポジトリをMySQLで作成する
+    ///
+    /// # Arguments
+    /// * `pool` - マイグレーションを適用したMySQLの接続プール
+    /// * `invariant_checks` - 注文・在庫の保存・読み込み時に集約の不変条件を検証するかどうか
+    /// * `order_number_config` - 注文番号の接頭辞と連番の桁数
+    pub fn mysql(
+        pool: Pool<MySql>,
+        invariant_checks: bool,
+        order_number_config: OrderNumberConfig,
+    ) -> Self {
+        Self {
+            orders: Arc::new(
+                MySqlOrderRepository::new(pool.clone()).with_invariant_checks(invariant_checks),
+            ),
+            inventory: Arc::new(
+                MySqlInventoryRepository::new(pool.clone())
+                    .with_invariant_checks(invariant_checks),
+            ),
+            book_catalog: Arc::new(MySqlBookCatalog::new(pool.clone())),
+            order_numbers: Arc::new(MySqlOrderNumberGenerator::new(
+                pool.clone(),
+                order_number_config.prefix,
+                order_number_config.digits,
+            )),
+            customers: Arc::new(MySqlCustomerRepository::new(pool.clone())),
+            tracking_events: Arc::new(MySqlTrackingEventRepository::new(pool.clone())),
+            parked_events: Arc::new(MySqlParkedEventRepository::new(pool.clone())),
+            event_journal: Arc::new(MySqlEventJournal::new(pool.clone())),
+            waitlist: Arc::new(MySqlWaitlistRepository::new(pool.clone())),
+            checkout_holds: Arc::new(MySqlCheckoutHoldRepository::new(pool.clone())),
+            allocation_quotas: Arc::new(MySqlAllocationQuotaRepository::new(pool.clone())),
+            processed_events: Arc::new(MySqlProcessedEventRepository::new(pool.clone())),
+            failed_notifications: Arc::new(MySqlFailedNotificationRepository::new(pool.clone())),
+            webhook_subscriptions: Arc::new(MySqlWebhookSubscriptionRepository::new(pool.clone())),
+            webhook_deliveries: Arc::new(MySqlWebhookDeliveryRepository::new(pool.clone())),
+            saga_compensations: Arc::new(MySqlSagaCompensationRepository::new(pool.clone())),
+            pending_operations: Arc::new(MySqlPendingOperationRepository::new(pool.clone())),
+            status_override_audits: Arc::new(MySqlStatusOverrideAuditRepository::new(
+                pool.clone(),
+            )),
+            action_link_audits: Arc::new(MySqlActionLinkAuditRepository::new(pool.clone())),
+            cycle_counts: Arc::new(MySqlCycleCountRepository::new(pool.clone())),
+            device_registrations: Arc::new(MySqlDeviceRegistrationRepository::new(pool.clone())),
+            inbox: Arc::new(MySqlInboxRepository::new(pool.clone())),
+            legacy_imports: Arc::new(MySqlLegacyImportRepository::new(pool.clone())),
+            order_notes: Arc::new(MySqlOrderNoteRepository::new(pool.clone())),
+            order_locks: Arc::new(MySqlOrderLockRepository::new(pool.clone())),
+            subscription_registry: Arc::new(MySqlSubscriptionRegistryRepository::new(pool)),
+        }
+    }
+
+    /// 注文・在庫・書籍カタログをSQLiteで、それ以外をプロセス内のメモリで作成する
+    /// 外部のデータベースを用意せずに動かすデモ・テスト用（メモリに置いた内容は再起動で失われる）
+    ///
+    /// # Arguments
+    /// * `pool` - SqliteDatabaseで作成したSQLiteの接続プール
+    /// * `invariant_checks` - 注文・在庫の保存・読み込み時に集約の不変条件を検証するかどうか
+    /// * `order_number_config` - 注文番号の接頭辞と連番の桁数
+    pub fn sqlite(
+        pool: Pool<Sqlite>,
+        invariant_checks: bool,
+        order_number_config: OrderNumberConfig,
+    ) -> Self {
+        Self {
+            orders: Arc::new(
+                SqliteOrderRepository::new(pool.clone()).with_invariant_checks(invariant_checks),
+            ),
+            inventory: Arc::new(
+                SqliteInventoryRepository::new(pool.clone())
+                    .with_invariant_checks(invariant_checks),
+            ),
+            book_catalog: Arc::new(SqliteBookCatalog::new(pool)),
+            order_numbers: Arc::new(InMemoryOrderNumberGenerator::new(
+                order_number_config.prefix,
+                order_number_config.digits,
+            )),
+            customers: Arc::new(InMemoryCustomerRepository::new()),
+            tracking_events: Arc::new(InMemoryTrackingEventRepository::new()),
+            parked_events: Arc::new(InMemoryParkedEventRepository::new()),
+            event_journal: Arc::new(InMemoryEventJournal::new()),
+            waitlist: Arc::new(InMemoryWaitlistRepository::new()),
+            checkout_holds: Arc::new(InMemoryCheckoutHoldRepository::new()),
+            allocation_quotas: Arc::new(InMemoryAllocationQuotaRepository::new()),
+            processed_events: Arc::new(InMemoryProcessedEventRepository::new()),
+            failed_notifications: Arc::new(InMemoryFailedNotificationRepository::new()),
+            webhook_subscriptions: Arc::new(InMemoryWebhookSubscriptionRepository::new()),
+            webhook_deliveries: Arc::new(InMemoryWebhookDeliveryRepository::new()),
+            saga_compensations: Arc::new(InMemorySagaCompensationRepository::new()),
+            pending_operations: Arc::new(InMemoryPendingOperationRepository::new()),
+            status_override_audits: Arc::new(InMemoryStatusOverrideAuditRepository::new()),
+            action_link_audits: Arc::new(InMemoryActionLinkAuditRepository::new()),
+            cycle_counts: Arc::new(InMemoryCycleCountRepository::new()),
+            device_registrations: Arc::new(InMemoryDeviceRegistrationRepository::new()),
+            inbox: Arc::new(InMemoryInboxRepository::new()),
+            legacy_imports: Arc::new(InMemoryLegacyImportRepository::new()),
+            order_notes: Arc::new(InMemoryOrderNoteRepository::new()),
+            order_locks: Arc::new(InMemoryOrderLockRepository::new()),
+            subscription_registry: Arc::new(InMemorySubscriptionRegistryRepository::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_storage_backend_from_args() {
+        assert_eq!(
+            StorageBackend::from_args(args(&[])).unwrap(),
+            StorageBackend::MySql
+        );
+        assert_eq!(
+            StorageBackend::from_args(args(&["--sqlite"])).unwrap(),
+            StorageBackend::Sqlite {
+                url: DEFAULT_SQLITE_URL.to_string()
+            }
+        );
+        assert_eq!(
+            StorageBackend::from_args(args(&["--sqlite=sqlite://bookstore.db"])).unwrap(),
+            StorageBackend::Sqlite {
+                url: "sqlite://bookstore.db".to_string()
+            }
+        );
+        assert!(StorageBackend::from_args(args(&["--sqlite="])).is_err());
+        assert!(StorageBackend::from_args(args(&["--mysql"])).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::model::Money;
+
+/// 不正の疑いで注文を自動で保留にする設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct RiskHoldConfig {
+    /// 保留にする注文の合計金額の下限（Noneの場合は自動で保留にしない）
+    pub threshold: Option<Money>,
+}
+
+impl RiskHoldConfig {
+    /// 環境変数から設定を読み取る
+    /// - RISK_HOLD_THRESHOLD: 確定時に自動で保留にする注文の合計金額（円、デフォルト: 0 = 自動で保留にしない）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let threshold: i64 = parse_env("RISK_HOLD_THRESHOLD", 0)?;
+        if threshold < 0 {
+            return Err(ConfigError::InvalidValue(
+                "RISK_HOLD_THRESHOLD must be 0 or greater".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            threshold: (threshold > 0).then(|| Money::jpy(threshold)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_env_parses_threshold() {
+        env::set_var("RISK_HOLD_THRESHOLD", "50000");
+        assert_eq!(
+            RiskHoldConfig::from_env().unwrap().threshold,
+            Some(Money::jpy(50000))
+        );
+
+        env::set_var("RISK_HOLD_THRESHOLD", "-1");
+        assert!(RiskHoldConfig::from_env().is_err());
+
+        env::remove_var("RISK_HOLD_THRESHOLD");
+        assert_eq!(RiskHoldConfig::from_env().unwrap().threshold, None);
+    }
+}
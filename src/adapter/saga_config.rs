@@ -0,0 +1,156 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::saga_orchestrator::SagaMode;
+use crate::domain::saga_timeout::SagaTimeoutPolicy;
+use chrono::TimeDelta;
+use std::env;
+use std::time::Duration;
+
+/// オーケストレーションでステップの返信を待つ時間のデフォルト値（ミリ秒）
+const DEFAULT_SAGA_STEP_TIMEOUT_MS: u64 = 5000;
+/// 決済代行のシミュレーターが請求に応答するまでの時間のデフォルト値（ミリ秒）
+const DEFAULT_PAYMENT_SIMULATION_DELAY_MS: u64 = 100;
+/// 確定から在庫予約までの期限のデフォルト値（分）
+const DEFAULT_SAGA_RESERVATION_TIMEOUT_MINUTES: i64 = 30;
+/// 発送から配達完了までの期限のデフォルト値（日）
+const DEFAULT_SAGA_DELIVERY_TIMEOUT_DAYS: i64 = 7;
+/// サーガのタイムアウトを確認する間隔のデフォルト値（秒）
+const DEFAULT_SAGA_TIMEOUT_CHECK_INTERVAL_SECONDS: u64 = 300;
+
+/// 注文確定後のサーガの設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct SagaConfig {
+    pub mode: SagaMode,
+    /// orchestrationでステップの返信を待つ時間
+    pub step_timeout: Duration,
+    /// orchestrationで決済代行のシミュレーターが請求に応答するまでの時間
+    pub payment_delay: Duration,
+    /// orchestrationで決済代行のシミュレーターが請求を拒否する確率（0.0〜1.0）
+    pub payment_failure_rate: f64,
+    /// 停滞したサーガをタイムアウトとみなすまでの期限
+    pub timeout_policy: SagaTimeoutPolicy,
+    /// サーガのタイムアウトを確認する間隔
+    pub timeout_check_interval: Duration,
+}
+
+impl SagaConfig {
+    /// 環境変数から設定を読み取る
+    /// - SAGA_MODE: サーガの進め方（choreography / orchestration、デフォルト: choreography）
+    /// - SAGA_STEP_TIMEOUT_MS: ステップの返信を待つ時間（デフォルト: 5000）
+    /// - PAYMENT_SIMULATION_DELAY_MS: 決済代行のシミュレーターが請求に応答するまでの時間（デフォルト: 100）
+    /// - PAYMENT_SIMULATION_FAILURE_RATE: 決済代行のシミュレーターが請求を拒否する確率（デフォルト: 0.0）
+    /// - SAGA_RESERVATION_TIMEOUT_MINUTES: 確定から在庫予約までの期限（デフォルト: 30）
+    /// - SAGA_DELIVERY_TIMEOUT_DAYS: 発送から配達完了までの期限（デフォルト: 7）
+    /// - SAGA_TIMEOUT_CHECK_INTERVAL_SECONDS: タイムアウトを確認する間隔（デフォルト: 300）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let mode = match env::var("SAGA_MODE") {
+            Ok(value) => SagaMode::from_string(value.trim())
+                .map_err(|_| ConfigError::InvalidValue(format!("Invalid SAGA_MODE: {}", value)))?,
+            Err(_) => SagaMode::default(),
+        };
+        let step_timeout_ms: u64 = parse_env("SAGA_STEP_TIMEOUT_MS", DEFAULT_SAGA_STEP_TIMEOUT_MS)?;
+        if step_timeout_ms == 0 {
+            return Err(ConfigError::InvalidValue(
+                "SAGA_STEP_TIMEOUT_MS must be greater than 0".to_string(),
+            ));
+        }
+        let payment_delay = Duration::from_millis(parse_env(
+            "PAYMENT_SIMULATION_DELAY_MS",
+            DEFAULT_PAYMENT_SIMULATION_DELAY_MS,
+        )?);
+        let payment_failure_rate: f64 = parse_env("PAYMENT_SIMULATION_FAILURE_RATE", 0.0)?;
+        if !(0.0..=1.0).contains(&payment_failure_rate) {
+            return Err(ConfigError::InvalidValue(
+                "PAYMENT_SIMULATION_FAILURE_RATE must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+
+        let reservation_timeout_minutes: i64 = parse_env(
+            "SAGA_RESERVATION_TIMEOUT_MINUTES",
+            DEFAULT_SAGA_RESERVATION_TIMEOUT_MINUTES,
+        )?;
+        let delivery_timeout_days: i64 = parse_env(
+            "SAGA_DELIVERY_TIMEOUT_DAYS",
+            DEFAULT_SAGA_DELIVERY_TIMEOUT_DAYS,
+        )?;
+        if reservation_timeout_minutes <= 0 || delivery_timeout_days <= 0 {
+            return Err(ConfigError::InvalidValue(
+                "SAGA_RESERVATION_TIMEOUT_MINUTES and SAGA_DELIVERY_TIMEOUT_DAYS must be greater than 0"
+                    .to_string(),
+            ));
+        }
+        let timeout_check_interval_seconds: u64 = parse_env(
+            "SAGA_TIMEOUT_CHECK_INTERVAL_SECONDS",
+            DEFAULT_SAGA_TIMEOUT_CHECK_INTERVAL_SECONDS,
+        )?;
+        if timeout_check_interval_seconds == 0 {
+            return Err(ConfigError::InvalidValue(
+                "SAGA_TIMEOUT_CHECK_INTERVAL_SECONDS must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            mode,
+            step_timeout: Duration::from_millis(step_timeout_ms),
+            payment_delay,
+            payment_failure_rate,
+            timeout_policy: SagaTimeoutPolicy {
+                reserve_within: TimeDelta::minutes(reservation_timeout_minutes),
+                deliver_within: TimeDelta::days(delivery_timeout_days),
+            },
+            timeout_check_interval: Duration::from_secs(timeout_check_interval_seconds),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env_parses_saga_mode_and_payment_simulation() {
+        env::set_var("SAGA_MODE", "orchestration");
+        env::set_var("SAGA_STEP_TIMEOUT_MS", "250");
+        env::set_var("PAYMENT_SIMULATION_FAILURE_RATE", "0.3");
+        let config = SagaConfig::from_env().unwrap();
+        assert_eq!(config.mode, SagaMode::Orchestration);
+        assert_eq!(config.step_timeout, Duration::from_millis(250));
+        assert_eq!(config.payment_failure_rate, 0.3);
+
+        env::set_var("SAGA_STEP_TIMEOUT_MS", "0");
+        assert!(SagaConfig::from_env().is_err());
+        env::remove_var("SAGA_STEP_TIMEOUT_MS");
+
+        env::set_var("SAGA_RESERVATION_TIMEOUT_MINUTES", "15");
+        env::set_var("SAGA_DELIVERY_TIMEOUT_DAYS", "3");
+        let config = SagaConfig::from_env().unwrap();
+        assert_eq!(config.timeout_policy.reserve_within, TimeDelta::minutes(15));
+        assert_eq!(config.timeout_policy.deliver_within, TimeDelta::days(3));
+        env::set_var("SAGA_DELIVERY_TIMEOUT_DAYS", "0");
+        assert!(SagaConfig::from_env().is_err());
+        env::remove_var("SAGA_RESERVATION_TIMEOUT_MINUTES");
+        env::remove_var("SAGA_DELIVERY_TIMEOUT_DAYS");
+
+        env::set_var("SAGA_MODE", "orchestrated");
+        assert!(SagaConfig::from_env().is_err());
+
+        env::remove_var("SAGA_MODE");
+        env::remove_var("PAYMENT_SIMULATION_FAILURE_RATE");
+        let config = SagaConfig::from_env().unwrap();
+        assert_eq!(config.mode, SagaMode::Choreography);
+        assert_eq!(
+            config.step_timeout,
+            Duration::from_millis(DEFAULT_SAGA_STEP_TIMEOUT_MS)
+        );
+        assert_eq!(
+            config.payment_delay,
+            Duration::from_millis(DEFAULT_PAYMENT_SIMULATION_DELAY_MS)
+        );
+        assert_eq!(config.payment_failure_rate, 0.0);
+        assert_eq!(config.timeout_policy, SagaTimeoutPolicy::default());
+        assert_eq!(
+            config.timeout_check_interval,
+            Duration::from_secs(DEFAULT_SAGA_TIMEOUT_CHECK_INTERVAL_SECONDS)
+        );
+    }
+}
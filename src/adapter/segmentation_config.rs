@@ -0,0 +1,67 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::model::Money;
+use crate::domain::segmentation::{
+    SegmentationRules, DEFAULT_HIGH_VALUE_ORDER_AMOUNT, DEFAULT_REPEAT_BUYER_ORDER_COUNT,
+};
+
+/// 顧客セグメントのルールの設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct SegmentationConfig {
+    pub rules: SegmentationRules,
+}
+
+impl SegmentationConfig {
+    /// 環境変数から設定を読み取る
+    /// - SEGMENT_REPEAT_BUYER_ORDER_COUNT: リピーターとみなす配達完了した注文の数（デフォルト: 2、0の場合は判定しない）
+    /// - SEGMENT_HIGH_VALUE_ORDER_AMOUNT: 高額注文とみなす合計金額（円、デフォルト: 10000、0の場合は判定しない）
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let repeat_buyer_order_count: u32 = parse_env(
+            "SEGMENT_REPEAT_BUYER_ORDER_COUNT",
+            DEFAULT_REPEAT_BUYER_ORDER_COUNT,
+        )?;
+        let high_value_order_amount: i64 = parse_env(
+            "SEGMENT_HIGH_VALUE_ORDER_AMOUNT",
+            DEFAULT_HIGH_VALUE_ORDER_AMOUNT,
+        )?;
+        if high_value_order_amount < 0 {
+            return Err(ConfigError::InvalidValue(
+                "SEGMENT_HIGH_VALUE_ORDER_AMOUNT must be 0 or greater".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            rules: SegmentationRules {
+                repeat_buyer_order_count: (repeat_buyer_order_count > 0)
+                    .then_some(repeat_buyer_order_count),
+                high_value_order_amount: (high_value_order_amount > 0)
+                    .then(|| Money::jpy(high_value_order_amount)),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_env_parses_segmentation_rules() {
+        env::set_var("SEGMENT_REPEAT_BUYER_ORDER_COUNT", "3");
+        env::set_var("SEGMENT_HIGH_VALUE_ORDER_AMOUNT", "0");
+        let rules = SegmentationConfig::from_env().unwrap().rules;
+        assert_eq!(rules.repeat_buyer_order_count, Some(3));
+        assert_eq!(rules.high_value_order_amount, None);
+
+        env::set_var("SEGMENT_HIGH_VALUE_ORDER_AMOUNT", "-1");
+        assert!(SegmentationConfig::from_env().is_err());
+
+        env::remove_var("SEGMENT_REPEAT_BUYER_ORDER_COUNT");
+        env::remove_var("SEGMENT_HIGH_VALUE_ORDER_AMOUNT");
+        assert_eq!(
+            SegmentationConfig::from_env().unwrap().rules,
+            SegmentationRules::default()
+        );
+    }
+}
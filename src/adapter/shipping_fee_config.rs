@@ -0,0 +1,120 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::shipping_fee::{
+    CarrierLimits, ShippingFeePolicy, ShippingRateTable, WeightRate,
+};
+use std::collections::HashMap;
+use std::env;
+
+/// 配送料の設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct ShippingFeeConfig {
+    pub policy: ShippingFeePolicy,
+}
+
+/// "キー:金額" をカンマ区切りで並べた環境変数を解析する
+pub(crate) fn parse_entries(name: &str, value: &str) -> Result<Vec<(String, i64)>, ConfigError> {
+    value
+        .split(',')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let (key, fee) = entry.split_once(':').ok_or_else(|| {
+                ConfigError::InvalidValue(format!("Invalid {} entry: {}", name, entry))
+            })?;
+            let fee = fee.trim().parse::<i64>().map_err(|e| {
+                ConfigError::InvalidValue(format!("Invalid {} entry: {} ({})", name, entry, e))
+            })?;
+            Ok((key.trim().to_string(), fee))
+        })
+        .collect()
+}
+
+impl ShippingFeeConfig {
+    /// 環境変数から設定を読み取る
+    /// - SHIPPING_FREE_THRESHOLD: 送料無料になる小計の下限（デフォルト: 10000）
+    /// - SHIPPING_WEIGHT_RATES: 重量帯ごとの基本料金（例: "2000:500,5000:800"、グラム:円）
+    /// - SHIPPING_PREFECTURE_SURCHARGES: 都道府県ごとの追加料金（例: "北海道:300,沖縄県:700"）
+    /// - CARRIER_MAX_WEIGHT_GRAMS / CARRIER_MAX_SIZE_CM: 配送業者の1梱包の上限
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let defaults = ShippingFeePolicy::default();
+
+        let weight_rates = match env::var("SHIPPING_WEIGHT_RATES") {
+            Ok(value) => parse_entries("SHIPPING_WEIGHT_RATES", &value)?
+                .into_iter()
+                .map(|(max_weight, fee)| {
+                    let max_weight_grams = max_weight.parse::<u32>().map_err(|e| {
+                        ConfigError::InvalidValue(format!(
+                            "Invalid SHIPPING_WEIGHT_RATES weight: {} ({})",
+                            max_weight, e
+                        ))
+                    })?;
+                    Ok(WeightRate {
+                        max_weight_grams,
+                        fee,
+                    })
+                })
+                .collect::<Result<Vec<_>, ConfigError>>()?,
+            Err(_) => defaults.rate_table().weight_rates().to_vec(),
+        };
+        let prefecture_surcharges = match env::var("SHIPPING_PREFECTURE_SURCHARGES") {
+            Ok(value) => parse_entries("SHIPPING_PREFECTURE_SURCHARGES", &value)?
+                .into_iter()
+                .collect::<HashMap<_, _>>(),
+            Err(_) => defaults.rate_table().prefecture_surcharges().clone(),
+        };
+        let rate_table =
+            ShippingRateTable::new(weight_rates, prefecture_surcharges).map_err(|e| {
+                ConfigError::InvalidValue(format!("Invalid shipping rate table: {}", e))
+            })?;
+
+        let carrier_limits = CarrierLimits {
+            max_weight_grams: parse_env(
+                "CARRIER_MAX_WEIGHT_GRAMS",
+                defaults.carrier_limits().max_weight_grams,
+            )?,
+            max_size_cm: parse_env("CARRIER_MAX_SIZE_CM", defaults.carrier_limits().max_size_cm)?,
+        };
+        if carrier_limits.max_weight_grams == 0 || carrier_limits.max_size_cm == 0 {
+            return Err(ConfigError::InvalidValue(
+                "CARRIER_MAX_WEIGHT_GRAMS and CARRIER_MAX_SIZE_CM must be greater than 0"
+                    .to_string(),
+            ));
+        }
+
+        let free_shipping_threshold = parse_env(
+            "SHIPPING_FREE_THRESHOLD",
+            defaults.free_shipping_threshold(),
+        )?;
+
+        Ok(Self {
+            policy: ShippingFeePolicy::new(rate_table, free_shipping_threshold, carrier_limits),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env_overrides_and_validation() {
+        env::set_var("SHIPPING_WEIGHT_RATES", "5000:900, 1000:400");
+        env::set_var("SHIPPING_PREFECTURE_SURCHARGES", "沖縄県:1000");
+        let config = ShippingFeeConfig::from_env().unwrap();
+        let rate_table = config.policy.rate_table();
+        assert_eq!(rate_table.base_fee(800), 400);
+        assert_eq!(rate_table.base_fee(3000), 900);
+        assert_eq!(rate_table.prefecture_surcharge("沖縄県"), 1000);
+        assert_eq!(rate_table.prefecture_surcharge("北海道"), 0);
+        assert_eq!(config.policy.free_shipping_threshold(), 10_000);
+
+        env::set_var("SHIPPING_WEIGHT_RATES", "heavy:900");
+        assert!(ShippingFeeConfig::from_env().is_err());
+
+        env::set_var("SHIPPING_WEIGHT_RATES", "");
+        assert!(ShippingFeeConfig::from_env().is_err());
+
+        env::remove_var("SHIPPING_WEIGHT_RATES");
+        env::remove_var("SHIPPING_PREFECTURE_SURCHARGES");
+    }
+}
@@ -0,0 +1,79 @@
+use crate::adapter::alerting_config::parse_env;
+use crate::adapter::database_config::ConfigError;
+use crate::domain::sla::SlaPolicy;
+use chrono::TimeDelta;
+use std::time::Duration;
+
+/// SLA監視の確認間隔のデフォルト値（秒）
+const DEFAULT_CHECK_INTERVAL_SECONDS: u64 = 300;
+
+/// フルフィルメントSLA設定を管理する構造体
+#[derive(Debug, Clone)]
+pub struct SlaConfig {
+    pub policy: SlaPolicy,
+    /// SLA監視タスクの確認間隔
+    pub check_interval: Duration,
+}
+
+impl SlaConfig {
+    /// 環境変数から設定を読み取る
+    /// 環境変数が設定されていない場合はデフォルト値を使用
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let defaults = SlaPolicy::default();
+        let policy = SlaPolicy {
+            ship_within: TimeDelta::hours(parse_env(
+                "SLA_SHIP_WITHIN_HOURS",
+                defaults.ship_within.num_hours(),
+            )?),
+            deliver_within: TimeDelta::hours(parse_env(
+                "SLA_DELIVER_WITHIN_HOURS",
+                defaults.deliver_within.num_hours(),
+            )?),
+            at_risk_window: TimeDelta::hours(parse_env(
+                "SLA_AT_RISK_HOURS",
+                defaults.at_risk_window.num_hours(),
+            )?),
+        };
+        let check_interval = Duration::from_secs(parse_env(
+            "SLA_CHECK_INTERVAL_SECONDS",
+            DEFAULT_CHECK_INTERVAL_SECONDS,
+        )?);
+
+        if policy.ship_within <= TimeDelta::zero() || policy.deliver_within <= TimeDelta::zero() {
+            return Err(ConfigError::InvalidValue(
+                "SLA_SHIP_WITHIN_HOURS and SLA_DELIVER_WITHIN_HOURS must be greater than 0"
+                    .to_string(),
+            ));
+        }
+        if check_interval.is_zero() {
+            return Err(ConfigError::InvalidValue(
+                "SLA_CHECK_INTERVAL_SECONDS must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            policy,
+            check_interval,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_env_overrides_and_validation() {
+        env::set_var("SLA_SHIP_WITHIN_HOURS", "24");
+        let config = SlaConfig::from_env().unwrap();
+        assert_eq!(config.policy.ship_within, TimeDelta::hours(24));
+        assert_eq!(config.policy.deliver_within, TimeDelta::hours(72));
+        assert_eq!(config.check_interval, Duration::from_secs(300));
+
+        env::set_var("SLA_SHIP_WITHIN_HOURS", "0");
+        assert!(SlaConfig::from_env().is_err());
+
+        env::remove_var("SLA_SHIP_WITHIN_HOURS");
+    }
+}
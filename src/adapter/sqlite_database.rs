@@ -0,0 +1,55 @@
+use crate::adapter::database_error::DatabaseError;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{Executor, Pool, Sqlite};
+use std::str::FromStr;
+
+/// SQLite版のスキーマ
+const SCHEMA: &str =
+    include_str!("../../migrations/sqlite/001_create_order_and_inventory_tables.sql");
+
+/// SQLiteデータベース（外部のデータベースを用意せずに動かすデモ・テスト用）
+/// 注文・在庫・書籍カタログのテーブルを作成した接続プールを返す
+pub struct SqliteDatabase;
+
+impl SqliteDatabase {
+    /// SQLiteデータベースに接続し、スキーマを作成する
+    /// SQLiteは書き込みを同時に1つしか実行できず、インメモリのデータベースは接続ごとに別になるため、
+    /// 接続は1つだけにして閉じずに使い続ける
+    ///
+    /// # Arguments
+    /// * `url` - 接続先（例: `sqlite://bookstore.db`、`sqlite::memory:`）。ファイルがなければ作成する
+    ///
+    /// # Returns
+    /// * `Ok(Pool<Sqlite>)` - スキーマを作成した接続プール
+    /// * `Err(DatabaseError)` - 接続またはスキーマの作成に失敗
+    pub async fn connect(url: &str) -> Result<Pool<Sqlite>, DatabaseError> {
+        let options = SqliteConnectOptions::from_str(url)
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("SQLiteの接続先が不正です: {}", e))
+            })?
+            .create_if_missing(true)
+            .foreign_keys(true);
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .min_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await
+            .map_err(|e| {
+                DatabaseError::ConnectionError(format!("SQLiteに接続できませんでした: {}", e))
+            })?;
+
+        pool.execute(SCHEMA).await.map_err(|e| {
+            DatabaseError::MigrationError(format!("SQLiteのスキーマの作成に失敗しました: {}", e))
+        })?;
+
+        Ok(pool)
+    }
+
+    /// インメモリのSQLiteデータベースを作成する（プールを破棄するとデータも消える）
+    pub async fn in_memory() -> Result<Pool<Sqlite>, DatabaseError> {
+        Self::connect("sqlite::memory:").await
+    }
+}
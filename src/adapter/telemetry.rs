@@ -0,0 +1,262 @@
+// テレメトリー（tracingとOpenTelemetry連携）
+// ログ・スパンは常にtracingのサブスクライバーで標準出力に出力し、
+// `otel` フィーチャーが有効な場合のみOTLPエクスポーターを構成する
+// 設定は標準のOTEL_*環境変数（OTEL_EXPORTER_OTLP_ENDPOINT, OTEL_SERVICE_NAME など）から読み取る
+
+use std::time::Duration;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+
+/// OTEL_SERVICE_NAMEが未設定の場合に使用するサービス名
+#[cfg_attr(not(feature = "otel"), allow(dead_code))]
+const DEFAULT_SERVICE_NAME: &str = "bookstore-order-management";
+
+/// テレメトリー初期化エラー
+#[derive(Debug, thiserror::Error)]
+pub enum TelemetryError {
+    #[error("Telemetry initialization failed: {0}")]
+    InitializationFailed(String),
+}
+
+/// テレメトリーのライフサイクルを管理するガード
+/// ドロップ時に未送信のトレース・メトリクスをフラッシュする
+pub struct TelemetryGuard {
+    #[cfg(feature = "otel")]
+    providers: Option<otel::Providers>,
+}
+
+impl TelemetryGuard {
+    /// OTLPエクスポートが有効かどうか
+    pub fn is_enabled(&self) -> bool {
+        #[cfg(feature = "otel")]
+        {
+            self.providers.is_some()
+        }
+        #[cfg(not(feature = "otel"))]
+        {
+            false
+        }
+    }
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "otel")]
+        if let Some(providers) = self.providers.take() {
+            providers.shutdown();
+        }
+    }
+}
+
+/// テレメトリーを初期化する
+/// ログ・スパンを標準出力に出力するサブスクライバーを登録し、
+/// `otel` フィーチャーが有効でOTEL_SDK_DISABLED=trueでない場合はスパンをOTLPにもエクスポートする
+///
+/// # Arguments
+/// * `log_filter` - 出力するログ・スパンのレベル（tracingのEnvFilterの書式、AppConfigのlog_filter）
+pub fn init_telemetry(log_filter: &str) -> Result<TelemetryGuard, TelemetryError> {
+    let filter = EnvFilter::try_new(log_filter)
+        .map_err(|e| TelemetryError::InitializationFailed(e.to_string()))?;
+    let registry = tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer());
+
+    #[cfg(feature = "otel")]
+    {
+        let providers = if otel::sdk_disabled() {
+            None
+        } else {
+            Some(otel::init()?)
+        };
+        let otel_layer = providers
+            .as_ref()
+            .map(|providers| tracing_opentelemetry::layer().with_tracer(providers.tracer()));
+        registry
+            .with(otel_layer)
+            .try_init()
+            .map_err(|e| TelemetryError::InitializationFailed(e.to_string()))?;
+        Ok(TelemetryGuard { providers })
+    }
+    #[cfg(not(feature = "otel"))]
+    {
+        registry
+            .try_init()
+            .map_err(|e| TelemetryError::InitializationFailed(e.to_string()))?;
+        Ok(TelemetryGuard {})
+    }
+}
+
+/// イベントハンドラーの実行結果をメトリクスとして記録
+/// `otel` フィーチャーが無効な場合は何もしない
+pub fn record_handler_execution(
+    handler_name: &str,
+    event_type: &str,
+    duration: Duration,
+    succeeded: bool,
+) {
+    #[cfg(feature = "otel")]
+    otel::record_handler_execution(handler_name, event_type, duration, succeeded);
+    #[cfg(not(feature = "otel"))]
+    let _ = (handler_name, event_type, duration, succeeded);
+}
+
+/// 負荷制限で重要でないハンドラーへの配信を見送ったことをメトリクスとして記録
+/// `otel` フィーチャーが無効な場合は何もしない
+pub fn record_shed_event(handler_name: &str, event_type: &str) {
+    #[cfg(feature = "otel")]
+    otel::record_shed_event(handler_name, event_type);
+    #[cfg(not(feature = "otel"))]
+    let _ = (handler_name, event_type);
+}
+
+/// 配信待ちのイベントがたまって負荷制限を始めたことをメトリクスとして記録
+/// `otel` フィーチャーが無効な場合は何もしない
+pub fn record_load_shedding_activation() {
+    #[cfg(feature = "otel")]
+    otel::record_load_shedding_activation();
+}
+
+#[cfg(feature = "otel")]
+mod otel {
+    use super::{TelemetryError, DEFAULT_SERVICE_NAME};
+    use opentelemetry::metrics::{Counter, Histogram};
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry::{global, KeyValue};
+    use opentelemetry_sdk::metrics::{PeriodicReader, SdkMeterProvider};
+    use opentelemetry_sdk::trace::TracerProvider;
+    use opentelemetry_sdk::{runtime, Resource};
+    use std::env;
+    use opentelemetry_sdk::trace::Tracer;
+    use std::sync::OnceLock;
+    use std::time::Duration;
+
+    pub(super) struct Providers {
+        tracer_provider: TracerProvider,
+        meter_provider: SdkMeterProvider,
+    }
+
+    impl Providers {
+        /// スパンをOTLPにエクスポートするトレーサー
+        pub(super) fn tracer(&self) -> Tracer {
+            self.tracer_provider.tracer(DEFAULT_SERVICE_NAME)
+        }
+
+        pub(super) fn shutdown(self) {
+            // シャットダウン時のエラーは終了処理を妨げないよう無視する
+            let _ = self.tracer_provider.shutdown();
+            let _ = self.meter_provider.shutdown();
+        }
+    }
+
+    /// 標準のOTEL_SDK_DISABLED環境変数でSDKが無効化されているか
+    pub(super) fn sdk_disabled() -> bool {
+        env::var("OTEL_SDK_DISABLED")
+            .map(|value| value.eq_ignore_ascii_case("true"))
+            .unwrap_or(false)
+    }
+
+    /// サービスを表すリソースを作成
+    /// OTEL_RESOURCE_ATTRIBUTESなどはデフォルトのリソース検出で読み込まれる
+    fn resource() -> Resource {
+        let service_name =
+            env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| DEFAULT_SERVICE_NAME.to_string());
+        Resource::default().merge(&Resource::new(vec![KeyValue::new(
+            "service.name",
+            service_name,
+        )]))
+    }
+
+    pub(super) fn init() -> Result<Providers, TelemetryError> {
+        let span_exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .build()
+            .map_err(|e| TelemetryError::InitializationFailed(e.to_string()))?;
+        let tracer_provider = TracerProvider::builder()
+            .with_batch_exporter(span_exporter, runtime::Tokio)
+            .with_resource(resource())
+            .build();
+
+        let metric_exporter = opentelemetry_otlp::MetricExporter::builder()
+            .with_http()
+            .build()
+            .map_err(|e| TelemetryError::InitializationFailed(e.to_string()))?;
+        let meter_provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(metric_exporter, runtime::Tokio).build())
+            .with_resource(resource())
+            .build();
+
+        global::set_tracer_provider(tracer_provider.clone());
+        global::set_meter_provider(meter_provider.clone());
+
+        Ok(Providers {
+            tracer_provider,
+            meter_provider,
+        })
+    }
+
+    struct HandlerInstruments {
+        executions: Counter<u64>,
+        duration: Histogram<f64>,
+        shed_events: Counter<u64>,
+        load_shedding_activations: Counter<u64>,
+    }
+
+    fn handler_instruments() -> &'static HandlerInstruments {
+        static INSTRUMENTS: OnceLock<HandlerInstruments> = OnceLock::new();
+        INSTRUMENTS.get_or_init(|| {
+            let meter = global::meter(DEFAULT_SERVICE_NAME);
+            HandlerInstruments {
+                executions: meter
+                    .u64_counter("event_handler.executions")
+                    .with_description("イベントハンドラーの実行回数")
+                    .build(),
+                duration: meter
+                    .f64_histogram("event_handler.duration")
+                    .with_unit("s")
+                    .with_description("イベントハンドラーの実行時間")
+                    .build(),
+                shed_events: meter
+                    .u64_counter("event_handler.shed_events")
+                    .with_description("負荷制限で配信を見送ったイベント数")
+                    .build(),
+                load_shedding_activations: meter
+                    .u64_counter("event_bus.load_shedding.activations")
+                    .with_description("配信待ちのイベントがたまって負荷制限を始めた回数")
+                    .build(),
+            }
+        })
+    }
+
+    pub(super) fn record_handler_execution(
+        handler_name: &str,
+        event_type: &str,
+        duration: Duration,
+        succeeded: bool,
+    ) {
+        let instruments = handler_instruments();
+        let attributes = [
+            KeyValue::new("handler.name", handler_name.to_string()),
+            KeyValue::new("event.type", event_type.to_string()),
+            KeyValue::new("outcome", if succeeded { "success" } else { "failure" }),
+        ];
+        instruments.executions.add(1, &attributes);
+        instruments
+            .duration
+            .record(duration.as_secs_f64(), &attributes);
+    }
+
+    pub(super) fn record_shed_event(handler_name: &str, event_type: &str) {
+        handler_instruments().shed_events.add(
+            1,
+            &[
+                KeyValue::new("handler.name", handler_name.to_string()),
+                KeyValue::new("event.type", event_type.to_string()),
+            ],
+        );
+    }
+
+    pub(super) fn record_load_shedding_activation() {
+        handler_instruments().load_shedding_activations.add(1, &[]);
+    }
+}
@@ -2,7 +2,7 @@ use crate::domain::error::DomainError;
 use crate::domain::port::RepositoryError;
 
 /// アプリケーション層のエラー型
-/// ドメインエラー、リポジトリエラー、イベント発行エラーをラップする
+/// ドメインエラー、リポジトリエラー、イベント発行エラー、外部サービスエラーをラップする
 #[derive(Debug)]
 pub enum ApplicationError {
     /// ドメインエラー（ビジネスルール違反）
@@ -13,6 +13,10 @@ pub enum ApplicationError {
     EventPublishingFailed(String),
     /// エンティティが見つからない
     NotFound(String),
+    /// 外部サービス（プッシュ通知など）の呼び出し失敗
+    ExternalServiceFailed(String),
+    /// この構成では提供していない操作
+    Unsupported(String),
 }
 
 impl std::fmt::Display for ApplicationError {
@@ -24,6 +28,10 @@ impl std::fmt::Display for ApplicationError {
                 write!(f, "Event publishing failed: {}", msg)
             }
             ApplicationError::NotFound(msg) => write!(f, "Not found: {}", msg),
+            ApplicationError::ExternalServiceFailed(msg) => {
+                write!(f, "External service failed: {}", msg)
+            }
+            ApplicationError::Unsupported(msg) => write!(f, "Unsupported: {}", msg),
         }
     }
 }
@@ -39,6 +47,10 @@ impl From<DomainError> for ApplicationError {
 
 impl From<RepositoryError> for ApplicationError {
     fn from(err: RepositoryError) -> Self {
-        ApplicationError::RepositoryError(err)
+        match err {
+            // 購入制限の超過はリポジトリで判定してもドメインのルール違反として扱う
+            RepositoryError::PurchaseLimitExceeded(err) => ApplicationError::DomainError(err),
+            err => ApplicationError::RepositoryError(err),
+        }
     }
 }
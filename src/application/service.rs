@@ -1,14 +1,159 @@
 use crate::application::ApplicationError;
+use crate::domain::action_link::{ActionLinkClaims, ActionLinkUse, CustomerAction};
+use crate::domain::address_normalizer::{AddressInput, AddressNormalizer};
+use crate::domain::book_translation::{BookTitles, Language, LocalizedTitle};
+use crate::domain::bulk_cancellation::BulkCancellationFilter;
+use crate::domain::cancellation_policy::CancellationPolicy;
+use crate::domain::checkout_hold::{held_quantities, CheckoutHold};
+use crate::domain::customer_export::{
+    CustomerExportError, CustomerExportJob, CustomerExportStatus, CustomerExporter,
+};
+use crate::domain::customer_notification::CustomerNotification;
+use crate::domain::customer_webhook::{WebhookDelivery, WebhookDispatcher, WebhookSubscription};
+use crate::domain::dead_letter::DeadLetterSummary;
+use crate::domain::delivery_estimate::DeliveryEstimator;
+use crate::domain::diagnostics::DiagnosticsReport;
+use crate::domain::error::DomainError;
 use crate::domain::event::{
-    DomainEvent, OrderCancelled, OrderConfirmed, OrderDelivered, OrderShipped,
+    CarrierTrackingUpdated, DomainEvent, InventoryAdjusted, OrderCancelled, OrderConfirmed,
+    OrderDelivered, OrderHeld, OrderReleased, OrderShipped, OrderStatusForcefullyChanged,
+    ReturnApproved, ReturnRequested, ShippingAddressChanged,
 };
 use crate::domain::model::{
-    BookId, CustomerId, Inventory, Money, Order, OrderId, OrderStatus, ShippingAddress,
+    AllocationQuota, BookId, BookSpec, Customer, CustomerId, CycleCount, CycleCountId, DevicePlatform, DeviceRegistration, DeviceToken,
+    EmailAddress, FulfillmentType, HoldReason, Inventory, LineAttribute, Money, Order, OrderId, OrderLine, OrderNumber, OrderStatus, Recipient,
+    SalesChannel, ShippingAddress, TrackingToken, CYCLE_COUNT_ADJUSTMENT_REASON, RESTOCK_ADJUSTMENT_REASON,
+};
+use crate::domain::event_bus::{
+    DeadLetter, EventDispatchStatus, HandlerError, PausedEventHandling, SubscriptionStatus,
+};
+use crate::domain::event_export::{
+    EventExportError, EventExportRange, EventExporter, EventImportError, EventImportSummary,
+    EventImporter, ExportManifest,
+};
+use crate::domain::event_trace::EventTraceReport;
+use crate::domain::forecast::{DemandForecast, ForecastPolicy};
+use crate::domain::fulfillment_mode::FulfillmentMode;
+use crate::domain::inbox::{CarrierTrackingMessage, InboxMessage, InboxMessageHandler};
+use crate::domain::inventory_snapshot::{
+    InventoryChanges, InventorySnapshot, MAX_CHANGES_PER_PAGE,
 };
-use crate::domain::port::{EventBus, InventoryRepository, OrderRepository};
+use crate::domain::inventory_valuation::InventoryValuationReport;
+use crate::domain::port::{
+    ActionLinkAuditRepository, ActionLinkSigner, AllocationQuotaRepository, BookCatalog, CheckoutHoldRepository, CustomerNotificationSource, CustomerRepository, CycleCountRepository, DeadLetterMonitor, DeviceRegistrationRepository, EventBus, EventDispatchControl,
+    EventBusError, EventJournal, EventStreamMonitor, EventTraceMonitor, FailedNotificationRepository, InboxRepository, InventoryRepository, LegacyImportRepository, LiveEventSource,
+    NotificationSender, OrderLockRepository, OrderNoteRepository, OrderNumberGenerator, OrderRepository,
+    ParkedEventRepository, PendingOperationRepository, RepositoryError,
+    PushNotificationPort, SagaCompensationRepository, StatusOverrideAuditRepository, SubscriptionManager, SubscriptionRegistryRepository, TrackingEventRepository,
+    WaitlistRepository, WebhookDeliveryRepository, WebhookSubscriptionRepository,
+};
+use crate::domain::late_event::ParkedEvent;
+use crate::domain::legacy_import::{LegacyImportOutcome, LegacyImportRecord};
+use crate::domain::load_shedding::LoadSheddingStatus;
+use crate::domain::notification_retry::{
+    FailedNotification, FailedNotificationStatus, NotificationRetryPolicy,
+};
+use crate::domain::order_event_stream::OrderEventStreamFilter;
+use crate::domain::order_lock::{OrderLock, MAX_ORDER_LOCK_TTL_MINUTES};
+use crate::domain::order_note::OrderNote;
+use crate::domain::order_repair::{self, OrderRepairReport};
+use crate::domain::order_timeline::OrderTimeline;
+use crate::domain::order_totals::OrderTotals;
+use crate::domain::packing_slip::PackingSlip;
+use crate::domain::pending_operation::PendingOperation;
+use crate::domain::projection::{ProjectionRegistry, ProjectionStatus};
+use crate::domain::pricing::{self, PriceChange, PriceChangePolicy};
+use crate::domain::purchase_policy::{PurchaseCheck, PurchasePolicy};
+use crate::domain::reconciliation::InventoryReconciliationReport;
+use crate::domain::retry_policy::{EventRetryPolicy, RetryPolicies};
+use crate::domain::saga_metrics::SagaMetricsReport;
+use crate::domain::shipping_fee::ShippingFeePolicy;
+use crate::domain::sla::{SlaPolicy, SlaReport};
+use crate::domain::status_override::StatusOverrideAudit;
+use crate::domain::subscription_registry::{RegisteredSubscriptionState, SubscriptionRegistration};
+use crate::domain::task_supervisor::{BackgroundTaskHealth, TaskSupervisor};
+use crate::domain::tracking::{PublicTrackingView, TrackingStage, TrackingTimeline};
+use crate::domain::validation::{Constraint, FieldViolation};
+use crate::domain::waitlist::{position_in_queue, WaitlistPosition};
+use crate::domain::warning::DomainWarning;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc};
 use uuid::Uuid;
 
+/// 状態を変更するコマンドの応答
+/// 発行したドメインイベントを返し、クライアントが楽観的に更新した画面を
+/// イベントID・相関IDでイベントの配信と突き合わせられるようにする
+#[derive(Debug, Clone)]
+pub struct CommandAcknowledgement {
+    /// 成功した上で確認してほしい警告
+    pub warnings: Vec<DomainWarning>,
+    /// コマンドが発行したドメインイベント
+    pub event: DomainEvent,
+    /// イベントの発行に失敗して再試行待ちにした場合の操作ID（状態の変更は保存済み）
+    pub pending_operation_id: Option<Uuid>,
+}
+
+impl CommandAcknowledgement {
+    /// 警告のない応答を作成
+    pub fn from_event(event: DomainEvent) -> Self {
+        Self {
+            warnings: Vec::new(),
+            event,
+            pending_operation_id: None,
+        }
+    }
+}
+
+/// 一括のステータス遷移で1回に指定できる注文の上限
+pub const MAX_BULK_TRANSITION_SIZE: usize = 100;
+
+/// 重複の可能性がある注文を探すときの作成日時の差の上限（分、指定がない場合）
+pub const DEFAULT_SIMILAR_ORDER_WINDOW_MINUTES: u32 = 30;
+
+/// イベントの配信全体を一時停止するときに、処理中のハンドラーが終わるのを待つ上限（秒、指定がない場合）
+pub const DEFAULT_EVENT_DISPATCH_DRAIN_TIMEOUT_SECONDS: u64 = 30;
+
+/// 複数の注文にまとめて適用するステータス遷移（倉庫でのまとめての発送・配達の記録）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkTransition {
+    /// 発送済みにする（POST /orders/bulk/ship）
+    Ship,
+    /// 配達完了にする（POST /orders/bulk/deliver）
+    Deliver,
+    /// キャンセルする（POST /admin/orders/bulk-cancel）
+    Cancel,
+}
+
+/// 一括のステータス遷移の、注文1件ごとの結果
+#[derive(Debug)]
+pub struct BulkTransitionOutcome {
+    pub order_id: OrderId,
+    /// 成功した場合は発行したイベント
+    pub result: Result<DomainEvent, ApplicationError>,
+}
+
+/// 一括キャンセルのプレビュー
+#[derive(Debug)]
+pub struct BulkCancellationPreview {
+    /// 条件に一致した（キャンセルの対象になる）注文
+    pub orders: Vec<Order>,
+    /// 実行時に指定する確認トークン
+    pub confirmation_token: String,
+}
+
+/// 注文ごとのイベントストリームの購読
+/// 購読した後に発行されたイベントを受け取り、絞り込みで注文に関するものだけを通す
+#[derive(Debug)]
+pub struct OrderEventSubscription {
+    /// 発行されたすべてのイベントの受信側
+    pub events: broadcast::Receiver<DomainEvent>,
+    /// 注文に関するイベントの絞り込み
+    pub filter: OrderEventStreamFilter,
+}
+
 /// 注文アプリケーションサービス
 pub struct OrderApplicationService<OR>
 where
@@ -16,6 +161,26 @@ where
 {
     order_repository: OR,
     event_bus: Arc<dyn EventBus>,
+    /// 注文作成・書籍追加時の購入制限（デフォルトは制限しない）
+    purchase_policy: PurchasePolicy,
+    sla_policy: SlaPolicy,
+    shipping_fee_policy: ShippingFeePolicy,
+    delivery_estimator: DeliveryEstimator,
+    /// 確定時に価格を照合する書籍カタログ（未設定の場合は照合しない）
+    book_catalog: Option<Arc<dyn BookCatalog>>,
+    price_change_policy: PriceChangePolicy,
+    fulfillment_mode: FulfillmentMode,
+    cancellation_policy: CancellationPolicy,
+    /// 発行に失敗したイベントを記録する再試行待ちの操作（未設定の場合は発行の失敗をエラーで返す）
+    pending_operations: Option<Arc<dyn PendingOperationRepository>>,
+    /// 注文の作成時に参照先を検証する顧客リポジトリ（未設定の場合は検証しない）
+    customer_repository: Option<Arc<dyn CustomerRepository>>,
+    /// 注文の作成時に注文番号を採番する（未設定の場合は注文番号なしで作成する）
+    order_number_generator: Option<Arc<dyn OrderNumberGenerator>>,
+    /// 強制遷移の監査記録（未設定の場合は強制遷移を受け付けない）
+    status_override_audits: Option<Arc<dyn StatusOverrideAuditRepository>>,
+    /// 注文ごとのイベントストリームで発行されたイベントを受け取る（未設定の場合はストリームを提供しない）
+    live_events: Option<Arc<dyn LiveEventSource>>,
 }
 
 impl<OR> OrderApplicationService<OR>
@@ -31,49 +196,319 @@ where
         Self {
             order_repository,
             event_bus,
+            purchase_policy: PurchasePolicy::default(),
+            sla_policy: SlaPolicy::default(),
+            shipping_fee_policy: ShippingFeePolicy::default(),
+            delivery_estimator: DeliveryEstimator::default(),
+            book_catalog: None,
+            price_change_policy: PriceChangePolicy::default(),
+            fulfillment_mode: FulfillmentMode::default(),
+            cancellation_policy: CancellationPolicy::default(),
+            pending_operations: None,
+            customer_repository: None,
+            order_number_generator: None,
+            status_override_audits: None,
+            live_events: None,
+        }
+    }
+
+    /// 購入ポリシーを設定
+    ///
+    /// # Arguments
+    /// * `purchase_policy` - 注文作成・書籍追加時に適用する購入ポリシー
+    pub fn with_purchase_policy(mut self, purchase_policy: PurchasePolicy) -> Self {
+        self.purchase_policy = purchase_policy;
+        self
+    }
+
+    /// フルフィルメントSLAを設定
+    ///
+    /// # Arguments
+    /// * `sla_policy` - SLAレポートの集計に使用するSLA設定
+    pub fn with_sla_policy(mut self, sla_policy: SlaPolicy) -> Self {
+        self.sla_policy = sla_policy;
+        self
+    }
+
+    /// 配送料ポリシーを設定
+    ///
+    /// # Arguments
+    /// * `shipping_fee_policy` - 配送料の算出と発送時の配送業者の上限の検証に使用する配送料ポリシー
+    pub fn with_shipping_fee_policy(mut self, shipping_fee_policy: ShippingFeePolicy) -> Self {
+        self.shipping_fee_policy = shipping_fee_policy;
+        self
+    }
+
+    /// 配達予定日の見積もりを設定
+    ///
+    /// # Arguments
+    /// * `delivery_estimator` - 確定時・発送時に注文の配達予定日を算出する見積もり
+    pub fn with_delivery_estimator(mut self, delivery_estimator: DeliveryEstimator) -> Self {
+        self.delivery_estimator = delivery_estimator;
+        self
+    }
+
+    /// 確定時の価格チェックを設定
+    ///
+    /// # Arguments
+    /// * `book_catalog` - 明細の単価と照合する書籍カタログ
+    /// * `price_change_policy` - 注文後に価格が変更されていた場合の扱い
+    pub fn with_price_protection(
+        mut self,
+        book_catalog: Arc<dyn BookCatalog>,
+        price_change_policy: PriceChangePolicy,
+    ) -> Self {
+        self.book_catalog = Some(book_catalog);
+        self.price_change_policy = price_change_policy;
+        self
+    }
+
+    /// 発送・配達の進め方を設定
+    ///
+    /// # Arguments
+    /// * `fulfillment_mode` - autoの場合は手動の発送・配達完了の操作を拒否する
+    pub fn with_fulfillment_mode(mut self, fulfillment_mode: FulfillmentMode) -> Self {
+        self.fulfillment_mode = fulfillment_mode;
+        self
+    }
+
+    /// キャンセルポリシーを設定
+    ///
+    /// # Arguments
+    /// * `cancellation_policy` - 確定済みの注文のキャンセル受付期限
+    pub fn with_cancellation_policy(mut self, cancellation_policy: CancellationPolicy) -> Self {
+        self.cancellation_policy = cancellation_policy;
+        self
+    }
+
+    /// 再試行待ちの操作を設定
+    /// 設定すると、状態の保存後にイベントの発行に失敗したコマンドは発行を再試行待ちにして応答する
+    ///
+    /// # Arguments
+    /// * `pending_operations` - 再試行待ちの操作リポジトリ
+    pub fn with_pending_operations(
+        mut self,
+        pending_operations: Arc<dyn PendingOperationRepository>,
+    ) -> Self {
+        self.pending_operations = Some(pending_operations);
+        self
+    }
+
+    /// 顧客リポジトリを設定
+    /// 設定すると、注文の作成時に登録済みの顧客かを検証し、顧客の既定の配送先住所を注文に設定する
+    ///
+    /// # Arguments
+    /// * `customer_repository` - 顧客リポジトリ
+    pub fn with_customers(mut self, customer_repository: Arc<dyn CustomerRepository>) -> Self {
+        self.customer_repository = Some(customer_repository);
+        self
+    }
+
+    /// 注文番号の採番を設定
+    /// 設定すると、注文の作成時に顧客に伝える注文番号を採番する
+    ///
+    /// # Arguments
+    /// * `order_number_generator` - 注文番号の採番
+    pub fn with_order_numbers(
+        mut self,
+        order_number_generator: Arc<dyn OrderNumberGenerator>,
+    ) -> Self {
+        self.order_number_generator = Some(order_number_generator);
+        self
+    }
+
+    /// 強制遷移の監査記録を設定
+    /// 設定すると、管理者による注文のステータスの強制遷移を受け付ける
+    ///
+    /// # Arguments
+    /// * `status_override_audits` - 強制遷移の監査記録リポジトリ
+    pub fn with_status_override_audits(
+        mut self,
+        status_override_audits: Arc<dyn StatusOverrideAuditRepository>,
+    ) -> Self {
+        self.status_override_audits = Some(status_override_audits);
+        self
+    }
+
+    /// ライブイベントの受け取り先を設定
+    /// 設定すると、注文ごとに発行されたイベントをストリームで提供する
+    ///
+    /// # Arguments
+    /// * `live_events` - 発行されたイベントをその場で受け取るためのイベントバス
+    pub fn with_live_events(mut self, live_events: Arc<dyn LiveEventSource>) -> Self {
+        self.live_events = Some(live_events);
+        self
+    }
+
+    /// 手動の発送・配達完了の操作を受け付けるか検証
+    fn ensure_manual_transitions_allowed(&self) -> Result<(), ApplicationError> {
+        if self.fulfillment_mode.allows_manual_transitions() {
+            return Ok(());
         }
+        Err(ApplicationError::Unsupported(format!(
+            "フルフィルメントモードが{}のため、発送・配達完了は自動で行われます",
+            self.fulfillment_mode.as_str()
+        )))
+    }
+
+    /// 配送料ポリシーを取得
+    pub fn shipping_fee_policy(&self) -> &ShippingFeePolicy {
+        &self.shipping_fee_policy
+    }
+
+    /// キャンセルポリシーを取得
+    pub fn cancellation_policy(&self) -> &CancellationPolicy {
+        &self.cancellation_policy
+    }
+
+    /// フルフィルメントSLAレポートを取得
+    /// 注文のステータス遷移日時から、区間ごとの違反件数と期限が迫っている注文を集計する
+    ///
+    /// # Arguments
+    /// * `now` - 集計の基準日時
+    ///
+    /// # Returns
+    /// * `Ok(SlaReport)` - SLAレポート
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn get_sla_report(&self, now: DateTime<Utc>) -> Result<SlaReport, ApplicationError> {
+        let orders = self.order_repository.find_all().await?;
+        Ok(self.sla_policy.build_report(&orders, now))
     }
 
-    /// イベントに相関IDを設定するヘルパー関数
-    fn set_correlation_id_to_event(
+    /// 注文を保存し、注文の変更を表すイベントを発行する
+    /// 保存の前に注文内のイベント連番を採番し、発行するイベントに連番と相関IDを設定する
+    /// 相関IDは指定されたもの、確定時に始まったサーガの相関ID、新しい相関IDの順に使う
+    /// 発行に失敗した場合は、再試行待ちの操作が設定されていればイベントを記録して再試行に任せる
+    ///
+    /// # Arguments
+    /// * `order` - 保存する注文
+    /// * `event` - 発行するイベント
+    /// * `correlation_id` - 呼び出し元が指定した相関ID（リクエストヘッダーなど）
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - 発行した（再試行待ちにした場合は操作IDを含む）イベント
+    /// * `Err(ApplicationError)` - 保存または発行に失敗
+    async fn save_and_publish(
         &self,
+        order: &mut Order,
         mut event: DomainEvent,
-        correlation_id: Uuid,
-    ) -> DomainEvent {
-        match &mut event {
-            DomainEvent::OrderConfirmed(ref mut e) => e.metadata.correlation_id = correlation_id,
-            DomainEvent::OrderCancelled(ref mut e) => e.metadata.correlation_id = correlation_id,
-            DomainEvent::OrderShipped(ref mut e) => e.metadata.correlation_id = correlation_id,
-            DomainEvent::OrderDelivered(ref mut e) => e.metadata.correlation_id = correlation_id,
-            DomainEvent::InventoryReserved(ref mut e) => e.metadata.correlation_id = correlation_id,
-            DomainEvent::InventoryReleased(ref mut e) => e.metadata.correlation_id = correlation_id,
-            DomainEvent::InventoryReservationFailed(ref mut e) => {
-                e.metadata.correlation_id = correlation_id
-            }
-            DomainEvent::ShippingFailed(ref mut e) => e.metadata.correlation_id = correlation_id,
-            DomainEvent::DeliveryFailed(ref mut e) => e.metadata.correlation_id = correlation_id,
-            DomainEvent::SagaCompensationStarted(ref mut e) => {
-                e.metadata.correlation_id = correlation_id
-            }
-            DomainEvent::SagaCompensationCompleted(ref mut e) => {
-                e.metadata.correlation_id = correlation_id
-            }
+        correlation_id: Option<Uuid>,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        let sequence_number = order.record_event();
+        self.order_repository.save(order).await?;
+
+        let correlation_id = correlation_id
+            .or(order.saga_correlation_id())
+            .unwrap_or_else(Uuid::new_v4);
+        tracing::Span::current().record("correlation_id", tracing::field::display(correlation_id));
+        let metadata = event.metadata_mut();
+        metadata.sequence_number = Some(sequence_number);
+        metadata.correlation_id = correlation_id;
+
+        let mut acknowledgement = CommandAcknowledgement::from_event(event.clone());
+        if let Err(e) = self.event_bus.publish(event).await {
+            let operation_id = self.defer_publish(&acknowledgement.event, e).await?;
+            acknowledgement.pending_operation_id = Some(operation_id);
+        }
+        Ok(acknowledgement)
+    }
+
+    /// 発行に失敗したイベントを再試行待ちの操作として記録する
+    /// 再試行待ちの操作が未設定の場合や記録に失敗した場合は、発行の失敗をそのまま返す
+    async fn defer_publish(
+        &self,
+        event: &DomainEvent,
+        error: EventBusError,
+    ) -> Result<Uuid, ApplicationError> {
+        let publish_failed = || ApplicationError::EventPublishingFailed(error.to_string());
+        let Some(pending_operations) = &self.pending_operations else {
+            return Err(publish_failed());
+        };
+        let operation = PendingOperation::publish_event(event, &error.to_string(), Utc::now())
+            .map_err(|_| publish_failed())?;
+        if let Err(e) = pending_operations.save(&operation).await {
+            tracing::error!(
+                event.type = event.event_type(),
+                event.id = %event.metadata().event_id,
+                error = %e,
+                "failed to record pending operation"
+            );
+            return Err(publish_failed());
         }
-        event
+
+        tracing::warn!(
+            event.type = event.event_type(),
+            event.id = %event.metadata().event_id,
+            operation.id = %operation.id,
+            error = %error,
+            "event publishing deferred to pending operation"
+        );
+        Ok(operation.id)
     }
 
     /// 新しい注文を作成
+    /// 顧客リポジトリが設定されている場合は登録済みの顧客のみ注文でき、顧客の既定の配送先住所を注文に設定する
+    /// 注文番号の採番が設定されている場合は注文番号を採番する
     ///
     /// # Arguments
     /// * `customer_id` - 顧客ID
     ///
     /// # Returns
     /// * `Ok(OrderId)` - 作成された注文のID
+    /// * `Err(ApplicationError::DomainError)` - 未登録の顧客（VALIDATION_FAILED）・未完了の注文数の上限超過
     /// * `Err(ApplicationError)` - 作成失敗
     pub async fn create_order(&self, customer_id: CustomerId) -> Result<OrderId, ApplicationError> {
+        self.create_order_in_channel(customer_id, SalesChannel::default())
+            .await
+    }
+
+    /// 販売チャネルを指定して新しい注文を作成
+    /// 販売チャネルは書籍の割当枠（チャネル別の割当比率）の判定に使う
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `sales_channel` - 注文を受け付けた販売チャネル
+    ///
+    /// # Returns
+    /// * `Ok(OrderId)` - 作成された注文のID
+    /// * `Err(ApplicationError)` - 作成失敗（create_orderと同じ）
+    #[tracing::instrument(name = "command.create_order", skip_all, fields(customer_id = %customer_id, sales_channel = %sales_channel), err)]
+    pub async fn create_order_in_channel(
+        &self,
+        customer_id: CustomerId,
+        sales_channel: SalesChannel,
+    ) -> Result<OrderId, ApplicationError> {
+        let default_shipping_address = match &self.customer_repository {
+            Some(customer_repository) => customer_repository
+                .find_by_id(customer_id)
+                .await?
+                .ok_or_else(|| {
+                    DomainError::from(FieldViolation::new(
+                        "customer_id",
+                        Constraint::Exists,
+                        Some(customer_id.to_string()),
+                        "顧客が登録されていません",
+                    ))
+                })?
+                .default_shipping_address()
+                .cloned(),
+            None => None,
+        };
+
+        let order_number = match &self.order_number_generator {
+            Some(generator) => Some(generator.next_order_number(Utc::now()).await?),
+            None => None,
+        };
+
         let order_id = self.order_repository.next_identity();
-        let order = crate::domain::model::Order::new(order_id, customer_id);
-        self.order_repository.save(&order).await?;
+        let mut order = crate::domain::model::Order::new(order_id, customer_id)
+            .with_order_number(order_number)
+            .with_sales_channel(sales_channel);
+        if let Some(address) = default_shipping_address {
+            order.set_shipping_address(address)?;
+        }
+        self.save_within_purchase_limits(&mut order, self.purchase_policy.open_order_check())
+            .await?;
         Ok(order_id)
     }
 
@@ -86,7 +521,7 @@ where
     /// * `price` - 単価
     ///
     /// # Returns
-    /// * `Ok(())` - 追加成功
+    /// * `Ok(Vec<DomainWarning>)` - 追加成功（処理を止めない警告を含む）
     /// * `Err(ApplicationError)` - 追加失敗
     pub async fn add_book_to_order(
         &self,
@@ -94,6 +529,119 @@ where
         book_id: BookId,
         quantity: u32,
         price: Money,
+    ) -> Result<Vec<DomainWarning>, ApplicationError> {
+        self.add_book_to_order_with_fulfillment(
+            order_id,
+            book_id,
+            quantity,
+            price,
+            FulfillmentType::Physical,
+        )
+        .await
+    }
+
+    /// フルフィルメント種別（物理書籍・電子書籍）を指定して注文に書籍を追加
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `book_id` - 書籍ID
+    /// * `quantity` - 数量
+    /// * `price` - 単価
+    /// * `fulfillment_type` - フルフィルメント種別
+    ///
+    /// # Returns
+    /// * `Ok(Vec<DomainWarning>)` - 追加成功（処理を止めない警告を含む）
+    /// * `Err(ApplicationError)` - 追加失敗
+    pub async fn add_book_to_order_with_fulfillment(
+        &self,
+        order_id: OrderId,
+        book_id: BookId,
+        quantity: u32,
+        price: Money,
+        fulfillment_type: FulfillmentType,
+    ) -> Result<Vec<DomainWarning>, ApplicationError> {
+        self.add_book_to_order_with_spec(order_id, book_id, quantity, price, fulfillment_type, None)
+            .await
+    }
+
+    /// フルフィルメント種別と書籍の重量・寸法を指定して注文に書籍を追加
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `book_id` - 書籍ID
+    /// * `quantity` - 数量
+    /// * `price` - 単価
+    /// * `fulfillment_type` - フルフィルメント種別
+    /// * `spec` - 書籍の重量と寸法（配送料の算出に使用、省略時は重量0として扱う）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<DomainWarning>)` - 追加成功（処理を止めない警告を含む）
+    /// * `Err(ApplicationError)` - 追加失敗
+    #[tracing::instrument(name = "command.add_book_to_order", skip_all, fields(order_id = %order_id, book_id = %book_id, quantity = quantity, fulfillment_type = %fulfillment_type), err)]
+    pub async fn add_book_to_order_with_spec(
+        &self,
+        order_id: OrderId,
+        book_id: BookId,
+        quantity: u32,
+        price: Money,
+        fulfillment_type: FulfillmentType,
+        spec: Option<BookSpec>,
+    ) -> Result<Vec<DomainWarning>, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "注文が見つかりません: {}",
+                    order_id
+                ))
+            })?;
+
+        order.add_order_line(book_id, quantity, price, fulfillment_type, spec)?;
+        let warnings = order.take_warnings();
+        let check = self
+            .purchase_policy
+            .book_quantity_check(book_id, quantity, Utc::now());
+        self.save_within_purchase_limits(&mut order, check).await?;
+        Ok(warnings)
+    }
+
+    /// 購入制限を確認したうえで注文を保存する
+    /// 制限がない場合は通常どおり保存し、制限がある場合は集計から保存までをリポジトリに任せて排他する
+    async fn save_within_purchase_limits(
+        &self,
+        order: &mut Order,
+        check: Option<PurchaseCheck>,
+    ) -> Result<(), ApplicationError> {
+        match check {
+            Some(check) => {
+                self.order_repository
+                    .save_within_purchase_limits(order, &self.purchase_policy, check)
+                    .await?
+            }
+            None => self.order_repository.save(order).await?,
+        }
+        Ok(())
+    }
+
+    /// 注文明細の属性（「サイン本希望」などの要望）を設定
+    /// 属性は注文確定時のOrderConfirmedイベントで倉庫側に伝わるため、Pending状態でのみ変更できる
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `book_id` - 属性を設定する明細の書籍ID
+    /// * `attributes` - 明細属性（既存の属性はすべて置き換える）
+    ///
+    /// # Returns
+    /// * `Ok(())` - 設定成功
+    /// * `Err(ApplicationError)` - 設定失敗
+    #[tracing::instrument(name = "command.set_line_attributes", skip_all, fields(order_id = %order_id, book_id = %book_id), err)]
+    pub async fn set_line_attributes(
+        &self,
+        order_id: OrderId,
+        book_id: BookId,
+        attributes: Vec<LineAttribute>,
     ) -> Result<(), ApplicationError> {
         let mut order = self
             .order_repository
@@ -105,12 +653,15 @@ where
                     order_id
                 ))
             })?;
-        order.add_book(book_id, quantity, price)?;
-        self.order_repository.save(&order).await?;
+
+        order.set_line_attributes(book_id, attributes)?;
+        self.order_repository.save(&mut order).await?;
         Ok(())
     }
 
     /// 注文に配送先住所を設定
+    /// 入力された住所をAddressNormalizerで正規化して設定し、入力されたままの住所を監査用に保持する
+    /// 確定後（発送前）の変更では配送料を再計算し、ShippingAddressChangedイベントを発行する
     ///
     /// # Arguments
     /// * `order_id` - 注文ID
@@ -121,8 +672,9 @@ where
     /// * `address_line2` - 住所2（オプション）
     ///
     /// # Returns
-    /// * `Ok(())` - 設定成功
+    /// * `Ok(Vec<DomainWarning>)` - 設定成功（処理を止めない警告を含む）
     /// * `Err(ApplicationError)` - 設定失敗
+    #[tracing::instrument(name = "command.set_shipping_address_from_request", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
     pub async fn set_shipping_address_from_request(
         &self,
         order_id: OrderId,
@@ -131,7 +683,7 @@ where
         city: String,
         address_line1: String,
         address_line2: Option<String>,
-    ) -> Result<(), ApplicationError> {
+    ) -> Result<Vec<DomainWarning>, ApplicationError> {
         let mut order = self
             .order_repository
             .find_by_id(order_id)
@@ -142,22 +694,38 @@ where
                     order_id
                 ))
             })?;
-        let address =
-            ShippingAddress::new(postal_code, prefecture, city, address_line1, address_line2)?;
-        order.set_shipping_address(address);
-        self.order_repository.save(&order).await?;
-        Ok(())
+        let entered = AddressInput {
+            postal_code,
+            prefecture,
+            city,
+            street: address_line1,
+            building: address_line2,
+        };
+        let previous_address = order.shipping_address().cloned();
+        let previous_shipping_fee =
+            OrderTotals::for_order(&order, &self.shipping_fee_policy).shipping_fee();
+        order.set_entered_shipping_address(entered, &AddressNormalizer)?;
+
+        self.save_shipping_destination(order, previous_address, previous_shipping_fee)
+            .await
     }
 
-    /// 注文を確定
+    /// ギフト注文の受取人を設定
+    /// 受取人の住所が配送先住所になり、確定後（発送前）の変更では住所変更と同様にShippingAddressChangedイベントを発行する
     ///
     /// # Arguments
     /// * `order_id` - 注文ID
+    /// * `recipient` - 受取人（氏名・住所・電話番号）
     ///
     /// # Returns
-    /// * `Ok(())` - 確定成功
-    /// * `Err(ApplicationError)` - 確定失敗
-    pub async fn confirm_order(&self, order_id: OrderId) -> Result<(), ApplicationError> {
+    /// * `Ok(Vec<DomainWarning>)` - 設定成功（処理を止めない警告を含む）
+    /// * `Err(ApplicationError)` - 設定失敗
+    #[tracing::instrument(name = "command.set_gift_recipient", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn set_gift_recipient(
+        &self,
+        order_id: OrderId,
+        recipient: Recipient,
+    ) -> Result<Vec<DomainWarning>, ApplicationError> {
         let mut order = self
             .order_repository
             .find_by_id(order_id)
@@ -168,38 +736,101 @@ where
                     order_id
                 ))
             })?;
+        let previous_address = order.shipping_address().cloned();
+        let previous_shipping_fee =
+            OrderTotals::for_order(&order, &self.shipping_fee_policy).shipping_fee();
+        order.set_recipient(recipient)?;
 
-        order.confirm()?;
-        self.order_repository.save(&order).await?;
+        self.save_shipping_destination(order, previous_address, previous_shipping_fee)
+            .await
+    }
 
-        let correlation_id = Uuid::new_v4();
-        let total_amount = order.calculate_total();
-        let event = OrderConfirmed::new(
+    /// 配送先を変更した注文を保存し、注文と配送料ポリシーの警告を返す
+    /// 確定後（発送前）の変更では配送料を再計算し、ShippingAddressChangedイベントを発行する
+    async fn save_shipping_destination(
+        &self,
+        mut order: Order,
+        previous_address: Option<ShippingAddress>,
+        previous_shipping_fee: Money,
+    ) -> Result<Vec<DomainWarning>, ApplicationError> {
+        let mut warnings = order.take_warnings();
+        let address = order
+            .shipping_address()
+            .expect("配送先を変更した注文には配送先住所が必須です")
+            .clone();
+        if !order.is_digital_only() {
+            warnings.extend(self.shipping_fee_policy.destination_warning(&address));
+        }
+
+        // 確定前の住所設定は注文の下書きの一部のため、イベントは発行しない
+        if order.status() == OrderStatus::Pending {
+            self.order_repository.save(&mut order).await?;
+            return Ok(warnings);
+        }
+
+        // 配送先の変更で配送料が変わるため、確定時の金額を再計算する
+        order.snapshot_totals(&self.shipping_fee_policy);
+        // 配送先の都道府県で配送日数が変わるため、配達予定日も見積もり直す
+        order.estimate_delivery(&self.delivery_estimator);
+        let event = ShippingAddressChanged::new(
             order.id(),
             order.customer_id(),
-            order.order_lines().to_vec(),
-            total_amount,
+            previous_address,
+            address,
+            previous_shipping_fee,
+            OrderTotals::for_order(&order, &self.shipping_fee_policy).shipping_fee(),
         );
-        let event_with_correlation =
-            self.set_correlation_id_to_event(DomainEvent::OrderConfirmed(event), correlation_id);
+        self.save_and_publish(&mut order, DomainEvent::ShippingAddressChanged(event), None)
+            .await?;
 
-        self.event_bus
-            .publish(event_with_correlation)
-            .await
-            .map_err(|e| ApplicationError::EventPublishingFailed(e.to_string()))?;
+        Ok(warnings)
+    }
 
+    /// 明細の単価を書籍カタログの現在の価格と照合し、差異のある明細を返す
+    /// 書籍カタログが設定されていない場合は照合しない
+    async fn detect_price_changes(
+        &self,
+        order: &Order,
+    ) -> Result<Vec<PriceChange>, ApplicationError> {
+        let Some(book_catalog) = &self.book_catalog else {
+            return Ok(Vec::new());
+        };
+        let book_ids: Vec<BookId> = order
+            .order_lines()
+            .iter()
+            .map(|line| line.book_id())
+            .collect();
+        let catalog_prices = book_catalog.find_prices(&book_ids).await?;
+        Ok(pricing::detect_price_changes(order, &catalog_prices))
+    }
+
+    /// 明細の単価をカタログの現在の価格に更新する
+    fn apply_price_changes(
+        order: &mut Order,
+        changes: &[PriceChange],
+    ) -> Result<(), ApplicationError> {
+        for change in changes {
+            order.reprice_line(change.book_id, change.current_price)?;
+        }
         Ok(())
     }
 
-    /// 注文をキャンセル
+    /// 注文を確定
+    /// 書籍カタログが設定されている場合は、確定前に明細の単価をカタログの価格と照合し、
+    /// 価格変更ポリシーに従って確定を拒否するか単価を更新する
     ///
     /// # Arguments
     /// * `order_id` - 注文ID
     ///
     /// # Returns
-    /// * `Ok(())` - キャンセル成功
-    /// * `Err(ApplicationError)` - キャンセル失敗
-    pub async fn cancel_order(&self, order_id: OrderId) -> Result<(), ApplicationError> {
+    /// * `Ok(CommandAcknowledgement)` - 確定成功（発行したOrderConfirmedと、単価を更新した場合の警告を含む。
+    ///   発行に失敗して再試行待ちにした場合は操作IDを含む）
+    /// * `Err(ApplicationError)` - 確定失敗（価格が変更されていて拒否した場合を含む）
+    #[tracing::instrument(name = "command.confirm_order", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn confirm_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
         let mut order = self
             .order_repository
             .find_by_id(order_id)
@@ -211,75 +842,95 @@ where
                 ))
             })?;
 
-        order.cancel()?;
-        self.order_repository.save(&order).await?;
+        if order.status() == OrderStatus::Pending {
+            let changes = self.detect_price_changes(&order).await?;
+            if !changes.is_empty() {
+                match self.price_change_policy {
+                    PriceChangePolicy::Reject => {
+                        return Err(pricing::price_changed_error(&changes).into())
+                    }
+                    PriceChangePolicy::Reprice => Self::apply_price_changes(&mut order, &changes)?,
+                }
+            }
+        }
 
-        let correlation_id = Uuid::new_v4();
-        let event = OrderCancelled::new(
+        order.confirm()?;
+        // 確定でサーガを開始し、以降の発送・配達・キャンセルのイベントに同じ相関IDを引き継ぐ
+        order.begin_saga(Uuid::new_v4());
+        // 確定時の金額を保存し、以降は配送料ポリシーや税率が変わっても確定時の金額を使う
+        order.snapshot_totals(&self.shipping_fee_policy);
+        order.estimate_delivery(&self.delivery_estimator);
+        let warnings = order.take_warnings();
+
+        let total_amount = OrderTotals::for_order(&order, &self.shipping_fee_policy).total;
+        let event = OrderConfirmed::new(
             order.id(),
             order.customer_id(),
             order.order_lines().to_vec(),
+            total_amount,
         );
-        let event_with_correlation =
-            self.set_correlation_id_to_event(DomainEvent::OrderCancelled(event), correlation_id);
-
-        self.event_bus
-            .publish(event_with_correlation)
-            .await
-            .map_err(|e| ApplicationError::EventPublishingFailed(e.to_string()))?;
+        let acknowledgement = self
+            .save_and_publish(&mut order, DomainEvent::OrderConfirmed(event), None)
+            .await?;
 
-        Ok(())
+        Ok(CommandAcknowledgement {
+            warnings,
+            ..acknowledgement
+        })
     }
 
-    /// 注文を発送済みにマーク
+    /// Pending状態の注文の明細の単価を書籍カタログの現在の価格に更新
+    /// 価格変更ポリシーがrejectの場合に、利用者が価格を確認してから確定するために使用する
     ///
     /// # Arguments
     /// * `order_id` - 注文ID
     ///
     /// # Returns
-    /// * `Ok(())` - マーク成功
-    /// * `Err(ApplicationError)` - マーク失敗
-    pub async fn mark_order_as_shipped(&self, order_id: OrderId) -> Result<(), ApplicationError> {
+    /// * `Ok(Vec<DomainWarning>)` - 更新成功（単価を更新した明細ごとの警告）
+    /// * `Err(ApplicationError)` - 更新失敗
+    #[tracing::instrument(name = "command.reprice_order", skip_all, fields(order_id = %order_id), err)]
+    pub async fn reprice_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<DomainWarning>, ApplicationError> {
         let mut order = self
             .order_repository
             .find_by_id(order_id)
             .await?
             .ok_or_else(|| {
-                ApplicationError::NotFound(format!(
-                    "注文が見つかりません: {}",
-                    order_id
-                ))
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
             })?;
+        if order.status() != OrderStatus::Pending {
+            return Err(DomainError::InvalidOrderState(
+                "再価格付けはPending状態の注文でのみ実行できます".to_string(),
+            )
+            .into());
+        }
 
-        order.mark_as_shipped()?;
-        self.order_repository.save(&order).await?;
-
-        let correlation_id = Uuid::new_v4();
-        let shipping_address = order
-            .shipping_address()
-            .expect("Confirmed状態の注文には配送先住所が必須です")
-            .clone();
-        let event = OrderShipped::new(order.id(), shipping_address);
-        let event_with_correlation =
-            self.set_correlation_id_to_event(DomainEvent::OrderShipped(event), correlation_id);
-
-        self.event_bus
-            .publish(event_with_correlation)
-            .await
-            .map_err(|e| ApplicationError::EventPublishingFailed(e.to_string()))?;
-
-        Ok(())
+        let changes = self.detect_price_changes(&order).await?;
+        if changes.is_empty() {
+            return Ok(Vec::new());
+        }
+        Self::apply_price_changes(&mut order, &changes)?;
+        let warnings = order.take_warnings();
+        self.order_repository.save(&mut order).await?;
+        Ok(warnings)
     }
 
-    /// 注文を配達完了にマーク
+    /// 注文をキャンセル
+    /// 確定済みの注文はキャンセルポリシーの受付期限内に限ってキャンセルできる
     ///
     /// # Arguments
     /// * `order_id` - 注文ID
     ///
     /// # Returns
-    /// * `Ok(())` - マーク成功
-    /// * `Err(ApplicationError)` - マーク失敗
-    pub async fn mark_order_as_delivered(&self, order_id: OrderId) -> Result<(), ApplicationError> {
+    /// * `Ok(CommandAcknowledgement)` - キャンセル成功（発行したOrderCancelledを含む）
+    /// * `Err(ApplicationError)` - キャンセル失敗（受付期限を過ぎた場合はCancellationWindowExpired）
+    #[tracing::instrument(name = "command.cancel_order", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn cancel_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
         let mut order = self
             .order_repository
             .find_by_id(order_id)
@@ -291,20 +942,457 @@ where
                 ))
             })?;
 
-        order.mark_as_delivered()?;
-        self.order_repository.save(&order).await?;
-
-        let correlation_id = Uuid::new_v4();
-        let event = OrderDelivered::new(order.id());
-        let event_with_correlation =
-            self.set_correlation_id_to_event(DomainEvent::OrderDelivered(event), correlation_id);
-
-        self.event_bus
-            .publish(event_with_correlation)
-            .await
-            .map_err(|e| ApplicationError::EventPublishingFailed(e.to_string()))?;
+        order.cancel_with_policy(&self.cancellation_policy, Utc::now())?;
 
-        Ok(())
+        let event = OrderCancelled::new(
+            order.id(),
+            order.customer_id(),
+            order.order_lines().to_vec(),
+        );
+        self.save_and_publish(&mut order, DomainEvent::OrderCancelled(event), None)
+            .await
+    }
+
+    /// 確定済み（発送前）の注文を保留にする
+    /// 保留中の注文は解除するまで発送されず、顧客への通知も見送られる
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `reason` - 保留にする理由
+    /// * `note` - 補足（管理者のメモなど）
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - 保留成功（発行したOrderHeldを含む）
+    /// * `Err(ApplicationError)` - 保留失敗（Confirmed以外の注文・電子書籍のみの注文）
+    #[tracing::instrument(name = "command.place_order_on_hold", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn place_order_on_hold(
+        &self,
+        order_id: OrderId,
+        reason: HoldReason,
+        note: Option<String>,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+
+        order.place_on_hold(reason)?;
+
+        let event = OrderHeld::new(order.id(), order.customer_id(), reason, note);
+        self.save_and_publish(&mut order, DomainEvent::OrderHeld(event), None)
+            .await
+    }
+
+    /// 保留中の注文の保留を解除する
+    /// 解除した注文は確定済みに戻り、配達予定日を見積もり直す
+    /// （FULFILLMENT_MODEがauto / hybridの場合は、OrderReleasedを受けて自動で発送する）
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - 解除成功（発行したOrderReleasedを含む）
+    /// * `Err(ApplicationError)` - 解除失敗（OnHold以外の注文）
+    #[tracing::instrument(name = "command.release_order_hold", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn release_order_hold(
+        &self,
+        order_id: OrderId,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+
+        let reason = order.release_hold()?;
+        order.estimate_delivery(&self.delivery_estimator);
+
+        let event = OrderReleased::new(order.id(), order.customer_id(), reason);
+        self.save_and_publish(&mut order, DomainEvent::OrderReleased(event), None)
+            .await
+    }
+
+    /// 注文を発送済みにマーク
+    /// 総重量・梱包サイズが配送業者の上限を超える注文は発送できない
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `correlation_id` - OrderShippedの相関ID（Noneの場合は確定時に始まったサーガの相関ID）
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - マーク成功（発行したOrderShippedを含む）
+    /// * `Err(ApplicationError)` - マーク失敗（フルフィルメントモードがautoの場合はUnsupported）
+    #[tracing::instrument(name = "command.mark_order_as_shipped", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn mark_order_as_shipped(
+        &self,
+        order_id: OrderId,
+        correlation_id: Option<Uuid>,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        self.ensure_manual_transitions_allowed()?;
+        let mut order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "注文が見つかりません: {}",
+                    order_id
+                ))
+            })?;
+
+        // 1梱包で発送できない注文は発送済みにしない
+        self.shipping_fee_policy
+            .carrier_limits()
+            .ensure_can_ship(&order)?;
+        order.mark_as_shipped()?;
+        // 発送日から配達予定日を見積もり直す
+        order.estimate_delivery(&self.delivery_estimator);
+
+        let shipping_address = order
+            .shipping_address()
+            .expect("Confirmed状態の注文には配送先住所が必須です")
+            .clone();
+        let event = OrderShipped::new(order.id(), shipping_address)
+            .with_recipient(order.recipient().cloned())
+            .with_estimated_delivery_date(order.estimated_delivery_date());
+        self.save_and_publish(&mut order, DomainEvent::OrderShipped(event), correlation_id)
+            .await
+    }
+
+    /// 注文を配達完了にマーク
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `correlation_id` - OrderDeliveredの相関ID（Noneの場合は確定時に始まったサーガの相関ID）
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - マーク成功（発行したOrderDeliveredを含む）
+    /// * `Err(ApplicationError)` - マーク失敗（フルフィルメントモードがautoの場合はUnsupported）
+    #[tracing::instrument(name = "command.mark_order_as_delivered", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn mark_order_as_delivered(
+        &self,
+        order_id: OrderId,
+        correlation_id: Option<Uuid>,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        self.ensure_manual_transitions_allowed()?;
+        let mut order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "注文が見つかりません: {}",
+                    order_id
+                ))
+            })?;
+
+        order.mark_as_delivered()?;
+
+        let event = OrderDelivered::new(order.id()).with_recipient(order.recipient().cloned());
+        self.save_and_publish(&mut order, DomainEvent::OrderDelivered(event), correlation_id)
+            .await
+    }
+
+    /// 配達済みの注文の返品を受け付ける
+    /// 返品を承認するまで在庫は戻さない
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `reason` - 返品の理由（顧客の申告）
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - 受付成功（発行したReturnRequestedを含む）
+    /// * `Err(ApplicationError)` - 受付失敗（Delivered以外の注文）
+    #[tracing::instrument(name = "command.request_order_return", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn request_order_return(
+        &self,
+        order_id: OrderId,
+        reason: Option<String>,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+
+        order.return_order()?;
+
+        let event = ReturnRequested::new(order.id(), order.customer_id(), reason);
+        self.save_and_publish(&mut order, DomainEvent::ReturnRequested(event), None)
+            .await
+    }
+
+    /// 返品を承認する
+    /// ReturnApprovedを受けてReturnHandlerが返品された書籍を在庫に戻す
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - 承認成功（発行したReturnApprovedを含む）
+    /// * `Err(ApplicationError)` - 承認失敗（ReturnRequested以外の注文）
+    #[tracing::instrument(name = "command.approve_order_return", skip_all, fields(order_id = %order_id, correlation_id = tracing::field::Empty), err)]
+    pub async fn approve_order_return(
+        &self,
+        order_id: OrderId,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+
+        order.approve_return()?;
+
+        let event = ReturnApproved::new(
+            order.id(),
+            order.customer_id(),
+            order.order_lines().to_vec(),
+        );
+        self.save_and_publish(&mut order, DomainEvent::ReturnApproved(event), None)
+            .await
+    }
+
+    /// 管理者が注文のステータスを強制的に変更する（誤った状態で止まった注文の修正用）
+    /// 強制遷移の遷移表で検証して変更し、監査記録を残してからOrderStatusForcefullyChangedを発行する
+    /// 通常の遷移のイベントは発行しないため、在庫の予約・解放などのサーガは起動しない
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `to` - 遷移先のステータス
+    /// * `reason` - 強制遷移の理由
+    /// * `operator` - 強制遷移を行うオペレーター
+    /// * `now` - 変更日時
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - 変更を受け付けた（発行したOrderStatusForcefullyChangedを含む）
+    /// * `Err(ApplicationError::NotFound)` - 注文が見つからない
+    /// * `Err(ApplicationError::DomainError)` - 許可されていない遷移・理由やオペレーターの指定がない
+    /// * `Err(ApplicationError::Unsupported)` - 強制遷移の監査記録が設定されていない
+    #[tracing::instrument(name = "command.force_order_status", skip_all, fields(order_id = %order_id, to_status = %to, correlation_id = tracing::field::Empty), err)]
+    pub async fn force_order_status(
+        &self,
+        order_id: OrderId,
+        to: OrderStatus,
+        reason: &str,
+        operator: &str,
+        now: DateTime<Utc>,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        let Some(status_override_audits) = &self.status_override_audits else {
+            return Err(ApplicationError::Unsupported(
+                "強制遷移の監査記録が設定されていないため、ステータスを強制的に変更できません"
+                    .to_string(),
+            ));
+        };
+        let mut order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+
+        order.force_status(to, reason, operator, now)?;
+        let status_override = order
+            .status_override()
+            .cloned()
+            .expect("強制遷移した注文は強制遷移を保持している");
+
+        // 監査記録を先に残す（記録できない場合はステータスを変更しない）
+        let event =
+            OrderStatusForcefullyChanged::new(order.id(), order.customer_id(), &status_override);
+        let audit =
+            StatusOverrideAudit::record(order_id, &status_override, event.metadata.event_id, now);
+        status_override_audits.add(&audit).await?;
+
+        self.save_and_publish(
+            &mut order,
+            DomainEvent::OrderStatusForcefullyChanged(event),
+            None,
+        )
+        .await
+    }
+
+    /// 注文の強制遷移の監査記録を取得（記録日時の昇順）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<StatusOverrideAudit>)` - 監査記録（強制遷移していない注文は空）
+    /// * `Err(ApplicationError::Unsupported)` - 強制遷移の監査記録が設定されていない
+    pub async fn list_status_overrides(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<StatusOverrideAudit>, ApplicationError> {
+        let Some(status_override_audits) = &self.status_override_audits else {
+            return Err(ApplicationError::Unsupported(
+                "強制遷移の監査記録が設定されていません".to_string(),
+            ));
+        };
+        Ok(status_override_audits.find_by_order(order_id).await?)
+    }
+
+    /// 複数の注文にまとめてステータス遷移を適用
+    /// 注文ごとに独立して遷移させてイベントを発行し、失敗した注文があっても残りの注文の処理を続ける
+    /// （成功した注文の遷移は取り消さない）。同じ注文IDが複数回指定された場合は最初の1回だけ処理する
+    ///
+    /// # Arguments
+    /// * `transition` - 適用するステータス遷移
+    /// * `order_ids` - 注文IDのリスト（1件以上MAX_BULK_TRANSITION_SIZE件以下）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<BulkTransitionOutcome>)` - 指定された順の注文ごとの結果
+    /// * `Err(ApplicationError)` - 注文IDのリストが空または上限を超える、または発送・配達でフルフィルメントモードがauto
+    pub async fn bulk_transition(
+        &self,
+        transition: BulkTransition,
+        order_ids: &[OrderId],
+    ) -> Result<Vec<BulkTransitionOutcome>, ApplicationError> {
+        if order_ids.is_empty() {
+            return Err(ApplicationError::DomainError(DomainError::InvalidValue(
+                "注文IDが指定されていません".to_string(),
+            )));
+        }
+        if order_ids.len() > MAX_BULK_TRANSITION_SIZE {
+            return Err(ApplicationError::DomainError(DomainError::InvalidValue(format!(
+                "1回に指定できる注文は{}件までです",
+                MAX_BULK_TRANSITION_SIZE
+            ))));
+        }
+        if transition != BulkTransition::Cancel {
+            self.ensure_manual_transitions_allowed()?;
+        }
+
+        let mut outcomes: Vec<BulkTransitionOutcome> = Vec::with_capacity(order_ids.len());
+        for &order_id in order_ids {
+            if outcomes.iter().any(|outcome| outcome.order_id == order_id) {
+                continue;
+            }
+            let result = match transition {
+                BulkTransition::Ship => self.mark_order_as_shipped(order_id, None).await,
+                BulkTransition::Deliver => self.mark_order_as_delivered(order_id, None).await,
+                BulkTransition::Cancel => self.cancel_order(order_id).await,
+            }
+            .map(|acknowledgement| acknowledgement.event);
+            outcomes.push(BulkTransitionOutcome { order_id, result });
+        }
+
+        Ok(outcomes)
+    }
+
+    /// 一括キャンセルの対象をプレビュー
+    /// 実行する前に対象の注文を確認させ、実行時に指定する確認トークンを返す
+    ///
+    /// # Arguments
+    /// * `filter` - 対象を絞り込む条件
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(BulkCancellationPreview)` - 対象の注文と確認トークン
+    /// * `Err(ApplicationError)` - 対象がMAX_BULK_TRANSITION_SIZE件を超える、または取得失敗
+    pub async fn preview_bulk_cancellation(
+        &self,
+        filter: &BulkCancellationFilter,
+        now: DateTime<Utc>,
+    ) -> Result<BulkCancellationPreview, ApplicationError> {
+        let orders = self
+            .order_repository
+            .find_by_status_created_before(filter.status, filter.created_before(now))
+            .await?;
+        if orders.len() > MAX_BULK_TRANSITION_SIZE {
+            return Err(ApplicationError::DomainError(DomainError::InvalidValue(format!(
+                "条件に一致する注文が{}件を超えています。条件を絞り込んでください",
+                MAX_BULK_TRANSITION_SIZE
+            ))));
+        }
+
+        let order_ids: Vec<OrderId> = orders.iter().map(|order| order.id()).collect();
+        let confirmation_token = filter.confirmation_token(&order_ids);
+        Ok(BulkCancellationPreview {
+            orders,
+            confirmation_token,
+        })
+    }
+
+    /// 確認トークンを照合して一括キャンセルの対象を確定
+    /// プレビュー後に対象の注文が変わっていた場合は、改めてプレビューさせるためにエラーにする
+    ///
+    /// # Arguments
+    /// * `filter` - プレビューと同じ条件
+    /// * `confirmation_token` - プレビューで返した確認トークン
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(Vec<OrderId>)` - キャンセルする注文ID
+    /// * `Err(ApplicationError)` - 確認トークンが一致しない、または取得失敗
+    pub async fn confirm_bulk_cancellation(
+        &self,
+        filter: &BulkCancellationFilter,
+        confirmation_token: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<OrderId>, ApplicationError> {
+        let preview = self.preview_bulk_cancellation(filter, now).await?;
+        if preview.confirmation_token != confirmation_token {
+            return Err(ApplicationError::DomainError(
+                DomainError::ConfirmationTokenMismatch(
+                    "プレビュー後に対象の注文が変わりました。改めてプレビューしてください"
+                        .to_string(),
+                ),
+            ));
+        }
+
+        Ok(preview.orders.iter().map(|order| order.id()).collect())
+    }
+
+    /// 納品書を取得
+    /// ギフト注文では金額を伏せ、受取人を宛先にする
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(PackingSlip)` - 納品書
+    /// * `Err(ApplicationError)` - 注文が見つからない、または取得失敗
+    pub async fn get_packing_slip(&self, order_id: OrderId) -> Result<PackingSlip, ApplicationError> {
+        let order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "注文が見つかりません: {}",
+                    order_id
+                ))
+            })?;
+        Ok(PackingSlip::build(&order, &self.shipping_fee_policy))
+    }
+
+    /// 追跡トークンで注文の公開用の配送状況を取得
+    /// アカウントなしで確認できるため、一部を伏せた最小限の情報のみ返す
+    ///
+    /// # Arguments
+    /// * `token` - 確定時に発行した追跡トークン
+    ///
+    /// # Returns
+    /// * `Ok(PublicTrackingView)` - 公開用の配送状況
+    /// * `Err(ApplicationError)` - トークンに対応する注文がない（キャンセルで失効した場合を含む）、または取得失敗
+    pub async fn get_public_tracking(
+        &self,
+        token: &TrackingToken,
+    ) -> Result<PublicTrackingView, ApplicationError> {
+        let order = self
+            .order_repository
+            .find_by_tracking_token(token)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound("追跡情報が見つかりません".to_string()))?;
+        Ok(PublicTrackingView::build(&order, &self.sla_policy))
     }
 
     /// 注文IDで注文を取得
@@ -323,6 +1411,86 @@ where
             .map_err(ApplicationError::from)
     }
 
+    /// 注文に関して以降に発行されるイベントを購読する
+    /// 注文の存在を確認する間に発行されたイベントを取りこぼさないように、確認より先に購読する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(OrderEventSubscription)` - 購読
+    /// * `Err(ApplicationError::NotFound)` - 注文が見つからない
+    /// * `Err(ApplicationError::Unsupported)` - ライブイベントの受け取り先が設定されていない
+    pub async fn subscribe_order_events(
+        &self,
+        order_id: OrderId,
+    ) -> Result<OrderEventSubscription, ApplicationError> {
+        let live_events = self.live_events.as_ref().ok_or_else(|| {
+            ApplicationError::Unsupported(
+                "注文のイベントストリームは利用できません".to_string(),
+            )
+        })?;
+        let events = live_events.subscribe_all();
+        if self.order_repository.find_by_id(order_id).await?.is_none() {
+            return Err(ApplicationError::NotFound(format!(
+                "注文が見つかりません: {}",
+                order_id
+            )));
+        }
+        Ok(OrderEventSubscription {
+            events,
+            filter: OrderEventStreamFilter::new(order_id),
+        })
+    }
+
+    /// 注文番号で注文を取得（電話などで注文番号を伝えられた場合の検索用）
+    ///
+    /// # Arguments
+    /// * `order_number` - 注文番号（大文字・小文字は区別しない）
+    ///
+    /// # Returns
+    /// * `Ok(Some(Order))` - 注文が見つかった
+    /// * `Ok(None)` - 注文が見つからなかった
+    /// * `Err(ApplicationError::DomainError)` - 注文番号の形式が正しくない（VALIDATION_FAILED）
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn get_order_by_number(
+        &self,
+        order_number: &str,
+    ) -> Result<Option<Order>, ApplicationError> {
+        let order_number = OrderNumber::parse(order_number)?;
+        self.order_repository
+            .find_by_order_number(&order_number)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// 誤って二重に購入した可能性がある注文を探す（サポートでの返金対応用）
+    /// 同じ顧客・同じ明細で、作成日時の差が指定した時間以内の注文（キャンセル済みを除く）を返す
+    ///
+    /// # Arguments
+    /// * `order_id` - 基準にする注文ID
+    /// * `within` - 作成日時の差の上限
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Order>)` - 重複の可能性がある注文のリスト（作成日時の昇順）
+    /// * `Err(ApplicationError)` - 注文が見つからない、または取得失敗
+    pub async fn find_similar_orders(
+        &self,
+        order_id: OrderId,
+        within: TimeDelta,
+    ) -> Result<Vec<Order>, ApplicationError> {
+        if self.order_repository.find_by_id(order_id).await?.is_none() {
+            return Err(ApplicationError::NotFound(format!(
+                "注文が見つかりません: {}",
+                order_id
+            )));
+        }
+        self.order_repository
+            .find_similar_orders(order_id, within)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
     /// すべての注文を取得
     /// 作成日時の降順で並べて返す
     ///
@@ -379,6 +1547,9 @@ where
 /// 在庫アプリケーションサービス
 pub struct InventoryApplicationService {
     inventory_repository: Arc<dyn InventoryRepository>,
+    event_bus: Arc<dyn EventBus>,
+    /// 割当枠リポジトリ（設定されていない場合は割当枠を管理できない）
+    allocation_quotas: Option<Arc<dyn AllocationQuotaRepository>>,
 }
 
 impl InventoryApplicationService {
@@ -386,33 +1557,107 @@ impl InventoryApplicationService {
     ///
     /// # Arguments
     /// * `inventory_repository` - 在庫リポジトリ
-    pub fn new(inventory_repository: Arc<dyn InventoryRepository>) -> Self {
+    /// * `event_bus` - イベントバス
+    pub fn new(
+        inventory_repository: Arc<dyn InventoryRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
         Self {
             inventory_repository,
+            event_bus,
+            allocation_quotas: None,
         }
     }
 
+    /// 割当枠リポジトリを設定する（限定版などの購入上限・販売チャネル別の割当枠を管理する）
+    ///
+    /// # Arguments
+    /// * `allocation_quotas` - 割当枠リポジトリ
+    ///
+    /// # Returns
+    /// * 割当枠を管理できるInventoryApplicationService
+    pub fn with_allocation_quotas(
+        mut self,
+        allocation_quotas: Arc<dyn AllocationQuotaRepository>,
+    ) -> Self {
+        self.allocation_quotas = Some(allocation_quotas);
+        self
+    }
+
     /// 新しい在庫を作成
     ///
     /// # Arguments
     /// * `book_id` - 書籍ID
     /// * `quantity` - 初期在庫数
+    /// * `release_date` - 発売日（未発売の書籍の場合に指定）
+    /// * `waitlist_enabled` - 在庫を超える注文を順番待ちにするかどうか（人気の新刊など）
     ///
     /// # Returns
     /// * `Ok(())` - 作成成功
     /// * `Err(ApplicationError)` - 作成失敗
+    #[tracing::instrument(name = "command.create_inventory", skip_all, fields(book_id = %book_id, quantity = quantity), err)]
     pub async fn create_inventory(
         &self,
         book_id: BookId,
         quantity: u32,
+        release_date: Option<NaiveDate>,
+        waitlist_enabled: bool,
     ) -> Result<(), ApplicationError> {
-        let inventory = Inventory::new(book_id, quantity);
+        let inventory = Inventory::new(book_id, quantity)
+            .with_release_date(release_date)
+            .with_waitlist_enabled(waitlist_enabled);
         self.inventory_repository
             .save(&inventory)
             .await
             .map_err(ApplicationError::from)
     }
 
+    /// 入荷した数量を在庫に加える
+    /// InventoryAdjustedイベントを発行し、順番待ちの注文を先着順に繰り上げる
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `quantity` - 入荷数
+    ///
+    /// # Returns
+    /// * `Ok(Inventory)` - 入荷後の在庫
+    /// * `Err(ApplicationError::NotFound)` - 在庫が見つからない
+    #[tracing::instrument(name = "command.restock_inventory", skip_all, fields(book_id = %book_id, quantity = quantity), err)]
+    pub async fn restock_inventory(
+        &self,
+        book_id: BookId,
+        quantity: u32,
+    ) -> Result<Inventory, ApplicationError> {
+        if quantity == 0 {
+            return Err(DomainError::InvalidQuantity.into());
+        }
+        let mut inventory = self
+            .inventory_repository
+            .find_by_book_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("書籍ID {} の在庫が見つかりません", book_id))
+            })?;
+
+        let previous_quantity = inventory.quantity_on_hand();
+        inventory.adjust(i64::from(quantity))?;
+        self.inventory_repository.save(&inventory).await?;
+
+        let event = InventoryAdjusted::with_correlation_id(
+            book_id,
+            previous_quantity,
+            inventory.quantity_on_hand(),
+            RESTOCK_ADJUSTMENT_REASON.to_string(),
+            Uuid::new_v4(),
+        );
+        self.event_bus
+            .publish(DomainEvent::InventoryAdjusted(event))
+            .await
+            .map_err(|e| ApplicationError::EventPublishingFailed(e.to_string()))?;
+
+        Ok(inventory)
+    }
+
     /// 書籍IDで在庫を取得
     ///
     /// # Arguments
@@ -463,4 +1708,2847 @@ impl InventoryApplicationService {
             .await
             .map_err(ApplicationError::from)
     }
+
+    /// 在庫変動ログと食い違っている在庫を検出して修復する（管理者向け）
+    /// 食い違っている書籍を凍結し、棚卸しで照合するためのレポートを返す（在庫数と在庫変動ログは変更しない）
+    /// 凍結した書籍は照合後にunfreeze_inventoryで予約を再開する
+    ///
+    /// # Arguments
+    /// * `now` - レポートの作成日時
+    ///
+    /// # Returns
+    /// * `Ok(InventoryReconciliationReport)` - 検出・凍結の結果
+    /// * `Err(ApplicationError)` - 検出または凍結に失敗
+    #[tracing::instrument(name = "command.repair_ledger_drifts", skip_all, err)]
+    pub async fn repair_ledger_drifts(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<InventoryReconciliationReport, ApplicationError> {
+        let drifts = self.inventory_repository.find_ledger_drifts().await?;
+
+        let mut results = Vec::with_capacity(drifts.len());
+        for drift in drifts {
+            let frozen = self
+                .inventory_repository
+                .freeze_ledger_drift(drift.book_id)
+                .await?;
+            results.push((drift, frozen));
+        }
+
+        Ok(InventoryReconciliationReport::build(now, &results))
+    }
+
+    /// 在庫評価レポートを作成（書籍ごとの在庫数 × 販売価格と、その合計）
+    /// 販売価格は書籍カタログの現在の価格を使う
+    ///
+    /// # Arguments
+    /// * `now` - レポートの作成日時
+    ///
+    /// # Returns
+    /// * `Ok(InventoryValuationReport)` - 在庫評価レポート
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn get_valuation_report(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<InventoryValuationReport, ApplicationError> {
+        let valuations = self.inventory_repository.find_stock_valuations().await?;
+        Ok(InventoryValuationReport::build(now, &valuations))
+    }
+
+    /// 在庫のスナップショットを取得（店舗のPOSの全件同期用）
+    /// スナップショットのversionを起点にget_inventory_changesで差分を同期する
+    ///
+    /// # Arguments
+    /// * `now` - スナップショットの取得日時
+    ///
+    /// # Returns
+    /// * `Ok(InventorySnapshot)` - 書籍ごとの在庫数とversion
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn get_inventory_snapshot(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<InventorySnapshot, ApplicationError> {
+        self.inventory_repository
+            .take_snapshot(now)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// 指定したversionより後の在庫変動を取得（店舗のPOSの差分同期用）
+    /// 1回に返す件数には上限があり、残りがある場合はhas_moreがtrueになる
+    ///
+    /// # Arguments
+    /// * `since_version` - 同期済みの最後のversion（スナップショットまたは前回の差分のversion）
+    ///
+    /// # Returns
+    /// * `Ok(InventoryChanges)` - versionの昇順の在庫変動
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn get_inventory_changes(
+        &self,
+        since_version: u64,
+    ) -> Result<InventoryChanges, ApplicationError> {
+        // 上限を超える変動が残っているかを判定するため1件多く取得する
+        let movements = self
+            .inventory_repository
+            .find_movements_since(since_version, MAX_CHANGES_PER_PAGE + 1)
+            .await?;
+        Ok(InventoryChanges::build(
+            since_version,
+            movements,
+            MAX_CHANGES_PER_PAGE,
+        ))
+    }
+
+    /// 在庫の凍結を解除する（照合の完了後に予約を再開する）
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    ///
+    /// # Returns
+    /// * `Ok(Inventory)` - 凍結を解除した在庫
+    /// * `Err(ApplicationError::NotFound)` - 在庫が見つからない
+    #[tracing::instrument(name = "command.unfreeze_inventory", skip_all, fields(book_id = %book_id), err)]
+    pub async fn unfreeze_inventory(&self, book_id: BookId) -> Result<Inventory, ApplicationError> {
+        let mut inventory = self
+            .inventory_repository
+            .find_by_book_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("書籍ID {} の在庫が見つかりません", book_id))
+            })?;
+
+        inventory.unfreeze();
+        self.inventory_repository.save(&inventory).await?;
+        Ok(inventory)
+    }
+
+    /// 割当枠リポジトリを取得する（設定されていない構成ではUnsupported）
+    fn allocation_quota_repository(
+        &self,
+    ) -> Result<&Arc<dyn AllocationQuotaRepository>, ApplicationError> {
+        self.allocation_quotas.as_ref().ok_or_else(|| {
+            ApplicationError::Unsupported("割当枠の管理が設定されていません".to_string())
+        })
+    }
+
+    /// 書籍の割当枠を設定する
+    /// 既に設定されている場合は設定だけを変更し、割当済みの数量は引き継ぐ
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `per_customer_cap` - 1顧客が購入できる最大数量（Noneの場合は制限しない）
+    /// * `channel_shares` - 販売チャネルごとの割当比率（%）
+    /// * `allocation_pool` - 割当比率をかける数量（Noneの場合は現在の在庫数）
+    ///
+    /// # Returns
+    /// * `Ok(AllocationQuota)` - 設定した割当枠
+    /// * `Err(ApplicationError::NotFound)` - 在庫が見つからない
+    /// * `Err(ApplicationError::DomainError)` - 割当枠の設定が不正
+    /// * `Err(ApplicationError::Unsupported)` - 割当枠の管理が設定されていない
+    #[tracing::instrument(name = "command.set_allocation_quota", skip_all, fields(book_id = %book_id), err)]
+    pub async fn set_allocation_quota(
+        &self,
+        book_id: BookId,
+        per_customer_cap: Option<u32>,
+        channel_shares: BTreeMap<SalesChannel, u32>,
+        allocation_pool: Option<u32>,
+    ) -> Result<AllocationQuota, ApplicationError> {
+        let repository = self.allocation_quota_repository()?;
+        let inventory = self
+            .inventory_repository
+            .find_by_book_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("書籍ID {} の在庫が見つかりません", book_id))
+            })?;
+        let allocation_pool = allocation_pool.unwrap_or(inventory.quantity_on_hand());
+
+        let quota = match repository.find_by_book_id(book_id).await? {
+            Some(mut quota) => {
+                quota.reconfigure(per_customer_cap, channel_shares, allocation_pool)?;
+                quota
+            }
+            None => AllocationQuota::new(book_id, per_customer_cap, channel_shares, allocation_pool)?,
+        };
+        repository.save(&quota).await?;
+        Ok(quota)
+    }
+
+    /// 書籍の割当枠を取得する
+    ///
+    /// # Returns
+    /// * `Ok(Some(AllocationQuota))` - 割当枠
+    /// * `Ok(None)` - 割当枠が設定されていない
+    /// * `Err(ApplicationError::Unsupported)` - 割当枠の管理が設定されていない
+    pub async fn get_allocation_quota(
+        &self,
+        book_id: BookId,
+    ) -> Result<Option<AllocationQuota>, ApplicationError> {
+        Ok(self
+            .allocation_quota_repository()?
+            .find_by_book_id(book_id)
+            .await?)
+    }
+
+    /// 書籍の割当枠を解除する（以降は制限なく予約できる）
+    ///
+    /// # Returns
+    /// * `Ok(())` - 解除成功
+    /// * `Err(ApplicationError::NotFound)` - 割当枠が設定されていない
+    /// * `Err(ApplicationError::Unsupported)` - 割当枠の管理が設定されていない
+    #[tracing::instrument(name = "command.remove_allocation_quota", skip_all, fields(book_id = %book_id), err)]
+    pub async fn remove_allocation_quota(&self, book_id: BookId) -> Result<(), ApplicationError> {
+        if !self.allocation_quota_repository()?.delete(book_id).await? {
+            return Err(ApplicationError::NotFound(format!(
+                "書籍ID {} の割当枠が設定されていません",
+                book_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 順番待ちアプリケーションサービス
+/// 顧客向けに、順番待ちの注文が書籍ごとに何番目に並んでいるかを照会する
+pub struct WaitlistApplicationService {
+    waitlist_repository: Arc<dyn WaitlistRepository>,
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl WaitlistApplicationService {
+    /// 新しい順番待ちアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `waitlist_repository` - 順番待ちリポジトリ
+    /// * `order_repository` - 注文リポジトリ
+    pub fn new(
+        waitlist_repository: Arc<dyn WaitlistRepository>,
+        order_repository: Arc<dyn OrderRepository>,
+    ) -> Self {
+        Self {
+            waitlist_repository,
+            order_repository,
+        }
+    }
+
+    /// 注文の順番待ちの位置を取得
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok((OrderStatus, Vec<WaitlistPosition>))` - 注文のステータスと書籍ごとの位置（順番待ちでない場合は空）
+    /// * `Err(ApplicationError::NotFound)` - 注文が見つからない
+    pub async fn get_positions(
+        &self,
+        order_id: OrderId,
+    ) -> Result<(OrderStatus, Vec<WaitlistPosition>), ApplicationError> {
+        let order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+
+        let mut positions = Vec::new();
+        for entry in self.waitlist_repository.find_by_order(order_id).await? {
+            let queue = self.waitlist_repository.find_by_book(entry.book_id).await?;
+            positions.extend(position_in_queue(&queue, order_id));
+        }
+        Ok((order.status(), positions))
+    }
+}
+
+/// 在庫の仮押さえアプリケーションサービス
+/// チェックアウトを開始した注文の物理書籍の在庫を期限付きで確保し、支払い情報の入力中の売り越しを防ぐ
+/// 仮押さえは注文の確定時に在庫予約へ引き継がれ、期限切れの仮押さえはCheckoutHoldSweeperが解放する
+pub struct CheckoutHoldApplicationService {
+    order_repository: Arc<dyn OrderRepository>,
+    inventory_repository: Arc<dyn InventoryRepository>,
+    hold_repository: Arc<dyn CheckoutHoldRepository>,
+    hold_duration: TimeDelta,
+    /// 割当枠リポジトリ（設定されている場合は、仮押さえの前に割当枠の範囲に収まるかを確認する）
+    allocation_quotas: Option<Arc<dyn AllocationQuotaRepository>>,
+}
+
+impl CheckoutHoldApplicationService {
+    /// 新しい在庫の仮押さえアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ
+    /// * `inventory_repository` - 在庫リポジトリ
+    /// * `hold_repository` - 在庫の仮押さえリポジトリ
+    /// * `hold_duration` - 仮押さえの期間
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        inventory_repository: Arc<dyn InventoryRepository>,
+        hold_repository: Arc<dyn CheckoutHoldRepository>,
+        hold_duration: TimeDelta,
+    ) -> Self {
+        Self {
+            order_repository,
+            inventory_repository,
+            hold_repository,
+            hold_duration,
+            allocation_quotas: None,
+        }
+    }
+
+    /// 割当枠リポジトリを設定する
+    /// 割当枠は在庫の予約時に記録するため、仮押さえでは確認だけを行い、超える注文を早い段階で断る
+    ///
+    /// # Arguments
+    /// * `allocation_quotas` - 割当枠リポジトリ
+    pub fn with_allocation_quotas(
+        mut self,
+        allocation_quotas: Arc<dyn AllocationQuotaRepository>,
+    ) -> Self {
+        self.allocation_quotas = Some(allocation_quotas);
+        self
+    }
+
+    /// 注文の物理書籍の在庫を仮押さえする
+    /// 既に仮押さえしている場合は現在の注文明細との差分だけ在庫を確保・解放し、期限を延長する
+    /// いずれかの書籍の在庫が足りない場合は、この呼び出しで確保した在庫を戻して失敗する（既存の仮押さえは維持する）
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `now` - 現在日時（期限の起点）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<CheckoutHold>)` - 書籍ごとの仮押さえ
+    /// * `Err(ApplicationError::NotFound)` - 注文が見つからない
+    /// * `Err(ApplicationError::DomainError)` - 注文が未確定でない、明細がない、在庫不足・在庫の凍結中、または割当枠の超過
+    #[tracing::instrument(name = "command.place_checkout_hold", skip_all, fields(order_id = %order_id), err)]
+    pub async fn place_hold(
+        &self,
+        order_id: OrderId,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<CheckoutHold>, ApplicationError> {
+        let order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+        if order.status() != OrderStatus::Pending {
+            return Err(DomainError::InvalidOrderState(format!(
+                "在庫を仮押さえできるのは未確定の注文のみです（現在: {}）",
+                order.status()
+            ))
+            .into());
+        }
+        if order.order_lines().is_empty() {
+            return Err(DomainError::from(FieldViolation::new(
+                "order_lines",
+                Constraint::Required,
+                None,
+                "注文明細がない注文の在庫は仮押さえできません",
+            ))
+            .into());
+        }
+
+        let held = held_quantities(&self.hold_repository.find_by_order(order_id).await?);
+        let expires_at = now + self.hold_duration;
+        let holds: Vec<CheckoutHold> = order
+            .order_lines()
+            .iter()
+            .filter(|line| !line.is_digital())
+            .map(|line| CheckoutHold::new(order_id, line.book_id(), line.quantity(), expires_at))
+            .collect();
+
+        // 割当枠のある書籍は、顧客の購入上限・販売チャネルの割当枠に収まるかを確認する
+        if let Some(allocation_quotas) = &self.allocation_quotas {
+            for hold in &holds {
+                if let Some(quota) = allocation_quotas.find_by_book_id(hold.book_id).await? {
+                    quota.ensure_can_allocate(
+                        order.customer_id(),
+                        order.sales_channel(),
+                        hold.quantity,
+                    )?;
+                }
+            }
+        }
+
+        // 追加で必要な数量を確保する（失敗した場合はここまでに確保した分を戻す）
+        let mut reserved: Vec<(BookId, u32)> = Vec::new();
+        for hold in &holds {
+            let additional = hold
+                .quantity
+                .saturating_sub(held.get(&hold.book_id).copied().unwrap_or(0));
+            if additional == 0 {
+                continue;
+            }
+            if let Err(e) = self.adjust_stock(hold.book_id, additional, true).await {
+                for (book_id, quantity) in reserved {
+                    self.adjust_stock(book_id, quantity, false).await?;
+                }
+                return Err(e);
+            }
+            reserved.push((hold.book_id, additional));
+        }
+
+        // 数量を減らした書籍・注文から外した書籍の余った分を解放する
+        for (book_id, held_quantity) in &held {
+            let quantity = holds
+                .iter()
+                .find(|hold| hold.book_id == *book_id)
+                .map_or(0, |hold| hold.quantity);
+            if *held_quantity > quantity {
+                self.adjust_stock(*book_id, held_quantity - quantity, false)
+                    .await?;
+            }
+        }
+
+        self.hold_repository
+            .replace_for_order(order_id, &holds)
+            .await?;
+        Ok(holds)
+    }
+
+    /// 書籍の在庫を確保（reserve = true）または解放する
+    async fn adjust_stock(
+        &self,
+        book_id: BookId,
+        quantity: u32,
+        reserve: bool,
+    ) -> Result<(), ApplicationError> {
+        let mut inventory = self
+            .inventory_repository
+            .find_by_book_id(book_id)
+            .await?
+            .unwrap_or_else(|| Inventory::new(book_id, 0));
+        if reserve {
+            inventory.reserve(quantity)?;
+        } else {
+            inventory.release(quantity)?;
+        }
+        self.inventory_repository.save(&inventory).await?;
+        Ok(())
+    }
+}
+
+/// 棚卸しアプリケーションサービス
+/// 棚卸しセッションの作成から実数の記録・提出・承認までを調整し、
+/// 承認された差異を在庫に反映する
+pub struct CycleCountApplicationService {
+    cycle_count_repository: Arc<dyn CycleCountRepository>,
+    inventory_repository: Arc<dyn InventoryRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl CycleCountApplicationService {
+    /// 新しい棚卸しアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `cycle_count_repository` - 棚卸しリポジトリ
+    /// * `inventory_repository` - 在庫リポジトリ
+    /// * `event_bus` - イベントバス
+    pub fn new(
+        cycle_count_repository: Arc<dyn CycleCountRepository>,
+        inventory_repository: Arc<dyn InventoryRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            cycle_count_repository,
+            inventory_repository,
+            event_bus,
+        }
+    }
+
+    /// 棚卸しセッションを開始
+    ///
+    /// # Arguments
+    /// * `book_ids` - 棚卸し対象の書籍ID（空の場合は登録済みのすべての在庫が対象）
+    ///
+    /// # Returns
+    /// * `Ok(CycleCountId)` - 作成された棚卸しのID
+    /// * `Err(ApplicationError)` - 作成失敗
+    #[tracing::instrument(name = "command.start_cycle_count", skip_all, fields(book_count = book_ids.len()), err)]
+    pub async fn start_cycle_count(
+        &self,
+        book_ids: Vec<BookId>,
+    ) -> Result<CycleCountId, ApplicationError> {
+        let book_ids = if book_ids.is_empty() {
+            self.inventory_repository
+                .find_all()
+                .await?
+                .iter()
+                .map(Inventory::book_id)
+                .collect()
+        } else {
+            book_ids
+        };
+
+        let cycle_count = CycleCount::new(CycleCountId::new(), book_ids)?;
+        self.cycle_count_repository.save(&cycle_count).await?;
+
+        Ok(cycle_count.id())
+    }
+
+    /// 棚卸しを取得
+    ///
+    /// # Arguments
+    /// * `cycle_count_id` - 棚卸しID
+    ///
+    /// # Returns
+    /// * `Ok(Some(CycleCount))` - 棚卸しが見つかった
+    /// * `Ok(None)` - 棚卸しが見つからなかった
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn get_cycle_count(
+        &self,
+        cycle_count_id: CycleCountId,
+    ) -> Result<Option<CycleCount>, ApplicationError> {
+        self.cycle_count_repository
+            .find_by_id(cycle_count_id)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// 実数を記録
+    ///
+    /// # Arguments
+    /// * `cycle_count_id` - 棚卸しID
+    /// * `counts` - 書籍IDと実数の組
+    ///
+    /// # Returns
+    /// * `Ok(())` - 記録成功
+    /// * `Err(ApplicationError)` - 記録失敗
+    #[tracing::instrument(name = "command.record_cycle_count", skip_all, fields(cycle_count_id = %cycle_count_id), err)]
+    pub async fn record_counts(
+        &self,
+        cycle_count_id: CycleCountId,
+        counts: Vec<(BookId, u32)>,
+    ) -> Result<(), ApplicationError> {
+        let mut cycle_count = self.find_cycle_count(cycle_count_id).await?;
+
+        for (book_id, counted_quantity) in counts {
+            cycle_count.record_count(book_id, counted_quantity)?;
+        }
+        self.cycle_count_repository.save(&cycle_count).await?;
+
+        Ok(())
+    }
+
+    /// 実数を提出し、現在のシステム在庫との差異を算出
+    ///
+    /// # Arguments
+    /// * `cycle_count_id` - 棚卸しID
+    ///
+    /// # Returns
+    /// * `Ok(())` - 提出成功
+    /// * `Err(ApplicationError)` - 提出失敗
+    #[tracing::instrument(name = "command.submit_cycle_count", skip_all, fields(cycle_count_id = %cycle_count_id), err)]
+    pub async fn submit_cycle_count(
+        &self,
+        cycle_count_id: CycleCountId,
+    ) -> Result<(), ApplicationError> {
+        let mut cycle_count = self.find_cycle_count(cycle_count_id).await?;
+
+        let mut system_quantities = HashMap::new();
+        for line in cycle_count.lines() {
+            if let Some(inventory) = self
+                .inventory_repository
+                .find_by_book_id(line.book_id())
+                .await?
+            {
+                system_quantities.insert(line.book_id(), inventory.quantity_on_hand());
+            }
+        }
+
+        cycle_count.submit(&system_quantities)?;
+        self.cycle_count_repository.save(&cycle_count).await?;
+
+        Ok(())
+    }
+
+    /// 棚卸しを承認し、差異を在庫に反映
+    /// 提出後に在庫が変動している可能性があるため、差異は現在の在庫数に対して差分で適用する
+    /// 調整した書籍ごとにInventoryAdjustedイベント（理由: "cycle_count"）を発行する
+    ///
+    /// # Arguments
+    /// * `cycle_count_id` - 棚卸しID
+    /// * `approved_by` - 承認者
+    ///
+    /// # Returns
+    /// * `Ok(())` - 承認成功
+    /// * `Err(ApplicationError)` - 承認失敗
+    #[tracing::instrument(name = "command.approve_cycle_count", skip_all, fields(cycle_count_id = %cycle_count_id), err)]
+    pub async fn approve_cycle_count(
+        &self,
+        cycle_count_id: CycleCountId,
+        approved_by: String,
+    ) -> Result<(), ApplicationError> {
+        let mut cycle_count = self.find_cycle_count(cycle_count_id).await?;
+        cycle_count.approve(approved_by)?;
+
+        // 在庫を更新する前にすべての調整が適用可能か検証する
+        let mut adjusted_inventories = Vec::new();
+        for (book_id, variance) in cycle_count.adjustments() {
+            let inventory = self
+                .inventory_repository
+                .find_by_book_id(book_id)
+                .await?
+                .unwrap_or_else(|| Inventory::new(book_id, 0));
+            let previous_quantity = inventory.quantity_on_hand();
+            let mut adjusted = inventory;
+            adjusted.adjust(variance)?;
+            adjusted_inventories.push((previous_quantity, adjusted));
+        }
+
+        self.cycle_count_repository.save(&cycle_count).await?;
+
+        let correlation_id = cycle_count_id.as_uuid();
+        for (previous_quantity, inventory) in adjusted_inventories {
+            self.inventory_repository.save(&inventory).await?;
+
+            let mut event = InventoryAdjusted::with_correlation_id(
+                inventory.book_id(),
+                previous_quantity,
+                inventory.quantity_on_hand(),
+                CYCLE_COUNT_ADJUSTMENT_REASON.to_string(),
+                correlation_id,
+            );
+            event
+                .metadata
+                .additional_metadata
+                .insert("cycle_count_id".to_string(), cycle_count_id.to_string());
+
+            self.event_bus
+                .publish(DomainEvent::InventoryAdjusted(event))
+                .await
+                .map_err(|e| ApplicationError::EventPublishingFailed(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// 棚卸しを却下（在庫は調整しない）
+    ///
+    /// # Arguments
+    /// * `cycle_count_id` - 棚卸しID
+    ///
+    /// # Returns
+    /// * `Ok(())` - 却下成功
+    /// * `Err(ApplicationError)` - 却下失敗
+    #[tracing::instrument(name = "command.reject_cycle_count", skip_all, fields(cycle_count_id = %cycle_count_id), err)]
+    pub async fn reject_cycle_count(
+        &self,
+        cycle_count_id: CycleCountId,
+    ) -> Result<(), ApplicationError> {
+        let mut cycle_count = self.find_cycle_count(cycle_count_id).await?;
+        cycle_count.reject()?;
+        self.cycle_count_repository.save(&cycle_count).await?;
+
+        Ok(())
+    }
+
+    async fn find_cycle_count(
+        &self,
+        cycle_count_id: CycleCountId,
+    ) -> Result<CycleCount, ApplicationError> {
+        self.cycle_count_repository
+            .find_by_id(cycle_count_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("棚卸しが見つかりません: {}", cycle_count_id))
+            })
+    }
+}
+
+/// 顧客アプリケーションサービス
+/// 顧客の登録と、顧客情報・顧客の注文の照会を調整する
+pub struct CustomerApplicationService {
+    customer_repository: Arc<dyn CustomerRepository>,
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl CustomerApplicationService {
+    /// 新しい顧客アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `customer_repository` - 顧客リポジトリ
+    /// * `order_repository` - 顧客の注文を取得する注文リポジトリ
+    pub fn new(
+        customer_repository: Arc<dyn CustomerRepository>,
+        order_repository: Arc<dyn OrderRepository>,
+    ) -> Self {
+        Self {
+            customer_repository,
+            order_repository,
+        }
+    }
+
+    /// 顧客を登録
+    /// メールアドレスは小文字に正規化し、登録済みのメールアドレスでは登録できない
+    ///
+    /// # Arguments
+    /// * `name` - 氏名
+    /// * `email` - メールアドレス
+    /// * `default_shipping_address` - 既定の配送先住所（注文の作成時に設定する）
+    ///
+    /// # Returns
+    /// * `Ok(Customer)` - 登録された顧客
+    /// * `Err(ApplicationError::DomainError)` - 氏名・メールアドレスが不正、またはメールアドレスが登録済み
+    #[tracing::instrument(name = "command.register_customer", skip_all, err)]
+    pub async fn register_customer(
+        &self,
+        name: String,
+        email: String,
+        default_shipping_address: Option<ShippingAddress>,
+    ) -> Result<Customer, ApplicationError> {
+        let email = EmailAddress::new(email)?;
+        if self
+            .customer_repository
+            .find_by_email(&email)
+            .await?
+            .is_some()
+        {
+            return Err(DomainError::from(FieldViolation::new(
+                "email",
+                Constraint::Unique,
+                Some(email.to_string()),
+                "メールアドレスは既に登録されています",
+            ))
+            .into());
+        }
+
+        let customer = Customer::register(
+            CustomerId::new(),
+            name,
+            email,
+            default_shipping_address,
+            Utc::now(),
+        )?;
+        self.customer_repository.save(&customer).await?;
+        Ok(customer)
+    }
+
+    /// 顧客を取得
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(Customer)` - 顧客
+    /// * `Err(ApplicationError::NotFound)` - 顧客が見つからない
+    pub async fn get_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Customer, ApplicationError> {
+        self.customer_repository
+            .find_by_id(customer_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("顧客が見つかりません: {}", customer_id))
+            })
+    }
+
+    /// 顧客に通知を送る言語を変更
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `language` - 通知の言語
+    ///
+    /// # Returns
+    /// * `Ok(Customer)` - 変更した顧客
+    /// * `Err(ApplicationError::NotFound)` - 顧客が見つからない
+    #[tracing::instrument(name = "command.set_preferred_language", skip_all, fields(customer_id = %customer_id, language = %language), err)]
+    pub async fn set_preferred_language(
+        &self,
+        customer_id: CustomerId,
+        language: Language,
+    ) -> Result<Customer, ApplicationError> {
+        let mut customer = self.get_customer(customer_id).await?;
+        customer.change_preferred_language(language);
+        self.customer_repository.save(&customer).await?;
+        Ok(customer)
+    }
+
+    /// 顧客の注文を作成日時の降順で取得
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Order>)` - 顧客の注文のリスト
+    /// * `Err(ApplicationError::NotFound)` - 顧客が見つからない
+    pub async fn list_customer_orders(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, ApplicationError> {
+        self.get_customer(customer_id).await?;
+        Ok(self.order_repository.find_by_customer(customer_id).await?)
+    }
+}
+
+/// デバイスアプリケーションサービス
+/// 顧客のプッシュ通知用デバイスの登録・解除と、顧客トピックへの購読管理を調整する
+pub struct DeviceApplicationService {
+    device_repository: Arc<dyn DeviceRegistrationRepository>,
+    push_notification: Arc<dyn PushNotificationPort>,
+}
+
+impl DeviceApplicationService {
+    /// 新しいデバイスアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `device_repository` - デバイス登録リポジトリ
+    /// * `push_notification` - プッシュ通知ポート
+    pub fn new(
+        device_repository: Arc<dyn DeviceRegistrationRepository>,
+        push_notification: Arc<dyn PushNotificationPort>,
+    ) -> Self {
+        Self {
+            device_repository,
+            push_notification,
+        }
+    }
+
+    /// デバイスを登録し、顧客のトピックに購読させる
+    /// 同じトークンを再登録した場合はプラットフォームを更新する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `token` - デバイストークン
+    /// * `platform` - デバイスのプラットフォーム
+    ///
+    /// # Returns
+    /// * `Ok(DeviceRegistration)` - 登録されたデバイス
+    /// * `Err(ApplicationError)` - 登録失敗
+    #[tracing::instrument(name = "command.register_device", skip_all, fields(customer_id = %customer_id, platform = %platform), err)]
+    pub async fn register_device(
+        &self,
+        customer_id: CustomerId,
+        token: DeviceToken,
+        platform: DevicePlatform,
+    ) -> Result<DeviceRegistration, ApplicationError> {
+        let registration = DeviceRegistration::new(customer_id, token, platform);
+
+        // 購読に失敗したデバイスは登録しない
+        self.push_notification
+            .subscribe_to_topic(registration.token(), &registration.topic())
+            .await
+            .map_err(|e| ApplicationError::ExternalServiceFailed(e.to_string()))?;
+        self.device_repository.save(&registration).await?;
+
+        Ok(registration)
+    }
+
+    /// 顧客の登録済みデバイスを取得
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<DeviceRegistration>)` - 登録済みデバイスのリスト
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn list_devices(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<DeviceRegistration>, ApplicationError> {
+        self.device_repository
+            .find_by_customer(customer_id)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// デバイスの登録を解除し、顧客のトピックの購読を解除する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `token` - 解除するデバイストークン
+    ///
+    /// # Returns
+    /// * `Ok(())` - 解除成功
+    /// * `Err(ApplicationError)` - 解除失敗（未登録の場合はNotFound）
+    #[tracing::instrument(name = "command.unregister_device", skip_all, fields(customer_id = %customer_id), err)]
+    pub async fn unregister_device(
+        &self,
+        customer_id: CustomerId,
+        token: DeviceToken,
+    ) -> Result<(), ApplicationError> {
+        let registration = self
+            .device_repository
+            .find_by_customer(customer_id)
+            .await?
+            .into_iter()
+            .find(|registration| registration.token() == &token)
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("デバイスが登録されていません: {}", token))
+            })?;
+
+        self.push_notification
+            .unsubscribe_from_topic(registration.token(), &registration.topic())
+            .await
+            .map_err(|e| ApplicationError::ExternalServiceFailed(e.to_string()))?;
+        self.device_repository.delete(customer_id, &token).await?;
+
+        Ok(())
+    }
+}
+
+/// 配信ログの取得件数の上限
+pub const MAX_WEBHOOK_DELIVERIES: u32 = 100;
+
+/// Webhookアプリケーションサービス
+/// 顧客（B2Bの取引先）ごとのWebhookの購読の管理と、配信ログの参照・再配信を調整する
+/// 購読・配信記録は顧客IDで所有者を確認し、他の顧客のものは存在しないものとして扱う
+pub struct WebhookApplicationService {
+    subscription_repository: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    dispatcher: WebhookDispatcher,
+}
+
+impl WebhookApplicationService {
+    /// 新しいWebhookアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `subscription_repository` - Webhookの購読リポジトリ
+    /// * `delivery_repository` - Webhookの配信ログリポジトリ
+    /// * `dispatcher` - Webhookの配信（再配信に使う）
+    pub fn new(
+        subscription_repository: Arc<dyn WebhookSubscriptionRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+        dispatcher: WebhookDispatcher,
+    ) -> Self {
+        Self {
+            subscription_repository,
+            delivery_repository,
+            dispatcher,
+        }
+    }
+
+    /// 顧客のWebhookの購読を登録
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `url` - 配信先のURL
+    /// * `secret` - 署名用のシークレット
+    /// * `event_types` - 配信するイベントの種類
+    ///
+    /// # Returns
+    /// * `Ok(WebhookSubscription)` - 登録された購読
+    /// * `Err(ApplicationError)` - 登録失敗（URL・シークレット・イベントの種類が不正な場合はバリデーションエラー）
+    #[tracing::instrument(name = "command.create_webhook_subscription", skip_all, fields(customer_id = %customer_id), err)]
+    pub async fn create_subscription(
+        &self,
+        customer_id: CustomerId,
+        url: String,
+        secret: String,
+        event_types: Vec<String>,
+    ) -> Result<WebhookSubscription, ApplicationError> {
+        let subscription =
+            WebhookSubscription::new(customer_id, url, secret, event_types, Utc::now())?;
+        self.subscription_repository.save(&subscription).await?;
+        Ok(subscription)
+    }
+
+    /// 顧客のWebhookの購読を登録順に取得
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    pub async fn list_subscriptions(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<WebhookSubscription>, ApplicationError> {
+        self.subscription_repository
+            .find_by_customer(customer_id)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// 顧客のWebhookの購読を削除（配信ログも削除される）
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `subscription_id` - 購読ID
+    ///
+    /// # Returns
+    /// * `Ok(())` - 削除成功
+    /// * `Err(ApplicationError)` - 削除失敗（顧客の購読でない場合はNotFound）
+    #[tracing::instrument(name = "command.delete_webhook_subscription", skip_all, fields(customer_id = %customer_id, subscription_id = %subscription_id), err)]
+    pub async fn delete_subscription(
+        &self,
+        customer_id: CustomerId,
+        subscription_id: Uuid,
+    ) -> Result<(), ApplicationError> {
+        if !self
+            .subscription_repository
+            .delete(customer_id, subscription_id)
+            .await?
+        {
+            return Err(Self::subscription_not_found(subscription_id));
+        }
+        Ok(())
+    }
+
+    /// 購読の配信ログを新しい順に取得
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `subscription_id` - 購読ID
+    /// * `limit` - 取得する最大件数（MAX_WEBHOOK_DELIVERIESまで）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<WebhookDelivery>)` - 配信記録のリスト
+    /// * `Err(ApplicationError)` - 取得失敗（顧客の購読でない場合はNotFound）
+    pub async fn list_deliveries(
+        &self,
+        customer_id: CustomerId,
+        subscription_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, ApplicationError> {
+        let subscription = self.find_subscription(customer_id, subscription_id).await?;
+        self.delivery_repository
+            .find_by_subscription(subscription.id, limit.clamp(1, MAX_WEBHOOK_DELIVERIES))
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// 記録済みの配信を同じペイロードで再配信する
+    /// 再配信の結果は新しい配信記録として配信ログに追加する（配信先が失敗した場合も記録を返す）
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `subscription_id` - 購読ID
+    /// * `delivery_id` - 再配信する配信ID
+    ///
+    /// # Returns
+    /// * `Ok(WebhookDelivery)` - 再配信の記録
+    /// * `Err(ApplicationError)` - 再配信失敗（顧客の購読・その購読の配信記録でない場合はNotFound）
+    #[tracing::instrument(name = "command.redeliver_webhook", skip_all, fields(customer_id = %customer_id, subscription_id = %subscription_id, delivery_id = %delivery_id), err)]
+    pub async fn redeliver(
+        &self,
+        customer_id: CustomerId,
+        subscription_id: Uuid,
+        delivery_id: Uuid,
+    ) -> Result<WebhookDelivery, ApplicationError> {
+        let subscription = self.find_subscription(customer_id, subscription_id).await?;
+        let original = self
+            .delivery_repository
+            .find_by_id(delivery_id)
+            .await?
+            .filter(|delivery| delivery.subscription_id == subscription.id)
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Webhookの配信記録が見つかりません: {}",
+                    delivery_id
+                ))
+            })?;
+
+        self.dispatcher
+            .redeliver(&subscription, &original)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    async fn find_subscription(
+        &self,
+        customer_id: CustomerId,
+        subscription_id: Uuid,
+    ) -> Result<WebhookSubscription, ApplicationError> {
+        self.subscription_repository
+            .find_by_id(subscription_id)
+            .await?
+            .filter(|subscription| subscription.customer_id == customer_id)
+            .ok_or_else(|| Self::subscription_not_found(subscription_id))
+    }
+
+    fn subscription_not_found(subscription_id: Uuid) -> ApplicationError {
+        ApplicationError::NotFound(format!(
+            "Webhookの購読が見つかりません: {}",
+            subscription_id
+        ))
+    }
+}
+
+/// 書籍カタログアプリケーションサービス
+/// 注文の確定時に照合する書籍の販売価格と、注文の表示に使う言語ごとのタイトルを管理するための窓口
+pub struct CatalogApplicationService {
+    book_catalog: Arc<dyn BookCatalog>,
+}
+
+impl CatalogApplicationService {
+    /// 新しい書籍カタログアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `book_catalog` - 書籍カタログ
+    pub fn new(book_catalog: Arc<dyn BookCatalog>) -> Self {
+        Self { book_catalog }
+    }
+
+    /// 書籍の販売価格を設定
+    /// Pending状態の注文には確定時（または再価格付け時）に反映される
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `price` - 販売価格
+    ///
+    /// # Returns
+    /// * `Ok(())` - 設定成功
+    /// * `Err(ApplicationError)` - 設定失敗
+    #[tracing::instrument(name = "command.set_book_price", skip_all, fields(book_id = %book_id, amount = price.amount()), err)]
+    pub async fn set_price(&self, book_id: BookId, price: Money) -> Result<(), ApplicationError> {
+        self.book_catalog
+            .set_price(book_id, price)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// 書籍の販売価格を取得
+    ///
+    /// # Returns
+    /// * `Ok(Some(Money))` - 価格が登録されている
+    /// * `Ok(None)` - 価格が登録されていない
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn get_price(&self, book_id: BookId) -> Result<Option<Money>, ApplicationError> {
+        self.book_catalog
+            .find_price(book_id)
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    /// 書籍のタイトルを言語ごとに設定
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `language` - タイトルの言語
+    /// * `title` - タイトル（前後の空白を取り除き、1文字以上255文字以内）
+    ///
+    /// # Returns
+    /// * `Ok(BookTitles)` - 設定成功（設定後の言語ごとのタイトル）
+    /// * `Err(ApplicationError)` - 設定失敗
+    #[tracing::instrument(name = "command.set_book_title", skip_all, fields(book_id = %book_id, language = %language), err)]
+    pub async fn set_title(
+        &self,
+        book_id: BookId,
+        language: Language,
+        title: &str,
+    ) -> Result<BookTitles, ApplicationError> {
+        let title = BookTitles::validate_title(title)?;
+        self.book_catalog
+            .set_title(book_id, language, &title)
+            .await?;
+        self.get_titles(book_id).await
+    }
+
+    /// 書籍の言語ごとのタイトルを取得（登録されていない場合は空）
+    pub async fn get_titles(&self, book_id: BookId) -> Result<BookTitles, ApplicationError> {
+        let mut titles = self.book_catalog.find_titles(&[book_id]).await?;
+        Ok(titles.remove(&book_id).unwrap_or_default())
+    }
+
+    /// 複数の書籍のタイトルを表示言語で取得
+    /// 表示言語のタイトルがない書籍は既定の言語・その他の言語にフォールバックし、タイトルが1つもない書籍は結果に含まれない
+    ///
+    /// # Arguments
+    /// * `book_ids` - 書籍IDのリスト
+    /// * `language` - 表示言語
+    pub async fn localized_titles(
+        &self,
+        book_ids: &[BookId],
+        language: Language,
+    ) -> Result<HashMap<BookId, LocalizedTitle>, ApplicationError> {
+        let titles = self.book_catalog.find_titles(book_ids).await?;
+        Ok(titles
+            .into_iter()
+            .filter_map(|(book_id, titles)| titles.localize(language).map(|title| (book_id, title)))
+            .collect())
+    }
+}
+
+/// 保留イベントアプリケーションサービス
+/// 遅延イベントポリシーで保留されたイベントを管理者が確認するための窓口
+pub struct ParkedEventApplicationService {
+    parked_event_repository: Arc<dyn ParkedEventRepository>,
+}
+
+impl ParkedEventApplicationService {
+    /// 新しい保留イベントアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `parked_event_repository` - 保留イベントリポジトリ
+    pub fn new(parked_event_repository: Arc<dyn ParkedEventRepository>) -> Self {
+        Self {
+            parked_event_repository,
+        }
+    }
+
+    /// 保留中のイベントを保留日時の昇順で取得
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ParkedEvent>)` - 保留中のイベントのリスト
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn list_parked_events(&self) -> Result<Vec<ParkedEvent>, ApplicationError> {
+        self.parked_event_repository
+            .find_all()
+            .await
+            .map_err(ApplicationError::from)
+    }
+}
+
+/// デッドレターキューアプリケーションサービス
+/// 処理に失敗してデッドレターキューに送られたイベントを管理者が確認するための窓口
+pub struct DeadLetterApplicationService {
+    dead_letter_monitor: Arc<dyn DeadLetterMonitor>,
+    retry_policies: RetryPolicies,
+}
+
+impl DeadLetterApplicationService {
+    /// 新しいデッドレターキューアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `dead_letter_monitor` - デッドレターキューの取得先
+    pub fn new(dead_letter_monitor: Arc<dyn DeadLetterMonitor>) -> Self {
+        Self {
+            dead_letter_monitor,
+            retry_policies: RetryPolicies::default(),
+        }
+    }
+
+    /// イベントバスに設定したリトライポリシーを設定
+    ///
+    /// # Arguments
+    /// * `retry_policies` - 管理者向けに公開するイベントタイプごとのリトライポリシー
+    pub fn with_retry_policies(mut self, retry_policies: RetryPolicies) -> Self {
+        self.retry_policies = retry_policies;
+        self
+    }
+
+    /// イベントタイプごとに適用されるリトライポリシーの一覧を取得
+    pub fn list_retry_policies(&self) -> Vec<EventRetryPolicy> {
+        self.retry_policies.effective_policies()
+    }
+
+    /// デッドレターキューのエントリを古い順に取得
+    ///
+    /// # Arguments
+    /// * `aggregate_id` - 指定した場合はその集約（注文など）のエントリのみ
+    pub async fn list_dead_letters(&self, aggregate_id: Option<&str>) -> Vec<DeadLetter> {
+        let mut dead_letters = self.dead_letter_monitor.dead_letters().await;
+        if let Some(aggregate_id) = aggregate_id {
+            dead_letters.retain(|dead_letter| {
+                dead_letter.context.aggregate_id.as_deref() == Some(aggregate_id)
+            });
+        }
+        dead_letters
+    }
+
+    /// デッドレターキューのエントリをハンドラーとエラーの指紋ごとに集計する
+    /// 繰り返し発生している失敗のパターンを、エントリを1件ずつ確認せずに把握するために使う
+    pub async fn summarize_dead_letters(&self) -> DeadLetterSummary {
+        DeadLetterSummary::build(&self.dead_letter_monitor.dead_letters().await)
+    }
+}
+
+/// イベント配信トレースアプリケーションサービス
+/// サーガの実行中に発行されたイベントがどのハンドラーに配信され、どうなったかを管理者が確認するための窓口
+pub struct EventTraceApplicationService {
+    trace_monitor: Arc<dyn EventTraceMonitor>,
+}
+
+impl EventTraceApplicationService {
+    /// 新しいイベント配信トレースアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `trace_monitor` - イベントの配信のトレースの取得先
+    pub fn new(trace_monitor: Arc<dyn EventTraceMonitor>) -> Self {
+        Self { trace_monitor }
+    }
+
+    /// 記録したイベントの配信のトレースと、イベントタイプ×ハンドラーの配信結果の対応表を取得
+    ///
+    /// # Arguments
+    /// * `correlation_id` - 指定した場合はその相関ID（サーガ）のトレースのみ
+    ///
+    /// # Returns
+    /// * `Ok(EventTraceReport)` - 発生順のトレースと対応表
+    /// * `Err(ApplicationError::Unsupported)` - トレースモードが無効
+    pub fn trace_report(
+        &self,
+        correlation_id: Option<Uuid>,
+    ) -> Result<EventTraceReport, ApplicationError> {
+        if !self.trace_monitor.tracing_enabled() {
+            return Err(ApplicationError::Unsupported(
+                "イベントの配信のトレースは無効です（EVENT_TRACE_CAPACITYで有効にできます）"
+                    .to_string(),
+            ));
+        }
+        Ok(EventTraceReport::build(
+            self.trace_monitor.event_traces(correlation_id),
+        ))
+    }
+}
+
+/// プロジェクション監視アプリケーションサービス
+/// プロジェクションの処理位置とイベントストリームの先頭から遅延を算出する
+pub struct ProjectionApplicationService {
+    registry: ProjectionRegistry,
+    stream_monitor: Arc<dyn EventStreamMonitor>,
+}
+
+impl ProjectionApplicationService {
+    /// 新しいプロジェクション監視アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `registry` - 監視対象のプロジェクション
+    /// * `stream_monitor` - イベントストリームの先頭の取得先
+    pub fn new(registry: ProjectionRegistry, stream_monitor: Arc<dyn EventStreamMonitor>) -> Self {
+        Self {
+            registry,
+            stream_monitor,
+        }
+    }
+
+    /// すべてのプロジェクションの処理位置と遅延を取得
+    pub async fn list_projections(&self) -> Vec<ProjectionStatus> {
+        let head = self.stream_monitor.stream_head();
+        self.registry.statuses(&head).await
+    }
+
+    /// プロジェクションを再構築
+    /// 発行済みイベントを保存するイベントストアがないため、現在の構成では再構築できない
+    ///
+    /// # Returns
+    /// * `Err(ApplicationError::NotFound)` - 登録されていないプロジェクション
+    /// * `Err(ApplicationError::Unsupported)` - 再構築に必要なイベントの再生手段がない
+    pub async fn rebuild_projection(&self, name: &str) -> Result<(), ApplicationError> {
+        if !self.registry.contains(name) {
+            return Err(ApplicationError::NotFound(format!(
+                "プロジェクションが見つかりません: {}",
+                name
+            )));
+        }
+
+        Err(ApplicationError::Unsupported(format!(
+            "イベントを再生する手段がないため、プロジェクションを再構築できません: {}",
+            name
+        )))
+    }
+}
+
+/// サーガ集計アプリケーションサービス
+/// 注文のステータス遷移日時と補償の記録から、サーガの進行状況を集計する
+pub struct SagaMetricsApplicationService {
+    order_repository: Arc<dyn OrderRepository>,
+    compensation_repository: Arc<dyn SagaCompensationRepository>,
+}
+
+impl SagaMetricsApplicationService {
+    /// 新しいサーガ集計アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ
+    /// * `compensation_repository` - サーガ補償リポジトリ
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        compensation_repository: Arc<dyn SagaCompensationRepository>,
+    ) -> Self {
+        Self {
+            order_repository,
+            compensation_repository,
+        }
+    }
+
+    /// サーガの集計を取得
+    ///
+    /// # Arguments
+    /// * `now` - 集計日時（止まっているサーガの経過時間の算出に使用）
+    ///
+    /// # Returns
+    /// * `Ok(SagaMetricsReport)` - サーガの集計
+    pub async fn get_metrics(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<SagaMetricsReport, ApplicationError> {
+        let orders = self.order_repository.find_all().await?;
+        let compensations = self.compensation_repository.count_by_failed_step().await?;
+        Ok(SagaMetricsReport::build(&orders, compensations, now))
+    }
+}
+
+/// 需要予測アプリケーションサービス
+/// 確定した注文の履歴から書籍ごとの需要を移動平均で見積もり、在庫の残り日数と発注点を返す
+pub struct ForecastApplicationService {
+    order_repository: Arc<dyn OrderRepository>,
+    inventory_repository: Arc<dyn InventoryRepository>,
+    policy: ForecastPolicy,
+}
+
+impl ForecastApplicationService {
+    /// 新しい需要予測アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 需要を集計する注文リポジトリ
+    /// * `inventory_repository` - 在庫リポジトリ
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        inventory_repository: Arc<dyn InventoryRepository>,
+    ) -> Self {
+        Self {
+            order_repository,
+            inventory_repository,
+            policy: ForecastPolicy::default(),
+        }
+    }
+
+    /// 需要予測の設定を変更する（未設定の場合は直近28日の移動平均、入荷まで7日）
+    pub fn with_policy(mut self, policy: ForecastPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// 書籍の需要予測を取得
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `now` - 現在日時（前日までの需要を集計する）
+    ///
+    /// # Returns
+    /// * `Ok(DemandForecast)` - 需要予測
+    /// * `Err(ApplicationError::NotFound)` - 在庫が見つからない
+    pub async fn get_forecast(
+        &self,
+        book_id: BookId,
+        now: DateTime<Utc>,
+    ) -> Result<DemandForecast, ApplicationError> {
+        let inventory = self
+            .inventory_repository
+            .find_by_book_id(book_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("書籍ID {} の在庫が見つかりません", book_id))
+            })?;
+        let demand = self
+            .order_repository
+            .sum_daily_book_demand_since(book_id, self.policy.window_start(now))
+            .await?;
+        Ok(DemandForecast::build(
+            book_id,
+            &demand,
+            inventory.quantity_on_hand(),
+            &self.policy,
+            now,
+        ))
+    }
+}
+
+/// 注文修復アプリケーションサービス（管理者向け）
+/// 停滞した注文をジャーナルに記録されたイベントと突き合わせて診断し、運用手順書の修復を適用する
+pub struct OrderRepairApplicationService {
+    order_repository: Arc<dyn OrderRepository>,
+    event_journal: Arc<dyn EventJournal>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl OrderRepairApplicationService {
+    /// 新しい注文修復アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ
+    /// * `event_journal` - 発行されたイベントを記録したジャーナル
+    /// * `event_bus` - 修復のイベントを発行するイベントバス
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_journal: Arc<dyn EventJournal>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_journal,
+            event_bus,
+        }
+    }
+
+    /// 注文の停滞を診断し、検出した問題の修復を適用する
+    /// 在庫予約の漏れはOrderConfirmedを再発行して在庫予約をやり直し、
+    /// OrderShippedの漏れはイベントを作り直して発行する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `dry_run` - trueの場合は診断と修復手順の確認だけを行い、イベントを発行しない
+    /// * `now` - 診断日時
+    ///
+    /// # Returns
+    /// * `Ok(OrderRepairReport)` - 検出した問題と、適用した（ドライランでは適用する）修復
+    /// * `Err(ApplicationError::NotFound)` - 注文が見つからない
+    /// * `Err(ApplicationError::EventPublishingFailed)` - 修復のイベントの発行に失敗
+    #[tracing::instrument(name = "command.repair_order", skip_all, fields(order_id = %order_id, dry_run = dry_run), err)]
+    pub async fn repair_order(
+        &self,
+        order_id: OrderId,
+        dry_run: bool,
+        now: DateTime<Utc>,
+    ) -> Result<OrderRepairReport, ApplicationError> {
+        let order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+        let journaled_events = self
+            .event_journal
+            .find_by_aggregate(&order_id.to_string())
+            .await?;
+
+        let issues = order_repair::diagnose(&order, &journaled_events, now);
+        let repairs = order_repair::plan_repairs(&order, &issues, &journaled_events);
+
+        let mut results = Vec::with_capacity(repairs.len());
+        for repair in repairs {
+            if !dry_run {
+                self.event_bus
+                    .publish(repair.event.clone())
+                    .await
+                    .map_err(|e| ApplicationError::EventPublishingFailed(e.to_string()))?;
+            }
+            results.push((repair, !dry_run));
+        }
+
+        Ok(OrderRepairReport::build(&order, dry_run, now, &results))
+    }
+}
+
+/// 注文の編集ロックアプリケーションサービス（管理者向け）
+/// オペレーターが時間のかかる編集をしている注文を期限付きでロックし、他の操作を423 Lockedで拒否する
+/// オペレーターは認証した利用者の識別子で識別する（認証を無効にしている場合はX-Operatorヘッダーの名前）
+pub struct OrderLockApplicationService {
+    order_repository: Arc<dyn OrderRepository>,
+    lock_repository: Arc<dyn OrderLockRepository>,
+}
+
+impl OrderLockApplicationService {
+    /// 新しい注文の編集ロックアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ（ロックする注文の存在確認に使う）
+    /// * `lock_repository` - 注文の編集ロックリポジトリ
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        lock_repository: Arc<dyn OrderLockRepository>,
+    ) -> Self {
+        Self {
+            order_repository,
+            lock_repository,
+        }
+    }
+
+    /// 注文をロックする
+    /// 同じオペレーターが既にロックしている場合は期限を延長する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `operator` - ロックするオペレーター
+    /// * `ttl` - ロックの期間
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(OrderLock)` - 取得したロック
+    /// * `Err(ApplicationError::NotFound)` - 注文が見つからない
+    /// * `Err(ApplicationError::DomainError)` - オペレーター・期間が無効、または他のオペレーターがロック中（OrderLocked）
+    #[tracing::instrument(name = "command.lock_order", skip_all, fields(order_id = %order_id, operator = %operator), err)]
+    pub async fn lock(
+        &self,
+        order_id: OrderId,
+        operator: &str,
+        ttl: TimeDelta,
+        now: DateTime<Utc>,
+    ) -> Result<OrderLock, ApplicationError> {
+        let operator = operator.trim();
+        if operator.is_empty() {
+            return Err(DomainError::from(FieldViolation::new(
+                "operator",
+                Constraint::Required,
+                None,
+                "ロックするオペレーターを指定してください",
+            ))
+            .into());
+        }
+        if ttl <= TimeDelta::zero() || ttl > TimeDelta::minutes(MAX_ORDER_LOCK_TTL_MINUTES) {
+            return Err(DomainError::from(FieldViolation::new(
+                "ttl_minutes",
+                Constraint::Min,
+                Some(ttl.num_minutes().to_string()),
+                format!(
+                    "ロックの期間は1分から{}分の間で指定してください",
+                    MAX_ORDER_LOCK_TTL_MINUTES
+                ),
+            ))
+            .into());
+        }
+        if self.order_repository.find_by_id(order_id).await?.is_none() {
+            return Err(ApplicationError::NotFound(format!(
+                "注文が見つかりません: {}",
+                order_id
+            )));
+        }
+
+        let requested = OrderLock::new(order_id, operator.to_string(), now, ttl);
+        let current = self.lock_repository.try_acquire(&requested).await?;
+        if current.locked_by != requested.locked_by {
+            return Err(current.locked_error().into());
+        }
+        Ok(current)
+    }
+
+    /// 注文のロックを解除する
+    /// ロックがない、または期限切れの場合は何もしない
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `operator` - ロックを解除するオペレーター
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(())` - 解除成功
+    /// * `Err(ApplicationError::DomainError)` - 他のオペレーターがロック中（OrderLocked）
+    #[tracing::instrument(name = "command.unlock_order", skip_all, fields(order_id = %order_id), err)]
+    pub async fn unlock(
+        &self,
+        order_id: OrderId,
+        operator: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<(), ApplicationError> {
+        let Some(lock) = self.lock_repository.find_by_order_id(order_id).await? else {
+            return Ok(());
+        };
+        if lock.blocks(operator, now) {
+            return Err(lock.locked_error().into());
+        }
+        self.lock_repository.remove(order_id).await?;
+        Ok(())
+    }
+
+    /// 注文を変更する操作がロックに妨げられないことを確認する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `operator` - 操作するオペレーター（名乗らない場合はNone）
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(())` - 操作できる
+    /// * `Err(ApplicationError::DomainError)` - 他のオペレーターがロック中（OrderLocked）
+    pub async fn ensure_unlocked(
+        &self,
+        order_id: OrderId,
+        operator: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<(), ApplicationError> {
+        match self.lock_repository.find_by_order_id(order_id).await? {
+            Some(lock) if lock.blocks(operator, now) => Err(lock.locked_error().into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// 期限内のロックを注文IDごとに取得する（管理画面の注文一覧に表示する）
+    pub async fn active_locks(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<HashMap<OrderId, OrderLock>, ApplicationError> {
+        Ok(self
+            .lock_repository
+            .find_active(now)
+            .await?
+            .into_iter()
+            .map(|lock| (lock.order_id, lock))
+            .collect())
+    }
+}
+
+/// 再試行待ち操作アプリケーションサービス
+/// 状態の保存後にイベントの発行に失敗したコマンドの、再試行の進み具合をクライアントに返す
+pub struct PendingOperationApplicationService {
+    repository: Arc<dyn PendingOperationRepository>,
+}
+
+impl PendingOperationApplicationService {
+    /// 新しい再試行待ち操作アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - 再試行待ちの操作リポジトリ
+    pub fn new(repository: Arc<dyn PendingOperationRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// 操作を取得
+    ///
+    /// # Arguments
+    /// * `operation_id` - コマンドの応答で返した操作ID
+    ///
+    /// # Returns
+    /// * `Ok(PendingOperation)` - 操作（再試行待ち・完了・再試行をあきらめた）
+    /// * `Err(ApplicationError::NotFound)` - 操作が見つからない
+    pub async fn get_operation(
+        &self,
+        operation_id: Uuid,
+    ) -> Result<PendingOperation, ApplicationError> {
+        self.repository
+            .find_by_id(operation_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("操作が見つかりません: {}", operation_id))
+            })
+    }
+}
+
+/// 一覧で返す送信に失敗した通知の上限
+pub const FAILED_NOTIFICATION_LIST_LIMIT: u32 = 100;
+
+/// 通知アプリケーションサービス
+/// 送信に失敗した通知を管理者が確認し、再送をあきらめた通知を手動で再送する
+pub struct NotificationApplicationService {
+    repository: Arc<dyn FailedNotificationRepository>,
+    sender: Arc<dyn NotificationSender>,
+    policy: NotificationRetryPolicy,
+    /// 接続中の顧客への通知の受信（未設定の場合は接続できない）
+    live_notifications: Option<Arc<dyn CustomerNotificationSource>>,
+}
+
+impl NotificationApplicationService {
+    /// 新しい通知アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `repository` - 送信に失敗した通知のリポジトリ
+    /// * `sender` - 通知の送信先（メール・SMSの送信サービス）
+    /// * `policy` - 手動の再送にも失敗した場合に適用する再送ポリシー
+    pub fn new(
+        repository: Arc<dyn FailedNotificationRepository>,
+        sender: Arc<dyn NotificationSender>,
+        policy: NotificationRetryPolicy,
+    ) -> Self {
+        Self {
+            repository,
+            sender,
+            policy,
+            live_notifications: None,
+        }
+    }
+
+    /// 接続中の顧客への通知の受信を設定する（WebSocketで通知を受け取れるようにする）
+    ///
+    /// # Arguments
+    /// * `source` - 通知ハンドラーが配信する通知の受信元
+    ///
+    /// # Returns
+    /// 通知アプリケーションサービス
+    pub fn with_live_notifications(mut self, source: Arc<dyn CustomerNotificationSource>) -> Self {
+        self.live_notifications = Some(source);
+        self
+    }
+
+    /// 顧客への通知の受信を始める
+    ///
+    /// # Arguments
+    /// * `customer_id` - 通知を受け取る顧客
+    ///
+    /// # Returns
+    /// * `Ok(Receiver)` - 接続後に配信された顧客への通知（破棄すると接続から外れる）
+    /// * `Err(ApplicationError::Unsupported)` - 通知の受信元が設定されていない
+    pub fn connect_customer_notifications(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<mpsc::Receiver<CustomerNotification>, ApplicationError> {
+        let source = self.live_notifications.as_ref().ok_or_else(|| {
+            ApplicationError::Unsupported("接続中の顧客への通知は利用できません".to_string())
+        })?;
+        Ok(source.connect(customer_id))
+    }
+
+    /// 送信に失敗した通知を状態で絞り込んで取得（最終更新日時の降順、最大100件）
+    ///
+    /// # Arguments
+    /// * `status` - 通知の状態（再送待ち・送信済み・再送をあきらめた）
+    pub async fn list_failed_notifications(
+        &self,
+        status: FailedNotificationStatus,
+    ) -> Result<Vec<FailedNotification>, ApplicationError> {
+        Ok(self
+            .repository
+            .find_by_status(status, FAILED_NOTIFICATION_LIST_LIMIT)
+            .await?)
+    }
+
+    /// 通知をすぐに再送する
+    /// 再送に失敗した場合も試行回数と理由を記録して返す（再送をあきらめた通知は再送をあきらめたまま）
+    ///
+    /// # Arguments
+    /// * `notification_id` - 送信に失敗した通知のID
+    ///
+    /// # Returns
+    /// * `Ok(FailedNotification)` - 再送後の通知（送信できた場合は送信済み）
+    /// * `Err(ApplicationError::NotFound)` - 通知が見つからない
+    /// * `Err(ApplicationError::DomainError)` - 既に送信済みの通知
+    pub async fn resend(
+        &self,
+        notification_id: Uuid,
+    ) -> Result<FailedNotification, ApplicationError> {
+        let mut failed = self
+            .repository
+            .find_by_id(notification_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("通知が見つかりません: {}", notification_id))
+            })?;
+        failed.ensure_resendable()?;
+
+        let now = Utc::now();
+        match self.sender.send(&failed.notification).await {
+            Ok(()) => failed.record_success(now),
+            Err(e) => failed.record_failure(&e.to_string(), &self.policy, now),
+        }
+        self.repository.save(&failed).await?;
+        Ok(failed)
+    }
+}
+
+/// アクションリンクアプリケーションサービス
+/// 通知に載せた署名付きのリンクから、ログインせずに顧客の操作を実行する
+pub struct ActionLinkApplicationService<OR>
+where
+    OR: OrderRepository,
+{
+    order_service: Arc<OrderApplicationService<OR>>,
+    signer: Arc<dyn ActionLinkSigner>,
+    audit_repository: Arc<dyn ActionLinkAuditRepository>,
+}
+
+impl<OR> ActionLinkApplicationService<OR>
+where
+    OR: OrderRepository,
+{
+    /// 新しいアクションリンクアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_service` - 操作を実行する注文アプリケーションサービス
+    /// * `signer` - リンクを発行したときと同じ署名
+    /// * `audit_repository` - アクションリンクの使用記録リポジトリ
+    pub fn new(
+        order_service: Arc<OrderApplicationService<OR>>,
+        signer: Arc<dyn ActionLinkSigner>,
+        audit_repository: Arc<dyn ActionLinkAuditRepository>,
+    ) -> Self {
+        Self {
+            order_service,
+            signer,
+            audit_repository,
+        }
+    }
+
+    /// リンクで実行する操作を確認（操作は実行しない）
+    ///
+    /// # Arguments
+    /// * `token` - リンクのトークン
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(ActionLinkClaims)` - リンクの内容
+    /// * `Err(ApplicationError::NotFound)` - トークンが正しくない、または有効期限切れ
+    pub fn describe(
+        &self,
+        token: &str,
+        now: DateTime<Utc>,
+    ) -> Result<ActionLinkClaims, ApplicationError> {
+        let claims = self.verify(token)?;
+        if claims.is_expired(now) {
+            return Err(Self::expired());
+        }
+        Ok(claims)
+    }
+
+    /// リンクの操作を実行し、使われたトークンを記録する
+    ///
+    /// # Arguments
+    /// * `token` - リンクのトークン
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(CommandAcknowledgement)` - 実行した操作のイベント
+    /// * `Err(ApplicationError::NotFound)` - トークンが正しくない、または有効期限切れ
+    /// * `Err(ApplicationError)` - 注文の状態などにより実行できない
+    pub async fn execute(
+        &self,
+        token: &str,
+        now: DateTime<Utc>,
+    ) -> Result<CommandAcknowledgement, ApplicationError> {
+        let claims = self.verify(token)?;
+        let result = if claims.is_expired(now) {
+            Err(Self::expired())
+        } else {
+            match claims.action {
+                CustomerAction::CancelOrder => {
+                    self.order_service.cancel_order(claims.order_id).await
+                }
+                CustomerAction::ConfirmDelivery => {
+                    self.order_service
+                        .mark_order_as_delivered(claims.order_id, None)
+                        .await
+                }
+            }
+        };
+
+        let record =
+            ActionLinkUse::record(&claims, result.as_ref().err().map(|e| e.to_string()), now);
+        // 操作は実行済みのため、記録に失敗しても操作の結果を返す
+        if let Err(e) = self.audit_repository.save(&record).await {
+            tracing::error!(
+                link_id = %record.link_id,
+                order_id = %record.order_id,
+                outcome = %record.outcome,
+                error = %e,
+                "アクションリンクの使用記録に失敗しました"
+            );
+        }
+        result
+    }
+
+    /// トークンの署名を検証（改ざん・推測されたトークンは記録せずに拒否する）
+    fn verify(&self, token: &str) -> Result<ActionLinkClaims, ApplicationError> {
+        self.signer.verify(token).map_err(|e| {
+            tracing::warn!(error = %e, "無効なアクションリンクが使われました");
+            ApplicationError::NotFound("リンクが見つかりません".to_string())
+        })
+    }
+
+    fn expired() -> ApplicationError {
+        ApplicationError::NotFound("リンクの有効期限が切れています".to_string())
+    }
+}
+
+/// 管理者の指定した期間のイベントを、オフライン分析用にオブジェクトストレージへ書き出す
+/// 書き出したアーカイブは別の環境のジャーナルに取り込める
+pub struct EventExportApplicationService {
+    exporter: EventExporter,
+    importer: EventImporter,
+}
+
+impl EventExportApplicationService {
+    /// 新しいイベントエクスポートアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `exporter` - イベントのエクスポート（夜間ジョブと共有する）
+    /// * `importer` - アーカイブからのイベントの取り込み
+    pub fn new(exporter: EventExporter, importer: EventImporter) -> Self {
+        Self { exporter, importer }
+    }
+
+    /// 期間内のイベントを書き出す
+    /// 同じ期間を再度指定すると、前回書き出した位置から再開する
+    ///
+    /// # Arguments
+    /// * `from` - 期間の開始（この日時を含む）
+    /// * `to` - 期間の終了（この日時を含まない）
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(ExportManifest)` - 書き出し後のマニフェスト
+    /// * `Err(ApplicationError::DomainError)` - 期間が不正
+    /// * `Err(ApplicationError::ExternalServiceFailed)` - ストレージへの書き込みに失敗
+    #[tracing::instrument(name = "command.export_events", skip_all, fields(from = %from, to = %to), err)]
+    pub async fn export_events(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<ExportManifest, ApplicationError> {
+        let range = EventExportRange::new(from, to)?;
+        self.exporter
+            .export(&range, now)
+            .await
+            .map_err(|e| match e {
+                EventExportError::JournalFailed(message) => {
+                    ApplicationError::RepositoryError(RepositoryError::FetchFailed(message))
+                }
+                e => ApplicationError::ExternalServiceFailed(e.to_string()),
+            })
+    }
+
+    /// エクスポートしたアーカイブのイベントをジャーナルに取り込む
+    /// 既に記録されているイベント（同じイベントID）は読み飛ばすため、同じアーカイブを再度取り込める
+    ///
+    /// # Arguments
+    /// * `archive` - アーカイブのキーの接頭辞
+    ///
+    /// # Returns
+    /// * `Ok(EventImportSummary)` - 取り込み結果
+    /// * `Err(ApplicationError::NotFound)` - アーカイブのマニフェストがない
+    /// * `Err(ApplicationError::DomainError)` - アーカイブが不正（1件も取り込まない）
+    /// * `Err(ApplicationError::ExternalServiceFailed)` - ストレージからの読み込みに失敗
+    #[tracing::instrument(name = "command.import_events", skip_all, fields(archive = %archive), err)]
+    pub async fn import_events(
+        &self,
+        archive: &str,
+    ) -> Result<EventImportSummary, ApplicationError> {
+        self.importer.import(archive).await.map_err(|e| match e {
+            EventImportError::ArchiveNotFound(_) => ApplicationError::NotFound(e.to_string()),
+            EventImportError::InvalidArchive { .. } => {
+                ApplicationError::DomainError(DomainError::InvalidValue(e.to_string()))
+            }
+            EventImportError::JournalFailed(message) => {
+                ApplicationError::RepositoryError(RepositoryError::OperationFailed(message))
+            }
+            EventImportError::StorageFailed(_) => {
+                ApplicationError::ExternalServiceFailed(e.to_string())
+            }
+        })
+    }
+}
+
+/// 顧客のデータのエクスポートアプリケーションサービス（データポータビリティ）
+/// 顧客の依頼でアーカイブの作成をバックグラウンドで始め、ジョブIDで状態の確認とダウンロードをできるようにする
+pub struct CustomerExportApplicationService {
+    exporter: Arc<CustomerExporter>,
+}
+
+impl CustomerExportApplicationService {
+    /// 新しい顧客のデータのエクスポートアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `exporter` - 顧客のデータのエクスポート
+    pub fn new(exporter: Arc<CustomerExporter>) -> Self {
+        Self { exporter }
+    }
+
+    /// エクスポートを依頼する（アーカイブはバックグラウンドで作成する）
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(CustomerExportJob)` - 作成中のジョブ
+    /// * `Err(ApplicationError::NotFound)` - 顧客が登録されていない
+    /// * `Err(ApplicationError::ExternalServiceFailed)` - ストレージへの書き込みに失敗
+    #[tracing::instrument(name = "command.request_customer_export", skip_all, fields(customer_id = %customer_id), err)]
+    pub async fn request_export(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<CustomerExportJob, ApplicationError> {
+        let job = self
+            .exporter
+            .start(customer_id, Utc::now())
+            .await
+            .map_err(Self::map_export_error)?;
+
+        let exporter = self.exporter.clone();
+        let pending = job.clone();
+        tokio::spawn(async move {
+            exporter.run(pending).await;
+        });
+        Ok(job)
+    }
+
+    /// エクスポートのジョブを取得する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `job_id` - ジョブID
+    ///
+    /// # Returns
+    /// * `Ok(CustomerExportJob)` - ジョブ
+    /// * `Err(ApplicationError::NotFound)` - 顧客のジョブが見つからない
+    pub async fn get_export(
+        &self,
+        customer_id: CustomerId,
+        job_id: Uuid,
+    ) -> Result<CustomerExportJob, ApplicationError> {
+        self.exporter
+            .find_job(job_id)
+            .await
+            .map_err(Self::map_export_error)?
+            .filter(|job| job.customer_id == customer_id)
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("エクスポートが見つかりません: {}", job_id))
+            })
+    }
+
+    /// 完了したエクスポートのアーカイブ（ZIP）を取得する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `job_id` - ジョブID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - アーカイブ
+    /// * `Err(ApplicationError::NotFound)` - 顧客のジョブが見つからない、またはアーカイブがまだ作成されていない
+    pub async fn download_export(
+        &self,
+        customer_id: CustomerId,
+        job_id: Uuid,
+    ) -> Result<Vec<u8>, ApplicationError> {
+        let job = self.get_export(customer_id, job_id).await?;
+        if job.status != CustomerExportStatus::Completed {
+            return Err(ApplicationError::NotFound(format!(
+                "エクスポートのアーカイブはまだ作成されていません: {}",
+                job_id
+            )));
+        }
+        self.exporter
+            .read_archive(&job)
+            .await
+            .map_err(Self::map_export_error)?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "エクスポートのアーカイブが見つかりません: {}",
+                    job_id
+                ))
+            })
+    }
+
+    fn map_export_error(e: CustomerExportError) -> ApplicationError {
+        match e {
+            CustomerExportError::CustomerNotFound(customer_id) => {
+                ApplicationError::NotFound(format!("顧客が見つかりません: {}", customer_id))
+            }
+            CustomerExportError::RepositoryFailed(message) => {
+                ApplicationError::RepositoryError(RepositoryError::FetchFailed(message))
+            }
+            e => ApplicationError::ExternalServiceFailed(e.to_string()),
+        }
+    }
+}
+
+/// 起動時の診断アプリケーションサービス
+/// 起動時に記録した診断レポートを管理者に公開する
+pub struct DiagnosticsApplicationService {
+    report: DiagnosticsReport,
+    /// 現在のイベントの配信全体の状態の取得先（未設定の場合はレポートに含めない）
+    dispatch_control: Option<Arc<dyn EventDispatchControl>>,
+    /// バックグラウンドタスクの監督（未設定の場合は監督中のタスクがないものとする）
+    task_supervisor: Option<Arc<TaskSupervisor>>,
+}
+
+impl DiagnosticsApplicationService {
+    /// 新しい起動時の診断アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `report` - 起動時の診断レポート
+    pub fn new(report: DiagnosticsReport) -> Self {
+        Self {
+            report,
+            dispatch_control: None,
+            task_supervisor: None,
+        }
+    }
+
+    /// 配信全体の状態の取得先を設定
+    /// 設定すると、診断レポートに現在のイベントの配信全体の状態（デプロイ時の一時停止）を含める
+    ///
+    /// # Arguments
+    /// * `dispatch_control` - 配信全体の制御先
+    pub fn with_dispatch_control(mut self, dispatch_control: Arc<dyn EventDispatchControl>) -> Self {
+        self.dispatch_control = Some(dispatch_control);
+        self
+    }
+
+    /// バックグラウンドタスクの監督を設定
+    /// 設定すると、監督中のタスクの生存状況を準備状態の確認（/health/ready）に含める
+    ///
+    /// # Arguments
+    /// * `task_supervisor` - バックグラウンドタスクの監督
+    pub fn with_task_supervisor(mut self, task_supervisor: Arc<TaskSupervisor>) -> Self {
+        self.task_supervisor = Some(task_supervisor);
+        self
+    }
+
+    /// 監督中のバックグラウンドタスクの生存状況
+    pub fn background_tasks(&self) -> Vec<BackgroundTaskHealth> {
+        self.task_supervisor
+            .as_ref()
+            .map(|supervisor| supervisor.health(Utc::now()))
+            .unwrap_or_default()
+    }
+
+    /// 最後に記録した診断レポートに、現在のイベントの配信全体の状態を加えて取得
+    pub fn latest_report(&self) -> DiagnosticsReport {
+        let mut report = self.report.clone();
+        report.event_dispatch = self
+            .dispatch_control
+            .as_ref()
+            .map(|dispatch_control| dispatch_control.dispatch_status());
+        report
+    }
+}
+
+/// 購読管理アプリケーションサービス
+/// イベントバスの購読を管理者が確認・解除・一時停止・再開するための窓口
+pub struct SubscriptionApplicationService {
+    subscription_manager: Arc<dyn SubscriptionManager>,
+    registry: Option<Arc<dyn SubscriptionRegistryRepository>>,
+    /// 配信全体の一時停止・再開の制御先（未設定の場合は配信全体を一時停止できない）
+    dispatch_control: Option<Arc<dyn EventDispatchControl>>,
+}
+
+impl SubscriptionApplicationService {
+    /// 新しい購読管理アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `subscription_manager` - 購読の管理先（イベントバス）
+    pub fn new(subscription_manager: Arc<dyn SubscriptionManager>) -> Self {
+        Self {
+            subscription_manager,
+            registry: None,
+            dispatch_control: None,
+        }
+    }
+
+    /// 購読の登録内容リポジトリを設定
+    /// 設定すると、一時停止・再開・購読解除した状態を保存し、起動時に復元できる
+    ///
+    /// # Arguments
+    /// * `registry` - 購読の登録内容リポジトリ
+    pub fn with_registry(mut self, registry: Arc<dyn SubscriptionRegistryRepository>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// 配信全体の制御先を設定
+    /// 設定すると、デプロイの間にイベントの配信全体を一時停止・再開できる
+    ///
+    /// # Arguments
+    /// * `dispatch_control` - 配信全体の制御先（イベントバスまたはブローカーからの受信の配信ゲート）
+    pub fn with_dispatch_control(mut self, dispatch_control: Arc<dyn EventDispatchControl>) -> Self {
+        self.dispatch_control = Some(dispatch_control);
+        self
+    }
+
+    /// 保存した登録内容をイベントバスに反映し、現在の購読を登録内容として保存する
+    /// すべてのハンドラーを購読した後の起動時に呼び出す
+    /// 保存した登録内容のうち、購読していないハンドラーのものは無視する
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 一時停止または購読解除の状態を復元したハンドラーの数
+    /// * `Err(ApplicationError)` - 登録内容の取得・保存に失敗
+    pub async fn restore_subscriptions(&self) -> Result<usize, ApplicationError> {
+        let Some(registry) = &self.registry else {
+            return Ok(0);
+        };
+        let registrations = registry.find_all().await?;
+        let mut restored = 0;
+        for registration in &registrations {
+            let result = match registration.state {
+                RegisteredSubscriptionState::Active => continue,
+                RegisteredSubscriptionState::Paused(paused_events) => self
+                    .subscription_manager
+                    .pause(&registration.handler_name, paused_events)
+                    .await
+                    .map(|_| ()),
+                RegisteredSubscriptionState::Unsubscribed => self
+                    .subscription_manager
+                    .unsubscribe(&registration.handler_name)
+                    .await
+                    .map(|_| ()),
+            };
+            match result {
+                Ok(()) => restored += 1,
+                Err(EventBusError::SubscriptionNotFound(_)) => {}
+                Err(error) => return Err(subscription_error(error)),
+            }
+        }
+
+        let now = Utc::now();
+        let statuses = self.subscription_manager.subscriptions().await;
+        for registration in SubscriptionRegistration::all_from_statuses(&statuses, now) {
+            registry.save(&registration).await?;
+        }
+        Ok(restored)
+    }
+
+    /// 保存した登録内容の一覧をハンドラー名の順に取得
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SubscriptionRegistration>)` - 登録内容（リポジトリを設定していない場合は空）
+    /// * `Err(ApplicationError)` - 取得失敗
+    pub async fn list_registrations(&self) -> Result<Vec<SubscriptionRegistration>, ApplicationError> {
+        match &self.registry {
+            Some(registry) => Ok(registry.find_all().await?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// 保存した登録内容を削除（次の起動時は購読時の状態で始まる）
+    ///
+    /// # Returns
+    /// * `Ok(())` - 削除成功
+    /// * `Err(ApplicationError::NotFound)` - 該当する登録内容がない
+    pub async fn forget_registration(&self, handler_name: &str) -> Result<(), ApplicationError> {
+        let deleted = match &self.registry {
+            Some(registry) => registry.delete(handler_name).await?,
+            None => false,
+        };
+        if !deleted {
+            return Err(ApplicationError::NotFound(format!(
+                "購読の登録内容が見つかりません: {}",
+                handler_name
+            )));
+        }
+        Ok(())
+    }
+
+    /// ハンドラーの現在の購読を指定した状態で保存する
+    async fn save_registration(
+        &self,
+        handler_name: &str,
+        statuses: &[SubscriptionStatus],
+        state: RegisteredSubscriptionState,
+    ) -> Result<(), ApplicationError> {
+        let Some(registry) = &self.registry else {
+            return Ok(());
+        };
+        if let Some(registration) =
+            SubscriptionRegistration::from_statuses(handler_name, statuses, state, Utc::now())
+        {
+            registry.save(&registration).await?;
+        }
+        Ok(())
+    }
+
+    /// 現在の購読の一覧を登録順に取得
+    pub async fn list_subscriptions(&self) -> Vec<SubscriptionStatus> {
+        self.subscription_manager.subscriptions().await
+    }
+
+    /// 配信待ちのイベント数と負荷制限の状態を取得
+    pub fn load_shedding_status(&self) -> LoadSheddingStatus {
+        self.subscription_manager.load_shedding()
+    }
+
+    /// ハンドラーの購読を解除
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 解除した購読の数
+    /// * `Err(ApplicationError::NotFound)` - 該当するハンドラーがない
+    pub async fn unsubscribe(&self, handler_name: &str) -> Result<usize, ApplicationError> {
+        // 解除すると購読の一覧から消えるため、解除前の購読を保存に使う
+        let statuses = self.subscription_manager.subscriptions().await;
+        let unsubscribed = self
+            .subscription_manager
+            .unsubscribe(handler_name)
+            .await
+            .map_err(subscription_error)?;
+        self.save_registration(
+            handler_name,
+            &statuses,
+            RegisteredSubscriptionState::Unsubscribed,
+        )
+        .await?;
+        Ok(unsubscribed)
+    }
+
+    /// ハンドラーへの配信を一時停止
+    ///
+    /// # Arguments
+    /// * `handler_name` - ハンドラー名
+    /// * `paused_events` - 一時停止中のイベントをためるか読み飛ばすか
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 一時停止した購読の数
+    /// * `Err(ApplicationError::NotFound)` - 該当するハンドラーがない
+    pub async fn pause(
+        &self,
+        handler_name: &str,
+        paused_events: PausedEventHandling,
+    ) -> Result<usize, ApplicationError> {
+        let paused = self
+            .subscription_manager
+            .pause(handler_name, paused_events)
+            .await
+            .map_err(subscription_error)?;
+        let statuses = self.subscription_manager.subscriptions().await;
+        self.save_registration(
+            handler_name,
+            &statuses,
+            RegisteredSubscriptionState::Paused(paused_events),
+        )
+        .await?;
+        Ok(paused)
+    }
+
+    /// ハンドラーへの配信を再開し、ためていたイベントを配信
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 再開時に配信したイベント数
+    /// * `Err(ApplicationError::NotFound)` - 該当するハンドラーがない
+    pub async fn resume(&self, handler_name: &str) -> Result<usize, ApplicationError> {
+        let delivered = self
+            .subscription_manager
+            .resume(handler_name)
+            .await
+            .map_err(subscription_error)?;
+        let statuses = self.subscription_manager.subscriptions().await;
+        self.save_registration(handler_name, &statuses, RegisteredSubscriptionState::Active)
+            .await?;
+        Ok(delivered)
+    }
+
+    /// 配信全体の制御先を取得
+    fn dispatch_control(&self) -> Result<&Arc<dyn EventDispatchControl>, ApplicationError> {
+        self.dispatch_control.as_ref().ok_or_else(|| {
+            ApplicationError::Unsupported("イベントの配信全体の一時停止は利用できません".to_string())
+        })
+    }
+
+    /// イベントの配信全体を一時停止し、処理中のハンドラーが終わるのを待つ（ローリングデプロイの前に使う）
+    /// 再起動すると一時停止は解除されるため、購読の登録内容には保存しない
+    ///
+    /// # Arguments
+    /// * `drain_timeout` - 処理中のハンドラーが終わるのを待つ上限
+    ///
+    /// # Returns
+    /// * `Ok(EventDispatchStatus)` - 一時停止した後の状態（処理中のイベント数が0であれば止め終えている）
+    /// * `Err(ApplicationError::Unsupported)` - 配信全体の制御先が設定されていない
+    pub async fn pause_event_dispatch(
+        &self,
+        drain_timeout: std::time::Duration,
+    ) -> Result<EventDispatchStatus, ApplicationError> {
+        Ok(self.dispatch_control()?.pause_dispatch(drain_timeout).await)
+    }
+
+    /// イベントの配信全体を再開し、一時停止中にためていたイベントを発行順に配信
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 再開時に配信したイベント数
+    /// * `Err(ApplicationError::Unsupported)` - 配信全体の制御先が設定されていない
+    pub async fn resume_event_dispatch(&self) -> Result<usize, ApplicationError> {
+        Ok(self.dispatch_control()?.resume_dispatch().await)
+    }
+
+    /// イベントの配信全体の状態を取得
+    ///
+    /// # Returns
+    /// * `Ok(EventDispatchStatus)` - 一時停止の状態と処理中・一時停止中にためているイベント数
+    /// * `Err(ApplicationError::Unsupported)` - 配信全体の制御先が設定されていない
+    pub fn event_dispatch_status(&self) -> Result<EventDispatchStatus, ApplicationError> {
+        Ok(self.dispatch_control()?.dispatch_status())
+    }
+}
+
+/// 購読管理のエラーをアプリケーションエラーに変換
+fn subscription_error(error: EventBusError) -> ApplicationError {
+    match error {
+        EventBusError::SubscriptionNotFound(handler_name) => {
+            ApplicationError::NotFound(format!("購読が見つかりません: {}", handler_name))
+        }
+        other => ApplicationError::EventPublishingFailed(other.to_string()),
+    }
+}
+
+/// 配送追跡アプリケーションサービス
+/// 配送業者からの追跡情報の受付と、顧客向け配送追跡タイムラインの取得を調整する
+pub struct TrackingApplicationService {
+    order_repository: Arc<dyn OrderRepository>,
+    tracking_repository: Arc<dyn TrackingEventRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl TrackingApplicationService {
+    /// 新しい配送追跡アプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ
+    /// * `tracking_repository` - 配送追跡イベントリポジトリ
+    /// * `event_bus` - イベントバス
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        tracking_repository: Arc<dyn TrackingEventRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            order_repository,
+            tracking_repository,
+            event_bus,
+        }
+    }
+
+    /// 配送業者から受信した追跡情報を受け付け、CarrierTrackingUpdatedイベントを発行する
+    /// タイムラインへの反映はプロジェクションハンドラーが非同期に行う
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `stage` - 配送業者が報告した段階
+    /// * `reported_at` - 配送業者側で発生した日時
+    /// * `location` - 拠点
+    /// * `description` - 詳細
+    ///
+    /// # Returns
+    /// * `Ok(())` - 受付成功
+    /// * `Err(ApplicationError)` - 受付失敗（注文が存在しない場合はNotFound）
+    #[tracing::instrument(name = "command.record_carrier_tracking", skip_all, fields(order_id = %order_id, stage = %stage, correlation_id = tracing::field::Empty), err)]
+    pub async fn record_carrier_update(
+        &self,
+        order_id: OrderId,
+        stage: TrackingStage,
+        reported_at: DateTime<Utc>,
+        location: Option<String>,
+        description: Option<String>,
+    ) -> Result<(), ApplicationError> {
+        self.ensure_order_exists(order_id).await?;
+
+        let mut event =
+            CarrierTrackingUpdated::new(order_id, stage, reported_at, location, description);
+        event.metadata.correlation_id = Uuid::new_v4();
+        tracing::Span::current().record(
+            "correlation_id",
+            tracing::field::display(event.metadata.correlation_id),
+        );
+
+        self.event_bus
+            .publish(DomainEvent::CarrierTrackingUpdated(event))
+            .await
+            .map_err(|e| ApplicationError::EventPublishingFailed(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// 注文の配送追跡タイムラインを取得
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(TrackingTimeline)` - タイムライン
+    /// * `Err(ApplicationError)` - 取得失敗（注文が存在しない場合はNotFound）
+    pub async fn get_tracking_timeline(
+        &self,
+        order_id: OrderId,
+    ) -> Result<TrackingTimeline, ApplicationError> {
+        self.ensure_order_exists(order_id).await?;
+
+        let events = self.tracking_repository.find_by_order(order_id).await?;
+        Ok(TrackingTimeline::build(order_id, &events))
+    }
+
+    async fn ensure_order_exists(&self, order_id: OrderId) -> Result<(), ApplicationError> {
+        self.order_repository
+            .find_by_id(order_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+            })?;
+        Ok(())
+    }
+}
+
+/// 配送業者の追跡Webhookのメッセージを受信メッセージの処理タスクから取り込む
+#[async_trait]
+impl InboxMessageHandler for TrackingApplicationService {
+    async fn handle_inbox_message(&self, message: &InboxMessage) -> Result<(), HandlerError> {
+        let carrier_message: CarrierTrackingMessage = serde_json::from_str(&message.payload)
+            .map_err(|e| {
+                HandlerError::PermanentError(format!("メッセージ本文の解析に失敗しました: {}", e))
+            })?;
+        let stage = carrier_message
+            .tracking_stage()
+            .map_err(|e| HandlerError::PermanentError(e.to_string()))?;
+
+        self.record_carrier_update(
+            OrderId::from_uuid(carrier_message.order_id),
+            stage,
+            carrier_message.occurred_at.unwrap_or(message.received_at),
+            carrier_message.location,
+            carrier_message.description,
+        )
+        .await
+        .map_err(|e| match e {
+            // 存在しない注文への追跡情報は再処理しても成功しない
+            ApplicationError::NotFound(msg) => HandlerError::PermanentError(msg),
+            other => HandlerError::TransientError(other.to_string()),
+        })
+    }
+}
+
+/// 注文タイムラインアプリケーションサービス（サポート向け）
+/// ドメインイベント・ステータスの遷移・CSメモ・配送業者の追跡情報をまとめた注文のタイムラインを提供する
+pub struct TimelineApplicationService {
+    order_repository: Arc<dyn OrderRepository>,
+    event_journal: Arc<dyn EventJournal>,
+    note_repository: Arc<dyn OrderNoteRepository>,
+    tracking_repository: Arc<dyn TrackingEventRepository>,
+}
+
+impl TimelineApplicationService {
+    /// 新しい注文タイムラインアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ
+    /// * `event_journal` - 発行されたイベントを記録したジャーナル
+    /// * `note_repository` - CSメモリポジトリ
+    /// * `tracking_repository` - 配送追跡イベントリポジトリ
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_journal: Arc<dyn EventJournal>,
+        note_repository: Arc<dyn OrderNoteRepository>,
+        tracking_repository: Arc<dyn TrackingEventRepository>,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_journal,
+            note_repository,
+            tracking_repository,
+        }
+    }
+
+    /// 注文のタイムラインを取得する
+    /// 注文・イベント・メモ・追跡情報はそれぞれのストアから並行して取得する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(OrderTimeline)` - 発生日時の昇順に並べたタイムライン
+    /// * `Err(ApplicationError::NotFound)` - 注文が見つからない
+    #[tracing::instrument(name = "query.get_order_timeline", skip_all, fields(order_id = %order_id), err)]
+    pub async fn get_order_timeline(
+        &self,
+        order_id: OrderId,
+    ) -> Result<OrderTimeline, ApplicationError> {
+        let aggregate_id = order_id.to_string();
+        let (order, events, notes, tracking) = tokio::try_join!(
+            self.order_repository.find_by_id(order_id),
+            self.event_journal.find_by_aggregate(&aggregate_id),
+            self.note_repository.find_by_order(order_id),
+            self.tracking_repository.find_by_order(order_id),
+        )?;
+        let order = order.ok_or_else(|| {
+            ApplicationError::NotFound(format!("注文が見つかりません: {}", order_id))
+        })?;
+
+        Ok(OrderTimeline::build(&order, &events, &notes, &tracking))
+    }
+
+    /// 注文にCSメモを追加する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `author` - メモを書いたオペレーター
+    /// * `body` - 本文
+    /// * `now` - 作成日時
+    ///
+    /// # Returns
+    /// * `Ok(OrderNote)` - 追加したメモ
+    /// * `Err(ApplicationError::NotFound)` - 注文が見つからない
+    /// * `Err(ApplicationError::DomainError)` - オペレーターまたは本文が不正
+    #[tracing::instrument(name = "command.add_order_note", skip_all, fields(order_id = %order_id), err)]
+    pub async fn add_note(
+        &self,
+        order_id: OrderId,
+        author: &str,
+        body: &str,
+        now: DateTime<Utc>,
+    ) -> Result<OrderNote, ApplicationError> {
+        if self.order_repository.find_by_id(order_id).await?.is_none() {
+            return Err(ApplicationError::NotFound(format!(
+                "注文が見つかりません: {}",
+                order_id
+            )));
+        }
+
+        let note = OrderNote::new(order_id, author, body, now)?;
+        self.note_repository.add(&note).await?;
+        Ok(note)
+    }
+}
+
+/// 受信メッセージアプリケーションサービス
+/// 外部システムから受信したメッセージを処理の前にinboxへ記録する
+/// 記録したメッセージはInboxProcessorが非同期に内部の処理へ振り分ける
+pub struct InboxApplicationService {
+    inbox_repository: Arc<dyn InboxRepository>,
+}
+
+impl InboxApplicationService {
+    /// 新しい受信メッセージアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `inbox_repository` - 受信メッセージリポジトリ
+    pub fn new(inbox_repository: Arc<dyn InboxRepository>) -> Self {
+        Self { inbox_repository }
+    }
+
+    /// 受信したメッセージを記録する
+    ///
+    /// # Arguments
+    /// * `source` - 送信元
+    /// * `external_id` - 送信元が付与したメッセージID
+    /// * `payload` - 受信したメッセージの本文
+    /// * `received_at` - 受信日時
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 新しく記録した
+    /// * `Ok(false)` - 再送されたメッセージ（記録済みのため何もしない）
+    /// * `Err(ApplicationError)` - 記録失敗
+    #[tracing::instrument(name = "command.receive_inbox_message", skip_all, fields(source = %source, external_id = %external_id), err)]
+    pub async fn receive(
+        &self,
+        source: &str,
+        external_id: String,
+        payload: String,
+        received_at: DateTime<Utc>,
+    ) -> Result<bool, ApplicationError> {
+        if external_id.trim().is_empty() {
+            return Err(ApplicationError::DomainError(DomainError::InvalidValue(
+                "メッセージIDは必須です".to_string(),
+            )));
+        }
+
+        let message = InboxMessage::received(source, external_id, payload, received_at);
+        self.inbox_repository
+            .insert(&message)
+            .await
+            .map_err(ApplicationError::from)
+    }
+}
+
+/// 旧システムの注文の取り込みコマンド
+/// 旧システムのレコードを腐敗防止層で変換した結果（このシステムの値オブジェクトのみで表現する）
+#[derive(Debug, Clone)]
+pub struct ImportLegacyOrderCommand {
+    /// 旧システムの注文番号
+    pub legacy_order_no: String,
+    pub customer_id: CustomerId,
+    pub order_lines: Vec<OrderLine>,
+    pub shipping_address: Option<ShippingAddress>,
+    pub status: OrderStatus,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// 旧システムの注文1件の取り込み結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum LegacyImportResult {
+    /// 注文として取り込んだ
+    Imported(OrderId),
+    /// 取り込み済みのため何もしなかった
+    AlreadyImported(OrderId),
+    /// 取り込めなかったため隔離した
+    Quarantined(Vec<String>),
+}
+
+/// 旧システム注文取り込みアプリケーションサービス
+/// 腐敗防止層で変換した注文を保存し、変換・検証できなかったレコードを隔離する
+/// 取り込んだ注文は既に旧システムで処理が進んでいるため、イベントは発行せず在庫も予約しない
+pub struct LegacyImportApplicationService {
+    order_repository: Arc<dyn OrderRepository>,
+    legacy_import_repository: Arc<dyn LegacyImportRepository>,
+}
+
+impl LegacyImportApplicationService {
+    /// 新しい旧システム注文取り込みアプリケーションサービスを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ
+    /// * `legacy_import_repository` - 取り込み記録リポジトリ
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        legacy_import_repository: Arc<dyn LegacyImportRepository>,
+    ) -> Self {
+        Self {
+            order_repository,
+            legacy_import_repository,
+        }
+    }
+
+    /// 変換した注文を取り込む
+    /// 注文の検証に失敗した場合はレコードを隔離する
+    ///
+    /// # Arguments
+    /// * `command` - 取り込みコマンド
+    /// * `payload` - 旧システムから受け取ったレコード（隔離時に保存する）
+    /// * `now` - 取り込み日時
+    ///
+    /// # Returns
+    /// * `Ok(LegacyImportResult)` - 取り込み結果
+    /// * `Err(ApplicationError)` - 保存失敗
+    #[tracing::instrument(name = "command.import_legacy_order", skip_all, fields(legacy_order_no = %command.legacy_order_no), err)]
+    pub async fn import_order(
+        &self,
+        command: ImportLegacyOrderCommand,
+        payload: String,
+        now: DateTime<Utc>,
+    ) -> Result<LegacyImportResult, ApplicationError> {
+        if let Some(order_id) = self.imported_order_id(&command.legacy_order_no).await? {
+            return Ok(LegacyImportResult::AlreadyImported(order_id));
+        }
+
+        let mut order = match Order::import(
+            self.order_repository.next_identity(),
+            command.customer_id,
+            command.order_lines,
+            command.shipping_address,
+            command.status,
+            command.confirmed_at,
+            command.shipped_at,
+            command.delivered_at,
+        ) {
+            Ok(order) => order,
+            Err(e) => {
+                let reasons = vec![e.to_string()];
+                let record = LegacyImportRecord::quarantined(
+                    command.legacy_order_no,
+                    payload,
+                    reasons.clone(),
+                    now,
+                );
+                self.legacy_import_repository.save(&record).await?;
+                return Ok(LegacyImportResult::Quarantined(reasons));
+            }
+        };
+
+        self.order_repository.save(&mut order).await?;
+        let record =
+            LegacyImportRecord::imported(command.legacy_order_no, order.id(), payload, now);
+        self.legacy_import_repository.save(&record).await?;
+
+        Ok(LegacyImportResult::Imported(order.id()))
+    }
+
+    /// 変換できなかったレコードを隔離する
+    /// 取り込み済みの注文番号の場合は隔離せず、取り込み済みの注文を返す
+    ///
+    /// # Arguments
+    /// * `legacy_order_no` - 旧システムの注文番号
+    /// * `payload` - 旧システムから受け取ったレコード
+    /// * `reasons` - 変換できなかった理由
+    /// * `now` - 隔離日時
+    pub async fn quarantine(
+        &self,
+        legacy_order_no: String,
+        payload: String,
+        reasons: Vec<String>,
+        now: DateTime<Utc>,
+    ) -> Result<LegacyImportResult, ApplicationError> {
+        if let Some(order_id) = self.imported_order_id(&legacy_order_no).await? {
+            return Ok(LegacyImportResult::AlreadyImported(order_id));
+        }
+
+        let record =
+            LegacyImportRecord::quarantined(legacy_order_no, payload, reasons.clone(), now);
+        self.legacy_import_repository.save(&record).await?;
+        Ok(LegacyImportResult::Quarantined(reasons))
+    }
+
+    /// 隔離中のレコードを隔離日時の昇順で取得
+    pub async fn list_quarantined(&self) -> Result<Vec<LegacyImportRecord>, ApplicationError> {
+        self.legacy_import_repository
+            .find_quarantined()
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    async fn imported_order_id(
+        &self,
+        legacy_order_no: &str,
+    ) -> Result<Option<OrderId>, ApplicationError> {
+        let record = self
+            .legacy_import_repository
+            .find_by_legacy_order_no(legacy_order_no)
+            .await?;
+        Ok(record
+            .filter(|record| record.outcome == LegacyImportOutcome::Imported)
+            .and_then(|record| record.order_id))
+    }
 }
@@ -0,0 +1,472 @@
+//! イベントジェネレーター
+//!
+//! 下流のイベント利用者の開発向けに、サーガ単位で相関したランダムなドメインイベントを生成する。
+//! イベントは `EventSerializer` でシリアライズし、標準出力（1行1イベントのJSON Lines）または
+//! Kafka REST Proxyのトピック（`POST /topics/{topic}`）に出力する。
+//! 同じシードを指定すると同じイベント列を生成する（JSONのキーは辞書順に並べる）。
+//!
+//! ```bash
+//! cargo run --bin eventgen -- --seed 42 --sagas 100 --shipping-failure-rate 0.1
+//! cargo run --bin eventgen -- --seed 42 --output http://localhost:8082/topics/bookstore-events
+//! ```
+
+use bookstore_order_management::domain::event::{
+    DeliveryFailed, DomainEvent, EventMetadata, InventoryReleased, InventoryReservationFailed,
+    InventoryReserved, OrderCancelled, OrderConfirmed, OrderDelivered, OrderShipped,
+    ShippingFailed,
+};
+use bookstore_order_management::domain::model::{
+    BookId, CustomerId, Money, OrderId, OrderLine, ShippingAddress,
+};
+use bookstore_order_management::domain::serialization::EventSerializer;
+use chrono::{DateTime, TimeDelta, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::io::Write;
+use uuid::Uuid;
+
+const USAGE: &str = "\
+使い方: eventgen [オプション]
+
+  --seed <N>                            乱数のシード（省略時はランダム。使用したシードを標準エラーに出力）
+  --sagas <N>                           生成するサーガ（注文）の数 [既定: 10]
+  --books <N>                           注文に使う書籍の種類 [既定: 20]
+  --start <RFC3339>                     最初の注文の確定日時 [既定: 2024-01-01T00:00:00Z]
+  --inventory-reservation-failure-rate <0.0-1.0>  在庫予約の失敗率 [既定: 0.05]
+  --shipping-failure-rate <0.0-1.0>     発送の失敗率 [既定: 0.02]
+  --delivery-failure-rate <0.0-1.0>     配達の失敗率 [既定: 0.01]
+  --output <stdout|URL>                 出力先（URLの場合はKafka REST Proxyのトピック） [既定: stdout]
+";
+
+/// 配送先の候補（郵便番号, 都道府県, 市区町村, 番地）
+const ADDRESSES: [(&str, &str, &str, &str); 4] = [
+    ("1500001", "東京都", "渋谷区", "神宮前1-1-1"),
+    ("5300001", "大阪府", "大阪市北区", "梅田2-2-2"),
+    ("0600001", "北海道", "札幌市中央区", "北一条西3-3-3"),
+    ("9000001", "沖縄県", "那覇市", "港町4-4-4"),
+];
+
+/// 書籍の価格の候補（円）
+const PRICES: [i64; 5] = [880, 1320, 1540, 2200, 3080];
+
+/// サーガのステップごとの失敗率
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FailureRates {
+    inventory_reservation: f64,
+    shipping: f64,
+    delivery: f64,
+}
+
+impl Default for FailureRates {
+    fn default() -> Self {
+        Self {
+            inventory_reservation: 0.05,
+            shipping: 0.02,
+            delivery: 0.01,
+        }
+    }
+}
+
+/// イベントの出力先
+#[derive(Debug, Clone, PartialEq)]
+enum Output {
+    Stdout,
+    /// Kafka REST ProxyのトピックのURL
+    RestProxy(String),
+}
+
+/// コマンドライン引数
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    seed: Option<u64>,
+    sagas: usize,
+    books: usize,
+    start: DateTime<Utc>,
+    failure_rates: FailureRates,
+    output: Output,
+}
+
+impl Options {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            seed: None,
+            sagas: 10,
+            books: 20,
+            start: DateTime::from_timestamp(1_704_067_200, 0).expect("既定の開始日時は有効です"),
+            failure_rates: FailureRates::default(),
+            output: Output::Stdout,
+        };
+
+        let mut args = args.into_iter();
+        while let Some(name) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("{} に値が指定されていません", name))
+            };
+            match name.as_str() {
+                "--seed" => options.seed = Some(parse_number(&name, &value()?)?),
+                "--sagas" => options.sagas = parse_number(&name, &value()?)?,
+                "--books" => options.books = parse_number(&name, &value()?)?,
+                "--start" => {
+                    let start = value()?;
+                    options.start = DateTime::parse_from_rfc3339(&start)
+                        .map_err(|e| format!("--start の日時が不正です: {} ({})", start, e))?
+                        .with_timezone(&Utc);
+                }
+                "--inventory-reservation-failure-rate" => {
+                    options.failure_rates.inventory_reservation = parse_rate(&name, &value()?)?
+                }
+                "--shipping-failure-rate" => {
+                    options.failure_rates.shipping = parse_rate(&name, &value()?)?
+                }
+                "--delivery-failure-rate" => {
+                    options.failure_rates.delivery = parse_rate(&name, &value()?)?
+                }
+                "--output" => {
+                    options.output = match value()?.as_str() {
+                        "stdout" => Output::Stdout,
+                        url if url.starts_with("http://") || url.starts_with("https://") => {
+                            Output::RestProxy(url.to_string())
+                        }
+                        other => return Err(format!("--output の値が不正です: {}", other)),
+                    }
+                }
+                other => return Err(format!("不明なオプションです: {}", other)),
+            }
+        }
+
+        if options.books == 0 {
+            return Err("--books は1以上で指定してください".to_string());
+        }
+        Ok(options)
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} の値が不正です: {}", name, value))
+}
+
+fn parse_rate(name: &str, value: &str) -> Result<f64, String> {
+    let rate: f64 = parse_number(name, value)?;
+    if !(0.0..=1.0).contains(&rate) {
+        return Err(format!("{} は0.0〜1.0で指定してください: {}", name, value));
+    }
+    Ok(rate)
+}
+
+/// シード付きの乱数でサーガ単位のイベント列を生成する
+/// イベントID・日時も乱数と開始日時から決めるため、同じシードからは同じイベント列になる
+struct EventGenerator {
+    rng: StdRng,
+    failure_rates: FailureRates,
+    books: Vec<(BookId, Money)>,
+    /// 次の注文の確定日時
+    clock: DateTime<Utc>,
+}
+
+impl EventGenerator {
+    fn new(seed: u64, books: usize, start: DateTime<Utc>, failure_rates: FailureRates) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let books = (0..books)
+            .map(|_| {
+                let book_id = BookId::from_uuid(random_uuid(&mut rng));
+                let price = PRICES[rng.gen_range(0..PRICES.len())];
+                (book_id, Money::jpy(price))
+            })
+            .collect();
+        Self {
+            rng,
+            failure_rates,
+            books,
+            clock: start,
+        }
+    }
+
+    /// 指定した数のサーガのイベントを生成し、発生日時の順に並べる
+    /// 注文の間隔よりサーガの所要時間が長いため、複数のサーガのイベントが交互に現れる
+    fn generate(&mut self, sagas: usize) -> Vec<DomainEvent> {
+        let mut events = Vec::new();
+        for _ in 0..sagas {
+            events.extend(self.saga());
+            self.clock += TimeDelta::minutes(self.rng.gen_range(1..=30));
+        }
+        // 同じ日時のイベントは生成順（サーガ内の順序）を保つ
+        events.sort_by_key(|event| event.metadata().occurred_at);
+        events
+    }
+
+    /// 注文1件のサーガ（確定 → 在庫予約 → 発送 → 配達完了）のイベントを生成する
+    /// 各ステップは失敗率に応じて失敗し、アプリケーションの補償ハンドラーと同じイベントで終わる
+    fn saga(&mut self) -> Vec<DomainEvent> {
+        let order_id = OrderId::from_uuid(self.uuid());
+        let customer_id = CustomerId::from_uuid(self.uuid());
+        let correlation_id = self.uuid();
+        let order_lines = self.order_lines();
+        let total_amount = Money::jpy(
+            order_lines
+                .iter()
+                .map(|line| line.unit_price().amount() * i64::from(line.quantity()))
+                .sum(),
+        );
+        let mut at = self.clock;
+        let mut events = Vec::new();
+
+        let mut confirmed =
+            OrderConfirmed::new(order_id, customer_id, order_lines.clone(), total_amount);
+        self.stamp(&mut confirmed.metadata, correlation_id, at, Some(1));
+        let confirmed_event_id = confirmed.metadata.event_id;
+        events.push(DomainEvent::OrderConfirmed(confirmed));
+
+        // 在庫予約（確定直後に自動実行）
+        at += TimeDelta::seconds(self.rng.gen_range(1..=30));
+        if self.rng.gen_bool(self.failure_rates.inventory_reservation) {
+            let mut failed = InventoryReservationFailed::with_correlation_id(
+                order_id,
+                order_lines.clone(),
+                "在庫予約失敗: 在庫が不足しています".to_string(),
+                confirmed_event_id,
+                correlation_id,
+            );
+            self.stamp(&mut failed.metadata, correlation_id, at, None);
+            events.push(DomainEvent::InventoryReservationFailed(failed));
+
+            at += TimeDelta::seconds(1);
+            let mut cancelled = OrderCancelled::with_correlation_id(
+                order_id,
+                customer_id,
+                order_lines,
+                correlation_id,
+            );
+            self.stamp(&mut cancelled.metadata, correlation_id, at, Some(2));
+            events.push(DomainEvent::OrderCancelled(cancelled));
+            return events;
+        }
+        let mut reserved =
+            InventoryReserved::with_correlation_id(order_id, order_lines.clone(), correlation_id);
+        self.stamp(&mut reserved.metadata, correlation_id, at, None);
+        events.push(DomainEvent::InventoryReserved(reserved));
+
+        // 発送
+        at += TimeDelta::minutes(self.rng.gen_range(60..=48 * 60));
+        if self.rng.gen_bool(self.failure_rates.shipping) {
+            let mut failed = ShippingFailed::with_correlation_id(
+                order_id,
+                "発送処理失敗: 配送業者の集荷がキャンセルされました".to_string(),
+                confirmed_event_id,
+                correlation_id,
+            );
+            self.stamp(&mut failed.metadata, correlation_id, at, None);
+            events.push(DomainEvent::ShippingFailed(failed));
+
+            at += TimeDelta::seconds(1);
+            let mut released =
+                InventoryReleased::with_correlation_id(order_id, order_lines, correlation_id);
+            self.stamp(&mut released.metadata, correlation_id, at, None);
+            events.push(DomainEvent::InventoryReleased(released));
+            return events;
+        }
+        let mut shipped =
+            OrderShipped::with_correlation_id(order_id, self.shipping_address(), correlation_id);
+        self.stamp(&mut shipped.metadata, correlation_id, at, Some(2));
+        let shipped_event_id = shipped.metadata.event_id;
+        events.push(DomainEvent::OrderShipped(shipped));
+
+        // 配達完了
+        at += TimeDelta::minutes(self.rng.gen_range(12 * 60..=72 * 60));
+        if self.rng.gen_bool(self.failure_rates.delivery) {
+            let mut failed = DeliveryFailed::with_correlation_id(
+                order_id,
+                "配達処理失敗: 受取人が不在でした".to_string(),
+                shipped_event_id,
+                correlation_id,
+            );
+            self.stamp(&mut failed.metadata, correlation_id, at, None);
+            events.push(DomainEvent::DeliveryFailed(failed));
+            return events;
+        }
+        let mut delivered = OrderDelivered::with_correlation_id(order_id, correlation_id);
+        self.stamp(&mut delivered.metadata, correlation_id, at, Some(3));
+        events.push(DomainEvent::OrderDelivered(delivered));
+
+        events
+    }
+
+    /// 1〜3冊の異なる書籍の注文明細
+    fn order_lines(&mut self) -> Vec<OrderLine> {
+        let line_count = self.rng.gen_range(1..=3).min(self.books.len());
+        let mut order_lines: Vec<OrderLine> = Vec::new();
+        while order_lines.len() < line_count {
+            let (book_id, unit_price) = self.books[self.rng.gen_range(0..self.books.len())];
+            if order_lines.iter().any(|line| line.book_id() == book_id) {
+                continue;
+            }
+            let quantity = self.rng.gen_range(1..=3);
+            order_lines.push(
+                OrderLine::new(book_id, quantity, unit_price).expect("数量は1以上で生成します"),
+            );
+        }
+        order_lines
+    }
+
+    fn shipping_address(&mut self) -> ShippingAddress {
+        let (postal_code, prefecture, city, street) =
+            ADDRESSES[self.rng.gen_range(0..ADDRESSES.len())];
+        ShippingAddress::new(
+            postal_code.to_string(),
+            prefecture.to_string(),
+            city.to_string(),
+            street.to_string(),
+            None,
+        )
+        .expect("候補の住所は有効です")
+    }
+
+    /// イベントID・相関ID・発生日時・連番を、生成したイベント列の値に置き換える
+    fn stamp(
+        &mut self,
+        metadata: &mut EventMetadata,
+        correlation_id: Uuid,
+        occurred_at: DateTime<Utc>,
+        sequence_number: Option<u64>,
+    ) {
+        metadata.event_id = self.uuid();
+        metadata.correlation_id = correlation_id;
+        metadata.occurred_at = occurred_at;
+        metadata.sequence_number = sequence_number;
+    }
+
+    fn uuid(&mut self) -> Uuid {
+        random_uuid(&mut self.rng)
+    }
+}
+
+fn random_uuid(rng: &mut StdRng) -> Uuid {
+    uuid::Builder::from_random_bytes(rng.gen()).into_uuid()
+}
+
+/// イベントをEventSerializerでシリアライズし、キーを辞書順に並べたJSONに変換する
+/// 追加メタデータはHashMapのため、そのままでは実行ごとにキーの順序が変わる
+fn to_json(serializer: &EventSerializer, event: &DomainEvent) -> Result<serde_json::Value, String> {
+    let json = serializer
+        .serialize_event(event)
+        .map_err(|e| format!("イベントのシリアライズに失敗しました: {}", e))?;
+    serde_json::from_str(&json).map_err(|e| format!("JSONの変換に失敗しました: {}", e))
+}
+
+async fn publish(
+    client: &reqwest::Client,
+    url: &str,
+    event: &DomainEvent,
+    value: serde_json::Value,
+) -> Result<(), String> {
+    // 集約IDをキーにして、同じ注文のイベントを同じパーティションに送る
+    let body = serde_json::json!({
+        "records": [{ "key": event.aggregate_id(), "value": value }]
+    });
+    let response = client
+        .post(url)
+        .header("Content-Type", "application/vnd.kafka.json.v2+json")
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("ブローカーへの送信に失敗しました: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "ブローカーがエラーを返しました: {}",
+            response.status()
+        ));
+    }
+    Ok(())
+}
+
+async fn run(options: Options) -> Result<(), String> {
+    let seed = options.seed.unwrap_or_else(rand::random);
+    eprintln!("eventgen: seed={} sagas={}", seed, options.sagas);
+
+    let events = EventGenerator::new(seed, options.books, options.start, options.failure_rates)
+        .generate(options.sagas);
+    let serializer = EventSerializer::new();
+    let client = reqwest::Client::new();
+    let mut stdout = std::io::stdout().lock();
+
+    for event in &events {
+        let value = to_json(&serializer, event)?;
+        match &options.output {
+            Output::Stdout => match writeln!(stdout, "{}", value) {
+                Ok(()) => {}
+                // headなどで出力先が閉じられた場合は、エラーにせず終了する
+                Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => return Ok(()),
+                Err(e) => return Err(format!("標準出力への書き込みに失敗しました: {}", e)),
+            },
+            Output::RestProxy(url) => publish(&client, url, event, value).await?,
+        }
+    }
+
+    eprintln!("eventgen: {}件のイベントを出力しました", events.len());
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        print!("{}", USAGE);
+        return;
+    }
+
+    let result = match Options::parse(args) {
+        Ok(options) => run(options).await,
+        Err(message) => Err(format!("{}\n\n{}", message, USAGE)),
+    };
+    if let Err(message) = result {
+        eprintln!("eventgen: {}", message);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate(seed: u64, failure_rates: FailureRates) -> Vec<String> {
+        let serializer = EventSerializer::new();
+        let start = DateTime::from_timestamp(1_704_067_200, 0).unwrap();
+        EventGenerator::new(seed, 5, start, failure_rates)
+            .generate(20)
+            .iter()
+            .map(|event| to_json(&serializer, event).unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_same_seed_generates_same_stream() {
+        let rates = FailureRates::default();
+        assert_eq!(generate(42, rates), generate(42, rates));
+        assert_ne!(generate(42, rates), generate(43, rates));
+
+        // 在庫予約が必ず失敗する場合は、すべてのサーガがキャンセルで終わる
+        let always_fail = FailureRates {
+            inventory_reservation: 1.0,
+            ..rates
+        };
+        let events = EventGenerator::new(7, 5, Utc::now(), always_fail).generate(3);
+        let event_types: Vec<&str> = events.iter().map(|event| event.event_type()).collect();
+        assert_eq!(
+            event_types
+                .iter()
+                .filter(|&&event_type| event_type == "OrderCancelled")
+                .count(),
+            3
+        );
+        assert!(!event_types.contains(&"OrderShipped"));
+
+        let options =
+            Options::parse(["--seed", "1", "--shipping-failure-rate", "0.5"].map(String::from))
+                .unwrap();
+        assert_eq!(options.seed, Some(1));
+        assert_eq!(options.failure_rates.shipping, 0.5);
+        assert!(Options::parse(["--delivery-failure-rate", "1.5"].map(String::from)).is_err());
+    }
+}
@@ -1,7 +1,68 @@
+pub mod access_control;
+pub mod action_link;
+pub mod address_normalizer;
+pub mod alerting;
+pub mod anonymization;
+pub mod book_translation;
+pub mod bulk_cancellation;
+pub mod cancellation_policy;
+pub mod checkout_hold;
+pub mod customer_export;
+pub mod customer_notification;
+pub mod customer_webhook;
+pub mod dead_letter;
+pub mod delivery_estimate;
+pub mod diagnostics;
 pub mod error;
 pub mod event;
 pub mod event_bus;
+pub mod event_export;
+pub mod event_schema;
+pub mod event_sourcing;
+pub mod event_trace;
+pub mod forecast;
+pub mod fulfillment_mode;
+pub mod glossary;
 pub mod handler;
+pub mod handler_health;
+pub mod inbox;
+pub mod invariant;
+pub mod inventory_snapshot;
+pub mod inventory_valuation;
+pub mod late_event;
+pub mod legacy_import;
+pub mod load_shedding;
+pub mod metrics;
 pub mod model;
+pub mod notification_retry;
+pub mod notification_template;
+pub mod order_event_stream;
+pub mod order_lock;
+pub mod order_note;
+pub mod order_repair;
+pub mod order_timeline;
+pub mod order_totals;
+pub mod packing_slip;
+pub mod pending_operation;
 pub mod port;
+pub mod pre_order;
+pub mod pricing;
+pub mod projection;
+pub mod purchase_policy;
+pub mod reconciliation;
+pub mod retry_policy;
+pub mod saga_metrics;
+pub mod saga_orchestrator;
+pub mod saga_timeout;
+pub mod segmentation;
+pub mod sequence;
 pub mod serialization;
+pub mod shipping_fee;
+pub mod sla;
+pub mod status_override;
+pub mod subscription_registry;
+pub mod task_supervisor;
+pub mod tracking;
+pub mod validation;
+pub mod waitlist;
+pub mod warning;
@@ -0,0 +1,171 @@
+use crate::domain::model::CustomerId;
+use serde::{Deserialize, Serialize};
+
+/// APIの利用者の役割
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// 顧客（自分の注文と顧客情報だけを操作できる）
+    Customer,
+    /// 店舗・倉庫・サポートのスタッフ（発送・配達・在庫の変更などの業務を行う）
+    Staff,
+}
+
+impl Role {
+    /// 役割の名前（トークンのroleクレームの値）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Customer => "customer",
+            Role::Staff => "staff",
+        }
+    }
+
+    /// 役割に付与する権限
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Role::Customer => &[
+                Permission::OrdersRead,
+                Permission::OrdersWrite,
+                Permission::CustomersRead,
+                Permission::CustomersWrite,
+            ],
+            Role::Staff => &Permission::ALL,
+        }
+    }
+}
+
+/// エンドポイントのポリシーが要求する権限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Permission {
+    /// 注文の参照
+    #[serde(rename = "orders:read")]
+    OrdersRead,
+    /// 注文の作成・変更・キャンセル
+    #[serde(rename = "orders:write")]
+    OrdersWrite,
+    /// 全顧客の注文の参照と、発送・配達・保留などの業務の操作
+    #[serde(rename = "orders:manage")]
+    OrdersManage,
+    /// 顧客情報の参照
+    #[serde(rename = "customers:read")]
+    CustomersRead,
+    /// 顧客情報の変更
+    #[serde(rename = "customers:write")]
+    CustomersWrite,
+    /// 在庫・カタログの変更と在庫の修復
+    #[serde(rename = "inventory:admin")]
+    InventoryAdmin,
+    /// 保留・デッドレターのイベントと送信に失敗した通知の確認・再送
+    #[serde(rename = "dlq:manage")]
+    DlqManage,
+    /// その他の管理用API・メトリクス・レポート
+    #[serde(rename = "admin:access")]
+    AdminAccess,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 8] = [
+        Permission::OrdersRead,
+        Permission::OrdersWrite,
+        Permission::OrdersManage,
+        Permission::CustomersRead,
+        Permission::CustomersWrite,
+        Permission::InventoryAdmin,
+        Permission::DlqManage,
+        Permission::AdminAccess,
+    ];
+
+    /// 権限の名前
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::OrdersRead => "orders:read",
+            Permission::OrdersWrite => "orders:write",
+            Permission::OrdersManage => "orders:manage",
+            Permission::CustomersRead => "customers:read",
+            Permission::CustomersWrite => "customers:write",
+            Permission::InventoryAdmin => "inventory:admin",
+            Permission::DlqManage => "dlq:manage",
+            Permission::AdminAccess => "admin:access",
+        }
+    }
+}
+
+/// 認証済みの利用者
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    /// 利用者の識別子（トークンのsubクレーム）
+    pub subject: String,
+    pub role: Role,
+    /// 顧客の場合は顧客ID
+    pub customer_id: Option<CustomerId>,
+}
+
+impl Principal {
+    /// スタッフの利用者を作成
+    pub fn staff(subject: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            role: Role::Staff,
+            customer_id: None,
+        }
+    }
+
+    /// 顧客の利用者を作成
+    pub fn customer(subject: impl Into<String>, customer_id: CustomerId) -> Self {
+        Self {
+            subject: subject.into(),
+            role: Role::Customer,
+            customer_id: Some(customer_id),
+        }
+    }
+
+    pub fn is_staff(&self) -> bool {
+        self.role == Role::Staff
+    }
+
+    /// 役割に権限が付与されているか
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.role.permissions().contains(&permission)
+    }
+
+    /// 指定した顧客として操作できるか（スタッフはすべての顧客、顧客は本人だけ）
+    ///
+    /// # Arguments
+    /// * `customer_id` - 操作の対象の顧客（注文の場合は注文した顧客）
+    pub fn can_act_for(&self, customer_id: CustomerId) -> bool {
+        match self.role {
+            Role::Staff => true,
+            Role::Customer => self.customer_id == Some(customer_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_customers_can_act_only_for_themselves() {
+        let customer_id = CustomerId::new();
+        let customer = Principal::customer("user-1", customer_id);
+
+        assert!(customer.can_act_for(customer_id));
+        assert!(!customer.can_act_for(CustomerId::new()));
+        assert!(!customer.is_staff());
+        assert!(Principal::staff("operator-1").can_act_for(customer_id));
+    }
+
+    #[test]
+    fn test_customers_have_no_back_office_permissions() {
+        let customer = Principal::customer("user-1", CustomerId::new());
+        let staff = Principal::staff("operator-1");
+
+        assert!(customer.has_permission(Permission::OrdersWrite));
+        assert!(!customer.has_permission(Permission::OrdersManage));
+        assert!(!customer.has_permission(Permission::InventoryAdmin));
+        assert!(!customer.has_permission(Permission::DlqManage));
+        assert!(Permission::ALL
+            .iter()
+            .all(|permission| staff.has_permission(*permission)));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::OrderId;
+use crate::domain::port::ActionLinkSigner;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// アクションリンクで実行できる顧客の操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerAction {
+    /// 注文をキャンセルする（確定後の通知に載せる）
+    CancelOrder,
+    /// 商品の受け取りを確認して配達完了にする（発送の通知に載せる）
+    ConfirmDelivery,
+}
+
+impl CustomerAction {
+    /// 操作の名前（トークンとURLに含める）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomerAction::CancelOrder => "cancel_order",
+            CustomerAction::ConfirmDelivery => "confirm_delivery",
+        }
+    }
+
+    /// 文字列からCustomerActionを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "cancel_order" => Ok(CustomerAction::CancelOrder),
+            "confirm_delivery" => Ok(CustomerAction::ConfirmDelivery),
+            _ => Err(DomainError::InvalidValue(format!("無効な操作: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for CustomerAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// アクションリンクに署名して埋め込む内容
+/// 署名を検証できたトークンからだけ復元する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActionLinkClaims {
+    /// リンクごとに発行するID（監査ログで使われたトークンを特定する）
+    pub link_id: Uuid,
+    pub action: CustomerAction,
+    pub order_id: OrderId,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl ActionLinkClaims {
+    /// 新しいリンクの内容を作成
+    ///
+    /// # Arguments
+    /// * `action` - 実行する操作
+    /// * `order_id` - 操作の対象の注文
+    /// * `now` - 発行日時
+    /// * `ttl` - リンクの有効期間
+    pub fn new(
+        action: CustomerAction,
+        order_id: OrderId,
+        now: DateTime<Utc>,
+        ttl: TimeDelta,
+    ) -> Self {
+        Self {
+            link_id: Uuid::new_v4(),
+            action,
+            order_id,
+            // トークンには秒単位で埋め込むため、秒未満は切り捨てる
+            expires_at: DateTime::from_timestamp((now + ttl).timestamp(), 0).unwrap_or(now + ttl),
+        }
+    }
+
+    /// 有効期限が切れているか
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// 通知に載せるアクションリンク
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActionLink {
+    pub action: CustomerAction,
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// アクションリンクの発行
+/// 署名したトークンを`{ベースURL}/actions/{トークン}`の形式のURLにする
+#[derive(Clone)]
+pub struct ActionLinkIssuer {
+    signer: Arc<dyn ActionLinkSigner>,
+    base_url: String,
+    ttl: TimeDelta,
+}
+
+impl ActionLinkIssuer {
+    /// 新しいアクションリンクの発行を作成
+    ///
+    /// # Arguments
+    /// * `signer` - トークンの署名
+    /// * `base_url` - リンクのベースURL（例: https://shop.example.com）
+    /// * `ttl` - リンクの有効期間
+    pub fn new(signer: Arc<dyn ActionLinkSigner>, base_url: String, ttl: TimeDelta) -> Self {
+        Self {
+            signer,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            ttl,
+        }
+    }
+
+    /// 注文に対する操作のリンクを発行
+    pub fn issue(
+        &self,
+        action: CustomerAction,
+        order_id: OrderId,
+        now: DateTime<Utc>,
+    ) -> ActionLink {
+        let claims = ActionLinkClaims::new(action, order_id, now, self.ttl);
+        ActionLink {
+            action,
+            url: format!("{}/actions/{}", self.base_url, self.signer.sign(&claims)),
+            expires_at: claims.expires_at,
+        }
+    }
+}
+
+/// アクションリンクを使った結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionLinkOutcome {
+    /// 操作を実行した
+    Executed,
+    /// 有効期限切れ、または注文の状態などにより実行できなかった
+    Rejected,
+}
+
+impl ActionLinkOutcome {
+    /// 文字列からActionLinkOutcomeを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Executed" => Ok(ActionLinkOutcome::Executed),
+            "Rejected" => Ok(ActionLinkOutcome::Rejected),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効なアクションリンクの結果: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for ActionLinkOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome_str = match self {
+            ActionLinkOutcome::Executed => "Executed",
+            ActionLinkOutcome::Rejected => "Rejected",
+        };
+        write!(f, "{}", outcome_str)
+    }
+}
+
+/// アクションリンクの使用の監査記録
+/// 署名を検証できたトークンを使うたびに、実行できたかどうかに関わらず記録する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionLinkUse {
+    pub id: Uuid,
+    /// 使われたトークンのリンクID
+    pub link_id: Uuid,
+    pub action: CustomerAction,
+    pub order_id: OrderId,
+    /// トークンの有効期限
+    pub expires_at: DateTime<Utc>,
+    pub outcome: ActionLinkOutcome,
+    /// 実行できなかった理由
+    pub error: Option<String>,
+    pub used_at: DateTime<Utc>,
+}
+
+impl ActionLinkUse {
+    /// トークンの使用を記録
+    ///
+    /// # Arguments
+    /// * `claims` - 検証したトークンの内容
+    /// * `error` - 実行できなかった理由（実行できた場合はNone）
+    /// * `used_at` - 使用日時
+    pub fn record(
+        claims: &ActionLinkClaims,
+        error: Option<String>,
+        used_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            link_id: claims.link_id,
+            action: claims.action,
+            order_id: claims.order_id,
+            expires_at: claims.expires_at,
+            outcome: if error.is_none() {
+                ActionLinkOutcome::Executed
+            } else {
+                ActionLinkOutcome::Rejected
+            },
+            error,
+            used_at,
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::ShippingAddress;
+use serde::{Deserialize, Serialize};
+
+/// 都道府県の正式名称
+const PREFECTURES: [&str; 47] = [
+    "北海道", "青森県", "岩手県", "宮城県", "秋田県", "山形県", "福島県", "茨城県", "栃木県",
+    "群馬県", "埼玉県", "千葉県", "東京都", "神奈川県", "新潟県", "富山県", "石川県", "福井県",
+    "山梨県", "長野県", "岐阜県", "静岡県", "愛知県", "三重県", "滋賀県", "京都府", "大阪府",
+    "兵庫県", "奈良県", "和歌山県", "鳥取県", "島根県", "岡山県", "広島県", "山口県", "徳島県",
+    "香川県", "愛媛県", "高知県", "福岡県", "佐賀県", "長崎県", "熊本県", "大分県", "宮崎県",
+    "鹿児島県", "沖縄県",
+];
+
+/// 半角カタカナ（U+FF61〜U+FF9F）に対応する全角文字
+const HALF_WIDTH_KATAKANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン゛゜";
+
+/// 入力されたままの住所（正規化前）
+/// 正規化で値が変わった場合に、顧客が何を入力したかを確認できるよう監査用に保持する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressInput {
+    pub postal_code: String,
+    pub prefecture: String,
+    pub city: String,
+    pub street: String,
+    pub building: Option<String>,
+}
+
+/// 配送先住所の正規化（ドメインサービス）
+/// 表記ゆれのある入力を同じ住所が同じ表記になるように揃えてから配送先住所を作成する
+/// - 前後の空白を取り除き、連続する空白を1つにまとめる
+/// - 全角英数字・記号・空白を半角に、半角カタカナを全角にする
+/// - 都道府県を正式名称にする（例: "東京" → "東京都"）
+/// - 郵便番号の「〒」・ハイフン・空白を取り除き、先頭の0が落ちた郵便番号（5〜6桁）を7桁に0埋めする
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AddressNormalizer;
+
+impl AddressNormalizer {
+    /// 入力された住所を正規化して配送先住所を作成する
+    /// 正規化後の値をShippingAddress::newで検証する（違反はまとめて返す）
+    pub fn normalize(&self, input: &AddressInput) -> Result<ShippingAddress, DomainError> {
+        let building = input
+            .building
+            .as_deref()
+            .map(normalize_text)
+            .filter(|building| !building.is_empty());
+
+        ShippingAddress::new(
+            normalize_postal_code(&input.postal_code),
+            normalize_prefecture(&input.prefecture),
+            normalize_text(&input.city),
+            normalize_text(&input.street),
+            building,
+        )
+    }
+}
+
+/// 文字幅を揃え、前後の空白を取り除いて連続する空白を1つにまとめる
+fn normalize_text(value: &str) -> String {
+    let chars = normalize_width(value);
+    let mut normalized = String::with_capacity(value.len());
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_whitespace() {
+            if !normalized.ends_with(' ') {
+                normalized.push(' ');
+            }
+            continue;
+        }
+        // 番地の数字の間の長音符・ダッシュはハイフンとみなす（例: "1ー2ー3" → "1-2-3"）
+        let between_digits = i > 0
+            && chars[i - 1].is_ascii_digit()
+            && chars.get(i + 1).is_some_and(|next| next.is_ascii_digit());
+        if between_digits && matches!(c, 'ー' | '‐' | '‑' | '–' | '—' | '−') {
+            normalized.push('-');
+        } else {
+            normalized.push(*c);
+        }
+    }
+    normalized.trim().to_string()
+}
+
+/// 全角英数字・記号・空白を半角に、半角カタカナを全角にする（濁点・半濁点は直前の文字と合成する）
+fn normalize_width(value: &str) -> Vec<char> {
+    let mut chars: Vec<char> = Vec::with_capacity(value.len());
+    for c in value.chars() {
+        let converted = match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            '\u{FF61}'..='\u{FF9F}' => HALF_WIDTH_KATAKANA
+                .chars()
+                .nth((c as u32 - 0xFF61) as usize)
+                .unwrap_or(c),
+            _ => c,
+        };
+        let composed = match (converted, chars.last()) {
+            ('゛', Some(&'ウ')) => Some('ヴ'),
+            ('゛', Some(&base)) if "カキクケコサシスセソタチツテトハヒフヘホ".contains(base) => {
+                char::from_u32(base as u32 + 1)
+            }
+            ('゜', Some(&base)) if "ハヒフヘホ".contains(base) => char::from_u32(base as u32 + 2),
+            _ => None,
+        };
+        match composed {
+            // 半角の濁点・半濁点のみ合成する（全角の゛゜はそのまま残す）
+            Some(composed) if c != converted => {
+                chars.pop();
+                chars.push(composed);
+            }
+            _ => chars.push(converted),
+        }
+    }
+    chars
+}
+
+/// 郵便番号を7桁の数字に揃える
+fn normalize_postal_code(value: &str) -> String {
+    let digits: String = normalize_width(value)
+        .into_iter()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '〒' | '-' | 'ー' | '‐' | '−'))
+        .collect();
+    if matches!(digits.len(), 5 | 6) && digits.chars().all(|c| c.is_ascii_digit()) {
+        format!("{:0>7}", digits)
+    } else {
+        digits
+    }
+}
+
+/// 都道府県を正式名称にする（「都・府・県」を省略した入力も受け付ける）
+/// 該当する都道府県がない場合は空白などを揃えた値をそのまま返す
+fn normalize_prefecture(value: &str) -> String {
+    let normalized = normalize_text(value);
+    PREFECTURES
+        .iter()
+        .find(|prefecture| {
+            **prefecture == normalized
+                || prefecture.strip_suffix(['都', '府', '県']) == Some(normalized.as_str())
+        })
+        .map(|prefecture| prefecture.to_string())
+        .unwrap_or(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(postal_code: &str, prefecture: &str, city: &str, street: &str) -> AddressInput {
+        AddressInput {
+            postal_code: postal_code.to_string(),
+            prefecture: prefecture.to_string(),
+            city: city.to_string(),
+            street: street.to_string(),
+            building: Some("　ﾒｿﾞﾝ･ﾄﾞ･ﾊﾟﾙｸ　１０１ ".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_normalize_width_whitespace_and_prefecture() {
+        let address = AddressNormalizer
+            .normalize(&input("〒１５０－００４３", " 東京 ", "渋谷区", "道玄坂１ー２ー３"))
+            .unwrap();
+
+        assert_eq!(address.postal_code(), "1500043");
+        assert_eq!(address.prefecture(), "東京都");
+        assert_eq!(address.city(), "渋谷区");
+        assert_eq!(address.street(), "道玄坂1-2-3");
+        assert_eq!(address.building(), Some("メゾン・ド・パルク 101"));
+    }
+
+    #[test]
+    fn test_normalize_postal_code_and_prefecture_names() {
+        // 先頭の0が落ちた郵便番号は7桁に0埋めする
+        assert_eq!(normalize_postal_code("60000"), "0060000");
+        assert_eq!(normalize_postal_code("123-4567"), "1234567");
+        // 0埋めしても郵便番号にならない値はそのまま（検証でエラーになる）
+        assert_eq!(normalize_postal_code("123"), "123");
+
+        assert_eq!(normalize_prefecture("大阪"), "大阪府");
+        assert_eq!(normalize_prefecture("北海道"), "北海道");
+        assert_eq!(normalize_prefecture("神奈川県"), "神奈川県");
+        assert_eq!(normalize_prefecture("Tokyo"), "Tokyo");
+
+        let result = AddressNormalizer.normalize(&input("123", "東京都", "　", "道玄坂1-1"));
+        let Err(DomainError::Validation(errors)) = result else {
+            panic!("検証エラーになるはず");
+        };
+        assert_eq!(errors.violations().len(), 2);
+    }
+}
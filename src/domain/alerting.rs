@@ -0,0 +1,477 @@
+use crate::domain::metrics::BusinessMetrics;
+use crate::domain::port::{AlertingPort, DeadLetterMonitor, Logger};
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// アラートの重要度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum AlertSeverity {
+    Warning,
+    Critical,
+}
+
+/// 検知対象の異常の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum AnomalyKind {
+    /// 在庫予約失敗率の上昇
+    ReservationFailureRate,
+    /// 補償発生率の上昇
+    CompensationRate,
+    /// デッドレターキューへの流入増加
+    DeadLetterSpike,
+    /// 失敗し続けるイベントハンドラー（自動で一時停止した）
+    HandlerUnhealthy,
+    /// 再起動を繰り返すバックグラウンドタスク
+    BackgroundTaskFailing,
+}
+
+impl AnomalyKind {
+    /// 異常の種類を表す名前
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnomalyKind::ReservationFailureRate => "reservation_failure_rate",
+            AnomalyKind::CompensationRate => "compensation_rate",
+            AnomalyKind::DeadLetterSpike => "dead_letter_spike",
+            AnomalyKind::HandlerUnhealthy => "handler_unhealthy",
+            AnomalyKind::BackgroundTaskFailing => "background_task_failing",
+        }
+    }
+}
+
+/// アラート
+/// 異常検知の結果として外部に通知される内容
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    pub kind: AnomalyKind,
+    pub severity: AlertSeverity,
+    pub message: String,
+    /// 観測値（率または件数）
+    pub observed_value: f64,
+    /// 閾値
+    pub threshold: f64,
+    /// 集計対象の時間窓（秒）
+    pub window_seconds: u64,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// 異常検知の閾値設定
+#[derive(Debug, Clone)]
+pub struct AnomalyThresholds {
+    /// 集計する時間窓
+    pub window: Duration,
+    /// メトリクスを確認する間隔
+    pub check_interval: Duration,
+    /// 在庫予約失敗率の閾値（0.05 = 5%）
+    pub reservation_failure_rate: f64,
+    /// 補償発生率の閾値（確定注文数に対する割合）
+    pub compensation_rate: f64,
+    /// 時間窓内でデッドレターキューに送られたイベント数の閾値
+    pub dead_letter_count: u64,
+    /// 率を評価するために必要な最小件数（少数サンプルでの誤検知を防ぐ）
+    pub min_sample_size: u64,
+}
+
+impl Default for AnomalyThresholds {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(600),
+            check_interval: Duration::from_secs(60),
+            reservation_failure_rate: 0.05,
+            compensation_rate: 0.05,
+            dead_letter_count: 10,
+            min_sample_size: 20,
+        }
+    }
+}
+
+/// 特定時点のメトリクスの累計値
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsSample {
+    pub taken_at: DateTime<Utc>,
+    pub orders_confirmed: u64,
+    pub reservations_succeeded: u64,
+    pub reservations_failed: u64,
+    pub compensations: u64,
+    pub dead_lettered: u64,
+}
+
+/// 異常検知器
+/// 累計メトリクスを定期的にサンプリングし、時間窓内の差分から異常を検知する
+/// 同じ種類の異常は閾値を下回るまで再通知しない
+pub struct AnomalyDetector {
+    metrics: BusinessMetrics,
+    dead_letter_monitor: Arc<dyn DeadLetterMonitor>,
+    alerting: Arc<dyn AlertingPort>,
+    logger: Arc<dyn Logger>,
+    thresholds: AnomalyThresholds,
+    samples: VecDeque<MetricsSample>,
+    active_anomalies: HashSet<AnomalyKind>,
+}
+
+impl AnomalyDetector {
+    pub fn new(
+        metrics: BusinessMetrics,
+        dead_letter_monitor: Arc<dyn DeadLetterMonitor>,
+        alerting: Arc<dyn AlertingPort>,
+        logger: Arc<dyn Logger>,
+        thresholds: AnomalyThresholds,
+    ) -> Self {
+        Self {
+            metrics,
+            dead_letter_monitor,
+            alerting,
+            logger,
+            thresholds,
+            samples: VecDeque::new(),
+            active_anomalies: HashSet::new(),
+        }
+    }
+
+    /// 検知タスクをバックグラウンドで起動
+    pub fn spawn(mut self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.thresholds.check_interval);
+            loop {
+                interval.tick().await;
+                self.check().await;
+            }
+        })
+    }
+
+    /// メトリクスをサンプリングし、検知したアラートを送信する
+    pub async fn check(&mut self) -> Vec<Alert> {
+        let snapshot = self.metrics.snapshot().await;
+        let sample = MetricsSample {
+            taken_at: Utc::now(),
+            orders_confirmed: snapshot.orders_confirmed_total,
+            reservations_succeeded: snapshot.reservations_succeeded_total,
+            reservations_failed: snapshot.reservations_failed_total,
+            compensations: snapshot.compensations_total,
+            dead_lettered: self.dead_letter_monitor.dead_lettered_total(),
+        };
+
+        let alerts = self.evaluate(sample);
+        for alert in &alerts {
+            if let Err(e) = self.alerting.send_alert(alert).await {
+                let mut context = HashMap::new();
+                context.insert("anomaly".to_string(), alert.kind.name().to_string());
+                context.insert("error".to_string(), e.to_string());
+                self.logger.error(
+                    "AnomalyDetector",
+                    "Failed to send alert",
+                    None,
+                    Some(context),
+                );
+            }
+        }
+        alerts
+    }
+
+    /// サンプルを時間窓に追加し、新たに閾値を超えた異常のアラートを返す
+    pub fn evaluate(&mut self, sample: MetricsSample) -> Vec<Alert> {
+        self.push_sample(sample);
+
+        // 時間窓の最古のサンプルとの差分を評価する
+        let Some(baseline) = self.samples.front().copied() else {
+            return Vec::new();
+        };
+        let window_seconds = self.thresholds.window.as_secs();
+
+        let reservations_failed = sample
+            .reservations_failed
+            .saturating_sub(baseline.reservations_failed);
+        let reservations_total = reservations_failed
+            + sample
+                .reservations_succeeded
+                .saturating_sub(baseline.reservations_succeeded);
+        let compensations = sample.compensations.saturating_sub(baseline.compensations);
+        let orders_confirmed = sample
+            .orders_confirmed
+            .saturating_sub(baseline.orders_confirmed);
+        let dead_lettered = sample.dead_lettered.saturating_sub(baseline.dead_lettered);
+
+        let mut alerts = Vec::new();
+
+        let reservation_failure_rate = (reservations_total >= self.thresholds.min_sample_size)
+            .then(|| reservations_failed as f64 / reservations_total as f64);
+        if let Some(alert) = self.transition(
+            AnomalyKind::ReservationFailureRate,
+            reservation_failure_rate,
+            self.thresholds.reservation_failure_rate,
+            AlertSeverity::Warning,
+            sample.taken_at,
+            format!(
+                "在庫予約失敗率が閾値を超えました: {}/{} 件が失敗（直近{}秒）",
+                reservations_failed, reservations_total, window_seconds
+            ),
+        ) {
+            alerts.push(alert);
+        }
+
+        let compensation_rate = (orders_confirmed >= self.thresholds.min_sample_size)
+            .then(|| compensations as f64 / orders_confirmed as f64);
+        if let Some(alert) = self.transition(
+            AnomalyKind::CompensationRate,
+            compensation_rate,
+            self.thresholds.compensation_rate,
+            AlertSeverity::Warning,
+            sample.taken_at,
+            format!(
+                "補償発生率が閾値を超えました: 確定注文{}件に対して補償{}件（直近{}秒）",
+                orders_confirmed, compensations, window_seconds
+            ),
+        ) {
+            alerts.push(alert);
+        }
+
+        if let Some(alert) = self.transition(
+            AnomalyKind::DeadLetterSpike,
+            Some(dead_lettered as f64),
+            self.thresholds.dead_letter_count as f64,
+            AlertSeverity::Critical,
+            sample.taken_at,
+            format!(
+                "デッドレターキューへの流入が閾値を超えました: {}件（直近{}秒）",
+                dead_lettered, window_seconds
+            ),
+        ) {
+            alerts.push(alert);
+        }
+
+        alerts
+    }
+
+    /// サンプルを追加し、時間窓の外にあるサンプルを削除
+    /// 差分の基準として時間窓の開始時点以前のサンプルを1件だけ残す
+    fn push_sample(&mut self, sample: MetricsSample) {
+        self.samples.push_back(sample);
+
+        let window = TimeDelta::from_std(self.thresholds.window).unwrap_or(TimeDelta::MAX);
+        let window_start = sample.taken_at - window;
+        while self.samples.len() > 1 && self.samples[1].taken_at <= window_start {
+            self.samples.pop_front();
+        }
+    }
+
+    /// 異常状態の遷移を判定
+    /// 閾値を新たに超えた場合のみアラートを生成し、下回った場合は状態を解除する
+    fn transition(
+        &mut self,
+        kind: AnomalyKind,
+        observed_value: Option<f64>,
+        threshold: f64,
+        severity: AlertSeverity,
+        detected_at: DateTime<Utc>,
+        message: String,
+    ) -> Option<Alert> {
+        match observed_value {
+            Some(value) if value > threshold => {
+                if !self.active_anomalies.insert(kind) {
+                    return None;
+                }
+                Some(Alert {
+                    kind,
+                    severity,
+                    message,
+                    observed_value: value,
+                    threshold,
+                    window_seconds: self.thresholds.window.as_secs(),
+                    detected_at,
+                })
+            }
+            _ => {
+                if self.active_anomalies.remove(&kind) {
+                    let mut context = HashMap::new();
+                    context.insert("anomaly".to_string(), kind.name().to_string());
+                    self.logger
+                        .info("AnomalyDetector", "Anomaly resolved", None, Some(context));
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event_bus::DeadLetter;
+    use crate::domain::port::AlertingError;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    struct MockLogger;
+
+    impl Logger for MockLogger {
+        fn debug(
+            &self,
+            _component: &str,
+            _message: &str,
+            _correlation_id: Option<Uuid>,
+            _context: Option<HashMap<String, String>>,
+        ) {
+            // テスト用なので何もしない
+        }
+
+        fn info(
+            &self,
+            _component: &str,
+            _message: &str,
+            _correlation_id: Option<Uuid>,
+            _context: Option<HashMap<String, String>>,
+        ) {
+            // テスト用なので何もしない
+        }
+
+        fn warn(
+            &self,
+            _component: &str,
+            _message: &str,
+            _correlation_id: Option<Uuid>,
+            _context: Option<HashMap<String, String>>,
+        ) {
+            // テスト用なので何もしない
+        }
+
+        fn error(
+            &self,
+            _component: &str,
+            _message: &str,
+            _correlation_id: Option<Uuid>,
+            _context: Option<HashMap<String, String>>,
+        ) {
+            // テスト用なので何もしない
+        }
+    }
+
+    #[derive(Default)]
+    struct MockDeadLetterMonitor {
+        total: AtomicU64,
+    }
+
+    #[async_trait]
+    impl DeadLetterMonitor for MockDeadLetterMonitor {
+        fn dead_lettered_total(&self) -> u64 {
+            self.total.load(Ordering::SeqCst)
+        }
+
+        async fn dead_letters(&self) -> Vec<DeadLetter> {
+            Vec::new()
+        }
+    }
+
+    #[derive(Default)]
+    struct MockAlerting {
+        sent: Mutex<Vec<Alert>>,
+    }
+
+    #[async_trait]
+    impl AlertingPort for MockAlerting {
+        async fn send_alert(&self, alert: &Alert) -> Result<(), AlertingError> {
+            self.sent.lock().unwrap().push(alert.clone());
+            Ok(())
+        }
+    }
+
+    fn create_detector(
+        metrics: BusinessMetrics,
+        monitor: Arc<MockDeadLetterMonitor>,
+        alerting: Arc<MockAlerting>,
+    ) -> AnomalyDetector {
+        AnomalyDetector::new(
+            metrics,
+            monitor,
+            alerting,
+            Arc::new(MockLogger),
+            AnomalyThresholds {
+                min_sample_size: 10,
+                dead_letter_count: 3,
+                ..AnomalyThresholds::default()
+            },
+        )
+    }
+
+    fn sample_at(minutes_ago: i64, succeeded: u64, failed: u64) -> MetricsSample {
+        MetricsSample {
+            taken_at: Utc::now() - TimeDelta::minutes(minutes_ago),
+            reservations_succeeded: succeeded,
+            reservations_failed: failed,
+            ..MetricsSample::default()
+        }
+    }
+
+    #[test]
+    fn test_reservation_failure_rate_alert_fires_once_until_resolved() {
+        let mut detector = create_detector(
+            BusinessMetrics::new(),
+            Arc::new(MockDeadLetterMonitor::default()),
+            Arc::new(MockAlerting::default()),
+        );
+
+        assert!(detector.evaluate(sample_at(5, 0, 0)).is_empty());
+
+        // 20件中2件失敗（10%）→ 閾値5%を超える
+        let alerts = detector.evaluate(sample_at(4, 18, 2));
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AnomalyKind::ReservationFailureRate);
+        assert_eq!(alerts[0].observed_value, 0.1);
+
+        // 異常が継続している間は再通知しない
+        assert!(detector.evaluate(sample_at(3, 19, 3)).is_empty());
+
+        // 失敗率が下がると解除され、再度超えると通知される
+        assert!(detector.evaluate(sample_at(2, 200, 3)).is_empty());
+        let alerts = detector.evaluate(sample_at(1, 200, 30));
+        assert_eq!(alerts.len(), 1);
+    }
+
+    #[test]
+    fn test_small_sample_does_not_trigger_rate_alert() {
+        let mut detector = create_detector(
+            BusinessMetrics::new(),
+            Arc::new(MockDeadLetterMonitor::default()),
+            Arc::new(MockAlerting::default()),
+        );
+
+        detector.evaluate(sample_at(2, 0, 0));
+        // 5件中5件失敗でも最小件数に満たないため通知しない
+        assert!(detector.evaluate(sample_at(1, 0, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_samples_outside_window_are_not_counted() {
+        let mut detector = create_detector(
+            BusinessMetrics::new(),
+            Arc::new(MockDeadLetterMonitor::default()),
+            Arc::new(MockAlerting::default()),
+        );
+
+        // 時間窓（10分）より前の失敗は差分に含まれない
+        detector.evaluate(sample_at(30, 0, 0));
+        detector.evaluate(sample_at(20, 0, 50));
+        assert!(detector.evaluate(sample_at(0, 100, 50)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_check_sends_dead_letter_alert() {
+        let monitor = Arc::new(MockDeadLetterMonitor::default());
+        let alerting = Arc::new(MockAlerting::default());
+        let mut detector =
+            create_detector(BusinessMetrics::new(), monitor.clone(), alerting.clone());
+
+        detector.check().await;
+        monitor.total.store(5, Ordering::SeqCst);
+        let alerts = detector.check().await;
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AnomalyKind::DeadLetterSpike);
+        assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+        assert_eq!(alerting.sent.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::domain::address_normalizer::AddressInput;
+use crate::domain::error::DomainError;
+use crate::domain::model::{CustomerId, EmailAddress, ShippingAddress, TrackingToken};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use uuid::Uuid;
+
+/// 匿名化した氏名に使う姓の候補
+const FAMILY_NAMES: [&str; 10] = [
+    "佐藤", "鈴木", "高橋", "田中", "伊藤", "渡辺", "山本", "中村", "小林", "加藤",
+];
+
+/// 匿名化した氏名に使う名の候補
+const GIVEN_NAMES: [&str; 10] = [
+    "太郎",
+    "花子",
+    "一郎",
+    "陽子",
+    "健太",
+    "美咲",
+    "翔",
+    "結衣",
+    "大輔",
+    "真由美",
+];
+
+/// 匿名化したメールアドレスのドメイン（RFC 2606で予約されたドメイン）
+const EMAIL_DOMAIN: &str = "example.com";
+
+/// 匿名化した明細属性の値
+pub const ANONYMIZED_ATTRIBUTE_VALUE: &str = "（匿名化済み）";
+
+/// 個人情報の匿名化（ドメインサービス）
+/// 本番と同じ形のデータからステージング用のデータを作るために、個人を特定できる値を置き換える
+/// - 顧客IDは別のIDに付け替え、同じ顧客IDは常に同じIDに置き換える（注文と顧客の参照を保つ）
+/// - 住所は都道府県を保ったまま、同じ都道府県で観測した市区町村からランダムに選び、郵便番号の下4桁・番地・建物名を置き換える
+/// - 氏名・メールアドレス・電話番号・追跡トークンはランダムな値に置き換える
+///
+/// 同じシードと同じ入力からは同じ結果を生成する
+pub struct Anonymizer {
+    rng: StdRng,
+    /// 元の顧客IDから付け替えた顧客IDへの対応
+    customer_ids: HashMap<CustomerId, CustomerId>,
+    /// 都道府県ごとに観測した市区町村（結果を再現できるよう順序付きで保持する）
+    cities: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Anonymizer {
+    /// 新しい匿名化サービスを作成
+    ///
+    /// # Arguments
+    /// * `seed` - 乱数のシード
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            customer_ids: HashMap::new(),
+            cities: BTreeMap::new(),
+        }
+    }
+
+    /// 住所の置き換えに使う市区町村を記録する
+    /// 匿名化する前に、元のデータに含まれる住所の都道府県と市区町村をすべて記録しておく
+    pub fn observe_city(&mut self, prefecture: &str, city: &str) {
+        self.cities
+            .entry(prefecture.to_string())
+            .or_default()
+            .insert(city.to_string());
+    }
+
+    /// 顧客IDを付け替える
+    /// 同じ顧客IDには常に同じIDを返す
+    pub fn customer_id(&mut self, original: CustomerId) -> CustomerId {
+        if let Some(remapped) = self.customer_ids.get(&original) {
+            return *remapped;
+        }
+        let remapped = CustomerId::from_uuid(self.uuid());
+        self.customer_ids.insert(original, remapped);
+        remapped
+    }
+
+    /// 付け替えた顧客IDの数
+    pub fn remapped_customers(&self) -> usize {
+        self.customer_ids.len()
+    }
+
+    /// 住所を同じ都道府県のランダムな住所に置き換える
+    /// 郵便番号の上3桁（地域）は保ち、市区町村は同じ都道府県で観測したものから選ぶ
+    ///
+    /// # Returns
+    /// * `Ok(ShippingAddress)` - 置き換えた住所
+    /// * `Err(DomainError)` - 元の郵便番号が7桁の数字でない場合
+    pub fn shipping_address(
+        &mut self,
+        original: &ShippingAddress,
+    ) -> Result<ShippingAddress, DomainError> {
+        let city = self
+            .cities
+            .get(original.prefecture())
+            .map(|cities| cities.iter().collect::<Vec<_>>())
+            .and_then(|cities| cities.choose(&mut self.rng).map(|city| city.to_string()))
+            .unwrap_or_else(|| original.city().to_string());
+        let region = original.postal_code().get(..3).unwrap_or("000");
+        let postal_code = format!("{}{:04}", region, self.rng.gen_range(0..10_000));
+        let street = format!(
+            "{}-{}-{}",
+            self.rng.gen_range(1..10),
+            self.rng.gen_range(1..30),
+            self.rng.gen_range(1..20)
+        );
+        let building = original
+            .building()
+            .map(|_| format!("{}号室", self.rng.gen_range(101..1000)));
+
+        ShippingAddress::new(
+            postal_code,
+            original.prefecture().to_string(),
+            city,
+            street,
+            building,
+        )
+    }
+
+    /// 正規化前の住所（監査用）を匿名化した住所で置き換える
+    pub fn address_input(&self, anonymized: &ShippingAddress) -> AddressInput {
+        AddressInput {
+            postal_code: anonymized.postal_code().to_string(),
+            prefecture: anonymized.prefecture().to_string(),
+            city: anonymized.city().to_string(),
+            street: anonymized.street().to_string(),
+            building: anonymized.building().map(str::to_string),
+        }
+    }
+
+    /// ランダムな氏名を生成
+    pub fn person_name(&mut self) -> String {
+        format!(
+            "{} {}",
+            FAMILY_NAMES[self.rng.gen_range(0..FAMILY_NAMES.len())],
+            GIVEN_NAMES[self.rng.gen_range(0..GIVEN_NAMES.len())]
+        )
+    }
+
+    /// 付け替えた顧客IDからメールアドレスを生成（顧客ごとに一意になる）
+    pub fn email(&self, customer_id: CustomerId) -> EmailAddress {
+        EmailAddress::new(format!(
+            "customer-{}@{}",
+            customer_id.as_uuid().simple(),
+            EMAIL_DOMAIN
+        ))
+        .expect("生成したメールアドレスは有効です")
+    }
+
+    /// ランダムな携帯電話番号を生成
+    pub fn phone(&mut self) -> String {
+        format!(
+            "090-{:04}-{:04}",
+            self.rng.gen_range(0..10_000),
+            self.rng.gen_range(0..10_000)
+        )
+    }
+
+    /// 新しい追跡トークンを生成
+    pub fn tracking_token(&mut self) -> TrackingToken {
+        TrackingToken::parse(&format!("{}{}", self.uuid().simple(), self.uuid().simple()))
+            .expect("生成した追跡トークンは有効です")
+    }
+
+    fn uuid(&mut self) -> Uuid {
+        uuid::Builder::from_random_bytes(self.rng.gen()).into_uuid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anonymizer_remaps_consistently_and_keeps_prefecture() {
+        let address = ShippingAddress::new(
+            "1500001".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "神宮前1-1-1".to_string(),
+            Some("メゾン神宮前202".to_string()),
+        )
+        .unwrap();
+        let anonymize = |seed: u64| {
+            let mut anonymizer = Anonymizer::new(seed);
+            anonymizer.observe_city("東京都", "渋谷区");
+            anonymizer.observe_city("東京都", "新宿区");
+            anonymizer.observe_city("大阪府", "大阪市北区");
+            let customer_id = CustomerId::from_uuid(Uuid::nil());
+            let remapped = anonymizer.customer_id(customer_id);
+            assert_eq!(anonymizer.customer_id(customer_id), remapped);
+            assert_ne!(remapped, customer_id);
+            (remapped, anonymizer.shipping_address(&address).unwrap())
+        };
+
+        let (remapped, anonymized) = anonymize(7);
+        assert_eq!(anonymized.prefecture(), "東京都");
+        assert!(["渋谷区", "新宿区"].contains(&anonymized.city()));
+        assert!(anonymized.postal_code().starts_with("150"));
+        assert_ne!(anonymized.street(), address.street());
+        assert!(anonymized.building().unwrap().ends_with("号室"));
+
+        // 同じシードからは同じ結果を生成する
+        assert_eq!(anonymize(7), (remapped, anonymized));
+    }
+}
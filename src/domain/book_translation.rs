@@ -0,0 +1,182 @@
+use crate::domain::error::DomainError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// 書籍のメタデータを表示する言語
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// 日本語
+    Ja,
+    /// 英語
+    En,
+}
+
+impl Language {
+    /// 既定の言語（指定がない場合の表示言語で、翻訳がない場合のフォールバック先）
+    pub const DEFAULT: Language = Language::Ja;
+    /// 対応している言語
+    pub const ALL: [Language; 2] = [Language::Ja, Language::En];
+
+    /// 言語コード（"ja"、"en"）
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Ja => "ja",
+            Language::En => "en",
+        }
+    }
+
+    /// 言語タグから言語を取得（"en-US"のような地域付きのタグは主言語で判定し、大文字小文字は区別しない）
+    /// 対応していない言語の場合はNone
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.trim().split(['-', '_']).next()?;
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(primary))
+    }
+
+    /// Accept-Languageヘッダーの値から表示言語を選ぶ
+    /// q値の高い順に、対応している最初の言語を返す（対応している言語がない場合はNone）
+    pub fn negotiate(accept_language: &str) -> Option<Self> {
+        let mut candidates: Vec<(Language, f32)> = accept_language
+            .split(',')
+            .filter_map(|item| {
+                let mut parts = item.split(';');
+                let language = Self::from_tag(parts.next()?)?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                (quality > 0.0).then_some((language, quality))
+            })
+            .collect();
+        // 同じq値の場合はヘッダーに書かれた順を優先する（安定ソート）
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+        candidates.first().map(|(language, _)| *language)
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// 表示言語で選んだ書籍のタイトル
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LocalizedTitle {
+    pub title: String,
+    /// 実際に使ったタイトルの言語（翻訳がない場合は要求と異なる）
+    pub language: Language,
+}
+
+/// 書籍の言語ごとのタイトル
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BookTitles {
+    titles: HashMap<Language, String>,
+}
+
+impl BookTitles {
+    /// タイトルの最大文字数
+    pub const MAX_TITLE_LENGTH: usize = 255;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// タイトルを検証する（前後の空白を取り除き、1文字以上255文字以内）
+    pub fn validate_title(title: &str) -> Result<String, DomainError> {
+        let title = title.trim();
+        if title.is_empty() || title.chars().count() > Self::MAX_TITLE_LENGTH {
+            return Err(DomainError::InvalidValue(format!(
+                "書籍のタイトルは1文字以上{}文字以内で指定してください",
+                Self::MAX_TITLE_LENGTH
+            )));
+        }
+        Ok(title.to_string())
+    }
+
+    /// 言語のタイトルを設定
+    pub fn insert(&mut self, language: Language, title: String) {
+        self.titles.insert(language, title);
+    }
+
+    /// 言語のタイトルを取得
+    pub fn get(&self, language: Language) -> Option<&str> {
+        self.titles.get(&language).map(String::as_str)
+    }
+
+    /// 登録されている言語とタイトル（Language::ALLの順）
+    pub fn iter(&self) -> impl Iterator<Item = (Language, &str)> {
+        Language::ALL
+            .into_iter()
+            .filter_map(|language| self.get(language).map(|title| (language, title)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.titles.is_empty()
+    }
+
+    /// 表示言語のタイトルを選ぶ
+    /// 要求された言語 → 既定の言語 → その他の言語の順にフォールバックする
+    pub fn localize(&self, requested: Language) -> Option<LocalizedTitle> {
+        [requested, Language::DEFAULT]
+            .into_iter()
+            .chain(Language::ALL)
+            .find_map(|language| {
+                self.get(language).map(|title| LocalizedTitle {
+                    title: title.to_string(),
+                    language,
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_accept_language() {
+        assert_eq!(
+            Language::negotiate("en-US,en;q=0.9,ja;q=0.8"),
+            Some(Language::En)
+        );
+        assert_eq!(
+            Language::negotiate("fr-FR, ja;q=0.5, en;q=0.7"),
+            Some(Language::En)
+        );
+        assert_eq!(Language::negotiate("JA"), Some(Language::Ja));
+        assert_eq!(Language::negotiate("en;q=0, ja;q=0.1"), Some(Language::Ja));
+        assert_eq!(Language::negotiate("fr, de;q=0.8"), None);
+        assert_eq!(Language::negotiate(""), None);
+    }
+
+    #[test]
+    fn test_localize_falls_back_to_default_then_any_language() {
+        let mut titles = BookTitles::new();
+        titles.insert(Language::En, "Domain-Driven Design".to_string());
+        // 既定の言語（日本語）がなければ英語にフォールバックする
+        assert_eq!(
+            titles.localize(Language::Ja).map(|title| title.language),
+            Some(Language::En)
+        );
+
+        titles.insert(Language::Ja, "ドメイン駆動設計".to_string());
+        assert_eq!(
+            titles.localize(Language::En).unwrap().title,
+            "Domain-Driven Design"
+        );
+        assert_eq!(
+            titles.localize(Language::Ja).unwrap().title,
+            "ドメイン駆動設計"
+        );
+        assert_eq!(BookTitles::new().localize(Language::Ja), None);
+
+        assert!(BookTitles::validate_title("   ").is_err());
+        assert_eq!(
+            BookTitles::validate_title(" エヴァンス本 ").unwrap(),
+            "エヴァンス本"
+        );
+    }
+}
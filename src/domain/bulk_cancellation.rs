@@ -0,0 +1,101 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::{OrderId, OrderStatus};
+use chrono::{DateTime, TimeDelta, Utc};
+use sha2::{Digest, Sha256};
+
+/// 一括キャンセルの対象を絞り込む条件
+/// 放置された注文（作成したまま確定されていない注文など）をまとめてキャンセルするために使う
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkCancellationFilter {
+    /// 対象にする注文ステータス
+    pub status: OrderStatus,
+    /// 作成から指定した日数以上経過した注文だけを対象にする（Noneの場合は経過日数で絞り込まない）
+    pub older_than_days: Option<u32>,
+}
+
+impl BulkCancellationFilter {
+    /// 新しい一括キャンセルの条件を作成
+    ///
+    /// # Arguments
+    /// * `status` - 対象にする注文ステータス（キャンセルできるステータスのみ）
+    /// * `older_than_days` - 作成からの経過日数の下限
+    ///
+    /// # Returns
+    /// * `Ok(BulkCancellationFilter)` - 作成成功
+    /// * `Err(DomainError)` - キャンセルできないステータスを指定した
+    pub fn new(status: OrderStatus, older_than_days: Option<u32>) -> Result<Self, DomainError> {
+        match status {
+            OrderStatus::Pending
+            | OrderStatus::Confirmed
+            | OrderStatus::OnHold
+            | OrderStatus::AwaitingRelease
+            | OrderStatus::Waitlisted => Ok(Self {
+                status,
+                older_than_days,
+            }),
+            _ => Err(DomainError::InvalidValue(format!(
+                "{}の注文はキャンセルできないため一括キャンセルの対象にできません",
+                status
+            ))),
+        }
+    }
+
+    /// 対象にする注文の作成日時の上限（この日時より前に作成された注文が対象）
+    ///
+    /// # Arguments
+    /// * `now` - 現在日時
+    pub fn created_before(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self.older_than_days {
+            Some(days) => now - TimeDelta::days(i64::from(days)),
+            None => now,
+        }
+    }
+
+    /// プレビューした対象を実行時に確認するためのトークン
+    /// 条件と対象の注文IDから算出するため、プレビュー後に対象が変わった場合は一致しなくなる
+    ///
+    /// # Arguments
+    /// * `order_ids` - 条件に一致した注文ID（順不同）
+    pub fn confirmation_token(&self, order_ids: &[OrderId]) -> String {
+        let mut order_ids: Vec<String> = order_ids.iter().map(|id| id.to_string()).collect();
+        order_ids.sort();
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.status.to_string());
+        hasher.update(format!(":{:?}", self.older_than_days));
+        for order_id in order_ids {
+            hasher.update(":");
+            hasher.update(order_id);
+        }
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_confirmation_token_changes_with_filter_and_targets() {
+        let filter = BulkCancellationFilter::new(OrderStatus::Pending, Some(7)).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 4, 8, 0, 0, 0).unwrap();
+        assert_eq!(
+            filter.created_before(now),
+            Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()
+        );
+
+        let (first, second) = (OrderId::new(), OrderId::new());
+        let token = filter.confirmation_token(&[first, second]);
+        assert_eq!(token, filter.confirmation_token(&[second, first]));
+        assert_ne!(token, filter.confirmation_token(&[first]));
+
+        let other_filter = BulkCancellationFilter::new(OrderStatus::Pending, Some(14)).unwrap();
+        assert_ne!(token, other_filter.confirmation_token(&[first, second]));
+
+        assert!(matches!(
+            BulkCancellationFilter::new(OrderStatus::Shipped, None),
+            Err(DomainError::InvalidValue(_))
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::{Order, OrderStatus};
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// 確定済みの注文のキャンセル受付期限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CancellationWindow {
+    /// キャンセルを受け付ける期限（確定日時 + 受付期間）
+    pub deadline: DateTime<Utc>,
+    /// 期限までの残り時間（期限を過ぎている場合は0）
+    pub remaining: TimeDelta,
+}
+
+impl CancellationWindow {
+    /// 期限を過ぎているか
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= TimeDelta::zero()
+    }
+}
+
+/// キャンセルポリシー（ドメインサービス）
+/// 確定済み（発送待ち）の注文を、確定から一定時間内に限ってキャンセルできるようにする
+/// 確定前の注文と、発売待ち・順番待ちの注文は期限なくキャンセルできる
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CancellationPolicy {
+    /// 確定からキャンセルを受け付ける期間（Noneの場合は期限なし）
+    window: Option<TimeDelta>,
+}
+
+impl CancellationPolicy {
+    /// 確定からキャンセルを受け付ける期間を指定してキャンセルポリシーを作成
+    pub fn within(window: TimeDelta) -> Self {
+        Self {
+            window: Some(window),
+        }
+    }
+
+    /// 期限なくキャンセルできるキャンセルポリシー
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// 確定からキャンセルを受け付ける期間
+    pub fn window(&self) -> Option<TimeDelta> {
+        self.window
+    }
+
+    /// 注文のキャンセル受付期限を取得
+    /// 期限のない注文（期限なしのポリシー、または確定済み以外の注文）の場合はNone
+    ///
+    /// # Arguments
+    /// * `order` - 対象の注文
+    /// * `now` - 残り時間の基準日時
+    pub fn window_for(&self, order: &Order, now: DateTime<Utc>) -> Option<CancellationWindow> {
+        let window = self.window?;
+        if order.status() != OrderStatus::Confirmed {
+            return None;
+        }
+        let deadline = order.confirmed_at()? + window;
+        Some(CancellationWindow {
+            deadline,
+            remaining: (deadline - now).max(TimeDelta::zero()),
+        })
+    }
+
+    /// 注文をキャンセルできるか判定
+    ///
+    /// # Arguments
+    /// * `order` - 対象の注文
+    /// * `now` - 判定日時
+    pub fn ensure_can_cancel(&self, order: &Order, now: DateTime<Utc>) -> Result<(), DomainError> {
+        match self.window_for(order, now) {
+            Some(window) if window.is_expired() => {
+                Err(DomainError::CancellationWindowExpired(format!(
+                    "キャンセルを受け付けられるのは確定から{}分以内です（期限: {}）",
+                    self.window.unwrap_or_default().num_minutes(),
+                    window.deadline.to_rfc3339()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, CustomerId, Money, OrderId, ShippingAddress};
+
+    fn confirmed_order(confirmed_at: DateTime<Utc>) -> Order {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1500001".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "神宮前1-1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        order.confirm().unwrap();
+        order.with_transition_times(Some(confirmed_at), None, None)
+    }
+
+    #[test]
+    fn test_cancellation_window_applies_only_to_confirmed_orders() {
+        let confirmed_at = Utc::now();
+        let policy = CancellationPolicy::within(TimeDelta::minutes(30));
+        let order = confirmed_order(confirmed_at);
+
+        let window = policy
+            .window_for(&order, confirmed_at + TimeDelta::minutes(10))
+            .unwrap();
+        assert_eq!(window.deadline, confirmed_at + TimeDelta::minutes(30));
+        assert_eq!(window.remaining, TimeDelta::minutes(20));
+        assert!(policy
+            .ensure_can_cancel(&order, confirmed_at + TimeDelta::minutes(30) - TimeDelta::seconds(1))
+            .is_ok());
+
+        let expired_at = confirmed_at + TimeDelta::minutes(31);
+        assert!(policy.window_for(&order, expired_at).unwrap().is_expired());
+        assert!(matches!(
+            policy.ensure_can_cancel(&order, expired_at),
+            Err(DomainError::CancellationWindowExpired(_))
+        ));
+
+        // 期限なしのポリシーと確定前の注文には期限がない
+        assert!(CancellationPolicy::unlimited()
+            .window_for(&order, expired_at)
+            .is_none());
+        let pending = Order::new(OrderId::new(), CustomerId::new());
+        assert!(policy.window_for(&pending, expired_at).is_none());
+    }
+}
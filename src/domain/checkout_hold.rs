@@ -0,0 +1,202 @@
+use crate::domain::event::{CheckoutHoldExpired, DomainEvent};
+use crate::domain::model::{BookId, OrderId};
+use crate::domain::port::{
+    CheckoutHoldRepository, EventBus, InventoryRepository, Logger, RepositoryError,
+};
+use crate::domain::task_supervisor::SupervisedTask;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// チェックアウト中の在庫の仮押さえ
+/// 支払い情報の入力中に他の注文に在庫を取られないよう、確定前の注文の書籍の在庫を期限付きで確保する
+/// 確定時に在庫予約に引き継がれ、期限までに確定しなかった場合は解放される
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckoutHold {
+    pub order_id: OrderId,
+    pub book_id: BookId,
+    /// 確保している数量（注文明細の数量）
+    pub quantity: u32,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl CheckoutHold {
+    pub fn new(order_id: OrderId, book_id: BookId, quantity: u32, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            order_id,
+            book_id,
+            quantity,
+            expires_at,
+        }
+    }
+
+    /// 期限を過ぎているか
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// 仮押さえしている数量を書籍ごとに集計する
+pub fn held_quantities(holds: &[CheckoutHold]) -> HashMap<BookId, u32> {
+    let mut quantities = HashMap::new();
+    for hold in holds {
+        *quantities.entry(hold.book_id).or_insert(0) += hold.quantity;
+    }
+    quantities
+}
+
+/// 期限切れの仮押さえの解放ジョブ
+/// 期限までに確定しなかった注文の仮押さえを定期的に探し、確保していた在庫を解放する
+/// 解放した在庫で順番待ちの注文を繰り上げられるよう、仮押さえしていた数量を載せたCheckoutHoldExpiredイベントを発行する
+pub struct CheckoutHoldSweeper {
+    hold_repository: Arc<dyn CheckoutHoldRepository>,
+    inventory_repository: Arc<dyn InventoryRepository>,
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+}
+
+impl CheckoutHoldSweeper {
+    pub fn new(
+        hold_repository: Arc<dyn CheckoutHoldRepository>,
+        inventory_repository: Arc<dyn InventoryRepository>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            hold_repository,
+            inventory_repository,
+            event_bus,
+            logger,
+        }
+    }
+
+    /// ジョブをバックグラウンドで起動
+    pub fn spawn(self, sweep_interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(sweep_interval);
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// 指定日時点で期限切れの仮押さえを解放する
+    ///
+    /// # Returns
+    /// * 仮押さえを解放した注文IDのリスト
+    pub async fn run(&self, now: DateTime<Utc>) -> Result<Vec<OrderId>, RepositoryError> {
+        // 期限の古い順に取得した仮押さえを、注文ごとにまとめる
+        let mut holds_by_order: Vec<(OrderId, Vec<CheckoutHold>)> = Vec::new();
+        for hold in self.hold_repository.find_expired(now).await? {
+            match holds_by_order.iter_mut().find(|(order_id, _)| *order_id == hold.order_id) {
+                Some((_, holds)) => holds.push(hold),
+                None => holds_by_order.push((hold.order_id, vec![hold])),
+            }
+        }
+
+        let mut released = Vec::new();
+        for (order_id, holds) in holds_by_order {
+            match self.release(order_id, &holds).await {
+                Ok(()) => released.push(order_id),
+                Err(e) => {
+                    self.log_failure(order_id, "Failed to release checkout hold", &e.to_string())
+                }
+            }
+        }
+        Ok(released)
+    }
+
+    /// 注文の仮押さえの在庫を解放し、仮押さえを削除する
+    async fn release(&self, order_id: OrderId, holds: &[CheckoutHold]) -> Result<(), RepositoryError> {
+        for hold in holds {
+            let Some(mut inventory) = self
+                .inventory_repository
+                .find_by_book_id(hold.book_id)
+                .await?
+            else {
+                continue;
+            };
+            inventory
+                .release(hold.quantity)
+                .map_err(|e| RepositoryError::OperationFailed(e.to_string()))?;
+            self.inventory_repository.save(&inventory).await?;
+        }
+        self.hold_repository.remove_order(order_id).await?;
+
+        // 仮押さえしていた数量を通知し、順番待ちの繰り上げを促す
+        let correlation_id = Uuid::new_v4();
+        let event = CheckoutHoldExpired::with_correlation_id(order_id, holds.to_vec(), correlation_id);
+        if let Err(e) = self
+            .event_bus
+            .publish(DomainEvent::CheckoutHoldExpired(event))
+            .await
+        {
+            self.log_failure(order_id, "Failed to publish CheckoutHoldExpired", &e.to_string());
+        }
+
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), order_id.to_string());
+        context.insert("hold_count".to_string(), holds.len().to_string());
+        self.logger.info(
+            "CheckoutHoldSweeper",
+            "Expired checkout hold released",
+            Some(correlation_id),
+            Some(context),
+        );
+        Ok(())
+    }
+
+    fn log_failure(&self, order_id: OrderId, message: &str, error: &str) {
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), order_id.to_string());
+        context.insert("error".to_string(), error.to_string());
+        self.logger
+            .error("CheckoutHoldSweeper", message, None, Some(context));
+    }
+}
+
+#[async_trait]
+impl SupervisedTask for CheckoutHoldSweeper {
+    async fn run_once(&self) {
+        if let Err(e) = self.run(Utc::now()).await {
+            let mut context = HashMap::new();
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "CheckoutHoldSweeper",
+                "Failed to load expired checkout holds",
+                None,
+                Some(context),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn test_hold_expiry_and_held_quantities() {
+        let now = Utc::now();
+        let order_id = OrderId::new();
+        let book_id = BookId::new();
+        let hold = CheckoutHold::new(order_id, book_id, 2, now + TimeDelta::minutes(15));
+        assert!(!hold.is_expired(now));
+        assert!(hold.is_expired(now + TimeDelta::minutes(15)));
+
+        let other_book = BookId::new();
+        let quantities = held_quantities(&[
+            hold.clone(),
+            CheckoutHold::new(order_id, other_book, 1, hold.expires_at),
+        ]);
+        assert_eq!(quantities[&book_id], 2);
+        assert_eq!(quantities[&other_book], 1);
+    }
+}
@@ -0,0 +1,415 @@
+use crate::domain::book_translation::Language;
+use crate::domain::model::{
+    Customer, CustomerId, EmailAddress, Money, Order, OrderId, OrderLine, OrderNumber, Recipient,
+    SalesChannel, ShippingAddress,
+};
+use crate::domain::notification_retry::FailedNotification;
+use crate::domain::port::{
+    CustomerRepository, FailedNotificationRepository, ObjectStorageError, ObjectStoragePort,
+    OrderRepository,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Write};
+use std::sync::Arc;
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+/// 1回に読み込む注文数のデフォルト値
+pub const DEFAULT_CUSTOMER_EXPORT_PAGE_SIZE: u32 = 100;
+
+/// アーカイブの形式のバージョン（ファイルの構成を変えた場合に上げる）
+const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+/// 顧客のデータのエクスポートの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerExportStatus {
+    /// アーカイブを作成中
+    Pending,
+    /// アーカイブをダウンロードできる
+    Completed,
+    /// アーカイブの作成に失敗した（もう一度エクスポートを依頼する）
+    Failed,
+}
+
+impl CustomerExportStatus {
+    /// JSONでの表記（pending / completed / failed）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomerExportStatus::Pending => "pending",
+            CustomerExportStatus::Completed => "completed",
+            CustomerExportStatus::Failed => "failed",
+        }
+    }
+}
+
+/// 顧客のデータのエクスポートのジョブ
+/// オブジェクトストレージにアーカイブと並べて保存し、ジョブIDで状態を確認する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomerExportJob {
+    pub job_id: Uuid,
+    pub customer_id: CustomerId,
+    pub status: CustomerExportStatus,
+    pub requested_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// アーカイブに含めた注文数
+    #[serde(default)]
+    pub order_count: usize,
+    /// 失敗した理由
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CustomerExportJob {
+    /// 作成中のジョブを作成
+    pub fn new(customer_id: CustomerId, now: DateTime<Utc>) -> Self {
+        Self {
+            job_id: Uuid::new_v4(),
+            customer_id,
+            status: CustomerExportStatus::Pending,
+            requested_at: now,
+            completed_at: None,
+            order_count: 0,
+            error: None,
+        }
+    }
+
+    /// ジョブとアーカイブを置くキーの接頭辞
+    fn key_prefix(job_id: Uuid) -> String {
+        format!("customer-exports/{}", job_id)
+    }
+
+    fn job_key(job_id: Uuid) -> String {
+        format!("{}/job.json", Self::key_prefix(job_id))
+    }
+
+    /// アーカイブ（ZIP）のキー
+    pub fn archive_key(&self) -> String {
+        format!("{}/export.zip", Self::key_prefix(self.job_id))
+    }
+}
+
+/// 顧客のデータのエクスポートエラー
+#[derive(Debug, thiserror::Error)]
+pub enum CustomerExportError {
+    #[error("Customer not found: {0}")]
+    CustomerNotFound(CustomerId),
+    #[error("Repository read failed: {0}")]
+    RepositoryFailed(String),
+    #[error(transparent)]
+    StorageFailed(#[from] ObjectStorageError),
+    #[error("Archive creation failed: {0}")]
+    ArchiveFailed(String),
+    #[error("Invalid export job {key}: {message}")]
+    InvalidJob { key: String, message: String },
+}
+
+/// アーカイブの manifest.json
+#[derive(Serialize)]
+struct ArchiveManifest {
+    format_version: u32,
+    job_id: Uuid,
+    customer_id: CustomerId,
+    generated_at: DateTime<Utc>,
+    order_count: usize,
+    notification_count: usize,
+}
+
+/// アーカイブの customer.json
+#[derive(Serialize)]
+struct ExportedCustomer<'a> {
+    customer_id: CustomerId,
+    name: &'a str,
+    email: &'a EmailAddress,
+    default_shipping_address: Option<&'a ShippingAddress>,
+    preferred_language: Language,
+    registered_at: DateTime<Utc>,
+}
+
+impl<'a> From<&'a Customer> for ExportedCustomer<'a> {
+    fn from(customer: &'a Customer) -> Self {
+        Self {
+            customer_id: customer.id(),
+            name: customer.name(),
+            email: customer.email(),
+            default_shipping_address: customer.default_shipping_address(),
+            preferred_language: customer.preferred_language(),
+            registered_at: customer.registered_at(),
+        }
+    }
+}
+
+/// アーカイブの orders/{order_id}/order.json
+#[derive(Serialize)]
+struct ExportedOrder<'a> {
+    order_id: OrderId,
+    order_number: Option<&'a OrderNumber>,
+    status: String,
+    sales_channel: SalesChannel,
+    lines: &'a [OrderLine],
+    shipping_address: Option<&'a ShippingAddress>,
+    recipient: Option<&'a Recipient>,
+    subtotal: Money,
+    shipping_fee: Money,
+    total: Money,
+    confirmed_at: Option<DateTime<Utc>>,
+    shipped_at: Option<DateTime<Utc>>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> From<&'a Order> for ExportedOrder<'a> {
+    fn from(order: &'a Order) -> Self {
+        Self {
+            order_id: order.id(),
+            order_number: order.order_number(),
+            status: order.status().to_string(),
+            sales_channel: order.sales_channel(),
+            lines: order.order_lines(),
+            shipping_address: order.shipping_address(),
+            recipient: order.recipient(),
+            subtotal: order.subtotal(),
+            shipping_fee: order.shipping_fee(),
+            total: order.calculate_total(),
+            confirmed_at: order.confirmed_at(),
+            shipped_at: order.shipped_at(),
+            delivered_at: order.delivered_at(),
+        }
+    }
+}
+
+/// アーカイブの orders/{order_id}/notifications.json の1件
+#[derive(Serialize)]
+struct ExportedNotification<'a> {
+    channel: String,
+    event_type: &'a str,
+    message: &'a str,
+    status: String,
+    attempts: u32,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> From<&'a FailedNotification> for ExportedNotification<'a> {
+    fn from(notification: &'a FailedNotification) -> Self {
+        Self {
+            channel: notification.notification.channel.to_string(),
+            event_type: &notification.notification.event_type,
+            message: &notification.notification.message,
+            status: notification.status.to_string(),
+            attempts: notification.attempts,
+            created_at: notification.created_at,
+            delivered_at: notification.delivered_at,
+        }
+    }
+}
+
+/// 顧客のデータのエクスポート（データポータビリティ）
+/// 顧客情報・注文（明細・配送先を含む）・通知の履歴をJSONファイルにしてZIPにまとめ、オブジェクトストレージに保存する
+/// 注文は少しずつ読み込んでアーカイブに書き足すため、注文の多い顧客でもすべての注文を一度にメモリに載せない
+/// 通知の履歴は記録しているもの（送信に失敗して再送キューに入った通知）だけを含む
+pub struct CustomerExporter {
+    customers: Arc<dyn CustomerRepository>,
+    orders: Arc<dyn OrderRepository>,
+    notifications: Arc<dyn FailedNotificationRepository>,
+    storage: Arc<dyn ObjectStoragePort>,
+    page_size: u32,
+}
+
+impl CustomerExporter {
+    pub fn new(
+        customers: Arc<dyn CustomerRepository>,
+        orders: Arc<dyn OrderRepository>,
+        notifications: Arc<dyn FailedNotificationRepository>,
+        storage: Arc<dyn ObjectStoragePort>,
+    ) -> Self {
+        Self {
+            customers,
+            orders,
+            notifications,
+            storage,
+            page_size: DEFAULT_CUSTOMER_EXPORT_PAGE_SIZE,
+        }
+    }
+
+    /// 1回に読み込む注文数を設定
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// エクスポートのジョブを作成中として保存する（アーカイブは run で作成する）
+    ///
+    /// # Returns
+    /// * `Ok(CustomerExportJob)` - 作成中のジョブ
+    /// * `Err(CustomerExportError::CustomerNotFound)` - 顧客が登録されていない
+    pub async fn start(
+        &self,
+        customer_id: CustomerId,
+        now: DateTime<Utc>,
+    ) -> Result<CustomerExportJob, CustomerExportError> {
+        self.load_customer(customer_id).await?;
+        let job = CustomerExportJob::new(customer_id, now);
+        self.save_job(&job).await?;
+        Ok(job)
+    }
+
+    /// アーカイブを作成してジョブを完了にする（失敗した場合は失敗した理由をジョブに記録する）
+    ///
+    /// # Returns
+    /// 完了・失敗したジョブ
+    pub async fn run(&self, mut job: CustomerExportJob) -> CustomerExportJob {
+        match self.write_archive(&job).await {
+            Ok(order_count) => {
+                job.status = CustomerExportStatus::Completed;
+                job.order_count = order_count;
+            }
+            Err(e) => {
+                tracing::error!(job_id = %job.job_id, customer_id = %job.customer_id, error = %e, "customer export failed");
+                job.status = CustomerExportStatus::Failed;
+                job.error = Some(e.to_string());
+            }
+        }
+        job.completed_at = Some(Utc::now());
+        if let Err(e) = self.save_job(&job).await {
+            tracing::error!(job_id = %job.job_id, error = %e, "failed to save customer export job");
+        }
+        job
+    }
+
+    /// ジョブを取得する（存在しない場合はNone）
+    pub async fn find_job(
+        &self,
+        job_id: Uuid,
+    ) -> Result<Option<CustomerExportJob>, CustomerExportError> {
+        let key = CustomerExportJob::job_key(job_id);
+        let Some(body) = self.storage.get_object(&key).await? else {
+            return Ok(None);
+        };
+        serde_json::from_slice(&body)
+            .map(Some)
+            .map_err(|e| CustomerExportError::InvalidJob {
+                key,
+                message: e.to_string(),
+            })
+    }
+
+    /// 完了したジョブのアーカイブを読み出す（存在しない場合はNone）
+    pub async fn read_archive(
+        &self,
+        job: &CustomerExportJob,
+    ) -> Result<Option<Vec<u8>>, CustomerExportError> {
+        Ok(self.storage.get_object(&job.archive_key()).await?)
+    }
+
+    async fn load_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Customer, CustomerExportError> {
+        self.customers
+            .find_by_id(customer_id)
+            .await
+            .map_err(|e| CustomerExportError::RepositoryFailed(e.to_string()))?
+            .ok_or(CustomerExportError::CustomerNotFound(customer_id))
+    }
+
+    async fn save_job(&self, job: &CustomerExportJob) -> Result<(), CustomerExportError> {
+        let key = CustomerExportJob::job_key(job.job_id);
+        let body = serde_json::to_vec_pretty(job).map_err(|e| CustomerExportError::InvalidJob {
+            key: key.clone(),
+            message: e.to_string(),
+        })?;
+        self.storage.put_object(&key, body).await?;
+        Ok(())
+    }
+
+    /// アーカイブを作成して保存する
+    ///
+    /// # Returns
+    /// アーカイブに含めた注文数
+    async fn write_archive(&self, job: &CustomerExportJob) -> Result<usize, CustomerExportError> {
+        let customer = self.load_customer(job.customer_id).await?;
+        let mut archive = ArchiveWriter::new();
+        archive.add_json("customer.json", &ExportedCustomer::from(&customer))?;
+
+        let mut order_count = 0;
+        let mut notification_count = 0;
+        let mut after = None;
+        loop {
+            let orders = self
+                .orders
+                .find_by_customer_page(job.customer_id, after, self.page_size)
+                .await
+                .map_err(|e| CustomerExportError::RepositoryFailed(e.to_string()))?;
+            for order in &orders {
+                let notifications = self
+                    .notifications
+                    .find_by_order(order.id())
+                    .await
+                    .map_err(|e| CustomerExportError::RepositoryFailed(e.to_string()))?;
+                let notifications: Vec<ExportedNotification> = notifications
+                    .iter()
+                    .map(ExportedNotification::from)
+                    .collect();
+
+                let dir = format!("orders/{}", order.id());
+                archive.add_json(&format!("{}/order.json", dir), &ExportedOrder::from(order))?;
+                archive.add_json(&format!("{}/notifications.json", dir), &notifications)?;
+                notification_count += notifications.len();
+            }
+            order_count += orders.len();
+
+            after = orders.last().map(Order::id);
+            if orders.len() < self.page_size as usize {
+                break;
+            }
+        }
+
+        archive.add_json(
+            "manifest.json",
+            &ArchiveManifest {
+                format_version: ARCHIVE_FORMAT_VERSION,
+                job_id: job.job_id,
+                customer_id: job.customer_id,
+                generated_at: Utc::now(),
+                order_count,
+                notification_count,
+            },
+        )?;
+        self.storage
+            .put_object(&job.archive_key(), archive.finish()?)
+            .await?;
+        Ok(order_count)
+    }
+}
+
+/// JSONファイルを1つずつ圧縮して書き足すZIPアーカイブ
+struct ArchiveWriter {
+    zip: ZipWriter<Cursor<Vec<u8>>>,
+}
+
+impl ArchiveWriter {
+    fn new() -> Self {
+        Self {
+            zip: ZipWriter::new(Cursor::new(Vec::new())),
+        }
+    }
+
+    fn add_json(&mut self, name: &str, value: &impl Serialize) -> Result<(), CustomerExportError> {
+        let body = serde_json::to_vec_pretty(value)
+            .map_err(|e| CustomerExportError::ArchiveFailed(format!("{}: {}", name, e)))?;
+        self.zip
+            .start_file(name, SimpleFileOptions::default())
+            .and_then(|()| self.zip.write_all(&body).map_err(Into::into))
+            .map_err(|e| CustomerExportError::ArchiveFailed(format!("{}: {}", name, e)))
+    }
+
+    fn finish(self) -> Result<Vec<u8>, CustomerExportError> {
+        self.zip
+            .finish()
+            .map(Cursor::into_inner)
+            .map_err(|e| CustomerExportError::ArchiveFailed(e.to_string()))
+    }
+}
@@ -0,0 +1,19 @@
+use crate::domain::model::{CustomerId, OrderId};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// 接続中の顧客に届ける通知
+/// メール・SMSとは別に、アプリやブラウザに接続している顧客へそのままJSONで送る
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CustomerNotification {
+    /// 通知の宛先の顧客（注文した顧客）
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    /// 通知のきっかけになったイベントの種類
+    pub event_type: String,
+    pub message: String,
+    /// イベントの相関ID（イベントの再配信で同じ通知が届いた場合に、クライアントが重複を除くのに使う）
+    pub correlation_id: Uuid,
+    pub sent_at: DateTime<Utc>,
+}
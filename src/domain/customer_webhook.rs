@@ -0,0 +1,407 @@
+use crate::domain::error::DomainError;
+use crate::domain::event::DomainEvent;
+use crate::domain::model::{CustomerId, OrderId};
+use crate::domain::port::{
+    RepositoryError, WebhookDeliveryRepository, WebhookSender, WebhookSubscriptionRepository,
+};
+use crate::domain::validation::{Constraint, FieldViolation, ValidationErrors};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Webhookで配信できるイベントの種類（DomainEvent::event_typeの名前）
+/// 顧客の注文のステータスが変わるイベントと、顧客セグメントの派生イベントに限る
+pub const WEBHOOK_EVENT_TYPES: [&str; 8] = [
+    "OrderConfirmed",
+    "OrderCancelled",
+    "ShippingAddressChanged",
+    "OrderShipped",
+    "OrderDelivered",
+    "DigitalItemsFulfilled",
+    "HighValueOrderPlaced",
+    "CustomerBecameRepeatBuyer",
+];
+
+/// 署名用のシークレットの最小文字数
+pub const MIN_WEBHOOK_SECRET_LENGTH: usize = 16;
+
+/// WebhookのURLの最大文字数
+pub const MAX_WEBHOOK_URL_LENGTH: usize = 2048;
+
+/// 顧客（B2Bの取引先）ごとのWebhookの購読
+/// 購読した顧客の注文のイベントのうち、指定した種類のイベントだけを配信する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub customer_id: CustomerId,
+    /// 配信先のURL（http・https）
+    pub url: String,
+    /// ペイロードの署名（HMAC-SHA256）に使うシークレット
+    pub secret: String,
+    /// 配信するイベントの種類（WEBHOOK_EVENT_TYPESのいずれか）
+    pub event_types: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    /// 新しい購読を作成
+    /// バリデーション:
+    /// - URLはhttp://またはhttps://で始まる2048文字以内
+    /// - シークレットは16文字以上
+    /// - イベントの種類は1つ以上で、配信できる種類のいずれか（重複は取り除く）
+    pub fn new(
+        customer_id: CustomerId,
+        url: String,
+        secret: String,
+        event_types: Vec<String>,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let mut errors = ValidationErrors::new();
+
+        let url = url.trim().to_string();
+        let has_scheme = ["http://", "https://"].iter().any(|scheme| {
+            url.len() > scheme.len() && url[..scheme.len()].eq_ignore_ascii_case(scheme)
+        });
+        if !has_scheme || url.len() > MAX_WEBHOOK_URL_LENGTH {
+            errors.add(FieldViolation::new(
+                "url",
+                Constraint::Pattern,
+                Some(url.clone()),
+                format!(
+                    "URLはhttp://またはhttps://で始まる{}文字以内で指定してください",
+                    MAX_WEBHOOK_URL_LENGTH
+                ),
+            ));
+        }
+
+        if secret.chars().count() < MIN_WEBHOOK_SECRET_LENGTH {
+            errors.add(FieldViolation::new(
+                "secret",
+                Constraint::Min,
+                None,
+                format!(
+                    "シークレットは{}文字以上で指定してください",
+                    MIN_WEBHOOK_SECRET_LENGTH
+                ),
+            ));
+        }
+
+        if event_types.is_empty() {
+            errors.add(FieldViolation::new(
+                "event_types",
+                Constraint::Required,
+                None,
+                "配信するイベントの種類を1つ以上指定してください",
+            ));
+        }
+        let mut unique_event_types: Vec<String> = Vec::new();
+        for (index, event_type) in event_types.into_iter().enumerate() {
+            if !WEBHOOK_EVENT_TYPES.contains(&event_type.as_str()) {
+                errors.add(FieldViolation::new(
+                    format!("event_types[{}]", index),
+                    Constraint::OneOf,
+                    Some(event_type),
+                    format!(
+                        "配信できるイベントの種類は{}のいずれかです",
+                        WEBHOOK_EVENT_TYPES.join("・")
+                    ),
+                ));
+            } else if !unique_event_types.contains(&event_type) {
+                unique_event_types.push(event_type);
+            }
+        }
+        errors.into_result()?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            customer_id,
+            url,
+            secret,
+            event_types: unique_event_types,
+            created_at,
+        })
+    }
+
+    /// 顧客の注文のイベントを配信する購読かどうか
+    /// 他の顧客の注文のイベントは、購読しているイベントの種類でも配信しない
+    pub fn accepts(&self, customer_id: CustomerId, event_type: &str) -> bool {
+        self.customer_id == customer_id
+            && self
+                .event_types
+                .iter()
+                .any(|subscribed| subscribed == event_type)
+    }
+}
+
+/// Webhookの配信結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookDeliveryStatus {
+    /// 配信先が2xxを返した
+    Succeeded,
+    /// 配信先に接続できなかった、または2xx以外を返した
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    /// 文字列からWebhookDeliveryStatusを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Succeeded" => Ok(WebhookDeliveryStatus::Succeeded),
+            "Failed" => Ok(WebhookDeliveryStatus::Failed),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効なWebhookの配信結果: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            WebhookDeliveryStatus::Succeeded => "Succeeded",
+            WebhookDeliveryStatus::Failed => "Failed",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+/// Webhookの配信記録（購読ごとの配信ログの1行）
+/// 再配信では同じペイロードを新しい配信として記録する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    /// 配信したドメインイベントのID（受信側での重複排除に使う）
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub order_id: OrderId,
+    /// 配信したJSONペイロード
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    /// 配信先が返したHTTPステータス（接続できなかった場合はNone）
+    pub response_status: Option<u16>,
+    /// 失敗の理由（成功した場合はNone）
+    pub error: Option<String>,
+    /// 再配信の場合は元の配信のID
+    pub redelivery_of: Option<Uuid>,
+    pub attempted_at: DateTime<Utc>,
+}
+
+/// Webhookの送信結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookOutcome {
+    /// 配信先が2xxを返した
+    Delivered { status: u16 },
+    /// 配信先が2xx以外を返した
+    Rejected { status: u16 },
+    /// 配信先に接続できなかった（タイムアウトを含む）
+    Unreachable { reason: String },
+}
+
+/// イベントをWebhookで配信するペイロードを作成
+/// dataにはイベント本体（DomainEventのシリアライズ結果）をそのまま含める
+pub fn webhook_payload(customer_id: CustomerId, order_id: OrderId, event: &DomainEvent) -> String {
+    let metadata = event.metadata();
+    serde_json::json!({
+        "event_id": metadata.event_id.to_string(),
+        "event_type": event.event_type(),
+        "occurred_at": metadata.occurred_at.to_rfc3339(),
+        "customer_id": customer_id.to_string(),
+        "order_id": order_id.to_string(),
+        "data": event,
+    })
+    .to_string()
+}
+
+/// 顧客の注文のイベントを、その顧客の購読にだけ配信する
+/// 配信ごとに結果を配信ログに記録する（配信先の失敗は記録して次の購読に進み、再配信はAPIから行う）
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    subscription_repository: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    sender: Arc<dyn WebhookSender>,
+}
+
+impl WebhookDispatcher {
+    /// 新しいWebhookの配信を作成
+    ///
+    /// # Arguments
+    /// * `subscription_repository` - Webhookの購読リポジトリ
+    /// * `delivery_repository` - Webhookの配信ログリポジトリ
+    /// * `sender` - Webhookの送信ポート
+    pub fn new(
+        subscription_repository: Arc<dyn WebhookSubscriptionRepository>,
+        delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+        sender: Arc<dyn WebhookSender>,
+    ) -> Self {
+        Self {
+            subscription_repository,
+            delivery_repository,
+            sender,
+        }
+    }
+
+    /// 注文のイベントを顧客の購読に配信する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 注文の顧客ID（この顧客の購読にだけ配信する）
+    /// * `order_id` - 注文ID
+    /// * `event` - 配信するイベント
+    ///
+    /// # Returns
+    /// * `Ok(Vec<WebhookDelivery>)` - 購読ごとの配信記録（配信先の失敗を含む）
+    /// * `Err(RepositoryError)` - 購読の取得または配信ログの記録に失敗
+    pub async fn dispatch(
+        &self,
+        customer_id: CustomerId,
+        order_id: OrderId,
+        event: &DomainEvent,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let event_type = event.event_type();
+        let subscriptions: Vec<WebhookSubscription> = self
+            .subscription_repository
+            .find_by_customer(customer_id)
+            .await?
+            .into_iter()
+            .filter(|subscription| subscription.accepts(customer_id, event_type))
+            .collect();
+        if subscriptions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let payload = webhook_payload(customer_id, order_id, event);
+        let mut deliveries = Vec::with_capacity(subscriptions.len());
+        for subscription in &subscriptions {
+            deliveries.push(
+                self.deliver(
+                    subscription,
+                    event.metadata().event_id,
+                    event_type.to_string(),
+                    order_id,
+                    payload.clone(),
+                    None,
+                )
+                .await?,
+            );
+        }
+        Ok(deliveries)
+    }
+
+    /// 記録済みの配信と同じペイロードを再配信する
+    ///
+    /// # Arguments
+    /// * `subscription` - 配信先の購読
+    /// * `original` - 再配信する配信記録
+    pub async fn redeliver(
+        &self,
+        subscription: &WebhookSubscription,
+        original: &WebhookDelivery,
+    ) -> Result<WebhookDelivery, RepositoryError> {
+        self.deliver(
+            subscription,
+            original.event_id,
+            original.event_type.clone(),
+            original.order_id,
+            original.payload.clone(),
+            Some(original.id),
+        )
+        .await
+    }
+
+    async fn deliver(
+        &self,
+        subscription: &WebhookSubscription,
+        event_id: Uuid,
+        event_type: String,
+        order_id: OrderId,
+        payload: String,
+        redelivery_of: Option<Uuid>,
+    ) -> Result<WebhookDelivery, RepositoryError> {
+        let delivery_id = Uuid::new_v4();
+        let outcome = self
+            .sender
+            .send(subscription, delivery_id, &event_type, &payload)
+            .await;
+        let (status, response_status, error) = match outcome {
+            WebhookOutcome::Delivered { status } => {
+                (WebhookDeliveryStatus::Succeeded, Some(status), None)
+            }
+            WebhookOutcome::Rejected { status } => (
+                WebhookDeliveryStatus::Failed,
+                Some(status),
+                Some(format!("配信先がHTTP {}を返しました", status)),
+            ),
+            WebhookOutcome::Unreachable { reason } => {
+                (WebhookDeliveryStatus::Failed, None, Some(reason))
+            }
+        };
+
+        let delivery = WebhookDelivery {
+            id: delivery_id,
+            subscription_id: subscription.id,
+            event_id,
+            event_type,
+            order_id,
+            payload,
+            status,
+            response_status,
+            error,
+            redelivery_of,
+            attempted_at: Utc::now(),
+        };
+        self.delivery_repository.record(&delivery).await?;
+        Ok(delivery)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscription_validates_and_scopes_to_customer() {
+        let customer_id = CustomerId::new();
+        let subscription = WebhookSubscription::new(
+            customer_id,
+            " https://partner.example.com/hooks ".to_string(),
+            "0123456789abcdef".to_string(),
+            vec![
+                "OrderShipped".to_string(),
+                "OrderDelivered".to_string(),
+                "OrderShipped".to_string(),
+            ],
+            Utc::now(),
+        )
+        .unwrap();
+
+        assert_eq!(subscription.url, "https://partner.example.com/hooks");
+        assert_eq!(
+            subscription.event_types,
+            vec!["OrderShipped", "OrderDelivered"]
+        );
+        assert!(subscription.accepts(customer_id, "OrderShipped"));
+        assert!(!subscription.accepts(customer_id, "OrderConfirmed"));
+        // 他の顧客の注文のイベントは配信しない
+        assert!(!subscription.accepts(CustomerId::new(), "OrderShipped"));
+
+        let result = WebhookSubscription::new(
+            customer_id,
+            "ftp://partner.example.com".to_string(),
+            "short".to_string(),
+            vec!["InventoryReserved".to_string()],
+            Utc::now(),
+        );
+        let Err(DomainError::Validation(errors)) = result else {
+            panic!("バリデーションエラーになるはず");
+        };
+        let fields: Vec<&str> = errors
+            .violations()
+            .iter()
+            .map(|violation| violation.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["url", "secret", "event_types[0]"]);
+    }
+}
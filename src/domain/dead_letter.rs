@@ -0,0 +1,185 @@
+use crate::domain::event_bus::DeadLetter;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// UUIDの文字数（ハイフンを含む）
+const UUID_LEN: usize = 36;
+
+/// エラーメッセージから注文IDや数値などエントリごとに異なる部分を取り除いた指紋
+/// 同じ原因の失敗が同じ指紋になるように、UUIDを `<uuid>`、数値（日時・小数を含む）を `<n>` に置き換える
+pub fn error_fingerprint(error: &str) -> String {
+    let chars: Vec<char> = error.chars().collect();
+    let mut fingerprint = String::with_capacity(error.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if is_uuid_at(&chars, i) {
+            fingerprint.push_str("<uuid>");
+            i += UUID_LEN;
+        } else if chars[i].is_ascii_digit() {
+            // 数字に続く区切り（2024-01-15、12:00:00、1.5）もまとめて1つの数値とみなす
+            while i < chars.len()
+                && (chars[i].is_ascii_digit()
+                    || (matches!(chars[i], '-' | ':' | '.')
+                        && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())))
+            {
+                i += 1;
+            }
+            fingerprint.push_str("<n>");
+        } else if chars[i].is_whitespace() {
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            fingerprint.push(' ');
+        } else {
+            fingerprint.push(chars[i]);
+            i += 1;
+        }
+    }
+    fingerprint.trim().to_string()
+}
+
+fn is_uuid_at(chars: &[char], start: usize) -> bool {
+    let Some(candidate) = chars.get(start..start + UUID_LEN) else {
+        return false;
+    };
+    // 英数字の途中から始まる場合はUUIDとみなさない
+    if start > 0 && chars[start - 1].is_ascii_alphanumeric() {
+        return false;
+    }
+    candidate.iter().enumerate().all(|(index, c)| match index {
+        8 | 13 | 18 | 23 => *c == '-',
+        _ => c.is_ascii_hexdigit(),
+    })
+}
+
+/// ハンドラーとエラーの指紋ごとにまとめたデッドレターキューのエントリ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeadLetterGroup {
+    pub handler_name: String,
+    pub error_fingerprint: String,
+    /// グループに含まれるエントリのイベントタイプ（重複なし、最初に現れた順）
+    pub event_types: Vec<String>,
+    pub count: usize,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    /// 最後に送られたエントリのエラーメッセージ（調査の手がかり）
+    pub sample_error: String,
+}
+
+/// デッドレターキューの集計（GET /admin/dead-letters/summary のレスポンス）
+/// 繰り返し発生している失敗のパターンを件数の多い順に並べる
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeadLetterSummary {
+    pub total_entries: usize,
+    pub groups: Vec<DeadLetterGroup>,
+}
+
+impl DeadLetterSummary {
+    /// エントリをハンドラーとエラーの指紋ごとにまとめる
+    /// 件数の多い順（同数の場合は最後に送られたのが新しい順）に並べる
+    pub fn build(dead_letters: &[DeadLetter]) -> Self {
+        let mut groups: Vec<DeadLetterGroup> = Vec::new();
+        for dead_letter in dead_letters {
+            let fingerprint = error_fingerprint(&dead_letter.error);
+            let seen_at = dead_letter.dead_lettered_at;
+            match groups.iter_mut().find(|group| {
+                group.handler_name == dead_letter.handler_name
+                    && group.error_fingerprint == fingerprint
+            }) {
+                Some(group) => {
+                    group.count += 1;
+                    if !group.event_types.contains(&dead_letter.event_type) {
+                        group.event_types.push(dead_letter.event_type.clone());
+                    }
+                    group.first_seen = group.first_seen.min(seen_at);
+                    if seen_at >= group.last_seen {
+                        group.last_seen = seen_at;
+                        group.sample_error = dead_letter.error.clone();
+                    }
+                }
+                None => groups.push(DeadLetterGroup {
+                    handler_name: dead_letter.handler_name.clone(),
+                    error_fingerprint: fingerprint,
+                    event_types: vec![dead_letter.event_type.clone()],
+                    count: 1,
+                    first_seen: seen_at,
+                    last_seen: seen_at,
+                    sample_error: dead_letter.error.clone(),
+                }),
+            }
+        }
+        groups.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| b.last_seen.cmp(&a.last_seen))
+        });
+
+        Self {
+            total_entries: dead_letters.len(),
+            groups,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event_bus::HandlerErrorContext;
+    use chrono::TimeDelta;
+
+    fn dead_letter(handler_name: &str, error: &str, dead_lettered_at: DateTime<Utc>) -> DeadLetter {
+        DeadLetter {
+            handler_name: handler_name.to_string(),
+            event_type: "OrderConfirmed".to_string(),
+            error: error.to_string(),
+            context: HandlerErrorContext::default(),
+            attempt_count: 3,
+            retryable: true,
+            dead_lettered_at,
+        }
+    }
+
+    #[test]
+    fn test_error_fingerprint_removes_ids_and_numbers() {
+        assert_eq!(
+            error_fingerprint(
+                "Repository error: 注文が見つかりません: 550e8400-e29b-41d4-a716-446655440000"
+            ),
+            "Repository error: 注文が見つかりません: <uuid>"
+        );
+        assert_eq!(
+            error_fingerprint("Transient error (retryable): timeout after 30.5s at 2024-01-15T12:00:00Z"),
+            "Transient error (retryable): timeout after <n>s at <n>T<n>Z"
+        );
+        // 英数字の一部はUUIDとみなさない
+        assert_eq!(error_fingerprint("v2  book-1"), "v<n> book-<n>");
+    }
+
+    #[test]
+    fn test_summary_groups_by_handler_and_fingerprint() {
+        let now = Utc::now();
+        let summary = DeadLetterSummary::build(&[
+            dead_letter(
+                "ShippingHandler",
+                "Transient error (retryable): carrier timeout for order 550e8400-e29b-41d4-a716-446655440000",
+                now - TimeDelta::minutes(30),
+            ),
+            dead_letter("InventoryReservationHandler", "Domain error: 在庫不足", now),
+            dead_letter(
+                "ShippingHandler",
+                "Transient error (retryable): carrier timeout for order 7c9e6679-7425-40de-944b-e07fc1f90ae7",
+                now - TimeDelta::minutes(10),
+            ),
+        ]);
+
+        assert_eq!(summary.total_entries, 3);
+        assert_eq!(summary.groups.len(), 2);
+        let shipping = &summary.groups[0];
+        assert_eq!(shipping.handler_name, "ShippingHandler");
+        assert_eq!(shipping.count, 2);
+        assert_eq!(shipping.first_seen, now - TimeDelta::minutes(30));
+        assert_eq!(shipping.last_seen, now - TimeDelta::minutes(10));
+        assert!(shipping.sample_error.ends_with("7c9e6679-7425-40de-944b-e07fc1f90ae7"));
+        assert_eq!(summary.groups[1].handler_name, "InventoryReservationHandler");
+    }
+}
@@ -0,0 +1,154 @@
+use crate::domain::model::{Order, OrderStatus};
+use chrono::{NaiveDate, TimeDelta};
+use std::collections::HashMap;
+
+/// 配達予定日の見積もり
+/// 配送先の都道府県ごとの配送日数（発送から配達まで）と、確定から発送までの日数から配達予定日を算出する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryEstimator {
+    /// 確定から発送までの日数
+    handling_days: u32,
+    /// 配送日数の設定がない都道府県の配送日数
+    default_lead_time_days: u32,
+    /// 都道府県ごとの配送日数（例: "沖縄県" => 4）
+    prefecture_lead_times: HashMap<String, u32>,
+}
+
+impl Default for DeliveryEstimator {
+    fn default() -> Self {
+        Self {
+            handling_days: 1,
+            default_lead_time_days: 2,
+            prefecture_lead_times: HashMap::from([
+                ("北海道".to_string(), 3),
+                ("沖縄県".to_string(), 4),
+            ]),
+        }
+    }
+}
+
+impl DeliveryEstimator {
+    /// 配達予定日の見積もりを作成
+    ///
+    /// # Arguments
+    /// * `handling_days` - 確定から発送までの日数
+    /// * `default_lead_time_days` - 配送日数の設定がない都道府県の配送日数
+    /// * `prefecture_lead_times` - 都道府県ごとの配送日数
+    pub fn new(
+        handling_days: u32,
+        default_lead_time_days: u32,
+        prefecture_lead_times: HashMap<String, u32>,
+    ) -> Self {
+        Self {
+            handling_days,
+            default_lead_time_days,
+            prefecture_lead_times,
+        }
+    }
+
+    /// 確定から発送までの日数を取得
+    pub fn handling_days(&self) -> u32 {
+        self.handling_days
+    }
+
+    /// 配送日数の設定がない都道府県の配送日数を取得
+    pub fn default_lead_time_days(&self) -> u32 {
+        self.default_lead_time_days
+    }
+
+    /// 都道府県ごとの配送日数を取得
+    pub fn prefecture_lead_times(&self) -> &HashMap<String, u32> {
+        &self.prefecture_lead_times
+    }
+
+    /// 配送先の都道府県の配送日数（設定がない都道府県は既定の日数）
+    pub fn lead_time_days(&self, prefecture: &str) -> u32 {
+        self.prefecture_lead_times
+            .get(prefecture)
+            .copied()
+            .unwrap_or(self.default_lead_time_days)
+    }
+
+    /// 注文の配達予定日を算出する
+    /// 発送済みの注文は発送日に配送日数を、確定済みで未発送の注文は確定日に発送までの日数と配送日数を足す（日付はUTC）
+    ///
+    /// # Arguments
+    /// * `order` - 見積もる注文
+    ///
+    /// # Returns
+    /// * `Some(NaiveDate)` - 配達予定日
+    /// * `None` - 発送を伴わない注文（電子書籍のみ）・配送先が未設定の注文・発送の予定が立たない注文（確定前・保留中・発売待ち・順番待ち・キャンセル済み）
+    pub fn estimate(&self, order: &Order) -> Option<NaiveDate> {
+        if !order.requires_shipping() {
+            return None;
+        }
+        let address = order.shipping_address()?;
+        let lead_time = TimeDelta::days(i64::from(self.lead_time_days(address.prefecture())));
+
+        match order.status() {
+            OrderStatus::Shipped
+            | OrderStatus::Delivered
+            | OrderStatus::ReturnRequested
+            | OrderStatus::Returned => Some(order.shipped_at()?.date_naive() + lead_time),
+            OrderStatus::Confirmed => {
+                let handling = TimeDelta::days(i64::from(self.handling_days));
+                Some(order.confirmed_at()?.date_naive() + handling + lead_time)
+            }
+            OrderStatus::Pending
+            | OrderStatus::OnHold
+            | OrderStatus::AwaitingRelease
+            | OrderStatus::Waitlisted
+            | OrderStatus::Fulfilled
+            | OrderStatus::Cancelled => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, CustomerId, Money, OrderId, ShippingAddress};
+
+    fn order_to(prefecture: &str) -> Order {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "9000001".to_string(),
+                    prefecture.to_string(),
+                    "那覇市".to_string(),
+                    "泉崎1-1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        order
+    }
+
+    #[test]
+    fn test_estimate_by_prefecture_and_status() {
+        let estimator = DeliveryEstimator::default();
+
+        let mut order = order_to("沖縄県");
+        assert_eq!(estimator.estimate(&order), None);
+
+        order.confirm().unwrap();
+        let confirmed_on = order.confirmed_at().unwrap().date_naive();
+        assert_eq!(
+            estimator.estimate(&order),
+            Some(confirmed_on + TimeDelta::days(1 + 4))
+        );
+
+        order.mark_as_shipped().unwrap();
+        let shipped_on = order.shipped_at().unwrap().date_naive();
+        assert_eq!(
+            estimator.estimate(&order),
+            Some(shipped_on + TimeDelta::days(4))
+        );
+
+        // 配送日数の設定がない都道府県は既定の日数
+        assert_eq!(estimator.lead_time_days("東京都"), 2);
+    }
+}
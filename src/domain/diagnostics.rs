@@ -0,0 +1,235 @@
+use crate::domain::event_bus::{EventDispatchStatus, SubscriptionStatus};
+use crate::domain::port::Logger;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// 診断項目の結果
+/// 並び順は深刻度の順（最も深刻な結果を全体の結果にする）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticStatus {
+    /// 問題なし
+    Ok,
+    /// 起動はできるが確認が必要（例: 再起動で無効になる設定の省略）
+    Warning,
+    /// 起動できない（例: データベースに接続できない、設定値が不正）
+    Failed,
+}
+
+/// 診断項目の分類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticCategory {
+    /// 設定値の検証
+    Config,
+    /// データベースへの接続
+    Database,
+    /// データベースマイグレーションの適用状況
+    Migrations,
+    /// イベントバスの配信先
+    EventBus,
+    /// イベントハンドラーの登録状況
+    Handlers,
+}
+
+/// 診断項目1件の結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiagnosticCheck {
+    pub category: DiagnosticCategory,
+    /// 診断項目名（設定の場合は設定の型名）
+    pub name: String,
+    pub status: DiagnosticStatus,
+    /// 結果の説明（失敗した場合はエラーの内容）
+    pub detail: String,
+}
+
+impl DiagnosticCheck {
+    /// 問題のなかった診断項目を作成
+    pub fn ok(category: DiagnosticCategory, name: &str, detail: impl Into<String>) -> Self {
+        Self::new(category, name, DiagnosticStatus::Ok, detail)
+    }
+
+    /// 確認が必要な診断項目を作成
+    pub fn warning(category: DiagnosticCategory, name: &str, detail: impl Into<String>) -> Self {
+        Self::new(category, name, DiagnosticStatus::Warning, detail)
+    }
+
+    /// 失敗した診断項目を作成
+    pub fn failed(category: DiagnosticCategory, name: &str, detail: impl Into<String>) -> Self {
+        Self::new(category, name, DiagnosticStatus::Failed, detail)
+    }
+
+    fn new(
+        category: DiagnosticCategory,
+        name: &str,
+        status: DiagnosticStatus,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self {
+            category,
+            name: name.to_string(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// 起動時の診断レポート
+/// 設定値・データベース・マイグレーション・イベントバス・ハンドラーの登録状況を起動時に確認した結果
+/// （設定の誤ったデプロイを、最初のリクエストではなく起動時に原因とともに失敗させる）
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsReport {
+    pub started_at: DateTime<Utc>,
+    /// 全体の結果（最も深刻な診断項目の結果）
+    pub status: DiagnosticStatus,
+    pub checks: Vec<DiagnosticCheck>,
+    /// イベントタイプごとの登録済みハンドラー名
+    pub handlers: BTreeMap<String, Vec<String>>,
+    /// 取得時点のイベントの配信全体の状態（デプロイ時の一時停止の確認用、起動時の診断には含めない）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_dispatch: Option<EventDispatchStatus>,
+}
+
+impl DiagnosticsReport {
+    /// 新しい診断レポートを作成
+    ///
+    /// # Arguments
+    /// * `started_at` - 診断を開始した日時
+    pub fn new(started_at: DateTime<Utc>) -> Self {
+        Self {
+            started_at,
+            status: DiagnosticStatus::Ok,
+            checks: Vec::new(),
+            handlers: BTreeMap::new(),
+            event_dispatch: None,
+        }
+    }
+
+    /// 診断項目の結果を記録する
+    pub fn record(&mut self, check: DiagnosticCheck) {
+        self.status = self.status.max(check.status);
+        self.checks.push(check);
+    }
+
+    /// イベントハンドラーの登録状況を記録する
+    /// 1件も登録されていない場合はイベントが処理されないため失敗にする
+    ///
+    /// # Arguments
+    /// * `subscriptions` - イベントバスの購読の一覧
+    pub fn record_handlers(&mut self, subscriptions: &[SubscriptionStatus]) {
+        for subscription in subscriptions {
+            self.handlers
+                .entry(subscription.event_type.clone())
+                .or_default()
+                .push(subscription.handler_name.clone());
+        }
+
+        let check = if subscriptions.is_empty() {
+            DiagnosticCheck::failed(
+                DiagnosticCategory::Handlers,
+                "subscriptions",
+                "イベントハンドラーが1件も登録されていません",
+            )
+        } else {
+            DiagnosticCheck::ok(
+                DiagnosticCategory::Handlers,
+                "subscriptions",
+                format!(
+                    "{}件のハンドラーを{}種類のイベントに登録しました",
+                    subscriptions.len(),
+                    self.handlers.len()
+                ),
+            )
+        };
+        self.record(check);
+    }
+
+    /// 失敗した診断項目
+    pub fn failures(&self) -> Vec<&DiagnosticCheck> {
+        self.checks
+            .iter()
+            .filter(|check| check.status == DiagnosticStatus::Failed)
+            .collect()
+    }
+
+    /// 失敗した診断項目があるか
+    pub fn has_failures(&self) -> bool {
+        self.status == DiagnosticStatus::Failed
+    }
+
+    /// 失敗した診断項目をまとめたエラーメッセージ
+    pub fn failure_summary(&self) -> String {
+        let failures: Vec<String> = self
+            .failures()
+            .iter()
+            .map(|check| format!("{}: {}", check.name, check.detail))
+            .collect();
+        format!(
+            "起動時の診断で{}件の問題が見つかりました: {}",
+            failures.len(),
+            failures.join("; ")
+        )
+    }
+
+    /// 診断項目ごとに結果をログに出力する（失敗はerror、確認が必要な項目はwarn）
+    ///
+    /// # Arguments
+    /// * `logger` - ログの出力先
+    pub fn log(&self, logger: &dyn Logger) {
+        for check in &self.checks {
+            let mut context = HashMap::new();
+            context.insert("category".to_string(), format!("{:?}", check.category));
+            context.insert("check".to_string(), check.name.clone());
+            let message = format!("[{}] {}", check.name, check.detail);
+            match check.status {
+                DiagnosticStatus::Ok => logger.info("Diagnostics", &message, None, Some(context)),
+                DiagnosticStatus::Warning => {
+                    logger.warn("Diagnostics", &message, None, Some(context))
+                }
+                DiagnosticStatus::Failed => {
+                    logger.error("Diagnostics", &message, None, Some(context))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_status_is_the_most_severe_check() {
+        let mut report = DiagnosticsReport::new(Utc::now());
+        report.record(DiagnosticCheck::ok(
+            DiagnosticCategory::Database,
+            "connection",
+            "接続しました",
+        ));
+        report.record(DiagnosticCheck::warning(
+            DiagnosticCategory::Config,
+            "ActionLinkConfig",
+            "ACTION_LINK_SECRETが未設定です",
+        ));
+        assert_eq!(report.status, DiagnosticStatus::Warning);
+        assert!(!report.has_failures());
+
+        report.record(DiagnosticCheck::failed(
+            DiagnosticCategory::Config,
+            "SlaConfig",
+            "SLA_SHIP_WITHIN_HOURSが不正です",
+        ));
+        report.record(DiagnosticCheck::ok(
+            DiagnosticCategory::Migrations,
+            "schema_migrations",
+            "適用済みです",
+        ));
+        assert_eq!(report.status, DiagnosticStatus::Failed);
+        assert_eq!(report.failures().len(), 1);
+        assert!(report.failure_summary().contains("SlaConfig"));
+
+        report.record_handlers(&[]);
+        assert_eq!(report.failures().len(), 2);
+    }
+}
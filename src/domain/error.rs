@@ -1,3 +1,5 @@
+use crate::domain::validation::{FieldViolation, ValidationErrors};
+
 /// ドメイン層のエラー型
 /// ビジネスルール違反を表現する
 #[derive(Debug, Clone, PartialEq)]
@@ -8,14 +10,33 @@ pub enum DomainError {
     InsufficientInventory,
     /// 無効な数量（例: 0以下の数量）
     InvalidQuantity,
-    /// 無効な住所（例: 郵便番号が7桁でない）
-    InvalidAddress(String),
-    /// 注文の検証失敗（例: 注文明細が空の状態で確定しようとした）
-    OrderValidation(String),
+    /// 検証失敗（例: 郵便番号が7桁でない、注文明細が空の状態で確定しようとした）
+    /// 違反した項目のパス・制約・実際の値を持つ
+    Validation(ValidationErrors),
     /// 通貨の不一致
     CurrencyMismatch,
     /// 無効な値
     InvalidValue(String),
+    /// 書籍ごとの購入数量の上限超過
+    PurchaseQuantityLimitExceeded(String),
+    /// 未完了の注文数の上限超過
+    OpenOrderLimitExceeded(String),
+    /// 無効な棚卸し状態（例: 提出前の棚卸しを承認しようとした）
+    InvalidCycleCountState(String),
+    /// 在庫が凍結中（例: 在庫変動ログとの食い違いを検出して修復した書籍を予約しようとした）
+    InventoryFrozen(String),
+    /// 配送業者の上限超過（例: 総重量が1梱包の最大重量を超えている）
+    CarrierLimitExceeded(String),
+    /// 注文後に書籍の価格が変更された（例: 確定時にカタログの価格と明細の単価が異なる）
+    PriceChanged(String),
+    /// キャンセルの受付期限切れ（例: 確定から一定時間を過ぎた注文をキャンセルしようとした）
+    CancellationWindowExpired(String),
+    /// 注文が他のオペレーターの編集ロック中（例: 別のオペレーターが住所を修正している注文を発送しようとした）
+    OrderLocked(String),
+    /// 確認トークンの不一致（例: 一括キャンセルのプレビュー後に対象の注文が変わった）
+    ConfirmationTokenMismatch(String),
+    /// 割当枠の超過（例: 限定版の1顧客あたりの購入上限や、販売チャネルの割当枠を超えて予約しようとした）
+    QuotaExceeded(String),
 }
 
 impl std::fmt::Display for DomainError {
@@ -24,12 +45,47 @@ impl std::fmt::Display for DomainError {
             DomainError::InvalidOrderState(msg) => write!(f, "Invalid order state: {}", msg),
             DomainError::InsufficientInventory => write!(f, "Insufficient inventory"),
             DomainError::InvalidQuantity => write!(f, "Invalid quantity"),
-            DomainError::InvalidAddress(msg) => write!(f, "Invalid address: {}", msg),
-            DomainError::OrderValidation(msg) => write!(f, "Order validation failed: {}", msg),
+            DomainError::Validation(errors) => write!(f, "Validation failed: {}", errors),
             DomainError::CurrencyMismatch => write!(f, "Currency mismatch"),
             DomainError::InvalidValue(msg) => write!(f, "Invalid value: {}", msg),
+            DomainError::PurchaseQuantityLimitExceeded(msg) => {
+                write!(f, "Purchase quantity limit exceeded: {}", msg)
+            }
+            DomainError::OpenOrderLimitExceeded(msg) => {
+                write!(f, "Open order limit exceeded: {}", msg)
+            }
+            DomainError::InvalidCycleCountState(msg) => {
+                write!(f, "Invalid cycle count state: {}", msg)
+            }
+            DomainError::InventoryFrozen(book_id) => {
+                write!(f, "Inventory is frozen: {}", book_id)
+            }
+            DomainError::CarrierLimitExceeded(msg) => {
+                write!(f, "Carrier limit exceeded: {}", msg)
+            }
+            DomainError::PriceChanged(msg) => write!(f, "Price changed: {}", msg),
+            DomainError::CancellationWindowExpired(msg) => {
+                write!(f, "Cancellation window expired: {}", msg)
+            }
+            DomainError::OrderLocked(msg) => write!(f, "Order is locked: {}", msg),
+            DomainError::ConfirmationTokenMismatch(msg) => {
+                write!(f, "Confirmation token mismatch: {}", msg)
+            }
+            DomainError::QuotaExceeded(msg) => write!(f, "Quota exceeded: {}", msg),
         }
     }
 }
 
 impl std::error::Error for DomainError {}
+
+impl From<ValidationErrors> for DomainError {
+    fn from(errors: ValidationErrors) -> Self {
+        DomainError::Validation(errors)
+    }
+}
+
+impl From<FieldViolation> for DomainError {
+    fn from(violation: FieldViolation) -> Self {
+        DomainError::Validation(violation.into())
+    }
+}
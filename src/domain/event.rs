@@ -1,5 +1,15 @@
-use crate::domain::model::{CustomerId, Money, OrderId, OrderLine, ShippingAddress};
-use chrono::{DateTime, Utc};
+use crate::domain::checkout_hold::CheckoutHold;
+use crate::domain::glossary::domain_model;
+use crate::domain::model::{
+    BookId, CustomerId, DownloadLink, HoldReason, Money, OrderId, OrderLine, Recipient,
+    ShippingAddress,
+};
+use crate::domain::saga_timeout::SagaTimeoutStage;
+use crate::domain::sla::SlaStage;
+use crate::domain::status_override::StatusOverride;
+use crate::domain::tracking::TrackingStage;
+use crate::domain::waitlist::WaitlistPosition;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -16,6 +26,10 @@ pub struct EventMetadata {
     pub correlation_id: Uuid,
     /// イベントバージョン（スキーマ進化対応）
     pub event_version: u32,
+    /// 集約ごとのイベント連番（集約がイベントを記録した時点で1から採番）
+    /// 連番を持たないイベント（配送業者からの通知など）はNone
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
     /// 追加のメタデータ（拡張可能）
     pub additional_metadata: HashMap<String, String>,
 }
@@ -28,6 +42,7 @@ impl EventMetadata {
             occurred_at: Utc::now(),
             correlation_id: Uuid::new_v4(),
             event_version: 1,
+            sequence_number: None,
             additional_metadata: HashMap::new(),
         }
     }
@@ -39,10 +54,17 @@ impl EventMetadata {
             occurred_at: Utc::now(),
             correlation_id,
             event_version: 1,
+            sequence_number: None,
             additional_metadata: HashMap::new(),
         }
     }
 
+    /// 集約内のイベント連番を設定
+    pub fn with_sequence_number(mut self, sequence_number: u64) -> Self {
+        self.sequence_number = Some(sequence_number);
+        self
+    }
+
     /// 追加メタデータを設定
     pub fn with_metadata(mut self, key: String, value: String) -> Self {
         self.additional_metadata.insert(key, value);
@@ -56,6 +78,39 @@ impl Default for EventMetadata {
     }
 }
 
+/// すべてのイベントタイプ（DomainEvent::event_typeの値）
+pub const EVENT_TYPES: [&str; 29] = [
+    "OrderConfirmed",
+    "OrderCancelled",
+    "ShippingAddressChanged",
+    "OrderHeld",
+    "OrderReleased",
+    "OrderShipped",
+    "OrderDelivered",
+    "ReturnRequested",
+    "ReturnApproved",
+    "OrderStatusForcefullyChanged",
+    "PreOrderActivated",
+    "WaitlistJoined",
+    "WaitlistPromoted",
+    "DigitalItemsFulfilled",
+    "FulfillmentSlaBreached",
+    "CarrierTrackingUpdated",
+    "InventoryReserved",
+    "InventoryReleased",
+    "CheckoutHoldExpired",
+    "ItemsRestocked",
+    "InventoryAdjusted",
+    "HighValueOrderPlaced",
+    "CustomerBecameRepeatBuyer",
+    "InventoryReservationFailed",
+    "ShippingFailed",
+    "DeliveryFailed",
+    "SagaCompensationStarted",
+    "SagaCompensationCompleted",
+    "SagaTimedOut",
+];
+
 /// ドメインイベント列挙型
 /// ビジネス上の重要なイベントを表現する
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,14 +120,48 @@ pub enum DomainEvent {
     OrderConfirmed(OrderConfirmed),
     /// 注文がキャンセルされた
     OrderCancelled(OrderCancelled),
+    /// 確定後（発送前）に配送先住所が変更された
+    ShippingAddressChanged(ShippingAddressChanged),
+    /// 確定後（発送前）の注文が保留にされた
+    OrderHeld(OrderHeld),
+    /// 保留中の注文が解除された
+    OrderReleased(OrderReleased),
     /// 注文が発送された
     OrderShipped(OrderShipped),
     /// 注文が配達完了した
     OrderDelivered(OrderDelivered),
+    /// 配達済みの注文の返品が依頼された
+    ReturnRequested(ReturnRequested),
+    /// 返品が承認された
+    ReturnApproved(ReturnApproved),
+    /// 管理者が注文のステータスを強制的に変更した
+    OrderStatusForcefullyChanged(OrderStatusForcefullyChanged),
+    /// 予約注文が有効化された（発売日到来）
+    PreOrderActivated(PreOrderActivated),
+    /// 在庫を超えた注文が順番待ちに登録された
+    WaitlistJoined(WaitlistJoined),
+    /// 順番待ちの注文が在庫を確保して繰り上がった
+    WaitlistPromoted(WaitlistPromoted),
+    /// 電子書籍のダウンロードリンクが発行された
+    DigitalItemsFulfilled(DigitalItemsFulfilled),
+    /// フルフィルメントSLA（発送・配達の期限）を超過した
+    FulfillmentSlaBreached(FulfillmentSlaBreached),
+    /// 配送業者から配送追跡情報が届いた
+    CarrierTrackingUpdated(CarrierTrackingUpdated),
     /// 在庫が予約された
     InventoryReserved(InventoryReserved),
     /// 在庫が解放された
     InventoryReleased(InventoryReleased),
+    /// 期限までに確定しなかった注文の仮押さえが解放された
+    CheckoutHoldExpired(CheckoutHoldExpired),
+    /// 返品された書籍が在庫に戻された
+    ItemsRestocked(ItemsRestocked),
+    /// 在庫数が調整された（棚卸しなど）
+    InventoryAdjusted(InventoryAdjusted),
+    /// 金額が閾値以上の注文が確定した（顧客セグメントの派生イベント）
+    HighValueOrderPlaced(HighValueOrderPlaced),
+    /// 顧客がリピーターになった（顧客セグメントの派生イベント）
+    CustomerBecameRepeatBuyer(CustomerBecameRepeatBuyer),
 
     // 補償イベント（サーガ失敗時のロールバック用）
     /// 在庫予約失敗（補償イベント）
@@ -85,6 +174,8 @@ pub enum DomainEvent {
     SagaCompensationStarted(SagaCompensationStarted),
     /// サーガ補償完了（補償プロセス完了の通知）
     SagaCompensationCompleted(SagaCompensationCompleted),
+    /// サーガのタイムアウト（在庫予約・配達完了が期限内に進まなかった）
+    SagaTimedOut(SagaTimedOut),
 }
 
 impl DomainEvent {
@@ -93,15 +184,68 @@ impl DomainEvent {
         match self {
             DomainEvent::OrderConfirmed(event) => &event.metadata,
             DomainEvent::OrderCancelled(event) => &event.metadata,
+            DomainEvent::ShippingAddressChanged(event) => &event.metadata,
+            DomainEvent::OrderHeld(event) => &event.metadata,
+            DomainEvent::OrderReleased(event) => &event.metadata,
             DomainEvent::OrderShipped(event) => &event.metadata,
             DomainEvent::OrderDelivered(event) => &event.metadata,
+            DomainEvent::ReturnRequested(event) => &event.metadata,
+            DomainEvent::ReturnApproved(event) => &event.metadata,
+            DomainEvent::OrderStatusForcefullyChanged(event) => &event.metadata,
+            DomainEvent::PreOrderActivated(event) => &event.metadata,
+            DomainEvent::WaitlistJoined(event) => &event.metadata,
+            DomainEvent::WaitlistPromoted(event) => &event.metadata,
+            DomainEvent::DigitalItemsFulfilled(event) => &event.metadata,
+            DomainEvent::FulfillmentSlaBreached(event) => &event.metadata,
+            DomainEvent::CarrierTrackingUpdated(event) => &event.metadata,
             DomainEvent::InventoryReserved(event) => &event.metadata,
             DomainEvent::InventoryReleased(event) => &event.metadata,
+            DomainEvent::CheckoutHoldExpired(event) => &event.metadata,
+            DomainEvent::ItemsRestocked(event) => &event.metadata,
+            DomainEvent::InventoryAdjusted(event) => &event.metadata,
+            DomainEvent::HighValueOrderPlaced(event) => &event.metadata,
+            DomainEvent::CustomerBecameRepeatBuyer(event) => &event.metadata,
             DomainEvent::InventoryReservationFailed(event) => &event.metadata,
             DomainEvent::ShippingFailed(event) => &event.metadata,
             DomainEvent::DeliveryFailed(event) => &event.metadata,
             DomainEvent::SagaCompensationStarted(event) => &event.metadata,
             DomainEvent::SagaCompensationCompleted(event) => &event.metadata,
+            DomainEvent::SagaTimedOut(event) => &event.metadata,
+        }
+    }
+
+    /// イベントのメタデータを変更用に取得
+    pub fn metadata_mut(&mut self) -> &mut EventMetadata {
+        match self {
+            DomainEvent::OrderConfirmed(event) => &mut event.metadata,
+            DomainEvent::OrderCancelled(event) => &mut event.metadata,
+            DomainEvent::ShippingAddressChanged(event) => &mut event.metadata,
+            DomainEvent::OrderHeld(event) => &mut event.metadata,
+            DomainEvent::OrderReleased(event) => &mut event.metadata,
+            DomainEvent::OrderShipped(event) => &mut event.metadata,
+            DomainEvent::OrderDelivered(event) => &mut event.metadata,
+            DomainEvent::ReturnRequested(event) => &mut event.metadata,
+            DomainEvent::ReturnApproved(event) => &mut event.metadata,
+            DomainEvent::OrderStatusForcefullyChanged(event) => &mut event.metadata,
+            DomainEvent::PreOrderActivated(event) => &mut event.metadata,
+            DomainEvent::WaitlistJoined(event) => &mut event.metadata,
+            DomainEvent::WaitlistPromoted(event) => &mut event.metadata,
+            DomainEvent::DigitalItemsFulfilled(event) => &mut event.metadata,
+            DomainEvent::FulfillmentSlaBreached(event) => &mut event.metadata,
+            DomainEvent::CarrierTrackingUpdated(event) => &mut event.metadata,
+            DomainEvent::InventoryReserved(event) => &mut event.metadata,
+            DomainEvent::InventoryReleased(event) => &mut event.metadata,
+            DomainEvent::CheckoutHoldExpired(event) => &mut event.metadata,
+            DomainEvent::ItemsRestocked(event) => &mut event.metadata,
+            DomainEvent::InventoryAdjusted(event) => &mut event.metadata,
+            DomainEvent::HighValueOrderPlaced(event) => &mut event.metadata,
+            DomainEvent::CustomerBecameRepeatBuyer(event) => &mut event.metadata,
+            DomainEvent::InventoryReservationFailed(event) => &mut event.metadata,
+            DomainEvent::ShippingFailed(event) => &mut event.metadata,
+            DomainEvent::DeliveryFailed(event) => &mut event.metadata,
+            DomainEvent::SagaCompensationStarted(event) => &mut event.metadata,
+            DomainEvent::SagaCompensationCompleted(event) => &mut event.metadata,
+            DomainEvent::SagaTimedOut(event) => &mut event.metadata,
         }
     }
 
@@ -110,15 +254,70 @@ impl DomainEvent {
         match self {
             DomainEvent::OrderConfirmed(_) => "OrderConfirmed",
             DomainEvent::OrderCancelled(_) => "OrderCancelled",
+            DomainEvent::ShippingAddressChanged(_) => "ShippingAddressChanged",
+            DomainEvent::OrderHeld(_) => "OrderHeld",
+            DomainEvent::OrderReleased(_) => "OrderReleased",
             DomainEvent::OrderShipped(_) => "OrderShipped",
             DomainEvent::OrderDelivered(_) => "OrderDelivered",
+            DomainEvent::ReturnRequested(_) => "ReturnRequested",
+            DomainEvent::ReturnApproved(_) => "ReturnApproved",
+            DomainEvent::OrderStatusForcefullyChanged(_) => "OrderStatusForcefullyChanged",
+            DomainEvent::PreOrderActivated(_) => "PreOrderActivated",
+            DomainEvent::WaitlistJoined(_) => "WaitlistJoined",
+            DomainEvent::WaitlistPromoted(_) => "WaitlistPromoted",
+            DomainEvent::DigitalItemsFulfilled(_) => "DigitalItemsFulfilled",
+            DomainEvent::FulfillmentSlaBreached(_) => "FulfillmentSlaBreached",
+            DomainEvent::CarrierTrackingUpdated(_) => "CarrierTrackingUpdated",
             DomainEvent::InventoryReserved(_) => "InventoryReserved",
             DomainEvent::InventoryReleased(_) => "InventoryReleased",
+            DomainEvent::CheckoutHoldExpired(_) => "CheckoutHoldExpired",
+            DomainEvent::ItemsRestocked(_) => "ItemsRestocked",
+            DomainEvent::InventoryAdjusted(_) => "InventoryAdjusted",
+            DomainEvent::HighValueOrderPlaced(_) => "HighValueOrderPlaced",
+            DomainEvent::CustomerBecameRepeatBuyer(_) => "CustomerBecameRepeatBuyer",
             DomainEvent::InventoryReservationFailed(_) => "InventoryReservationFailed",
             DomainEvent::ShippingFailed(_) => "ShippingFailed",
             DomainEvent::DeliveryFailed(_) => "DeliveryFailed",
             DomainEvent::SagaCompensationStarted(_) => "SagaCompensationStarted",
             DomainEvent::SagaCompensationCompleted(_) => "SagaCompensationCompleted",
+            DomainEvent::SagaTimedOut(_) => "SagaTimedOut",
+        }
+    }
+
+    /// イベントが属する集約のID
+    /// 注文に関するイベントは注文ID、在庫調整は書籍ID、リピーター化は顧客ID、サーガの進行はサーガIDを返す
+    /// 同じ集約のイベントを発行順に処理するためのキーとして使用する
+    pub fn aggregate_id(&self) -> String {
+        match self {
+            DomainEvent::OrderConfirmed(event) => event.order_id.to_string(),
+            DomainEvent::OrderCancelled(event) => event.order_id.to_string(),
+            DomainEvent::ShippingAddressChanged(event) => event.order_id.to_string(),
+            DomainEvent::OrderHeld(event) => event.order_id.to_string(),
+            DomainEvent::OrderReleased(event) => event.order_id.to_string(),
+            DomainEvent::OrderShipped(event) => event.order_id.to_string(),
+            DomainEvent::OrderDelivered(event) => event.order_id.to_string(),
+            DomainEvent::ReturnRequested(event) => event.order_id.to_string(),
+            DomainEvent::ReturnApproved(event) => event.order_id.to_string(),
+            DomainEvent::OrderStatusForcefullyChanged(event) => event.order_id.to_string(),
+            DomainEvent::PreOrderActivated(event) => event.order_id.to_string(),
+            DomainEvent::WaitlistJoined(event) => event.order_id.to_string(),
+            DomainEvent::WaitlistPromoted(event) => event.order_id.to_string(),
+            DomainEvent::DigitalItemsFulfilled(event) => event.order_id.to_string(),
+            DomainEvent::FulfillmentSlaBreached(event) => event.order_id.to_string(),
+            DomainEvent::CarrierTrackingUpdated(event) => event.order_id.to_string(),
+            DomainEvent::InventoryReserved(event) => event.order_id.to_string(),
+            DomainEvent::InventoryReleased(event) => event.order_id.to_string(),
+            DomainEvent::CheckoutHoldExpired(event) => event.order_id.to_string(),
+            DomainEvent::ItemsRestocked(event) => event.order_id.to_string(),
+            DomainEvent::InventoryAdjusted(event) => event.book_id.to_string(),
+            DomainEvent::HighValueOrderPlaced(event) => event.order_id.to_string(),
+            DomainEvent::CustomerBecameRepeatBuyer(event) => event.customer_id.to_string(),
+            DomainEvent::InventoryReservationFailed(event) => event.order_id.to_string(),
+            DomainEvent::ShippingFailed(event) => event.order_id.to_string(),
+            DomainEvent::DeliveryFailed(event) => event.order_id.to_string(),
+            DomainEvent::SagaCompensationStarted(event) => event.saga_id.to_string(),
+            DomainEvent::SagaCompensationCompleted(event) => event.saga_id.to_string(),
+            DomainEvent::SagaTimedOut(event) => event.order_id.to_string(),
         }
     }
 }
@@ -138,6 +337,8 @@ pub struct OrderConfirmed {
     pub total_amount: Money,
 }
 
+domain_model!(OrderConfirmed, DomainEvent, "注文が確定された（在庫予約のサーガを開始する）", related = [Order]);
+
 impl OrderConfirmed {
     /// 新しい注文確定イベントを作成
     pub fn new(
@@ -171,6 +372,8 @@ pub struct OrderCancelled {
     pub order_lines: Vec<OrderLine>,
 }
 
+domain_model!(OrderCancelled, DomainEvent, "注文がキャンセルされた", related = [Order]);
+
 impl OrderCancelled {
     /// 新しい注文キャンセルイベントを作成
     pub fn new(order_id: OrderId, customer_id: CustomerId, order_lines: Vec<OrderLine>) -> Self {
@@ -202,6 +405,135 @@ impl OrderCancelled {
     }
 }
 
+/// 配送先住所変更イベント
+/// 確定後・発送前の注文の配送先住所が変更されたときに発行する
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShippingAddressChanged {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 変更前の配送先住所
+    pub previous_address: Option<ShippingAddress>,
+    /// 変更後の配送先住所
+    pub new_address: ShippingAddress,
+    /// 変更前の配送料
+    pub previous_shipping_fee: Money,
+    /// 変更後の住所で再計算した配送料
+    pub shipping_fee: Money,
+}
+
+domain_model!(
+    ShippingAddressChanged,
+    DomainEvent,
+    "確定後（発送前）に配送先住所が変更された",
+    related = [Order, ShippingAddress]
+);
+
+impl ShippingAddressChanged {
+    /// 新しい配送先住所変更イベントを作成
+    pub fn new(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        previous_address: Option<ShippingAddress>,
+        new_address: ShippingAddress,
+        previous_shipping_fee: Money,
+        shipping_fee: Money,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            previous_address,
+            new_address,
+            previous_shipping_fee,
+            shipping_fee,
+        }
+    }
+}
+
+/// 注文保留イベント
+/// 確定後・発送前の注文を保留にしたときに発行する（解除されるまで発送しない）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderHeld {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 保留にした理由
+    pub reason: HoldReason,
+    /// 補足（管理者のメモなど）
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+domain_model!(
+    OrderHeld,
+    DomainEvent,
+    "確定後（発送前）の注文が保留にされた（解除されるまで発送しない）",
+    related = [Order, HoldReason]
+);
+
+impl OrderHeld {
+    /// 新しい注文保留イベントを作成
+    pub fn new(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        reason: HoldReason,
+        note: Option<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            reason,
+            note,
+        }
+    }
+}
+
+/// 注文の保留解除イベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderReleased {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 解除した保留の理由
+    pub reason: HoldReason,
+}
+
+domain_model!(
+    OrderReleased,
+    DomainEvent,
+    "保留中の注文が解除された（発送を再開する）",
+    related = [Order, HoldReason]
+);
+
+impl OrderReleased {
+    /// 新しい注文の保留解除イベントを作成
+    pub fn new(order_id: OrderId, customer_id: CustomerId, reason: HoldReason) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            reason,
+        }
+    }
+}
+
 /// 注文発送イベント
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderShipped {
@@ -211,8 +543,16 @@ pub struct OrderShipped {
     pub order_id: OrderId,
     /// 配送先住所
     pub shipping_address: ShippingAddress,
+    /// ギフト注文の受取人（ギフト注文の場合のみ、発送通知の宛先）
+    #[serde(default)]
+    pub recipient: Option<Recipient>,
+    /// 配達予定日（見積もりがない場合はNone）
+    #[serde(default)]
+    pub estimated_delivery_date: Option<NaiveDate>,
 }
 
+domain_model!(OrderShipped, DomainEvent, "注文が発送された", related = [Order]);
+
 impl OrderShipped {
     /// 新しい注文発送イベントを作成
     pub fn new(order_id: OrderId, shipping_address: ShippingAddress) -> Self {
@@ -222,6 +562,8 @@ impl OrderShipped {
                 .with_metadata("aggregate_id".to_string(), order_id.to_string()),
             order_id,
             shipping_address,
+            recipient: None,
+            estimated_delivery_date: None,
         }
     }
 
@@ -237,8 +579,25 @@ impl OrderShipped {
                 .with_metadata("aggregate_id".to_string(), order_id.to_string()),
             order_id,
             shipping_address,
+            recipient: None,
+            estimated_delivery_date: None,
         }
     }
+
+    /// ギフト注文の受取人を設定
+    pub fn with_recipient(mut self, recipient: Option<Recipient>) -> Self {
+        self.recipient = recipient;
+        self
+    }
+
+    /// 配達予定日を設定
+    pub fn with_estimated_delivery_date(
+        mut self,
+        estimated_delivery_date: Option<NaiveDate>,
+    ) -> Self {
+        self.estimated_delivery_date = estimated_delivery_date;
+        self
+    }
 }
 
 /// 注文配達完了イベント
@@ -248,8 +607,13 @@ pub struct OrderDelivered {
     pub metadata: EventMetadata,
     /// 注文ID
     pub order_id: OrderId,
+    /// ギフト注文の受取人（ギフト注文の場合のみ、配達完了通知の宛先）
+    #[serde(default)]
+    pub recipient: Option<Recipient>,
 }
 
+domain_model!(OrderDelivered, DomainEvent, "注文が配達完了した", related = [Order]);
+
 impl OrderDelivered {
     /// 新しい注文配達完了イベントを作成
     pub fn new(order_id: OrderId) -> Self {
@@ -258,6 +622,7 @@ impl OrderDelivered {
                 .with_metadata("aggregate_type".to_string(), "Order".to_string())
                 .with_metadata("aggregate_id".to_string(), order_id.to_string()),
             order_id,
+            recipient: None,
         }
     }
 
@@ -268,9 +633,386 @@ impl OrderDelivered {
                 .with_metadata("aggregate_type".to_string(), "Order".to_string())
                 .with_metadata("aggregate_id".to_string(), order_id.to_string()),
             order_id,
+            recipient: None,
+        }
+    }
+
+    /// ギフト注文の受取人を設定
+    pub fn with_recipient(mut self, recipient: Option<Recipient>) -> Self {
+        self.recipient = recipient;
+        self
+    }
+}
+
+/// 返品依頼イベント
+/// 配達済みの注文の返品を受け付けたときに発行する（承認するまで在庫は戻さない）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReturnRequested {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 返品の理由（顧客の申告）
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+domain_model!(
+    ReturnRequested,
+    DomainEvent,
+    "配達済みの注文の返品が依頼された（承認待ち）",
+    related = [Order]
+);
+
+impl ReturnRequested {
+    /// 新しい返品依頼イベントを作成
+    pub fn new(order_id: OrderId, customer_id: CustomerId, reason: Option<String>) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            reason,
+        }
+    }
+}
+
+/// 返品承認イベント
+/// 返品を承認したときに発行する（ReturnHandlerが返品された書籍を在庫に戻す）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReturnApproved {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 返品された注文明細のリスト
+    pub order_lines: Vec<OrderLine>,
+}
+
+domain_model!(
+    ReturnApproved,
+    DomainEvent,
+    "返品が承認された（返品された書籍を在庫に戻す）",
+    related = [Order]
+);
+
+impl ReturnApproved {
+    /// 新しい返品承認イベントを作成
+    pub fn new(order_id: OrderId, customer_id: CustomerId, order_lines: Vec<OrderLine>) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            order_lines,
+        }
+    }
+}
+
+/// 注文ステータス強制変更イベント
+/// 管理者が誤った状態で止まった注文のステータスを直接変更したときに発行する
+/// （通常の遷移のイベントは発行せず、在庫の予約・解放などのサーガも起動しない。読み取りモデルはこのイベントで追従する）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderStatusForcefullyChanged {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 変更前のステータス
+    pub from_status: String,
+    /// 変更後のステータス
+    pub to_status: String,
+    /// 変更の理由
+    pub reason: String,
+    /// 変更したオペレーター
+    pub operator: String,
+}
+
+domain_model!(
+    OrderStatusForcefullyChanged,
+    DomainEvent,
+    "管理者が注文のステータスを強制的に変更した（サーガは起動しない）",
+    related = [Order, OrderStatus]
+);
+
+impl OrderStatusForcefullyChanged {
+    /// 新しい注文ステータス強制変更イベントを作成
+    pub fn new(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        status_override: &StatusOverride,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            from_status: status_override.from_status.to_string(),
+            to_status: status_override.to_status.to_string(),
+            reason: status_override.reason.clone(),
+            operator: status_override.operator.clone(),
+        }
+    }
+}
+
+/// 予約注文有効化イベント
+/// 発売日を迎えた予約注文の在庫予約を開始するために発行される
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreOrderActivated {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 注文明細のリスト
+    pub order_lines: Vec<OrderLine>,
+    /// 合計金額
+    pub total_amount: Money,
+}
+
+domain_model!(PreOrderActivated, DomainEvent, "発売日を迎えた予約注文が有効化された", related = [Order]);
+
+impl PreOrderActivated {
+    /// 新しい予約注文有効化イベントを作成
+    pub fn new(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        order_lines: Vec<OrderLine>,
+        total_amount: Money,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            order_lines,
+            total_amount,
+        }
+    }
+}
+
+/// 順番待ち登録イベント
+/// 順番待ちが有効な書籍の在庫を超えた注文を、在庫を予約せずに順番待ちにしたときに発行される
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitlistJoined {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 順番待ちに登録した書籍ごとの位置
+    pub positions: Vec<WaitlistPosition>,
+}
+
+domain_model!(
+    WaitlistJoined,
+    DomainEvent,
+    "在庫を超えた注文が順番待ちに登録された",
+    related = [Order, Inventory]
+);
+
+impl WaitlistJoined {
+    /// 相関IDを指定して順番待ち登録イベントを作成
+    pub fn with_correlation_id(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        positions: Vec<WaitlistPosition>,
+        correlation_id: Uuid,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(correlation_id)
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            positions,
         }
     }
 }
+
+/// 順番待ち繰り上げイベント
+/// 入荷やキャンセルで在庫が空き、順番待ちの注文の在庫を予約したときに発行される
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitlistPromoted {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 注文明細のリスト
+    pub order_lines: Vec<OrderLine>,
+}
+
+domain_model!(
+    WaitlistPromoted,
+    DomainEvent,
+    "順番待ちの注文が在庫を確保して繰り上がった",
+    related = [Order, Inventory]
+);
+
+impl WaitlistPromoted {
+    /// 相関IDを指定して順番待ち繰り上げイベントを作成
+    pub fn with_correlation_id(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        order_lines: Vec<OrderLine>,
+        correlation_id: Uuid,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(correlation_id)
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            order_lines,
+        }
+    }
+}
+
+/// 電子書籍フルフィルメントイベント
+/// 注文に含まれる電子書籍のダウンロードリンクを発行したときに発行される
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigitalItemsFulfilled {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 発行したダウンロードリンクのリスト
+    pub download_links: Vec<DownloadLink>,
+    /// 電子書籍のみの注文で、注文全体のフルフィルメントが完了したかどうか
+    pub order_fulfilled: bool,
+}
+
+domain_model!(
+    DigitalItemsFulfilled,
+    DomainEvent,
+    "電子書籍のダウンロードリンクが発行された",
+    related = [Order, DownloadLink]
+);
+
+impl DigitalItemsFulfilled {
+    /// 相関IDを指定して電子書籍フルフィルメントイベントを作成
+    pub fn with_correlation_id(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        download_links: Vec<DownloadLink>,
+        order_fulfilled: bool,
+        correlation_id: Uuid,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(correlation_id)
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            download_links,
+            order_fulfilled,
+        }
+    }
+}
+
+/// フルフィルメントSLA違反イベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FulfillmentSlaBreached {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 期限を超過した区間
+    pub stage: SlaStage,
+    /// 区間の開始日時（確定日時または発送日時）
+    pub started_at: DateTime<Utc>,
+    /// 期限
+    pub deadline: DateTime<Utc>,
+    /// 違反を検出した日時
+    pub detected_at: DateTime<Utc>,
+}
+
+domain_model!(FulfillmentSlaBreached, DomainEvent, "発送・配達の期限を超過した", related = [Order]);
+
+impl FulfillmentSlaBreached {
+    /// 新しいフルフィルメントSLA違反イベントを作成
+    pub fn new(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        stage: SlaStage,
+        started_at: DateTime<Utc>,
+        deadline: DateTime<Utc>,
+        detected_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            stage,
+            started_at,
+            deadline,
+            detected_at,
+        }
+    }
+}
+
+/// 配送追跡更新イベント
+/// 配送業者のWebhookで受信した追跡情報を表す
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CarrierTrackingUpdated {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 配送業者が報告した段階
+    pub stage: TrackingStage,
+    /// 配送業者側で発生した日時
+    pub reported_at: DateTime<Utc>,
+    /// 拠点
+    pub location: Option<String>,
+    /// 詳細
+    pub description: Option<String>,
+}
+
+domain_model!(CarrierTrackingUpdated, DomainEvent, "配送業者から配送追跡情報が届いた", related = [Order]);
+
+impl CarrierTrackingUpdated {
+    /// 新しい配送追跡更新イベントを作成
+    pub fn new(
+        order_id: OrderId,
+        stage: TrackingStage,
+        reported_at: DateTime<Utc>,
+        location: Option<String>,
+        description: Option<String>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::new()
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            stage,
+            reported_at,
+            location,
+            description,
+        }
+    }
+}
+
 /// 在庫予約イベント
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InventoryReserved {
@@ -282,6 +1024,13 @@ pub struct InventoryReserved {
     pub order_lines: Vec<OrderLine>,
 }
 
+domain_model!(
+    InventoryReserved,
+    DomainEvent,
+    "注文の在庫が予約された",
+    related = [Inventory, Order]
+);
+
 impl InventoryReserved {
     /// 相関IDを指定して在庫予約イベントを作成
     pub fn with_correlation_id(
@@ -310,6 +1059,13 @@ pub struct InventoryReleased {
     pub order_lines: Vec<OrderLine>,
 }
 
+domain_model!(
+    InventoryReleased,
+    DomainEvent,
+    "予約していた在庫が解放された",
+    related = [Inventory, Order]
+);
+
 impl InventoryReleased {
     /// 相関IDを指定して在庫解放イベントを作成
     pub fn with_correlation_id(
@@ -327,6 +1083,197 @@ impl InventoryReleased {
     }
 }
 
+/// チェックアウトの仮押さえの期限切れイベント
+/// 解放したのは仮押さえしていた数量で、注文明細の数量とは異なる場合がある
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckoutHoldExpired {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 解放した仮押さえのリスト
+    pub holds: Vec<CheckoutHold>,
+}
+
+domain_model!(
+    CheckoutHoldExpired,
+    DomainEvent,
+    "期限までに確定しなかった注文の仮押さえが解放された",
+    related = [Inventory, Order]
+);
+
+impl CheckoutHoldExpired {
+    /// 相関IDを指定して仮押さえの期限切れイベントを作成
+    pub fn with_correlation_id(
+        order_id: OrderId,
+        holds: Vec<CheckoutHold>,
+        correlation_id: Uuid,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(correlation_id)
+                .with_metadata("aggregate_type".to_string(), "Inventory".to_string())
+                .with_metadata("related_order_id".to_string(), order_id.to_string()),
+            order_id,
+            holds,
+        }
+    }
+}
+
+/// 返品の再入庫イベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemsRestocked {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 在庫に戻した注文明細のリスト（物理書籍のみ）
+    pub order_lines: Vec<OrderLine>,
+}
+
+domain_model!(
+    ItemsRestocked,
+    DomainEvent,
+    "返品された書籍が在庫に戻された",
+    related = [Inventory, Order]
+);
+
+impl ItemsRestocked {
+    /// 相関IDを指定して返品の再入庫イベントを作成
+    pub fn with_correlation_id(
+        order_id: OrderId,
+        order_lines: Vec<OrderLine>,
+        correlation_id: Uuid,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(correlation_id)
+                .with_metadata("aggregate_type".to_string(), "Inventory".to_string())
+                .with_metadata("related_order_id".to_string(), order_id.to_string()),
+            order_id,
+            order_lines,
+        }
+    }
+}
+
+/// 在庫調整イベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryAdjusted {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 書籍ID
+    pub book_id: BookId,
+    /// 調整前の在庫数
+    pub previous_quantity: u32,
+    /// 調整後の在庫数
+    pub new_quantity: u32,
+    /// 差異（調整後 - 調整前）
+    pub variance: i64,
+    /// 調整理由（例: "cycle_count"）
+    pub reason: String,
+}
+
+domain_model!(
+    InventoryAdjusted,
+    DomainEvent,
+    "入荷・棚卸しなどで在庫数が調整された",
+    related = [Inventory]
+);
+
+impl InventoryAdjusted {
+    /// 相関IDを指定して在庫調整イベントを作成
+    pub fn with_correlation_id(
+        book_id: BookId,
+        previous_quantity: u32,
+        new_quantity: u32,
+        reason: String,
+        correlation_id: Uuid,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(correlation_id)
+                .with_metadata("aggregate_type".to_string(), "Inventory".to_string())
+                .with_metadata("aggregate_id".to_string(), book_id.to_string()),
+            book_id,
+            previous_quantity,
+            new_quantity,
+            variance: i64::from(new_quantity) - i64::from(previous_quantity),
+            reason,
+        }
+    }
+}
+
+/// 高額注文イベント
+/// 顧客セグメントのルールで決めた金額以上の注文が確定したときに発行される
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HighValueOrderPlaced {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 合計金額
+    pub total_amount: Money,
+    /// 高額注文とみなす金額（ルールの閾値）
+    pub threshold: Money,
+}
+
+domain_model!(HighValueOrderPlaced, DomainEvent, "金額が閾値以上の注文が確定した", related = [Order]);
+
+impl HighValueOrderPlaced {
+    /// 相関IDを指定して高額注文イベントを作成
+    pub fn with_correlation_id(
+        order_id: OrderId,
+        customer_id: CustomerId,
+        total_amount: Money,
+        threshold: Money,
+        correlation_id: Uuid,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(correlation_id)
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string()),
+            order_id,
+            customer_id,
+            total_amount,
+            threshold,
+        }
+    }
+}
+
+/// リピーター化イベント
+/// 顧客の配達完了した注文の数が顧客セグメントのルールで決めた数に達したときに1回だけ発行される
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerBecameRepeatBuyer {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// リピーターになるきっかけになった（配達完了した）注文ID
+    pub order_id: OrderId,
+    /// 配達完了した注文の数
+    pub delivered_order_count: u32,
+}
+
+domain_model!(CustomerBecameRepeatBuyer, DomainEvent, "顧客がリピーターになった", related = [Order]);
+
+impl CustomerBecameRepeatBuyer {
+    /// 相関IDを指定してリピーター化イベントを作成
+    pub fn with_correlation_id(
+        customer_id: CustomerId,
+        order_id: OrderId,
+        delivered_order_count: u32,
+        correlation_id: Uuid,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(correlation_id)
+                .with_metadata("aggregate_type".to_string(), "Customer".to_string())
+                .with_metadata("aggregate_id".to_string(), customer_id.to_string()),
+            customer_id,
+            order_id,
+            delivered_order_count,
+        }
+    }
+}
+
 // ========== 補償イベント（サーガ失敗時のロールバック用） ==========
 
 /// 在庫予約失敗イベント（補償イベント）
@@ -344,6 +1291,13 @@ pub struct InventoryReservationFailed {
     pub original_event_id: Uuid,
 }
 
+domain_model!(
+    InventoryReservationFailed,
+    DomainEvent,
+    "在庫予約に失敗した（補償イベント）",
+    related = [Inventory, Order]
+);
+
 impl InventoryReservationFailed {
     /// 新しい在庫予約失敗イベントを作成
     pub fn new(
@@ -398,6 +1352,8 @@ pub struct ShippingFailed {
     pub original_event_id: Uuid,
 }
 
+domain_model!(ShippingFailed, DomainEvent, "発送に失敗した（補償イベント）", related = [Order]);
+
 impl ShippingFailed {
     /// 新しい発送失敗イベントを作成
     pub fn new(order_id: OrderId, failure_reason: String, original_event_id: Uuid) -> Self {
@@ -444,6 +1400,8 @@ pub struct DeliveryFailed {
     pub original_event_id: Uuid,
 }
 
+domain_model!(DeliveryFailed, DomainEvent, "配達に失敗した（補償イベント）", related = [Order]);
+
 impl DeliveryFailed {
     /// 相関IDを指定して配達失敗イベントを作成
     pub fn with_correlation_id(
@@ -479,6 +1437,8 @@ pub struct SagaCompensationStarted {
     pub compensation_steps: Vec<String>,
 }
 
+domain_model!(SagaCompensationStarted, DomainEvent, "サーガの補償が開始された", related = [Order]);
+
 impl SagaCompensationStarted {
     /// 新しいサーガ補償開始イベントを作成
     pub fn new(
@@ -513,6 +1473,8 @@ pub struct SagaCompensationCompleted {
     pub compensation_result: CompensationResult,
 }
 
+domain_model!(SagaCompensationCompleted, DomainEvent, "サーガの補償が完了した", related = [Order]);
+
 impl SagaCompensationCompleted {
     /// 新しいサーガ補償完了イベントを作成
     pub fn new(
@@ -532,6 +1494,56 @@ impl SagaCompensationCompleted {
     }
 }
 
+/// サーガタイムアウトイベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SagaTimedOut {
+    /// イベントメタデータ
+    pub metadata: EventMetadata,
+    /// サーガID（注文確定時の相関ID）
+    pub saga_id: Uuid,
+    /// 注文ID
+    pub order_id: OrderId,
+    /// 顧客ID
+    pub customer_id: CustomerId,
+    /// 期限内に進まなかったステップ
+    pub stage: SagaTimeoutStage,
+    /// ステップの開始日時（確定日時または発送日時）
+    pub started_at: DateTime<Utc>,
+    /// 期限
+    pub deadline: DateTime<Utc>,
+    /// タイムアウトを検出した日時
+    pub detected_at: DateTime<Utc>,
+}
+
+domain_model!(SagaTimedOut, DomainEvent, "サーガのステップが期限内に進まなかった", related = [Order]);
+
+impl SagaTimedOut {
+    /// 新しいサーガタイムアウトイベントを作成
+    pub fn new(
+        saga_id: Uuid,
+        order_id: OrderId,
+        customer_id: CustomerId,
+        stage: SagaTimeoutStage,
+        started_at: DateTime<Utc>,
+        deadline: DateTime<Utc>,
+        detected_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            metadata: EventMetadata::with_correlation_id(saga_id)
+                .with_metadata("aggregate_type".to_string(), "Order".to_string())
+                .with_metadata("aggregate_id".to_string(), order_id.to_string())
+                .with_metadata("saga_id".to_string(), saga_id.to_string()),
+            saga_id,
+            order_id,
+            customer_id,
+            stage,
+            started_at,
+            deadline,
+            detected_at,
+        }
+    }
+}
+
 /// 補償結果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CompensationResult {
@@ -1,5 +1,13 @@
 use crate::domain::event::DomainEvent;
+use crate::domain::load_shedding::HandlerCriticality;
+use crate::domain::model::Money;
+use crate::domain::retry_policy::DeliveryGuarantee;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
 
 /// イベントハンドラーエラー
 #[derive(Debug, Clone, thiserror::Error)]
@@ -14,54 +22,985 @@ pub enum HandlerError {
     TransientError(String),
     #[error("Permanent error (not retryable): {0}")]
     PermanentError(String),
+    /// 発生箇所の情報を付加したエラー（表示は元のエラーと同じ）
+    #[error("{source}")]
+    WithContext {
+        source: Box<HandlerError>,
+        context: HandlerErrorContext,
+    },
+}
+
+/// ハンドラーエラーの発生箇所
+/// デッドレターキューで失敗の原因となったイベント・集約・処理ステップを特定するために使う
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct HandlerErrorContext {
+    /// 失敗したイベントのID
+    pub event_id: Option<Uuid>,
+    /// 失敗したイベントの種類
+    pub event_type: Option<String>,
+    /// 集約ID（注文イベントでは注文ID）
+    pub aggregate_id: Option<String>,
+    /// 失敗したハンドラー内の処理ステップ（例: "load_order"）
+    pub step: Option<String>,
+}
+
+impl HandlerError {
+    /// 失敗した処理ステップを付加する（既に付加されている場合は内側のステップを優先する）
+    pub fn at_step(self, step: &str) -> Self {
+        self.map_context(|context| {
+            context.step.get_or_insert_with(|| step.to_string());
+        })
+    }
+
+    /// 失敗したイベントの情報を付加する（既に付加されている項目は変更しない）
+    pub fn for_event(self, event: &DomainEvent) -> Self {
+        self.map_context(|context| {
+            context.event_id.get_or_insert(event.metadata().event_id);
+            context
+                .event_type
+                .get_or_insert_with(|| event.event_type().to_string());
+            context
+                .aggregate_id
+                .get_or_insert_with(|| event.aggregate_id());
+        })
+    }
+
+    /// 付加された発生箇所（付加されていない場合はNone）
+    pub fn context(&self) -> Option<&HandlerErrorContext> {
+        match self {
+            HandlerError::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// 発生箇所の情報を除いた元のエラー
+    pub fn kind(&self) -> &HandlerError {
+        match self {
+            HandlerError::WithContext { source, .. } => source.kind(),
+            error => error,
+        }
+    }
+
+    /// リトライしても成功しないエラーかどうか
+    pub fn is_permanent(&self) -> bool {
+        matches!(self.kind(), HandlerError::PermanentError(_))
+    }
+
+    /// リトライで回復する可能性があるエラーかどうか
+    pub fn is_transient(&self) -> bool {
+        matches!(self.kind(), HandlerError::TransientError(_))
+    }
+
+    fn map_context(self, update: impl FnOnce(&mut HandlerErrorContext)) -> Self {
+        let (source, mut context) = match self {
+            HandlerError::WithContext { source, context } => (source, context),
+            error => (Box::new(error), HandlerErrorContext::default()),
+        };
+        update(&mut context);
+        HandlerError::WithContext { source, context }
+    }
+}
+
+/// デッドレターキューに送られたイベント処理（管理者向けの表示用）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeadLetter {
+    pub handler_name: String,
+    pub event_type: String,
+    /// エラーメッセージ（HandlerErrorの表示と同じ）
+    pub error: String,
+    /// 失敗したイベント・集約・処理ステップ
+    pub context: HandlerErrorContext,
+    pub attempt_count: u32,
+    /// 一時的なエラーで、再処理で回復する可能性があるかどうか
+    pub retryable: bool,
+    pub dead_lettered_at: DateTime<Utc>,
+}
+
+/// 一時停止中のハンドラー宛てのイベントの扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PausedEventHandling {
+    /// メモリ上にためておき、再開時に発行順に配信する
+    #[default]
+    Buffer,
+    /// 配信せずに読み飛ばす（再開後も配信しない）
+    Skip,
+}
+
+/// 購読時に指定するイベントの絞り込み条件
+/// 条件を満たさないイベントはハンドラーに配信せず、一時停止中でもためない
+#[derive(Clone)]
+pub struct EventFilter {
+    description: String,
+    predicate: Arc<dyn Fn(&DomainEvent) -> bool + Send + Sync>,
+}
+
+impl EventFilter {
+    /// 条件を指定して作成
+    ///
+    /// # Arguments
+    /// * `description` - 購読の一覧に表示する条件の説明
+    /// * `predicate` - 配信するイベントでtrueを返す条件
+    pub fn new(
+        description: impl Into<String>,
+        predicate: impl Fn(&DomainEvent) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            description: description.into(),
+            predicate: Arc::new(predicate),
+        }
+    }
+
+    /// 合計金額が指定額以上の注文確定のみ配信する（VIP向けの対応など）
+    /// OrderConfirmed以外のイベントは絞り込まない
+    pub fn order_total_at_least(threshold: Money) -> Self {
+        Self::new(
+            format!(
+                "OrderConfirmed.total_amount >= {} {}",
+                threshold.amount(),
+                threshold.currency()
+            ),
+            move |event| match event {
+                DomainEvent::OrderConfirmed(e) => {
+                    e.total_amount.currency() == threshold.currency()
+                        && e.total_amount.amount() >= threshold.amount()
+                }
+                _ => true,
+            },
+        )
+    }
+
+    /// 条件の説明
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// イベントが条件を満たすか
+    pub fn matches(&self, event: &DomainEvent) -> bool {
+        (self.predicate)(event)
+    }
+}
+
+impl fmt::Debug for EventFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventFilter")
+            .field("description", &self.description)
+            .finish()
+    }
+}
+
+/// 購読の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "state", content = "paused_events")]
+pub enum SubscriptionState {
+    Active,
+    Paused(PausedEventHandling),
+}
+
+/// イベントバスの購読（管理者向けの表示用）
+/// ハンドラーが複数のイベントタイプを購読している場合は、イベントタイプごとに1件
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubscriptionStatus {
+    /// ハンドラー名（ハンドラーの型名。解除・一時停止の指定に使う）
+    pub handler_name: String,
+    pub event_type: String,
+    pub delivery_guarantee: DeliveryGuarantee,
+    /// 購読時に指定した重要度（重要でない購読は負荷制限中に配信を見送る）
+    pub criticality: HandlerCriticality,
+    /// 購読時に指定した絞り込み条件の説明（指定していない場合はNone）
+    pub filter: Option<String>,
+    #[serde(flatten)]
+    pub state: SubscriptionState,
+    /// 一時停止中にためているイベント数
+    pub buffered_events: usize,
+    /// 一時停止中に読み飛ばしたイベントの累計数
+    pub skipped_events: u64,
+    /// 負荷制限中に配信を見送ったイベントの累計数
+    pub shed_events: u64,
+    /// 失敗率が閾値を超えて自動で一時停止している場合の、再開を試みる日時
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_paused_until: Option<DateTime<Utc>>,
+}
+
+/// イベントの配信全体の状態（デプロイ時の一時停止、管理者向けの表示用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventDispatchStatus {
+    pub paused: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused_since: Option<DateTime<Utc>>,
+    /// 処理中のイベント数（一時停止した後に0になれば、処理を止め終えている）
+    pub in_flight_events: usize,
+    /// 一時停止中にためているイベント数（ブローカーから受信する場合、イベントはブローカーに残るため常に0）
+    pub buffered_events: usize,
 }
 
 /// イベントハンドラートレイト
 /// 特定のイベントタイプを処理するハンドラーを定義
 #[async_trait]
-pub trait EventHandler<E>: Send + Sync {
-    async fn handle(&self, event: E) -> Result<(), HandlerError>;
+pub trait EventHandler<E>: Send + Sync {
+    async fn handle(&self, event: E) -> Result<(), HandlerError>;
+}
+
+/// 型消去されたイベントハンドラー
+/// 異なるイベントタイプのハンドラーを統一的に扱うため
+#[async_trait]
+pub trait DynEventHandler: Send + Sync {
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError>;
+    fn can_handle(&self, event: &DomainEvent) -> bool;
+    fn handler_name(&self) -> &str;
+    fn supports_schema_version(&self, version: u32) -> bool;
+}
+
+/// OrderConfirmed用のハンドラーラッパー
+pub struct OrderConfirmedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderConfirmed>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> OrderConfirmedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderConfirmed>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "OrderConfirmedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for OrderConfirmedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderConfirmed>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::OrderConfirmed(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::OrderConfirmed(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        // OrderConfirmed supports versions 1 and above
+        version >= 1
+    }
+}
+
+/// OrderCancelled用のハンドラーラッパー
+pub struct OrderCancelledHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderCancelled>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> OrderCancelledHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderCancelled>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "OrderCancelledHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for OrderCancelledHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderCancelled>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::OrderCancelled(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::OrderCancelled(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// OrderShipped用のハンドラーラッパー
+pub struct OrderShippedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderShipped>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> OrderShippedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderShipped>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "OrderShippedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for OrderShippedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderShipped>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::OrderShipped(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::OrderShipped(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// OrderDelivered用のハンドラーラッパー
+pub struct OrderDeliveredHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderDelivered>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> OrderDeliveredHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderDelivered>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "OrderDeliveredHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for OrderDeliveredHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderDelivered>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::OrderDelivered(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::OrderDelivered(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// PreOrderActivated用のハンドラーラッパー
+pub struct PreOrderActivatedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::PreOrderActivated>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> PreOrderActivatedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::PreOrderActivated>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "PreOrderActivatedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for PreOrderActivatedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::PreOrderActivated>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::PreOrderActivated(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::PreOrderActivated(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// WaitlistJoined用のハンドラーラッパー
+pub struct WaitlistJoinedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::WaitlistJoined>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> WaitlistJoinedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::WaitlistJoined>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "WaitlistJoinedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for WaitlistJoinedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::WaitlistJoined>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::WaitlistJoined(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::WaitlistJoined(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// WaitlistPromoted用のハンドラーラッパー
+pub struct WaitlistPromotedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::WaitlistPromoted>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> WaitlistPromotedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::WaitlistPromoted>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "WaitlistPromotedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for WaitlistPromotedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::WaitlistPromoted>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::WaitlistPromoted(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::WaitlistPromoted(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// DigitalItemsFulfilled用のハンドラーラッパー
+pub struct DigitalItemsFulfilledHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::DigitalItemsFulfilled>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> DigitalItemsFulfilledHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::DigitalItemsFulfilled>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "DigitalItemsFulfilledHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for DigitalItemsFulfilledHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::DigitalItemsFulfilled>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::DigitalItemsFulfilled(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::DigitalItemsFulfilled(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// FulfillmentSlaBreached用のハンドラーラッパー
+pub struct FulfillmentSlaBreachedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::FulfillmentSlaBreached>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> FulfillmentSlaBreachedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::FulfillmentSlaBreached>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "FulfillmentSlaBreachedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for FulfillmentSlaBreachedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::FulfillmentSlaBreached>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::FulfillmentSlaBreached(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::FulfillmentSlaBreached(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// CarrierTrackingUpdated用のハンドラーラッパー
+pub struct CarrierTrackingUpdatedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::CarrierTrackingUpdated>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> CarrierTrackingUpdatedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::CarrierTrackingUpdated>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "CarrierTrackingUpdatedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for CarrierTrackingUpdatedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::CarrierTrackingUpdated>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::CarrierTrackingUpdated(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::CarrierTrackingUpdated(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// ShippingAddressChanged用のハンドラーラッパー
+pub struct ShippingAddressChangedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ShippingAddressChanged>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> ShippingAddressChangedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ShippingAddressChanged>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "ShippingAddressChangedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for ShippingAddressChangedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ShippingAddressChanged>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::ShippingAddressChanged(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::ShippingAddressChanged(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// OrderHeld用のハンドラーラッパー
+pub struct OrderHeldHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderHeld>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> OrderHeldHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderHeld>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "OrderHeldHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for OrderHeldHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderHeld>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::OrderHeld(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::OrderHeld(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// OrderReleased用のハンドラーラッパー
+pub struct OrderReleasedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderReleased>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> OrderReleasedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderReleased>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "OrderReleasedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for OrderReleasedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::OrderReleased>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::OrderReleased(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::OrderReleased(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// ReturnRequested用のハンドラーラッパー
+pub struct ReturnRequestedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ReturnRequested>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> ReturnRequestedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ReturnRequested>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "ReturnRequestedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for ReturnRequestedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ReturnRequested>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::ReturnRequested(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::ReturnRequested(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+/// ReturnApproved用のハンドラーラッパー
+pub struct ReturnApprovedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ReturnApproved>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> ReturnApprovedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ReturnApproved>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "ReturnApprovedHandler".to_string(),
+        }
+    }
 }
 
-/// 型消去されたイベントハンドラー
-/// 異なるイベントタイプのハンドラーを統一的に扱うため
 #[async_trait]
-pub trait DynEventHandler: Send + Sync {
-    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError>;
-    fn can_handle(&self, event: &DomainEvent) -> bool;
-    fn handler_name(&self) -> &str;
-    fn supports_schema_version(&self, version: u32) -> bool;
+impl<H> DynEventHandler for ReturnApprovedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ReturnApproved>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::ReturnApproved(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::ReturnApproved(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
 }
 
-/// OrderConfirmed用のハンドラーラッパー
-pub struct OrderConfirmedHandlerWrapper<H>
+/// OrderStatusForcefullyChanged用のハンドラーラッパー
+pub struct OrderStatusForcefullyChangedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderConfirmed>,
+    H: EventHandler<crate::domain::event::OrderStatusForcefullyChanged>,
 {
     handler: H,
     name: String,
 }
 
-impl<H> OrderConfirmedHandlerWrapper<H>
+impl<H> OrderStatusForcefullyChangedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderConfirmed>,
+    H: EventHandler<crate::domain::event::OrderStatusForcefullyChanged>,
 {
     pub fn new(handler: H) -> Self {
         Self {
             handler,
-            name: "OrderConfirmedHandler".to_string(),
+            name: "OrderStatusForcefullyChangedHandler".to_string(),
         }
     }
 }
 
 #[async_trait]
-impl<H> DynEventHandler for OrderConfirmedHandlerWrapper<H>
+impl<H> DynEventHandler for OrderStatusForcefullyChangedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderConfirmed>,
+    H: EventHandler<crate::domain::event::OrderStatusForcefullyChanged>,
 {
     async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
         match event {
-            DomainEvent::OrderConfirmed(e) => self.handler.handle(e.clone()).await,
+            DomainEvent::OrderStatusForcefullyChanged(e) => self.handler.handle(e.clone()).await,
             _ => Err(HandlerError::ProcessingFailed(
                 "Event type mismatch".to_string(),
             )),
@@ -69,7 +1008,7 @@ where
     }
 
     fn can_handle(&self, event: &DomainEvent) -> bool {
-        matches!(event, DomainEvent::OrderConfirmed(_))
+        matches!(event, DomainEvent::OrderStatusForcefullyChanged(_))
     }
 
     fn handler_name(&self) -> &str {
@@ -77,40 +1016,39 @@ where
     }
 
     fn supports_schema_version(&self, version: u32) -> bool {
-        // OrderConfirmed supports versions 1 and above
         version >= 1
     }
 }
 
-/// OrderCancelled用のハンドラーラッパー
-pub struct OrderCancelledHandlerWrapper<H>
+/// InventoryReserved用のハンドラーラッパー
+pub struct InventoryReservedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderCancelled>,
+    H: EventHandler<crate::domain::event::InventoryReserved>,
 {
     handler: H,
     name: String,
 }
 
-impl<H> OrderCancelledHandlerWrapper<H>
+impl<H> InventoryReservedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderCancelled>,
+    H: EventHandler<crate::domain::event::InventoryReserved>,
 {
     pub fn new(handler: H) -> Self {
         Self {
             handler,
-            name: "OrderCancelledHandler".to_string(),
+            name: "InventoryReservedHandler".to_string(),
         }
     }
 }
 
 #[async_trait]
-impl<H> DynEventHandler for OrderCancelledHandlerWrapper<H>
+impl<H> DynEventHandler for InventoryReservedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderCancelled>,
+    H: EventHandler<crate::domain::event::InventoryReserved>,
 {
     async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
         match event {
-            DomainEvent::OrderCancelled(e) => self.handler.handle(e.clone()).await,
+            DomainEvent::InventoryReserved(e) => self.handler.handle(e.clone()).await,
             _ => Err(HandlerError::ProcessingFailed(
                 "Event type mismatch".to_string(),
             )),
@@ -118,7 +1056,7 @@ where
     }
 
     fn can_handle(&self, event: &DomainEvent) -> bool {
-        matches!(event, DomainEvent::OrderCancelled(_))
+        matches!(event, DomainEvent::InventoryReserved(_))
     }
 
     fn handler_name(&self) -> &str {
@@ -130,35 +1068,35 @@ where
     }
 }
 
-/// OrderShipped用のハンドラーラッパー
-pub struct OrderShippedHandlerWrapper<H>
+/// InventoryAdjusted用のハンドラーラッパー
+pub struct InventoryAdjustedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderShipped>,
+    H: EventHandler<crate::domain::event::InventoryAdjusted>,
 {
     handler: H,
     name: String,
 }
 
-impl<H> OrderShippedHandlerWrapper<H>
+impl<H> InventoryAdjustedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderShipped>,
+    H: EventHandler<crate::domain::event::InventoryAdjusted>,
 {
     pub fn new(handler: H) -> Self {
         Self {
             handler,
-            name: "OrderShippedHandler".to_string(),
+            name: "InventoryAdjustedHandler".to_string(),
         }
     }
 }
 
 #[async_trait]
-impl<H> DynEventHandler for OrderShippedHandlerWrapper<H>
+impl<H> DynEventHandler for InventoryAdjustedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderShipped>,
+    H: EventHandler<crate::domain::event::InventoryAdjusted>,
 {
     async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
         match event {
-            DomainEvent::OrderShipped(e) => self.handler.handle(e.clone()).await,
+            DomainEvent::InventoryAdjusted(e) => self.handler.handle(e.clone()).await,
             _ => Err(HandlerError::ProcessingFailed(
                 "Event type mismatch".to_string(),
             )),
@@ -166,7 +1104,7 @@ where
     }
 
     fn can_handle(&self, event: &DomainEvent) -> bool {
-        matches!(event, DomainEvent::OrderShipped(_))
+        matches!(event, DomainEvent::InventoryAdjusted(_))
     }
 
     fn handler_name(&self) -> &str {
@@ -178,35 +1116,35 @@ where
     }
 }
 
-/// OrderDelivered用のハンドラーラッパー
-pub struct OrderDeliveredHandlerWrapper<H>
+/// HighValueOrderPlaced用のハンドラーラッパー
+pub struct HighValueOrderPlacedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderDelivered>,
+    H: EventHandler<crate::domain::event::HighValueOrderPlaced>,
 {
     handler: H,
     name: String,
 }
 
-impl<H> OrderDeliveredHandlerWrapper<H>
+impl<H> HighValueOrderPlacedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderDelivered>,
+    H: EventHandler<crate::domain::event::HighValueOrderPlaced>,
 {
     pub fn new(handler: H) -> Self {
         Self {
             handler,
-            name: "OrderDeliveredHandler".to_string(),
+            name: "HighValueOrderPlacedHandler".to_string(),
         }
     }
 }
 
 #[async_trait]
-impl<H> DynEventHandler for OrderDeliveredHandlerWrapper<H>
+impl<H> DynEventHandler for HighValueOrderPlacedHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::OrderDelivered>,
+    H: EventHandler<crate::domain::event::HighValueOrderPlaced>,
 {
     async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
         match event {
-            DomainEvent::OrderDelivered(e) => self.handler.handle(e.clone()).await,
+            DomainEvent::HighValueOrderPlaced(e) => self.handler.handle(e.clone()).await,
             _ => Err(HandlerError::ProcessingFailed(
                 "Event type mismatch".to_string(),
             )),
@@ -214,7 +1152,7 @@ where
     }
 
     fn can_handle(&self, event: &DomainEvent) -> bool {
-        matches!(event, DomainEvent::OrderDelivered(_))
+        matches!(event, DomainEvent::HighValueOrderPlaced(_))
     }
 
     fn handler_name(&self) -> &str {
@@ -226,35 +1164,35 @@ where
     }
 }
 
-/// InventoryReserved用のハンドラーラッパー
-pub struct InventoryReservedHandlerWrapper<H>
+/// CustomerBecameRepeatBuyer用のハンドラーラッパー
+pub struct CustomerBecameRepeatBuyerHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::InventoryReserved>,
+    H: EventHandler<crate::domain::event::CustomerBecameRepeatBuyer>,
 {
     handler: H,
     name: String,
 }
 
-impl<H> InventoryReservedHandlerWrapper<H>
+impl<H> CustomerBecameRepeatBuyerHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::InventoryReserved>,
+    H: EventHandler<crate::domain::event::CustomerBecameRepeatBuyer>,
 {
     pub fn new(handler: H) -> Self {
         Self {
             handler,
-            name: "InventoryReservedHandler".to_string(),
+            name: "CustomerBecameRepeatBuyerHandler".to_string(),
         }
     }
 }
 
 #[async_trait]
-impl<H> DynEventHandler for InventoryReservedHandlerWrapper<H>
+impl<H> DynEventHandler for CustomerBecameRepeatBuyerHandlerWrapper<H>
 where
-    H: EventHandler<crate::domain::event::InventoryReserved>,
+    H: EventHandler<crate::domain::event::CustomerBecameRepeatBuyer>,
 {
     async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
         match event {
-            DomainEvent::InventoryReserved(e) => self.handler.handle(e.clone()).await,
+            DomainEvent::CustomerBecameRepeatBuyer(e) => self.handler.handle(e.clone()).await,
             _ => Err(HandlerError::ProcessingFailed(
                 "Event type mismatch".to_string(),
             )),
@@ -262,7 +1200,7 @@ where
     }
 
     fn can_handle(&self, event: &DomainEvent) -> bool {
-        matches!(event, DomainEvent::InventoryReserved(_))
+        matches!(event, DomainEvent::CustomerBecameRepeatBuyer(_))
     }
 
     fn handler_name(&self) -> &str {
@@ -287,6 +1225,12 @@ impl<H> InventoryReleasedHandlerWrapper<H>
 where
     H: EventHandler<crate::domain::event::InventoryReleased>,
 {
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "InventoryReleasedHandler".to_string(),
+        }
+    }
 }
 
 #[async_trait]
@@ -318,6 +1262,104 @@ where
 
 // ========== 補償イベント用のハンドラーラッパー ==========
 
+/// CheckoutHoldExpired用のハンドラーラッパー
+pub struct CheckoutHoldExpiredHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::CheckoutHoldExpired>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> CheckoutHoldExpiredHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::CheckoutHoldExpired>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "CheckoutHoldExpiredHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for CheckoutHoldExpiredHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::CheckoutHoldExpired>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::CheckoutHoldExpired(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::CheckoutHoldExpired(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
+// ========== 補償イベント用のハンドラーラッパー ==========
+
+/// ItemsRestocked用のハンドラーラッパー
+pub struct ItemsRestockedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ItemsRestocked>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> ItemsRestockedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ItemsRestocked>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "ItemsRestockedHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for ItemsRestockedHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::ItemsRestocked>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::ItemsRestocked(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::ItemsRestocked(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
+
 /// InventoryReservationFailed用のハンドラーラッパー
 pub struct InventoryReservationFailedHandlerWrapper<H>
 where
@@ -557,3 +1599,51 @@ where
         version >= 1
     }
 }
+
+/// SagaTimedOut用のハンドラーラッパー
+pub struct SagaTimedOutHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::SagaTimedOut>,
+{
+    handler: H,
+    name: String,
+}
+
+impl<H> SagaTimedOutHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::SagaTimedOut>,
+{
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            name: "SagaTimedOutHandler".to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<H> DynEventHandler for SagaTimedOutHandlerWrapper<H>
+where
+    H: EventHandler<crate::domain::event::SagaTimedOut>,
+{
+    async fn handle_event(&self, event: &DomainEvent) -> Result<(), HandlerError> {
+        match event {
+            DomainEvent::SagaTimedOut(e) => self.handler.handle(e.clone()).await,
+            _ => Err(HandlerError::ProcessingFailed(
+                "Event type mismatch".to_string(),
+            )),
+        }
+    }
+
+    fn can_handle(&self, event: &DomainEvent) -> bool {
+        matches!(event, DomainEvent::SagaTimedOut(_))
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports_schema_version(&self, version: u32) -> bool {
+        version >= 1
+    }
+}
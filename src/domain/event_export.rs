@@ -0,0 +1,497 @@
+use crate::domain::error::DomainError;
+use crate::domain::event::DomainEvent;
+use crate::domain::port::{EventJournal, Logger, ObjectStorageError, ObjectStoragePort};
+use crate::domain::serialization::EventSerializer;
+use crate::domain::task_supervisor::SupervisedTask;
+use async_trait::async_trait;
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// 1つのチャンク（オブジェクト）に書き出すイベント数のデフォルト値
+pub const DEFAULT_EXPORT_CHUNK_SIZE: usize = 1000;
+
+/// イベントジャーナルに記録されたイベント
+/// 発行されたドメインイベントをシリアライズした形で、記録順の位置とともに保持する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournaledEvent {
+    /// ジャーナル内の記録順の位置（1から始まる）
+    pub position: u64,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub occurred_at: DateTime<Utc>,
+    /// シリアライズしたイベント（1行のJSON）
+    pub payload: String,
+}
+
+/// エクスポートするイベントの期間（発生日時が from 以上 to 未満）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventExportRange {
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+}
+
+impl EventExportRange {
+    /// 期間を作成する（from が to より前でない場合はエラー）
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Self, DomainError> {
+        if from >= to {
+            return Err(DomainError::InvalidValue(format!(
+                "エクスポートの期間の開始は終了より前である必要があります（{} 〜 {}）",
+                from.to_rfc3339(),
+                to.to_rfc3339()
+            )));
+        }
+        Ok(Self { from, to })
+    }
+
+    /// 指定日時の前日（UTCの0時から24時間）の期間（夜間エクスポート用）
+    pub fn previous_day(now: DateTime<Utc>) -> Self {
+        let to = now.duration_trunc(TimeDelta::days(1)).unwrap_or(now);
+        Self {
+            from: to - TimeDelta::days(1),
+            to,
+        }
+    }
+
+    pub fn from(&self) -> DateTime<Utc> {
+        self.from
+    }
+
+    pub fn to(&self) -> DateTime<Utc> {
+        self.to
+    }
+
+    /// 期間のオブジェクトを置くキーの接頭辞（同じ期間の再実行は同じ場所を再開する）
+    pub fn key_prefix(&self) -> String {
+        format!(
+            "events/{}_{}",
+            self.from.format("%Y%m%dT%H%M%S%.fZ"),
+            self.to.format("%Y%m%dT%H%M%S%.fZ")
+        )
+    }
+
+    fn manifest_key(&self) -> String {
+        manifest_key(&self.key_prefix())
+    }
+
+    fn chunk_key(&self, index: usize) -> String {
+        format!("{}/part-{:05}.ndjson", self.key_prefix(), index)
+    }
+}
+
+/// アーカイブ（期間のオブジェクトを置くキーの接頭辞）のマニフェストのキー
+fn manifest_key(prefix: &str) -> String {
+    format!("{}/manifest.json", prefix)
+}
+
+/// 書き出したチャンク（改行区切りのイベントのオブジェクト）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportChunk {
+    pub key: String,
+    pub first_position: u64,
+    pub last_position: u64,
+    pub event_count: usize,
+}
+
+/// エクスポートのマニフェスト
+/// チャンクごとに更新して保存し、中断したエクスポートは最後に書き出した位置から再開する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportManifest {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub chunks: Vec<ExportChunk>,
+    pub total_events: usize,
+    /// 書き出した最後のイベントのジャーナル内の位置（再開位置）
+    pub last_position: u64,
+    /// 期間の終了を過ぎてすべてのイベントを書き出したか（未完了の場合は次回の実行で続きを書き出す）
+    pub completed: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExportManifest {
+    fn new(range: &EventExportRange, now: DateTime<Utc>) -> Self {
+        Self {
+            from: range.from,
+            to: range.to,
+            chunks: Vec::new(),
+            total_events: 0,
+            last_position: 0,
+            completed: false,
+            updated_at: now,
+        }
+    }
+}
+
+/// イベントのエクスポートエラー
+#[derive(Debug, thiserror::Error)]
+pub enum EventExportError {
+    #[error("Event journal read failed: {0}")]
+    JournalFailed(String),
+    #[error(transparent)]
+    StorageFailed(#[from] ObjectStorageError),
+    #[error("Invalid export manifest {key}: {message}")]
+    InvalidManifest { key: String, message: String },
+}
+
+/// イベントのエクスポート
+/// 期間内のイベントをジャーナルからチャンクごとに読み出し、改行区切りのJSON（NDJSON）として
+/// オブジェクトストレージに書き出す（オフライン分析用）
+#[derive(Clone)]
+pub struct EventExporter {
+    journal: Arc<dyn EventJournal>,
+    storage: Arc<dyn ObjectStoragePort>,
+    chunk_size: usize,
+}
+
+impl EventExporter {
+    pub fn new(journal: Arc<dyn EventJournal>, storage: Arc<dyn ObjectStoragePort>) -> Self {
+        Self {
+            journal,
+            storage,
+            chunk_size: DEFAULT_EXPORT_CHUNK_SIZE,
+        }
+    }
+
+    /// 1つのチャンクに書き出すイベント数を設定
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// 期間内のイベントを書き出す
+    /// 既にマニフェストがある期間は、最後に書き出した位置から続きを書き出す（完了済みの場合は何もしない）
+    ///
+    /// # Arguments
+    /// * `range` - エクスポートする期間
+    /// * `now` - 現在日時（期間の終了を過ぎている場合のみ完了とする）
+    ///
+    /// # Returns
+    /// * `Ok(ExportManifest)` - 書き出し後のマニフェスト
+    /// * `Err(EventExportError)` - ジャーナルの読み出し・ストレージへの書き込みの失敗
+    pub async fn export(
+        &self,
+        range: &EventExportRange,
+        now: DateTime<Utc>,
+    ) -> Result<ExportManifest, EventExportError> {
+        let mut manifest = self.load_manifest(range, now).await?;
+        if manifest.completed {
+            return Ok(manifest);
+        }
+
+        loop {
+            let events = self
+                .journal
+                .read_range(range.from, range.to, manifest.last_position, self.chunk_size)
+                .await
+                .map_err(|e| EventExportError::JournalFailed(e.to_string()))?;
+            let (Some(first), Some(last)) = (events.first(), events.last()) else {
+                break;
+            };
+
+            let key = range.chunk_key(manifest.chunks.len());
+            let mut body = String::new();
+            for event in &events {
+                body.push_str(&event.payload);
+                body.push('\n');
+            }
+            self.storage.put_object(&key, body.into_bytes()).await?;
+
+            manifest.chunks.push(ExportChunk {
+                key,
+                first_position: first.position,
+                last_position: last.position,
+                event_count: events.len(),
+            });
+            manifest.total_events += events.len();
+            manifest.last_position = last.position;
+            manifest.updated_at = now;
+            self.save_manifest(range, &manifest).await?;
+
+            if events.len() < self.chunk_size {
+                break;
+            }
+        }
+
+        manifest.completed = range.to <= now;
+        manifest.updated_at = now;
+        self.save_manifest(range, &manifest).await?;
+        Ok(manifest)
+    }
+
+    async fn load_manifest(
+        &self,
+        range: &EventExportRange,
+        now: DateTime<Utc>,
+    ) -> Result<ExportManifest, EventExportError> {
+        let key = range.manifest_key();
+        match self.storage.get_object(&key).await? {
+            Some(body) => {
+                serde_json::from_slice(&body).map_err(|e| EventExportError::InvalidManifest {
+                    key,
+                    message: e.to_string(),
+                })
+            }
+            None => Ok(ExportManifest::new(range, now)),
+        }
+    }
+
+    async fn save_manifest(
+        &self,
+        range: &EventExportRange,
+        manifest: &ExportManifest,
+    ) -> Result<(), EventExportError> {
+        let key = range.manifest_key();
+        let body = serde_json::to_vec_pretty(manifest).map_err(|e| {
+            EventExportError::InvalidManifest {
+                key: key.clone(),
+                message: e.to_string(),
+            }
+        })?;
+        self.storage.put_object(&key, body).await?;
+        Ok(())
+    }
+}
+
+/// イベントの取り込みエラー
+#[derive(Debug, thiserror::Error)]
+pub enum EventImportError {
+    #[error("Export archive not found: {0}")]
+    ArchiveNotFound(String),
+    #[error("Invalid export archive {key}: {message}")]
+    InvalidArchive { key: String, message: String },
+    #[error(transparent)]
+    StorageFailed(#[from] ObjectStorageError),
+    #[error("Event journal write failed: {0}")]
+    JournalFailed(String),
+}
+
+/// イベントの取り込み結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventImportSummary {
+    pub archive: String,
+    /// アーカイブに含まれるイベント数
+    pub total_events: usize,
+    /// 新しく記録したイベント数
+    pub imported: usize,
+    /// 既に記録されていたため読み飛ばしたイベント数（イベントIDで判定）
+    pub skipped_duplicates: usize,
+    /// エクスポート元で期間の書き出しが完了していたか
+    pub completed: bool,
+}
+
+/// エクスポートしたアーカイブからのイベントの取り込み
+/// 別の環境で書き出したアーカイブを検証してからジャーナルに記録する（デバッグ用の環境の複製）
+#[derive(Clone)]
+pub struct EventImporter {
+    journal: Arc<dyn EventJournal>,
+    storage: Arc<dyn ObjectStoragePort>,
+}
+
+impl EventImporter {
+    pub fn new(journal: Arc<dyn EventJournal>, storage: Arc<dyn ObjectStoragePort>) -> Self {
+        Self { journal, storage }
+    }
+
+    /// アーカイブのイベントを記録順に取り込む
+    /// すべてのチャンクを検証してから記録するため、不正なアーカイブからは1件も取り込まない
+    ///
+    /// # Arguments
+    /// * `archive` - アーカイブのキーの接頭辞（例: events/20240114T000000Z_20240115T000000Z）
+    ///
+    /// # Returns
+    /// * `Ok(EventImportSummary)` - 取り込み結果（記録済みのイベントは読み飛ばす）
+    /// * `Err(EventImportError)` - アーカイブがない・不正、またはジャーナルへの記録の失敗
+    pub async fn import(&self, archive: &str) -> Result<EventImportSummary, EventImportError> {
+        let archive = archive.trim_end_matches('/');
+        if archive.is_empty() {
+            return Err(EventImportError::InvalidArchive {
+                key: archive.to_string(),
+                message: "アーカイブを指定してください".to_string(),
+            });
+        }
+
+        let key = manifest_key(archive);
+        let body = self
+            .storage
+            .get_object(&key)
+            .await?
+            .ok_or_else(|| EventImportError::ArchiveNotFound(archive.to_string()))?;
+        let manifest: ExportManifest =
+            serde_json::from_slice(&body).map_err(|e| EventImportError::InvalidArchive {
+                key,
+                message: e.to_string(),
+            })?;
+
+        let mut events = Vec::with_capacity(manifest.total_events);
+        for chunk in &manifest.chunks {
+            events.extend(self.read_chunk(archive, &manifest, chunk).await?);
+        }
+        if events.len() != manifest.total_events {
+            return Err(EventImportError::InvalidArchive {
+                key: manifest_key(archive),
+                message: format!(
+                    "イベント数がマニフェストと一致しません（マニフェスト: {}、チャンク: {}）",
+                    manifest.total_events,
+                    events.len()
+                ),
+            });
+        }
+
+        let mut imported = 0;
+        for event in &events {
+            let appended = self
+                .journal
+                .append(event)
+                .await
+                .map_err(|e| EventImportError::JournalFailed(e.to_string()))?;
+            if appended {
+                imported += 1;
+            }
+        }
+
+        Ok(EventImportSummary {
+            archive: archive.to_string(),
+            total_events: events.len(),
+            imported,
+            skipped_duplicates: events.len() - imported,
+            completed: manifest.completed,
+        })
+    }
+
+    /// チャンクを読み出し、件数と各イベントの発生日時がマニフェストと一致することを検証する
+    async fn read_chunk(
+        &self,
+        archive: &str,
+        manifest: &ExportManifest,
+        chunk: &ExportChunk,
+    ) -> Result<Vec<DomainEvent>, EventImportError> {
+        let invalid = |message: String| EventImportError::InvalidArchive {
+            key: chunk.key.clone(),
+            message,
+        };
+        // マニフェストに書かれたキーでアーカイブの外のオブジェクトを読まない
+        if !chunk.key.starts_with(&format!("{}/", archive)) {
+            return Err(invalid("アーカイブの外のチャンクは読み込めません".to_string()));
+        }
+        let body = self
+            .storage
+            .get_object(&chunk.key)
+            .await?
+            .ok_or_else(|| invalid("チャンクがありません".to_string()))?;
+        let body = String::from_utf8(body).map_err(|e| invalid(e.to_string()))?;
+
+        let serializer = EventSerializer::new();
+        let mut events = Vec::with_capacity(chunk.event_count);
+        for (index, line) in body.lines().filter(|line| !line.is_empty()).enumerate() {
+            let event = serializer
+                .deserialize_event(line)
+                .map_err(|e| invalid(format!("{}件目: {}", index + 1, e)))?;
+            let occurred_at = event.metadata().occurred_at;
+            if occurred_at < manifest.from || occurred_at >= manifest.to {
+                return Err(invalid(format!(
+                    "{}件目の発生日時 {} が期間外です",
+                    index + 1,
+                    occurred_at.to_rfc3339()
+                )));
+            }
+            events.push(event);
+        }
+        if events.len() != chunk.event_count {
+            return Err(invalid(format!(
+                "イベント数がマニフェストと一致しません（マニフェスト: {}、チャンク: {}）",
+                chunk.event_count,
+                events.len()
+            )));
+        }
+        Ok(events)
+    }
+}
+
+/// 夜間のイベントエクスポートジョブ
+/// 定期的に前日分のイベントを書き出す。完了済みの期間は書き出さないため、
+/// 再起動などで実行が途切れても次の実行で中断した位置から再開する
+pub struct EventExportJob {
+    exporter: EventExporter,
+    logger: Arc<dyn Logger>,
+}
+
+impl EventExportJob {
+    pub fn new(exporter: EventExporter, logger: Arc<dyn Logger>) -> Self {
+        Self { exporter, logger }
+    }
+
+    /// ジョブをバックグラウンドで起動
+    pub fn spawn(self, check_interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(check_interval);
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// 指定日時の前日分のイベントを書き出す
+    pub async fn run(&self, now: DateTime<Utc>) -> Option<ExportManifest> {
+        let range = EventExportRange::previous_day(now);
+        match self.exporter.export(&range, now).await {
+            Ok(manifest) => {
+                let mut context = HashMap::new();
+                context.insert("prefix".to_string(), range.key_prefix());
+                context.insert("total_events".to_string(), manifest.total_events.to_string());
+                self.logger.debug(
+                    "EventExportJob",
+                    "Events exported to object storage",
+                    None,
+                    Some(context),
+                );
+                Some(manifest)
+            }
+            Err(e) => {
+                let mut context = HashMap::new();
+                context.insert("prefix".to_string(), range.key_prefix());
+                context.insert("error".to_string(), e.to_string());
+                self.logger.error(
+                    "EventExportJob",
+                    "Failed to export events",
+                    None,
+                    Some(context),
+                );
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl SupervisedTask for EventExportJob {
+    async fn run_once(&self) {
+        self.run(Utc::now()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_validation_and_previous_day() {
+        let now = DateTime::parse_from_rfc3339("2025-03-02T05:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert!(EventExportRange::new(now, now).is_err());
+
+        let range = EventExportRange::previous_day(now);
+        assert_eq!(range.from().to_rfc3339(), "2025-03-01T00:00:00+00:00");
+        assert_eq!(range.to().to_rfc3339(), "2025-03-02T00:00:00+00:00");
+        assert_eq!(range.key_prefix(), "events/20250301T000000Z_20250302T000000Z");
+        assert_eq!(
+            range.chunk_key(3),
+            "events/20250301T000000Z_20250302T000000Z/part-00003.ndjson"
+        );
+    }
+}
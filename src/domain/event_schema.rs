@@ -0,0 +1,634 @@
+use crate::domain::event::EVENT_TYPES;
+use serde_json::{json, Map, Value};
+
+/// 現在発行しているイベントのスキーマバージョン（EventMetadata::event_version）
+pub const CURRENT_EVENT_VERSION: u32 = 1;
+
+/// イベントタイプ・バージョンごとのペイロードのJSONスキーマ
+/// EventSerializerの形式（event_typeとevent_dataの封筒）のメッセージ全体を表す
+///
+/// スキーマはJSON Schemaのうち type・properties・required・items のみを使う
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventSchema {
+    pub event_type: String,
+    pub version: u32,
+    pub schema: Value,
+}
+
+impl EventSchema {
+    /// スキーマレジストリの登録先（サブジェクト）の名前
+    /// トピックとイベントタイプごとに分け、同じサブジェクトの中でバージョンの互換性を検証する
+    pub fn subject(&self, topic: &str) -> String {
+        format!("{}-{}", topic, self.event_type)
+    }
+
+    /// メッセージがスキーマに適合するか検証する
+    ///
+    /// # Returns
+    /// * `Ok(())` - 適合する
+    /// * `Err(Vec<String>)` - 適合しない箇所（JSONポインターと理由）
+    pub fn validate(&self, message: &Value) -> Result<(), Vec<String>> {
+        let mut violations = Vec::new();
+        validate_value(&self.schema, message, "", &mut violations);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// 以前のスキーマで作られたコンシューマーが、このスキーマのメッセージを読めるか検証する
+    /// 以前の必須フィールドをなくしたり、型を変えたりする変更は互換性がない（フィールドの追加はよい）
+    ///
+    /// # Arguments
+    /// * `previous` - 登録済みの最新のスキーマ
+    ///
+    /// # Returns
+    /// * `Ok(())` - 互換性がある
+    /// * `Err(Vec<String>)` - 互換性のない変更（JSONポインターと理由）
+    pub fn check_compatible_with(&self, previous: &EventSchema) -> Result<(), Vec<String>> {
+        let mut violations = Vec::new();
+        compare_schemas(&previous.schema, &self.schema, "", &mut violations);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// 現在のバージョンのすべてのイベントタイプのスキーマ（EVENT_TYPESの順）
+pub fn event_schemas() -> Vec<EventSchema> {
+    EVENT_TYPES
+        .iter()
+        .map(|event_type| EventSchema {
+            event_type: event_type.to_string(),
+            version: CURRENT_EVENT_VERSION,
+            schema: json!({
+                "title": format!("{} v{}", event_type, CURRENT_EVENT_VERSION),
+                "type": "object",
+                "properties": {
+                    "event_type": string(),
+                    "event_data": event_data(event_type),
+                },
+                "required": ["event_type", "event_data"],
+            }),
+        })
+        .collect()
+}
+
+/// イベントタイプごとのevent_dataのスキーマ
+fn event_data(event_type: &str) -> Value {
+    let (fields, optional): (Vec<(&str, Value)>, &[&str]) = match event_type {
+        "OrderConfirmed" | "PreOrderActivated" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("order_lines", array(order_line())),
+                ("total_amount", money()),
+            ],
+            &[],
+        ),
+        "OrderCancelled" | "ReturnApproved" | "WaitlistPromoted" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("order_lines", array(order_line())),
+            ],
+            &[],
+        ),
+        "ShippingAddressChanged" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("previous_address", nullable(shipping_address())),
+                ("new_address", shipping_address()),
+                ("previous_shipping_fee", money()),
+                ("shipping_fee", money()),
+            ],
+            &[],
+        ),
+        "OrderHeld" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("reason", string()),
+                ("note", nullable(string())),
+            ],
+            &["note"],
+        ),
+        "OrderReleased" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("reason", string()),
+            ],
+            &[],
+        ),
+        "OrderShipped" => (
+            vec![
+                ("order_id", string()),
+                ("shipping_address", shipping_address()),
+                ("recipient", nullable(object(vec![], &[]))),
+                ("estimated_delivery_date", nullable(string())),
+            ],
+            &["recipient", "estimated_delivery_date"],
+        ),
+        "OrderDelivered" => (
+            vec![
+                ("order_id", string()),
+                ("recipient", nullable(object(vec![], &[]))),
+            ],
+            &["recipient"],
+        ),
+        "OrderStatusForcefullyChanged" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("from_status", string()),
+                ("to_status", string()),
+                ("reason", string()),
+                ("operator", string()),
+            ],
+            &[],
+        ),
+        "ReturnRequested" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("reason", nullable(string())),
+            ],
+            &["reason"],
+        ),
+        "WaitlistJoined" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("positions", array(object(vec![], &[]))),
+            ],
+            &[],
+        ),
+        "DigitalItemsFulfilled" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("download_links", array(object(vec![], &[]))),
+                ("order_fulfilled", boolean()),
+            ],
+            &[],
+        ),
+        "FulfillmentSlaBreached" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("stage", string()),
+                ("started_at", string()),
+                ("deadline", string()),
+                ("detected_at", string()),
+            ],
+            &[],
+        ),
+        "CarrierTrackingUpdated" => (
+            vec![
+                ("order_id", string()),
+                ("stage", string()),
+                ("reported_at", string()),
+                ("location", nullable(string())),
+                ("description", nullable(string())),
+            ],
+            &[],
+        ),
+        "InventoryReserved" | "InventoryReleased" | "ItemsRestocked" => (
+            vec![
+                ("order_id", string()),
+                ("order_lines", array(order_line())),
+            ],
+            &[],
+        ),
+        "CheckoutHoldExpired" => (
+            vec![
+                ("order_id", string()),
+                ("holds", array(checkout_hold())),
+            ],
+            &[],
+        ),
+        "InventoryAdjusted" => (
+            vec![
+                ("book_id", string()),
+                ("previous_quantity", integer()),
+                ("new_quantity", integer()),
+                ("variance", integer()),
+                ("reason", string()),
+            ],
+            &[],
+        ),
+        "HighValueOrderPlaced" => (
+            vec![
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("total_amount", money()),
+                ("threshold", money()),
+            ],
+            &[],
+        ),
+        "CustomerBecameRepeatBuyer" => (
+            vec![
+                ("customer_id", string()),
+                ("order_id", string()),
+                ("delivered_order_count", integer()),
+            ],
+            &[],
+        ),
+        "InventoryReservationFailed" => (
+            vec![
+                ("order_id", string()),
+                ("order_lines", array(order_line())),
+                ("failure_reason", string()),
+                ("original_event_id", string()),
+            ],
+            &[],
+        ),
+        "ShippingFailed" | "DeliveryFailed" => (
+            vec![
+                ("order_id", string()),
+                ("failure_reason", string()),
+                ("original_event_id", string()),
+            ],
+            &[],
+        ),
+        "SagaCompensationStarted" => (
+            vec![
+                ("saga_id", string()),
+                ("failed_step", string()),
+                ("failure_reason", string()),
+                ("compensation_steps", array(string())),
+            ],
+            &[],
+        ),
+        "SagaCompensationCompleted" => (
+            vec![
+                ("saga_id", string()),
+                ("compensated_steps", array(string())),
+                // Successは文字列、それ以外はバリアント名をキーにしたオブジェクト
+                ("compensation_result", json!({ "type": ["string", "object"] })),
+            ],
+            &[],
+        ),
+        "SagaTimedOut" => (
+            vec![
+                ("saga_id", string()),
+                ("order_id", string()),
+                ("customer_id", string()),
+                ("stage", string()),
+                ("started_at", string()),
+                ("deadline", string()),
+                ("detected_at", string()),
+            ],
+            &[],
+        ),
+        _ => (Vec::new(), &[]),
+    };
+
+    let mut properties = vec![("metadata", metadata())];
+    properties.extend(fields);
+    object(properties, optional)
+}
+
+/// EventMetadataのスキーマ
+fn metadata() -> Value {
+    object(
+        vec![
+            ("event_id", string()),
+            ("occurred_at", string()),
+            ("correlation_id", string()),
+            ("event_version", integer()),
+            ("sequence_number", nullable(integer())),
+            ("additional_metadata", object(vec![], &[])),
+        ],
+        &["sequence_number"],
+    )
+}
+
+fn money() -> Value {
+    object(vec![("amount", integer()), ("currency", string())], &[])
+}
+
+fn order_line() -> Value {
+    object(
+        vec![
+            ("book_id", string()),
+            ("quantity", integer()),
+            ("unit_price", money()),
+            ("fulfillment_type", string()),
+            ("spec", nullable(object(vec![], &[]))),
+            ("attributes", array(object(vec![], &[]))),
+        ],
+        &["fulfillment_type", "spec", "attributes"],
+    )
+}
+
+fn checkout_hold() -> Value {
+    object(
+        vec![
+            ("order_id", string()),
+            ("book_id", string()),
+            ("quantity", integer()),
+            ("expires_at", string()),
+        ],
+        &[],
+    )
+}
+
+fn shipping_address() -> Value {
+    object(
+        vec![
+            ("postal_code", string()),
+            ("prefecture", string()),
+            ("city", string()),
+            ("street", string()),
+            ("building", nullable(string())),
+        ],
+        &[],
+    )
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+/// 型にnullを加える
+fn nullable(schema: Value) -> Value {
+    let mut schema = schema;
+    if let Some(type_name) = schema.get("type").and_then(Value::as_str) {
+        schema["type"] = json!([type_name, "null"]);
+    }
+    schema
+}
+
+/// オブジェクトのスキーマ（optionalに挙げたもの以外のプロパティは必須）
+fn object(properties: Vec<(&str, Value)>, optional: &[&str]) -> Value {
+    let required: Vec<&str> = properties
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| !optional.contains(name))
+        .collect();
+    let properties: Map<String, Value> = properties
+        .into_iter()
+        .map(|(name, schema)| (name.to_string(), schema))
+        .collect();
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+/// スキーマで許可している型の一覧（指定がない場合は空）
+fn allowed_types(schema: &Value) -> Vec<&str> {
+    match schema.get("type") {
+        Some(Value::String(type_name)) => vec![type_name.as_str()],
+        Some(Value::Array(type_names)) => type_names.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn required_fields(schema: &Value) -> Vec<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_i64() || number.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn validate_value(schema: &Value, value: &Value, path: &str, violations: &mut Vec<String>) {
+    let types = allowed_types(schema);
+    let actual = type_of(value);
+    let type_matches = types.is_empty()
+        || types.contains(&actual)
+        || (actual == "integer" && types.contains(&"number"));
+    if !type_matches {
+        violations.push(format!(
+            "{}: expected {}, found {}",
+            display_path(path),
+            types.join(" or "),
+            actual
+        ));
+        return;
+    }
+
+    match value {
+        Value::Object(fields) => {
+            for name in required_fields(schema) {
+                if !fields.contains_key(name) {
+                    violations.push(format!("{}/{}: required field is missing", path, name));
+                }
+            }
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (name, property) in properties {
+                    if let Some(field) = fields.get(name) {
+                        validate_value(property, field, &format!("{}/{}", path, name), violations);
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_value(item_schema, item, &format!("{}/{}", path, index), violations);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn compare_schemas(previous: &Value, current: &Value, path: &str, violations: &mut Vec<String>) {
+    let previous_types = allowed_types(previous);
+    let current_types = allowed_types(current);
+    // 以前は受け付けなかった型の値が届くと、以前のコンシューマーは読めない
+    if !previous_types.is_empty() {
+        let added: Vec<&str> = current_types
+            .iter()
+            .filter(|type_name| !previous_types.contains(type_name))
+            .copied()
+            .collect();
+        if current_types.is_empty() || !added.is_empty() {
+            violations.push(format!(
+                "{}: type changed from {} to {}",
+                display_path(path),
+                previous_types.join(" or "),
+                if current_types.is_empty() {
+                    "any".to_string()
+                } else {
+                    current_types.join(" or ")
+                }
+            ));
+            return;
+        }
+    }
+
+    let current_required = required_fields(current);
+    for name in required_fields(previous) {
+        if !current_required.contains(&name) {
+            violations.push(format!("{}/{}: required field was removed", path, name));
+        }
+    }
+    if let (Some(previous_properties), Some(current_properties)) = (
+        previous.get("properties").and_then(Value::as_object),
+        current.get("properties").and_then(Value::as_object),
+    ) {
+        for (name, previous_property) in previous_properties {
+            if let Some(current_property) = current_properties.get(name) {
+                compare_schemas(
+                    previous_property,
+                    current_property,
+                    &format!("{}/{}", path, name),
+                    violations,
+                );
+            }
+        }
+    }
+    if let (Some(previous_items), Some(current_items)) = (previous.get("items"), current.get("items"))
+    {
+        compare_schemas(previous_items, current_items, &format!("{}/items", path), violations);
+    }
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() {
+        "/"
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::checkout_hold::CheckoutHold;
+    use crate::domain::event::{
+        CheckoutHoldExpired, CompensationResult, DomainEvent, OrderConfirmed, OrderShipped,
+        SagaCompensationCompleted,
+    };
+    use crate::domain::model::{BookId, CustomerId, Money, OrderId, OrderLine, ShippingAddress};
+    use crate::domain::serialization::EventSerializer;
+
+    fn schema_for(event_type: &str) -> EventSchema {
+        event_schemas()
+            .into_iter()
+            .find(|schema| schema.event_type == event_type)
+            .unwrap()
+    }
+
+    fn message(event: &DomainEvent) -> Value {
+        serde_json::from_str(&EventSerializer::new().serialize_event(event).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_serialized_events_conform_to_their_schemas() {
+        assert_eq!(event_schemas().len(), EVENT_TYPES.len());
+        let address = ShippingAddress::new(
+            "1500001".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "神宮前1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        let events = vec![
+            DomainEvent::OrderConfirmed(OrderConfirmed::new(
+                OrderId::new(),
+                CustomerId::new(),
+                vec![OrderLine::new(BookId::new(), 2, Money::jpy(1500)).unwrap()],
+                Money::jpy(3000),
+            )),
+            DomainEvent::OrderShipped(OrderShipped::new(OrderId::new(), address)),
+            DomainEvent::CheckoutHoldExpired(CheckoutHoldExpired::with_correlation_id(
+                OrderId::new(),
+                vec![CheckoutHold::new(
+                    OrderId::new(),
+                    BookId::new(),
+                    1,
+                    chrono::Utc::now(),
+                )],
+                uuid::Uuid::new_v4(),
+            )),
+            DomainEvent::SagaCompensationCompleted(SagaCompensationCompleted::new(
+                uuid::Uuid::new_v4(),
+                vec!["ReleaseInventory".to_string()],
+                CompensationResult::PartialSuccess {
+                    failed_steps: vec!["NotifyCustomer".to_string()],
+                },
+            )),
+        ];
+
+        for event in &events {
+            assert_eq!(
+                schema_for(event.event_type()).validate(&message(event)),
+                Ok(()),
+                "{}",
+                event.event_type()
+            );
+        }
+
+        let mut broken = message(&events[0]);
+        broken["event_data"]["total_amount"]["amount"] = json!("3000");
+        broken["event_data"]
+            .as_object_mut()
+            .unwrap()
+            .remove("customer_id");
+        let violations = schema_for("OrderConfirmed").validate(&broken).unwrap_err();
+        assert_eq!(
+            violations,
+            vec![
+                "/event_data/customer_id: required field is missing".to_string(),
+                "/event_data/total_amount/amount: expected integer, found string".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_removing_required_fields_or_changing_types_is_incompatible() {
+        let previous = schema_for("OrderCancelled");
+
+        // 任意のフィールドの追加は互換性がある
+        let mut added = previous.clone();
+        added.version = 2;
+        added.schema["properties"]["event_data"]["properties"]["reason"] = nullable(string());
+        assert_eq!(added.check_compatible_with(&previous), Ok(()));
+
+        let mut changed = previous.clone();
+        changed.version = 2;
+        changed.schema["properties"]["event_data"]["properties"]["order_id"] = integer();
+        changed.schema["properties"]["event_data"]["required"] =
+            json!(["metadata", "order_id", "order_lines"]);
+        let violations = changed.check_compatible_with(&previous).unwrap_err();
+        assert_eq!(
+            violations,
+            vec![
+                "/event_data/customer_id: required field was removed".to_string(),
+                "/event_data/order_id: type changed from string to integer".to_string(),
+            ]
+        );
+    }
+}
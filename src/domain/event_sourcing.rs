@@ -0,0 +1,484 @@
+use crate::domain::error::DomainError;
+use crate::domain::event::{
+    DigitalItemsFulfilled, DomainEvent, OrderCancelled, OrderConfirmed, OrderDelivered, OrderHeld,
+    OrderReleased, OrderShipped, OrderStatusForcefullyChanged, PreOrderActivated, ReturnApproved,
+    ReturnRequested, ShippingAddressChanged, WaitlistPromoted,
+};
+use crate::domain::model::{
+    CustomerId, HoldReason, Order, OrderId, OrderLine, OrderStatus, Recipient, ShippingAddress,
+};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 集約ごとのイベントストリーム
+/// バージョンはストリームに追記したイベントの件数（最初のイベントが1）
+#[derive(Debug, Clone)]
+pub struct EventStream {
+    pub stream_id: String,
+    pub version: u64,
+    pub events: Vec<DomainEvent>,
+}
+
+impl EventStream {
+    /// イベントのない空のストリームを作成
+    pub fn empty(stream_id: &str) -> Self {
+        Self {
+            stream_id: stream_id.to_string(),
+            version: 0,
+            events: Vec::new(),
+        }
+    }
+
+    /// イベントがないか
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// 注文のイベントストリームID
+pub fn order_stream_id(order_id: OrderId) -> String {
+    format!("Order-{}", order_id)
+}
+
+/// イベントの履歴から注文を再構築する
+///
+/// ステータス・明細・遷移日時・配送先住所・受取人・保留の理由・サーガの相関IDはイベントから復元する。
+/// イベントに含まれない属性（確定前に設定した配送先住所、確定時の金額、追跡トークン、配達予定日など）は
+/// 状態テーブルの注文から引き継ぐ
+///
+/// # Arguments
+/// * `stream` - 注文のイベントストリーム
+/// * `snapshot` - 状態テーブルの注文（イベントに含まれない属性の引き継ぎ元）
+///
+/// # Returns
+/// * `Ok(Some(Order))` - 再構築した注文（ストリームのバージョンを設定済み）
+/// * `Ok(None)` - イベントがない
+/// * `Err(DomainError)` - 最初のイベントが注文の明細を含まない
+pub fn replay_order(
+    stream: &EventStream,
+    snapshot: Option<&Order>,
+) -> Result<Option<Order>, DomainError> {
+    let Some(first) = stream.events.first() else {
+        return Ok(None);
+    };
+    let mut state = OrderReplay::start(first)?;
+    for event in &stream.events[1..] {
+        state.apply(event);
+    }
+
+    let order = Order::reconstruct(
+        state.order_id,
+        state.customer_id,
+        state.order_lines,
+        state
+            .shipping_address
+            .or_else(|| snapshot.and_then(|order| order.shipping_address().cloned())),
+        state.status,
+    )?
+    .with_transition_times(state.confirmed_at, state.shipped_at, state.delivered_at)
+    .with_recipient(
+        state
+            .recipient
+            .or_else(|| snapshot.and_then(|order| order.recipient().cloned())),
+    )
+    .with_order_number(snapshot.and_then(|order| order.order_number().cloned()))
+    .with_saga_correlation_id(state.saga_correlation_id)
+    .with_hold_reason(state.hold_reason)
+    .with_original_shipping_address(
+        snapshot.and_then(|order| order.original_shipping_address().cloned()),
+    )
+    .with_event_sequence(snapshot.map_or(0, |order| order.event_sequence()))
+    .with_confirmed_totals(snapshot.and_then(|order| order.confirmed_totals().cloned()))
+    .with_tracking_token(snapshot.and_then(|order| order.tracking_token().cloned()))
+    .with_estimated_delivery_date(snapshot.and_then(Order::estimated_delivery_date))
+    .with_stream_version(stream.version)
+    .with_version(snapshot.map_or(0, Order::version));
+
+    Ok(Some(order))
+}
+
+/// 保存前の注文から保存する注文への変化を表すイベントを導出する
+///
+/// 確定前の状態（Pending・AwaitingRelease・Waitlisted）への変化はイベントにしない。
+/// 確定を経ずに発送済み以降の注文を保存する場合（移行した注文など）は、途中の確定・発送のイベントも導出する。
+/// 管理者が強制遷移した注文は、途中のイベントを導出せずOrderStatusForcefullyChangedだけにする
+///
+/// # Arguments
+/// * `previous` - 保存前の注文（新しい注文の場合はNone）
+/// * `current` - 保存する注文
+///
+/// # Returns
+/// * 発生順のイベント（変化がない場合は空）
+pub fn events_since(previous: Option<&Order>, current: &Order) -> Vec<DomainEvent> {
+    let previous_status = previous.map(|order| order.status());
+    if previous_status == Some(current.status()) {
+        return address_change(previous, current).into_iter().collect();
+    }
+
+    let order_id = current.id();
+    let correlation_id = current.saga_correlation_id().unwrap_or_else(Uuid::new_v4);
+    if let Some(status_override) = current.status_override() {
+        let mut event =
+            OrderStatusForcefullyChanged::new(order_id, current.customer_id(), status_override);
+        event.metadata.correlation_id = correlation_id;
+        return vec![DomainEvent::OrderStatusForcefullyChanged(event)];
+    }
+    let lines = current.order_lines().to_vec();
+    let mut events = Vec::new();
+
+    if current.status() == OrderStatus::Cancelled {
+        events.push(DomainEvent::OrderCancelled(
+            OrderCancelled::with_correlation_id(
+                order_id,
+                current.customer_id(),
+                lines,
+                correlation_id,
+            ),
+        ));
+        return events;
+    }
+
+    let from = previous_status.map_or(0, progress);
+    let to = progress(current.status());
+
+    if from < 1 && to >= 1 {
+        let confirmed = match previous_status {
+            Some(OrderStatus::AwaitingRelease) => {
+                let mut event = PreOrderActivated::new(
+                    order_id,
+                    current.customer_id(),
+                    lines,
+                    current.calculate_total(),
+                );
+                event.metadata.correlation_id = correlation_id;
+                DomainEvent::PreOrderActivated(event)
+            }
+            Some(OrderStatus::Waitlisted) => {
+                DomainEvent::WaitlistPromoted(WaitlistPromoted::with_correlation_id(
+                    order_id,
+                    current.customer_id(),
+                    lines,
+                    correlation_id,
+                ))
+            }
+            _ => {
+                let mut event = OrderConfirmed::new(
+                    order_id,
+                    current.customer_id(),
+                    lines,
+                    current.calculate_total(),
+                );
+                event.metadata.correlation_id = correlation_id;
+                DomainEvent::OrderConfirmed(event)
+            }
+        };
+        events.push(at(confirmed, current.confirmed_at()));
+    } else if let Some(changed) = address_change(previous, current) {
+        events.push(changed);
+    }
+
+    if current.status() == OrderStatus::OnHold {
+        let reason = current.hold_reason().unwrap_or(HoldReason::SuspectedFraud);
+        let mut event = OrderHeld::new(order_id, current.customer_id(), reason, None);
+        event.metadata.correlation_id = correlation_id;
+        events.push(DomainEvent::OrderHeld(event));
+    } else if previous_status == Some(OrderStatus::OnHold) {
+        let reason = previous
+            .and_then(Order::hold_reason)
+            .unwrap_or(HoldReason::SuspectedFraud);
+        let mut event = OrderReleased::new(order_id, current.customer_id(), reason);
+        event.metadata.correlation_id = correlation_id;
+        events.push(DomainEvent::OrderReleased(event));
+    }
+
+    if current.status() == OrderStatus::Fulfilled {
+        events.push(DomainEvent::DigitalItemsFulfilled(
+            DigitalItemsFulfilled::with_correlation_id(
+                order_id,
+                current.customer_id(),
+                Vec::new(),
+                true,
+                correlation_id,
+            ),
+        ));
+    }
+
+    if from < 2 && to >= 2 {
+        if let Some(address) = current.shipping_address() {
+            let shipped =
+                OrderShipped::with_correlation_id(order_id, address.clone(), correlation_id)
+                    .with_recipient(current.recipient().cloned())
+                    .with_estimated_delivery_date(current.estimated_delivery_date());
+            events.push(at(DomainEvent::OrderShipped(shipped), current.shipped_at()));
+        }
+    }
+
+    if from < 3 && to >= 3 {
+        let delivered = OrderDelivered::with_correlation_id(order_id, correlation_id)
+            .with_recipient(current.recipient().cloned());
+        events.push(at(
+            DomainEvent::OrderDelivered(delivered),
+            current.delivered_at(),
+        ));
+    }
+
+    if from < 4 && to >= 4 {
+        let mut event = ReturnRequested::new(order_id, current.customer_id(), None);
+        event.metadata.correlation_id = correlation_id;
+        events.push(DomainEvent::ReturnRequested(event));
+    }
+
+    if from < 5 && to >= 5 {
+        let mut event = ReturnApproved::new(
+            order_id,
+            current.customer_id(),
+            current.order_lines().to_vec(),
+        );
+        event.metadata.correlation_id = correlation_id;
+        events.push(DomainEvent::ReturnApproved(event));
+    }
+
+    events
+}
+
+/// 確定後（発送前）の配送先住所の変更をイベントにする
+fn address_change(previous: Option<&Order>, current: &Order) -> Option<DomainEvent> {
+    let previous = previous
+        .filter(|order| matches!(order.status(), OrderStatus::Confirmed | OrderStatus::OnHold))?;
+    let new_address = current.shipping_address()?;
+    if previous.shipping_address() == Some(new_address) {
+        return None;
+    }
+
+    let mut event = ShippingAddressChanged::new(
+        current.id(),
+        current.customer_id(),
+        previous.shipping_address().cloned(),
+        new_address.clone(),
+        previous.shipping_fee(),
+        current.shipping_fee(),
+    );
+    if let Some(correlation_id) = current.saga_correlation_id() {
+        event.metadata.correlation_id = correlation_id;
+    }
+    Some(DomainEvent::ShippingAddressChanged(event))
+}
+
+/// ステータスの進み具合（確定前は0、確定済み・保留中は1、発送済みは2、配達完了は3、返品受付中は4、返品済みは5）
+fn progress(status: OrderStatus) -> u8 {
+    match status {
+        OrderStatus::Pending | OrderStatus::AwaitingRelease | OrderStatus::Waitlisted => 0,
+        OrderStatus::Confirmed
+        | OrderStatus::OnHold
+        | OrderStatus::Fulfilled
+        | OrderStatus::Cancelled => 1,
+        OrderStatus::Shipped => 2,
+        OrderStatus::Delivered => 3,
+        OrderStatus::ReturnRequested => 4,
+        OrderStatus::Returned => 5,
+    }
+}
+
+/// 遷移日時が分かっている場合は、イベントの発生日時を遷移日時にする（再構築時に遷移日時を復元するため）
+fn at(mut event: DomainEvent, occurred_at: Option<DateTime<Utc>>) -> DomainEvent {
+    if let Some(occurred_at) = occurred_at {
+        event.metadata_mut().occurred_at = occurred_at;
+    }
+    event
+}
+
+/// イベントを順に適用して再構築中の注文の状態
+struct OrderReplay {
+    order_id: OrderId,
+    customer_id: CustomerId,
+    order_lines: Vec<OrderLine>,
+    shipping_address: Option<ShippingAddress>,
+    recipient: Option<Recipient>,
+    status: OrderStatus,
+    confirmed_at: Option<DateTime<Utc>>,
+    shipped_at: Option<DateTime<Utc>>,
+    delivered_at: Option<DateTime<Utc>>,
+    hold_reason: Option<HoldReason>,
+    saga_correlation_id: Option<Uuid>,
+}
+
+impl OrderReplay {
+    /// 最初のイベント（注文の明細を含む確定・キャンセルのイベント）から再構築を始める
+    fn start(event: &DomainEvent) -> Result<Self, DomainError> {
+        let (order_id, customer_id, order_lines) = match event {
+            DomainEvent::OrderConfirmed(e) => (e.order_id, e.customer_id, &e.order_lines),
+            DomainEvent::PreOrderActivated(e) => (e.order_id, e.customer_id, &e.order_lines),
+            DomainEvent::WaitlistPromoted(e) => (e.order_id, e.customer_id, &e.order_lines),
+            DomainEvent::OrderCancelled(e) => (e.order_id, e.customer_id, &e.order_lines),
+            other => {
+                return Err(DomainError::InvalidValue(format!(
+                    "イベントストリームの最初のイベント（{}）から注文を再構築できません",
+                    other.event_type()
+                )))
+            }
+        };
+
+        let mut state = Self {
+            order_id,
+            customer_id,
+            order_lines: order_lines.clone(),
+            shipping_address: None,
+            recipient: None,
+            status: OrderStatus::Pending,
+            confirmed_at: None,
+            shipped_at: None,
+            delivered_at: None,
+            hold_reason: None,
+            saga_correlation_id: None,
+        };
+        state.apply(event);
+        Ok(state)
+    }
+
+    /// イベントを1件適用する（注文の状態を変えないイベントは無視する）
+    fn apply(&mut self, event: &DomainEvent) {
+        let occurred_at = event.metadata().occurred_at;
+        match event {
+            DomainEvent::OrderConfirmed(_)
+            | DomainEvent::PreOrderActivated(_)
+            | DomainEvent::WaitlistPromoted(_) => {
+                self.status = OrderStatus::Confirmed;
+                self.confirmed_at = Some(occurred_at);
+                self.saga_correlation_id
+                    .get_or_insert(event.metadata().correlation_id);
+            }
+            DomainEvent::ShippingAddressChanged(e) => {
+                self.shipping_address = Some(e.new_address.clone());
+            }
+            DomainEvent::OrderHeld(e) => {
+                self.status = OrderStatus::OnHold;
+                self.hold_reason = Some(e.reason);
+            }
+            DomainEvent::OrderReleased(_) => {
+                self.status = OrderStatus::Confirmed;
+                self.hold_reason = None;
+            }
+            DomainEvent::OrderShipped(e) => {
+                self.status = OrderStatus::Shipped;
+                self.shipped_at = Some(occurred_at);
+                self.shipping_address = Some(e.shipping_address.clone());
+                if e.recipient.is_some() {
+                    self.recipient = e.recipient.clone();
+                }
+            }
+            DomainEvent::OrderDelivered(_) => {
+                self.status = OrderStatus::Delivered;
+                self.delivered_at = Some(occurred_at);
+            }
+            DomainEvent::ReturnRequested(_) => {
+                self.status = OrderStatus::ReturnRequested;
+            }
+            DomainEvent::ReturnApproved(_) => {
+                self.status = OrderStatus::Returned;
+            }
+            DomainEvent::DigitalItemsFulfilled(e) if e.order_fulfilled => {
+                self.status = OrderStatus::Fulfilled;
+            }
+            DomainEvent::OrderCancelled(_) => {
+                self.status = OrderStatus::Cancelled;
+                self.hold_reason = None;
+            }
+            DomainEvent::OrderStatusForcefullyChanged(e) => {
+                // 記録したステータスを解釈できない場合は無視する
+                let Ok(status) = OrderStatus::from_string(&e.to_status) else {
+                    return;
+                };
+                self.status = status;
+                match status {
+                    OrderStatus::Confirmed => {
+                        self.shipped_at = None;
+                        self.delivered_at = None;
+                        self.hold_reason = None;
+                        self.confirmed_at.get_or_insert(occurred_at);
+                    }
+                    OrderStatus::Shipped => {
+                        self.delivered_at = None;
+                        self.hold_reason = None;
+                        self.shipped_at.get_or_insert(occurred_at);
+                    }
+                    OrderStatus::Delivered => {
+                        self.delivered_at.get_or_insert(occurred_at);
+                    }
+                    OrderStatus::Cancelled => {
+                        self.shipped_at = None;
+                        self.hold_reason = None;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, Money};
+
+    #[test]
+    fn test_order_is_rebuilt_from_events_derived_for_each_save() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 2, Money::jpy(1500)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1000001".to_string(),
+                    "東京都".to_string(),
+                    "千代田区".to_string(),
+                    "千代田1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert!(events_since(None, &order).is_empty());
+
+        let draft = order.clone();
+        order.confirm().unwrap();
+        let mut stream = EventStream::empty(&order_stream_id(order.id()));
+        stream.events.extend(events_since(Some(&draft), &order));
+        stream.version = stream.events.len() as u64;
+        assert_eq!(stream.events[0].event_type(), "OrderConfirmed");
+
+        let confirmed = replay_order(&stream, Some(&draft)).unwrap().unwrap();
+        assert_eq!(confirmed.status(), OrderStatus::Confirmed);
+        assert_eq!(confirmed.order_lines(), order.order_lines());
+        assert_eq!(confirmed.shipping_address(), order.shipping_address());
+        assert_eq!(confirmed.stream_version(), 1);
+
+        let mut shipped = confirmed.clone();
+        shipped.mark_as_shipped().unwrap();
+        stream
+            .events
+            .extend(events_since(Some(&confirmed), &shipped));
+        stream.version = stream.events.len() as u64;
+
+        let rebuilt = replay_order(&stream, None).unwrap().unwrap();
+        assert_eq!(rebuilt.status(), OrderStatus::Shipped);
+        assert_eq!(rebuilt.shipped_at(), shipped.shipped_at());
+        assert_eq!(rebuilt.confirmed_at(), order.confirmed_at());
+        assert_eq!(rebuilt.stream_version(), 2);
+
+        // 強制遷移は途中のイベントを導出せず、OrderStatusForcefullyChangedだけを記録する
+        let mut forced = rebuilt.clone();
+        forced
+            .force_status(OrderStatus::Confirmed, "発送の誤登録", "operator-1", Utc::now())
+            .unwrap();
+        let events = events_since(Some(&rebuilt), &forced);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), "OrderStatusForcefullyChanged");
+        stream.events.extend(events);
+        stream.version = stream.events.len() as u64;
+
+        let reverted = replay_order(&stream, None).unwrap().unwrap();
+        assert_eq!(reverted.status(), OrderStatus::Confirmed);
+        assert_eq!(reverted.shipped_at(), None);
+        assert_eq!(reverted.confirmed_at(), order.confirmed_at());
+    }
+}
@@ -0,0 +1,202 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use uuid::Uuid;
+
+/// ハンドラーへの配信結果（トレース用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "status", content = "error")]
+pub enum HandlerOutcome {
+    /// 処理に成功した（リトライで成功した場合を含む）
+    Succeeded,
+    /// リトライを使い切って失敗した（エラーメッセージ）
+    Failed(String),
+    /// 購読が一時停止中のため、イベントをためた
+    Buffered,
+    /// 購読が一時停止中のため、イベントを読み飛ばした
+    Skipped,
+    /// 配信待ちのイベントがたまっているため、重要でないハンドラーへの配信を見送った
+    Shed,
+}
+
+/// 1つのハンドラーへの配信のトレース
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HandlerTrace {
+    pub handler_name: String,
+    #[serde(flatten)]
+    pub outcome: HandlerOutcome,
+    /// リトライの待機時間を含む処理時間（ミリ秒）
+    pub duration_ms: u64,
+    /// リトライした回数（1回目で成功した場合は0）
+    pub retries: u32,
+}
+
+/// 発行されたイベント1件の配信のトレース
+/// どのハンドラーに配信され、それぞれがどうなったかを記録する（購読しているハンドラーがない場合は空）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventTrace {
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub correlation_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub handlers: Vec<HandlerTrace>,
+}
+
+/// 直近のイベントの配信のトレースを保持するリングバッファ
+/// 容量を超えた場合は古いトレースから消す
+#[derive(Debug, Clone)]
+pub struct EventTraceBuffer {
+    capacity: usize,
+    traces: VecDeque<EventTrace>,
+}
+
+impl EventTraceBuffer {
+    /// 容量を指定して作成
+    ///
+    /// # Arguments
+    /// * `capacity` - 保持するトレースの最大件数
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            traces: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// トレースを記録する
+    pub fn record(&mut self, trace: EventTrace) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.traces.len() >= self.capacity {
+            self.traces.pop_front();
+        }
+        self.traces.push_back(trace);
+    }
+
+    /// 記録したトレースをイベントの発生順に取得する
+    /// （トレースは配信を終えた順に記録されるため、ハンドラー内で発行されたイベントが先に記録される）
+    ///
+    /// # Arguments
+    /// * `correlation_id` - 指定した場合はその相関ID（サーガ）のトレースのみ
+    pub fn traces(&self, correlation_id: Option<Uuid>) -> Vec<EventTrace> {
+        let mut traces: Vec<EventTrace> = self
+            .traces
+            .iter()
+            .filter(|trace| correlation_id.is_none_or(|id| trace.correlation_id == id))
+            .cloned()
+            .collect();
+        traces.sort_by_key(|trace| trace.occurred_at);
+        traces
+    }
+}
+
+/// イベントタイプとハンドラーの組ごとの配信結果の集計
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct HandlerOutcomeCounts {
+    pub succeeded: u32,
+    pub failed: u32,
+    pub buffered: u32,
+    pub skipped: u32,
+    pub shed: u32,
+    /// リトライした回数の合計
+    pub retries: u32,
+    /// 処理時間の合計（ミリ秒）
+    pub total_duration_ms: u64,
+}
+
+/// イベントの配信のトレースの一覧と、イベントタイプ×ハンドラーの配信結果の対応表
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventTraceReport {
+    pub traces: Vec<EventTrace>,
+    /// イベントタイプごとの、ハンドラー名ごとの配信結果（購読しているハンドラーがないイベントタイプは空）
+    pub matrix: BTreeMap<String, BTreeMap<String, HandlerOutcomeCounts>>,
+}
+
+impl EventTraceReport {
+    /// トレースから対応表を集計して作成
+    pub fn build(traces: Vec<EventTrace>) -> Self {
+        let mut matrix: BTreeMap<String, BTreeMap<String, HandlerOutcomeCounts>> = BTreeMap::new();
+        for trace in &traces {
+            let row = matrix.entry(trace.event_type.clone()).or_default();
+            for handler in &trace.handlers {
+                let counts = row.entry(handler.handler_name.clone()).or_default();
+                match handler.outcome {
+                    HandlerOutcome::Succeeded => counts.succeeded += 1,
+                    HandlerOutcome::Failed(_) => counts.failed += 1,
+                    HandlerOutcome::Buffered => counts.buffered += 1,
+                    HandlerOutcome::Skipped => counts.skipped += 1,
+                    HandlerOutcome::Shed => counts.shed += 1,
+                }
+                counts.retries += handler.retries;
+                counts.total_duration_ms += handler.duration_ms;
+            }
+        }
+        Self { traces, matrix }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(event_type: &str, correlation_id: Uuid, handlers: Vec<HandlerTrace>) -> EventTrace {
+        EventTrace {
+            event_id: Uuid::new_v4(),
+            event_type: event_type.to_string(),
+            aggregate_id: "order-1".to_string(),
+            correlation_id,
+            occurred_at: Utc::now(),
+            handlers,
+        }
+    }
+
+    fn handler(name: &str, outcome: HandlerOutcome, retries: u32) -> HandlerTrace {
+        HandlerTrace {
+            handler_name: name.to_string(),
+            outcome,
+            duration_ms: 5,
+            retries,
+        }
+    }
+
+    #[test]
+    fn test_buffer_keeps_latest_traces_and_report_builds_matrix() {
+        let saga = Uuid::new_v4();
+        let mut buffer = EventTraceBuffer::new(3);
+        buffer.record(trace("OrderConfirmed", Uuid::new_v4(), Vec::new()));
+        buffer.record(trace(
+            "OrderConfirmed",
+            saga,
+            vec![handler(
+                "InventoryReservationHandler",
+                HandlerOutcome::Succeeded,
+                0,
+            )],
+        ));
+        buffer.record(trace(
+            "InventoryReserved",
+            saga,
+            vec![handler(
+                "ShippingHandler",
+                HandlerOutcome::Failed("carrier unavailable".to_string()),
+                2,
+            )],
+        ));
+        buffer.record(trace("OrderShipped", Uuid::new_v4(), Vec::new()));
+
+        // 容量を超えたため最も古いトレースが消えている
+        assert_eq!(buffer.traces(None).len(), 3);
+        let saga_traces = buffer.traces(Some(saga));
+        assert_eq!(saga_traces.len(), 2);
+
+        let report = EventTraceReport::build(saga_traces);
+        let shipping = &report.matrix["InventoryReserved"]["ShippingHandler"];
+        assert_eq!(shipping.failed, 1);
+        assert_eq!(shipping.retries, 2);
+        assert_eq!(
+            report.matrix["OrderConfirmed"]["InventoryReservationHandler"].succeeded,
+            1
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::domain::model::BookId;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// 需要の移動平均を取る日数のデフォルト値
+pub const DEFAULT_FORECAST_WINDOW_DAYS: u32 = 28;
+
+/// 発注から入荷までの日数のデフォルト値（発注点の計算に使う）
+pub const DEFAULT_REORDER_LEAD_TIME_DAYS: u32 = 7;
+
+/// 需要予測の設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForecastPolicy {
+    /// 移動平均を取る日数（今日を含まない直近の日数）
+    pub window_days: u32,
+    /// 発注から入荷までの日数
+    pub lead_time_days: u32,
+}
+
+impl Default for ForecastPolicy {
+    fn default() -> Self {
+        Self {
+            window_days: DEFAULT_FORECAST_WINDOW_DAYS,
+            lead_time_days: DEFAULT_REORDER_LEAD_TIME_DAYS,
+        }
+    }
+}
+
+impl ForecastPolicy {
+    /// 需要を集計する期間の開始日時（今日から移動平均の日数だけさかのぼった日の0時）
+    pub fn window_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        (now.date_naive() - TimeDelta::days(i64::from(self.window_days)))
+            .and_hms_opt(0, 0, 0)
+            .map(|start| start.and_utc())
+            .unwrap_or(now)
+    }
+}
+
+/// 1日の需要
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DailyDemand {
+    pub date: NaiveDate,
+    /// その日に確定した注文に含まれる数量
+    pub quantity: u32,
+}
+
+/// 書籍の需要予測
+/// 確定した注文の数量の単純移動平均を1日の需要とみなし、在庫がなくなるまでの日数と発注点を見積もる
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DemandForecast {
+    pub book_id: BookId,
+    pub generated_at: DateTime<Utc>,
+    pub window_days: u32,
+    /// 移動平均を取った期間の日ごとの需要（古い日から順に、需要のない日も含む）
+    pub daily_demand: Vec<DailyDemand>,
+    /// 1日あたりの平均需要
+    pub average_daily_demand: f64,
+    pub quantity_on_hand: u32,
+    /// 在庫がなくなるまでの日数（需要がない場合はNone）
+    pub days_of_stock_remaining: Option<f64>,
+    /// 在庫がなくなる見込みの日（需要がない場合はNone）
+    pub projected_stockout_date: Option<NaiveDate>,
+    pub lead_time_days: u32,
+    /// 発注点（入荷までの日数の需要。在庫数がこれ以下になったら発注する）
+    pub reorder_point: u32,
+    /// 在庫数が発注点以下か
+    pub needs_reorder: bool,
+}
+
+impl DemandForecast {
+    /// 日ごとの需要と在庫数から需要予測を作成
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `demand` - 期間内の日ごとの需要（期間外の日は無視する）
+    /// * `quantity_on_hand` - 現在の在庫数
+    /// * `policy` - 需要予測の設定
+    /// * `now` - 現在日時
+    pub fn build(
+        book_id: BookId,
+        demand: &BTreeMap<NaiveDate, u32>,
+        quantity_on_hand: u32,
+        policy: &ForecastPolicy,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let today = now.date_naive();
+        let daily_demand: Vec<DailyDemand> = (1..=i64::from(policy.window_days))
+            .rev()
+            .map(|days_ago| {
+                let date = today - TimeDelta::days(days_ago);
+                DailyDemand {
+                    date,
+                    quantity: demand.get(&date).copied().unwrap_or(0),
+                }
+            })
+            .collect();
+
+        let total: u64 = daily_demand.iter().map(|d| u64::from(d.quantity)).sum();
+        let average_daily_demand = if policy.window_days == 0 {
+            0.0
+        } else {
+            total as f64 / f64::from(policy.window_days)
+        };
+
+        let days_of_stock_remaining = (average_daily_demand > 0.0)
+            .then(|| f64::from(quantity_on_hand) / average_daily_demand);
+        let projected_stockout_date =
+            days_of_stock_remaining.map(|days| today + TimeDelta::days(days.floor() as i64));
+        let reorder_point = (average_daily_demand * f64::from(policy.lead_time_days)).ceil() as u32;
+
+        Self {
+            book_id,
+            generated_at: now,
+            window_days: policy.window_days,
+            daily_demand,
+            average_daily_demand,
+            quantity_on_hand,
+            days_of_stock_remaining,
+            projected_stockout_date,
+            lead_time_days: policy.lead_time_days,
+            reorder_point,
+            needs_reorder: average_daily_demand > 0.0 && quantity_on_hand <= reorder_point,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_forecast_uses_moving_average_including_days_without_demand() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 29, 9, 0, 0).unwrap();
+        let policy = ForecastPolicy {
+            window_days: 4,
+            lead_time_days: 3,
+        };
+        let date = |day| NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+        let demand = BTreeMap::from([
+            (date(24), 100), // 期間外
+            (date(25), 3),
+            (date(27), 5),
+            (date(29), 50), // 今日の分は含めない
+        ]);
+
+        let forecast = DemandForecast::build(BookId::new(), &demand, 10, &policy, now);
+        assert_eq!(
+            forecast
+                .daily_demand
+                .iter()
+                .map(|d| (d.date, d.quantity))
+                .collect::<Vec<_>>(),
+            vec![(date(25), 3), (date(26), 0), (date(27), 5), (date(28), 0)]
+        );
+        assert_eq!(forecast.average_daily_demand, 2.0);
+        assert_eq!(forecast.days_of_stock_remaining, Some(5.0));
+        assert_eq!(
+            forecast.projected_stockout_date,
+            Some(date(29) + TimeDelta::days(5))
+        );
+        assert_eq!(forecast.reorder_point, 6);
+        assert!(!forecast.needs_reorder);
+        assert_eq!(
+            policy.window_start(now),
+            Utc.with_ymd_and_hms(2024, 3, 25, 0, 0, 0).unwrap()
+        );
+
+        // 需要がない書籍は在庫がなくならず、発注も不要
+        let idle = DemandForecast::build(BookId::new(), &BTreeMap::new(), 0, &policy, now);
+        assert_eq!(idle.days_of_stock_remaining, None);
+        assert_eq!(idle.reorder_point, 0);
+        assert!(!idle.needs_reorder);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::domain::error::DomainError;
+use serde::Serialize;
+
+/// 発送・配達の進め方
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentMode {
+    /// 発送・配達完了はREST APIの手動操作でのみ進める
+    #[default]
+    Manual,
+    /// 在庫予約後の発送と発送後の配達完了をイベントハンドラーが自動で進める
+    /// （手動の発送・配達完了の操作は受け付けない）
+    Auto,
+    /// 自動で進めつつ、滞留した注文を手動の操作でも進められる
+    Hybrid,
+}
+
+impl FulfillmentMode {
+    /// 文字列からFulfillmentModeを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "manual" => Ok(FulfillmentMode::Manual),
+            "auto" => Ok(FulfillmentMode::Auto),
+            "hybrid" => Ok(FulfillmentMode::Hybrid),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効なフルフィルメントモード: {}",
+                s
+            ))),
+        }
+    }
+
+    /// 発送・配達のハンドラー（ShippingHandler・DeliveryHandler）を購読するか
+    pub fn is_automatic(&self) -> bool {
+        matches!(self, FulfillmentMode::Auto | FulfillmentMode::Hybrid)
+    }
+
+    /// 手動の発送・配達完了の操作を受け付けるか
+    pub fn allows_manual_transitions(&self) -> bool {
+        matches!(self, FulfillmentMode::Manual | FulfillmentMode::Hybrid)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FulfillmentMode::Manual => "manual",
+            FulfillmentMode::Auto => "auto",
+            FulfillmentMode::Hybrid => "hybrid",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modes_select_automatic_handlers_and_manual_transitions() {
+        let cases = [
+            ("manual", false, true),
+            ("auto", true, false),
+            ("hybrid", true, true),
+        ];
+        for (value, automatic, manual) in cases {
+            let mode = FulfillmentMode::from_string(value).unwrap();
+            assert_eq!(mode.as_str(), value);
+            assert_eq!(mode.is_automatic(), automatic, "{}", value);
+            assert_eq!(mode.allows_manual_transitions(), manual, "{}", value);
+        }
+        assert!(FulfillmentMode::from_string("semi").is_err());
+        assert_eq!(FulfillmentMode::default(), FulfillmentMode::Manual);
+    }
+}
@@ -0,0 +1,405 @@
+use crate::domain::event::{
+    CarrierTrackingUpdated, CheckoutHoldExpired, CustomerBecameRepeatBuyer, DeliveryFailed,
+    DigitalItemsFulfilled, FulfillmentSlaBreached, HighValueOrderPlaced, InventoryAdjusted,
+    InventoryReleased, InventoryReservationFailed, InventoryReserved, ItemsRestocked,
+    OrderCancelled, OrderConfirmed, OrderDelivered, OrderHeld, OrderReleased, OrderShipped,
+    OrderStatusForcefullyChanged, PreOrderActivated, ReturnApproved, ReturnRequested,
+    SagaCompensationCompleted, SagaCompensationStarted, SagaTimedOut, ShippingAddressChanged,
+    ShippingFailed, WaitlistJoined, WaitlistPromoted,
+};
+use crate::domain::model::{
+    AllocationQuota, BookId, BookSpec, Customer, CustomerId, CycleCount, CycleCountId,
+    CycleCountLine, DevicePlatform, DeviceRegistration, DeviceToken, DownloadLink, EmailAddress,
+    FulfillmentType, HoldReason, Inventory, LineAttribute, Money, Order, OrderId, OrderLine,
+    OrderNumber, OrderStatus, Recipient, SalesChannel, ShippingAddress, TrackingToken,
+};
+use serde::Serialize;
+
+/// このシステムの境界づけられたコンテキスト
+pub const BOUNDED_CONTEXT: &str = "書店注文管理";
+
+/// ドメインモデルの要素の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ModelElementKind {
+    /// 集約（整合性の境界となるエンティティのまとまり）
+    Aggregate,
+    /// 集約内のエンティティ
+    Entity,
+    /// 値オブジェクト
+    ValueObject,
+    /// ドメインイベント
+    DomainEvent,
+}
+
+/// ドメインモデルの要素の説明
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModelElement {
+    /// 型名（ユビキタス言語での名前）
+    pub name: &'static str,
+    pub kind: ModelElementKind,
+    pub description: &'static str,
+    /// 定義しているモジュール
+    pub module: &'static str,
+    /// 関連する要素の名前（集約の構成要素、イベントを発行する集約など）
+    pub related: Vec<&'static str>,
+}
+
+/// ドメインモデルの要素として説明を持つ型
+/// 型の定義の近くで`domain_model!`マクロを使って実装する
+pub trait DescribeModel {
+    /// 要素の説明を取得
+    fn describe() -> ModelElement;
+}
+
+/// 型にドメインモデルの要素としての説明を付ける
+///
+/// ```text
+/// domain_model!(Order, Aggregate, "注文集約", related = [OrderLine, ShippingAddress]);
+/// ```
+macro_rules! domain_model {
+    ($ty:ident, $kind:ident, $description:expr $(, related = [$($related:ident),* $(,)?])? $(,)?) => {
+        impl $crate::domain::glossary::DescribeModel for $ty {
+            fn describe() -> $crate::domain::glossary::ModelElement {
+                $crate::domain::glossary::ModelElement {
+                    name: stringify!($ty),
+                    kind: $crate::domain::glossary::ModelElementKind::$kind,
+                    description: $description,
+                    module: module_path!(),
+                    related: vec![$($(stringify!($related)),*)?],
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use domain_model;
+
+/// サーガの1ステップ（イベントを受けたハンドラーが次のイベントを発行する）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SagaStep {
+    /// ステップを開始するイベント（REST APIの操作で開始する場合はNone）
+    pub trigger: Option<&'static str>,
+    /// 処理するハンドラー（REST APIの操作の場合はエンドポイント）
+    pub handler: &'static str,
+    /// 発行しうるイベント
+    pub emits: Vec<&'static str>,
+}
+
+/// サーガ（複数の集約をまたぐ処理の流れ）の説明
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SagaDescription {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub steps: Vec<SagaStep>,
+}
+
+/// ドメインモデル全体の説明（GET /meta/domain-model のレスポンス）
+/// コンテキストマップやドキュメントの自動生成ツール向けの機械可読な形式
+#[derive(Debug, Clone, Serialize)]
+pub struct DomainModelDescription {
+    pub bounded_context: &'static str,
+    pub aggregates: Vec<ModelElement>,
+    pub entities: Vec<ModelElement>,
+    pub value_objects: Vec<ModelElement>,
+    pub events: Vec<ModelElement>,
+    pub sagas: Vec<SagaDescription>,
+}
+
+/// ドメインモデルの要素の登録簿
+#[derive(Debug, Default)]
+pub struct DomainModelRegistry {
+    elements: Vec<ModelElement>,
+    sagas: Vec<SagaDescription>,
+}
+
+impl DomainModelRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 要素を登録
+    pub fn register<T: DescribeModel>(mut self) -> Self {
+        self.elements.push(T::describe());
+        self
+    }
+
+    /// サーガを登録
+    pub fn with_saga(mut self, saga: SagaDescription) -> Self {
+        self.sagas.push(saga);
+        self
+    }
+
+    /// このシステムのドメインモデルをすべて登録した登録簿
+    pub fn bookstore() -> Self {
+        Self::new()
+            // 集約
+            .register::<Order>()
+            .register::<Inventory>()
+            .register::<AllocationQuota>()
+            .register::<CycleCount>()
+            .register::<DeviceRegistration>()
+            .register::<Customer>()
+            // エンティティ
+            .register::<CycleCountLine>()
+            // 値オブジェクト
+            .register::<OrderId>()
+            .register::<BookId>()
+            .register::<CustomerId>()
+            .register::<Money>()
+            .register::<OrderLine>()
+            .register::<OrderStatus>()
+            .register::<HoldReason>()
+            .register::<SalesChannel>()
+            .register::<FulfillmentType>()
+            .register::<BookSpec>()
+            .register::<LineAttribute>()
+            .register::<ShippingAddress>()
+            .register::<Recipient>()
+            .register::<DownloadLink>()
+            .register::<TrackingToken>()
+            .register::<OrderNumber>()
+            .register::<CycleCountId>()
+            .register::<DeviceToken>()
+            .register::<DevicePlatform>()
+            .register::<EmailAddress>()
+            // ドメインイベント
+            .register::<OrderConfirmed>()
+            .register::<OrderCancelled>()
+            .register::<ShippingAddressChanged>()
+            .register::<OrderHeld>()
+            .register::<OrderReleased>()
+            .register::<OrderShipped>()
+            .register::<OrderDelivered>()
+            .register::<ReturnRequested>()
+            .register::<ReturnApproved>()
+            .register::<OrderStatusForcefullyChanged>()
+            .register::<PreOrderActivated>()
+            .register::<WaitlistJoined>()
+            .register::<WaitlistPromoted>()
+            .register::<DigitalItemsFulfilled>()
+            .register::<FulfillmentSlaBreached>()
+            .register::<CarrierTrackingUpdated>()
+            .register::<InventoryReserved>()
+            .register::<InventoryReleased>()
+            .register::<CheckoutHoldExpired>()
+            .register::<ItemsRestocked>()
+            .register::<InventoryAdjusted>()
+            .register::<HighValueOrderPlaced>()
+            .register::<CustomerBecameRepeatBuyer>()
+            .register::<InventoryReservationFailed>()
+            .register::<ShippingFailed>()
+            .register::<DeliveryFailed>()
+            .register::<SagaCompensationStarted>()
+            .register::<SagaCompensationCompleted>()
+            .register::<SagaTimedOut>()
+            .with_saga(order_fulfillment_saga())
+            .with_saga(compensation_saga())
+            .with_saga(order_orchestration_saga())
+    }
+
+    /// 登録した要素を種類ごとにまとめた説明を作成
+    pub fn describe(&self) -> DomainModelDescription {
+        let of_kind = |kind: ModelElementKind| {
+            self.elements
+                .iter()
+                .filter(|element| element.kind == kind)
+                .cloned()
+                .collect()
+        };
+        DomainModelDescription {
+            bounded_context: BOUNDED_CONTEXT,
+            aggregates: of_kind(ModelElementKind::Aggregate),
+            entities: of_kind(ModelElementKind::Entity),
+            value_objects: of_kind(ModelElementKind::ValueObject),
+            events: of_kind(ModelElementKind::DomainEvent),
+            sagas: self.sagas.clone(),
+        }
+    }
+}
+
+fn step(trigger: Option<&'static str>, handler: &'static str, emits: &[&'static str]) -> SagaStep {
+    SagaStep {
+        trigger,
+        handler,
+        emits: emits.to_vec(),
+    }
+}
+
+/// 注文の確定から配達完了までのサーガ（コレオグラフィ）
+fn order_fulfillment_saga() -> SagaDescription {
+    SagaDescription {
+        name: "OrderFulfillment",
+        description: "注文の確定から在庫予約・発送・配達完了・返品までを、集約をまたぐイベントの連鎖で進める",
+        steps: vec![
+            step(None, "POST /orders/:id/confirm", &["OrderConfirmed"]),
+            step(
+                Some("OrderConfirmed"),
+                "InventoryReservationHandler",
+                &["InventoryReserved", "InventoryReservationFailed", "WaitlistJoined"],
+            ),
+            step(
+                Some("PreOrderActivated"),
+                "InventoryReservationHandler",
+                &["InventoryReserved", "InventoryReservationFailed", "WaitlistJoined"],
+            ),
+            step(
+                Some("InventoryAdjusted"),
+                "WaitlistPromotionHandler",
+                &["WaitlistPromoted", "InventoryReserved"],
+            ),
+            step(
+                Some("InventoryReleased"),
+                "WaitlistPromotionHandler",
+                &["WaitlistPromoted", "InventoryReserved"],
+            ),
+            step(None, "CheckoutHoldSweeper", &["CheckoutHoldExpired"]),
+            step(
+                Some("CheckoutHoldExpired"),
+                "WaitlistPromotionHandler",
+                &["WaitlistPromoted", "InventoryReserved"],
+            ),
+            step(
+                Some("OrderCancelled"),
+                "WaitlistPromotionHandler",
+                &["WaitlistPromoted", "InventoryReserved"],
+            ),
+            step(
+                Some("InventoryReserved"),
+                "FulfillmentRouter",
+                &["DigitalItemsFulfilled"],
+            ),
+            step(
+                Some("ShippingAddressChanged"),
+                "ShippingHandler",
+                &["ShippingFailed"],
+            ),
+            // RISK_HOLD_THRESHOLDを設定した場合のみ購読する
+            step(Some("OrderConfirmed"), "RiskHoldHandler", &["OrderHeld"]),
+            step(None, "POST /orders/:id/hold", &["OrderHeld"]),
+            step(None, "POST /orders/:id/release", &["OrderReleased"]),
+            // FULFILLMENT_MODE=auto / hybridの場合のみ購読する
+            step(
+                Some("InventoryReserved"),
+                "ShippingHandler",
+                &["OrderShipped", "ShippingFailed"],
+            ),
+            step(
+                Some("OrderReleased"),
+                "ShippingHandler",
+                &["OrderShipped", "ShippingFailed"],
+            ),
+            step(
+                Some("OrderShipped"),
+                "DeliveryHandler",
+                &["OrderDelivered", "DeliveryFailed"],
+            ),
+            step(None, "POST /orders/:id/ship", &["OrderShipped"]),
+            step(None, "POST /orders/:id/deliver", &["OrderDelivered"]),
+            step(None, "POST /orders/:id/return", &["ReturnRequested"]),
+            step(None, "POST /orders/:id/return/approve", &["ReturnApproved"]),
+            step(Some("ReturnApproved"), "ReturnHandler", &["ItemsRestocked"]),
+            step(
+                None,
+                "POST /admin/orders/:id/force-status",
+                &["OrderStatusForcefullyChanged"],
+            ),
+        ],
+    }
+}
+
+/// 在庫予約・発送・配達の失敗を補償するサーガ
+fn compensation_saga() -> SagaDescription {
+    SagaDescription {
+        name: "Compensation",
+        description: "在庫予約・発送・配達の失敗やタイムアウトを受けて、予約した在庫の解放や注文のキャンセルで整合性を回復する",
+        steps: vec![
+            step(
+                Some("InventoryReservationFailed"),
+                "InventoryReservationFailureCompensationHandler",
+                &["OrderCancelled"],
+            ),
+            step(
+                Some("ShippingFailed"),
+                "ShippingFailureCompensationHandler",
+                &["InventoryReleased"],
+            ),
+            step(
+                Some("DeliveryFailed"),
+                "DeliveryFailureCompensationHandler",
+                &[],
+            ),
+            step(None, "SagaTimeoutMonitor", &["SagaTimedOut", "SagaCompensationStarted"]),
+            step(
+                Some("SagaCompensationStarted"),
+                "SagaCompensationCoordinator",
+                &["SagaCompensationStarted"],
+            ),
+            step(
+                Some("SagaCompensationCompleted"),
+                "CompensationCompletionHandler",
+                &[],
+            ),
+        ],
+    }
+}
+
+/// 注文の確定から発送までのサーガ（オーケストレーション、SAGA_MODE=orchestration）
+fn order_orchestration_saga() -> SagaDescription {
+    SagaDescription {
+        name: "OrderOrchestration",
+        description: "OrderSagaOrchestratorが在庫予約・請求・発送を順に指示し、失敗・タイムアウト時は逆順に取り消して注文をキャンセルする",
+        steps: vec![
+            step(None, "POST /orders/:id/confirm", &["OrderConfirmed"]),
+            step(
+                Some("OrderConfirmed"),
+                "OrderSagaOrchestrator",
+                &[
+                    "InventoryReserved",
+                    "OrderShipped",
+                    "InventoryReservationFailed",
+                    "ShippingFailed",
+                    "InventoryReleased",
+                    "OrderCancelled",
+                ],
+            ),
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event::EVENT_TYPES;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_registry_covers_all_events_and_references_resolve() {
+        let registry = DomainModelRegistry::bookstore();
+        let description = registry.describe();
+
+        // すべてのイベントタイプが説明されている
+        let events: HashSet<&str> = description.events.iter().map(|e| e.name).collect();
+        for event_type in EVENT_TYPES {
+            assert!(events.contains(event_type), "未登録のイベント: {}", event_type);
+        }
+
+        // 関連する要素・サーガのイベントはすべて登録済みの要素を指している
+        let names: HashSet<&str> = registry.elements.iter().map(|e| e.name).collect();
+        for element in &registry.elements {
+            for related in &element.related {
+                assert!(names.contains(related), "{} → {}", element.name, related);
+            }
+        }
+        for saga in &description.sagas {
+            for step in &saga.steps {
+                for event in step.trigger.iter().chain(step.emits.iter()) {
+                    assert!(events.contains(event), "{}: {}", saga.name, event);
+                }
+            }
+        }
+
+        let order = &description.aggregates[0];
+        assert_eq!(order.name, "Order");
+        assert_eq!(order.module, "bookstore_order_management::domain::model::order");
+    }
+}
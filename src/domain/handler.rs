@@ -1,59 +1,140 @@
 use async_trait::async_trait;
-use std::collections::{HashMap, HashSet};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use crate::domain::action_link::{ActionLinkIssuer, CustomerAction};
+use crate::domain::book_translation::Language;
+use crate::domain::checkout_hold::held_quantities;
+use crate::domain::customer_webhook::{WebhookDeliveryStatus, WebhookDispatcher};
+use crate::domain::delivery_estimate::DeliveryEstimator;
+use crate::domain::error::DomainError;
 use crate::domain::event::{
-    CompensationResult, DomainEvent, InventoryReleased, InventoryReservationFailed,
-    InventoryReserved, InventoryReserved as InventoryReservedEvent, OrderCancelled, OrderConfirmed,
-    OrderDelivered, OrderShipped, SagaCompensationCompleted, SagaCompensationStarted,
-    ShippingFailed,
+    CarrierTrackingUpdated, CheckoutHoldExpired, CompensationResult, CustomerBecameRepeatBuyer,
+    DeliveryFailed, DigitalItemsFulfilled, DomainEvent, EventMetadata, FulfillmentSlaBreached,
+    HighValueOrderPlaced, InventoryAdjusted, InventoryReleased, InventoryReservationFailed,
+    InventoryReserved, InventoryReserved as InventoryReservedEvent, ItemsRestocked, OrderCancelled,
+    OrderConfirmed, OrderDelivered, OrderHeld, OrderReleased, OrderShipped,
+    OrderStatusForcefullyChanged, PreOrderActivated, ReturnApproved, ReturnRequested, SagaCompensationCompleted, SagaCompensationStarted, ShippingAddressChanged,
+    ShippingFailed, WaitlistJoined, WaitlistPromoted,
 };
 use crate::domain::event_bus::{EventHandler, HandlerError};
-use crate::domain::model::{Inventory, OrderId, OrderStatus};
-use crate::domain::port::{EventBus, InventoryRepository, Logger, OrderRepository};
-
-/// 処理済みイベントを追跡するためのリポジトリ
-/// 実際の実装では永続化ストレージ（Redis、データベースなど）を使用
+use crate::domain::invariant::describe_violations;
+use crate::domain::late_event::{LateEvent, LateEventAction, LateEventGuard};
+use crate::domain::customer_notification::CustomerNotification;
+use crate::domain::metrics::BusinessMetrics;
+use crate::domain::model::{
+    customer_push_topic, AllocationQuota, BookId, CustomerId, HoldReason, Inventory, Money, Order,
+    OrderId, OrderLine, OrderStatus, Recipient, SalesChannel,
+};
+use crate::domain::notification_retry::{
+    FailedNotification, Notification, NotificationChannel, NotificationRetryPolicy,
+};
+use crate::domain::notification_template::{
+    NotificationTemplateEngine, NotificationTemplateKey, TemplateParams,
+};
+use crate::domain::port::{
+    AllocationQuotaRepository, CarrierDeliveryResult, CheckoutHoldRepository, CustomerRepository, DeliveryResultCallback, DownloadLinkGenerator, EventBus,
+    FailedNotificationRepository, InventoryRepository, Logger, NotificationChannelPort, NotificationSender, OrderRepository,
+    ProcessedEventRepository, PushNotificationPort, RepositoryError,
+    SagaCompensationRepository, Shipment, ShippingCarrierPort, TrackingEventRepository, WaitlistRepository,
+};
+use crate::domain::projection::{
+    CheckpointedProjection, ProjectionCheckpoint, ProjectionProgress, SequencedProjector,
+};
+use crate::domain::saga_metrics::{
+    SagaCompensation, FAILED_STEP_DELIVERY, FAILED_STEP_INVENTORY_RESERVATION, FAILED_STEP_SHIPPING,
+};
+use crate::domain::segmentation::SegmentationRules;
+use crate::domain::shipping_fee::CarrierLimits;
+use crate::domain::sla::SlaStage;
+use crate::domain::tracking::{TrackingEvent, TrackingStage};
+use crate::domain::waitlist::{position_in_queue, WaitlistEntry};
+
+/// 処理済みイベントの追跡
+/// ハンドラーごとに処理済みのイベントIDをProcessedEventRepositoryに記録し、再配信されたイベントを読み飛ばす
+/// 記録は永続化されるため、サービスを再起動しても冪等性が保たれる
 #[derive(Clone)]
 pub struct ProcessedEventTracker {
-    processed_events: Arc<Mutex<HashSet<Uuid>>>,
-}
-
-impl Default for ProcessedEventTracker {
-    fn default() -> Self {
-        Self::new()
-    }
+    repository: Arc<dyn ProcessedEventRepository>,
+    handler_name: &'static str,
+    logger: Arc<dyn Logger>,
 }
 
 impl ProcessedEventTracker {
-    pub fn new() -> Self {
+    /// 新しい処理済みイベントの追跡を作成
+    ///
+    /// # Arguments
+    /// * `repository` - 処理済みイベントリポジトリ
+    /// * `handler_name` - 処理済みのイベントを記録するハンドラー名
+    /// * `logger` - 記録に失敗した場合のログ出力
+    pub fn new(
+        repository: Arc<dyn ProcessedEventRepository>,
+        handler_name: &'static str,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
         Self {
-            processed_events: Arc::new(Mutex::new(HashSet::new())),
+            repository,
+            handler_name,
+            logger,
         }
     }
 
     /// イベントが既に処理済みかチェック
-    pub async fn is_processed(&self, event_id: Uuid) -> bool {
-        let processed = self.processed_events.lock().await;
-        processed.contains(&event_id)
+    pub async fn is_processed(&self, event_id: Uuid) -> Result<bool, HandlerError> {
+        self.repository
+            .is_processed(event_id, self.handler_name)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("処理済みイベントの確認エラー: {}", e))
+                    .at_step("check_idempotency")
+            })
     }
 
     /// イベントを処理済みとしてマーク
+    /// 処理は完了しているため、記録に失敗してもエラーにはせず警告ログを出力する
+    /// （エラーにするとリトライで同じイベントを二重に処理してしまう）
     pub async fn mark_processed(&self, event_id: Uuid) {
-        let mut processed = self.processed_events.lock().await;
-        processed.insert(event_id);
+        if let Err(e) = self
+            .repository
+            .mark_processed(event_id, self.handler_name, Utc::now())
+            .await
+        {
+            let mut context = HashMap::new();
+            context.insert("event_id".to_string(), event_id.to_string());
+            context.insert("error".to_string(), e.to_string());
+            self.logger.warn(
+                self.handler_name,
+                "Failed to record processed event",
+                None,
+                Some(context),
+            );
+        }
     }
 }
 
+/// 遅延イベントとして保留する際のイベント本体を作成
+fn late_event_payload<E: serde::Serialize>(event: &E) -> Result<serde_json::Value, HandlerError> {
+    serde_json::to_value(event).map_err(|e| {
+        HandlerError::ProcessingFailed(format!("イベントのシリアライズエラー: {}", e))
+            .at_step("serialize_event")
+    })
+}
+
 /// 在庫予約ハンドラー
 /// OrderConfirmedイベントを受信して在庫を予約する
+/// 未発売の書籍を含む注文は発売待ちにし、PreOrderActivatedイベントの受信時に在庫を予約する
+#[derive(Clone)]
 pub struct InventoryReservationHandler {
     inventory_repository: Arc<dyn InventoryRepository>,
     order_repository: Arc<dyn OrderRepository>,
     event_bus: Arc<dyn EventBus>,
     processed_events: ProcessedEventTracker,
+    late_events: LateEventGuard,
+    waitlist_repository: Option<Arc<dyn WaitlistRepository>>,
+    checkout_hold_repository: Option<Arc<dyn CheckoutHoldRepository>>,
+    allocation_quota_repository: Option<Arc<dyn AllocationQuotaRepository>>,
     logger: Arc<dyn Logger>,
 }
 
@@ -63,16 +144,55 @@ impl InventoryReservationHandler {
         inventory_repository: Arc<dyn InventoryRepository>,
         order_repository: Arc<dyn OrderRepository>,
         event_bus: Arc<dyn EventBus>,
+        processed_event_repository: Arc<dyn ProcessedEventRepository>,
         logger: Arc<dyn Logger>,
     ) -> Self {
         Self {
             inventory_repository,
             order_repository,
             event_bus,
-            processed_events: ProcessedEventTracker::new(),
+            processed_events: ProcessedEventTracker::new(
+                processed_event_repository,
+                "InventoryReservationHandler",
+                logger.clone(),
+            ),
+            late_events: LateEventGuard::skip_only(logger.clone()),
+            waitlist_repository: None,
+            checkout_hold_repository: None,
+            allocation_quota_repository: None,
             logger,
         }
     }
+
+    /// 遅延イベントの扱いを設定する（未設定の場合は読み飛ばす）
+    pub fn with_late_event_guard(mut self, late_events: LateEventGuard) -> Self {
+        self.late_events = late_events;
+        self
+    }
+
+    /// 順番待ちを設定する（未設定の場合、在庫不足の注文は常に補償フローで失敗させる）
+    pub fn with_waitlist(mut self, waitlist_repository: Arc<dyn WaitlistRepository>) -> Self {
+        self.waitlist_repository = Some(waitlist_repository);
+        self
+    }
+
+    /// チェックアウト中の在庫の仮押さえを設定する（確定した注文の仮押さえを在庫予約に引き継ぐ）
+    pub fn with_checkout_holds(
+        mut self,
+        checkout_hold_repository: Arc<dyn CheckoutHoldRepository>,
+    ) -> Self {
+        self.checkout_hold_repository = Some(checkout_hold_repository);
+        self
+    }
+
+    /// 割当枠を設定する（割当枠のある書籍は、顧客の購入上限と販売チャネルの割当枠の範囲でのみ予約する）
+    pub fn with_allocation_quotas(
+        mut self,
+        allocation_quota_repository: Arc<dyn AllocationQuotaRepository>,
+    ) -> Self {
+        self.allocation_quota_repository = Some(allocation_quota_repository);
+        self
+    }
 }
 
 #[async_trait]
@@ -94,7 +214,7 @@ impl EventHandler<OrderConfirmed> for InventoryReservationHandler {
         if self
             .processed_events
             .is_processed(event.metadata.event_id)
-            .await
+            .await?
         {
             let mut context = HashMap::new();
             context.insert("event_id".to_string(), event.metadata.event_id.to_string());
@@ -114,43 +234,493 @@ impl EventHandler<OrderConfirmed> for InventoryReservationHandler {
             .order_repository
             .find_by_id(event.order_id)
             .await
-            .map_err(|e| HandlerError::RepositoryError(format!("注文取得エラー: {}", e)))?
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
             .ok_or_else(|| {
                 HandlerError::ProcessingFailed(format!(
                     "注文が見つかりません: {:?}",
                     event.order_id
                 ))
+                .at_step("load_order")
             })?;
 
-        // 注文がConfirmed状態でない場合は処理をスキップ（既に処理済み）
-        if order.status() != OrderStatus::Confirmed {
+        // 注文がConfirmed（または在庫の予約前に保留にされたOnHold）状態でない場合は遅延イベントとして扱う
+        // キャンセル済み・発送済みなどの注文に在庫を予約すると在庫数が狂うため、適用はしない
+        if !matches!(order.status(), OrderStatus::Confirmed | OrderStatus::OnHold) {
+            self.late_events
+                .resolve(
+                    "InventoryReservationHandler",
+                    LateEvent {
+                        event_type: "OrderConfirmed",
+                        metadata: &event.metadata,
+                        order_id: event.order_id,
+                        order_status: order.status(),
+                        expected_status: OrderStatus::Confirmed,
+                        compatible: false,
+                        payload: late_event_payload(&event)?,
+                    },
+                )
+                .await?;
+
+            // イベントを処理済みとしてマーク
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+            return Ok(());
+        }
+
+        // 保留中の注文は発売待ち・順番待ちにできないため、在庫の予約だけを済ませて解除を待つ
+        let on_hold = order.status() == OrderStatus::OnHold;
+
+        // 未発売の書籍を含む場合は在庫を予約せずに発売待ちにする（予約注文）
+        if !on_hold && self.contains_unreleased_book(&event.order_lines).await? {
+            let mut order = order;
+            order.mark_as_awaiting_release().map_err(|e| {
+                HandlerError::DomainError(format!("発売待ちへの変更エラー: {}", e))
+                    .at_step("await_release")
+            })?;
+            self.order_repository.save(&mut order).await.map_err(|e| {
+                HandlerError::RepositoryError(format!("注文保存エラー: {}", e))
+                    .at_step("save_order")
+            })?;
+
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+
             let mut context = HashMap::new();
-            context.insert("current_status".to_string(), format!("{:?}", order.status()));
-            context.insert("expected_status".to_string(), "Confirmed".to_string());
-            
-            self.logger.debug(
+            context.insert("event_type".to_string(), "OrderConfirmed".to_string());
+            context.insert("order_id".to_string(), event.order_id.to_string());
+            self.logger.info(
                 "InventoryReservationHandler",
-                "Order is not in Confirmed state, skipping inventory reservation",
+                "Order contains unreleased books, awaiting release before reserving inventory",
                 Some(event.metadata.correlation_id),
                 Some(context),
             );
-            
-            // イベントを処理済みとしてマーク
+            return Ok(());
+        }
+
+        let buyer = (order.customer_id(), order.sales_channel());
+        let held = self.load_held_quantities(event.order_id).await?;
+        if !on_hold
+            && self
+                .join_waitlist_if_needed(
+                    order,
+                    &event.order_lines,
+                    &held,
+                    &event.metadata,
+                    "OrderConfirmed",
+                )
+                .await?
+        {
+            return Ok(());
+        }
+
+        self.reserve_order_lines(
+            event.order_id,
+            buyer,
+            &event.order_lines,
+            &held,
+            &event.metadata,
+            "OrderConfirmed",
+            start_time,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl EventHandler<PreOrderActivated> for InventoryReservationHandler {
+    async fn handle(&self, event: PreOrderActivated) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "PreOrderActivated".to_string());
+        self.logger.info(
+            "InventoryReservationHandler",
+            "Processing PreOrderActivated event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        let start_time = std::time::Instant::now();
+
+        // 冪等性チェック: 既に処理済みのイベントかどうか確認
+        if self
+            .processed_events
+            .is_processed(event.metadata.event_id)
+            .await?
+        {
+            return Ok(());
+        }
+
+        // 注文の現在状態を確認
+        let order = self
+            .order_repository
+            .find_by_id(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!(
+                    "注文が見つかりません: {:?}",
+                    event.order_id
+                ))
+                .at_step("load_order")
+            })?;
+
+        // 有効化後にキャンセルされた場合などは遅延イベントとして扱う
+        if order.status() != OrderStatus::Confirmed {
+            self.late_events
+                .resolve(
+                    "InventoryReservationHandler",
+                    LateEvent {
+                        event_type: "PreOrderActivated",
+                        metadata: &event.metadata,
+                        order_id: event.order_id,
+                        order_status: order.status(),
+                        expected_status: OrderStatus::Confirmed,
+                        compatible: false,
+                        payload: late_event_payload(&event)?,
+                    },
+                )
+                .await?;
             self.processed_events
                 .mark_processed(event.metadata.event_id)
                 .await;
             return Ok(());
         }
 
-        // 各注文明細について在庫を予約
-        for order_line in &event.order_lines {
+        let buyer = (order.customer_id(), order.sales_channel());
+        let held = self.load_held_quantities(event.order_id).await?;
+        if self
+            .join_waitlist_if_needed(
+                order,
+                &event.order_lines,
+                &held,
+                &event.metadata,
+                "PreOrderActivated",
+            )
+            .await?
+        {
+            return Ok(());
+        }
+
+        self.reserve_order_lines(
+            event.order_id,
+            buyer,
+            &event.order_lines,
+            &held,
+            &event.metadata,
+            "PreOrderActivated",
+            start_time,
+        )
+        .await
+    }
+}
+
+impl InventoryReservationHandler {
+    /// 割当枠のある書籍について、注文の数量を割り当てた割当枠を返す（保存はしない）
+    /// 割当枠が設定されていない場合は空を返す
+    /// 顧客の購入上限または販売チャネルの割当枠を超える場合はDomainError::QuotaExceededを返す
+    async fn allocate_quotas(
+        &self,
+        order_id: OrderId,
+        customer_id: CustomerId,
+        sales_channel: SalesChannel,
+        order_lines: &[OrderLine],
+    ) -> Result<Result<Vec<AllocationQuota>, DomainError>, HandlerError> {
+        let Some(repository) = &self.allocation_quota_repository else {
+            return Ok(Ok(Vec::new()));
+        };
+
+        let mut quotas: Vec<AllocationQuota> = Vec::new();
+        for order_line in order_lines.iter().filter(|line| !line.is_digital()) {
+            let position = match quotas
+                .iter()
+                .position(|quota| quota.book_id() == order_line.book_id())
+            {
+                Some(position) => position,
+                None => {
+                    let quota = repository
+                        .find_by_book_id(order_line.book_id())
+                        .await
+                        .map_err(|e| {
+                            HandlerError::RepositoryError(format!("割当枠取得エラー: {}", e))
+                                .at_step("load_allocation_quota")
+                        })?;
+                    let Some(quota) = quota else {
+                        continue;
+                    };
+                    quotas.push(quota);
+                    quotas.len() - 1
+                }
+            };
+            if let Err(domain_error) =
+                quotas[position].allocate(order_id, customer_id, sales_channel, order_line.quantity())
+            {
+                return Ok(Err(domain_error));
+            }
+        }
+        Ok(Ok(quotas))
+    }
+
+    /// 在庫の予約に失敗した注文の補償イベント（InventoryReservationFailed）を発行し、ハンドラーが返すエラーを返す
+    async fn fail_reservation(
+        &self,
+        order_id: OrderId,
+        order_lines: &[OrderLine],
+        domain_error: DomainError,
+        metadata: &EventMetadata,
+        event_type: &str,
+        start_time: std::time::Instant,
+    ) -> HandlerError {
+        let failure_reason = match domain_error {
+            DomainError::InventoryFrozen(_) => format!("在庫凍結中: {}", domain_error),
+            DomainError::QuotaExceeded(_) => format!("割当枠超過: {}", domain_error),
+            _ => format!("在庫不足: {}", domain_error),
+        };
+        let compensation_event = InventoryReservationFailed::with_correlation_id(
+            order_id,
+            order_lines.to_vec(),
+            failure_reason.clone(),
+            metadata.event_id,
+            metadata.correlation_id,
+        );
+
+        if let Err(e) = self
+            .event_bus
+            .publish(DomainEvent::InventoryReservationFailed(compensation_event))
+            .await
+        {
+            return HandlerError::ProcessingFailed(format!("補償イベント発行エラー: {}", e))
+                .at_step("publish_compensation_event");
+        }
+
+        // エラーログ出力
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), event_type.to_string());
+        context.insert("error".to_string(), failure_reason.clone());
+        context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
+
+        self.logger.error(
+            "InventoryReservationHandler",
+            &format!("{} event processing failed: {}", event_type, failure_reason),
+            Some(metadata.correlation_id),
+            Some(context),
+        );
+
+        // イベントを処理済みとしてマーク（失敗した場合でも重複処理を防ぐ）
+        self.processed_events
+            .mark_processed(metadata.event_id)
+            .await;
+
+        HandlerError::DomainError(format!("在庫予約エラー: {}", domain_error))
+            .at_step("reserve_inventory")
+    }
+
+    /// 注文明細に未発売の書籍が含まれるかチェック
+    async fn contains_unreleased_book(
+        &self,
+        order_lines: &[OrderLine],
+    ) -> Result<bool, HandlerError> {
+        let today = Utc::now().date_naive();
+        for order_line in order_lines {
+            let inventory = self
+                .inventory_repository
+                .find_by_book_id(order_line.book_id())
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("load_inventory")
+                })?;
+            if inventory.is_some_and(|inventory| !inventory.is_released(today)) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// 注文がチェックアウト中に仮押さえした在庫の数量を書籍ごとに取得する（仮押さえが未設定の場合は空）
+    async fn load_held_quantities(
+        &self,
+        order_id: OrderId,
+    ) -> Result<HashMap<BookId, u32>, HandlerError> {
+        let Some(checkout_hold_repository) = &self.checkout_hold_repository else {
+            return Ok(HashMap::new());
+        };
+        let holds = checkout_hold_repository
+            .find_by_order(order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("仮押さえ取得エラー: {}", e))
+                    .at_step("load_checkout_holds")
+            })?;
+        Ok(held_quantities(&holds))
+    }
+
+    /// 順番待ちが有効な書籍の在庫が足りない場合、在庫を予約せずに注文を順番待ちにする
+    /// 仮押さえしている数量は確保済みとして扱う
+    /// 既に順番待ちがある書籍は、在庫が足りていても先に並んでいる注文を追い越さないよう順番待ちにする
+    /// 順番待ちにした場合はtrueを返す
+    async fn join_waitlist_if_needed(
+        &self,
+        mut order: Order,
+        order_lines: &[OrderLine],
+        held: &HashMap<BookId, u32>,
+        metadata: &EventMetadata,
+        event_type: &str,
+    ) -> Result<bool, HandlerError> {
+        let Some(waitlist_repository) = &self.waitlist_repository else {
+            return Ok(false);
+        };
+
+        let mut entries = Vec::new();
+        for order_line in order_lines.iter().filter(|line| !line.is_digital()) {
+            let inventory = self
+                .inventory_repository
+                .find_by_book_id(order_line.book_id())
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("load_inventory")
+                })?;
+            let required = order_line
+                .quantity()
+                .saturating_sub(held.get(&order_line.book_id()).copied().unwrap_or(0));
+            let inventory = match inventory {
+                Some(inventory) if inventory.is_waitlist_enabled() => inventory,
+                inventory => {
+                    // 順番待ちの対象外の書籍が在庫不足の場合は、待っても予約できないため通常どおり失敗させる
+                    if !inventory.is_some_and(|inventory| inventory.has_available_stock(required)) {
+                        return Ok(false);
+                    }
+                    continue;
+                }
+            };
+            let queue = waitlist_repository
+                .find_by_book(order_line.book_id())
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("順番待ち取得エラー: {}", e))
+                        .at_step("load_waitlist")
+                })?;
+            if !queue.is_empty() || !inventory.has_available_stock(required) {
+                entries.push(WaitlistEntry::new(
+                    order.id(),
+                    order.customer_id(),
+                    order_line.book_id(),
+                    order_line.quantity(),
+                ));
+            }
+        }
+        if entries.is_empty() {
+            return Ok(false);
+        }
+
+        waitlist_repository.join(&entries).await.map_err(|e| {
+            HandlerError::RepositoryError(format!("順番待ち登録エラー: {}", e))
+                .at_step("join_waitlist")
+        })?;
+        order.mark_as_waitlisted().map_err(|e| {
+            HandlerError::DomainError(format!("順番待ちへの変更エラー: {}", e))
+                .at_step("join_waitlist")
+        })?;
+        self.order_repository.save(&mut order).await.map_err(|e| {
+            HandlerError::RepositoryError(format!("注文保存エラー: {}", e)).at_step("save_order")
+        })?;
+
+        // 登録後の順番を通知するため、書籍ごとの順番待ちを取得し直す
+        let mut positions = Vec::new();
+        for entry in &entries {
+            let queue = waitlist_repository
+                .find_by_book(entry.book_id)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("順番待ち取得エラー: {}", e))
+                        .at_step("load_waitlist")
+                })?;
+            positions.extend(position_in_queue(&queue, order.id()));
+        }
+        let joined_event = WaitlistJoined::with_correlation_id(
+            order.id(),
+            order.customer_id(),
+            positions,
+            metadata.correlation_id,
+        );
+        self.event_bus
+            .publish(DomainEvent::WaitlistJoined(joined_event))
+            .await
+            .map_err(|e| {
+                HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                    .at_step("publish_event")
+            })?;
+
+        self.processed_events
+            .mark_processed(metadata.event_id)
+            .await;
+
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), event_type.to_string());
+        context.insert("order_id".to_string(), order.id().to_string());
+        context.insert("waitlisted_books".to_string(), entries.len().to_string());
+        self.logger.info(
+            "InventoryReservationHandler",
+            "Inventory is short for waitlist-enabled books, order joined the waitlist",
+            Some(metadata.correlation_id),
+            Some(context),
+        );
+        Ok(true)
+    }
+
+    /// 注文明細の在庫を予約し、InventoryReservedイベントを発行する
+    /// 在庫不足・在庫の凍結中・割当枠の超過の場合はInventoryReservationFailed（補償イベント）を発行する
+    /// チェックアウト中に仮押さえした数量は予約済みとして引き継ぎ、不足分だけを予約する
+    #[allow(clippy::too_many_arguments)]
+    async fn reserve_order_lines(
+        &self,
+        order_id: OrderId,
+        (customer_id, sales_channel): (CustomerId, SalesChannel),
+        order_lines: &[OrderLine],
+        held: &HashMap<BookId, u32>,
+        metadata: &EventMetadata,
+        event_type: &str,
+        start_time: std::time::Instant,
+    ) -> Result<(), HandlerError> {
+        // 割当枠のある書籍は、在庫を予約する前に注文の全数量を割り当てる（仮押さえした数量も含む）
+        let quotas = match self
+            .allocate_quotas(order_id, customer_id, sales_channel, order_lines)
+            .await?
+        {
+            Ok(quotas) => quotas,
+            Err(domain_error) => {
+                return Err(self
+                    .fail_reservation(
+                        order_id,
+                        order_lines,
+                        domain_error,
+                        metadata,
+                        event_type,
+                        start_time,
+                    )
+                    .await)
+            }
+        };
+
+        // 各注文明細について在庫を予約（電子書籍は在庫を持たないため対象外）
+        for order_line in order_lines.iter().filter(|line| !line.is_digital()) {
             // 在庫を取得
             let mut inventory = match self
                 .inventory_repository
                 .find_by_book_id(order_line.book_id())
                 .await
-                .map_err(|e| HandlerError::RepositoryError(format!("在庫取得エラー: {}", e)))?
-            {
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("load_inventory")
+                })? {
                 Some(inventory) => inventory,
                 None => {
                     // 在庫が見つからない場合は新しい在庫を作成（在庫数0）
@@ -158,8 +728,14 @@ impl EventHandler<OrderConfirmed> for InventoryReservationHandler {
                 }
             };
 
-            // 在庫を予約（失敗時は補償イベントを発行）
-            match inventory.reserve(order_line.quantity()) {
+            // 仮押さえで不足する分の在庫を予約（失敗時は補償イベントを発行）
+            let required = order_line
+                .quantity()
+                .saturating_sub(held.get(&order_line.book_id()).copied().unwrap_or(0));
+            if required == 0 {
+                continue;
+            }
+            match inventory.reserve(required) {
                 Ok(()) => {
                     // 在庫を保存
                     self.inventory_repository
@@ -167,492 +743,690 @@ impl EventHandler<OrderConfirmed> for InventoryReservationHandler {
                         .await
                         .map_err(|e| {
                             HandlerError::RepositoryError(format!("在庫保存エラー: {}", e))
+                                .at_step("save_inventory")
                         })?;
                 }
                 Err(domain_error) => {
-                    // 在庫予約失敗 - 補償イベントを発行
-                    let failure_reason = format!("在庫不足: {}", domain_error);
-                    let compensation_event = InventoryReservationFailed::with_correlation_id(
-                        event.order_id,
-                        event.order_lines.clone(),
-                        failure_reason.clone(),
-                        event.metadata.event_id,
-                        event.metadata.correlation_id,
-                    );
-
-                    self.event_bus
-                        .publish(DomainEvent::InventoryReservationFailed(compensation_event))
-                        .await
-                        .map_err(|e| {
-                            HandlerError::ProcessingFailed(format!("補償イベント発行エラー: {}", e))
-                        })?;
+                    return Err(self
+                        .fail_reservation(
+                            order_id,
+                            order_lines,
+                            domain_error,
+                            metadata,
+                            event_type,
+                            start_time,
+                        )
+                        .await)
+                }
+            }
+        }
 
-                    // エラーログ出力
-                    let mut context = HashMap::new();
-                    context.insert("event_type".to_string(), "OrderConfirmed".to_string());
-                    context.insert("error".to_string(), failure_reason.clone());
-                    context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
-                    
-                    self.logger.error(
-                        "InventoryReservationHandler",
-                        &format!("OrderConfirmed event processing failed: {}", failure_reason),
-                        Some(event.metadata.correlation_id),
-                        Some(context),
-                    );
+        // 在庫を予約できた場合のみ割当を記録する
+        if let Some(repository) = &self.allocation_quota_repository {
+            for quota in &quotas {
+                repository.save(quota).await.map_err(|e| {
+                    HandlerError::RepositoryError(format!("割当枠保存エラー: {}", e))
+                        .at_step("save_allocation_quota")
+                })?;
+            }
+        }
 
-                    // イベントを処理済みとしてマーク（失敗した場合でも重複処理を防ぐ）
-                    self.processed_events
-                        .mark_processed(event.metadata.event_id)
-                        .await;
+        self.convert_checkout_holds(order_id, order_lines, held, metadata)
+            .await?;
 
-                    return Err(HandlerError::DomainError(format!(
-                        "在庫予約エラー: {}",
-                        domain_error
-                    )));
-                }
-            }
+        // 明細属性（サイン本希望などの要望）をピッキング指示として倉庫に伝える
+        for order_line in order_lines
+            .iter()
+            .filter(|line| !line.is_digital() && !line.attributes().is_empty())
+        {
+            let attributes: Vec<String> = order_line
+                .attributes()
+                .iter()
+                .map(|attribute| format!("{}={}", attribute.key(), attribute.value()))
+                .collect();
+            let mut context = HashMap::new();
+            context.insert("order_id".to_string(), order_id.to_string());
+            context.insert("book_id".to_string(), order_line.book_id().to_string());
+            context.insert("attributes".to_string(), attributes.join(", "));
+            self.logger.info(
+                "InventoryReservationHandler",
+                "Forwarding line attributes to picking",
+                Some(metadata.correlation_id),
+                Some(context),
+            );
         }
 
         // InventoryReservedイベントを発行
         let inventory_reserved_event = InventoryReservedEvent::with_correlation_id(
-            event.order_id,
-            event.order_lines.clone(),
-            event.metadata.correlation_id,
+            order_id,
+            order_lines.to_vec(),
+            metadata.correlation_id,
         );
 
         self.event_bus
             .publish(DomainEvent::InventoryReserved(inventory_reserved_event))
             .await
-            .map_err(|e| HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e)))?;
+            .map_err(|e| {
+                HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                    .at_step("publish_event")
+            })?;
 
         // イベントを処理済みとしてマーク（成功時）
         self.processed_events
-            .mark_processed(event.metadata.event_id)
+            .mark_processed(metadata.event_id)
             .await;
 
         // 処理成功ログ
         let execution_time = start_time.elapsed();
         let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
+        context.insert("event_type".to_string(), event_type.to_string());
         context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
         
         self.logger.info(
             "InventoryReservationHandler",
-            "OrderConfirmed event processed successfully",
-            Some(event.metadata.correlation_id),
+            &format!("{} event processed successfully", event_type),
+            Some(metadata.correlation_id),
             Some(context),
         );
 
         Ok(())
     }
-}
 
-/// 発送ハンドラー
-/// InventoryReservedイベントを受信して注文を発送可能状態にする
-pub struct ShippingHandler {
+    /// 在庫予約に引き継いだ仮押さえを削除する
+    /// 確定までに数量を減らした書籍・注文から外した書籍の仮押さえの余りは在庫に戻す
+    async fn convert_checkout_holds(
+        &self,
+        order_id: OrderId,
+        order_lines: &[OrderLine],
+        held: &HashMap<BookId, u32>,
+        metadata: &EventMetadata,
+    ) -> Result<(), HandlerError> {
+        let Some(checkout_hold_repository) = &self.checkout_hold_repository else {
+            return Ok(());
+        };
+        if held.is_empty() {
+            return Ok(());
+        }
+
+        for (book_id, held_quantity) in held {
+            let quantity = order_lines
+                .iter()
+                .filter(|line| !line.is_digital() && line.book_id() == *book_id)
+                .map(OrderLine::quantity)
+                .sum::<u32>();
+            if *held_quantity <= quantity {
+                continue;
+            }
+            let inventory = self
+                .inventory_repository
+                .find_by_book_id(*book_id)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("release_checkout_hold")
+                })?;
+            if let Some(mut inventory) = inventory {
+                inventory.release(held_quantity - quantity).map_err(|e| {
+                    HandlerError::DomainError(format!("仮押さえの解放エラー: {}", e))
+                        .at_step("release_checkout_hold")
+                })?;
+                self.inventory_repository
+                    .save(&inventory)
+                    .await
+                    .map_err(|e| {
+                        HandlerError::RepositoryError(format!("在庫保存エラー: {}", e))
+                            .at_step("release_checkout_hold")
+                    })?;
+            }
+        }
+
+        checkout_hold_repository
+            .remove_order(order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("仮押さえ削除エラー: {}", e))
+                    .at_step("convert_checkout_hold")
+            })?;
+
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), order_id.to_string());
+        context.insert("held_books".to_string(), held.len().to_string());
+        self.logger.info(
+            "InventoryReservationHandler",
+            "Checkout hold converted into inventory reservation",
+            Some(metadata.correlation_id),
+            Some(context),
+        );
+        Ok(())
+    }
+}
+
+/// 順番待ちの注文の繰り上げ結果
+enum Promotion {
+    /// 在庫を予約して繰り上げた（注文が並んでいた書籍）
+    Promoted(Vec<BookId>),
+    /// キャンセル済みなどで順番待ちから外した（注文が並んでいた書籍）
+    Removed(Vec<BookId>),
+    /// 先頭に並んでいない書籍があるか、在庫が足りないため繰り上げられない
+    Blocked,
+}
+
+/// 順番待ち繰り上げハンドラー
+/// 入荷（InventoryAdjusted）や在庫の解放（InventoryReleased）、順番待ちの注文のキャンセル（OrderCancelled）を受信し、
+/// 書籍ごとの順番待ちを先頭から繰り上げる
+/// 公平性のため、先頭の注文の在庫が足りない場合は後ろの注文を追い越して繰り上げない
+#[derive(Clone)]
+pub struct WaitlistPromotionHandler {
+    waitlist_repository: Arc<dyn WaitlistRepository>,
+    inventory_repository: Arc<dyn InventoryRepository>,
     order_repository: Arc<dyn OrderRepository>,
     event_bus: Arc<dyn EventBus>,
-    processed_events: ProcessedEventTracker,
+    delivery_estimator: DeliveryEstimator,
     logger: Arc<dyn Logger>,
 }
 
-impl ShippingHandler {
-    /// 新しい発送ハンドラーを作成
-    pub fn new(order_repository: Arc<dyn OrderRepository>, event_bus: Arc<dyn EventBus>, logger: Arc<dyn Logger>) -> Self {
+impl WaitlistPromotionHandler {
+    /// 新しい順番待ち繰り上げハンドラーを作成
+    pub fn new(
+        waitlist_repository: Arc<dyn WaitlistRepository>,
+        inventory_repository: Arc<dyn InventoryRepository>,
+        order_repository: Arc<dyn OrderRepository>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
         Self {
+            waitlist_repository,
+            inventory_repository,
             order_repository,
             event_bus,
-            processed_events: ProcessedEventTracker::new(),
+            delivery_estimator: DeliveryEstimator::default(),
             logger,
         }
     }
-}
 
-#[async_trait]
-impl EventHandler<InventoryReserved> for ShippingHandler {
-    async fn handle(&self, event: InventoryReserved) -> Result<(), HandlerError> {
-        // ハンドラー開始ログ
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "InventoryReserved".to_string());
-        self.logger.info(
-            "ShippingHandler",
-            "Processing InventoryReserved event",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
+    /// 繰り上げた注文の配達予定日の見積もりを設定する
+    pub fn with_delivery_estimator(mut self, delivery_estimator: DeliveryEstimator) -> Self {
+        self.delivery_estimator = delivery_estimator;
+        self
+    }
 
-        let start_time = std::time::Instant::now();
+    /// 書籍の順番待ちを先頭から繰り上げる
+    /// 繰り上げた注文が他の書籍にも並んでいた場合は、その書籍の順番待ちも続けて繰り上げる
+    async fn promote_waiting_orders(
+        &self,
+        book_ids: Vec<BookId>,
+        correlation_id: Uuid,
+    ) -> Result<(), HandlerError> {
+        let mut pending = book_ids;
+        while let Some(book_id) = pending.pop() {
+            loop {
+                let queue = self.find_queue(book_id).await?;
+                let Some(head) = queue.first() else {
+                    break;
+                };
+                match self.try_promote(head.order_id, correlation_id).await? {
+                    Promotion::Promoted(books) | Promotion::Removed(books) => {
+                        for other in books {
+                            if other != book_id && !pending.contains(&other) {
+                                pending.push(other);
+                            }
+                        }
+                    }
+                    Promotion::Blocked => break,
+                }
+            }
+        }
+        Ok(())
+    }
 
-        // 冪等性チェック: 既に処理済みのイベントかどうか確認
-        if self
-            .processed_events
-            .is_processed(event.metadata.event_id)
+    async fn find_queue(&self, book_id: BookId) -> Result<Vec<WaitlistEntry>, HandlerError> {
+        self.waitlist_repository
+            .find_by_book(book_id)
             .await
-        {
-            let mut context = HashMap::new();
-            context.insert("event_id".to_string(), event.metadata.event_id.to_string());
-            context.insert("already_processed".to_string(), "true".to_string());
-            
-            self.logger.debug(
-                "ShippingHandler",
-                "Idempotency check: Event already processed, skipping",
-                Some(event.metadata.correlation_id),
-                Some(context),
-            );
-            return Ok(());
-        }
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("順番待ち取得エラー: {}", e))
+                    .at_step("load_waitlist")
+            })
+    }
 
-        // 注文を取得
-        let mut order = self
+    async fn remove_from_waitlist(&self, order_id: OrderId) -> Result<(), HandlerError> {
+        self.waitlist_repository
+            .remove_order(order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("順番待ち削除エラー: {}", e))
+                    .at_step("remove_from_waitlist")
+            })
+    }
+
+    /// 順番待ちの注文の在庫を予約して繰り上げ、WaitlistPromotedとInventoryReservedを発行する
+    /// InventoryReservedにより、通常の注文と同じく発送準備のフローへ進む
+    async fn try_promote(
+        &self,
+        order_id: OrderId,
+        correlation_id: Uuid,
+    ) -> Result<Promotion, HandlerError> {
+        let entries = self
+            .waitlist_repository
+            .find_by_order(order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("順番待ち取得エラー: {}", e))
+                    .at_step("load_waitlist")
+            })?;
+        let book_ids: Vec<BookId> = entries.iter().map(|entry| entry.book_id).collect();
+
+        let order = self
             .order_repository
-            .find_by_id(event.order_id)
+            .find_by_id(order_id)
             .await
-            .map_err(|e| HandlerError::RepositoryError(format!("注文取得エラー: {}", e)))?
-            .ok_or_else(|| {
-                HandlerError::ProcessingFailed(format!(
-                    "注文が見つかりません: {:?}",
-                    event.order_id
-                ))
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
             })?;
+        let Some(mut order) = order.filter(|order| order.status() == OrderStatus::Waitlisted)
+        else {
+            // キャンセル済みなど、もう待っていない注文は順番待ちから外す
+            self.remove_from_waitlist(order_id).await?;
+            return Ok(Promotion::Removed(book_ids));
+        };
 
-        // 注文がConfirmed状態でない場合は処理をスキップ（既に処理済みまたは無効な状態）
-        if order.status() != OrderStatus::Confirmed {
-            let mut context = HashMap::new();
-            context.insert("current_status".to_string(), format!("{:?}", order.status()));
-            context.insert("expected_status".to_string(), "Confirmed".to_string());
-            
-            self.logger.debug(
-                "ShippingHandler",
-                "Order is not in Confirmed state, skipping shipping",
-                Some(event.metadata.correlation_id),
-                Some(context),
-            );
-            
-            // イベントを処理済みとしてマーク
-            self.processed_events
-                .mark_processed(event.metadata.event_id)
-                .await;
-            return Ok(());
+        // 並んでいるすべての書籍で先頭でなければ、先に並んだ注文の在庫を横取りしてしまう
+        for book_id in &book_ids {
+            let queue = self.find_queue(*book_id).await?;
+            if queue.first().map(|head| head.order_id) != Some(order_id) {
+                return Ok(Promotion::Blocked);
+            }
         }
 
-        // 注文を発送済みにマーク（失敗時は補償イベントを発行）
-        match order.mark_as_shipped() {
-            Ok(()) => {
-                // 注文を保存
-                self.order_repository
-                    .save(&order)
-                    .await
-                    .map_err(|e| HandlerError::RepositoryError(format!("注文保存エラー: {}", e)))?;
-
-                let shipping_address = order
-                    .shipping_address()
-                    .expect("Confirmed状態の注文には配送先住所が必須です")
-                    .clone();
-                let shipped_event = crate::domain::event::OrderShipped::with_correlation_id(
-                    order.id(),
-                    shipping_address,
-                    event.metadata.correlation_id,
-                );
-                let domain_event = crate::domain::event::DomainEvent::OrderShipped(shipped_event);
-
-                self.event_bus.publish(domain_event).await.map_err(|e| {
-                    HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+        let mut inventories = Vec::new();
+        for order_line in order.order_lines().iter().filter(|line| !line.is_digital()) {
+            let inventory = self
+                .inventory_repository
+                .find_by_book_id(order_line.book_id())
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("load_inventory")
                 })?;
+            let Some(mut inventory) = inventory else {
+                return Ok(Promotion::Blocked);
+            };
+            if inventory.reserve(order_line.quantity()).is_err() {
+                return Ok(Promotion::Blocked);
             }
-            Err(domain_error) => {
-                // 発送失敗 - 補償イベントを発行
-                let failure_reason = format!("発送処理失敗: {}", domain_error);
-                let compensation_event = ShippingFailed::with_correlation_id(
-                    event.order_id,
-                    failure_reason.clone(),
-                    event.metadata.event_id,
-                    event.metadata.correlation_id,
-                );
-
-                self.event_bus
-                    .publish(DomainEvent::ShippingFailed(compensation_event))
-                    .await
-                    .map_err(|e| {
-                        HandlerError::ProcessingFailed(format!("補償イベント発行エラー: {}", e))
-                    })?;
-
-                // エラーログ出力
-                let mut context = HashMap::new();
-                context.insert("event_type".to_string(), "InventoryReserved".to_string());
-                context.insert("error".to_string(), failure_reason.clone());
-                context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
-                
-                self.logger.error(
-                    "ShippingHandler",
-                    &format!("InventoryReserved event processing failed: {}", failure_reason),
-                    Some(event.metadata.correlation_id),
-                    Some(context),
-                );
-
-                // イベントを処理済みとしてマーク（失敗した場合でも重複処理を防ぐ）
-                self.processed_events
-                    .mark_processed(event.metadata.event_id)
-                    .await;
-
-                return Err(HandlerError::DomainError(format!(
-                    "発送マークエラー: {}",
-                    domain_error
-                )));
-            }
+            inventories.push(inventory);
         }
 
-        // イベントを処理済みとしてマーク（成功時）
-        self.processed_events
-            .mark_processed(event.metadata.event_id)
-            .await;
+        for inventory in &inventories {
+            self.inventory_repository
+                .save(inventory)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫保存エラー: {}", e))
+                        .at_step("save_inventory")
+                })?;
+        }
+        order.promote_from_waitlist().map_err(|e| {
+            HandlerError::DomainError(format!("順番待ちの繰り上げエラー: {}", e))
+                .at_step("promote_order")
+        })?;
+        order.estimate_delivery(&self.delivery_estimator);
+        self.order_repository.save(&mut order).await.map_err(|e| {
+            HandlerError::RepositoryError(format!("注文保存エラー: {}", e)).at_step("save_order")
+        })?;
+        self.remove_from_waitlist(order_id).await?;
+
+        let promoted_event = WaitlistPromoted::with_correlation_id(
+            order_id,
+            order.customer_id(),
+            order.order_lines().to_vec(),
+            correlation_id,
+        );
+        let reserved_event = InventoryReservedEvent::with_correlation_id(
+            order_id,
+            order.order_lines().to_vec(),
+            correlation_id,
+        );
+        for event in [
+            DomainEvent::WaitlistPromoted(promoted_event),
+            DomainEvent::InventoryReserved(reserved_event),
+        ] {
+            self.event_bus.publish(event).await.map_err(|e| {
+                HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                    .at_step("publish_event")
+            })?;
+        }
 
-        // 処理成功ログ
-        let execution_time = start_time.elapsed();
         let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "InventoryReserved".to_string());
-        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
-        
+        context.insert("order_id".to_string(), order_id.to_string());
         self.logger.info(
-            "ShippingHandler",
-            "InventoryReserved event processed successfully",
-            Some(event.metadata.correlation_id),
+            "WaitlistPromotionHandler",
+            "Waitlisted order promoted after inventory became available",
+            Some(correlation_id),
             Some(context),
         );
+        Ok(Promotion::Promoted(book_ids))
+    }
+}
 
-        Ok(())
+#[async_trait]
+impl EventHandler<InventoryAdjusted> for WaitlistPromotionHandler {
+    async fn handle(&self, event: InventoryAdjusted) -> Result<(), HandlerError> {
+        // 在庫が増えた場合のみ繰り上げの余地がある
+        if event.variance <= 0 {
+            return Ok(());
+        }
+        self.promote_waiting_orders(vec![event.book_id], event.metadata.correlation_id)
+            .await
     }
 }
 
-/// 通知ハンドラー
-/// 各種注文イベントを受信して通知を送信する
+#[async_trait]
+impl EventHandler<InventoryReleased> for WaitlistPromotionHandler {
+    async fn handle(&self, event: InventoryReleased) -> Result<(), HandlerError> {
+        let book_ids = event
+            .order_lines
+            .iter()
+            .filter(|line| !line.is_digital())
+            .map(|line| line.book_id())
+            .collect();
+        self.promote_waiting_orders(book_ids, event.metadata.correlation_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl EventHandler<CheckoutHoldExpired> for WaitlistPromotionHandler {
+    async fn handle(&self, event: CheckoutHoldExpired) -> Result<(), HandlerError> {
+        let book_ids = held_quantities(&event.holds).into_keys().collect();
+        self.promote_waiting_orders(book_ids, event.metadata.correlation_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl EventHandler<OrderCancelled> for WaitlistPromotionHandler {
+    async fn handle(&self, event: OrderCancelled) -> Result<(), HandlerError> {
+        // 順番待ちの注文がキャンセルされた場合、後ろに並んでいた注文が先頭になる
+        let entries = self
+            .waitlist_repository
+            .find_by_order(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("順番待ち取得エラー: {}", e))
+                    .at_step("load_waitlist")
+            })?;
+        if entries.is_empty() {
+            return Ok(());
+        }
+        self.remove_from_waitlist(event.order_id).await?;
+        self.promote_waiting_orders(
+            entries.iter().map(|entry| entry.book_id).collect(),
+            event.metadata.correlation_id,
+        )
+        .await
+    }
+}
+
+/// 割当返却ハンドラー
+/// OrderCancelled・InventoryReleasedイベントを受信して、注文に割り当てた数量を割当枠に戻す
+/// 割当は注文ごとに記録しているため、同じ注文で両方のイベントを受信しても一度だけ戻す
 #[derive(Clone)]
-pub struct NotificationHandler {
+pub struct AllocationReturnHandler {
+    allocation_quota_repository: Arc<dyn AllocationQuotaRepository>,
     logger: Arc<dyn Logger>,
 }
 
-impl NotificationHandler {
-    /// 新しい通知ハンドラーを作成
-    pub fn new(logger: Arc<dyn Logger>) -> Self {
-        Self { logger }
+impl AllocationReturnHandler {
+    /// 新しい割当返却ハンドラーを作成
+    pub fn new(
+        allocation_quota_repository: Arc<dyn AllocationQuotaRepository>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            allocation_quota_repository,
+            logger,
+        }
     }
 
-    /// 通知メッセージを送信（実装では外部サービスを呼び出し）
-    async fn send_notification(
+    /// 注文の書籍の割当枠から、注文に割り当てた数量を戻す（電子書籍は割り当てないため対象外）
+    async fn return_allocations(
         &self,
-        message: &str,
+        order_id: OrderId,
+        order_lines: &[OrderLine],
         correlation_id: Uuid,
     ) -> Result<(), HandlerError> {
-        // 実際の実装では外部通知サービス（メール、SMS、プッシュ通知など）を呼び出し
-        // 今回はログ出力で代用
-        let mut context = HashMap::new();
-        context.insert("notification_type".to_string(), "General".to_string());
-        context.insert("recipient".to_string(), "customer".to_string());
-        
-        self.logger.info(
-            "NotificationHandler",
-            &format!("Notification sent: General"),
-            Some(correlation_id),
-            Some(context),
-        );
-
-        // 通知内容もログに記録
-        self.logger.info("NotificationHandler", message, Some(correlation_id), None);
+        let mut book_ids: Vec<BookId> = order_lines
+            .iter()
+            .filter(|line| !line.is_digital())
+            .map(|line| line.book_id())
+            .collect();
+        book_ids.sort_by_key(|book_id| book_id.to_string());
+        book_ids.dedup();
+
+        for book_id in book_ids {
+            let Some(mut quota) = self
+                .allocation_quota_repository
+                .find_by_book_id(book_id)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("割当枠取得エラー: {}", e))
+                        .at_step("load_allocation_quota")
+                })?
+            else {
+                continue;
+            };
+            if !quota.release_order(order_id) {
+                continue;
+            }
+            self.allocation_quota_repository
+                .save(&quota)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("割当枠保存エラー: {}", e))
+                        .at_step("save_allocation_quota")
+                })?;
 
+            let mut context = HashMap::new();
+            context.insert("order_id".to_string(), order_id.to_string());
+            context.insert("book_id".to_string(), book_id.to_string());
+            self.logger.info(
+                "AllocationReturnHandler",
+                "Returned order allocation to quota",
+                Some(correlation_id),
+                Some(context),
+            );
+        }
         Ok(())
     }
 }
 
 #[async_trait]
-impl EventHandler<OrderConfirmed> for NotificationHandler {
-    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
-        // ハンドラー開始ログ
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
-        self.logger.info(
-            "NotificationHandler",
-            "Processing OrderConfirmed event",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        let start_time = std::time::Instant::now();
-
-        let message = format!(
-            "ご注文が確定されました。注文ID: {:?}, 合計金額: {}円",
+impl EventHandler<OrderCancelled> for AllocationReturnHandler {
+    async fn handle(&self, event: OrderCancelled) -> Result<(), HandlerError> {
+        self.return_allocations(
             event.order_id,
-            event.total_amount.amount()
-        );
-
-        self.send_notification(&message, event.metadata.correlation_id)
-            .await?;
-
-        // 処理成功ログ
-        let execution_time = start_time.elapsed();
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
-        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
-        
-        self.logger.info(
-            "NotificationHandler",
-            "OrderConfirmed event processed successfully",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        Ok(())
+            &event.order_lines,
+            event.metadata.correlation_id,
+        )
+        .await
     }
 }
 
 #[async_trait]
-impl EventHandler<OrderShipped> for NotificationHandler {
-    async fn handle(&self, event: OrderShipped) -> Result<(), HandlerError> {
-        // ハンドラー開始ログ
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderShipped".to_string());
-        self.logger.info(
-            "NotificationHandler",
-            "Processing OrderShipped event",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        let start_time = std::time::Instant::now();
-
-        let message = format!(
-            "ご注文が発送されました。注文ID: {:?}, 配送先: {}",
+impl EventHandler<InventoryReleased> for AllocationReturnHandler {
+    async fn handle(&self, event: InventoryReleased) -> Result<(), HandlerError> {
+        self.return_allocations(
             event.order_id,
-            format_args!(
-                "{} {} {}",
-                event.shipping_address.prefecture(),
-                event.shipping_address.city(),
-                event.shipping_address.street()
-            )
-        );
-
-        self.send_notification(&message, event.metadata.correlation_id)
-            .await?;
-
-        // 処理成功ログ
-        let execution_time = start_time.elapsed();
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderShipped".to_string());
-        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
-        
-        self.logger.info(
-            "NotificationHandler",
-            "OrderShipped event processed successfully",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        Ok(())
+            &event.order_lines,
+            event.metadata.correlation_id,
+        )
+        .await
     }
 }
 
-#[async_trait]
-impl EventHandler<OrderDelivered> for NotificationHandler {
-    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
-        // ハンドラー開始ログ
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderDelivered".to_string());
-        self.logger.info(
-            "NotificationHandler",
-            "Processing OrderDelivered event",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        let start_time = std::time::Instant::now();
-
-        let message = format!("ご注文の配達が完了しました。注文ID: {:?}", event.order_id);
+/// 発送ハンドラー
+/// InventoryReservedイベントを受信して注文を発送可能状態にする
+/// ShippingAddressChangedイベントを受信して倉庫の配送先を再検証・更新する
+#[derive(Clone)]
+pub struct ShippingHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    event_bus: Arc<dyn EventBus>,
+    processed_events: ProcessedEventTracker,
+    late_events: LateEventGuard,
+    carrier_limits: CarrierLimits,
+    delivery_estimator: DeliveryEstimator,
+    logger: Arc<dyn Logger>,
+}
 
-        self.send_notification(&message, event.metadata.correlation_id)
-            .await?;
+impl ShippingHandler {
+    /// 新しい発送ハンドラーを作成
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_bus: Arc<dyn EventBus>,
+        processed_event_repository: Arc<dyn ProcessedEventRepository>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_bus,
+            processed_events: ProcessedEventTracker::new(
+                processed_event_repository,
+                "ShippingHandler",
+                logger.clone(),
+            ),
+            late_events: LateEventGuard::skip_only(logger.clone()),
+            carrier_limits: CarrierLimits::default(),
+            delivery_estimator: DeliveryEstimator::default(),
+            logger,
+        }
+    }
 
-        // 処理成功ログ
-        let execution_time = start_time.elapsed();
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderDelivered".to_string());
-        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
-        
-        self.logger.info(
-            "NotificationHandler",
-            "OrderDelivered event processed successfully",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
+    /// 遅延イベントの扱いを設定する（未設定の場合は読み飛ばす）
+    pub fn with_late_event_guard(mut self, late_events: LateEventGuard) -> Self {
+        self.late_events = late_events;
+        self
+    }
 
-        Ok(())
+    /// 配送業者の上限を設定する（上限を超える注文は発送せずに補償イベントを発行する）
+    pub fn with_carrier_limits(mut self, carrier_limits: CarrierLimits) -> Self {
+        self.carrier_limits = carrier_limits;
+        self
     }
-}
 
-#[async_trait]
-impl EventHandler<OrderCancelled> for NotificationHandler {
-    async fn handle(&self, event: OrderCancelled) -> Result<(), HandlerError> {
-        // ハンドラー開始ログ
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderCancelled".to_string());
-        self.logger.info(
-            "NotificationHandler",
-            "Processing OrderCancelled event",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
+    /// 配達予定日の見積もりを設定する（発送日から配達予定日を見積もり直す）
+    pub fn with_delivery_estimator(mut self, delivery_estimator: DeliveryEstimator) -> Self {
+        self.delivery_estimator = delivery_estimator;
+        self
+    }
 
-        let start_time = std::time::Instant::now();
+    /// 配送業者の上限を検証して注文を発送済みにマークし、OrderShippedイベントを発行する
+    /// 発送できない場合は補償イベント（ShippingFailed）を発行し、契機のイベントを処理済みにしてエラーを返す
+    ///
+    /// # Arguments
+    /// * `order` - 発送する注文（Confirmed状態で、発送が必要な明細を含む）
+    /// * `event_type` - 発送の契機になったイベントのタイプ（ログ用）
+    /// * `metadata` - 発送の契機になったイベントのメタデータ
+    /// * `start_time` - 契機のイベントの処理を始めた時刻（ログ用）
+    async fn ship(
+        &self,
+        mut order: Order,
+        event_type: &str,
+        metadata: &EventMetadata,
+        start_time: std::time::Instant,
+    ) -> Result<(), HandlerError> {
+        // 配送業者の上限を検証して注文を発送済みにマーク（失敗時は補償イベントを発行）
+        match self
+            .carrier_limits
+            .ensure_can_ship(&order)
+            .and_then(|()| order.mark_as_shipped())
+        {
+            Ok(()) => {
+                order.estimate_delivery(&self.delivery_estimator);
+                // 注文を保存（イベントの連番も一緒に記録）
+                let sequence_number = order.record_event();
+                self.order_repository.save(&mut order).await.map_err(|e| {
+                    HandlerError::RepositoryError(format!("注文保存エラー: {}", e))
+                        .at_step("save_order")
+                })?;
 
-        let message = format!("ご注文がキャンセルされました。注文ID: {:?}", event.order_id);
+                let shipping_address = order
+                    .shipping_address()
+                    .expect("Confirmed状態の注文には配送先住所が必須です")
+                    .clone();
+                let mut shipped_event = crate::domain::event::OrderShipped::with_correlation_id(
+                    order.id(),
+                    shipping_address,
+                    metadata.correlation_id,
+                )
+                .with_recipient(order.recipient().cloned())
+                .with_estimated_delivery_date(order.estimated_delivery_date());
+                shipped_event.metadata.sequence_number = Some(sequence_number);
+                let domain_event = crate::domain::event::DomainEvent::OrderShipped(shipped_event);
 
-        self.send_notification(&message, event.metadata.correlation_id)
-            .await?;
+                self.event_bus.publish(domain_event).await.map_err(|e| {
+                    HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                        .at_step("publish_event")
+                })?;
+            }
+            Err(domain_error) => {
+                // 発送失敗 - 補償イベントを発行
+                let failure_reason = format!("発送処理失敗: {}", domain_error);
+                let compensation_event = ShippingFailed::with_correlation_id(
+                    order.id(),
+                    failure_reason.clone(),
+                    metadata.event_id,
+                    metadata.correlation_id,
+                );
 
-        // 処理成功ログ
-        let execution_time = start_time.elapsed();
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderCancelled".to_string());
-        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
-        
-        self.logger.info(
-            "NotificationHandler",
-            "OrderCancelled event processed successfully",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
+                self.event_bus
+                    .publish(DomainEvent::ShippingFailed(compensation_event))
+                    .await
+                    .map_err(|e| {
+                        HandlerError::ProcessingFailed(format!("補償イベント発行エラー: {}", e))
+                            .at_step("publish_compensation_event")
+                    })?;
 
-        Ok(())
-    }
-}
+                // エラーログ出力
+                let mut context = HashMap::new();
+                context.insert("event_type".to_string(), event_type.to_string());
+                context.insert("error".to_string(), failure_reason.clone());
+                context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
+                
+                self.logger.error(
+                    "ShippingHandler",
+                    &format!("{} event processing failed: {}", event_type, failure_reason),
+                    Some(metadata.correlation_id),
+                    Some(context),
+                );
 
-/// 配達ハンドラー
-/// OrderShippedイベントを受信して注文を配達完了状態にする
-pub struct DeliveryHandler {
-    order_repository: Arc<dyn OrderRepository>,
-    event_bus: Arc<dyn EventBus>,
-    processed_events: ProcessedEventTracker,
-    logger: Arc<dyn Logger>,
-}
+                // イベントを処理済みとしてマーク（失敗した場合でも重複処理を防ぐ）
+                self.processed_events
+                    .mark_processed(metadata.event_id)
+                    .await;
 
-impl DeliveryHandler {
-    /// 新しい配達ハンドラーを作成
-    pub fn new(order_repository: Arc<dyn OrderRepository>, event_bus: Arc<dyn EventBus>, logger: Arc<dyn Logger>) -> Self {
-        Self {
-            order_repository,
-            event_bus,
-            processed_events: ProcessedEventTracker::new(),
-            logger,
+                return Err(HandlerError::DomainError(format!(
+                    "発送マークエラー: {}",
+                    domain_error
+                ))
+                .at_step("mark_as_shipped"));
+            }
         }
+
+        Ok(())
     }
 }
 
 #[async_trait]
-impl EventHandler<OrderShipped> for DeliveryHandler {
-    async fn handle(&self, event: OrderShipped) -> Result<(), HandlerError> {
+impl EventHandler<InventoryReserved> for ShippingHandler {
+    async fn handle(&self, event: InventoryReserved) -> Result<(), HandlerError> {
         // ハンドラー開始ログ
         let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderShipped".to_string());
+        context.insert("event_type".to_string(), "InventoryReserved".to_string());
         self.logger.info(
-            "DeliveryHandler",
-            "Processing OrderShipped event",
+            "ShippingHandler",
+            "Processing InventoryReserved event",
             Some(event.metadata.correlation_id),
             Some(context),
         );
@@ -663,14 +1437,14 @@ impl EventHandler<OrderShipped> for DeliveryHandler {
         if self
             .processed_events
             .is_processed(event.metadata.event_id)
-            .await
+            .await?
         {
             let mut context = HashMap::new();
             context.insert("event_id".to_string(), event.metadata.event_id.to_string());
             context.insert("already_processed".to_string(), "true".to_string());
             
             self.logger.debug(
-                "DeliveryHandler",
+                "ShippingHandler",
                 "Idempotency check: Event already processed, skipping",
                 Some(event.metadata.correlation_id),
                 Some(context),
@@ -679,100 +1453,79 @@ impl EventHandler<OrderShipped> for DeliveryHandler {
         }
 
         // 注文を取得
-        let mut order = self
+        let order = self
             .order_repository
             .find_by_id(event.order_id)
             .await
-            .map_err(|e| HandlerError::RepositoryError(format!("注文取得エラー: {}", e)))?
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
             .ok_or_else(|| {
                 HandlerError::ProcessingFailed(format!(
                     "注文が見つかりません: {:?}",
                     event.order_id
                 ))
+                .at_step("load_order")
             })?;
 
-        // 注文がShipped状態でない場合は処理をスキップ（既に処理済みまたは無効な状態）
-        if order.status() != OrderStatus::Shipped {
+        // 保留中の注文は解除されるまで発送しない（解除時のOrderReleasedで発送する）
+        if order.status() == OrderStatus::OnHold {
             let mut context = HashMap::new();
-            context.insert("current_status".to_string(), format!("{:?}", order.status()));
-            context.insert("expected_status".to_string(), "Shipped".to_string());
-            
-            self.logger.debug(
-                "DeliveryHandler",
-                "Order is not in Shipped state, skipping delivery",
+            context.insert("order_id".to_string(), event.order_id.to_string());
+            self.logger.info(
+                "ShippingHandler",
+                "Order is on hold, shipping deferred until release",
                 Some(event.metadata.correlation_id),
                 Some(context),
             );
-            
-            // イベントを処理済みとしてマーク
             self.processed_events
                 .mark_processed(event.metadata.event_id)
                 .await;
             return Ok(());
         }
 
-        // 注文を配達完了にマーク（失敗時は補償イベントを発行）
-        match order.mark_as_delivered() {
-            Ok(()) => {
-                // 注文を保存
-                self.order_repository
-                    .save(&order)
-                    .await
-                    .map_err(|e| HandlerError::RepositoryError(format!("注文保存エラー: {}", e)))?;
-
-                let delivered_event = crate::domain::event::OrderDelivered::with_correlation_id(
-                    order.id(),
-                    event.metadata.correlation_id,
-                );
-                let domain_event =
-                    crate::domain::event::DomainEvent::OrderDelivered(delivered_event);
-
-                self.event_bus.publish(domain_event).await.map_err(|e| {
-                    HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
-                })?;
-            }
-            Err(domain_error) => {
-                // 配達失敗 - 補償イベントを発行
-                let failure_reason = format!("配達処理失敗: {}", domain_error);
-                let compensation_event = crate::domain::event::DeliveryFailed::with_correlation_id(
-                    event.order_id,
-                    failure_reason.clone(),
-                    event.metadata.event_id,
-                    event.metadata.correlation_id,
-                );
-
-                self.event_bus
-                    .publish(DomainEvent::DeliveryFailed(compensation_event))
-                    .await
-                    .map_err(|e| {
-                        HandlerError::ProcessingFailed(format!("補償イベント発行エラー: {}", e))
-                    })?;
-
-                // エラーログ出力
-                let mut context = HashMap::new();
-                context.insert("event_type".to_string(), "OrderShipped".to_string());
-                context.insert("error".to_string(), failure_reason.clone());
-                context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
-                
-                self.logger.error(
-                    "DeliveryHandler",
-                    &format!("OrderShipped event processing failed: {}", failure_reason),
-                    Some(event.metadata.correlation_id),
-                    Some(context),
-                );
+        // 注文がConfirmed状態でない場合は遅延イベントとして扱う（発送は確定済みの注文にしか適用できない）
+        if order.status() != OrderStatus::Confirmed {
+            self.late_events
+                .resolve(
+                    "ShippingHandler",
+                    LateEvent {
+                        event_type: "InventoryReserved",
+                        metadata: &event.metadata,
+                        order_id: event.order_id,
+                        order_status: order.status(),
+                        expected_status: OrderStatus::Confirmed,
+                        compatible: false,
+                        payload: late_event_payload(&event)?,
+                    },
+                )
+                .await?;
 
-                // イベントを処理済みとしてマーク（失敗した場合でも重複処理を防ぐ）
-                self.processed_events
-                    .mark_processed(event.metadata.event_id)
-                    .await;
+            // イベントを処理済みとしてマーク
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+            return Ok(());
+        }
 
-                return Err(HandlerError::DomainError(format!(
-                    "配達マークエラー: {}",
-                    domain_error
-                )));
-            }
+        // 電子書籍のみの注文は発送しない（FulfillmentRouterがフルフィルメントを完了する）
+        if !order.requires_shipping() {
+            self.logger.debug(
+                "ShippingHandler",
+                "Digital-only order does not require shipping, skipping",
+                Some(event.metadata.correlation_id),
+                None,
+            );
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+            return Ok(());
         }
 
+        self.ship(order, "InventoryReserved", &event.metadata, start_time)
+            .await?;
+
         // イベントを処理済みとしてマーク（成功時）
         self.processed_events
             .mark_processed(event.metadata.event_id)
@@ -781,12 +1534,12 @@ impl EventHandler<OrderShipped> for DeliveryHandler {
         // 処理成功ログ
         let execution_time = start_time.elapsed();
         let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderShipped".to_string());
+        context.insert("event_type".to_string(), "InventoryReserved".to_string());
         context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
         
         self.logger.info(
-            "DeliveryHandler",
-            "OrderShipped event processed successfully",
+            "ShippingHandler",
+            "InventoryReserved event processed successfully",
             Some(event.metadata.correlation_id),
             Some(context),
         );
@@ -795,204 +1548,319 @@ impl EventHandler<OrderShipped> for DeliveryHandler {
     }
 }
 
-/// 配達失敗補償ハンドラー
-/// DeliveryFailedイベントを受信して発送状態を元に戻す
-pub struct DeliveryFailureCompensationHandler {
-    order_repository: Arc<dyn OrderRepository>,
-    #[allow(dead_code)]
-    event_bus: Arc<dyn EventBus>,
-    logger: Arc<dyn Logger>,
-}
+/// 保留を解除された注文を発送する
+/// 保留中に届いたInventoryReservedでは発送を見送っているため、解除を契機に発送を再開する
+#[async_trait]
+impl EventHandler<OrderReleased> for ShippingHandler {
+    async fn handle(&self, event: OrderReleased) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
 
-impl DeliveryFailureCompensationHandler {
-    /// 新しい配達失敗補償ハンドラーを作成
-    pub fn new(order_repository: Arc<dyn OrderRepository>, event_bus: Arc<dyn EventBus>, logger: Arc<dyn Logger>) -> Self {
-        Self {
-            order_repository,
-            event_bus,
-            logger,
+        // 冪等性チェック: 既に処理済みのイベントかどうか確認
+        if self
+            .processed_events
+            .is_processed(event.metadata.event_id)
+            .await?
+        {
+            return Ok(());
         }
-    }
-}
 
-#[async_trait]
-impl EventHandler<crate::domain::event::DeliveryFailed> for DeliveryFailureCompensationHandler {
-    async fn handle(
-        &self,
-        event: crate::domain::event::DeliveryFailed,
-    ) -> Result<(), HandlerError> {
-        // 補償ログ出力
+        let order = self
+            .order_repository
+            .find_by_id(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!(
+                    "注文が見つかりません: {:?}",
+                    event.order_id
+                ))
+                .at_step("load_order")
+            })?;
+
+        // 解除後に再び保留・キャンセルされた注文や、既に発送した注文は発送しない
+        if order.status() != OrderStatus::Confirmed || !order.requires_shipping() {
+            let mut context = HashMap::new();
+            context.insert("current_status".to_string(), order.status().to_string());
+            self.logger.debug(
+                "ShippingHandler",
+                "Released order is no longer awaiting shipment, skipping",
+                Some(event.metadata.correlation_id),
+                Some(context),
+            );
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+            return Ok(());
+        }
+
+        self.ship(order, "OrderReleased", &event.metadata, start_time)
+            .await?;
+
+        self.processed_events
+            .mark_processed(event.metadata.event_id)
+            .await;
+
         let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "DeliveryFailed".to_string());
-        context.insert("compensation_type".to_string(), "DeliveryFailure".to_string());
+        context.insert("order_id".to_string(), event.order_id.to_string());
         self.logger.info(
-            "DeliveryFailureCompensationHandler",
-            "Processing DeliveryFailed compensation event",
+            "ShippingHandler",
+            "Released order shipped",
             Some(event.metadata.correlation_id),
             Some(context),
         );
 
-        let start_time = std::time::Instant::now();
+        Ok(())
+    }
+}
 
-        // 注文を取得
+#[async_trait]
+impl EventHandler<ShippingAddressChanged> for ShippingHandler {
+    async fn handle(&self, event: ShippingAddressChanged) -> Result<(), HandlerError> {
+        // 冪等性チェック: 既に処理済みのイベントかどうか確認
+        if self
+            .processed_events
+            .is_processed(event.metadata.event_id)
+            .await?
+        {
+            return Ok(());
+        }
+
+        // 再検証: 最新の注文が発送待ちで、変更後の住所が現在の住所と一致することを確認
         let order = self
             .order_repository
             .find_by_id(event.order_id)
             .await
-            .map_err(|e| HandlerError::RepositoryError(format!("注文取得エラー: {}", e)))?
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
             .ok_or_else(|| {
                 HandlerError::ProcessingFailed(format!(
                     "注文が見つかりません: {:?}",
                     event.order_id
                 ))
+                .at_step("load_order")
             })?;
 
-        // 注文状態を発送済みに戻す（補償アクション）
-        // 実際の実装では、より複雑な補償ロジックが必要になる場合がある
-        // ここでは簡単のため、ステータスを直接変更
-        if order.status() == crate::domain::model::OrderStatus::Delivered {
-            // 注文を発送済み状態に戻すためのロジック
-            // 実際の実装では、注文集約にcompensate_deliveryメソッドを追加することを推奨
-        }
-
-        // 補償処理完了ログ
-        let execution_time = start_time.elapsed();
         let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "DeliveryFailed".to_string());
-        context.insert("compensation_type".to_string(), "DeliveryFailure".to_string());
-        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
-        
-        self.logger.info(
-            "DeliveryFailureCompensationHandler",
-            "DeliveryFailed compensation completed successfully",
-            Some(event.metadata.correlation_id),
-            Some(context),
+        context.insert("current_status".to_string(), order.status().to_string());
+        let awaiting_shipment = matches!(
+            order.status(),
+            OrderStatus::Confirmed
+                | OrderStatus::OnHold
+                | OrderStatus::AwaitingRelease
+                | OrderStatus::Waitlisted
         );
+        if !awaiting_shipment || order.shipping_address() != Some(&event.new_address) {
+            // 発送済み・キャンセル済み、またはより新しい住所変更で上書き済みの場合は反映しない
+            self.logger.warn(
+                "ShippingHandler",
+                "Shipping address change is stale, skipping warehouse update",
+                Some(event.metadata.correlation_id),
+                Some(context),
+            );
+        } else {
+            context.insert(
+                "new_address".to_string(),
+                format!(
+                    "{} {} {} {}",
+                    event.new_address.postal_code(),
+                    event.new_address.prefecture(),
+                    event.new_address.city(),
+                    event.new_address.street()
+                ),
+            );
+            context.insert(
+                "shipping_fee".to_string(),
+                event.shipping_fee.amount().to_string(),
+            );
+            self.logger.info(
+                "ShippingHandler",
+                "Warehouse shipping destination updated",
+                Some(event.metadata.correlation_id),
+                Some(context),
+            );
+        }
+
+        self.processed_events
+            .mark_processed(event.metadata.event_id)
+            .await;
 
         Ok(())
     }
 }
 
-/// 結果整合性検証ハンドラー
-/// サーガの完了を監視し、システム全体の整合性を検証する
+/// フルフィルメントルーター
+/// InventoryReservedイベントを受信し、注文明細のフルフィルメント種別ごとに処理を振り分ける
+/// - 電子書籍の明細: ダウンロードリンクを発行してDigitalItemsFulfilledイベントを発行
+/// - 物理書籍の明細: 従来どおり発送・配達のフローで処理（ここでは何もしない）
+///
+/// 電子書籍のみの注文は発送・配達を経ずにFulfilled状態にする
 #[derive(Clone)]
-pub struct EventualConsistencyVerifier {
+pub struct FulfillmentRouter {
     order_repository: Arc<dyn OrderRepository>,
-    inventory_repository: Arc<dyn InventoryRepository>,
+    event_bus: Arc<dyn EventBus>,
+    download_link_generator: Arc<dyn DownloadLinkGenerator>,
+    processed_events: ProcessedEventTracker,
+    late_events: LateEventGuard,
     logger: Arc<dyn Logger>,
 }
 
-impl EventualConsistencyVerifier {
-    /// 新しい結果整合性検証ハンドラーを作成
+impl FulfillmentRouter {
+    /// 新しいフルフィルメントルーターを作成
     pub fn new(
         order_repository: Arc<dyn OrderRepository>,
-        inventory_repository: Arc<dyn InventoryRepository>,
+        event_bus: Arc<dyn EventBus>,
+        download_link_generator: Arc<dyn DownloadLinkGenerator>,
+        processed_event_repository: Arc<dyn ProcessedEventRepository>,
         logger: Arc<dyn Logger>,
     ) -> Self {
         Self {
             order_repository,
-            inventory_repository,
+            event_bus,
+            download_link_generator,
+            processed_events: ProcessedEventTracker::new(
+                processed_event_repository,
+                "FulfillmentRouter",
+                logger.clone(),
+            ),
+            late_events: LateEventGuard::skip_only(logger.clone()),
             logger,
         }
     }
 
-    /// 注文とその関連する在庫の整合性を検証
-    async fn verify_order_inventory_consistency(
-        &self,
-        order_id: OrderId,
-        _correlation_id: Uuid,
-    ) -> Result<(), HandlerError> {
+    /// 遅延イベントの扱いを設定する（未設定の場合は読み飛ばす）
+    pub fn with_late_event_guard(mut self, late_events: LateEventGuard) -> Self {
+        self.late_events = late_events;
+        self
+    }
+}
+
+#[async_trait]
+impl EventHandler<InventoryReserved> for FulfillmentRouter {
+    async fn handle(&self, event: InventoryReserved) -> Result<(), HandlerError> {
+        // 冪等性チェック: 既に処理済みのイベントかどうか確認
+        if self
+            .processed_events
+            .is_processed(event.metadata.event_id)
+            .await?
+        {
+            return Ok(());
+        }
+
         // 注文を取得
-        let order = self
+        let mut order = self
             .order_repository
-            .find_by_id(order_id)
+            .find_by_id(event.order_id)
             .await
-            .map_err(|e| HandlerError::RepositoryError(format!("注文取得エラー: {}", e)))?
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
             .ok_or_else(|| {
-                HandlerError::ProcessingFailed(format!("注文が見つかりません: {:?}", order_id))
+                HandlerError::ProcessingFailed(format!(
+                    "注文が見つかりません: {:?}",
+                    event.order_id
+                ))
+                .at_step("load_order")
             })?;
 
-        // 注文が確定済みまたは発送済みの場合、在庫が適切に予約されているかチェック
-        if matches!(
-            order.status(),
-            crate::domain::model::OrderStatus::Confirmed
-                | crate::domain::model::OrderStatus::Shipped
-        ) {
-            for order_line in order.order_lines() {
-                let inventory = self
-                    .inventory_repository
-                    .find_by_book_id(order_line.book_id())
-                    .await
-                    .map_err(|e| HandlerError::RepositoryError(format!("在庫取得エラー: {}", e)))?;
+        // 電子書籍の明細がない場合は何もしない
+        let digital_lines: Vec<_> = order
+            .order_lines()
+            .iter()
+            .filter(|line| line.is_digital())
+            .cloned()
+            .collect();
+        if digital_lines.is_empty() {
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+            return Ok(());
+        }
 
-                if let Some(inventory) = inventory {
-                    // 在庫が十分にあることを確認（実際の実装では予約済み在庫の追跡が必要）
-                    if !inventory.has_available_stock(order_line.quantity()) {
-                    }
-                } else {
-                }
+        // Confirmed状態でない場合は遅延イベントとして扱う
+        // 混在注文の物理書籍が先に発送された場合は、ダウンロードリンクの発行だけなら適用できる
+        if order.status() != OrderStatus::Confirmed {
+            let compatible = matches!(order.status(), OrderStatus::Shipped | OrderStatus::Delivered);
+            let action = self
+                .late_events
+                .resolve(
+                    "FulfillmentRouter",
+                    LateEvent {
+                        event_type: "InventoryReserved",
+                        metadata: &event.metadata,
+                        order_id: event.order_id,
+                        order_status: order.status(),
+                        expected_status: OrderStatus::Confirmed,
+                        compatible,
+                        payload: late_event_payload(&event)?,
+                    },
+                )
+                .await?;
+            if action != LateEventAction::Apply {
+                self.processed_events
+                    .mark_processed(event.metadata.event_id)
+                    .await;
+                return Ok(());
             }
         }
 
-        Ok(())
-    }
-}
+        // 電子書籍の明細ごとにダウンロードリンクを発行
+        let mut download_links = Vec::with_capacity(digital_lines.len());
+        for line in &digital_lines {
+            let link = self
+                .download_link_generator
+                .generate_download_link(order.id(), line.book_id())
+                .await
+                .map_err(|e| {
+                    HandlerError::ProcessingFailed(format!("ダウンロードリンク発行エラー: {}", e))
+                        .at_step("issue_download_links")
+                })?;
+            download_links.push(link);
+        }
 
-#[async_trait]
-impl EventHandler<OrderConfirmed> for EventualConsistencyVerifier {
-    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
-        // 注文確定時の整合性検証
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
-        context.insert("verification_type".to_string(), "OrderInventoryConsistency".to_string());
-        self.logger.debug(
-            "EventualConsistencyVerifier",
-            "Starting order-inventory consistency verification",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        self.verify_order_inventory_consistency(event.order_id, event.metadata.correlation_id)
-            .await?;
-
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
-        context.insert("verification_result".to_string(), "Success".to_string());
-        self.logger.debug(
-            "EventualConsistencyVerifier",
-            "Order-inventory consistency verification completed",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        Ok(())
-    }
-}
+        // 電子書籍のみの注文は発送・配達を経ずにフルフィルメント完了
+        let order_fulfilled = order.is_digital_only();
+        if order_fulfilled {
+            order.mark_as_fulfilled().map_err(|e| {
+                HandlerError::DomainError(format!("フルフィルメント完了エラー: {}", e))
+                    .at_step("fulfill_digital_items")
+            })?;
+            self.order_repository.save(&mut order).await.map_err(|e| {
+                HandlerError::RepositoryError(format!("注文保存エラー: {}", e))
+                    .at_step("save_order")
+            })?;
+        }
 
-#[async_trait]
-impl EventHandler<OrderDelivered> for EventualConsistencyVerifier {
-    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
-        // 注文配達完了時の整合性検証
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderDelivered".to_string());
-        context.insert("verification_type".to_string(), "OrderInventoryConsistency".to_string());
-        self.logger.debug(
-            "EventualConsistencyVerifier",
-            "Starting order-inventory consistency verification",
-            Some(event.metadata.correlation_id),
-            Some(context),
+        let fulfilled_event = DigitalItemsFulfilled::with_correlation_id(
+            order.id(),
+            order.customer_id(),
+            download_links,
+            order_fulfilled,
+            event.metadata.correlation_id,
         );
+        self.event_bus
+            .publish(DomainEvent::DigitalItemsFulfilled(fulfilled_event))
+            .await
+            .map_err(|e| {
+                HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                    .at_step("publish_event")
+            })?;
 
-        self.verify_order_inventory_consistency(event.order_id, event.metadata.correlation_id)
-            .await?;
+        self.processed_events
+            .mark_processed(event.metadata.event_id)
+            .await;
 
-        // サーガ完了ログ
         let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "OrderDelivered".to_string());
-        context.insert("saga_status".to_string(), "Completed".to_string());
+        context.insert("digital_lines".to_string(), digital_lines.len().to_string());
+        context.insert("order_fulfilled".to_string(), order_fulfilled.to_string());
         self.logger.info(
-            "EventualConsistencyVerifier",
-            "Saga completed successfully - order delivered",
+            "FulfillmentRouter",
+            "Digital items fulfilled",
             Some(event.metadata.correlation_id),
             Some(context),
         );
@@ -1001,910 +1869,1229 @@ impl EventHandler<OrderDelivered> for EventualConsistencyVerifier {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::domain::model::{BookId, CustomerId, Money, OrderId, OrderLine, OrderStatus};
-    use crate::domain::port::{EventBus, EventBusError, RepositoryError};
-    use std::collections::HashMap;
-    use tokio::sync::Mutex;
+/// 通知の宛先
+/// 支払い・注文内容に関する通知は購入者へ、ギフト注文の発送・配達の通知は受取人へ送る
+enum NotificationAudience<'a> {
+    /// 購入者（注文した顧客）
+    Purchaser,
+    /// ギフト注文の受取人
+    GiftRecipient(&'a Recipient),
+}
 
-    // テスト用のモックイベントバス
-    #[derive(Clone)]
-    struct MockEventBus {
-        published_events: Arc<Mutex<Vec<DomainEvent>>>,
+impl<'a> NotificationAudience<'a> {
+    /// 発送・配達の通知の宛先（ギフト注文は受取人、それ以外は購入者）
+    fn for_delivery(recipient: Option<&'a Recipient>) -> Self {
+        recipient.map_or(NotificationAudience::Purchaser, NotificationAudience::GiftRecipient)
     }
+}
 
-    impl MockEventBus {
-        fn new() -> Self {
-            Self {
-                published_events: Arc::new(Mutex::new(Vec::new())),
-            }
+impl NotificationAudience<'_> {
+    /// 宛先に送るチャネル（購入者はメール、ギフト注文の受取人はSMS）と電話番号
+    fn channel(&self) -> (NotificationChannel, Option<String>) {
+        match self {
+            NotificationAudience::Purchaser => (NotificationChannel::Email, None),
+            NotificationAudience::GiftRecipient(recipient) => (
+                NotificationChannel::Sms,
+                Some(recipient.phone().to_string()),
+            ),
         }
+    }
+}
 
-        async fn get_published_events(&self) -> Vec<DomainEvent> {
-            let events = self.published_events.lock().await;
-            events.clone()
-        }
+/// 送信に失敗した通知の再送待ちキュー
+#[derive(Clone)]
+struct NotificationRetryQueue {
+    repository: Arc<dyn FailedNotificationRepository>,
+    policy: NotificationRetryPolicy,
+}
 
-        async fn clear_events(&self) {
-            let mut events = self.published_events.lock().await;
-            events.clear();
+/// 通知ハンドラー
+/// 各種注文イベントを受信して通知を送信する
+#[derive(Clone)]
+pub struct NotificationHandler {
+    logger: Arc<dyn Logger>,
+    /// 通知の送信先（未設定の場合はログ出力で代用する）
+    sender: Option<Arc<dyn NotificationSender>>,
+    /// 送信に失敗した通知の再送待ちキュー（未設定の場合は送信の失敗をハンドラーのエラーとして返す）
+    retry_queue: Option<NotificationRetryQueue>,
+    /// 保留中の注文の確認と注文番号の取得に使う注文リポジトリ（未設定の場合は保留中でも通知し、注文IDを載せる）
+    order_repository: Option<Arc<dyn OrderRepository>>,
+    /// 接続中の顧客への通知の配信先（未設定の場合は配信しない）
+    channel: Option<Arc<dyn NotificationChannelPort>>,
+    /// 通知のメッセージのテンプレート
+    templates: Arc<NotificationTemplateEngine>,
+    /// 通知の言語の取得に使う顧客リポジトリ（未設定の場合は既定の言語で通知する）
+    customer_repository: Option<Arc<dyn CustomerRepository>>,
+}
+
+impl NotificationHandler {
+    /// 新しい通知ハンドラーを作成（組み込みのテンプレートを使う）
+    pub fn new(logger: Arc<dyn Logger>) -> Self {
+        Self {
+            logger,
+            sender: None,
+            retry_queue: None,
+            order_repository: None,
+            channel: None,
+            templates: Arc::new(NotificationTemplateEngine::new()),
+            customer_repository: None,
         }
     }
 
-    #[async_trait]
-    impl EventBus for MockEventBus {
-        async fn publish(&self, event: DomainEvent) -> Result<(), EventBusError> {
-            let mut events = self.published_events.lock().await;
-            events.push(event);
-            Ok(())
-        }
+    /// 通知のメッセージのテンプレートを設定する
+    pub fn with_templates(mut self, templates: NotificationTemplateEngine) -> Self {
+        self.templates = Arc::new(templates);
+        self
     }
 
-    // テスト用のモックリポジトリ
-    struct MockInventoryRepository {
-        inventories: Arc<Mutex<HashMap<BookId, Inventory>>>,
+    /// 顧客リポジトリを設定する
+    /// 設定すると、注文した顧客の通知の言語で通知する（ギフト注文の受取人への通知も購入者の言語）
+    /// 宛先の顧客は注文から調べるため、注文リポジトリ（with_order_repository）も設定する
+    pub fn with_customer_repository(
+        mut self,
+        customer_repository: Arc<dyn CustomerRepository>,
+    ) -> Self {
+        self.customer_repository = Some(customer_repository);
+        self
     }
 
-    impl MockInventoryRepository {
-        fn new() -> Self {
-            Self {
-                inventories: Arc::new(Mutex::new(HashMap::new())),
-            }
-        }
+    /// 通知の送信先（メール・SMSの送信サービス）を設定する
+    pub fn with_sender(mut self, sender: Arc<dyn NotificationSender>) -> Self {
+        self.sender = Some(sender);
+        self
+    }
 
-        async fn add_inventory(&self, inventory: Inventory) {
-            let mut inventories = self.inventories.lock().await;
-            inventories.insert(inventory.book_id(), inventory);
-        }
+    /// 送信に失敗した通知をイベントのデッドレターキューではなく再送待ちキューに入れる
+    ///
+    /// # Arguments
+    /// * `repository` - 送信に失敗した通知のリポジトリ
+    /// * `policy` - チャネルごとの再送ポリシー
+    pub fn with_retry_queue(
+        mut self,
+        repository: Arc<dyn FailedNotificationRepository>,
+        policy: NotificationRetryPolicy,
+    ) -> Self {
+        self.retry_queue = Some(NotificationRetryQueue { repository, policy });
+        self
     }
 
-    #[async_trait]
-    impl InventoryRepository for MockInventoryRepository {
-        async fn save(&self, inventory: &Inventory) -> Result<(), RepositoryError> {
-            let mut inventories = self.inventories.lock().await;
-            inventories.insert(inventory.book_id(), inventory.clone());
-            Ok(())
-        }
+    /// 注文リポジトリを設定する
+    /// 設定すると、保留中（OnHold）の注文の通知を解除されるまで見送り、
+    /// 通知のメッセージに注文IDの代わりに注文番号を載せる（注文番号のない注文は注文ID）
+    pub fn with_order_repository(mut self, order_repository: Arc<dyn OrderRepository>) -> Self {
+        self.order_repository = Some(order_repository);
+        self
+    }
 
-        async fn find_by_book_id(
-            &self,
-            book_id: BookId,
-        ) -> Result<Option<Inventory>, RepositoryError> {
-            let inventories = self.inventories.lock().await;
-            Ok(inventories.get(&book_id).cloned())
-        }
+    /// 接続中の顧客（WebSocketなど）にも通知を配信する
+    /// 宛先の顧客は注文から調べるため、注文リポジトリ（with_order_repository）も設定する
+    /// 配信は送信先（メール・SMS）への送信の成否に関わらず行い、接続していない顧客には届けない
+    pub fn with_channel(mut self, channel: Arc<dyn NotificationChannelPort>) -> Self {
+        self.channel = Some(channel);
+        self
+    }
 
-        async fn find_all(&self) -> Result<Vec<Inventory>, RepositoryError> {
-            let inventories = self.inventories.lock().await;
-            Ok(inventories.values().cloned().collect())
+    /// 接続中の注文した顧客に通知を配信する
+    async fn push_to_customer(
+        &self,
+        order: Option<&Order>,
+        message: &str,
+        event_type: &str,
+        correlation_id: Uuid,
+    ) {
+        let (Some(channel), Some(order)) = (&self.channel, order) else {
+            return;
+        };
+        let notification = CustomerNotification {
+            customer_id: order.customer_id(),
+            order_id: order.id(),
+            event_type: event_type.to_string(),
+            message: message.to_string(),
+            correlation_id,
+            sent_at: Utc::now(),
+        };
+        let connections = channel.push(&notification).await;
+        if connections > 0 {
+            let mut context = HashMap::new();
+            context.insert("order_id".to_string(), notification.order_id.to_string());
+            context.insert("connections".to_string(), connections.to_string());
+            self.logger.debug(
+                "NotificationHandler",
+                "Notification pushed to connected customer",
+                Some(correlation_id),
+                Some(context),
+            );
         }
+    }
 
-        async fn find_by_max_quantity(
-            &self,
-            max_quantity: u32,
-        ) -> Result<Vec<Inventory>, RepositoryError> {
-            let inventories = self.inventories.lock().await;
-            Ok(inventories
-                .values()
-                .filter(|inv| inv.quantity_on_hand() <= max_quantity)
-                .cloned()
-                .collect())
-        }
+    /// 通知の対象の注文を取得する（注文リポジトリが未設定の場合はNone）
+    async fn load_order(&self, order_id: OrderId) -> Result<Option<Order>, HandlerError> {
+        let Some(order_repository) = &self.order_repository else {
+            return Ok(None);
+        };
+        order_repository.find_by_id(order_id).await.map_err(|e| {
+            HandlerError::RepositoryError(format!("注文取得エラー: {}", e)).at_step("load_order")
+        })
     }
 
-    struct MockOrderRepository {
-        orders: Arc<Mutex<HashMap<OrderId, crate::domain::model::Order>>>,
+    /// 通知の言語（注文した顧客の通知の言語、顧客がわからない場合は既定の言語）
+    async fn notification_language(&self, order: Option<&Order>) -> Result<Language, HandlerError> {
+        let (Some(customer_repository), Some(order)) = (&self.customer_repository, order) else {
+            return Ok(Language::DEFAULT);
+        };
+        let customer = customer_repository
+            .find_by_id(order.customer_id())
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("顧客取得エラー: {}", e))
+                    .at_step("load_customer")
+            })?;
+        Ok(customer.map_or(Language::DEFAULT, |customer| customer.preferred_language()))
     }
 
-    impl MockOrderRepository {
-        fn new() -> Self {
-            Self {
-                orders: Arc::new(Mutex::new(HashMap::new())),
-            }
+    /// 通知のメッセージに載せる注文の表記（注文番号がある場合は注文番号、ない場合は注文ID）
+    fn order_label(&self, order_id: OrderId, order: Option<&Order>, language: Language) -> String {
+        match order.and_then(Order::order_number) {
+            Some(order_number) => self.templates.render(
+                NotificationTemplateKey::OrderNumberLabel,
+                language,
+                &TemplateParams::new().with("order_number", order_number),
+            ),
+            None => self.templates.render(
+                NotificationTemplateKey::OrderIdLabel,
+                language,
+                &TemplateParams::new().with("order_id", format!("{:?}", order_id)),
+            ),
         }
     }
 
-    #[async_trait]
-    impl OrderRepository for MockOrderRepository {
-        async fn save(&self, order: &crate::domain::model::Order) -> Result<(), RepositoryError> {
-            let mut orders = self.orders.lock().await;
-            orders.insert(order.id(), order.clone());
-            Ok(())
+    /// 通知メッセージを送信
+    /// 送信先が設定されている場合は宛先に応じたチャネル（メール・SMS）で送信し、
+    /// 失敗した通知は再送待ちキューに入れてイベントの処理は成功として扱う
+    /// 保留中の注文の通知は送信しない
+    ///
+    /// # Arguments
+    /// * `template` - メッセージのテンプレートの種類
+    /// * `params` - 通知の言語からテンプレートに埋め込む値を作成する（注文の表記 {order} はここで埋め込む）
+    async fn send_notification(
+        &self,
+        template: NotificationTemplateKey,
+        params: impl FnOnce(Language) -> TemplateParams + Send,
+        audience: NotificationAudience<'_>,
+        order_id: OrderId,
+        event_type: &str,
+        correlation_id: Uuid,
+    ) -> Result<(), HandlerError> {
+        let order = self.load_order(order_id).await?;
+        if order
+            .as_ref()
+            .is_some_and(|order| order.status() == OrderStatus::OnHold)
+        {
+            let mut context = HashMap::new();
+            context.insert("order_id".to_string(), order_id.to_string());
+            context.insert("event_type".to_string(), event_type.to_string());
+            self.logger.info(
+                "NotificationHandler",
+                "Order is on hold, notification suppressed",
+                Some(correlation_id),
+                Some(context),
+            );
+            return Ok(());
         }
+        let language = self.notification_language(order.as_ref()).await?;
+        let params = params(language).with("order", self.order_label(order_id, order.as_ref(), language));
+        let message = self.templates.render(template, language, &params);
+        self.push_to_customer(order.as_ref(), &message, event_type, correlation_id)
+            .await;
 
-        async fn find_by_id(
-            &self,
-            order_id: OrderId,
-        ) -> Result<Option<crate::domain::model::Order>, RepositoryError> {
-            let orders = self.orders.lock().await;
-            Ok(orders.get(&order_id).cloned())
-        }
+        let Some(sender) = &self.sender else {
+            self.log_notification(&message, audience, correlation_id);
+            return Ok(());
+        };
 
-        async fn find_all(&self) -> Result<Vec<crate::domain::model::Order>, RepositoryError> {
-            let orders = self.orders.lock().await;
-            Ok(orders.values().cloned().collect())
-        }
+        let (channel, phone) = audience.channel();
+        let notification = Notification {
+            channel,
+            order_id,
+            phone,
+            event_type: event_type.to_string(),
+            message,
+            correlation_id,
+        };
+        let error = match sender.send(&notification).await {
+            Ok(()) => return Ok(()),
+            Err(e) => e.to_string(),
+        };
+        let Some(retry_queue) = &self.retry_queue else {
+            return Err(HandlerError::ProcessingFailed(format!("通知送信エラー: {}", error))
+                .at_step("send_notification"));
+        };
 
-        async fn find_by_status(
-            &self,
-            status: OrderStatus,
-        ) -> Result<Vec<crate::domain::model::Order>, RepositoryError> {
-            let orders = self.orders.lock().await;
-            Ok(orders
-                .values()
-                .filter(|order| order.status() == status)
-                .cloned()
-                .collect())
-        }
+        let failed = FailedNotification::new(notification, &error, &retry_queue.policy, Utc::now());
+        retry_queue.repository.save(&failed).await.map_err(|e| {
+            HandlerError::RepositoryError(format!("再送待ちの通知の保存エラー: {}", e))
+                .at_step("queue_notification")
+        })?;
 
-        fn next_identity(&self) -> OrderId {
-            OrderId::new()
-        }
+        let mut context = HashMap::new();
+        context.insert("notification_id".to_string(), failed.id.to_string());
+        context.insert("channel".to_string(), channel.to_string());
+        context.insert("error".to_string(), error);
+        self.logger.warn(
+            "NotificationHandler",
+            "Notification failed, queued for retry",
+            Some(failed.notification.correlation_id),
+            Some(context),
+        );
+        Ok(())
     }
 
-    // テスト用のモックロガー
-    #[derive(Clone)]
-    struct MockLogger;
-
-    impl Logger for MockLogger {
-        fn debug(&self, _component: &str, _message: &str, _correlation_id: Option<Uuid>, _context: Option<HashMap<String, String>>) {
-            // テスト用なので何もしない
-        }
-
-        fn info(&self, _component: &str, _message: &str, _correlation_id: Option<Uuid>, _context: Option<HashMap<String, String>>) {
-            // テスト用なので何もしない
-        }
-
-        fn warn(&self, _component: &str, _message: &str, _correlation_id: Option<Uuid>, _context: Option<HashMap<String, String>>) {
-            // テスト用なので何もしない
+    /// 送信先が設定されていない場合に通知の内容をログに出力する
+    fn log_notification(
+        &self,
+        message: &str,
+        audience: NotificationAudience<'_>,
+        correlation_id: Uuid,
+    ) {
+        let mut context = HashMap::new();
+        context.insert("notification_type".to_string(), "General".to_string());
+        match audience {
+            NotificationAudience::Purchaser => {
+                context.insert("recipient".to_string(), "customer".to_string());
+            }
+            NotificationAudience::GiftRecipient(recipient) => {
+                context.insert("recipient".to_string(), "gift_recipient".to_string());
+                context.insert("recipient_name".to_string(), recipient.name().to_string());
+            }
         }
+        
+        self.logger.info(
+            "NotificationHandler",
+            "Notification sent: General",
+            Some(correlation_id),
+            Some(context),
+        );
 
-        fn error(&self, _component: &str, _message: &str, _correlation_id: Option<Uuid>, _context: Option<HashMap<String, String>>) {
-            // テスト用なので何もしない
-        }
+        // 通知内容もログに記録
+        self.logger.info("NotificationHandler", message, Some(correlation_id), None);
     }
+}
 
-    #[tokio::test]
-    async fn test_inventory_reservation_handler_success() {
-        let inventory_repo = Arc::new(MockInventoryRepository::new());
-        let order_repo = Arc::new(MockOrderRepository::new());
-        let event_bus = Arc::new(MockEventBus::new());
-        let logger = Arc::new(MockLogger);
-        let handler = InventoryReservationHandler::new(
-            inventory_repo.clone(),
-            order_repo.clone(),
-            event_bus.clone(),
-            logger,
+#[async_trait]
+impl EventHandler<OrderConfirmed> for NotificationHandler {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
+        self.logger.info(
+            "NotificationHandler",
+            "Processing OrderConfirmed event",
+            Some(event.metadata.correlation_id),
+            Some(context),
         );
 
-        // テスト用の在庫を追加
-        let book_id = BookId::new();
-        let inventory = Inventory::new(book_id, 10);
-        inventory_repo.add_inventory(inventory).await;
+        let start_time = std::time::Instant::now();
 
-        // テスト用のOrderConfirmedイベントを作成
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let order_line = OrderLine::new(book_id, 3, Money::jpy(1000)).unwrap();
-        let event = OrderConfirmed::new(order_id, customer_id, vec![order_line], Money::jpy(3000));
+        self.send_notification(
+            NotificationTemplateKey::OrderConfirmed,
+            |_| TemplateParams::new().with("total", event.total_amount.amount()),
+            NotificationAudience::Purchaser,
+            event.order_id,
+            "OrderConfirmed",
+            event.metadata.correlation_id,
+        )
+        .await?;
 
-        // テスト用の注文を作成してリポジトリに保存
-        let mut order = crate::domain::model::Order::new(order_id, customer_id);
-        order.add_book(book_id, 3, Money::jpy(1000)).unwrap();
-        order.set_shipping_address(
-            crate::domain::model::ShippingAddress::new(
-                "1234567".to_string(),
-                "東京都".to_string(),
-                "渋谷区".to_string(),
-                "道玄坂1-1-1".to_string(),
-                None,
-            )
-            .unwrap(),
+        // 処理成功ログ
+        let execution_time = start_time.elapsed();
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
+        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
+        
+        self.logger.info(
+            "NotificationHandler",
+            "OrderConfirmed event processed successfully",
+            Some(event.metadata.correlation_id),
+            Some(context),
         );
-        order.confirm().unwrap();
-        order_repo.save(&order).await.unwrap();
-
-        // ハンドラーを実行
-        let result = handler.handle(event).await;
-        assert!(result.is_ok());
-
-        // 在庫が正しく減っていることを確認
-        let updated_inventory = inventory_repo
-            .find_by_book_id(book_id)
-            .await
-            .unwrap()
-            .unwrap();
-        assert_eq!(updated_inventory.quantity_on_hand(), 7);
 
-        // InventoryReservedイベントが発行されていることを確認
-        let published_events = event_bus.get_published_events().await;
-        assert_eq!(published_events.len(), 1);
-        match &published_events[0] {
-            DomainEvent::InventoryReserved(event) => {
-                assert_eq!(event.order_id, order_id);
-            }
-            _ => panic!("Expected InventoryReserved event"),
-        }
+        Ok(())
     }
+}
 
-    #[tokio::test]
-    async fn test_inventory_reservation_handler_insufficient_stock() {
-        let inventory_repo = Arc::new(MockInventoryRepository::new());
-        let order_repo = Arc::new(MockOrderRepository::new());
-        let event_bus = Arc::new(MockEventBus::new());
-        let logger = Arc::new(MockLogger);
-        let handler = InventoryReservationHandler::new(
-            inventory_repo.clone(),
-            order_repo.clone(),
-            event_bus.clone(),
-            logger,
+#[async_trait]
+impl EventHandler<OrderShipped> for NotificationHandler {
+    async fn handle(&self, event: OrderShipped) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderShipped".to_string());
+        self.logger.info(
+            "NotificationHandler",
+            "Processing OrderShipped event",
+            Some(event.metadata.correlation_id),
+            Some(context),
         );
 
-        // テスト用の在庫を追加（少ない在庫）
-        let book_id = BookId::new();
-        let inventory = Inventory::new(book_id, 2);
-        inventory_repo.add_inventory(inventory).await;
-
-        // テスト用のOrderConfirmedイベントを作成（在庫より多い数量）
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let order_line = OrderLine::new(book_id, 5, Money::jpy(1000)).unwrap();
-        let event = OrderConfirmed::new(order_id, customer_id, vec![order_line], Money::jpy(5000));
+        let start_time = std::time::Instant::now();
 
-        // テスト用の注文を作成してリポジトリに保存
-        let mut order = crate::domain::model::Order::new(order_id, customer_id);
-        order.add_book(book_id, 5, Money::jpy(1000)).unwrap();
-        order.set_shipping_address(
-            crate::domain::model::ShippingAddress::new(
-                "1234567".to_string(),
-                "東京都".to_string(),
-                "渋谷区".to_string(),
-                "道玄坂1-1-1".to_string(),
-                None,
-            )
-            .unwrap(),
+        let destination = format!(
+            "{} {} {}",
+            event.shipping_address.prefecture(),
+            event.shipping_address.city(),
+            event.shipping_address.street()
         );
-        order.confirm().unwrap();
-        order_repo.save(&order).await.unwrap();
+        let params = TemplateParams::new().with("address", destination);
+        let (template, params) = match &event.recipient {
+            Some(recipient) => (
+                NotificationTemplateKey::GiftShipped,
+                params.with("recipient", recipient.name()),
+            ),
+            None => (NotificationTemplateKey::OrderShipped, params),
+        };
 
-        // ハンドラーを実行（失敗するはず）
-        let result = handler.handle(event).await;
-        assert!(result.is_err());
+        self.send_notification(
+            template,
+            move |_| params,
+            NotificationAudience::for_delivery(event.recipient.as_ref()),
+            event.order_id,
+            "OrderShipped",
+            event.metadata.correlation_id,
+        )
+        .await?;
 
-        // 在庫が変わっていないことを確認
-        let inventory = inventory_repo
-            .find_by_book_id(book_id)
-            .await
-            .unwrap()
-            .unwrap();
-        assert_eq!(inventory.quantity_on_hand(), 2);
+        // 処理成功ログ
+        let execution_time = start_time.elapsed();
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderShipped".to_string());
+        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
+        
+        self.logger.info(
+            "NotificationHandler",
+            "OrderShipped event processed successfully",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
 
-        // InventoryReservationFailedイベントが発行されていることを確認
-        let published_events = event_bus.get_published_events().await;
-        assert_eq!(published_events.len(), 1);
-        match &published_events[0] {
-            DomainEvent::InventoryReservationFailed(event) => {
-                assert_eq!(event.order_id, order_id);
-            }
-            _ => panic!("Expected InventoryReservationFailed event"),
-        }
+        Ok(())
     }
+}
 
-    #[tokio::test]
-    async fn test_notification_handler_order_confirmed() {
-        let logger = Arc::new(MockLogger);
-        let handler = NotificationHandler::new(logger);
-
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let event = OrderConfirmed::new(order_id, customer_id, vec![], Money::jpy(1000));
+#[async_trait]
+impl EventHandler<OrderDelivered> for NotificationHandler {
+    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderDelivered".to_string());
+        self.logger.info(
+            "NotificationHandler",
+            "Processing OrderDelivered event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
 
-        let result = handler.handle(event).await;
-        assert!(result.is_ok());
-    }
+        let start_time = std::time::Instant::now();
 
-    #[tokio::test]
-    async fn test_notification_handler_order_shipped() {
-        let logger = Arc::new(MockLogger);
-        let handler = NotificationHandler::new(logger);
+        let (template, params) = match &event.recipient {
+            Some(recipient) => (
+                NotificationTemplateKey::GiftDelivered,
+                TemplateParams::new().with("recipient", recipient.name()),
+            ),
+            None => (NotificationTemplateKey::OrderDelivered, TemplateParams::new()),
+        };
 
-        let order_id = OrderId::new();
-        let shipping_address = crate::domain::model::ShippingAddress::new(
-            "1234567".to_string(),
-            "東京都".to_string(),
-            "渋谷区".to_string(),
-            "道玄坂1-1-1".to_string(),
-            None,
+        self.send_notification(
+            template,
+            move |_| params,
+            NotificationAudience::for_delivery(event.recipient.as_ref()),
+            event.order_id,
+            "OrderDelivered",
+            event.metadata.correlation_id,
         )
-        .unwrap();
+        .await?;
 
-        let event = OrderShipped::new(order_id, shipping_address);
+        // 処理成功ログ
+        let execution_time = start_time.elapsed();
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderDelivered".to_string());
+        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
+        
+        self.logger.info(
+            "NotificationHandler",
+            "OrderDelivered event processed successfully",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
 
-        let result = handler.handle(event).await;
-        assert!(result.is_ok());
+        Ok(())
     }
+}
 
-    #[tokio::test]
-    async fn test_notification_handler_order_delivered() {
-        let logger = Arc::new(MockLogger);
-        let handler = NotificationHandler::new(logger);
-
-        let order_id = OrderId::new();
-        let event = OrderDelivered::new(order_id);
+#[async_trait]
+impl EventHandler<DigitalItemsFulfilled> for NotificationHandler {
+    async fn handle(&self, event: DigitalItemsFulfilled) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "DigitalItemsFulfilled".to_string());
+        self.logger.info(
+            "NotificationHandler",
+            "Processing DigitalItemsFulfilled event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
 
-        let result = handler.handle(event).await;
-        assert!(result.is_ok());
+        let links = event
+            .download_links
+            .iter()
+            .map(|link| link.url())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.send_notification(
+            NotificationTemplateKey::DigitalItemsFulfilled,
+            |_| TemplateParams::new().with("links", links),
+            NotificationAudience::Purchaser,
+            event.order_id,
+            "DigitalItemsFulfilled",
+            event.metadata.correlation_id,
+        )
+        .await
     }
+}
 
-    #[tokio::test]
-    async fn test_notification_handler_order_cancelled() {
-        let logger = Arc::new(MockLogger);
-        let handler = NotificationHandler::new(logger);
+#[async_trait]
+impl EventHandler<FulfillmentSlaBreached> for NotificationHandler {
+    async fn handle(&self, event: FulfillmentSlaBreached) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "FulfillmentSlaBreached".to_string());
+        context.insert("stage".to_string(), event.stage.name().to_string());
+        self.logger.info(
+            "NotificationHandler",
+            "Processing FulfillmentSlaBreached event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
 
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let event = OrderCancelled::new(order_id, customer_id, vec![]);
+        let template = match event.stage {
+            SlaStage::Shipping => NotificationTemplateKey::ShippingDelayed,
+            SlaStage::Delivery => NotificationTemplateKey::DeliveryDelayed,
+        };
 
-        let result = handler.handle(event).await;
-        assert!(result.is_ok());
+        self.send_notification(
+            template,
+            |_| TemplateParams::new(),
+            NotificationAudience::Purchaser,
+            event.order_id,
+            "FulfillmentSlaBreached",
+            event.metadata.correlation_id,
+        )
+        .await
     }
+}
 
-    #[tokio::test]
-    async fn test_compensation_mechanism_inventory_reservation_failure() {
-        let order_repo = Arc::new(MockOrderRepository::new());
-        let event_bus = Arc::new(MockEventBus::new());
-        let logger = Arc::new(MockLogger);
-        let handler = InventoryReservationFailureCompensationHandler::new(
-            order_repo.clone(),
-            event_bus.clone(),
-            logger,
+#[async_trait]
+impl EventHandler<WaitlistJoined> for NotificationHandler {
+    async fn handle(&self, event: WaitlistJoined) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "WaitlistJoined".to_string());
+        self.logger.info(
+            "NotificationHandler",
+            "Processing WaitlistJoined event",
+            Some(event.metadata.correlation_id),
+            Some(context),
         );
 
-        // テスト用の注文を作成
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let mut order = crate::domain::model::Order::new(order_id, customer_id);
-
-        // 書籍を追加
-        let book_id = BookId::new();
-        let price = Money::jpy(1000);
-        order.add_book(book_id, 2, price).unwrap();
+        let positions = |language| {
+            let positions = event
+                .positions
+                .iter()
+                .map(|position| {
+                    self.templates.render(
+                        NotificationTemplateKey::WaitlistPosition,
+                        language,
+                        &TemplateParams::new()
+                            .with("book_id", position.book_id)
+                            .with("position", position.position),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            TemplateParams::new().with("positions", positions)
+        };
 
-        // 配送先住所を設定
-        let address = crate::domain::model::ShippingAddress::new(
-            "1234567".to_string(),
-            "東京都".to_string(),
-            "渋谷区".to_string(),
-            "道玄坂1-1-1".to_string(),
-            None,
+        self.send_notification(
+            NotificationTemplateKey::WaitlistJoined,
+            positions,
+            NotificationAudience::Purchaser,
+            event.order_id,
+            "WaitlistJoined",
+            event.metadata.correlation_id,
         )
-        .unwrap();
-        order.set_shipping_address(address);
+        .await
+    }
+}
 
-        // 注文を確定状態にする
-        order.confirm().unwrap();
+#[async_trait]
+impl EventHandler<WaitlistPromoted> for NotificationHandler {
+    async fn handle(&self, event: WaitlistPromoted) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "WaitlistPromoted".to_string());
+        self.logger.info(
+            "NotificationHandler",
+            "Processing WaitlistPromoted event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
 
-        // モックリポジトリに注文を保存
-        {
-            let mut orders = order_repo.orders.lock().await;
-            orders.insert(order_id, order);
-        }
+        self.send_notification(
+            NotificationTemplateKey::WaitlistPromoted,
+            |_| TemplateParams::new(),
+            NotificationAudience::Purchaser,
+            event.order_id,
+            "WaitlistPromoted",
+            event.metadata.correlation_id,
+        )
+        .await
+    }
+}
 
-        // 在庫予約失敗イベントを作成
-        let failure_event = InventoryReservationFailed::new(
-            order_id,
-            vec![],
-            "在庫不足".to_string(),
-            Uuid::new_v4(),
+#[async_trait]
+impl EventHandler<OrderCancelled> for NotificationHandler {
+    async fn handle(&self, event: OrderCancelled) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderCancelled".to_string());
+        self.logger.info(
+            "NotificationHandler",
+            "Processing OrderCancelled event",
+            Some(event.metadata.correlation_id),
+            Some(context),
         );
 
-        // 補償ハンドラーを実行
-        let result = handler.handle(failure_event).await;
-        assert!(result.is_ok());
+        let start_time = std::time::Instant::now();
 
-        // 注文がキャンセル状態になっていることを確認
-        let orders = order_repo.orders.lock().await;
-        let updated_order = orders.get(&order_id).unwrap();
-        assert_eq!(
-            updated_order.status(),
-            crate::domain::model::OrderStatus::Cancelled
-        );
-    }
+        self.send_notification(
+            NotificationTemplateKey::OrderCancelled,
+            |_| TemplateParams::new(),
+            NotificationAudience::Purchaser,
+            event.order_id,
+            "OrderCancelled",
+            event.metadata.correlation_id,
+        )
+        .await?;
 
-    #[tokio::test]
-    async fn test_compensation_mechanism_shipping_failure() {
-        let inventory_repo = Arc::new(MockInventoryRepository::new());
-        let order_repo = Arc::new(MockOrderRepository::new());
-        let event_bus = Arc::new(MockEventBus::new());
-        let logger = Arc::new(MockLogger);
-        let handler = ShippingFailureCompensationHandler::new(
-            inventory_repo.clone(),
-            order_repo.clone(),
-            event_bus.clone(),
-            logger,
+        // 処理成功ログ
+        let execution_time = start_time.elapsed();
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderCancelled".to_string());
+        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
+        
+        self.logger.info(
+            "NotificationHandler",
+            "OrderCancelled event processed successfully",
+            Some(event.metadata.correlation_id),
+            Some(context),
         );
 
-        // テスト用の在庫を追加
-        let book_id = BookId::new();
-        let inventory = Inventory::new(book_id, 5); // 在庫を予約済み状態にするため少なめに設定
-        inventory_repo.add_inventory(inventory).await;
+        Ok(())
+    }
+}
 
-        // テスト用の注文を作成
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+/// プッシュ通知ハンドラー
+/// 注文ステータスが変わるイベントを受信し、顧客ごとのトピックへ簡潔なJSONペイロードを配信する
+#[derive(Clone)]
+pub struct PushNotificationHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    push_notification: Arc<dyn PushNotificationPort>,
+    logger: Arc<dyn Logger>,
+    /// 通知に載せるアクションリンクの発行（未設定の場合はリンクを載せない）
+    action_links: Option<ActionLinkIssuer>,
+}
 
-        // 書籍を追加
-        let price = Money::jpy(1000);
-        order.add_book(book_id, 2, price).unwrap();
+impl PushNotificationHandler {
+    /// 新しいプッシュ通知ハンドラーを作成
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        push_notification: Arc<dyn PushNotificationPort>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            order_repository,
+            push_notification,
+            logger,
+            action_links: None,
+        }
+    }
 
-        // 配送先住所を設定
-        let address = crate::domain::model::ShippingAddress::new(
-            "1234567".to_string(),
-            "東京都".to_string(),
-            "渋谷区".to_string(),
-            "道玄坂1-1-1".to_string(),
-            None,
-        )
-        .unwrap();
-        order.set_shipping_address(address);
+    /// アクションリンクの発行を設定する
+    /// 確定の通知にはキャンセル、発送の通知には受け取り確認のリンクを載せる
+    pub fn with_action_links(mut self, action_links: ActionLinkIssuer) -> Self {
+        self.action_links = Some(action_links);
+        self
+    }
 
-        // 注文を確定状態にする
-        order.confirm().unwrap();
+    /// イベントに顧客IDが含まれない場合に注文から顧客IDを取得
+    async fn find_customer_id(&self, order_id: OrderId) -> Result<CustomerId, HandlerError> {
+        let order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!("注文が見つかりません: {:?}", order_id))
+                    .at_step("load_order")
+            })?;
+        Ok(order.customer_id())
+    }
 
-        // モックリポジトリに注文を保存
-        {
-            let mut orders = order_repo.orders.lock().await;
-            orders.insert(order_id, order);
+    /// 顧客のトピックへ注文ステータスを配信
+    /// 配信失敗は一時的なエラーとしてリトライ対象にする
+    async fn publish_status(
+        &self,
+        customer_id: CustomerId,
+        order_id: OrderId,
+        status: OrderStatus,
+        occurred_at: DateTime<Utc>,
+        correlation_id: Uuid,
+        action: Option<CustomerAction>,
+    ) -> Result<(), HandlerError> {
+        let topic = customer_push_topic(customer_id);
+        let mut payload = serde_json::json!({
+            "order_id": order_id.to_string(),
+            "status": status.to_string(),
+            "occurred_at": occurred_at.to_rfc3339(),
+        });
+        if let (Some(issuer), Some(action)) = (&self.action_links, action) {
+            payload["actions"] = serde_json::json!([issuer.issue(action, order_id, Utc::now())]);
         }
+        let payload = payload.to_string();
 
-        // 発送失敗イベントを作成
-        let failure_event =
-            ShippingFailed::new(order_id, "配送業者エラー".to_string(), Uuid::new_v4());
+        self.push_notification
+            .publish_to_topic(&topic, &payload)
+            .await
+            .map_err(|e| {
+                HandlerError::TransientError(format!("プッシュ通知配信エラー: {}", e))
+                    .at_step("send_push_notification")
+            })?;
 
-        // 補償ハンドラーを実行
-        let result = handler.handle(failure_event).await;
-        assert!(result.is_ok());
+        let mut context = HashMap::new();
+        context.insert("topic".to_string(), topic);
+        context.insert("status".to_string(), status.to_string());
+        self.logger.info(
+            "PushNotificationHandler",
+            "Order status push notification published",
+            Some(correlation_id),
+            Some(context),
+        );
 
-        // 在庫が解放されていることを確認
-        let updated_inventory = inventory_repo
-            .find_by_book_id(book_id)
-            .await
-            .unwrap()
-            .unwrap();
-        assert_eq!(updated_inventory.quantity_on_hand(), 7); // 5 + 2 = 7
+        Ok(())
     }
+}
 
-    #[tokio::test]
-    async fn test_delivery_handler_success() {
-        let order_repo = Arc::new(MockOrderRepository::new());
-        let event_bus = Arc::new(MockEventBus::new());
-        let logger = Arc::new(MockLogger);
-        let handler = DeliveryHandler::new(order_repo.clone(), event_bus.clone(), logger);
-
-        // テスト用の注文を作成（発送済み状態）
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+#[async_trait]
+impl EventHandler<OrderConfirmed> for PushNotificationHandler {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        // 確定と同時に保留にされた注文は、解除されるまで顧客に通知しない
+        let on_hold = self
+            .order_repository
+            .find_by_id(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .is_some_and(|order| order.status() == OrderStatus::OnHold);
+        if on_hold {
+            return Ok(());
+        }
+        self.publish_status(
+            event.customer_id,
+            event.order_id,
+            OrderStatus::Confirmed,
+            event.metadata.occurred_at,
+            event.metadata.correlation_id,
+            Some(CustomerAction::CancelOrder),
+        )
+        .await
+    }
+}
 
-        // 書籍を追加
-        let book_id = BookId::new();
-        let price = Money::jpy(1000);
-        order.add_book(book_id, 2, price).unwrap();
+#[async_trait]
+impl EventHandler<OrderShipped> for PushNotificationHandler {
+    async fn handle(&self, event: OrderShipped) -> Result<(), HandlerError> {
+        let customer_id = self.find_customer_id(event.order_id).await?;
+        self.publish_status(
+            customer_id,
+            event.order_id,
+            OrderStatus::Shipped,
+            event.metadata.occurred_at,
+            event.metadata.correlation_id,
+            Some(CustomerAction::ConfirmDelivery),
+        )
+        .await
+    }
+}
 
-        // 配送先住所を設定
-        let address = crate::domain::model::ShippingAddress::new(
-            "1234567".to_string(),
-            "東京都".to_string(),
-            "渋谷区".to_string(),
-            "道玄坂1-1-1".to_string(),
+#[async_trait]
+impl EventHandler<OrderDelivered> for PushNotificationHandler {
+    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+        let customer_id = self.find_customer_id(event.order_id).await?;
+        self.publish_status(
+            customer_id,
+            event.order_id,
+            OrderStatus::Delivered,
+            event.metadata.occurred_at,
+            event.metadata.correlation_id,
             None,
         )
-        .unwrap();
-        order.set_shipping_address(address.clone());
-
-        // 注文を確定状態にしてから発送済み状態にする
-        order.confirm().unwrap();
-        order.mark_as_shipped().unwrap();
+        .await
+    }
+}
 
-        // モックリポジトリに注文を保存
-        {
-            let mut orders = order_repo.orders.lock().await;
-            orders.insert(order_id, order);
+#[async_trait]
+impl EventHandler<DigitalItemsFulfilled> for PushNotificationHandler {
+    async fn handle(&self, event: DigitalItemsFulfilled) -> Result<(), HandlerError> {
+        // 物理書籍を含む注文は発送・配達で通知するため、注文全体が完了した場合のみ配信
+        if !event.order_fulfilled {
+            return Ok(());
         }
+        self.publish_status(
+            event.customer_id,
+            event.order_id,
+            OrderStatus::Fulfilled,
+            event.metadata.occurred_at,
+            event.metadata.correlation_id,
+            None,
+        )
+        .await
+    }
+}
 
-        // OrderShippedイベントを作成
-        let event = OrderShipped::new(order_id, address);
+#[async_trait]
+impl EventHandler<OrderCancelled> for PushNotificationHandler {
+    async fn handle(&self, event: OrderCancelled) -> Result<(), HandlerError> {
+        self.publish_status(
+            event.customer_id,
+            event.order_id,
+            OrderStatus::Cancelled,
+            event.metadata.occurred_at,
+            event.metadata.correlation_id,
+            None,
+        )
+        .await
+    }
+}
 
-        // ハンドラーを実行
-        let result = handler.handle(event).await;
-        assert!(result.is_ok());
+/// 顧客Webhookハンドラー
+/// 注文ステータスが変わるイベントを受信し、注文の顧客が登録したWebhookの購読に配信する
+/// 配信は少なくとも1回（at-least-once）のため、受信側はペイロードのevent_idで重複を排除する
+#[derive(Clone)]
+pub struct CustomerWebhookHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    dispatcher: WebhookDispatcher,
+    logger: Arc<dyn Logger>,
+}
 
-        // 注文が配達完了状態になっていることを確認
-        let orders = order_repo.orders.lock().await;
-        let updated_order = orders.get(&order_id).unwrap();
-        assert_eq!(
-            updated_order.status(),
-            crate::domain::model::OrderStatus::Delivered
-        );
+impl CustomerWebhookHandler {
+    /// 新しい顧客Webhookハンドラーを作成
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        dispatcher: WebhookDispatcher,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            order_repository,
+            dispatcher,
+            logger,
+        }
     }
 
-    #[tokio::test]
-    async fn test_eventual_consistency_verifier() {
-        let order_repo = Arc::new(MockOrderRepository::new());
-        let inventory_repo = Arc::new(MockInventoryRepository::new());
-        let logger = Arc::new(MockLogger);
-        let verifier = EventualConsistencyVerifier::new(order_repo.clone(), inventory_repo.clone(), logger);
+    /// イベントに顧客IDが含まれない場合に注文から顧客IDを取得
+    async fn find_customer_id(&self, order_id: OrderId) -> Result<CustomerId, HandlerError> {
+        let order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!("注文が見つかりません: {:?}", order_id))
+                    .at_step("load_order")
+            })?;
+        Ok(order.customer_id())
+    }
 
-        // テスト用の在庫を追加
-        let book_id = BookId::new();
-        let inventory = Inventory::new(book_id, 10);
-        inventory_repo.add_inventory(inventory).await;
+    /// 顧客の購読にイベントを配信
+    /// 配信先の失敗は配信ログに記録して再配信APIに任せ、配信ログの記録に失敗した場合のみリトライ対象にする
+    async fn dispatch(
+        &self,
+        customer_id: CustomerId,
+        order_id: OrderId,
+        event: DomainEvent,
+    ) -> Result<(), HandlerError> {
+        let deliveries = self
+            .dispatcher
+            .dispatch(customer_id, order_id, &event)
+            .await
+            .map_err(|e| {
+                HandlerError::TransientError(format!("Webhook配信エラー: {}", e))
+                    .at_step("dispatch_webhook")
+            })?;
 
-        // テスト用の注文を作成
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+        for delivery in &deliveries {
+            let mut context = HashMap::new();
+            context.insert(
+                "subscription_id".to_string(),
+                delivery.subscription_id.to_string(),
+            );
+            context.insert("event_type".to_string(), delivery.event_type.clone());
+            context.insert("status".to_string(), delivery.status.to_string());
+            if let Some(error) = &delivery.error {
+                context.insert("error".to_string(), error.clone());
+            }
+            let correlation_id = Some(event.metadata().correlation_id);
+            match delivery.status {
+                WebhookDeliveryStatus::Succeeded => self.logger.info(
+                    "CustomerWebhookHandler",
+                    "Customer webhook delivered",
+                    correlation_id,
+                    Some(context),
+                ),
+                WebhookDeliveryStatus::Failed => self.logger.warn(
+                    "CustomerWebhookHandler",
+                    "Customer webhook delivery failed",
+                    correlation_id,
+                    Some(context),
+                ),
+            }
+        }
 
-        // 書籍を追加
-        let price = Money::jpy(1000);
-        order.add_book(book_id, 3, price).unwrap();
+        Ok(())
+    }
+}
 
-        // 配送先住所を設定
-        let address = crate::domain::model::ShippingAddress::new(
-            "1234567".to_string(),
-            "東京都".to_string(),
-            "渋谷区".to_string(),
-            "道玄坂1-1-1".to_string(),
-            None,
+#[async_trait]
+impl EventHandler<OrderConfirmed> for CustomerWebhookHandler {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        self.dispatch(
+            event.customer_id,
+            event.order_id,
+            DomainEvent::OrderConfirmed(event),
         )
-        .unwrap();
-        order.set_shipping_address(address);
-
-        // 注文を確定状態にする
-        order.confirm().unwrap();
-
-        // モックリポジトリに注文を保存
-        {
-            let mut orders = order_repo.orders.lock().await;
-            orders.insert(order_id, order);
-        }
-
-        // OrderConfirmedイベントを作成
-        let event = OrderConfirmed::new(order_id, customer_id, vec![], Money::jpy(3000));
+        .await
+    }
+}
 
-        // 整合性検証を実行
-        let result = verifier.handle(event).await;
-        assert!(result.is_ok());
+#[async_trait]
+impl EventHandler<OrderCancelled> for CustomerWebhookHandler {
+    async fn handle(&self, event: OrderCancelled) -> Result<(), HandlerError> {
+        self.dispatch(
+            event.customer_id,
+            event.order_id,
+            DomainEvent::OrderCancelled(event),
+        )
+        .await
     }
+}
 
-    #[tokio::test]
-    async fn test_complete_saga_flow() {
-        // 修正されたフローのテスト: 注文確定→在庫予約まで（発送・配達は手動操作）
-        let inventory_repo = Arc::new(MockInventoryRepository::new());
-        let order_repo = Arc::new(MockOrderRepository::new());
-        let event_bus = Arc::new(MockEventBus::new());
+#[async_trait]
+impl EventHandler<ShippingAddressChanged> for CustomerWebhookHandler {
+    async fn handle(&self, event: ShippingAddressChanged) -> Result<(), HandlerError> {
+        self.dispatch(
+            event.customer_id,
+            event.order_id,
+            DomainEvent::ShippingAddressChanged(event),
+        )
+        .await
+    }
+}
 
-        // ハンドラーを作成（実際のフローに合わせて在庫予約のみ自動実行）
-        let logger = Arc::new(MockLogger);
-        let inventory_handler = InventoryReservationHandler::new(
-            inventory_repo.clone(),
-            order_repo.clone(),
-            event_bus.clone(),
-            logger.clone(),
-        );
+#[async_trait]
+impl EventHandler<OrderShipped> for CustomerWebhookHandler {
+    async fn handle(&self, event: OrderShipped) -> Result<(), HandlerError> {
+        let customer_id = self.find_customer_id(event.order_id).await?;
+        self.dispatch(
+            customer_id,
+            event.order_id,
+            DomainEvent::OrderShipped(event),
+        )
+        .await
+    }
+}
 
-        // テスト用の在庫を追加（十分な在庫を確保）
-        let book_id = BookId::new();
-        let inventory = Inventory::new(book_id, 20);
-        inventory_repo.add_inventory(inventory).await;
+#[async_trait]
+impl EventHandler<OrderDelivered> for CustomerWebhookHandler {
+    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+        let customer_id = self.find_customer_id(event.order_id).await?;
+        self.dispatch(
+            customer_id,
+            event.order_id,
+            DomainEvent::OrderDelivered(event),
+        )
+        .await
+    }
+}
 
-        // テスト用の注文を作成
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+#[async_trait]
+impl EventHandler<DigitalItemsFulfilled> for CustomerWebhookHandler {
+    async fn handle(&self, event: DigitalItemsFulfilled) -> Result<(), HandlerError> {
+        self.dispatch(
+            event.customer_id,
+            event.order_id,
+            DomainEvent::DigitalItemsFulfilled(event),
+        )
+        .await
+    }
+}
 
-        // 書籍を追加
-        let price = Money::jpy(1000);
-        order.add_book(book_id, 3, price).unwrap();
+#[async_trait]
+impl EventHandler<HighValueOrderPlaced> for CustomerWebhookHandler {
+    async fn handle(&self, event: HighValueOrderPlaced) -> Result<(), HandlerError> {
+        self.dispatch(
+            event.customer_id,
+            event.order_id,
+            DomainEvent::HighValueOrderPlaced(event),
+        )
+        .await
+    }
+}
 
-        // 配送先住所を設定
-        let address = crate::domain::model::ShippingAddress::new(
-            "1234567".to_string(),
-            "東京都".to_string(),
-            "渋谷区".to_string(),
-            "道玄坂1-1-1".to_string(),
-            None,
+#[async_trait]
+impl EventHandler<CustomerBecameRepeatBuyer> for CustomerWebhookHandler {
+    async fn handle(&self, event: CustomerBecameRepeatBuyer) -> Result<(), HandlerError> {
+        self.dispatch(
+            event.customer_id,
+            event.order_id,
+            DomainEvent::CustomerBecameRepeatBuyer(event),
         )
-        .unwrap();
-        order.set_shipping_address(address);
+        .await
+    }
+}
 
-        // 注文を確定状態にする
-        order.confirm().unwrap();
+/// 顧客セグメントハンドラー
+/// 注文の確定・配達完了をルールで判定し、マーケティング向けの派生イベント
+/// （HighValueOrderPlaced・CustomerBecameRepeatBuyer）をイベントバスに発行する
+/// 派生イベントは元のイベントの相関IDを引き継ぐ
+#[derive(Clone)]
+pub struct CustomerSegmentationHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+    rules: SegmentationRules,
+}
 
-        // モックリポジトリに注文を保存
-        {
-            let mut orders = order_repo.orders.lock().await;
-            orders.insert(order_id, order);
+impl CustomerSegmentationHandler {
+    /// 新しい顧客セグメントハンドラーを作成
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+        rules: SegmentationRules,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_bus,
+            logger,
+            rules,
         }
+    }
 
-        // OrderConfirmedイベントを発行（在庫予約まで自動実行）
-        let event = OrderConfirmed::new(
-            order_id,
-            customer_id,
-            vec![crate::domain::model::OrderLine::new(book_id, 3, price).unwrap()],
-            Money::jpy(3000),
+    async fn publish(&self, event: DomainEvent) -> Result<(), HandlerError> {
+        let correlation_id = event.metadata().correlation_id;
+        let event_type = event.event_type();
+        let aggregate_id = event.aggregate_id();
+        self.event_bus.publish(event).await.map_err(|e| {
+            HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                .at_step("publish_event")
+        })?;
+
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), event_type.to_string());
+        context.insert("aggregate_id".to_string(), aggregate_id);
+        self.logger.info(
+            "CustomerSegmentationHandler",
+            "Customer segmentation event published",
+            Some(correlation_id),
+            Some(context),
         );
+        Ok(())
+    }
+}
 
-        // 手動でハンドラーを実行（モックイベントバスでは自動実行されないため）
-        let result = inventory_handler.handle(event.clone()).await;
-        assert!(result.is_ok());
+#[async_trait]
+impl EventHandler<OrderConfirmed> for CustomerSegmentationHandler {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        let Some(threshold) = self.rules.high_value_threshold(&event.total_amount) else {
+            return Ok(());
+        };
+        self.publish(DomainEvent::HighValueOrderPlaced(
+            HighValueOrderPlaced::with_correlation_id(
+                event.order_id,
+                event.customer_id,
+                event.total_amount,
+                threshold,
+                event.metadata.correlation_id,
+            ),
+        ))
+        .await
+    }
+}
 
-        // イベントが発行されたことを確認
-        let published_events = event_bus.get_published_events().await;
-        assert_eq!(published_events.len(), 1);
-        match &published_events[0] {
-            DomainEvent::InventoryReserved(event) => {
-                assert_eq!(event.order_id, order_id);
-            }
-            _ => panic!("Expected InventoryReserved event"),
+#[async_trait]
+impl EventHandler<OrderDelivered> for CustomerSegmentationHandler {
+    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+        if self.rules.repeat_buyer_order_count.is_none() {
+            return Ok(());
+        }
+        let order = self
+            .order_repository
+            .find_by_id(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!(
+                    "注文が見つかりません: {:?}",
+                    event.order_id
+                ))
+                .at_step("load_order")
+            })?;
+        let delivered_order_count = self
+            .order_repository
+            .count_orders_by_customer_and_status(order.customer_id(), OrderStatus::Delivered)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("配達完了した注文数の取得エラー: {}", e))
+                    .at_step("count_delivered_orders")
+            })?;
+        if !self.rules.becomes_repeat_buyer(delivered_order_count) {
+            return Ok(());
         }
+        self.publish(DomainEvent::CustomerBecameRepeatBuyer(
+            CustomerBecameRepeatBuyer::with_correlation_id(
+                order.customer_id(),
+                event.order_id,
+                delivered_order_count,
+                event.metadata.correlation_id,
+            ),
+        ))
+        .await
+    }
+}
 
-        // 注文確定後の状態を確認（Confirmedのまま、在庫のみ予約済み）
-        let orders = order_repo.orders.lock().await;
-        let order_after_confirmation = orders.get(&order_id).unwrap();
+/// リスク保留ハンドラー
+/// 確定した注文の合計金額が閾値以上の場合に、不正の疑いとして注文を保留にする（OrderHeldを発行）
+/// 在庫の予約・通知より先に処理するよう購読し、保留にした注文は管理者が解除するまで発送・通知しない
+/// 保留にした注文は在庫の予約だけを行い、発売待ち・順番待ちにはしない
+#[derive(Clone)]
+pub struct RiskHoldHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+    threshold: Money,
+}
 
-        // 注文はConfirmed状態のまま（発送は手動操作のため）
-        assert_eq!(
-            order_after_confirmation.status(),
-            crate::domain::model::OrderStatus::Confirmed
-        );
-
-        // 在庫が予約されていることを確認
-        let updated_inventory = inventory_repo
-            .find_by_book_id(book_id)
-            .await
-            .unwrap()
-            .unwrap();
-        assert_eq!(
-            updated_inventory.quantity_on_hand(),
-            17, // 20 - 3 = 17
-            "Expected inventory to be reduced to 17, but got: {}",
-            updated_inventory.quantity_on_hand()
-        );
-    }
-
-    #[tokio::test]
-    async fn test_manual_shipping_and_delivery_flow() {
-        // 手動操作フローのテスト: 発送・配達は手動API呼び出しで実行
-        let inventory_repo = Arc::new(MockInventoryRepository::new());
-        let order_repo = Arc::new(MockOrderRepository::new());
-        let event_bus = Arc::new(MockEventBus::new());
-
-        // 通知ハンドラーのみ登録（発送・配達時の通知用）
-        let logger = Arc::new(MockLogger);
-        let notification_handler = NotificationHandler::new(logger.clone());
-
-        // テスト用の注文を作成（Confirmed状態）
-        let order_id = OrderId::new();
-        let customer_id = CustomerId::new();
-        let mut order = crate::domain::model::Order::new(order_id, customer_id);
-
-        // 書籍を追加
-        let book_id = BookId::new();
-        let price = Money::jpy(1000);
-        order.add_book(book_id, 3, price).unwrap();
-
-        // 配送先住所を設定
-        let address = crate::domain::model::ShippingAddress::new(
-            "1234567".to_string(),
-            "東京都".to_string(),
-            "渋谷区".to_string(),
-            "道玄坂1-1-1".to_string(),
-            None,
-        )
-        .unwrap();
-        order.set_shipping_address(address.clone());
-
-        // 注文を確定状態にする
-        order.confirm().unwrap();
-
-        // モックリポジトリに注文を保存
-        {
-            let mut orders = order_repo.orders.lock().await;
-            orders.insert(order_id, order);
-        }
-
-        // 手動発送操作をシミュレート
-        {
-            let mut orders = order_repo.orders.lock().await;
-            let mut order = orders.get_mut(&order_id).unwrap();
-            order.mark_as_shipped().unwrap();
-
-            // OrderShippedイベントを手動発行（実際のAPIでは自動発行される）
-            let shipped_event = crate::domain::event::OrderShipped::new(order_id, address.clone());
-            event_bus
-                .publish(crate::domain::event::DomainEvent::OrderShipped(
-                    shipped_event.clone(),
-                ))
-                .await
-                .unwrap();
-
-            // 通知ハンドラーを手動実行
-            notification_handler.handle(shipped_event).await.unwrap();
-        }
-
-        // イベント処理を待つ
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-
-        // 発送後の状態確認
-        {
-            let orders = order_repo.orders.lock().await;
-            let order = orders.get(&order_id).unwrap();
-            assert_eq!(order.status(), crate::domain::model::OrderStatus::Shipped);
-        }
-
-        // 手動配達完了操作をシミュレート
-        {
-            let mut orders = order_repo.orders.lock().await;
-            let mut order = orders.get_mut(&order_id).unwrap();
-            order.mark_as_delivered().unwrap();
-
-            // OrderDeliveredイベントを手動発行（実際のAPIでは自動発行される）
-            let delivered_event = crate::domain::event::OrderDelivered::new(order_id);
-            event_bus
-                .publish(crate::domain::event::DomainEvent::OrderDelivered(
-                    delivered_event.clone(),
-                ))
-                .await
-                .unwrap();
-
-            // 通知ハンドラーを手動実行
-            notification_handler.handle(delivered_event).await.unwrap();
-        }
-
-        // イベント処理を待つ
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-
-        // 配達完了後の状態確認
-        {
-            let orders = order_repo.orders.lock().await;
-            let order = orders.get(&order_id).unwrap();
-            assert_eq!(order.status(), crate::domain::model::OrderStatus::Delivered);
-        }
-    }
-
-    #[tokio::test]
-    async fn test_saga_compensation_coordinator() {
-        let event_bus = Arc::new(MockEventBus::new());
-        let logger = Arc::new(MockLogger);
-        let coordinator = SagaCompensationCoordinator::new(event_bus.clone(), logger);
-
-        let saga_id = Uuid::new_v4();
-        let result = coordinator
-            .start_compensation(
-                saga_id,
-                "shipping".to_string(),
-                "配送業者エラー".to_string(),
-            )
-            .await;
-
-        assert!(result.is_ok());
-
-        // SagaCompensationStartedイベントが発行されていることを確認
-        let published_events = event_bus.get_published_events().await;
-        assert_eq!(published_events.len(), 1);
-        match &published_events[0] {
-            DomainEvent::SagaCompensationStarted(event) => {
-                assert_eq!(event.saga_id, saga_id);
-            }
-            _ => panic!("Expected SagaCompensationStarted event"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_compensation_completion_handler() {
-        let logger = Arc::new(MockLogger);
-        let handler = CompensationCompletionHandler::new(logger);
-
-        let saga_id = Uuid::new_v4();
-        let event = SagaCompensationCompleted::new(
-            saga_id,
-            vec!["inventory_reservation".to_string()],
-            CompensationResult::Success,
-        );
-
-        let result = handler.handle(event).await;
-        assert!(result.is_ok());
-    }
-}
-
-// ========== 補償ハンドラー（サーガ失敗時のロールバック処理） ==========
-
-/// 在庫予約失敗補償ハンドラー
-/// InventoryReservationFailedイベントを受信して注文をキャンセルする
-pub struct InventoryReservationFailureCompensationHandler {
-    order_repository: Arc<dyn OrderRepository>,
-    event_bus: Arc<dyn EventBus>,
-    logger: Arc<dyn Logger>,
-}
-
-impl InventoryReservationFailureCompensationHandler {
-    /// 新しい在庫予約失敗補償ハンドラーを作成
-    pub fn new(order_repository: Arc<dyn OrderRepository>, event_bus: Arc<dyn EventBus>, logger: Arc<dyn Logger>) -> Self {
+impl RiskHoldHandler {
+    /// 新しいリスク保留ハンドラーを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ
+    /// * `event_bus` - OrderHeldを発行するイベントバス
+    /// * `logger` - ロガー
+    /// * `threshold` - 保留にする注文の合計金額の下限
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+        threshold: Money,
+    ) -> Self {
         Self {
             order_repository,
             event_bus,
             logger,
+            threshold,
         }
     }
 }
 
 #[async_trait]
-impl EventHandler<InventoryReservationFailed> for InventoryReservationFailureCompensationHandler {
-    async fn handle(&self, event: InventoryReservationFailed) -> Result<(), HandlerError> {
-        // 補償ログ出力
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "InventoryReservationFailed".to_string());
-        context.insert("compensation_type".to_string(), "InventoryReservationFailure".to_string());
-        self.logger.info(
-            "InventoryReservationFailureCompensationHandler",
-            "Processing InventoryReservationFailed compensation event",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        let start_time = std::time::Instant::now();
+impl EventHandler<OrderConfirmed> for RiskHoldHandler {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        if event.total_amount.amount() < self.threshold.amount() {
+            return Ok(());
+        }
 
-        // 注文を取得
         let mut order = self
             .order_repository
             .find_by_id(event.order_id)
             .await
-            .map_err(|e| HandlerError::RepositoryError(format!("注文取得エラー: {}", e)))?
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
             .ok_or_else(|| {
                 HandlerError::ProcessingFailed(format!(
                     "注文が見つかりません: {:?}",
                     event.order_id
                 ))
+                .at_step("load_order")
             })?;
 
-        // 注文をキャンセル（補償アクション）
-        order
-            .cancel()
-            .map_err(|e| HandlerError::DomainError(format!("注文キャンセルエラー: {}", e)))?;
+        // 既に保留中・発売待ち・キャンセル済みなどの注文と電子書籍のみの注文は保留にしない
+        if order.place_on_hold(HoldReason::SuspectedFraud).is_err() {
+            let mut context = HashMap::new();
+            context.insert("order_id".to_string(), event.order_id.to_string());
+            context.insert("current_status".to_string(), order.status().to_string());
+            self.logger.debug(
+                "RiskHoldHandler",
+                "High-value order cannot be placed on hold, skipping",
+                Some(event.metadata.correlation_id),
+                Some(context),
+            );
+            return Ok(());
+        }
 
-        // 注文を保存
-        self.order_repository
-            .save(&order)
-            .await
-            .map_err(|e| HandlerError::RepositoryError(format!("注文保存エラー: {}", e)))?;
+        let sequence_number = order.record_event();
+        self.order_repository.save(&mut order).await.map_err(|e| {
+            HandlerError::RepositoryError(format!("注文保存エラー: {}", e)).at_step("save_order")
+        })?;
 
-        let cancelled_event = crate::domain::event::OrderCancelled::with_correlation_id(
+        let mut held_event = OrderHeld::new(
             order.id(),
             order.customer_id(),
-            order.order_lines().to_vec(),
-            event.metadata.correlation_id,
+            HoldReason::SuspectedFraud,
+            Some(format!(
+                "合計金額が{}円以上のため自動で保留にしました",
+                self.threshold.amount()
+            )),
         );
-        let domain_event = crate::domain::event::DomainEvent::OrderCancelled(cancelled_event);
-
+        held_event.metadata.correlation_id = event.metadata.correlation_id;
+        held_event.metadata.sequence_number = Some(sequence_number);
         self.event_bus
-            .publish(domain_event)
+            .publish(DomainEvent::OrderHeld(held_event))
             .await
-            .map_err(|e| HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e)))?;
+            .map_err(|e| {
+                HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                    .at_step("publish_event")
+            })?;
 
-        // 補償処理完了ログ
-        let execution_time = start_time.elapsed();
         let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "InventoryReservationFailed".to_string());
-        context.insert("compensation_type".to_string(), "InventoryReservationFailure".to_string());
-        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
-        
-        self.logger.info(
-            "InventoryReservationFailureCompensationHandler",
-            "InventoryReservationFailed compensation completed successfully",
+        context.insert("order_id".to_string(), event.order_id.to_string());
+        context.insert(
+            "total_amount".to_string(),
+            event.total_amount.amount().to_string(),
+        );
+        self.logger.warn(
+            "RiskHoldHandler",
+            "High-value order placed on hold for risk review",
             Some(event.metadata.correlation_id),
             Some(context),
         );
@@ -1913,243 +3100,2684 @@ impl EventHandler<InventoryReservationFailed> for InventoryReservationFailureCom
     }
 }
 
-/// 発送失敗補償ハンドラー
-/// ShippingFailedイベントを受信して在庫を解放する
-pub struct ShippingFailureCompensationHandler {
-    inventory_repository: Arc<dyn InventoryRepository>,
-    order_repository: Arc<dyn OrderRepository>,
-    event_bus: Arc<dyn EventBus>,
-    logger: Arc<dyn Logger>,
+/// 配送追跡タイムラインのプロジェクション
+/// 注文イベントから作った追跡イベントをtracking_eventsへ投影する
+/// 投影対象外のイベント（キャンセル・住所変更・返品など）はNoneとして、チェックポイントだけを進める
+pub struct TrackingTimelineProjection {
+    tracking_repository: Arc<dyn TrackingEventRepository>,
 }
 
-impl ShippingFailureCompensationHandler {
-    /// 新しい発送失敗補償ハンドラーを作成
+#[async_trait]
+impl CheckpointedProjection for TrackingTimelineProjection {
+    type Change = Option<TrackingEvent>;
+
+    fn name(&self) -> &'static str {
+        TrackingProjectionHandler::PROJECTION_NAME
+    }
+
+    async fn apply_checkpointed(
+        &self,
+        checkpoint: &ProjectionCheckpoint,
+        change: &Self::Change,
+    ) -> Result<bool, RepositoryError> {
+        self.tracking_repository
+            .apply_checkpointed(checkpoint, change.as_ref())
+            .await
+    }
+
+    async fn apply(&self, change: &Self::Change) -> Result<(), RepositoryError> {
+        match change {
+            Some(tracking_event) => self.tracking_repository.append(tracking_event).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn load_checkpoints(&self) -> Result<Vec<ProjectionCheckpoint>, RepositoryError> {
+        self.tracking_repository
+            .load_checkpoints(TrackingProjectionHandler::PROJECTION_NAME)
+            .await
+    }
+}
+
+/// 配送追跡プロジェクションハンドラー
+/// 注文イベントと配送業者の追跡情報をtracking_eventsへ投影し、顧客向けタイムラインの元データにする
+/// 注文イベントは注文ごとの連番の順に投影し、先に届いたイベントは先行するイベントが揃うまで保留する
+/// 投影と一緒に注文ごとのチェックポイントを保存し、再起動後の再配信で同じイベントを二重に適用しない
+#[derive(Clone)]
+pub struct TrackingProjectionHandler {
+    projector: SequencedProjector<TrackingTimelineProjection>,
+}
+
+impl TrackingProjectionHandler {
+    /// プロジェクション名（チェックポイントと監視エンドポイントで使う）
+    pub const PROJECTION_NAME: &'static str = "tracking_timeline";
+
+    /// 新しい配送追跡プロジェクションハンドラーを作成
     pub fn new(
-        inventory_repository: Arc<dyn InventoryRepository>,
-        order_repository: Arc<dyn OrderRepository>,
-        event_bus: Arc<dyn EventBus>,
+        tracking_repository: Arc<dyn TrackingEventRepository>,
         logger: Arc<dyn Logger>,
     ) -> Self {
         Self {
-            inventory_repository,
-            order_repository,
-            event_bus,
-            logger,
+            projector: SequencedProjector::new(
+                TrackingTimelineProjection {
+                    tracking_repository,
+                },
+                logger,
+            ),
         }
     }
-}
 
-#[async_trait]
-impl EventHandler<ShippingFailed> for ShippingFailureCompensationHandler {
-    async fn handle(&self, event: ShippingFailed) -> Result<(), HandlerError> {
-        // 補償ログ出力
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "ShippingFailed".to_string());
-        context.insert("compensation_type".to_string(), "ShippingFailure".to_string());
-        self.logger.info(
-            "ShippingFailureCompensationHandler",
-            "Processing ShippingFailed compensation event",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
+    /// 投影の処理位置の記録先を設定
+    /// 監視エンドポイントと共有し、プロジェクションの遅延を公開する
+    pub fn with_progress(mut self, progress: ProjectionProgress) -> Self {
+        self.projector = self.projector.with_progress(progress);
+        self
+    }
 
-        let start_time = std::time::Instant::now();
+    /// 永続化したチェックポイントから投影を再開する（起動時に呼び出す）
+    /// チェックポイントのある注文は、適用済みの連番の続きから投影する
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 再開した注文の数
+    /// * `Err(HandlerError)` - チェックポイントの取得失敗
+    pub async fn recover(&self) -> Result<usize, HandlerError> {
+        self.projector.recover().await
+    }
 
-        // 注文を取得
-        let order = self
-            .order_repository
-            .find_by_id(event.order_id)
+    /// 注文イベントを連番の順に投影
+    /// 投影対象外のイベント（キャンセル・住所変更・返品）も連番を進めるために受け付ける
+    async fn project_in_sequence(
+        &self,
+        event_type: &'static str,
+        metadata: &EventMetadata,
+        order_id: OrderId,
+        tracking_event: Option<TrackingEvent>,
+    ) -> Result<(), HandlerError> {
+        self.projector
+            .project(event_type, metadata, &order_id.to_string(), tracking_event)
             .await
-            .map_err(|e| HandlerError::RepositoryError(format!("注文取得エラー: {}", e)))?
-            .ok_or_else(|| {
-                HandlerError::ProcessingFailed(format!(
-                    "注文が見つかりません: {:?}",
-                    event.order_id
-                ))
-            })?;
-
-        // 各注文明細について在庫を解放（補償アクション）
-        for order_line in order.order_lines() {
-            // 在庫を取得
-            let mut inventory = match self
-                .inventory_repository
-                .find_by_book_id(order_line.book_id())
-                .await
-                .map_err(|e| HandlerError::RepositoryError(format!("在庫取得エラー: {}", e)))?
-            {
-                Some(inventory) => inventory,
-                None => {
-                    // 在庫が見つからない場合はスキップ（ログに記録）
-                    let mut context = HashMap::new();
-                    context.insert("book_id".to_string(), format!("{:?}", order_line.book_id()));
-                    context.insert("reason".to_string(), "inventory_not_found".to_string());
-                    
-                    self.logger.warn(
-                        "ShippingFailureCompensationHandler",
-                        "Inventory not found for book, skipping release",
-                        Some(event.metadata.correlation_id),
-                        Some(context),
-                    );
-                    continue;
-                }
-            };
+    }
+}
 
-            // 在庫を解放
-            inventory
-                .release(order_line.quantity())
-                .map_err(|e| HandlerError::DomainError(format!("在庫解放エラー: {}", e)))?;
+#[async_trait]
+impl EventHandler<OrderConfirmed> for TrackingProjectionHandler {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        let tracking_event = TrackingEvent {
+            event_id: event.metadata.event_id,
+            order_id: event.order_id,
+            stage: TrackingStage::Ordered,
+            occurred_at: event.metadata.occurred_at,
+            location: None,
+            description: None,
+        };
+        self.project_in_sequence(
+            "OrderConfirmed",
+            &event.metadata,
+            event.order_id,
+            Some(tracking_event),
+        )
+        .await
+    }
+}
 
-            // 在庫を保存
-            self.inventory_repository
-                .save(&inventory)
-                .await
-                .map_err(|e| HandlerError::RepositoryError(format!("在庫保存エラー: {}", e)))?;
-        }
+#[async_trait]
+impl EventHandler<OrderShipped> for TrackingProjectionHandler {
+    async fn handle(&self, event: OrderShipped) -> Result<(), HandlerError> {
+        let tracking_event = TrackingEvent {
+            event_id: event.metadata.event_id,
+            order_id: event.order_id,
+            stage: TrackingStage::Shipped,
+            occurred_at: event.metadata.occurred_at,
+            location: None,
+            description: None,
+        };
+        self.project_in_sequence(
+            "OrderShipped",
+            &event.metadata,
+            event.order_id,
+            Some(tracking_event),
+        )
+        .await
+    }
+}
 
-        // InventoryReleasedイベントを発行
-        let inventory_released_event = InventoryReleased::with_correlation_id(
+#[async_trait]
+impl EventHandler<OrderDelivered> for TrackingProjectionHandler {
+    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+        let tracking_event = TrackingEvent {
+            event_id: event.metadata.event_id,
+            order_id: event.order_id,
+            stage: TrackingStage::Delivered,
+            occurred_at: event.metadata.occurred_at,
+            location: None,
+            description: None,
+        };
+        self.project_in_sequence(
+            "OrderDelivered",
+            &event.metadata,
             event.order_id,
-            order.order_lines().to_vec(),
-            event.metadata.correlation_id,
-        );
+            Some(tracking_event),
+        )
+        .await
+    }
+}
 
-        self.event_bus
-            .publish(DomainEvent::InventoryReleased(inventory_released_event))
+#[async_trait]
+impl EventHandler<OrderCancelled> for TrackingProjectionHandler {
+    async fn handle(&self, event: OrderCancelled) -> Result<(), HandlerError> {
+        self.project_in_sequence("OrderCancelled", &event.metadata, event.order_id, None)
             .await
-            .map_err(|e| HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e)))?;
-
-        // 補償処理完了ログ
-        let execution_time = start_time.elapsed();
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "ShippingFailed".to_string());
-        context.insert("compensation_type".to_string(), "ShippingFailure".to_string());
-        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
-        
-        self.logger.info(
-            "ShippingFailureCompensationHandler",
-            "ShippingFailed compensation completed successfully",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        Ok(())
     }
 }
 
-/// サーガ補償コーディネーター
-/// サーガ補償コーディネーター
-/// サーガの失敗を検出し、補償プロセスを開始する
-pub struct SagaCompensationCoordinator {
-    event_bus: Arc<dyn EventBus>,
-    logger: Arc<dyn Logger>,
+#[async_trait]
+impl EventHandler<OrderHeld> for TrackingProjectionHandler {
+    async fn handle(&self, event: OrderHeld) -> Result<(), HandlerError> {
+        self.project_in_sequence("OrderHeld", &event.metadata, event.order_id, None)
+            .await
+    }
 }
 
-impl SagaCompensationCoordinator {
-    /// 新しいサーガ補償コーディネーターを作成
-    pub fn new(event_bus: Arc<dyn EventBus>, logger: Arc<dyn Logger>) -> Self {
-        Self { event_bus, logger }
+#[async_trait]
+impl EventHandler<OrderReleased> for TrackingProjectionHandler {
+    async fn handle(&self, event: OrderReleased) -> Result<(), HandlerError> {
+        self.project_in_sequence("OrderReleased", &event.metadata, event.order_id, None)
+            .await
     }
+}
 
-    /// サーガ失敗を検出し、補償を開始
-    pub async fn start_compensation(
-        &self,
-        saga_id: Uuid,
-        failed_step: String,
-        failure_reason: String,
-    ) -> Result<(), HandlerError> {
-        // 補償が必要なステップを決定（逆順）
-        let compensation_steps = self.determine_compensation_steps(&failed_step);
+#[async_trait]
+impl EventHandler<OrderStatusForcefullyChanged> for TrackingProjectionHandler {
+    async fn handle(&self, event: OrderStatusForcefullyChanged) -> Result<(), HandlerError> {
+        // 発送済み・配達完了に強制的に変更した場合だけ配送状況に反映する（理由は社内向けのため公開しない）
+        let stage = match OrderStatus::from_string(&event.to_status) {
+            Ok(OrderStatus::Shipped) => Some(TrackingStage::Shipped),
+            Ok(OrderStatus::Delivered) => Some(TrackingStage::Delivered),
+            _ => None,
+        };
+        let tracking_event = stage.map(|stage| TrackingEvent {
+            event_id: event.metadata.event_id,
+            order_id: event.order_id,
+            stage,
+            occurred_at: event.metadata.occurred_at,
+            location: None,
+            description: None,
+        });
+        self.project_in_sequence(
+            "OrderStatusForcefullyChanged",
+            &event.metadata,
+            event.order_id,
+            tracking_event,
+        )
+        .await
+    }
+}
 
-        // サーガ補償開始イベントを発行
-        let compensation_started_event =
-            SagaCompensationStarted::new(saga_id, failed_step, failure_reason, compensation_steps);
+#[async_trait]
+impl EventHandler<ShippingAddressChanged> for TrackingProjectionHandler {
+    async fn handle(&self, event: ShippingAddressChanged) -> Result<(), HandlerError> {
+        self.project_in_sequence(
+            "ShippingAddressChanged",
+            &event.metadata,
+            event.order_id,
+            None,
+        )
+        .await
+    }
+}
 
-        self.event_bus
-            .publish(DomainEvent::SagaCompensationStarted(
-                compensation_started_event,
-            ))
+#[async_trait]
+impl EventHandler<ReturnRequested> for TrackingProjectionHandler {
+    async fn handle(&self, event: ReturnRequested) -> Result<(), HandlerError> {
+        self.project_in_sequence("ReturnRequested", &event.metadata, event.order_id, None)
             .await
-            .map_err(|e| {
-                HandlerError::ProcessingFailed(format!("補償開始イベント発行エラー: {}", e))
-            })?;
-
-        Ok(())
     }
+}
 
-    /// 失敗したステップに基づいて補償が必要なステップを決定
-    fn determine_compensation_steps(&self, failed_step: &str) -> Vec<String> {
-        match failed_step {
-            "inventory_reservation" => vec![], // 最初のステップなので補償不要
-            "shipping" => vec!["inventory_reservation".to_string()], // 在庫予約を補償
-            "delivery" => vec!["shipping".to_string(), "inventory_reservation".to_string()], // 発送と在庫予約を補償
-            _ => vec![], // 不明なステップ
-        }
+#[async_trait]
+impl EventHandler<ReturnApproved> for TrackingProjectionHandler {
+    async fn handle(&self, event: ReturnApproved) -> Result<(), HandlerError> {
+        self.project_in_sequence("ReturnApproved", &event.metadata, event.order_id, None)
+            .await
     }
 }
 
 #[async_trait]
-impl EventHandler<SagaCompensationStarted> for SagaCompensationCoordinator {
-    async fn handle(&self, event: SagaCompensationStarted) -> Result<(), HandlerError> {
-        // サーガ補償開始ログ
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "SagaCompensationStarted".to_string());
-        context.insert("saga_id".to_string(), event.saga_id.to_string());
-        context.insert("failed_step".to_string(), event.failed_step.clone());
-        context.insert("compensation_steps_count".to_string(), event.compensation_steps.len().to_string());
-        
-        self.logger.info(
-            "SagaCompensationCoordinator",
-            "Saga compensation process started",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
-
-        // 実際の補償処理は個別の補償ハンドラーが実行するため、
-        // ここでは補償プロセスの追跡とログ出力のみ行う
-
-        Ok(())
+impl EventHandler<CarrierTrackingUpdated> for TrackingProjectionHandler {
+    async fn handle(&self, event: CarrierTrackingUpdated) -> Result<(), HandlerError> {
+        // 配送業者側の発生日時でタイムラインに並べる
+        let tracking_event = TrackingEvent {
+            event_id: event.metadata.event_id,
+            order_id: event.order_id,
+            stage: event.stage,
+            occurred_at: event.reported_at,
+            location: event.location,
+            description: event.description,
+        };
+        // 配送業者の追跡情報は連番を持たないため到着順に投影する
+        self.project_in_sequence(
+            "CarrierTrackingUpdated",
+            &event.metadata,
+            event.order_id,
+            Some(tracking_event),
+        )
+        .await
     }
 }
 
-/// 補償完了ハンドラー
-/// 補償プロセスの完了を監視し、ログを記録する
-pub struct CompensationCompletionHandler {
+/// 配達ハンドラー
+/// OrderShippedイベントを受信して注文を配達完了状態にする
+/// 配送業者を設定した場合は荷物を引き渡し、配送業者から通知された配達の結果を反映する
+#[derive(Clone)]
+pub struct DeliveryHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    event_bus: Arc<dyn EventBus>,
+    processed_events: ProcessedEventTracker,
+    late_events: LateEventGuard,
+    carrier: Option<Arc<dyn ShippingCarrierPort>>,
     logger: Arc<dyn Logger>,
 }
 
-impl CompensationCompletionHandler {
-    /// 新しい補償完了ハンドラーを作成
-    pub fn new(logger: Arc<dyn Logger>) -> Self {
-        Self { logger }
+impl DeliveryHandler {
+    /// 新しい配達ハンドラーを作成
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_bus: Arc<dyn EventBus>,
+        processed_event_repository: Arc<dyn ProcessedEventRepository>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_bus,
+            processed_events: ProcessedEventTracker::new(
+                processed_event_repository,
+                "DeliveryHandler",
+                logger.clone(),
+            ),
+            late_events: LateEventGuard::skip_only(logger.clone()),
+            carrier: None,
+            logger,
+        }
     }
-}
 
-#[async_trait]
-impl EventHandler<SagaCompensationCompleted> for CompensationCompletionHandler {
-    async fn handle(&self, event: SagaCompensationCompleted) -> Result<(), HandlerError> {
-        let start_time = std::time::Instant::now();
+    /// 遅延イベントの扱いを設定する（未設定の場合は読み飛ばす）
+    pub fn with_late_event_guard(mut self, late_events: LateEventGuard) -> Self {
+        self.late_events = late_events;
+        self
+    }
 
-        // 補償完了ログ出力
-        let result_str = match &event.compensation_result {
-            CompensationResult::Success => "Success",
-            CompensationResult::PartialSuccess { .. } => "PartialSuccess",
-            CompensationResult::Failed { .. } => "Failed",
+    /// 配送業者を設定する（未設定の場合は発送後すぐに配達完了にする）
+    pub fn with_carrier(mut self, carrier: Arc<dyn ShippingCarrierPort>) -> Self {
+        self.carrier = Some(carrier);
+        self
+    }
+
+    /// 配達の結果を注文に反映する
+    /// 配達完了の場合は注文を配達完了にしてOrderDeliveredを発行し、
+    /// 配達できなかった場合は補償イベント（DeliveryFailed）を発行して失敗理由を返す
+    async fn apply_delivery_result(
+        &self,
+        order: &mut Order,
+        original_event_id: Uuid,
+        correlation_id: Uuid,
+        result: CarrierDeliveryResult,
+    ) -> Result<Option<String>, HandlerError> {
+        let outcome = match result {
+            CarrierDeliveryResult::Delivered => order.mark_as_delivered().map_err(|e| e.to_string()),
+            CarrierDeliveryResult::Failed(reason) => Err(reason),
         };
 
-        let mut context = HashMap::new();
-        context.insert("event_type".to_string(), "SagaCompensationCompleted".to_string());
-        context.insert("saga_id".to_string(), event.saga_id.to_string());
-        context.insert("compensation_result".to_string(), result_str.to_string());
-        context.insert("completed_steps_count".to_string(), event.compensated_steps.len().to_string());
-        context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
-        
-        self.logger.info(
-            "CompensationCompletionHandler",
-            "Saga compensation process completed",
-            Some(event.metadata.correlation_id),
-            Some(context),
-        );
+        match outcome {
+            Ok(()) => {
+                // 注文を保存（イベントの連番も一緒に記録）
+                let sequence_number = order.record_event();
+                self.order_repository.save(order).await.map_err(|e| {
+                    HandlerError::RepositoryError(format!("注文保存エラー: {}", e))
+                        .at_step("save_order")
+                })?;
 
-        Ok(())
+                let mut delivered_event =
+                    crate::domain::event::OrderDelivered::with_correlation_id(
+                        order.id(),
+                        correlation_id,
+                    )
+                    .with_recipient(order.recipient().cloned());
+                delivered_event.metadata.sequence_number = Some(sequence_number);
+                let domain_event =
+                    crate::domain::event::DomainEvent::OrderDelivered(delivered_event);
+
+                self.event_bus.publish(domain_event).await.map_err(|e| {
+                    HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                        .at_step("publish_event")
+                })?;
+                Ok(None)
+            }
+            Err(reason) => {
+                // 配達失敗 - 補償イベントを発行
+                let failure_reason = format!("配達処理失敗: {}", reason);
+                let compensation_event = crate::domain::event::DeliveryFailed::with_correlation_id(
+                    order.id(),
+                    failure_reason.clone(),
+                    original_event_id,
+                    correlation_id,
+                );
+
+                self.event_bus
+                    .publish(DomainEvent::DeliveryFailed(compensation_event))
+                    .await
+                    .map_err(|e| {
+                        HandlerError::ProcessingFailed(format!("補償イベント発行エラー: {}", e))
+                            .at_step("publish_compensation_event")
+                    })?;
+                Ok(Some(failure_reason))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DeliveryResultCallback for DeliveryHandler {
+    async fn on_delivery_result(&self, shipment: Shipment, result: CarrierDeliveryResult) {
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), shipment.order_id.to_string());
+        context.insert("result".to_string(), format!("{:?}", result));
+
+        let order = match self.order_repository.find_by_id(shipment.order_id).await {
+            Ok(Some(order)) => order,
+            Ok(None) => {
+                self.logger.error(
+                    "DeliveryHandler",
+                    "Order for carrier delivery result not found",
+                    Some(shipment.correlation_id),
+                    Some(context),
+                );
+                return;
+            }
+            Err(e) => {
+                context.insert("error".to_string(), e.to_string());
+                self.logger.error(
+                    "DeliveryHandler",
+                    "Failed to load order for carrier delivery result",
+                    Some(shipment.correlation_id),
+                    Some(context),
+                );
+                return;
+            }
+        };
+
+        // 引き渡し後に手動で配達完了にされた注文などには結果を反映しない
+        if order.status() != OrderStatus::Shipped {
+            context.insert("order_status".to_string(), format!("{:?}", order.status()));
+            self.logger.warn(
+                "DeliveryHandler",
+                "Carrier delivery result ignored because the order is no longer shipped",
+                Some(shipment.correlation_id),
+                Some(context),
+            );
+            return;
+        }
+
+        let mut order = order;
+        match self
+            .apply_delivery_result(
+                &mut order,
+                shipment.shipped_event_id,
+                shipment.correlation_id,
+                result,
+            )
+            .await
+        {
+            Ok(None) => self.logger.info(
+                "DeliveryHandler",
+                "Carrier delivery result applied",
+                Some(shipment.correlation_id),
+                Some(context),
+            ),
+            Ok(Some(failure_reason)) => {
+                context.insert("error".to_string(), failure_reason);
+                self.logger.error(
+                    "DeliveryHandler",
+                    "Carrier reported delivery failure",
+                    Some(shipment.correlation_id),
+                    Some(context),
+                );
+            }
+            Err(e) => {
+                context.insert("error".to_string(), e.to_string());
+                self.logger.error(
+                    "DeliveryHandler",
+                    "Failed to apply carrier delivery result",
+                    Some(shipment.correlation_id),
+                    Some(context),
+                );
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<OrderShipped> for DeliveryHandler {
+    async fn handle(&self, event: OrderShipped) -> Result<(), HandlerError> {
+        // ハンドラー開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderShipped".to_string());
+        self.logger.info(
+            "DeliveryHandler",
+            "Processing OrderShipped event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        let start_time = std::time::Instant::now();
+
+        // 冪等性チェック: 既に処理済みのイベントかどうか確認
+        if self
+            .processed_events
+            .is_processed(event.metadata.event_id)
+            .await?
+        {
+            let mut context = HashMap::new();
+            context.insert("event_id".to_string(), event.metadata.event_id.to_string());
+            context.insert("already_processed".to_string(), "true".to_string());
+            
+            self.logger.debug(
+                "DeliveryHandler",
+                "Idempotency check: Event already processed, skipping",
+                Some(event.metadata.correlation_id),
+                Some(context),
+            );
+            return Ok(());
+        }
+
+        // 注文を取得
+        let mut order = self
+            .order_repository
+            .find_by_id(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!(
+                    "注文が見つかりません: {:?}",
+                    event.order_id
+                ))
+                .at_step("load_order")
+            })?;
+
+        // 注文がShipped状態でない場合は遅延イベントとして扱う（配達完了は発送済みの注文にしか適用できない）
+        if order.status() != OrderStatus::Shipped {
+            self.late_events
+                .resolve(
+                    "DeliveryHandler",
+                    LateEvent {
+                        event_type: "OrderShipped",
+                        metadata: &event.metadata,
+                        order_id: event.order_id,
+                        order_status: order.status(),
+                        expected_status: OrderStatus::Shipped,
+                        compatible: false,
+                        payload: late_event_payload(&event)?,
+                    },
+                )
+                .await?;
+
+            // イベントを処理済みとしてマーク
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+            return Ok(());
+        }
+
+        // 配送業者に荷物を引き渡す（配達の結果は配送業者からのコールバックで反映する）
+        if let Some(carrier) = &self.carrier {
+            let shipment = Shipment {
+                order_id: order.id(),
+                shipping_address: order
+                    .shipping_address()
+                    .expect("Shipped状態の注文には配送先住所が必須です")
+                    .clone(),
+                shipped_event_id: event.metadata.event_id,
+                correlation_id: event.metadata.correlation_id,
+            };
+            carrier
+                .hand_over(shipment, Arc::new(self.clone()))
+                .await
+                .map_err(|e| {
+                    HandlerError::ProcessingFailed(format!("配送業者への引き渡しエラー: {}", e))
+                        .at_step("hand_over")
+                })?;
+
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+            self.logger.info(
+                "DeliveryHandler",
+                "Shipment handed over to carrier",
+                Some(event.metadata.correlation_id),
+                None,
+            );
+            return Ok(());
+        }
+
+        // 注文を配達完了にマーク（失敗時は補償イベントを発行）
+        if let Some(failure_reason) = self
+            .apply_delivery_result(
+                &mut order,
+                event.metadata.event_id,
+                event.metadata.correlation_id,
+                CarrierDeliveryResult::Delivered,
+            )
+            .await?
+        {
+            // エラーログ出力
+            let mut context = HashMap::new();
+            context.insert("event_type".to_string(), "OrderShipped".to_string());
+            context.insert("error".to_string(), failure_reason.clone());
+            context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
+
+            self.logger.error(
+                "DeliveryHandler",
+                &format!("OrderShipped event processing failed: {}", failure_reason),
+                Some(event.metadata.correlation_id),
+                Some(context),
+            );
+
+            // イベントを処理済みとしてマーク（失敗した場合でも重複処理を防ぐ）
+            self.processed_events
+                .mark_processed(event.metadata.event_id)
+                .await;
+
+            return Err(HandlerError::DomainError(failure_reason).at_step("mark_as_delivered"));
+        }
+
+        // イベントを処理済みとしてマーク（成功時）
+        self.processed_events
+            .mark_processed(event.metadata.event_id)
+            .await;
+
+        // 処理成功ログ
+        let execution_time = start_time.elapsed();
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderShipped".to_string());
+        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
+        
+        self.logger.info(
+            "DeliveryHandler",
+            "OrderShipped event processed successfully",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        Ok(())
+    }
+}
+
+/// 配達失敗補償ハンドラー
+/// DeliveryFailedイベントを受信して発送状態を元に戻す
+pub struct DeliveryFailureCompensationHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    #[allow(dead_code)]
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+}
+
+impl DeliveryFailureCompensationHandler {
+    /// 新しい配達失敗補償ハンドラーを作成
+    pub fn new(order_repository: Arc<dyn OrderRepository>, event_bus: Arc<dyn EventBus>, logger: Arc<dyn Logger>) -> Self {
+        Self {
+            order_repository,
+            event_bus,
+            logger,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<DeliveryFailed> for DeliveryFailureCompensationHandler {
+    async fn handle(&self, event: DeliveryFailed) -> Result<(), HandlerError> {
+        // 補償ログ出力
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "DeliveryFailed".to_string());
+        context.insert("compensation_type".to_string(), "DeliveryFailure".to_string());
+        self.logger.info(
+            "DeliveryFailureCompensationHandler",
+            "Processing DeliveryFailed compensation event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        let start_time = std::time::Instant::now();
+
+        // 注文を取得
+        let order = self
+            .order_repository
+            .find_by_id(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!(
+                    "注文が見つかりません: {:?}",
+                    event.order_id
+                ))
+                .at_step("load_order")
+            })?;
+
+        // 注文状態を発送済みに戻す（補償アクション）
+        // 実際の実装では、より複雑な補償ロジックが必要になる場合がある
+        // ここでは簡単のため、ステータスを直接変更
+        if order.status() == crate::domain::model::OrderStatus::Delivered {
+            // 注文を発送済み状態に戻すためのロジック
+            // 実際の実装では、注文集約にcompensate_deliveryメソッドを追加することを推奨
+        }
+
+        // 補償処理完了ログ
+        let execution_time = start_time.elapsed();
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "DeliveryFailed".to_string());
+        context.insert("compensation_type".to_string(), "DeliveryFailure".to_string());
+        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
+        
+        self.logger.info(
+            "DeliveryFailureCompensationHandler",
+            "DeliveryFailed compensation completed successfully",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        Ok(())
+    }
+}
+
+/// 返品ハンドラー
+/// ReturnApprovedイベントを受信して、返品された書籍（物理書籍の明細）を在庫に戻す
+#[derive(Clone)]
+pub struct ReturnHandler {
+    inventory_repository: Arc<dyn InventoryRepository>,
+    event_bus: Arc<dyn EventBus>,
+    processed_events: ProcessedEventTracker,
+    logger: Arc<dyn Logger>,
+}
+
+impl ReturnHandler {
+    /// 新しい返品ハンドラーを作成
+    pub fn new(
+        inventory_repository: Arc<dyn InventoryRepository>,
+        event_bus: Arc<dyn EventBus>,
+        processed_event_repository: Arc<dyn ProcessedEventRepository>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            inventory_repository,
+            event_bus,
+            processed_events: ProcessedEventTracker::new(
+                processed_event_repository,
+                "ReturnHandler",
+                logger.clone(),
+            ),
+            logger,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<ReturnApproved> for ReturnHandler {
+    async fn handle(&self, event: ReturnApproved) -> Result<(), HandlerError> {
+        // 再配信されたイベントで在庫を二重に戻さない
+        if self
+            .processed_events
+            .is_processed(event.metadata.event_id)
+            .await?
+        {
+            return Ok(());
+        }
+
+        // 電子書籍は在庫を持たないため、物理書籍の明細だけを在庫に戻す
+        let mut restocked = Vec::new();
+        for order_line in event.order_lines.iter().filter(|line| !line.is_digital()) {
+            let Some(mut inventory) = self
+                .inventory_repository
+                .find_by_book_id(order_line.book_id())
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("load_inventory")
+                })?
+            else {
+                // 在庫が見つからない場合（取り扱いを終了した書籍など）はスキップ
+                let mut context = HashMap::new();
+                context.insert("book_id".to_string(), order_line.book_id().to_string());
+                context.insert("reason".to_string(), "inventory_not_found".to_string());
+                self.logger.warn(
+                    "ReturnHandler",
+                    "Inventory not found for returned book, skipping restock",
+                    Some(event.metadata.correlation_id),
+                    Some(context),
+                );
+                continue;
+            };
+
+            inventory.release(order_line.quantity()).map_err(|e| {
+                HandlerError::DomainError(format!("在庫の再入庫エラー: {}", e))
+                    .at_step("restock_inventory")
+            })?;
+            self.inventory_repository
+                .save(&inventory)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫保存エラー: {}", e))
+                        .at_step("save_inventory")
+                })?;
+            restocked.push(order_line.clone());
+        }
+
+        let items_restocked = ItemsRestocked::with_correlation_id(
+            event.order_id,
+            restocked,
+            event.metadata.correlation_id,
+        );
+        self.event_bus
+            .publish(DomainEvent::ItemsRestocked(items_restocked))
+            .await
+            .map_err(|e| {
+                HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                    .at_step("publish_event")
+            })?;
+
+        self.processed_events
+            .mark_processed(event.metadata.event_id)
+            .await;
+        Ok(())
+    }
+}
+
+/// 結果整合性検証ハンドラー
+/// サーガの完了を監視し、システム全体の整合性を検証する
+#[derive(Clone)]
+pub struct EventualConsistencyVerifier {
+    order_repository: Arc<dyn OrderRepository>,
+    inventory_repository: Arc<dyn InventoryRepository>,
+    logger: Arc<dyn Logger>,
+}
+
+impl EventualConsistencyVerifier {
+    /// 新しい結果整合性検証ハンドラーを作成
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        inventory_repository: Arc<dyn InventoryRepository>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            order_repository,
+            inventory_repository,
+            logger,
+        }
+    }
+
+    /// 注文とその関連する在庫の整合性を検証
+    async fn verify_order_inventory_consistency(
+        &self,
+        order_id: OrderId,
+        correlation_id: Uuid,
+    ) -> Result<(), HandlerError> {
+        // 注文を取得
+        let order = self
+            .order_repository
+            .find_by_id(order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!("注文が見つかりません: {:?}", order_id))
+                    .at_step("load_order")
+            })?;
+
+        // 注文と、明細の書籍の在庫の不変条件を検証する
+        let mut violations = order.check_invariants();
+        for order_line in order.order_lines() {
+            let inventory = self
+                .inventory_repository
+                .find_by_book_id(order_line.book_id())
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("load_inventory")
+                })?;
+
+            if let Some(inventory) = inventory {
+                violations.extend(inventory.check_invariants());
+            }
+        }
+
+        // 不整合は検出して記録するだけで、サーガの処理は止めない
+        if !violations.is_empty() {
+            let mut context = HashMap::new();
+            context.insert("order_id".to_string(), order_id.to_string());
+            context.insert("violation_count".to_string(), violations.len().to_string());
+            context.insert("violations".to_string(), describe_violations(&violations));
+            self.logger.warn(
+                "EventualConsistencyVerifier",
+                "Aggregate invariant violations detected",
+                Some(correlation_id),
+                Some(context),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<OrderConfirmed> for EventualConsistencyVerifier {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        // 注文確定時の整合性検証
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
+        context.insert("verification_type".to_string(), "OrderInventoryConsistency".to_string());
+        self.logger.debug(
+            "EventualConsistencyVerifier",
+            "Starting order-inventory consistency verification",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        self.verify_order_inventory_consistency(event.order_id, event.metadata.correlation_id)
+            .await?;
+
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderConfirmed".to_string());
+        context.insert("verification_result".to_string(), "Success".to_string());
+        self.logger.debug(
+            "EventualConsistencyVerifier",
+            "Order-inventory consistency verification completed",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<OrderDelivered> for EventualConsistencyVerifier {
+    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+        // 注文配達完了時の整合性検証
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderDelivered".to_string());
+        context.insert("verification_type".to_string(), "OrderInventoryConsistency".to_string());
+        self.logger.debug(
+            "EventualConsistencyVerifier",
+            "Starting order-inventory consistency verification",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        self.verify_order_inventory_consistency(event.order_id, event.metadata.correlation_id)
+            .await?;
+
+        // サーガ完了ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "OrderDelivered".to_string());
+        context.insert("saga_status".to_string(), "Completed".to_string());
+        self.logger.info(
+            "EventualConsistencyVerifier",
+            "Saga completed successfully - order delivered",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        Ok(())
+    }
+}
+
+// ========== 補償ハンドラー（サーガ失敗時のロールバック処理） ==========
+
+/// 在庫予約失敗補償ハンドラー
+/// InventoryReservationFailedイベントを受信して注文をキャンセルする
+pub struct InventoryReservationFailureCompensationHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+}
+
+impl InventoryReservationFailureCompensationHandler {
+    /// 新しい在庫予約失敗補償ハンドラーを作成
+    pub fn new(order_repository: Arc<dyn OrderRepository>, event_bus: Arc<dyn EventBus>, logger: Arc<dyn Logger>) -> Self {
+        Self {
+            order_repository,
+            event_bus,
+            logger,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<InventoryReservationFailed> for InventoryReservationFailureCompensationHandler {
+    async fn handle(&self, event: InventoryReservationFailed) -> Result<(), HandlerError> {
+        // 補償ログ出力
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "InventoryReservationFailed".to_string());
+        context.insert("compensation_type".to_string(), "InventoryReservationFailure".to_string());
+        self.logger.info(
+            "InventoryReservationFailureCompensationHandler",
+            "Processing InventoryReservationFailed compensation event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        let start_time = std::time::Instant::now();
+
+        // 注文を取得
+        let mut order = self
+            .order_repository
+            .find_by_id(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!(
+                    "注文が見つかりません: {:?}",
+                    event.order_id
+                ))
+                .at_step("load_order")
+            })?;
+
+        // 注文をキャンセル（補償アクション）
+        order.cancel().map_err(|e| {
+            HandlerError::DomainError(format!("注文キャンセルエラー: {}", e))
+                .at_step("cancel_order")
+        })?;
+
+        // 注文を保存（イベントの連番も一緒に記録）
+        let sequence_number = order.record_event();
+        self.order_repository.save(&mut order).await.map_err(|e| {
+            HandlerError::RepositoryError(format!("注文保存エラー: {}", e)).at_step("save_order")
+        })?;
+
+        let mut cancelled_event = crate::domain::event::OrderCancelled::with_correlation_id(
+            order.id(),
+            order.customer_id(),
+            order.order_lines().to_vec(),
+            event.metadata.correlation_id,
+        );
+        cancelled_event.metadata.sequence_number = Some(sequence_number);
+        let domain_event = crate::domain::event::DomainEvent::OrderCancelled(cancelled_event);
+
+        self.event_bus.publish(domain_event).await.map_err(|e| {
+            HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                .at_step("publish_event")
+        })?;
+
+        // 補償処理完了ログ
+        let execution_time = start_time.elapsed();
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "InventoryReservationFailed".to_string());
+        context.insert("compensation_type".to_string(), "InventoryReservationFailure".to_string());
+        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
+        
+        self.logger.info(
+            "InventoryReservationFailureCompensationHandler",
+            "InventoryReservationFailed compensation completed successfully",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        Ok(())
+    }
+}
+
+/// 発送失敗補償ハンドラー
+/// ShippingFailedイベントを受信して在庫を解放する
+pub struct ShippingFailureCompensationHandler {
+    inventory_repository: Arc<dyn InventoryRepository>,
+    order_repository: Arc<dyn OrderRepository>,
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+}
+
+impl ShippingFailureCompensationHandler {
+    /// 新しい発送失敗補償ハンドラーを作成
+    pub fn new(
+        inventory_repository: Arc<dyn InventoryRepository>,
+        order_repository: Arc<dyn OrderRepository>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            inventory_repository,
+            order_repository,
+            event_bus,
+            logger,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<ShippingFailed> for ShippingFailureCompensationHandler {
+    async fn handle(&self, event: ShippingFailed) -> Result<(), HandlerError> {
+        // 補償ログ出力
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "ShippingFailed".to_string());
+        context.insert("compensation_type".to_string(), "ShippingFailure".to_string());
+        self.logger.info(
+            "ShippingFailureCompensationHandler",
+            "Processing ShippingFailed compensation event",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        let start_time = std::time::Instant::now();
+
+        // 注文を取得
+        let order = self
+            .order_repository
+            .find_by_id(event.order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!(
+                    "注文が見つかりません: {:?}",
+                    event.order_id
+                ))
+                .at_step("load_order")
+            })?;
+
+        // 各注文明細について在庫を解放（補償アクション、電子書籍は在庫を予約しないため対象外）
+        for order_line in order.order_lines().iter().filter(|line| !line.is_digital()) {
+            // 在庫を取得
+            let mut inventory = match self
+                .inventory_repository
+                .find_by_book_id(order_line.book_id())
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("load_inventory")
+                })? {
+                Some(inventory) => inventory,
+                None => {
+                    // 在庫が見つからない場合はスキップ（ログに記録）
+                    let mut context = HashMap::new();
+                    context.insert("book_id".to_string(), format!("{:?}", order_line.book_id()));
+                    context.insert("reason".to_string(), "inventory_not_found".to_string());
+                    
+                    self.logger.warn(
+                        "ShippingFailureCompensationHandler",
+                        "Inventory not found for book, skipping release",
+                        Some(event.metadata.correlation_id),
+                        Some(context),
+                    );
+                    continue;
+                }
+            };
+
+            // 在庫を解放
+            inventory.release(order_line.quantity()).map_err(|e| {
+                HandlerError::DomainError(format!("在庫解放エラー: {}", e))
+                    .at_step("release_inventory")
+            })?;
+
+            // 在庫を保存
+            self.inventory_repository
+                .save(&inventory)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫保存エラー: {}", e))
+                        .at_step("save_inventory")
+                })?;
+        }
+
+        // InventoryReleasedイベントを発行
+        let inventory_released_event = InventoryReleased::with_correlation_id(
+            event.order_id,
+            order.order_lines().to_vec(),
+            event.metadata.correlation_id,
+        );
+
+        self.event_bus
+            .publish(DomainEvent::InventoryReleased(inventory_released_event))
+            .await
+            .map_err(|e| {
+                HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e))
+                    .at_step("publish_event")
+            })?;
+
+        // 補償処理完了ログ
+        let execution_time = start_time.elapsed();
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "ShippingFailed".to_string());
+        context.insert("compensation_type".to_string(), "ShippingFailure".to_string());
+        context.insert("execution_time_ms".to_string(), execution_time.as_millis().to_string());
+        
+        self.logger.info(
+            "ShippingFailureCompensationHandler",
+            "ShippingFailed compensation completed successfully",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        Ok(())
+    }
+}
+
+/// サーガ補償コーディネーター
+/// サーガ補償コーディネーター
+/// サーガの失敗を検出し、補償プロセスを開始する
+pub struct SagaCompensationCoordinator {
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+}
+
+impl SagaCompensationCoordinator {
+    /// 新しいサーガ補償コーディネーターを作成
+    pub fn new(event_bus: Arc<dyn EventBus>, logger: Arc<dyn Logger>) -> Self {
+        Self { event_bus, logger }
+    }
+
+    /// サーガ失敗を検出し、補償を開始
+    pub async fn start_compensation(
+        &self,
+        saga_id: Uuid,
+        failed_step: String,
+        failure_reason: String,
+    ) -> Result<(), HandlerError> {
+        // 補償が必要なステップを決定（逆順）
+        let compensation_steps = self.determine_compensation_steps(&failed_step);
+
+        // サーガ補償開始イベントを発行
+        let compensation_started_event =
+            SagaCompensationStarted::new(saga_id, failed_step, failure_reason, compensation_steps);
+
+        self.event_bus
+            .publish(DomainEvent::SagaCompensationStarted(
+                compensation_started_event,
+            ))
+            .await
+            .map_err(|e| {
+                HandlerError::ProcessingFailed(format!("補償開始イベント発行エラー: {}", e))
+                    .at_step("publish_compensation_event")
+            })?;
+
+        Ok(())
+    }
+
+    /// 失敗したステップに基づいて補償が必要なステップを決定
+    fn determine_compensation_steps(&self, failed_step: &str) -> Vec<String> {
+        match failed_step {
+            "inventory_reservation" => vec![], // 最初のステップなので補償不要
+            "shipping" => vec!["inventory_reservation".to_string()], // 在庫予約を補償
+            "delivery" => vec!["shipping".to_string(), "inventory_reservation".to_string()], // 発送と在庫予約を補償
+            _ => vec![], // 不明なステップ
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler<SagaCompensationStarted> for SagaCompensationCoordinator {
+    async fn handle(&self, event: SagaCompensationStarted) -> Result<(), HandlerError> {
+        // サーガ補償開始ログ
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "SagaCompensationStarted".to_string());
+        context.insert("saga_id".to_string(), event.saga_id.to_string());
+        context.insert("failed_step".to_string(), event.failed_step.clone());
+        context.insert("compensation_steps_count".to_string(), event.compensation_steps.len().to_string());
+        
+        self.logger.info(
+            "SagaCompensationCoordinator",
+            "Saga compensation process started",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        // 実際の補償処理は個別の補償ハンドラーが実行するため、
+        // ここでは補償プロセスの追跡とログ出力のみ行う
+
+        Ok(())
+    }
+}
+
+/// 補償完了ハンドラー
+/// 補償プロセスの完了を監視し、ログを記録する
+pub struct CompensationCompletionHandler {
+    logger: Arc<dyn Logger>,
+}
+
+impl CompensationCompletionHandler {
+    /// 新しい補償完了ハンドラーを作成
+    pub fn new(logger: Arc<dyn Logger>) -> Self {
+        Self { logger }
+    }
+}
+
+#[async_trait]
+impl EventHandler<SagaCompensationCompleted> for CompensationCompletionHandler {
+    async fn handle(&self, event: SagaCompensationCompleted) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
+
+        // 補償完了ログ出力
+        let result_str = match &event.compensation_result {
+            CompensationResult::Success => "Success",
+            CompensationResult::PartialSuccess { .. } => "PartialSuccess",
+            CompensationResult::Failed { .. } => "Failed",
+        };
+
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), "SagaCompensationCompleted".to_string());
+        context.insert("saga_id".to_string(), event.saga_id.to_string());
+        context.insert("compensation_result".to_string(), result_str.to_string());
+        context.insert("completed_steps_count".to_string(), event.compensated_steps.len().to_string());
+        context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
+        
+        self.logger.info(
+            "CompensationCompletionHandler",
+            "Saga compensation process completed",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        Ok(())
+    }
+}
+
+/// ビジネスメトリクスハンドラー
+/// 注文・在庫・補償イベントを受信して業務指標を記録する
+#[derive(Clone)]
+pub struct BusinessMetricsHandler {
+    metrics: BusinessMetrics,
+    logger: Arc<dyn Logger>,
+}
+
+impl BusinessMetricsHandler {
+    /// 新しいビジネスメトリクスハンドラーを作成
+    pub fn new(metrics: BusinessMetrics, logger: Arc<dyn Logger>) -> Self {
+        Self { metrics, logger }
+    }
+
+    /// メトリクス記録のデバッグログを出力（記録にかかった時間を含める）
+    fn log_recorded(&self, event_type: &str, correlation_id: Uuid, start_time: std::time::Instant) {
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), event_type.to_string());
+        context.insert("execution_time_ms".to_string(), start_time.elapsed().as_millis().to_string());
+        self.logger.debug(
+            "BusinessMetricsHandler",
+            "Business metrics recorded",
+            Some(correlation_id),
+            Some(context),
+        );
+    }
+}
+
+#[async_trait]
+impl EventHandler<OrderConfirmed> for BusinessMetricsHandler {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
+        self.metrics
+            .record_order_confirmed(event.order_id, event.total_amount, event.metadata.occurred_at)
+            .await;
+        self.log_recorded("OrderConfirmed", event.metadata.correlation_id, start_time);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<OrderCancelled> for BusinessMetricsHandler {
+    async fn handle(&self, event: OrderCancelled) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
+        self.metrics
+            .record_order_cancelled(event.order_id, event.metadata.occurred_at)
+            .await;
+        self.log_recorded("OrderCancelled", event.metadata.correlation_id, start_time);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<OrderDelivered> for BusinessMetricsHandler {
+    async fn handle(&self, event: OrderDelivered) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
+        self.metrics
+            .record_order_delivered(event.order_id, event.metadata.occurred_at)
+            .await;
+        self.log_recorded("OrderDelivered", event.metadata.correlation_id, start_time);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<InventoryReserved> for BusinessMetricsHandler {
+    async fn handle(&self, event: InventoryReserved) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
+        self.metrics.record_reservation_succeeded().await;
+        self.log_recorded("InventoryReserved", event.metadata.correlation_id, start_time);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<InventoryReservationFailed> for BusinessMetricsHandler {
+    async fn handle(&self, event: InventoryReservationFailed) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
+        self.metrics.record_reservation_failed().await;
+        self.metrics.record_compensation().await;
+        self.log_recorded("InventoryReservationFailed", event.metadata.correlation_id, start_time);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<ShippingFailed> for BusinessMetricsHandler {
+    async fn handle(&self, event: ShippingFailed) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
+        self.metrics.record_compensation().await;
+        self.log_recorded("ShippingFailed", event.metadata.correlation_id, start_time);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<DeliveryFailed> for BusinessMetricsHandler {
+    async fn handle(&self, event: DeliveryFailed) -> Result<(), HandlerError> {
+        let start_time = std::time::Instant::now();
+        self.metrics.record_compensation().await;
+        self.log_recorded("DeliveryFailed", event.metadata.correlation_id, start_time);
+        Ok(())
+    }
+}
+
+/// サーガ補償記録ハンドラー
+/// 在庫予約・発送・配達の失敗イベントを受信し、失敗ステップごとの補償として永続化する
+/// BusinessMetricsの補償件数はプロセス内のみで保持されるため、サーガの集計ではこの記録を使用する
+#[derive(Clone)]
+pub struct SagaCompensationRecorder {
+    compensation_repository: Arc<dyn SagaCompensationRepository>,
+}
+
+impl SagaCompensationRecorder {
+    /// 新しいサーガ補償記録ハンドラーを作成
+    pub fn new(compensation_repository: Arc<dyn SagaCompensationRepository>) -> Self {
+        Self {
+            compensation_repository,
+        }
+    }
+
+    async fn record(
+        &self,
+        metadata: &EventMetadata,
+        order_id: OrderId,
+        failed_step: &str,
+        failure_reason: &str,
+    ) -> Result<(), HandlerError> {
+        let compensation = SagaCompensation {
+            event_id: metadata.event_id,
+            order_id,
+            failed_step: failed_step.to_string(),
+            failure_reason: failure_reason.to_string(),
+            occurred_at: metadata.occurred_at,
+        };
+        self.compensation_repository
+            .record(&compensation)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("補償の記録エラー: {}", e))
+                    .at_step("record_compensation")
+            })
+    }
+}
+
+#[async_trait]
+impl EventHandler<InventoryReservationFailed> for SagaCompensationRecorder {
+    async fn handle(&self, event: InventoryReservationFailed) -> Result<(), HandlerError> {
+        self.record(
+            &event.metadata,
+            event.order_id,
+            FAILED_STEP_INVENTORY_RESERVATION,
+            &event.failure_reason,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl EventHandler<ShippingFailed> for SagaCompensationRecorder {
+    async fn handle(&self, event: ShippingFailed) -> Result<(), HandlerError> {
+        self.record(
+            &event.metadata,
+            event.order_id,
+            FAILED_STEP_SHIPPING,
+            &event.failure_reason,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl EventHandler<DeliveryFailed> for SagaCompensationRecorder {
+    async fn handle(&self, event: DeliveryFailed) -> Result<(), HandlerError> {
+        self.record(
+            &event.metadata,
+            event.order_id,
+            FAILED_STEP_DELIVERY,
+            &event.failure_reason,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{
+        BookId, CustomerId, Money, OrderId, OrderLine, OrderNumber, OrderStatus, TrackingToken,
+    };
+    use crate::domain::port::{EventBus, EventBusError, RepositoryError};
+    use crate::domain::reconciliation::LedgerDrift;
+    use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+    use std::collections::{BTreeMap, HashMap, HashSet};
+    use tokio::sync::Mutex;
+
+    // テスト用のモックイベントバス
+    #[derive(Clone)]
+    struct MockEventBus {
+        published_events: Arc<Mutex<Vec<DomainEvent>>>,
+    }
+
+    impl MockEventBus {
+        fn new() -> Self {
+            Self {
+                published_events: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        async fn get_published_events(&self) -> Vec<DomainEvent> {
+            let events = self.published_events.lock().await;
+            events.clone()
+        }
+
+    }
+
+    #[async_trait]
+    impl EventBus for MockEventBus {
+        async fn publish(&self, event: DomainEvent) -> Result<(), EventBusError> {
+            let mut events = self.published_events.lock().await;
+            events.push(event);
+            Ok(())
+        }
+    }
+
+    // テスト用のモックリポジトリ
+    struct MockInventoryRepository {
+        inventories: Arc<Mutex<HashMap<BookId, Inventory>>>,
+    }
+
+    impl MockInventoryRepository {
+        fn new() -> Self {
+            Self {
+                inventories: Arc::new(Mutex::new(HashMap::new())),
+            }
+        }
+
+        async fn add_inventory(&self, inventory: Inventory) {
+            let mut inventories = self.inventories.lock().await;
+            inventories.insert(inventory.book_id(), inventory);
+        }
+    }
+
+    #[async_trait]
+    impl InventoryRepository for MockInventoryRepository {
+        async fn save(&self, inventory: &Inventory) -> Result<(), RepositoryError> {
+            let mut inventories = self.inventories.lock().await;
+            inventories.insert(inventory.book_id(), inventory.clone());
+            Ok(())
+        }
+
+        async fn find_by_book_id(
+            &self,
+            book_id: BookId,
+        ) -> Result<Option<Inventory>, RepositoryError> {
+            let inventories = self.inventories.lock().await;
+            Ok(inventories.get(&book_id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<Inventory>, RepositoryError> {
+            let inventories = self.inventories.lock().await;
+            Ok(inventories.values().cloned().collect())
+        }
+
+        async fn find_by_max_quantity(
+            &self,
+            max_quantity: u32,
+        ) -> Result<Vec<Inventory>, RepositoryError> {
+            let inventories = self.inventories.lock().await;
+            Ok(inventories
+                .values()
+                .filter(|inv| inv.quantity_on_hand() <= max_quantity)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_ledger_drifts(&self) -> Result<Vec<LedgerDrift>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
+        async fn freeze_ledger_drift(
+            &self,
+            _book_id: BookId,
+        ) -> Result<bool, RepositoryError> {
+            Ok(false)
+        }
+
+        async fn find_stock_valuations(
+            &self,
+        ) -> Result<Vec<crate::domain::inventory_valuation::StockValuation>, RepositoryError>
+        {
+            Ok(Vec::new())
+        }
+
+        async fn take_snapshot(
+            &self,
+            taken_at: DateTime<Utc>,
+        ) -> Result<crate::domain::inventory_snapshot::InventorySnapshot, RepositoryError>
+        {
+            Ok(crate::domain::inventory_snapshot::InventorySnapshot {
+                version: 0,
+                taken_at,
+                entries: Vec::new(),
+            })
+        }
+
+        async fn find_movements_since(
+            &self,
+            _since_version: u64,
+            _limit: usize,
+        ) -> Result<Vec<crate::domain::inventory_snapshot::StockMovement>, RepositoryError>
+        {
+            Ok(Vec::new())
+        }
+    }
+
+    struct MockOrderRepository {
+        orders: Arc<Mutex<HashMap<OrderId, crate::domain::model::Order>>>,
+        /// 注文ごとの作成日時（初めて保存した日時）
+        created_at: Arc<Mutex<HashMap<OrderId, DateTime<Utc>>>>,
+    }
+
+    impl MockOrderRepository {
+        fn new() -> Self {
+            Self {
+                orders: Arc::new(Mutex::new(HashMap::new())),
+                created_at: Arc::new(Mutex::new(HashMap::new())),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OrderRepository for MockOrderRepository {
+        async fn save(
+            &self,
+            order: &mut crate::domain::model::Order,
+        ) -> Result<(), RepositoryError> {
+            let mut orders = self.orders.lock().await;
+            self.created_at
+                .lock()
+                .await
+                .entry(order.id())
+                .or_insert_with(Utc::now);
+            orders.insert(order.id(), order.clone());
+            Ok(())
+        }
+
+        async fn save_within_purchase_limits(
+            &self,
+            order: &mut crate::domain::model::Order,
+            policy: &crate::domain::purchase_policy::PurchasePolicy,
+            check: crate::domain::purchase_policy::PurchaseCheck,
+        ) -> Result<(), RepositoryError> {
+            use crate::domain::purchase_policy::PurchaseCheck;
+
+            let mut orders = self.orders.lock().await;
+            let mut created_at = self.created_at.lock().await;
+            let customer_orders = orders
+                .values()
+                .filter(|stored| stored.customer_id() == order.customer_id());
+            let counted = match &check {
+                PurchaseCheck::OpenOrder => customer_orders
+                    .filter(|stored| {
+                        matches!(
+                            stored.status(),
+                            OrderStatus::Pending
+                                | OrderStatus::Confirmed
+                                | OrderStatus::OnHold
+                                | OrderStatus::AwaitingRelease
+                                | OrderStatus::Waitlisted
+                        )
+                    })
+                    .count() as u32,
+                PurchaseCheck::BookQuantity { book_id, since, .. } => customer_orders
+                    .filter(|stored| stored.status() != OrderStatus::Cancelled)
+                    .filter(|stored| {
+                        created_at
+                            .get(&stored.id())
+                            .is_some_and(|at| at >= since)
+                    })
+                    .flat_map(|stored| stored.order_lines())
+                    .filter(|line| line.book_id() == *book_id)
+                    .map(|line| line.quantity())
+                    .sum(),
+            };
+            policy
+                .ensure(&check, counted)
+                .map_err(RepositoryError::PurchaseLimitExceeded)?;
+            created_at.entry(order.id()).or_insert_with(Utc::now);
+            orders.insert(order.id(), order.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            order_id: OrderId,
+        ) -> Result<Option<crate::domain::model::Order>, RepositoryError> {
+            let orders = self.orders.lock().await;
+            Ok(orders.get(&order_id).cloned())
+        }
+
+        async fn find_all(&self) -> Result<Vec<crate::domain::model::Order>, RepositoryError> {
+            let orders = self.orders.lock().await;
+            Ok(orders.values().cloned().collect())
+        }
+
+        async fn find_by_status(
+            &self,
+            status: OrderStatus,
+        ) -> Result<Vec<crate::domain::model::Order>, RepositoryError> {
+            let orders = self.orders.lock().await;
+            Ok(orders
+                .values()
+                .filter(|order| order.status() == status)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_by_customer(
+            &self,
+            customer_id: CustomerId,
+        ) -> Result<Vec<crate::domain::model::Order>, RepositoryError> {
+            let orders = self.orders.lock().await;
+            Ok(orders
+                .values()
+                .filter(|order| order.customer_id() == customer_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_by_customer_page(
+            &self,
+            customer_id: CustomerId,
+            after: Option<OrderId>,
+            limit: u32,
+        ) -> Result<Vec<crate::domain::model::Order>, RepositoryError> {
+            let after = after.map(|id| id.to_string()).unwrap_or_default();
+            let mut orders: Vec<_> = self
+                .find_by_customer(customer_id)
+                .await?
+                .into_iter()
+                .filter(|order| order.id().to_string() > after)
+                .collect();
+            orders.sort_by_key(|order| order.id().to_string());
+            orders.truncate(limit as usize);
+            Ok(orders)
+        }
+
+        async fn find_by_status_created_before(
+            &self,
+            status: OrderStatus,
+            _created_before: DateTime<Utc>,
+        ) -> Result<Vec<crate::domain::model::Order>, RepositoryError> {
+            // モックでは作成日時を保持しないため期間で絞り込まない
+            self.find_by_status(status).await
+        }
+
+        async fn count_open_orders_by_customer(
+            &self,
+            customer_id: CustomerId,
+        ) -> Result<u32, RepositoryError> {
+            let orders = self.orders.lock().await;
+            Ok(orders
+                .values()
+                .filter(|order| order.customer_id() == customer_id)
+                .filter(|order| {
+                    matches!(
+                        order.status(),
+                        OrderStatus::Pending
+                            | OrderStatus::Confirmed
+                            | OrderStatus::OnHold
+                            | OrderStatus::AwaitingRelease
+                            | OrderStatus::Waitlisted
+                    )
+                })
+                .count() as u32)
+        }
+
+        async fn count_orders_by_customer_and_status(
+            &self,
+            customer_id: CustomerId,
+            status: OrderStatus,
+        ) -> Result<u32, RepositoryError> {
+            let orders = self.orders.lock().await;
+            Ok(orders
+                .values()
+                .filter(|order| order.customer_id() == customer_id && order.status() == status)
+                .count() as u32)
+        }
+
+        async fn sum_book_quantity_by_customer_since(
+            &self,
+            customer_id: CustomerId,
+            book_id: BookId,
+            since: DateTime<Utc>,
+        ) -> Result<u32, RepositoryError> {
+            let orders = self.orders.lock().await;
+            let created_at = self.created_at.lock().await;
+            Ok(orders
+                .values()
+                .filter(|order| order.customer_id() == customer_id)
+                .filter(|order| order.status() != OrderStatus::Cancelled)
+                .filter(|order| {
+                    created_at
+                        .get(&order.id())
+                        .is_some_and(|at| *at >= since)
+                })
+                .flat_map(|order| order.order_lines())
+                .filter(|line| line.book_id() == book_id)
+                .map(|line| line.quantity())
+                .sum())
+        }
+
+        async fn find_by_tracking_token(
+            &self,
+            token: &TrackingToken,
+        ) -> Result<Option<crate::domain::model::Order>, RepositoryError> {
+            let orders = self.orders.lock().await;
+            Ok(orders
+                .values()
+                .find(|order| order.tracking_token() == Some(token))
+                .cloned())
+        }
+
+        async fn find_by_order_number(
+            &self,
+            order_number: &OrderNumber,
+        ) -> Result<Option<crate::domain::model::Order>, RepositoryError> {
+            let orders = self.orders.lock().await;
+            Ok(orders
+                .values()
+                .find(|order| order.order_number() == Some(order_number))
+                .cloned())
+        }
+
+        async fn find_similar_orders(
+            &self,
+            order_id: OrderId,
+            _within: TimeDelta,
+        ) -> Result<Vec<crate::domain::model::Order>, RepositoryError> {
+            // モックでは作成日時を保持しないため期間で絞り込まない
+            let orders = self.orders.lock().await;
+            let Some(target) = orders.get(&order_id) else {
+                return Ok(Vec::new());
+            };
+            Ok(orders
+                .values()
+                .filter(|order| order.id() != order_id)
+                .filter(|order| order.customer_id() == target.customer_id())
+                .filter(|order| order.status() != OrderStatus::Cancelled)
+                .filter(|order| order.has_same_lines(target))
+                .cloned()
+                .collect())
+        }
+
+        async fn sum_daily_book_demand_since(
+            &self,
+            book_id: BookId,
+            since: DateTime<Utc>,
+        ) -> Result<BTreeMap<NaiveDate, u32>, RepositoryError> {
+            let orders = self.orders.lock().await;
+            let mut demand = BTreeMap::new();
+            for order in orders.values() {
+                let Some(confirmed_at) = order.confirmed_at() else {
+                    continue;
+                };
+                if order.status() == OrderStatus::Cancelled || confirmed_at < since {
+                    continue;
+                }
+                for line in order.order_lines() {
+                    if line.book_id() == book_id {
+                        *demand.entry(confirmed_at.date_naive()).or_insert(0) += line.quantity();
+                    }
+                }
+            }
+            Ok(demand)
+        }
+
+        fn next_identity(&self) -> OrderId {
+            OrderId::new()
+        }
+    }
+
+    // テスト用のモックロガー
+    #[derive(Clone)]
+    struct MockLogger;
+
+    impl Logger for MockLogger {
+        fn debug(&self, _component: &str, _message: &str, _correlation_id: Option<Uuid>, _context: Option<HashMap<String, String>>) {
+            // テスト用なので何もしない
+        }
+
+        fn info(&self, _component: &str, _message: &str, _correlation_id: Option<Uuid>, _context: Option<HashMap<String, String>>) {
+            // テスト用なので何もしない
+        }
+
+        fn warn(&self, _component: &str, _message: &str, _correlation_id: Option<Uuid>, _context: Option<HashMap<String, String>>) {
+            // テスト用なので何もしない
+        }
+
+        fn error(&self, _component: &str, _message: &str, _correlation_id: Option<Uuid>, _context: Option<HashMap<String, String>>) {
+            // テスト用なので何もしない
+        }
+    }
+
+    // テスト用のモック処理済みイベントリポジトリ
+    struct MockProcessedEventRepository {
+        processed: Mutex<HashSet<(Uuid, String)>>,
+    }
+
+    impl MockProcessedEventRepository {
+        fn new() -> Self {
+            Self {
+                processed: Mutex::new(HashSet::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ProcessedEventRepository for MockProcessedEventRepository {
+        async fn is_processed(
+            &self,
+            event_id: Uuid,
+            handler_name: &str,
+        ) -> Result<bool, RepositoryError> {
+            let processed = self.processed.lock().await;
+            Ok(processed.contains(&(event_id, handler_name.to_string())))
+        }
+
+        async fn mark_processed(
+            &self,
+            event_id: Uuid,
+            handler_name: &str,
+            _processed_at: DateTime<Utc>,
+        ) -> Result<(), RepositoryError> {
+            let mut processed = self.processed.lock().await;
+            processed.insert((event_id, handler_name.to_string()));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inventory_reservation_handler_success() {
+        let inventory_repo = Arc::new(MockInventoryRepository::new());
+        let order_repo = Arc::new(MockOrderRepository::new());
+        let event_bus = Arc::new(MockEventBus::new());
+        let logger = Arc::new(MockLogger);
+        let processed_event_repo = Arc::new(MockProcessedEventRepository::new());
+        let handler = InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            processed_event_repo.clone(),
+            logger.clone(),
+        );
+
+        // テスト用の在庫を追加
+        let book_id = BookId::new();
+        let inventory = Inventory::new(book_id, 10);
+        inventory_repo.add_inventory(inventory).await;
+
+        // テスト用のOrderConfirmedイベントを作成
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let order_line = OrderLine::new(book_id, 3, Money::jpy(1000)).unwrap();
+        let event = OrderConfirmed::new(order_id, customer_id, vec![order_line], Money::jpy(3000));
+
+        // テスト用の注文を作成してリポジトリに保存
+        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+        order.add_book(book_id, 3, Money::jpy(1000)).unwrap();
+        order.set_shipping_address(
+            crate::domain::model::ShippingAddress::new(
+                "1234567".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "道玄坂1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        ).unwrap();
+        order.confirm().unwrap();
+        order_repo.save(&mut order).await.unwrap();
+
+        // ハンドラーを実行
+        let result = handler.handle(event.clone()).await;
+        assert!(result.is_ok());
+
+        // 再起動後のハンドラーに同じイベントが再配信されても、処理済みとして読み飛ばす
+        let restarted_handler = InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            processed_event_repo,
+            logger,
+        );
+        assert!(restarted_handler.handle(event).await.is_ok());
+
+        // 在庫が正しく減っていることを確認
+        let updated_inventory = inventory_repo
+            .find_by_book_id(book_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated_inventory.quantity_on_hand(), 7);
+
+        // InventoryReservedイベントが発行されていることを確認
+        let published_events = event_bus.get_published_events().await;
+        assert_eq!(published_events.len(), 1);
+        match &published_events[0] {
+            DomainEvent::InventoryReserved(event) => {
+                assert_eq!(event.order_id, order_id);
+            }
+            _ => panic!("Expected InventoryReserved event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inventory_reservation_handler_insufficient_stock() {
+        let inventory_repo = Arc::new(MockInventoryRepository::new());
+        let order_repo = Arc::new(MockOrderRepository::new());
+        let event_bus = Arc::new(MockEventBus::new());
+        let logger = Arc::new(MockLogger);
+        let handler = InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::new()),
+            logger,
+        );
+
+        // テスト用の在庫を追加（少ない在庫）
+        let book_id = BookId::new();
+        let inventory = Inventory::new(book_id, 2);
+        inventory_repo.add_inventory(inventory).await;
+
+        // テスト用のOrderConfirmedイベントを作成（在庫より多い数量）
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let order_line = OrderLine::new(book_id, 5, Money::jpy(1000)).unwrap();
+        let event = OrderConfirmed::new(order_id, customer_id, vec![order_line], Money::jpy(5000));
+
+        // テスト用の注文を作成してリポジトリに保存
+        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+        order.add_book(book_id, 5, Money::jpy(1000)).unwrap();
+        order.set_shipping_address(
+            crate::domain::model::ShippingAddress::new(
+                "1234567".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "道玄坂1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        ).unwrap();
+        order.confirm().unwrap();
+        order_repo.save(&mut order).await.unwrap();
+
+        // ハンドラーを実行（失敗するはず）
+        let result = handler.handle(event).await;
+        assert!(result.is_err());
+
+        // 在庫が変わっていないことを確認
+        let inventory = inventory_repo
+            .find_by_book_id(book_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(inventory.quantity_on_hand(), 2);
+
+        // InventoryReservationFailedイベントが発行されていることを確認
+        let published_events = event_bus.get_published_events().await;
+        assert_eq!(published_events.len(), 1);
+        match &published_events[0] {
+            DomainEvent::InventoryReservationFailed(event) => {
+                assert_eq!(event.order_id, order_id);
+            }
+            _ => panic!("Expected InventoryReservationFailed event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notification_handler_order_confirmed() {
+        let logger = Arc::new(MockLogger);
+        let handler = NotificationHandler::new(logger);
+
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let event = OrderConfirmed::new(order_id, customer_id, vec![], Money::jpy(1000));
+
+        let result = handler.handle(event).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_notification_handler_order_shipped() {
+        let logger = Arc::new(MockLogger);
+        let handler = NotificationHandler::new(logger);
+
+        let order_id = OrderId::new();
+        let shipping_address = crate::domain::model::ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+
+        let event = OrderShipped::new(order_id, shipping_address);
+
+        let result = handler.handle(event).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_notification_handler_order_delivered() {
+        let logger = Arc::new(MockLogger);
+        let handler = NotificationHandler::new(logger);
+
+        let order_id = OrderId::new();
+        let event = OrderDelivered::new(order_id);
+
+        let result = handler.handle(event).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_notification_handler_order_cancelled() {
+        let logger = Arc::new(MockLogger);
+        let handler = NotificationHandler::new(logger);
+
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let event = OrderCancelled::new(order_id, customer_id, vec![]);
+
+        let result = handler.handle(event).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_compensation_mechanism_inventory_reservation_failure() {
+        let order_repo = Arc::new(MockOrderRepository::new());
+        let event_bus = Arc::new(MockEventBus::new());
+        let logger = Arc::new(MockLogger);
+        let handler = InventoryReservationFailureCompensationHandler::new(
+            order_repo.clone(),
+            event_bus.clone(),
+            logger,
+        );
+
+        // テスト用の注文を作成
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+
+        // 書籍を追加
+        let book_id = BookId::new();
+        let price = Money::jpy(1000);
+        order.add_book(book_id, 2, price).unwrap();
+
+        // 配送先住所を設定
+        let address = crate::domain::model::ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address).unwrap();
+
+        // 注文を確定状態にする
+        order.confirm().unwrap();
+
+        // モックリポジトリに注文を保存
+        {
+            let mut orders = order_repo.orders.lock().await;
+            orders.insert(order_id, order);
+        }
+
+        // 在庫予約失敗イベントを作成
+        let failure_event = InventoryReservationFailed::new(
+            order_id,
+            vec![],
+            "在庫不足".to_string(),
+            Uuid::new_v4(),
+        );
+
+        // 補償ハンドラーを実行
+        let result = handler.handle(failure_event).await;
+        assert!(result.is_ok());
+
+        // 注文がキャンセル状態になっていることを確認
+        let orders = order_repo.orders.lock().await;
+        let updated_order = orders.get(&order_id).unwrap();
+        assert_eq!(
+            updated_order.status(),
+            crate::domain::model::OrderStatus::Cancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compensation_mechanism_shipping_failure() {
+        let inventory_repo = Arc::new(MockInventoryRepository::new());
+        let order_repo = Arc::new(MockOrderRepository::new());
+        let event_bus = Arc::new(MockEventBus::new());
+        let logger = Arc::new(MockLogger);
+        let handler = ShippingFailureCompensationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            logger,
+        );
+
+        // テスト用の在庫を追加
+        let book_id = BookId::new();
+        let inventory = Inventory::new(book_id, 5); // 在庫を予約済み状態にするため少なめに設定
+        inventory_repo.add_inventory(inventory).await;
+
+        // テスト用の注文を作成
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+
+        // 書籍を追加
+        let price = Money::jpy(1000);
+        order.add_book(book_id, 2, price).unwrap();
+
+        // 配送先住所を設定
+        let address = crate::domain::model::ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address).unwrap();
+
+        // 注文を確定状態にする
+        order.confirm().unwrap();
+
+        // モックリポジトリに注文を保存
+        {
+            let mut orders = order_repo.orders.lock().await;
+            orders.insert(order_id, order);
+        }
+
+        // 発送失敗イベントを作成
+        let failure_event =
+            ShippingFailed::new(order_id, "配送業者エラー".to_string(), Uuid::new_v4());
+
+        // 補償ハンドラーを実行
+        let result = handler.handle(failure_event).await;
+        assert!(result.is_ok());
+
+        // 在庫が解放されていることを確認
+        let updated_inventory = inventory_repo
+            .find_by_book_id(book_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated_inventory.quantity_on_hand(), 7); // 5 + 2 = 7
+    }
+
+    #[tokio::test]
+    async fn test_return_handler_restocks_physical_lines_once() {
+        let inventory_repo = Arc::new(MockInventoryRepository::new());
+        let event_bus = Arc::new(MockEventBus::new());
+        let handler = ReturnHandler::new(
+            inventory_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::new()),
+            Arc::new(MockLogger),
+        );
+
+        let book_id = BookId::new();
+        inventory_repo
+            .add_inventory(Inventory::new(book_id, 4))
+            .await;
+        let physical = OrderLine::new(book_id, 2, Money::jpy(1000)).unwrap();
+        let digital = OrderLine::new(BookId::new(), 1, Money::jpy(800))
+            .unwrap()
+            .with_fulfillment_type(crate::domain::model::FulfillmentType::Digital);
+        let order_id = OrderId::new();
+        let event = ReturnApproved::new(order_id, CustomerId::new(), vec![physical, digital]);
+
+        handler.handle(event.clone()).await.unwrap();
+        // 再配信されたイベントで在庫を二重に戻さない
+        handler.handle(event).await.unwrap();
+
+        let inventory = inventory_repo
+            .find_by_book_id(book_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(inventory.quantity_on_hand(), 6);
+
+        let published_events = event_bus.get_published_events().await;
+        assert_eq!(published_events.len(), 1);
+        match &published_events[0] {
+            DomainEvent::ItemsRestocked(event) => {
+                assert_eq!(event.order_id, order_id);
+                assert_eq!(event.order_lines.len(), 1);
+                assert_eq!(event.order_lines[0].book_id(), book_id);
+            }
+            _ => panic!("Expected ItemsRestocked event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delivery_handler_success() {
+        let order_repo = Arc::new(MockOrderRepository::new());
+        let event_bus = Arc::new(MockEventBus::new());
+        let logger = Arc::new(MockLogger);
+        let handler = DeliveryHandler::new(
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::new()),
+            logger,
+        );
+
+        // テスト用の注文を作成（発送済み状態）
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+
+        // 書籍を追加
+        let book_id = BookId::new();
+        let price = Money::jpy(1000);
+        order.add_book(book_id, 2, price).unwrap();
+
+        // 配送先住所を設定
+        let address = crate::domain::model::ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address.clone()).unwrap();
+
+        // 注文を確定状態にしてから発送済み状態にする
+        order.confirm().unwrap();
+        order.mark_as_shipped().unwrap();
+
+        // モックリポジトリに注文を保存
+        {
+            let mut orders = order_repo.orders.lock().await;
+            orders.insert(order_id, order);
+        }
+
+        // OrderShippedイベントを作成
+        let event = OrderShipped::new(order_id, address);
+
+        // ハンドラーを実行
+        let result = handler.handle(event).await;
+        assert!(result.is_ok());
+
+        // 注文が配達完了状態になっていることを確認
+        let orders = order_repo.orders.lock().await;
+        let updated_order = orders.get(&order_id).unwrap();
+        assert_eq!(
+            updated_order.status(),
+            crate::domain::model::OrderStatus::Delivered
+        );
+    }
+
+    #[tokio::test]
+    async fn test_eventual_consistency_verifier() {
+        let order_repo = Arc::new(MockOrderRepository::new());
+        let inventory_repo = Arc::new(MockInventoryRepository::new());
+        let logger = Arc::new(MockLogger);
+        let verifier = EventualConsistencyVerifier::new(order_repo.clone(), inventory_repo.clone(), logger);
+
+        // テスト用の在庫を追加
+        let book_id = BookId::new();
+        let inventory = Inventory::new(book_id, 10);
+        inventory_repo.add_inventory(inventory).await;
+
+        // テスト用の注文を作成
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+
+        // 書籍を追加
+        let price = Money::jpy(1000);
+        order.add_book(book_id, 3, price).unwrap();
+
+        // 配送先住所を設定
+        let address = crate::domain::model::ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address).unwrap();
+
+        // 注文を確定状態にする
+        order.confirm().unwrap();
+
+        // モックリポジトリに注文を保存
+        {
+            let mut orders = order_repo.orders.lock().await;
+            orders.insert(order_id, order);
+        }
+
+        // OrderConfirmedイベントを作成
+        let event = OrderConfirmed::new(order_id, customer_id, vec![], Money::jpy(3000));
+
+        // 整合性検証を実行
+        let result = verifier.handle(event).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_complete_saga_flow() {
+        // 修正されたフローのテスト: 注文確定→在庫予約まで（発送・配達は手動操作）
+        let inventory_repo = Arc::new(MockInventoryRepository::new());
+        let order_repo = Arc::new(MockOrderRepository::new());
+        let event_bus = Arc::new(MockEventBus::new());
+
+        // ハンドラーを作成（実際のフローに合わせて在庫予約のみ自動実行）
+        let logger = Arc::new(MockLogger);
+        let inventory_handler = InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::new()),
+            logger.clone(),
+        );
+
+        // テスト用の在庫を追加（十分な在庫を確保）
+        let book_id = BookId::new();
+        let inventory = Inventory::new(book_id, 20);
+        inventory_repo.add_inventory(inventory).await;
+
+        // テスト用の注文を作成
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+
+        // 書籍を追加
+        let price = Money::jpy(1000);
+        order.add_book(book_id, 3, price).unwrap();
+
+        // 配送先住所を設定
+        let address = crate::domain::model::ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address).unwrap();
+
+        // 注文を確定状態にする
+        order.confirm().unwrap();
+
+        // モックリポジトリに注文を保存
+        {
+            let mut orders = order_repo.orders.lock().await;
+            orders.insert(order_id, order);
+        }
+
+        // OrderConfirmedイベントを発行（在庫予約まで自動実行）
+        let event = OrderConfirmed::new(
+            order_id,
+            customer_id,
+            vec![crate::domain::model::OrderLine::new(book_id, 3, price).unwrap()],
+            Money::jpy(3000),
+        );
+
+        // 手動でハンドラーを実行（モックイベントバスでは自動実行されないため）
+        let result = inventory_handler.handle(event.clone()).await;
+        assert!(result.is_ok());
+
+        // イベントが発行されたことを確認
+        let published_events = event_bus.get_published_events().await;
+        assert_eq!(published_events.len(), 1);
+        match &published_events[0] {
+            DomainEvent::InventoryReserved(event) => {
+                assert_eq!(event.order_id, order_id);
+            }
+            _ => panic!("Expected InventoryReserved event"),
+        }
+
+        // 注文確定後の状態を確認（Confirmedのまま、在庫のみ予約済み）
+        let orders = order_repo.orders.lock().await;
+        let order_after_confirmation = orders.get(&order_id).unwrap();
+
+        // 注文はConfirmed状態のまま（発送は手動操作のため）
+        assert_eq!(
+            order_after_confirmation.status(),
+            crate::domain::model::OrderStatus::Confirmed
+        );
+
+        // 在庫が予約されていることを確認
+        let updated_inventory = inventory_repo
+            .find_by_book_id(book_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated_inventory.quantity_on_hand(),
+            17, // 20 - 3 = 17
+            "Expected inventory to be reduced to 17, but got: {}",
+            updated_inventory.quantity_on_hand()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_manual_shipping_and_delivery_flow() {
+        // 手動操作フローのテスト: 発送・配達は手動API呼び出しで実行
+        let order_repo = Arc::new(MockOrderRepository::new());
+        let event_bus = Arc::new(MockEventBus::new());
+
+        // 通知ハンドラーのみ登録（発送・配達時の通知用）
+        let logger = Arc::new(MockLogger);
+        let notification_handler = NotificationHandler::new(logger.clone());
+
+        // テスト用の注文を作成（Confirmed状態）
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = crate::domain::model::Order::new(order_id, customer_id);
+
+        // 書籍を追加
+        let book_id = BookId::new();
+        let price = Money::jpy(1000);
+        order.add_book(book_id, 3, price).unwrap();
+
+        // 配送先住所を設定
+        let address = crate::domain::model::ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address.clone()).unwrap();
+
+        // 注文を確定状態にする
+        order.confirm().unwrap();
+
+        // モックリポジトリに注文を保存
+        {
+            let mut orders = order_repo.orders.lock().await;
+            orders.insert(order_id, order);
+        }
+
+        // 手動発送操作をシミュレート
+        {
+            let mut orders = order_repo.orders.lock().await;
+            let order = orders.get_mut(&order_id).unwrap();
+            order.mark_as_shipped().unwrap();
+
+            // OrderShippedイベントを手動発行（実際のAPIでは自動発行される）
+            let shipped_event = crate::domain::event::OrderShipped::new(order_id, address.clone());
+            event_bus
+                .publish(crate::domain::event::DomainEvent::OrderShipped(
+                    shipped_event.clone(),
+                ))
+                .await
+                .unwrap();
+
+            // 通知ハンドラーを手動実行
+            notification_handler.handle(shipped_event).await.unwrap();
+        }
+
+        // イベント処理を待つ
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        // 発送後の状態確認
+        {
+            let orders = order_repo.orders.lock().await;
+            let order = orders.get(&order_id).unwrap();
+            assert_eq!(order.status(), crate::domain::model::OrderStatus::Shipped);
+        }
+
+        // 手動配達完了操作をシミュレート
+        {
+            let mut orders = order_repo.orders.lock().await;
+            let order = orders.get_mut(&order_id).unwrap();
+            order.mark_as_delivered().unwrap();
+
+            // OrderDeliveredイベントを手動発行（実際のAPIでは自動発行される）
+            let delivered_event = crate::domain::event::OrderDelivered::new(order_id);
+            event_bus
+                .publish(crate::domain::event::DomainEvent::OrderDelivered(
+                    delivered_event.clone(),
+                ))
+                .await
+                .unwrap();
+
+            // 通知ハンドラーを手動実行
+            notification_handler.handle(delivered_event).await.unwrap();
+        }
+
+        // イベント処理を待つ
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        // 配達完了後の状態確認
+        {
+            let orders = order_repo.orders.lock().await;
+            let order = orders.get(&order_id).unwrap();
+            assert_eq!(order.status(), crate::domain::model::OrderStatus::Delivered);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_saga_compensation_coordinator() {
+        let event_bus = Arc::new(MockEventBus::new());
+        let logger = Arc::new(MockLogger);
+        let coordinator = SagaCompensationCoordinator::new(event_bus.clone(), logger);
+
+        let saga_id = Uuid::new_v4();
+        let result = coordinator
+            .start_compensation(
+                saga_id,
+                "shipping".to_string(),
+                "配送業者エラー".to_string(),
+            )
+            .await;
+
+        assert!(result.is_ok());
+
+        // SagaCompensationStartedイベントが発行されていることを確認
+        let published_events = event_bus.get_published_events().await;
+        assert_eq!(published_events.len(), 1);
+        match &published_events[0] {
+            DomainEvent::SagaCompensationStarted(event) => {
+                assert_eq!(event.saga_id, saga_id);
+            }
+            _ => panic!("Expected SagaCompensationStarted event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compensation_completion_handler() {
+        let logger = Arc::new(MockLogger);
+        let handler = CompensationCompletionHandler::new(logger);
+
+        let saga_id = Uuid::new_v4();
+        let event = SagaCompensationCompleted::new(
+            saga_id,
+            vec!["inventory_reservation".to_string()],
+            CompensationResult::Success,
+        );
+
+        let result = handler.handle(event).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_business_metrics_handler_records_saga_outcomes() {
+        let metrics = BusinessMetrics::new();
+        let handler = BusinessMetricsHandler::new(metrics.clone(), Arc::new(MockLogger));
+
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        handler
+            .handle(OrderConfirmed::new(order_id, customer_id, vec![], Money::jpy(3000)))
+            .await
+            .unwrap();
+        handler
+            .handle(InventoryReservationFailed::new(
+                order_id,
+                vec![],
+                "在庫不足".to_string(),
+                Uuid::new_v4(),
+            ))
+            .await
+            .unwrap();
+        handler
+            .handle(OrderCancelled::new(order_id, customer_id, vec![]))
+            .await
+            .unwrap();
+
+        let snapshot = metrics.snapshot().await;
+        assert_eq!(snapshot.orders_confirmed_total, 1);
+        assert_eq!(snapshot.orders_cancelled_total, 1);
+        assert_eq!(snapshot.average_order_value, 3000);
+        assert_eq!(snapshot.reservation_failure_rate, 1.0);
+        assert_eq!(snapshot.compensation_rate, 1.0);
+        assert_eq!(snapshot.active_sagas, 0);
     }
 }
@@ -0,0 +1,117 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// ハンドラーの自動一時停止のポリシー
+/// 時間窓内の配信の失敗率が閾値以上になったハンドラーを一時停止し、
+/// クールダウン後に再開を試みる（下流の障害で失敗し続けるハンドラーがリトライで配信を詰まらせないようにする）
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlerHealthPolicy {
+    /// 一時停止する失敗率（0.5 = 50%）
+    pub failure_rate: f64,
+    /// 失敗率を集計する時間窓
+    pub window: Duration,
+    /// 失敗率を評価するために必要な時間窓内の最小配信数（少数の失敗での一時停止を防ぐ）
+    pub min_deliveries: usize,
+    /// 一時停止してから再開を試みるまでの時間
+    pub cooldown: Duration,
+}
+
+impl Default for HandlerHealthPolicy {
+    fn default() -> Self {
+        Self {
+            failure_rate: 0.5,
+            window: Duration::from_secs(60),
+            min_deliveries: 10,
+            cooldown: Duration::from_secs(60),
+        }
+    }
+}
+
+/// ハンドラーの直近の配信結果
+/// リトライを含めて1回の配信を1件として、時間窓内の成功・失敗を保持する
+#[derive(Debug, Clone, Default)]
+pub struct HandlerHealth {
+    /// 配信日時と成功したかどうか（古い順）
+    outcomes: VecDeque<(Instant, bool)>,
+}
+
+impl HandlerHealth {
+    /// 配信結果を記録し、失敗率が閾値以上になった場合はその失敗率を返す
+    ///
+    /// # Arguments
+    /// * `succeeded` - 配信に成功したか
+    /// * `now` - 配信日時
+    /// * `policy` - 自動一時停止のポリシー
+    pub fn record(
+        &mut self,
+        succeeded: bool,
+        now: Instant,
+        policy: &HandlerHealthPolicy,
+    ) -> Option<f64> {
+        self.outcomes.push_back((now, succeeded));
+        while let Some((recorded_at, _)) = self.outcomes.front() {
+            if now.duration_since(*recorded_at) <= policy.window {
+                break;
+            }
+            self.outcomes.pop_front();
+        }
+
+        if self.outcomes.len() < policy.min_deliveries.max(1) {
+            return None;
+        }
+        let rate = self.failure_rate();
+        (rate >= policy.failure_rate).then_some(rate)
+    }
+
+    /// 時間窓内の配信の失敗率（配信がない場合は0）
+    pub fn failure_rate(&self) -> f64 {
+        if self.outcomes.is_empty() {
+            return 0.0;
+        }
+        let failures = self
+            .outcomes
+            .iter()
+            .filter(|(_, succeeded)| !succeeded)
+            .count();
+        failures as f64 / self.outcomes.len() as f64
+    }
+
+    /// 記録した配信結果を消去する（再開時に一時停止前の失敗で再び止めないようにする）
+    pub fn reset(&mut self) {
+        self.outcomes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_trips_only_above_threshold_with_enough_deliveries() {
+        let policy = HandlerHealthPolicy {
+            failure_rate: 0.5,
+            window: Duration::from_secs(60),
+            min_deliveries: 4,
+            cooldown: Duration::from_secs(30),
+        };
+        let mut health = HandlerHealth::default();
+        let start = Instant::now();
+
+        // 最小配信数に満たない間は失敗しても一時停止しない
+        for _ in 0..3 {
+            assert_eq!(health.record(false, start, &policy), None);
+        }
+        assert_eq!(health.record(true, start, &policy), Some(0.75));
+
+        // 時間窓を過ぎた配信結果は集計しない
+        health.reset();
+        health.record(false, start, &policy);
+        health.record(false, start, &policy);
+        let later = start + Duration::from_secs(61);
+        health.record(true, later, &policy);
+        health.record(true, later, &policy);
+        health.record(false, later, &policy);
+        assert_eq!(health.record(true, later, &policy), None);
+        assert_eq!(health.failure_rate(), 0.25);
+    }
+}
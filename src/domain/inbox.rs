@@ -0,0 +1,436 @@
+use crate::domain::error::DomainError;
+use crate::domain::event_bus::HandlerError;
+use crate::domain::port::{InboxRepository, Logger, RepositoryError};
+use crate::domain::task_supervisor::SupervisedTask;
+use crate::domain::tracking::TrackingStage;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// 配送業者の追跡Webhookのメッセージの送信元
+pub const INBOX_SOURCE_CARRIER: &str = "carrier";
+
+/// 受信メッセージの処理状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InboxMessageStatus {
+    /// 未処理（失敗した場合も試行回数の上限までは再処理する）
+    Pending,
+    /// 処理済み
+    Processed,
+    /// 処理を諦めた（永続的エラー、または試行回数の上限に達した）
+    Failed,
+}
+
+impl InboxMessageStatus {
+    /// 文字列からInboxMessageStatusを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Pending" => Ok(InboxMessageStatus::Pending),
+            "Processed" => Ok(InboxMessageStatus::Processed),
+            "Failed" => Ok(InboxMessageStatus::Failed),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な受信メッセージの状態: {}",
+                s
+            ))),
+        }
+    }
+
+    /// 状態を表す文字列
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InboxMessageStatus::Pending => "Pending",
+            InboxMessageStatus::Processed => "Processed",
+            InboxMessageStatus::Failed => "Failed",
+        }
+    }
+}
+
+/// 外部システムから受信したメッセージ（inbox_messagesテーブルの1行）
+/// 処理の前に送信元の外部IDとともに記録し、再送や再起動をまたいで1回だけ取り込む
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboxMessage {
+    /// 送信元（例: "carrier"）
+    pub source: String,
+    /// 送信元が付与したメッセージID（送信元ごとに一意）
+    pub external_id: String,
+    /// 受信したメッセージの本文（そのまま保存する）
+    pub payload: String,
+    pub received_at: DateTime<Utc>,
+    pub status: InboxMessageStatus,
+    /// 処理を試行した回数
+    pub attempts: u32,
+    /// 最後に失敗したときのエラー
+    pub last_error: Option<String>,
+    pub processed_at: Option<DateTime<Utc>>,
+}
+
+impl InboxMessage {
+    /// 受信したメッセージを未処理として作成
+    pub fn received(
+        source: &str,
+        external_id: String,
+        payload: String,
+        received_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            source: source.to_string(),
+            external_id,
+            payload,
+            received_at,
+            status: InboxMessageStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            processed_at: None,
+        }
+    }
+}
+
+/// 配送業者の追跡Webhookのメッセージ本文
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CarrierTrackingMessage {
+    pub order_id: Uuid,
+    /// 配送業者のステータスコード（例: packed, in_transit, out_for_delivery, delivered）
+    pub status: String,
+    /// 配送業者側の発生日時（省略時は受信日時）
+    pub occurred_at: Option<DateTime<Utc>>,
+    pub location: Option<String>,
+    pub description: Option<String>,
+}
+
+impl CarrierTrackingMessage {
+    /// 配送業者のステータスコードを配送追跡の段階に変換
+    pub fn tracking_stage(&self) -> Result<TrackingStage, DomainError> {
+        TrackingStage::from_carrier_status(&self.status)
+    }
+}
+
+/// 受信メッセージを内部の処理に振り分けるハンドラー
+/// 送信元ごとに1つ登録する
+#[async_trait]
+pub trait InboxMessageHandler: Send + Sync {
+    /// メッセージを処理する
+    /// 永続的エラー（HandlerError::PermanentError）を返したメッセージは再処理しない
+    async fn handle_inbox_message(&self, message: &InboxMessage) -> Result<(), HandlerError>;
+}
+
+/// 受信メッセージの処理結果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InboxRunSummary {
+    pub processed: usize,
+    /// 失敗して再処理を待つメッセージ数
+    pub retrying: usize,
+    /// 処理を諦めたメッセージ数
+    pub failed: usize,
+}
+
+/// 受信メッセージの処理タスク
+/// 未処理のメッセージを受信順に取得し、送信元ごとのハンドラーに振り分ける
+/// 処理状態はinbox_messagesテーブルで管理するため、再起動後も未処理のメッセージから再開する
+pub struct InboxProcessor {
+    inbox_repository: Arc<dyn InboxRepository>,
+    logger: Arc<dyn Logger>,
+    handlers: HashMap<String, Arc<dyn InboxMessageHandler>>,
+    max_attempts: u32,
+    batch_size: usize,
+}
+
+impl InboxProcessor {
+    /// 1メッセージあたりの既定の試行回数の上限
+    pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+    /// 1回の実行で処理するメッセージ数の上限
+    pub const DEFAULT_BATCH_SIZE: usize = 100;
+
+    pub fn new(inbox_repository: Arc<dyn InboxRepository>, logger: Arc<dyn Logger>) -> Self {
+        Self {
+            inbox_repository,
+            logger,
+            handlers: HashMap::new(),
+            max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// 送信元のハンドラーを登録
+    pub fn with_handler(mut self, source: &str, handler: Arc<dyn InboxMessageHandler>) -> Self {
+        self.handlers.insert(source.to_string(), handler);
+        self
+    }
+
+    /// 1メッセージあたりの試行回数の上限を設定
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// 処理タスクをバックグラウンドで起動
+    pub fn spawn(self, poll_interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// 未処理のメッセージを受信順に処理する
+    ///
+    /// # Arguments
+    /// * `now` - 処理日時（処理済みの日時として記録する）
+    ///
+    /// # Returns
+    /// * `Ok(InboxRunSummary)` - 処理結果
+    /// * `Err(RepositoryError)` - 受信メッセージの取得・更新失敗
+    pub async fn run(&self, now: DateTime<Utc>) -> Result<InboxRunSummary, RepositoryError> {
+        let messages = self.inbox_repository.find_pending(self.batch_size).await?;
+
+        let mut summary = InboxRunSummary::default();
+        for message in messages {
+            let result = match self.handlers.get(&message.source) {
+                Some(handler) => handler.handle_inbox_message(&message).await,
+                None => Err(HandlerError::PermanentError(format!(
+                    "未対応の送信元: {}",
+                    message.source
+                ))),
+            };
+
+            match result {
+                Ok(()) => {
+                    self.inbox_repository
+                        .mark_processed(&message.source, &message.external_id, now)
+                        .await?;
+                    summary.processed += 1;
+                }
+                Err(error) => {
+                    let give_up =
+                        error.is_permanent() || message.attempts + 1 >= self.max_attempts;
+                    self.inbox_repository
+                        .record_failure(
+                            &message.source,
+                            &message.external_id,
+                            &error.to_string(),
+                            give_up,
+                        )
+                        .await?;
+
+                    let mut context = HashMap::new();
+                    context.insert("source".to_string(), message.source.clone());
+                    context.insert("external_id".to_string(), message.external_id.clone());
+                    context.insert("error".to_string(), error.to_string());
+                    if give_up {
+                        self.logger.error(
+                            "InboxProcessor",
+                            "Gave up processing inbox message",
+                            None,
+                            Some(context),
+                        );
+                        summary.failed += 1;
+                    } else {
+                        self.logger.warn(
+                            "InboxProcessor",
+                            "Failed to process inbox message, will retry",
+                            None,
+                            Some(context),
+                        );
+                        summary.retrying += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+#[async_trait]
+impl SupervisedTask for InboxProcessor {
+    async fn run_once(&self) {
+        if let Err(e) = self.run(Utc::now()).await {
+            let mut context = HashMap::new();
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "InboxProcessor",
+                "Failed to process inbox messages",
+                None,
+                Some(context),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// メモリ上で受信メッセージを管理するリポジトリ
+    #[derive(Default)]
+    struct InMemoryInboxRepository {
+        messages: Mutex<Vec<InboxMessage>>,
+    }
+
+    impl InMemoryInboxRepository {
+        fn message(&self, external_id: &str) -> InboxMessage {
+            self.messages
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|message| message.external_id == external_id)
+                .cloned()
+                .unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl InboxRepository for InMemoryInboxRepository {
+        async fn insert(&self, message: &InboxMessage) -> Result<bool, RepositoryError> {
+            let mut messages = self.messages.lock().unwrap();
+            if messages.iter().any(|m| {
+                m.source == message.source && m.external_id == message.external_id
+            }) {
+                return Ok(false);
+            }
+            messages.push(message.clone());
+            Ok(true)
+        }
+
+        async fn find_pending(&self, limit: usize) -> Result<Vec<InboxMessage>, RepositoryError> {
+            let messages = self.messages.lock().unwrap();
+            Ok(messages
+                .iter()
+                .filter(|message| message.status == InboxMessageStatus::Pending)
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+
+        async fn mark_processed(
+            &self,
+            source: &str,
+            external_id: &str,
+            processed_at: DateTime<Utc>,
+        ) -> Result<(), RepositoryError> {
+            let mut messages = self.messages.lock().unwrap();
+            for message in messages.iter_mut() {
+                if message.source == source && message.external_id == external_id {
+                    message.status = InboxMessageStatus::Processed;
+                    message.attempts += 1;
+                    message.processed_at = Some(processed_at);
+                }
+            }
+            Ok(())
+        }
+
+        async fn record_failure(
+            &self,
+            source: &str,
+            external_id: &str,
+            error: &str,
+            give_up: bool,
+        ) -> Result<(), RepositoryError> {
+            let mut messages = self.messages.lock().unwrap();
+            for message in messages.iter_mut() {
+                if message.source == source && message.external_id == external_id {
+                    message.attempts += 1;
+                    message.last_error = Some(error.to_string());
+                    if give_up {
+                        message.status = InboxMessageStatus::Failed;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// 本文が"transient"のメッセージでは一時的エラーを返し、処理した外部IDを記録するハンドラー
+    #[derive(Default)]
+    struct RecordingHandler {
+        handled: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl InboxMessageHandler for RecordingHandler {
+        async fn handle_inbox_message(&self, message: &InboxMessage) -> Result<(), HandlerError> {
+            if message.payload == "transient" {
+                return Err(HandlerError::TransientError("接続できません".to_string()));
+            }
+            self.handled
+                .lock()
+                .unwrap()
+                .push(message.external_id.clone());
+            Ok(())
+        }
+    }
+
+    struct NoopLogger;
+
+    impl Logger for NoopLogger {
+        fn debug(&self, _: &str, _: &str, _: Option<Uuid>, _: Option<HashMap<String, String>>) {}
+        fn info(&self, _: &str, _: &str, _: Option<Uuid>, _: Option<HashMap<String, String>>) {}
+        fn warn(&self, _: &str, _: &str, _: Option<Uuid>, _: Option<HashMap<String, String>>) {}
+        fn error(&self, _: &str, _: &str, _: Option<Uuid>, _: Option<HashMap<String, String>>) {}
+    }
+
+    #[tokio::test]
+    async fn test_processor_handles_each_message_once_and_gives_up_after_max_attempts() {
+        let repository = Arc::new(InMemoryInboxRepository::default());
+        let handler = Arc::new(RecordingHandler::default());
+        let processor = InboxProcessor::new(repository.clone(), Arc::new(NoopLogger))
+            .with_handler(INBOX_SOURCE_CARRIER, handler.clone())
+            .with_max_attempts(2);
+        let now = Utc::now();
+
+        let receive = |external_id: &str, source: &str, payload: &str| {
+            InboxMessage::received(source, external_id.to_string(), payload.to_string(), now)
+        };
+        assert!(repository
+            .insert(&receive("evt-1", INBOX_SOURCE_CARRIER, "{}"))
+            .await
+            .unwrap());
+        // 再送された同じメッセージは記録しない
+        assert!(!repository
+            .insert(&receive("evt-1", INBOX_SOURCE_CARRIER, "{}"))
+            .await
+            .unwrap());
+        repository
+            .insert(&receive("evt-2", INBOX_SOURCE_CARRIER, "transient"))
+            .await
+            .unwrap();
+        repository
+            .insert(&receive("evt-3", "payment", "{}"))
+            .await
+            .unwrap();
+
+        let first = processor.run(now).await.unwrap();
+        assert_eq!(
+            first,
+            InboxRunSummary {
+                processed: 1,
+                retrying: 1,
+                failed: 1,
+            }
+        );
+        assert_eq!(
+            repository.message("evt-3").status,
+            InboxMessageStatus::Failed
+        );
+
+        // 処理済みのメッセージは再処理せず、一時的エラーは上限まで再試行する
+        let second = processor.run(now).await.unwrap();
+        assert_eq!(second.failed, 1);
+        assert_eq!(*handler.handled.lock().unwrap(), vec!["evt-1".to_string()]);
+        let retried = repository.message("evt-2");
+        assert_eq!(retried.status, InboxMessageStatus::Failed);
+        assert_eq!(retried.attempts, 2);
+        assert_eq!(
+            repository.message("evt-1").processed_at,
+            Some(now)
+        );
+        assert_eq!(processor.run(now).await.unwrap(), InboxRunSummary::default());
+    }
+}
@@ -0,0 +1,51 @@
+use serde::Serialize;
+use std::fmt;
+
+/// 集約の不変条件の違反
+/// 集約の操作（ドメインメソッド）は不変条件を守るが、永続化層からの復元や移行で
+/// 守られていない状態が紛れ込んだことを検出するために使う
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvariantViolation {
+    /// 集約の種類（"Order"、"Inventory"）
+    pub aggregate: &'static str,
+    /// 集約のID
+    pub aggregate_id: String,
+    /// 違反した不変条件の識別子（例: "shipping_address_required"）
+    pub rule: &'static str,
+    pub message: String,
+}
+
+impl InvariantViolation {
+    pub fn new(
+        aggregate: &'static str,
+        aggregate_id: impl ToString,
+        rule: &'static str,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            aggregate,
+            aggregate_id: aggregate_id.to_string(),
+            rule,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}({}) の不変条件 {} に違反しています: {}",
+            self.aggregate, self.aggregate_id, self.rule, self.message
+        )
+    }
+}
+
+/// 違反の一覧を1つのメッセージにまとめる（エラーやログ用）
+pub fn describe_violations(violations: &[InvariantViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,102 @@
+use crate::domain::model::BookId;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 差分同期で1回に返す在庫変動の上限
+pub const MAX_CHANGES_PER_PAGE: usize = 1000;
+
+/// 在庫変動ログの1行（在庫数が変わるたびに在庫の保存と同じトランザクションで記録する）
+/// versionは在庫変動ログ全体で単調増加し、店舗のPOSが差分同期の位置として使う
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StockMovement {
+    pub version: u64,
+    pub book_id: BookId,
+    /// 在庫数の増減（入荷・返品は正、予約は負、在庫の新規登録時は登録した在庫数）
+    pub quantity_delta: i64,
+    /// 変動後の在庫数
+    pub quantity_on_hand: u32,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// 在庫スナップショットの1行
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InventorySnapshotEntry {
+    pub book_id: BookId,
+    pub quantity_on_hand: u32,
+    /// 凍結中（照合が終わるまで予約を受け付けない）
+    pub frozen: bool,
+}
+
+/// 在庫スナップショット（POSの全件同期用）
+/// 在庫と在庫変動ログのversionを同じ時点で読み取ったもの
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InventorySnapshot {
+    /// スナップショットに反映済みの最後の在庫変動のversion（変動がなければ0）
+    /// 以降の差分はGET /inventory/changes?since_version={version}で取得する
+    pub version: u64,
+    pub taken_at: DateTime<Utc>,
+    /// 書籍ごとの在庫数（書籍IDの昇順）
+    pub entries: Vec<InventorySnapshotEntry>,
+}
+
+/// 在庫の差分（POSの差分同期用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InventoryChanges {
+    pub since_version: u64,
+    /// 次の差分同期の起点にするversion（返した最後の変動のversion、変動がなければsince_version）
+    pub version: u64,
+    /// 上限を超えたため返していない変動が残っている（versionから続けて取得する）
+    pub has_more: bool,
+    /// since_versionより後の在庫変動（versionの昇順）
+    pub changes: Vec<StockMovement>,
+}
+
+impl InventoryChanges {
+    /// 取得した在庫変動から差分を作成
+    ///
+    /// # Arguments
+    /// * `since_version` - 差分の起点のversion
+    /// * `movements` - since_versionより後の在庫変動（versionの昇順、上限を超えたかを判定するため最大limit + 1件）
+    /// * `limit` - 1回に返す在庫変動の上限
+    pub fn build(since_version: u64, mut movements: Vec<StockMovement>, limit: usize) -> Self {
+        let has_more = movements.len() > limit;
+        movements.truncate(limit);
+
+        Self {
+            since_version,
+            version: movements
+                .last()
+                .map_or(since_version, |movement| movement.version),
+            has_more,
+            changes: movements,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movement(version: u64) -> StockMovement {
+        StockMovement {
+            version,
+            book_id: BookId::new(),
+            quantity_delta: -1,
+            quantity_on_hand: 3,
+            recorded_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_changes_continue_from_last_returned_version() {
+        let changes = InventoryChanges::build(4, vec![movement(5), movement(7), movement(8)], 2);
+        assert!(changes.has_more);
+        assert_eq!(changes.version, 7);
+        assert_eq!(changes.changes.len(), 2);
+
+        // 変動がなければ起点のversionのまま
+        let changes = InventoryChanges::build(8, Vec::new(), 2);
+        assert!(!changes.has_more);
+        assert_eq!(changes.version, 8);
+    }
+}
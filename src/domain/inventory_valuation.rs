@@ -0,0 +1,135 @@
+use crate::domain::model::{BookId, Money};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 永続化層から取得した書籍ごとの在庫数と販売価格
+/// 在庫とカタログの価格を結合した結果のため、価格が登録されていない書籍はunit_priceがNone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StockValuation {
+    pub book_id: BookId,
+    pub quantity_on_hand: u32,
+    pub unit_price: Option<Money>,
+}
+
+/// 在庫評価レポートの1行
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InventoryValuationEntry {
+    pub book_id: BookId,
+    pub quantity_on_hand: u32,
+    /// 販売価格（円、価格が登録されていない書籍はNone）
+    pub unit_price: Option<i64>,
+    /// 在庫金額（在庫数 × 販売価格、価格が登録されていない書籍はNone）
+    pub stock_value: Option<i64>,
+}
+
+/// 在庫評価レポート
+/// 書籍ごとの在庫数に現在の販売価格を掛けた在庫金額と、その合計をまとめる
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InventoryValuationReport {
+    pub generated_at: DateTime<Utc>,
+    /// 在庫数の合計
+    pub total_quantity: u64,
+    /// 在庫金額の合計（円、価格が登録されていない書籍は含まない）
+    pub total_value: i64,
+    /// 価格が登録されていない書籍の数
+    pub unpriced_count: usize,
+    /// 書籍ごとの在庫金額（書籍IDの昇順）
+    pub entries: Vec<InventoryValuationEntry>,
+}
+
+impl InventoryValuationReport {
+    /// 書籍ごとの在庫数と販売価格からレポートを作成
+    ///
+    /// # Arguments
+    /// * `generated_at` - レポートの作成日時
+    /// * `valuations` - 書籍ごとの在庫数と販売価格
+    pub fn build(generated_at: DateTime<Utc>, valuations: &[StockValuation]) -> Self {
+        let entries: Vec<InventoryValuationEntry> = valuations
+            .iter()
+            .map(|valuation| InventoryValuationEntry {
+                book_id: valuation.book_id,
+                quantity_on_hand: valuation.quantity_on_hand,
+                unit_price: valuation.unit_price.map(|price| price.amount()),
+                stock_value: valuation
+                    .unit_price
+                    .map(|price| price.multiply(valuation.quantity_on_hand).amount()),
+            })
+            .collect();
+
+        Self {
+            generated_at,
+            total_quantity: entries
+                .iter()
+                .map(|entry| u64::from(entry.quantity_on_hand))
+                .sum(),
+            total_value: entries.iter().filter_map(|entry| entry.stock_value).sum(),
+            unpriced_count: entries
+                .iter()
+                .filter(|entry| entry.unit_price.is_none())
+                .count(),
+            entries,
+        }
+    }
+
+    /// CSV形式で出力する（表計算ソフトでの集計用）
+    /// 価格が登録されていない書籍の販売価格・在庫金額は空欄にし、最終行に合計を出力する
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("book_id,quantity_on_hand,unit_price,stock_value\n");
+        for entry in &self.entries {
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                entry.book_id,
+                entry.quantity_on_hand,
+                entry
+                    .unit_price
+                    .map_or(String::new(), |price| price.to_string()),
+                entry
+                    .stock_value
+                    .map_or(String::new(), |value| value.to_string()),
+            ));
+        }
+        csv.push_str(&format!(
+            "total,{},,{}\n",
+            self.total_quantity, self.total_value
+        ));
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_totals_priced_books_and_exports_csv() {
+        let priced_book = BookId::new();
+        let unpriced_book = BookId::new();
+        let valuations = vec![
+            StockValuation {
+                book_id: priced_book,
+                quantity_on_hand: 3,
+                unit_price: Some(Money::jpy(1800)),
+            },
+            StockValuation {
+                book_id: unpriced_book,
+                quantity_on_hand: 2,
+                unit_price: None,
+            },
+        ];
+
+        let report = InventoryValuationReport::build(Utc::now(), &valuations);
+
+        assert_eq!(report.total_quantity, 5);
+        assert_eq!(report.total_value, 5400);
+        assert_eq!(report.unpriced_count, 1);
+        assert_eq!(report.entries[0].stock_value, Some(5400));
+        assert_eq!(report.entries[1].stock_value, None);
+        assert_eq!(
+            report.to_csv(),
+            format!(
+                "book_id,quantity_on_hand,unit_price,stock_value\n{},3,1800,5400\n{},2,,\ntotal,5,,5400\n",
+                priced_book, unpriced_book
+            )
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use crate::domain::error::DomainError;
+use crate::domain::event::EventMetadata;
+use crate::domain::event_bus::HandlerError;
+use crate::domain::model::{OrderId, OrderStatus};
+use crate::domain::port::{Logger, ParkedEventRepository};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 遅延イベントポリシーを設定できるハンドラー
+pub const LATE_EVENT_HANDLERS: [&str; 4] = [
+    "InventoryReservationHandler",
+    "ShippingHandler",
+    "DeliveryHandler",
+    "FulfillmentRouter",
+];
+
+/// 遅延イベントの扱い
+/// 遅延イベントとは、ハンドラーが想定する状態を注文が既に過ぎてから届いたイベント
+/// （例: キャンセル済みの注文に届いたOrderConfirmed）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LateEventPolicy {
+    /// ログを出力して読み飛ばす
+    #[default]
+    Skip,
+    /// 手動で確認できるよう保留する
+    Park,
+    /// 現在の状態でも適用できる場合は適用し、できない場合は保留する
+    ApplyIfCompatible,
+}
+
+impl LateEventPolicy {
+    /// 文字列からLateEventPolicyを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "skip" => Ok(LateEventPolicy::Skip),
+            "park" => Ok(LateEventPolicy::Park),
+            "apply_if_compatible" => Ok(LateEventPolicy::ApplyIfCompatible),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な遅延イベントポリシー: {}",
+                s
+            ))),
+        }
+    }
+
+    /// 遅延イベントへの対応を決定する
+    ///
+    /// # Arguments
+    /// * `compatible` - 現在の注文状態でもイベントを適用できるかどうか
+    pub fn decide(&self, compatible: bool) -> LateEventAction {
+        match self {
+            LateEventPolicy::Skip => LateEventAction::Skip,
+            LateEventPolicy::Park => LateEventAction::Park,
+            LateEventPolicy::ApplyIfCompatible if compatible => LateEventAction::Apply,
+            LateEventPolicy::ApplyIfCompatible => LateEventAction::Park,
+        }
+    }
+}
+
+impl fmt::Display for LateEventPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let policy_str = match self {
+            LateEventPolicy::Skip => "skip",
+            LateEventPolicy::Park => "park",
+            LateEventPolicy::ApplyIfCompatible => "apply_if_compatible",
+        };
+        write!(f, "{}", policy_str)
+    }
+}
+
+/// 遅延イベントに対して実際に行う対応
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LateEventAction {
+    /// 通常どおり処理する
+    Apply,
+    /// 読み飛ばす
+    Skip,
+    /// 保留する
+    Park,
+}
+
+/// ハンドラーごとの遅延イベントポリシー
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LateEventPolicies {
+    /// 個別の指定がないハンドラーに適用するポリシー
+    pub default_policy: LateEventPolicy,
+    /// ハンドラー名ごとのポリシー（例: "InventoryReservationHandler" => Park）
+    pub overrides: HashMap<String, LateEventPolicy>,
+}
+
+impl LateEventPolicies {
+    /// ハンドラーに適用するポリシーを取得
+    pub fn policy_for(&self, handler: &str) -> LateEventPolicy {
+        self.overrides
+            .get(handler)
+            .copied()
+            .unwrap_or(self.default_policy)
+    }
+}
+
+/// 保留された遅延イベント（parked_eventsテーブルの1行）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParkedEvent {
+    pub event_id: Uuid,
+    pub event_type: String,
+    /// イベントを保留したハンドラー
+    pub handler: String,
+    pub order_id: OrderId,
+    pub correlation_id: Uuid,
+    /// イベント受信時の注文状態
+    pub order_status: String,
+    /// ハンドラーが想定していた注文状態
+    pub expected_status: String,
+    /// 手動で再処理できるよう保存したイベント本体
+    pub payload: serde_json::Value,
+    pub parked_at: DateTime<Utc>,
+}
+
+/// 遅延イベントの内容（ハンドラーが判定に使う情報）
+pub struct LateEvent<'a> {
+    pub event_type: &'a str,
+    pub metadata: &'a EventMetadata,
+    pub order_id: OrderId,
+    pub order_status: OrderStatus,
+    pub expected_status: OrderStatus,
+    /// 現在の注文状態でもイベントを適用できるかどうか（ハンドラーごとに判定する）
+    pub compatible: bool,
+    pub payload: serde_json::Value,
+}
+
+/// 遅延イベントの扱いをハンドラー間で共通化するガード
+/// ポリシーに従って読み飛ばし・保留・適用を決め、保留する場合はリポジトリに記録する
+#[derive(Clone)]
+pub struct LateEventGuard {
+    policies: LateEventPolicies,
+    parked_events: Option<Arc<dyn ParkedEventRepository>>,
+    logger: Arc<dyn Logger>,
+}
+
+impl LateEventGuard {
+    /// 新しいガードを作成
+    ///
+    /// # Arguments
+    /// * `policies` - ハンドラーごとの遅延イベントポリシー
+    /// * `parked_events` - 保留したイベントの保存先
+    /// * `logger` - ロガー
+    pub fn new(
+        policies: LateEventPolicies,
+        parked_events: Arc<dyn ParkedEventRepository>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            policies,
+            parked_events: Some(parked_events),
+            logger,
+        }
+    }
+
+    /// 遅延イベントを読み飛ばすだけのガードを作成（保存先を持たない）
+    pub fn skip_only(logger: Arc<dyn Logger>) -> Self {
+        Self {
+            policies: LateEventPolicies::default(),
+            parked_events: None,
+            logger,
+        }
+    }
+
+    /// 遅延イベントへの対応を決定し、読み飛ばし・保留を実行する
+    ///
+    /// # Returns
+    /// * `Ok(LateEventAction::Apply)` - 呼び出し元のハンドラーで通常どおり処理する
+    /// * `Ok(LateEventAction::Skip | LateEventAction::Park)` - 対応済み（処理済みとしてマークする）
+    /// * `Err(HandlerError)` - 保留したイベントの保存に失敗
+    pub async fn resolve(
+        &self,
+        handler: &str,
+        event: LateEvent<'_>,
+    ) -> Result<LateEventAction, HandlerError> {
+        let policy = self.policies.policy_for(handler);
+        let action = match (policy.decide(event.compatible), &self.parked_events) {
+            (LateEventAction::Park, None) => {
+                self.logger.error(
+                    handler,
+                    "Late event policy requires parking but no parked event store is configured",
+                    Some(event.metadata.correlation_id),
+                    None,
+                );
+                LateEventAction::Skip
+            }
+            (action, _) => action,
+        };
+
+        let mut context = HashMap::new();
+        context.insert("event_type".to_string(), event.event_type.to_string());
+        context.insert("event_id".to_string(), event.metadata.event_id.to_string());
+        context.insert("order_id".to_string(), event.order_id.to_string());
+        context.insert("current_status".to_string(), event.order_status.to_string());
+        context.insert(
+            "expected_status".to_string(),
+            event.expected_status.to_string(),
+        );
+        context.insert("late_event_policy".to_string(), policy.to_string());
+
+        match action {
+            LateEventAction::Apply => {
+                self.logger.info(
+                    handler,
+                    "Late event is compatible with current order state, applying",
+                    Some(event.metadata.correlation_id),
+                    Some(context),
+                );
+            }
+            LateEventAction::Skip => {
+                self.logger.warn(
+                    handler,
+                    "Late event skipped",
+                    Some(event.metadata.correlation_id),
+                    Some(context),
+                );
+            }
+            LateEventAction::Park => {
+                let parked_event = ParkedEvent {
+                    event_id: event.metadata.event_id,
+                    event_type: event.event_type.to_string(),
+                    handler: handler.to_string(),
+                    order_id: event.order_id,
+                    correlation_id: event.metadata.correlation_id,
+                    order_status: event.order_status.to_string(),
+                    expected_status: event.expected_status.to_string(),
+                    payload: event.payload,
+                    parked_at: Utc::now(),
+                };
+                if let Some(parked_events) = &self.parked_events {
+                    parked_events.park(&parked_event).await.map_err(|e| {
+                        HandlerError::RepositoryError(format!("遅延イベント保留エラー: {}", e))
+                    })?;
+                }
+                self.logger.warn(
+                    handler,
+                    "Late event parked for manual review",
+                    Some(event.metadata.correlation_id),
+                    Some(context),
+                );
+            }
+        }
+
+        Ok(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_decision() {
+        assert_eq!(LateEventPolicy::Skip.decide(true), LateEventAction::Skip);
+        assert_eq!(LateEventPolicy::Park.decide(true), LateEventAction::Park);
+        assert_eq!(
+            LateEventPolicy::ApplyIfCompatible.decide(true),
+            LateEventAction::Apply
+        );
+        assert_eq!(
+            LateEventPolicy::ApplyIfCompatible.decide(false),
+            LateEventAction::Park
+        );
+    }
+
+    #[test]
+    fn test_policy_for_handler() {
+        let policies = LateEventPolicies {
+            default_policy: LateEventPolicy::Skip,
+            overrides: HashMap::from([(
+                "FulfillmentRouter".to_string(),
+                LateEventPolicy::ApplyIfCompatible,
+            )]),
+        };
+
+        assert_eq!(
+            policies.policy_for("FulfillmentRouter"),
+            LateEventPolicy::ApplyIfCompatible
+        );
+        assert_eq!(
+            policies.policy_for("ShippingHandler"),
+            LateEventPolicy::Skip
+        );
+        assert_eq!(
+            LateEventPolicy::from_string("apply_if_compatible").unwrap(),
+            LateEventPolicy::ApplyIfCompatible
+        );
+        assert!(LateEventPolicy::from_string("drop").is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::OrderId;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 旧システムの注文の取り込み結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum LegacyImportOutcome {
+    /// 注文として取り込んだ
+    Imported,
+    /// 変換できなかったため隔離した（修正後に再度取り込める）
+    Quarantined,
+}
+
+impl LegacyImportOutcome {
+    /// 文字列からLegacyImportOutcomeを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Imported" => Ok(LegacyImportOutcome::Imported),
+            "Quarantined" => Ok(LegacyImportOutcome::Quarantined),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な取り込み結果: {}",
+                s
+            ))),
+        }
+    }
+
+    /// 取り込み結果を表す文字列
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LegacyImportOutcome::Imported => "Imported",
+            LegacyImportOutcome::Quarantined => "Quarantined",
+        }
+    }
+}
+
+/// 旧システムの注文1件の取り込みの記録（legacy_order_importsテーブルの1行）
+/// 旧システムの注文番号ごとに1件で、取り込み済みの注文の二重取り込みを防ぐ
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LegacyImportRecord {
+    /// 旧システムの注文番号
+    pub legacy_order_no: String,
+    pub outcome: LegacyImportOutcome,
+    /// 取り込んだ注文のID（隔離した場合はNone）
+    pub order_id: Option<OrderId>,
+    /// 旧システムから受け取ったレコード（JSON）
+    pub payload: String,
+    /// 隔離した理由
+    pub reasons: Vec<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl LegacyImportRecord {
+    /// 注文として取り込んだ記録を作成
+    pub fn imported(
+        legacy_order_no: String,
+        order_id: OrderId,
+        payload: String,
+        recorded_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            legacy_order_no,
+            outcome: LegacyImportOutcome::Imported,
+            order_id: Some(order_id),
+            payload,
+            reasons: Vec::new(),
+            recorded_at,
+        }
+    }
+
+    /// 変換できずに隔離した記録を作成
+    pub fn quarantined(
+        legacy_order_no: String,
+        payload: String,
+        reasons: Vec<String>,
+        recorded_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            legacy_order_no,
+            outcome: LegacyImportOutcome::Quarantined,
+            order_id: None,
+            payload,
+            reasons,
+            recorded_at,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 購読ごとの重要度（購読時に指定する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HandlerCriticality {
+    /// 配信待ちのイベントがたまっていても必ず配信する（在庫予約・発送などのサーガの処理）
+    #[default]
+    Critical,
+    /// 負荷制限中は配信を見送る（通知・分析など、欠けても注文の処理に影響しないもの）
+    NonCritical,
+}
+
+/// 負荷制限のポリシー
+/// 配信待ちのイベント数が閾値以上になったら重要でないハンドラーへの配信を見送り、
+/// 再開の閾値以下まで減ったら配信を再開する（閾値の付近で切り替わり続けないよう2つの閾値を使う）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadSheddingPolicy {
+    /// 負荷制限を始める配信待ちのイベント数
+    pub shed_at_depth: usize,
+    /// 負荷制限を終える配信待ちのイベント数
+    pub resume_at_depth: usize,
+}
+
+impl Default for LoadSheddingPolicy {
+    fn default() -> Self {
+        Self {
+            shed_at_depth: 1000,
+            resume_at_depth: 100,
+        }
+    }
+}
+
+/// 負荷制限の切り替え
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadSheddingTransition {
+    Started,
+    Stopped,
+}
+
+/// 負荷制限の状態
+#[derive(Debug, Clone, Default)]
+pub struct LoadShedder {
+    /// 負荷制限中の場合は、始めた日時
+    shedding_since: Option<DateTime<Utc>>,
+    activations: u64,
+    shed_events: u64,
+}
+
+impl LoadShedder {
+    /// 配信待ちのイベント数を反映し、負荷制限を切り替えた場合はその切り替えを返す
+    ///
+    /// # Arguments
+    /// * `queue_depth` - 配信待ちのイベント数
+    /// * `now` - 現在日時
+    /// * `policy` - 負荷制限のポリシー
+    pub fn observe(
+        &mut self,
+        queue_depth: usize,
+        now: DateTime<Utc>,
+        policy: &LoadSheddingPolicy,
+    ) -> Option<LoadSheddingTransition> {
+        match self.shedding_since {
+            None if queue_depth >= policy.shed_at_depth => {
+                self.shedding_since = Some(now);
+                self.activations += 1;
+                Some(LoadSheddingTransition::Started)
+            }
+            Some(_) if queue_depth <= policy.resume_at_depth => {
+                self.shedding_since = None;
+                Some(LoadSheddingTransition::Stopped)
+            }
+            _ => None,
+        }
+    }
+
+    /// 負荷制限中か
+    pub fn is_shedding(&self) -> bool {
+        self.shedding_since.is_some()
+    }
+
+    /// 配信を見送ったイベントを記録する
+    pub fn record_shed(&mut self) {
+        self.shed_events += 1;
+    }
+
+    /// 管理者向けの状態
+    ///
+    /// # Arguments
+    /// * `queue_depth` - 配信待ちのイベント数
+    /// * `policy` - 負荷制限のポリシー
+    pub fn status(&self, queue_depth: usize, policy: &LoadSheddingPolicy) -> LoadSheddingStatus {
+        LoadSheddingStatus {
+            enabled: true,
+            shedding: self.is_shedding(),
+            queue_depth,
+            shed_at_depth: Some(policy.shed_at_depth),
+            resume_at_depth: Some(policy.resume_at_depth),
+            shedding_since: self.shedding_since,
+            activations_total: self.activations,
+            shed_events_total: self.shed_events,
+        }
+    }
+}
+
+/// 負荷制限の状態（管理者向けの表示用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LoadSheddingStatus {
+    /// 負荷制限を有効にしているか（無効の場合は配信待ちのイベント数だけを返す）
+    pub enabled: bool,
+    pub shedding: bool,
+    /// 配信待ちのイベント数（同期配信の場合は常に0）
+    pub queue_depth: usize,
+    pub shed_at_depth: Option<usize>,
+    pub resume_at_depth: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shedding_since: Option<DateTime<Utc>>,
+    /// 負荷制限を始めた累計回数
+    pub activations_total: u64,
+    /// 配信を見送った累計数（ハンドラーごと）
+    pub shed_events_total: u64,
+}
+
+impl LoadSheddingStatus {
+    /// 負荷制限を無効にしている場合の状態
+    pub fn disabled(queue_depth: usize) -> Self {
+        Self {
+            enabled: false,
+            shedding: false,
+            queue_depth,
+            shed_at_depth: None,
+            resume_at_depth: None,
+            shedding_since: None,
+            activations_total: 0,
+            shed_events_total: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shedding_starts_at_threshold_and_stops_after_backlog_drains() {
+        let policy = LoadSheddingPolicy {
+            shed_at_depth: 10,
+            resume_at_depth: 3,
+        };
+        let mut shedder = LoadShedder::default();
+        let now = Utc::now();
+
+        assert_eq!(shedder.observe(9, now, &policy), None);
+        assert_eq!(
+            shedder.observe(10, now, &policy),
+            Some(LoadSheddingTransition::Started)
+        );
+        // 再開の閾値を下回るまでは負荷制限を続ける
+        assert_eq!(shedder.observe(5, now, &policy), None);
+        assert!(shedder.is_shedding());
+        shedder.record_shed();
+        assert_eq!(
+            shedder.observe(3, now, &policy),
+            Some(LoadSheddingTransition::Stopped)
+        );
+
+        let status = shedder.status(3, &policy);
+        assert!(!status.shedding);
+        assert_eq!(status.activations_total, 1);
+        assert_eq!(status.shed_events_total, 1);
+    }
+}
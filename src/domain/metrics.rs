@@ -0,0 +1,316 @@
+use crate::domain::model::{Money, OrderId};
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// サーガ完了時間ヒストグラムのバケット上限（秒）
+const SAGA_COMPLETION_BUCKETS_SECONDS: [u64; 6] = [60, 600, 3_600, 86_400, 259_200, 604_800];
+
+/// 時間別集計を保持する期間（時間）
+const HOURLY_RETENTION_HOURS: i64 = 24;
+
+/// ビジネスメトリクス
+/// 注文・在庫予約・サーガに関する業務指標を集計する
+/// 実際の実装では時系列データベース（Prometheusなど）を使用
+#[derive(Clone, Default)]
+pub struct BusinessMetrics {
+    state: Arc<Mutex<MetricsState>>,
+}
+
+#[derive(Default)]
+struct MetricsState {
+    orders_confirmed: u64,
+    orders_cancelled: u64,
+    confirmed_per_hour: BTreeMap<DateTime<Utc>, u64>,
+    cancelled_per_hour: BTreeMap<DateTime<Utc>, u64>,
+    total_order_value: i64,
+    reservations_succeeded: u64,
+    reservations_failed: u64,
+    compensations: u64,
+    saga_started_at: HashMap<OrderId, DateTime<Utc>>,
+    saga_completion_buckets: [u64; SAGA_COMPLETION_BUCKETS_SECONDS.len() + 1],
+    saga_completion_count: u64,
+    saga_completion_total_seconds: u64,
+}
+
+impl MetricsState {
+    /// 保持期間を過ぎた時間別集計を削除
+    fn prune_hourly(&mut self, now: DateTime<Utc>) {
+        let threshold = truncate_to_hour(now) - TimeDelta::hours(HOURLY_RETENTION_HOURS);
+        self.confirmed_per_hour.retain(|hour, _| *hour > threshold);
+        self.cancelled_per_hour.retain(|hour, _| *hour > threshold);
+    }
+}
+
+/// 時刻を時間単位に切り捨てる
+fn truncate_to_hour(at: DateTime<Utc>) -> DateTime<Utc> {
+    at.duration_trunc(TimeDelta::hours(1)).unwrap_or(at)
+}
+
+/// 割合を計算（分母が0の場合は0.0）
+fn ratio(numerator: u64, denominator: u64) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+impl BusinessMetrics {
+    /// 新しいビジネスメトリクスを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注文確定を記録
+    /// サーガの開始時刻として確定日時も保持する
+    pub async fn record_order_confirmed(
+        &self,
+        order_id: OrderId,
+        total_amount: Money,
+        occurred_at: DateTime<Utc>,
+    ) {
+        let mut state = self.state.lock().await;
+        state.orders_confirmed += 1;
+        state.total_order_value += total_amount.amount();
+        *state
+            .confirmed_per_hour
+            .entry(truncate_to_hour(occurred_at))
+            .or_insert(0) += 1;
+        state.saga_started_at.insert(order_id, occurred_at);
+        state.prune_hourly(Utc::now());
+    }
+
+    /// 注文キャンセルを記録
+    pub async fn record_order_cancelled(&self, order_id: OrderId, occurred_at: DateTime<Utc>) {
+        let mut state = self.state.lock().await;
+        state.orders_cancelled += 1;
+        *state
+            .cancelled_per_hour
+            .entry(truncate_to_hour(occurred_at))
+            .or_insert(0) += 1;
+        state.saga_started_at.remove(&order_id);
+        state.prune_hourly(Utc::now());
+    }
+
+    /// 在庫予約成功を記録
+    pub async fn record_reservation_succeeded(&self) {
+        let mut state = self.state.lock().await;
+        state.reservations_succeeded += 1;
+    }
+
+    /// 在庫予約失敗を記録
+    pub async fn record_reservation_failed(&self) {
+        let mut state = self.state.lock().await;
+        state.reservations_failed += 1;
+    }
+
+    /// 補償の発生を記録
+    pub async fn record_compensation(&self) {
+        let mut state = self.state.lock().await;
+        state.compensations += 1;
+    }
+
+    /// 配達完了（サーガ完了）を記録
+    /// 確定日時が記録されている場合はサーガ完了時間をヒストグラムに追加する
+    pub async fn record_order_delivered(&self, order_id: OrderId, occurred_at: DateTime<Utc>) {
+        let mut state = self.state.lock().await;
+        let Some(started_at) = state.saga_started_at.remove(&order_id) else {
+            return;
+        };
+
+        let elapsed_seconds = (occurred_at - started_at).num_seconds().max(0) as u64;
+        let bucket_index = SAGA_COMPLETION_BUCKETS_SECONDS
+            .iter()
+            .position(|upper_bound| elapsed_seconds <= *upper_bound)
+            .unwrap_or(SAGA_COMPLETION_BUCKETS_SECONDS.len());
+        state.saga_completion_buckets[bucket_index] += 1;
+        state.saga_completion_count += 1;
+        state.saga_completion_total_seconds += elapsed_seconds;
+    }
+
+    /// 現在のメトリクスのスナップショットを取得
+    pub async fn snapshot(&self) -> BusinessMetricsSnapshot {
+        let mut state = self.state.lock().await;
+        state.prune_hourly(Utc::now());
+
+        let to_hourly = |counts: &BTreeMap<DateTime<Utc>, u64>| {
+            counts
+                .iter()
+                .map(|(hour, count)| HourlyCount {
+                    hour: hour.to_rfc3339(),
+                    count: *count,
+                })
+                .collect()
+        };
+
+        let mut saga_completion_time_histogram: Vec<HistogramBucket> =
+            SAGA_COMPLETION_BUCKETS_SECONDS
+                .iter()
+                .zip(state.saga_completion_buckets.iter())
+                .map(|(upper_bound, count)| HistogramBucket {
+                    le_seconds: Some(*upper_bound),
+                    count: *count,
+                })
+                .collect();
+        saga_completion_time_histogram.push(HistogramBucket {
+            le_seconds: None,
+            count: state.saga_completion_buckets[SAGA_COMPLETION_BUCKETS_SECONDS.len()],
+        });
+
+        let average_order_value = state
+            .total_order_value
+            .checked_div(state.orders_confirmed as i64)
+            .unwrap_or(0);
+
+        let average_saga_completion_seconds = state
+            .saga_completion_total_seconds
+            .checked_div(state.saga_completion_count)
+            .unwrap_or(0);
+
+        BusinessMetricsSnapshot {
+            orders_confirmed_total: state.orders_confirmed,
+            orders_cancelled_total: state.orders_cancelled,
+            orders_confirmed_per_hour: to_hourly(&state.confirmed_per_hour),
+            orders_cancelled_per_hour: to_hourly(&state.cancelled_per_hour),
+            average_order_value,
+            reservations_succeeded_total: state.reservations_succeeded,
+            reservations_failed_total: state.reservations_failed,
+            compensations_total: state.compensations,
+            reservation_failure_rate: ratio(
+                state.reservations_failed,
+                state.reservations_succeeded + state.reservations_failed,
+            ),
+            compensation_rate: ratio(state.compensations, state.orders_confirmed),
+            active_sagas: state.saga_started_at.len() as u64,
+            saga_completion_count: state.saga_completion_count,
+            average_saga_completion_seconds,
+            saga_completion_time_histogram,
+        }
+    }
+}
+
+/// 時間別の件数
+#[derive(Debug, Clone, Serialize)]
+pub struct HourlyCount {
+    /// 集計対象の時間（時間単位に切り捨てたRFC3339）
+    pub hour: String,
+    /// 件数
+    pub count: u64,
+}
+
+/// ヒストグラムのバケット
+#[derive(Debug, Clone, Serialize)]
+pub struct HistogramBucket {
+    /// バケットの上限（秒）。Noneは上限なし
+    pub le_seconds: Option<u64>,
+    /// バケットに含まれる件数
+    pub count: u64,
+}
+
+/// ビジネスメトリクスのスナップショット
+#[derive(Debug, Clone, Serialize)]
+pub struct BusinessMetricsSnapshot {
+    pub orders_confirmed_total: u64,
+    pub orders_cancelled_total: u64,
+    pub orders_confirmed_per_hour: Vec<HourlyCount>,
+    pub orders_cancelled_per_hour: Vec<HourlyCount>,
+    pub average_order_value: i64,
+    pub reservations_succeeded_total: u64,
+    pub reservations_failed_total: u64,
+    pub compensations_total: u64,
+    pub reservation_failure_rate: f64,
+    pub compensation_rate: f64,
+    pub active_sagas: u64,
+    pub saga_completion_count: u64,
+    pub average_saga_completion_seconds: u64,
+    pub saga_completion_time_histogram: Vec<HistogramBucket>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_average_order_value_and_counts() {
+        let metrics = BusinessMetrics::new();
+        let now = Utc::now();
+
+        metrics
+            .record_order_confirmed(OrderId::new(), Money::jpy(2000), now)
+            .await;
+        metrics
+            .record_order_confirmed(OrderId::new(), Money::jpy(4000), now)
+            .await;
+        metrics.record_order_cancelled(OrderId::new(), now).await;
+
+        let snapshot = metrics.snapshot().await;
+        assert_eq!(snapshot.orders_confirmed_total, 2);
+        assert_eq!(snapshot.orders_cancelled_total, 1);
+        assert_eq!(snapshot.average_order_value, 3000);
+        assert_eq!(snapshot.orders_confirmed_per_hour.len(), 1);
+        assert_eq!(snapshot.orders_confirmed_per_hour[0].count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_reservation_failure_and_compensation_rates() {
+        let metrics = BusinessMetrics::new();
+        let now = Utc::now();
+
+        for _ in 0..4 {
+            metrics
+                .record_order_confirmed(OrderId::new(), Money::jpy(1000), now)
+                .await;
+        }
+        for _ in 0..3 {
+            metrics.record_reservation_succeeded().await;
+        }
+        metrics.record_reservation_failed().await;
+        metrics.record_compensation().await;
+
+        let snapshot = metrics.snapshot().await;
+        assert_eq!(snapshot.reservation_failure_rate, 0.25);
+        assert_eq!(snapshot.compensation_rate, 0.25);
+    }
+
+    #[tokio::test]
+    async fn test_saga_completion_histogram() {
+        let metrics = BusinessMetrics::new();
+        let order_id = OrderId::new();
+        let confirmed_at = Utc::now() - TimeDelta::minutes(30);
+
+        metrics
+            .record_order_confirmed(order_id, Money::jpy(1000), confirmed_at)
+            .await;
+        assert_eq!(metrics.snapshot().await.active_sagas, 1);
+
+        metrics.record_order_delivered(order_id, Utc::now()).await;
+
+        let snapshot = metrics.snapshot().await;
+        assert_eq!(snapshot.active_sagas, 0);
+        assert_eq!(snapshot.saga_completion_count, 1);
+        // 30分 → 3600秒以下のバケット
+        let bucket = snapshot
+            .saga_completion_time_histogram
+            .iter()
+            .find(|bucket| bucket.le_seconds == Some(3_600))
+            .unwrap();
+        assert_eq!(bucket.count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_hourly_counts_outside_retention_are_pruned() {
+        let metrics = BusinessMetrics::new();
+        let old = Utc::now() - TimeDelta::hours(HOURLY_RETENTION_HOURS + 2);
+
+        metrics
+            .record_order_confirmed(OrderId::new(), Money::jpy(1000), old)
+            .await;
+
+        let snapshot = metrics.snapshot().await;
+        assert_eq!(snapshot.orders_confirmed_total, 1);
+        assert!(snapshot.orders_confirmed_per_hour.is_empty());
+    }
+}
@@ -1,12 +1,24 @@
 // ドメインモデル（エンティティと値オブジェクト）
 
+mod allocation_quota;
+mod customer;
+mod cycle_count;
+mod device;
 mod inventory;
 mod order;
 mod value_objects;
 
 pub use value_objects::{
-    BookId, CustomerId, Money, OrderId, OrderLine, OrderStatus, ShippingAddress,
+    BookId, BookSpec, CustomerId, DownloadLink, FulfillmentType, HoldReason, LineAttribute, Money,
+    OrderId, OrderLine, OrderNumber, OrderStatus, Recipient, SalesChannel, ShippingAddress,
+    TrackingToken,
 };
 
-pub use inventory::Inventory;
+pub use allocation_quota::{AllocationQuota, OrderAllocation, MAX_TOTAL_CHANNEL_SHARE};
+pub use customer::{Customer, EmailAddress, MAX_CUSTOMER_NAME_LENGTH};
+pub use cycle_count::{
+    CycleCount, CycleCountId, CycleCountLine, CycleCountStatus, CYCLE_COUNT_ADJUSTMENT_REASON,
+};
+pub use device::{customer_push_topic, DevicePlatform, DeviceRegistration, DeviceToken};
+pub use inventory::{Inventory, MAX_QUANTITY_ON_HAND, RESTOCK_ADJUSTMENT_REASON};
 pub use order::Order;
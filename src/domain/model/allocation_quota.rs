@@ -0,0 +1,358 @@
+use crate::domain::error::DomainError;
+use crate::domain::glossary::domain_model;
+use crate::domain::model::{BookId, CustomerId, OrderId, SalesChannel};
+use std::collections::{BTreeMap, HashMap};
+
+/// 販売チャネルの割当比率（%）の合計の上限
+pub const MAX_TOTAL_CHANNEL_SHARE: u32 = 100;
+
+/// 割当枠集約
+/// 限定版などの書籍について、顧客ごとの購入上限と販売チャネル別の割当枠を管理する
+/// 割当は在庫の予約時に注文ごとに記録し、注文のキャンセルや予約した在庫の解放で在庫と同じく戻す
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllocationQuota {
+    book_id: BookId,
+    /// 1顧客が購入できる最大数量（Noneの場合は顧客ごとに制限しない）
+    per_customer_cap: Option<u32>,
+    /// 販売チャネルごとの割当比率（%、空の場合はチャネルで制限しない。設定のないチャネルには割り当てない）
+    channel_shares: BTreeMap<SalesChannel, u32>,
+    /// 割当比率をかける数量（割当枠を設定したときの在庫数）
+    allocation_pool: u32,
+    /// 販売チャネルごとの割当済みの数量
+    channel_allocated: BTreeMap<SalesChannel, u32>,
+    /// 顧客ごとの割当済みの数量
+    customer_allocated: HashMap<CustomerId, u32>,
+    /// 注文ごとの割当（割当を戻すときに顧客と販売チャネルの数量から差し引く）
+    order_allocations: HashMap<OrderId, OrderAllocation>,
+}
+
+/// 注文に割り当てた数量
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderAllocation {
+    pub customer_id: CustomerId,
+    pub channel: SalesChannel,
+    pub quantity: u32,
+}
+
+domain_model!(
+    AllocationQuota,
+    Aggregate,
+    "限定版などの書籍の顧客ごとの購入上限と販売チャネル別の割当枠を管理する",
+    related = [BookId, CustomerId, SalesChannel]
+);
+
+impl AllocationQuota {
+    /// 新しい割当枠を作成
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `per_customer_cap` - 1顧客が購入できる最大数量（Noneの場合は制限しない）
+    /// * `channel_shares` - 販売チャネルごとの割当比率（%）
+    /// * `allocation_pool` - 割当比率をかける数量
+    ///
+    /// # Returns
+    /// * `Ok(AllocationQuota)` - 作成成功
+    /// * `Err(DomainError::InvalidValue)` - 上限が0、比率が0または合計が100%を超える、制限が何もない
+    pub fn new(
+        book_id: BookId,
+        per_customer_cap: Option<u32>,
+        channel_shares: BTreeMap<SalesChannel, u32>,
+        allocation_pool: u32,
+    ) -> Result<Self, DomainError> {
+        let mut quota = Self {
+            book_id,
+            per_customer_cap: None,
+            channel_shares: BTreeMap::new(),
+            allocation_pool: 0,
+            channel_allocated: BTreeMap::new(),
+            customer_allocated: HashMap::new(),
+            order_allocations: HashMap::new(),
+        };
+        quota.reconfigure(per_customer_cap, channel_shares, allocation_pool)?;
+        Ok(quota)
+    }
+
+    /// 永続化された割当済みの数量を設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_allocations(
+        mut self,
+        channel_allocated: BTreeMap<SalesChannel, u32>,
+        customer_allocated: HashMap<CustomerId, u32>,
+        order_allocations: HashMap<OrderId, OrderAllocation>,
+    ) -> Self {
+        self.channel_allocated = channel_allocated;
+        self.customer_allocated = customer_allocated;
+        self.order_allocations = order_allocations;
+        self
+    }
+
+    /// 割当枠の設定を変更する（割当済みの数量は引き継ぐ）
+    ///
+    /// # Arguments
+    /// * `per_customer_cap` - 1顧客が購入できる最大数量（Noneの場合は制限しない）
+    /// * `channel_shares` - 販売チャネルごとの割当比率（%）
+    /// * `allocation_pool` - 割当比率をかける数量
+    pub fn reconfigure(
+        &mut self,
+        per_customer_cap: Option<u32>,
+        channel_shares: BTreeMap<SalesChannel, u32>,
+        allocation_pool: u32,
+    ) -> Result<(), DomainError> {
+        if per_customer_cap == Some(0) {
+            return Err(DomainError::InvalidValue(
+                "顧客ごとの購入上限は1以上を指定してください".to_string(),
+            ));
+        }
+        if let Some((channel, _)) = channel_shares.iter().find(|(_, share)| **share == 0) {
+            return Err(DomainError::InvalidValue(format!(
+                "{}の割当比率は1%以上を指定してください",
+                channel
+            )));
+        }
+        let total_share: u32 = channel_shares.values().sum();
+        if total_share > MAX_TOTAL_CHANNEL_SHARE {
+            return Err(DomainError::InvalidValue(format!(
+                "販売チャネルの割当比率の合計は{}%以内で指定してください（指定: {}%）",
+                MAX_TOTAL_CHANNEL_SHARE, total_share
+            )));
+        }
+        if per_customer_cap.is_none() && channel_shares.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "顧客ごとの購入上限か販売チャネルの割当比率を指定してください".to_string(),
+            ));
+        }
+
+        self.per_customer_cap = per_customer_cap;
+        self.channel_shares = channel_shares;
+        self.allocation_pool = allocation_pool;
+        Ok(())
+    }
+
+    /// 書籍IDを取得
+    pub fn book_id(&self) -> BookId {
+        self.book_id
+    }
+
+    /// 顧客ごとの購入上限を取得
+    pub fn per_customer_cap(&self) -> Option<u32> {
+        self.per_customer_cap
+    }
+
+    /// 販売チャネルごとの割当比率を取得
+    pub fn channel_shares(&self) -> &BTreeMap<SalesChannel, u32> {
+        &self.channel_shares
+    }
+
+    /// 割当比率をかける数量を取得
+    pub fn allocation_pool(&self) -> u32 {
+        self.allocation_pool
+    }
+
+    /// 販売チャネルごとの割当済みの数量を取得
+    pub fn channel_allocated(&self) -> &BTreeMap<SalesChannel, u32> {
+        &self.channel_allocated
+    }
+
+    /// 顧客ごとの割当済みの数量を取得
+    pub fn customer_allocated(&self) -> &HashMap<CustomerId, u32> {
+        &self.customer_allocated
+    }
+
+    /// 注文ごとの割当を取得
+    pub fn order_allocations(&self) -> &HashMap<OrderId, OrderAllocation> {
+        &self.order_allocations
+    }
+
+    /// 販売チャネルに割り当てられる数量（端数は切り捨て。チャネルで制限しない場合はNone）
+    pub fn channel_limit(&self, channel: SalesChannel) -> Option<u32> {
+        if self.channel_shares.is_empty() {
+            return None;
+        }
+        let share = self.channel_shares.get(&channel).copied().unwrap_or(0);
+        Some((u64::from(self.allocation_pool) * u64::from(share) / 100) as u32)
+    }
+
+    /// 割当枠の範囲で予約できるか判定する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 購入する顧客
+    /// * `channel` - 注文を受け付けた販売チャネル
+    /// * `quantity` - 予約する数量
+    ///
+    /// # Returns
+    /// * `Ok(())` - 予約できる
+    /// * `Err(DomainError::QuotaExceeded)` - 顧客の購入上限または販売チャネルの割当枠を超える
+    pub fn ensure_can_allocate(
+        &self,
+        customer_id: CustomerId,
+        channel: SalesChannel,
+        quantity: u32,
+    ) -> Result<(), DomainError> {
+        if let Some(cap) = self.per_customer_cap {
+            let allocated = self.customer_allocated.get(&customer_id).copied().unwrap_or(0);
+            if allocated.saturating_add(quantity) > cap {
+                return Err(DomainError::QuotaExceeded(format!(
+                    "書籍 {} の1顧客あたりの購入上限（{}冊）を超えています（購入済み: {}冊, 追加: {}冊）",
+                    self.book_id, cap, allocated, quantity
+                )));
+            }
+        }
+        if let Some(limit) = self.channel_limit(channel) {
+            let allocated = self.channel_allocated.get(&channel).copied().unwrap_or(0);
+            if allocated.saturating_add(quantity) > limit {
+                return Err(DomainError::QuotaExceeded(format!(
+                    "書籍 {} の{}の割当枠（{}冊）を超えています（割当済み: {}冊, 追加: {}冊）",
+                    self.book_id, channel, limit, allocated, quantity
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// 割当枠から注文で予約した数量を割り当てる
+    ///
+    /// # Arguments
+    /// * `order_id` - 予約した注文
+    /// * `customer_id` - 購入する顧客
+    /// * `channel` - 注文を受け付けた販売チャネル
+    /// * `quantity` - 予約する数量
+    ///
+    /// # Returns
+    /// * `Ok(())` - 割当成功
+    /// * `Err(DomainError::QuotaExceeded)` - 顧客の購入上限または販売チャネルの割当枠を超える
+    pub fn allocate(
+        &mut self,
+        order_id: OrderId,
+        customer_id: CustomerId,
+        channel: SalesChannel,
+        quantity: u32,
+    ) -> Result<(), DomainError> {
+        self.ensure_can_allocate(customer_id, channel, quantity)?;
+        *self.customer_allocated.entry(customer_id).or_insert(0) += quantity;
+        *self.channel_allocated.entry(channel).or_insert(0) += quantity;
+        self.order_allocations
+            .entry(order_id)
+            .and_modify(|allocation| allocation.quantity += quantity)
+            .or_insert(OrderAllocation {
+                customer_id,
+                channel,
+                quantity,
+            });
+        Ok(())
+    }
+
+    /// 注文に割り当てた数量を割当枠に戻す
+    ///
+    /// # Arguments
+    /// * `order_id` - キャンセルした・在庫を解放した注文
+    ///
+    /// # Returns
+    /// * `true` - 割当を戻した
+    /// * `false` - 注文に割り当てた数量がない（割り当てていない・戻し済み）
+    pub fn release_order(&mut self, order_id: OrderId) -> bool {
+        let Some(allocation) = self.order_allocations.remove(&order_id) else {
+            return false;
+        };
+        if let Some(allocated) = self.customer_allocated.get_mut(&allocation.customer_id) {
+            *allocated = allocated.saturating_sub(allocation.quantity);
+            if *allocated == 0 {
+                self.customer_allocated.remove(&allocation.customer_id);
+            }
+        }
+        if let Some(allocated) = self.channel_allocated.get_mut(&allocation.channel) {
+            *allocated = allocated.saturating_sub(allocation.quantity);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limited_edition() -> AllocationQuota {
+        AllocationQuota::new(
+            BookId::new(),
+            Some(2),
+            BTreeMap::from([(SalesChannel::Online, 70), (SalesChannel::InStore, 30)]),
+            10,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_allocate_enforces_customer_cap_and_channel_quota() {
+        let mut quota = limited_edition();
+        assert_eq!(quota.channel_limit(SalesChannel::Online), Some(7));
+        assert_eq!(quota.channel_limit(SalesChannel::InStore), Some(3));
+
+        let customer = CustomerId::new();
+        quota.allocate(OrderId::new(), customer, SalesChannel::Online, 2).unwrap();
+        assert!(matches!(
+            quota.allocate(OrderId::new(), customer, SalesChannel::InStore, 1),
+            Err(DomainError::QuotaExceeded(_))
+        ));
+
+        // 店頭の割当枠（3冊）を使い切ると、別の顧客でも店頭では予約できない
+        quota.allocate(OrderId::new(), CustomerId::new(), SalesChannel::InStore, 2).unwrap();
+        quota.allocate(OrderId::new(), CustomerId::new(), SalesChannel::InStore, 1).unwrap();
+        assert!(matches!(
+            quota.allocate(OrderId::new(), CustomerId::new(), SalesChannel::InStore, 1),
+            Err(DomainError::QuotaExceeded(_))
+        ));
+        assert!(quota.allocate(OrderId::new(), CustomerId::new(), SalesChannel::Online, 1).is_ok());
+
+        assert_eq!(quota.channel_allocated()[&SalesChannel::InStore], 3);
+        assert_eq!(quota.customer_allocated()[&customer], 2);
+    }
+
+    #[test]
+    fn test_release_order_returns_the_order_allocation_once() {
+        let mut quota = limited_edition();
+        let customer = CustomerId::new();
+        let order_id = OrderId::new();
+        quota.allocate(order_id, customer, SalesChannel::InStore, 2).unwrap();
+        quota.allocate(OrderId::new(), CustomerId::new(), SalesChannel::InStore, 1).unwrap();
+        assert!(matches!(
+            quota.allocate(OrderId::new(), CustomerId::new(), SalesChannel::InStore, 1),
+            Err(DomainError::QuotaExceeded(_))
+        ));
+
+        assert!(quota.release_order(order_id));
+        assert!(!quota.release_order(order_id));
+        assert_eq!(quota.channel_allocated()[&SalesChannel::InStore], 1);
+        assert!(!quota.customer_allocated().contains_key(&customer));
+        // 戻した数量は別の注文で割り当てられる
+        quota.allocate(OrderId::new(), customer, SalesChannel::InStore, 2).unwrap();
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_configuration() {
+        let book_id = BookId::new();
+        assert!(AllocationQuota::new(book_id, None, BTreeMap::new(), 10).is_err());
+        assert!(AllocationQuota::new(book_id, Some(0), BTreeMap::new(), 10).is_err());
+        assert!(AllocationQuota::new(
+            book_id,
+            None,
+            BTreeMap::from([(SalesChannel::Online, 80), (SalesChannel::InStore, 30)]),
+            10
+        )
+        .is_err());
+
+        // 割当比率のないチャネルには割り当てない
+        let quota = AllocationQuota::new(
+            book_id,
+            None,
+            BTreeMap::from([(SalesChannel::Online, 100)]),
+            10,
+        )
+        .unwrap();
+        assert_eq!(quota.channel_limit(SalesChannel::InStore), Some(0));
+        assert_eq!(
+            AllocationQuota::new(book_id, Some(1), BTreeMap::new(), 10)
+                .unwrap()
+                .channel_limit(SalesChannel::Online),
+            None
+        );
+    }
+}
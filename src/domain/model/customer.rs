@@ -0,0 +1,241 @@
+use crate::domain::book_translation::Language;
+use crate::domain::error::DomainError;
+use crate::domain::glossary::domain_model;
+use crate::domain::model::{CustomerId, ShippingAddress};
+use crate::domain::validation::{Constraint, FieldViolation};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 顧客の氏名の最大文字数
+pub const MAX_CUSTOMER_NAME_LENGTH: usize = 100;
+
+/// メールアドレスの最大長
+const MAX_EMAIL_LENGTH: usize = 254;
+
+/// メールアドレスを表す値オブジェクト
+/// 大文字・小文字の違いで別の顧客として登録されないよう、小文字に正規化して保持する
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EmailAddress(String);
+
+domain_model!(
+    EmailAddress,
+    ValueObject,
+    "顧客のメールアドレス（小文字に正規化して比較する）"
+);
+
+impl EmailAddress {
+    /// メールアドレスを作成
+    /// バリデーション:
+    /// - 前後の空白を除いて254文字以内で、空白を含まない
+    /// - `@` を1つだけ含み、その前後が空でなく、ドメインに `.` を含む
+    pub fn new(email: String) -> Result<Self, DomainError> {
+        let normalized = email.trim().to_lowercase();
+        if !Self::is_valid(&normalized) {
+            return Err(FieldViolation::new(
+                "email",
+                Constraint::Pattern,
+                Some(email),
+                "メールアドレスの形式が正しくありません",
+            )
+            .into());
+        }
+        Ok(Self(normalized))
+    }
+
+    fn is_valid(email: &str) -> bool {
+        if email.len() > MAX_EMAIL_LENGTH || email.chars().any(char::is_whitespace) {
+            return false;
+        }
+        let Some((local, domain)) = email.split_once('@') else {
+            return false;
+        };
+        !local.is_empty()
+            && !domain.contains('@')
+            && domain.contains('.')
+            && domain.split('.').all(|label| !label.is_empty())
+    }
+
+    /// メールアドレスの文字列を取得
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// 顧客
+/// 注文は登録済みの顧客を参照し、注文の作成時に既定の配送先住所を引き継ぐ
+#[derive(Debug, Clone, PartialEq)]
+pub struct Customer {
+    id: CustomerId,
+    name: String,
+    email: EmailAddress,
+    default_shipping_address: Option<ShippingAddress>,
+    /// 通知を送る言語
+    preferred_language: Language,
+    registered_at: DateTime<Utc>,
+}
+
+domain_model!(
+    Customer,
+    Aggregate,
+    "顧客の氏名・メールアドレス・既定の配送先住所・通知の言語を管理する",
+    related = [CustomerId, EmailAddress, ShippingAddress]
+);
+
+impl Customer {
+    /// 新しい顧客を登録
+    /// 氏名は前後の空白を除いて1〜100文字である必要がある
+    /// 通知の言語は既定の言語（日本語）で登録する
+    pub fn register(
+        id: CustomerId,
+        name: String,
+        email: EmailAddress,
+        default_shipping_address: Option<ShippingAddress>,
+        registered_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(FieldViolation::new(
+                "name",
+                Constraint::Required,
+                None,
+                "顧客の氏名は空にできません",
+            )
+            .into());
+        }
+        if name.chars().count() > MAX_CUSTOMER_NAME_LENGTH {
+            return Err(FieldViolation::new(
+                "name",
+                Constraint::Pattern,
+                Some(name),
+                format!(
+                    "顧客の氏名は{}文字以内で指定してください",
+                    MAX_CUSTOMER_NAME_LENGTH
+                ),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            id,
+            name,
+            email,
+            default_shipping_address,
+            preferred_language: Language::DEFAULT,
+            registered_at,
+        })
+    }
+
+    /// 永続化されたデータから顧客を再構築
+    pub fn reconstruct(
+        id: CustomerId,
+        name: String,
+        email: EmailAddress,
+        default_shipping_address: Option<ShippingAddress>,
+        preferred_language: Language,
+        registered_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            email,
+            default_shipping_address,
+            preferred_language,
+            registered_at,
+        }
+    }
+
+    /// 通知を送る言語を変更
+    pub fn change_preferred_language(&mut self, language: Language) {
+        self.preferred_language = language;
+    }
+
+    /// 顧客IDを取得
+    pub fn id(&self) -> CustomerId {
+        self.id
+    }
+
+    /// 氏名を取得
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// メールアドレスを取得
+    pub fn email(&self) -> &EmailAddress {
+        &self.email
+    }
+
+    /// 既定の配送先住所を取得
+    pub fn default_shipping_address(&self) -> Option<&ShippingAddress> {
+        self.default_shipping_address.as_ref()
+    }
+
+    /// 通知を送る言語を取得
+    pub fn preferred_language(&self) -> Language {
+        self.preferred_language
+    }
+
+    /// 登録日時を取得
+    pub fn registered_at(&self) -> DateTime<Utc> {
+        self.registered_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_normalizes_email_and_validates_name() {
+        let email = EmailAddress::new(" Hanako.Yamada@Example.COM ".to_string()).unwrap();
+        assert_eq!(email.as_str(), "hanako.yamada@example.com");
+        for invalid in [
+            "",
+            "no-at-sign",
+            "a@b",
+            "a@@example.com",
+            "a b@example.com",
+            "@example.com",
+        ] {
+            assert!(
+                EmailAddress::new(invalid.to_string()).is_err(),
+                "{}",
+                invalid
+            );
+        }
+
+        let customer = Customer::register(
+            CustomerId::new(),
+            " 山田花子 ".to_string(),
+            email.clone(),
+            None,
+            Utc::now(),
+        )
+        .unwrap();
+        assert_eq!(customer.name(), "山田花子");
+        assert!(customer.default_shipping_address().is_none());
+
+        assert!(Customer::register(
+            CustomerId::new(),
+            "  ".to_string(),
+            email.clone(),
+            None,
+            Utc::now()
+        )
+        .is_err());
+        assert!(Customer::register(
+            CustomerId::new(),
+            "あ".repeat(MAX_CUSTOMER_NAME_LENGTH + 1),
+            email,
+            None,
+            Utc::now()
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,380 @@
+use crate::domain::error::DomainError;
+use crate::domain::glossary::domain_model;
+use crate::domain::model::BookId;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+/// 棚卸しによる在庫調整の理由
+pub const CYCLE_COUNT_ADJUSTMENT_REASON: &str = "cycle_count";
+
+/// 棚卸しセッションの一意識別子
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CycleCountId(Uuid);
+
+domain_model!(CycleCountId, ValueObject, "棚卸しの一意識別子");
+
+impl CycleCountId {
+    /// 新しい一意のCycleCountIdを生成
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// UUIDからCycleCountIdを作成
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// 文字列からCycleCountIdを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|e| DomainError::InvalidValue(format!("無効な棚卸しID: {}", e)))
+    }
+
+    /// 内部のUUIDを取得
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for CycleCountId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for CycleCountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// 棚卸しセッションのステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CycleCountStatus {
+    /// 実数を記録中
+    Open,
+    /// 差異を算出し、承認待ち
+    Submitted,
+    /// 承認済み（在庫調整を適用済み）
+    Approved,
+    /// 却下
+    Rejected,
+}
+
+impl fmt::Display for CycleCountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            CycleCountStatus::Open => "Open",
+            CycleCountStatus::Submitted => "Submitted",
+            CycleCountStatus::Approved => "Approved",
+            CycleCountStatus::Rejected => "Rejected",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+impl CycleCountStatus {
+    /// 文字列からCycleCountStatusを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Open" => Ok(CycleCountStatus::Open),
+            "Submitted" => Ok(CycleCountStatus::Submitted),
+            "Approved" => Ok(CycleCountStatus::Approved),
+            "Rejected" => Ok(CycleCountStatus::Rejected),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な棚卸しステータス: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 棚卸しの明細（書籍ごとの実数とシステム在庫）
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleCountLine {
+    book_id: BookId,
+    counted_quantity: Option<u32>,
+    system_quantity: Option<u32>,
+}
+
+domain_model!(CycleCountLine, Entity, "棚卸し対象の書籍ごとのシステム在庫数と実数", related = [BookId]);
+
+impl CycleCountLine {
+    /// 永続化されたデータから明細を再構築
+    pub fn reconstruct(
+        book_id: BookId,
+        counted_quantity: Option<u32>,
+        system_quantity: Option<u32>,
+    ) -> Self {
+        Self {
+            book_id,
+            counted_quantity,
+            system_quantity,
+        }
+    }
+
+    /// 書籍IDを取得
+    pub fn book_id(&self) -> BookId {
+        self.book_id
+    }
+
+    /// 実数（実際に数えた数量）を取得
+    pub fn counted_quantity(&self) -> Option<u32> {
+        self.counted_quantity
+    }
+
+    /// 提出時点のシステム在庫数を取得
+    pub fn system_quantity(&self) -> Option<u32> {
+        self.system_quantity
+    }
+
+    /// 差異（実数 - システム在庫数）を取得
+    /// 提出前は算出されていないためNone
+    pub fn variance(&self) -> Option<i64> {
+        match (self.counted_quantity, self.system_quantity) {
+            (Some(counted), Some(system)) => Some(i64::from(counted) - i64::from(system)),
+            _ => None,
+        }
+    }
+}
+
+/// 棚卸し集約
+/// 実地棚卸しの実数を記録し、システム在庫との差異を承認してから在庫に反映する
+#[derive(Debug, Clone)]
+pub struct CycleCount {
+    id: CycleCountId,
+    status: CycleCountStatus,
+    lines: Vec<CycleCountLine>,
+    approved_by: Option<String>,
+}
+
+domain_model!(
+    CycleCount,
+    Aggregate,
+    "実地棚卸しの記録・提出・承認と、在庫との差異の算出を管理する",
+    related = [CycleCountId, CycleCountLine]
+);
+
+impl CycleCount {
+    /// 新しい棚卸しセッションを作成
+    /// 対象の書籍は1冊以上必要（重複は除外）
+    pub fn new(id: CycleCountId, book_ids: Vec<BookId>) -> Result<Self, DomainError> {
+        let mut lines: Vec<CycleCountLine> = Vec::new();
+        for book_id in book_ids {
+            if !lines.iter().any(|line| line.book_id == book_id) {
+                lines.push(CycleCountLine::reconstruct(book_id, None, None));
+            }
+        }
+
+        if lines.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "棚卸しの対象となる書籍がありません".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            id,
+            status: CycleCountStatus::Open,
+            lines,
+            approved_by: None,
+        })
+    }
+
+    /// 永続化されたデータから棚卸しセッションを再構築
+    pub fn reconstruct(
+        id: CycleCountId,
+        status: CycleCountStatus,
+        lines: Vec<CycleCountLine>,
+        approved_by: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            status,
+            lines,
+            approved_by,
+        }
+    }
+
+    /// 棚卸しIDを取得
+    pub fn id(&self) -> CycleCountId {
+        self.id
+    }
+
+    /// ステータスを取得
+    pub fn status(&self) -> CycleCountStatus {
+        self.status
+    }
+
+    /// 明細を取得
+    pub fn lines(&self) -> &[CycleCountLine] {
+        &self.lines
+    }
+
+    /// 承認者を取得
+    pub fn approved_by(&self) -> Option<&str> {
+        self.approved_by.as_deref()
+    }
+
+    /// 書籍の実数を記録する（同じ書籍は上書き）
+    /// 事前条件:
+    /// - ステータスがOpen
+    /// - 対象の書籍が棚卸しに含まれている
+    pub fn record_count(
+        &mut self,
+        book_id: BookId,
+        counted_quantity: u32,
+    ) -> Result<(), DomainError> {
+        self.ensure_status(
+            CycleCountStatus::Open,
+            "実数を記録できるのはOpen状態のみです",
+        )?;
+
+        let line = self
+            .lines
+            .iter_mut()
+            .find(|line| line.book_id == book_id)
+            .ok_or_else(|| {
+                DomainError::InvalidValue(format!("棚卸しの対象ではない書籍です: {}", book_id))
+            })?;
+        line.counted_quantity = Some(counted_quantity);
+
+        Ok(())
+    }
+
+    /// 実数を提出し、システム在庫との差異を算出する
+    /// 事前条件:
+    /// - ステータスがOpen
+    /// - すべての明細の実数が記録済み
+    ///
+    /// # Arguments
+    /// * `system_quantities` - 書籍ごとの現在のシステム在庫数（存在しない書籍は0とみなす）
+    pub fn submit(&mut self, system_quantities: &HashMap<BookId, u32>) -> Result<(), DomainError> {
+        self.ensure_status(CycleCountStatus::Open, "提出できるのはOpen状態のみです")?;
+
+        if let Some(line) = self
+            .lines
+            .iter()
+            .find(|line| line.counted_quantity.is_none())
+        {
+            return Err(DomainError::InvalidValue(format!(
+                "実数が記録されていない書籍があります: {}",
+                line.book_id
+            )));
+        }
+
+        for line in &mut self.lines {
+            line.system_quantity = Some(system_quantities.get(&line.book_id).copied().unwrap_or(0));
+        }
+        self.status = CycleCountStatus::Submitted;
+
+        Ok(())
+    }
+
+    /// 差異を承認する
+    /// 事前条件:
+    /// - ステータスがSubmitted
+    /// - 承認者が指定されている
+    pub fn approve(&mut self, approved_by: String) -> Result<(), DomainError> {
+        self.ensure_status(
+            CycleCountStatus::Submitted,
+            "承認できるのはSubmitted状態のみです",
+        )?;
+
+        if approved_by.trim().is_empty() {
+            return Err(DomainError::InvalidValue(
+                "承認者を指定してください".to_string(),
+            ));
+        }
+
+        self.approved_by = Some(approved_by);
+        self.status = CycleCountStatus::Approved;
+
+        Ok(())
+    }
+
+    /// 棚卸しを却下する（在庫は調整しない）
+    /// 事前条件:
+    /// - ステータスがOpenまたはSubmitted
+    pub fn reject(&mut self) -> Result<(), DomainError> {
+        match self.status {
+            CycleCountStatus::Open | CycleCountStatus::Submitted => {
+                self.status = CycleCountStatus::Rejected;
+                Ok(())
+            }
+            CycleCountStatus::Approved | CycleCountStatus::Rejected => {
+                Err(DomainError::InvalidCycleCountState(
+                    "承認済みまたは却下済みの棚卸しは却下できません".to_string(),
+                ))
+            }
+        }
+    }
+
+    /// 在庫に反映すべき調整（差異が0でない書籍と差異）
+    pub fn adjustments(&self) -> Vec<(BookId, i64)> {
+        self.lines
+            .iter()
+            .filter_map(|line| line.variance().map(|variance| (line.book_id, variance)))
+            .filter(|(_, variance)| *variance != 0)
+            .collect()
+    }
+
+    fn ensure_status(&self, expected: CycleCountStatus, message: &str) -> Result<(), DomainError> {
+        if self.status != expected {
+            return Err(DomainError::InvalidCycleCountState(message.to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_count_workflow_computes_variances() {
+        let book_a = BookId::new();
+        let book_b = BookId::new();
+        let mut cycle_count =
+            CycleCount::new(CycleCountId::new(), vec![book_a, book_b, book_a]).unwrap();
+        assert_eq!(cycle_count.lines().len(), 2);
+
+        cycle_count.record_count(book_a, 8).unwrap();
+        // すべての実数が記録されるまで提出できない
+        assert!(cycle_count.submit(&HashMap::new()).is_err());
+        cycle_count.record_count(book_b, 5).unwrap();
+
+        let system_quantities = HashMap::from([(book_a, 10), (book_b, 5)]);
+        cycle_count.submit(&system_quantities).unwrap();
+        assert_eq!(cycle_count.status(), CycleCountStatus::Submitted);
+        assert_eq!(cycle_count.lines()[0].variance(), Some(-2));
+        assert_eq!(cycle_count.lines()[1].variance(), Some(0));
+
+        // 提出後は実数を変更できない
+        assert!(cycle_count.record_count(book_a, 9).is_err());
+
+        // 差異のある書籍のみ調整対象
+        assert_eq!(cycle_count.adjustments(), vec![(book_a, -2)]);
+
+        assert!(cycle_count.approve("  ".to_string()).is_err());
+        cycle_count.approve("manager".to_string()).unwrap();
+        assert_eq!(cycle_count.status(), CycleCountStatus::Approved);
+        assert_eq!(cycle_count.approved_by(), Some("manager"));
+        assert!(cycle_count.reject().is_err());
+    }
+
+    #[test]
+    fn test_cycle_count_requires_books_and_rejects_unknown_book() {
+        assert!(CycleCount::new(CycleCountId::new(), vec![]).is_err());
+
+        let mut cycle_count = CycleCount::new(CycleCountId::new(), vec![BookId::new()]).unwrap();
+        assert!(cycle_count.record_count(BookId::new(), 1).is_err());
+        // 提出前は承認できない
+        assert!(cycle_count.approve("manager".to_string()).is_err());
+
+        cycle_count.reject().unwrap();
+        assert_eq!(cycle_count.status(), CycleCountStatus::Rejected);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::domain::error::DomainError;
+use crate::domain::glossary::domain_model;
+use crate::domain::model::CustomerId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// デバイストークンの最大長
+const MAX_DEVICE_TOKEN_LENGTH: usize = 512;
+
+/// プッシュ通知を受け取るデバイスのプラットフォーム
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DevicePlatform {
+    Ios,
+    Android,
+    Web,
+}
+
+domain_model!(DevicePlatform, ValueObject, "プッシュ通知を受け取るデバイスのプラットフォーム");
+
+impl fmt::Display for DevicePlatform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let platform_str = match self {
+            DevicePlatform::Ios => "Ios",
+            DevicePlatform::Android => "Android",
+            DevicePlatform::Web => "Web",
+        };
+        write!(f, "{}", platform_str)
+    }
+}
+
+impl DevicePlatform {
+    /// 文字列からDevicePlatformを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Ios" => Ok(DevicePlatform::Ios),
+            "Android" => Ok(DevicePlatform::Android),
+            "Web" => Ok(DevicePlatform::Web),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効なデバイスプラットフォーム: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// プッシュ通知サービスが発行したデバイストークン
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DeviceToken(String);
+
+domain_model!(DeviceToken, ValueObject, "プッシュ通知の配信先のデバイストークン");
+
+impl DeviceToken {
+    /// デバイストークンを作成
+    /// 空白を含まない、1〜512文字の文字列である必要がある
+    pub fn new(token: String) -> Result<Self, DomainError> {
+        if token.is_empty() || token.len() > MAX_DEVICE_TOKEN_LENGTH {
+            return Err(DomainError::InvalidValue(format!(
+                "デバイストークンは1〜{}文字で指定してください",
+                MAX_DEVICE_TOKEN_LENGTH
+            )));
+        }
+        if token.chars().any(char::is_whitespace) {
+            return Err(DomainError::InvalidValue(
+                "デバイストークンに空白を含めることはできません".to_string(),
+            ));
+        }
+        Ok(Self(token))
+    }
+
+    /// トークン文字列を取得
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for DeviceToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// 顧客のデバイス登録
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceRegistration {
+    customer_id: CustomerId,
+    token: DeviceToken,
+    platform: DevicePlatform,
+}
+
+domain_model!(
+    DeviceRegistration,
+    Aggregate,
+    "顧客のデバイスの登録と、顧客トピックへの購読を管理する",
+    related = [CustomerId, DeviceToken, DevicePlatform]
+);
+
+impl DeviceRegistration {
+    /// 新しいデバイス登録を作成
+    pub fn new(customer_id: CustomerId, token: DeviceToken, platform: DevicePlatform) -> Self {
+        Self {
+            customer_id,
+            token,
+            platform,
+        }
+    }
+
+    /// 顧客IDを取得
+    pub fn customer_id(&self) -> CustomerId {
+        self.customer_id
+    }
+
+    /// デバイストークンを取得
+    pub fn token(&self) -> &DeviceToken {
+        &self.token
+    }
+
+    /// プラットフォームを取得
+    pub fn platform(&self) -> DevicePlatform {
+        self.platform
+    }
+
+    /// 顧客ごとのプッシュ通知トピック名
+    pub fn topic(&self) -> String {
+        customer_push_topic(self.customer_id)
+    }
+}
+
+/// 顧客ごとのプッシュ通知トピック名を取得
+/// トピック名に使用できる文字（英数字・`-`・`_`）のみで構成する
+pub fn customer_push_topic(customer_id: CustomerId) -> String {
+    format!("customer_{}", customer_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_token_validation() {
+        assert!(DeviceToken::new("fcm-token_123:abc".to_string()).is_ok());
+        assert!(DeviceToken::new(String::new()).is_err());
+        assert!(DeviceToken::new("has space".to_string()).is_err());
+        assert!(DeviceToken::new("a".repeat(MAX_DEVICE_TOKEN_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_registration_topic_is_per_customer() {
+        let customer_id = CustomerId::new();
+        let registration = DeviceRegistration::new(
+            customer_id,
+            DeviceToken::new("token".to_string()).unwrap(),
+            DevicePlatform::Android,
+        );
+
+        assert_eq!(registration.topic(), format!("customer_{}", customer_id));
+    }
+}
@@ -1,5 +1,15 @@
 use crate::domain::error::DomainError;
+use crate::domain::glossary::domain_model;
+use crate::domain::invariant::InvariantViolation;
 use crate::domain::model::BookId;
+use chrono::NaiveDate;
+
+/// 入荷による在庫調整の理由（InventoryAdjustedイベントのreason）
+pub const RESTOCK_ADJUSTMENT_REASON: &str = "restock";
+
+/// 在庫数として妥当な上限
+/// これを超える在庫数は、符号なしの在庫数が負に回り込んだ（実質的に負の在庫）とみなす
+pub const MAX_QUANTITY_ON_HAND: u32 = i32::MAX as u32;
 
 /// 在庫集約
 /// 書籍の在庫数を管理する
@@ -7,8 +17,21 @@ use crate::domain::model::BookId;
 pub struct Inventory {
     book_id: BookId,
     quantity_on_hand: u32,
+    /// 発売日（未設定の場合は発売済みとして扱う）
+    release_date: Option<NaiveDate>,
+    /// 凍結中かどうか（在庫数の不整合を調査している間は新たな予約を受け付けない）
+    frozen: bool,
+    /// 順番待ちを有効にするかどうか（人気の新刊など、在庫を超える注文を先着順で待たせる書籍）
+    waitlist_enabled: bool,
 }
 
+domain_model!(
+    Inventory,
+    Aggregate,
+    "書籍ごとの在庫数・予約数と、負の在庫を防ぐ保護・順番待ちを管理する",
+    related = [BookId]
+);
+
 impl Inventory {
     /// 新しい在庫を作成
     ///
@@ -19,9 +42,39 @@ impl Inventory {
         Self {
             book_id,
             quantity_on_hand,
+            release_date: None,
+            frozen: false,
+            waitlist_enabled: false,
         }
     }
 
+    /// 発売日を設定した在庫を作成
+    ///
+    /// # Arguments
+    /// * `release_date` - 発売日（Noneの場合は発売済み）
+    pub fn with_release_date(mut self, release_date: Option<NaiveDate>) -> Self {
+        self.release_date = release_date;
+        self
+    }
+
+    /// 凍結状態を設定した在庫を作成（永続化層からの復元用）
+    ///
+    /// # Arguments
+    /// * `frozen` - 凍結中かどうか
+    pub fn with_frozen(mut self, frozen: bool) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    /// 順番待ちの有効・無効を設定した在庫を作成
+    ///
+    /// # Arguments
+    /// * `waitlist_enabled` - 在庫を超える注文を順番待ちにするかどうか
+    pub fn with_waitlist_enabled(mut self, waitlist_enabled: bool) -> Self {
+        self.waitlist_enabled = waitlist_enabled;
+        self
+    }
+
     /// 書籍IDを取得
     pub fn book_id(&self) -> BookId {
         self.book_id
@@ -32,6 +85,39 @@ impl Inventory {
         self.quantity_on_hand
     }
 
+    /// 発売日を取得
+    pub fn release_date(&self) -> Option<NaiveDate> {
+        self.release_date
+    }
+
+    /// 凍結中かどうかを取得
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// 順番待ちが有効かどうかを取得
+    pub fn is_waitlist_enabled(&self) -> bool {
+        self.waitlist_enabled
+    }
+
+    /// 在庫を凍結する（在庫数の不整合を解消するまで予約を止める）
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    /// 在庫の凍結を解除する
+    pub fn unfreeze(&mut self) {
+        self.frozen = false;
+    }
+
+    /// 指定日時点で発売済みかチェック
+    ///
+    /// # Arguments
+    /// * `today` - 判定する日付
+    pub fn is_released(&self, today: NaiveDate) -> bool {
+        self.release_date.is_none_or(|date| date <= today)
+    }
+
     /// 在庫を予約する
     ///
     /// # Arguments
@@ -39,21 +125,61 @@ impl Inventory {
     ///
     /// # Returns
     /// * `Ok(())` - 予約成功
+    /// * `Err(DomainError::InventoryFrozen)` - 在庫が凍結中
     /// * `Err(DomainError::InsufficientInventory)` - 在庫不足
     pub fn reserve(&mut self, quantity: u32) -> Result<(), DomainError> {
-        if !self.has_available_stock(quantity) {
-            return Err(DomainError::InsufficientInventory);
+        if self.frozen {
+            return Err(DomainError::InventoryFrozen(self.book_id.to_string()));
         }
-        self.quantity_on_hand -= quantity;
+        self.quantity_on_hand = self
+            .quantity_on_hand
+            .checked_sub(quantity)
+            .ok_or(DomainError::InsufficientInventory)?;
         Ok(())
     }
 
     /// 在庫を解放する（キャンセル時など）
+    /// 凍結中でも解放は受け付ける（在庫数が増える方向の変更のため）
     ///
     /// # Arguments
     /// * `quantity` - 解放する数量
+    ///
+    /// # Returns
+    /// * `Ok(())` - 解放成功
+    /// * `Err(DomainError::InvalidValue)` - 解放後の在庫数が上限を超える
     pub fn release(&mut self, quantity: u32) -> Result<(), DomainError> {
-        self.quantity_on_hand += quantity;
+        self.quantity_on_hand = self
+            .quantity_on_hand
+            .checked_add(quantity)
+            .filter(|&released| released <= MAX_QUANTITY_ON_HAND)
+            .ok_or_else(|| {
+                DomainError::InvalidValue(format!(
+                    "在庫を解放できません（現在: {}, 解放: {}）",
+                    self.quantity_on_hand, quantity
+                ))
+            })?;
+        Ok(())
+    }
+
+    /// 在庫数を差分で調整する（棚卸しの差異の反映など）
+    ///
+    /// # Arguments
+    /// * `delta` - 調整する数量（正の値で増加、負の値で減少）
+    ///
+    /// # Returns
+    /// * `Ok(())` - 調整成功
+    /// * `Err(DomainError::InvalidValue)` - 調整後の在庫数が負または上限を超える
+    pub fn adjust(&mut self, delta: i64) -> Result<(), DomainError> {
+        let adjusted = i64::from(self.quantity_on_hand) + delta;
+        self.quantity_on_hand = u32::try_from(adjusted)
+            .ok()
+            .filter(|&adjusted| adjusted <= MAX_QUANTITY_ON_HAND)
+            .ok_or_else(|| {
+                DomainError::InvalidValue(format!(
+                    "在庫数を調整できません（現在: {}, 調整: {}）",
+                    self.quantity_on_hand, delta
+                ))
+            })?;
         Ok(())
     }
 
@@ -68,6 +194,24 @@ impl Inventory {
     pub fn has_available_stock(&self, quantity: u32) -> bool {
         self.quantity_on_hand >= quantity
     }
+
+    /// 在庫の不変条件を検証し、違反をすべて返す（違反がなければ空）
+    /// 永続化層からの復元時や整合性の検証で、実質的に負の在庫が紛れ込んでいないかを検出する
+    pub fn check_invariants(&self) -> Vec<InvariantViolation> {
+        let mut violations = Vec::new();
+        if self.quantity_on_hand > MAX_QUANTITY_ON_HAND {
+            violations.push(InvariantViolation::new(
+                "Inventory",
+                self.book_id,
+                "effective_stock_non_negative",
+                format!(
+                    "在庫数 {} が上限 {} を超えています（負の在庫の回り込み）",
+                    self.quantity_on_hand, MAX_QUANTITY_ON_HAND
+                ),
+            ));
+        }
+        violations
+    }
 }
 
 #[cfg(test)]
@@ -82,6 +226,17 @@ mod tests {
         assert_eq!(inventory.quantity_on_hand(), 10);
     }
 
+    #[test]
+    fn test_is_released() {
+        let today = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let inventory = Inventory::new(BookId::new(), 10);
+        assert!(inventory.is_released(today));
+
+        let inventory = inventory.with_release_date(today.succ_opt());
+        assert!(!inventory.is_released(today));
+        assert!(inventory.is_released(today.succ_opt().unwrap()));
+    }
+
     #[test]
     fn test_reserve_success() {
         let book_id = BookId::new();
@@ -110,6 +265,46 @@ mod tests {
         assert_eq!(inventory.quantity_on_hand(), 8);
     }
 
+    #[test]
+    fn test_release_overflow_is_rejected() {
+        let mut inventory = Inventory::new(BookId::new(), u32::MAX);
+        assert!(matches!(
+            inventory.release(1),
+            Err(DomainError::InvalidValue(_))
+        ));
+        assert_eq!(inventory.quantity_on_hand(), u32::MAX);
+    }
+
+    #[test]
+    fn test_frozen_inventory_rejects_reservation() {
+        let mut inventory = Inventory::new(BookId::new(), 10);
+        inventory.freeze();
+
+        assert!(matches!(
+            inventory.reserve(1),
+            Err(DomainError::InventoryFrozen(_))
+        ));
+        // 棚卸しによる調整と解放は凍結中でも受け付ける
+        inventory.adjust(-2).unwrap();
+        inventory.release(1).unwrap();
+        assert_eq!(inventory.quantity_on_hand(), 9);
+
+        inventory.unfreeze();
+        inventory.reserve(1).unwrap();
+        assert_eq!(inventory.quantity_on_hand(), 8);
+    }
+
+    #[test]
+    fn test_adjust() {
+        let mut inventory = Inventory::new(BookId::new(), 5);
+        inventory.adjust(-2).unwrap();
+        assert_eq!(inventory.quantity_on_hand(), 3);
+        inventory.adjust(4).unwrap();
+        assert_eq!(inventory.quantity_on_hand(), 7);
+        assert!(inventory.adjust(-8).is_err());
+        assert_eq!(inventory.quantity_on_hand(), 7);
+    }
+
     #[test]
     fn test_has_available_stock() {
         let book_id = BookId::new();
@@ -127,4 +322,22 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(inventory.quantity_on_hand(), 0);
     }
+
+    #[test]
+    fn test_check_invariants_detects_wrapped_negative_stock() {
+        assert!(Inventory::new(BookId::new(), MAX_QUANTITY_ON_HAND)
+            .check_invariants()
+            .is_empty());
+
+        // 負の在庫が符号なしの在庫数に回り込んだ状態
+        let inventory = Inventory::new(BookId::new(), u32::MAX);
+        let violations = inventory.check_invariants();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, "effective_stock_non_negative");
+
+        // 操作で上限を超えることはない
+        let mut inventory = Inventory::new(BookId::new(), MAX_QUANTITY_ON_HAND);
+        assert!(inventory.release(1).is_err());
+        assert!(inventory.adjust(1).is_err());
+    }
 }
@@ -1,7 +1,23 @@
+use crate::domain::address_normalizer::{AddressInput, AddressNormalizer};
+use crate::domain::cancellation_policy::CancellationPolicy;
+use crate::domain::delivery_estimate::DeliveryEstimator;
 use crate::domain::error::DomainError;
+use crate::domain::glossary::domain_model;
+use crate::domain::invariant::InvariantViolation;
+use crate::domain::model::value_objects::zero_quantity_violation;
 use crate::domain::model::{
-    BookId, CustomerId, Money, OrderId, OrderLine, OrderStatus, ShippingAddress,
+    BookId, BookSpec, CustomerId, FulfillmentType, HoldReason, LineAttribute, Money, OrderId,
+    OrderLine, OrderNumber, OrderStatus, Recipient, SalesChannel, ShippingAddress, TrackingToken,
 };
+use crate::domain::order_totals::OrderTotals;
+use crate::domain::shipping_fee::ShippingFeePolicy;
+use crate::domain::status_override::{
+    ensure_override_allowed, validate_override_reason, StatusOverride,
+};
+use crate::domain::validation::{Constraint, FieldViolation, ValidationErrors};
+use crate::domain::warning::DomainWarning;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
 
 /// 注文集約
 /// 注文のライフサイクルを管理し、ビジネスルールを適用する
@@ -9,22 +25,87 @@ use crate::domain::model::{
 #[derive(Debug, Clone)]
 pub struct Order {
     id: OrderId,
+    /// 顧客に伝える注文番号（作成時に採番する。採番を導入する前に作成した注文はNone）
+    order_number: Option<OrderNumber>,
     customer_id: CustomerId,
+    /// 注文を受け付けた販売チャネル（作成時に決まり、以降は変わらない）
+    sales_channel: SalesChannel,
     order_lines: Vec<OrderLine>,
     shipping_address: Option<ShippingAddress>,
+    /// 正規化前の入力されたままの配送先住所（監査用、正規化して設定した場合のみ）
+    original_shipping_address: Option<AddressInput>,
+    /// ギフト注文の受取人（購入者と異なる相手に届ける場合のみ）
+    recipient: Option<Recipient>,
     status: OrderStatus,
+    confirmed_at: Option<DateTime<Utc>>,
+    shipped_at: Option<DateTime<Utc>>,
+    delivered_at: Option<DateTime<Utc>>,
+    /// 最後に記録したドメインイベントの連番（イベント未記録の場合は0）
+    event_sequence: u64,
+    /// 確定時に始まったサーガの相関ID（確定前はNone）
+    /// 以降の発送・配達・キャンセルなどのイベントに引き継ぎ、注文のライフサイクル全体を1つのトレースにまとめる
+    saga_correlation_id: Option<Uuid>,
+    /// 確定時に算出した金額（確定前と、スナップショット導入前に確定した注文はNone）
+    confirmed_totals: Option<OrderTotals>,
+    /// 配送状況の公開確認用のトークン（確定時に発行し、キャンセル時に失効させる）
+    tracking_token: Option<TrackingToken>,
+    /// イベントストアから再構築したときのイベントストリームのバージョン（楽観的排他制御用、イベントストアを使わない場合は0）
+    stream_version: u64,
+    /// 配達予定日（確定時・発送時・配送先の変更時に見積もる。発送の予定が立たない注文はNone）
+    estimated_delivery_date: Option<NaiveDate>,
+    /// 保留にした理由（OnHoldの間のみ）
+    hold_reason: Option<HoldReason>,
+    /// 保存されている注文のバージョン（楽観的排他制御用、保存するたびに1増える。未保存の注文は0）
+    version: u64,
+    /// 操作中に記録した警告（永続化しない。アプリケーション層がtake_warningsで回収する）
+    warnings: Vec<DomainWarning>,
+    /// 管理者が適用した強制遷移（永続化しない。保存時のイベントの導出に使う）
+    status_override: Option<StatusOverride>,
 }
 
+domain_model!(
+    Order,
+    Aggregate,
+    "注文のライフサイクル（作成・確定・発送・配達・キャンセル）とビジネスルールを管理する",
+    related = [
+        OrderId,
+        OrderNumber,
+        CustomerId,
+        OrderLine,
+        ShippingAddress,
+        Recipient,
+        OrderStatus,
+        SalesChannel
+    ]
+);
+
 impl Order {
     /// 新しい注文を作成
     /// 初期ステータスはPending
     pub fn new(id: OrderId, customer_id: CustomerId) -> Self {
         Self {
             id,
+            order_number: None,
             customer_id,
+            sales_channel: SalesChannel::default(),
             order_lines: Vec::new(),
             shipping_address: None,
+            original_shipping_address: None,
+            recipient: None,
             status: OrderStatus::Pending,
+            confirmed_at: None,
+            shipped_at: None,
+            delivered_at: None,
+            event_sequence: 0,
+            saga_correlation_id: None,
+            confirmed_totals: None,
+            tracking_token: None,
+            estimated_delivery_date: None,
+            hold_reason: None,
+            stream_version: 0,
+            version: 0,
+            warnings: Vec::new(),
+            status_override: None,
         }
     }
 
@@ -39,13 +120,288 @@ impl Order {
     ) -> Result<Self, DomainError> {
         Ok(Self {
             id,
+            order_number: None,
             customer_id,
+            sales_channel: SalesChannel::default(),
             order_lines,
             shipping_address,
+            original_shipping_address: None,
+            recipient: None,
             status,
+            confirmed_at: None,
+            shipped_at: None,
+            delivered_at: None,
+            event_sequence: 0,
+            saga_correlation_id: None,
+            confirmed_totals: None,
+            tracking_token: None,
+            estimated_delivery_date: None,
+            hold_reason: None,
+            stream_version: 0,
+            version: 0,
+            warnings: Vec::new(),
+            status_override: None,
         })
     }
 
+    /// 外部システムから移行した注文を作成
+    /// 移行元で既に進んでいたステータスと遷移日時をそのまま引き継ぐ（イベントは発行しない）
+    /// バリデーション:
+    /// - 移行できるステータスはPending・Confirmed・Shipped・Delivered・Cancelledのみ
+    /// - Pending・Cancelled以外は明細が1件以上必要
+    /// - 物理書籍を含む確定済み以降の注文は配送先住所が必要
+    #[allow(clippy::too_many_arguments)]
+    pub fn import(
+        id: OrderId,
+        customer_id: CustomerId,
+        order_lines: Vec<OrderLine>,
+        shipping_address: Option<ShippingAddress>,
+        status: OrderStatus,
+        confirmed_at: Option<DateTime<Utc>>,
+        shipped_at: Option<DateTime<Utc>>,
+        delivered_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, DomainError> {
+        let in_fulfillment = matches!(
+            status,
+            OrderStatus::Confirmed | OrderStatus::Shipped | OrderStatus::Delivered
+        );
+        if !in_fulfillment && !matches!(status, OrderStatus::Pending | OrderStatus::Cancelled) {
+            return Err(FieldViolation::new(
+                "status",
+                Constraint::OneOf,
+                Some(status.to_string()),
+                format!("{}の注文は移行できません", status),
+            )
+            .into());
+        }
+
+        let order = Self {
+            id,
+            order_number: None,
+            customer_id,
+            sales_channel: SalesChannel::default(),
+            order_lines,
+            shipping_address,
+            original_shipping_address: None,
+            recipient: None,
+            status,
+            confirmed_at,
+            shipped_at,
+            delivered_at,
+            event_sequence: 0,
+            saga_correlation_id: None,
+            confirmed_totals: None,
+            tracking_token: None,
+            estimated_delivery_date: None,
+            hold_reason: None,
+            stream_version: 0,
+            version: 0,
+            warnings: Vec::new(),
+            status_override: None,
+        };
+
+        if in_fulfillment {
+            order.validate_for_fulfillment("注文明細が空です")?;
+        }
+
+        Ok(order)
+    }
+
+    /// 永続化されたステータス遷移日時を設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_transition_times(
+        mut self,
+        confirmed_at: Option<DateTime<Utc>>,
+        shipped_at: Option<DateTime<Utc>>,
+        delivered_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.confirmed_at = confirmed_at;
+        self.shipped_at = shipped_at;
+        self.delivered_at = delivered_at;
+        self
+    }
+
+    /// 注文番号を設定
+    /// 作成時の採番と、リポジトリでの再構築時に使用
+    pub fn with_order_number(mut self, order_number: Option<OrderNumber>) -> Self {
+        self.order_number = order_number;
+        self
+    }
+
+    /// 注文番号を取得（採番を導入する前に作成した注文はNone）
+    pub fn order_number(&self) -> Option<&OrderNumber> {
+        self.order_number.as_ref()
+    }
+
+    /// 販売チャネルを設定
+    /// 作成時と、リポジトリでの再構築時に使用
+    pub fn with_sales_channel(mut self, sales_channel: SalesChannel) -> Self {
+        self.sales_channel = sales_channel;
+        self
+    }
+
+    /// 注文を受け付けた販売チャネルを取得
+    pub fn sales_channel(&self) -> SalesChannel {
+        self.sales_channel
+    }
+
+    /// 永続化されたギフト注文の受取人を設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_recipient(mut self, recipient: Option<Recipient>) -> Self {
+        self.recipient = recipient;
+        self
+    }
+
+    /// 永続化された正規化前の配送先住所を設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_original_shipping_address(mut self, original: Option<AddressInput>) -> Self {
+        self.original_shipping_address = original;
+        self
+    }
+
+    /// 永続化されたイベント連番を設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_event_sequence(mut self, event_sequence: u64) -> Self {
+        self.event_sequence = event_sequence;
+        self
+    }
+
+    /// 永続化されたサーガの相関IDを設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_saga_correlation_id(mut self, saga_correlation_id: Option<Uuid>) -> Self {
+        self.saga_correlation_id = saga_correlation_id;
+        self
+    }
+
+    /// サーガを開始し、その相関IDを返す
+    /// 既にサーガが始まっている注文（再確定など）では最初の相関IDをそのまま使う
+    pub fn begin_saga(&mut self, correlation_id: Uuid) -> Uuid {
+        *self.saga_correlation_id.get_or_insert(correlation_id)
+    }
+
+    /// 確定時に始まったサーガの相関IDを取得
+    pub fn saga_correlation_id(&self) -> Option<Uuid> {
+        self.saga_correlation_id
+    }
+
+    /// 永続化された確定時の金額を設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_confirmed_totals(mut self, confirmed_totals: Option<OrderTotals>) -> Self {
+        self.confirmed_totals = confirmed_totals;
+        self
+    }
+
+    /// 現在の配送料ポリシーで金額を算出し、確定時の金額として保存する
+    /// 確定時と、確定後に配送先が変わって配送料を再計算したときに呼び出す
+    pub fn snapshot_totals(&mut self, shipping_fee_policy: &ShippingFeePolicy) {
+        self.confirmed_totals = Some(OrderTotals::calculate(self, shipping_fee_policy));
+    }
+
+    /// 確定時の金額を取得
+    pub fn confirmed_totals(&self) -> Option<&OrderTotals> {
+        self.confirmed_totals.as_ref()
+    }
+
+    /// 永続化された追跡トークンを設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_tracking_token(mut self, tracking_token: Option<TrackingToken>) -> Self {
+        self.tracking_token = tracking_token;
+        self
+    }
+
+    /// 永続化された配達予定日を設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_estimated_delivery_date(
+        mut self,
+        estimated_delivery_date: Option<NaiveDate>,
+    ) -> Self {
+        self.estimated_delivery_date = estimated_delivery_date;
+        self
+    }
+
+    /// 永続化された保留の理由を設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_hold_reason(mut self, hold_reason: Option<HoldReason>) -> Self {
+        self.hold_reason = hold_reason;
+        self
+    }
+
+    /// 保留にした理由を取得（保留中でない注文はNone）
+    pub fn hold_reason(&self) -> Option<HoldReason> {
+        self.hold_reason
+    }
+
+    /// 配達予定日を見積もり直して保存する
+    /// 確定時・発送時と、確定後に配送先が変わったときに呼び出す
+    pub fn estimate_delivery(&mut self, estimator: &DeliveryEstimator) {
+        self.estimated_delivery_date = estimator.estimate(self);
+    }
+
+    /// 配達予定日を取得（発送の予定が立たない注文はNone）
+    pub fn estimated_delivery_date(&self) -> Option<NaiveDate> {
+        self.estimated_delivery_date
+    }
+
+    /// イベントストリームのバージョンを設定
+    /// イベントソーシングのリポジトリでの再構築時に使用
+    pub fn with_stream_version(mut self, stream_version: u64) -> Self {
+        self.stream_version = stream_version;
+        self
+    }
+
+    /// 再構築したときのイベントストリームのバージョンを取得
+    pub fn stream_version(&self) -> u64 {
+        self.stream_version
+    }
+
+    /// イベントストリームへの追記に成功した後のバージョンを記録する
+    /// イベントソーシングのリポジトリでの保存時に使用
+    pub fn mark_stream_appended(&mut self, stream_version: u64) {
+        self.stream_version = stream_version;
+    }
+
+    /// 保存されている注文のバージョンを設定
+    /// リポジトリでの再構築時に使用
+    pub fn with_version(mut self, version: u64) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// 保存されている注文のバージョンを取得（未保存の注文は0）
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// 保存に成功した後のバージョンを記録する
+    /// リポジトリでの保存時に使用（続けて保存するときに、自身の保存を他の操作の更新と誤認しないようにする）
+    /// 保存した強制遷移はイベントに導出済みのため破棄する
+    pub fn mark_saved(&mut self, version: u64) {
+        self.version = version;
+        self.status_override = None;
+    }
+
+    /// 配送状況の公開確認用のトークンを取得（確定前・キャンセル済みの注文はNone）
+    pub fn tracking_token(&self) -> Option<&TrackingToken> {
+        self.tracking_token.as_ref()
+    }
+
+    /// ドメインイベントを記録し、注文内のイベント連番を採番する
+    /// 採番した連番は注文と一緒に永続化されるため、保存前に呼び出す
+    pub fn record_event(&mut self) -> u64 {
+        self.event_sequence += 1;
+        self.event_sequence
+    }
+
+    /// 最後に記録したドメインイベントの連番を取得
+    pub fn event_sequence(&self) -> u64 {
+        self.event_sequence
+    }
+
+    /// 記録した警告を取り出す（取り出した警告は注文から消える）
+    pub fn take_warnings(&mut self) -> Vec<DomainWarning> {
+        std::mem::take(&mut self.warnings)
+    }
+
     /// 注文IDを取得
     pub fn id(&self) -> OrderId {
         self.id
@@ -66,11 +422,41 @@ impl Order {
         self.shipping_address.as_ref()
     }
 
+    /// 正規化前の入力されたままの配送先住所を取得
+    pub fn original_shipping_address(&self) -> Option<&AddressInput> {
+        self.original_shipping_address.as_ref()
+    }
+
+    /// ギフト注文の受取人を取得
+    pub fn recipient(&self) -> Option<&Recipient> {
+        self.recipient.as_ref()
+    }
+
+    /// ギフト注文（購入者と異なる受取人に届ける注文）かどうか
+    pub fn is_gift(&self) -> bool {
+        self.recipient.is_some()
+    }
+
     /// 注文ステータスを取得
     pub fn status(&self) -> OrderStatus {
         self.status
     }
 
+    /// 確定日時を取得（予約注文の場合は有効化された日時）
+    pub fn confirmed_at(&self) -> Option<DateTime<Utc>> {
+        self.confirmed_at
+    }
+
+    /// 発送日時を取得
+    pub fn shipped_at(&self) -> Option<DateTime<Utc>> {
+        self.shipped_at
+    }
+
+    /// 配達完了日時を取得
+    pub fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.delivered_at
+    }
+
     /// 書籍を注文に追加
     /// 同じ書籍が既に存在する場合は数量を増加
     pub fn add_book(
@@ -78,10 +464,53 @@ impl Order {
         book_id: BookId,
         quantity: u32,
         unit_price: Money,
+    ) -> Result<(), DomainError> {
+        self.add_book_with_fulfillment(book_id, quantity, unit_price, FulfillmentType::Physical)
+    }
+
+    /// 重量と寸法を指定して物理書籍を注文に追加
+    pub fn add_book_with_spec(
+        &mut self,
+        book_id: BookId,
+        quantity: u32,
+        unit_price: Money,
+        spec: BookSpec,
+    ) -> Result<(), DomainError> {
+        self.add_order_line(
+            book_id,
+            quantity,
+            unit_price,
+            FulfillmentType::Physical,
+            Some(spec),
+        )
+    }
+
+    /// フルフィルメント種別を指定して書籍を注文に追加
+    /// 同じ書籍が既に存在する場合は数量を増加（種別が異なる場合はエラー）
+    pub fn add_book_with_fulfillment(
+        &mut self,
+        book_id: BookId,
+        quantity: u32,
+        unit_price: Money,
+        fulfillment_type: FulfillmentType,
+    ) -> Result<(), DomainError> {
+        self.add_order_line(book_id, quantity, unit_price, fulfillment_type, None)
+    }
+
+    /// フルフィルメント種別と書籍の重量・寸法を指定して書籍を注文に追加
+    /// 同じ書籍が既に存在する場合は数量を増加（種別が異なる場合はエラー）
+    /// 重量・寸法は指定された場合のみ更新する
+    pub fn add_order_line(
+        &mut self,
+        book_id: BookId,
+        quantity: u32,
+        unit_price: Money,
+        fulfillment_type: FulfillmentType,
+        spec: Option<BookSpec>,
     ) -> Result<(), DomainError> {
         // 数量のバリデーション（1以上）
         if quantity == 0 {
-            return Err(DomainError::InvalidQuantity);
+            return Err(zero_quantity_violation().into());
         }
 
         // 同じ書籍が既に存在するか確認
@@ -90,48 +519,239 @@ impl Order {
             .iter_mut()
             .find(|line| line.book_id() == book_id)
         {
+            if existing_line.fulfillment_type() != fulfillment_type {
+                return Err(FieldViolation::new(
+                    "fulfillment_type",
+                    Constraint::Consistent,
+                    Some(format!("{:?}", fulfillment_type)),
+                    "同じ書籍を異なるフルフィルメント種別で追加することはできません",
+                )
+                .into());
+            }
             // 既存の注文明細の数量を増加
             existing_line.increase_quantity(quantity)?;
+            if let Some(spec) = spec {
+                existing_line.update_spec(spec);
+            }
+            let warning = DomainWarning::duplicate_book_merged(book_id, existing_line.quantity());
+            self.warnings.push(warning);
         } else {
             // 新しい注文明細を作成して追加
-            let order_line = OrderLine::new(book_id, quantity, unit_price)?;
+            let order_line = OrderLine::new(book_id, quantity, unit_price)?
+                .with_fulfillment_type(fulfillment_type)
+                .with_spec(spec);
             self.order_lines.push(order_line);
         }
 
         Ok(())
     }
 
+    /// 注文明細の属性（「サイン本希望」などの要望）を設定
+    /// 既存の属性は置き換え、空のリストを指定すると削除する
+    /// 事前条件:
+    /// - ステータスがPending（確定後は倉庫に伝わっているため変更できない）
+    /// - 指定した書籍の明細が存在する
+    pub fn set_line_attributes(
+        &mut self,
+        book_id: BookId,
+        attributes: Vec<LineAttribute>,
+    ) -> Result<(), DomainError> {
+        if self.status != OrderStatus::Pending {
+            return Err(DomainError::InvalidOrderState(
+                "明細属性はPending状態の注文でのみ変更できます".to_string(),
+            ));
+        }
+        let line = self
+            .order_lines
+            .iter_mut()
+            .find(|line| line.book_id() == book_id)
+            .ok_or_else(|| missing_line_violation(book_id))?;
+        line.replace_attributes(attributes)
+    }
+
+    /// 注文明細の単価をカタログの現在の価格に更新
+    /// 事前条件:
+    /// - ステータスがPending（確定後の価格は確定時点のものを維持する）
+    /// - 指定した書籍の明細が存在する
+    pub fn reprice_line(&mut self, book_id: BookId, unit_price: Money) -> Result<(), DomainError> {
+        if self.status != OrderStatus::Pending {
+            return Err(DomainError::InvalidOrderState(
+                "単価はPending状態の注文でのみ変更できます".to_string(),
+            ));
+        }
+        let line = self
+            .order_lines
+            .iter_mut()
+            .find(|line| line.book_id() == book_id)
+            .ok_or_else(|| missing_line_violation(book_id))?;
+        if line.unit_price() != unit_price {
+            let warning = DomainWarning::line_repriced(book_id, line.unit_price(), unit_price);
+            line.update_unit_price(unit_price);
+            self.warnings.push(warning);
+        }
+        Ok(())
+    }
+
+    /// 発送が必要な明細（物理書籍）を含むかどうか
+    pub fn requires_shipping(&self) -> bool {
+        self.order_lines.iter().any(|line| !line.is_digital())
+    }
+
+    /// 電子書籍のみの注文かどうか
+    pub fn is_digital_only(&self) -> bool {
+        !self.order_lines.is_empty() && !self.requires_shipping()
+    }
+
+    /// 明細（書籍と数量）が同じ注文かどうか（誤って二重に購入した注文の判定に使用）
+    /// 明細のない注文はどの注文とも同じとみなさない
+    pub fn has_same_lines(&self, other: &Order) -> bool {
+        !self.order_lines.is_empty()
+            && self.order_lines.len() == other.order_lines.len()
+            && self.order_lines.iter().all(|line| {
+                other.order_lines.iter().any(|other_line| {
+                    other_line.book_id() == line.book_id()
+                        && other_line.quantity() == line.quantity()
+                })
+            })
+    }
+
+    /// 注文明細と配送先住所が揃っているかを検証する（違反はまとめて返す）
+    fn validate_for_fulfillment(&self, empty_lines_message: &str) -> Result<(), DomainError> {
+        let mut errors = ValidationErrors::new();
+        if self.order_lines.is_empty() {
+            errors.add(FieldViolation::new(
+                "order_lines",
+                Constraint::Required,
+                None,
+                empty_lines_message,
+            ));
+        }
+        if self.requires_shipping() && self.shipping_address.is_none() {
+            errors.add(FieldViolation::new(
+                "shipping_address",
+                Constraint::Required,
+                None,
+                "配送先住所が設定されていません",
+            ));
+        }
+        Ok(errors.into_result()?)
+    }
+
     /// 配送先住所を設定
-    pub fn set_shipping_address(&mut self, address: ShippingAddress) {
+    /// ギフト注文の場合は受取人の住所も変更する
+    /// 事前条件:
+    /// - ステータスがPending・AwaitingRelease・Confirmedのいずれか（発送前）
+    pub fn set_shipping_address(&mut self, address: ShippingAddress) -> Result<(), DomainError> {
+        self.ensure_before_shipment()?;
+        self.warn_if_confirmed();
+        self.recipient = self
+            .recipient
+            .take()
+            .map(|recipient| recipient.with_address(address.clone()));
         self.shipping_address = Some(address);
+        self.original_shipping_address = None;
+        Ok(())
+    }
+
+    /// 入力された配送先住所を正規化して設定
+    /// 正規化した住所を配送先住所とし、入力されたままの住所を監査用に保持する
+    /// 事前条件はset_shipping_addressと同じ
+    pub fn set_entered_shipping_address(
+        &mut self,
+        entered: AddressInput,
+        normalizer: &AddressNormalizer,
+    ) -> Result<(), DomainError> {
+        let address = normalizer.normalize(&entered)?;
+        self.set_shipping_address(address)?;
+        self.original_shipping_address = Some(entered);
+        Ok(())
+    }
+
+    /// ギフト注文の受取人を設定
+    /// 受取人の住所を配送先住所とし、購入者（顧客）は請求先のまま変わらない
+    /// 事前条件:
+    /// - ステータスがPending・AwaitingRelease・Confirmedのいずれか（発送前）
+    /// - 電子書籍のみの注文ではない（受取人に届ける物がない）
+    pub fn set_recipient(&mut self, recipient: Recipient) -> Result<(), DomainError> {
+        self.ensure_before_shipment()?;
+        if self.is_digital_only() {
+            return Err(FieldViolation::new(
+                "recipient",
+                Constraint::Consistent,
+                None,
+                "電子書籍のみの注文には受取人を設定できません",
+            )
+            .into());
+        }
+        self.warn_if_confirmed();
+        self.shipping_address = Some(recipient.address().clone());
+        self.original_shipping_address = None;
+        self.recipient = Some(recipient);
+        Ok(())
+    }
+
+    /// 確定後の配送先変更を警告として記録
+    fn warn_if_confirmed(&mut self) {
+        if self.status != OrderStatus::Pending {
+            self.warnings
+                .push(DomainWarning::destination_changed_after_confirmation());
+        }
+    }
+
+    /// 配送先を変更できる状態（発送前）か確認
+    fn ensure_before_shipment(&self) -> Result<(), DomainError> {
+        match self.status {
+            OrderStatus::Pending
+            | OrderStatus::AwaitingRelease
+            | OrderStatus::Waitlisted
+            | OrderStatus::Confirmed
+            | OrderStatus::OnHold => Ok(()),
+            OrderStatus::Shipped
+            | OrderStatus::Delivered
+            | OrderStatus::ReturnRequested
+            | OrderStatus::Returned
+            | OrderStatus::Fulfilled
+            | OrderStatus::Cancelled => Err(DomainError::InvalidOrderState(
+                "発送済み・完了済み・キャンセル済みの注文の配送先住所は変更できません".to_string(),
+            )),
+        }
     }
 
     /// 合計金額を計算
-    /// 小計 + 配送料（10,000円以上なら0円、未満なら500円）
+    /// 小計 + 配送料（既定の配送料ポリシーで算出）
     pub fn calculate_total(&self) -> Money {
+        ShippingFeePolicy::default().total(self)
+    }
+
+    /// 小計を計算（配送料を除く）
+    pub fn subtotal(&self) -> Money {
         // 全注文明細の小計を合算
-        let subtotal = self
-            .order_lines
+        self.order_lines
             .iter()
             .map(|line| line.subtotal())
-            .fold(Money::jpy(0), |acc, amount| acc.add(&amount).unwrap_or(acc));
+            .fold(Money::jpy(0), |acc, amount| acc.add(&amount).unwrap_or(acc))
+    }
 
-        // 配送料の計算（10,000円以上なら0円、未満なら500円）
-        let shipping_fee = if subtotal.amount() >= 10_000 {
-            Money::jpy(0)
-        } else {
-            Money::jpy(500)
-        };
+    /// 配送料を計算（既定の配送料ポリシーで算出）
+    /// 電子書籍のみの注文は0円、小計10,000円以上なら0円、それ以外は総重量と配送先の都道府県で決まる
+    /// 設定した料金表を使う場合はShippingFeePolicyを直接使用する
+    pub fn shipping_fee(&self) -> Money {
+        ShippingFeePolicy::default().shipping_fee(self)
+    }
 
-        // 最終金額 = 小計 + 配送料
-        subtotal.add(&shipping_fee).unwrap_or(subtotal)
+    /// 発送する書籍の総重量（グラム）
+    pub fn total_weight_grams(&self) -> u32 {
+        self.order_lines
+            .iter()
+            .map(|line| line.shipping_weight_grams())
+            .sum()
     }
 
     /// 注文を確定
     /// 事前条件:
     /// - ステータスがPending
     /// - 注文明細が1つ以上
-    /// - 配送先住所が設定済み
+    /// - 配送先住所が設定済み（電子書籍のみの注文を除く）
     pub fn confirm(&mut self) -> Result<(), DomainError> {
         // ステータスがPendingであることを確認
         if self.status != OrderStatus::Pending {
@@ -140,38 +760,156 @@ impl Order {
             ));
         }
 
-        // 注文明細が1つ以上あることを確認
-        if self.order_lines.is_empty() {
-            return Err(DomainError::OrderValidation(
-                "注文明細が空です。少なくとも1つの書籍を追加してください".to_string(),
+        // 注文明細が1つ以上あり、配送先住所が設定されていることを確認
+        // （電子書籍のみの注文は発送しないため配送先住所は不要）
+        self.validate_for_fulfillment("注文明細が空です。少なくとも1つの書籍を追加してください")?;
+
+        // ステータスをConfirmedに変更し、配送状況の公開確認用のトークンを発行する
+        self.status = OrderStatus::Confirmed;
+        self.confirmed_at = Some(Utc::now());
+        self.tracking_token = Some(TrackingToken::generate());
+
+        Ok(())
+    }
+
+    /// 注文を発売待ちにする（予約注文）
+    /// 未発売の書籍を含むため、在庫を予約せずに発売日まで待機する
+    /// 事前条件:
+    /// - ステータスがConfirmed
+    pub fn mark_as_awaiting_release(&mut self) -> Result<(), DomainError> {
+        // ステータスがConfirmedであることを確認
+        if self.status != OrderStatus::Confirmed {
+            return Err(DomainError::InvalidOrderState(
+                "発売待ちにできるのはConfirmed状態のみです".to_string(),
             ));
         }
 
-        // 配送先住所が設定されていることを確認
-        if self.shipping_address.is_none() {
-            return Err(DomainError::OrderValidation(
-                "配送先住所が設定されていません".to_string(),
+        // ステータスをAwaitingReleaseに変更
+        self.status = OrderStatus::AwaitingRelease;
+        // 発送の予定が立たないため、確定時に見積もった配達予定日を取り消す
+        self.estimated_delivery_date = None;
+
+        Ok(())
+    }
+
+    /// 予約注文を有効化する（発売日到来時）
+    /// 事前条件:
+    /// - ステータスがAwaitingRelease
+    pub fn activate_pre_order(&mut self) -> Result<(), DomainError> {
+        // ステータスがAwaitingReleaseであることを確認
+        if self.status != OrderStatus::AwaitingRelease {
+            return Err(DomainError::InvalidOrderState(
+                "予約注文を有効化できるのはAwaitingRelease状態のみです".to_string(),
             ));
         }
 
-        // ステータスをConfirmedに変更
+        // ステータスをConfirmedに戻し、在庫予約以降のサーガを再開する
+        // 発送SLAは発売日を起点とするため、確定日時を有効化した日時に更新する
         self.status = OrderStatus::Confirmed;
+        self.confirmed_at = Some(Utc::now());
 
         Ok(())
     }
 
+    /// 注文を順番待ちにする
+    /// 順番待ちが有効な書籍の在庫が不足しているため、在庫を予約せずに入荷・キャンセルを待つ
+    /// 事前条件:
+    /// - ステータスがConfirmed
+    pub fn mark_as_waitlisted(&mut self) -> Result<(), DomainError> {
+        if self.status != OrderStatus::Confirmed {
+            return Err(DomainError::InvalidOrderState(
+                "順番待ちにできるのはConfirmed状態のみです".to_string(),
+            ));
+        }
+
+        self.status = OrderStatus::Waitlisted;
+        // 発送の予定が立たないため、確定時に見積もった配達予定日を取り消す
+        self.estimated_delivery_date = None;
+
+        Ok(())
+    }
+
+    /// 順番待ちの注文を繰り上げる（在庫を確保できた時）
+    /// 事前条件:
+    /// - ステータスがWaitlisted
+    pub fn promote_from_waitlist(&mut self) -> Result<(), DomainError> {
+        if self.status != OrderStatus::Waitlisted {
+            return Err(DomainError::InvalidOrderState(
+                "繰り上げできるのはWaitlisted状態のみです".to_string(),
+            ));
+        }
+
+        // 発送SLAは在庫を確保した時点を起点とするため、確定日時を繰り上げた日時に更新する
+        self.status = OrderStatus::Confirmed;
+        self.confirmed_at = Some(Utc::now());
+
+        Ok(())
+    }
+
+    /// 注文を保留にする（不正の疑いなどの確認中）
+    /// 解除するまで発送せず、確定時に見積もった配達予定日を取り消す
+    /// 事前条件:
+    /// - ステータスがConfirmed
+    /// - 発送が必要な明細（物理書籍）を含む（電子書籍のみの注文は確定と同時に提供する）
+    pub fn place_on_hold(&mut self, reason: HoldReason) -> Result<(), DomainError> {
+        if self.status != OrderStatus::Confirmed {
+            return Err(DomainError::InvalidOrderState(
+                "保留にできるのはConfirmed状態のみです".to_string(),
+            ));
+        }
+        if !self.requires_shipping() {
+            return Err(DomainError::InvalidOrderState(
+                "電子書籍のみの注文は保留にできません".to_string(),
+            ));
+        }
+
+        self.status = OrderStatus::OnHold;
+        self.hold_reason = Some(reason);
+        self.estimated_delivery_date = None;
+
+        Ok(())
+    }
+
+    /// 保留を解除する
+    /// ステータスをConfirmedに戻し、解除した保留の理由を返す
+    /// 事前条件:
+    /// - ステータスがOnHold
+    pub fn release_hold(&mut self) -> Result<HoldReason, DomainError> {
+        if self.status != OrderStatus::OnHold {
+            return Err(DomainError::InvalidOrderState(
+                "保留を解除できるのはOnHold状態のみです".to_string(),
+            ));
+        }
+
+        self.status = OrderStatus::Confirmed;
+        // 理由が記録されていない場合（移行データなど）は不正の疑いとして扱う
+        Ok(self
+            .hold_reason
+            .take()
+            .unwrap_or(HoldReason::SuspectedFraud))
+    }
+
     /// 注文をキャンセル
     /// 事前条件:
-    /// - ステータスがPendingまたはConfirmed
+    /// - ステータスがPending、Confirmed、OnHold、AwaitingReleaseまたはWaitlisted
     pub fn cancel(&mut self) -> Result<(), DomainError> {
-        // ステータスがPendingまたはConfirmedであることを確認
+        // ステータスがPending、Confirmed、OnHold、AwaitingReleaseまたはWaitlistedであることを確認
         match self.status {
-            OrderStatus::Pending | OrderStatus::Confirmed => {
+            OrderStatus::Pending
+            | OrderStatus::Confirmed
+            | OrderStatus::OnHold
+            | OrderStatus::AwaitingRelease
+            | OrderStatus::Waitlisted => {
                 // キャンセル可能
             }
-            OrderStatus::Shipped | OrderStatus::Delivered => {
+            OrderStatus::Shipped
+            | OrderStatus::Delivered
+            | OrderStatus::ReturnRequested
+            | OrderStatus::Returned
+            | OrderStatus::Fulfilled => {
                 return Err(DomainError::InvalidOrderState(
-                    "発送済みまたは配達完了の注文はキャンセルできません".to_string(),
+                    "発送済み・配達完了・返品・フルフィルメント完了の注文はキャンセルできません"
+                        .to_string(),
                 ));
             }
             OrderStatus::Cancelled => {
@@ -181,16 +919,43 @@ impl Order {
             }
         }
 
-        // ステータスをCancelledに変更
+        // ステータスをCancelledに変更し、共有済みの追跡トークンと配達予定日を取り消す
         self.status = OrderStatus::Cancelled;
+        self.tracking_token = None;
+        self.estimated_delivery_date = None;
+        self.hold_reason = None;
 
         Ok(())
     }
 
+    /// キャンセルポリシーに従って注文をキャンセル
+    /// 顧客・管理者の操作によるキャンセルで使用し、確定済みの注文は受付期限を過ぎるとキャンセルできない
+    /// （在庫予約の失敗などの補償によるキャンセルは期限に関わらずcancelを使う）
+    ///
+    /// # Arguments
+    /// * `policy` - キャンセルポリシー
+    /// * `now` - キャンセル日時
+    pub fn cancel_with_policy(
+        &mut self,
+        policy: &CancellationPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        policy.ensure_can_cancel(self, now)?;
+        self.cancel()
+    }
+
     /// 注文を発送済みにマーク
     /// 事前条件:
     /// - ステータスがConfirmed
+    /// - 発送が必要な明細（物理書籍）を含む
     pub fn mark_as_shipped(&mut self) -> Result<(), DomainError> {
+        // 保留中の注文は解除するまで発送しない
+        if self.status == OrderStatus::OnHold {
+            return Err(DomainError::InvalidOrderState(
+                "保留中の注文は保留を解除するまで発送できません".to_string(),
+            ));
+        }
+
         // ステータスがConfirmedであることを確認
         if self.status != OrderStatus::Confirmed {
             return Err(DomainError::InvalidOrderState(
@@ -198,8 +963,16 @@ impl Order {
             ));
         }
 
+        // 電子書籍のみの注文は発送しない
+        if !self.requires_shipping() {
+            return Err(DomainError::InvalidOrderState(
+                "電子書籍のみの注文は発送できません".to_string(),
+            ));
+        }
+
         // ステータスをShippedに変更
         self.status = OrderStatus::Shipped;
+        self.shipped_at = Some(Utc::now());
 
         Ok(())
     }
@@ -217,9 +990,254 @@ impl Order {
 
         // ステータスをDeliveredに変更
         self.status = OrderStatus::Delivered;
+        self.delivered_at = Some(Utc::now());
+
+        Ok(())
+    }
+
+    /// 配達済みの注文の返品を受け付ける
+    /// 返品を承認するまで在庫は戻さない
+    /// 事前条件:
+    /// - ステータスがDelivered
+    pub fn return_order(&mut self) -> Result<(), DomainError> {
+        if self.status != OrderStatus::Delivered {
+            return Err(DomainError::InvalidOrderState(
+                "返品を受け付けられるのはDelivered状態のみです".to_string(),
+            ));
+        }
+
+        self.status = OrderStatus::ReturnRequested;
+
+        Ok(())
+    }
+
+    /// 返品を承認する
+    /// 事前条件:
+    /// - ステータスがReturnRequested
+    pub fn approve_return(&mut self) -> Result<(), DomainError> {
+        if self.status != OrderStatus::ReturnRequested {
+            return Err(DomainError::InvalidOrderState(
+                "返品を承認できるのはReturnRequested状態のみです".to_string(),
+            ));
+        }
+
+        self.status = OrderStatus::Returned;
+
+        Ok(())
+    }
+
+    /// 注文をフルフィルメント完了にマーク（電子書籍のみの注文）
+    /// 発送・配達を経ずにダウンロードリンクの発行で完了する
+    /// 事前条件:
+    /// - ステータスがConfirmed
+    /// - 電子書籍のみの注文
+    pub fn mark_as_fulfilled(&mut self) -> Result<(), DomainError> {
+        // ステータスがConfirmedであることを確認
+        if self.status != OrderStatus::Confirmed {
+            return Err(DomainError::InvalidOrderState(
+                "フルフィルメント完了にできるのはConfirmed状態のみです".to_string(),
+            ));
+        }
+
+        // 物理書籍を含む注文は発送・配達で完了する
+        if self.requires_shipping() {
+            return Err(DomainError::InvalidOrderState(
+                "物理書籍を含む注文は発送・配達で完了します".to_string(),
+            ));
+        }
+
+        // ステータスをFulfilledに変更
+        self.status = OrderStatus::Fulfilled;
+
+        Ok(())
+    }
+
+    /// 管理者が注文のステータスを強制的に変更する（誤った状態で止まった注文の修正用）
+    /// 通常の遷移ルールの代わりに強制遷移の遷移表で遷移元・遷移先を検証し、
+    /// 変更後の注文が不変条件を満たさない場合は変更しない
+    ///
+    /// # Arguments
+    /// * `to` - 遷移先のステータス
+    /// * `reason` - 強制遷移の理由
+    /// * `operator` - 強制遷移を行うオペレーター
+    /// * `now` - 変更日時（遷移日時が未記録の場合に使う）
+    pub fn force_status(
+        &mut self,
+        to: OrderStatus,
+        reason: &str,
+        operator: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        ensure_override_allowed(self.status, to)?;
+        let reason = validate_override_reason(reason)?;
+        let operator = operator.trim();
+        if operator.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "強制遷移を行うオペレーターを指定してください".to_string(),
+            ));
+        }
+
+        let mut forced = self.clone();
+        forced.status = to;
+        match to {
+            OrderStatus::Confirmed => {
+                forced.shipped_at = None;
+                forced.delivered_at = None;
+                forced.hold_reason = None;
+                forced.confirmed_at.get_or_insert(now);
+                forced.tracking_token.get_or_insert_with(TrackingToken::generate);
+            }
+            OrderStatus::Shipped => {
+                forced.delivered_at = None;
+                forced.hold_reason = None;
+                forced.shipped_at.get_or_insert(now);
+            }
+            OrderStatus::Delivered => {
+                forced.delivered_at.get_or_insert(now);
+            }
+            OrderStatus::Cancelled => {
+                forced.shipped_at = None;
+                forced.tracking_token = None;
+                forced.estimated_delivery_date = None;
+                forced.hold_reason = None;
+            }
+            _ => {}
+        }
+        if let Some(violation) = forced.check_invariants().into_iter().next() {
+            return Err(DomainError::InvalidOrderState(format!(
+                "{}に変更すると注文の整合性が保てません: {}",
+                to, violation.message
+            )));
+        }
 
+        forced.status_override = Some(StatusOverride {
+            from_status: self.status,
+            to_status: to,
+            reason,
+            operator: operator.to_string(),
+        });
+        *self = forced;
         Ok(())
     }
+
+    /// 適用した強制遷移を取得（強制遷移していない場合はNone）
+    pub fn status_override(&self) -> Option<&StatusOverride> {
+        self.status_override.as_ref()
+    }
+
+    /// 注文の不変条件を検証し、違反をすべて返す（違反がなければ空）
+    /// ドメインメソッドで遷移した注文は常に満たすため、永続化層からの復元や移行で
+    /// 不整合な状態が紛れ込んでいないかの検出に使う
+    pub fn check_invariants(&self) -> Vec<InvariantViolation> {
+        let mut violations = Vec::new();
+        let mut violate = |rule: &'static str, message: String| {
+            violations.push(InvariantViolation::new("Order", self.id, rule, message));
+        };
+
+        let past_pending = !matches!(self.status, OrderStatus::Pending | OrderStatus::Cancelled);
+        if past_pending && self.order_lines.is_empty() {
+            violate(
+                "order_lines_required",
+                format!("{}の注文に明細がありません", self.status),
+            );
+        }
+
+        let awaiting_shipment_or_shipped = matches!(
+            self.status,
+            OrderStatus::Confirmed
+                | OrderStatus::OnHold
+                | OrderStatus::AwaitingRelease
+                | OrderStatus::Waitlisted
+                | OrderStatus::Shipped
+                | OrderStatus::Delivered
+                | OrderStatus::ReturnRequested
+                | OrderStatus::Returned
+        );
+        if awaiting_shipment_or_shipped
+            && self.requires_shipping()
+            && self.shipping_address.is_none()
+        {
+            violate(
+                "shipping_address_required",
+                format!(
+                    "物理書籍を含む{}の注文に配送先住所がありません",
+                    self.status
+                ),
+            );
+        }
+
+        match self.status {
+            OrderStatus::Shipped | OrderStatus::Delivered if self.is_digital_only() => violate(
+                "digital_only_not_shipped",
+                format!("電子書籍のみの注文が{}になっています", self.status),
+            ),
+            OrderStatus::Fulfilled if self.requires_shipping() => violate(
+                "physical_lines_not_fulfilled",
+                "物理書籍を含む注文がFulfilledになっています".to_string(),
+            ),
+            _ => {}
+        }
+
+        // 遷移日時はステータスより先に進まない
+        if self.confirmed_at.is_some() && self.status == OrderStatus::Pending {
+            violate(
+                "transition_time_matches_status",
+                "Pendingの注文に確定日時があります".to_string(),
+            );
+        }
+        let shipped_or_later = matches!(
+            self.status,
+            OrderStatus::Shipped
+                | OrderStatus::Delivered
+                | OrderStatus::ReturnRequested
+                | OrderStatus::Returned
+        );
+        if self.shipped_at.is_some() && !shipped_or_later {
+            violate(
+                "transition_time_matches_status",
+                format!("{}の注文に発送日時があります", self.status),
+            );
+        }
+        let delivered_or_later = matches!(
+            self.status,
+            OrderStatus::Delivered | OrderStatus::ReturnRequested | OrderStatus::Returned
+        );
+        if self.delivered_at.is_some() && !delivered_or_later {
+            violate(
+                "transition_time_matches_status",
+                format!("{}の注文に配達日時があります", self.status),
+            );
+        }
+
+        // 遷移日時は確定→発送→配達の順に並ぶ
+        let ordered = |earlier: Option<DateTime<Utc>>, later: Option<DateTime<Utc>>| {
+            earlier
+                .zip(later)
+                .is_none_or(|(earlier, later)| earlier <= later)
+        };
+        if !ordered(self.confirmed_at, self.shipped_at)
+            || !ordered(self.shipped_at, self.delivered_at)
+            || !ordered(self.confirmed_at, self.delivered_at)
+        {
+            violate(
+                "transition_times_ordered",
+                "確定・発送・配達の日時が順に並んでいません".to_string(),
+            );
+        }
+
+        violations
+    }
+}
+
+/// 注文に指定した書籍の明細がない場合の違反
+fn missing_line_violation(book_id: BookId) -> DomainError {
+    FieldViolation::new(
+        "book_id",
+        Constraint::Exists,
+        Some(book_id.to_string()),
+        format!("注文に書籍 {} の明細がありません", book_id),
+    )
+    .into()
 }
 
 #[cfg(test)]
@@ -296,10 +1314,127 @@ mod tests {
         )
         .unwrap();
 
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
         assert!(order.shipping_address().is_some());
     }
 
+    #[test]
+    fn test_set_entered_shipping_address_keeps_original() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        let entered = AddressInput {
+            postal_code: "１２３－４５６７".to_string(),
+            prefecture: "東京".to_string(),
+            city: "渋谷区".to_string(),
+            street: "道玄坂１－１－１".to_string(),
+            building: None,
+        };
+
+        order
+            .set_entered_shipping_address(entered.clone(), &AddressNormalizer)
+            .unwrap();
+        let address = order.shipping_address().unwrap();
+        assert_eq!(address.postal_code(), "1234567");
+        assert_eq!(address.prefecture(), "東京都");
+        assert_eq!(address.street(), "道玄坂1-1-1");
+        assert_eq!(order.original_shipping_address(), Some(&entered));
+
+        // 正規化せずに住所を設定した場合は入力値を保持しない
+        order.set_shipping_address(address.clone()).unwrap();
+        assert!(order.original_shipping_address().is_none());
+    }
+
+    #[test]
+    fn test_change_shipping_address_only_before_shipment() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        let address = |city: &str| {
+            ShippingAddress::new(
+                "1234567".to_string(),
+                "東京都".to_string(),
+                city.to_string(),
+                "道玄坂1-1-1".to_string(),
+                None,
+            )
+            .unwrap()
+        };
+        order.set_shipping_address(address("渋谷区")).unwrap();
+        order.confirm().unwrap();
+
+        // 確定後でも発送前なら変更できる
+        order.set_shipping_address(address("新宿区")).unwrap();
+        assert_eq!(order.shipping_address().unwrap().city(), "新宿区");
+
+        // 発送後は変更できない
+        order.mark_as_shipped().unwrap();
+        assert!(order.set_shipping_address(address("港区")).is_err());
+        assert_eq!(order.shipping_address().unwrap().city(), "新宿区");
+    }
+
+#[test]
+fn test_set_gift_recipient_syncs_shipping_address() {
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+    let address = |city: &str| {
+        ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            city.to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap()
+    };
+    let recipient = Recipient::new(
+        "山田花子".to_string(),
+        "090-1234-5678".to_string(),
+        address("渋谷区"),
+    )
+    .unwrap();
+
+    order.set_recipient(recipient).unwrap();
+    assert!(order.is_gift());
+    assert_eq!(order.shipping_address().unwrap().city(), "渋谷区");
+
+    // 配送先住所を変更すると受取人の住所も変わる
+    order.set_shipping_address(address("新宿区")).unwrap();
+    assert_eq!(order.recipient().unwrap().address().city(), "新宿区");
+    assert_eq!(order.recipient().unwrap().name(), "山田花子");
+}
+
+    #[test]
+    fn test_warnings_are_recorded_without_blocking() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        let book_id = BookId::new();
+        order.add_book(book_id, 1, Money::jpy(1000)).unwrap();
+        assert!(order.take_warnings().is_empty());
+
+        // 同じ書籍の追加は数量を合算し、警告を記録する
+        order.add_book(book_id, 2, Money::jpy(1000)).unwrap();
+        assert_eq!(order.order_lines()[0].quantity(), 3);
+        let warnings = order.take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "DUPLICATE_BOOK_MERGED");
+        assert!(order.take_warnings().is_empty());
+
+        // 確定後の配送先変更は成功するが警告を記録する
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address.clone()).unwrap();
+        assert!(order.take_warnings().is_empty());
+        order.confirm().unwrap();
+        order.set_shipping_address(address).unwrap();
+        assert_eq!(
+            order.take_warnings(),
+            vec![DomainWarning::destination_changed_after_confirmation()]
+        );
+    }
+
     #[test]
     fn test_calculate_total_with_shipping_fee() {
         let order_id = OrderId::new();
@@ -350,7 +1485,7 @@ mod tests {
             None,
         )
         .unwrap();
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
 
         // 注文を確定
         let result = order.confirm();
@@ -373,7 +1508,7 @@ mod tests {
             None,
         )
         .unwrap();
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
 
         // 注文明細なしで確定を試みる
         let result = order.confirm();
@@ -425,7 +1560,7 @@ mod tests {
             None,
         )
         .unwrap();
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
         order.confirm().unwrap();
 
         // キャンセル
@@ -434,6 +1569,169 @@ mod tests {
         assert_eq!(order.status(), OrderStatus::Cancelled);
     }
 
+    #[test]
+    fn test_tracking_token_is_issued_on_confirm_and_revoked_on_cancel() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1234567".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "道玄坂1-1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert!(order.tracking_token().is_none());
+
+        order.confirm().unwrap();
+        let token = order.tracking_token().cloned().unwrap();
+        assert_eq!(token.as_str().len(), TrackingToken::LENGTH);
+        assert_eq!(TrackingToken::parse(token.as_str()).unwrap(), token);
+
+        order.cancel().unwrap();
+        assert!(order.tracking_token().is_none());
+    }
+
+    #[test]
+    fn test_pre_order_awaiting_release_and_activation() {
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = Order::new(order_id, customer_id);
+
+        // Pending状態からは発売待ちにできない
+        assert!(order.mark_as_awaiting_release().is_err());
+
+        let book_id = BookId::new();
+        let price = Money::jpy(1000);
+        order.add_book(book_id, 1, price).unwrap();
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address).unwrap();
+        order.confirm().unwrap();
+
+        order.mark_as_awaiting_release().unwrap();
+        assert_eq!(order.status(), OrderStatus::AwaitingRelease);
+        // 発売待ちの注文は発送できない
+        assert!(order.mark_as_shipped().is_err());
+
+        order.activate_pre_order().unwrap();
+        assert_eq!(order.status(), OrderStatus::Confirmed);
+        assert!(order.activate_pre_order().is_err());
+
+        // 発売待ちの注文はキャンセルできる
+        order.mark_as_awaiting_release().unwrap();
+        order.cancel().unwrap();
+        assert_eq!(order.status(), OrderStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_hold_blocks_shipping_until_released() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1234567".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "道玄坂1-1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        // Pending状態からは保留にできない
+        assert!(order.place_on_hold(HoldReason::SuspectedFraud).is_err());
+        order.confirm().unwrap();
+
+        order.place_on_hold(HoldReason::SuspectedFraud).unwrap();
+        assert_eq!(order.status(), OrderStatus::OnHold);
+        assert_eq!(order.hold_reason(), Some(HoldReason::SuspectedFraud));
+        assert!(order.place_on_hold(HoldReason::CustomerRequest).is_err());
+        // 保留中の注文は発送できない
+        assert!(order.mark_as_shipped().is_err());
+        assert!(order.check_invariants().is_empty());
+
+        assert_eq!(order.release_hold().unwrap(), HoldReason::SuspectedFraud);
+        assert_eq!(order.status(), OrderStatus::Confirmed);
+        assert!(order.hold_reason().is_none());
+        assert!(order.release_hold().is_err());
+
+        // 保留中の注文はキャンセルできる
+        order
+            .place_on_hold(HoldReason::AddressVerification)
+            .unwrap();
+        order.cancel().unwrap();
+        assert_eq!(order.status(), OrderStatus::Cancelled);
+        assert!(order.hold_reason().is_none());
+    }
+
+    #[test]
+    fn test_digital_only_order_is_fulfilled_without_shipping() {
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = Order::new(order_id, customer_id);
+
+        order
+            .add_book_with_fulfillment(BookId::new(), 1, Money::jpy(1000), FulfillmentType::Digital)
+            .unwrap();
+        assert!(order.is_digital_only());
+        // 電子書籍のみの注文には配送料がかからない
+        assert_eq!(order.calculate_total().amount(), 1000);
+
+        // 配送先住所なしで確定できる
+        order.confirm().unwrap();
+        assert!(order.mark_as_shipped().is_err());
+
+        order.mark_as_fulfilled().unwrap();
+        assert_eq!(order.status(), OrderStatus::Fulfilled);
+        assert!(order.cancel().is_err());
+    }
+
+    #[test]
+    fn test_mixed_order_requires_shipping() {
+        let order_id = OrderId::new();
+        let customer_id = CustomerId::new();
+        let mut order = Order::new(order_id, customer_id);
+
+        let digital_book = BookId::new();
+        order
+            .add_book_with_fulfillment(digital_book, 1, Money::jpy(1000), FulfillmentType::Digital)
+            .unwrap();
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        assert!(order.requires_shipping());
+        assert!(!order.is_digital_only());
+        assert_eq!(order.calculate_total().amount(), 2500);
+
+        // 同じ書籍を異なる種別で追加することはできない
+        assert!(order.add_book(digital_book, 1, Money::jpy(1000)).is_err());
+
+        // 物理書籍を含む注文は配送先住所が必要で、フルフィルメント完了にはできない
+        assert!(order.confirm().is_err());
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address).unwrap();
+        order.confirm().unwrap();
+        assert!(order.mark_as_fulfilled().is_err());
+    }
+
     #[test]
     fn test_cancel_shipped_order_fails() {
         let order_id = OrderId::new();
@@ -452,7 +1750,7 @@ mod tests {
             None,
         )
         .unwrap();
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
         order.confirm().unwrap();
         order.mark_as_shipped().unwrap();
 
@@ -479,7 +1777,7 @@ mod tests {
             None,
         )
         .unwrap();
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
         order.confirm().unwrap();
 
         // 発送済みにマーク
@@ -516,7 +1814,7 @@ mod tests {
             None,
         )
         .unwrap();
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
         order.confirm().unwrap();
         order.mark_as_shipped().unwrap();
 
@@ -524,6 +1822,12 @@ mod tests {
         let result = order.mark_as_delivered();
         assert!(result.is_ok());
         assert_eq!(order.status(), OrderStatus::Delivered);
+
+        // ステータス遷移日時が記録されている
+        let confirmed_at = order.confirmed_at().unwrap();
+        let shipped_at = order.shipped_at().unwrap();
+        let delivered_at = order.delivered_at().unwrap();
+        assert!(confirmed_at <= shipped_at && shipped_at <= delivered_at);
     }
 
     #[test]
@@ -544,11 +1848,153 @@ mod tests {
             None,
         )
         .unwrap();
-        order.set_shipping_address(address);
+        order.set_shipping_address(address).unwrap();
         order.confirm().unwrap();
 
         // 配達完了にマークを試みる（Shipped状態でないので失敗）
         let result = order.mark_as_delivered();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_return_is_accepted_only_after_delivery() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address).unwrap();
+        order.confirm().unwrap();
+        order.mark_as_shipped().unwrap();
+
+        // 配達前の注文は返品できず、返品は依頼を受けてから承認する
+        assert!(order.return_order().is_err());
+        order.mark_as_delivered().unwrap();
+        assert!(order.approve_return().is_err());
+        order.return_order().unwrap();
+        assert_eq!(order.status(), OrderStatus::ReturnRequested);
+        assert!(order.cancel().is_err());
+        order.approve_return().unwrap();
+        assert_eq!(order.status(), OrderStatus::Returned);
+        assert!(order.check_invariants().is_empty());
+    }
+
+    #[test]
+    fn test_import_keeps_status_and_requires_address_after_confirmation() {
+        let line = OrderLine::new(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        let shipped_at = Utc::now();
+
+        // 確定済み以降の物理書籍の注文は配送先住所が必要
+        let result = Order::import(
+            OrderId::new(),
+            CustomerId::new(),
+            vec![line.clone()],
+            None,
+            OrderStatus::Shipped,
+            None,
+            Some(shipped_at),
+            None,
+        );
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        let order = Order::import(
+            OrderId::new(),
+            CustomerId::new(),
+            vec![line],
+            Some(address),
+            OrderStatus::Shipped,
+            None,
+            Some(shipped_at),
+            None,
+        )
+        .unwrap();
+        assert_eq!(order.status(), OrderStatus::Shipped);
+        assert_eq!(order.shipped_at(), Some(shipped_at));
+        assert_eq!(order.event_sequence(), 0);
+
+        // サーガの途中の状態は移行できない
+        let result = Order::import(
+            OrderId::new(),
+            CustomerId::new(),
+            Vec::new(),
+            None,
+            OrderStatus::Waitlisted,
+            None,
+            None,
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_invariants_detects_inconsistent_reconstructed_orders() {
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        let line = OrderLine::new(BookId::new(), 1, Money::jpy(1000)).unwrap();
+
+        // ドメインメソッドで遷移した注文は不変条件を満たす
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        order.set_shipping_address(address).unwrap();
+        order.confirm().unwrap();
+        order.mark_as_shipped().unwrap();
+        order.mark_as_delivered().unwrap();
+        assert!(order.check_invariants().is_empty());
+
+        // 住所のない確定済みの注文
+        let order = Order::reconstruct(
+            OrderId::new(),
+            CustomerId::new(),
+            vec![line],
+            None,
+            OrderStatus::Confirmed,
+        )
+        .unwrap();
+        let rules: Vec<_> = order.check_invariants().iter().map(|v| v.rule).collect();
+        assert_eq!(rules, vec!["shipping_address_required"]);
+
+        // 明細のない発送済みの注文で、配達日時が発送日時より前
+        let shipped_at = Utc::now();
+        let order = Order::reconstruct(
+            OrderId::new(),
+            CustomerId::new(),
+            Vec::new(),
+            None,
+            OrderStatus::Shipped,
+        )
+        .unwrap()
+        .with_transition_times(
+            None,
+            Some(shipped_at),
+            Some(shipped_at - chrono::Duration::hours(1)),
+        );
+        let rules: Vec<_> = order.check_invariants().iter().map(|v| v.rule).collect();
+        assert_eq!(
+            rules,
+            vec![
+                "order_lines_required",
+                "transition_time_matches_status",
+                "transition_times_ordered"
+            ]
+        );
+    }
 }
@@ -1,4 +1,7 @@
 use crate::domain::error::DomainError;
+use crate::domain::glossary::domain_model;
+use crate::domain::validation::{Constraint, FieldViolation, ValidationErrors};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -8,6 +11,8 @@ use std::fmt;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct OrderId(Uuid);
 
+domain_model!(OrderId, ValueObject, "注文の一意識別子");
+
 impl OrderId {
     /// 新しい一意のOrderIdを生成
     pub fn new() -> Self {
@@ -47,6 +52,8 @@ impl Default for OrderId {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BookId(Uuid);
 
+domain_model!(BookId, ValueObject, "書籍の一意識別子");
+
 impl BookId {
     /// 新しい一意のBookIdを生成
     pub fn new() -> Self {
@@ -81,6 +88,8 @@ impl Default for BookId {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CustomerId(Uuid);
 
+domain_model!(CustomerId, ValueObject, "顧客の一意識別子");
+
 impl CustomerId {
     /// 新しい一意のCustomerIdを生成
     pub fn new() -> Self {
@@ -131,6 +140,8 @@ pub struct Money {
     currency: Currency,
 }
 
+domain_model!(Money, ValueObject, "通貨つきの金額（現在は日本円のみ）");
+
 impl Money {
     /// 金額と通貨から作成
     pub fn new(amount: i64, currency: String) -> Result<Self, DomainError> {
@@ -186,28 +197,216 @@ impl Money {
     }
 }
 
+/// 商品のフルフィルメント種別
+/// 物理書籍は発送・配達を経て、電子書籍はダウンロードリンクの発行で提供する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum FulfillmentType {
+    /// 物理書籍（発送が必要）
+    #[default]
+    Physical,
+    /// 電子書籍（ダウンロード提供）
+    Digital,
+}
+
+domain_model!(FulfillmentType, ValueObject, "明細の届け方（物理書籍の発送・電子書籍のダウンロード）");
+
+impl fmt::Display for FulfillmentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let type_str = match self {
+            FulfillmentType::Physical => "Physical",
+            FulfillmentType::Digital => "Digital",
+        };
+        write!(f, "{}", type_str)
+    }
+}
+
+impl FulfillmentType {
+    /// 文字列からFulfillmentTypeを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Physical" => Ok(FulfillmentType::Physical),
+            "Digital" => Ok(FulfillmentType::Digital),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効なフルフィルメント種別: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 書籍の重量と寸法を表す値オブジェクト
+/// 配送料の算出と配送業者の上限の検証に使用する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookSpec {
+    weight_grams: u32,
+    width_mm: u32,
+    height_mm: u32,
+    thickness_mm: u32,
+}
+
+domain_model!(BookSpec, ValueObject, "配送料の算出に使う書籍の重量と寸法");
+
+impl BookSpec {
+    /// 重量（グラム）と寸法（ミリメートル）から作成
+    /// いずれも1以上である必要がある
+    pub fn new(
+        weight_grams: u32,
+        width_mm: u32,
+        height_mm: u32,
+        thickness_mm: u32,
+    ) -> Result<Self, DomainError> {
+        if weight_grams == 0 || width_mm == 0 || height_mm == 0 || thickness_mm == 0 {
+            return Err(DomainError::InvalidValue(
+                "書籍の重量と寸法は1以上である必要があります".to_string(),
+            ));
+        }
+        Ok(Self {
+            weight_grams,
+            width_mm,
+            height_mm,
+            thickness_mm,
+        })
+    }
+
+    /// 重量（グラム）を取得
+    pub fn weight_grams(&self) -> u32 {
+        self.weight_grams
+    }
+
+    /// 幅（ミリメートル）を取得
+    pub fn width_mm(&self) -> u32 {
+        self.width_mm
+    }
+
+    /// 高さ（ミリメートル）を取得
+    pub fn height_mm(&self) -> u32 {
+        self.height_mm
+    }
+
+    /// 厚さ（ミリメートル）を取得
+    pub fn thickness_mm(&self) -> u32 {
+        self.thickness_mm
+    }
+}
+
+/// 注文明細の属性（「サイン本希望」などの明細ごとの要望）を表す値オブジェクト
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineAttribute {
+    key: String,
+    value: String,
+}
+
+domain_model!(LineAttribute, ValueObject, "明細ごとの要望（サイン本希望・ギフト包装など）");
+
+impl LineAttribute {
+    /// キーの最大文字数
+    pub const MAX_KEY_LENGTH: usize = 50;
+    /// 値の最大文字数
+    pub const MAX_VALUE_LENGTH: usize = 200;
+    /// 1明細あたりの最大属性数
+    pub const MAX_PER_LINE: usize = 10;
+
+    /// 新しい明細属性を作成
+    /// キーは英小文字・数字・アンダースコア（例: "signed_copy"）、値は制御文字を含まない1文字以上
+    pub fn new(key: String, value: String) -> Result<Self, DomainError> {
+        let key = key.trim().to_string();
+        let value = value.trim().to_string();
+        if key.is_empty()
+            || key.chars().count() > Self::MAX_KEY_LENGTH
+            || !key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(DomainError::InvalidValue(format!(
+                "明細属性のキーは{}文字以内の英小文字・数字・アンダースコアで指定してください: {}",
+                Self::MAX_KEY_LENGTH,
+                key
+            )));
+        }
+        if value.is_empty()
+            || value.chars().count() > Self::MAX_VALUE_LENGTH
+            || value.chars().any(char::is_control)
+        {
+            return Err(DomainError::InvalidValue(format!(
+                "明細属性 {} の値は{}文字以内で指定してください（改行などの制御文字は使えません）",
+                key,
+                Self::MAX_VALUE_LENGTH
+            )));
+        }
+        Ok(Self { key, value })
+    }
+
+    /// キーを取得
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 値を取得
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
 /// 注文明細を表す値オブジェクト
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrderLine {
     book_id: BookId,
     quantity: u32,
     unit_price: Money,
+    /// フルフィルメント種別（既存のイベントとの互換性のため未指定時は物理書籍）
+    #[serde(default)]
+    fulfillment_type: FulfillmentType,
+    /// 書籍の重量と寸法（未登録の場合は配送料の算出で重量0として扱う）
+    #[serde(default)]
+    spec: Option<BookSpec>,
+    /// 明細ごとの要望（既存のイベントとの互換性のため未指定時は空）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<LineAttribute>,
 }
 
+domain_model!(
+    OrderLine,
+    ValueObject,
+    "注文明細（書籍・数量・単価）",
+    related = [BookId, Money, FulfillmentType, BookSpec, LineAttribute]
+);
+
 impl OrderLine {
     /// 新しい注文明細を作成
     /// 数量は1以上である必要がある
     pub fn new(book_id: BookId, quantity: u32, unit_price: Money) -> Result<Self, DomainError> {
         if quantity == 0 {
-            return Err(DomainError::InvalidQuantity);
+            return Err(zero_quantity_violation().into());
         }
         Ok(Self {
             book_id,
             quantity,
             unit_price,
+            fulfillment_type: FulfillmentType::Physical,
+            spec: None,
+            attributes: Vec::new(),
         })
     }
 
+    /// フルフィルメント種別を指定した注文明細を作成
+    pub fn with_fulfillment_type(mut self, fulfillment_type: FulfillmentType) -> Self {
+        self.fulfillment_type = fulfillment_type;
+        self
+    }
+
+    /// 書籍の重量と寸法を指定した注文明細を作成
+    pub fn with_spec(mut self, spec: Option<BookSpec>) -> Self {
+        self.spec = spec;
+        self
+    }
+
+    /// 明細属性を指定した注文明細を作成
+    /// リポジトリでの再構築時に使用（検証済みの属性を前提とする）
+    pub fn with_attributes(mut self, attributes: Vec<LineAttribute>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     /// 書籍IDを取得
     pub fn book_id(&self) -> BookId {
         self.book_id
@@ -223,6 +422,34 @@ impl OrderLine {
         self.unit_price
     }
 
+    /// フルフィルメント種別を取得
+    pub fn fulfillment_type(&self) -> FulfillmentType {
+        self.fulfillment_type
+    }
+
+    /// 書籍の重量と寸法を取得
+    pub fn spec(&self) -> Option<BookSpec> {
+        self.spec
+    }
+
+    /// 明細属性を取得
+    pub fn attributes(&self) -> &[LineAttribute] {
+        &self.attributes
+    }
+
+    /// 電子書籍の明細かどうか
+    pub fn is_digital(&self) -> bool {
+        self.fulfillment_type == FulfillmentType::Digital
+    }
+
+    /// 発送する書籍の重量（重量 × 数量、電子書籍・重量未登録の場合は0）
+    pub fn shipping_weight_grams(&self) -> u32 {
+        match self.spec {
+            Some(spec) if !self.is_digital() => spec.weight_grams * self.quantity,
+            _ => 0,
+        }
+    }
+
     /// 小計を計算（単価 × 数量）
     pub fn subtotal(&self) -> Money {
         self.unit_price.multiply(self.quantity)
@@ -231,11 +458,230 @@ impl OrderLine {
     /// 数量を増加させる（同じ書籍を追加する場合）
     pub fn increase_quantity(&mut self, additional_quantity: u32) -> Result<(), DomainError> {
         if additional_quantity == 0 {
-            return Err(DomainError::InvalidQuantity);
+            return Err(zero_quantity_violation().into());
         }
         self.quantity += additional_quantity;
         Ok(())
     }
+
+    /// 単価を更新する（カタログの価格変更を注文に反映する場合）
+    pub fn update_unit_price(&mut self, unit_price: Money) {
+        self.unit_price = unit_price;
+    }
+
+    /// 書籍の重量と寸法を更新する（同じ書籍を重量・寸法付きで追加する場合）
+    pub fn update_spec(&mut self, spec: BookSpec) {
+        self.spec = Some(spec);
+    }
+
+    /// 明細属性を置き換える
+    /// 属性数は上限以内で、キーの重複は認めない
+    pub fn replace_attributes(
+        &mut self,
+        attributes: Vec<LineAttribute>,
+    ) -> Result<(), DomainError> {
+        let mut errors = ValidationErrors::new();
+        if attributes.len() > LineAttribute::MAX_PER_LINE {
+            errors.add(FieldViolation::new(
+                "attributes",
+                Constraint::MaxItems,
+                Some(attributes.len().to_string()),
+                format!(
+                    "明細属性は1明細あたり{}件までです",
+                    LineAttribute::MAX_PER_LINE
+                ),
+            ));
+        }
+        for (index, attribute) in attributes.iter().enumerate() {
+            if attributes[..index]
+                .iter()
+                .any(|other| other.key == attribute.key)
+            {
+                errors.add(FieldViolation::new(
+                    format!("attributes[{}].key", index),
+                    Constraint::Unique,
+                    Some(attribute.key.clone()),
+                    format!("明細属性のキーが重複しています: {}", attribute.key),
+                ));
+            }
+        }
+        errors.into_result()?;
+        self.attributes = attributes;
+        Ok(())
+    }
+}
+
+/// 数量が0の場合の違反（数量は1以上）
+pub(crate) fn zero_quantity_violation() -> FieldViolation {
+    FieldViolation::new(
+        "quantity",
+        Constraint::Min,
+        Some("0".to_string()),
+        "数量は1以上である必要があります",
+    )
+}
+
+/// 電子書籍のダウンロードリンクを表す値オブジェクト
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DownloadLink {
+    book_id: BookId,
+    url: String,
+    expires_at: DateTime<Utc>,
+}
+
+domain_model!(DownloadLink, ValueObject, "電子書籍のダウンロードリンクと有効期限", related = [BookId]);
+
+impl DownloadLink {
+    /// 新しいダウンロードリンクを作成
+    pub fn new(book_id: BookId, url: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            book_id,
+            url,
+            expires_at,
+        }
+    }
+
+    /// 書籍IDを取得
+    pub fn book_id(&self) -> BookId {
+        self.book_id
+    }
+
+    /// ダウンロードURLを取得
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// 有効期限を取得
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+/// 注文の配送状況をアカウントなしで確認するためのトークン
+/// 注文の確定時に発行し、購入者が受取人に共有できるよう推測できないランダムな値にする
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TrackingToken(String);
+
+domain_model!(TrackingToken, ValueObject, "アカウントなしで注文の配送状況を確認するための推測できないトークン");
+
+impl TrackingToken {
+    /// トークンの文字数
+    pub const LENGTH: usize = 64;
+
+    /// 新しいトークンを発行
+    /// UUID v4を2つ連結した64文字の16進数（ランダムな244ビット）
+    pub fn generate() -> Self {
+        Self(format!(
+            "{}{}",
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        ))
+    }
+
+    /// 文字列からトークンを作成
+    /// 64文字の16進数（小文字）である必要がある
+    pub fn parse(token: &str) -> Result<Self, DomainError> {
+        let valid = token.len() == Self::LENGTH
+            && token
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+        if !valid {
+            return Err(DomainError::InvalidValue(
+                "追跡トークンの形式が正しくありません".to_string(),
+            ));
+        }
+        Ok(Self(token.to_string()))
+    }
+
+    /// トークン文字列を取得
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TrackingToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// 顧客が電話などで伝えられる注文番号（例: BK-2024-000123）
+/// `{接頭辞}-{作成年}-{年ごとの連番}` の形式で、注文の作成時に採番する（注文の識別は引き続き注文IDで行う）
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OrderNumber(String);
+
+domain_model!(OrderNumber, ValueObject, "顧客が電話などで伝えられる人が読める注文番号（接頭辞-作成年-連番）");
+
+impl OrderNumber {
+    /// 接頭辞の最大文字数
+    pub const MAX_PREFIX_LENGTH: usize = 8;
+
+    /// 接頭辞・作成年・連番から注文番号を作成
+    /// 連番は指定した桁数まで0で埋める（桁数を超えた連番はそのまま表記する）
+    ///
+    /// # Arguments
+    /// * `prefix` - 接頭辞（英大文字1〜8文字）
+    /// * `year` - 注文の作成年
+    /// * `sequence` - 年ごとの連番（1以上）
+    /// * `digits` - 連番の最小桁数
+    pub fn new(prefix: &str, year: i32, sequence: u64, digits: usize) -> Result<Self, DomainError> {
+        if !Self::is_valid_prefix(prefix) || !(1000..=9999).contains(&year) || sequence == 0 {
+            return Err(DomainError::InvalidValue(format!(
+                "注文番号を作成できません（接頭辞: {}, 年: {}, 連番: {}）",
+                prefix, year, sequence
+            )));
+        }
+        Ok(Self(format!(
+            "{}-{}-{:0width$}",
+            prefix,
+            year,
+            sequence,
+            width = digits
+        )))
+    }
+
+    /// 接頭辞として使える文字列か（英大文字1〜8文字）
+    pub fn is_valid_prefix(prefix: &str) -> bool {
+        (1..=Self::MAX_PREFIX_LENGTH).contains(&prefix.len())
+            && prefix.chars().all(|c| c.is_ascii_uppercase())
+    }
+
+    /// 文字列から注文番号を作成
+    /// 前後の空白を除き、大文字に揃えてから `{接頭辞}-{4桁の年}-{連番}` の形式か検証する
+    pub fn parse(order_number: &str) -> Result<Self, DomainError> {
+        let normalized = order_number.trim().to_ascii_uppercase();
+        let parts: Vec<&str> = normalized.split('-').collect();
+        let valid = matches!(
+            parts.as_slice(),
+            [prefix, year, sequence]
+                if Self::is_valid_prefix(prefix)
+                    && year.len() == 4
+                    && year.chars().all(|c| c.is_ascii_digit())
+                    && !sequence.is_empty()
+                    && sequence.chars().all(|c| c.is_ascii_digit())
+        );
+        if !valid {
+            return Err(FieldViolation::new(
+                "order_number",
+                Constraint::Pattern,
+                Some(order_number.to_string()),
+                "注文番号の形式が正しくありません（例: BK-2024-000123）",
+            )
+            .into());
+        }
+        Ok(Self(normalized))
+    }
+
+    /// 注文番号の文字列を取得
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for OrderNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 /// 配送先住所を表す値オブジェクト
@@ -248,6 +694,8 @@ pub struct ShippingAddress {
     building: Option<String>,
 }
 
+domain_model!(ShippingAddress, ValueObject, "配送先住所（郵便番号・都道府県・市区町村・番地）");
+
 impl ShippingAddress {
     /// 新しい配送先住所を作成
     /// バリデーション:
@@ -260,29 +708,34 @@ impl ShippingAddress {
         street: String,
         building: Option<String>,
     ) -> Result<Self, DomainError> {
+        let mut errors = ValidationErrors::new();
+
         // 郵便番号のバリデーション（7桁の数字）
         if !Self::is_valid_postal_code(&postal_code) {
-            return Err(DomainError::InvalidAddress(
-                "郵便番号は7桁の数字である必要があります".to_string(),
+            errors.add(FieldViolation::new(
+                "postal_code",
+                Constraint::Pattern,
+                Some(postal_code.clone()),
+                "郵便番号は7桁の数字である必要があります",
             ));
         }
 
         // 必須フィールドのバリデーション
-        if prefecture.trim().is_empty() {
-            return Err(DomainError::InvalidAddress(
-                "都道府県は空にできません".to_string(),
-            ));
-        }
-        if city.trim().is_empty() {
-            return Err(DomainError::InvalidAddress(
-                "市区町村は空にできません".to_string(),
-            ));
-        }
-        if street.trim().is_empty() {
-            return Err(DomainError::InvalidAddress(
-                "番地は空にできません".to_string(),
-            ));
+        for (field, value, message) in [
+            ("prefecture", &prefecture, "都道府県は空にできません"),
+            ("city", &city, "市区町村は空にできません"),
+            ("street", &street, "番地は空にできません"),
+        ] {
+            if value.trim().is_empty() {
+                errors.add(FieldViolation::new(
+                    field,
+                    Constraint::Required,
+                    None,
+                    message,
+                ));
+            }
         }
+        errors.into_result()?;
 
         Ok(Self {
             postal_code,
@@ -324,6 +777,69 @@ impl ShippingAddress {
     }
 }
 
+/// ギフト注文の受取人を表す値オブジェクト
+/// 購入者（請求先）とは別の氏名・住所・電話番号で届ける
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recipient {
+    name: String,
+    phone: String,
+    address: ShippingAddress,
+}
+
+domain_model!(Recipient, ValueObject, "ギフト注文の受取人", related = [ShippingAddress]);
+
+impl Recipient {
+    /// 新しい受取人を作成
+    /// バリデーション:
+    /// - 氏名は空でない必要がある
+    /// - 電話番号はハイフンを除いて10桁または11桁の数字である必要がある
+    pub fn new(name: String, phone: String, address: ShippingAddress) -> Result<Self, DomainError> {
+        if name.trim().is_empty() {
+            return Err(DomainError::InvalidValue(
+                "受取人の氏名は空にできません".to_string(),
+            ));
+        }
+        if !Self::is_valid_phone(&phone) {
+            return Err(DomainError::InvalidValue(
+                "受取人の電話番号は10桁または11桁の数字である必要があります".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            name,
+            phone,
+            address,
+        })
+    }
+
+    /// 電話番号が有効かチェック（ハイフンを除いて10桁または11桁の数字）
+    fn is_valid_phone(phone: &str) -> bool {
+        let digits: Vec<char> = phone.chars().filter(|&c| c != '-').collect();
+        (10..=11).contains(&digits.len()) && digits.iter().all(|c| c.is_ascii_digit())
+    }
+
+    /// 配送先住所を変更した受取人を作成
+    pub fn with_address(mut self, address: ShippingAddress) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// 氏名を取得
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 電話番号を取得
+    pub fn phone(&self) -> &str {
+        &self.phone
+    }
+
+    /// 配送先住所を取得
+    pub fn address(&self) -> &ShippingAddress {
+        &self.address
+    }
+}
+
 /// 注文のステータス
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OrderStatus {
@@ -331,21 +847,41 @@ pub enum OrderStatus {
     Pending,
     /// 確認済み（在庫予約済み）
     Confirmed,
+    /// 保留（不正の疑いなどを確認中。解除するまで発送しない）
+    OnHold,
+    /// 発売待ち（未発売の書籍を含む予約注文。在庫は未予約）
+    AwaitingRelease,
+    /// 順番待ち（順番待ちが有効な書籍の在庫を超えた注文。在庫は未予約）
+    Waitlisted,
     /// 発送済み
     Shipped,
     /// 配達完了
     Delivered,
+    /// 返品受付中（配達完了後に顧客が返品を依頼し、承認待ち）
+    ReturnRequested,
+    /// 返品済み（返品を承認し、在庫を戻した）
+    Returned,
+    /// フルフィルメント完了（電子書籍のみの注文でダウンロードリンクを発行済み）
+    Fulfilled,
     /// キャンセル済み
     Cancelled,
 }
 
+domain_model!(OrderStatus, ValueObject, "注文のステータス");
+
 impl fmt::Display for OrderStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let status_str = match self {
             OrderStatus::Pending => "Pending",
             OrderStatus::Confirmed => "Confirmed",
+            OrderStatus::OnHold => "OnHold",
+            OrderStatus::AwaitingRelease => "AwaitingRelease",
+            OrderStatus::Waitlisted => "Waitlisted",
             OrderStatus::Shipped => "Shipped",
             OrderStatus::Delivered => "Delivered",
+            OrderStatus::ReturnRequested => "ReturnRequested",
+            OrderStatus::Returned => "Returned",
+            OrderStatus::Fulfilled => "Fulfilled",
             OrderStatus::Cancelled => "Cancelled",
         };
         write!(f, "{}", status_str)
@@ -358,8 +894,14 @@ impl OrderStatus {
         match s {
             "Pending" => Ok(OrderStatus::Pending),
             "Confirmed" => Ok(OrderStatus::Confirmed),
+            "OnHold" => Ok(OrderStatus::OnHold),
+            "AwaitingRelease" => Ok(OrderStatus::AwaitingRelease),
+            "Waitlisted" => Ok(OrderStatus::Waitlisted),
             "Shipped" => Ok(OrderStatus::Shipped),
             "Delivered" => Ok(OrderStatus::Delivered),
+            "ReturnRequested" => Ok(OrderStatus::ReturnRequested),
+            "Returned" => Ok(OrderStatus::Returned),
+            "Fulfilled" => Ok(OrderStatus::Fulfilled),
             "Cancelled" => Ok(OrderStatus::Cancelled),
             _ => Err(DomainError::InvalidValue(format!(
                 "無効な注文ステータス: {}",
@@ -369,6 +911,96 @@ impl OrderStatus {
     }
 }
 
+/// 注文を保留にした理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HoldReason {
+    /// 不正注文の疑い
+    SuspectedFraud,
+    /// 支払いの確認待ち
+    PaymentVerification,
+    /// 配送先住所の確認待ち
+    AddressVerification,
+    /// 顧客からの依頼
+    CustomerRequest,
+}
+
+domain_model!(
+    HoldReason,
+    ValueObject,
+    "注文を保留にした理由（不正の疑い・支払いの確認など）"
+);
+
+impl fmt::Display for HoldReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason_str = match self {
+            HoldReason::SuspectedFraud => "SuspectedFraud",
+            HoldReason::PaymentVerification => "PaymentVerification",
+            HoldReason::AddressVerification => "AddressVerification",
+            HoldReason::CustomerRequest => "CustomerRequest",
+        };
+        write!(f, "{}", reason_str)
+    }
+}
+
+impl HoldReason {
+    /// 文字列からHoldReasonを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "SuspectedFraud" => Ok(HoldReason::SuspectedFraud),
+            "PaymentVerification" => Ok(HoldReason::PaymentVerification),
+            "AddressVerification" => Ok(HoldReason::AddressVerification),
+            "CustomerRequest" => Ok(HoldReason::CustomerRequest),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な保留の理由: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 注文を受け付けた販売チャネル
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
+pub enum SalesChannel {
+    /// オンラインストア（APIから作成した注文の既定値）
+    #[default]
+    Online,
+    /// 店頭
+    InStore,
+}
+
+domain_model!(
+    SalesChannel,
+    ValueObject,
+    "注文を受け付けた販売チャネル（オンライン・店頭）"
+);
+
+impl SalesChannel {
+    /// すべての販売チャネル
+    pub const ALL: [SalesChannel; 2] = [SalesChannel::Online, SalesChannel::InStore];
+
+    /// 文字列からSalesChannelを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Online" => Ok(SalesChannel::Online),
+            "InStore" => Ok(SalesChannel::InStore),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な販売チャネル: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for SalesChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let channel_str = match self {
+            SalesChannel::Online => "Online",
+            SalesChannel::InStore => "InStore",
+        };
+        write!(f, "{}", channel_str)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -436,6 +1068,55 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_shipping_address_reports_every_violation() {
+        let result = ShippingAddress::new(
+            "12-3456".to_string(),
+            "東京都".to_string(),
+            " ".to_string(),
+            "".to_string(),
+            None,
+        );
+        let Err(DomainError::Validation(errors)) = result else {
+            panic!("検証エラーになるはず");
+        };
+        let fields: Vec<(&str, Constraint)> = errors
+            .violations()
+            .iter()
+            .map(|violation| (violation.field.as_str(), violation.constraint))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("postal_code", Constraint::Pattern),
+                ("city", Constraint::Required),
+                ("street", Constraint::Required),
+            ]
+        );
+        assert_eq!(errors.violations()[0].actual.as_deref(), Some("12-3456"));
+    }
+
+    #[test]
+    fn test_recipient_validation() {
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+
+        assert!(Recipient::new(
+            "山田花子".to_string(),
+            "090-1234-5678".to_string(),
+            address.clone()
+        )
+        .is_ok());
+        assert!(Recipient::new("".to_string(), "0312345678".to_string(), address.clone()).is_err());
+        assert!(Recipient::new("山田花子".to_string(), "090-1234".to_string(), address).is_err());
+    }
+
     #[test]
     fn test_shipping_address_empty_required_field() {
         let result = ShippingAddress::new(
@@ -447,4 +1128,51 @@ mod tests {
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_line_attribute_validation() {
+        let attribute =
+            LineAttribute::new(" signed_copy ".to_string(), "requested".to_string()).unwrap();
+        assert_eq!(attribute.key(), "signed_copy");
+
+        assert!(LineAttribute::new("Signed Copy".to_string(), "requested".to_string()).is_err());
+        assert!(LineAttribute::new("note".to_string(), "".to_string()).is_err());
+        assert!(LineAttribute::new("note".to_string(), "1行目\n2行目".to_string()).is_err());
+        assert!(LineAttribute::new("note".to_string(), "あ".repeat(200)).is_ok());
+        assert!(LineAttribute::new("note".to_string(), "あ".repeat(201)).is_err());
+
+        let mut line = OrderLine::new(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        let duplicated = vec![attribute.clone(), attribute.clone()];
+        assert!(line.replace_attributes(duplicated).is_err());
+        let too_many = (0..=LineAttribute::MAX_PER_LINE)
+            .map(|i| LineAttribute::new(format!("note_{}", i), "x".to_string()).unwrap())
+            .collect();
+        assert!(line.replace_attributes(too_many).is_err());
+        line.replace_attributes(vec![attribute]).unwrap();
+        assert_eq!(line.attributes().len(), 1);
+    }
+
+    #[test]
+    fn test_order_number_format_and_parse() {
+        let number = OrderNumber::new("BK", 2024, 123, 6).unwrap();
+        assert_eq!(number.as_str(), "BK-2024-000123");
+        assert_eq!(
+            OrderNumber::new("BK", 2024, 1234567, 6).unwrap().as_str(),
+            "BK-2024-1234567"
+        );
+        assert!(OrderNumber::new("bk", 2024, 1, 6).is_err());
+        assert!(OrderNumber::new("BK", 2024, 0, 6).is_err());
+
+        assert_eq!(OrderNumber::parse(" bk-2024-000123 ").unwrap(), number);
+        for invalid in [
+            "",
+            "BK2024000123",
+            "BK-24-000123",
+            "BK-2024-",
+            "B1-2024-1",
+            "BK-2024-12a",
+        ] {
+            assert!(OrderNumber::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
 }
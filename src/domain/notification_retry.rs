@@ -0,0 +1,400 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::OrderId;
+use crate::domain::port::{
+    FailedNotificationRepository, Logger, NotificationSender, RepositoryError,
+};
+use crate::domain::task_supervisor::SupervisedTask;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// 1回の再送で処理する通知の上限
+pub const NOTIFICATION_RETRY_BATCH_SIZE: u32 = 100;
+
+/// 通知の送信チャネル
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NotificationChannel {
+    /// メール（購入者への通知）
+    Email,
+    /// SMS（ギフト注文の受取人への通知）
+    Sms,
+}
+
+impl NotificationChannel {
+    /// 文字列からNotificationChannelを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Email" => Ok(NotificationChannel::Email),
+            "Sms" => Ok(NotificationChannel::Sms),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な通知のチャネル: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for NotificationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let channel_str = match self {
+            NotificationChannel::Email => "Email",
+            NotificationChannel::Sms => "Sms",
+        };
+        write!(f, "{}", channel_str)
+    }
+}
+
+/// 送信する通知
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub channel: NotificationChannel,
+    /// 通知の対象の注文（メールはこの注文の購入者に送る）
+    pub order_id: OrderId,
+    /// SMSの宛先の電話番号（メールの場合はNone）
+    pub phone: Option<String>,
+    /// 通知のきっかけになったイベントの種類
+    pub event_type: String,
+    pub message: String,
+    pub correlation_id: Uuid,
+}
+
+/// チャネルごとの再送の設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelRetryPolicy {
+    /// 送信をあきらめるまでの試行回数（最初の送信を含む）
+    pub max_attempts: u32,
+    /// 最初の失敗から再送までの待ち時間（失敗するたびに倍にする）
+    pub initial_backoff: TimeDelta,
+    /// 再送までの待ち時間の上限
+    pub max_backoff: TimeDelta,
+}
+
+impl ChannelRetryPolicy {
+    /// 指定回数の試行に失敗した後、次の再送までの待ち時間
+    pub fn backoff_after(&self, attempts: u32) -> TimeDelta {
+        let factor = 2_i32.saturating_pow(attempts.saturating_sub(1).min(30));
+        self.initial_backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+/// 通知の再送ポリシー
+/// メールは一時的な失敗が多いため間隔を詰めて多めに、SMSは重複して届くと迷惑なため間隔を空けて少なめに再送する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationRetryPolicy {
+    pub email: ChannelRetryPolicy,
+    pub sms: ChannelRetryPolicy,
+}
+
+impl Default for NotificationRetryPolicy {
+    fn default() -> Self {
+        Self {
+            email: ChannelRetryPolicy {
+                max_attempts: 5,
+                initial_backoff: TimeDelta::minutes(1),
+                max_backoff: TimeDelta::hours(1),
+            },
+            sms: ChannelRetryPolicy {
+                max_attempts: 3,
+                initial_backoff: TimeDelta::minutes(5),
+                max_backoff: TimeDelta::hours(1),
+            },
+        }
+    }
+}
+
+impl NotificationRetryPolicy {
+    /// チャネルの再送の設定を取得
+    pub fn for_channel(&self, channel: NotificationChannel) -> &ChannelRetryPolicy {
+        match channel {
+            NotificationChannel::Email => &self.email,
+            NotificationChannel::Sms => &self.sms,
+        }
+    }
+}
+
+/// 送信に失敗した通知の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailedNotificationStatus {
+    /// 再送待ち
+    Pending,
+    /// 再送で送信できた
+    Delivered,
+    /// 試行回数の上限に達したため再送をあきらめた（管理画面から手動で再送できる）
+    Undeliverable,
+}
+
+impl FailedNotificationStatus {
+    /// 文字列からFailedNotificationStatusを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Pending" => Ok(FailedNotificationStatus::Pending),
+            "Delivered" => Ok(FailedNotificationStatus::Delivered),
+            "Undeliverable" => Ok(FailedNotificationStatus::Undeliverable),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な通知の状態: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for FailedNotificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            FailedNotificationStatus::Pending => "Pending",
+            FailedNotificationStatus::Delivered => "Delivered",
+            FailedNotificationStatus::Undeliverable => "Undeliverable",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+/// 送信に失敗した通知
+/// イベントのデッドレターキューとは別に保持し、チャネルごとの間隔で再送する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedNotification {
+    pub id: Uuid,
+    pub notification: Notification,
+    pub status: FailedNotificationStatus,
+    /// 試行回数（最初の送信の失敗を含む）
+    pub attempts: u32,
+    /// 最後に失敗した理由
+    pub last_error: String,
+    /// 次に再送する日時（再送待ち以外はNone）
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl FailedNotification {
+    /// 送信に失敗した通知を再送待ちとして作成
+    ///
+    /// # Arguments
+    /// * `notification` - 送信できなかった通知
+    /// * `error` - 送信に失敗した理由
+    /// * `policy` - 再送ポリシー
+    /// * `now` - 失敗した日時
+    pub fn new(
+        notification: Notification,
+        error: &str,
+        policy: &NotificationRetryPolicy,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut failed = Self {
+            id: Uuid::new_v4(),
+            notification,
+            status: FailedNotificationStatus::Pending,
+            attempts: 0,
+            last_error: String::new(),
+            next_attempt_at: None,
+            created_at: now,
+            updated_at: now,
+            delivered_at: None,
+        };
+        failed.record_failure(error, policy, now);
+        failed
+    }
+
+    /// 送信できたことを記録する
+    pub fn record_success(&mut self, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = FailedNotificationStatus::Delivered;
+        self.next_attempt_at = None;
+        self.updated_at = now;
+        self.delivered_at = Some(now);
+    }
+
+    /// 送信に失敗したことを記録する
+    /// チャネルの試行回数の上限に達したら再送をあきらめ、それ以外は待ち時間を空けて再送する
+    pub fn record_failure(
+        &mut self,
+        error: &str,
+        policy: &NotificationRetryPolicy,
+        now: DateTime<Utc>,
+    ) {
+        let channel_policy = policy.for_channel(self.notification.channel);
+        self.attempts += 1;
+        self.last_error = error.to_string();
+        self.updated_at = now;
+        if self.attempts >= channel_policy.max_attempts {
+            self.status = FailedNotificationStatus::Undeliverable;
+            self.next_attempt_at = None;
+        } else {
+            self.next_attempt_at = Some(now + channel_policy.backoff_after(self.attempts));
+        }
+    }
+
+    /// 手動で再送できるかチェック（送信済みの通知は再送しない）
+    pub fn ensure_resendable(&self) -> Result<(), DomainError> {
+        if self.status == FailedNotificationStatus::Delivered {
+            return Err(DomainError::InvalidValue(format!(
+                "通知は既に送信済みです: {}",
+                self.id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 送信に失敗した通知の再送ジョブ
+/// 再送の日時を過ぎた通知を送り直し、状態を更新する
+#[derive(Clone)]
+pub struct NotificationRetrier {
+    repository: Arc<dyn FailedNotificationRepository>,
+    sender: Arc<dyn NotificationSender>,
+    policy: NotificationRetryPolicy,
+    logger: Arc<dyn Logger>,
+}
+
+impl NotificationRetrier {
+    pub fn new(
+        repository: Arc<dyn FailedNotificationRepository>,
+        sender: Arc<dyn NotificationSender>,
+        policy: NotificationRetryPolicy,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            repository,
+            sender,
+            policy,
+            logger,
+        }
+    }
+
+    /// ジョブをバックグラウンドで起動
+    pub fn spawn(self, retry_interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(retry_interval);
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// 再送の日時を過ぎた通知を再送する
+    ///
+    /// # Returns
+    /// * 再送で送信できた通知のIDのリスト
+    pub async fn run(&self, now: DateTime<Utc>) -> Result<Vec<Uuid>, RepositoryError> {
+        let mut delivered = Vec::new();
+        for mut failed in self
+            .repository
+            .find_due(now, NOTIFICATION_RETRY_BATCH_SIZE)
+            .await?
+        {
+            match self.sender.send(&failed.notification).await {
+                Ok(()) => failed.record_success(now),
+                Err(e) => failed.record_failure(&e.to_string(), &self.policy, now),
+            }
+            self.repository.save(&failed).await?;
+            match failed.status {
+                FailedNotificationStatus::Delivered => delivered.push(failed.id),
+                FailedNotificationStatus::Undeliverable => self.log_undeliverable(&failed),
+                FailedNotificationStatus::Pending => {}
+            }
+        }
+        Ok(delivered)
+    }
+
+    fn log_undeliverable(&self, failed: &FailedNotification) {
+        let mut context = HashMap::new();
+        context.insert("notification_id".to_string(), failed.id.to_string());
+        context.insert(
+            "channel".to_string(),
+            failed.notification.channel.to_string(),
+        );
+        context.insert(
+            "order_id".to_string(),
+            failed.notification.order_id.to_string(),
+        );
+        context.insert("error".to_string(), failed.last_error.clone());
+        self.logger.warn(
+            "NotificationRetrier",
+            "Gave up retrying notification",
+            Some(failed.notification.correlation_id),
+            Some(context),
+        );
+    }
+}
+
+#[async_trait]
+impl SupervisedTask for NotificationRetrier {
+    async fn run_once(&self) {
+        if let Err(e) = self.run(Utc::now()).await {
+            let mut context = HashMap::new();
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "NotificationRetrier",
+                "Failed to load failed notifications",
+                None,
+                Some(context),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(channel: NotificationChannel) -> Notification {
+        Notification {
+            channel,
+            order_id: OrderId::new(),
+            phone: None,
+            event_type: "OrderShipped".to_string(),
+            message: "ご注文が発送されました".to_string(),
+            correlation_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_failed_notification_backs_off_per_channel_until_undeliverable() {
+        let policy = NotificationRetryPolicy::default();
+        let now = Utc::now();
+
+        // メールは1分・2分・4分と待ち時間を倍にして、5回目の失敗であきらめる
+        let mut email = FailedNotification::new(
+            notification(NotificationChannel::Email),
+            "smtp timeout",
+            &policy,
+            now,
+        );
+        assert_eq!(email.status, FailedNotificationStatus::Pending);
+        assert_eq!(email.next_attempt_at, Some(now + TimeDelta::minutes(1)));
+        email.record_failure("smtp timeout", &policy, now);
+        assert_eq!(email.next_attempt_at, Some(now + TimeDelta::minutes(2)));
+        for _ in 0..3 {
+            email.record_failure("smtp timeout", &policy, now);
+        }
+        assert_eq!(email.attempts, 5);
+        assert_eq!(email.status, FailedNotificationStatus::Undeliverable);
+        assert_eq!(email.next_attempt_at, None);
+        assert!(email.ensure_resendable().is_ok());
+
+        // SMSは5分から始めて、3回目の失敗であきらめる
+        let mut sms = FailedNotification::new(
+            notification(NotificationChannel::Sms),
+            "carrier rejected",
+            &policy,
+            now,
+        );
+        assert_eq!(sms.next_attempt_at, Some(now + TimeDelta::minutes(5)));
+        sms.record_success(now);
+        assert_eq!(sms.status, FailedNotificationStatus::Delivered);
+        assert!(sms.ensure_resendable().is_err());
+
+        // 待ち時間は上限を超えない
+        assert_eq!(policy.email.backoff_after(20), TimeDelta::hours(1));
+    }
+}
@@ -0,0 +1,315 @@
+use crate::domain::book_translation::Language;
+use crate::domain::error::DomainError;
+use crate::domain::validation::{Constraint, FieldViolation};
+use std::collections::HashMap;
+
+/// 通知のテンプレートの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotificationTemplateKey {
+    OrderConfirmed,
+    OrderShipped,
+    /// ギフト注文の発送（受取人への通知）
+    GiftShipped,
+    OrderDelivered,
+    /// ギフト注文の配達完了（受取人への通知）
+    GiftDelivered,
+    DigitalItemsFulfilled,
+    /// 発送のSLA超過
+    ShippingDelayed,
+    /// 配達のSLA超過
+    DeliveryDelayed,
+    WaitlistJoined,
+    /// 順番待ちの書籍ごとの順番（WaitlistJoinedの {positions} に並べる）
+    WaitlistPosition,
+    WaitlistPromoted,
+    OrderCancelled,
+    /// 注文番号のある注文の表記（各通知の {order}）
+    OrderNumberLabel,
+    /// 注文番号のない注文の表記（各通知の {order}）
+    OrderIdLabel,
+}
+
+impl NotificationTemplateKey {
+    /// テンプレートの種類を表す名前
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotificationTemplateKey::OrderConfirmed => "order_confirmed",
+            NotificationTemplateKey::OrderShipped => "order_shipped",
+            NotificationTemplateKey::GiftShipped => "gift_shipped",
+            NotificationTemplateKey::OrderDelivered => "order_delivered",
+            NotificationTemplateKey::GiftDelivered => "gift_delivered",
+            NotificationTemplateKey::DigitalItemsFulfilled => "digital_items_fulfilled",
+            NotificationTemplateKey::ShippingDelayed => "shipping_delayed",
+            NotificationTemplateKey::DeliveryDelayed => "delivery_delayed",
+            NotificationTemplateKey::WaitlistJoined => "waitlist_joined",
+            NotificationTemplateKey::WaitlistPosition => "waitlist_position",
+            NotificationTemplateKey::WaitlistPromoted => "waitlist_promoted",
+            NotificationTemplateKey::OrderCancelled => "order_cancelled",
+            NotificationTemplateKey::OrderNumberLabel => "order_number_label",
+            NotificationTemplateKey::OrderIdLabel => "order_id_label",
+        }
+    }
+
+    /// テンプレートで使えるプレースホルダー
+    pub fn placeholders(&self) -> &'static [&'static str] {
+        match self {
+            NotificationTemplateKey::OrderConfirmed => &["order", "total"],
+            NotificationTemplateKey::OrderShipped => &["order", "address"],
+            NotificationTemplateKey::GiftShipped => &["order", "address", "recipient"],
+            NotificationTemplateKey::GiftDelivered => &["order", "recipient"],
+            NotificationTemplateKey::DigitalItemsFulfilled => &["order", "links"],
+            NotificationTemplateKey::WaitlistJoined => &["order", "positions"],
+            NotificationTemplateKey::WaitlistPosition => &["book_id", "position"],
+            NotificationTemplateKey::OrderNumberLabel => &["order_number"],
+            NotificationTemplateKey::OrderIdLabel => &["order_id"],
+            NotificationTemplateKey::OrderDelivered
+            | NotificationTemplateKey::ShippingDelayed
+            | NotificationTemplateKey::DeliveryDelayed
+            | NotificationTemplateKey::WaitlistPromoted
+            | NotificationTemplateKey::OrderCancelled => &["order"],
+        }
+    }
+}
+
+/// 組み込みのテンプレート（日本語）
+const JAPANESE_TEMPLATES: [(NotificationTemplateKey, &str); 14] = [
+    (NotificationTemplateKey::OrderConfirmed, "ご注文が確定されました。{order}, 合計金額: {total}円"),
+    (NotificationTemplateKey::OrderShipped, "ご注文が発送されました。{order}, 配送先: {address}"),
+    (NotificationTemplateKey::GiftShipped, "{recipient}様へのギフトが発送されました。{order}, 配送先: {address}"),
+    (NotificationTemplateKey::OrderDelivered, "ご注文の配達が完了しました。{order}"),
+    (NotificationTemplateKey::GiftDelivered, "{recipient}様へのギフトの配達が完了しました。{order}"),
+    (NotificationTemplateKey::DigitalItemsFulfilled, "電子書籍のダウンロードリンクを発行しました。{order}, リンク: {links}"),
+    (NotificationTemplateKey::ShippingDelayed, "ご注文の発送が遅れております。{order}"),
+    (NotificationTemplateKey::DeliveryDelayed, "ご注文のお届けが遅れております。{order}"),
+    (NotificationTemplateKey::WaitlistJoined, "在庫が不足しているため、ご注文を順番待ちに登録しました。入荷次第、順番にお届けします。{order}, 順番: {positions}"),
+    (NotificationTemplateKey::WaitlistPosition, "書籍 {book_id}: {position}番目"),
+    (NotificationTemplateKey::WaitlistPromoted, "順番待ちのご注文の在庫を確保しました。発送の準備を始めます。{order}"),
+    (NotificationTemplateKey::OrderCancelled, "ご注文がキャンセルされました。{order}"),
+    (NotificationTemplateKey::OrderNumberLabel, "注文番号: {order_number}"),
+    (NotificationTemplateKey::OrderIdLabel, "注文ID: {order_id}"),
+];
+
+/// 組み込みのテンプレート（英語）
+const ENGLISH_TEMPLATES: [(NotificationTemplateKey, &str); 14] = [
+    (NotificationTemplateKey::OrderConfirmed, "Your order has been confirmed. {order}, Total: ¥{total}"),
+    (NotificationTemplateKey::OrderShipped, "Your order has been shipped. {order}, Ship to: {address}"),
+    (NotificationTemplateKey::GiftShipped, "Your gift for {recipient} has been shipped. {order}, Ship to: {address}"),
+    (NotificationTemplateKey::OrderDelivered, "Your order has been delivered. {order}"),
+    (NotificationTemplateKey::GiftDelivered, "Your gift for {recipient} has been delivered. {order}"),
+    (NotificationTemplateKey::DigitalItemsFulfilled, "Download links for your e-books are ready. {order}, Links: {links}"),
+    (NotificationTemplateKey::ShippingDelayed, "Shipment of your order is delayed. {order}"),
+    (NotificationTemplateKey::DeliveryDelayed, "Delivery of your order is delayed. {order}"),
+    (NotificationTemplateKey::WaitlistJoined, "Some items are out of stock, so your order has been added to the waitlist. We will ship in turn as stock arrives. {order}, Position: {positions}"),
+    (NotificationTemplateKey::WaitlistPosition, "Book {book_id}: #{position}"),
+    (NotificationTemplateKey::WaitlistPromoted, "Stock for your waitlisted order has been secured. We are preparing it for shipment. {order}"),
+    (NotificationTemplateKey::OrderCancelled, "Your order has been cancelled. {order}"),
+    (NotificationTemplateKey::OrderNumberLabel, "Order number: {order_number}"),
+    (NotificationTemplateKey::OrderIdLabel, "Order ID: {order_id}"),
+];
+
+/// テンプレートのプレースホルダーに埋め込む値
+#[derive(Debug, Clone, Default)]
+pub struct TemplateParams {
+    values: HashMap<&'static str, String>,
+}
+
+impl TemplateParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// プレースホルダーの値を設定
+    pub fn with(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.values.insert(name, value.to_string());
+        self
+    }
+
+    /// プレースホルダーの値を取得（未設定の場合はNone）
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+}
+
+/// テンプレートに含まれるプレースホルダー（`{name}`）の名前を出現順に返す
+fn placeholder_names(template: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        names.push(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+    names
+}
+
+/// 通知のテンプレートエンジン
+/// 注文のイベントの通知を、言語ごとのテンプレートのプレースホルダー（`{order}`・`{total}`・`{address}` など）に値を埋め込んで作成する
+/// 日本語と英語のテンプレートを組み込みで持ち、テンプレートのない言語は既定の言語（日本語）で作成する
+#[derive(Debug, Clone)]
+pub struct NotificationTemplateEngine {
+    templates: HashMap<(Language, NotificationTemplateKey), String>,
+}
+
+impl Default for NotificationTemplateEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationTemplateEngine {
+    /// 組み込みのテンプレート（日本語・英語）でテンプレートエンジンを作成
+    pub fn new() -> Self {
+        let templates = JAPANESE_TEMPLATES
+            .iter()
+            .map(|(key, template)| ((Language::Ja, *key), template.to_string()))
+            .chain(
+                ENGLISH_TEMPLATES
+                    .iter()
+                    .map(|(key, template)| ((Language::En, *key), template.to_string())),
+            )
+            .collect();
+        Self { templates }
+    }
+
+    /// テンプレートを差し替える
+    ///
+    /// # Arguments
+    /// * `language` - テンプレートの言語
+    /// * `key` - テンプレートの種類
+    /// * `template` - テンプレート（種類ごとに使えるプレースホルダーのみ）
+    ///
+    /// # Returns
+    /// * `Ok(Self)` - テンプレートを差し替えたテンプレートエンジン
+    /// * `Err(DomainError)` - 使えないプレースホルダーが含まれている
+    pub fn with_template(
+        mut self,
+        language: Language,
+        key: NotificationTemplateKey,
+        template: &str,
+    ) -> Result<Self, DomainError> {
+        if let Some(unknown) = placeholder_names(template)
+            .into_iter()
+            .find(|name| !key.placeholders().contains(name))
+        {
+            return Err(FieldViolation::new(
+                "template",
+                Constraint::OneOf,
+                Some(template.to_string()),
+                format!(
+                    "テンプレート {} で使えないプレースホルダーです: {{{}}}（{}）",
+                    key.name(),
+                    unknown,
+                    key.placeholders()
+                        .iter()
+                        .map(|name| format!("{{{}}}", name))
+                        .collect::<Vec<_>>()
+                        .join("・")
+                ),
+            )
+            .into());
+        }
+        self.templates.insert((language, key), template.to_string());
+        Ok(self)
+    }
+
+    /// 通知のメッセージを作成する
+    /// 値が設定されていないプレースホルダーは空文字列にする
+    ///
+    /// # Arguments
+    /// * `key` - テンプレートの種類
+    /// * `language` - 通知の言語（テンプレートがない場合は既定の言語）
+    /// * `params` - プレースホルダーに埋め込む値
+    pub fn render(
+        &self,
+        key: NotificationTemplateKey,
+        language: Language,
+        params: &TemplateParams,
+    ) -> String {
+        let template = self
+            .templates
+            .get(&(language, key))
+            .or_else(|| self.templates.get(&(Language::DEFAULT, key)))
+            .map_or("", String::as_str);
+
+        let mut message = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}') else {
+                break;
+            };
+            message.push_str(&rest[..start]);
+            message.push_str(params.get(&rest[start + 1..start + end]).unwrap_or(""));
+            rest = &rest[start + end + 1..];
+        }
+        message.push_str(rest);
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_selects_language_and_falls_back_to_default_template() {
+        let engine = NotificationTemplateEngine::new();
+        let params = TemplateParams::new()
+            .with("order", "BK-1")
+            .with("total", 3000);
+        assert_eq!(
+            engine.render(
+                NotificationTemplateKey::OrderConfirmed,
+                Language::Ja,
+                &params
+            ),
+            "ご注文が確定されました。BK-1, 合計金額: 3000円"
+        );
+        assert_eq!(
+            engine.render(
+                NotificationTemplateKey::OrderConfirmed,
+                Language::En,
+                &params
+            ),
+            "Your order has been confirmed. BK-1, Total: ¥3000"
+        );
+
+        // 値のないプレースホルダーは空にする
+        assert_eq!(
+            engine.render(
+                NotificationTemplateKey::OrderCancelled,
+                Language::En,
+                &TemplateParams::new()
+            ),
+            "Your order has been cancelled. "
+        );
+    }
+
+    #[test]
+    fn test_with_template_rejects_unknown_placeholders() {
+        let engine = NotificationTemplateEngine::new()
+            .with_template(
+                Language::En,
+                NotificationTemplateKey::OrderCancelled,
+                "{order} was cancelled",
+            )
+            .unwrap();
+        assert_eq!(
+            engine.render(
+                NotificationTemplateKey::OrderCancelled,
+                Language::En,
+                &TemplateParams::new().with("order", "Order number: BK-1")
+            ),
+            "Order number: BK-1 was cancelled"
+        );
+
+        assert!(NotificationTemplateEngine::new()
+            .with_template(
+                Language::Ja,
+                NotificationTemplateKey::OrderCancelled,
+                "ご注文がキャンセルされました。合計金額: {total}円",
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::domain::event::DomainEvent;
+use crate::domain::model::OrderId;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// 注文ごとのイベントストリームの絞り込み
+/// 発行されたイベントのうち、指定した注文に関するものだけを通す
+/// サーガの補償イベントは集約IDがサーガID（相関ID）のため、注文のイベントで見た相関IDと一致するものを通す
+#[derive(Debug, Clone)]
+pub struct OrderEventStreamFilter {
+    order_id: String,
+    /// 注文のイベントで見た相関ID（サーガID）
+    saga_ids: HashSet<Uuid>,
+}
+
+impl OrderEventStreamFilter {
+    /// 注文を指定して作成
+    pub fn new(order_id: OrderId) -> Self {
+        Self {
+            order_id: order_id.to_string(),
+            saga_ids: HashSet::new(),
+        }
+    }
+
+    /// イベントが注文に関するものか判定する
+    /// 注文のイベントの相関IDを覚えておき、以降の同じサーガの補償イベントも通す
+    pub fn matches(&mut self, event: &DomainEvent) -> bool {
+        match event {
+            DomainEvent::SagaCompensationStarted(e) => self.saga_ids.contains(&e.saga_id),
+            DomainEvent::SagaCompensationCompleted(e) => self.saga_ids.contains(&e.saga_id),
+            _ if event.aggregate_id() == self.order_id => {
+                self.saga_ids.insert(event.metadata().correlation_id);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event::{
+        CompensationResult, OrderCancelled, OrderConfirmed, SagaCompensationCompleted,
+        SagaCompensationStarted,
+    };
+    use crate::domain::model::{CustomerId, Money};
+
+    #[test]
+    fn test_matches_order_events_and_compensation_of_its_saga() {
+        let order_id = OrderId::new();
+        let mut filter = OrderEventStreamFilter::new(order_id);
+
+        let confirmed = OrderConfirmed::new(order_id, CustomerId::new(), vec![], Money::jpy(0));
+        let saga_id = confirmed.metadata.correlation_id;
+        let other_order =
+            OrderConfirmed::new(OrderId::new(), CustomerId::new(), vec![], Money::jpy(0));
+
+        // 注文のイベントを見る前の補償イベントは、どの注文のものか分からないため通さない
+        let started = SagaCompensationStarted::new(
+            saga_id,
+            "reserve_inventory".to_string(),
+            "在庫不足".to_string(),
+            vec!["cancel_order".to_string()],
+        );
+        assert!(!filter.matches(&DomainEvent::SagaCompensationStarted(started.clone())));
+
+        assert!(filter.matches(&DomainEvent::OrderConfirmed(confirmed)));
+        assert!(!filter.matches(&DomainEvent::OrderConfirmed(other_order)));
+        assert!(filter.matches(&DomainEvent::SagaCompensationStarted(started)));
+        assert!(filter.matches(&DomainEvent::SagaCompensationCompleted(
+            SagaCompensationCompleted::new(
+                saga_id,
+                vec!["cancel_order".to_string()],
+                CompensationResult::Success,
+            )
+        )));
+        assert!(!filter.matches(&DomainEvent::SagaCompensationCompleted(
+            SagaCompensationCompleted::new(Uuid::new_v4(), vec![], CompensationResult::Success)
+        )));
+        assert!(
+            filter.matches(&DomainEvent::OrderCancelled(OrderCancelled::new(
+                order_id,
+                CustomerId::new(),
+                vec![],
+            )))
+        );
+    }
+}
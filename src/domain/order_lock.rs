@@ -0,0 +1,93 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::OrderId;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+
+/// 注文の編集ロックの期間のデフォルト値（分）
+pub const DEFAULT_ORDER_LOCK_TTL_MINUTES: i64 = 30;
+
+/// 注文の編集ロックの期間の上限（分）
+pub const MAX_ORDER_LOCK_TTL_MINUTES: i64 = 8 * 60;
+
+/// 注文の編集ロック
+/// オペレーターが時間のかかる編集（住所の修正・明細の組み直しなど）をしている間、
+/// 他のオペレーターや顧客の操作で注文が変更されないよう、期限付きで注文を押さえる
+/// 期限を過ぎたロックは解除されたものとみなす
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OrderLock {
+    pub order_id: OrderId,
+    /// ロックしているオペレーター
+    pub locked_by: String,
+    pub locked_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl OrderLock {
+    /// 新しい編集ロックを作成
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `locked_by` - ロックするオペレーター
+    /// * `now` - ロックした日時
+    /// * `ttl` - ロックの期間
+    pub fn new(order_id: OrderId, locked_by: String, now: DateTime<Utc>, ttl: TimeDelta) -> Self {
+        Self {
+            order_id,
+            locked_by,
+            locked_at: now,
+            expires_at: now + ttl,
+        }
+    }
+
+    /// 期限を過ぎているか
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    /// 指定したオペレーターの操作を妨げるか
+    /// 期限内のロックは、ロックしているオペレーター以外（オペレーターを名乗らない操作を含む）の操作を妨げる
+    ///
+    /// # Arguments
+    /// * `operator` - 操作しようとしているオペレーター（顧客の操作など、名乗らない場合はNone）
+    /// * `now` - 現在日時
+    pub fn blocks(&self, operator: Option<&str>, now: DateTime<Utc>) -> bool {
+        !self.is_expired(now) && operator != Some(self.locked_by.as_str())
+    }
+
+    /// ロックによって操作できないことを表すエラー
+    pub fn locked_error(&self) -> DomainError {
+        DomainError::OrderLocked(format!(
+            "注文 {} は {} が {} まで編集中です",
+            self.order_id,
+            self.locked_by,
+            self.expires_at.to_rfc3339()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_lock_blocks_other_operators_until_expired() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 29, 9, 0, 0).unwrap();
+        let lock = OrderLock::new(
+            OrderId::new(),
+            "alice".to_string(),
+            now,
+            TimeDelta::minutes(DEFAULT_ORDER_LOCK_TTL_MINUTES),
+        );
+
+        assert!(!lock.blocks(Some("alice"), now));
+        assert!(lock.blocks(Some("bob"), now));
+        assert!(lock.blocks(None, now));
+        assert!(matches!(lock.locked_error(), DomainError::OrderLocked(_)));
+
+        let expired_at = now + TimeDelta::minutes(DEFAULT_ORDER_LOCK_TTL_MINUTES);
+        assert!(lock.is_expired(expired_at));
+        assert!(!lock.blocks(Some("bob"), expired_at));
+        assert!(!lock.blocks(None, expired_at));
+    }
+}
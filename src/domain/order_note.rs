@@ -0,0 +1,64 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::OrderId;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// CSメモの本文の最大文字数
+pub const MAX_ORDER_NOTE_LENGTH: usize = 1000;
+
+/// 注文のCSメモ
+/// カスタマーサポートのオペレーターが問い合わせ対応の経緯などを注文に残す（注文の状態は変えない）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OrderNote {
+    pub note_id: Uuid,
+    pub order_id: OrderId,
+    /// メモを書いたオペレーター
+    pub author: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OrderNote {
+    /// 新しいCSメモを作成
+    /// オペレーターと本文は前後の空白を除いて空にできず、本文は最大文字数まで
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `author` - メモを書いたオペレーター
+    /// * `body` - 本文
+    /// * `now` - 作成日時
+    pub fn new(
+        order_id: OrderId,
+        author: &str,
+        body: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let author = author.trim();
+        if author.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "メモを書いたオペレーターを指定してください".to_string(),
+            ));
+        }
+        let body = body.trim();
+        if body.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "メモの本文を入力してください".to_string(),
+            ));
+        }
+        if body.chars().count() > MAX_ORDER_NOTE_LENGTH {
+            return Err(DomainError::InvalidValue(format!(
+                "メモの本文は{}文字以内で入力してください",
+                MAX_ORDER_NOTE_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            note_id: Uuid::new_v4(),
+            order_id,
+            author: author.to_string(),
+            body: body.to_string(),
+            created_at: now,
+        })
+    }
+}
@@ -0,0 +1,302 @@
+use crate::domain::event::{DomainEvent, OrderConfirmed, OrderShipped};
+use crate::domain::event_export::JournaledEvent;
+use crate::domain::model::{Order, OrderId, OrderStatus};
+use crate::domain::serialization::EventSerializer;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// 停滞とみなすまでの猶予（分）
+/// 確定・発送の直後はイベントの処理中のことがあるため、この時間を過ぎた注文だけを診断する
+pub const STUCK_ORDER_GRACE_PERIOD_MINUTES: i64 = 5;
+
+/// 停滞した注文で検出する問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StuckOrderIssue {
+    /// Confirmed状態のまま在庫予約（InventoryReserved）が記録されていない
+    MissingInventoryReservation,
+    /// Shipped状態なのにOrderShippedが発行されていない
+    MissingShippedEvent,
+}
+
+/// 問題に対して適用する修復手順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepairRemediation {
+    /// ジャーナルに記録されたイベントを同じイベントIDのまま再発行する
+    RepublishJournaledEvent,
+    /// 注文の現在の状態からイベントを作り直して発行する
+    PublishRebuiltEvent,
+}
+
+/// 問題ごとの修復手順と、そのために発行するイベント
+#[derive(Debug, Clone)]
+pub struct PlannedRepair {
+    pub issue: StuckOrderIssue,
+    pub remediation: RepairRemediation,
+    pub event: DomainEvent,
+}
+
+/// 修復レポートの1行
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OrderRepairAction {
+    pub issue: StuckOrderIssue,
+    pub remediation: RepairRemediation,
+    pub event_type: String,
+    pub event_id: Uuid,
+    /// イベントを発行したかどうか（ドライランではfalse）
+    pub applied: bool,
+}
+
+/// 停滞した注文の診断と修復のレポート
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OrderRepairReport {
+    pub order_id: OrderId,
+    pub status: String,
+    pub dry_run: bool,
+    pub generated_at: DateTime<Utc>,
+    /// 検出した問題ごとの修復（問題がなければ空）
+    pub actions: Vec<OrderRepairAction>,
+}
+
+impl OrderRepairReport {
+    /// 修復手順と、それぞれのイベントを発行したかどうかからレポートを作成
+    ///
+    /// # Arguments
+    /// * `order` - 診断した注文
+    /// * `dry_run` - ドライランかどうか
+    /// * `generated_at` - レポートの作成日時
+    /// * `results` - 修復手順と、イベントを発行したかどうかの組
+    pub fn build(
+        order: &Order,
+        dry_run: bool,
+        generated_at: DateTime<Utc>,
+        results: &[(PlannedRepair, bool)],
+    ) -> Self {
+        Self {
+            order_id: order.id(),
+            status: order.status().to_string(),
+            dry_run,
+            generated_at,
+            actions: results
+                .iter()
+                .map(|(repair, applied)| OrderRepairAction {
+                    issue: repair.issue,
+                    remediation: repair.remediation,
+                    event_type: repair.event.event_type().to_string(),
+                    event_id: repair.event.metadata().event_id,
+                    applied: *applied,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 注文の状態とジャーナルに記録されたイベントを突き合わせて、停滞している問題を検出する
+///
+/// # Arguments
+/// * `order` - 診断する注文
+/// * `journaled_events` - 注文を集約IDとしてジャーナルに記録されたイベント
+/// * `now` - 診断日時
+///
+/// # Returns
+/// * 検出した問題（猶予期間内の注文や、問題のない注文では空）
+pub fn diagnose(
+    order: &Order,
+    journaled_events: &[JournaledEvent],
+    now: DateTime<Utc>,
+) -> Vec<StuckOrderIssue> {
+    let grace_period = TimeDelta::minutes(STUCK_ORDER_GRACE_PERIOD_MINUTES);
+    let settled = |at: Option<DateTime<Utc>>| at.is_some_and(|at| at + grace_period <= now);
+    let journaled = |event_type: &str| {
+        journaled_events
+            .iter()
+            .any(|event| event.event_type == event_type)
+    };
+
+    let mut issues = Vec::new();
+    match order.status() {
+        OrderStatus::Confirmed
+            if settled(order.confirmed_at()) && !journaled("InventoryReserved") =>
+        {
+            issues.push(StuckOrderIssue::MissingInventoryReservation);
+        }
+        OrderStatus::Shipped
+            if settled(order.shipped_at())
+                && order.shipping_address().is_some()
+                && !journaled("OrderShipped") =>
+        {
+            issues.push(StuckOrderIssue::MissingShippedEvent);
+        }
+        _ => {}
+    }
+    issues
+}
+
+/// 検出した問題ごとに修復手順を決める
+/// 在庫予約の漏れはOrderConfirmedを発行し直して在庫予約をやり直す
+/// ジャーナルにOrderConfirmedがあれば同じイベントIDのまま再発行し、処理済みのハンドラーには読み飛ばさせる
+/// OrderShippedの漏れはイベントを作り直して発行する
+///
+/// # Arguments
+/// * `order` - 診断した注文
+/// * `issues` - 検出した問題
+/// * `journaled_events` - 注文を集約IDとしてジャーナルに記録されたイベント
+///
+/// # Returns
+/// * 問題ごとの修復手順
+pub fn plan_repairs(
+    order: &Order,
+    issues: &[StuckOrderIssue],
+    journaled_events: &[JournaledEvent],
+) -> Vec<PlannedRepair> {
+    issues
+        .iter()
+        .filter_map(|issue| match issue {
+            StuckOrderIssue::MissingInventoryReservation => {
+                let journaled = journaled_events
+                    .iter()
+                    .rev()
+                    .filter(|event| event.event_type == "OrderConfirmed")
+                    .find_map(|event| {
+                        EventSerializer::new()
+                            .deserialize_event(&event.payload)
+                            .ok()
+                    });
+                Some(match journaled {
+                    Some(event) => PlannedRepair {
+                        issue: *issue,
+                        remediation: RepairRemediation::RepublishJournaledEvent,
+                        event,
+                    },
+                    None => PlannedRepair {
+                        issue: *issue,
+                        remediation: RepairRemediation::PublishRebuiltEvent,
+                        event: rebuild_order_confirmed(order),
+                    },
+                })
+            }
+            StuckOrderIssue::MissingShippedEvent => {
+                rebuild_order_shipped(order).map(|event| PlannedRepair {
+                    issue: *issue,
+                    remediation: RepairRemediation::PublishRebuiltEvent,
+                    event,
+                })
+            }
+        })
+        .collect()
+}
+
+/// 注文の現在の状態からOrderConfirmedを作り直す（金額は確定時の金額を使う）
+fn rebuild_order_confirmed(order: &Order) -> DomainEvent {
+    let total_amount = order
+        .confirmed_totals()
+        .map(|totals| totals.total)
+        .unwrap_or_else(|| order.calculate_total());
+    let mut event = OrderConfirmed::new(
+        order.id(),
+        order.customer_id(),
+        order.order_lines().to_vec(),
+        total_amount,
+    );
+    event.metadata.correlation_id = saga_correlation_id(order);
+    DomainEvent::OrderConfirmed(event)
+}
+
+/// 注文の現在の状態からOrderShippedを作り直す（配送先住所のない注文はNone）
+fn rebuild_order_shipped(order: &Order) -> Option<DomainEvent> {
+    let shipping_address = order.shipping_address()?.clone();
+    let mut event =
+        OrderShipped::new(order.id(), shipping_address).with_recipient(order.recipient().cloned());
+    event.metadata.correlation_id = saga_correlation_id(order);
+    Some(DomainEvent::OrderShipped(event))
+}
+
+/// 作り直したイベントには確定時に始まったサーガの相関IDを引き継ぐ
+fn saga_correlation_id(order: &Order) -> Uuid {
+    order.saga_correlation_id().unwrap_or_else(Uuid::new_v4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event::InventoryReserved;
+    use crate::domain::model::{BookId, CustomerId, Money, ShippingAddress};
+
+    fn journaled(position: u64, event: &DomainEvent) -> JournaledEvent {
+        JournaledEvent {
+            position,
+            event_id: event.metadata().event_id,
+            event_type: event.event_type().to_string(),
+            occurred_at: event.metadata().occurred_at,
+            payload: EventSerializer::new().serialize_event(event).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_stuck_orders_are_diagnosed_after_the_grace_period() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1500043".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "道玄坂1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        order.confirm().unwrap();
+        let saga_id = order.begin_saga(Uuid::new_v4());
+        let later = Utc::now() + TimeDelta::minutes(STUCK_ORDER_GRACE_PERIOD_MINUTES);
+
+        // 確定直後は在庫予約の処理中の可能性があるため診断しない
+        assert!(diagnose(&order, &[], Utc::now()).is_empty());
+        assert_eq!(
+            diagnose(&order, &[], later),
+            vec![StuckOrderIssue::MissingInventoryReservation]
+        );
+
+        // ジャーナルのOrderConfirmedは同じイベントIDのまま再発行する
+        let confirmed = rebuild_order_confirmed(&order);
+        let journal = vec![journaled(1, &confirmed)];
+        let repairs = plan_repairs(
+            &order,
+            &[StuckOrderIssue::MissingInventoryReservation],
+            &journal,
+        );
+        assert_eq!(
+            repairs[0].remediation,
+            RepairRemediation::RepublishJournaledEvent
+        );
+        assert_eq!(
+            repairs[0].event.metadata().event_id,
+            confirmed.metadata().event_id
+        );
+
+        // 在庫予約が記録されていれば問題なし
+        let reserved = DomainEvent::InventoryReserved(InventoryReserved::with_correlation_id(
+            order.id(),
+            order.order_lines().to_vec(),
+            saga_id,
+        ));
+        assert!(diagnose(&order, &[journaled(2, &reserved)], later).is_empty());
+
+        // 発送済みなのにOrderShippedがなければ、サーガの相関IDで作り直す
+        order.mark_as_shipped().unwrap();
+        let later = Utc::now() + TimeDelta::minutes(STUCK_ORDER_GRACE_PERIOD_MINUTES);
+        let issues = diagnose(&order, &journal, later);
+        assert_eq!(issues, vec![StuckOrderIssue::MissingShippedEvent]);
+        let repairs = plan_repairs(&order, &issues, &journal);
+        assert_eq!(
+            repairs[0].remediation,
+            RepairRemediation::PublishRebuiltEvent
+        );
+        assert_eq!(repairs[0].event.event_type(), "OrderShipped");
+        assert_eq!(repairs[0].event.metadata().correlation_id, saga_id);
+    }
+}
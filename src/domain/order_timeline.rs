@@ -0,0 +1,209 @@
+use crate::domain::event_export::JournaledEvent;
+use crate::domain::model::{Order, OrderId, OrderStatus};
+use crate::domain::order_note::OrderNote;
+use crate::domain::tracking::{TrackingEvent, TrackingStage};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// 注文タイムラインの1件（種類ごとに内容が異なる）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TimelineEntry {
+    /// 注文に関するドメインイベント（イベントジャーナルに記録されたもの）
+    Event {
+        occurred_at: DateTime<Utc>,
+        event_id: Uuid,
+        event_type: String,
+    },
+    /// 注文のステータスの遷移（注文に記録された遷移日時）
+    StatusChanged {
+        occurred_at: DateTime<Utc>,
+        status: String,
+    },
+    /// CSメモ
+    Note {
+        occurred_at: DateTime<Utc>,
+        note_id: Uuid,
+        author: String,
+        body: String,
+    },
+    /// 配送業者から届いた追跡情報
+    Tracking {
+        occurred_at: DateTime<Utc>,
+        stage: TrackingStage,
+        label: &'static str,
+        location: Option<String>,
+        description: Option<String>,
+    },
+}
+
+impl TimelineEntry {
+    /// タイムラインに並べる日時
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            TimelineEntry::Event { occurred_at, .. }
+            | TimelineEntry::StatusChanged { occurred_at, .. }
+            | TimelineEntry::Note { occurred_at, .. }
+            | TimelineEntry::Tracking { occurred_at, .. } => *occurred_at,
+        }
+    }
+}
+
+/// 注文タイムライン（サポート向け）
+/// ドメインイベント・ステータスの遷移・CSメモ・配送業者の追跡情報を1つの時系列にまとめる
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OrderTimeline {
+    pub order_id: OrderId,
+    /// 現在のステータス
+    pub status: String,
+    /// 発生日時の昇順（同じ日時の場合はイベント・ステータス・追跡情報・メモの順）
+    pub entries: Vec<TimelineEntry>,
+}
+
+impl OrderTimeline {
+    /// 注文とそれぞれの記録からタイムラインを作成
+    /// 注文イベントから投影された追跡情報は元のイベントと重複するため、配送業者から届いた追跡情報だけを含める
+    /// （配送業者の追跡情報は、受信日時ではなく配送業者側の発生日時で並べる）
+    ///
+    /// # Arguments
+    /// * `order` - 注文
+    /// * `events` - 注文に関するイベントジャーナルの記録
+    /// * `notes` - CSメモ
+    /// * `tracking` - 配送追跡イベント
+    pub fn build(
+        order: &Order,
+        events: &[JournaledEvent],
+        notes: &[OrderNote],
+        tracking: &[TrackingEvent],
+    ) -> Self {
+        let mut entries = Vec::new();
+
+        // 配送業者の追跡情報は追跡情報として並べるため、イベントとしては含めない
+        let mut projected_event_ids = HashSet::new();
+        for event in events
+            .iter()
+            .filter(|event| event.event_type != "CarrierTrackingUpdated")
+        {
+            projected_event_ids.insert(event.event_id);
+            entries.push(TimelineEntry::Event {
+                occurred_at: event.occurred_at,
+                event_id: event.event_id,
+                event_type: event.event_type.clone(),
+            });
+        }
+
+        let transitions = [
+            (OrderStatus::Confirmed, order.confirmed_at()),
+            (OrderStatus::Shipped, order.shipped_at()),
+            (OrderStatus::Delivered, order.delivered_at()),
+        ];
+        for (status, occurred_at) in transitions {
+            if let Some(occurred_at) = occurred_at {
+                entries.push(TimelineEntry::StatusChanged {
+                    occurred_at,
+                    status: status.to_string(),
+                });
+            }
+        }
+
+        for tracking_event in tracking
+            .iter()
+            .filter(|tracking_event| !projected_event_ids.contains(&tracking_event.event_id))
+        {
+            entries.push(TimelineEntry::Tracking {
+                occurred_at: tracking_event.occurred_at,
+                stage: tracking_event.stage,
+                label: tracking_event.stage.label(),
+                location: tracking_event.location.clone(),
+                description: tracking_event.description.clone(),
+            });
+        }
+
+        for note in notes {
+            entries.push(TimelineEntry::Note {
+                occurred_at: note.created_at,
+                note_id: note.note_id,
+                author: note.author.clone(),
+                body: note.body.clone(),
+            });
+        }
+
+        // 安定ソートのため、同じ日時の記録は追加した種類の順に並ぶ
+        entries.sort_by_key(TimelineEntry::occurred_at);
+
+        Self {
+            order_id: order.id(),
+            status: order.status().to_string(),
+            entries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::CustomerId;
+    use chrono::TimeDelta;
+
+    fn journaled(event_type: &str, occurred_at: DateTime<Utc>) -> JournaledEvent {
+        JournaledEvent {
+            position: 0,
+            event_id: Uuid::new_v4(),
+            event_type: event_type.to_string(),
+            occurred_at,
+            payload: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_entries_are_merged_chronologically_without_projected_duplicates() {
+        let order = Order::new(OrderId::new(), CustomerId::new());
+        let now = Utc::now();
+        let confirmed = journaled("OrderConfirmed", now);
+        let carrier = journaled("CarrierTrackingUpdated", now + TimeDelta::hours(2));
+        let projected = TrackingEvent {
+            event_id: confirmed.event_id,
+            order_id: order.id(),
+            stage: TrackingStage::Ordered,
+            occurred_at: now,
+            location: None,
+            description: None,
+        };
+        // 配送業者側の発生日時は受信より前
+        let reported = TrackingEvent {
+            event_id: carrier.event_id,
+            order_id: order.id(),
+            stage: TrackingStage::Packed,
+            occurred_at: now + TimeDelta::hours(1),
+            location: Some("東京ベース".to_string()),
+            description: None,
+        };
+        let note = OrderNote::new(
+            order.id(),
+            "alice",
+            "配送日の問い合わせあり",
+            now + TimeDelta::minutes(30),
+        )
+        .unwrap();
+
+        let timeline = OrderTimeline::build(
+            &order,
+            &[confirmed.clone(), carrier],
+            &[note],
+            &[projected, reported],
+        );
+
+        assert_eq!(timeline.entries.len(), 3);
+        assert!(matches!(
+            &timeline.entries[0],
+            TimelineEntry::Event { event_type, .. } if event_type == "OrderConfirmed"
+        ));
+        assert!(matches!(&timeline.entries[1], TimelineEntry::Note { .. }));
+        assert!(matches!(
+            &timeline.entries[2],
+            TimelineEntry::Tracking { stage: TrackingStage::Packed, .. }
+        ));
+    }
+}
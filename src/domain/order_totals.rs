@@ -0,0 +1,103 @@
+use crate::domain::model::{Money, Order};
+use crate::domain::shipping_fee::{ShippingFeeBreakdown, ShippingFeePolicy};
+use serde::{Deserialize, Serialize};
+
+/// 消費税率（%）
+pub const CONSUMPTION_TAX_RATE_PERCENT: i64 = 10;
+
+/// 注文の金額（小計・配送料・消費税・合計）
+/// 確定時に算出した金額をスナップショットとして注文に保存し、確定後は配送料ポリシーや税率が変わっても請求額が変わらないようにする
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderTotals {
+    /// 小計（配送料を除く）
+    pub subtotal: Money,
+    /// 配送料の内訳
+    pub shipping: ShippingFeeBreakdown,
+    /// 合計金額に含まれる消費税額（書籍の価格・配送料は税込のため内税、1円未満切り捨て）
+    pub tax: Money,
+    /// 合計金額（小計 + 配送料）
+    pub total: Money,
+}
+
+impl OrderTotals {
+    /// 現在の配送料ポリシーと税率で注文の金額を算出
+    pub fn calculate(order: &Order, shipping_fee_policy: &ShippingFeePolicy) -> Self {
+        let total = shipping_fee_policy.total(order);
+        Self {
+            subtotal: order.subtotal(),
+            shipping: shipping_fee_policy.quote(order),
+            tax: Money::jpy(
+                total.amount() * CONSUMPTION_TAX_RATE_PERCENT
+                    / (100 + CONSUMPTION_TAX_RATE_PERCENT),
+            ),
+            total,
+        }
+    }
+
+    /// 注文の金額を取得
+    /// 確定時のスナップショットがある注文はその金額を返し、ない注文（Pending・スナップショット導入前に確定した注文）は再計算する
+    pub fn for_order(order: &Order, shipping_fee_policy: &ShippingFeePolicy) -> Self {
+        order
+            .confirmed_totals()
+            .copied()
+            .unwrap_or_else(|| Self::calculate(order, shipping_fee_policy))
+    }
+
+    /// 配送料を取得
+    pub fn shipping_fee(&self) -> Money {
+        Money::jpy(self.shipping.fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, BookSpec, CustomerId, OrderId, ShippingAddress};
+    use crate::domain::shipping_fee::{CarrierLimits, ShippingRateTable};
+
+    #[test]
+    fn test_confirmed_totals_survive_policy_changes() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order
+            .add_book_with_spec(
+                BookId::new(),
+                2,
+                Money::jpy(1000),
+                BookSpec::new(300, 128, 182, 15).unwrap(),
+            )
+            .unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1500043".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "道玄坂1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        order.confirm().unwrap();
+        order.snapshot_totals(&ShippingFeePolicy::default());
+
+        // 2000円 + 配送料500円、消費税は2500円の内税
+        let totals = OrderTotals::for_order(&order, &ShippingFeePolicy::default());
+        assert_eq!(totals.subtotal.amount(), 2000);
+        assert_eq!(totals.shipping_fee().amount(), 500);
+        assert_eq!(totals.tax.amount(), 227);
+        assert_eq!(totals.total.amount(), 2500);
+
+        // 送料無料の下限が下がっても確定時の金額のまま
+        let cheaper = ShippingFeePolicy::new(
+            ShippingRateTable::default(),
+            1_000,
+            CarrierLimits::default(),
+        );
+        assert_eq!(
+            OrderTotals::calculate(&order, &cheaper).total.amount(),
+            2000
+        );
+        assert_eq!(OrderTotals::for_order(&order, &cheaper), totals);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::domain::model::{BookId, Order, OrderId};
+use crate::domain::order_totals::OrderTotals;
+use crate::domain::shipping_fee::ShippingFeePolicy;
+use serde::Serialize;
+
+/// 納品書の宛先
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PackingSlipAddressee {
+    /// 受取人の氏名（ギフト注文の場合のみ）
+    pub name: Option<String>,
+    /// 受取人の電話番号（ギフト注文の場合のみ）
+    pub phone: Option<String>,
+    pub postal_code: String,
+    pub prefecture: String,
+    pub city: String,
+    pub street: String,
+    pub building: Option<String>,
+}
+
+/// 納品書の明細
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PackingSlipLine {
+    pub book_id: BookId,
+    pub quantity: u32,
+    /// 単価（ギフト注文では表示しない）
+    pub unit_price: Option<i64>,
+    /// 小計（ギフト注文では表示しない）
+    pub subtotal: Option<i64>,
+}
+
+/// 納品書（梱包時に同封する明細）
+/// 発送する物理書籍の明細のみを記載し、ギフト注文では金額を伏せる
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PackingSlip {
+    pub order_id: OrderId,
+    pub gift: bool,
+    /// 宛先（配送先住所が未設定の場合はNone）
+    pub ship_to: Option<PackingSlipAddressee>,
+    pub lines: Vec<PackingSlipLine>,
+    /// 小計（ギフト注文では表示しない）
+    pub subtotal: Option<i64>,
+    /// 配送料（ギフト注文では表示しない）
+    pub shipping_fee: Option<i64>,
+    /// 合計金額（ギフト注文では表示しない）
+    pub total: Option<i64>,
+}
+
+impl PackingSlip {
+    /// 注文から納品書を作成
+    pub fn build(order: &Order, shipping_fee_policy: &ShippingFeePolicy) -> Self {
+        let gift = order.is_gift();
+        // ギフト注文では金額を伏せる
+        let price = |amount: i64| (!gift).then_some(amount);
+        // 確定済みの注文は確定時の金額を記載する
+        let totals = OrderTotals::for_order(order, shipping_fee_policy);
+
+        let ship_to = order
+            .shipping_address()
+            .map(|address| PackingSlipAddressee {
+                name: order
+                    .recipient()
+                    .map(|recipient| recipient.name().to_string()),
+                phone: order
+                    .recipient()
+                    .map(|recipient| recipient.phone().to_string()),
+                postal_code: address.postal_code().to_string(),
+                prefecture: address.prefecture().to_string(),
+                city: address.city().to_string(),
+                street: address.street().to_string(),
+                building: address.building().map(str::to_string),
+            });
+
+        let lines = order
+            .order_lines()
+            .iter()
+            .filter(|line| !line.is_digital())
+            .map(|line| PackingSlipLine {
+                book_id: line.book_id(),
+                quantity: line.quantity(),
+                unit_price: price(line.unit_price().amount()),
+                subtotal: price(line.subtotal().amount()),
+            })
+            .collect();
+
+        Self {
+            order_id: order.id(),
+            gift,
+            ship_to,
+            lines,
+            subtotal: price(totals.subtotal.amount()),
+            shipping_fee: price(totals.shipping_fee().amount()),
+            total: price(totals.total.amount()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{CustomerId, FulfillmentType, Money, Recipient, ShippingAddress};
+
+    #[test]
+    fn test_gift_packing_slip_hides_prices() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 2, Money::jpy(1500)).unwrap();
+        order
+            .add_book_with_fulfillment(BookId::new(), 1, Money::jpy(800), FulfillmentType::Digital)
+            .unwrap();
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address.clone()).unwrap();
+        let policy = ShippingFeePolicy::default();
+
+        // 通常の注文では金額を記載する（電子書籍の明細は同封しない）
+        let slip = PackingSlip::build(&order, &policy);
+        assert!(!slip.gift);
+        assert_eq!(slip.lines.len(), 1);
+        assert_eq!(slip.lines[0].unit_price, Some(1500));
+        assert_eq!(slip.total, Some(4300));
+        assert_eq!(slip.ship_to.as_ref().unwrap().name, None);
+
+        // ギフト注文では受取人を宛先にし、金額を伏せる
+        let recipient =
+            Recipient::new("山田花子".to_string(), "09012345678".to_string(), address).unwrap();
+        order.set_recipient(recipient).unwrap();
+        let slip = PackingSlip::build(&order, &policy);
+        assert!(slip.gift);
+        assert_eq!(slip.lines[0].quantity, 2);
+        assert_eq!(slip.lines[0].unit_price, None);
+        assert_eq!(slip.lines[0].subtotal, None);
+        assert_eq!(
+            (slip.subtotal, slip.shipping_fee, slip.total),
+            (None, None, None)
+        );
+        assert_eq!(slip.ship_to.unwrap().name.as_deref(), Some("山田花子"));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::domain::error::DomainError;
+use crate::domain::event::DomainEvent;
+use crate::domain::port::{EventBus, Logger, PendingOperationRepository, RepositoryError};
+use crate::domain::serialization::{EventSerializer, SerializationError};
+use crate::domain::task_supervisor::SupervisedTask;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// イベントの発行を再試行する操作の種類
+pub const OPERATION_PUBLISH_EVENT: &str = "publish_event";
+
+/// 再試行をあきらめるまでの試行回数（最初の発行の失敗を含む）
+pub const MAX_PENDING_OPERATION_ATTEMPTS: u32 = 10;
+
+/// 1回の再試行で処理する操作の上限
+pub const PENDING_OPERATION_BATCH_SIZE: u32 = 100;
+
+/// 再試行待ちの操作の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PendingOperationStatus {
+    /// 再試行待ち
+    Pending,
+    /// 再試行で完了した
+    Completed,
+    /// 試行回数の上限に達したため再試行をあきらめた
+    Failed,
+}
+
+impl PendingOperationStatus {
+    /// 文字列からPendingOperationStatusを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Pending" => Ok(PendingOperationStatus::Pending),
+            "Completed" => Ok(PendingOperationStatus::Completed),
+            "Failed" => Ok(PendingOperationStatus::Failed),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な操作の状態: {}",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for PendingOperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status_str = match self {
+            PendingOperationStatus::Pending => "Pending",
+            PendingOperationStatus::Completed => "Completed",
+            PendingOperationStatus::Failed => "Failed",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+/// 再試行待ちの操作
+/// 状態の保存後にイベントの発行に失敗したコマンドの、発行できなかったイベントを保持する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingOperation {
+    pub id: Uuid,
+    /// 操作の種類（現在はイベントの発行のみ）
+    pub operation_type: String,
+    pub aggregate_id: String,
+    pub event_type: String,
+    /// 発行するイベント（EventSerializerの形式）
+    pub payload: String,
+    pub status: PendingOperationStatus,
+    /// 試行回数（最初の発行の失敗を含む）
+    pub attempts: u32,
+    /// 最後に失敗した理由
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl PendingOperation {
+    /// 発行に失敗したイベントから再試行待ちの操作を作成
+    ///
+    /// # Arguments
+    /// * `event` - 発行できなかったイベント（イベントID・連番・相関IDはそのまま再発行する）
+    /// * `error` - 発行に失敗した理由
+    /// * `now` - 失敗した日時
+    ///
+    /// # Returns
+    /// * `Ok(PendingOperation)` - 再試行待ちの操作
+    /// * `Err(SerializationError)` - イベントをシリアライズできない（再試行しても発行できない）
+    pub fn publish_event(
+        event: &DomainEvent,
+        error: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self {
+            id: Uuid::new_v4(),
+            operation_type: OPERATION_PUBLISH_EVENT.to_string(),
+            aggregate_id: event.aggregate_id(),
+            event_type: event.event_type().to_string(),
+            payload: EventSerializer::new().serialize_event(event)?,
+            status: PendingOperationStatus::Pending,
+            attempts: 1,
+            last_error: Some(error.to_string()),
+            created_at: now,
+            updated_at: now,
+            completed_at: None,
+        })
+    }
+
+    /// 再試行が成功したことを記録する
+    pub fn record_success(&mut self, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = PendingOperationStatus::Completed;
+        self.updated_at = now;
+        self.completed_at = Some(now);
+    }
+
+    /// 再試行が失敗したことを記録する（試行回数の上限に達したらあきらめる）
+    pub fn record_failure(&mut self, error: &str, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_error = Some(error.to_string());
+        self.updated_at = now;
+        if self.attempts >= MAX_PENDING_OPERATION_ATTEMPTS {
+            self.status = PendingOperationStatus::Failed;
+        }
+    }
+}
+
+/// 再試行待ちの操作の再試行ジョブ
+/// 発行に失敗したイベントを記録順に再発行し、操作の状態を更新する
+#[derive(Clone)]
+pub struct PendingOperationRetrier {
+    repository: Arc<dyn PendingOperationRepository>,
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+}
+
+impl PendingOperationRetrier {
+    pub fn new(
+        repository: Arc<dyn PendingOperationRepository>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            repository,
+            event_bus,
+            logger,
+        }
+    }
+
+    /// ジョブをバックグラウンドで起動
+    pub fn spawn(self, retry_interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(retry_interval);
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// 再試行待ちの操作を再試行する
+    ///
+    /// # Returns
+    /// * 再試行で完了した操作のIDのリスト
+    pub async fn run(&self, now: DateTime<Utc>) -> Result<Vec<Uuid>, RepositoryError> {
+        let mut completed = Vec::new();
+        for mut operation in self
+            .repository
+            .find_pending(PENDING_OPERATION_BATCH_SIZE)
+            .await?
+        {
+            match self.retry(&operation).await {
+                Ok(()) => operation.record_success(now),
+                Err(error) => operation.record_failure(&error, now),
+            }
+            self.repository.save(&operation).await?;
+            match operation.status {
+                PendingOperationStatus::Completed => completed.push(operation.id),
+                PendingOperationStatus::Failed => self.log_abandoned(&operation),
+                PendingOperationStatus::Pending => {}
+            }
+        }
+        Ok(completed)
+    }
+
+    /// 操作を1回試行する（失敗した場合は理由を返す）
+    async fn retry(&self, operation: &PendingOperation) -> Result<(), String> {
+        let event = EventSerializer::new()
+            .deserialize_event(&operation.payload)
+            .map_err(|e| e.to_string())?;
+        self.event_bus
+            .publish(event)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    fn log_abandoned(&self, operation: &PendingOperation) {
+        let mut context = HashMap::new();
+        context.insert("operation_id".to_string(), operation.id.to_string());
+        context.insert("event_type".to_string(), operation.event_type.clone());
+        context.insert("aggregate_id".to_string(), operation.aggregate_id.clone());
+        context.insert(
+            "error".to_string(),
+            operation.last_error.clone().unwrap_or_default(),
+        );
+        self.logger.error(
+            "PendingOperationRetrier",
+            "Gave up retrying pending operation",
+            None,
+            Some(context),
+        );
+    }
+}
+
+#[async_trait]
+impl SupervisedTask for PendingOperationRetrier {
+    async fn run_once(&self) {
+        if let Err(e) = self.run(Utc::now()).await {
+            let mut context = HashMap::new();
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "PendingOperationRetrier",
+                "Failed to load pending operations",
+                None,
+                Some(context),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::event::OrderConfirmed;
+    use crate::domain::model::{CustomerId, Money, OrderId};
+
+    #[test]
+    fn test_pending_operation_gives_up_after_max_attempts() {
+        let now = Utc::now();
+        let event = DomainEvent::OrderConfirmed(OrderConfirmed::new(
+            OrderId::new(),
+            CustomerId::new(),
+            vec![],
+            Money::jpy(0),
+        ));
+        let mut operation = PendingOperation::publish_event(&event, "lane closed", now).unwrap();
+        assert_eq!(operation.status, PendingOperationStatus::Pending);
+        assert_eq!(operation.attempts, 1);
+        assert_eq!(
+            EventSerializer::new()
+                .deserialize_event(&operation.payload)
+                .unwrap()
+                .metadata()
+                .event_id,
+            event.metadata().event_id
+        );
+
+        for _ in 1..MAX_PENDING_OPERATION_ATTEMPTS - 1 {
+            operation.record_failure("lane closed", now);
+        }
+        assert_eq!(operation.status, PendingOperationStatus::Pending);
+        operation.record_failure("still closed", now);
+        assert_eq!(operation.status, PendingOperationStatus::Failed);
+        assert_eq!(operation.attempts, MAX_PENDING_OPERATION_ATTEMPTS);
+        assert_eq!(operation.last_error.as_deref(), Some("still closed"));
+
+        let mut retried = PendingOperation::publish_event(&event, "lane closed", now).unwrap();
+        retried.record_success(now);
+        assert_eq!(retried.status, PendingOperationStatus::Completed);
+        assert_eq!(retried.completed_at, Some(now));
+    }
+}
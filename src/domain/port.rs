@@ -2,10 +2,52 @@
 // ドメイン層が外部に依存する機能をトレイトとして定義
 // アダプター層でこれらのトレイトを実装する
 
+use crate::domain::action_link::{ActionLinkClaims, ActionLinkUse};
+use crate::domain::alerting::Alert;
+use crate::domain::book_translation::{BookTitles, Language};
+use crate::domain::checkout_hold::CheckoutHold;
+use crate::domain::customer_notification::CustomerNotification;
+use crate::domain::customer_webhook::{WebhookDelivery, WebhookOutcome, WebhookSubscription};
+use crate::domain::error::DomainError;
 use crate::domain::event::DomainEvent;
-use crate::domain::model::{BookId, Inventory, Order, OrderId, OrderStatus};
+use crate::domain::event_bus::{
+    DeadLetter, EventDispatchStatus, PausedEventHandling, SubscriptionStatus,
+};
+use crate::domain::event_export::JournaledEvent;
+use crate::domain::event_schema::EventSchema;
+use crate::domain::event_sourcing::EventStream;
+use crate::domain::event_trace::EventTrace;
+use crate::domain::inbox::InboxMessage;
+use crate::domain::inventory_snapshot::{InventorySnapshot, StockMovement};
+use crate::domain::inventory_valuation::StockValuation;
+use crate::domain::legacy_import::LegacyImportRecord;
+use crate::domain::load_shedding::LoadSheddingStatus;
+use crate::domain::model::{
+    AllocationQuota, BookId, Customer, CustomerId, CycleCount, CycleCountId, DeviceRegistration, DeviceToken,
+    DownloadLink, EmailAddress, Inventory, Money, Order, OrderId, OrderNumber, OrderStatus,
+    ShippingAddress, TrackingToken,
+};
+use crate::domain::late_event::ParkedEvent;
+use crate::domain::notification_retry::{
+    FailedNotification, FailedNotificationStatus, Notification,
+};
+use crate::domain::order_lock::OrderLock;
+use crate::domain::order_note::OrderNote;
+use crate::domain::pending_operation::PendingOperation;
+use crate::domain::purchase_policy::{PurchaseCheck, PurchasePolicy};
+use crate::domain::projection::{EventStreamHead, ProjectionCheckpoint};
+use crate::domain::reconciliation::LedgerDrift;
+use crate::domain::saga_metrics::SagaCompensation;
+use crate::domain::status_override::StatusOverrideAudit;
+use crate::domain::subscription_registry::SubscriptionRegistration;
+use crate::domain::tracking::TrackingEvent;
+use crate::domain::waitlist::WaitlistEntry;
 use async_trait::async_trait;
-use std::collections::HashMap;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
 use uuid::Uuid;
 
 /// ログレベル
@@ -68,6 +110,10 @@ pub enum RepositoryError {
     OperationFailed(String),
     /// データの取得に失敗
     FetchFailed(String),
+    /// 読み込んだ後に他の操作が集約を更新していた（行のバージョン・ストリームのバージョンによる楽観的排他制御の競合）
+    ConcurrencyConflict(String),
+    /// 保存時に確認した購入制限を超えていたため保存しなかった
+    PurchaseLimitExceeded(DomainError),
 }
 
 impl std::fmt::Display for RepositoryError {
@@ -76,6 +122,12 @@ impl std::fmt::Display for RepositoryError {
             RepositoryError::ConnectionFailed(msg) => write!(f, "Connection failed: {}", msg),
             RepositoryError::OperationFailed(msg) => write!(f, "Operation failed: {}", msg),
             RepositoryError::FetchFailed(msg) => write!(f, "Fetch failed: {}", msg),
+            RepositoryError::ConcurrencyConflict(msg) => {
+                write!(f, "Concurrency conflict: {}", msg)
+            }
+            RepositoryError::PurchaseLimitExceeded(err) => {
+                write!(f, "Purchase limit exceeded: {}", err)
+            }
         }
     }
 }
@@ -87,14 +139,38 @@ impl std::error::Error for RepositoryError {}
 #[async_trait]
 pub trait OrderRepository: Send + Sync {
     /// 注文を保存する
+    /// 読み込んだときのバージョンが保存されているバージョンと一致する場合のみ保存し、
+    /// 保存に成功した場合は注文のバージョンを保存後のバージョンに更新する（同じ注文を続けて保存できる）
     ///
     /// # Arguments
     /// * `order` - 保存する注文
     ///
     /// # Returns
     /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError::ConcurrencyConflict)` - 読み込んだ後に他の操作が注文を保存していた
     /// * `Err(RepositoryError)` - 保存失敗
-    async fn save(&self, order: &Order) -> Result<(), RepositoryError>;
+    async fn save(&self, order: &mut Order) -> Result<(), RepositoryError>;
+
+    /// 購入制限を確認したうえで注文を保存する
+    /// 顧客ごとに排他してから集計・判定・保存までを行うため、同じ顧客の同時の注文作成・書籍追加が
+    /// 互いの集計をすり抜けて上限を超えることはない（バージョンの扱いは`save`と同じ）
+    ///
+    /// # Arguments
+    /// * `order` - 保存する注文
+    /// * `policy` - 判定に使う購入ポリシー
+    /// * `check` - 確認する購入制限（集計は保存前の状態に対して行う）
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError::PurchaseLimitExceeded)` - 購入制限を超えていたため保存しなかった
+    /// * `Err(RepositoryError::ConcurrencyConflict)` - 読み込んだ後に他の操作が注文を保存していた
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save_within_purchase_limits(
+        &self,
+        order: &mut Order,
+        policy: &PurchasePolicy,
+        check: PurchaseCheck,
+    ) -> Result<(), RepositoryError>;
 
     /// 注文IDで注文を検索する
     ///
@@ -126,6 +202,161 @@ pub trait OrderRepository: Send + Sync {
     /// * `Err(RepositoryError)` - 取得失敗
     async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, RepositoryError>;
 
+    /// 顧客の注文を取得する
+    /// 作成日時の降順で並べて返す
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Order>)` - 顧客の注文のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, RepositoryError>;
+
+    /// 顧客の注文を注文IDの昇順で少しずつ取得する
+    /// 注文の多い顧客でもすべてを一度に読み込まないよう、前のページの最後の注文IDから続きを取得する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `after` - 前のページの最後の注文ID（最初のページはNone）
+    /// * `limit` - 取得する最大件数
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Order>)` - 注文のリスト（limit件より少なければ最後のページ）
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_customer_page(
+        &self,
+        customer_id: CustomerId,
+        after: Option<OrderId>,
+        limit: u32,
+    ) -> Result<Vec<Order>, RepositoryError>;
+
+    /// 指定されたステータスで、指定日時より前に作成された注文を取得する（順不同）
+    ///
+    /// # Arguments
+    /// * `status` - フィルタリングする注文ステータス
+    /// * `created_before` - 作成日時の上限（この日時より前に作成された注文を取得）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Order>)` - 指定された条件の注文のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_status_created_before(
+        &self,
+        status: OrderStatus,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, RepositoryError>;
+
+    /// 顧客の未完了（Pending・Confirmed・OnHold・AwaitingRelease・Waitlisted）の注文数を数える
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - 未完了の注文数
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn count_open_orders_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<u32, RepositoryError>;
+
+    /// 顧客の指定したステータスの注文数を数える
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `status` - 注文ステータス
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - 注文数
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn count_orders_by_customer_and_status(
+        &self,
+        customer_id: CustomerId,
+        status: OrderStatus,
+    ) -> Result<u32, RepositoryError>;
+
+    /// 指定日時以降に作成された顧客の注文に含まれる書籍の合計数量を集計する
+    /// キャンセル済みの注文は除外する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `book_id` - 書籍ID
+    /// * `since` - 集計開始日時
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - 合計数量
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn sum_book_quantity_by_customer_since(
+        &self,
+        customer_id: CustomerId,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, RepositoryError>;
+
+    /// 追跡トークンで注文を検索する
+    /// キャンセルで失効したトークンは見つからない
+    ///
+    /// # Arguments
+    /// * `token` - 追跡トークン
+    ///
+    /// # Returns
+    /// * `Ok(Some(Order))` - 注文が見つかった
+    /// * `Ok(None)` - 注文が見つからなかった
+    /// * `Err(RepositoryError)` - 検索失敗
+    async fn find_by_tracking_token(
+        &self,
+        token: &TrackingToken,
+    ) -> Result<Option<Order>, RepositoryError>;
+
+    /// 注文番号で注文を検索する
+    ///
+    /// # Arguments
+    /// * `order_number` - 注文番号
+    ///
+    /// # Returns
+    /// * `Ok(Some(Order))` - 注文が見つかった
+    /// * `Ok(None)` - 注文が見つからなかった
+    /// * `Err(RepositoryError)` - 検索失敗
+    async fn find_by_order_number(
+        &self,
+        order_number: &OrderNumber,
+    ) -> Result<Option<Order>, RepositoryError>;
+
+    /// 指定した注文と重複している可能性がある注文を探す
+    /// 同じ顧客・同じ明細（書籍と数量）で、作成日時の差が指定した時間以内の注文を作成日時の昇順で返す
+    /// 指定した注文自身とキャンセル済みの注文は除外する
+    ///
+    /// # Arguments
+    /// * `order_id` - 基準にする注文ID
+    /// * `within` - 作成日時の差の上限
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Order>)` - 重複の可能性がある注文のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_similar_orders(
+        &self,
+        order_id: OrderId,
+        within: TimeDelta,
+    ) -> Result<Vec<Order>, RepositoryError>;
+
+    /// 指定日時以降に確定した注文に含まれる書籍の数量を、確定日（UTC）ごとに集計する
+    /// キャンセル済みの注文は除外する
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `since` - 集計開始日時
+    ///
+    /// # Returns
+    /// * `Ok(BTreeMap<NaiveDate, u32>)` - 確定日ごとの合計数量（注文のない日は含まない）
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn sum_daily_book_demand_since(
+        &self,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<BTreeMap<NaiveDate, u32>, RepositoryError>;
+
     /// 新しい一意の注文IDを生成する
     ///
     /// # Returns
@@ -133,11 +364,156 @@ pub trait OrderRepository: Send + Sync {
     fn next_identity(&self) -> OrderId;
 }
 
+/// 共有された注文リポジトリ
+/// 起動時に選んだ永続化先（MySQL・SQLite）を`Arc<dyn OrderRepository>`としてサービスに渡せるよう、参照先に委譲する
+#[async_trait]
+impl<T> OrderRepository for Arc<T>
+where
+    T: OrderRepository + ?Sized,
+{
+    async fn save(&self, order: &mut Order) -> Result<(), RepositoryError> {
+        (**self).save(order).await
+    }
+
+    async fn save_within_purchase_limits(
+        &self,
+        order: &mut Order,
+        policy: &PurchasePolicy,
+        check: PurchaseCheck,
+    ) -> Result<(), RepositoryError> {
+        (**self)
+            .save_within_purchase_limits(order, policy, check)
+            .await
+    }
+
+    async fn find_by_id(&self, order_id: OrderId) -> Result<Option<Order>, RepositoryError> {
+        (**self).find_by_id(order_id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<Order>, RepositoryError> {
+        (**self).find_all().await
+    }
+
+    async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, RepositoryError> {
+        (**self).find_by_status(status).await
+    }
+
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        (**self).find_by_customer(customer_id).await
+    }
+
+    async fn find_by_customer_page(
+        &self,
+        customer_id: CustomerId,
+        after: Option<OrderId>,
+        limit: u32,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        (**self)
+            .find_by_customer_page(customer_id, after, limit)
+            .await
+    }
+
+    async fn find_by_status_created_before(
+        &self,
+        status: OrderStatus,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        (**self)
+            .find_by_status_created_before(status, created_before)
+            .await
+    }
+
+    async fn count_open_orders_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<u32, RepositoryError> {
+        (**self).count_open_orders_by_customer(customer_id).await
+    }
+
+    async fn count_orders_by_customer_and_status(
+        &self,
+        customer_id: CustomerId,
+        status: OrderStatus,
+    ) -> Result<u32, RepositoryError> {
+        (**self)
+            .count_orders_by_customer_and_status(customer_id, status)
+            .await
+    }
+
+    async fn sum_book_quantity_by_customer_since(
+        &self,
+        customer_id: CustomerId,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, RepositoryError> {
+        (**self)
+            .sum_book_quantity_by_customer_since(customer_id, book_id, since)
+            .await
+    }
+
+    async fn find_by_tracking_token(
+        &self,
+        token: &TrackingToken,
+    ) -> Result<Option<Order>, RepositoryError> {
+        (**self).find_by_tracking_token(token).await
+    }
+
+    async fn find_by_order_number(
+        &self,
+        order_number: &OrderNumber,
+    ) -> Result<Option<Order>, RepositoryError> {
+        (**self).find_by_order_number(order_number).await
+    }
+
+    async fn find_similar_orders(
+        &self,
+        order_id: OrderId,
+        within: TimeDelta,
+    ) -> Result<Vec<Order>, RepositoryError> {
+        (**self).find_similar_orders(order_id, within).await
+    }
+
+    async fn sum_daily_book_demand_since(
+        &self,
+        book_id: BookId,
+        since: DateTime<Utc>,
+    ) -> Result<BTreeMap<NaiveDate, u32>, RepositoryError> {
+        (**self).sum_daily_book_demand_since(book_id, since).await
+    }
+
+    fn next_identity(&self) -> OrderId {
+        (**self).next_identity()
+    }
+}
+
+/// 注文番号の採番トレイト
+/// 注文の作成時に、作成年ごとの連番から注文番号を採番するポート
+#[async_trait]
+pub trait OrderNumberGenerator: Send + Sync {
+    /// 次の注文番号を採番する
+    /// 同じ年の連番は重複せず、同時に採番しても同じ番号を返さない（欠番は許容する）
+    ///
+    /// # Arguments
+    /// * `created_at` - 注文の作成日時（連番を区切る年に使う）
+    ///
+    /// # Returns
+    /// * `Ok(OrderNumber)` - 採番した注文番号
+    /// * `Err(RepositoryError)` - 採番失敗
+    async fn next_order_number(
+        &self,
+        created_at: DateTime<Utc>,
+    ) -> Result<OrderNumber, RepositoryError>;
+}
+
 /// 在庫リポジトリトレイト
 /// 在庫集約の永続化を抽象化する
 #[async_trait]
 pub trait InventoryRepository: Send + Sync {
     /// 在庫を保存する
+    /// 在庫数が変わった場合（新規登録を含む）は同じトランザクションで在庫変動ログに記録する
     ///
     /// # Arguments
     /// * `inventory` - 保存する在庫
@@ -179,19 +555,1414 @@ pub trait InventoryRepository: Send + Sync {
         &self,
         max_quantity: u32,
     ) -> Result<Vec<Inventory>, RepositoryError>;
+
+    /// 在庫数が在庫変動ログの残高と食い違っている書籍を検出する
+    /// 在庫変動ログの記録が始まる前から変動のない書籍は照合できないため対象外とし、書籍IDの昇順で並べて返す
+    ///
+    /// # Returns
+    /// * `Ok(Vec<LedgerDrift>)` - 食い違いのリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_ledger_drifts(&self) -> Result<Vec<LedgerDrift>, RepositoryError>;
+
+    /// 在庫変動ログと食い違っている書籍を凍結する
+    /// 在庫数も在庫変動ログも変更しない（実数は棚卸しで照合する）。
+    /// 在庫の行をロックした上で食い違いを確認し直し、検出後に解消されていた場合は更新しない
+    ///
+    /// # Arguments
+    /// * `book_id` - 凍結する書籍ID
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 食い違いを確認して凍結した（凍結済みの書籍を含む）
+    /// * `Ok(false)` - 食い違いがなかった（検出後に解消済み）
+    /// * `Err(RepositoryError)` - 更新失敗
+    async fn freeze_ledger_drift(&self, book_id: BookId) -> Result<bool, RepositoryError>;
+
+    /// すべての在庫の在庫数と書籍カタログの販売価格を取得する（在庫評価用）
+    /// 価格が登録されていない書籍も結果に含め、書籍IDの昇順で並べて返す
+    ///
+    /// # Returns
+    /// * `Ok(Vec<StockValuation>)` - 書籍ごとの在庫数と販売価格のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_stock_valuations(&self) -> Result<Vec<StockValuation>, RepositoryError>;
+
+    /// 在庫のスナップショットを取得する（POSの全件同期用）
+    /// 在庫と在庫変動ログの最新のversionが同じ時点を指すよう、REPEATABLE READのトランザクション内で読み取る
+    ///
+    /// # Arguments
+    /// * `taken_at` - スナップショットの取得日時
+    ///
+    /// # Returns
+    /// * `Ok(InventorySnapshot)` - 書籍IDの昇順の在庫とversion
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn take_snapshot(
+        &self,
+        taken_at: DateTime<Utc>,
+    ) -> Result<InventorySnapshot, RepositoryError>;
+
+    /// 指定したversionより後の在庫変動をversionの昇順で取得する（POSの差分同期用）
+    ///
+    /// # Arguments
+    /// * `since_version` - 取得済みの最後のversion
+    /// * `limit` - 取得する最大件数
+    ///
+    /// # Returns
+    /// * `Ok(Vec<StockMovement>)` - 在庫変動のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_movements_since(
+        &self,
+        since_version: u64,
+        limit: usize,
+    ) -> Result<Vec<StockMovement>, RepositoryError>;
 }
 
-/// イベントバスエラー
-#[derive(Debug, thiserror::Error)]
-pub enum EventBusError {
-    #[error("Event publishing failed: {0}")]
-    PublishingFailed(String),
+/// 棚卸しリポジトリトレイト
+/// 棚卸し集約の永続化を担当するポート
+#[async_trait]
+pub trait CycleCountRepository: Send + Sync {
+    /// 棚卸しを保存する（明細を含む）
+    ///
+    /// # Arguments
+    /// * `cycle_count` - 保存する棚卸し
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, cycle_count: &CycleCount) -> Result<(), RepositoryError>;
+
+    /// IDで棚卸しを検索する
+    ///
+    /// # Arguments
+    /// * `cycle_count_id` - 検索する棚卸しID
+    ///
+    /// # Returns
+    /// * `Ok(Some(CycleCount))` - 棚卸しが見つかった
+    /// * `Ok(None)` - 棚卸しが見つからなかった
+    /// * `Err(RepositoryError)` - 検索失敗
+    async fn find_by_id(
+        &self,
+        cycle_count_id: CycleCountId,
+    ) -> Result<Option<CycleCount>, RepositoryError>;
 }
 
-/// イベントバストレイト
-/// イベントの発行と配信を管理するポート
+/// 配送追跡イベントリポジトリトレイト
+/// 配送追跡タイムラインの投影先（tracking_eventsテーブル）を担当するポート
 #[async_trait]
-pub trait EventBus: Send + Sync {
-    /// イベントを発行し、登録されたハンドラーに配信
-    async fn publish(&self, event: DomainEvent) -> Result<(), EventBusError>;
+pub trait TrackingEventRepository: Send + Sync {
+    /// 追跡イベントを追加する
+    /// 同じevent_idのイベントが既に存在する場合は何もしない（再配信による重複投影を防ぐ）
+    ///
+    /// # Arguments
+    /// * `event` - 追加する追跡イベント
+    ///
+    /// # Returns
+    /// * `Ok(())` - 追加成功
+    /// * `Err(RepositoryError)` - 追加失敗
+    async fn append(&self, event: &TrackingEvent) -> Result<(), RepositoryError>;
+
+    /// 注文の追跡イベントを発生日時の昇順で取得する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TrackingEvent>)` - 追跡イベントのリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_order(&self, order_id: OrderId) -> Result<Vec<TrackingEvent>, RepositoryError>;
+
+    /// 連番を持つイベントの投影とチェックポイントの更新を同じトランザクションで行う
+    /// チェックポイントの連番以下のイベントは適用済みとして何もしない（同じイベントを何度適用しても結果は変わらない）
+    /// 投影する追跡イベントがない場合（キャンセルなど）もチェックポイントは進める
+    ///
+    /// # Arguments
+    /// * `checkpoint` - 適用後のチェックポイント（適用するイベントの連番）
+    /// * `event` - 追加する追跡イベント（投影対象外のイベントはNone）
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 適用した
+    /// * `Ok(false)` - 適用済みのため何もしなかった
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn apply_checkpointed(
+        &self,
+        checkpoint: &ProjectionCheckpoint,
+        event: Option<&TrackingEvent>,
+    ) -> Result<bool, RepositoryError>;
+
+    /// プロジェクションのチェックポイントをすべて取得する（起動時の復旧に使う）
+    ///
+    /// # Arguments
+    /// * `projection` - プロジェクション名
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ProjectionCheckpoint>)` - 集約ごとのチェックポイント
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn load_checkpoints(
+        &self,
+        projection: &str,
+    ) -> Result<Vec<ProjectionCheckpoint>, RepositoryError>;
+}
+
+/// 保留イベントリポジトリトレイト
+/// 遅延イベントポリシーで保留されたイベントの永続化を担当するポート
+#[async_trait]
+pub trait ParkedEventRepository: Send + Sync {
+    /// イベントを保留する
+    /// 同じハンドラーが同じイベントを既に保留している場合は何もしない（再配信による重複を防ぐ）
+    ///
+    /// # Arguments
+    /// * `event` - 保留するイベント
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn park(&self, event: &ParkedEvent) -> Result<(), RepositoryError>;
+
+    /// 保留中のイベントを保留日時の昇順で取得する
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ParkedEvent>)` - 保留中のイベントのリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_all(&self) -> Result<Vec<ParkedEvent>, RepositoryError>;
+}
+
+/// 再試行待ちの操作リポジトリトレイト
+/// 状態の保存後にイベントの発行に失敗したコマンドの、再試行待ちの操作の永続化を担当するポート
+#[async_trait]
+pub trait PendingOperationRepository: Send + Sync {
+    /// 操作を保存する（同じIDの操作が既に存在する場合は状態を更新する）
+    ///
+    /// # Arguments
+    /// * `operation` - 保存する操作
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, operation: &PendingOperation) -> Result<(), RepositoryError>;
+
+    /// IDで操作を取得する
+    ///
+    /// # Arguments
+    /// * `id` - 操作ID
+    ///
+    /// # Returns
+    /// * `Ok(Some(PendingOperation))` - 操作が見つかった
+    /// * `Ok(None)` - 操作が見つからない
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PendingOperation>, RepositoryError>;
+
+    /// 再試行待ちの操作を作成日時の昇順で取得する
+    ///
+    /// # Arguments
+    /// * `limit` - 取得する最大件数
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PendingOperation>)` - 再試行待ちの操作のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_pending(&self, limit: u32) -> Result<Vec<PendingOperation>, RepositoryError>;
+}
+
+/// 送信に失敗した通知のリポジトリトレイト
+/// イベントのデッドレターキューとは別に、再送待ち・再送をあきらめた通知の永続化を担当するポート
+#[async_trait]
+pub trait FailedNotificationRepository: Send + Sync {
+    /// 通知を保存する（同じIDの通知が既に存在する場合は状態を更新する）
+    ///
+    /// # Arguments
+    /// * `notification` - 保存する通知
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, notification: &FailedNotification) -> Result<(), RepositoryError>;
+
+    /// IDで通知を取得する
+    ///
+    /// # Arguments
+    /// * `id` - 通知ID
+    ///
+    /// # Returns
+    /// * `Ok(Some(FailedNotification))` - 通知が見つかった
+    /// * `Ok(None)` - 通知が見つからない
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<FailedNotification>, RepositoryError>;
+
+    /// 再送の日時を過ぎた再送待ちの通知を再送の日時の昇順で取得する
+    ///
+    /// # Arguments
+    /// * `now` - 基準日時
+    /// * `limit` - 取得する最大件数
+    ///
+    /// # Returns
+    /// * `Ok(Vec<FailedNotification>)` - 再送する通知のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<FailedNotification>, RepositoryError>;
+
+    /// 指定した状態の通知を最終更新日時の降順で取得する
+    ///
+    /// # Arguments
+    /// * `status` - 通知の状態
+    /// * `limit` - 取得する最大件数
+    ///
+    /// # Returns
+    /// * `Ok(Vec<FailedNotification>)` - 通知のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_status(
+        &self,
+        status: FailedNotificationStatus,
+        limit: u32,
+    ) -> Result<Vec<FailedNotification>, RepositoryError>;
+
+    /// 注文の通知を作成日時の昇順で取得する（顧客のデータのエクスポート用）
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<FailedNotification>)` - 注文の通知のリスト（再送済み・再送をあきらめたものを含む）
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<FailedNotification>, RepositoryError>;
+}
+
+/// アクションリンクの使用記録リポジトリトレイト
+/// 顧客がアクションリンクを使った記録（監査ログ）の永続化を担当するポート
+#[async_trait]
+pub trait ActionLinkAuditRepository: Send + Sync {
+    /// 使用記録を保存する
+    ///
+    /// # Arguments
+    /// * `record` - 保存する使用記録
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, record: &ActionLinkUse) -> Result<(), RepositoryError>;
+
+    /// 注文に対する使用記録を使用日時の昇順で取得する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ActionLinkUse>)` - 使用記録のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_order(&self, order_id: OrderId)
+        -> Result<Vec<ActionLinkUse>, RepositoryError>;
+}
+
+/// 顧客リポジトリトレイト
+/// 顧客集約の永続化を抽象化する
+#[async_trait]
+pub trait CustomerRepository: Send + Sync {
+    /// 顧客を保存する
+    ///
+    /// # Arguments
+    /// * `customer` - 保存する顧客
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, customer: &Customer) -> Result<(), RepositoryError>;
+
+    /// 顧客IDで顧客を検索する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 検索する顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(Some(Customer))` - 顧客が見つかった
+    /// * `Ok(None)` - 顧客が見つからなかった
+    /// * `Err(RepositoryError)` - 検索失敗
+    async fn find_by_id(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Option<Customer>, RepositoryError>;
+
+    /// メールアドレスで顧客を検索する
+    ///
+    /// # Arguments
+    /// * `email` - 検索するメールアドレス
+    ///
+    /// # Returns
+    /// * `Ok(Some(Customer))` - 顧客が見つかった
+    /// * `Ok(None)` - 顧客が見つからなかった
+    /// * `Err(RepositoryError)` - 検索失敗
+    async fn find_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Option<Customer>, RepositoryError>;
+}
+
+/// デバイス登録リポジトリトレイト
+/// 顧客のプッシュ通知用デバイストークンの永続化を担当するポート
+#[async_trait]
+pub trait DeviceRegistrationRepository: Send + Sync {
+    /// デバイス登録を保存する
+    /// 同じ顧客・トークンの組み合わせが既に存在する場合はプラットフォームを更新する
+    ///
+    /// # Arguments
+    /// * `registration` - 保存するデバイス登録
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, registration: &DeviceRegistration) -> Result<(), RepositoryError>;
+
+    /// デバイス登録を削除する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `token` - 削除するデバイストークン
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 削除成功
+    /// * `Ok(false)` - 該当する登録が存在しなかった
+    /// * `Err(RepositoryError)` - 削除失敗
+    async fn delete(
+        &self,
+        customer_id: CustomerId,
+        token: &DeviceToken,
+    ) -> Result<bool, RepositoryError>;
+
+    /// 顧客のデバイス登録を登録順に取得する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<DeviceRegistration>)` - デバイス登録のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<DeviceRegistration>, RepositoryError>;
+}
+
+/// Webhookの購読リポジトリトレイト
+/// 顧客ごとのWebhookの購読の永続化を担当するポート
+#[async_trait]
+pub trait WebhookSubscriptionRepository: Send + Sync {
+    /// 購読を保存する
+    ///
+    /// # Arguments
+    /// * `subscription` - 保存する購読
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, subscription: &WebhookSubscription) -> Result<(), RepositoryError>;
+
+    /// IDで購読を取得する
+    ///
+    /// # Arguments
+    /// * `id` - 購読ID
+    ///
+    /// # Returns
+    /// * `Ok(Some(WebhookSubscription))` - 購読が見つかった
+    /// * `Ok(None)` - 購読が見つからない
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, RepositoryError>;
+
+    /// 顧客の購読を登録順に取得する
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<WebhookSubscription>)` - 購読のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError>;
+
+    /// 顧客の購読を削除する（購読の配信ログも削除する）
+    ///
+    /// # Arguments
+    /// * `customer_id` - 顧客ID
+    /// * `id` - 削除する購読ID
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 削除成功
+    /// * `Ok(false)` - 該当する購読が存在しなかった（他の顧客の購読を含む）
+    /// * `Err(RepositoryError)` - 削除失敗
+    async fn delete(&self, customer_id: CustomerId, id: Uuid) -> Result<bool, RepositoryError>;
+}
+
+/// Webhookの配信ログリポジトリトレイト
+/// 購読ごとのWebhookの配信記録の永続化を担当するポート
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// 配信記録を追加する
+    ///
+    /// # Arguments
+    /// * `delivery` - 追加する配信記録
+    ///
+    /// # Returns
+    /// * `Ok(())` - 追加成功
+    /// * `Err(RepositoryError)` - 追加失敗
+    async fn record(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError>;
+
+    /// IDで配信記録を取得する
+    ///
+    /// # Arguments
+    /// * `id` - 配信ID
+    ///
+    /// # Returns
+    /// * `Ok(Some(WebhookDelivery))` - 配信記録が見つかった
+    /// * `Ok(None)` - 配信記録が見つからない
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RepositoryError>;
+
+    /// 購読の配信記録を新しい順に取得する
+    ///
+    /// # Arguments
+    /// * `subscription_id` - 購読ID
+    /// * `limit` - 取得する最大件数
+    ///
+    /// # Returns
+    /// * `Ok(Vec<WebhookDelivery>)` - 配信記録のリスト
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+}
+
+/// 順番待ちリポジトリトレイト
+/// 順番待ちが有効な書籍の、注文の先着順の待ち行列の永続化を担当するポート
+#[async_trait]
+pub trait WaitlistRepository: Send + Sync {
+    /// 順番待ちに登録する（登録順が先着順になる）
+    /// 同じ注文・書籍の組み合わせが既に登録されている場合は何もしない（再配信による重複を防ぐ）
+    ///
+    /// # Arguments
+    /// * `entries` - 登録する順番待ち（注文の書籍ごと）
+    ///
+    /// # Returns
+    /// * `Ok(())` - 登録成功
+    /// * `Err(RepositoryError)` - 登録失敗
+    async fn join(&self, entries: &[WaitlistEntry]) -> Result<(), RepositoryError>;
+
+    /// 書籍の順番待ちを先着順で取得する
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<WaitlistEntry>)` - 順番待ちのリスト（先頭から順に）
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_book(&self, book_id: BookId) -> Result<Vec<WaitlistEntry>, RepositoryError>;
+
+    /// 注文の順番待ちを取得する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<WaitlistEntry>)` - 注文が並んでいる書籍ごとの順番待ち
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_order(&self, order_id: OrderId)
+        -> Result<Vec<WaitlistEntry>, RepositoryError>;
+
+    /// 注文の順番待ちをすべて削除する（繰り上げ・キャンセル時）
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(())` - 削除成功
+    /// * `Err(RepositoryError)` - 削除失敗
+    async fn remove_order(&self, order_id: OrderId) -> Result<(), RepositoryError>;
+}
+
+/// 割当枠リポジトリトレイト
+/// 書籍ごとの購入上限・販売チャネル別の割当枠と、その割当済みの数量の永続化を担当するポート
+#[async_trait]
+pub trait AllocationQuotaRepository: Send + Sync {
+    /// 書籍の割当枠を取得する
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    ///
+    /// # Returns
+    /// * `Ok(Some(AllocationQuota))` - 割当枠が見つかった
+    /// * `Ok(None)` - 割当枠が設定されていない（制限しない）
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_book_id(
+        &self,
+        book_id: BookId,
+    ) -> Result<Option<AllocationQuota>, RepositoryError>;
+
+    /// 割当枠を保存する（設定と割当済みの数量をまとめて置き換える）
+    ///
+    /// # Arguments
+    /// * `quota` - 保存する割当枠
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, quota: &AllocationQuota) -> Result<(), RepositoryError>;
+
+    /// 書籍の割当枠を削除する（割当済みの数量の記録も削除する）
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 削除成功
+    /// * `Ok(false)` - 割当枠が設定されていなかった
+    /// * `Err(RepositoryError)` - 削除失敗
+    async fn delete(&self, book_id: BookId) -> Result<bool, RepositoryError>;
+}
+
+/// 在庫の仮押さえリポジトリトレイト
+/// チェックアウト中の注文が書籍ごとに仮押さえしている在庫の永続化を担当するポート
+#[async_trait]
+pub trait CheckoutHoldRepository: Send + Sync {
+    /// 注文の仮押さえを置き換える（仮押さえの延長・数量の変更時）
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `holds` - 注文の新しい仮押さえ（書籍ごと）
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn replace_for_order(
+        &self,
+        order_id: OrderId,
+        holds: &[CheckoutHold],
+    ) -> Result<(), RepositoryError>;
+
+    /// 注文の仮押さえを取得する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<CheckoutHold>)` - 注文の書籍ごとの仮押さえ
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_order(&self, order_id: OrderId)
+        -> Result<Vec<CheckoutHold>, RepositoryError>;
+
+    /// 期限切れの仮押さえを期限の古い順に取得する
+    ///
+    /// # Arguments
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(Vec<CheckoutHold>)` - 期限切れの仮押さえ
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<CheckoutHold>, RepositoryError>;
+
+    /// 注文の仮押さえをすべて削除する（確定による在庫予約への引き継ぎ・期限切れ時）
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(())` - 削除成功
+    /// * `Err(RepositoryError)` - 削除失敗
+    async fn remove_order(&self, order_id: OrderId) -> Result<(), RepositoryError>;
+}
+
+/// 注文の編集ロックリポジトリトレイト
+/// オペレーターが注文を編集中であることを示すロックの永続化を担当するポート
+#[async_trait]
+pub trait OrderLockRepository: Send + Sync {
+    /// ロックの取得を試みる
+    /// 注文のロックがない、期限切れ、または同じオペレーターのロックの場合だけ置き換える（延長する）
+    ///
+    /// # Arguments
+    /// * `lock` - 取得するロック
+    ///
+    /// # Returns
+    /// * `Ok(OrderLock)` - 試みた後の注文のロック（locked_byが異なる場合は他のオペレーターがロック中）
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn try_acquire(&self, lock: &OrderLock) -> Result<OrderLock, RepositoryError>;
+
+    /// 注文のロックを取得する（期限切れのロックを含む）
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(Some(OrderLock))` - ロックが見つかった
+    /// * `Ok(None)` - ロックがない
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_order_id(&self, order_id: OrderId)
+        -> Result<Option<OrderLock>, RepositoryError>;
+
+    /// 期限内のロックをすべて取得する（管理画面の注文一覧に表示する）
+    ///
+    /// # Arguments
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// * `Ok(Vec<OrderLock>)` - 期限内のロック
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_active(&self, now: DateTime<Utc>) -> Result<Vec<OrderLock>, RepositoryError>;
+
+    /// 注文のロックを削除する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(())` - 削除成功
+    /// * `Err(RepositoryError)` - 削除失敗
+    async fn remove(&self, order_id: OrderId) -> Result<(), RepositoryError>;
+}
+
+/// CSメモリポジトリトレイト
+/// サポート担当者が注文に残したメモの永続化を担当するポート
+#[async_trait]
+pub trait OrderNoteRepository: Send + Sync {
+    /// メモを追加する
+    ///
+    /// # Arguments
+    /// * `note` - 追加するメモ
+    ///
+    /// # Returns
+    /// * `Ok(())` - 追加成功
+    /// * `Err(RepositoryError)` - 追加失敗
+    async fn add(&self, note: &OrderNote) -> Result<(), RepositoryError>;
+
+    /// 注文のメモを作成日時の昇順で取得する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<OrderNote>)` - 作成日時の昇順のメモ
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_order(&self, order_id: OrderId) -> Result<Vec<OrderNote>, RepositoryError>;
+}
+
+/// 強制遷移の監査記録リポジトリトレイト
+/// 管理者が注文のステータスを強制的に変更した記録の永続化を担当するポート
+#[async_trait]
+pub trait StatusOverrideAuditRepository: Send + Sync {
+    /// 監査記録を追加する
+    ///
+    /// # Arguments
+    /// * `audit` - 追加する監査記録
+    ///
+    /// # Returns
+    /// * `Ok(())` - 追加成功
+    /// * `Err(RepositoryError)` - 追加失敗
+    async fn add(&self, audit: &StatusOverrideAudit) -> Result<(), RepositoryError>;
+
+    /// 注文の監査記録を記録日時の昇順で取得する
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<StatusOverrideAudit>)` - 記録日時の昇順の監査記録
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<StatusOverrideAudit>, RepositoryError>;
+}
+
+/// 処理済みイベントリポジトリトレイト
+/// イベントハンドラーが処理済みのイベントIDの永続化を担当するポート
+/// （サービスを再起動しても、再配信されたイベントを二重に処理しないようにする）
+#[async_trait]
+pub trait ProcessedEventRepository: Send + Sync {
+    /// イベントがハンドラーで処理済みかチェックする
+    ///
+    /// # Arguments
+    /// * `event_id` - イベントID
+    /// * `handler_name` - ハンドラー名
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - 処理済みの場合はtrue
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn is_processed(&self, event_id: Uuid, handler_name: &str)
+        -> Result<bool, RepositoryError>;
+
+    /// イベントをハンドラーで処理済みとして記録する
+    /// 既に記録されている場合は何もしない
+    ///
+    /// # Arguments
+    /// * `event_id` - イベントID
+    /// * `handler_name` - ハンドラー名
+    /// * `processed_at` - 処理した日時
+    ///
+    /// # Returns
+    /// * `Ok(())` - 記録成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn mark_processed(
+        &self,
+        event_id: Uuid,
+        handler_name: &str,
+        processed_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+}
+
+/// サーガ補償リポジトリトレイト
+/// 在庫予約・発送・配達の失敗による補償の記録を担当するポート（サーガの集計に使用）
+#[async_trait]
+pub trait SagaCompensationRepository: Send + Sync {
+    /// 補償を記録する
+    /// 同じ失敗イベントが既に記録されている場合は何もしない（再配信による重複を防ぐ）
+    ///
+    /// # Arguments
+    /// * `compensation` - 補償の記録
+    ///
+    /// # Returns
+    /// * `Ok(())` - 記録成功
+    /// * `Err(RepositoryError)` - 記録失敗
+    async fn record(&self, compensation: &SagaCompensation) -> Result<(), RepositoryError>;
+
+    /// 失敗ステップごとの補償の件数を取得する
+    ///
+    /// # Returns
+    /// * `Ok(BTreeMap<String, u64>)` - 失敗ステップ名と件数
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn count_by_failed_step(&self) -> Result<BTreeMap<String, u64>, RepositoryError>;
+}
+
+/// 受信メッセージリポジトリトレイト
+/// 外部システムから受信したメッセージ（inbox）の記録と処理状態の管理を担当するポート
+#[async_trait]
+pub trait InboxRepository: Send + Sync {
+    /// 受信したメッセージを記録する
+    /// 同じ送信元・外部IDのメッセージが既に記録されている場合は何もしない（再送による重複を防ぐ）
+    ///
+    /// # Arguments
+    /// * `message` - 受信したメッセージ
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 新しく記録した
+    /// * `Ok(false)` - 記録済みのメッセージだった
+    /// * `Err(RepositoryError)` - 記録失敗
+    async fn insert(&self, message: &InboxMessage) -> Result<bool, RepositoryError>;
+
+    /// 未処理のメッセージを受信順に取得する
+    ///
+    /// # Arguments
+    /// * `limit` - 取得する件数の上限
+    async fn find_pending(&self, limit: usize) -> Result<Vec<InboxMessage>, RepositoryError>;
+
+    /// メッセージを処理済みにする
+    async fn mark_processed(
+        &self,
+        source: &str,
+        external_id: &str,
+        processed_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+
+    /// メッセージの処理の失敗を記録する（試行回数を1増やす）
+    ///
+    /// # Arguments
+    /// * `error` - エラーメッセージ
+    /// * `give_up` - trueの場合は処理を諦め、以降は再処理しない
+    async fn record_failure(
+        &self,
+        source: &str,
+        external_id: &str,
+        error: &str,
+        give_up: bool,
+    ) -> Result<(), RepositoryError>;
+}
+
+/// 旧システムの注文の取り込み記録リポジトリトレイト
+/// 旧システムの注文番号ごとの取り込み結果と、隔離したレコードの管理を担当するポート
+#[async_trait]
+pub trait LegacyImportRepository: Send + Sync {
+    /// 取り込み結果を保存する（同じ注文番号の記録は置き換える）
+    async fn save(&self, record: &LegacyImportRecord) -> Result<(), RepositoryError>;
+
+    /// 旧システムの注文番号で取り込みの記録を取得する
+    ///
+    /// # Returns
+    /// * `Ok(Some(LegacyImportRecord))` - 記録が見つかった
+    /// * `Ok(None)` - まだ取り込んでいない
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_legacy_order_no(
+        &self,
+        legacy_order_no: &str,
+    ) -> Result<Option<LegacyImportRecord>, RepositoryError>;
+
+    /// 隔離中のレコードを記録日時の昇順で取得する
+    async fn find_quarantined(&self) -> Result<Vec<LegacyImportRecord>, RepositoryError>;
+}
+
+/// 書籍カタログトレイト
+/// 書籍の現在の販売価格と、言語ごとのタイトルの管理を担当するポート
+#[async_trait]
+pub trait BookCatalog: Send + Sync {
+    /// 書籍の現在の価格を設定する
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `price` - 販売価格
+    ///
+    /// # Returns
+    /// * `Ok(())` - 設定成功
+    /// * `Err(RepositoryError)` - 設定失敗
+    async fn set_price(&self, book_id: BookId, price: Money) -> Result<(), RepositoryError>;
+
+    /// 書籍の現在の価格を取得する
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    ///
+    /// # Returns
+    /// * `Ok(Some(Money))` - 価格が登録されている
+    /// * `Ok(None)` - 価格が登録されていない
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_price(&self, book_id: BookId) -> Result<Option<Money>, RepositoryError>;
+
+    /// 複数の書籍の現在の価格をまとめて取得する
+    /// 価格が登録されていない書籍は結果に含まれない
+    ///
+    /// # Arguments
+    /// * `book_ids` - 書籍IDのリスト
+    ///
+    /// # Returns
+    /// * `Ok(HashMap<BookId, Money>)` - 書籍IDごとの価格
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_prices(
+        &self,
+        book_ids: &[BookId],
+    ) -> Result<HashMap<BookId, Money>, RepositoryError>;
+
+    /// 書籍のタイトルを言語ごとに設定する（同じ言語のタイトルは置き換える）
+    ///
+    /// # Arguments
+    /// * `book_id` - 書籍ID
+    /// * `language` - タイトルの言語
+    /// * `title` - タイトル
+    ///
+    /// # Returns
+    /// * `Ok(())` - 設定成功
+    /// * `Err(RepositoryError)` - 設定失敗
+    async fn set_title(
+        &self,
+        book_id: BookId,
+        language: Language,
+        title: &str,
+    ) -> Result<(), RepositoryError>;
+
+    /// 複数の書籍の言語ごとのタイトルをまとめて取得する
+    /// タイトルが登録されていない書籍は結果に含まれない
+    ///
+    /// # Arguments
+    /// * `book_ids` - 書籍IDのリスト
+    ///
+    /// # Returns
+    /// * `Ok(HashMap<BookId, BookTitles>)` - 書籍IDごとのタイトル
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_titles(
+        &self,
+        book_ids: &[BookId],
+    ) -> Result<HashMap<BookId, BookTitles>, RepositoryError>;
+}
+
+/// イベントバスエラー
+#[derive(Debug, thiserror::Error)]
+pub enum EventBusError {
+    #[error("Event publishing failed: {0}")]
+    PublishingFailed(String),
+    #[error("Subscription not found: {0}")]
+    SubscriptionNotFound(String),
+}
+
+/// イベントバストレイト
+/// イベントの発行と配信を管理するポート
+#[async_trait]
+pub trait EventBus: Send + Sync {
+    /// イベントを発行し、登録されたハンドラーに配信
+    async fn publish(&self, event: DomainEvent) -> Result<(), EventBusError>;
+}
+
+/// スキーマレジストリエラー
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaRegistryError {
+    #[error("Incompatible schema for {subject}: {}", .violations.join("; "))]
+    Incompatible {
+        subject: String,
+        violations: Vec<String>,
+    },
+    #[error("Schema registry unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// スキーマレジストリトレイト
+/// イベントのペイロードのスキーマをサブジェクトごとに登録し、互換性のない変更を拒否するポート
+#[async_trait]
+pub trait SchemaRegistry: Send + Sync {
+    /// スキーマを登録する
+    /// 同じサブジェクトに同じスキーマを登録した場合は、登録済みのIDを返す
+    ///
+    /// # Arguments
+    /// * `subject` - 登録先のサブジェクト（EventSchema::subject）
+    /// * `schema` - 登録するスキーマ
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - スキーマID
+    /// * `Err(SchemaRegistryError::Incompatible)` - 登録済みの最新のスキーマと互換性がない
+    /// * `Err(SchemaRegistryError::Unavailable)` - レジストリに接続できない
+    async fn register(&self, subject: &str, schema: &EventSchema) -> Result<u32, SchemaRegistryError>;
+}
+
+/// デッドレターキュー監視ポート
+/// 異常検知と管理者向けの表示のためにデッドレターキューの状況を公開する
+#[async_trait]
+pub trait DeadLetterMonitor: Send + Sync {
+    /// これまでにデッドレターキューへ送られたイベントの累計数
+    fn dead_lettered_total(&self) -> u64;
+
+    /// デッドレターキューに残っているエントリ（古い順）
+    async fn dead_letters(&self) -> Vec<DeadLetter>;
+}
+
+/// 購読管理ポート
+/// 管理者向けに、イベントバスの購読の一覧・解除・一時停止・再開を公開する
+/// ハンドラー名で指定し、同じハンドラーが複数のイベントタイプを購読している場合はすべてに適用する
+#[async_trait]
+pub trait SubscriptionManager: Send + Sync {
+    /// 現在の購読の一覧（登録順）
+    async fn subscriptions(&self) -> Vec<SubscriptionStatus>;
+
+    /// ハンドラーの購読を解除する
+    /// 一時停止中にためていたイベントは破棄する
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 解除した購読の数
+    /// * `Err(EventBusError::SubscriptionNotFound)` - 該当するハンドラーがない
+    async fn unsubscribe(&self, handler_name: &str) -> Result<usize, EventBusError>;
+
+    /// ハンドラーへの配信を一時停止する
+    ///
+    /// # Arguments
+    /// * `handler_name` - ハンドラー名
+    /// * `paused_events` - 一時停止中のイベントをためるか読み飛ばすか
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 一時停止した購読の数
+    /// * `Err(EventBusError::SubscriptionNotFound)` - 該当するハンドラーがない
+    async fn pause(
+        &self,
+        handler_name: &str,
+        paused_events: PausedEventHandling,
+    ) -> Result<usize, EventBusError>;
+
+    /// ハンドラーへの配信を再開し、ためていたイベントを発行順に配信する
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 再開時に配信したイベント数
+    /// * `Err(EventBusError::SubscriptionNotFound)` - 該当するハンドラーがない
+    async fn resume(&self, handler_name: &str) -> Result<usize, EventBusError>;
+
+    /// 配信待ちのイベント数と負荷制限の状態
+    fn load_shedding(&self) -> LoadSheddingStatus;
+}
+
+/// イベント配信制御ポート
+/// ローリングデプロイの間、イベントを失わずに処理を止めるために、配信全体の一時停止・再開を公開する
+/// 一時停止中のイベントはためておくか、ブローカーから受信せずにブローカーに残す
+#[async_trait]
+pub trait EventDispatchControl: Send + Sync {
+    /// 新しいイベントの配信を止め、処理中のハンドラーが終わるのを待つ
+    ///
+    /// # Arguments
+    /// * `drain_timeout` - 処理中のハンドラーが終わるのを待つ上限（過ぎても一時停止はしたまま）
+    ///
+    /// # Returns
+    /// * 一時停止した後の状態（処理中のイベント数が0であれば止め終えている）
+    async fn pause_dispatch(&self, drain_timeout: Duration) -> EventDispatchStatus;
+
+    /// 配信を再開し、一時停止中にためていたイベントを発行順に配信する
+    ///
+    /// # Returns
+    /// * 再開時に配信したイベント数
+    async fn resume_dispatch(&self) -> usize;
+
+    /// 配信全体の現在の状態
+    fn dispatch_status(&self) -> EventDispatchStatus;
+}
+
+/// 購読の登録内容リポジトリトレイト
+/// 管理者が一時停止・解除したハンドラーの状態を再起動後も保つために、購読の登録内容の永続化を担当するポート
+#[async_trait]
+pub trait SubscriptionRegistryRepository: Send + Sync {
+    /// 登録内容を保存する（同じハンドラーの登録内容は置き換える）
+    ///
+    /// # Arguments
+    /// * `registration` - 保存する登録内容
+    ///
+    /// # Returns
+    /// * `Ok(())` - 保存成功
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn save(&self, registration: &SubscriptionRegistration) -> Result<(), RepositoryError>;
+
+    /// 保存したすべての登録内容をハンドラー名の順に取得する
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SubscriptionRegistration>)` - 登録内容
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_all(&self) -> Result<Vec<SubscriptionRegistration>, RepositoryError>;
+
+    /// 登録内容を削除する（次の起動時は購読時の状態で始まる）
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 削除した
+    /// * `Ok(false)` - 該当する登録内容がない
+    /// * `Err(RepositoryError)` - 削除失敗
+    async fn delete(&self, handler_name: &str) -> Result<bool, RepositoryError>;
+}
+
+/// イベントストリーム監視ポート
+/// プロジェクションの遅延を算出するために、最後に発行されたイベントを公開する
+pub trait EventStreamMonitor: Send + Sync {
+    /// イベントストリームの先頭（最後に発行されたイベント）
+    fn stream_head(&self) -> EventStreamHead;
+}
+
+/// イベント配信トレース監視ポート
+/// 発行されたイベントごとに、どのハンドラーに配信されてどうなったかを公開する（サーガの調査用）
+pub trait EventTraceMonitor: Send + Sync {
+    /// トレースモードが有効か（無効の場合はトレースを記録しない）
+    fn tracing_enabled(&self) -> bool;
+
+    /// 記録したイベントの配信のトレースをイベントの発生順に取得する
+    ///
+    /// # Arguments
+    /// * `correlation_id` - 指定した場合はその相関ID（サーガ）のトレースのみ
+    fn event_traces(&self, correlation_id: Option<Uuid>) -> Vec<EventTrace>;
+}
+
+/// ライブイベントポート
+/// 発行されたドメインイベントを、購読を登録せずにその場で受け取るための窓口（注文ごとのイベントストリーム用）
+pub trait LiveEventSource: Send + Sync {
+    /// 以降に発行されるすべてのイベントを受け取る
+    /// 受信が遅れて取りこぼした場合は、受信側で`RecvError::Lagged`として取りこぼした件数が分かる
+    fn subscribe_all(&self) -> broadcast::Receiver<DomainEvent>;
+}
+
+/// イベントジャーナルトレイト
+/// 発行されたドメインイベントを記録順に永続化し、期間を指定して読み出すポート（イベントのエクスポート用）
+#[async_trait]
+pub trait EventJournal: Send + Sync {
+    /// イベントを記録する
+    /// 同じイベントIDのイベントが既に記録されている場合は何もしない
+    ///
+    /// # Arguments
+    /// * `event` - 発行されたイベント
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 新しく記録した
+    /// * `Ok(false)` - 既に記録されていた
+    /// * `Err(RepositoryError)` - 記録失敗
+    async fn append(&self, event: &DomainEvent) -> Result<bool, RepositoryError>;
+
+    /// 発生日時が期間内のイベントを、指定した位置より後から記録順に取得する
+    ///
+    /// # Arguments
+    /// * `from` - 期間の開始（この日時を含む）
+    /// * `to` - 期間の終了（この日時を含まない）
+    /// * `after_position` - この位置より後に記録されたイベントを取得する（最初からの場合は0）
+    /// * `limit` - 取得する最大件数
+    ///
+    /// # Returns
+    /// * `Ok(Vec<JournaledEvent>)` - 記録順のイベント
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn read_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after_position: u64,
+        limit: usize,
+    ) -> Result<Vec<JournaledEvent>, RepositoryError>;
+
+    /// 集約IDが一致するイベントを記録順に取得する（停滞した注文の診断用）
+    ///
+    /// # Arguments
+    /// * `aggregate_id` - 集約ID（注文に関するイベントは注文ID）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<JournaledEvent>)` - 記録順のイベント
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn find_by_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Vec<JournaledEvent>, RepositoryError>;
+}
+
+/// イベントストアトレイト
+/// 集約ごとのイベントストリームにドメインイベントを追記し、ストリーム全体を読み出すポート（イベントソーシング用）
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// ストリームの末尾にイベントを追記する
+    /// ストリームのバージョンが期待したバージョンと異なる場合は追記しない（楽観的排他制御）
+    ///
+    /// # Arguments
+    /// * `stream_id` - イベントストリームID
+    /// * `events` - 追記するイベント（先頭から順にバージョンを採番する）
+    /// * `expected_version` - 読み込んだときのストリームのバージョン（新しいストリームの場合は0）
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - 追記後のストリームのバージョン
+    /// * `Err(RepositoryError::ConcurrencyConflict)` - 読み込んだ後に他の操作が追記していた
+    /// * `Err(RepositoryError)` - 追記失敗
+    async fn append(
+        &self,
+        stream_id: &str,
+        events: &[DomainEvent],
+        expected_version: u64,
+    ) -> Result<u64, RepositoryError>;
+
+    /// ストリームのイベントをバージョン順にすべて取得する
+    ///
+    /// # Arguments
+    /// * `stream_id` - イベントストリームID
+    ///
+    /// # Returns
+    /// * `Ok(EventStream)` - イベントストリーム（イベントがない場合はバージョン0の空のストリーム）
+    /// * `Err(RepositoryError)` - 取得失敗
+    async fn load_stream(&self, stream_id: &str) -> Result<EventStream, RepositoryError>;
+}
+
+/// オブジェクトストレージエラー
+#[derive(Debug, thiserror::Error)]
+pub enum ObjectStorageError {
+    #[error("Object write failed: {0}")]
+    WriteFailed(String),
+    #[error("Object read failed: {0}")]
+    ReadFailed(String),
+}
+
+/// オブジェクトストレージトレイト
+/// ローカルファイルシステムやS3互換ストレージへのオブジェクトの読み書きを抽象化するポート
+#[async_trait]
+pub trait ObjectStoragePort: Send + Sync {
+    /// オブジェクトを書き込む（同じキーのオブジェクトは上書きする）
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> Result<(), ObjectStorageError>;
+
+    /// オブジェクトを読み出す（存在しない場合はNone）
+    async fn get_object(&self, key: &str) -> Result<Option<Vec<u8>>, ObjectStorageError>;
+}
+
+/// アラート通知エラー
+#[derive(Debug, thiserror::Error)]
+pub enum AlertingError {
+    #[error("Alert delivery failed: {0}")]
+    DeliveryFailed(String),
+}
+
+/// アラート通知トレイト
+/// Slack・Webhook・コンソールなどへのアラート送信を抽象化するポート
+#[async_trait]
+pub trait AlertingPort: Send + Sync {
+    /// アラートを送信する
+    async fn send_alert(&self, alert: &Alert) -> Result<(), AlertingError>;
+}
+
+/// ダウンロードリンク生成エラー
+#[derive(Debug, thiserror::Error)]
+pub enum DownloadLinkError {
+    #[error("Download link generation failed: {0}")]
+    GenerationFailed(String),
+}
+
+/// ダウンロードリンク生成トレイト
+/// 電子書籍を提供するための期限付きダウンロードリンクの発行を抽象化するポート
+#[async_trait]
+pub trait DownloadLinkGenerator: Send + Sync {
+    /// 注文の電子書籍に対するダウンロードリンクを生成する
+    async fn generate_download_link(
+        &self,
+        order_id: OrderId,
+        book_id: BookId,
+    ) -> Result<DownloadLink, DownloadLinkError>;
+}
+
+/// アクションリンクの検証エラー
+#[derive(Debug, thiserror::Error)]
+pub enum ActionLinkError {
+    #[error("Malformed action link token: {0}")]
+    Malformed(String),
+    #[error("Action link signature mismatch")]
+    InvalidSignature,
+}
+
+/// アクションリンクの署名トレイト
+/// 通知に載せるアクションリンクのトークンの署名と検証を抽象化するポート
+pub trait ActionLinkSigner: Send + Sync {
+    /// リンクの内容に署名してURLに埋め込めるトークンを作成する
+    fn sign(&self, claims: &ActionLinkClaims) -> String;
+
+    /// トークンの署名を検証してリンクの内容を取り出す（有効期限は検証しない）
+    ///
+    /// # Returns
+    /// * `Ok(ActionLinkClaims)` - 署名が正しいトークンの内容
+    /// * `Err(ActionLinkError)` - 形式が正しくない、または署名が一致しない
+    fn verify(&self, token: &str) -> Result<ActionLinkClaims, ActionLinkError>;
+}
+
+/// プッシュ通知エラー
+#[derive(Debug, thiserror::Error)]
+pub enum PushNotificationError {
+    #[error("Push notification delivery failed: {0}")]
+    DeliveryFailed(String),
+    #[error("Topic subscription failed: {0}")]
+    SubscriptionFailed(String),
+}
+
+/// プッシュ通知トレイト
+/// FCMなどのトピック型プッシュ通知サービスへの購読管理と配信を抽象化するポート
+#[async_trait]
+pub trait PushNotificationPort: Send + Sync {
+    /// デバイスをトピックに購読させる
+    async fn subscribe_to_topic(
+        &self,
+        token: &DeviceToken,
+        topic: &str,
+    ) -> Result<(), PushNotificationError>;
+
+    /// デバイスのトピック購読を解除する
+    async fn unsubscribe_from_topic(
+        &self,
+        token: &DeviceToken,
+        topic: &str,
+    ) -> Result<(), PushNotificationError>;
+
+    /// トピックにJSONペイロードを配信する
+    async fn publish_to_topic(&self, topic: &str, payload: &str)
+        -> Result<(), PushNotificationError>;
+}
+
+/// 通知の送信エラー
+#[derive(Debug, thiserror::Error)]
+pub enum NotificationError {
+    #[error("Notification delivery failed: {0}")]
+    DeliveryFailed(String),
+}
+
+/// 通知の送信トレイト
+/// メール・SMSなどの外部の通知サービスへの送信を抽象化するポート
+#[async_trait]
+pub trait NotificationSender: Send + Sync {
+    /// 通知をチャネル（メール・SMS）で送信する
+    async fn send(&self, notification: &Notification) -> Result<(), NotificationError>;
+}
+
+/// 接続中の顧客への通知の配信トレイト
+/// WebSocketなどで接続している顧客に通知を届けるポート
+/// （メール・SMSの区別は notification_retry::NotificationChannel で、こちらは送信先と別に配信する経路）
+#[async_trait]
+pub trait NotificationChannelPort: Send + Sync {
+    /// 顧客が接続しているすべての宛先に通知を配信する（接続していない場合は何もしない）
+    ///
+    /// # Returns
+    /// 通知を配信した接続の数
+    async fn push(&self, notification: &CustomerNotification) -> usize;
+}
+
+/// 接続中の顧客への通知の受信トレイト
+/// 顧客の接続ごとに、NotificationChannelPort で配信された通知を受け取る
+pub trait CustomerNotificationSource: Send + Sync {
+    /// 顧客の通知の受信を始める（受信側を破棄すると接続から外れる）
+    fn connect(&self, customer_id: CustomerId) -> mpsc::Receiver<CustomerNotification>;
+}
+
+/// Webhookの送信トレイト
+/// 購読の配信先URLへの署名付きのHTTP送信を抽象化するポート
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// ペイロードを購読の配信先に送信する
+    /// 配信先の失敗はエラーではなく送信結果として返す（配信ログに記録するため）
+    ///
+    /// # Arguments
+    /// * `subscription` - 配信先の購読（URLと署名用のシークレット）
+    /// * `delivery_id` - 配信ID（受信側が配信ログと突き合わせるためにヘッダーで送る）
+    /// * `event_type` - イベントの種類
+    /// * `payload` - JSONペイロード
+    async fn send(
+        &self,
+        subscription: &WebhookSubscription,
+        delivery_id: Uuid,
+        event_type: &str,
+        payload: &str,
+    ) -> WebhookOutcome;
+}
+
+/// 配送業者に引き渡す荷物
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shipment {
+    pub order_id: OrderId,
+    pub shipping_address: ShippingAddress,
+    /// 発送したOrderShippedイベントのID（配達失敗時の追跡用）
+    pub shipped_event_id: Uuid,
+    pub correlation_id: Uuid,
+}
+
+/// 配送業者から通知される配達の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CarrierDeliveryResult {
+    /// 配達完了
+    Delivered,
+    /// 配達失敗（理由）
+    Failed(String),
+}
+
+/// 配送業者エラー
+#[derive(Debug, thiserror::Error)]
+pub enum CarrierError {
+    #[error("Carrier hand-over failed: {0}")]
+    HandOverFailed(String),
+}
+
+/// 配達の結果を受け取るコールバック
+#[async_trait]
+pub trait DeliveryResultCallback: Send + Sync {
+    /// 配送業者から配達の結果を受け取る
+    async fn on_delivery_result(&self, shipment: Shipment, result: CarrierDeliveryResult);
+}
+
+/// 配送業者トレイト
+/// 発送した荷物の配送業者への引き渡しと、配達の結果の通知を抽象化するポート
+#[async_trait]
+pub trait ShippingCarrierPort: Send + Sync {
+    /// 荷物を配送業者に引き渡す
+    /// 配達の結果は引き渡しとは非同期に`callback`へ通知される
+    async fn hand_over(
+        &self,
+        shipment: Shipment,
+        callback: Arc<dyn DeliveryResultCallback>,
+    ) -> Result<(), CarrierError>;
+}
+
+/// 決済エラー
+#[derive(Debug, thiserror::Error)]
+pub enum PaymentError {
+    #[error("Payment declined: {0}")]
+    Declined(String),
+    #[error("Payment gateway unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// 決済トレイト
+/// 注文の代金の請求と返金を抽象化するポート
+/// 請求・返金は注文IDで冪等に扱う（同じ注文への再請求は二重に請求せず、請求のない注文の返金は何もしない）
+#[async_trait]
+pub trait PaymentPort: Send + Sync {
+    /// 注文の代金を請求する
+    async fn charge(&self, order_id: OrderId, amount: &Money) -> Result<(), PaymentError>;
+
+    /// 注文に請求した代金を返金する
+    async fn refund(&self, order_id: OrderId) -> Result<(), PaymentError>;
 }
@@ -0,0 +1,192 @@
+use crate::domain::delivery_estimate::DeliveryEstimator;
+use crate::domain::event::{DomainEvent, PreOrderActivated};
+use crate::domain::model::{Order, OrderId, OrderStatus};
+use crate::domain::order_totals::OrderTotals;
+use crate::domain::port::{
+    EventBus, InventoryRepository, Logger, OrderRepository, RepositoryError,
+};
+use crate::domain::shipping_fee::ShippingFeePolicy;
+use crate::domain::task_supervisor::SupervisedTask;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// 予約注文の発売日ジョブ
+/// 発売待ちの注文を定期的に確認し、含まれる書籍がすべて発売済みになった注文を有効化する
+/// 有効化した注文はPreOrderActivatedイベントを発行し、在庫予約以降のサーガを再開する
+pub struct PreOrderReleaseJob {
+    order_repository: Arc<dyn OrderRepository>,
+    inventory_repository: Arc<dyn InventoryRepository>,
+    event_bus: Arc<dyn EventBus>,
+    shipping_fee_policy: ShippingFeePolicy,
+    delivery_estimator: DeliveryEstimator,
+    logger: Arc<dyn Logger>,
+}
+
+impl PreOrderReleaseJob {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        inventory_repository: Arc<dyn InventoryRepository>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            order_repository,
+            inventory_repository,
+            event_bus,
+            shipping_fee_policy: ShippingFeePolicy::default(),
+            delivery_estimator: DeliveryEstimator::default(),
+            logger,
+        }
+    }
+
+    /// 有効化した注文の合計金額の算出に使用する配送料ポリシーを設定
+    pub fn with_shipping_fee_policy(mut self, shipping_fee_policy: ShippingFeePolicy) -> Self {
+        self.shipping_fee_policy = shipping_fee_policy;
+        self
+    }
+
+    /// 有効化した注文の配達予定日の見積もりを設定
+    pub fn with_delivery_estimator(mut self, delivery_estimator: DeliveryEstimator) -> Self {
+        self.delivery_estimator = delivery_estimator;
+        self
+    }
+
+    /// ジョブをバックグラウンドで起動
+    pub fn spawn(self, check_interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(check_interval);
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// 指定日時点で発売済みになった予約注文を有効化する
+    ///
+    /// # Returns
+    /// * 有効化した注文IDのリスト
+    pub async fn run(&self, today: NaiveDate) -> Result<Vec<OrderId>, RepositoryError> {
+        let orders = self
+            .order_repository
+            .find_by_status(OrderStatus::AwaitingRelease)
+            .await?;
+
+        let mut activated = Vec::new();
+        for order in orders {
+            let order_id = order.id();
+            match self.is_released(&order, today).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    self.log_failure(order_id, "Failed to check release dates", &e.to_string());
+                    continue;
+                }
+            }
+
+            if self.activate(order).await {
+                activated.push(order_id);
+            }
+        }
+
+        Ok(activated)
+    }
+
+    /// 注文に含まれる書籍がすべて発売済みかチェック
+    async fn is_released(&self, order: &Order, today: NaiveDate) -> Result<bool, RepositoryError> {
+        for order_line in order.order_lines() {
+            let inventory = self
+                .inventory_repository
+                .find_by_book_id(order_line.book_id())
+                .await?;
+            if inventory.is_some_and(|inventory| !inventory.is_released(today)) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// 予約注文を有効化してPreOrderActivatedイベントを発行する
+    /// イベント発行に失敗した場合は発売待ちに戻し、次回の実行で再試行する
+    async fn activate(&self, mut order: Order) -> bool {
+        let order_id = order.id();
+        if let Err(e) = order.activate_pre_order() {
+            self.log_failure(order_id, "Failed to activate pre-order", &e.to_string());
+            return false;
+        }
+        order.estimate_delivery(&self.delivery_estimator);
+        if let Err(e) = self.order_repository.save(&mut order).await {
+            self.log_failure(
+                order_id,
+                "Failed to save activated pre-order",
+                &e.to_string(),
+            );
+            return false;
+        }
+
+        let event = PreOrderActivated::new(
+            order_id,
+            order.customer_id(),
+            order.order_lines().to_vec(),
+            OrderTotals::for_order(&order, &self.shipping_fee_policy).total,
+        );
+        let correlation_id = event.metadata.correlation_id;
+
+        if let Err(e) = self
+            .event_bus
+            .publish(DomainEvent::PreOrderActivated(event))
+            .await
+        {
+            self.log_failure(
+                order_id,
+                "Failed to publish PreOrderActivated",
+                &e.to_string(),
+            );
+            if order.mark_as_awaiting_release().is_ok() {
+                if let Err(e) = self.order_repository.save(&mut order).await {
+                    self.log_failure(order_id, "Failed to revert pre-order", &e.to_string());
+                }
+            }
+            return false;
+        }
+
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), order_id.to_string());
+        self.logger.info(
+            "PreOrderReleaseJob",
+            "Pre-order activated",
+            Some(correlation_id),
+            Some(context),
+        );
+        true
+    }
+
+    fn log_failure(&self, order_id: OrderId, message: &str, error: &str) {
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), order_id.to_string());
+        context.insert("error".to_string(), error.to_string());
+        self.logger
+            .error("PreOrderReleaseJob", message, None, Some(context));
+    }
+}
+
+#[async_trait]
+impl SupervisedTask for PreOrderReleaseJob {
+    async fn run_once(&self) {
+        let today = chrono::Utc::now().date_naive();
+        if let Err(e) = self.run(today).await {
+            let mut context = HashMap::new();
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "PreOrderReleaseJob",
+                "Failed to load orders awaiting release",
+                None,
+                Some(context),
+            );
+        }
+    }
+}
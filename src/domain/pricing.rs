@@ -0,0 +1,125 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::{BookId, Money, Order};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// 注文後にカタログの価格が変更されていた場合の扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceChangePolicy {
+    /// 確定を拒否する（利用者が価格を確認して再価格付けするまで確定させない）
+    #[default]
+    Reject,
+    /// カタログの価格で明細の単価を更新し、警告を返して確定する
+    Reprice,
+}
+
+impl PriceChangePolicy {
+    /// 文字列からPriceChangePolicyを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "reject" => Ok(PriceChangePolicy::Reject),
+            "reprice" => Ok(PriceChangePolicy::Reprice),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な価格変更ポリシー: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// 注文明細の単価とカタログの現在の価格の差異
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PriceChange {
+    pub book_id: BookId,
+    /// 明細に記録されている単価（書籍を追加した時点の価格）
+    pub previous_price: Money,
+    /// カタログの現在の価格
+    pub current_price: Money,
+}
+
+/// 注文明細の単価をカタログの価格と比較し、差異のある明細を返す
+/// カタログに価格が登録されていない書籍は比較の対象外とする
+///
+/// # Arguments
+/// * `order` - 比較する注文
+/// * `catalog_prices` - 書籍IDごとのカタログの現在の価格
+pub fn detect_price_changes(
+    order: &Order,
+    catalog_prices: &HashMap<BookId, Money>,
+) -> Vec<PriceChange> {
+    order
+        .order_lines()
+        .iter()
+        .filter_map(|line| {
+            let current_price = *catalog_prices.get(&line.book_id())?;
+            (current_price != line.unit_price()).then(|| PriceChange {
+                book_id: line.book_id(),
+                previous_price: line.unit_price(),
+                current_price,
+            })
+        })
+        .collect()
+}
+
+/// 価格の差異を確定拒否のエラーに変換する
+pub fn price_changed_error(changes: &[PriceChange]) -> DomainError {
+    let details = changes
+        .iter()
+        .map(|change| {
+            format!(
+                "{}: {}円 → {}円",
+                change.book_id,
+                change.previous_price.amount(),
+                change.current_price.amount()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    DomainError::PriceChanged(format!(
+        "注文後に書籍の価格が変更されました。再価格付けしてから確定してください（{}）",
+        details
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{CustomerId, OrderId};
+
+    #[test]
+    fn test_detect_price_changes_skips_unlisted_books() {
+        let changed = BookId::new();
+        let unchanged = BookId::new();
+        let unlisted = BookId::new();
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(changed, 1, Money::jpy(1000)).unwrap();
+        order.add_book(unchanged, 1, Money::jpy(2000)).unwrap();
+        order.add_book(unlisted, 1, Money::jpy(3000)).unwrap();
+        let catalog_prices =
+            HashMap::from([(changed, Money::jpy(1200)), (unchanged, Money::jpy(2000))]);
+
+        let changes = detect_price_changes(&order, &catalog_prices);
+        assert_eq!(
+            changes,
+            vec![PriceChange {
+                book_id: changed,
+                previous_price: Money::jpy(1000),
+                current_price: Money::jpy(1200),
+            }]
+        );
+        assert!(matches!(
+            price_changed_error(&changes),
+            DomainError::PriceChanged(_)
+        ));
+
+        order.reprice_line(changed, Money::jpy(1200)).unwrap();
+        assert!(detect_price_changes(&order, &catalog_prices).is_empty());
+        assert_eq!(order.take_warnings()[0].code, "LINE_REPRICED");
+        assert_eq!(
+            PriceChangePolicy::from_string("reprice").unwrap(),
+            PriceChangePolicy::Reprice
+        );
+        assert!(PriceChangePolicy::from_string("ignore").is_err());
+    }
+}
@@ -0,0 +1,521 @@
+use crate::domain::event::EventMetadata;
+use crate::domain::event_bus::HandlerError;
+use crate::domain::port::{Logger, RepositoryError};
+use crate::domain::sequence::{SequenceCheck, SequencedBuffer};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// イベントストリームの先頭（最後に発行されたイベント）
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EventStreamHead {
+    /// これまでに発行されたイベントの累計数
+    pub published_events: u64,
+    pub last_event_id: Option<Uuid>,
+    pub last_event_type: Option<String>,
+    pub last_occurred_at: Option<DateTime<Utc>>,
+}
+
+/// プロジェクションの処理位置（最後に適用したイベント）
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ProjectionPosition {
+    /// これまでに適用したイベントの累計数
+    pub processed_events: u64,
+    pub last_event_id: Option<Uuid>,
+    pub last_event_type: Option<String>,
+    /// 最後に適用したイベントの集約内連番（連番を持たないイベントの場合はNone）
+    pub last_sequence_number: Option<u64>,
+    /// 最後に適用したイベントの発生日時
+    pub last_occurred_at: Option<DateTime<Utc>>,
+    /// 最後にイベントを適用した日時
+    pub last_processed_at: Option<DateTime<Utc>>,
+}
+
+/// プロジェクションのチェックポイント（集約ごとに最後に適用したイベントの連番）
+/// 投影の更新と同じトランザクションで保存し、再起動後はここから適用を再開する
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectionCheckpoint {
+    pub projection: String,
+    pub aggregate_id: String,
+    /// 最後に適用したイベントの集約内連番
+    pub sequence_number: u64,
+}
+
+impl ProjectionCheckpoint {
+    pub fn new(projection: &str, aggregate_id: &str, sequence_number: u64) -> Self {
+        Self {
+            projection: projection.to_string(),
+            aggregate_id: aggregate_id.to_string(),
+            sequence_number,
+        }
+    }
+
+    /// 連番のイベントが適用済みかどうか（チェックポイント以下の連番は適用済み）
+    pub fn covers(&self, sequence_number: u64) -> bool {
+        sequence_number <= self.sequence_number
+    }
+}
+
+/// プロジェクションの処理位置を記録する
+/// プロジェクションハンドラーと監視エンドポイントで共有する
+#[derive(Clone, Default)]
+pub struct ProjectionProgress {
+    position: Arc<Mutex<ProjectionPosition>>,
+}
+
+impl ProjectionProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// イベントの適用を記録
+    pub async fn record_applied(
+        &self,
+        event_type: &str,
+        metadata: &EventMetadata,
+        processed_at: DateTime<Utc>,
+    ) {
+        let mut position = self.position.lock().await;
+        position.processed_events += 1;
+        position.last_event_id = Some(metadata.event_id);
+        position.last_event_type = Some(event_type.to_string());
+        position.last_sequence_number = metadata.sequence_number;
+        position.last_occurred_at = Some(metadata.occurred_at);
+        position.last_processed_at = Some(processed_at);
+    }
+
+    /// 現在の処理位置を取得
+    pub async fn position(&self) -> ProjectionPosition {
+        self.position.lock().await.clone()
+    }
+}
+
+/// プロジェクションの状態（GET /admin/projectionsのレスポンス）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProjectionStatus {
+    pub name: String,
+    #[serde(flatten)]
+    pub position: ProjectionPosition,
+    /// イベントストリームの先頭の発生日時
+    pub head_occurred_at: Option<DateTime<Utc>>,
+    /// 先頭のイベントと最後に適用したイベントの発生日時の差（秒）
+    /// 先頭には購読対象外のイベントも含まれるため、遅延の目安として使う
+    /// イベントが発行されていない場合は0、まだ何も適用していない場合はNone
+    pub lag_seconds: Option<i64>,
+}
+
+impl ProjectionStatus {
+    /// 処理位置とイベントストリームの先頭から状態を算出する
+    pub fn evaluate(name: &str, position: ProjectionPosition, head: &EventStreamHead) -> Self {
+        let lag_seconds = match (head.last_occurred_at, position.last_occurred_at) {
+            (None, _) => Some(0),
+            (Some(_), None) => None,
+            (Some(head_at), Some(applied_at)) => Some((head_at - applied_at).num_seconds().max(0)),
+        };
+
+        Self {
+            name: name.to_string(),
+            position,
+            head_occurred_at: head.last_occurred_at,
+            lag_seconds,
+        }
+    }
+}
+
+/// 監視対象のプロジェクションの一覧
+#[derive(Clone, Default)]
+pub struct ProjectionRegistry {
+    projections: Vec<(String, ProjectionProgress)>,
+}
+
+impl ProjectionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// プロジェクションを登録
+    pub fn with_projection(mut self, name: &str, progress: ProjectionProgress) -> Self {
+        self.projections.push((name.to_string(), progress));
+        self
+    }
+
+    /// 登録済みのプロジェクションかどうか
+    pub fn contains(&self, name: &str) -> bool {
+        self.projections
+            .iter()
+            .any(|(registered, _)| registered == name)
+    }
+
+    /// すべてのプロジェクションの状態を取得（登録順）
+    pub async fn statuses(&self, head: &EventStreamHead) -> Vec<ProjectionStatus> {
+        let mut statuses = Vec::with_capacity(self.projections.len());
+        for (name, progress) in &self.projections {
+            statuses.push(ProjectionStatus::evaluate(
+                name,
+                progress.position().await,
+                head,
+            ));
+        }
+        statuses
+    }
+}
+
+/// チェックポイント付きで投影するプロジェクション
+/// 連番を持つイベントは投影とチェックポイントの更新を同じトランザクションで行い、再配信されても二重に適用しない
+/// 投影の順序の整列・チェックポイントからの再開・処理位置の記録はSequencedProjectorが共通で行う
+#[async_trait]
+pub trait CheckpointedProjection: Send + Sync + 'static {
+    /// 1件のイベントから投影する内容
+    type Change: Clone + Send + Sync + 'static;
+
+    /// プロジェクション名（チェックポイントと監視エンドポイントで使う）
+    fn name(&self) -> &'static str;
+
+    /// 投影とチェックポイントの更新を同じトランザクションで行う
+    /// チェックポイントの連番以下のイベントは適用済みとして何もしない
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 適用した
+    /// * `Ok(false)` - 適用済みのため何もしなかった
+    /// * `Err(RepositoryError)` - 保存失敗
+    async fn apply_checkpointed(
+        &self,
+        checkpoint: &ProjectionCheckpoint,
+        change: &Self::Change,
+    ) -> Result<bool, RepositoryError>;
+
+    /// 連番を持たないイベントを到着順に投影する
+    async fn apply(&self, change: &Self::Change) -> Result<(), RepositoryError>;
+
+    /// チェックポイントをすべて取得する（起動時の復旧に使う）
+    async fn load_checkpoints(&self) -> Result<Vec<ProjectionCheckpoint>, RepositoryError>;
+}
+
+/// 投影を待っているイベント
+#[derive(Clone)]
+struct PendingChange<C> {
+    event_type: &'static str,
+    metadata: EventMetadata,
+    change: C,
+}
+
+/// 集約ごとの連番バッファ
+type AggregateBuffer<C> = Arc<Mutex<SequencedBuffer<PendingChange<C>>>>;
+
+/// イベントを集約ごとの連番の順にプロジェクションへ適用する
+/// 先に届いたイベントは先行するイベントが揃うまで保留し、投影に失敗した場合は連番を進めずに再配信を待つ
+/// 連番のバッファは集約ごとにロックするため、ある集約の投影の保存を待っている間も他の集約の投影は進む
+pub struct SequencedProjector<P: CheckpointedProjection> {
+    projection: Arc<P>,
+    logger: Arc<dyn Logger>,
+    buffers: Arc<Mutex<HashMap<String, AggregateBuffer<P::Change>>>>,
+    progress: ProjectionProgress,
+}
+
+impl<P: CheckpointedProjection> Clone for SequencedProjector<P> {
+    fn clone(&self) -> Self {
+        Self {
+            projection: self.projection.clone(),
+            logger: self.logger.clone(),
+            buffers: self.buffers.clone(),
+            progress: self.progress.clone(),
+        }
+    }
+}
+
+impl<P: CheckpointedProjection> SequencedProjector<P> {
+    pub fn new(projection: P, logger: Arc<dyn Logger>) -> Self {
+        Self {
+            projection: Arc::new(projection),
+            logger,
+            buffers: Arc::new(Mutex::new(HashMap::new())),
+            progress: ProjectionProgress::new(),
+        }
+    }
+
+    /// 投影の処理位置の記録先を設定
+    /// 監視エンドポイントと共有し、プロジェクションの遅延を公開する
+    pub fn with_progress(mut self, progress: ProjectionProgress) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// 集約の連番バッファを取得（初めての集約の場合は作成する）
+    /// 全体のロックはバッファの取得の間だけ保持する
+    async fn buffer(&self, aggregate_id: &str) -> AggregateBuffer<P::Change> {
+        self.buffers
+            .lock()
+            .await
+            .entry(aggregate_id.to_string())
+            .or_insert_with(|| Arc::new(Mutex::new(SequencedBuffer::new())))
+            .clone()
+    }
+
+    /// 永続化したチェックポイントから投影を再開する（起動時に呼び出す）
+    /// チェックポイントのある集約は、適用済みの連番の続きから投影する
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 再開した集約の数
+    /// * `Err(HandlerError)` - チェックポイントの取得失敗
+    pub async fn recover(&self) -> Result<usize, HandlerError> {
+        let checkpoints = self.projection.load_checkpoints().await.map_err(|e| {
+            HandlerError::RepositoryError(format!("チェックポイント取得エラー: {}", e))
+                .at_step("load_checkpoints")
+        })?;
+
+        for checkpoint in &checkpoints {
+            self.buffer(&checkpoint.aggregate_id)
+                .await
+                .lock()
+                .await
+                .resume_from(&checkpoint.aggregate_id, checkpoint.sequence_number);
+        }
+
+        let mut context = HashMap::new();
+        context.insert("projection".to_string(), self.projection.name().to_string());
+        context.insert("checkpoints".to_string(), checkpoints.len().to_string());
+        self.logger.info(
+            "SequencedProjector",
+            "Projection resumed from checkpoints",
+            None,
+            Some(context),
+        );
+
+        Ok(checkpoints.len())
+    }
+
+    /// イベントを集約ごとの連番の順に投影
+    /// 連番のないイベントは到着順に投影する
+    pub async fn project(
+        &self,
+        event_type: &'static str,
+        metadata: &EventMetadata,
+        aggregate_id: &str,
+        change: P::Change,
+    ) -> Result<(), HandlerError> {
+        let pending = PendingChange {
+            event_type,
+            metadata: metadata.clone(),
+            change,
+        };
+        let Some(sequence_number) = metadata.sequence_number else {
+            return self.apply(aggregate_id, pending).await;
+        };
+
+        // 同じ集約のイベントは1件ずつ順に適用するため、投影の保存を終えるまで集約のバッファをロックする
+        let buffer = self.buffer(aggregate_id).await;
+        let mut sequence = buffer.lock().await;
+        let warning = match sequence.accept(aggregate_id, sequence_number, pending) {
+            SequenceCheck::InOrder => None,
+            SequenceCheck::Gap { expected, received } => Some((
+                "Event arrived ahead of sequence, buffering",
+                expected,
+                received,
+            )),
+            SequenceCheck::Stale { expected, received } => Some((
+                "Event already applied in sequence, skipping",
+                expected,
+                received,
+            )),
+        };
+        if let Some((message, expected, received)) = warning {
+            let mut context = HashMap::new();
+            context.insert("projection".to_string(), self.projection.name().to_string());
+            context.insert("event_type".to_string(), event_type.to_string());
+            context.insert("aggregate_id".to_string(), aggregate_id.to_string());
+            context.insert("expected_sequence".to_string(), expected.to_string());
+            context.insert("received_sequence".to_string(), received.to_string());
+            self.logger.warn(
+                "SequencedProjector",
+                message,
+                Some(metadata.correlation_id),
+                Some(context),
+            );
+        }
+
+        // 投影に失敗した場合は連番を進めず、再配信時にもう一度投影する
+        while let Some(pending) = sequence.next_ready(aggregate_id).cloned() {
+            self.apply(aggregate_id, pending).await?;
+            sequence.advance(aggregate_id);
+        }
+
+        Ok(())
+    }
+
+    /// イベントを投影し、処理位置を記録
+    /// 連番を持つイベントはチェックポイントと一緒に保存し、適用済みの場合は処理位置も進めない
+    async fn apply(
+        &self,
+        aggregate_id: &str,
+        pending: PendingChange<P::Change>,
+    ) -> Result<(), HandlerError> {
+        let applied = match pending.metadata.sequence_number {
+            Some(sequence_number) => {
+                let checkpoint =
+                    ProjectionCheckpoint::new(self.projection.name(), aggregate_id, sequence_number);
+                self.projection
+                    .apply_checkpointed(&checkpoint, &pending.change)
+                    .await
+                    .map_err(|e| {
+                        HandlerError::RepositoryError(format!("投影の保存エラー: {}", e))
+                            .at_step("apply_checkpointed")
+                    })?
+            }
+            None => {
+                self.projection.apply(&pending.change).await.map_err(|e| {
+                    HandlerError::RepositoryError(format!("投影の保存エラー: {}", e))
+                        .at_step("apply_projection")
+                })?;
+                true
+            }
+        };
+
+        let mut context = HashMap::new();
+        context.insert("projection".to_string(), self.projection.name().to_string());
+        context.insert("event_type".to_string(), pending.event_type.to_string());
+        context.insert("aggregate_id".to_string(), aggregate_id.to_string());
+        if !applied {
+            self.logger.debug(
+                "SequencedProjector",
+                "Event already applied at checkpoint, skipping",
+                Some(pending.metadata.correlation_id),
+                Some(context),
+            );
+            return Ok(());
+        }
+
+        self.logger.debug(
+            "SequencedProjector",
+            "Event projected",
+            Some(pending.metadata.correlation_id),
+            Some(context),
+        );
+        self.progress
+            .record_applied(pending.event_type, &pending.metadata, Utc::now())
+            .await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+    use tokio::sync::Notify;
+
+    struct NoopLogger;
+
+    impl Logger for NoopLogger {
+        fn debug(&self, _: &str, _: &str, _: Option<Uuid>, _: Option<HashMap<String, String>>) {}
+        fn info(&self, _: &str, _: &str, _: Option<Uuid>, _: Option<HashMap<String, String>>) {}
+        fn warn(&self, _: &str, _: &str, _: Option<Uuid>, _: Option<HashMap<String, String>>) {}
+        fn error(&self, _: &str, _: &str, _: Option<Uuid>, _: Option<HashMap<String, String>>) {}
+    }
+
+    /// 集約"slow"の保存だけが解除されるまで待つプロジェクション
+    #[derive(Default)]
+    struct GatedProjection {
+        gate: Notify,
+        applied: Mutex<Vec<(String, u64)>>,
+    }
+
+    #[async_trait]
+    impl CheckpointedProjection for Arc<GatedProjection> {
+        type Change = ();
+
+        fn name(&self) -> &'static str {
+            "gated"
+        }
+
+        async fn apply_checkpointed(
+            &self,
+            checkpoint: &ProjectionCheckpoint,
+            _change: &(),
+        ) -> Result<bool, RepositoryError> {
+            if checkpoint.aggregate_id == "slow" {
+                self.gate.notified().await;
+            }
+            self.applied
+                .lock()
+                .await
+                .push((checkpoint.aggregate_id.clone(), checkpoint.sequence_number));
+            Ok(true)
+        }
+
+        async fn apply(&self, _change: &()) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn load_checkpoints(&self) -> Result<Vec<ProjectionCheckpoint>, RepositoryError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_projector_keeps_projecting_other_aggregates_while_one_is_saving() {
+        let projection = Arc::new(GatedProjection::default());
+        let progress = ProjectionProgress::new();
+        let projector = SequencedProjector::new(projection.clone(), Arc::new(NoopLogger))
+            .with_progress(progress.clone());
+
+        let slow = {
+            let projector = projector.clone();
+            tokio::spawn(async move {
+                let metadata = EventMetadata::new().with_sequence_number(1);
+                projector.project("OrderShipped", &metadata, "slow", ()).await
+            })
+        };
+        tokio::task::yield_now().await;
+
+        // 他の集約の投影は、保存を待っている集約のロックを待たずに進む
+        let metadata = EventMetadata::new().with_sequence_number(1);
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            projector.project("OrderShipped", &metadata, "fast", ()),
+        )
+        .await
+        .expect("他の集約の投影が待たされた")
+        .unwrap();
+        assert_eq!(*projection.applied.lock().await, vec![("fast".to_string(), 1)]);
+
+        projection.gate.notify_one();
+        slow.await.unwrap().unwrap();
+        assert_eq!(projection.applied.lock().await.len(), 2);
+        assert_eq!(progress.position().await.processed_events, 2);
+    }
+
+    #[tokio::test]
+    async fn test_projection_lag_against_stream_head() {
+        let progress = ProjectionProgress::new();
+        let registry =
+            ProjectionRegistry::new().with_projection("tracking_timeline", progress.clone());
+        let now = Utc::now();
+
+        // イベントが発行されていなければ遅延なし
+        let statuses = registry.statuses(&EventStreamHead::default()).await;
+        assert_eq!(statuses[0].lag_seconds, Some(0));
+
+        let metadata = EventMetadata::new().with_sequence_number(2);
+        progress
+            .record_applied("OrderShipped", &metadata, now)
+            .await;
+        let head = EventStreamHead {
+            published_events: 5,
+            last_event_id: Some(Uuid::new_v4()),
+            last_event_type: Some("OrderDelivered".to_string()),
+            last_occurred_at: Some(metadata.occurred_at + TimeDelta::seconds(30)),
+        };
+
+        let status = &registry.statuses(&head).await[0];
+        assert_eq!(status.name, "tracking_timeline");
+        assert_eq!(status.position.processed_events, 1);
+        assert_eq!(status.position.last_sequence_number, Some(2));
+        assert_eq!(status.lag_seconds, Some(30));
+        assert!(registry.contains("tracking_timeline"));
+        assert!(!registry.contains("unknown"));
+    }
+}
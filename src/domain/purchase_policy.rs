@@ -0,0 +1,178 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::BookId;
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// 購入制限
+/// 転売目的の大量購入を防ぐための上限値（Noneの上限は制限しない。デフォルトはどちらも制限しない）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PurchaseLimits {
+    /// 1顧客が同じ書籍を1日（直近24時間）に購入できる最大数量
+    pub max_quantity_per_book_per_day: Option<u32>,
+    /// 1顧客が同時に保持できる未完了（Pending・Confirmed・OnHold・AwaitingRelease・Waitlisted）の注文数
+    pub max_open_orders_per_customer: Option<u32>,
+}
+
+/// 注文の保存時に確認する購入制限
+/// リポジトリは顧客ごとに排他したうえで集計し、制限を満たす場合のみ保存する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaseCheck {
+    /// 新しい注文を作成する（未完了の注文数を確認する）
+    OpenOrder,
+    /// 注文に書籍を追加する（集計期間内に購入済みの同じ書籍の数量を確認する）
+    BookQuantity {
+        book_id: BookId,
+        requested: u32,
+        since: DateTime<Utc>,
+    },
+}
+
+/// 購入ポリシー（ドメインサービス）
+/// リポジトリから取得した集計値をもとに購入制限を判定する
+#[derive(Debug, Clone, Default)]
+pub struct PurchasePolicy {
+    limits: PurchaseLimits,
+}
+
+impl PurchasePolicy {
+    /// 購入制限を指定して購入ポリシーを作成
+    pub fn new(limits: PurchaseLimits) -> Self {
+        Self { limits }
+    }
+
+    /// 購入制限を取得
+    pub fn limits(&self) -> PurchaseLimits {
+        self.limits
+    }
+
+    /// 書籍ごとの購入数量を集計する期間の開始日時
+    pub fn quantity_window_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - TimeDelta::days(1)
+    }
+
+    /// 新しい注文の作成時の確認
+    pub fn open_order_check(&self) -> Option<PurchaseCheck> {
+        self.limits
+            .max_open_orders_per_customer
+            .map(|_| PurchaseCheck::OpenOrder)
+    }
+
+    /// 書籍の追加時の確認
+    ///
+    /// # Arguments
+    /// * `book_id` - 追加する書籍のID
+    /// * `requested` - 追加する数量
+    /// * `now` - 集計期間の基準日時
+    pub fn book_quantity_check(
+        &self,
+        book_id: BookId,
+        requested: u32,
+        now: DateTime<Utc>,
+    ) -> Option<PurchaseCheck> {
+        self.limits
+            .max_quantity_per_book_per_day
+            .map(|_| PurchaseCheck::BookQuantity {
+                book_id,
+                requested,
+                since: self.quantity_window_start(now),
+            })
+    }
+
+    /// 保存時の確認を判定する
+    ///
+    /// # Arguments
+    /// * `check` - 確認する購入制限
+    /// * `counted` - 排他したうえで集計した値（OpenOrderは未完了の注文数、BookQuantityは購入済みの数量）
+    pub fn ensure(&self, check: &PurchaseCheck, counted: u32) -> Result<(), DomainError> {
+        match check {
+            PurchaseCheck::OpenOrder => self.ensure_can_open_order(counted),
+            PurchaseCheck::BookQuantity { requested, .. } => {
+                self.ensure_can_purchase(counted, *requested)
+            }
+        }
+    }
+
+    /// 新しい注文を作成できるか判定
+    ///
+    /// # Arguments
+    /// * `open_orders` - 顧客の未完了の注文数
+    pub fn ensure_can_open_order(&self, open_orders: u32) -> Result<(), DomainError> {
+        let Some(max_open_orders) = self.limits.max_open_orders_per_customer else {
+            return Ok(());
+        };
+        if open_orders >= max_open_orders {
+            return Err(DomainError::OpenOrderLimitExceeded(format!(
+                "未完了の注文が上限（{}件）に達しています",
+                max_open_orders
+            )));
+        }
+        Ok(())
+    }
+
+    /// 書籍を追加購入できるか判定
+    ///
+    /// # Arguments
+    /// * `purchased_quantity` - 集計期間内に購入済み（注文中を含む）の数量
+    /// * `requested_quantity` - 追加しようとしている数量
+    pub fn ensure_can_purchase(
+        &self,
+        purchased_quantity: u32,
+        requested_quantity: u32,
+    ) -> Result<(), DomainError> {
+        let Some(max_quantity) = self.limits.max_quantity_per_book_per_day else {
+            return Ok(());
+        };
+        let total = purchased_quantity.saturating_add(requested_quantity);
+        if total > max_quantity {
+            return Err(DomainError::PurchaseQuantityLimitExceeded(format!(
+                "同じ書籍の1日あたりの購入上限（{}冊）を超えています（購入済み: {}冊, 追加: {}冊）",
+                max_quantity, purchased_quantity, requested_quantity
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> PurchasePolicy {
+        PurchasePolicy::new(PurchaseLimits {
+            max_quantity_per_book_per_day: Some(3),
+            max_open_orders_per_customer: Some(2),
+        })
+    }
+
+    #[test]
+    fn test_default_policy_has_no_limits() {
+        let policy = PurchasePolicy::default();
+
+        assert!(policy.ensure_can_open_order(1_000).is_ok());
+        assert!(policy.ensure_can_purchase(1_000, 1_000).is_ok());
+        assert_eq!(policy.open_order_check(), None);
+        assert_eq!(policy.book_quantity_check(BookId::new(), 1, Utc::now()), None);
+    }
+
+    #[test]
+    fn test_open_order_limit() {
+        let policy = policy();
+
+        assert!(policy.ensure_can_open_order(1).is_ok());
+        assert!(matches!(
+            policy.ensure_can_open_order(2),
+            Err(DomainError::OpenOrderLimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_quantity_limit_includes_already_purchased() {
+        let policy = policy();
+
+        assert!(policy.ensure_can_purchase(0, 3).is_ok());
+        assert!(policy.ensure_can_purchase(2, 1).is_ok());
+        assert!(matches!(
+            policy.ensure_can_purchase(2, 2),
+            Err(DomainError::PurchaseQuantityLimitExceeded(_))
+        ));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::model::BookId;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 在庫数と在庫変動ログの残高の食い違い
+/// 在庫数の変更は在庫変動ログと同じトランザクションで記録するため、食い違いはログを経由しない変更
+/// （データベースの直接更新やバックアップからの部分的な復元など）で生じる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LedgerDrift {
+    pub book_id: BookId,
+    /// 在庫に記録されている在庫数
+    pub quantity_on_hand: u32,
+    /// 在庫変動ログの変動量の合計
+    pub ledger_quantity: i64,
+}
+
+impl LedgerDrift {
+    /// 在庫数から在庫変動ログの残高を引いた差（正の値はログで説明できない在庫）
+    pub fn difference(&self) -> i64 {
+        i64::from(self.quantity_on_hand) - self.ledger_quantity
+    }
+}
+
+/// 在庫照合レポートの1行
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InventoryReconciliationEntry {
+    pub book_id: BookId,
+    /// 在庫に記録されていた在庫数
+    pub quantity_on_hand: u32,
+    /// 在庫変動ログの変動量の合計
+    pub ledger_quantity: i64,
+    /// 在庫数と在庫変動ログの残高の差（棚卸しで実数との照合が必要な数量）
+    pub difference: i64,
+    /// 今回の修復で凍結したかどうか（検出後に別の処理で解消済みの場合はfalse）
+    pub frozen: bool,
+}
+
+/// 在庫照合レポート
+/// 在庫変動ログとの食い違いを検出・凍結した結果をまとめ、棚卸しによる照合の手がかりにする
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InventoryReconciliationReport {
+    pub generated_at: DateTime<Utc>,
+    /// 凍結した書籍の数
+    pub frozen_count: usize,
+    pub entries: Vec<InventoryReconciliationEntry>,
+}
+
+impl InventoryReconciliationReport {
+    /// 検出した食い違いと凍結結果からレポートを作成
+    ///
+    /// # Arguments
+    /// * `generated_at` - レポートの作成日時
+    /// * `results` - 検出した食い違いと、凍結できたかどうかの組
+    pub fn build(generated_at: DateTime<Utc>, results: &[(LedgerDrift, bool)]) -> Self {
+        let entries: Vec<InventoryReconciliationEntry> = results
+            .iter()
+            .map(|(drift, frozen)| InventoryReconciliationEntry {
+                book_id: drift.book_id,
+                quantity_on_hand: drift.quantity_on_hand,
+                ledger_quantity: drift.ledger_quantity,
+                difference: drift.difference(),
+                frozen: *frozen,
+            })
+            .collect();
+
+        Self {
+            generated_at,
+            frozen_count: entries.iter().filter(|entry| entry.frozen).count(),
+            entries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_counts_frozen_books_and_difference() {
+        let frozen_book = BookId::new();
+        let resolved_book = BookId::new();
+        let results = vec![
+            (
+                LedgerDrift {
+                    book_id: frozen_book,
+                    quantity_on_hand: 5,
+                    ledger_quantity: 2,
+                },
+                true,
+            ),
+            (
+                LedgerDrift {
+                    book_id: resolved_book,
+                    quantity_on_hand: 0,
+                    ledger_quantity: 1,
+                },
+                false,
+            ),
+        ];
+
+        let report = InventoryReconciliationReport::build(Utc::now(), &results);
+
+        assert_eq!(report.frozen_count, 1);
+        assert_eq!(report.entries[0].book_id, frozen_book);
+        assert_eq!(report.entries[0].quantity_on_hand, 5);
+        assert_eq!(report.entries[0].ledger_quantity, 2);
+        assert_eq!(report.entries[0].difference, 3);
+        assert_eq!(report.entries[1].difference, -1);
+        assert!(!report.entries[1].frozen);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::domain::error::DomainError;
+use crate::domain::event::EVENT_TYPES;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// リトライを使い切ったイベント処理の扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadLetterRouting {
+    /// デッドレターキューに送る
+    #[default]
+    DeadLetter,
+    /// 警告ログを出力して破棄する（通知など、失敗しても業務に影響しないイベント向け）
+    Discard,
+}
+
+impl DeadLetterRouting {
+    pub fn from_string(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "dead_letter" => Ok(DeadLetterRouting::DeadLetter),
+            "discard" => Ok(DeadLetterRouting::Discard),
+            _ => Err(DomainError::InvalidValue(format!(
+                "不明なデッドレターの扱いです: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// 購読ごとの配信保証
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryGuarantee {
+    /// 失敗時はリトライポリシーに従って再実行し、使い切った場合はデッドレターの扱いに従う
+    /// 一時的な失敗では同じイベントを重複して処理する可能性がある
+    #[default]
+    AtLeastOnce,
+    /// 1回だけ実行し、失敗してもリトライせずデッドレターキューにも送らない
+    /// 重複より欠落の方が許容できるハンドラー（SMS送信など）向け
+    AtMostOnce,
+}
+
+/// イベント処理のリトライポリシー
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RetryPolicy {
+    /// 初回を含む最大試行回数（1の場合はリトライしない）
+    pub max_attempts: u32,
+    /// 最初のリトライまでの待機時間（ミリ秒）
+    pub initial_backoff_ms: u64,
+    /// リトライごとに待機時間を何倍にするか（1の場合は一定間隔）
+    pub backoff_multiplier: u32,
+    /// 待機時間の上限（ミリ秒）
+    pub max_backoff_ms: u64,
+    /// リトライを使い切った場合の扱い
+    pub dead_letter: DeadLetterRouting,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff_ms: 1_000,
+            backoff_multiplier: 1,
+            max_backoff_ms: 30_000,
+            dead_letter: DeadLetterRouting::DeadLetter,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// リトライポリシーを作成
+    /// 試行回数と倍率は1以上が必要
+    pub fn new(
+        max_attempts: u32,
+        initial_backoff_ms: u64,
+        backoff_multiplier: u32,
+        max_backoff_ms: u64,
+        dead_letter: DeadLetterRouting,
+    ) -> Result<Self, DomainError> {
+        if max_attempts == 0 || backoff_multiplier == 0 {
+            return Err(DomainError::InvalidValue(
+                "リトライの試行回数と倍率は1以上で指定してください".to_string(),
+            ));
+        }
+        Ok(Self {
+            max_attempts,
+            initial_backoff_ms,
+            backoff_multiplier,
+            max_backoff_ms,
+            dead_letter,
+        })
+    }
+
+    /// 指定した回数の試行に失敗した後、次の試行までの待機時間
+    /// 初回の失敗後はinitial_backoff_ms、以降は倍率を掛けて上限で打ち切る
+    pub fn backoff_after(&self, failed_attempts: u32) -> Duration {
+        let exponent = failed_attempts.saturating_sub(1);
+        let backoff_ms = (self.backoff_multiplier as u64)
+            .checked_pow(exponent)
+            .and_then(|factor| self.initial_backoff_ms.checked_mul(factor))
+            .unwrap_or(u64::MAX)
+            .min(self.max_backoff_ms);
+        Duration::from_millis(backoff_ms)
+    }
+}
+
+/// イベントタイプごとのリトライポリシー
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RetryPolicies {
+    /// 個別の指定がないイベントタイプに適用するポリシー
+    pub default_policy: RetryPolicy,
+    /// イベントタイプごとのポリシー（例: "OrderConfirmed" => 試行5回）
+    pub overrides: BTreeMap<String, RetryPolicy>,
+}
+
+/// イベントタイプに適用されるリトライポリシー（GET /admin/retry-policiesのレスポンス）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventRetryPolicy {
+    pub event_type: String,
+    /// イベントタイプ個別の指定かどうか（falseの場合は既定のポリシー）
+    pub overridden: bool,
+    #[serde(flatten)]
+    pub policy: RetryPolicy,
+}
+
+impl RetryPolicies {
+    /// イベントタイプに適用するポリシーを取得
+    pub fn policy_for(&self, event_type: &str) -> &RetryPolicy {
+        self.overrides
+            .get(event_type)
+            .unwrap_or(&self.default_policy)
+    }
+
+    /// すべてのイベントタイプに適用されるポリシーの一覧
+    pub fn effective_policies(&self) -> Vec<EventRetryPolicy> {
+        EVENT_TYPES
+            .iter()
+            .map(|&event_type| EventRetryPolicy {
+                event_type: event_type.to_string(),
+                overridden: self.overrides.contains_key(event_type),
+                policy: *self.policy_for(event_type),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff_is_capped() {
+        let policy = RetryPolicy::new(5, 100, 2, 500, DeadLetterRouting::DeadLetter).unwrap();
+        assert_eq!(policy.backoff_after(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff_after(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff_after(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff_after(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff_after(64), Duration::from_millis(500));
+        assert!(RetryPolicy::new(0, 100, 2, 500, DeadLetterRouting::DeadLetter).is_err());
+
+        let no_retry = RetryPolicy::new(1, 0, 1, 0, DeadLetterRouting::Discard).unwrap();
+        let policies = RetryPolicies {
+            default_policy: RetryPolicy::default(),
+            overrides: BTreeMap::from([("FulfillmentSlaBreached".to_string(), no_retry)]),
+        };
+        assert_eq!(policies.policy_for("FulfillmentSlaBreached"), &no_retry);
+        assert_eq!(policies.policy_for("OrderConfirmed").max_attempts, 3);
+
+        let effective = policies.effective_policies();
+        assert_eq!(effective.len(), EVENT_TYPES.len());
+        assert!(effective
+            .iter()
+            .any(|policy| policy.event_type == "FulfillmentSlaBreached" && policy.overridden));
+    }
+}
@@ -0,0 +1,248 @@
+use crate::domain::model::{Order, OrderId, OrderStatus};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// 補償の起点となった失敗ステップ（SagaCompensationStartedのfailed_stepと同じ名前）
+pub const FAILED_STEP_INVENTORY_RESERVATION: &str = "inventory_reservation";
+pub const FAILED_STEP_SHIPPING: &str = "shipping";
+pub const FAILED_STEP_DELIVERY: &str = "delivery";
+
+/// 進行中のサーガが待っているステップ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SagaStep {
+    /// 発売日を待っている（AwaitingRelease）
+    AwaitingRelease,
+    /// 順番待ちで在庫の確保を待っている（Waitlisted）
+    Waitlist,
+    /// 発送を待っている（Confirmed）
+    Shipping,
+    /// 配達完了を待っている（Shipped）
+    Delivery,
+}
+
+impl SagaStep {
+    /// ステップを表す名前
+    pub fn name(&self) -> &'static str {
+        match self {
+            SagaStep::AwaitingRelease => "awaiting_release",
+            SagaStep::Waitlist => "waitlist",
+            SagaStep::Shipping => "shipping",
+            SagaStep::Delivery => "delivery",
+        }
+    }
+
+    /// 注文が待っているステップとその開始日時
+    /// 確定前・完了済み・キャンセル済みの注文はサーガが進行中でないためNone
+    pub fn current(order: &Order) -> Option<(SagaStep, DateTime<Utc>)> {
+        let step = match order.status() {
+            OrderStatus::AwaitingRelease => SagaStep::AwaitingRelease,
+            OrderStatus::Waitlisted => SagaStep::Waitlist,
+            OrderStatus::Confirmed => SagaStep::Shipping,
+            OrderStatus::Shipped => SagaStep::Delivery,
+            _ => return None,
+        };
+        // 発売待ち・順番待ちは確定直後に遷移するため、確定日時をステップの開始とみなす
+        let started_at = match step {
+            SagaStep::Delivery => order.shipped_at(),
+            _ => order.confirmed_at(),
+        }?;
+        Some((step, started_at))
+    }
+}
+
+/// 永続化された補償の記録
+/// 在庫予約・発送・配達の失敗イベントごとに1件記録する
+#[derive(Debug, Clone, PartialEq)]
+pub struct SagaCompensation {
+    /// 失敗イベントのID（再配信による重複記録を防ぐ）
+    pub event_id: Uuid,
+    pub order_id: OrderId,
+    /// 補償の起点となった失敗ステップ（例: "shipping"）
+    pub failed_step: String,
+    pub failure_reason: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// 完了したステップの所要時間の集計
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StepDuration {
+    /// 集計対象の完了件数
+    pub completed: u64,
+    /// 平均所要時間（秒）。完了件数が0の場合はNone
+    pub average_seconds: Option<i64>,
+}
+
+/// 最も長く同じステップで止まっているサーガ
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StuckSaga {
+    pub order_id: String,
+    pub step: SagaStep,
+    /// ステップの開始日時
+    pub step_started_at: DateTime<Utc>,
+    /// ステップの開始からの経過時間（秒）
+    pub age_seconds: i64,
+}
+
+/// サーガの集計（GET /admin/sagas/metricsのレスポンス）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SagaMetricsReport {
+    pub generated_at: DateTime<Utc>,
+    /// ステップごとの進行中のサーガ数
+    pub active_sagas: BTreeMap<String, u64>,
+    /// ステップごとの平均所要時間（確定→発送、発送→配達完了）
+    pub average_step_durations: BTreeMap<String, StepDuration>,
+    /// 失敗ステップごとの補償の件数
+    pub compensations_by_failed_step: BTreeMap<String, u64>,
+    /// 最も古いステップで止まっているサーガ（進行中のサーガがない場合はNone）
+    pub oldest_stuck_saga: Option<StuckSaga>,
+}
+
+impl SagaMetricsReport {
+    /// 注文のステータス遷移日時と補償の件数からサーガの集計を作成
+    pub fn build(
+        orders: &[Order],
+        compensations_by_failed_step: BTreeMap<String, u64>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut active_sagas: BTreeMap<String, u64> = [
+            SagaStep::AwaitingRelease,
+            SagaStep::Waitlist,
+            SagaStep::Shipping,
+            SagaStep::Delivery,
+        ]
+        .iter()
+        .map(|step| (step.name().to_string(), 0))
+        .collect();
+        let mut oldest: Option<(SagaStep, DateTime<Utc>, &Order)> = None;
+        let mut shipping_seconds = Vec::new();
+        let mut delivery_seconds = Vec::new();
+
+        for order in orders {
+            if let Some((step, started_at)) = SagaStep::current(order) {
+                *active_sagas.entry(step.name().to_string()).or_default() += 1;
+                if oldest.is_none_or(|(_, oldest_started_at, _)| started_at < oldest_started_at) {
+                    oldest = Some((step, started_at, order));
+                }
+            }
+
+            if let (Some(confirmed_at), Some(shipped_at)) =
+                (order.confirmed_at(), order.shipped_at())
+            {
+                shipping_seconds.push((shipped_at - confirmed_at).num_seconds());
+            }
+            if let (Some(shipped_at), Some(delivered_at)) =
+                (order.shipped_at(), order.delivered_at())
+            {
+                delivery_seconds.push((delivered_at - shipped_at).num_seconds());
+            }
+        }
+
+        let average_step_durations = BTreeMap::from([
+            (
+                SagaStep::Shipping.name().to_string(),
+                StepDuration::from_seconds(&shipping_seconds),
+            ),
+            (
+                SagaStep::Delivery.name().to_string(),
+                StepDuration::from_seconds(&delivery_seconds),
+            ),
+        ]);
+
+        let oldest_stuck_saga = oldest.map(|(step, started_at, order)| StuckSaga {
+            order_id: order.id().to_string(),
+            step,
+            step_started_at: started_at,
+            age_seconds: (now - started_at).num_seconds(),
+        });
+
+        Self {
+            generated_at: now,
+            active_sagas,
+            average_step_durations,
+            compensations_by_failed_step,
+            oldest_stuck_saga,
+        }
+    }
+}
+
+impl StepDuration {
+    fn from_seconds(seconds: &[i64]) -> Self {
+        let completed = seconds.len() as u64;
+        let average_seconds =
+            (completed > 0).then(|| seconds.iter().sum::<i64>() / completed as i64);
+        Self {
+            completed,
+            average_seconds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, CustomerId, Money};
+    use chrono::TimeDelta;
+
+    fn order_with(
+        status: OrderStatus,
+        confirmed_at: Option<DateTime<Utc>>,
+        shipped_at: Option<DateTime<Utc>>,
+        delivered_at: Option<DateTime<Utc>>,
+    ) -> Order {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        Order::reconstruct(
+            order.id(),
+            order.customer_id(),
+            order.order_lines().to_vec(),
+            None,
+            status,
+        )
+        .unwrap()
+        .with_transition_times(confirmed_at, shipped_at, delivered_at)
+    }
+
+    #[test]
+    fn test_report_counts_active_steps_and_finds_oldest_stuck_saga() {
+        let now = Utc::now();
+        let hours_ago = |hours| Some(now - TimeDelta::hours(hours));
+        let stuck = order_with(OrderStatus::Waitlisted, hours_ago(50), None, None);
+        let orders = vec![
+            order_with(OrderStatus::Confirmed, hours_ago(5), None, None),
+            stuck.clone(),
+            order_with(OrderStatus::Shipped, hours_ago(30), hours_ago(10), None),
+            order_with(
+                OrderStatus::Delivered,
+                hours_ago(40),
+                hours_ago(20),
+                hours_ago(8),
+            ),
+            order_with(OrderStatus::Pending, None, None, None),
+        ];
+        let compensations = BTreeMap::from([(FAILED_STEP_SHIPPING.to_string(), 2)]);
+
+        let report = SagaMetricsReport::build(&orders, compensations, now);
+
+        assert_eq!(report.active_sagas["shipping"], 1);
+        assert_eq!(report.active_sagas["waitlist"], 1);
+        assert_eq!(report.active_sagas["delivery"], 1);
+        assert_eq!(report.active_sagas["awaiting_release"], 0);
+        // 確定→発送は20時間と20時間、発送→配達完了は12時間の1件
+        let shipping = &report.average_step_durations["shipping"];
+        assert_eq!(shipping.completed, 2);
+        assert_eq!(shipping.average_seconds, Some(20 * 3600));
+        assert_eq!(
+            report.average_step_durations["delivery"].average_seconds,
+            Some(12 * 3600)
+        );
+        assert_eq!(report.compensations_by_failed_step["shipping"], 2);
+
+        let oldest = report.oldest_stuck_saga.unwrap();
+        assert_eq!(oldest.order_id, stuck.id().to_string());
+        assert_eq!(oldest.step, SagaStep::Waitlist);
+        assert_eq!(oldest.age_seconds, 50 * 3600);
+    }
+}
@@ -0,0 +1,574 @@
+use crate::domain::delivery_estimate::DeliveryEstimator;
+use crate::domain::error::DomainError;
+use crate::domain::event::{
+    DomainEvent, EventMetadata, InventoryReleased, InventoryReservationFailed, InventoryReserved,
+    OrderCancelled, OrderConfirmed, OrderShipped, ShippingFailed,
+};
+use crate::domain::event_bus::{EventHandler, HandlerError};
+use crate::domain::fulfillment_mode::FulfillmentMode;
+use crate::domain::handler::ProcessedEventTracker;
+use crate::domain::model::{BookId, Inventory, Order, OrderId, OrderLine, OrderStatus};
+use crate::domain::port::{
+    EventBus, InventoryRepository, Logger, OrderRepository, PaymentPort, ProcessedEventRepository,
+};
+use crate::domain::shipping_fee::CarrierLimits;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// ステップの返信を待つ時間のデフォルト値
+const DEFAULT_STEP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 注文確定後のサーガの進め方
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SagaMode {
+    /// 各ハンドラーが前のステップのイベントに反応して次のステップを進める（コレオグラフィ）
+    #[default]
+    Choreography,
+    /// OrderSagaOrchestratorがOrderConfirmedを受けて各ステップを順に指示する（オーケストレーション）
+    Orchestration,
+}
+
+impl SagaMode {
+    /// 文字列からSagaModeを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "choreography" => Ok(SagaMode::Choreography),
+            "orchestration" => Ok(SagaMode::Orchestration),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効なサーガのモード: {}",
+                s
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SagaMode::Choreography => "choreography",
+            SagaMode::Orchestration => "orchestration",
+        }
+    }
+}
+
+/// オーケストレーターが指示するサーガのステップ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SagaStep {
+    /// 在庫の予約
+    ReserveInventory,
+    /// 代金の請求
+    ChargePayment,
+    /// 発送
+    Ship,
+}
+
+impl SagaStep {
+    /// ステップを表す名前
+    pub fn name(&self) -> &'static str {
+        match self {
+            SagaStep::ReserveInventory => "reserve_inventory",
+            SagaStep::ChargePayment => "charge_payment",
+            SagaStep::Ship => "ship",
+        }
+    }
+}
+
+/// ステップの失敗
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SagaStepError {
+    /// 参加者がコマンドを拒否した
+    #[error("{0}")]
+    Rejected(String),
+    /// 期限までに参加者から返信がなかった
+    #[error("No reply within {0:?}")]
+    TimedOut(Duration),
+}
+
+/// 1つの注文のサーガの結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SagaOutcome {
+    /// すべてのステップが完了した（電子書籍のみの注文は発送せずに完了）
+    Completed,
+    /// 請求まで完了し、発送は保留の解除・手動の操作を待つ
+    AwaitingShipment,
+    /// ステップが失敗したため、完了したステップを取り消して注文をキャンセルした
+    Compensated {
+        failed_step: SagaStep,
+        error: SagaStepError,
+    },
+    /// 注文が確定状態でないため、サーガを始めなかった
+    Skipped(OrderStatus),
+}
+
+/// 注文のサーガのオーケストレーター
+/// OrderConfirmedを受けて、在庫の予約 → 代金の請求 → 発送の順に各ステップへコマンドを送り、
+/// 返信をステップごとの期限まで待つ。ステップが失敗・タイムアウトした場合は、完了したステップを
+/// 逆順に取り消して注文をキャンセルする
+/// 発行するイベントはコレオグラフィと同じもの（InventoryReserved・OrderShipped・OrderCancelledなど）のため、
+/// 通知・プロジェクション・メトリクスはどちらのモードでも同じように動く
+/// 予約注文・順番待ち・仮押さえ・割当枠はInventoryReservationHandlerだけが扱うため、
+/// オーケストレーションでは在庫のある書籍の予約だけを行う
+pub struct OrderSagaOrchestrator {
+    order_repository: Arc<dyn OrderRepository>,
+    inventory_repository: Arc<dyn InventoryRepository>,
+    payment: Arc<dyn PaymentPort>,
+    event_bus: Arc<dyn EventBus>,
+    processed_events: ProcessedEventTracker,
+    step_timeout: Duration,
+    fulfillment_mode: FulfillmentMode,
+    carrier_limits: CarrierLimits,
+    delivery_estimator: DeliveryEstimator,
+    logger: Arc<dyn Logger>,
+}
+
+impl OrderSagaOrchestrator {
+    /// 新しいオーケストレーターを作成
+    ///
+    /// # Arguments
+    /// * `order_repository` - 注文リポジトリ
+    /// * `inventory_repository` - 在庫リポジトリ
+    /// * `payment` - 代金を請求・返金する決済
+    /// * `event_bus` - ステップの結果のイベントを発行するイベントバス
+    /// * `processed_event_repository` - 処理済みのOrderConfirmedを記録するリポジトリ
+    /// * `logger` - ログ出力
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        inventory_repository: Arc<dyn InventoryRepository>,
+        payment: Arc<dyn PaymentPort>,
+        event_bus: Arc<dyn EventBus>,
+        processed_event_repository: Arc<dyn ProcessedEventRepository>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            order_repository,
+            inventory_repository,
+            payment,
+            event_bus,
+            processed_events: ProcessedEventTracker::new(
+                processed_event_repository,
+                "OrderSagaOrchestrator",
+                logger.clone(),
+            ),
+            step_timeout: DEFAULT_STEP_TIMEOUT,
+            fulfillment_mode: FulfillmentMode::Auto,
+            carrier_limits: CarrierLimits::default(),
+            delivery_estimator: DeliveryEstimator::default(),
+            logger,
+        }
+    }
+
+    /// ステップの返信を待つ時間を設定する（未設定の場合は5秒）
+    pub fn with_step_timeout(mut self, step_timeout: Duration) -> Self {
+        self.step_timeout = step_timeout;
+        self
+    }
+
+    /// 発送・配達の進め方を設定する（未設定の場合はauto）
+    /// 発送を自動で進めないmanualでは、請求まで済ませて手動の発送を待つ
+    pub fn with_fulfillment_mode(mut self, fulfillment_mode: FulfillmentMode) -> Self {
+        self.fulfillment_mode = fulfillment_mode;
+        self
+    }
+
+    /// 配送業者の上限を設定する（上限を超える注文は発送のステップで失敗させる）
+    pub fn with_carrier_limits(mut self, carrier_limits: CarrierLimits) -> Self {
+        self.carrier_limits = carrier_limits;
+        self
+    }
+
+    /// 配達予定日の見積もりを設定する（発送日から配達予定日を見積もり直す）
+    pub fn with_delivery_estimator(mut self, delivery_estimator: DeliveryEstimator) -> Self {
+        self.delivery_estimator = delivery_estimator;
+        self
+    }
+
+    /// 確定した注文のサーガを最後まで進める
+    ///
+    /// # Returns
+    /// サーガの結果（ステップの失敗は補償を済ませたうえでSagaOutcome::Compensatedとして返す）
+    /// 補償そのものに失敗した場合はエラー
+    pub async fn run(&self, event: &OrderConfirmed) -> Result<SagaOutcome, HandlerError> {
+        let order = self.load_order(event.order_id).await?;
+        if !matches!(order.status(), OrderStatus::Confirmed | OrderStatus::OnHold) {
+            let mut context = HashMap::new();
+            context.insert("order_id".to_string(), event.order_id.to_string());
+            context.insert("order_status".to_string(), order.status().to_string());
+            self.logger.info(
+                "OrderSagaOrchestrator",
+                "Order is no longer confirmed, saga not started",
+                Some(event.metadata.correlation_id),
+                Some(context),
+            );
+            return Ok(SagaOutcome::Skipped(order.status()));
+        }
+
+        // 1. 在庫の予約（タイムアウトで途中まで予約した場合も取り消せるよう、保存した予約を記録する）
+        let reserved = Mutex::new(Vec::new());
+        let reservation = self
+            .send(
+                SagaStep::ReserveInventory,
+                &event.metadata,
+                self.reserve_inventory(&event.order_lines, &reserved),
+            )
+            .await;
+        let reserved = reserved
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Err(error) = reservation {
+            let failed = InventoryReservationFailed::with_correlation_id(
+                event.order_id,
+                event.order_lines.clone(),
+                error.to_string(),
+                event.metadata.event_id,
+                event.metadata.correlation_id,
+            );
+            self.publish(DomainEvent::InventoryReservationFailed(failed))
+                .await
+                .map_err(|e| e.at_step("publish_compensation_event"))?;
+            return self
+                .compensate(event, SagaStep::ReserveInventory, error, &reserved, false)
+                .await;
+        }
+
+        // 2. 代金の請求
+        if let Err(error) = self
+            .send(
+                SagaStep::ChargePayment,
+                &event.metadata,
+                self.charge_payment(event),
+            )
+            .await
+        {
+            // タイムアウトした請求は返信の前に通っている可能性があるため、返金まで行う
+            let charged = matches!(error, SagaStepError::TimedOut(_));
+            return self
+                .compensate(event, SagaStep::ChargePayment, error, &reserved, charged)
+                .await;
+        }
+
+        // 在庫の予約と請求が済んだことをコレオグラフィと同じイベントで知らせる
+        // （電子書籍のダウンロードリンクの発行はこのイベントを契機にFulfillmentRouterが行う）
+        let reserved_event = InventoryReserved::with_correlation_id(
+            event.order_id,
+            event.order_lines.clone(),
+            event.metadata.correlation_id,
+        );
+        self.publish(DomainEvent::InventoryReserved(reserved_event))
+            .await
+            .map_err(|e| e.at_step("publish_event"))?;
+
+        // 3. 発送（保留中の注文は解除時にShippingHandlerが発送する）
+        let order = self.load_order(event.order_id).await?;
+        if !order.requires_shipping() {
+            return Ok(SagaOutcome::Completed);
+        }
+        if order.status() == OrderStatus::OnHold || !self.fulfillment_mode.is_automatic() {
+            return Ok(SagaOutcome::AwaitingShipment);
+        }
+        if let Err(error) = self
+            .send(
+                SagaStep::Ship,
+                &event.metadata,
+                self.ship(order, &event.metadata),
+            )
+            .await
+        {
+            let failed = ShippingFailed::with_correlation_id(
+                event.order_id,
+                error.to_string(),
+                event.metadata.event_id,
+                event.metadata.correlation_id,
+            );
+            self.publish(DomainEvent::ShippingFailed(failed))
+                .await
+                .map_err(|e| e.at_step("publish_compensation_event"))?;
+            return self
+                .compensate(event, SagaStep::Ship, error, &reserved, true)
+                .await;
+        }
+
+        Ok(SagaOutcome::Completed)
+    }
+
+    /// ステップにコマンドを送り、期限まで返信を待つ
+    async fn send<T>(
+        &self,
+        step: SagaStep,
+        metadata: &EventMetadata,
+        command: impl Future<Output = Result<T, String>>,
+    ) -> Result<T, SagaStepError> {
+        let mut context = HashMap::new();
+        context.insert("step".to_string(), step.name().to_string());
+        self.logger.debug(
+            "OrderSagaOrchestrator",
+            "Sending saga command",
+            Some(metadata.correlation_id),
+            Some(context.clone()),
+        );
+
+        let error = match tokio::time::timeout(self.step_timeout, command).await {
+            Ok(Ok(reply)) => return Ok(reply),
+            Ok(Err(reason)) => SagaStepError::Rejected(reason),
+            Err(_) => SagaStepError::TimedOut(self.step_timeout),
+        };
+        context.insert("error".to_string(), error.to_string());
+        self.logger.warn(
+            "OrderSagaOrchestrator",
+            "Saga step failed",
+            Some(metadata.correlation_id),
+            Some(context),
+        );
+        Err(error)
+    }
+
+    /// 在庫の予約のコマンド（電子書籍は在庫を持たないため対象外）
+    async fn reserve_inventory(
+        &self,
+        order_lines: &[OrderLine],
+        reserved: &Mutex<Vec<(BookId, u32)>>,
+    ) -> Result<(), String> {
+        for order_line in order_lines.iter().filter(|line| !line.is_digital()) {
+            let mut inventory = self
+                .inventory_repository
+                .find_by_book_id(order_line.book_id())
+                .await
+                .map_err(|e| format!("在庫取得エラー: {}", e))?
+                .unwrap_or_else(|| Inventory::new(order_line.book_id(), 0));
+            inventory
+                .reserve(order_line.quantity())
+                .map_err(|e| format!("在庫不足: {}", e))?;
+            self.inventory_repository
+                .save(&inventory)
+                .await
+                .map_err(|e| format!("在庫保存エラー: {}", e))?;
+            reserved
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push((order_line.book_id(), order_line.quantity()));
+        }
+        Ok(())
+    }
+
+    /// 代金の請求のコマンド
+    async fn charge_payment(&self, event: &OrderConfirmed) -> Result<(), String> {
+        self.payment
+            .charge(event.order_id, &event.total_amount)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// 発送のコマンド（配送業者の上限を検証して発送済みにし、OrderShippedを発行する）
+    async fn ship(&self, mut order: Order, metadata: &EventMetadata) -> Result<(), String> {
+        self.carrier_limits
+            .ensure_can_ship(&order)
+            .and_then(|()| order.mark_as_shipped())
+            .map_err(|e| format!("発送処理失敗: {}", e))?;
+        order.estimate_delivery(&self.delivery_estimator);
+        let sequence_number = order.record_event();
+        self.order_repository
+            .save(&mut order)
+            .await
+            .map_err(|e| format!("注文保存エラー: {}", e))?;
+
+        let shipping_address = order
+            .shipping_address()
+            .expect("Confirmed状態の注文には配送先住所が必須です")
+            .clone();
+        let mut shipped_event = OrderShipped::with_correlation_id(
+            order.id(),
+            shipping_address,
+            metadata.correlation_id,
+        )
+        .with_recipient(order.recipient().cloned())
+        .with_estimated_delivery_date(order.estimated_delivery_date());
+        shipped_event.metadata.sequence_number = Some(sequence_number);
+        self.event_bus
+            .publish(DomainEvent::OrderShipped(shipped_event))
+            .await
+            .map_err(|e| format!("イベント発行エラー: {}", e))
+    }
+
+    /// 完了したステップを逆順に取り消し、注文をキャンセルする
+    ///
+    /// # Arguments
+    /// * `reserved` - 予約を保存した在庫（書籍と数量）
+    /// * `charged` - 請求が通っている（可能性がある）か
+    async fn compensate(
+        &self,
+        event: &OrderConfirmed,
+        failed_step: SagaStep,
+        error: SagaStepError,
+        reserved: &[(BookId, u32)],
+        charged: bool,
+    ) -> Result<SagaOutcome, HandlerError> {
+        // 返金に失敗しても在庫の解放とキャンセルは進める（返金は決済側で照合できる）
+        if charged {
+            let refund =
+                tokio::time::timeout(self.step_timeout, self.payment.refund(event.order_id)).await;
+            let refund_error = match refund {
+                Ok(Ok(())) => None,
+                Ok(Err(e)) => Some(e.to_string()),
+                Err(_) => Some(SagaStepError::TimedOut(self.step_timeout).to_string()),
+            };
+            if let Some(refund_error) = refund_error {
+                let mut context = HashMap::new();
+                context.insert("order_id".to_string(), event.order_id.to_string());
+                context.insert("error".to_string(), refund_error);
+                self.logger.error(
+                    "OrderSagaOrchestrator",
+                    "Failed to refund payment during compensation",
+                    Some(event.metadata.correlation_id),
+                    Some(context),
+                );
+            }
+        }
+
+        if !reserved.is_empty() {
+            self.release_inventory(reserved).await?;
+            let released = InventoryReleased::with_correlation_id(
+                event.order_id,
+                event.order_lines.clone(),
+                event.metadata.correlation_id,
+            );
+            self.publish(DomainEvent::InventoryReleased(released))
+                .await
+                .map_err(|e| e.at_step("publish_event"))?;
+        }
+
+        let mut order = self.load_order(event.order_id).await?;
+        order.cancel().map_err(|e| {
+            HandlerError::DomainError(format!("注文キャンセルエラー: {}", e))
+                .at_step("cancel_order")
+        })?;
+        let sequence_number = order.record_event();
+        self.order_repository.save(&mut order).await.map_err(|e| {
+            HandlerError::RepositoryError(format!("注文保存エラー: {}", e)).at_step("save_order")
+        })?;
+        let mut cancelled_event = OrderCancelled::with_correlation_id(
+            order.id(),
+            order.customer_id(),
+            order.order_lines().to_vec(),
+            event.metadata.correlation_id,
+        );
+        cancelled_event.metadata.sequence_number = Some(sequence_number);
+        self.publish(DomainEvent::OrderCancelled(cancelled_event))
+            .await
+            .map_err(|e| e.at_step("publish_event"))?;
+
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), event.order_id.to_string());
+        context.insert("failed_step".to_string(), failed_step.name().to_string());
+        context.insert("error".to_string(), error.to_string());
+        self.logger.error(
+            "OrderSagaOrchestrator",
+            "Saga compensated and order cancelled",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+
+        Ok(SagaOutcome::Compensated { failed_step, error })
+    }
+
+    /// 予約した在庫を解放する
+    async fn release_inventory(&self, reserved: &[(BookId, u32)]) -> Result<(), HandlerError> {
+        for &(book_id, quantity) in reserved {
+            let Some(mut inventory) = self
+                .inventory_repository
+                .find_by_book_id(book_id)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫取得エラー: {}", e))
+                        .at_step("load_inventory")
+                })?
+            else {
+                continue;
+            };
+            inventory.release(quantity).map_err(|e| {
+                HandlerError::DomainError(format!("在庫解放エラー: {}", e))
+                    .at_step("release_inventory")
+            })?;
+            self.inventory_repository
+                .save(&inventory)
+                .await
+                .map_err(|e| {
+                    HandlerError::RepositoryError(format!("在庫保存エラー: {}", e))
+                        .at_step("save_inventory")
+                })?;
+        }
+        Ok(())
+    }
+
+    async fn load_order(&self, order_id: OrderId) -> Result<Order, HandlerError> {
+        self.order_repository
+            .find_by_id(order_id)
+            .await
+            .map_err(|e| {
+                HandlerError::RepositoryError(format!("注文取得エラー: {}", e))
+                    .at_step("load_order")
+            })?
+            .ok_or_else(|| {
+                HandlerError::ProcessingFailed(format!("注文が見つかりません: {:?}", order_id))
+                    .at_step("load_order")
+            })
+    }
+
+    async fn publish(&self, event: DomainEvent) -> Result<(), HandlerError> {
+        self.event_bus
+            .publish(event)
+            .await
+            .map_err(|e| HandlerError::ProcessingFailed(format!("イベント発行エラー: {}", e)))
+    }
+}
+
+#[async_trait]
+impl EventHandler<OrderConfirmed> for OrderSagaOrchestrator {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        // 冪等性チェック: 再配信されたOrderConfirmedでステップを二重に指示しない
+        if self
+            .processed_events
+            .is_processed(event.metadata.event_id)
+            .await?
+        {
+            return Ok(());
+        }
+
+        let start_time = std::time::Instant::now();
+        let outcome = self.run(&event).await?;
+        self.processed_events
+            .mark_processed(event.metadata.event_id)
+            .await;
+
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), event.order_id.to_string());
+        context.insert("outcome".to_string(), format!("{:?}", outcome));
+        context.insert(
+            "execution_time_ms".to_string(),
+            start_time.elapsed().as_millis().to_string(),
+        );
+        self.logger.info(
+            "OrderSagaOrchestrator",
+            "Order saga finished",
+            Some(event.metadata.correlation_id),
+            Some(context),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saga_mode_round_trips_and_defaults_to_choreography() {
+        for mode in [SagaMode::Choreography, SagaMode::Orchestration] {
+            assert_eq!(SagaMode::from_string(mode.as_str()).unwrap(), mode);
+        }
+        assert!(SagaMode::from_string("orchestrated").is_err());
+        assert_eq!(SagaMode::default(), SagaMode::Choreography);
+    }
+}
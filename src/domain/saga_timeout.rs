@@ -0,0 +1,326 @@
+use crate::domain::event::{DomainEvent, SagaTimedOut};
+use crate::domain::handler::SagaCompensationCoordinator;
+use crate::domain::model::{CustomerId, Order, OrderId, OrderStatus};
+use crate::domain::port::{EventBus, EventJournal, Logger, OrderRepository, RepositoryError};
+use crate::domain::task_supervisor::SupervisedTask;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// 期限内に進まなかったサーガのステップ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SagaTimeoutStage {
+    /// 確定（Confirmed）したまま在庫予約（InventoryReserved）が記録されない
+    InventoryReservation,
+    /// 発送（Shipped）したまま配達完了にならない
+    Delivery,
+}
+
+impl SagaTimeoutStage {
+    /// ステップを表す名前（SagaCompensationCoordinatorの失敗したステップの名前と同じ）
+    pub fn name(&self) -> &'static str {
+        match self {
+            SagaTimeoutStage::InventoryReservation => "inventory_reservation",
+            SagaTimeoutStage::Delivery => "delivery",
+        }
+    }
+}
+
+/// サーガのタイムアウトの設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SagaTimeoutPolicy {
+    /// 確定から在庫予約までの期限
+    pub reserve_within: TimeDelta,
+    /// 発送から配達完了までの期限
+    pub deliver_within: TimeDelta,
+}
+
+impl Default for SagaTimeoutPolicy {
+    fn default() -> Self {
+        Self {
+            reserve_within: TimeDelta::minutes(30),
+            deliver_within: TimeDelta::days(7),
+        }
+    }
+}
+
+/// 期限を超過したサーガのステップ
+#[derive(Debug, Clone, PartialEq)]
+pub struct SagaTimeout {
+    pub order_id: OrderId,
+    pub customer_id: CustomerId,
+    pub stage: SagaTimeoutStage,
+    /// ステップの開始日時（確定日時または発送日時）
+    pub started_at: DateTime<Utc>,
+    /// 期限
+    pub deadline: DateTime<Utc>,
+}
+
+impl SagaTimeoutPolicy {
+    /// 注文の状態から、指定日時時点で期限を超過しているステップを返す
+    /// 在庫予約は記録されているかをイベントジャーナルで確かめる必要があるため、ここでは確定からの経過時間だけで判定する
+    pub fn overdue(&self, order: &Order, now: DateTime<Utc>) -> Option<SagaTimeout> {
+        let (stage, started_at, limit) = match order.status() {
+            OrderStatus::Confirmed => (
+                SagaTimeoutStage::InventoryReservation,
+                order.confirmed_at()?,
+                self.reserve_within,
+            ),
+            OrderStatus::Shipped => (
+                SagaTimeoutStage::Delivery,
+                order.shipped_at()?,
+                self.deliver_within,
+            ),
+            _ => return None,
+        };
+        let deadline = started_at + limit;
+        (deadline <= now).then(|| SagaTimeout {
+            order_id: order.id(),
+            customer_id: order.customer_id(),
+            stage,
+            started_at,
+            deadline,
+        })
+    }
+}
+
+/// サーガのタイムアウト監視タスク
+/// 確定したまま在庫予約が記録されない注文と、発送したまま期限を過ぎても配達完了にならない注文を
+/// 定期的に検出し、注文・ステップごとにSagaTimedOutイベントを1回だけ発行して補償を開始する
+/// 発行済みのタイムアウトはメモリ上で管理するため、プロセス再起動後は停滞したままの注文が再度発行される
+pub struct SagaTimeoutMonitor {
+    order_repository: Arc<dyn OrderRepository>,
+    event_journal: Arc<dyn EventJournal>,
+    event_bus: Arc<dyn EventBus>,
+    coordinator: SagaCompensationCoordinator,
+    logger: Arc<dyn Logger>,
+    policy: SagaTimeoutPolicy,
+    reported: Mutex<HashSet<(OrderId, SagaTimeoutStage)>>,
+}
+
+impl SagaTimeoutMonitor {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_journal: Arc<dyn EventJournal>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+        policy: SagaTimeoutPolicy,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_journal,
+            coordinator: SagaCompensationCoordinator::new(event_bus.clone(), logger.clone()),
+            event_bus,
+            logger,
+            policy,
+            reported: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 監視タスクをバックグラウンドで起動
+    pub fn spawn(self, check_interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(check_interval);
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// 指定日時時点で期限を超過しているステップを検出し、イベントの発行と補償の開始を行う
+    ///
+    /// # Returns
+    /// * 新たにタイムアウトとして発行したステップのリスト
+    pub async fn run(&self, now: DateTime<Utc>) -> Result<Vec<SagaTimeout>, RepositoryError> {
+        let mut orders = self
+            .order_repository
+            .find_by_status(OrderStatus::Confirmed)
+            .await?;
+        orders.extend(
+            self.order_repository
+                .find_by_status(OrderStatus::Shipped)
+                .await?,
+        );
+
+        let mut timed_out = Vec::new();
+        for order in &orders {
+            let Some(timeout) = self.policy.overdue(order, now) else {
+                continue;
+            };
+            let key = (timeout.order_id, timeout.stage);
+            if self.reported.lock().await.contains(&key) {
+                continue;
+            }
+
+            // 在庫予約済みのまま発送を待っている注文（manualなど）は停滞していない
+            if timeout.stage == SagaTimeoutStage::InventoryReservation {
+                let journaled = self
+                    .event_journal
+                    .find_by_aggregate(&order.id().to_string())
+                    .await?;
+                if journaled
+                    .iter()
+                    .any(|event| event.event_type == "InventoryReserved")
+                {
+                    continue;
+                }
+            }
+
+            let saga_id = order.saga_correlation_id().unwrap_or_else(Uuid::new_v4);
+            if self.report_timeout(&timeout, saga_id, now).await {
+                self.reported.lock().await.insert(key);
+                timed_out.push(timeout);
+            }
+        }
+
+        Ok(timed_out)
+    }
+
+    /// SagaTimedOutイベントを発行し、補償を開始する
+    /// イベントの発行に失敗した場合は次回の実行で再試行する
+    async fn report_timeout(
+        &self,
+        timeout: &SagaTimeout,
+        saga_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let event = SagaTimedOut::new(
+            saga_id,
+            timeout.order_id,
+            timeout.customer_id,
+            timeout.stage,
+            timeout.started_at,
+            timeout.deadline,
+            now,
+        );
+
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), timeout.order_id.to_string());
+        context.insert("stage".to_string(), timeout.stage.name().to_string());
+
+        if let Err(e) = self
+            .event_bus
+            .publish(DomainEvent::SagaTimedOut(event))
+            .await
+        {
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "SagaTimeoutMonitor",
+                "Failed to publish SagaTimedOut",
+                Some(saga_id),
+                Some(context),
+            );
+            return false;
+        }
+
+        let failure_reason = format!(
+            "{}が期限（{}）までに完了しませんでした",
+            timeout.stage.name(),
+            timeout.deadline.to_rfc3339()
+        );
+        if let Err(e) = self
+            .coordinator
+            .start_compensation(saga_id, timeout.stage.name().to_string(), failure_reason)
+            .await
+        {
+            let mut context = context.clone();
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "SagaTimeoutMonitor",
+                "Failed to start compensation for timed out saga",
+                Some(saga_id),
+                Some(context),
+            );
+        }
+
+        self.logger.warn(
+            "SagaTimeoutMonitor",
+            "Saga timed out",
+            Some(saga_id),
+            Some(context),
+        );
+        true
+    }
+}
+
+#[async_trait]
+impl SupervisedTask for SagaTimeoutMonitor {
+    async fn run_once(&self) {
+        if let Err(e) = self.run(Utc::now()).await {
+            let mut context = HashMap::new();
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "SagaTimeoutMonitor",
+                "Failed to load orders for saga timeout check",
+                None,
+                Some(context),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, Money, ShippingAddress};
+
+    fn confirmed_order(confirmed_at: DateTime<Utc>, shipped_at: Option<DateTime<Utc>>) -> Order {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1234567".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "道玄坂1-1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        order.confirm().unwrap();
+        if shipped_at.is_some() {
+            order.mark_as_shipped().unwrap();
+        }
+        order.with_transition_times(Some(confirmed_at), shipped_at, None)
+    }
+
+    #[test]
+    fn test_overdue_detects_stalled_reservation_and_delivery() {
+        let policy = SagaTimeoutPolicy::default();
+        let now = Utc::now();
+
+        let stalled = confirmed_order(now - TimeDelta::minutes(30), None);
+        let timeout = policy.overdue(&stalled, now).unwrap();
+        assert_eq!(timeout.stage, SagaTimeoutStage::InventoryReservation);
+        assert_eq!(timeout.deadline, now);
+        assert!(policy
+            .overdue(&confirmed_order(now - TimeDelta::minutes(29), None), now)
+            .is_none());
+
+        let shipped_at = now - TimeDelta::days(8);
+        let undelivered = confirmed_order(shipped_at - TimeDelta::hours(1), Some(shipped_at));
+        let timeout = policy.overdue(&undelivered, now).unwrap();
+        assert_eq!(timeout.stage, SagaTimeoutStage::Delivery);
+        assert_eq!(timeout.started_at, shipped_at);
+        assert!(policy
+            .overdue(
+                &confirmed_order(now - TimeDelta::days(3), Some(now - TimeDelta::days(2))),
+                now
+            )
+            .is_none());
+
+        let mut cancelled = confirmed_order(now - TimeDelta::days(1), None);
+        cancelled.cancel().unwrap();
+        assert!(policy.overdue(&cancelled, now).is_none());
+    }
+}
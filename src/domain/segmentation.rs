@@ -0,0 +1,75 @@
+use crate::domain::model::Money;
+
+/// リピーターとみなす配達完了した注文の数のデフォルト値
+pub const DEFAULT_REPEAT_BUYER_ORDER_COUNT: u32 = 2;
+
+/// 高額注文とみなす合計金額（円）のデフォルト値
+pub const DEFAULT_HIGH_VALUE_ORDER_AMOUNT: i64 = 10_000;
+
+/// 顧客セグメントのルール
+/// マーケティング向けの派生イベント（HighValueOrderPlaced・CustomerBecameRepeatBuyer）を発行する条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentationRules {
+    /// リピーターとみなす配達完了した注文の数（Noneの場合はリピーター化を判定しない）
+    pub repeat_buyer_order_count: Option<u32>,
+    /// 高額注文とみなす合計金額（Noneの場合は高額注文を判定しない）
+    pub high_value_order_amount: Option<Money>,
+}
+
+impl Default for SegmentationRules {
+    fn default() -> Self {
+        Self {
+            repeat_buyer_order_count: Some(DEFAULT_REPEAT_BUYER_ORDER_COUNT),
+            high_value_order_amount: Some(Money::jpy(DEFAULT_HIGH_VALUE_ORDER_AMOUNT)),
+        }
+    }
+}
+
+impl SegmentationRules {
+    /// 確定した注文が高額注文か
+    ///
+    /// # Returns
+    /// * `Some(Money)` - 高額注文の場合、閾値の金額
+    /// * `None` - 高額注文ではない、または判定しない場合
+    pub fn high_value_threshold(&self, total_amount: &Money) -> Option<Money> {
+        self.high_value_order_amount.filter(|threshold| {
+            threshold.currency() == total_amount.currency()
+                && total_amount.amount() >= threshold.amount()
+        })
+    }
+
+    /// 注文の配達完了で顧客がリピーターになったか
+    /// 配達完了した注文の数がちょうど閾値に達したときだけtrueを返し、リピーター化を1回だけ判定する
+    ///
+    /// # Arguments
+    /// * `delivered_order_count` - 今回の注文を含む、顧客の配達完了した注文の数
+    pub fn becomes_repeat_buyer(&self, delivered_order_count: u32) -> bool {
+        self.repeat_buyer_order_count == Some(delivered_order_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_detect_high_value_orders_and_repeat_buyers_once() {
+        let rules = SegmentationRules::default();
+        assert_eq!(
+            rules.high_value_threshold(&Money::jpy(10_000)),
+            Some(Money::jpy(10_000))
+        );
+        assert_eq!(rules.high_value_threshold(&Money::jpy(9_999)), None);
+
+        assert!(!rules.becomes_repeat_buyer(1));
+        assert!(rules.becomes_repeat_buyer(2));
+        assert!(!rules.becomes_repeat_buyer(3));
+
+        let disabled = SegmentationRules {
+            repeat_buyer_order_count: None,
+            high_value_order_amount: None,
+        };
+        assert_eq!(disabled.high_value_threshold(&Money::jpy(100_000)), None);
+        assert!(!disabled.becomes_repeat_buyer(2));
+    }
+}
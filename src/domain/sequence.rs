@@ -0,0 +1,202 @@
+use std::collections::{BTreeMap, HashMap};
+
+/// イベント連番の到着状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceCheck {
+    /// 次に適用すべき連番が届いた
+    InOrder,
+    /// 連番が飛んでいる（先行するイベントが未着のため保留した）
+    Gap { expected: u64, received: u64 },
+    /// 適用済みの連番が再び届いた（重複配信または順序の入れ替わり）
+    Stale { expected: u64, received: u64 },
+}
+
+/// 集約ごとの連番の進み具合
+#[derive(Debug)]
+struct AggregateSequence<T> {
+    /// 次に適用すべき連番
+    next_expected: u64,
+    /// 連番の順に適用を待っているイベント
+    pending: BTreeMap<u64, T>,
+}
+
+/// 集約ごとのイベント連番に従って適用順を揃えるバッファ
+/// 順序どおりに届いたイベントはすぐに適用でき、先に届いたイベントは先行するイベントが揃うまで保留する
+///
+/// 進み具合はメモリ上に保持するため、初めて見る集約は届いた連番から追跡を始める
+/// （再起動前に適用済みのイベントを待ち続けないようにするため）。
+/// 永続化したチェックポイントがある集約はresume_fromでその続きから追跡する
+#[derive(Debug)]
+pub struct SequencedBuffer<T> {
+    aggregates: HashMap<String, AggregateSequence<T>>,
+}
+
+impl<T> Default for SequencedBuffer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SequencedBuffer<T> {
+    pub fn new() -> Self {
+        Self {
+            aggregates: HashMap::new(),
+        }
+    }
+
+    /// 届いたイベントを受け付け、連番の到着状況を判定する
+    /// 適用済みの連番のイベントは保留せずに破棄する
+    pub fn accept(&mut self, aggregate_id: &str, sequence_number: u64, item: T) -> SequenceCheck {
+        let aggregate = self
+            .aggregates
+            .entry(aggregate_id.to_string())
+            .or_insert_with(|| AggregateSequence {
+                next_expected: sequence_number,
+                pending: BTreeMap::new(),
+            });
+
+        let expected = aggregate.next_expected;
+        if sequence_number < expected {
+            return SequenceCheck::Stale {
+                expected,
+                received: sequence_number,
+            };
+        }
+
+        aggregate.pending.insert(sequence_number, item);
+        if sequence_number == expected {
+            SequenceCheck::InOrder
+        } else {
+            SequenceCheck::Gap {
+                expected,
+                received: sequence_number,
+            }
+        }
+    }
+
+    /// 次に適用できるイベントを取得する（先行するイベントが未着の場合はNone）
+    pub fn next_ready(&self, aggregate_id: &str) -> Option<&T> {
+        let aggregate = self.aggregates.get(aggregate_id)?;
+        aggregate.pending.get(&aggregate.next_expected)
+    }
+
+    /// next_readyで取得したイベントを適用済みにし、次の連番に進める
+    pub fn advance(&mut self, aggregate_id: &str) {
+        if let Some(aggregate) = self.aggregates.get_mut(aggregate_id) {
+            if aggregate.pending.remove(&aggregate.next_expected).is_some() {
+                aggregate.next_expected += 1;
+            }
+        }
+    }
+
+    /// 適用済みの連番（チェックポイント）の続きから集約の追跡を再開する
+    /// チェックポイント以下の連番で保留中のイベントは破棄する
+    pub fn resume_from(&mut self, aggregate_id: &str, last_applied: u64) {
+        let aggregate = self
+            .aggregates
+            .entry(aggregate_id.to_string())
+            .or_insert_with(|| AggregateSequence {
+                next_expected: last_applied + 1,
+                pending: BTreeMap::new(),
+            });
+        aggregate.next_expected = aggregate.next_expected.max(last_applied + 1);
+        aggregate
+            .pending
+            .retain(|&sequence_number, _| sequence_number > last_applied);
+    }
+
+    /// 集約で保留中のイベント数を取得
+    pub fn pending_count(&self, aggregate_id: &str) -> usize {
+        self.aggregates
+            .get(aggregate_id)
+            .map_or(0, |aggregate| aggregate.pending.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(buffer: &mut SequencedBuffer<&'static str>, aggregate_id: &str) -> Vec<&'static str> {
+        let mut applied = Vec::new();
+        while let Some(&item) = buffer.next_ready(aggregate_id) {
+            applied.push(item);
+            buffer.advance(aggregate_id);
+        }
+        applied
+    }
+
+    #[test]
+    fn test_out_of_order_events_are_applied_in_sequence() {
+        let mut buffer = SequencedBuffer::new();
+
+        assert_eq!(
+            buffer.accept("order-1", 1, "confirmed"),
+            SequenceCheck::InOrder
+        );
+        assert_eq!(drain(&mut buffer, "order-1"), vec!["confirmed"]);
+
+        // 3が先に届いた場合は2が揃うまで保留する
+        assert_eq!(
+            buffer.accept("order-1", 3, "delivered"),
+            SequenceCheck::Gap {
+                expected: 2,
+                received: 3
+            }
+        );
+        assert!(drain(&mut buffer, "order-1").is_empty());
+        assert_eq!(buffer.pending_count("order-1"), 1);
+
+        assert_eq!(
+            buffer.accept("order-1", 2, "shipped"),
+            SequenceCheck::InOrder
+        );
+        assert_eq!(drain(&mut buffer, "order-1"), vec!["shipped", "delivered"]);
+
+        // 適用済みの連番は破棄する
+        assert_eq!(
+            buffer.accept("order-1", 2, "shipped"),
+            SequenceCheck::Stale {
+                expected: 4,
+                received: 2
+            }
+        );
+        assert_eq!(buffer.pending_count("order-1"), 0);
+    }
+
+    #[test]
+    fn test_unknown_aggregate_starts_from_received_sequence() {
+        let mut buffer = SequencedBuffer::new();
+
+        assert_eq!(
+            buffer.accept("order-2", 5, "shipped"),
+            SequenceCheck::InOrder
+        );
+        // 適用に失敗した場合は進めずに残しておき、再配信時にもう一度適用する
+        assert_eq!(
+            buffer.accept("order-2", 5, "shipped"),
+            SequenceCheck::InOrder
+        );
+        assert_eq!(drain(&mut buffer, "order-2"), vec!["shipped"]);
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint_skips_applied_sequences() {
+        let mut buffer = SequencedBuffer::new();
+        buffer.resume_from("order-3", 2);
+
+        // チェックポイント以下の再配信は適用済みとして破棄する
+        assert_eq!(
+            buffer.accept("order-3", 2, "shipped"),
+            SequenceCheck::Stale {
+                expected: 3,
+                received: 2
+            }
+        );
+        assert_eq!(
+            buffer.accept("order-3", 3, "delivered"),
+            SequenceCheck::InOrder
+        );
+        assert_eq!(drain(&mut buffer, "order-3"), vec!["delivered"]);
+    }
+}
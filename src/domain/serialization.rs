@@ -256,15 +256,15 @@ impl EventSerializer {
                     });
                 }
             }
-            DomainEvent::InventoryReserved(inventory_reserved) => {
-                if inventory_reserved.order_lines.is_empty() {
-                    return Err(SerializationError::InvalidFieldValue {
-                        field_name: "order_lines".to_string(),
-                        field_value: "empty array".to_string(),
-                        event_type: event_type.to_string(),
-                        reason: "Order lines cannot be empty for inventory reservation".to_string(),
-                    });
-                }
+            DomainEvent::InventoryReserved(inventory_reserved)
+                if inventory_reserved.order_lines.is_empty() =>
+            {
+                return Err(SerializationError::InvalidFieldValue {
+                    field_name: "order_lines".to_string(),
+                    field_value: "empty array".to_string(),
+                    event_type: event_type.to_string(),
+                    reason: "Order lines cannot be empty for inventory reservation".to_string(),
+                });
             }
             // 他のイベントタイプの検証も必要に応じて追加
             _ => {}
@@ -0,0 +1,363 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::{Money, Order, ShippingAddress};
+use crate::domain::warning::DomainWarning;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 重量帯ごとの配送料
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeightRate {
+    /// この重量帯の上限（グラム、上限を含む）
+    pub max_weight_grams: u32,
+    /// 配送料（円）
+    pub fee: i64,
+}
+
+/// 配送料の料金表
+/// 重量帯ごとの基本料金と、配送先の都道府県ごとの追加料金
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShippingRateTable {
+    /// 重量帯ごとの基本料金（上限の昇順）
+    weight_rates: Vec<WeightRate>,
+    /// 都道府県ごとの追加料金（例: "北海道" => 300）
+    prefecture_surcharges: HashMap<String, i64>,
+}
+
+impl Default for ShippingRateTable {
+    fn default() -> Self {
+        Self {
+            weight_rates: vec![
+                WeightRate {
+                    max_weight_grams: 2_000,
+                    fee: 500,
+                },
+                WeightRate {
+                    max_weight_grams: 5_000,
+                    fee: 800,
+                },
+                WeightRate {
+                    max_weight_grams: 10_000,
+                    fee: 1_200,
+                },
+                WeightRate {
+                    max_weight_grams: 25_000,
+                    fee: 1_800,
+                },
+            ],
+            prefecture_surcharges: HashMap::from([
+                ("北海道".to_string(), 300),
+                ("沖縄県".to_string(), 700),
+            ]),
+        }
+    }
+}
+
+impl ShippingRateTable {
+    /// 料金表を作成
+    /// 重量帯は1つ以上必要で、上限の昇順に並べ替える
+    pub fn new(
+        mut weight_rates: Vec<WeightRate>,
+        prefecture_surcharges: HashMap<String, i64>,
+    ) -> Result<Self, DomainError> {
+        if weight_rates.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "配送料の重量帯が設定されていません".to_string(),
+            ));
+        }
+        if weight_rates.iter().any(|rate| rate.fee < 0)
+            || prefecture_surcharges
+                .values()
+                .any(|&surcharge| surcharge < 0)
+        {
+            return Err(DomainError::InvalidValue(
+                "配送料に負の金額は設定できません".to_string(),
+            ));
+        }
+        weight_rates.sort_by_key(|rate| rate.max_weight_grams);
+
+        Ok(Self {
+            weight_rates,
+            prefecture_surcharges,
+        })
+    }
+
+    /// 重量帯ごとの基本料金を取得
+    pub fn weight_rates(&self) -> &[WeightRate] {
+        &self.weight_rates
+    }
+
+    /// 都道府県ごとの追加料金を取得
+    pub fn prefecture_surcharges(&self) -> &HashMap<String, i64> {
+        &self.prefecture_surcharges
+    }
+
+    /// 総重量に対応する基本料金
+    /// 最大の重量帯を超える場合は最大の重量帯の料金を適用する（超過は発送時に検証する）
+    pub fn base_fee(&self, total_weight_grams: u32) -> i64 {
+        self.weight_rates
+            .iter()
+            .find(|rate| total_weight_grams <= rate.max_weight_grams)
+            .or(self.weight_rates.last())
+            .map_or(0, |rate| rate.fee)
+    }
+
+    /// 配送先の都道府県の追加料金（設定がない都道府県は0円）
+    pub fn prefecture_surcharge(&self, prefecture: &str) -> i64 {
+        self.prefecture_surcharges
+            .get(prefecture)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// 配送業者が引き受けられる荷物の上限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CarrierLimits {
+    /// 1梱包の最大重量（グラム）
+    pub max_weight_grams: u32,
+    /// 1梱包の最大サイズ（縦・横・高さの合計、センチメートル）
+    pub max_size_cm: u32,
+}
+
+impl Default for CarrierLimits {
+    fn default() -> Self {
+        Self {
+            max_weight_grams: 25_000,
+            max_size_cm: 160,
+        }
+    }
+}
+
+impl CarrierLimits {
+    /// 注文を1梱包で発送できるか判定
+    /// 梱包サイズは書籍を平積みにした場合（最大の幅 + 最大の高さ + 厚さの合計）で見積もる
+    /// 重量・寸法が未登録の書籍は0として扱う
+    pub fn ensure_can_ship(&self, order: &Order) -> Result<(), DomainError> {
+        let total_weight_grams = order.total_weight_grams();
+        if total_weight_grams > self.max_weight_grams {
+            return Err(DomainError::CarrierLimitExceeded(format!(
+                "総重量{}gが配送業者の上限（{}g）を超えています",
+                total_weight_grams, self.max_weight_grams
+            )));
+        }
+
+        let package_size_cm = Self::package_size_mm(order).div_ceil(10);
+        if package_size_cm > self.max_size_cm {
+            return Err(DomainError::CarrierLimitExceeded(format!(
+                "梱包サイズ{}cmが配送業者の上限（{}cm）を超えています",
+                package_size_cm, self.max_size_cm
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// 梱包サイズ（縦・横・高さの合計、ミリメートル）
+    fn package_size_mm(order: &Order) -> u32 {
+        let specs = order
+            .order_lines()
+            .iter()
+            .filter(|line| !line.is_digital())
+            .filter_map(|line| line.spec().map(|spec| (spec, line.quantity())));
+
+        let (width, height, thickness) =
+            specs.fold((0, 0, 0), |(width, height, thickness), (spec, quantity)| {
+                (
+                    width.max(spec.width_mm()),
+                    height.max(spec.height_mm()),
+                    thickness + spec.thickness_mm() * quantity,
+                )
+            });
+        width + height + thickness
+    }
+}
+
+/// 配送料の内訳
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShippingFeeBreakdown {
+    /// 発送する書籍の総重量（グラム）
+    pub total_weight_grams: u32,
+    /// 重量帯の基本料金（円）
+    pub base_fee: i64,
+    /// 配送先の都道府県の追加料金（円）
+    pub prefecture_surcharge: i64,
+    /// 送料無料の対象かどうか
+    pub free_shipping: bool,
+    /// 請求する配送料（円）
+    pub fee: i64,
+}
+
+/// 配送料ポリシー（ドメインサービス）
+/// 総重量と配送先の都道府県から料金表で配送料を算出する
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShippingFeePolicy {
+    rate_table: ShippingRateTable,
+    /// 送料無料になる小計の下限（円）
+    free_shipping_threshold: i64,
+    carrier_limits: CarrierLimits,
+}
+
+impl Default for ShippingFeePolicy {
+    fn default() -> Self {
+        Self {
+            rate_table: ShippingRateTable::default(),
+            free_shipping_threshold: 10_000,
+            carrier_limits: CarrierLimits::default(),
+        }
+    }
+}
+
+impl ShippingFeePolicy {
+    /// 料金表・送料無料の下限・配送業者の上限を指定して配送料ポリシーを作成
+    pub fn new(
+        rate_table: ShippingRateTable,
+        free_shipping_threshold: i64,
+        carrier_limits: CarrierLimits,
+    ) -> Self {
+        Self {
+            rate_table,
+            free_shipping_threshold,
+            carrier_limits,
+        }
+    }
+
+    /// 料金表を取得
+    pub fn rate_table(&self) -> &ShippingRateTable {
+        &self.rate_table
+    }
+
+    /// 送料無料になる小計の下限を取得
+    pub fn free_shipping_threshold(&self) -> i64 {
+        self.free_shipping_threshold
+    }
+
+    /// 配送業者の上限を取得
+    pub fn carrier_limits(&self) -> CarrierLimits {
+        self.carrier_limits
+    }
+
+    /// 注文の配送料と内訳を算出
+    /// - 電子書籍のみの注文は発送しないため0円
+    /// - 小計が送料無料の下限以上の場合は追加料金を含めて0円
+    /// - 配送先住所が未設定の場合は追加料金なしで見積もる
+    pub fn quote(&self, order: &Order) -> ShippingFeeBreakdown {
+        let total_weight_grams = order.total_weight_grams();
+        if !order.requires_shipping() {
+            return ShippingFeeBreakdown {
+                total_weight_grams,
+                base_fee: 0,
+                prefecture_surcharge: 0,
+                free_shipping: false,
+                fee: 0,
+            };
+        }
+
+        let base_fee = self.rate_table.base_fee(total_weight_grams);
+        let prefecture_surcharge = order.shipping_address().map_or(0, |address| {
+            self.rate_table.prefecture_surcharge(address.prefecture())
+        });
+        let free_shipping = order.subtotal().amount() >= self.free_shipping_threshold;
+        let fee = if free_shipping {
+            0
+        } else {
+            base_fee + prefecture_surcharge
+        };
+
+        ShippingFeeBreakdown {
+            total_weight_grams,
+            base_fee,
+            prefecture_surcharge,
+            free_shipping,
+            fee,
+        }
+    }
+
+    /// 配送先が追加料金のかかる地域の場合の警告
+    pub fn destination_warning(&self, address: &ShippingAddress) -> Option<DomainWarning> {
+        let surcharge = self.rate_table.prefecture_surcharge(address.prefecture());
+        (surcharge > 0).then(|| {
+            DomainWarning::remote_shipping_destination(address.prefecture(), Money::jpy(surcharge))
+        })
+    }
+
+    /// 注文の配送料を算出
+    pub fn shipping_fee(&self, order: &Order) -> Money {
+        Money::jpy(self.quote(order).fee)
+    }
+
+    /// 注文の合計金額を算出（小計 + 配送料）
+    pub fn total(&self, order: &Order) -> Money {
+        let subtotal = order.subtotal();
+        subtotal.add(&self.shipping_fee(order)).unwrap_or(subtotal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, BookSpec, CustomerId, OrderId, ShippingAddress};
+
+    fn order_to(prefecture: &str, unit_price: i64, spec: BookSpec, quantity: u32) -> Order {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order
+            .add_book_with_spec(BookId::new(), quantity, Money::jpy(unit_price), spec)
+            .unwrap();
+        let address = ShippingAddress::new(
+            "1234567".to_string(),
+            prefecture.to_string(),
+            "札幌市".to_string(),
+            "北1条西1-1".to_string(),
+            None,
+        )
+        .unwrap();
+        order.set_shipping_address(address).unwrap();
+        order
+    }
+
+    #[test]
+    fn test_fee_by_weight_and_prefecture() {
+        let policy = ShippingFeePolicy::default();
+        // 600g × 5冊 = 3kg → 800円 + 北海道300円
+        let spec = BookSpec::new(600, 182, 257, 30).unwrap();
+        let order = order_to("北海道", 1000, spec, 5);
+
+        let breakdown = policy.quote(&order);
+        assert_eq!(breakdown.total_weight_grams, 3000);
+        assert_eq!(breakdown.base_fee, 800);
+        assert_eq!(breakdown.prefecture_surcharge, 300);
+        assert_eq!(breakdown.fee, 1100);
+        assert_eq!(policy.total(&order).amount(), 6100);
+
+        // 送料無料の対象は追加料金も含めて0円
+        let order = order_to("北海道", 2000, spec, 5);
+        let breakdown = policy.quote(&order);
+        assert!(breakdown.free_shipping);
+        assert_eq!(breakdown.fee, 0);
+    }
+
+    #[test]
+    fn test_carrier_limits() {
+        let limits = CarrierLimits {
+            max_weight_grams: 5_000,
+            max_size_cm: 60,
+        };
+        // 幅18.2cm + 高さ25.7cm + 厚さ3cm × 5冊 = 58.9cm → 59cm
+        let spec = BookSpec::new(600, 182, 257, 30).unwrap();
+        assert!(limits
+            .ensure_can_ship(&order_to("東京都", 1000, spec, 5))
+            .is_ok());
+
+        // 6冊では梱包サイズが上限を超える
+        assert!(matches!(
+            limits.ensure_can_ship(&order_to("東京都", 1000, spec, 6)),
+            Err(DomainError::CarrierLimitExceeded(_))
+        ));
+
+        // 重量が上限を超える
+        let heavy = BookSpec::new(2_600, 182, 257, 10).unwrap();
+        assert!(matches!(
+            limits.ensure_can_ship(&order_to("東京都", 1000, heavy, 2)),
+            Err(DomainError::CarrierLimitExceeded(_))
+        ));
+    }
+}
@@ -0,0 +1,462 @@
+use crate::domain::event::{DomainEvent, FulfillmentSlaBreached};
+use crate::domain::model::{CustomerId, Order, OrderId, OrderStatus};
+use crate::domain::port::{EventBus, Logger, OrderRepository, RepositoryError};
+use crate::domain::task_supervisor::SupervisedTask;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+/// フルフィルメントSLAの対象区間
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SlaStage {
+    /// 確定（Confirmed）から発送（Shipped）まで
+    Shipping,
+    /// 発送（Shipped）から配達完了（Delivered）まで
+    Delivery,
+}
+
+impl SlaStage {
+    /// 区間を表す名前
+    pub fn name(&self) -> &'static str {
+        match self {
+            SlaStage::Shipping => "shipping",
+            SlaStage::Delivery => "delivery",
+        }
+    }
+}
+
+/// フルフィルメントSLAの設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlaPolicy {
+    /// 確定から発送までの期限
+    pub ship_within: TimeDelta,
+    /// 発送から配達完了までの期限
+    pub deliver_within: TimeDelta,
+    /// 期限までの残り時間がこの値以下の未完了の注文を「違反の恐れあり」とみなす
+    pub at_risk_window: TimeDelta,
+}
+
+impl Default for SlaPolicy {
+    fn default() -> Self {
+        Self {
+            ship_within: TimeDelta::hours(48),
+            deliver_within: TimeDelta::hours(72),
+            at_risk_window: TimeDelta::hours(12),
+        }
+    }
+}
+
+/// 注文1件・1区間のSLA判定
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlaCheck {
+    pub order_id: OrderId,
+    pub customer_id: CustomerId,
+    pub stage: SlaStage,
+    /// 区間の開始日時
+    pub started_at: DateTime<Utc>,
+    /// 期限
+    pub deadline: DateTime<Utc>,
+    /// 区間の完了日時（未完了の場合はNone）
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl SlaCheck {
+    /// 区間が未完了かどうか
+    pub fn is_open(&self) -> bool {
+        self.completed_at.is_none()
+    }
+
+    /// 期限を超過しているかどうか（完了済みの場合は完了日時で判定）
+    pub fn is_breached(&self, now: DateTime<Utc>) -> bool {
+        self.completed_at.unwrap_or(now) > self.deadline
+    }
+
+    /// 期限までの残り時間
+    pub fn remaining(&self, now: DateTime<Utc>) -> TimeDelta {
+        self.deadline - now
+    }
+}
+
+impl SlaPolicy {
+    /// 区間ごとの期限を取得
+    pub fn limit_for(&self, stage: SlaStage) -> TimeDelta {
+        match stage {
+            SlaStage::Shipping => self.ship_within,
+            SlaStage::Delivery => self.deliver_within,
+        }
+    }
+
+    /// 注文のステータス遷移日時からSLA判定を作成
+    /// 発送を伴わない注文（電子書籍のみ）や、区間の途中でキャンセルされた注文は対象外
+    pub fn checks(&self, order: &Order) -> Vec<SlaCheck> {
+        let mut checks = Vec::new();
+
+        if let Some(confirmed_at) = order.confirmed_at() {
+            let shipping_open = order.status() == OrderStatus::Confirmed;
+            if order.requires_shipping() && (order.shipped_at().is_some() || shipping_open) {
+                checks.push(self.check(
+                    order,
+                    SlaStage::Shipping,
+                    confirmed_at,
+                    order.shipped_at(),
+                ));
+            }
+        }
+
+        if let Some(shipped_at) = order.shipped_at() {
+            let delivery_open = order.status() == OrderStatus::Shipped;
+            if order.delivered_at().is_some() || delivery_open {
+                checks.push(self.check(
+                    order,
+                    SlaStage::Delivery,
+                    shipped_at,
+                    order.delivered_at(),
+                ));
+            }
+        }
+
+        checks
+    }
+
+    fn check(
+        &self,
+        order: &Order,
+        stage: SlaStage,
+        started_at: DateTime<Utc>,
+        completed_at: Option<DateTime<Utc>>,
+    ) -> SlaCheck {
+        SlaCheck {
+            order_id: order.id(),
+            customer_id: order.customer_id(),
+            stage,
+            started_at,
+            deadline: started_at + self.limit_for(stage),
+            completed_at,
+        }
+    }
+
+    /// SLAの期限から注文の配達予定日時を見積もる
+    /// 確定済みの注文は確定日時 + 発送期限 + 配達期限、発送済みの注文は発送日時 + 配達期限
+    /// 配達完了・キャンセル済み・発送を伴わない注文（電子書籍のみ）と、発売待ち・順番待ちで発送の目処が立たない注文はNone
+    pub fn estimated_delivery_at(&self, order: &Order) -> Option<DateTime<Utc>> {
+        if !order.requires_shipping() {
+            return None;
+        }
+        match order.status() {
+            OrderStatus::Confirmed => order
+                .confirmed_at()
+                .map(|confirmed_at| confirmed_at + self.ship_within + self.deliver_within),
+            OrderStatus::Shipped => order
+                .shipped_at()
+                .map(|shipped_at| shipped_at + self.deliver_within),
+            _ => None,
+        }
+    }
+
+    /// 注文のリストからSLAレポートを作成
+    pub fn build_report(&self, orders: &[Order], now: DateTime<Utc>) -> SlaReport {
+        let mut shipping = SlaStageSummary::new(self.ship_within);
+        let mut delivery = SlaStageSummary::new(self.deliver_within);
+        let mut at_risk_orders = Vec::new();
+
+        for check in orders.iter().flat_map(|order| self.checks(order)) {
+            let summary = match check.stage {
+                SlaStage::Shipping => &mut shipping,
+                SlaStage::Delivery => &mut delivery,
+            };
+            summary.tracked += 1;
+            if check.is_open() {
+                summary.in_progress += 1;
+            }
+
+            if check.is_breached(now) {
+                summary.breached += 1;
+                if check.is_open() {
+                    summary.open_breaches += 1;
+                }
+            } else if check.is_open() && check.remaining(now) <= self.at_risk_window {
+                at_risk_orders.push(AtRiskOrder {
+                    order_id: check.order_id.to_string(),
+                    stage: check.stage,
+                    deadline: check.deadline,
+                    remaining_minutes: check.remaining(now).num_minutes(),
+                });
+            }
+        }
+
+        // 期限が近い順に並べる
+        at_risk_orders.sort_by_key(|order| order.deadline);
+
+        SlaReport {
+            generated_at: now,
+            shipping,
+            delivery,
+            at_risk_orders,
+        }
+    }
+}
+
+/// 区間ごとのSLA集計
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlaStageSummary {
+    /// 期限（時間）
+    pub sla_hours: i64,
+    /// 集計対象の注文数
+    pub tracked: u64,
+    /// 区間が未完了の注文数
+    pub in_progress: u64,
+    /// 期限を超過した注文数（完了済みの遅延を含む）
+    pub breached: u64,
+    /// 期限を超過したまま未完了の注文数
+    pub open_breaches: u64,
+}
+
+impl SlaStageSummary {
+    fn new(limit: TimeDelta) -> Self {
+        Self {
+            sla_hours: limit.num_hours(),
+            tracked: 0,
+            in_progress: 0,
+            breached: 0,
+            open_breaches: 0,
+        }
+    }
+}
+
+/// 期限が迫っている未完了の注文
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AtRiskOrder {
+    pub order_id: String,
+    pub stage: SlaStage,
+    pub deadline: DateTime<Utc>,
+    pub remaining_minutes: i64,
+}
+
+/// SLAレポート
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlaReport {
+    pub generated_at: DateTime<Utc>,
+    pub shipping: SlaStageSummary,
+    pub delivery: SlaStageSummary,
+    pub at_risk_orders: Vec<AtRiskOrder>,
+}
+
+/// SLA監視タスク
+/// 発送待ち・配達待ちの注文を定期的に確認し、期限を超過した注文ごとに
+/// FulfillmentSlaBreachedイベントを1回だけ発行する
+/// 発行済みの違反はメモリ上で管理するため、プロセス再起動後は未完了の違反が再度発行される
+pub struct SlaMonitor {
+    order_repository: Arc<dyn OrderRepository>,
+    event_bus: Arc<dyn EventBus>,
+    logger: Arc<dyn Logger>,
+    policy: SlaPolicy,
+    reported: Mutex<HashSet<(OrderId, SlaStage)>>,
+}
+
+impl SlaMonitor {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_bus: Arc<dyn EventBus>,
+        logger: Arc<dyn Logger>,
+        policy: SlaPolicy,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_bus,
+            logger,
+            policy,
+            reported: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 監視タスクをバックグラウンドで起動
+    pub fn spawn(self, check_interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(check_interval);
+            loop {
+                interval.tick().await;
+                self.run_once().await;
+            }
+        })
+    }
+
+    /// 指定日時時点で期限を超過している未完了の注文を検出し、イベントを発行する
+    ///
+    /// # Returns
+    /// * 新たに違反として発行した判定のリスト
+    pub async fn run(&self, now: DateTime<Utc>) -> Result<Vec<SlaCheck>, RepositoryError> {
+        let mut orders = self
+            .order_repository
+            .find_by_status(OrderStatus::Confirmed)
+            .await?;
+        orders.extend(
+            self.order_repository
+                .find_by_status(OrderStatus::Shipped)
+                .await?,
+        );
+
+        let mut breached = Vec::new();
+        for check in orders.iter().flat_map(|order| self.policy.checks(order)) {
+            if !check.is_open() || !check.is_breached(now) {
+                continue;
+            }
+
+            let key = (check.order_id, check.stage);
+            if self.reported.lock().await.contains(&key) {
+                continue;
+            }
+
+            if self.publish_breach(&check, now).await {
+                self.reported.lock().await.insert(key);
+                breached.push(check);
+            }
+        }
+
+        Ok(breached)
+    }
+
+    /// FulfillmentSlaBreachedイベントを発行する
+    /// 発行に失敗した場合は次回の実行で再試行する
+    async fn publish_breach(&self, check: &SlaCheck, now: DateTime<Utc>) -> bool {
+        let event = FulfillmentSlaBreached::new(
+            check.order_id,
+            check.customer_id,
+            check.stage,
+            check.started_at,
+            check.deadline,
+            now,
+        );
+        let correlation_id = event.metadata.correlation_id;
+
+        let mut context = HashMap::new();
+        context.insert("order_id".to_string(), check.order_id.to_string());
+        context.insert("stage".to_string(), check.stage.name().to_string());
+
+        if let Err(e) = self
+            .event_bus
+            .publish(DomainEvent::FulfillmentSlaBreached(event))
+            .await
+        {
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "SlaMonitor",
+                "Failed to publish FulfillmentSlaBreached",
+                Some(correlation_id),
+                Some(context),
+            );
+            return false;
+        }
+
+        self.logger.warn(
+            "SlaMonitor",
+            "Fulfillment SLA breached",
+            Some(correlation_id),
+            Some(context),
+        );
+        true
+    }
+}
+
+#[async_trait]
+impl SupervisedTask for SlaMonitor {
+    async fn run_once(&self) {
+        if let Err(e) = self.run(Utc::now()).await {
+            let mut context = HashMap::new();
+            context.insert("error".to_string(), e.to_string());
+            self.logger.error(
+                "SlaMonitor",
+                "Failed to load orders for SLA check",
+                None,
+                Some(context),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, Money, ShippingAddress};
+
+    fn shipped_order(confirmed_at: DateTime<Utc>, shipped_at: Option<DateTime<Utc>>) -> Order {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        order.set_shipping_address(
+            ShippingAddress::new(
+                "1234567".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "道玄坂1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        ).unwrap();
+        order.confirm().unwrap();
+        if shipped_at.is_some() {
+            order.mark_as_shipped().unwrap();
+        }
+        order.with_transition_times(Some(confirmed_at), shipped_at, None)
+    }
+
+    #[test]
+    fn test_report_counts_breaches_and_at_risk_orders() {
+        let policy = SlaPolicy {
+            ship_within: TimeDelta::hours(24),
+            deliver_within: TimeDelta::hours(48),
+            at_risk_window: TimeDelta::hours(6),
+        };
+        let now = Utc::now();
+
+        let orders = vec![
+            // 発送期限を超過して未発送
+            shipped_order(now - TimeDelta::hours(30), None),
+            // 発送期限まで残り2時間（違反の恐れあり）
+            shipped_order(now - TimeDelta::hours(22), None),
+            // 期限内に発送済み・配達期限まで余裕あり
+            shipped_order(now - TimeDelta::hours(10), Some(now - TimeDelta::hours(5))),
+            // 発送が遅延した（完了済みの違反）
+            shipped_order(now - TimeDelta::hours(40), Some(now - TimeDelta::hours(1))),
+        ];
+
+        let report = policy.build_report(&orders, now);
+
+        assert_eq!(report.shipping.tracked, 4);
+        assert_eq!(report.shipping.in_progress, 2);
+        assert_eq!(report.shipping.breached, 2);
+        assert_eq!(report.shipping.open_breaches, 1);
+        assert_eq!(report.delivery.tracked, 2);
+        assert_eq!(report.delivery.breached, 0);
+        assert_eq!(report.at_risk_orders.len(), 1);
+        assert_eq!(
+            report.at_risk_orders[0].order_id,
+            orders[1].id().to_string()
+        );
+        assert_eq!(report.at_risk_orders[0].stage, SlaStage::Shipping);
+    }
+
+    #[test]
+    fn test_digital_only_and_cancelled_orders_are_not_tracked() {
+        let policy = SlaPolicy::default();
+
+        let mut digital = Order::new(OrderId::new(), CustomerId::new());
+        digital
+            .add_book_with_fulfillment(
+                BookId::new(),
+                1,
+                Money::jpy(1000),
+                crate::domain::model::FulfillmentType::Digital,
+            )
+            .unwrap();
+        digital.confirm().unwrap();
+        assert!(policy.checks(&digital).is_empty());
+
+        let mut cancelled = shipped_order(Utc::now(), None);
+        cancelled.cancel().unwrap();
+        assert!(policy.checks(&cancelled).is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::{OrderId, OrderStatus};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// 強制遷移の理由の最大文字数
+pub const MAX_STATUS_OVERRIDE_REASON_LENGTH: usize = 500;
+
+/// 管理者による強制遷移で移れる遷移先と、その遷移元
+/// 通常の操作（顧客・スタッフ）の遷移ルールとは別に、誤った状態で止まった注文の修正に必要な遷移だけを許可する
+/// 強制遷移は在庫の予約・解放などのサーガを起動しないため、在庫の予約の有無が変わる遷移は含めない
+/// （予約した注文のキャンセルや、順番待ち・入荷待ちの注文の確定は、在庫を予約・解放する通常の操作で行う）
+const OVERRIDE_MATRIX: [(OrderStatus, &[OrderStatus]); 5] = [
+    (
+        OrderStatus::Confirmed,
+        &[OrderStatus::OnHold, OrderStatus::Shipped],
+    ),
+    (
+        OrderStatus::Shipped,
+        &[
+            OrderStatus::Confirmed,
+            OrderStatus::OnHold,
+            OrderStatus::Delivered,
+        ],
+    ),
+    (
+        OrderStatus::Delivered,
+        &[OrderStatus::Shipped, OrderStatus::ReturnRequested],
+    ),
+    (OrderStatus::Fulfilled, &[OrderStatus::Confirmed]),
+    // 在庫を予約する前の注文だけ
+    (OrderStatus::Cancelled, &[OrderStatus::Pending]),
+];
+
+/// 強制遷移で遷移先に移れる遷移元（遷移先にできないステータスの場合は空）
+pub fn allowed_override_sources(to: OrderStatus) -> &'static [OrderStatus] {
+    OVERRIDE_MATRIX
+        .iter()
+        .find(|(target, _)| *target == to)
+        .map(|(_, sources)| *sources)
+        .unwrap_or(&[])
+}
+
+/// 強制遷移が許可されているかを検証する
+pub fn ensure_override_allowed(from: OrderStatus, to: OrderStatus) -> Result<(), DomainError> {
+    if allowed_override_sources(to).contains(&from) {
+        return Ok(());
+    }
+    Err(DomainError::InvalidOrderState(format!(
+        "{}から{}への強制遷移は許可されていません",
+        from, to
+    )))
+}
+
+/// 強制遷移の理由を検証する（前後の空白を除いて空にできず、最大文字数まで）
+pub fn validate_override_reason(reason: &str) -> Result<String, DomainError> {
+    let reason = reason.trim();
+    if reason.is_empty() {
+        return Err(DomainError::InvalidValue(
+            "強制遷移の理由を入力してください".to_string(),
+        ));
+    }
+    if reason.chars().count() > MAX_STATUS_OVERRIDE_REASON_LENGTH {
+        return Err(DomainError::InvalidValue(format!(
+            "強制遷移の理由は{}文字以内で入力してください",
+            MAX_STATUS_OVERRIDE_REASON_LENGTH
+        )));
+    }
+    Ok(reason.to_string())
+}
+
+/// 注文に適用した強制遷移（永続化しない。保存時にイベントを導出するために注文が一時的に保持する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusOverride {
+    pub from_status: OrderStatus,
+    pub to_status: OrderStatus,
+    pub reason: String,
+    pub operator: String,
+}
+
+/// 強制遷移の監査記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatusOverrideAudit {
+    pub audit_id: Uuid,
+    pub order_id: OrderId,
+    pub from_status: String,
+    pub to_status: String,
+    pub reason: String,
+    /// 強制遷移を行ったオペレーター
+    pub operator: String,
+    /// 発行したOrderStatusForcefullyChangedイベントのID
+    pub event_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl StatusOverrideAudit {
+    /// 強制遷移の監査記録を作成
+    ///
+    /// # Arguments
+    /// * `order_id` - 注文ID
+    /// * `status_override` - 適用した強制遷移
+    /// * `event_id` - 発行したイベントのID
+    /// * `now` - 記録日時
+    pub fn record(
+        order_id: OrderId,
+        status_override: &StatusOverride,
+        event_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            audit_id: Uuid::new_v4(),
+            order_id,
+            from_status: status_override.from_status.to_string(),
+            to_status: status_override.to_status.to_string(),
+            reason: status_override.reason.clone(),
+            operator: status_override.operator.clone(),
+            event_id,
+            created_at: now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_override_matrix_only_allows_listed_transitions() {
+        assert!(ensure_override_allowed(OrderStatus::Shipped, OrderStatus::Confirmed).is_ok());
+        assert!(ensure_override_allowed(OrderStatus::Pending, OrderStatus::Cancelled).is_ok());
+        // 在庫の予約・解放を伴う遷移は通常の操作で行う
+        for from in [
+            OrderStatus::Confirmed,
+            OrderStatus::OnHold,
+            OrderStatus::AwaitingRelease,
+            OrderStatus::Waitlisted,
+            OrderStatus::Shipped,
+        ] {
+            assert!(ensure_override_allowed(from, OrderStatus::Cancelled).is_err());
+        }
+        assert!(
+            ensure_override_allowed(OrderStatus::Waitlisted, OrderStatus::Confirmed).is_err()
+        );
+        assert!(
+            ensure_override_allowed(OrderStatus::AwaitingRelease, OrderStatus::Confirmed).is_err()
+        );
+        // 通常の遷移でも強制遷移の対象外のものは許可しない
+        assert!(ensure_override_allowed(OrderStatus::Pending, OrderStatus::Confirmed).is_err());
+        assert!(
+            ensure_override_allowed(OrderStatus::ReturnRequested, OrderStatus::Returned).is_err()
+        );
+        // 終了した注文は動かさない
+        assert!(ensure_override_allowed(OrderStatus::Cancelled, OrderStatus::Confirmed).is_err());
+        assert!(allowed_override_sources(OrderStatus::Pending).is_empty());
+
+        assert!(validate_override_reason("   ").is_err());
+        assert_eq!(
+            validate_override_reason(" 配送業者の誤登録 ").unwrap(),
+            "配送業者の誤登録"
+        );
+    }
+}
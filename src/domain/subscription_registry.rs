@@ -0,0 +1,157 @@
+use crate::domain::event_bus::{PausedEventHandling, SubscriptionState, SubscriptionStatus};
+use crate::domain::retry_policy::DeliveryGuarantee;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 永続化する購読の状態
+/// 管理者が一時停止・解除したハンドラーは、再起動後も同じ状態に戻す
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "state", content = "paused_events")]
+pub enum RegisteredSubscriptionState {
+    Active,
+    Paused(PausedEventHandling),
+    /// 購読を解除した（再起動後も購読しない）
+    Unsubscribed,
+}
+
+impl From<SubscriptionState> for RegisteredSubscriptionState {
+    fn from(state: SubscriptionState) -> Self {
+        match state {
+            SubscriptionState::Active => RegisteredSubscriptionState::Active,
+            SubscriptionState::Paused(paused_events) => {
+                RegisteredSubscriptionState::Paused(paused_events)
+            }
+        }
+    }
+}
+
+/// 購読の登録内容（ハンドラーごと）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubscriptionRegistration {
+    pub handler_name: String,
+    /// 購読しているイベントタイプ（登録順）
+    pub event_types: Vec<String>,
+    #[serde(flatten)]
+    pub state: RegisteredSubscriptionState,
+    /// 絞り込み条件の説明（指定していない場合はNone）
+    pub filter: Option<String>,
+    pub delivery_guarantee: DeliveryGuarantee,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SubscriptionRegistration {
+    /// イベントタイプごとの購読の一覧から、ハンドラーの登録内容をまとめる
+    ///
+    /// # Arguments
+    /// * `handler_name` - ハンドラー名
+    /// * `statuses` - 現在の購読の一覧（他のハンドラーの購読を含んでよい）
+    /// * `state` - 保存する状態
+    /// * `now` - 更新日時
+    ///
+    /// # Returns
+    /// * `Some(SubscriptionRegistration)` - ハンドラーの登録内容
+    /// * `None` - ハンドラーの購読がない
+    pub fn from_statuses(
+        handler_name: &str,
+        statuses: &[SubscriptionStatus],
+        state: RegisteredSubscriptionState,
+        now: DateTime<Utc>,
+    ) -> Option<Self> {
+        let subscriptions: Vec<&SubscriptionStatus> = statuses
+            .iter()
+            .filter(|status| status.handler_name == handler_name)
+            .collect();
+        let first = subscriptions.first()?;
+        Some(Self {
+            handler_name: handler_name.to_string(),
+            event_types: subscriptions
+                .iter()
+                .map(|status| status.event_type.clone())
+                .collect(),
+            state,
+            filter: first.filter.clone(),
+            delivery_guarantee: first.delivery_guarantee,
+            updated_at: now,
+        })
+    }
+
+    /// 現在の購読の一覧を、状態を含めてハンドラーごとの登録内容にまとめる（登録順）
+    pub fn all_from_statuses(statuses: &[SubscriptionStatus], now: DateTime<Utc>) -> Vec<Self> {
+        let mut handler_names: Vec<&str> = Vec::new();
+        for status in statuses {
+            if !handler_names.contains(&status.handler_name.as_str()) {
+                handler_names.push(&status.handler_name);
+            }
+        }
+        handler_names
+            .into_iter()
+            .filter_map(|handler_name| {
+                let state = statuses
+                    .iter()
+                    .find(|status| status.handler_name == handler_name)
+                    .map(|status| status.state.into())?;
+                Self::from_statuses(handler_name, statuses, state, now)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::load_shedding::HandlerCriticality;
+
+    fn status(handler_name: &str, event_type: &str, state: SubscriptionState) -> SubscriptionStatus {
+        SubscriptionStatus {
+            handler_name: handler_name.to_string(),
+            event_type: event_type.to_string(),
+            delivery_guarantee: DeliveryGuarantee::AtMostOnce,
+            criticality: HandlerCriticality::NonCritical,
+            filter: Some("digital only".to_string()),
+            state,
+            buffered_events: 0,
+            skipped_events: 0,
+            shed_events: 0,
+            auto_paused_until: None,
+        }
+    }
+
+    #[test]
+    fn test_registrations_group_event_types_per_handler() {
+        let statuses = vec![
+            status(
+                "NotificationHandler",
+                "OrderConfirmed",
+                SubscriptionState::Paused(PausedEventHandling::Skip),
+            ),
+            status("MetricsHandler", "OrderConfirmed", SubscriptionState::Active),
+            status(
+                "NotificationHandler",
+                "OrderShipped",
+                SubscriptionState::Paused(PausedEventHandling::Skip),
+            ),
+        ];
+
+        let registrations = SubscriptionRegistration::all_from_statuses(&statuses, Utc::now());
+
+        assert_eq!(registrations.len(), 2);
+        assert_eq!(registrations[0].handler_name, "NotificationHandler");
+        assert_eq!(
+            registrations[0].event_types,
+            vec!["OrderConfirmed".to_string(), "OrderShipped".to_string()]
+        );
+        assert_eq!(
+            registrations[0].state,
+            RegisteredSubscriptionState::Paused(PausedEventHandling::Skip)
+        );
+        assert_eq!(registrations[0].filter.as_deref(), Some("digital only"));
+        assert_eq!(registrations[1].state, RegisteredSubscriptionState::Active);
+        assert!(SubscriptionRegistration::from_statuses(
+            "UnknownHandler",
+            &statuses,
+            RegisteredSubscriptionState::Unsubscribed,
+            Utc::now()
+        )
+        .is_none());
+    }
+}
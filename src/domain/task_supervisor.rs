@@ -0,0 +1,413 @@
+use crate::domain::alerting::{Alert, AlertSeverity, AnomalyKind};
+use crate::domain::port::AlertingPort;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// 監督下で定期的に実行するバックグラウンドタスク
+/// 1回分の処理だけを実装し、実行間隔と再起動はタスクの監督に任せる
+#[async_trait]
+pub trait SupervisedTask: Send + Sync + 'static {
+    /// 1回分の処理（失敗はタスク内でログに記録する）
+    async fn run_once(&self);
+}
+
+/// タスクの監督の設定
+#[derive(Debug, Clone)]
+pub struct TaskSupervisionPolicy {
+    /// 何回分の実行間隔のあいだ生存通知がなければ止まったとみなすか
+    pub missed_heartbeats: u32,
+    /// タスクごとの再起動の上限（超えた場合は再起動をあきらめる）
+    pub max_restarts: u32,
+    /// この回数だけ再起動したタスクをアラートで通知する（繰り返し失敗している）
+    pub alert_after_restarts: u32,
+    /// 生存通知を確認する間隔
+    pub check_interval: Duration,
+}
+
+impl Default for TaskSupervisionPolicy {
+    fn default() -> Self {
+        Self {
+            missed_heartbeats: 3,
+            max_restarts: 5,
+            alert_after_restarts: 2,
+            check_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+/// バックグラウンドタスクの生存状況
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackgroundTaskHealth {
+    pub name: String,
+    /// 生存通知が途切れておらず、再起動をあきらめていない
+    pub healthy: bool,
+    pub last_heartbeat_at: DateTime<Utc>,
+    pub restarts: u32,
+    /// 直近に再起動した理由
+    pub last_failure: Option<String>,
+    /// 再起動の上限を超えたため止めたままにしている
+    pub gave_up: bool,
+}
+
+/// タスクの生存通知（最後に通知した日時のミリ秒）
+#[derive(Debug, Clone)]
+struct Heartbeat(Arc<AtomicI64>);
+
+impl Heartbeat {
+    fn new(at: DateTime<Utc>) -> Self {
+        Self(Arc::new(AtomicI64::new(at.timestamp_millis())))
+    }
+
+    fn beat(&self, at: DateTime<Utc>) {
+        self.0.store(at.timestamp_millis(), Ordering::Relaxed);
+    }
+
+    fn last(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(self.0.load(Ordering::Relaxed)).unwrap_or_default()
+    }
+}
+
+/// 監督中のタスクの実行状態
+struct TaskRuntime {
+    handle: Option<JoinHandle<()>>,
+    restarts: u32,
+    last_failure: Option<String>,
+    gave_up: bool,
+}
+
+/// 監督中のタスク
+struct SupervisedEntry {
+    name: &'static str,
+    task: Arc<dyn SupervisedTask>,
+    interval: Duration,
+    heartbeat: Heartbeat,
+    runtime: Mutex<TaskRuntime>,
+}
+
+impl SupervisedEntry {
+    fn lock_runtime(&self) -> std::sync::MutexGuard<'_, TaskRuntime> {
+        self.runtime.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// 実行ループを起動する（実行間隔ごとに生存通知をしてから1回分の処理を行う）
+    fn start(&self) -> JoinHandle<()> {
+        let task = self.task.clone();
+        let heartbeat = self.heartbeat.clone();
+        let period = self.interval;
+        heartbeat.beat(Utc::now());
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                heartbeat.beat(Utc::now());
+                task.run_once().await;
+                heartbeat.beat(Utc::now());
+            }
+        })
+    }
+
+    /// 実行間隔の指定回数分より長く生存通知が途切れているか
+    fn is_stalled(&self, now: DateTime<Utc>, missed_heartbeats: u32) -> bool {
+        let timeout = self.interval.saturating_mul(missed_heartbeats);
+        let timeout = TimeDelta::from_std(timeout).unwrap_or(TimeDelta::MAX);
+        now - self.heartbeat.last() > timeout
+    }
+}
+
+/// バックグラウンドタスク（定期ジョブ・監視タスクなど）の監督
+/// タスクの実行ループを持ち、実行のたびに生存通知を記録する
+/// 監視タスクが、終了した（panicを含む）タスクと生存通知が途切れた（処理が止まった）タスクを再起動し、
+/// 再起動を繰り返すタスクをアラートで通知する。再起動の上限を超えたタスクは止めたままにする
+pub struct TaskSupervisor {
+    tasks: Mutex<Vec<Arc<SupervisedEntry>>>,
+    policy: TaskSupervisionPolicy,
+    alerting: Option<Arc<dyn AlertingPort>>,
+}
+
+impl TaskSupervisor {
+    pub fn new(policy: TaskSupervisionPolicy) -> Self {
+        Self {
+            tasks: Mutex::new(Vec::new()),
+            policy,
+            alerting: None,
+        }
+    }
+
+    /// アラートの送信先を設定
+    /// 設定すると、再起動を繰り返すタスクと再起動をあきらめたタスクを通知する
+    ///
+    /// # Arguments
+    /// * `alerting` - アラートの送信先
+    pub fn with_alerting(mut self, alerting: Arc<dyn AlertingPort>) -> Self {
+        self.alerting = Some(alerting);
+        self
+    }
+
+    fn lock_tasks(&self) -> std::sync::MutexGuard<'_, Vec<Arc<SupervisedEntry>>> {
+        self.tasks.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// タスクを登録して起動する
+    ///
+    /// # Arguments
+    /// * `name` - タスクの名前（生存状況とアラートに表示する）
+    /// * `task` - 1回分の処理
+    /// * `interval` - 実行間隔（生存通知の間隔でもある）
+    pub fn supervise(&self, name: &'static str, task: Arc<dyn SupervisedTask>, interval: Duration) {
+        let entry = Arc::new(SupervisedEntry {
+            name,
+            task,
+            interval,
+            heartbeat: Heartbeat::new(Utc::now()),
+            runtime: Mutex::new(TaskRuntime {
+                handle: None,
+                restarts: 0,
+                last_failure: None,
+                gave_up: false,
+            }),
+        });
+        entry.lock_runtime().handle = Some(entry.start());
+        self.lock_tasks().push(entry);
+    }
+
+    /// 終了したタスクと生存通知が途切れたタスクを再起動する
+    ///
+    /// # Returns
+    /// * 再起動したタスクの名前のリスト（再起動をあきらめたタスクは含めない）
+    pub async fn check(&self, now: DateTime<Utc>) -> Vec<&'static str> {
+        let tasks = self.lock_tasks().clone();
+        let mut restarted = Vec::new();
+        for entry in tasks {
+            let Some(failure) = self.detect_failure(&entry, now).await else {
+                continue;
+            };
+            tracing::warn!(task.name = entry.name, reason = %failure, "background task failed");
+
+            let (restarts, gave_up) = {
+                let mut runtime = entry.lock_runtime();
+                runtime.last_failure = Some(failure.clone());
+                if runtime.restarts >= self.policy.max_restarts {
+                    runtime.gave_up = true;
+                } else {
+                    runtime.restarts += 1;
+                    runtime.handle = Some(entry.start());
+                }
+                (runtime.restarts, runtime.gave_up)
+            };
+
+            if gave_up {
+                tracing::error!(
+                    task.name = entry.name,
+                    restarts,
+                    "giving up restarting background task"
+                );
+                self.alert(
+                    &entry,
+                    AlertSeverity::Critical,
+                    format!(
+                        "バックグラウンドタスク {} が {} 回の再起動の後も失敗したため、再起動をあきらめました（{}）",
+                        entry.name, restarts, failure
+                    ),
+                    restarts,
+                )
+                .await;
+                continue;
+            }
+            restarted.push(entry.name);
+            if restarts == self.policy.alert_after_restarts {
+                self.alert(
+                    &entry,
+                    AlertSeverity::Warning,
+                    format!(
+                        "バックグラウンドタスク {} が失敗を繰り返しています（{} 回再起動、直近: {}）",
+                        entry.name, restarts, failure
+                    ),
+                    restarts,
+                )
+                .await;
+            }
+        }
+        restarted
+    }
+
+    /// タスクが終了したか、生存通知が途切れていれば、止めて理由を返す
+    async fn detect_failure(&self, entry: &SupervisedEntry, now: DateTime<Utc>) -> Option<String> {
+        let finished = {
+            let mut runtime = entry.lock_runtime();
+            if runtime.gave_up {
+                return None;
+            }
+            match runtime.handle.take() {
+                Some(handle) if handle.is_finished() => handle,
+                Some(handle) => {
+                    if !entry.is_stalled(now, self.policy.missed_heartbeats) {
+                        runtime.handle = Some(handle);
+                        return None;
+                    }
+                    handle.abort();
+                    return Some(format!(
+                        "生存通知が {} 回分の実行間隔のあいだ途切れました",
+                        self.policy.missed_heartbeats
+                    ));
+                }
+                None => return Some("起動していません".to_string()),
+            }
+        };
+        Some(match finished.await {
+            Err(e) if e.is_panic() => "panicで終了しました".to_string(),
+            _ => "終了しました".to_string(),
+        })
+    }
+
+    async fn alert(
+        &self,
+        entry: &SupervisedEntry,
+        severity: AlertSeverity,
+        message: String,
+        restarts: u32,
+    ) {
+        let Some(alerting) = &self.alerting else {
+            return;
+        };
+        let alert = Alert {
+            kind: AnomalyKind::BackgroundTaskFailing,
+            severity,
+            message,
+            observed_value: f64::from(restarts),
+            threshold: f64::from(self.policy.max_restarts),
+            window_seconds: entry
+                .interval
+                .saturating_mul(self.policy.missed_heartbeats)
+                .as_secs(),
+            detected_at: Utc::now(),
+        };
+        if let Err(e) = alerting.send_alert(&alert).await {
+            tracing::warn!(task.name = entry.name, error = %e, "failed to send background task alert");
+        }
+    }
+
+    /// 監督中のタスクの生存状況（登録順）
+    pub fn health(&self, now: DateTime<Utc>) -> Vec<BackgroundTaskHealth> {
+        self.lock_tasks()
+            .iter()
+            .map(|entry| {
+                let runtime = entry.lock_runtime();
+                let finished = runtime.handle.as_ref().is_none_or(JoinHandle::is_finished);
+                BackgroundTaskHealth {
+                    name: entry.name.to_string(),
+                    healthy: !runtime.gave_up
+                        && !finished
+                        && !entry.is_stalled(now, self.policy.missed_heartbeats),
+                    last_heartbeat_at: entry.heartbeat.last(),
+                    restarts: runtime.restarts,
+                    last_failure: runtime.last_failure.clone(),
+                    gave_up: runtime.gave_up,
+                }
+            })
+            .collect()
+    }
+
+    /// 監視タスクをバックグラウンドで起動
+    pub fn spawn_watchdog(self: &Arc<Self>) -> JoinHandle<()> {
+        let supervisor = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(supervisor.policy.check_interval);
+            loop {
+                interval.tick().await;
+                supervisor.check(Utc::now()).await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    /// 指定した回数目の実行で処理が止まる（またはpanicする）タスク
+    struct FlakyTask {
+        runs: AtomicU32,
+        fail_on: u32,
+        panic: bool,
+    }
+
+    #[async_trait]
+    impl SupervisedTask for FlakyTask {
+        async fn run_once(&self) {
+            let run = self.runs.fetch_add(1, Ordering::SeqCst) + 1;
+            if run >= self.fail_on {
+                if self.panic {
+                    panic!("task failed");
+                }
+                std::future::pending::<()>().await;
+            }
+        }
+    }
+
+    fn policy() -> TaskSupervisionPolicy {
+        TaskSupervisionPolicy {
+            missed_heartbeats: 3,
+            max_restarts: 2,
+            alert_after_restarts: 1,
+            check_interval: Duration::from_millis(10),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_restarts_panicked_task_and_gives_up_after_max_restarts() {
+        let supervisor = TaskSupervisor::new(policy());
+        let task = Arc::new(FlakyTask {
+            runs: AtomicU32::new(0),
+            fail_on: 1,
+            panic: true,
+        });
+        supervisor.supervise("flaky", task.clone(), Duration::from_millis(10));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        assert_eq!(supervisor.check(Utc::now()).await, vec!["flaky"]);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(supervisor.check(Utc::now()).await, vec!["flaky"]);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        // 再起動の上限を超えたため、止めたままにする
+        assert!(supervisor.check(Utc::now()).await.is_empty());
+        let health = supervisor.health(Utc::now());
+        assert!(!health[0].healthy);
+        assert!(health[0].gave_up);
+        assert_eq!(health[0].restarts, 2);
+        assert_eq!(
+            health[0].last_failure.as_deref(),
+            Some("panicで終了しました")
+        );
+        assert_eq!(task.runs.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_restarts_task_that_misses_heartbeats() {
+        let supervisor = TaskSupervisor::new(policy());
+        let task = Arc::new(FlakyTask {
+            runs: AtomicU32::new(0),
+            fail_on: 2,
+            panic: false,
+        });
+        supervisor.supervise("stuck", task.clone(), Duration::from_millis(10));
+        assert!(supervisor.health(Utc::now())[0].healthy);
+
+        // 2回目の実行で止まり、生存通知が途切れる
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(!supervisor.health(Utc::now())[0].healthy);
+        assert_eq!(supervisor.check(Utc::now()).await, vec!["stuck"]);
+
+        let health = supervisor.health(Utc::now());
+        assert!(health[0].healthy);
+        assert_eq!(health[0].restarts, 1);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(task.runs.load(Ordering::SeqCst) >= 3);
+    }
+}
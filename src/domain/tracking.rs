@@ -0,0 +1,322 @@
+use crate::domain::error::DomainError;
+use crate::domain::model::{Order, OrderId, OrderStatus, ShippingAddress};
+use crate::domain::sla::SlaPolicy;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+/// 配送追跡の段階（顧客向けタイムラインの並び順）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TrackingStage {
+    /// ご注文受付
+    Ordered,
+    /// 梱包完了
+    Packed,
+    /// 発送済み
+    Shipped,
+    /// 配達中（最寄りの営業所から配達に出発）
+    OutForDelivery,
+    /// 配達完了
+    Delivered,
+}
+
+impl TrackingStage {
+    /// タイムラインに表示するすべての段階（表示順）
+    pub const ALL: [TrackingStage; 5] = [
+        TrackingStage::Ordered,
+        TrackingStage::Packed,
+        TrackingStage::Shipped,
+        TrackingStage::OutForDelivery,
+        TrackingStage::Delivered,
+    ];
+
+    /// 顧客向けの表示名
+    pub fn label(&self) -> &'static str {
+        match self {
+            TrackingStage::Ordered => "ご注文受付",
+            TrackingStage::Packed => "梱包完了",
+            TrackingStage::Shipped => "発送済み",
+            TrackingStage::OutForDelivery => "配達中",
+            TrackingStage::Delivered => "配達完了",
+        }
+    }
+
+    /// 文字列からTrackingStageを作成
+    pub fn from_string(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "Ordered" => Ok(TrackingStage::Ordered),
+            "Packed" => Ok(TrackingStage::Packed),
+            "Shipped" => Ok(TrackingStage::Shipped),
+            "OutForDelivery" => Ok(TrackingStage::OutForDelivery),
+            "Delivered" => Ok(TrackingStage::Delivered),
+            _ => Err(DomainError::InvalidValue(format!(
+                "無効な配送追跡の段階: {}",
+                s
+            ))),
+        }
+    }
+
+    /// 配送業者のステータスコードから作成（大文字・小文字は区別しない）
+    pub fn from_carrier_status(status: &str) -> Result<Self, DomainError> {
+        match status.to_ascii_lowercase().as_str() {
+            "label_created" | "packed" => Ok(TrackingStage::Packed),
+            "picked_up" | "shipped" | "in_transit" => Ok(TrackingStage::Shipped),
+            "out_for_delivery" => Ok(TrackingStage::OutForDelivery),
+            "delivered" => Ok(TrackingStage::Delivered),
+            _ => Err(DomainError::InvalidValue(format!(
+                "未対応の配送業者ステータス: {}",
+                status
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for TrackingStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stage_str = match self {
+            TrackingStage::Ordered => "Ordered",
+            TrackingStage::Packed => "Packed",
+            TrackingStage::Shipped => "Shipped",
+            TrackingStage::OutForDelivery => "OutForDelivery",
+            TrackingStage::Delivered => "Delivered",
+        };
+        write!(f, "{}", stage_str)
+    }
+}
+
+/// 配送追跡イベント（tracking_eventsテーブルの1行）
+/// 注文イベントと配送業者からの追跡情報を投影したもの
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackingEvent {
+    /// 投影元のドメインイベントID（重複投影の防止に使用）
+    pub event_id: Uuid,
+    pub order_id: OrderId,
+    pub stage: TrackingStage,
+    pub occurred_at: DateTime<Utc>,
+    /// 配送業者が報告した拠点（例: 東京ベース）
+    pub location: Option<String>,
+    /// 配送業者が報告した詳細
+    pub description: Option<String>,
+}
+
+/// タイムラインの1段階
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TrackingStep {
+    pub stage: TrackingStage,
+    pub label: &'static str,
+    /// この段階を通過済みかどうか
+    pub completed: bool,
+    /// この段階に最初に到達した日時（追跡情報が届いていない場合はNone）
+    pub occurred_at: Option<DateTime<Utc>>,
+    pub location: Option<String>,
+}
+
+/// 顧客向けの配送追跡タイムライン
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TrackingTimeline {
+    pub order_id: OrderId,
+    /// 到達済みの最も進んだ段階
+    pub current_stage: Option<TrackingStage>,
+    /// ご注文受付 → 梱包完了 → 発送済み → 配達中 → 配達完了 の各段階
+    pub steps: Vec<TrackingStep>,
+    /// すべての追跡イベント（発生日時の昇順）
+    pub history: Vec<TrackingEvent>,
+}
+
+impl TrackingTimeline {
+    /// 追跡イベントからタイムラインを組み立てる
+    /// 配送業者が一部の段階を報告しない場合でも、より後の段階に到達していれば通過済みとみなす
+    pub fn build(order_id: OrderId, events: &[TrackingEvent]) -> Self {
+        let mut history: Vec<TrackingEvent> = events
+            .iter()
+            .filter(|event| event.order_id == order_id)
+            .cloned()
+            .collect();
+        history.sort_by_key(|event| (event.occurred_at, event.stage));
+
+        let current_stage = history.iter().map(|event| event.stage).max();
+        let steps = TrackingStage::ALL
+            .iter()
+            .map(|&stage| {
+                // 同じ段階が複数回報告された場合は最初の到達を表示する
+                let first = history.iter().find(|event| event.stage == stage);
+                TrackingStep {
+                    stage,
+                    label: stage.label(),
+                    completed: current_stage.is_some_and(|current| current >= stage),
+                    occurred_at: first.map(|event| event.occurred_at),
+                    location: first.and_then(|event| event.location.clone()),
+                }
+            })
+            .collect();
+
+        Self {
+            order_id,
+            current_stage,
+            steps,
+            history,
+        }
+    }
+}
+
+/// 一部を伏せた配送先住所（公開用）
+/// 郵便番号は上3桁のみ、番地・建物名は含めない
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaskedAddress {
+    /// 例: 150-****
+    pub postal_code: String,
+    pub prefecture: String,
+    pub city: String,
+}
+
+impl MaskedAddress {
+    /// 配送先住所の一部を伏せる
+    pub fn mask(address: &ShippingAddress) -> Self {
+        let prefix: String = address.postal_code().chars().take(3).collect();
+        Self {
+            postal_code: format!("{}-****", prefix),
+            prefecture: address.prefecture().to_string(),
+            city: address.city().to_string(),
+        }
+    }
+}
+
+/// 追跡トークンで公開する注文の配送状況（読み取りモデル）
+/// アカウントを持たない受取人にも見せるため、注文ID・顧客・明細・金額・受取人は含めない
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicTrackingView {
+    pub status: OrderStatus,
+    /// 配達予定日時（SLAの期限からの見積もり、見積もれない注文はNone）
+    pub estimated_delivery_at: Option<DateTime<Utc>>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    /// 一部を伏せた配送先住所（電子書籍のみの注文はNone）
+    pub shipping_address: Option<MaskedAddress>,
+}
+
+impl PublicTrackingView {
+    /// 注文から公開用の配送状況を作成
+    /// 保留中の注文は確認中であることを知らせないよう、確定済みとして公開する
+    pub fn build(order: &Order, sla_policy: &SlaPolicy) -> Self {
+        let status = match order.status() {
+            OrderStatus::OnHold => OrderStatus::Confirmed,
+            status => status,
+        };
+        Self {
+            status,
+            estimated_delivery_at: sla_policy.estimated_delivery_at(order),
+            shipped_at: order.shipped_at(),
+            delivered_at: order.delivered_at(),
+            shipping_address: order.shipping_address().map(MaskedAddress::mask),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::{BookId, CustomerId, Money};
+    use chrono::TimeDelta;
+
+    fn tracking_event(
+        order_id: OrderId,
+        stage: TrackingStage,
+        occurred_at: DateTime<Utc>,
+    ) -> TrackingEvent {
+        TrackingEvent {
+            event_id: Uuid::new_v4(),
+            order_id,
+            stage,
+            occurred_at,
+            location: None,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_timeline_marks_skipped_stages_as_completed() {
+        let order_id = OrderId::new();
+        let now = Utc::now();
+        let events = vec![
+            tracking_event(order_id, TrackingStage::Shipped, now + TimeDelta::hours(5)),
+            tracking_event(order_id, TrackingStage::Ordered, now),
+            tracking_event(order_id, TrackingStage::Shipped, now + TimeDelta::hours(6)),
+            tracking_event(OrderId::new(), TrackingStage::Delivered, now),
+        ];
+
+        let timeline = TrackingTimeline::build(order_id, &events);
+
+        assert_eq!(timeline.current_stage, Some(TrackingStage::Shipped));
+        assert_eq!(timeline.history.len(), 3);
+        assert_eq!(timeline.history[0].stage, TrackingStage::Ordered);
+
+        let completed: Vec<bool> = timeline.steps.iter().map(|step| step.completed).collect();
+        assert_eq!(completed, vec![true, true, true, false, false]);
+        // 梱包は報告されていないため日時は不明
+        assert_eq!(timeline.steps[1].occurred_at, None);
+        // 発送は最初の報告日時を表示
+        assert_eq!(
+            timeline.steps[2].occurred_at,
+            Some(now + TimeDelta::hours(5))
+        );
+    }
+
+    #[test]
+    fn test_public_view_masks_address_and_estimates_delivery() {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1000)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1500043".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "道玄坂1-1-1".to_string(),
+                    Some("サンプルビル 3F".to_string()),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        order.confirm().unwrap();
+        let confirmed_at = order.confirmed_at().unwrap();
+        let policy = SlaPolicy::default();
+
+        let view = PublicTrackingView::build(&order, &policy);
+        assert_eq!(view.status, OrderStatus::Confirmed);
+        assert_eq!(
+            view.shipping_address,
+            Some(MaskedAddress {
+                postal_code: "150-****".to_string(),
+                prefecture: "東京都".to_string(),
+                city: "渋谷区".to_string(),
+            })
+        );
+        assert_eq!(
+            view.estimated_delivery_at,
+            Some(confirmed_at + policy.ship_within + policy.deliver_within)
+        );
+
+        // 発送後は発送日時から見積もり、配達完了後は見積もらない
+        order.mark_as_shipped().unwrap();
+        let shipped_at = order.shipped_at().unwrap();
+        assert_eq!(
+            PublicTrackingView::build(&order, &policy).estimated_delivery_at,
+            Some(shipped_at + policy.deliver_within)
+        );
+        order.mark_as_delivered().unwrap();
+        assert_eq!(
+            PublicTrackingView::build(&order, &policy).estimated_delivery_at,
+            None
+        );
+    }
+
+    #[test]
+    fn test_empty_timeline() {
+        let timeline = TrackingTimeline::build(OrderId::new(), &[]);
+
+        assert_eq!(timeline.current_stage, None);
+        assert!(timeline.steps.iter().all(|step| !step.completed));
+        assert_eq!(timeline.steps[0].label, "ご注文受付");
+    }
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 検証で違反した制約の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Constraint {
+    /// 必須（空にできない）
+    Required,
+    /// 書式（例: 郵便番号は7桁の数字）
+    Pattern,
+    /// 最小値（例: 数量は1以上）
+    Min,
+    /// 最大件数
+    MaxItems,
+    /// 重複不可
+    Unique,
+    /// 参照先が存在する
+    Exists,
+    /// 許可された値のいずれか
+    OneOf,
+    /// 他の項目や注文の内容と矛盾しない
+    Consistent,
+}
+
+/// 検証に失敗した項目
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldViolation {
+    /// 項目のパス（例: "postal_code"、"attributes[1].key"）
+    pub field: String,
+    pub constraint: Constraint,
+    /// 実際の値（値がない場合はNone）
+    pub actual: Option<String>,
+    pub message: String,
+}
+
+impl FieldViolation {
+    pub fn new(
+        field: impl Into<String>,
+        constraint: Constraint,
+        actual: Option<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            constraint,
+            actual,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// 検証エラー（違反した項目の一覧）
+/// 値オブジェクト・集約の検証で、どの項目がどの制約に違反したかを呼び出し側に伝える
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationErrors {
+    violations: Vec<FieldViolation>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 違反を追加
+    pub fn add(&mut self, violation: FieldViolation) {
+        self.violations.push(violation);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn violations(&self) -> &[FieldViolation] {
+        &self.violations
+    }
+
+    /// 違反がない場合はOk、ある場合はすべての違反を含むエラー
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl From<FieldViolation> for ValidationErrors {
+    fn from(violation: FieldViolation) -> Self {
+        Self {
+            violations: vec![violation],
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.violations.iter().map(ToString::to_string).collect();
+        write!(f, "{}", messages.join("; "))
+    }
+}
@@ -0,0 +1,83 @@
+use crate::domain::model::{BookId, CustomerId, OrderId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 順番待ちの登録
+/// 順番待ちが有効な書籍の在庫を超えた注文を、書籍ごとに先着順で並べる
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WaitlistEntry {
+    pub order_id: OrderId,
+    pub customer_id: CustomerId,
+    pub book_id: BookId,
+    /// 待っている数量（注文明細の数量）
+    pub quantity: u32,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl WaitlistEntry {
+    pub fn new(order_id: OrderId, customer_id: CustomerId, book_id: BookId, quantity: u32) -> Self {
+        Self {
+            order_id,
+            customer_id,
+            book_id,
+            quantity,
+            joined_at: Utc::now(),
+        }
+    }
+}
+
+/// 書籍ごとの順番待ちの位置（顧客向けの照会とWaitlistJoinedイベントで使用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WaitlistPosition {
+    pub book_id: BookId,
+    /// 先頭を1とする順番
+    pub position: u32,
+    pub quantity: u32,
+    /// 前に並んでいる注文が待っている数量の合計
+    pub quantity_ahead: u32,
+}
+
+/// 書籍の順番待ち（先着順に並んだ登録）のなかで注文の位置を求める
+/// 注文が並んでいない場合はNoneを返す
+///
+/// # Arguments
+/// * `queue` - 書籍の順番待ち（先着順）
+/// * `order_id` - 位置を求める注文
+pub fn position_in_queue(queue: &[WaitlistEntry], order_id: OrderId) -> Option<WaitlistPosition> {
+    let index = queue.iter().position(|entry| entry.order_id == order_id)?;
+    let entry = &queue[index];
+    Some(WaitlistPosition {
+        book_id: entry.book_id,
+        position: index as u32 + 1,
+        quantity: entry.quantity,
+        quantity_ahead: queue[..index].iter().map(|ahead| ahead.quantity).sum(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_in_queue_counts_orders_ahead() {
+        let book_id = BookId::new();
+        let queue: Vec<WaitlistEntry> = [2, 1, 3]
+            .into_iter()
+            .map(|quantity| {
+                WaitlistEntry::new(OrderId::new(), CustomerId::new(), book_id, quantity)
+            })
+            .collect();
+
+        let position = position_in_queue(&queue, queue[2].order_id).unwrap();
+        assert_eq!(position.position, 3);
+        assert_eq!(position.quantity, 3);
+        assert_eq!(position.quantity_ahead, 3);
+        assert_eq!(
+            position_in_queue(&queue, queue[0].order_id)
+                .unwrap()
+                .quantity_ahead,
+            0
+        );
+        assert!(position_in_queue(&queue, OrderId::new()).is_none());
+    }
+}
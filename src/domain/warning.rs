@@ -0,0 +1,65 @@
+use crate::domain::model::{BookId, Money};
+use serde::{Deserialize, Serialize};
+
+/// コマンドを止めずに利用者へ伝える注意事項
+/// エラーと異なり処理は成功しており、APIのレスポンスで呼び出し元に返す
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainWarning {
+    /// 警告の種類（例: "REMOTE_SHIPPING_DESTINATION"）
+    pub code: String,
+    pub message: String,
+}
+
+impl DomainWarning {
+    fn new(code: &str, message: String) -> Self {
+        Self {
+            code: code.to_string(),
+            message,
+        }
+    }
+
+    /// 注文済みの書籍を追加したため、既存の明細に数量を合算した
+    pub fn duplicate_book_merged(book_id: BookId, total_quantity: u32) -> Self {
+        Self::new(
+            "DUPLICATE_BOOK_MERGED",
+            format!(
+                "書籍 {} は既に注文に含まれているため、数量を合算しました（合計: {}）",
+                book_id, total_quantity
+            ),
+        )
+    }
+
+    /// 確定後に配送先を変更した（出荷準備のやり直しが必要になる場合がある）
+    pub fn destination_changed_after_confirmation() -> Self {
+        Self::new(
+            "DESTINATION_CHANGED_AFTER_CONFIRMATION",
+            "確定済みの注文の配送先を変更しました。出荷準備中の場合は発送が遅れることがあります"
+                .to_string(),
+        )
+    }
+
+    /// 倉庫から離れた地域への配送のため、追加の配送料がかかる
+    pub fn remote_shipping_destination(prefecture: &str, surcharge: Money) -> Self {
+        Self::new(
+            "REMOTE_SHIPPING_DESTINATION",
+            format!(
+                "{}は倉庫から離れた地域のため、配送料に{}円が加算されます",
+                prefecture,
+                surcharge.amount()
+            ),
+        )
+    }
+
+    /// 注文後に変更されたカタログの価格で明細の単価を更新した
+    pub fn line_repriced(book_id: BookId, previous_price: Money, current_price: Money) -> Self {
+        Self::new(
+            "LINE_REPRICED",
+            format!(
+                "書籍 {} の価格が変更されたため、単価を{}円から{}円に更新しました",
+                book_id,
+                previous_price.amount(),
+                current_price.amount()
+            ),
+        )
+    }
+}
@@ -1,193 +1,176 @@
-mod adapter;
-mod application;
-mod domain;
-
-use adapter::driven::{ConsoleLogger, EventBusConfig, InMemoryEventBus, MySqlInventoryRepository, MySqlOrderRepository};
-use adapter::driver::rest_api::{create_router, AppStateInner};
-use adapter::{DatabaseConfig, DatabaseMigration};
-use application::service::{InventoryApplicationService, OrderApplicationService};
-use domain::port::Logger;
-
-use sqlx::mysql::MySqlPoolOptions;
+use bookstore_order_management::adapter::bootstrap::startup_failure;
+use bookstore_order_management::adapter::driven::TracingLogger;
+use bookstore_order_management::adapter::driver::grpc;
+use bookstore_order_management::adapter::telemetry::init_telemetry;
+use bookstore_order_management::adapter::{build_application, AppConfig, DatabaseMigration, GrpcConfig, OrderNumberConfig, Repositories, StartupDiagnostics, StorageBackend};
+use bookstore_order_management::domain::port::Logger;
+
+use std::net::SocketAddr;
 use std::sync::Arc;
-use tower_http::cors::CorsLayer;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // ロガーを作成
-    let logger: Arc<dyn Logger> = Arc::new(ConsoleLogger::new());
-
-    logger.debug("Main", "=== 書店注文管理システム REST API ===", None, None);
-    logger.debug("Main", "ドメイン駆動設計サンプルプロジェクト", None, None);
-
     // .envファイルから環境変数を読み込む
     dotenvy::dotenv().ok();
 
-    // データベース設定を読み込む
-    let config = DatabaseConfig::from_env()?;
+    // アプリケーションの設定を読み込む（プロファイルのデフォルト値 → 設定ファイル → 環境変数）
+    let app_config = AppConfig::load()?;
+
+    // テレメトリーを初期化（ログ・スパンを標準出力に出力し、otelフィーチャー有効時はOTLPへもエクスポート）
+    let telemetry = init_telemetry(&app_config.log_filter)?;
+
+    // ロガーを作成（tracingのイベントとして出力し、実行中のスパンにひも付ける）
+    let logger: Arc<dyn Logger> = Arc::new(TracingLogger::new());
+
+    logger.debug("Main", "=== 書店注文管理システム REST API ===", None, None);
+    logger.debug("Main", "ドメイン駆動設計サンプルプロジェクト", None, None);
     logger.debug(
         "Main",
-        &format!("データベース設定を読み込みました: {}:{}", config.host, config.port),
+        &format!(
+            "プロファイル: {}（設定ファイル: {}）",
+            app_config.profile.as_str(),
+            app_config
+                .config_file
+                .as_ref()
+                .map_or_else(|| "なし".to_string(), |path| path.display().to_string())
+        ),
         None,
         None,
     );
 
-    // 接続プールを作成
-    let pool = MySqlPoolOptions::new()
-        .max_connections(config.max_connections)
-        .connect(&config.connection_string())
-        .await?;
-    logger.debug("Main", "データベース接続プールを作成しました", None, None);
-
-    // マイグレーションを実行
-    let migration = DatabaseMigration::new(pool.clone(), logger.clone());
-    migration.run().await?;
-    logger.debug("Main", "データベースマイグレーションを実行しました", None, None);
-
-    // MySQLリポジトリを作成
-    let order_repository = Arc::new(MySqlOrderRepository::new(pool.clone()));
-    let inventory_repository = Arc::new(MySqlInventoryRepository::new(pool.clone()));
-
-    // イベントバスを作成
-    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
-
-    // イベントハンドラーを作成して登録
-    let inventory_handler = crate::domain::handler::InventoryReservationHandler::new(
-        inventory_repository.clone(),
-        order_repository.clone(),
-        event_bus.clone(),
-        logger.clone(),
-    );
-    let _shipping_handler = crate::domain::handler::ShippingHandler::new(
-        order_repository.clone(),
-        event_bus.clone(),
-        logger.clone(),
-    );
-    let _delivery_handler = crate::domain::handler::DeliveryHandler::new(
-        order_repository.clone(),
-        event_bus.clone(),
-        logger.clone(),
-    );
-    let notification_handler = crate::domain::handler::NotificationHandler::new(logger.clone());
-    let consistency_verifier = crate::domain::handler::EventualConsistencyVerifier::new(
-        order_repository.clone(),
-        inventory_repository.clone(),
-        logger.clone(),
-    );
-
-    // 補償ハンドラーを作成
-    let inventory_compensation_handler =
-        crate::domain::handler::InventoryReservationFailureCompensationHandler::new(
-            order_repository.clone(),
-            event_bus.clone(),
-            logger.clone(),
-        );
-    let shipping_compensation_handler =
-        crate::domain::handler::ShippingFailureCompensationHandler::new(
-            inventory_repository.clone(),
-            order_repository.clone(),
-            event_bus.clone(),
-            logger.clone(),
-        );
-    let delivery_compensation_handler =
-        crate::domain::handler::DeliveryFailureCompensationHandler::new(
-            order_repository.clone(),
-            event_bus.clone(),
-            logger.clone(),
-        );
-    let saga_coordinator =
-        crate::domain::handler::SagaCompensationCoordinator::new(event_bus.clone(), logger.clone());
-    let compensation_completion_handler =
-        crate::domain::handler::CompensationCompletionHandler::new(logger.clone());
-
-    // イベントハンドラーをイベントバスに登録
-    // 注文確定時は在庫予約のみ自動実行（発送・配達は手動操作）
-    event_bus
-        .subscribe_order_confirmed(inventory_handler)
-        .await?;
-
-    // 通知ハンドラーを各イベントに登録（並行処理で通知送信）
-    event_bus
-        .subscribe_order_confirmed(notification_handler.clone())
-        .await?;
-    event_bus
-        .subscribe_order_shipped(notification_handler.clone())
-        .await?;
-    event_bus
-        .subscribe_order_delivered(notification_handler.clone())
-        .await?;
-    event_bus
-        .subscribe_order_cancelled(notification_handler)
-        .await?;
-
-    // 整合性検証ハンドラーを登録（並行処理で検証実行）
-    event_bus
-        .subscribe_order_confirmed(consistency_verifier.clone())
-        .await?;
-    event_bus
-        .subscribe_order_delivered(consistency_verifier)
-        .await?;
-
-    // 補償ハンドラーを登録
-    event_bus
-        .subscribe_inventory_reservation_failed(inventory_compensation_handler)
-        .await?;
-    event_bus
-        .subscribe_shipping_failed(shipping_compensation_handler)
-        .await?;
-    event_bus
-        .subscribe_delivery_failed(delivery_compensation_handler)
-        .await?;
-    event_bus
-        .subscribe_saga_compensation_started(saga_coordinator)
-        .await?;
-    event_bus
-        .subscribe_saga_compensation_completed(compensation_completion_handler)
-        .await?;
-
-    logger.debug("Main", "イベントハンドラーを登録しました", None, None);
-    logger.debug("Main", "注文フロー:", None, None);
-    logger.debug("Main", "  1. 注文確定 → 在庫予約（自動）+ 通知送信", None, None);
-    logger.debug("Main", "  2. 注文発送 → 手動操作（POST /orders/:id/ship）", None, None);
-    logger.debug("Main", "  3. 配達完了 → 手動操作（POST /orders/:id/deliver）", None, None);
-
-    // アプリケーションサービスを作成（Arcを外して渡す）
-    let order_service =
-        OrderApplicationService::new(MySqlOrderRepository::new(pool.clone()), event_bus.clone());
-
-    // 在庫サービスを作成
-    let inventory_service = InventoryApplicationService::new(inventory_repository.clone());
-
-    // アプリケーション状態を作成
-    let app_state = AppStateInner {
-        order_service: Arc::new(order_service),
-        inventory_service: Arc::new(inventory_service),
+    if telemetry.is_enabled() {
+        logger.debug("Main", "OpenTelemetryエクスポーターを初期化しました", None, None);
+    }
+
+    // 起動時の診断: すべての設定を検証し、誤りがあれば原因をまとめてログに出力して起動を中止する
+    let mut diagnostics = StartupDiagnostics::new();
+    diagnostics.validate_configuration();
+    if diagnostics.report().has_failures() {
+        return Err(startup_failure(diagnostics.report(), logger.as_ref()));
+    }
+
+    // 永続化先を選ぶ（--sqlite を指定した場合は外部のデータベースを用意せずにSQLiteで動かす）
+    // DATABASE_INVARIANT_CHECKSが有効な場合は保存・読み込み時に集約の不変条件を検証する
+    let storage = StorageBackend::from_args(std::env::args().skip(1))?;
+    let order_number_config = OrderNumberConfig::from_env()?;
+    let repositories = match storage {
+        StorageBackend::MySql => {
+            // データベース設定を読み込む
+            let config = app_config.database.clone();
+            logger.debug(
+                "Main",
+                &format!("データベース設定を読み込みました: {}:{}", config.host, config.port),
+                None,
+                None,
+            );
+
+            // 接続プールを作成
+            let Some(pool) = diagnostics.connect_database(&config).await else {
+                return Err(startup_failure(diagnostics.report(), logger.as_ref()));
+            };
+            logger.debug("Main", "データベース接続プールを作成しました", None, None);
+
+            // マイグレーションを実行
+            let migration = DatabaseMigration::new(pool.clone(), logger.clone());
+            diagnostics.run_migrations(&migration).await;
+            if diagnostics.report().has_failures() {
+                return Err(startup_failure(diagnostics.report(), logger.as_ref()));
+            }
+            logger.debug("Main", "データベースマイグレーションを実行しました", None, None);
+
+            Repositories::mysql(pool, config.invariant_checks, order_number_config)
+        }
+        StorageBackend::Sqlite { url } => {
+            let Some(pool) = diagnostics.connect_sqlite(&url).await else {
+                return Err(startup_failure(diagnostics.report(), logger.as_ref()));
+            };
+            logger.info(
+                "Main",
+                &format!(
+                    "SQLiteモードで起動します: {}（注文・在庫・書籍カタログ以外はメモリに保持し、再起動で失われます）",
+                    url
+                ),
+                None,
+                None,
+            );
+            Repositories::sqlite(
+                pool,
+                app_config.database.invariant_checks,
+                order_number_config,
+            )
+        }
     };
 
-    // REST APIルーターを作成
-    let app = create_router()
-        .layer(CorsLayer::permissive())
-        .with_state(app_state);
+    // イベントハンドラー・バックグラウンドタスク・アプリケーションサービス・REST APIのルーターを組み立てる
+    let application =
+        build_application(&app_config, repositories, logger.clone(), diagnostics).await?;
+
+    // gRPCサーバー（GRPC_ENABLED=trueの場合のみ）
+    // REST APIと同じアプリケーションサービスを共有する
+    let grpc_config = GrpcConfig::from_env()?;
+    let grpc_server = grpc_config.enabled.then(|| {
+        grpc::serve(
+            SocketAddr::from(([0, 0, 0, 0], grpc_config.port)),
+            application.state.order_service.clone(),
+            application.state.inventory_service.clone(),
+            application.authenticator.clone(),
+        )
+    });
 
     // サーバーを起動
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
-    logger.debug("Main", "REST APIサーバーが起動しました: http://localhost:3000", None, None);
-    logger.debug("Main", "ヘルスチェック: GET http://localhost:3000/health", None, None);
+    let listener = tokio::net::TcpListener::bind(app_config.bind_address).await?;
+    let local_addr = listener.local_addr()?;
+    logger.debug("Main", &format!("REST APIサーバーが起動しました: http://{}", local_addr), None, None);
+    logger.debug("Main", &format!("ヘルスチェック: GET http://{}/health", local_addr), None, None);
     logger.debug("Main", "API仕様:", None, None);
+    logger.debug("Main", "  GET  /health/ready - 準備状態（バックグラウンドタスクの生存状況）", None, None);
     logger.debug("Main", "  POST /orders - 注文作成", None, None);
     logger.debug("Main", "  GET  /orders - 注文一覧取得", None, None);
     logger.debug("Main", "  GET  /orders/:id - 注文詳細取得", None, None);
+    logger.debug("Main", "  GET  /orders/:id/events - 注文のイベントのライブストリーム（SSE）", None, None);
+    logger.debug("Main", "  GET  /ws/notifications - 顧客への通知（WebSocket）", None, None);
     logger.debug("Main", "  POST /orders/:id/books - 本を注文に追加", None, None);
     logger.debug("Main", "  PUT  /orders/:id/shipping-address - 配送先住所設定", None, None);
+    logger.debug("Main", "  POST /orders/:id/checkout-hold - チェックアウト中の在庫の仮押さえ", None, None);
     logger.debug("Main", "  POST /orders/:id/confirm - 注文確定", None, None);
+    logger.debug("Main", "  POST /orders/:id/reprice - 明細の単価をカタログの価格に更新", None, None);
     logger.debug("Main", "  POST /orders/:id/cancel - 注文キャンセル", None, None);
     logger.debug("Main", "  POST /orders/:id/ship - 注文発送", None, None);
     logger.debug("Main", "  POST /orders/:id/deliver - 注文配達完了", None, None);
+    logger.debug("Main", "  PUT  /customers/:id/preferred-language - 顧客の通知の言語設定（ja・en）", None, None);
+    logger.debug("Main", "  GET  /customers/:id/export - 顧客のデータのエクスポートの開始", None, None);
+    logger.debug("Main", "  GET  /customers/:id/export/:job_id - 顧客のデータのエクスポートの状態", None, None);
+    logger.debug("Main", "  GET  /customers/:id/export/:job_id/download - エクスポートしたアーカイブ（ZIP）のダウンロード", None, None);
     logger.debug("Main", "  POST /inventory - 在庫作成（テスト用）", None, None);
     logger.debug("Main", "  GET  /inventory - 在庫一覧取得", None, None);
     logger.debug("Main", "  GET  /inventory/:book_id - 在庫詳細取得", None, None);
-
-    axum::serve(listener, app).await?;
+    logger.debug("Main", "  PUT  /catalog/books/:book_id/price - 書籍の販売価格設定", None, None);
+    logger.debug("Main", "  GET  /metrics - ビジネスメトリクス取得", None, None);
+    logger.debug("Main", "  GET  /admin/sagas/metrics - サーガの進行状況の集計", None, None);
+    logger.debug("Main", "  GET  /admin/diagnostics - 起動時の診断レポート", None, None);
+    logger.debug("Main", "  GET  /admin/event-bus - イベントの配信全体の状態", None, None);
+    logger.debug("Main", "  POST /admin/event-bus/pause - イベントの配信全体の一時停止（デプロイ前）", None, None);
+    logger.debug("Main", "  POST /admin/event-bus/resume - イベントの配信全体の再開", None, None);
+    logger.debug("Main", "  GET  /admin/event-traces - イベントの配信のトレース（EVENT_TRACE_CAPACITY設定時）", None, None);
+    logger.debug("Main", "  GET  /admin/ui - 管理画面（注文・デッドレター・サーガ・在庫）", None, None);
+    logger.debug("Main", "  POST /admin/legacy-orders/import - 旧システムの注文の取り込み", None, None);
+    logger.debug("Main", "  POST /admin/exports/events - イベントのエクスポート", None, None);
+
+    match grpc_server {
+        Some(grpc_server) => {
+            logger.info(
+                "Main",
+                &format!("gRPCサーバーが起動しました: localhost:{}", grpc_config.port),
+                None,
+                None,
+            );
+            tokio::try_join!(
+                async { axum::serve(listener, application.router).await.map_err(Box::<dyn std::error::Error>::from) },
+                async { grpc_server.await.map_err(Box::<dyn std::error::Error>::from) },
+            )?;
+        }
+        None => axum::serve(listener, application.router).await?,
+    }
 
     Ok(())
 }
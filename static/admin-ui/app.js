@@ -0,0 +1,227 @@
+// 管理画面のスクリプト
+// 既存の管理用APIを呼び出して結果を表に表示する（ビルド手順なし、依存ライブラリなし）
+"use strict";
+
+const errorBox = document.getElementById("error");
+
+// APIを呼び出してJSONを返す（失敗した場合はApiErrorのメッセージを表示して例外を投げる）
+async function fetchJson(path, method = "GET") {
+  errorBox.hidden = true;
+  const response = await fetch(path, { method, headers: { Accept: "application/json" } });
+  const body = await response.json().catch(() => null);
+  if (!response.ok) {
+    const message = body && body.error ? `${body.error}（${body.code}）` : response.statusText;
+    errorBox.textContent = `${path}: ${message}`;
+    errorBox.hidden = false;
+    throw new Error(message);
+  }
+  return body;
+}
+
+// クエリ文字列を作成する（空の値は送らない）
+function query(form) {
+  const params = new URLSearchParams();
+  for (const [name, value] of new FormData(form)) {
+    if (value !== "") {
+      params.append(name, value);
+    }
+  }
+  const text = params.toString();
+  return text ? `?${text}` : "";
+}
+
+// 表の行を作成する（値はtextContentで設定し、HTMLとして解釈しない）
+function row(cells) {
+  const tr = document.createElement("tr");
+  for (const cell of cells) {
+    const td = document.createElement("td");
+    if (cell instanceof Node) {
+      td.appendChild(cell);
+    } else {
+      td.textContent = cell === null || cell === undefined ? "" : String(cell);
+      if (typeof cell === "number") {
+        td.className = "number";
+      }
+    }
+    tr.appendChild(td);
+  }
+  return tr;
+}
+
+function fillTable(id, rows) {
+  document.getElementById(id).replaceChildren(...rows);
+}
+
+function formatDate(value) {
+  return value ? new Date(value).toLocaleString() : "";
+}
+
+// 注文
+async function loadOrders() {
+  const form = document.getElementById("orders-filter");
+  const orders = await fetchJson(`/orders${query(form)}`);
+  fillTable(
+    "orders-rows",
+    orders.map((order) => {
+      const link = document.createElement("a");
+      link.textContent = order.order_id;
+      link.addEventListener("click", () => loadOrderDetail(order.order_id));
+      return row([
+        link,
+        order.customer_id,
+        order.status,
+        `${order.total_amount} ${order.total_currency}`,
+        formatDate(order.created_at),
+        order.lock ? order.lock.locked_by : "",
+      ]);
+    }),
+  );
+}
+
+async function loadOrderDetail(orderId) {
+  const order = await fetchJson(`/orders/${encodeURIComponent(orderId)}`);
+  document.getElementById("order-detail").textContent = JSON.stringify(order, null, 2);
+}
+
+// デッドレター
+async function loadDeadLetters() {
+  const form = document.getElementById("dead-letters-filter");
+  const [summary, deadLetters] = await Promise.all([
+    fetchJson("/admin/dead-letters/summary"),
+    fetchJson(`/admin/dead-letters${query(form)}`),
+  ]);
+  fillTable(
+    "dead-letter-groups",
+    summary.groups.map((group) =>
+      row([
+        group.handler_name,
+        group.event_types.join(", "),
+        group.count,
+        formatDate(group.last_seen),
+        group.sample_error,
+      ]),
+    ),
+  );
+  fillTable(
+    "dead-letter-rows",
+    deadLetters.map((deadLetter) =>
+      row([
+        formatDate(deadLetter.dead_lettered_at),
+        deadLetter.handler_name,
+        deadLetter.event_type,
+        deadLetter.context.aggregate_id,
+        deadLetter.attempt_count,
+        deadLetter.retryable ? "はい" : "いいえ",
+        deadLetter.error,
+      ]),
+    ),
+  );
+}
+
+// 送信に失敗した通知
+async function loadNotifications() {
+  const form = document.getElementById("notifications-filter");
+  const notifications = await fetchJson(`/admin/notifications${query(form)}`);
+  fillTable(
+    "notification-rows",
+    notifications.map((notification) => {
+      const resend = document.createElement("button");
+      resend.type = "button";
+      resend.textContent = "再送";
+      resend.hidden = notification.status === "Delivered";
+      resend.addEventListener("click", () =>
+        fetchJson(`/admin/notifications/${encodeURIComponent(notification.notification_id)}/resend`, "POST")
+          .then(loadNotifications)
+          .catch(() => {}),
+      );
+      return row([
+        formatDate(notification.updated_at),
+        notification.channel,
+        notification.order_id,
+        notification.event_type,
+        notification.attempts,
+        formatDate(notification.next_attempt_at),
+        notification.last_error,
+        resend,
+      ]);
+    }),
+  );
+}
+
+// サーガ
+async function loadSagas() {
+  const metrics = await fetchJson("/admin/sagas/metrics");
+  fillTable(
+    "active-sagas",
+    Object.entries(metrics.active_sagas).map(([step, count]) => row([step, count])),
+  );
+  fillTable(
+    "step-durations",
+    Object.entries(metrics.average_step_durations).map(([step, duration]) =>
+      row([step, duration.completed, duration.average_seconds]),
+    ),
+  );
+  fillTable(
+    "compensations",
+    Object.entries(metrics.compensations_by_failed_step).map(([step, count]) => row([step, count])),
+  );
+  document.getElementById("oldest-stuck-saga").textContent = metrics.oldest_stuck_saga
+    ? JSON.stringify(metrics.oldest_stuck_saga, null, 2)
+    : "進行中のサーガはありません";
+}
+
+// 在庫
+async function loadInventory() {
+  const form = document.getElementById("inventory-filter");
+  const inventories = await fetchJson(`/inventory${query(form)}`);
+  fillTable(
+    "inventory-rows",
+    inventories.map((inventory) =>
+      row([
+        inventory.book_id,
+        inventory.quantity_on_hand,
+        inventory.release_date,
+        inventory.frozen ? "はい" : "",
+        inventory.waitlist_enabled ? "有効" : "",
+      ]),
+    ),
+  );
+}
+
+const loaders = {
+  orders: loadOrders,
+  "dead-letters": loadDeadLetters,
+  notifications: loadNotifications,
+  sagas: loadSagas,
+  inventory: loadInventory,
+};
+
+// タブの切り替え（表示したタブのデータを読み込む）
+function showTab(name) {
+  for (const button of document.querySelectorAll("nav button")) {
+    button.classList.toggle("active", button.dataset.tab === name);
+  }
+  for (const section of document.querySelectorAll("main section")) {
+    section.hidden = section.id !== name;
+  }
+  loaders[name]().catch(() => {});
+}
+
+for (const button of document.querySelectorAll("nav button")) {
+  button.addEventListener("click", () => showTab(button.dataset.tab));
+}
+
+for (const [formId, loader] of [
+  ["orders-filter", loadOrders],
+  ["dead-letters-filter", loadDeadLetters],
+  ["notifications-filter", loadNotifications],
+  ["inventory-filter", loadInventory],
+]) {
+  document.getElementById(formId).addEventListener("submit", (event) => {
+    event.preventDefault();
+    loader().catch(() => {});
+  });
+}
+document.getElementById("sagas-reload").addEventListener("click", () => loadSagas().catch(() => {}));
+
+showTab("orders");
@@ -0,0 +1,104 @@
+//! MySQLを使うテストの共通ヘルパー
+
+use bookstore_order_management::adapter::driven::ConsoleLogger;
+use bookstore_order_management::adapter::{DatabaseConfig, DatabaseMigration};
+use sqlx::mysql::MySqlPoolOptions;
+use sqlx::{Connection, MySql, MySqlConnection, Pool};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// テストごとに作成するデータベース名の接頭辞
+const TEST_DATABASE_PREFIX: &str = "bookstore_test_";
+
+/// テストごとに分離したデータベースを用意するフィクスチャ
+///
+/// リポジトリはコネクションプールを直接使い、マイグレーションのDDLは暗黙にコミットされるため、
+/// トランザクションのロールバックではなくテストごとにデータベースを作成して分離する。
+/// データベースはDrop時に削除するので、複数のテストを並列に実行しても互いのデータを上書きしない。
+///
+/// 接続先は `DATABASE_HOST` / `DATABASE_PORT`、データベースの作成・削除には
+/// `DATABASE_ADMIN_USER` / `DATABASE_ADMIN_PASSWORD`（既定はdocker composeのroot）を使用する。
+pub struct DbTestContext {
+    pool: Pool<MySql>,
+    admin_url: String,
+    database: String,
+}
+
+impl DbTestContext {
+    /// 空のデータベースを作成し、マイグレーションを適用する
+    pub async fn new() -> Self {
+        let config = DatabaseConfig::from_env().expect("DATABASE_*の設定が不正です");
+        let admin_user =
+            std::env::var("DATABASE_ADMIN_USER").unwrap_or_else(|_| "root".to_string());
+        let admin_password =
+            std::env::var("DATABASE_ADMIN_PASSWORD").unwrap_or_else(|_| "rootpassword".to_string());
+        let admin_url = format!(
+            "mysql://{}:{}@{}:{}",
+            admin_user, admin_password, config.host, config.port
+        );
+        let database = format!("{}{}", TEST_DATABASE_PREFIX, Uuid::new_v4().simple());
+
+        let mut admin = MySqlConnection::connect(&admin_url)
+            .await
+            .expect("MySQLに接続できません（docker compose up -d で起動してください）");
+        sqlx::query(&format!(
+            "CREATE DATABASE `{}` CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            database
+        ))
+        .execute(&mut admin)
+        .await
+        .expect("テスト用データベースを作成できません");
+        admin.close().await.ok();
+
+        let pool = MySqlPoolOptions::new()
+            .max_connections(2)
+            .connect(&format!("{}/{}", admin_url, database))
+            .await
+            .expect("テスト用データベースに接続できません");
+        DatabaseMigration::new(pool.clone(), Arc::new(ConsoleLogger::new()))
+            .run()
+            .await
+            .expect("マイグレーションに失敗しました");
+
+        Self {
+            pool,
+            admin_url,
+            database,
+        }
+    }
+
+    /// テスト用データベースへのコネクションプール
+    pub fn pool(&self) -> Pool<MySql> {
+        self.pool.clone()
+    }
+}
+
+impl Drop for DbTestContext {
+    /// テスト用データベースを削除する
+    /// Dropは非同期にできず、テストのランタイムは終了処理中の場合があるため、別スレッドの専用ランタイムで実行する
+    fn drop(&mut self) {
+        let admin_url = self.admin_url.clone();
+        let database = self.database.clone();
+        let result = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("ランタイムを作成できません");
+            runtime.block_on(async move {
+                let mut admin = MySqlConnection::connect(&admin_url).await?;
+                sqlx::query(&format!("DROP DATABASE IF EXISTS `{}`", database))
+                    .execute(&mut admin)
+                    .await?;
+                admin.close().await
+            })
+        })
+        .join();
+
+        if !matches!(result, Ok(Ok(()))) {
+            eprintln!(
+                "テスト用データベース {} を削除できませんでした",
+                self.database
+            );
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! 起動中のアプリケーションとMySQLに対するE2Eスモークテスト
+//!
+//! docker composeでMySQLを、`cargo run`（またはデプロイ済みのコンテナ）でアプリケーションを
+//! 起動した状態で実行する。デプロイのゲートとして、公開エンドポイントを型付きクライアントで
+//! 呼び出して注文のライフサイクルを一通り進め、最後にデータベースの状態を検証する。
+//!
+//! ```bash
+//! E2E_BASE_URL=http://localhost:3000 cargo test --features e2e --test e2e_smoke_tests
+//! ```
+//!
+//! データベースの接続先はアプリケーションと同じ `DATABASE_*` 環境変数で指定する。
+
+use bookstore_order_management::adapter::driver::api_client::ApiClient;
+use bookstore_order_management::adapter::driver::request_dto::{
+    AddBookRequest, CreateInventoryRequest, CreateOrderRequest, RegisterCustomerRequest,
+    SetShippingAddressRequest,
+};
+use bookstore_order_management::adapter::DatabaseConfig;
+use bookstore_order_management::domain::model::{FulfillmentType, SalesChannel};
+use sqlx::mysql::MySqlPoolOptions;
+use sqlx::{MySql, Pool, Row};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// 非同期に処理されるイベントの反映を待つ最大時間
+const SETTLE_TIMEOUT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+fn client() -> ApiClient {
+    let base_url =
+        std::env::var("E2E_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
+    ApiClient::new(base_url)
+}
+
+async fn pool() -> Pool<MySql> {
+    let config = DatabaseConfig::from_env().expect("DATABASE_*の設定が不正です");
+    MySqlPoolOptions::new()
+        .max_connections(2)
+        .connect(&config.connection_string())
+        .await
+        .expect("MySQLに接続できません（docker compose up -d で起動してください）")
+}
+
+/// 在庫を作成し、その書籍を指定数量注文して配送先住所を設定した注文を作る
+async fn prepare_order(client: &ApiClient, stock: u32, quantity: u32) -> (Uuid, Uuid) {
+    let book_id = Uuid::new_v4();
+    client
+        .create_inventory(&CreateInventoryRequest {
+            book_id,
+            quantity: stock,
+            release_date: None,
+            waitlist_enabled: false,
+        })
+        .await
+        .unwrap();
+
+    // 注文は登録済みの顧客を参照する必要がある
+    let customer = client
+        .register_customer(&RegisterCustomerRequest {
+            name: "E2E 顧客".to_string(),
+            email: format!("e2e-{}@example.com", Uuid::new_v4()),
+            default_shipping_address: None,
+        })
+        .await
+        .unwrap();
+    let customer_id = Uuid::parse_str(&customer.customer_id).unwrap();
+
+    let order_id = client
+        .create_order(&CreateOrderRequest {
+            customer_id: Some(customer_id),
+            sales_channel: SalesChannel::Online,
+        })
+        .await
+        .unwrap()
+        .order_id;
+    client
+        .add_book(
+            order_id,
+            &AddBookRequest {
+                book_id,
+                quantity,
+                unit_price: 1500,
+                fulfillment_type: FulfillmentType::Physical,
+                spec: None,
+            },
+        )
+        .await
+        .unwrap();
+    client
+        .set_shipping_address(
+            order_id,
+            &SetShippingAddressRequest {
+                postal_code: "1500001".to_string(),
+                prefecture: "東京都".to_string(),
+                city: "渋谷区".to_string(),
+                address_line1: "神宮前1-1-1".to_string(),
+                address_line2: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    (order_id, book_id)
+}
+
+/// 注文が指定のステータスになるまで待つ
+async fn wait_for_status(client: &ApiClient, order_id: Uuid, expected: &str) {
+    let deadline = tokio::time::Instant::now() + SETTLE_TIMEOUT;
+    loop {
+        let status = client.get_order(order_id).await.unwrap().status;
+        if status == expected {
+            return;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "注文 {} が {} になりませんでした（現在: {}）",
+            order_id,
+            expected,
+            status
+        );
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// 在庫数が指定の数量になるまで待つ
+async fn wait_for_stock(client: &ApiClient, book_id: Uuid, expected: u32) {
+    let deadline = tokio::time::Instant::now() + SETTLE_TIMEOUT;
+    loop {
+        let quantity = client
+            .get_inventory(book_id)
+            .await
+            .unwrap()
+            .quantity_on_hand;
+        if quantity == expected {
+            return;
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "書籍 {} の在庫数が {} になりませんでした（現在: {}）",
+            book_id,
+            expected,
+            quantity
+        );
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+async fn order_row(pool: &Pool<MySql>, order_id: Uuid) -> sqlx::mysql::MySqlRow {
+    sqlx::query(
+        "SELECT status, confirmed_at IS NOT NULL AS confirmed, shipped_at IS NOT NULL AS shipped, \
+         delivered_at IS NOT NULL AS delivered FROM orders WHERE id = ?",
+    )
+    .bind(order_id.to_string())
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn stock_in_db(pool: &Pool<MySql>, book_id: Uuid) -> u32 {
+    sqlx::query("SELECT quantity_on_hand FROM inventories WHERE book_id = ?")
+        .bind(book_id.to_string())
+        .fetch_one(pool)
+        .await
+        .unwrap()
+        .get("quantity_on_hand")
+}
+
+/// 在庫作成 → 注文 → 確定（在庫予約） → 発送 → 配達完了の正常系
+#[tokio::test]
+async fn test_happy_path_order_lifecycle() {
+    let client = client();
+    let pool = pool().await;
+    assert_eq!(client.health().await.unwrap()["status"], "ok");
+
+    let (order_id, book_id) = prepare_order(&client, 5, 2).await;
+    client.confirm_order(order_id).await.unwrap();
+    wait_for_stock(&client, book_id, 3).await;
+
+    client.mark_order_as_shipped(order_id).await.unwrap();
+    client.mark_order_as_delivered(order_id).await.unwrap();
+    wait_for_status(&client, order_id, "Delivered").await;
+
+    let order = client.get_order(order_id).await.unwrap();
+    assert_eq!(order.order_lines.len(), 1);
+    assert_eq!(order.subtotal_amount, 3000);
+
+    // データベースの最終状態
+    let row = order_row(&pool, order_id).await;
+    assert_eq!(row.get::<String, _>("status"), "Delivered");
+    assert!(row.get::<bool, _>("confirmed"));
+    assert!(row.get::<bool, _>("shipped"));
+    assert!(row.get::<bool, _>("delivered"));
+    assert_eq!(stock_in_db(&pool, book_id).await, 3);
+}
+
+/// 在庫不足で予約に失敗した注文が補償フローでキャンセルされ、在庫が変わらない
+#[tokio::test]
+async fn test_compensation_cancels_order_on_insufficient_stock() {
+    let client = client();
+    let pool = pool().await;
+
+    let (order_id, book_id) = prepare_order(&client, 1, 3).await;
+    client.confirm_order(order_id).await.unwrap();
+    wait_for_status(&client, order_id, "Cancelled").await;
+
+    // キャンセル済みの注文は発送できない
+    assert!(client.mark_order_as_shipped(order_id).await.is_err());
+
+    // データベースの最終状態
+    let row = order_row(&pool, order_id).await;
+    assert_eq!(row.get::<String, _>("status"), "Cancelled");
+    assert!(!row.get::<bool, _>("shipped"));
+    assert_eq!(stock_in_db(&pool, book_id).await, 1);
+}
@@ -222,7 +222,7 @@ proptest! {
                 "道玄坂1-1-1".to_string(),
                 None,
             ).unwrap();
-            order.set_shipping_address(address);
+            order.set_shipping_address(address).unwrap();
         }
 
         let result = order.confirm();
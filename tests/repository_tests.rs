@@ -0,0 +1,1403 @@
+//! MySQLリポジトリのテスト
+//!
+//! テストごとに `DbTestContext` で分離したデータベースを使うため、並列に実行できる。
+//!
+//! ```bash
+//! docker compose up -d
+//! cargo test --features mysql-tests --test repository_tests
+//! ```
+
+mod common;
+
+use bookstore_order_management::adapter::driven::{
+    ConsoleLogger, EventSourcedOrderRepository, MySqlAllocationQuotaRepository, HmacActionLinkSigner, InMemoryEventBus, MySqlActionLinkAuditRepository, MySqlBookCatalog, MySqlCheckoutHoldRepository, MySqlCustomerRepository, MySqlEventJournal, MySqlEventStore, MySqlFailedNotificationRepository, MySqlInventoryRepository, MySqlOrderLockRepository, MySqlOrderNumberGenerator, MySqlOrderRepository, MySqlPendingOperationRepository, MySqlProcessedEventRepository, MySqlSagaCompensationRepository, MySqlSubscriptionRegistryRepository,
+    MySqlWaitlistRepository, MySqlWebhookDeliveryRepository, MySqlWebhookSubscriptionRepository,
+};
+use bookstore_order_management::adapter::DatasetAnonymizer;
+use bookstore_order_management::domain::action_link::{
+    ActionLinkClaims, ActionLinkOutcome, ActionLinkUse, CustomerAction,
+};
+use bookstore_order_management::domain::address_normalizer::{AddressInput, AddressNormalizer};
+use bookstore_order_management::domain::anonymization::Anonymizer;
+use bookstore_order_management::domain::book_translation::Language;
+use bookstore_order_management::domain::checkout_hold::CheckoutHold;
+use bookstore_order_management::domain::customer_webhook::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookSubscription,
+};
+use bookstore_order_management::domain::delivery_estimate::DeliveryEstimator;
+use bookstore_order_management::application::service::SubscriptionApplicationService;
+use bookstore_order_management::domain::event::{DomainEvent, OrderCancelled, OrderDelivered};
+use bookstore_order_management::domain::event_bus::{
+    EventHandler, HandlerError, PausedEventHandling, SubscriptionState,
+};
+use bookstore_order_management::domain::event_sourcing::order_stream_id;
+use bookstore_order_management::domain::model::{
+    AllocationQuota, BookId, Customer, CustomerId, EmailAddress, Inventory, LineAttribute, Money,
+    Order, OrderId, OrderStatus, SalesChannel, ShippingAddress,
+};
+use bookstore_order_management::domain::notification_retry::{
+    FailedNotification, FailedNotificationStatus, Notification, NotificationChannel,
+    NotificationRetryPolicy,
+};
+use bookstore_order_management::domain::order_lock::OrderLock;
+use bookstore_order_management::domain::pending_operation::{
+    PendingOperation, PendingOperationStatus,
+};
+use bookstore_order_management::domain::port::{
+    ActionLinkAuditRepository, ActionLinkSigner, AllocationQuotaRepository, BookCatalog, CheckoutHoldRepository, CustomerRepository, EventJournal, EventStore, FailedNotificationRepository, InventoryRepository, OrderLockRepository, OrderNumberGenerator, OrderRepository, PendingOperationRepository, ProcessedEventRepository, RepositoryError, SagaCompensationRepository, SubscriptionManager, SubscriptionRegistryRepository, WaitlistRepository,
+    WebhookDeliveryRepository, WebhookSubscriptionRepository,
+};
+use bookstore_order_management::domain::purchase_policy::{
+    PurchaseCheck, PurchaseLimits, PurchasePolicy,
+};
+use bookstore_order_management::domain::saga_metrics::{
+    SagaCompensation, FAILED_STEP_INVENTORY_RESERVATION, FAILED_STEP_SHIPPING,
+};
+use bookstore_order_management::domain::shipping_fee::ShippingFeePolicy;
+use bookstore_order_management::domain::subscription_registry::RegisteredSubscriptionState;
+use bookstore_order_management::domain::waitlist::WaitlistEntry;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use common::DbTestContext;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_order_round_trip() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    let book_id = BookId::new();
+    order.add_book(book_id, 2, Money::jpy(1500)).unwrap();
+    let attributes = vec![
+        LineAttribute::new("signed_copy".to_string(), "requested".to_string()).unwrap(),
+        LineAttribute::new("gift_wrap".to_string(), "青い包装紙".to_string()).unwrap(),
+    ];
+    order
+        .set_line_attributes(book_id, attributes.clone())
+        .unwrap();
+    order
+        .set_shipping_address(
+            ShippingAddress::new(
+                "1500001".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "神宮前1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    order.confirm().unwrap();
+    repository.save(&mut order).await.unwrap();
+
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(found.status(), OrderStatus::Confirmed);
+    assert_eq!(found.order_lines().len(), 1);
+    assert_eq!(found.subtotal(), Money::jpy(3000));
+    assert_eq!(found.shipping_address(), order.shipping_address());
+    assert_eq!(found.order_lines()[0].attributes(), attributes.as_slice());
+}
+
+#[tokio::test]
+async fn test_order_keeps_original_shipping_address() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+    let entered = AddressInput {
+        postal_code: "〒１５０－０００１".to_string(),
+        prefecture: "東京".to_string(),
+        city: "渋谷区".to_string(),
+        street: "神宮前１ー１ー１".to_string(),
+        building: Some("ﾒｿﾞﾝ 101".to_string()),
+    };
+    order
+        .set_entered_shipping_address(entered.clone(), &AddressNormalizer)
+        .unwrap();
+    repository.save(&mut order).await.unwrap();
+
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(found.shipping_address().unwrap().postal_code(), "1500001");
+    assert_eq!(found.shipping_address().unwrap().building(), Some("メゾン 101"));
+    assert_eq!(found.original_shipping_address(), Some(&entered));
+}
+
+#[tokio::test]
+async fn test_order_keeps_confirmed_totals() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 2, Money::jpy(1500)).unwrap();
+    order
+        .set_shipping_address(
+            ShippingAddress::new(
+                "1500001".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "神宮前1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    repository.save(&mut order).await.unwrap();
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert!(found.confirmed_totals().is_none());
+
+    order.confirm().unwrap();
+    order.snapshot_totals(&ShippingFeePolicy::default());
+    repository.save(&mut order).await.unwrap();
+
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(found.confirmed_totals(), order.confirmed_totals());
+    assert_eq!(found.confirmed_totals().unwrap().total.amount(), 3500);
+}
+
+#[tokio::test]
+async fn test_stale_order_save_is_rejected_with_concurrency_conflict() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+    order
+        .set_shipping_address(
+            ShippingAddress::new(
+                "1500001".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "神宮前1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    repository.save(&mut order).await.unwrap();
+    assert_eq!(order.version(), 1);
+
+    // 同じ注文を続けて保存できる（保存後のバージョンが記録される）
+    order.add_book(BookId::new(), 1, Money::jpy(800)).unwrap();
+    repository.save(&mut order).await.unwrap();
+    assert_eq!(order.version(), 2);
+
+    // 同じバージョンを読み込んだ2つの操作のうち、後から保存した方は競合する
+    let mut confirmed = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    let mut cancelled = confirmed.clone();
+    confirmed.confirm().unwrap();
+    repository.save(&mut confirmed).await.unwrap();
+    cancelled.cancel().unwrap();
+    assert!(matches!(
+        repository.save(&mut cancelled).await,
+        Err(RepositoryError::ConcurrencyConflict(_))
+    ));
+
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(found.status(), OrderStatus::Confirmed);
+    assert_eq!(found.version(), 3);
+
+    // 同じIDの未保存の注文の追加も競合になる
+    let mut duplicate = Order::new(order.id(), order.customer_id());
+    assert!(matches!(
+        repository.save(&mut duplicate).await,
+        Err(RepositoryError::ConcurrencyConflict(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_estimated_delivery_date_is_persisted() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+    order
+        .set_shipping_address(
+            ShippingAddress::new(
+                "9000001".to_string(),
+                "沖縄県".to_string(),
+                "那覇市".to_string(),
+                "泉崎1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    order.confirm().unwrap();
+    order.estimate_delivery(&DeliveryEstimator::default());
+    assert!(order.estimated_delivery_date().is_some());
+    repository.save(&mut order).await.unwrap();
+
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(
+        found.estimated_delivery_date(),
+        order.estimated_delivery_date()
+    );
+    let found_by_status = repository
+        .find_by_status(OrderStatus::Confirmed)
+        .await
+        .unwrap();
+    assert!(found_by_status.iter().any(|found| found.id() == order.id()
+        && found.estimated_delivery_date() == order.estimated_delivery_date()));
+}
+
+#[tokio::test]
+async fn test_order_is_found_by_tracking_token_until_cancelled() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+    order
+        .set_shipping_address(
+            ShippingAddress::new(
+                "1500001".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "神宮前1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    order.confirm().unwrap();
+    repository.save(&mut order).await.unwrap();
+    let token = order.tracking_token().cloned().unwrap();
+
+    let found = repository
+        .find_by_tracking_token(&token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id(), order.id());
+    assert_eq!(found.tracking_token(), Some(&token));
+
+    // キャンセルするとトークンは失効する
+    order.cancel().unwrap();
+    repository.save(&mut order).await.unwrap();
+    assert!(repository
+        .find_by_tracking_token(&token)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_order_numbers_are_sequenced_per_year_and_searchable() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+    let generator = MySqlOrderNumberGenerator::new(db.pool(), "BK".to_string(), 6);
+
+    let in_2024: DateTime<Utc> = "2024-06-01T00:00:00Z".parse().unwrap();
+    let in_2025: DateTime<Utc> = "2025-01-01T00:00:00Z".parse().unwrap();
+    let first = generator.next_order_number(in_2024).await.unwrap();
+    let second = generator.next_order_number(in_2024).await.unwrap();
+    assert_eq!(first.as_str(), "BK-2024-000001");
+    assert_eq!(second.as_str(), "BK-2024-000002");
+    // 年が変わると連番は1から始まる
+    assert_eq!(
+        generator.next_order_number(in_2025).await.unwrap().as_str(),
+        "BK-2025-000001"
+    );
+
+    let mut order =
+        Order::new(OrderId::new(), CustomerId::new()).with_order_number(Some(second.clone()));
+    repository.save(&mut order).await.unwrap();
+    let found = repository
+        .find_by_order_number(&second)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id(), order.id());
+    assert_eq!(found.order_number(), Some(&second));
+    assert!(repository
+        .find_by_order_number(&first)
+        .await
+        .unwrap()
+        .is_none());
+
+    // 同じ注文番号の注文は保存できない（注文IDの重複ではないため競合ではなく保存の失敗）
+    let mut duplicate =
+        Order::new(OrderId::new(), CustomerId::new()).with_order_number(Some(second));
+    assert!(matches!(
+        repository.save(&mut duplicate).await,
+        Err(RepositoryError::OperationFailed(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_webhook_deliveries_are_listed_newest_first_and_removed_with_subscription() {
+    let db = DbTestContext::new().await;
+    let subscriptions = MySqlWebhookSubscriptionRepository::new(db.pool());
+    let deliveries = MySqlWebhookDeliveryRepository::new(db.pool());
+
+    let customer_id = CustomerId::new();
+    let subscription = WebhookSubscription::new(
+        customer_id,
+        "https://partner.example.com/hooks".to_string(),
+        "whsec_0123456789abcdef".to_string(),
+        vec!["OrderConfirmed".to_string(), "OrderShipped".to_string()],
+        Utc::now(),
+    )
+    .unwrap();
+    subscriptions.save(&subscription).await.unwrap();
+
+    let found = subscriptions.find_by_customer(customer_id).await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, subscription.id);
+    assert_eq!(found[0].event_types, subscription.event_types);
+    assert_eq!(found[0].secret, subscription.secret);
+
+    let order_id = OrderId::new();
+    let failed = WebhookDelivery {
+        id: Uuid::new_v4(),
+        subscription_id: subscription.id,
+        event_id: Uuid::new_v4(),
+        event_type: "OrderShipped".to_string(),
+        order_id,
+        payload: r#"{"event_type":"OrderShipped"}"#.to_string(),
+        status: WebhookDeliveryStatus::Failed,
+        response_status: None,
+        error: Some("connection refused".to_string()),
+        redelivery_of: None,
+        attempted_at: Utc::now() - TimeDelta::minutes(5),
+    };
+    let redelivered = WebhookDelivery {
+        id: Uuid::new_v4(),
+        status: WebhookDeliveryStatus::Succeeded,
+        response_status: Some(200),
+        error: None,
+        redelivery_of: Some(failed.id),
+        attempted_at: Utc::now(),
+        ..failed.clone()
+    };
+    deliveries.record(&failed).await.unwrap();
+    deliveries.record(&redelivered).await.unwrap();
+
+    let logged = deliveries
+        .find_by_subscription(subscription.id, 10)
+        .await
+        .unwrap();
+    let ids: Vec<Uuid> = logged.iter().map(|delivery| delivery.id).collect();
+    assert_eq!(ids, vec![redelivered.id, failed.id]);
+    assert_eq!(logged[0].redelivery_of, Some(failed.id));
+    assert_eq!(logged[0].response_status, Some(200));
+    assert_eq!(logged[1].error.as_deref(), Some("connection refused"));
+    assert_eq!(logged[1].order_id, order_id);
+
+    // 他の顧客からは削除できず、削除すると配信ログも消える
+    assert!(!subscriptions
+        .delete(CustomerId::new(), subscription.id)
+        .await
+        .unwrap());
+    assert!(subscriptions
+        .delete(customer_id, subscription.id)
+        .await
+        .unwrap());
+    assert!(deliveries.find_by_id(failed.id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_similar_orders_match_customer_lines_and_creation_time() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+    let customer_id = CustomerId::new();
+    let book_id = BookId::new();
+    let other_book_id = BookId::new();
+
+    let mut saved = Vec::new();
+    for (customer_id, lines) in [
+        (customer_id, vec![(book_id, 1), (other_book_id, 2)]),
+        (customer_id, vec![(other_book_id, 2), (book_id, 1)]),
+        (customer_id, vec![(other_book_id, 2), (book_id, 1)]),
+        (customer_id, vec![(book_id, 1)]),
+        (CustomerId::new(), vec![(book_id, 1), (other_book_id, 2)]),
+    ] {
+        let mut order = Order::new(OrderId::new(), customer_id);
+        for (book_id, quantity) in lines {
+            order.add_book(book_id, quantity, Money::jpy(1500)).unwrap();
+        }
+        repository.save(&mut order).await.unwrap();
+        saved.push(order.id());
+    }
+    // 3件目は2時間前に作成されたことにする
+    sqlx::query("UPDATE orders SET created_at = created_at - INTERVAL 2 HOUR WHERE id = ?")
+        .bind(saved[2].to_string())
+        .execute(&db.pool())
+        .await
+        .unwrap();
+
+    let similar = repository
+        .find_similar_orders(saved[0], TimeDelta::minutes(30))
+        .await
+        .unwrap();
+    let similar_ids: Vec<OrderId> = similar.iter().map(Order::id).collect();
+    assert_eq!(similar_ids, vec![saved[1]]);
+
+    let similar = repository
+        .find_similar_orders(saved[0], TimeDelta::hours(3))
+        .await
+        .unwrap();
+    assert_eq!(similar.len(), 2);
+}
+
+#[tokio::test]
+async fn test_concurrent_saves_within_purchase_limits_do_not_exceed_open_order_limit() {
+    let db = DbTestContext::new().await;
+    let repository = Arc::new(MySqlOrderRepository::new(db.pool()));
+    let policy = PurchasePolicy::new(PurchaseLimits {
+        max_quantity_per_book_per_day: None,
+        max_open_orders_per_customer: Some(2),
+    });
+    let customer_id = CustomerId::new();
+
+    // 顧客ごとのロック行で直列化されるため、同時に保存しても上限を超えない
+    let tasks: Vec<_> = (0..5)
+        .map(|_| {
+            let repository = repository.clone();
+            let policy = policy.clone();
+            tokio::spawn(async move {
+                let mut order = Order::new(OrderId::new(), customer_id);
+                repository
+                    .save_within_purchase_limits(&mut order, &policy, PurchaseCheck::OpenOrder)
+                    .await
+            })
+        })
+        .collect();
+    let mut saved = 0;
+    for task in tasks {
+        match task.await.unwrap() {
+            Ok(()) => saved += 1,
+            Err(error) => assert!(matches!(error, RepositoryError::PurchaseLimitExceeded(_))),
+        }
+    }
+
+    assert_eq!(saved, 2);
+    assert_eq!(
+        repository
+            .count_open_orders_by_customer(customer_id)
+            .await
+            .unwrap(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_orders_created_before_are_filtered_by_status() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+
+    let mut saved = Vec::new();
+    for _ in 0..3 {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        repository.save(&mut order).await.unwrap();
+        saved.push(order.id());
+    }
+    // 1件目と2件目は10日前に作成されたことにし、2件目はキャンセル済みにする
+    sqlx::query("UPDATE orders SET created_at = created_at - INTERVAL 10 DAY WHERE id IN (?, ?)")
+        .bind(saved[0].to_string())
+        .bind(saved[1].to_string())
+        .execute(&db.pool())
+        .await
+        .unwrap();
+    sqlx::query("UPDATE orders SET status = 'Cancelled' WHERE id = ?")
+        .bind(saved[1].to_string())
+        .execute(&db.pool())
+        .await
+        .unwrap();
+
+    let orders = repository
+        .find_by_status_created_before(OrderStatus::Pending, Utc::now() - TimeDelta::days(7))
+        .await
+        .unwrap();
+    let order_ids: Vec<OrderId> = orders.iter().map(Order::id).collect();
+    assert_eq!(order_ids, vec![saved[0]]);
+}
+
+#[tokio::test]
+async fn test_daily_book_demand_sums_confirmed_orders_by_date() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderRepository::new(db.pool());
+    let book_id = BookId::new();
+
+    let mut saved = Vec::new();
+    for quantity in [2, 3, 4, 5] {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(book_id, quantity, Money::jpy(1500)).unwrap();
+        order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1500001".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "神宮前1-1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        order.confirm().unwrap();
+        repository.save(&mut order).await.unwrap();
+        saved.push(order);
+    }
+    // 2件目は2日前、4件目は10日前に確定したことにする
+    for (order, days) in [(&saved[1], 2), (&saved[3], 10)] {
+        sqlx::query("UPDATE orders SET confirmed_at = confirmed_at - INTERVAL ? DAY WHERE id = ?")
+            .bind(days)
+            .bind(order.id().to_string())
+            .execute(&db.pool())
+            .await
+            .unwrap();
+    }
+    // 3件目はキャンセルされたため需要に含めない
+    saved[2].cancel().unwrap();
+    repository.save(&mut saved[2]).await.unwrap();
+
+    let today = Utc::now().date_naive();
+    let demand = repository
+        .sum_daily_book_demand_since(book_id, Utc::now() - TimeDelta::days(5))
+        .await
+        .unwrap();
+    assert_eq!(
+        demand.into_iter().collect::<Vec<_>>(),
+        vec![(today - TimeDelta::days(2), 3), (today, 2)]
+    );
+}
+
+#[tokio::test]
+async fn test_each_context_has_its_own_database() {
+    let first = DbTestContext::new().await;
+    let second = DbTestContext::new().await;
+
+    let inventory = Inventory::new(BookId::new(), 5);
+    MySqlInventoryRepository::new(first.pool())
+        .save(&inventory)
+        .await
+        .unwrap();
+
+    // 同じMySQL上でも、別のコンテキストからは見えない
+    let first_inventories = MySqlInventoryRepository::new(first.pool())
+        .find_all()
+        .await
+        .unwrap();
+    assert_eq!(first_inventories, vec![inventory]);
+    let second_inventories = MySqlInventoryRepository::new(second.pool())
+        .find_all()
+        .await
+        .unwrap();
+    assert!(second_inventories.is_empty());
+}
+
+#[tokio::test]
+async fn test_stock_valuations_join_catalog_prices() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlInventoryRepository::new(db.pool());
+    let catalog = MySqlBookCatalog::new(db.pool());
+
+    let priced_book = BookId::new();
+    let unpriced_book = BookId::new();
+    repository
+        .save(&Inventory::new(priced_book, 3))
+        .await
+        .unwrap();
+    repository
+        .save(&Inventory::new(unpriced_book, 2))
+        .await
+        .unwrap();
+    catalog
+        .set_price(priced_book, Money::jpy(1800))
+        .await
+        .unwrap();
+    // 在庫のない書籍の価格は結果に含めない
+    catalog
+        .set_price(BookId::new(), Money::jpy(900))
+        .await
+        .unwrap();
+
+    let valuations = repository.find_stock_valuations().await.unwrap();
+    assert_eq!(valuations.len(), 2);
+    let priced = valuations
+        .iter()
+        .find(|valuation| valuation.book_id == priced_book)
+        .unwrap();
+    assert_eq!(priced.quantity_on_hand, 3);
+    assert_eq!(priced.unit_price, Some(Money::jpy(1800)));
+    let unpriced = valuations
+        .iter()
+        .find(|valuation| valuation.book_id == unpriced_book)
+        .unwrap();
+    assert_eq!(unpriced.unit_price, None);
+}
+
+#[tokio::test]
+async fn test_book_titles_are_stored_per_language() {
+    let db = DbTestContext::new().await;
+    let catalog = MySqlBookCatalog::new(db.pool());
+    let book_id = BookId::new();
+
+    catalog
+        .set_title(book_id, Language::Ja, "ドメイン駆動設計")
+        .await
+        .unwrap();
+    catalog
+        .set_title(book_id, Language::En, "DDD")
+        .await
+        .unwrap();
+    // 同じ言語のタイトルは置き換える
+    catalog
+        .set_title(book_id, Language::En, "Domain-Driven Design")
+        .await
+        .unwrap();
+
+    let titles = catalog
+        .find_titles(&[book_id, BookId::new()])
+        .await
+        .unwrap();
+    assert_eq!(titles.len(), 1);
+    assert_eq!(titles[&book_id].get(Language::Ja), Some("ドメイン駆動設計"));
+    assert_eq!(
+        titles[&book_id].get(Language::En),
+        Some("Domain-Driven Design")
+    );
+}
+
+#[tokio::test]
+async fn test_waitlist_keeps_first_come_order() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlWaitlistRepository::new(db.pool());
+    let book_id = BookId::new();
+
+    let first = WaitlistEntry::new(OrderId::new(), CustomerId::new(), book_id, 2);
+    let second = WaitlistEntry::new(OrderId::new(), CustomerId::new(), book_id, 1);
+    repository.join(std::slice::from_ref(&first)).await.unwrap();
+    repository
+        .join(std::slice::from_ref(&second))
+        .await
+        .unwrap();
+    // 再配信で同じ注文が登録されても、最初に並んだ位置を保つ
+    repository.join(std::slice::from_ref(&first)).await.unwrap();
+
+    let queue = repository.find_by_book(book_id).await.unwrap();
+    let order_ids: Vec<OrderId> = queue.iter().map(|entry| entry.order_id).collect();
+    assert_eq!(order_ids, vec![first.order_id, second.order_id]);
+    assert_eq!(queue[0].quantity, 2);
+
+    repository.remove_order(first.order_id).await.unwrap();
+    assert!(repository
+        .find_by_order(first.order_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(repository.find_by_book(book_id).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_allocation_quota_round_trip_keeps_allocations() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlAllocationQuotaRepository::new(db.pool());
+    let book_id = BookId::new();
+    let customer_id = CustomerId::new();
+
+    let mut quota = AllocationQuota::new(
+        book_id,
+        Some(2),
+        BTreeMap::from([(SalesChannel::Online, 70), (SalesChannel::InStore, 30)]),
+        10,
+    )
+    .unwrap();
+    let order_id = OrderId::new();
+    quota.allocate(order_id, customer_id, SalesChannel::Online, 2).unwrap();
+    repository.save(&quota).await.unwrap();
+
+    let found = repository.find_by_book_id(book_id).await.unwrap().unwrap();
+    assert_eq!(found, quota);
+
+    // 設定を変更しても割当済みの数量は引き継ぐ
+    let mut reconfigured = found;
+    reconfigured
+        .reconfigure(Some(3), BTreeMap::new(), 10)
+        .unwrap();
+    repository.save(&reconfigured).await.unwrap();
+    let found = repository.find_by_book_id(book_id).await.unwrap().unwrap();
+    assert_eq!(found.customer_allocated()[&customer_id], 2);
+    assert!(found.channel_shares().is_empty());
+
+    // 注文の割当を戻した割当枠は、注文ごとの割当も含めて保存する
+    let mut released = found;
+    assert!(released.release_order(order_id));
+    repository.save(&released).await.unwrap();
+    let found = repository.find_by_book_id(book_id).await.unwrap().unwrap();
+    assert_eq!(found, released);
+    assert!(found.order_allocations().is_empty());
+
+    assert!(repository.delete(book_id).await.unwrap());
+    assert!(repository.find_by_book_id(book_id).await.unwrap().is_none());
+    assert!(!repository.delete(book_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_checkout_holds_are_replaced_and_found_when_expired() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlCheckoutHoldRepository::new(db.pool());
+    let now = Utc::now();
+    let order_id = OrderId::new();
+    let book_id = BookId::new();
+
+    repository
+        .replace_for_order(
+            order_id,
+            &[CheckoutHold::new(order_id, book_id, 2, now + TimeDelta::minutes(15))],
+        )
+        .await
+        .unwrap();
+    // 再度仮押さえすると数量と期限が置き換わる
+    let extended = CheckoutHold::new(order_id, book_id, 1, now + TimeDelta::minutes(30));
+    repository
+        .replace_for_order(order_id, std::slice::from_ref(&extended))
+        .await
+        .unwrap();
+
+    let holds = repository.find_by_order(order_id).await.unwrap();
+    assert_eq!(holds.len(), 1);
+    assert_eq!(holds[0].quantity, 1);
+    assert!(repository
+        .find_expired(now + TimeDelta::minutes(15))
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        repository
+            .find_expired(now + TimeDelta::minutes(30))
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    repository.remove_order(order_id).await.unwrap();
+    assert!(repository.find_by_order(order_id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_event_journal_reads_range_after_position() {
+    let db = DbTestContext::new().await;
+    let journal = MySqlEventJournal::new(db.pool());
+    let from = Utc::now() - TimeDelta::minutes(1);
+
+    let events: Vec<DomainEvent> = (0..3)
+        .map(|_| {
+            DomainEvent::OrderCancelled(OrderCancelled::new(
+                OrderId::new(),
+                CustomerId::new(),
+                vec![],
+            ))
+        })
+        .collect();
+    for event in &events {
+        assert!(journal.append(event).await.unwrap());
+    }
+    // 同じイベントの再記録は無視される
+    assert!(!journal.append(&events[0]).await.unwrap());
+
+    let to = Utc::now() + TimeDelta::minutes(1);
+    let all = journal.read_range(from, to, 0, 10).await.unwrap();
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[0].event_id, events[0].metadata().event_id);
+    assert_eq!(all[0].event_type, "OrderCancelled");
+
+    let rest = journal.read_range(from, to, all[0].position, 1).await.unwrap();
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].position, all[1].position);
+    assert!(journal
+        .read_range(to, to + TimeDelta::minutes(1), 0, 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // 集約IDで絞り込むと、その注文のイベントだけを取得する
+    let aggregate_id = events[1].aggregate_id();
+    let by_aggregate = journal.find_by_aggregate(&aggregate_id).await.unwrap();
+    assert_eq!(by_aggregate.len(), 1);
+    assert_eq!(by_aggregate[0].position, all[1].position);
+}
+
+#[tokio::test]
+async fn test_action_link_uses_are_listed_by_order_in_use_order() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlActionLinkAuditRepository::new(db.pool());
+    let signer = HmacActionLinkSigner::new("0123456789abcdef0123456789abcdef");
+    let now = Utc::now();
+
+    let order_id = OrderId::new();
+    let claims = ActionLinkClaims::new(
+        CustomerAction::ConfirmDelivery,
+        order_id,
+        now,
+        TimeDelta::hours(72),
+    );
+    let claims = signer.verify(&signer.sign(&claims)).unwrap();
+    let executed = ActionLinkUse::record(&claims, None, now);
+    let rejected = ActionLinkUse::record(
+        &claims,
+        Some("配達完了にできるのはShipped状態のみです".to_string()),
+        now + TimeDelta::seconds(1),
+    );
+    repository.save(&rejected).await.unwrap();
+    repository.save(&executed).await.unwrap();
+
+    let found = repository.find_by_order(order_id).await.unwrap();
+    assert_eq!(
+        found.iter().map(|r| r.outcome).collect::<Vec<_>>(),
+        vec![ActionLinkOutcome::Executed, ActionLinkOutcome::Rejected]
+    );
+    assert_eq!(found[0].link_id, claims.link_id);
+    assert_eq!(found[0].action, CustomerAction::ConfirmDelivery);
+    assert_eq!(found[0].expires_at, claims.expires_at);
+    assert_eq!(found[1].error, rejected.error);
+    assert!(repository
+        .find_by_order(OrderId::new())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_pending_operations_are_updated_and_listed_while_pending() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlPendingOperationRepository::new(db.pool());
+    let now = Utc::now();
+
+    let event = DomainEvent::OrderCancelled(OrderCancelled::new(
+        OrderId::new(),
+        CustomerId::new(),
+        vec![],
+    ));
+    let mut first = PendingOperation::publish_event(&event, "lane closed", now).unwrap();
+    let second =
+        PendingOperation::publish_event(&event, "lane closed", now + TimeDelta::seconds(1))
+            .unwrap();
+    repository.save(&first).await.unwrap();
+    repository.save(&second).await.unwrap();
+
+    let pending = repository.find_pending(10).await.unwrap();
+    assert_eq!(
+        pending.iter().map(|o| o.id).collect::<Vec<_>>(),
+        vec![first.id, second.id]
+    );
+
+    // 再試行の結果で状態を更新し、完了した操作は再試行の対象から外れる
+    first.record_success(now + TimeDelta::seconds(5));
+    repository.save(&first).await.unwrap();
+    let saved = repository.find_by_id(first.id).await.unwrap().unwrap();
+    assert_eq!(saved.status, PendingOperationStatus::Completed);
+    assert_eq!(saved.attempts, 2);
+    assert!(saved.completed_at.is_some());
+    assert_eq!(saved.payload, first.payload);
+    assert_eq!(repository.find_pending(10).await.unwrap().len(), 1);
+    assert!(repository
+        .find_by_id(Uuid::new_v4())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_failed_notifications_are_found_when_due_and_by_status() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlFailedNotificationRepository::new(db.pool());
+    let policy = NotificationRetryPolicy::default();
+    let now = Utc::now();
+
+    let notification = |channel, phone: Option<&str>| Notification {
+        channel,
+        order_id: OrderId::new(),
+        phone: phone.map(str::to_string),
+        event_type: "OrderShipped".to_string(),
+        message: "ご注文が発送されました".to_string(),
+        correlation_id: Uuid::new_v4(),
+    };
+    let mut email = FailedNotification::new(
+        notification(NotificationChannel::Email, None),
+        "timeout",
+        &policy,
+        now,
+    );
+    let sms = FailedNotification::new(
+        notification(NotificationChannel::Sms, Some("09012345678")),
+        "timeout",
+        &policy,
+        now,
+    );
+    repository.save(&email).await.unwrap();
+    repository.save(&sms).await.unwrap();
+
+    // 再送の日時はチャネルごとの待ち時間で決まる（メールが先）
+    let due = repository
+        .find_due(email.next_attempt_at.unwrap(), 10)
+        .await
+        .unwrap();
+    assert_eq!(due.iter().map(|n| n.id).collect::<Vec<_>>(), vec![email.id]);
+    let saved = repository.find_by_id(sms.id).await.unwrap().unwrap();
+    assert_eq!(saved.notification, sms.notification);
+
+    // 送信できた通知は再送の対象から外れる
+    email.record_success(now + TimeDelta::minutes(2));
+    repository.save(&email).await.unwrap();
+    assert!(repository
+        .find_due(now + TimeDelta::hours(1), 10)
+        .await
+        .unwrap()
+        .iter()
+        .all(|n| n.id == sms.id));
+    let delivered = repository
+        .find_by_status(FailedNotificationStatus::Delivered, 10)
+        .await
+        .unwrap();
+    assert_eq!(delivered.len(), 1);
+    assert_eq!(delivered[0].attempts, 2);
+    assert!(delivered[0].delivered_at.is_some());
+}
+
+#[tokio::test]
+async fn test_saga_compensations_are_counted_once_per_event() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlSagaCompensationRepository::new(db.pool());
+
+    let compensation = SagaCompensation {
+        event_id: Uuid::new_v4(),
+        order_id: OrderId::new(),
+        failed_step: FAILED_STEP_SHIPPING.to_string(),
+        failure_reason: "配送業者エラー".to_string(),
+        occurred_at: Utc::now(),
+    };
+    repository.record(&compensation).await.unwrap();
+    // 再配信された同じイベントは数えない
+    repository.record(&compensation).await.unwrap();
+    repository
+        .record(&SagaCompensation {
+            event_id: Uuid::new_v4(),
+            order_id: OrderId::new(),
+            failed_step: FAILED_STEP_INVENTORY_RESERVATION.to_string(),
+            failure_reason: "在庫不足".to_string(),
+            occurred_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    let counts = repository.count_by_failed_step().await.unwrap();
+    assert_eq!(counts.get(FAILED_STEP_SHIPPING), Some(&1));
+    assert_eq!(counts.get(FAILED_STEP_INVENTORY_RESERVATION), Some(&1));
+}
+
+#[tokio::test]
+async fn test_order_lock_is_kept_from_other_operators_until_expired() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlOrderLockRepository::new(db.pool());
+    let now = DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap();
+    let ttl = TimeDelta::minutes(30);
+
+    let order_id = OrderId::new();
+    let alice = OrderLock::new(order_id, "alice".to_string(), now, ttl);
+    assert_eq!(repository.try_acquire(&alice).await.unwrap(), alice);
+
+    // 他のオペレーターは期限内のロックを置き換えられない
+    let bob = OrderLock::new(order_id, "bob".to_string(), now, ttl);
+    assert_eq!(repository.try_acquire(&bob).await.unwrap(), alice);
+
+    // 同じオペレーターは期限を延長できる
+    let extended = OrderLock::new(
+        order_id,
+        "alice".to_string(),
+        now + TimeDelta::minutes(5),
+        ttl,
+    );
+    assert_eq!(repository.try_acquire(&extended).await.unwrap(), extended);
+    assert_eq!(
+        repository.find_active(now).await.unwrap(),
+        vec![extended.clone()]
+    );
+
+    // 期限切れのロックは他のオペレーターが引き継げる
+    let after_expiry = extended.expires_at;
+    assert!(repository.find_active(after_expiry).await.unwrap().is_empty());
+    let bob = OrderLock::new(order_id, "bob".to_string(), after_expiry, ttl);
+    assert_eq!(repository.try_acquire(&bob).await.unwrap(), bob);
+
+    repository.remove(order_id).await.unwrap();
+    assert!(repository.find_by_order_id(order_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_processed_events_are_recorded_per_handler() {
+    let db = DbTestContext::new().await;
+    let repository = MySqlProcessedEventRepository::new(db.pool());
+    let event_id = Uuid::new_v4();
+
+    assert!(!repository
+        .is_processed(event_id, "InventoryReservationHandler")
+        .await
+        .unwrap());
+    repository
+        .mark_processed(event_id, "InventoryReservationHandler", Utc::now())
+        .await
+        .unwrap();
+    // 再配信で同じイベントを記録しても失敗しない
+    repository
+        .mark_processed(event_id, "InventoryReservationHandler", Utc::now())
+        .await
+        .unwrap();
+
+    // 記録したインスタンスとは別のインスタンス（再起動後）からも処理済みと判定できる
+    let restarted = MySqlProcessedEventRepository::new(db.pool());
+    assert!(restarted
+        .is_processed(event_id, "InventoryReservationHandler")
+        .await
+        .unwrap());
+    // 処理済みかどうかはハンドラーごとに判定する
+    assert!(!restarted
+        .is_processed(event_id, "ShippingHandler")
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_event_sourced_order_is_rebuilt_from_stream_and_rejects_stale_saves() {
+    let db = DbTestContext::new().await;
+    let event_store = Arc::new(MySqlEventStore::new(db.pool()));
+    let repository =
+        EventSourcedOrderRepository::new(event_store.clone(), MySqlOrderRepository::new(db.pool()));
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 2, Money::jpy(1500)).unwrap();
+    order
+        .set_shipping_address(
+            ShippingAddress::new(
+                "1500001".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "神宮前1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    // 確定前の注文はイベントにならず、状態テーブルにだけ保存される
+    repository.save(&mut order).await.unwrap();
+    let stream_id = order_stream_id(order.id());
+    assert!(event_store
+        .load_stream(&stream_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let mut draft = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    draft.confirm().unwrap();
+    repository.save(&mut draft).await.unwrap();
+
+    let confirmed = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(confirmed.status(), OrderStatus::Confirmed);
+    assert_eq!(confirmed.stream_version(), 1);
+    assert_eq!(confirmed.subtotal(), Money::jpy(3000));
+
+    // 同じバージョンを読み込んだ2つの操作のうち、後から保存した方は競合する
+    let mut shipped = confirmed.clone();
+    shipped.mark_as_shipped().unwrap();
+    repository.save(&mut shipped).await.unwrap();
+    let mut cancelled = confirmed.clone();
+    cancelled.cancel().unwrap();
+    assert!(matches!(
+        repository.save(&mut cancelled).await,
+        Err(RepositoryError::ConcurrencyConflict(_))
+    ));
+
+    let stream = event_store.load_stream(&stream_id).await.unwrap();
+    let event_types: Vec<&str> = stream.events.iter().map(|e| e.event_type()).collect();
+    assert_eq!(event_types, vec!["OrderConfirmed", "OrderShipped"]);
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(found.status(), OrderStatus::Shipped);
+    assert_eq!(found.stream_version(), 2);
+}
+
+/// イベントの追記後に投影の更新に失敗しても保存は成功し、後の保存で投影に反映し直されることを検証
+#[tokio::test]
+async fn test_event_sourced_save_succeeds_when_projection_update_fails() {
+    let db = DbTestContext::new().await;
+    let event_store = Arc::new(MySqlEventStore::new(db.pool()));
+    let projection = MySqlOrderRepository::new(db.pool());
+    let repository =
+        EventSourcedOrderRepository::new(event_store.clone(), MySqlOrderRepository::new(db.pool()));
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+    order
+        .set_shipping_address(
+            ShippingAddress::new(
+                "1500001".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "神宮前1-1-1".to_string(),
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    repository.save(&mut order).await.unwrap();
+    order.confirm().unwrap();
+    repository.save(&mut order).await.unwrap();
+
+    // 注文明細のテーブルを使えなくして、投影の更新だけを失敗させる
+    sqlx::query("RENAME TABLE order_lines TO order_lines_unavailable")
+        .execute(&db.pool())
+        .await
+        .unwrap();
+    order.mark_as_shipped().unwrap();
+    repository.save(&mut order).await.unwrap();
+    sqlx::query("RENAME TABLE order_lines_unavailable TO order_lines")
+        .execute(&db.pool())
+        .await
+        .unwrap();
+
+    // イベントは追記されており、投影が古いままでも最新の状態を読み込める
+    let stream = event_store
+        .load_stream(&order_stream_id(order.id()))
+        .await
+        .unwrap();
+    assert_eq!(stream.version, 2);
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(found.status(), OrderStatus::Shipped);
+    let stale = projection.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(stale.status(), OrderStatus::Confirmed);
+
+    // 別の注文の保存時に投影へ反映し直す
+    let mut other = Order::new(OrderId::new(), CustomerId::new());
+    repository.save(&mut other).await.unwrap();
+    let rebuilt = projection.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(rebuilt.status(), OrderStatus::Shipped);
+
+    // 読み込んだ注文はそのまま保存を続けられる
+    let mut delivered = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    delivered.mark_as_delivered().unwrap();
+    repository.save(&mut delivered).await.unwrap();
+    assert_eq!(
+        projection
+            .find_by_id(order.id())
+            .await
+            .unwrap()
+            .unwrap()
+            .status(),
+        OrderStatus::Delivered
+    );
+}
+
+#[tokio::test]
+async fn test_customer_round_trip_and_orders_by_customer() {
+    let db = DbTestContext::new().await;
+    let customer_repository = MySqlCustomerRepository::new(db.pool());
+    let order_repository = MySqlOrderRepository::new(db.pool());
+
+    let address = ShippingAddress::new(
+        "1500043".to_string(),
+        "東京都".to_string(),
+        "渋谷区".to_string(),
+        "道玄坂1-1-1".to_string(),
+        Some("ビル3F".to_string()),
+    )
+    .unwrap();
+    let email = EmailAddress::new("hanako@example.com".to_string()).unwrap();
+    let customer = Customer::register(
+        CustomerId::new(),
+        "山田花子".to_string(),
+        email.clone(),
+        Some(address.clone()),
+        Utc::now(),
+    )
+    .unwrap();
+    customer_repository.save(&customer).await.unwrap();
+
+    let saved = customer_repository
+        .find_by_id(customer.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(saved.name(), "山田花子");
+    assert_eq!(saved.default_shipping_address(), Some(&address));
+    let by_email = customer_repository
+        .find_by_email(&email)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(by_email.id(), customer.id());
+    assert_eq!(by_email.preferred_language(), Language::Ja);
+    assert!(customer_repository
+        .find_by_id(CustomerId::new())
+        .await
+        .unwrap()
+        .is_none());
+
+    // 通知の言語の変更を保存する
+    let mut customer = customer;
+    customer.change_preferred_language(Language::En);
+    customer_repository.save(&customer).await.unwrap();
+    assert_eq!(
+        customer_repository
+            .find_by_id(customer.id())
+            .await
+            .unwrap()
+            .unwrap()
+            .preferred_language(),
+        Language::En
+    );
+
+    // 顧客の注文だけを取得する
+    let mut order = Order::new(OrderId::new(), customer.id());
+    order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+    order_repository.save(&mut order).await.unwrap();
+    let mut other = Order::new(OrderId::new(), CustomerId::new());
+    other.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+    order_repository.save(&mut other).await.unwrap();
+
+    let orders = order_repository
+        .find_by_customer(customer.id())
+        .await
+        .unwrap();
+    assert_eq!(
+        orders.iter().map(|o| o.id()).collect::<Vec<_>>(),
+        vec![order.id()]
+    );
+}
+
+#[tokio::test]
+async fn test_dataset_is_copied_with_anonymized_customers_and_addresses() {
+    let source = DbTestContext::new().await;
+    let target = DbTestContext::new().await;
+
+    let address = ShippingAddress::new(
+        "9000001".to_string(),
+        "沖縄県".to_string(),
+        "那覇市".to_string(),
+        "港町4-4-4".to_string(),
+        None,
+    )
+    .unwrap();
+    let customer = Customer::register(
+        CustomerId::new(),
+        "山田花子".to_string(),
+        EmailAddress::new("hanako@example.com".to_string()).unwrap(),
+        Some(address.clone()),
+        Utc::now(),
+    )
+    .unwrap();
+    MySqlCustomerRepository::new(source.pool())
+        .save(&customer)
+        .await
+        .unwrap();
+    let mut order = Order::new(OrderId::new(), customer.id());
+    order.add_book(BookId::new(), 2, Money::jpy(1500)).unwrap();
+    order.set_shipping_address(address.clone()).unwrap();
+    order.confirm().unwrap();
+    MySqlOrderRepository::new(source.pool())
+        .save(&mut order)
+        .await
+        .unwrap();
+
+    let report =
+        DatasetAnonymizer::new(source.pool(), target.pool(), Arc::new(ConsoleLogger::new()))
+            .run(&mut Anonymizer::new(42))
+            .await
+            .unwrap();
+    assert_eq!(report.remapped_customers, 1);
+    assert!(report.copied_rows.contains(&("orders".to_string(), 1)));
+
+    // 注文は同じIDとステータスのまま、付け替えた顧客を参照する
+    let copied = MySqlOrderRepository::new(target.pool())
+        .find_by_id(order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(copied.status(), OrderStatus::Confirmed);
+    assert_eq!(copied.calculate_total(), order.calculate_total());
+    assert_ne!(copied.customer_id(), customer.id());
+    let copied_address = copied.shipping_address().unwrap();
+    assert_eq!(copied_address.prefecture(), "沖縄県");
+    assert_ne!(copied_address.street(), address.street());
+
+    let copied_customer = MySqlCustomerRepository::new(target.pool())
+        .find_by_id(copied.customer_id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_ne!(copied_customer.name(), "山田花子");
+    assert_ne!(copied_customer.email(), customer.email());
+    assert_eq!(
+        copied_customer
+            .default_shipping_address()
+            .unwrap()
+            .prefecture(),
+        "沖縄県"
+    );
+}
+
+/// 配達完了を受け取るだけのハンドラー
+struct DeliveryAuditHandler;
+
+#[async_trait]
+impl EventHandler<OrderDelivered> for DeliveryAuditHandler {
+    async fn handle(&self, _event: OrderDelivered) -> Result<(), HandlerError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_paused_subscription_is_restored_after_restart() {
+    let db = DbTestContext::new().await;
+    let registry = Arc::new(MySqlSubscriptionRegistryRepository::new(db.pool()));
+
+    // 起動1回目: 管理者がハンドラーを一時停止する
+    let event_bus = Arc::new(InMemoryEventBus::default());
+    event_bus
+        .subscribe_order_delivered(DeliveryAuditHandler)
+        .await
+        .unwrap();
+    let service =
+        SubscriptionApplicationService::new(event_bus.clone()).with_registry(registry.clone());
+    assert_eq!(service.restore_subscriptions().await.unwrap(), 0);
+    service
+        .pause("DeliveryAuditHandler", PausedEventHandling::Skip)
+        .await
+        .unwrap();
+
+    let registrations = registry.find_all().await.unwrap();
+    assert_eq!(registrations.len(), 1);
+    assert_eq!(registrations[0].event_types, vec!["OrderDelivered".to_string()]);
+    assert_eq!(
+        registrations[0].state,
+        RegisteredSubscriptionState::Paused(PausedEventHandling::Skip)
+    );
+
+    // 起動2回目: 購読時は有効な状態から始まり、保存した状態に戻る
+    let restarted_bus = Arc::new(InMemoryEventBus::default());
+    restarted_bus
+        .subscribe_order_delivered(DeliveryAuditHandler)
+        .await
+        .unwrap();
+    let restarted =
+        SubscriptionApplicationService::new(restarted_bus.clone()).with_registry(registry.clone());
+    assert_eq!(restarted.restore_subscriptions().await.unwrap(), 1);
+    assert_eq!(
+        restarted_bus.subscriptions().await[0].state,
+        SubscriptionState::Paused(PausedEventHandling::Skip)
+    );
+
+    // 登録内容を削除すると、次の起動時は購読時の状態で始まる
+    restarted.forget_registration("DeliveryAuditHandler").await.unwrap();
+    assert!(registry.find_all().await.unwrap().is_empty());
+    assert!(restarted
+        .forget_registration("DeliveryAuditHandler")
+        .await
+        .is_err());
+}
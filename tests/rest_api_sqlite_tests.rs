@@ -0,0 +1,265 @@
+//! インメモリのSQLiteで組み立てたREST APIの統合テスト
+//!
+//! `--sqlite` モードと同じ配線（`build_application`）でルーターを作り、プロセス内で
+//! リクエストを送る。モックを使わず、外部のデータベースもアプリケーションの起動も不要。
+//! 設定は環境変数ではなくテスト用のTOMLから読み込み、スタッフのトークンで認証したリクエストを送る。
+//! 注文のライフサイクルを進め、最後にSQLiteの状態を検証する。
+
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderValue, StatusCode};
+use axum_test::TestServer;
+use bookstore_order_management::adapter::driven::ConsoleLogger;
+use bookstore_order_management::adapter::driver::auth::JwtClaims;
+use bookstore_order_management::adapter::driver::request_dto::{
+    AddBookRequest, CreateInventoryRequest, CreateOrderRequest, RegisterCustomerRequest,
+    SetShippingAddressRequest,
+};
+use bookstore_order_management::adapter::driver::response_dto::{
+    CustomerResponse, InventoryResponse, OrderDetailResponse,
+};
+use bookstore_order_management::adapter::driver::rest_api::CreateOrderResponse;
+use bookstore_order_management::adapter::{
+    build_application, AppConfig, OrderNumberConfig, Profile, Repositories, SqliteDatabase,
+    StartupDiagnostics,
+};
+use bookstore_order_management::domain::access_control::Role;
+use bookstore_order_management::domain::model::{FulfillmentType, SalesChannel};
+use jsonwebtoken::{EncodingKey, Header};
+use sqlx::{Pool, Row, Sqlite};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// テスト用の設定
+/// 起動時の夜間エクスポートが作業ディレクトリに書き出さないようにし、認証を有効にする
+const CONFIG: &str = r#"
+    [event_export]
+    nightly = false
+
+    [auth]
+    jwt_secret = "0123456789abcdef0123456789abcdef"
+"#;
+
+/// 設定のシークレットで署名したスタッフのトークン
+fn staff_token() -> String {
+    let claims = JwtClaims {
+        sub: "staff-1".to_string(),
+        role: Role::Staff,
+        customer_id: None,
+        exp: chrono::Utc::now().timestamp() + 3600,
+    };
+    jsonwebtoken::encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(b"0123456789abcdef0123456789abcdef"),
+    )
+    .unwrap()
+}
+
+/// テストごとに独立したインメモリのSQLiteでアプリケーションを組み立てる
+async fn server() -> (TestServer, Pool<Sqlite>) {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repositories = Repositories::sqlite(
+        pool.clone(),
+        true,
+        OrderNumberConfig {
+            prefix: "BK".to_string(),
+            digits: 6,
+        },
+    );
+    let app_config = AppConfig::from_toml(Profile::Test, CONFIG).unwrap();
+    let application = build_application(
+        &app_config,
+        repositories,
+        Arc::new(ConsoleLogger::new()),
+        StartupDiagnostics::new(),
+    )
+    .await
+    .unwrap();
+    let mut server = TestServer::new(application.router).unwrap();
+    server.add_header(
+        AUTHORIZATION,
+        HeaderValue::from_str(&format!("Bearer {}", staff_token())).unwrap(),
+    );
+    (server, pool)
+}
+
+/// 在庫を作成し、その書籍を指定数量注文して配送先住所を設定した注文を作る
+async fn prepare_order(server: &TestServer, stock: u32, quantity: u32) -> (Uuid, Uuid) {
+    let book_id = Uuid::new_v4();
+    server
+        .post("/inventory")
+        .json(&CreateInventoryRequest {
+            book_id,
+            quantity: stock,
+            release_date: None,
+            waitlist_enabled: false,
+        })
+        .await
+        .assert_status_success();
+
+    // 注文は登録済みの顧客を参照する必要がある
+    let customer: CustomerResponse = server
+        .post("/customers")
+        .json(&RegisterCustomerRequest {
+            name: "SQLite 顧客".to_string(),
+            email: format!("sqlite-{}@example.com", Uuid::new_v4()),
+            default_shipping_address: None,
+        })
+        .await
+        .json();
+    let customer_id = Uuid::parse_str(&customer.customer_id).unwrap();
+
+    let order_id = server
+        .post("/orders")
+        .json(&CreateOrderRequest {
+            customer_id: Some(customer_id),
+            sales_channel: SalesChannel::Online,
+        })
+        .await
+        .json::<CreateOrderResponse>()
+        .order_id;
+    server
+        .post(&format!("/orders/{}/books", order_id))
+        .json(&AddBookRequest {
+            book_id,
+            quantity,
+            unit_price: 1500,
+            fulfillment_type: FulfillmentType::Physical,
+            spec: None,
+        })
+        .await
+        .assert_status_success();
+    server
+        .put(&format!("/orders/{}/shipping-address", order_id))
+        .json(&SetShippingAddressRequest {
+            postal_code: "1500001".to_string(),
+            prefecture: "東京都".to_string(),
+            city: "渋谷区".to_string(),
+            address_line1: "神宮前1-1-1".to_string(),
+            address_line2: None,
+        })
+        .await
+        .assert_status_success();
+
+    (order_id, book_id)
+}
+
+async fn order(server: &TestServer, order_id: Uuid) -> OrderDetailResponse {
+    server.get(&format!("/orders/{}", order_id)).await.json()
+}
+
+async fn stock(server: &TestServer, book_id: Uuid) -> u32 {
+    server
+        .get(&format!("/inventory/{}", book_id))
+        .await
+        .json::<InventoryResponse>()
+        .quantity_on_hand
+}
+
+async fn order_status_in_db(pool: &Pool<Sqlite>, order_id: Uuid) -> String {
+    sqlx::query("SELECT status FROM orders WHERE id = ?")
+        .bind(order_id.to_string())
+        .fetch_one(pool)
+        .await
+        .unwrap()
+        .get("status")
+}
+
+async fn stock_in_db(pool: &Pool<Sqlite>, book_id: Uuid) -> u32 {
+    sqlx::query("SELECT quantity_on_hand FROM inventories WHERE book_id = ?")
+        .bind(book_id.to_string())
+        .fetch_one(pool)
+        .await
+        .unwrap()
+        .get("quantity_on_hand")
+}
+
+#[tokio::test]
+async fn test_health_check() {
+    let (server, _) = server().await;
+
+    let response = server.get("/health").await;
+
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["status"], "healthy");
+    // バックグラウンドタスクもSQLiteモードで起動している
+    server.get("/health/ready").await.assert_status_ok();
+}
+
+/// 在庫作成 → 注文 → 確定（在庫予約） → 発送 → 配達完了の正常系
+#[tokio::test]
+async fn test_happy_path_order_lifecycle() {
+    let (server, pool) = server().await;
+
+    let (order_id, book_id) = prepare_order(&server, 5, 2).await;
+    server
+        .post(&format!("/orders/{}/confirm", order_id))
+        .await
+        .assert_status_success();
+    assert_eq!(stock(&server, book_id).await, 3);
+
+    server
+        .post(&format!("/orders/{}/ship", order_id))
+        .await
+        .assert_status_success();
+    server
+        .post(&format!("/orders/{}/deliver", order_id))
+        .await
+        .assert_status_success();
+
+    let order = order(&server, order_id).await;
+    assert_eq!(order.status, "Delivered");
+    assert_eq!(order.order_lines.len(), 1);
+    assert_eq!(order.subtotal_amount, 3000);
+
+    // SQLiteの最終状態
+    assert_eq!(order_status_in_db(&pool, order_id).await, "Delivered");
+    assert_eq!(stock_in_db(&pool, book_id).await, 3);
+}
+
+/// 在庫不足で予約に失敗した注文が補償フローでキャンセルされ、在庫が変わらない
+#[tokio::test]
+async fn test_compensation_cancels_order_on_insufficient_stock() {
+    let (server, pool) = server().await;
+
+    let (order_id, book_id) = prepare_order(&server, 1, 3).await;
+    server
+        .post(&format!("/orders/{}/confirm", order_id))
+        .await
+        .assert_status_success();
+    assert_eq!(order(&server, order_id).await.status, "Cancelled");
+
+    // キャンセル済みの注文は発送できない
+    let response = server
+        .post(&format!("/orders/{}/ship", order_id))
+        .expect_failure()
+        .await;
+    assert_ne!(response.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    // SQLiteの最終状態
+    assert_eq!(order_status_in_db(&pool, order_id).await, "Cancelled");
+    assert_eq!(stock_in_db(&pool, book_id).await, 1);
+}
+
+#[tokio::test]
+async fn test_requests_without_token_are_rejected() {
+    let (server, _) = server().await;
+
+    server
+        .get(&format!("/orders/{}", Uuid::new_v4()))
+        .clear_headers()
+        .expect_failure()
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_unknown_order_returns_not_found() {
+    let (server, _) = server().await;
+
+    server
+        .get(&format!("/orders/{}", Uuid::new_v4()))
+        .expect_failure()
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
@@ -1,22 +1,103 @@
-use bookstore_order_management::adapter::driven::{EventBusConfig, InMemoryEventBus};
-use bookstore_order_management::application::service::OrderApplicationService;
-use bookstore_order_management::domain::event::{DomainEvent, OrderConfirmed};
-use bookstore_order_management::domain::event_bus::EventHandler;
+use bookstore_order_management::adapter::driven::{
+    EventBusConfig, HmacActionLinkSigner, InMemoryEventBus, InMemoryInventoryRepository, InMemoryNotificationHub, InMemoryOrderRepository, S3CompatibleObjectStorage, SimulatedCarrierAdapter,
+    SimulatedPaymentGateway, SqliteInventoryRepository, SqliteOrderRepository,
+    TokenDownloadLinkGenerator,
+};
+use bookstore_order_management::application::service::{
+    ActionLinkApplicationService, BulkTransition, CatalogApplicationService, CheckoutHoldApplicationService, CustomerApplicationService, CycleCountApplicationService, DeviceApplicationService, EventTraceApplicationService, ForecastApplicationService, InventoryApplicationService,
+    NotificationApplicationService, OrderApplicationService, OrderLockApplicationService, OrderRepairApplicationService, PendingOperationApplicationService,
+    ProjectionApplicationService, TimelineApplicationService, TrackingApplicationService,
+    WaitlistApplicationService, WebhookApplicationService, MAX_BULK_TRANSITION_SIZE,
+};
+use bookstore_order_management::adapter::SqliteDatabase;
+use bookstore_order_management::application::ApplicationError;
+use bookstore_order_management::domain::action_link::{
+    ActionLinkIssuer, ActionLinkOutcome, ActionLinkUse, CustomerAction,
+};
+use bookstore_order_management::domain::bulk_cancellation::BulkCancellationFilter;
+use bookstore_order_management::domain::cancellation_policy::CancellationPolicy;
+use bookstore_order_management::domain::checkout_hold::{CheckoutHold, CheckoutHoldSweeper};
+use bookstore_order_management::domain::customer_export::{CustomerExportStatus, CustomerExporter};
+use bookstore_order_management::domain::customer_webhook::{
+    WebhookDelivery, WebhookDeliveryStatus, WebhookDispatcher, WebhookOutcome, WebhookSubscription,
+};
+use bookstore_order_management::domain::delivery_estimate::DeliveryEstimator;
+use bookstore_order_management::domain::error::DomainError;
+use bookstore_order_management::domain::event::{
+    CheckoutHoldExpired, CustomerBecameRepeatBuyer, DeliveryFailed, DigitalItemsFulfilled, DomainEvent, FulfillmentSlaBreached,
+    HighValueOrderPlaced, InventoryAdjusted, InventoryReserved, ItemsRestocked, OrderConfirmed, OrderDelivered, OrderShipped,
+    OrderStatusForcefullyChanged, ReturnApproved, ReturnRequested, SagaCompensationStarted, SagaTimedOut,
+    ShippingAddressChanged,
+};
+use bookstore_order_management::domain::event_bus::{EventHandler, HandlerError};
+use bookstore_order_management::domain::event_export::{
+    EventExportRange, EventExporter, EventImportError, EventImporter, JournaledEvent,
+};
+use bookstore_order_management::domain::event_trace::HandlerOutcome;
+use bookstore_order_management::domain::forecast::ForecastPolicy;
+use bookstore_order_management::domain::fulfillment_mode::FulfillmentMode;
 use bookstore_order_management::domain::handler::{
-    EventualConsistencyVerifier, InventoryReservationFailureCompensationHandler,
-    InventoryReservationHandler, NotificationHandler, SagaCompensationCoordinator,
+    AllocationReturnHandler, CustomerSegmentationHandler, CustomerWebhookHandler, DeliveryFailureCompensationHandler, DeliveryHandler,
+    EventualConsistencyVerifier, FulfillmentRouter, InventoryReservationFailureCompensationHandler,
+    InventoryReservationHandler, NotificationHandler, PushNotificationHandler, ReturnHandler, RiskHoldHandler,
+    SagaCompensationCoordinator, ShippingHandler, TrackingProjectionHandler,
+    WaitlistPromotionHandler,
 };
 use bookstore_order_management::domain::model::{
-    BookId, CustomerId, Inventory, Money, Order, OrderId, OrderStatus,
+    AllocationQuota, BookId, BookSpec, Customer, CustomerId, CycleCount, CycleCountId, CycleCountStatus, DevicePlatform,
+    DeviceRegistration, DeviceToken, EmailAddress, FulfillmentType, HoldReason, Inventory, LineAttribute, Money, Order,
+    OrderId, OrderNumber, OrderStatus, Recipient, SalesChannel, ShippingAddress, TrackingToken,
+};
+use bookstore_order_management::domain::book_translation::{BookTitles, Language};
+use bookstore_order_management::domain::late_event::{
+    LateEventGuard, LateEventPolicies, LateEventPolicy, ParkedEvent,
+};
+use bookstore_order_management::domain::notification_retry::{
+    ChannelRetryPolicy, FailedNotification, FailedNotificationStatus, Notification,
+    NotificationChannel, NotificationRetrier, NotificationRetryPolicy,
+};
+use bookstore_order_management::domain::port::{CustomerNotificationSource, EventBus, EventBusError};
+use bookstore_order_management::domain::pending_operation::{
+    PendingOperation, PendingOperationRetrier, PendingOperationStatus,
+};
+use bookstore_order_management::domain::pre_order::PreOrderReleaseJob;
+use bookstore_order_management::domain::order_lock::OrderLock;
+use bookstore_order_management::domain::order_note::OrderNote;
+use bookstore_order_management::domain::order_repair::{RepairRemediation, StuckOrderIssue};
+use bookstore_order_management::domain::order_timeline::TimelineEntry;
+use bookstore_order_management::domain::order_totals::OrderTotals;
+use bookstore_order_management::domain::pricing::PriceChangePolicy;
+use bookstore_order_management::domain::projection::{
+    ProjectionCheckpoint, ProjectionProgress, ProjectionRegistry,
+};
+use bookstore_order_management::domain::purchase_policy::{PurchaseLimits, PurchasePolicy};
+use bookstore_order_management::domain::segmentation::SegmentationRules;
+use bookstore_order_management::domain::saga_orchestrator::OrderSagaOrchestrator;
+use bookstore_order_management::domain::saga_timeout::{
+    SagaTimeoutMonitor, SagaTimeoutPolicy, SagaTimeoutStage,
+};
+use bookstore_order_management::domain::retry_policy::{
+    DeadLetterRouting, RetryPolicies, RetryPolicy,
 };
-use bookstore_order_management::domain::port::EventBus;
 use bookstore_order_management::domain::port::{
-    InventoryRepository, Logger, OrderRepository, RepositoryError,
+    ActionLinkAuditRepository, ActionLinkSigner, AllocationQuotaRepository, BookCatalog, CheckoutHoldRepository, CustomerRepository, CycleCountRepository, DeadLetterMonitor, DeviceRegistrationRepository,
+    EventJournal, FailedNotificationRepository, InventoryRepository, Logger, NotificationError, NotificationSender, ObjectStoragePort, OrderLockRepository, OrderNoteRepository, OrderNumberGenerator, OrderRepository,
+    ParkedEventRepository, PendingOperationRepository, ProcessedEventRepository, PushNotificationError, PushNotificationPort,
+    RepositoryError, StatusOverrideAuditRepository, TrackingEventRepository, WaitlistRepository, WebhookDeliveryRepository,
+    WebhookSender, WebhookSubscriptionRepository,
 };
 use bookstore_order_management::domain::serialization::{EventSerializer, SerializationError};
+use bookstore_order_management::domain::shipping_fee::{
+    CarrierLimits, ShippingFeePolicy, ShippingRateTable,
+};
+use bookstore_order_management::domain::sla::{SlaMonitor, SlaPolicy, SlaStage};
+use bookstore_order_management::domain::status_override::{StatusOverride, StatusOverrideAudit};
+use bookstore_order_management::domain::tracking::{TrackingEvent, TrackingStage};
+use bookstore_order_management::domain::waitlist::WaitlistEntry;
 
 use async_trait::async_trait;
-use std::collections::HashMap;
+use chrono::{DateTime, Datelike, TimeDelta, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use uuid::Uuid;
@@ -65,97 +146,76 @@ fn test_event_round_trip(event: &DomainEvent) -> Result<bool, SerializationError
         && event.metadata().correlation_id == deserialized.metadata().correlation_id)
 }
 
-// テスト用のモックリポジトリ
-struct MockOrderRepository {
-    orders: Arc<Mutex<HashMap<OrderId, Order>>>,
+// テスト用のモックリポジトリ（注文・在庫はInMemoryOrderRepository・InMemoryInventoryRepositoryを使う）
+struct MockCycleCountRepository {
+    cycle_counts: Arc<Mutex<HashMap<CycleCountId, CycleCount>>>,
 }
 
-impl MockOrderRepository {
+impl MockCycleCountRepository {
     fn new() -> Self {
         Self {
-            orders: Arc::new(Mutex::new(HashMap::new())),
+            cycle_counts: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 }
 
 #[async_trait]
-impl OrderRepository for MockOrderRepository {
-    async fn save(&self, order: &Order) -> Result<(), RepositoryError> {
-        let mut orders = self.orders.lock().await;
-        orders.insert(order.id(), order.clone());
+impl CycleCountRepository for MockCycleCountRepository {
+    async fn save(&self, cycle_count: &CycleCount) -> Result<(), RepositoryError> {
+        let mut cycle_counts = self.cycle_counts.lock().await;
+        cycle_counts.insert(cycle_count.id(), cycle_count.clone());
         Ok(())
     }
 
-    async fn find_by_id(&self, order_id: OrderId) -> Result<Option<Order>, RepositoryError> {
-        let orders = self.orders.lock().await;
-        Ok(orders.get(&order_id).cloned())
-    }
-
-    async fn find_all(&self) -> Result<Vec<Order>, RepositoryError> {
-        let orders = self.orders.lock().await;
-        Ok(orders.values().cloned().collect())
-    }
-
-    async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, RepositoryError> {
-        let orders = self.orders.lock().await;
-        Ok(orders
-            .values()
-            .filter(|order| order.status() == status)
-            .cloned()
-            .collect())
-    }
-
-    fn next_identity(&self) -> OrderId {
-        OrderId::new()
+    async fn find_by_id(
+        &self,
+        cycle_count_id: CycleCountId,
+    ) -> Result<Option<CycleCount>, RepositoryError> {
+        let cycle_counts = self.cycle_counts.lock().await;
+        Ok(cycle_counts.get(&cycle_count_id).cloned())
     }
 }
 
-struct MockInventoryRepository {
-    inventories: Arc<Mutex<HashMap<BookId, Inventory>>>,
+#[derive(Default)]
+struct MockProcessedEventRepository {
+    processed: Mutex<HashSet<(Uuid, String)>>,
 }
 
-impl MockInventoryRepository {
-    fn new() -> Self {
-        Self {
-            inventories: Arc::new(Mutex::new(HashMap::new())),
-        }
+#[async_trait]
+impl ProcessedEventRepository for MockProcessedEventRepository {
+    async fn is_processed(
+        &self,
+        event_id: Uuid,
+        handler_name: &str,
+    ) -> Result<bool, RepositoryError> {
+        let processed = self.processed.lock().await;
+        Ok(processed.contains(&(event_id, handler_name.to_string())))
     }
 
-    async fn add_inventory(&self, inventory: Inventory) {
-        let mut inventories = self.inventories.lock().await;
-        inventories.insert(inventory.book_id(), inventory);
+    async fn mark_processed(
+        &self,
+        event_id: Uuid,
+        handler_name: &str,
+        _processed_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut processed = self.processed.lock().await;
+        processed.insert((event_id, handler_name.to_string()));
+        Ok(())
     }
 }
 
+// 在庫調整イベントを記録するテスト用ハンドラー
+#[derive(Clone)]
+struct InventoryAdjustedRecorder {
+    events: Arc<Mutex<Vec<InventoryAdjusted>>>,
+}
+
 #[async_trait]
-impl InventoryRepository for MockInventoryRepository {
-    async fn save(&self, inventory: &Inventory) -> Result<(), RepositoryError> {
-        let mut inventories = self.inventories.lock().await;
-        inventories.insert(inventory.book_id(), inventory.clone());
+impl EventHandler<InventoryAdjusted> for InventoryAdjustedRecorder {
+    async fn handle(&self, event: InventoryAdjusted) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
         Ok(())
     }
-
-    async fn find_by_book_id(&self, book_id: BookId) -> Result<Option<Inventory>, RepositoryError> {
-        let inventories = self.inventories.lock().await;
-        Ok(inventories.get(&book_id).cloned())
-    }
-
-    async fn find_all(&self) -> Result<Vec<Inventory>, RepositoryError> {
-        let inventories = self.inventories.lock().await;
-        Ok(inventories.values().cloned().collect())
-    }
-
-    async fn find_by_max_quantity(
-        &self,
-        max_quantity: u32,
-    ) -> Result<Vec<Inventory>, RepositoryError> {
-        let inventories = self.inventories.lock().await;
-        Ok(inventories
-            .values()
-            .filter(|inv| inv.quantity_on_hand() <= max_quantity)
-            .cloned()
-            .collect())
-    }
 }
 
 /// **Feature: choreography-saga-refactoring, Property 4: Eventual Consistency Across Aggregates**
@@ -163,15 +223,19 @@ impl InventoryRepository for MockInventoryRepository {
 #[tokio::test]
 async fn test_complete_order_lifecycle_saga_flow() {
     // インフラストラクチャの設定（リトライを有効にして冪等性の問題を検証）
-    let inventory_repo = Arc::new(MockInventoryRepository::new());
-    let order_repo = Arc::new(MockOrderRepository::new());
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
 
     // 通常のリトライ設定でイベントバスを作成（冪等性の問題を露呈させる）
     let config = EventBusConfig {
-        max_retry_attempts: 3, // リトライを有効にして冪等性の問題を検証
-        retry_delay: std::time::Duration::from_millis(50),
+        // リトライを有効にして冪等性の問題を検証
+        retry_policies: RetryPolicies {
+            default_policy: RetryPolicy::new(3, 50, 1, 50, DeadLetterRouting::DeadLetter).unwrap(),
+            ..RetryPolicies::default()
+        },
         dead_letter_queue_max_size: 100,
         handler_timeout: std::time::Duration::from_secs(5),
+        ..EventBusConfig::default()
     };
     let event_bus = Arc::new(InMemoryEventBus::new(config));
 
@@ -181,6 +245,7 @@ async fn test_complete_order_lifecycle_saga_flow() {
         inventory_repo.clone(),
         order_repo.clone(),
         event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
         logger.clone(),
     );
     let notification_handler = NotificationHandler::new(logger.clone());
@@ -207,9 +272,7 @@ async fn test_complete_order_lifecycle_saga_flow() {
 
     // アプリケーションサービスの作成
     let app_service = OrderApplicationService::new(
-        MockOrderRepository {
-            orders: order_repo.orders.clone(),
-        },
+        order_repo.as_ref().clone(),
         event_bus.clone(),
     );
 
@@ -279,19 +342,80 @@ async fn test_complete_order_lifecycle_saga_flow() {
     );
 }
 
+/// 注文確定から在庫予約までのサーガを、モックではなくSQLiteのリポジトリで実行するテスト
+#[tokio::test]
+async fn test_order_saga_reserves_inventory_with_sqlite_repositories() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let inventory_repo = Arc::new(SqliteInventoryRepository::new(pool.clone()));
+    let order_repo = Arc::new(SqliteOrderRepository::new(pool.clone()));
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+
+    event_bus
+        .subscribe_order_confirmed(InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::default()),
+            Arc::new(MockLogger),
+        ))
+        .await
+        .unwrap();
+    let app_service = OrderApplicationService::new(SqliteOrderRepository::new(pool), event_bus);
+
+    let book_id = BookId::new();
+    inventory_repo
+        .save(&Inventory::new(book_id, 10))
+        .await
+        .unwrap();
+
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, book_id, 3, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+
+    let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::Confirmed);
+    let inventory = inventory_repo
+        .find_by_book_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 7);
+    // 在庫の予約は在庫の変動として記録される
+    let movements = inventory_repo.find_movements_since(0, 10).await.unwrap();
+    let deltas: Vec<i64> = movements.iter().map(|movement| movement.quantity_delta).collect();
+    assert_eq!(deltas, vec![10, -3]);
+}
+
 /// **Feature: choreography-saga-refactoring, Property 25: Event Handler Idempotency**
 /// イベントハンドラーの冪等性テスト
 /// 同じイベントが複数回処理されても結果が同じであることを検証
 #[tokio::test]
 async fn test_event_handler_idempotency() {
-    let inventory_repo = Arc::new(MockInventoryRepository::new());
-    let order_repo = Arc::new(MockOrderRepository::new());
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
     let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
     let logger = Arc::new(MockLogger);
     let handler = InventoryReservationHandler::new(
         inventory_repo.clone(),
         order_repo.clone(),
         event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
         logger,
     );
 
@@ -327,16 +451,19 @@ async fn test_event_handler_idempotency() {
             None,
         )
         .unwrap(),
-    );
+    ).unwrap();
     order.confirm().unwrap();
-    order_repo.save(&order).await.unwrap();
+    order_repo.save(&mut order).await.unwrap();
 
     // 同じイベントを複数回処理
     let result1 = handler.handle(event.clone()).await;
     let result2 = handler.handle(event.clone()).await;
     let result3 = handler.handle(event.clone()).await;
 
-    // 全ての処理が成功することを確認（現在の実装では2回目以降は失敗する可能性がある）
+    // 全ての処理が成功することを確認（2回目以降は冪等性チェックでスキップされる）
+    assert!(result1.is_ok());
+    assert!(result2.is_ok());
+    assert!(result3.is_ok());
 
     // 在庫の最終状態を確認
     let final_inventory = inventory_repo
@@ -361,8 +488,8 @@ async fn test_event_handler_idempotency() {
 #[tokio::test]
 async fn test_saga_compensation_flow() {
     // インフラストラクチャの設定
-    let inventory_repo = Arc::new(MockInventoryRepository::new());
-    let order_repo = Arc::new(MockOrderRepository::new());
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
     let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
 
     // ハンドラーの作成
@@ -371,6 +498,7 @@ async fn test_saga_compensation_flow() {
         inventory_repo.clone(),
         order_repo.clone(),
         event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
         logger.clone(),
     );
     let compensation_handler =
@@ -393,9 +521,7 @@ async fn test_saga_compensation_flow() {
 
     // アプリ���ーションサービスの作成
     let app_service = OrderApplicationService::new(
-        MockOrderRepository {
-            orders: order_repo.orders.clone(),
-        },
+        order_repo.as_ref().clone(),
         event_bus.clone(),
     );
 
@@ -466,8 +592,8 @@ async fn test_saga_compensation_flow() {
 #[tokio::test]
 async fn test_concurrent_handler_processing() {
     let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
-    let order_repo = Arc::new(MockOrderRepository::new());
-    let inventory_repo = Arc::new(MockInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
 
     // 複数の異なるハンドラーを登録
     let logger = Arc::new(MockLogger);
@@ -564,14 +690,15 @@ async fn test_event_serialization_round_trip() {
 /// 注文確定サーガステップのテスト
 #[tokio::test]
 async fn test_order_confirmation_saga_step() {
-    let inventory_repo = Arc::new(MockInventoryRepository::new());
-    let order_repo = Arc::new(MockOrderRepository::new());
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
     let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
     let logger = Arc::new(MockLogger);
     let handler = InventoryReservationHandler::new(
         inventory_repo.clone(),
         order_repo.clone(),
         event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
         logger,
     );
 
@@ -603,9 +730,9 @@ async fn test_order_confirmation_saga_step() {
             None,
         )
         .unwrap(),
-    );
+    ).unwrap();
     order.confirm().unwrap();
-    order_repo.save(&order).await.unwrap();
+    order_repo.save(&mut order).await.unwrap();
 
     // イベントを発行
     let result = event_bus.publish(DomainEvent::OrderConfirmed(event)).await;
@@ -842,3 +969,5984 @@ async fn test_serialization_utility_functions() {
     assert!(round_trip_result.is_ok());
     assert!(round_trip_result.unwrap());
 }
+
+/// 購入ポリシー（購入数量・未完了注文数の上限）の適用テスト
+#[tokio::test]
+async fn test_purchase_policy_limits_are_enforced() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus)
+        .with_purchase_policy(PurchasePolicy::new(PurchaseLimits {
+            max_quantity_per_book_per_day: Some(3),
+            max_open_orders_per_customer: Some(2),
+        }));
+
+    let customer_id = CustomerId::new();
+    let book_id = BookId::new();
+
+    // 同じ書籍を複数の注文にまたがって上限を超えて購入することはできない
+    let first_order = app_service.create_order(customer_id).await.unwrap();
+    app_service
+        .add_book_to_order(first_order, book_id, 2, Money::jpy(1000))
+        .await
+        .unwrap();
+
+    let second_order = app_service.create_order(customer_id).await.unwrap();
+    let result = app_service
+        .add_book_to_order(second_order, book_id, 2, Money::jpy(1000))
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(
+            DomainError::PurchaseQuantityLimitExceeded(_)
+        ))
+    ));
+
+    // 未完了の注文数が上限に達している場合は新しい注文を作成できない
+    let result = app_service.create_order(customer_id).await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(
+            DomainError::OpenOrderLimitExceeded(_)
+        ))
+    ));
+
+    // 注文をキャンセルすると再び注文を作成できる
+    app_service.cancel_order(second_order).await.unwrap();
+    assert!(app_service.create_order(customer_id).await.is_ok());
+}
+
+/// 購入数量の上限は直近24時間に作成された注文だけを数える
+#[tokio::test]
+async fn test_purchase_quantity_limit_ignores_orders_outside_window() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let order_repo = InMemoryOrderRepository::new();
+    let app_service = OrderApplicationService::new(order_repo.clone(), event_bus)
+        .with_purchase_policy(PurchasePolicy::new(PurchaseLimits {
+            max_quantity_per_book_per_day: Some(3),
+            max_open_orders_per_customer: None,
+        }));
+
+    let customer_id = CustomerId::new();
+    let book_id = BookId::new();
+    let old_order = app_service.create_order(customer_id).await.unwrap();
+    app_service
+        .add_book_to_order(old_order, book_id, 3, Money::jpy(1000))
+        .await
+        .unwrap();
+
+    // 集計期間の開始直前（25時間前）に作成された注文は数えない
+    order_repo
+        .set_created_at(old_order, Utc::now() - TimeDelta::hours(25))
+        .await;
+    let recent_order = app_service.create_order(customer_id).await.unwrap();
+    app_service
+        .add_book_to_order(recent_order, book_id, 3, Money::jpy(1000))
+        .await
+        .unwrap();
+
+    // 集計期間内（23時間前）に作成された注文は数える
+    order_repo
+        .set_created_at(recent_order, Utc::now() - TimeDelta::hours(23))
+        .await;
+    let next_order = app_service.create_order(customer_id).await.unwrap();
+    let result = app_service
+        .add_book_to_order(next_order, book_id, 1, Money::jpy(1000))
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(
+            DomainError::PurchaseQuantityLimitExceeded(_)
+        ))
+    ));
+}
+
+/// 同じ顧客の注文を同時に作成しても、未完了の注文数の上限を超えない
+#[tokio::test]
+async fn test_open_order_limit_holds_under_concurrent_creation() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = Arc::new(
+        OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus)
+            .with_purchase_policy(PurchasePolicy::new(PurchaseLimits {
+                max_quantity_per_book_per_day: None,
+                max_open_orders_per_customer: Some(2),
+            })),
+    );
+
+    let customer_id = CustomerId::new();
+    let tasks: Vec<_> = (0..10)
+        .map(|_| {
+            let app_service = app_service.clone();
+            tokio::spawn(async move { app_service.create_order(customer_id).await })
+        })
+        .collect();
+    let mut created = 0;
+    for task in tasks {
+        if task.await.unwrap().is_ok() {
+            created += 1;
+        }
+    }
+
+    assert_eq!(created, 2);
+}
+
+/// 購入ポリシーを設定しない場合は購入数量・未完了注文数を制限しない
+#[tokio::test]
+async fn test_default_purchase_policy_does_not_limit_orders() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus);
+
+    let customer_id = CustomerId::new();
+    let book_id = BookId::new();
+    for _ in 0..6 {
+        let order_id = app_service.create_order(customer_id).await.unwrap();
+        app_service
+            .add_book_to_order(order_id, book_id, 20, Money::jpy(1000))
+            .await
+            .unwrap();
+    }
+}
+
+/// 予約注文のフローテスト
+/// 未発売の書籍を含む注文は在庫を予約せずに発売待ちになり、
+/// 発売日ジョブによって在庫予約以降のサーガが再開されることを検証
+#[tokio::test]
+async fn test_pre_order_is_activated_on_release_date() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+
+    let inventory_handler = InventoryReservationHandler::new(
+        inventory_repo.clone(),
+        order_repo.clone(),
+        event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
+        logger.clone(),
+    );
+    event_bus
+        .subscribe_order_confirmed(inventory_handler.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_pre_order_activated(inventory_handler)
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+
+    // 明日発売の書籍の在庫を用意
+    let today = Utc::now().date_naive();
+    let release_date = today.succ_opt().unwrap();
+    let book_id = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(book_id, 10).with_release_date(Some(release_date)))
+        .await;
+
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, book_id, 2, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+    // 確定しても在庫は予約されず、発売待ちになる
+    let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::AwaitingRelease);
+    let inventory = inventory_repo.find_by_book_id(book_id).await.unwrap().unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 10);
+
+    let job = PreOrderReleaseJob::new(
+        order_repo.clone(),
+        inventory_repo.clone(),
+        event_bus.clone(),
+        logger.clone(),
+    );
+
+    // 発売日前は何もしない
+    assert!(job.run(today).await.unwrap().is_empty());
+
+    // 発売日に予約注文が有効化され、在庫が予約される
+    assert_eq!(job.run(release_date).await.unwrap(), vec![order_id]);
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+    let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::Confirmed);
+    let inventory = inventory_repo.find_by_book_id(book_id).await.unwrap().unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 8);
+}
+
+/// 電子書籍のフルフィルメントテスト
+/// 電子書籍のみの注文は発送・配達を経ずにFulfilledになり、
+/// 物理書籍との混在注文は電子書籍のみリンクを発行して物理書籍の在庫を予約することを検証
+#[tokio::test]
+async fn test_digital_items_are_fulfilled_without_shipping() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+
+    event_bus
+        .subscribe_order_confirmed(InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::default()),
+            logger.clone(),
+        ))
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_inventory_reserved(FulfillmentRouter::new(
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(TokenDownloadLinkGenerator::new(
+                "https://downloads.example.com".to_string(),
+                TimeDelta::hours(72),
+            )),
+            Arc::new(MockProcessedEventRepository::default()),
+            logger.clone(),
+        ))
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+
+    let digital_book = BookId::new();
+    let physical_book = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(physical_book, 5))
+        .await;
+
+    // 電子書籍のみの注文（配送先住所は不要）
+    let digital_order = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order_with_fulfillment(
+            digital_order,
+            digital_book,
+            1,
+            Money::jpy(1200),
+            FulfillmentType::Digital,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(digital_order).await.unwrap();
+
+    // 物理書籍と電子書籍の混在注文
+    let mixed_order = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order_with_fulfillment(
+            mixed_order,
+            digital_book,
+            1,
+            Money::jpy(1200),
+            FulfillmentType::Digital,
+        )
+        .await
+        .unwrap();
+    app_service
+        .add_book_to_order(mixed_order, physical_book, 2, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            mixed_order,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(mixed_order).await.unwrap();
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
+
+    let order = order_repo.find_by_id(digital_order).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::Fulfilled);
+
+    // 混在注文は物理書籍の発送を待つ
+    let order = order_repo.find_by_id(mixed_order).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::Confirmed);
+
+    // 物理書籍の在庫のみ予約され、電子書籍の在庫は作成されない
+    let inventory = inventory_repo
+        .find_by_book_id(physical_book)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 3);
+    assert!(inventory_repo
+        .find_by_book_id(digital_book)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+/// 棚卸しの承認で差異が在庫に反映され、InventoryAdjustedイベントが発行されることを検証
+#[tokio::test]
+async fn test_cycle_count_approval_adjusts_inventory() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = InventoryAdjustedRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_inventory_adjusted(recorder.clone())
+        .await
+        .unwrap();
+
+    let service = CycleCountApplicationService::new(
+        Arc::new(MockCycleCountRepository::new()),
+        inventory_repo.clone(),
+        event_bus.clone(),
+    );
+
+    let shrinking_book = BookId::new();
+    let accurate_book = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(shrinking_book, 10))
+        .await;
+    inventory_repo
+        .add_inventory(Inventory::new(accurate_book, 5))
+        .await;
+
+    // 対象を省略すると全在庫が棚卸し対象になる
+    let cycle_count_id = service.start_cycle_count(vec![]).await.unwrap();
+    service
+        .record_counts(cycle_count_id, vec![(shrinking_book, 8), (accurate_book, 5)])
+        .await
+        .unwrap();
+
+    // 提出前は承認できない
+    let result = service
+        .approve_cycle_count(cycle_count_id, "manager".to_string())
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(
+            DomainError::InvalidCycleCountState(_)
+        ))
+    ));
+
+    service.submit_cycle_count(cycle_count_id).await.unwrap();
+
+    // 承認までは在庫は変わらない
+    let inventory = inventory_repo
+        .find_by_book_id(shrinking_book)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 10);
+
+    service
+        .approve_cycle_count(cycle_count_id, "manager".to_string())
+        .await
+        .unwrap();
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let cycle_count = service
+        .get_cycle_count(cycle_count_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cycle_count.status(), CycleCountStatus::Approved);
+
+    let inventory = inventory_repo
+        .find_by_book_id(shrinking_book)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 8);
+
+    // 差異のある書籍のみイベントが発行される
+    let events = recorder.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].book_id, shrinking_book);
+    assert_eq!(events[0].previous_quantity, 10);
+    assert_eq!(events[0].new_quantity, 8);
+    assert_eq!(events[0].variance, -2);
+    assert_eq!(events[0].reason, "cycle_count");
+    assert_eq!(
+        events[0].metadata.correlation_id,
+        cycle_count_id.as_uuid()
+    );
+}
+
+// SLA違反イベントを記録するテスト用ハンドラー
+#[derive(Clone)]
+struct SlaBreachRecorder {
+    events: Arc<Mutex<Vec<FulfillmentSlaBreached>>>,
+}
+
+#[async_trait]
+impl EventHandler<FulfillmentSlaBreached> for SlaBreachRecorder {
+    async fn handle(&self, event: FulfillmentSlaBreached) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+/// 期限を超過した発送待ちの注文に対してFulfillmentSlaBreachedが1回だけ発行されることを検証
+#[tokio::test]
+async fn test_sla_monitor_reports_each_breach_once() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = SlaBreachRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_fulfillment_sla_breached(recorder.clone())
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+
+    let policy = SlaPolicy {
+        ship_within: TimeDelta::hours(24),
+        deliver_within: TimeDelta::hours(48),
+        at_risk_window: TimeDelta::hours(6),
+    };
+    let monitor = SlaMonitor::new(
+        order_repo.clone(),
+        event_bus.clone(),
+        Arc::new(MockLogger),
+        policy,
+    );
+
+    // 期限内は違反なし（期限が近いためレポートでは違反の恐れあり）
+    let now = Utc::now();
+    assert!(monitor.run(now).await.unwrap().is_empty());
+    let report = app_service
+        .with_sla_policy(policy)
+        .get_sla_report(now + TimeDelta::hours(20))
+        .await
+        .unwrap();
+    assert_eq!(report.at_risk_orders.len(), 1);
+    assert_eq!(report.shipping.breached, 0);
+
+    // 期限超過後は1回だけ発行される
+    let later = now + TimeDelta::hours(25);
+    assert_eq!(monitor.run(later).await.unwrap().len(), 1);
+    assert!(monitor.run(later).await.unwrap().is_empty());
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let events = recorder.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].order_id, order_id);
+    assert_eq!(events[0].stage, SlaStage::Shipping);
+}
+
+// サーガのタイムアウトと補償の開始を記録するテスト用ハンドラー
+#[derive(Clone)]
+struct SagaTimeoutRecorder {
+    events: Arc<Mutex<Vec<DomainEvent>>>,
+}
+
+#[async_trait]
+impl EventHandler<SagaTimedOut> for SagaTimeoutRecorder {
+    async fn handle(&self, event: SagaTimedOut) -> Result<(), HandlerError> {
+        self.events.lock().await.push(DomainEvent::SagaTimedOut(event));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<SagaCompensationStarted> for SagaTimeoutRecorder {
+    async fn handle(&self, event: SagaCompensationStarted) -> Result<(), HandlerError> {
+        self.events
+            .lock()
+            .await
+            .push(DomainEvent::SagaCompensationStarted(event));
+        Ok(())
+    }
+}
+
+/// 在庫予約が記録されないまま期限を過ぎた注文と、配達完了にならないまま期限を過ぎた注文に対して
+/// SagaTimedOutが1回だけ発行され、補償が開始されることを検証
+#[tokio::test]
+async fn test_saga_timeout_monitor_reports_stalled_sagas_and_starts_compensation() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_journal = Arc::new(MockEventJournal::default());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = SagaTimeoutRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_saga_timed_out(recorder.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_saga_compensation_started(recorder.clone())
+        .await
+        .unwrap();
+
+    let now = Utc::now();
+    let order_at = |confirmed_at: DateTime<Utc>, shipped_at: Option<DateTime<Utc>>| {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+        order
+            .set_shipping_address(
+                ShippingAddress::new(
+                    "1234567".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "道玄坂1-1-1".to_string(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        order.confirm().unwrap();
+        if shipped_at.is_some() {
+            order.mark_as_shipped().unwrap();
+        }
+        order.with_transition_times(Some(confirmed_at), shipped_at, None)
+    };
+    // 在庫予約が記録されないまま停滞している注文
+    let unreserved = order_at(now - TimeDelta::hours(1), None);
+    // 在庫予約済みで発送を待っている注文
+    let reserved = order_at(now - TimeDelta::hours(1), None);
+    // 確定直後の注文
+    let recent = order_at(now - TimeDelta::minutes(5), None);
+    // 発送から8日経っても配達完了にならない注文
+    let undelivered = order_at(now - TimeDelta::days(9), Some(now - TimeDelta::days(8)));
+    event_journal
+        .append(&DomainEvent::InventoryReserved(
+            InventoryReserved::with_correlation_id(
+                reserved.id(),
+                reserved.order_lines().to_vec(),
+                Uuid::new_v4(),
+            ),
+        ))
+        .await
+        .unwrap();
+    for order in [&unreserved, &reserved, &recent, &undelivered] {
+        order_repo.add_order(order.clone()).await;
+    }
+
+    let monitor = SagaTimeoutMonitor::new(
+        order_repo.clone(),
+        event_journal.clone(),
+        event_bus.clone(),
+        Arc::new(MockLogger),
+        SagaTimeoutPolicy::default(),
+    );
+    let timed_out = monitor.run(now).await.unwrap();
+    let stages: HashSet<_> = timed_out
+        .iter()
+        .map(|timeout| (timeout.order_id, timeout.stage))
+        .collect();
+    assert_eq!(
+        stages,
+        HashSet::from([
+            (unreserved.id(), SagaTimeoutStage::InventoryReservation),
+            (undelivered.id(), SagaTimeoutStage::Delivery),
+        ])
+    );
+    // 発行済みのタイムアウトは再発行しない
+    assert!(monitor.run(now).await.unwrap().is_empty());
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let events = recorder.events.lock().await;
+    let timed_out_events: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            DomainEvent::SagaTimedOut(e) => Some(e),
+            _ => None,
+        })
+        .collect();
+    let compensations: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            DomainEvent::SagaCompensationStarted(e) => Some(e),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(timed_out_events.len(), 2);
+    assert_eq!(compensations.len(), 2);
+    for timed_out in timed_out_events {
+        let compensation = compensations
+            .iter()
+            .find(|c| c.saga_id == timed_out.saga_id)
+            .expect("タイムアウトしたサーガの補償が開始されていない");
+        assert_eq!(compensation.failed_step, timed_out.stage.name());
+        if timed_out.stage == SagaTimeoutStage::Delivery {
+            assert_eq!(timed_out.order_id, undelivered.id());
+            assert_eq!(
+                compensation.compensation_steps,
+                vec!["shipping".to_string(), "inventory_reservation".to_string()]
+            );
+        }
+    }
+}
+
+// テスト用のモックデバイス登録リポジトリ
+struct MockDeviceRegistrationRepository {
+    registrations: Arc<Mutex<Vec<DeviceRegistration>>>,
+}
+
+#[async_trait]
+impl DeviceRegistrationRepository for MockDeviceRegistrationRepository {
+    async fn save(&self, registration: &DeviceRegistration) -> Result<(), RepositoryError> {
+        let mut registrations = self.registrations.lock().await;
+        registrations.retain(|r| {
+            !(r.customer_id() == registration.customer_id() && r.token() == registration.token())
+        });
+        registrations.push(registration.clone());
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        customer_id: CustomerId,
+        token: &DeviceToken,
+    ) -> Result<bool, RepositoryError> {
+        let mut registrations = self.registrations.lock().await;
+        let before = registrations.len();
+        registrations.retain(|r| !(r.customer_id() == customer_id && r.token() == token));
+        Ok(registrations.len() < before)
+    }
+
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<DeviceRegistration>, RepositoryError> {
+        let registrations = self.registrations.lock().await;
+        Ok(registrations
+            .iter()
+            .filter(|r| r.customer_id() == customer_id)
+            .cloned()
+            .collect())
+    }
+}
+
+// トピック購読と配信を記録するテスト用プッシュ通知ポート
+#[derive(Default)]
+struct RecordingPushNotification {
+    subscriptions: Mutex<Vec<(String, String)>>,
+    published: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait]
+impl PushNotificationPort for RecordingPushNotification {
+    async fn subscribe_to_topic(
+        &self,
+        token: &DeviceToken,
+        topic: &str,
+    ) -> Result<(), PushNotificationError> {
+        self.subscriptions
+            .lock()
+            .await
+            .push((token.to_string(), topic.to_string()));
+        Ok(())
+    }
+
+    async fn unsubscribe_from_topic(
+        &self,
+        token: &DeviceToken,
+        topic: &str,
+    ) -> Result<(), PushNotificationError> {
+        self.subscriptions
+            .lock()
+            .await
+            .retain(|(t, s)| !(t == token.as_str() && s == topic));
+        Ok(())
+    }
+
+    async fn publish_to_topic(
+        &self,
+        topic: &str,
+        payload: &str,
+    ) -> Result<(), PushNotificationError> {
+        self.published
+            .lock()
+            .await
+            .push((topic.to_string(), payload.to_string()));
+        Ok(())
+    }
+}
+
+/// 登録したデバイスの顧客トピックへ注文ステータスの変化が配信されることを検証
+#[tokio::test]
+async fn test_order_status_changes_are_pushed_to_customer_topic() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let push = Arc::new(RecordingPushNotification::default());
+
+    let push_handler =
+        PushNotificationHandler::new(order_repo.clone(), push.clone(), Arc::new(MockLogger));
+    event_bus
+        .subscribe_order_confirmed(push_handler.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_shipped(push_handler)
+        .await
+        .unwrap();
+
+    // デバイスを登録すると顧客トピックに購読される
+    let customer_id = CustomerId::new();
+    let device_service = DeviceApplicationService::new(
+        Arc::new(MockDeviceRegistrationRepository {
+            registrations: Arc::new(Mutex::new(Vec::new())),
+        }),
+        push.clone(),
+    );
+    let token = DeviceToken::new("device-token-1".to_string()).unwrap();
+    let registration = device_service
+        .register_device(customer_id, token.clone(), DevicePlatform::Ios)
+        .await
+        .unwrap();
+    let topic = registration.topic();
+    assert_eq!(
+        push.subscriptions.lock().await.clone(),
+        vec![(token.to_string(), topic.clone())]
+    );
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let order_id = app_service.create_order(customer_id).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    app_service.mark_order_as_shipped(order_id, None).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let published = push.published.lock().await.clone();
+    let statuses: Vec<String> = published
+        .iter()
+        .map(|(published_topic, payload)| {
+            assert_eq!(published_topic, &topic);
+            let payload: serde_json::Value = serde_json::from_str(payload).unwrap();
+            assert_eq!(payload["order_id"], order_id.to_string());
+            payload["status"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(statuses, vec!["Confirmed", "Shipped"]);
+
+    // 登録解除で購読も解除され、未登録のトークンはNotFound
+    device_service
+        .unregister_device(customer_id, token.clone())
+        .await
+        .unwrap();
+    assert!(push.subscriptions.lock().await.is_empty());
+    assert!(matches!(
+        device_service.unregister_device(customer_id, token).await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+// 配送先住所変更イベントを記録するテスト用ハンドラー
+#[derive(Clone)]
+struct ShippingAddressChangedRecorder {
+    events: Arc<Mutex<Vec<ShippingAddressChanged>>>,
+}
+
+#[async_trait]
+impl EventHandler<ShippingAddressChanged> for ShippingAddressChangedRecorder {
+    async fn handle(&self, event: ShippingAddressChanged) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+/// 確定後・発送前の住所変更でShippingAddressChangedが発行され、発送後は拒否されることを検証
+#[tokio::test]
+async fn test_shipping_address_change_after_confirmation() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = ShippingAddressChangedRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_shipping_address_changed(recorder.clone())
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus.clone());
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    let set_address = |city: &str| {
+        app_service.set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            city.to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+    };
+
+    // 確定前の設定ではイベントは発行されない
+    set_address("渋谷区").await.unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    set_address("新宿区").await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    {
+        let events = recorder.events.lock().await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].order_id, order_id);
+        assert_eq!(
+            events[0].previous_address.as_ref().map(|a| a.city()),
+            Some("渋谷区")
+        );
+        assert_eq!(events[0].new_address.city(), "新宿区");
+        assert_eq!(events[0].shipping_fee, Money::jpy(500));
+    }
+
+    // 発送後の変更はドメインで拒否される
+    app_service.mark_order_as_shipped(order_id, None).await.unwrap();
+    let result = set_address("港区").await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(DomainError::InvalidOrderState(_)))
+    ));
+    let order = app_service.get_order_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.shipping_address().unwrap().city(), "新宿区");
+}
+
+/// 重量と配送先の都道府県から配送料を算出し、配送業者の上限を超える注文は発送できないことを検証
+#[tokio::test]
+async fn test_shipping_fee_by_weight_and_carrier_limits() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = ShippingAddressChangedRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_shipping_address_changed(recorder.clone())
+        .await
+        .unwrap();
+
+    let policy = ShippingFeePolicy::new(
+        ShippingRateTable::default(),
+        10_000,
+        CarrierLimits {
+            max_weight_grams: 5_000,
+            max_size_cm: 160,
+        },
+    );
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus.clone())
+        .with_shipping_fee_policy(policy);
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    let book_id = BookId::new();
+    let spec = BookSpec::new(1_200, 182, 257, 40).unwrap();
+    app_service
+        .add_book_to_order_with_spec(
+            order_id,
+            book_id,
+            3,
+            Money::jpy(1500),
+            FulfillmentType::Physical,
+            Some(spec),
+        )
+        .await
+        .unwrap();
+    let set_address_for = |order_id: OrderId| {
+        app_service.set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+    };
+    let set_address = |prefecture: &str| {
+        app_service.set_shipping_address_from_request(
+            order_id,
+            "0600001".to_string(),
+            prefecture.to_string(),
+            "札幌市".to_string(),
+            "北1条西1-1".to_string(),
+            None,
+        )
+    };
+    set_address_for(order_id).await.unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+
+    // 3.6kg → 800円、北海道への変更で300円を追加
+    set_address("北海道").await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    {
+        let events = recorder.events.lock().await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].previous_shipping_fee, Money::jpy(800));
+        assert_eq!(events[0].shipping_fee, Money::jpy(1100));
+    }
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let breakdown = app_service.shipping_fee_policy().quote(&order);
+    assert_eq!(breakdown.total_weight_grams, 3_600);
+    assert_eq!(breakdown.prefecture_surcharge, 300);
+
+    // 1.2kg × 5冊 = 6kgは配送業者の上限（5kg）を超えるため発送できない
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order_with_spec(
+            order_id,
+            BookId::new(),
+            5,
+            Money::jpy(1500),
+            FulfillmentType::Physical,
+            Some(spec),
+        )
+        .await
+        .unwrap();
+    set_address_for(order_id).await.unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+
+    let result = app_service.mark_order_as_shipped(order_id, None).await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(
+            DomainError::CarrierLimitExceeded(_)
+        ))
+    ));
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order.status(), OrderStatus::Confirmed);
+}
+
+#[derive(Clone)]
+struct OrderShippedRecorder {
+    events: Arc<Mutex<Vec<OrderShipped>>>,
+}
+
+#[async_trait]
+impl EventHandler<OrderShipped> for OrderShippedRecorder {
+    async fn handle(&self, event: OrderShipped) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+/// ギフト注文で納品書の金額が伏せられ、発送イベントに受取人が含まれることを検証
+#[tokio::test]
+async fn test_gift_order_with_separate_recipient() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = OrderShippedRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_order_shipped(recorder.clone())
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus.clone());
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 2, Money::jpy(1500))
+        .await
+        .unwrap();
+    let address = ShippingAddress::new(
+        "0600001".to_string(),
+        "北海道".to_string(),
+        "札幌市".to_string(),
+        "北1条西1-1".to_string(),
+        None,
+    )
+    .unwrap();
+    let recipient =
+        Recipient::new("山田花子".to_string(), "090-1234-5678".to_string(), address).unwrap();
+    app_service
+        .set_gift_recipient(order_id, recipient.clone())
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+
+    // 納品書は受取人宛てで、金額を記載しない
+    let slip = app_service.get_packing_slip(order_id).await.unwrap();
+    assert!(slip.gift);
+    assert_eq!(
+        slip.ship_to.as_ref().unwrap().name.as_deref(),
+        Some("山田花子")
+    );
+    assert_eq!(slip.lines[0].quantity, 2);
+    assert_eq!(slip.lines[0].unit_price, None);
+    assert_eq!(slip.total, None);
+
+    // 発送イベントには受取人が含まれる
+    app_service.mark_order_as_shipped(order_id, None).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let events = recorder.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].recipient.as_ref(), Some(&recipient));
+}
+
+/// まとめての発送では注文ごとに遷移してイベントを発行し、失敗した注文があっても残りを処理することを検証
+#[tokio::test]
+async fn test_bulk_ship_reports_each_order_and_continues_after_failures() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = OrderShippedRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_order_shipped(recorder.clone())
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus.clone());
+    let mut confirmed = Vec::new();
+    for _ in 0..2 {
+        let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+        app_service
+            .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+            .await
+            .unwrap();
+        app_service
+            .set_shipping_address_from_request(
+                order_id,
+                "1234567".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "道玄坂1-1-1".to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+        app_service.confirm_order(order_id).await.unwrap();
+        confirmed.push(order_id);
+    }
+    let pending = app_service.create_order(CustomerId::new()).await.unwrap();
+    let unknown = OrderId::new();
+
+    // 重複して指定された注文は1回だけ処理する
+    let outcomes = app_service
+        .bulk_transition(
+            BulkTransition::Ship,
+            &[confirmed[0], pending, unknown, confirmed[1], confirmed[0]],
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcomes.len(), 4);
+    assert!(outcomes[0].result.is_ok());
+    assert!(matches!(
+        outcomes[1].result,
+        Err(ApplicationError::DomainError(DomainError::InvalidOrderState(_)))
+    ));
+    assert!(matches!(outcomes[2].result, Err(ApplicationError::NotFound(_))));
+    assert_eq!(outcomes[3].order_id, confirmed[1]);
+    assert!(outcomes[3].result.is_ok());
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(recorder.events.lock().await.len(), 2);
+
+    let outcomes = app_service
+        .bulk_transition(BulkTransition::Deliver, &confirmed)
+        .await
+        .unwrap();
+    assert!(outcomes.iter().all(|outcome| outcome.result.is_ok()));
+
+    // 上限を超える指定は全体を拒否する
+    let too_many = vec![OrderId::new(); MAX_BULK_TRANSITION_SIZE + 1];
+    let result = app_service
+        .bulk_transition(BulkTransition::Ship, &too_many)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(DomainError::InvalidValue(_)))
+    ));
+}
+
+/// 一括キャンセルはプレビューで対象と確認トークンを返し、トークンが一致した場合だけ対象の注文をキャンセルすることを検証
+#[tokio::test]
+async fn test_bulk_cancel_requires_confirmation_of_preview() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let order_repo = InMemoryOrderRepository::new();
+    let app_service = OrderApplicationService::new(order_repo.clone(), event_bus);
+    // 一括キャンセルの対象は7日より前に作成された注文
+    let eight_days_ago = Utc::now() - TimeDelta::days(8);
+    let mut pending = Vec::new();
+    for _ in 0..2 {
+        let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+        order_repo.set_created_at(order_id, eight_days_ago).await;
+        pending.push(order_id);
+    }
+    // 作成から7日以内の注文は対象外
+    let recent = app_service.create_order(CustomerId::new()).await.unwrap();
+    let confirmed = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(confirmed, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            confirmed,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(confirmed).await.unwrap();
+
+    let filter = BulkCancellationFilter::new(OrderStatus::Pending, Some(7)).unwrap();
+    let now = Utc::now();
+    let preview = app_service
+        .preview_bulk_cancellation(&filter, now)
+        .await
+        .unwrap();
+    let mut previewed: Vec<OrderId> = preview.orders.iter().map(|order| order.id()).collect();
+    previewed.sort_by_key(|order_id| order_id.to_string());
+    pending.sort_by_key(|order_id| order_id.to_string());
+    assert_eq!(previewed, pending);
+
+    // プレビュー後に対象が増えた場合はトークンが一致しない
+    let late = app_service.create_order(CustomerId::new()).await.unwrap();
+    order_repo.set_created_at(late, eight_days_ago).await;
+    let result = app_service
+        .confirm_bulk_cancellation(&filter, &preview.confirmation_token, now)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(
+            DomainError::ConfirmationTokenMismatch(_)
+        ))
+    ));
+
+    let preview = app_service
+        .preview_bulk_cancellation(&filter, now)
+        .await
+        .unwrap();
+    let order_ids = app_service
+        .confirm_bulk_cancellation(&filter, &preview.confirmation_token, now)
+        .await
+        .unwrap();
+    assert_eq!(order_ids.len(), 3);
+    let outcomes = app_service
+        .bulk_transition(BulkTransition::Cancel, &order_ids)
+        .await
+        .unwrap();
+    assert!(outcomes.iter().all(|outcome| outcome.result.is_ok()));
+    for order_id in pending.iter().chain([&late]) {
+        let order = app_service
+            .get_order_by_id(*order_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::Cancelled);
+    }
+    let order = app_service
+        .get_order_by_id(confirmed)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order.status(), OrderStatus::Confirmed);
+    let order = app_service.get_order_by_id(recent).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::Pending);
+}
+
+/// 警告はコマンドを止めずに、アプリケーションサービスの戻り値として集約される
+#[tokio::test]
+async fn test_command_warnings_are_aggregated() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus);
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    let book_id = BookId::new();
+
+    let warnings = app_service
+        .add_book_to_order(order_id, book_id, 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    assert!(warnings.is_empty());
+    let warnings = app_service
+        .add_book_to_order(order_id, book_id, 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    assert_eq!(warnings[0].code, "DUPLICATE_BOOK_MERGED");
+
+    let set_address = |prefecture: &str| {
+        app_service.set_shipping_address_from_request(
+            order_id,
+            "1000001".to_string(),
+            prefecture.to_string(),
+            "千代田区".to_string(),
+            "千代田1-1".to_string(),
+            None,
+        )
+    };
+    assert!(set_address("東京都").await.unwrap().is_empty());
+    app_service.confirm_order(order_id).await.unwrap();
+
+    // 確定後に追加料金のかかる地域へ変更すると、注文と配送料ポリシーの警告がまとめて返る
+    let warnings = set_address("沖縄県").await.unwrap();
+    let codes: Vec<&str> = warnings.iter().map(|w| w.code.as_str()).collect();
+    assert_eq!(
+        codes,
+        vec![
+            "DESTINATION_CHANGED_AFTER_CONFIRMATION",
+            "REMOTE_SHIPPING_DESTINATION"
+        ]
+    );
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order.shipping_address().unwrap().prefecture(), "沖縄県");
+}
+
+#[derive(Clone)]
+struct OrderConfirmedRecorder {
+    events: Arc<Mutex<Vec<OrderConfirmed>>>,
+}
+
+#[async_trait]
+impl EventHandler<OrderConfirmed> for OrderConfirmedRecorder {
+    async fn handle(&self, event: OrderConfirmed) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+/// コマンドの応答で返すイベントが、購読者に配信されるイベントと同じであることを検証
+#[tokio::test]
+async fn test_commands_echo_the_published_event() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = OrderConfirmedRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_order_confirmed(recorder.clone())
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus);
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(2000))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let acknowledgement = app_service.confirm_order(order_id).await.unwrap();
+    assert_eq!(acknowledgement.event.event_type(), "OrderConfirmed");
+    assert_eq!(acknowledgement.event.aggregate_id(), order_id.to_string());
+    assert_eq!(acknowledgement.event.metadata().sequence_number, Some(1));
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    {
+        let events = recorder.events.lock().await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].metadata.event_id,
+            acknowledgement.event.metadata().event_id
+        );
+        assert_eq!(
+            events[0].metadata.correlation_id,
+            acknowledgement.event.metadata().correlation_id
+        );
+    }
+
+    // 続くコマンドは確定時のサーガの相関IDを引き継ぎ、次の連番のイベントを返す
+    let shipped = app_service.mark_order_as_shipped(order_id, None).await.unwrap();
+    assert_eq!(shipped.event.event_type(), "OrderShipped");
+    assert_eq!(shipped.event.metadata().sequence_number, Some(2));
+    assert_eq!(
+        shipped.event.metadata().correlation_id,
+        acknowledgement.event.metadata().correlation_id
+    );
+
+    // 相関IDを指定した場合はそれを使う（サーガの相関IDは変わらない）
+    let correlation_id = Uuid::new_v4();
+    let delivered = app_service
+        .mark_order_as_delivered(order_id, Some(correlation_id))
+        .await
+        .unwrap();
+    assert_eq!(delivered.event.metadata().correlation_id, correlation_id);
+    let order = app_service.get_order_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(
+        order.saga_correlation_id(),
+        Some(acknowledgement.event.metadata().correlation_id)
+    );
+
+    // 確定前の注文のコマンドはコマンドごとに新しい相関IDを使う
+    let pending = app_service.create_order(CustomerId::new()).await.unwrap();
+    let cancelled = app_service.cancel_order(pending).await.unwrap();
+    assert_ne!(
+        cancelled.event.metadata().correlation_id,
+        acknowledgement.event.metadata().correlation_id
+    );
+}
+
+/// 明細属性がOrderConfirmedイベントで倉庫側に伝わり、確定後は変更できないことを検証
+#[tokio::test]
+async fn test_line_attributes_are_echoed_in_order_confirmed() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let recorder = OrderConfirmedRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_order_confirmed(recorder.clone())
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus);
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    let book_id = BookId::new();
+    app_service
+        .add_book_to_order(order_id, book_id, 1, Money::jpy(2000))
+        .await
+        .unwrap();
+    let signed_copy =
+        LineAttribute::new("signed_copy".to_string(), "requested".to_string()).unwrap();
+
+    // 注文にない書籍には設定できない
+    let result = app_service
+        .set_line_attributes(order_id, BookId::new(), vec![signed_copy.clone()])
+        .await;
+    assert!(matches!(result, Err(ApplicationError::DomainError(_))));
+
+    app_service
+        .set_line_attributes(order_id, book_id, vec![signed_copy.clone()])
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1000001".to_string(),
+            "東京都".to_string(),
+            "千代田区".to_string(),
+            "千代田1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+
+    let events = recorder.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].order_lines[0].attributes(), &[signed_copy]);
+
+    // 確定後は変更できない
+    let result = app_service
+        .set_line_attributes(order_id, book_id, vec![])
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(
+            DomainError::InvalidOrderState(_)
+        ))
+    ));
+}
+
+// テスト用のモック書籍カタログ
+#[derive(Default)]
+struct MockBookCatalog {
+    prices: Mutex<HashMap<BookId, Money>>,
+    titles: Mutex<HashMap<BookId, BookTitles>>,
+}
+
+#[async_trait]
+impl BookCatalog for MockBookCatalog {
+    async fn set_price(&self, book_id: BookId, price: Money) -> Result<(), RepositoryError> {
+        self.prices.lock().await.insert(book_id, price);
+        Ok(())
+    }
+
+    async fn find_price(&self, book_id: BookId) -> Result<Option<Money>, RepositoryError> {
+        Ok(self.prices.lock().await.get(&book_id).copied())
+    }
+
+    async fn find_prices(
+        &self,
+        book_ids: &[BookId],
+    ) -> Result<HashMap<BookId, Money>, RepositoryError> {
+        let prices = self.prices.lock().await;
+        Ok(book_ids
+            .iter()
+            .filter_map(|book_id| prices.get(book_id).map(|price| (*book_id, *price)))
+            .collect())
+    }
+
+    async fn set_title(
+        &self,
+        book_id: BookId,
+        language: Language,
+        title: &str,
+    ) -> Result<(), RepositoryError> {
+        self.titles
+            .lock()
+            .await
+            .entry(book_id)
+            .or_default()
+            .insert(language, title.to_string());
+        Ok(())
+    }
+
+    async fn find_titles(
+        &self,
+        book_ids: &[BookId],
+    ) -> Result<HashMap<BookId, BookTitles>, RepositoryError> {
+        let titles = self.titles.lock().await;
+        Ok(book_ids
+            .iter()
+            .filter_map(|book_id| titles.get(book_id).map(|titles| (*book_id, titles.clone())))
+            .collect())
+    }
+}
+
+#[tokio::test]
+async fn test_confirm_checks_catalog_prices_by_policy() {
+    let book_catalog = Arc::new(MockBookCatalog::default());
+    let book_id = BookId::new();
+    book_catalog
+        .set_price(book_id, Money::jpy(2000))
+        .await
+        .unwrap();
+
+    for policy in [PriceChangePolicy::Reject, PriceChangePolicy::Reprice] {
+        let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+        let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus)
+            .with_price_protection(book_catalog.clone(), policy);
+        let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+        app_service
+            .add_book_to_order(order_id, book_id, 2, Money::jpy(1500))
+            .await
+            .unwrap();
+        app_service
+            .set_shipping_address_from_request(
+                order_id,
+                "1000001".to_string(),
+                "東京都".to_string(),
+                "千代田区".to_string(),
+                "千代田1-1".to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        // 注文後に価格が変更された
+        let warnings = match policy {
+            PriceChangePolicy::Reject => {
+                let result = app_service.confirm_order(order_id).await;
+                assert!(matches!(
+                    result,
+                    Err(ApplicationError::DomainError(DomainError::PriceChanged(_)))
+                ));
+                let order = app_service
+                    .get_order_by_id(order_id)
+                    .await
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(order.status(), OrderStatus::Pending);
+
+                // 明示的に再価格付けしてから確定する
+                let warnings = app_service.reprice_order(order_id).await.unwrap();
+                assert!(app_service
+                    .confirm_order(order_id)
+                    .await
+                    .unwrap()
+                    .warnings
+                    .is_empty());
+                warnings
+            }
+            PriceChangePolicy::Reprice => {
+                app_service.confirm_order(order_id).await.unwrap().warnings
+            }
+        };
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "LINE_REPRICED");
+        let order = app_service
+            .get_order_by_id(order_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::Confirmed);
+        assert_eq!(order.order_lines()[0].unit_price(), Money::jpy(2000));
+        assert_eq!(order.subtotal(), Money::jpy(4000));
+    }
+}
+
+// テスト用のモック配送追跡イベントリポジトリ
+#[derive(Default)]
+struct MockTrackingEventRepository {
+    events: Mutex<Vec<TrackingEvent>>,
+    checkpoints: Mutex<HashMap<(String, String), u64>>,
+}
+
+#[async_trait]
+impl TrackingEventRepository for MockTrackingEventRepository {
+    async fn append(&self, event: &TrackingEvent) -> Result<(), RepositoryError> {
+        let mut events = self.events.lock().await;
+        if !events.iter().any(|e| e.event_id == event.event_id) {
+            events.push(event.clone());
+        }
+        Ok(())
+    }
+
+    async fn find_by_order(&self, order_id: OrderId) -> Result<Vec<TrackingEvent>, RepositoryError> {
+        let events = self.events.lock().await;
+        Ok(events
+            .iter()
+            .filter(|e| e.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn apply_checkpointed(
+        &self,
+        checkpoint: &ProjectionCheckpoint,
+        event: Option<&TrackingEvent>,
+    ) -> Result<bool, RepositoryError> {
+        let mut checkpoints = self.checkpoints.lock().await;
+        let key = (checkpoint.projection.clone(), checkpoint.aggregate_id.clone());
+        if checkpoints
+            .get(&key)
+            .is_some_and(|&last| last >= checkpoint.sequence_number)
+        {
+            return Ok(false);
+        }
+        if let Some(event) = event {
+            self.append(event).await?;
+        }
+        checkpoints.insert(key, checkpoint.sequence_number);
+        Ok(true)
+    }
+
+    async fn load_checkpoints(
+        &self,
+        projection: &str,
+    ) -> Result<Vec<ProjectionCheckpoint>, RepositoryError> {
+        let checkpoints = self.checkpoints.lock().await;
+        Ok(checkpoints
+            .iter()
+            .filter(|((name, _), _)| name == projection)
+            .map(|((name, aggregate_id), &last)| {
+                ProjectionCheckpoint::new(name, aggregate_id, last)
+            })
+            .collect())
+    }
+}
+
+/// 注文イベントと配送業者の追跡情報が顧客向けタイムラインに投影されることを検証
+#[tokio::test]
+async fn test_tracking_timeline_combines_order_and_carrier_events() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let tracking_repo = Arc::new(MockTrackingEventRepository::default());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+
+    let projection = TrackingProjectionHandler::new(tracking_repo.clone(), Arc::new(MockLogger));
+    event_bus
+        .subscribe_order_confirmed(projection.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_shipped(projection.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_carrier_tracking_updated(projection)
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let tracking_service =
+        TrackingApplicationService::new(order_repo.clone(), tracking_repo, event_bus.clone());
+
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    let now = Utc::now();
+    tracking_service
+        .record_carrier_update(order_id, TrackingStage::Packed, now, None, None)
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    app_service.mark_order_as_shipped(order_id, None).await.unwrap();
+    tracking_service
+        .record_carrier_update(
+            order_id,
+            TrackingStage::OutForDelivery,
+            now + TimeDelta::hours(20),
+            Some("渋谷営業所".to_string()),
+            None,
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let timeline = tracking_service
+        .get_tracking_timeline(order_id)
+        .await
+        .unwrap();
+    assert_eq!(timeline.current_stage, Some(TrackingStage::OutForDelivery));
+    assert_eq!(timeline.history.len(), 4);
+    let completed: Vec<bool> = timeline.steps.iter().map(|step| step.completed).collect();
+    assert_eq!(completed, vec![true, true, true, true, false]);
+    assert_eq!(timeline.steps[3].location.as_deref(), Some("渋谷営業所"));
+
+    // 存在しない注文の追跡情報は受け付けない
+    assert!(matches!(
+        tracking_service
+            .record_carrier_update(OrderId::new(), TrackingStage::Packed, now, None, None)
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+/// 在庫変動ログと食い違う在庫を修復すると凍結され（在庫数と在庫変動ログは変えない）、棚卸しで照合した後に凍結を解除できることを検証
+#[tokio::test]
+async fn test_ledger_drift_is_frozen_and_reconciled() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let inventory_service =
+        InventoryApplicationService::new(inventory_repo.clone(), event_bus.clone());
+    let cycle_count_service = CycleCountApplicationService::new(
+        Arc::new(MockCycleCountRepository::new()),
+        inventory_repo.clone(),
+        event_bus.clone(),
+    );
+
+    // 在庫変動ログを経由せずに在庫数が5から8に書き換えられた状態を作る
+    let drifted_book = BookId::new();
+    inventory_repo
+        .save(&Inventory::new(drifted_book, 5))
+        .await
+        .unwrap();
+    inventory_repo
+        .add_inventory(Inventory::new(drifted_book, 8))
+        .await;
+    // 変動のない書籍は照合の対象外
+    inventory_repo
+        .add_inventory(Inventory::new(BookId::new(), 4))
+        .await;
+
+    let report = inventory_service
+        .repair_ledger_drifts(Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(report.frozen_count, 1);
+    assert_eq!(report.entries.len(), 1);
+    assert_eq!(report.entries[0].book_id, drifted_book);
+    assert_eq!(report.entries[0].quantity_on_hand, 8);
+    assert_eq!(report.entries[0].ledger_quantity, 5);
+    assert_eq!(report.entries[0].difference, 3);
+
+    // 在庫数も在庫変動ログも変えずに凍結する
+    let inventory = inventory_repo
+        .find_by_book_id(drifted_book)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 8);
+    assert!(inventory.is_frozen());
+    let movements = inventory_repo.find_movements_since(0, 10).await.unwrap();
+    assert_eq!(movements.len(), 1);
+    assert_eq!(movements[0].quantity_on_hand, 5);
+
+    // 照合するまでは再実行しても同じ食い違いを報告する
+    let report = inventory_service
+        .repair_ledger_drifts(Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(report.entries.len(), 1);
+    assert_eq!(report.entries[0].difference, 3);
+
+    // 凍結中でも棚卸しによる照合は反映できる
+    let cycle_count_id = cycle_count_service
+        .start_cycle_count(vec![drifted_book])
+        .await
+        .unwrap();
+    cycle_count_service
+        .record_counts(cycle_count_id, vec![(drifted_book, 2)])
+        .await
+        .unwrap();
+    cycle_count_service
+        .submit_cycle_count(cycle_count_id)
+        .await
+        .unwrap();
+    cycle_count_service
+        .approve_cycle_count(cycle_count_id, "manager".to_string())
+        .await
+        .unwrap();
+
+    let inventory = inventory_service
+        .unfreeze_inventory(drifted_book)
+        .await
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 2);
+    assert!(!inventory.is_frozen());
+
+    let result = inventory_service.unfreeze_inventory(BookId::new()).await;
+    assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+}
+
+struct MockParkedEventRepository {
+    events: Mutex<Vec<ParkedEvent>>,
+}
+
+#[async_trait]
+impl ParkedEventRepository for MockParkedEventRepository {
+    async fn park(&self, event: &ParkedEvent) -> Result<(), RepositoryError> {
+        let mut events = self.events.lock().await;
+        if !events
+            .iter()
+            .any(|parked| parked.event_id == event.event_id && parked.handler == event.handler)
+        {
+            events.push(event.clone());
+        }
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<ParkedEvent>, RepositoryError> {
+        Ok(self.events.lock().await.clone())
+    }
+}
+
+#[derive(Clone)]
+struct DigitalItemsFulfilledRecorder {
+    events: Arc<Mutex<Vec<DigitalItemsFulfilled>>>,
+}
+
+#[async_trait]
+impl EventHandler<DigitalItemsFulfilled> for DigitalItemsFulfilledRecorder {
+    async fn handle(&self, event: DigitalItemsFulfilled) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+/// 遅延イベントがハンドラーごとのポリシーに従って保留・適用されることを検証
+#[tokio::test]
+async fn test_late_events_are_parked_or_applied_by_policy() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let parked_event_repo = Arc::new(MockParkedEventRepository {
+        events: Mutex::new(Vec::new()),
+    });
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+    let recorder = DigitalItemsFulfilledRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_digital_items_fulfilled(recorder.clone())
+        .await
+        .unwrap();
+
+    // 既定は保留、FulfillmentRouterは適用できる場合に適用
+    let guard = LateEventGuard::new(
+        LateEventPolicies {
+            default_policy: LateEventPolicy::Park,
+            overrides: HashMap::from([(
+                "FulfillmentRouter".to_string(),
+                LateEventPolicy::ApplyIfCompatible,
+            )]),
+        },
+        parked_event_repo.clone(),
+        logger.clone(),
+    );
+    let inventory_handler = InventoryReservationHandler::new(
+        inventory_repo.clone(),
+        order_repo.clone(),
+        event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
+        logger.clone(),
+    )
+    .with_late_event_guard(guard.clone());
+    let fulfillment_router = FulfillmentRouter::new(
+        order_repo.clone(),
+        event_bus.clone(),
+        Arc::new(TokenDownloadLinkGenerator::new(
+            "https://downloads.example.com".to_string(),
+            TimeDelta::hours(72),
+        )),
+        Arc::new(MockProcessedEventRepository::default()),
+        logger.clone(),
+    )
+    .with_late_event_guard(guard);
+
+    let address = bookstore_order_management::domain::model::ShippingAddress::new(
+        "1234567".to_string(),
+        "東京都".to_string(),
+        "渋谷区".to_string(),
+        "道玄坂1-1-1".to_string(),
+        None,
+    )
+    .unwrap();
+
+    // キャンセル後に届いたOrderConfirmedは在庫を予約せずに保留される
+    let physical_book = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(physical_book, 5))
+        .await;
+    let cancelled_order_id = OrderId::new();
+    let customer_id = CustomerId::new();
+    let mut cancelled_order = Order::new(cancelled_order_id, customer_id);
+    cancelled_order
+        .add_book(physical_book, 2, Money::jpy(1000))
+        .unwrap();
+    cancelled_order
+        .set_shipping_address(address.clone())
+        .unwrap();
+    cancelled_order.confirm().unwrap();
+    let confirmed_event = OrderConfirmed::new(
+        cancelled_order_id,
+        customer_id,
+        cancelled_order.order_lines().to_vec(),
+        Money::jpy(2000),
+    );
+    cancelled_order.cancel().unwrap();
+    order_repo.save(&mut cancelled_order).await.unwrap();
+
+    inventory_handler
+        .handle(confirmed_event.clone())
+        .await
+        .unwrap();
+    // 再配信されても二重に保留しない
+    inventory_handler
+        .handle(confirmed_event.clone())
+        .await
+        .unwrap();
+
+    let inventory = inventory_repo
+        .find_by_book_id(physical_book)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 5);
+
+    let parked = parked_event_repo.find_all().await.unwrap();
+    assert_eq!(parked.len(), 1);
+    assert_eq!(parked[0].event_id, confirmed_event.metadata.event_id);
+    assert_eq!(parked[0].handler, "InventoryReservationHandler");
+    assert_eq!(parked[0].order_status, "Cancelled");
+    assert_eq!(parked[0].expected_status, "Confirmed");
+    assert_eq!(
+        parked[0].payload["order_id"],
+        cancelled_order_id.to_string()
+    );
+
+    // 物理書籍の発送後に届いたInventoryReservedでも電子書籍のダウンロードリンクは発行できる
+    let shipped_order_id = OrderId::new();
+    let mut shipped_order = Order::new(shipped_order_id, CustomerId::new());
+    shipped_order
+        .add_book(physical_book, 1, Money::jpy(1000))
+        .unwrap();
+    shipped_order
+        .add_book_with_fulfillment(BookId::new(), 1, Money::jpy(800), FulfillmentType::Digital)
+        .unwrap();
+    shipped_order.set_shipping_address(address).unwrap();
+    shipped_order.confirm().unwrap();
+    shipped_order.mark_as_shipped().unwrap();
+    order_repo.save(&mut shipped_order).await.unwrap();
+
+    fulfillment_router
+        .handle(InventoryReserved::with_correlation_id(
+            shipped_order_id,
+            shipped_order.order_lines().to_vec(),
+            Uuid::new_v4(),
+        ))
+        .await
+        .unwrap();
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let fulfilled = recorder.events.lock().await;
+    assert_eq!(fulfilled.len(), 1);
+    assert_eq!(fulfilled[0].order_id, shipped_order_id);
+    assert!(!fulfilled[0].order_fulfilled);
+    assert_eq!(parked_event_repo.find_all().await.unwrap().len(), 1);
+
+    let order = order_repo
+        .find_by_id(shipped_order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order.status(), OrderStatus::Shipped);
+}
+
+/// 注文イベントに注文ごとの連番が採番され、投影では先に届いたイベントが連番の順に適用されることを検証
+#[tokio::test]
+async fn test_tracking_projection_applies_order_events_in_sequence() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let tracking_repo = Arc::new(MockTrackingEventRepository::default());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+
+    // 確定・発送・配達完了の順に連番が採番され、注文と一緒に保存される
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    app_service.mark_order_as_shipped(order_id, None).await.unwrap();
+    app_service.mark_order_as_delivered(order_id, None).await.unwrap();
+    let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.event_sequence(), 3);
+
+    // 配達完了(3)が発送(2)より先に届いた場合は、発送が届くまで投影を保留する
+    let projection = TrackingProjectionHandler::new(tracking_repo.clone(), Arc::new(MockLogger));
+    let order_id = OrderId::new();
+    let address = ShippingAddress::new(
+        "1234567".to_string(),
+        "東京都".to_string(),
+        "渋谷区".to_string(),
+        "道玄坂1-1-1".to_string(),
+        None,
+    )
+    .unwrap();
+    let mut confirmed = OrderConfirmed::new(order_id, CustomerId::new(), vec![], Money::jpy(1500));
+    confirmed.metadata.sequence_number = Some(1);
+    let mut shipped = OrderShipped::new(order_id, address);
+    shipped.metadata.sequence_number = Some(2);
+    let mut delivered = OrderDelivered::new(order_id);
+    delivered.metadata.sequence_number = Some(3);
+
+    projection.handle(confirmed).await.unwrap();
+    projection.handle(delivered.clone()).await.unwrap();
+    let stages: Vec<TrackingStage> = tracking_repo
+        .find_by_order(order_id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|event| event.stage)
+        .collect();
+    assert_eq!(stages, vec![TrackingStage::Ordered]);
+
+    projection.handle(shipped).await.unwrap();
+    // 適用済みの連番の再配信は読み飛ばす
+    projection.handle(delivered).await.unwrap();
+    let stages: Vec<TrackingStage> = tracking_repo
+        .find_by_order(order_id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|event| event.stage)
+        .collect();
+    assert_eq!(
+        stages,
+        vec![
+            TrackingStage::Ordered,
+            TrackingStage::Shipped,
+            TrackingStage::Delivered
+        ]
+    );
+}
+
+/// 返品のイベントも注文ごとの連番を進め、返品の後に発行されたイベントが保留されずに投影されることを検証
+#[tokio::test]
+async fn test_tracking_projection_advances_sequence_through_return_events() {
+    let tracking_repo = Arc::new(MockTrackingEventRepository::default());
+    let projection = TrackingProjectionHandler::new(tracking_repo.clone(), Arc::new(MockLogger));
+    let order_id = OrderId::new();
+    let customer_id = CustomerId::new();
+    let address = ShippingAddress::new(
+        "1234567".to_string(),
+        "東京都".to_string(),
+        "渋谷区".to_string(),
+        "道玄坂1-1-1".to_string(),
+        None,
+    )
+    .unwrap();
+    let mut confirmed = OrderConfirmed::new(order_id, customer_id, vec![], Money::jpy(1500));
+    confirmed.metadata.sequence_number = Some(1);
+    let mut shipped = OrderShipped::new(order_id, address);
+    shipped.metadata.sequence_number = Some(2);
+    let mut delivered = OrderDelivered::new(order_id);
+    delivered.metadata.sequence_number = Some(3);
+    let mut return_requested = ReturnRequested::new(order_id, customer_id, None);
+    return_requested.metadata.sequence_number = Some(4);
+    let mut return_approved = ReturnApproved::new(order_id, customer_id, vec![]);
+    return_approved.metadata.sequence_number = Some(5);
+    // 誤って承認した返品を管理者が配達完了に戻す
+    let mut reverted = OrderStatusForcefullyChanged::new(
+        order_id,
+        customer_id,
+        &StatusOverride {
+            from_status: OrderStatus::Returned,
+            to_status: OrderStatus::Delivered,
+            reason: "返品の承認を取り消し".to_string(),
+            operator: "admin".to_string(),
+        },
+    );
+    reverted.metadata.sequence_number = Some(6);
+
+    projection.handle(confirmed).await.unwrap();
+    projection.handle(shipped).await.unwrap();
+    projection.handle(delivered).await.unwrap();
+    projection.handle(return_requested).await.unwrap();
+    projection.handle(return_approved).await.unwrap();
+    projection.handle(reverted).await.unwrap();
+
+    let stages: Vec<TrackingStage> = tracking_repo
+        .find_by_order(order_id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|event| event.stage)
+        .collect();
+    assert_eq!(
+        stages,
+        vec![
+            TrackingStage::Ordered,
+            TrackingStage::Shipped,
+            TrackingStage::Delivered,
+            TrackingStage::Delivered
+        ]
+    );
+}
+
+/// 再起動後はチェックポイントから投影を再開し、適用済みのイベントの再配信を二重に適用しないことを検証
+#[tokio::test]
+async fn test_tracking_projection_resumes_from_checkpoint_after_restart() {
+    let tracking_repo = Arc::new(MockTrackingEventRepository::default());
+    let order_id = OrderId::new();
+    let address = ShippingAddress::new(
+        "1234567".to_string(),
+        "東京都".to_string(),
+        "渋谷区".to_string(),
+        "道玄坂1-1-1".to_string(),
+        None,
+    )
+    .unwrap();
+    let mut confirmed = OrderConfirmed::new(order_id, CustomerId::new(), vec![], Money::jpy(1500));
+    confirmed.metadata.sequence_number = Some(1);
+    let mut shipped = OrderShipped::new(order_id, address);
+    shipped.metadata.sequence_number = Some(2);
+    let mut delivered = OrderDelivered::new(order_id);
+    delivered.metadata.sequence_number = Some(3);
+
+    let projection = TrackingProjectionHandler::new(tracking_repo.clone(), Arc::new(MockLogger));
+    projection.handle(confirmed).await.unwrap();
+    projection.handle(shipped.clone()).await.unwrap();
+
+    // 再起動後のハンドラーは永続化したチェックポイントの続きから投影する
+    let progress = ProjectionProgress::new();
+    let restarted = TrackingProjectionHandler::new(tracking_repo.clone(), Arc::new(MockLogger))
+        .with_progress(progress.clone());
+    assert_eq!(restarted.recover().await.unwrap(), 1);
+
+    // 適用済みの発送(2)の再配信は処理位置を進めない
+    restarted.handle(shipped).await.unwrap();
+    assert_eq!(progress.position().await.processed_events, 0);
+
+    restarted.handle(delivered).await.unwrap();
+    let position = progress.position().await;
+    assert_eq!(position.processed_events, 1);
+    assert_eq!(position.last_sequence_number, Some(3));
+    assert_eq!(tracking_repo.find_by_order(order_id).await.unwrap().len(), 3);
+    assert_eq!(
+        tracking_repo
+            .load_checkpoints(TrackingProjectionHandler::PROJECTION_NAME)
+            .await
+            .unwrap(),
+        vec![ProjectionCheckpoint::new(
+            TrackingProjectionHandler::PROJECTION_NAME,
+            &order_id.to_string(),
+            3
+        )]
+    );
+}
+
+/// プロジェクションの処理位置と、イベントストリームの先頭に対する遅延が公開されることを検証
+#[tokio::test]
+async fn test_projection_status_reports_progress_and_lag() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let tracking_repo = Arc::new(MockTrackingEventRepository::default());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+
+    let progress = ProjectionProgress::new();
+    let projection = TrackingProjectionHandler::new(tracking_repo, Arc::new(MockLogger))
+        .with_progress(progress.clone());
+    event_bus
+        .subscribe_order_confirmed(projection.clone())
+        .await
+        .unwrap();
+    event_bus.subscribe_order_shipped(projection).await.unwrap();
+    let projection_service = ProjectionApplicationService::new(
+        ProjectionRegistry::new().with_projection("tracking_timeline", progress),
+        event_bus.clone(),
+    );
+
+    // イベント発行前は遅延なし
+    let statuses = projection_service.list_projections().await;
+    assert_eq!(statuses.len(), 1);
+    assert_eq!(statuses[0].position.processed_events, 0);
+    assert_eq!(statuses[0].lag_seconds, Some(0));
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    app_service.mark_order_as_shipped(order_id, None).await.unwrap();
+
+    let status = &projection_service.list_projections().await[0];
+    assert_eq!(status.name, "tracking_timeline");
+    assert_eq!(status.position.processed_events, 2);
+    assert_eq!(
+        status.position.last_event_type.as_deref(),
+        Some("OrderShipped")
+    );
+    assert_eq!(status.position.last_sequence_number, Some(2));
+    assert_eq!(status.lag_seconds, Some(0));
+
+    // イベントの再生手段がないため再構築はできない
+    assert!(matches!(
+        projection_service
+            .rebuild_projection("tracking_timeline")
+            .await,
+        Err(ApplicationError::Unsupported(_))
+    ));
+    assert!(matches!(
+        projection_service.rebuild_projection("unknown").await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+// テスト用のモック順番待ちリポジトリ（登録順を先着順とする）
+#[derive(Default)]
+struct MockWaitlistRepository {
+    entries: Mutex<Vec<WaitlistEntry>>,
+}
+
+#[async_trait]
+impl WaitlistRepository for MockWaitlistRepository {
+    async fn join(&self, entries: &[WaitlistEntry]) -> Result<(), RepositoryError> {
+        let mut stored = self.entries.lock().await;
+        for entry in entries {
+            if !stored
+                .iter()
+                .any(|e| e.order_id == entry.order_id && e.book_id == entry.book_id)
+            {
+                stored.push(entry.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_by_book(&self, book_id: BookId) -> Result<Vec<WaitlistEntry>, RepositoryError> {
+        let stored = self.entries.lock().await;
+        Ok(stored
+            .iter()
+            .filter(|e| e.book_id == book_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<WaitlistEntry>, RepositoryError> {
+        let stored = self.entries.lock().await;
+        Ok(stored
+            .iter()
+            .filter(|e| e.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn remove_order(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        self.entries.lock().await.retain(|e| e.order_id != order_id);
+        Ok(())
+    }
+}
+
+/// 書籍を指定数量注文して確定する
+async fn confirm_order_for(
+    app_service: &OrderApplicationService<InMemoryOrderRepository>,
+    book_id: BookId,
+    quantity: u32,
+) -> OrderId {
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, book_id, quantity, Money::jpy(1800))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1500001".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "神宮前1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    order_id
+}
+
+/// 順番待ちが有効な書籍の在庫を超えた注文は先着順に並び、
+/// 入荷やキャンセルで在庫が空くと追い越しなしで繰り上がることを検証
+#[tokio::test]
+async fn test_waitlist_promotes_orders_in_fifo_order() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let waitlist_repo = Arc::new(MockWaitlistRepository::default());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+
+    let inventory_handler = InventoryReservationHandler::new(
+        inventory_repo.clone(),
+        order_repo.clone(),
+        event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
+        logger.clone(),
+    )
+    .with_waitlist(waitlist_repo.clone());
+    event_bus
+        .subscribe_order_confirmed(inventory_handler)
+        .await
+        .unwrap();
+    let promotion_handler = WaitlistPromotionHandler::new(
+        waitlist_repo.clone(),
+        inventory_repo.clone(),
+        order_repo.clone(),
+        event_bus.clone(),
+        logger.clone(),
+    );
+    event_bus
+        .subscribe_inventory_adjusted(promotion_handler.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_cancelled(promotion_handler)
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let inventory_service =
+        InventoryApplicationService::new(inventory_repo.clone(), event_bus.clone());
+    let waitlist_service =
+        WaitlistApplicationService::new(waitlist_repo.clone(), order_repo.clone());
+
+    let book_id = BookId::new();
+    inventory_service
+        .create_inventory(book_id, 1, None, true)
+        .await
+        .unwrap();
+
+    // 在庫の範囲内の注文は通常どおり予約される
+    let first = confirm_order_for(&app_service, book_id, 1).await;
+    assert_eq!(
+        order_repo
+            .find_by_id(first)
+            .await
+            .unwrap()
+            .unwrap()
+            .status(),
+        OrderStatus::Confirmed
+    );
+
+    // 在庫を超えた注文は順番待ちになり、後の注文はその後ろに並ぶ
+    let second = confirm_order_for(&app_service, book_id, 2).await;
+    let third = confirm_order_for(&app_service, book_id, 1).await;
+    let (status, positions) = waitlist_service.get_positions(third).await.unwrap();
+    assert_eq!(status, OrderStatus::Waitlisted);
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].position, 2);
+    assert_eq!(positions[0].quantity_ahead, 2);
+
+    // 先頭の注文に足りない入荷では、後ろの注文も追い越して繰り上がらない
+    inventory_service
+        .restock_inventory(book_id, 1)
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    for order_id in [second, third] {
+        assert_eq!(
+            order_repo
+                .find_by_id(order_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .status(),
+            OrderStatus::Waitlisted
+        );
+    }
+
+    // 先頭の注文の数量がそろうと繰り上がる
+    inventory_service
+        .restock_inventory(book_id, 1)
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(
+        order_repo
+            .find_by_id(second)
+            .await
+            .unwrap()
+            .unwrap()
+            .status(),
+        OrderStatus::Confirmed
+    );
+    let inventory = inventory_repo
+        .find_by_book_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 0);
+    let (_, positions) = waitlist_service.get_positions(third).await.unwrap();
+    assert_eq!(positions[0].position, 1);
+
+    // 順番待ちの注文をキャンセルすると順番待ちから外れる
+    app_service.cancel_order(third).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let (status, positions) = waitlist_service.get_positions(third).await.unwrap();
+    assert_eq!(status, OrderStatus::Cancelled);
+    assert!(positions.is_empty());
+    assert!(waitlist_repo
+        .find_by_book(book_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+/// フルフィルメントモードごとに、自動の発送・配達と手動の操作の可否を検証
+#[tokio::test]
+async fn test_fulfillment_modes_select_automatic_or_manual_transitions() {
+    for mode in [
+        FulfillmentMode::Manual,
+        FulfillmentMode::Auto,
+        FulfillmentMode::Hybrid,
+    ] {
+        let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+        let logger = Arc::new(MockLogger);
+
+        // main.rsと同じく、auto / hybridの場合のみ発送・配達ハンドラーを購読する
+        event_bus
+            .subscribe_order_confirmed(InventoryReservationHandler::new(
+                inventory_repo.clone(),
+                order_repo.clone(),
+                event_bus.clone(),
+                Arc::new(MockProcessedEventRepository::default()),
+                logger.clone(),
+            ))
+            .await
+            .unwrap();
+        if mode.is_automatic() {
+            event_bus
+                .subscribe_inventory_reserved(ShippingHandler::new(
+                    order_repo.clone(),
+                    event_bus.clone(),
+                    Arc::new(MockProcessedEventRepository::default()),
+                    logger.clone(),
+                ))
+                .await
+                .unwrap();
+            event_bus
+                .subscribe_order_shipped(DeliveryHandler::new(
+                    order_repo.clone(),
+                    event_bus.clone(),
+                    Arc::new(MockProcessedEventRepository::default()),
+                    logger.clone(),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let app_service = OrderApplicationService::new(
+            order_repo.as_ref().clone(),
+            event_bus.clone(),
+        )
+        .with_fulfillment_mode(mode);
+
+        let book_id = BookId::new();
+        inventory_repo.add_inventory(Inventory::new(book_id, 5)).await;
+        let order_id = confirm_order_for(&app_service, book_id, 1).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+        let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+        let expected = if mode.is_automatic() {
+            OrderStatus::Delivered
+        } else {
+            OrderStatus::Confirmed
+        };
+        assert_eq!(order.status(), expected, "{:?}", mode);
+
+        let manual_ship = app_service.mark_order_as_shipped(order_id, None).await;
+        let bulk_deliver = app_service
+            .bulk_transition(BulkTransition::Deliver, &[order_id])
+            .await;
+        match mode {
+            FulfillmentMode::Manual => {
+                assert!(manual_ship.is_ok());
+                assert!(bulk_deliver.unwrap()[0].result.is_ok());
+            }
+            FulfillmentMode::Auto => {
+                // 手動の操作は注文の状態に関わらず拒否する
+                assert!(matches!(manual_ship, Err(ApplicationError::Unsupported(_))));
+                assert!(matches!(bulk_deliver, Err(ApplicationError::Unsupported(_))));
+            }
+            FulfillmentMode::Hybrid => {
+                // 手動の操作は受け付けるが、自動で配達完了した注文は遷移できない
+                assert!(matches!(
+                    manual_ship,
+                    Err(ApplicationError::DomainError(DomainError::InvalidOrderState(_)))
+                ));
+                assert!(bulk_deliver.unwrap()[0].result.is_err());
+            }
+        }
+
+        let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+        assert_eq!(order.status(), OrderStatus::Delivered, "{:?}", mode);
+    }
+}
+
+/// オーケストレーションでは在庫の予約 → 請求 → 発送をオーケストレーターが順に進め、
+/// 請求の拒否・タイムアウト時は予約した在庫を解放して注文をキャンセルすることを検証
+#[tokio::test]
+async fn test_orchestrated_saga_charges_and_ships_or_compensates() {
+    // (請求を拒否する確率, 請求の応答までの時間, 注文の状態, 残りの在庫数, 請求が残るか)
+    let cases = [
+        (0.0, 0, OrderStatus::Shipped, 4, true),
+        (1.0, 0, OrderStatus::Cancelled, 5, false),
+        (0.0, 500, OrderStatus::Cancelled, 5, false),
+    ];
+    for (failure_rate, delay_ms, expected_status, expected_stock, charged) in cases {
+        let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+        let payment = Arc::new(SimulatedPaymentGateway::new(
+            std::time::Duration::from_millis(delay_ms),
+            failure_rate,
+        ));
+        event_bus
+            .subscribe_order_confirmed(
+                OrderSagaOrchestrator::new(
+                    order_repo.clone(),
+                    inventory_repo.clone(),
+                    payment.clone(),
+                    event_bus.clone(),
+                    Arc::new(MockProcessedEventRepository::default()),
+                    Arc::new(MockLogger),
+                )
+                .with_step_timeout(std::time::Duration::from_millis(50)),
+            )
+            .await
+            .unwrap();
+        let shipped = OrderShippedRecorder {
+            events: Arc::new(Mutex::new(Vec::new())),
+        };
+        event_bus
+            .subscribe_order_shipped(shipped.clone())
+            .await
+            .unwrap();
+
+        let app_service = OrderApplicationService::new(
+            order_repo.as_ref().clone(),
+            event_bus.clone(),
+        );
+        let book_id = BookId::new();
+        inventory_repo.add_inventory(Inventory::new(book_id, 5)).await;
+        let order_id = confirm_order_for(&app_service, book_id, 1).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+        assert_eq!(order.status(), expected_status, "{}/{}", failure_rate, delay_ms);
+        let inventory = inventory_repo.find_by_book_id(book_id).await.unwrap().unwrap();
+        assert_eq!(inventory.quantity_on_hand(), expected_stock);
+        assert_eq!(
+            payment.charged_amount(order_id),
+            charged.then(|| order.calculate_total().amount())
+        );
+        assert_eq!(
+            shipped.events.lock().await.len(),
+            usize::from(expected_status == OrderStatus::Shipped)
+        );
+    }
+}
+
+#[derive(Clone)]
+struct DeliveryFailedRecorder {
+    events: Arc<Mutex<Vec<DeliveryFailed>>>,
+}
+
+#[async_trait]
+impl EventHandler<DeliveryFailed> for DeliveryFailedRecorder {
+    async fn handle(&self, event: DeliveryFailed) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+/// autoモードで配送業者のシミュレーターに引き渡した荷物が、通知された結果で
+/// 配達完了になるか、DeliveryFailedで補償されることを検証
+#[tokio::test]
+async fn test_simulated_carrier_completes_or_fails_delivery() {
+    for failure_rate in [0.0, 1.0] {
+        let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+        let logger = Arc::new(MockLogger);
+        let delivery_failed = DeliveryFailedRecorder {
+            events: Arc::new(Mutex::new(Vec::new())),
+        };
+
+        event_bus
+            .subscribe_order_confirmed(InventoryReservationHandler::new(
+                inventory_repo.clone(),
+                order_repo.clone(),
+                event_bus.clone(),
+                Arc::new(MockProcessedEventRepository::default()),
+                logger.clone(),
+            ))
+            .await
+            .unwrap();
+        event_bus
+            .subscribe_inventory_reserved(ShippingHandler::new(
+                order_repo.clone(),
+                event_bus.clone(),
+                Arc::new(MockProcessedEventRepository::default()),
+                logger.clone(),
+            ))
+            .await
+            .unwrap();
+        event_bus
+            .subscribe_order_shipped(
+                DeliveryHandler::new(
+                    order_repo.clone(),
+                    event_bus.clone(),
+                    Arc::new(MockProcessedEventRepository::default()),
+                    logger.clone(),
+                )
+                .with_carrier(Arc::new(SimulatedCarrierAdapter::new(
+                    std::time::Duration::from_millis(100),
+                    failure_rate,
+                ))),
+            )
+            .await
+            .unwrap();
+        event_bus
+            .subscribe_delivery_failed(DeliveryFailureCompensationHandler::new(
+                order_repo.clone(),
+                event_bus.clone(),
+                logger.clone(),
+            ))
+            .await
+            .unwrap();
+        event_bus
+            .subscribe_delivery_failed(delivery_failed.clone())
+            .await
+            .unwrap();
+
+        let app_service = OrderApplicationService::new(
+            order_repo.as_ref().clone(),
+            event_bus.clone(),
+        )
+        .with_fulfillment_mode(FulfillmentMode::Auto);
+
+        let book_id = BookId::new();
+        inventory_repo.add_inventory(Inventory::new(book_id, 5)).await;
+        let order_id = confirm_order_for(&app_service, book_id, 1).await;
+
+        // 配送業者が結果を通知するまでは発送済みのまま
+        let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+        assert_eq!(order.status(), OrderStatus::Shipped);
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
+        let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+        let failures = delivery_failed.events.lock().await;
+        if failure_rate == 0.0 {
+            assert_eq!(order.status(), OrderStatus::Delivered);
+            assert!(failures.is_empty());
+        } else {
+            assert_eq!(order.status(), OrderStatus::Shipped);
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].order_id, order_id);
+        }
+    }
+}
+
+/// 確定済みの注文はキャンセルポリシーの受付期限内に限ってキャンセルできることを検証
+#[tokio::test]
+async fn test_cancellation_window_limits_cancelling_confirmed_orders() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let order_repo = InMemoryOrderRepository::new();
+
+    // 受付期間0分: 確定済みの注文はすぐに期限切れになる
+    let app_service = OrderApplicationService::new(order_repo.clone(), event_bus.clone())
+        .with_cancellation_policy(CancellationPolicy::within(TimeDelta::zero()));
+    let expired = confirm_order_for(&app_service, BookId::new(), 1).await;
+    let result = app_service.cancel_order(expired).await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(DomainError::CancellationWindowExpired(_)))
+    ));
+
+    // 確定前の注文は期限なくキャンセルできる
+    let pending = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service.cancel_order(pending).await.unwrap();
+
+    // 受付期間内であれば確定済みの注文もキャンセルできる
+    let app_service = OrderApplicationService::new(
+        order_repo.clone(),
+        event_bus.clone(),
+    )
+    .with_cancellation_policy(CancellationPolicy::within(TimeDelta::minutes(30)));
+    let order_id = confirm_order_for(&app_service, BookId::new(), 1).await;
+    let order = app_service.get_order_by_id(order_id).await.unwrap().unwrap();
+    let window = app_service
+        .cancellation_policy()
+        .window_for(&order, Utc::now())
+        .unwrap();
+    assert!(window.remaining > TimeDelta::minutes(29));
+    app_service.cancel_order(order_id).await.unwrap();
+
+    let order = app_service.get_order_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::Cancelled);
+    assert!(app_service
+        .cancellation_policy()
+        .window_for(&order, Utc::now())
+        .is_none());
+}
+
+#[derive(Default)]
+struct MockCheckoutHoldRepository {
+    holds: Mutex<Vec<CheckoutHold>>,
+}
+
+#[async_trait]
+impl CheckoutHoldRepository for MockCheckoutHoldRepository {
+    async fn replace_for_order(
+        &self,
+        order_id: OrderId,
+        holds: &[CheckoutHold],
+    ) -> Result<(), RepositoryError> {
+        let mut stored = self.holds.lock().await;
+        stored.retain(|hold| hold.order_id != order_id);
+        stored.extend_from_slice(holds);
+        Ok(())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<CheckoutHold>, RepositoryError> {
+        let stored = self.holds.lock().await;
+        Ok(stored
+            .iter()
+            .filter(|hold| hold.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<CheckoutHold>, RepositoryError> {
+        let stored = self.holds.lock().await;
+        Ok(stored
+            .iter()
+            .filter(|hold| hold.is_expired(now))
+            .cloned()
+            .collect())
+    }
+
+    async fn remove_order(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        self.holds.lock().await.retain(|hold| hold.order_id != order_id);
+        Ok(())
+    }
+}
+
+/// 書籍を指定数量注文した未確定の注文を作成する
+async fn pending_order_for(
+    app_service: &OrderApplicationService<InMemoryOrderRepository>,
+    book_id: BookId,
+    quantity: u32,
+) -> OrderId {
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, book_id, quantity, Money::jpy(1800))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1500001".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "神宮前1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    order_id
+}
+
+/// チェックアウト中の仮押さえで他の注文への売り越しを防ぎ、
+/// 確定時は二重に予約せずに在庫予約へ引き継ぎ、期限切れの仮押さえは解放されることを検証
+#[tokio::test]
+async fn test_checkout_hold_prevents_oversell_until_confirmed_or_expired() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let hold_repo = Arc::new(MockCheckoutHoldRepository::default());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+
+    let inventory_handler = InventoryReservationHandler::new(
+        inventory_repo.clone(),
+        order_repo.clone(),
+        event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
+        logger.clone(),
+    )
+    .with_checkout_holds(hold_repo.clone());
+    event_bus
+        .subscribe_order_confirmed(inventory_handler)
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let hold_service = CheckoutHoldApplicationService::new(
+        order_repo.clone(),
+        inventory_repo.clone(),
+        hold_repo.clone(),
+        TimeDelta::minutes(15),
+    );
+    let quantity_on_hand = |book_id: BookId| {
+        let inventory_repo = inventory_repo.clone();
+        async move {
+            inventory_repo
+                .find_by_book_id(book_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .quantity_on_hand()
+        }
+    };
+
+    let book_id = BookId::new();
+    inventory_repo.add_inventory(Inventory::new(book_id, 3)).await;
+    let now = Utc::now();
+
+    // 仮押さえした数量は在庫から確保され、再度呼び出しても二重には確保しない
+    let first = pending_order_for(&app_service, book_id, 2).await;
+    let holds = hold_service.place_hold(first, now).await.unwrap();
+    assert_eq!(holds.len(), 1);
+    assert_eq!(holds[0].expires_at, now + TimeDelta::minutes(15));
+    assert_eq!(quantity_on_hand(book_id).await, 1);
+    hold_service.place_hold(first, now).await.unwrap();
+    assert_eq!(quantity_on_hand(book_id).await, 1);
+
+    // 仮押さえで確保された在庫は他の注文のチェックアウトに回らない
+    let second = pending_order_for(&app_service, book_id, 2).await;
+    let result = hold_service.place_hold(second, now).await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::DomainError(DomainError::InsufficientInventory))
+    ));
+    assert_eq!(quantity_on_hand(book_id).await, 1);
+    assert!(hold_repo.find_by_order(second).await.unwrap().is_empty());
+
+    // 確定すると仮押さえが在庫予約に引き継がれる（残りの在庫は減らない）
+    app_service.confirm_order(first).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(
+        order_repo
+            .find_by_id(first)
+            .await
+            .unwrap()
+            .unwrap()
+            .status(),
+        OrderStatus::Confirmed
+    );
+    assert_eq!(quantity_on_hand(book_id).await, 1);
+    assert!(hold_repo.find_by_order(first).await.unwrap().is_empty());
+
+    // 確定済みの注文は仮押さえできない
+    assert!(matches!(
+        hold_service.place_hold(first, now).await,
+        Err(ApplicationError::DomainError(DomainError::InvalidOrderState(_)))
+    ));
+
+    // 期限までに確定しなかった注文の仮押さえは解放される
+    let third = pending_order_for(&app_service, book_id, 1).await;
+    hold_service.place_hold(third, now).await.unwrap();
+    assert_eq!(quantity_on_hand(book_id).await, 0);
+    let expired = Arc::new(Mutex::new(Vec::new()));
+    event_bus
+        .subscribe_checkout_hold_expired(CheckoutHoldExpiredRecorder {
+            events: expired.clone(),
+        })
+        .await
+        .unwrap();
+    let sweeper = CheckoutHoldSweeper::new(
+        hold_repo.clone(),
+        inventory_repo.clone(),
+        event_bus.clone(),
+        logger.clone(),
+    );
+    assert!(sweeper
+        .run(now + TimeDelta::minutes(14))
+        .await
+        .unwrap()
+        .is_empty());
+    let released = sweeper.run(now + TimeDelta::minutes(15)).await.unwrap();
+    assert_eq!(released, vec![third]);
+    assert_eq!(quantity_on_hand(book_id).await, 1);
+    assert!(hold_repo.find_by_order(third).await.unwrap().is_empty());
+
+    // 解放した仮押さえの数量がCheckoutHoldExpiredで通知される
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let expired = expired.lock().await;
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].order_id, third);
+    assert_eq!(
+        expired[0]
+            .holds
+            .iter()
+            .map(|hold| (hold.book_id, hold.quantity))
+            .collect::<Vec<_>>(),
+        vec![(book_id, 1)]
+    );
+}
+
+// 仮押さえの期限切れを記録するテスト用ハンドラー
+#[derive(Clone)]
+struct CheckoutHoldExpiredRecorder {
+    events: Arc<Mutex<Vec<CheckoutHoldExpired>>>,
+}
+
+#[async_trait]
+impl EventHandler<CheckoutHoldExpired> for CheckoutHoldExpiredRecorder {
+    async fn handle(&self, event: CheckoutHoldExpired) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+// テスト用のモックイベントジャーナル
+#[derive(Default)]
+struct MockEventJournal {
+    events: Mutex<Vec<JournaledEvent>>,
+    aggregate_ids: Mutex<HashMap<Uuid, String>>,
+}
+
+#[async_trait]
+impl EventJournal for MockEventJournal {
+    async fn append(&self, event: &DomainEvent) -> Result<bool, RepositoryError> {
+        let mut events = self.events.lock().await;
+        let metadata = event.metadata();
+        if events.iter().any(|e| e.event_id == metadata.event_id) {
+            return Ok(false);
+        }
+        let payload = EventSerializer::new()
+            .serialize_event(event)
+            .map_err(|e| RepositoryError::OperationFailed(e.to_string()))?;
+        let position = events.len() as u64 + 1;
+        events.push(JournaledEvent {
+            position,
+            event_id: metadata.event_id,
+            event_type: event.event_type().to_string(),
+            occurred_at: metadata.occurred_at,
+            payload,
+        });
+        self.aggregate_ids
+            .lock()
+            .await
+            .insert(metadata.event_id, event.aggregate_id());
+        Ok(true)
+    }
+
+    async fn read_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        after_position: u64,
+        limit: usize,
+    ) -> Result<Vec<JournaledEvent>, RepositoryError> {
+        Ok(self
+            .events
+            .lock()
+            .await
+            .iter()
+            .filter(|e| e.occurred_at >= from && e.occurred_at < to && e.position > after_position)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_aggregate(
+        &self,
+        aggregate_id: &str,
+    ) -> Result<Vec<JournaledEvent>, RepositoryError> {
+        let events = self.events.lock().await;
+        let aggregate_ids = self.aggregate_ids.lock().await;
+        Ok(events
+            .iter()
+            .filter(|e| aggregate_ids.get(&e.event_id).map(String::as_str) == Some(aggregate_id))
+            .cloned()
+            .collect())
+    }
+}
+
+/// 発行したイベントがジャーナルに記録され、期間ごとにチャンクに分けて書き出されること、
+/// 未完了の期間は次回の実行で続きから書き出され、完了後の再実行は何もしないことを検証
+#[tokio::test]
+async fn test_event_export_writes_chunks_and_resumes_from_manifest() {
+    let journal = Arc::new(MockEventJournal::default());
+    let event_bus =
+        Arc::new(InMemoryEventBus::new(EventBusConfig::default()).with_journal(journal.clone()));
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus.clone());
+    let storage = Arc::new(S3CompatibleObjectStorage::new(
+        "http://localhost:9000".to_string(),
+        "events".to_string(),
+    ));
+    let exporter = EventExporter::new(journal.clone(), storage.clone()).with_chunk_size(2);
+
+    let started_at = Utc::now();
+    let range = EventExportRange::new(
+        started_at - TimeDelta::hours(1),
+        started_at + TimeDelta::hours(1),
+    )
+    .unwrap();
+
+    let book_id = BookId::new();
+    let first = confirm_order_for(&app_service, book_id, 1).await;
+    confirm_order_for(&app_service, book_id, 1).await;
+    app_service.cancel_order(first).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // 期間の途中ではチャンクを書き出しても完了にしない
+    let manifest = exporter.export(&range, Utc::now()).await.unwrap();
+    assert_eq!(manifest.total_events, 3);
+    assert_eq!(
+        manifest
+            .chunks
+            .iter()
+            .map(|c| c.event_count)
+            .collect::<Vec<_>>(),
+        vec![2, 1]
+    );
+    assert!(!manifest.completed);
+
+    let body = storage
+        .get_object(&manifest.chunks[0].key)
+        .await
+        .unwrap()
+        .unwrap();
+    let events: Vec<DomainEvent> = String::from_utf8(body)
+        .unwrap()
+        .lines()
+        .map(|line| EventSerializer::new().deserialize_event(line).unwrap())
+        .collect();
+    assert_eq!(events.len(), 2);
+    assert!(events
+        .iter()
+        .all(|event| event.event_type() == "OrderConfirmed"));
+
+    // 次回の実行では前回の続きのイベントのみを書き出す
+    let second = confirm_order_for(&app_service, book_id, 1).await;
+    let manifest = exporter.export(&range, Utc::now()).await.unwrap();
+    assert_eq!(manifest.total_events, 4);
+    assert_eq!(manifest.chunks.len(), 3);
+    assert_eq!(manifest.last_position, 4);
+
+    // 期間の終了後に完了とし、完了後の再実行ではイベントを書き出さない
+    let after_range = range.to() + TimeDelta::seconds(1);
+    let completed = exporter.export(&range, after_range).await.unwrap();
+    assert!(completed.completed);
+    app_service.cancel_order(second).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let rerun = exporter
+        .export(&range, after_range + TimeDelta::hours(1))
+        .await
+        .unwrap();
+    assert_eq!(rerun, completed);
+    assert_eq!(journal.events.lock().await.len(), 5);
+}
+
+/// 別の環境で書き出したアーカイブを検証してジャーナルに取り込み、
+/// 記録済みのイベントは読み飛ばし、不正なアーカイブからは1件も取り込まないことを検証
+#[tokio::test]
+async fn test_event_import_restores_archive_into_fresh_journal() {
+    let source_journal = Arc::new(MockEventJournal::default());
+    let event_bus = Arc::new(
+        InMemoryEventBus::new(EventBusConfig::default()).with_journal(source_journal.clone()),
+    );
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus.clone());
+    let storage = Arc::new(S3CompatibleObjectStorage::new(
+        "http://localhost:9000".to_string(),
+        "events".to_string(),
+    ));
+
+    let started_at = Utc::now();
+    let range = EventExportRange::new(
+        started_at - TimeDelta::hours(1),
+        started_at + TimeDelta::hours(1),
+    )
+    .unwrap();
+    let book_id = BookId::new();
+    let first = confirm_order_for(&app_service, book_id, 1).await;
+    confirm_order_for(&app_service, book_id, 1).await;
+    app_service.cancel_order(first).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let manifest = EventExporter::new(source_journal.clone(), storage.clone())
+        .with_chunk_size(2)
+        .export(&range, range.to())
+        .await
+        .unwrap();
+    assert_eq!(manifest.total_events, 3);
+
+    // 新しい環境のジャーナルに記録順のまま取り込む
+    let target_journal = Arc::new(MockEventJournal::default());
+    let importer = EventImporter::new(target_journal.clone(), storage.clone());
+    let summary = importer.import(&range.key_prefix()).await.unwrap();
+    assert_eq!(summary.total_events, 3);
+    assert_eq!(summary.imported, 3);
+    assert_eq!(summary.skipped_duplicates, 0);
+    assert!(summary.completed);
+    let source_ids: Vec<Uuid> = source_journal
+        .events
+        .lock()
+        .await
+        .iter()
+        .map(|e| e.event_id)
+        .collect();
+    let target_ids: Vec<Uuid> = target_journal
+        .events
+        .lock()
+        .await
+        .iter()
+        .map(|e| e.event_id)
+        .collect();
+    assert_eq!(target_ids, source_ids);
+
+    // 同じアーカイブを再度取り込んでもイベントは重複しない
+    let summary = importer
+        .import(&format!("{}/", range.key_prefix()))
+        .await
+        .unwrap();
+    assert_eq!(summary.imported, 0);
+    assert_eq!(summary.skipped_duplicates, 3);
+
+    // 存在しないアーカイブ
+    assert!(matches!(
+        importer.import("events/unknown").await,
+        Err(EventImportError::ArchiveNotFound(_))
+    ));
+
+    // チャンクが壊れたアーカイブからは1件も取り込まない
+    let corrupted_journal = Arc::new(MockEventJournal::default());
+    storage
+        .put_object(&manifest.chunks[1].key, b"{not json}\n".to_vec())
+        .await
+        .unwrap();
+    let result = EventImporter::new(corrupted_journal.clone(), storage.clone())
+        .import(&range.key_prefix())
+        .await;
+    assert!(matches!(
+        result,
+        Err(EventImportError::InvalidArchive { ref key, .. }) if key == &manifest.chunks[1].key
+    ));
+    assert!(corrupted_journal.events.lock().await.is_empty());
+}
+
+/// 在庫予約が記録されていない確定済みの注文と、OrderShippedが発行されていない発送済みの注文を
+/// ジャーナルとの突き合わせで検出し、ドライランでは発行せずに修復手順だけを返すことを検証
+#[tokio::test]
+async fn test_order_repair_republishes_missing_events_with_dry_run() {
+    let journal = Arc::new(MockEventJournal::default());
+    let event_bus =
+        Arc::new(InMemoryEventBus::new(EventBusConfig::default()).with_journal(journal.clone()));
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let repair_service =
+        OrderRepairApplicationService::new(order_repo.clone(), journal.clone(), event_bus.clone());
+    let book_id = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(book_id, 10))
+        .await;
+
+    // 在庫予約のハンドラーが止まっている間に確定した注文は在庫予約が記録されない
+    let order_id = confirm_order_for(&app_service, book_id, 2).await;
+    let later = Utc::now() + TimeDelta::minutes(10);
+    let report = repair_service
+        .repair_order(order_id, false, Utc::now())
+        .await
+        .unwrap();
+    assert!(report.actions.is_empty());
+
+    event_bus
+        .subscribe_order_confirmed(InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::default()),
+            Arc::new(MockLogger),
+        ))
+        .await
+        .unwrap();
+    let confirmed_event_id = journal.events.lock().await[0].event_id;
+
+    // ドライランでは修復手順だけを返し、イベントは発行しない
+    let report = repair_service
+        .repair_order(order_id, true, later)
+        .await
+        .unwrap();
+    assert!(report.dry_run);
+    assert_eq!(report.status, "Confirmed");
+    assert_eq!(report.actions.len(), 1);
+    assert_eq!(
+        report.actions[0].issue,
+        StuckOrderIssue::MissingInventoryReservation
+    );
+    assert_eq!(
+        report.actions[0].remediation,
+        RepairRemediation::RepublishJournaledEvent
+    );
+    assert_eq!(report.actions[0].event_id, confirmed_event_id);
+    assert!(!report.actions[0].applied);
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let inventory = inventory_repo
+        .find_by_book_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 10);
+
+    // 修復するとジャーナルのOrderConfirmedを再発行し、在庫予約をやり直す
+    let report = repair_service
+        .repair_order(order_id, false, later)
+        .await
+        .unwrap();
+    assert!(report.actions[0].applied);
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let inventory = inventory_repo
+        .find_by_book_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 8);
+    assert!(repair_service
+        .repair_order(order_id, false, later)
+        .await
+        .unwrap()
+        .actions
+        .is_empty());
+
+    // 発送済みにした後でOrderShippedの発行に失敗した注文は、イベントを作り直して発行する
+    let mut order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+    order.mark_as_shipped().unwrap();
+    order_repo.save(&mut order).await.unwrap();
+    let later = Utc::now() + TimeDelta::minutes(10);
+    let report = repair_service
+        .repair_order(order_id, false, later)
+        .await
+        .unwrap();
+    assert_eq!(report.status, "Shipped");
+    assert_eq!(
+        report.actions[0].issue,
+        StuckOrderIssue::MissingShippedEvent
+    );
+    assert_eq!(
+        report.actions[0].remediation,
+        RepairRemediation::PublishRebuiltEvent
+    );
+    let shipped = journal
+        .events
+        .lock()
+        .await
+        .iter()
+        .find(|event| event.event_type == "OrderShipped")
+        .cloned()
+        .unwrap();
+    assert_eq!(shipped.event_id, report.actions[0].event_id);
+    let shipped = EventSerializer::new()
+        .deserialize_event(&shipped.payload)
+        .unwrap();
+    assert_eq!(
+        Some(shipped.metadata().correlation_id),
+        order.saga_correlation_id()
+    );
+    assert!(repair_service
+        .repair_order(order_id, false, later)
+        .await
+        .unwrap()
+        .actions
+        .is_empty());
+
+    assert!(matches!(
+        repair_service
+            .repair_order(OrderId::new(), true, later)
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+// テスト用のモックCSメモリポジトリ
+#[derive(Default)]
+struct MockOrderNoteRepository {
+    notes: Mutex<Vec<OrderNote>>,
+}
+
+#[async_trait]
+impl OrderNoteRepository for MockOrderNoteRepository {
+    async fn add(&self, note: &OrderNote) -> Result<(), RepositoryError> {
+        self.notes.lock().await.push(note.clone());
+        Ok(())
+    }
+
+    async fn find_by_order(&self, order_id: OrderId) -> Result<Vec<OrderNote>, RepositoryError> {
+        let notes = self.notes.lock().await;
+        Ok(notes
+            .iter()
+            .filter(|note| note.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+}
+
+/// 注文タイムラインにイベント・ステータスの遷移・CSメモ・配送業者の追跡情報が時系列でまとめられ、
+/// 注文イベントから投影された追跡情報は重複して含まれないことを検証
+#[tokio::test]
+async fn test_order_timeline_merges_events_notes_and_carrier_tracking() {
+    let journal = Arc::new(MockEventJournal::default());
+    let event_bus =
+        Arc::new(InMemoryEventBus::new(EventBusConfig::default()).with_journal(journal.clone()));
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let tracking_repo = Arc::new(MockTrackingEventRepository::default());
+    let note_repo = Arc::new(MockOrderNoteRepository::default());
+
+    let projection = TrackingProjectionHandler::new(tracking_repo.clone(), Arc::new(MockLogger));
+    event_bus
+        .subscribe_order_confirmed(projection.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_carrier_tracking_updated(projection)
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let tracking_service = TrackingApplicationService::new(
+        order_repo.clone(),
+        tracking_repo.clone(),
+        event_bus.clone(),
+    );
+    let timeline_service = TimelineApplicationService::new(
+        order_repo.clone(),
+        journal.clone(),
+        note_repo.clone(),
+        tracking_repo,
+    );
+
+    let order_id = confirm_order_for(&app_service, BookId::new(), 1).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    timeline_service
+        .add_note(order_id, "alice", "  配送日の問い合わせあり ", Utc::now())
+        .await
+        .unwrap();
+    tracking_service
+        .record_carrier_update(
+            order_id,
+            TrackingStage::Packed,
+            Utc::now(),
+            Some("東京ベース".to_string()),
+            None,
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let timeline = timeline_service.get_order_timeline(order_id).await.unwrap();
+    assert_eq!(timeline.status, "Confirmed");
+    assert!(timeline
+        .entries
+        .windows(2)
+        .all(|pair| pair[0].occurred_at() <= pair[1].occurred_at()));
+
+    let event_types: Vec<&str> = timeline
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            TimelineEntry::Event { event_type, .. } => Some(event_type.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert!(event_types.contains(&"OrderConfirmed"));
+    assert!(!event_types.contains(&"CarrierTrackingUpdated"));
+    assert!(timeline.entries.iter().any(|entry| matches!(
+        entry,
+        TimelineEntry::StatusChanged { status, .. } if status == "Confirmed"
+    )));
+    assert!(timeline.entries.iter().any(|entry| matches!(
+        entry,
+        TimelineEntry::Note { author, body, .. } if author == "alice" && body == "配送日の問い合わせあり"
+    )));
+    let tracking_stages: Vec<TrackingStage> = timeline
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            TimelineEntry::Tracking { stage, .. } => Some(*stage),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(tracking_stages, vec![TrackingStage::Packed]);
+
+    // 空のメモと存在しない注文は受け付けない
+    let result = timeline_service
+        .add_note(order_id, "alice", "  ", Utc::now())
+        .await;
+    assert!(matches!(result, Err(ApplicationError::DomainError(_))));
+    let result = timeline_service.get_order_timeline(OrderId::new()).await;
+    assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+}
+
+/// 同じ顧客・同じ明細の注文を重複の可能性がある注文として見つけ、
+/// 明細や顧客が異なる注文・キャンセル済みの注文は含めないことを検証
+#[tokio::test]
+async fn test_similar_orders_find_accidental_double_purchases() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus);
+    let customer_id = CustomerId::new();
+    let book_id = BookId::new();
+    let other_book_id = BookId::new();
+
+    let place_order = |lines: Vec<(BookId, u32)>, customer_id: CustomerId| {
+        let app_service = &app_service;
+        async move {
+            let order_id = app_service.create_order(customer_id).await.unwrap();
+            for (book_id, quantity) in lines {
+                app_service
+                    .add_book_to_order(order_id, book_id, quantity, Money::jpy(1800))
+                    .await
+                    .unwrap();
+            }
+            order_id
+        }
+    };
+    let original = place_order(vec![(book_id, 1), (other_book_id, 2)], customer_id).await;
+    // 明細の追加順が違っても同じ明細とみなす
+    let duplicate = place_order(vec![(other_book_id, 2), (book_id, 1)], customer_id).await;
+    place_order(vec![(book_id, 1)], customer_id).await;
+    place_order(vec![(book_id, 1), (other_book_id, 3)], customer_id).await;
+    place_order(vec![(book_id, 1), (other_book_id, 2)], CustomerId::new()).await;
+    let cancelled = place_order(vec![(book_id, 1), (other_book_id, 2)], customer_id).await;
+    app_service.cancel_order(cancelled).await.unwrap();
+
+    let similar = app_service
+        .find_similar_orders(original, TimeDelta::minutes(30))
+        .await
+        .unwrap();
+    let similar_ids: Vec<OrderId> = similar.iter().map(|order| order.id()).collect();
+    assert_eq!(similar_ids, vec![duplicate]);
+
+    // 存在しない注文
+    assert!(matches!(
+        app_service
+            .find_similar_orders(OrderId::new(), TimeDelta::minutes(30))
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+/// 書籍のタイトルを言語ごとに登録し、表示言語のタイトルがない書籍は既定の言語にフォールバックすることを検証
+#[tokio::test]
+async fn test_book_titles_are_localized_with_fallback() {
+    let catalog_service = CatalogApplicationService::new(Arc::new(MockBookCatalog::default()));
+    let translated_book = BookId::new();
+    let japanese_only_book = BookId::new();
+    let untitled_book = BookId::new();
+
+    catalog_service
+        .set_title(translated_book, Language::Ja, "ドメイン駆動設計")
+        .await
+        .unwrap();
+    let titles = catalog_service
+        .set_title(translated_book, Language::En, "  Domain-Driven Design ")
+        .await
+        .unwrap();
+    assert_eq!(titles.get(Language::En), Some("Domain-Driven Design"));
+    catalog_service
+        .set_title(japanese_only_book, Language::Ja, "実践ドメイン駆動設計")
+        .await
+        .unwrap();
+
+    let localized = catalog_service
+        .localized_titles(
+            &[translated_book, japanese_only_book, untitled_book],
+            Language::En,
+        )
+        .await
+        .unwrap();
+    assert_eq!(localized[&translated_book].title, "Domain-Driven Design");
+    assert_eq!(localized[&japanese_only_book].title, "実践ドメイン駆動設計");
+    assert_eq!(localized[&japanese_only_book].language, Language::Ja);
+    assert!(!localized.contains_key(&untitled_book));
+
+    // 空のタイトルは登録できない
+    assert!(matches!(
+        catalog_service
+            .set_title(untitled_book, Language::En, " ")
+            .await,
+        Err(ApplicationError::DomainError(DomainError::InvalidValue(_)))
+    ));
+}
+
+/// 在庫評価レポートが在庫数と書籍カタログの価格から在庫金額を集計し、CSVでも出力できることを検証
+#[tokio::test]
+async fn test_inventory_valuation_report_totals_stock_value() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let inventory_service = InventoryApplicationService::new(inventory_repo.clone(), event_bus);
+
+    let priced_book = BookId::new();
+    let unpriced_book = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(priced_book, 4))
+        .await;
+    inventory_repo
+        .add_inventory(Inventory::new(unpriced_book, 1))
+        .await;
+    inventory_repo
+        .set_price(priced_book, Money::jpy(2200))
+        .await;
+
+    let report = inventory_service
+        .get_valuation_report(Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(report.total_quantity, 5);
+    assert_eq!(report.total_value, 8800);
+    assert_eq!(report.unpriced_count, 1);
+
+    let csv = report.to_csv();
+    assert!(csv.contains(&format!("{},4,2200,8800\n", priced_book)));
+    assert!(csv.contains(&format!("{},1,,\n", unpriced_book)));
+    assert!(csv.ends_with("total,5,,8800\n"));
+}
+
+/// POS向けの在庫スナップショットのversionを起点に、以降の在庫変動だけを差分として取得できることを検証
+#[tokio::test]
+async fn test_inventory_snapshot_and_incremental_changes() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let inventory_service = InventoryApplicationService::new(inventory_repo.clone(), event_bus);
+
+    let book_id = BookId::new();
+    inventory_service
+        .create_inventory(book_id, 5, None, false)
+        .await
+        .unwrap();
+
+    let snapshot = inventory_service
+        .get_inventory_snapshot(Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(snapshot.version, 1);
+    assert_eq!(snapshot.entries.len(), 1);
+    assert_eq!(snapshot.entries[0].quantity_on_hand, 5);
+
+    inventory_service.restock_inventory(book_id, 3).await.unwrap();
+
+    let changes = inventory_service
+        .get_inventory_changes(snapshot.version)
+        .await
+        .unwrap();
+    assert_eq!(changes.changes.len(), 1);
+    assert_eq!(changes.changes[0].quantity_delta, 3);
+    assert_eq!(changes.changes[0].quantity_on_hand, 8);
+    assert_eq!(changes.version, 2);
+    assert!(!changes.has_more);
+
+    // 同期済みのversionからは差分がない
+    let changes = inventory_service
+        .get_inventory_changes(changes.version)
+        .await
+        .unwrap();
+    assert!(changes.changes.is_empty());
+    assert_eq!(changes.version, 2);
+}
+
+/// 需要予測が確定した注文の数量の移動平均から発注点を求め、キャンセルされた注文と未確定の注文を除くことを検証
+#[tokio::test]
+async fn test_demand_forecast_averages_confirmed_orders_and_flags_reorder() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let order_repo = InMemoryOrderRepository::new();
+    let app_service = OrderApplicationService::new(
+        order_repo.clone(),
+        event_bus,
+    );
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let forecast_service = ForecastApplicationService::new(
+        Arc::new(order_repo),
+        inventory_repo.clone(),
+    )
+    .with_policy(ForecastPolicy {
+        window_days: 4,
+        lead_time_days: 3,
+    });
+
+    let book_id = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(book_id, 5))
+        .await;
+    confirm_order_for(&app_service, book_id, 3).await;
+    confirm_order_for(&app_service, book_id, 5).await;
+    let cancelled = confirm_order_for(&app_service, book_id, 10).await;
+    app_service.cancel_order(cancelled).await.unwrap();
+    pending_order_for(&app_service, book_id, 7).await;
+
+    // 今日確定した注文は翌日の予測から需要に含まれる
+    let tomorrow = Utc::now() + TimeDelta::days(1);
+    let forecast = forecast_service
+        .get_forecast(book_id, tomorrow)
+        .await
+        .unwrap();
+    assert_eq!(forecast.daily_demand.len(), 4);
+    assert_eq!(
+        forecast.daily_demand.last().unwrap().date,
+        Utc::now().date_naive()
+    );
+    assert_eq!(forecast.average_daily_demand, 2.0);
+    assert_eq!(forecast.days_of_stock_remaining, Some(2.5));
+    assert_eq!(forecast.reorder_point, 6);
+    assert!(forecast.needs_reorder);
+
+    // 在庫のない書籍は予測できない
+    assert!(matches!(
+        forecast_service.get_forecast(BookId::new(), tomorrow).await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+/// 確定時の金額を注文に保存し、確定後に配送料ポリシーが変わっても確定済みの注文の金額が変わらないことを検証
+#[tokio::test]
+async fn test_confirmed_totals_are_kept_after_policy_changes() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let order_repo = InMemoryOrderRepository::new();
+    let app_service = OrderApplicationService::new(
+        order_repo.clone(),
+        event_bus.clone(),
+    );
+    // 送料無料の下限を下げた後のポリシー
+    let cheaper_policy = ShippingFeePolicy::new(
+        ShippingRateTable::default(),
+        1_000,
+        CarrierLimits::default(),
+    );
+    let repriced_service = OrderApplicationService::new(order_repo, event_bus)
+        .with_shipping_fee_policy(cheaper_policy.clone());
+
+    let confirmed = confirm_order_for(&app_service, BookId::new(), 2).await;
+    let pending = pending_order_for(&app_service, BookId::new(), 2).await;
+
+    let order = app_service
+        .get_order_by_id(confirmed)
+        .await
+        .unwrap()
+        .unwrap();
+    let snapshot = *order
+        .confirmed_totals()
+        .expect("確定時の金額が保存されるはず");
+    assert_eq!(
+        snapshot.total,
+        order.subtotal().add(&snapshot.shipping_fee()).unwrap()
+    );
+    assert_eq!(snapshot.tax.amount(), snapshot.total.amount() * 10 / 110);
+
+    // 確定済みの注文は確定時の金額のまま、Pendingの注文は現在のポリシーで再計算する
+    assert_eq!(OrderTotals::for_order(&order, &cheaper_policy), snapshot);
+    let pending_order = app_service.get_order_by_id(pending).await.unwrap().unwrap();
+    assert!(pending_order.confirmed_totals().is_none());
+    assert_eq!(
+        OrderTotals::for_order(&pending_order, &cheaper_policy).shipping_fee(),
+        Money::jpy(0)
+    );
+
+    // 確定後に配送先を変更した場合は配送料を現在のポリシーで再計算する
+    repriced_service
+        .set_shipping_address_from_request(
+            confirmed,
+            "0600000".to_string(),
+            "北海道".to_string(),
+            "札幌市".to_string(),
+            "北1条西1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    let order = app_service
+        .get_order_by_id(confirmed)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        order.confirmed_totals().unwrap().shipping_fee(),
+        Money::jpy(0)
+    );
+    assert_eq!(order.confirmed_totals().unwrap().total, order.subtotal());
+}
+
+/// 確定時に発行した追跡トークンで、注文IDや個人情報を含まない配送状況を確認でき、
+/// キャンセルするとトークンが失効することを検証
+#[tokio::test]
+async fn test_public_tracking_token_is_revoked_on_cancellation() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus);
+
+    let order_id = confirm_order_for(&app_service, BookId::new(), 1).await;
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let token = order
+        .tracking_token()
+        .cloned()
+        .expect("確定時に追跡トークンが発行されるはず");
+
+    let view = app_service.get_public_tracking(&token).await.unwrap();
+    assert_eq!(view.status, OrderStatus::Confirmed);
+    assert_eq!(
+        view.estimated_delivery_at,
+        SlaPolicy::default().estimated_delivery_at(&order)
+    );
+    let address = view.shipping_address.unwrap();
+    assert_eq!(address.postal_code, "150-****");
+    assert_eq!(address.city, "渋谷区");
+
+    // 推測したトークンでは見つからない
+    assert!(matches!(
+        app_service
+            .get_public_tracking(&TrackingToken::generate())
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+
+    app_service.cancel_order(order_id).await.unwrap();
+    assert!(matches!(
+        app_service.get_public_tracking(&token).await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+// テスト用のモックWebhook購読リポジトリ
+#[derive(Default)]
+struct MockWebhookSubscriptionRepository {
+    subscriptions: Mutex<Vec<WebhookSubscription>>,
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepository for MockWebhookSubscriptionRepository {
+    async fn save(&self, subscription: &WebhookSubscription) -> Result<(), RepositoryError> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        subscriptions.retain(|s| s.id != subscription.id);
+        subscriptions.push(subscription.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        let subscriptions = self.subscriptions.lock().await;
+        Ok(subscriptions.iter().find(|s| s.id == id).cloned())
+    }
+
+    async fn find_by_customer(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        let subscriptions = self.subscriptions.lock().await;
+        Ok(subscriptions
+            .iter()
+            .filter(|s| s.customer_id == customer_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, customer_id: CustomerId, id: Uuid) -> Result<bool, RepositoryError> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        let before = subscriptions.len();
+        subscriptions.retain(|s| !(s.customer_id == customer_id && s.id == id));
+        Ok(subscriptions.len() < before)
+    }
+}
+
+// テスト用のモックWebhook配信ログリポジトリ
+#[derive(Default)]
+struct MockWebhookDeliveryRepository {
+    deliveries: Mutex<Vec<WebhookDelivery>>,
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for MockWebhookDeliveryRepository {
+    async fn record(&self, delivery: &WebhookDelivery) -> Result<(), RepositoryError> {
+        self.deliveries.lock().await.push(delivery.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let deliveries = self.deliveries.lock().await;
+        Ok(deliveries.iter().find(|d| d.id == id).cloned())
+    }
+
+    async fn find_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let deliveries = self.deliveries.lock().await;
+        Ok(deliveries
+            .iter()
+            .rev()
+            .filter(|d| d.subscription_id == subscription_id)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+// 送信先URLとペイロードを記録するテスト用Webhook送信（rejectingの間は配信先がHTTP 503を返す）
+#[derive(Default)]
+struct RecordingWebhookSender {
+    sent: Mutex<Vec<(String, String)>>,
+    rejecting: Mutex<bool>,
+}
+
+#[async_trait]
+impl WebhookSender for RecordingWebhookSender {
+    async fn send(
+        &self,
+        subscription: &WebhookSubscription,
+        _delivery_id: Uuid,
+        _event_type: &str,
+        payload: &str,
+    ) -> WebhookOutcome {
+        self.sent
+            .lock()
+            .await
+            .push((subscription.url.clone(), payload.to_string()));
+        if *self.rejecting.lock().await {
+            WebhookOutcome::Rejected { status: 503 }
+        } else {
+            WebhookOutcome::Delivered { status: 200 }
+        }
+    }
+}
+
+/// 顧客のWebhookには自分の注文の購読したイベントだけが配信され、
+/// 配信先の失敗は配信ログに記録されて再配信できることを検証
+#[tokio::test]
+async fn test_customer_webhooks_are_scoped_logged_and_redeliverable() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let subscription_repo = Arc::new(MockWebhookSubscriptionRepository::default());
+    let delivery_repo = Arc::new(MockWebhookDeliveryRepository::default());
+    let sender = Arc::new(RecordingWebhookSender::default());
+    let dispatcher = WebhookDispatcher::new(
+        subscription_repo.clone(),
+        delivery_repo.clone(),
+        sender.clone(),
+    );
+
+    let webhook_handler =
+        CustomerWebhookHandler::new(order_repo.clone(), dispatcher.clone(), Arc::new(MockLogger));
+    event_bus
+        .subscribe_order_confirmed(webhook_handler.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_shipped(webhook_handler)
+        .await
+        .unwrap();
+
+    let webhook_service =
+        WebhookApplicationService::new(subscription_repo, delivery_repo, dispatcher);
+    let customer_id = CustomerId::new();
+    let other_customer_id = CustomerId::new();
+    let subscription = webhook_service
+        .create_subscription(
+            customer_id,
+            "https://partner.example.com/hooks".to_string(),
+            "whsec_0123456789abcdef".to_string(),
+            vec!["OrderConfirmed".to_string(), "OrderShipped".to_string()],
+        )
+        .await
+        .unwrap();
+    webhook_service
+        .create_subscription(
+            other_customer_id,
+            "https://other.example.com/hooks".to_string(),
+            "whsec_fedcba9876543210".to_string(),
+            vec!["OrderConfirmed".to_string(), "OrderShipped".to_string()],
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        webhook_service
+            .create_subscription(
+                customer_id,
+                "https://partner.example.com/hooks".to_string(),
+                "whsec_0123456789abcdef".to_string(),
+                vec!["InventoryReserved".to_string()],
+            )
+            .await,
+        Err(ApplicationError::DomainError(DomainError::Validation(_)))
+    ));
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let order_id = app_service.create_order(customer_id).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1500001".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "神宮前1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // 配信先が落ちている間の発送は失敗として記録される
+    *sender.rejecting.lock().await = true;
+    app_service
+        .mark_order_as_shipped(order_id, None)
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // 他の顧客の購読には配信されない
+    let sent = sender.sent.lock().await.clone();
+    assert_eq!(sent.len(), 2);
+    assert!(sent
+        .iter()
+        .all(|(url, _)| url == "https://partner.example.com/hooks"));
+    let payload: serde_json::Value = serde_json::from_str(&sent[0].1).unwrap();
+    assert_eq!(payload["event_type"], "OrderConfirmed");
+    assert_eq!(payload["customer_id"], customer_id.to_string());
+    assert_eq!(payload["order_id"], order_id.to_string());
+
+    let deliveries = webhook_service
+        .list_deliveries(customer_id, subscription.id, 50)
+        .await
+        .unwrap();
+    let summary: Vec<(&str, WebhookDeliveryStatus, Option<u16>)> = deliveries
+        .iter()
+        .map(|d| (d.event_type.as_str(), d.status, d.response_status))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("OrderShipped", WebhookDeliveryStatus::Failed, Some(503)),
+            (
+                "OrderConfirmed",
+                WebhookDeliveryStatus::Succeeded,
+                Some(200)
+            ),
+        ]
+    );
+
+    // 他の顧客からは配信ログの参照も再配信もできない
+    let failed = &deliveries[0];
+    assert!(matches!(
+        webhook_service
+            .list_deliveries(other_customer_id, subscription.id, 50)
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+    assert!(matches!(
+        webhook_service
+            .redeliver(other_customer_id, subscription.id, failed.id)
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+
+    // 配信先の復旧後に同じペイロードを再配信する
+    *sender.rejecting.lock().await = false;
+    let redelivery = webhook_service
+        .redeliver(customer_id, subscription.id, failed.id)
+        .await
+        .unwrap();
+    assert_eq!(redelivery.status, WebhookDeliveryStatus::Succeeded);
+    assert_eq!(redelivery.redelivery_of, Some(failed.id));
+    assert_eq!(redelivery.event_id, failed.event_id);
+    assert_eq!(redelivery.payload, failed.payload);
+    assert_eq!(
+        webhook_service
+            .list_deliveries(customer_id, subscription.id, 50)
+            .await
+            .unwrap()
+            .len(),
+        3
+    );
+
+    webhook_service
+        .delete_subscription(customer_id, subscription.id)
+        .await
+        .unwrap();
+    assert!(webhook_service
+        .list_subscriptions(customer_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+// テスト用のモック再試行待ち操作リポジトリ
+#[derive(Default)]
+struct MockPendingOperationRepository {
+    operations: Mutex<Vec<PendingOperation>>,
+}
+
+#[async_trait]
+impl PendingOperationRepository for MockPendingOperationRepository {
+    async fn save(&self, operation: &PendingOperation) -> Result<(), RepositoryError> {
+        let mut operations = self.operations.lock().await;
+        match operations.iter_mut().find(|o| o.id == operation.id) {
+            Some(existing) => *existing = operation.clone(),
+            None => operations.push(operation.clone()),
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PendingOperation>, RepositoryError> {
+        Ok(self
+            .operations
+            .lock()
+            .await
+            .iter()
+            .find(|o| o.id == id)
+            .cloned())
+    }
+
+    async fn find_pending(&self, limit: u32) -> Result<Vec<PendingOperation>, RepositoryError> {
+        Ok(self
+            .operations
+            .lock()
+            .await
+            .iter()
+            .filter(|o| o.status == PendingOperationStatus::Pending)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+// 指定した回数だけ発行に失敗するイベントバス
+struct UnreliableEventBus {
+    inner: Arc<InMemoryEventBus>,
+    failures: Mutex<u32>,
+}
+
+#[async_trait]
+impl EventBus for UnreliableEventBus {
+    async fn publish(&self, event: DomainEvent) -> Result<(), EventBusError> {
+        let mut failures = self.failures.lock().await;
+        if *failures > 0 {
+            *failures -= 1;
+            return Err(EventBusError::PublishingFailed(
+                "Dispatch lane 0 is closed".to_string(),
+            ));
+        }
+        drop(failures);
+        self.inner.publish(event).await
+    }
+}
+
+/// 状態の保存後にイベントの発行に失敗した確定は再試行待ちの操作として記録して応答し、
+/// 再試行ジョブが同じイベントを発行して操作を完了にすることを検証
+#[tokio::test]
+async fn test_failed_publish_is_queued_and_retried_until_completed() {
+    let journal = Arc::new(MockEventJournal::default());
+    let event_bus = Arc::new(UnreliableEventBus {
+        inner: Arc::new(
+            InMemoryEventBus::new(EventBusConfig::default()).with_journal(journal.clone()),
+        ),
+        failures: Mutex::new(0),
+    });
+    let operation_repo = Arc::new(MockPendingOperationRepository::default());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    )
+    .with_pending_operations(operation_repo.clone());
+    let operation_service = PendingOperationApplicationService::new(operation_repo.clone());
+
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1800))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1500001".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "神宮前1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // 発行に失敗しても確定は保存され、操作IDを返す
+    *event_bus.failures.lock().await = 2;
+    let acknowledgement = app_service.confirm_order(order_id).await.unwrap();
+    let operation_id = acknowledgement.pending_operation_id.unwrap();
+    let order = order_repo.find_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::Confirmed);
+    assert!(journal.events.lock().await.is_empty());
+    let operation = operation_service.get_operation(operation_id).await.unwrap();
+    assert_eq!(operation.status, PendingOperationStatus::Pending);
+    assert_eq!(operation.event_type, "OrderConfirmed");
+    assert_eq!(operation.aggregate_id, order_id.to_string());
+
+    // 再試行に失敗した場合は再試行待ちのまま試行回数を数える
+    let retrier = PendingOperationRetrier::new(
+        operation_repo.clone(),
+        event_bus.clone(),
+        Arc::new(MockLogger),
+    );
+    assert!(retrier.run(Utc::now()).await.unwrap().is_empty());
+    let operation = operation_service.get_operation(operation_id).await.unwrap();
+    assert_eq!(operation.status, PendingOperationStatus::Pending);
+    assert_eq!(operation.attempts, 2);
+
+    // 発行できたら操作を完了にし、確定時と同じイベントを発行する
+    assert_eq!(retrier.run(Utc::now()).await.unwrap(), vec![operation_id]);
+    let operation = operation_service.get_operation(operation_id).await.unwrap();
+    assert_eq!(operation.status, PendingOperationStatus::Completed);
+    assert!(operation.completed_at.is_some());
+    let journaled = journal.events.lock().await;
+    assert_eq!(journaled.len(), 1);
+    assert_eq!(
+        journaled[0].event_id,
+        acknowledgement.event.metadata().event_id
+    );
+    drop(journaled);
+    assert!(retrier.run(Utc::now()).await.unwrap().is_empty());
+
+    // 再試行待ちの操作が未設定の場合は発行の失敗をそのまま返す
+    let unqueued_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    *event_bus.failures.lock().await = 1;
+    assert!(matches!(
+        unqueued_service.cancel_order(order_id).await,
+        Err(ApplicationError::EventPublishingFailed(_))
+    ));
+    assert!(matches!(
+        operation_service.get_operation(Uuid::new_v4()).await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+// アクションリンクの使用記録をメモリに保存するテスト用リポジトリ
+#[derive(Default)]
+struct MockActionLinkAuditRepository {
+    records: Mutex<Vec<ActionLinkUse>>,
+}
+
+#[async_trait]
+impl ActionLinkAuditRepository for MockActionLinkAuditRepository {
+    async fn save(&self, record: &ActionLinkUse) -> Result<(), RepositoryError> {
+        self.records.lock().await.push(record.clone());
+        Ok(())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<ActionLinkUse>, RepositoryError> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .iter()
+            .filter(|record| record.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+}
+
+/// 確定の通知に載せたキャンセルのリンクで注文をキャンセルでき、使われたトークンが記録されることを検証
+#[tokio::test]
+async fn test_signed_action_link_from_push_notification_cancels_order() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let push = Arc::new(RecordingPushNotification::default());
+    let signer: Arc<dyn ActionLinkSigner> = Arc::new(HmacActionLinkSigner::new(
+        "0123456789abcdef0123456789abcdef",
+    ));
+    let push_handler =
+        PushNotificationHandler::new(order_repo.clone(), push.clone(), Arc::new(MockLogger))
+            .with_action_links(ActionLinkIssuer::new(
+                signer.clone(),
+                "https://shop.example.com/".to_string(),
+                TimeDelta::hours(72),
+            ));
+    event_bus
+        .subscribe_order_confirmed(push_handler)
+        .await
+        .unwrap();
+
+    let app_service = Arc::new(OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    ));
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // 確定の通知にキャンセルのリンクが載る
+    let published = push.published.lock().await.clone();
+    let payload: serde_json::Value = serde_json::from_str(&published[0].1).unwrap();
+    assert_eq!(payload["actions"][0]["action"], "cancel_order");
+    let url = payload["actions"][0]["url"].as_str().unwrap();
+    assert!(url.starts_with("https://shop.example.com/actions/"));
+    let token = url.rsplit('/').next().unwrap();
+
+    let audit = Arc::new(MockActionLinkAuditRepository::default());
+    let action_links =
+        ActionLinkApplicationService::new(app_service.clone(), signer, audit.clone());
+    let now = Utc::now();
+    let claims = action_links.describe(token, now).unwrap();
+    assert_eq!(claims.action, CustomerAction::CancelOrder);
+    assert_eq!(claims.order_id, order_id);
+
+    action_links.execute(token, now).await.unwrap();
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order.status(), OrderStatus::Cancelled);
+
+    // 使用済みのリンクを再度使うと注文の状態により拒否され、その結果も記録される
+    assert!(matches!(
+        action_links.execute(token, now).await,
+        Err(ApplicationError::DomainError(
+            DomainError::InvalidOrderState(_)
+        ))
+    ));
+    // 有効期限切れのリンクは見つからない扱いにする
+    assert!(matches!(
+        action_links
+            .execute(token, now + TimeDelta::hours(73))
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+    let records = audit.find_by_order(order_id).await.unwrap();
+    assert_eq!(
+        records.iter().map(|r| r.outcome).collect::<Vec<_>>(),
+        vec![
+            ActionLinkOutcome::Executed,
+            ActionLinkOutcome::Rejected,
+            ActionLinkOutcome::Rejected
+        ]
+    );
+    assert!(records.iter().all(|r| r.link_id == claims.link_id));
+
+    // 改ざんしたトークンは記録せずに拒否する
+    let tampered = token.replacen("cancel_order", "confirm_delivery", 1);
+    assert!(matches!(
+        action_links.execute(&tampered, now).await,
+        Err(ApplicationError::NotFound(_))
+    ));
+    assert_eq!(audit.records.lock().await.len(), 3);
+}
+
+#[derive(Clone)]
+struct SegmentationRecorder {
+    events: Arc<Mutex<Vec<DomainEvent>>>,
+}
+
+#[async_trait]
+impl EventHandler<HighValueOrderPlaced> for SegmentationRecorder {
+    async fn handle(&self, event: HighValueOrderPlaced) -> Result<(), HandlerError> {
+        self.events
+            .lock()
+            .await
+            .push(DomainEvent::HighValueOrderPlaced(event));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<CustomerBecameRepeatBuyer> for SegmentationRecorder {
+    async fn handle(&self, event: CustomerBecameRepeatBuyer) -> Result<(), HandlerError> {
+        self.events
+            .lock()
+            .await
+            .push(DomainEvent::CustomerBecameRepeatBuyer(event));
+        Ok(())
+    }
+}
+
+/// 顧客セグメントハンドラーが高額注文の確定と2件目の配達完了で派生イベントを1回ずつ発行することを検証
+#[tokio::test]
+async fn test_segmentation_handler_publishes_high_value_and_repeat_buyer_events() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let order_repo = InMemoryOrderRepository::new();
+    let segmentation_handler = CustomerSegmentationHandler::new(
+        Arc::new(order_repo.clone()),
+        event_bus.clone(),
+        Arc::new(MockLogger),
+        SegmentationRules::default(),
+    );
+    event_bus
+        .subscribe_order_confirmed(segmentation_handler.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_delivered(segmentation_handler)
+        .await
+        .unwrap();
+    let recorder = SegmentationRecorder {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    event_bus
+        .subscribe_high_value_order_placed(recorder.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_customer_became_repeat_buyer(recorder.clone())
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(order_repo, event_bus);
+    let customer_id = CustomerId::new();
+    let mut order_ids = Vec::new();
+    for quantity in [6, 1, 1] {
+        let order_id = app_service.create_order(customer_id).await.unwrap();
+        app_service
+            .add_book_to_order(order_id, BookId::new(), quantity, Money::jpy(1800))
+            .await
+            .unwrap();
+        app_service
+            .set_shipping_address_from_request(
+                order_id,
+                "1500001".to_string(),
+                "東京都".to_string(),
+                "渋谷区".to_string(),
+                "神宮前1-1-1".to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+        app_service.confirm_order(order_id).await.unwrap();
+        order_ids.push(order_id);
+    }
+    for order_id in &order_ids {
+        app_service
+            .mark_order_as_shipped(*order_id, None)
+            .await
+            .unwrap();
+        app_service
+            .mark_order_as_delivered(*order_id, None)
+            .await
+            .unwrap();
+    }
+
+    let events = recorder.events.lock().await;
+    assert_eq!(events.len(), 2);
+    let DomainEvent::HighValueOrderPlaced(high_value) = &events[0] else {
+        panic!("expected HighValueOrderPlaced, got {:?}", events[0]);
+    };
+    assert_eq!(high_value.order_id, order_ids[0]);
+    assert_eq!(high_value.threshold, Money::jpy(10_000));
+    let DomainEvent::CustomerBecameRepeatBuyer(repeat_buyer) = &events[1] else {
+        panic!("expected CustomerBecameRepeatBuyer, got {:?}", events[1]);
+    };
+    assert_eq!(repeat_buyer.customer_id, customer_id);
+    assert_eq!(repeat_buyer.order_id, order_ids[1]);
+    assert_eq!(repeat_buyer.delivered_order_count, 2);
+}
+
+#[derive(Default)]
+struct MockOrderLockRepository {
+    locks: Mutex<HashMap<OrderId, OrderLock>>,
+}
+
+#[async_trait]
+impl OrderLockRepository for MockOrderLockRepository {
+    async fn try_acquire(&self, lock: &OrderLock) -> Result<OrderLock, RepositoryError> {
+        let mut locks = self.locks.lock().await;
+        let current = locks
+            .entry(lock.order_id)
+            .and_modify(|current| {
+                if current.is_expired(lock.locked_at) || current.locked_by == lock.locked_by {
+                    *current = lock.clone();
+                }
+            })
+            .or_insert_with(|| lock.clone());
+        Ok(current.clone())
+    }
+
+    async fn find_by_order_id(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Option<OrderLock>, RepositoryError> {
+        Ok(self.locks.lock().await.get(&order_id).cloned())
+    }
+
+    async fn find_active(&self, now: DateTime<Utc>) -> Result<Vec<OrderLock>, RepositoryError> {
+        let locks = self.locks.lock().await;
+        Ok(locks
+            .values()
+            .filter(|lock| !lock.is_expired(now))
+            .cloned()
+            .collect())
+    }
+
+    async fn remove(&self, order_id: OrderId) -> Result<(), RepositoryError> {
+        self.locks.lock().await.remove(&order_id);
+        Ok(())
+    }
+}
+
+/// 編集ロック中の注文は、ロックしたオペレーター以外の変更と解除を拒否し、
+/// 期限を過ぎると他のオペレーターがロックを引き継げることを検証
+#[tokio::test]
+async fn test_order_lock_rejects_other_operators_until_expired() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus,
+    );
+    let lock_service = OrderLockApplicationService::new(
+        order_repo.clone(),
+        Arc::new(MockOrderLockRepository::default()),
+    );
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    let now = Utc::now();
+    let ttl = TimeDelta::minutes(30);
+
+    let lock = lock_service
+        .lock(order_id, "alice", ttl, now)
+        .await
+        .unwrap();
+    assert_eq!(lock.locked_by, "alice");
+    assert_eq!(lock.expires_at, now + ttl);
+
+    // ロックしたオペレーターは変更でき、ロックを延長できる
+    lock_service
+        .ensure_unlocked(order_id, Some("alice"), now)
+        .await
+        .unwrap();
+    let later = now + TimeDelta::minutes(10);
+    let extended = lock_service
+        .lock(order_id, "alice", ttl, later)
+        .await
+        .unwrap();
+    assert_eq!(extended.expires_at, later + ttl);
+
+    // 他のオペレーターや名乗らない操作は拒否される
+    for operator in [Some("bob"), None] {
+        assert!(matches!(
+            lock_service
+                .ensure_unlocked(order_id, operator, later)
+                .await,
+            Err(ApplicationError::DomainError(DomainError::OrderLocked(_)))
+        ));
+    }
+    assert!(matches!(
+        lock_service.lock(order_id, "bob", ttl, later).await,
+        Err(ApplicationError::DomainError(DomainError::OrderLocked(_)))
+    ));
+    assert!(matches!(
+        lock_service.unlock(order_id, Some("bob"), later).await,
+        Err(ApplicationError::DomainError(DomainError::OrderLocked(_)))
+    ));
+    let active = lock_service.active_locks(later).await.unwrap();
+    assert_eq!(active[&order_id].locked_by, "alice");
+
+    // 期限を過ぎたロックは他のオペレーターが引き継げる
+    let expired = later + ttl;
+    assert!(lock_service.active_locks(expired).await.unwrap().is_empty());
+    lock_service
+        .ensure_unlocked(order_id, None, expired)
+        .await
+        .unwrap();
+    let taken_over = lock_service
+        .lock(order_id, "bob", ttl, expired)
+        .await
+        .unwrap();
+    assert_eq!(taken_over.locked_by, "bob");
+    lock_service
+        .unlock(order_id, Some("bob"), expired)
+        .await
+        .unwrap();
+    lock_service
+        .ensure_unlocked(order_id, None, expired)
+        .await
+        .unwrap();
+
+    // 存在しない注文・オペレーターの指定がない・期間が上限を超える場合はロックできない
+    assert!(matches!(
+        lock_service.lock(OrderId::new(), "alice", ttl, now).await,
+        Err(ApplicationError::NotFound(_))
+    ));
+    assert!(matches!(
+        lock_service.lock(order_id, " ", ttl, now).await,
+        Err(ApplicationError::DomainError(DomainError::Validation(_)))
+    ));
+    assert!(matches!(
+        lock_service
+            .lock(order_id, "alice", TimeDelta::days(1), now)
+            .await,
+        Err(ApplicationError::DomainError(DomainError::Validation(_)))
+    ));
+}
+
+/// トレースモードでは、サーガの実行中に発行されたイベントごとに配信先のハンドラーと結果を確認できる
+#[tokio::test]
+async fn test_event_traces_show_which_handlers_ran_during_saga() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig {
+        trace_capacity: 100,
+        ..EventBusConfig::default()
+    }));
+    let logger = Arc::new(MockLogger);
+    event_bus
+        .subscribe_order_confirmed(InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::default()),
+            logger.clone(),
+        ))
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_confirmed(NotificationHandler::new(logger.clone()))
+        .await
+        .unwrap();
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+
+    let book_id = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(book_id, 10))
+        .await;
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, book_id, 2, Money::jpy(1500))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+
+    let correlation_id = order_repo
+        .find_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .saga_correlation_id()
+        .unwrap();
+    let trace_service = EventTraceApplicationService::new(event_bus.clone());
+    let report = trace_service.trace_report(Some(correlation_id)).unwrap();
+
+    let event_types: Vec<&str> = report
+        .traces
+        .iter()
+        .map(|trace| trace.event_type.as_str())
+        .collect();
+    assert_eq!(event_types, vec!["OrderConfirmed", "InventoryReserved"]);
+    let confirmed = &report.traces[0];
+    let handler_names: Vec<&str> = confirmed
+        .handlers
+        .iter()
+        .map(|handler| handler.handler_name.as_str())
+        .collect();
+    assert_eq!(
+        handler_names,
+        vec!["InventoryReservationHandler", "NotificationHandler"]
+    );
+    assert!(confirmed
+        .handlers
+        .iter()
+        .all(|handler| handler.outcome == HandlerOutcome::Succeeded && handler.retries == 0));
+    // 購読しているハンドラーがないイベントも、配信先がないことが分かるように記録される
+    assert!(report.traces[1].handlers.is_empty());
+    assert_eq!(
+        report.matrix["OrderConfirmed"]["InventoryReservationHandler"].succeeded,
+        1
+    );
+    assert!(report.matrix["InventoryReserved"].is_empty());
+
+    // トレースモードが無効のイベントバスでは取得できない
+    let disabled = EventTraceApplicationService::new(Arc::new(InMemoryEventBus::default()));
+    assert!(matches!(
+        disabled.trace_report(None),
+        Err(ApplicationError::Unsupported(_))
+    ));
+}
+
+/// 確定時・配送先の変更時・発送時に配送先の都道府県から配達予定日を見積もり、OrderShippedに含めることを検証
+#[tokio::test]
+async fn test_delivery_date_is_estimated_by_prefecture() {
+    let estimator = DeliveryEstimator::new(2, 1, HashMap::from([("沖縄県".to_string(), 4)]));
+    let app_service = OrderApplicationService::new(
+        InMemoryOrderRepository::new(),
+        Arc::new(InMemoryEventBus::new(EventBusConfig::default())),
+    )
+    .with_delivery_estimator(estimator);
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    let set_address = |prefecture: &str| {
+        app_service.set_shipping_address_from_request(
+            order_id,
+            "1234567".to_string(),
+            prefecture.to_string(),
+            "渋谷区".to_string(),
+            "道玄坂1-1-1".to_string(),
+            None,
+        )
+    };
+
+    // 確定前は見積もらない
+    set_address("東京都").await.unwrap();
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order.estimated_delivery_date(), None);
+
+    // 確定日 + 発送までの日数 + 配送日数（設定がない都道府県は既定の日数）
+    app_service.confirm_order(order_id).await.unwrap();
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let confirmed_on = order.confirmed_at().unwrap().date_naive();
+    assert_eq!(
+        order.estimated_delivery_date(),
+        Some(confirmed_on + TimeDelta::days(2 + 1))
+    );
+
+    // 確定後に配送先が変わった場合は、変更後の都道府県で見積もり直す
+    set_address("沖縄県").await.unwrap();
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        order.estimated_delivery_date(),
+        Some(confirmed_on + TimeDelta::days(2 + 4))
+    );
+
+    // 発送時は発送日 + 配送日数で見積もり直し、OrderShippedに含める
+    let shipped = app_service
+        .mark_order_as_shipped(order_id, None)
+        .await
+        .unwrap();
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let expected = order.shipped_at().unwrap().date_naive() + TimeDelta::days(4);
+    assert_eq!(order.estimated_delivery_date(), Some(expected));
+    let DomainEvent::OrderShipped(event) = shipped.event else {
+        panic!("expected OrderShipped, got {:?}", shipped.event);
+    };
+    assert_eq!(event.estimated_delivery_date, Some(expected));
+}
+
+#[derive(Default)]
+struct MockFailedNotificationRepository {
+    notifications: Mutex<Vec<FailedNotification>>,
+}
+
+#[async_trait]
+impl FailedNotificationRepository for MockFailedNotificationRepository {
+    async fn save(&self, notification: &FailedNotification) -> Result<(), RepositoryError> {
+        let mut notifications = self.notifications.lock().await;
+        match notifications.iter_mut().find(|n| n.id == notification.id) {
+            Some(existing) => *existing = notification.clone(),
+            None => notifications.push(notification.clone()),
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<FailedNotification>, RepositoryError> {
+        Ok(self
+            .notifications
+            .lock()
+            .await
+            .iter()
+            .find(|n| n.id == id)
+            .cloned())
+    }
+
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        Ok(self
+            .notifications
+            .lock()
+            .await
+            .iter()
+            .filter(|n| {
+                n.status == FailedNotificationStatus::Pending
+                    && n.next_attempt_at.is_some_and(|at| at <= now)
+            })
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_status(
+        &self,
+        status: FailedNotificationStatus,
+        limit: u32,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        Ok(self
+            .notifications
+            .lock()
+            .await
+            .iter()
+            .filter(|n| n.status == status)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<FailedNotification>, RepositoryError> {
+        Ok(self
+            .notifications
+            .lock()
+            .await
+            .iter()
+            .filter(|n| n.notification.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+}
+
+// 失敗させている間は送信に失敗し、送信できた通知を記録する通知の送信先
+#[derive(Default)]
+struct FlakyNotificationSender {
+    failing: Mutex<bool>,
+    sent: Mutex<Vec<Notification>>,
+}
+
+#[async_trait]
+impl NotificationSender for FlakyNotificationSender {
+    async fn send(&self, notification: &Notification) -> Result<(), NotificationError> {
+        if *self.failing.lock().await {
+            return Err(NotificationError::DeliveryFailed(
+                "送信サービスがタイムアウトしました".to_string(),
+            ));
+        }
+        self.sent.lock().await.push(notification.clone());
+        Ok(())
+    }
+}
+
+/// 送信に失敗した通知はデッドレターキューではなく再送キューに入り、チャネルごとの待ち時間と試行回数で再送され、
+/// 再送をあきらめた通知は管理者が手動で再送できることを検証
+#[tokio::test]
+async fn test_failed_notifications_are_retried_per_channel_and_resent_manually() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+    let repository = Arc::new(MockFailedNotificationRepository::default());
+    let sender = Arc::new(FlakyNotificationSender::default());
+    *sender.failing.lock().await = true;
+    let policy = NotificationRetryPolicy {
+        email: ChannelRetryPolicy {
+            max_attempts: 5,
+            initial_backoff: TimeDelta::minutes(1),
+            max_backoff: TimeDelta::hours(1),
+        },
+        sms: ChannelRetryPolicy {
+            max_attempts: 2,
+            initial_backoff: TimeDelta::minutes(5),
+            max_backoff: TimeDelta::hours(1),
+        },
+    };
+    let notification_handler = NotificationHandler::new(logger.clone())
+        .with_sender(sender.clone())
+        .with_retry_queue(repository.clone(), policy);
+    event_bus
+        .subscribe_order_confirmed(notification_handler.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_shipped(notification_handler)
+        .await
+        .unwrap();
+
+    // ギフト注文: 確定の通知は購入者へメール、発送の通知は受取人へSMSで送る
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus.clone());
+    let order_id = app_service.create_order(CustomerId::new()).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+        .await
+        .unwrap();
+    let address = ShippingAddress::new(
+        "0600001".to_string(),
+        "北海道".to_string(),
+        "札幌市".to_string(),
+        "北1条西1-1".to_string(),
+        None,
+    )
+    .unwrap();
+    let recipient =
+        Recipient::new("山田花子".to_string(), "090-1234-5678".to_string(), address).unwrap();
+    app_service
+        .set_gift_recipient(order_id, recipient)
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+    app_service.mark_order_as_shipped(order_id, None).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // 送信の失敗は再送キューに入り、イベントのデッドレターキューには入らない
+    assert!(event_bus.dead_letters().await.is_empty());
+    let queued = repository.notifications.lock().await.clone();
+    assert_eq!(queued.len(), 2);
+    let email = queued
+        .iter()
+        .find(|n| n.notification.channel == NotificationChannel::Email)
+        .unwrap()
+        .clone();
+    let sms = queued
+        .iter()
+        .find(|n| n.notification.channel == NotificationChannel::Sms)
+        .unwrap()
+        .clone();
+    assert_eq!(sms.notification.phone.as_deref(), Some("090-1234-5678"));
+    assert_eq!(email.notification.event_type, "OrderConfirmed");
+    assert!(queued
+        .iter()
+        .all(|n| n.status == FailedNotificationStatus::Pending && n.attempts == 1));
+
+    // 再送はチャネルごとの待ち時間を過ぎた通知だけが対象になる
+    let retrier =
+        NotificationRetrier::new(repository.clone(), sender.clone(), policy, logger.clone());
+    let delivered = retrier
+        .run(email.next_attempt_at.unwrap() + TimeDelta::seconds(1))
+        .await
+        .unwrap();
+    assert!(delivered.is_empty());
+    let attempts = |id: Uuid, notifications: &[FailedNotification]| {
+        notifications.iter().find(|n| n.id == id).unwrap().attempts
+    };
+    {
+        let notifications = repository.notifications.lock().await;
+        assert_eq!(attempts(email.id, &notifications), 2);
+        assert_eq!(attempts(sms.id, &notifications), 1);
+    }
+
+    // SMSは試行回数の上限に達すると再送をあきらめる
+    retrier
+        .run(sms.next_attempt_at.unwrap() + TimeDelta::seconds(1))
+        .await
+        .unwrap();
+    let service = NotificationApplicationService::new(repository.clone(), sender.clone(), policy);
+    let undeliverable = service
+        .list_failed_notifications(FailedNotificationStatus::Undeliverable)
+        .await
+        .unwrap();
+    assert_eq!(undeliverable.len(), 1);
+    assert_eq!(undeliverable[0].id, sms.id);
+    assert_eq!(undeliverable[0].attempts, 2);
+
+    // 管理者が手動で再送すると送信済みになり、再度の再送はできない
+    *sender.failing.lock().await = false;
+    let resent = service.resend(sms.id).await.unwrap();
+    assert_eq!(resent.status, FailedNotificationStatus::Delivered);
+    assert!(resent.delivered_at.is_some());
+    assert_eq!(sender.sent.lock().await.len(), 1);
+    assert!(matches!(
+        service.resend(sms.id).await,
+        Err(ApplicationError::DomainError(_))
+    ));
+    assert!(matches!(
+        service.resend(Uuid::new_v4()).await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+// テスト用のモック顧客リポジトリ
+#[derive(Default)]
+struct MockCustomerRepository {
+    customers: Mutex<HashMap<CustomerId, Customer>>,
+}
+
+#[async_trait]
+impl CustomerRepository for MockCustomerRepository {
+    async fn save(&self, customer: &Customer) -> Result<(), RepositoryError> {
+        self.customers
+            .lock()
+            .await
+            .insert(customer.id(), customer.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Option<Customer>, RepositoryError> {
+        Ok(self.customers.lock().await.get(&customer_id).cloned())
+    }
+
+    async fn find_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Option<Customer>, RepositoryError> {
+        Ok(self
+            .customers
+            .lock()
+            .await
+            .values()
+            .find(|customer| customer.email() == email)
+            .cloned())
+    }
+}
+
+/// 登録済みの顧客だけが注文でき、注文に既定の配送先住所が引き継がれることを検証
+#[tokio::test]
+async fn test_orders_require_registered_customer_with_default_address() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let customer_repo = Arc::new(MockCustomerRepository::default());
+    let customer_service =
+        CustomerApplicationService::new(customer_repo.clone(), order_repo.clone());
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus,
+    )
+    .with_customers(customer_repo);
+
+    let address = ShippingAddress::new(
+        "1500043".to_string(),
+        "東京都".to_string(),
+        "渋谷区".to_string(),
+        "道玄坂1-1-1".to_string(),
+        None,
+    )
+    .unwrap();
+    let customer = customer_service
+        .register_customer(
+            "山田花子".to_string(),
+            "Hanako@Example.com".to_string(),
+            Some(address.clone()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(customer.email().as_str(), "hanako@example.com");
+
+    // 同じメールアドレス（大文字・小文字の違いのみ）では登録できない
+    match customer_service
+        .register_customer(
+            "山田太郎".to_string(),
+            "HANAKO@example.com".to_string(),
+            None,
+        )
+        .await
+    {
+        Err(ApplicationError::DomainError(DomainError::Validation(errors))) => {
+            assert_eq!(errors.violations()[0].field, "email");
+        }
+        other => panic!("unexpected result: {:?}", other.map(|c| c.id())),
+    }
+
+    // 既定の配送先住所が注文に引き継がれる
+    let order_id = app_service.create_order(customer.id()).await.unwrap();
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order.shipping_address(), Some(&address));
+
+    // 未登録の顧客では注文を作成できない
+    match app_service.create_order(CustomerId::new()).await {
+        Err(ApplicationError::DomainError(DomainError::Validation(errors))) => {
+            assert_eq!(errors.violations()[0].field, "customer_id");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let orders = customer_service
+        .list_customer_orders(customer.id())
+        .await
+        .unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].id(), order_id);
+    assert!(matches!(
+        customer_service
+            .list_customer_orders(CustomerId::new())
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
+
+/// 合計金額がしきい値以上の注文は確定時に自動で保留になり、保留中は発送も通知もされず、
+/// 解除すると発送されることを検証
+#[tokio::test]
+async fn test_on_hold_orders_are_not_shipped_or_notified_until_released() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+    let sender = Arc::new(FlakyNotificationSender::default());
+
+    // main.rsと同じく、リスク保留・在庫予約・通知の順で購読する
+    event_bus
+        .subscribe_order_confirmed(RiskHoldHandler::new(
+            order_repo.clone(),
+            event_bus.clone(),
+            logger.clone(),
+            Money::jpy(5000),
+        ))
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_confirmed(InventoryReservationHandler::new(
+            inventory_repo.clone(),
+            order_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::default()),
+            logger.clone(),
+        ))
+        .await
+        .unwrap();
+    let shipping_handler = ShippingHandler::new(
+        order_repo.clone(),
+        event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
+        logger.clone(),
+    );
+    event_bus
+        .subscribe_inventory_reserved(shipping_handler.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_released(shipping_handler)
+        .await
+        .unwrap();
+    let notification_handler = NotificationHandler::new(logger.clone())
+        .with_sender(sender.clone())
+        .with_order_repository(order_repo.clone());
+    event_bus
+        .subscribe_order_confirmed(notification_handler.clone())
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_shipped(notification_handler)
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let book_id = BookId::new();
+    inventory_repo.add_inventory(Inventory::new(book_id, 10)).await;
+    let sent_for = |order_id: OrderId, notifications: &[Notification]| {
+        notifications
+            .iter()
+            .filter(|n| n.order_id == order_id)
+            .map(|n| n.event_type.clone())
+            .collect::<HashSet<_>>()
+    };
+
+    // しきい値未満の注文はそのまま発送される
+    let regular_id = confirm_order_for(&app_service, book_id, 1).await;
+    let regular = order_repo.find_by_id(regular_id).await.unwrap().unwrap();
+    assert_eq!(regular.status(), OrderStatus::Shipped);
+
+    // しきい値以上の注文は保留になり、発送も確定の通知もされない
+    let held_id = confirm_order_for(&app_service, book_id, 3).await;
+    let held = order_repo.find_by_id(held_id).await.unwrap().unwrap();
+    assert_eq!(held.status(), OrderStatus::OnHold);
+    assert_eq!(held.hold_reason(), Some(HoldReason::SuspectedFraud));
+    assert!(sent_for(held_id, &sender.sent.lock().await).is_empty());
+    assert!(matches!(
+        app_service.mark_order_as_shipped(held_id, None).await,
+        Err(ApplicationError::DomainError(_))
+    ));
+
+    // 保留を解除すると発送され、発送の通知が送られる
+    app_service.release_order_hold(held_id).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    let released = order_repo.find_by_id(held_id).await.unwrap().unwrap();
+    assert_eq!(released.status(), OrderStatus::Shipped);
+    assert_eq!(released.hold_reason(), None);
+    assert_eq!(
+        sent_for(held_id, &sender.sent.lock().await),
+        HashSet::from(["OrderShipped".to_string()])
+    );
+    assert_eq!(
+        sent_for(regular_id, &sender.sent.lock().await),
+        HashSet::from(["OrderConfirmed".to_string(), "OrderShipped".to_string()])
+    );
+
+    // 発送済みの注文は保留にできない
+    assert!(matches!(
+        app_service
+            .place_order_on_hold(held_id, HoldReason::CustomerRequest, None)
+            .await,
+        Err(ApplicationError::DomainError(_))
+    ));
+}
+
+// 年ごとの連番をメモリに保持するモック注文番号採番
+#[derive(Default)]
+struct MockOrderNumberGenerator {
+    sequences: Mutex<HashMap<i32, u64>>,
+}
+
+#[async_trait]
+impl OrderNumberGenerator for MockOrderNumberGenerator {
+    async fn next_order_number(
+        &self,
+        created_at: DateTime<Utc>,
+    ) -> Result<OrderNumber, RepositoryError> {
+        let mut sequences = self.sequences.lock().await;
+        let sequence = sequences.entry(created_at.year()).or_insert(0);
+        *sequence += 1;
+        OrderNumber::new("BK", created_at.year(), *sequence, 6)
+            .map_err(|e| RepositoryError::OperationFailed(e.to_string()))
+    }
+}
+
+/// 注文の作成時に注文番号が採番され、注文番号で検索でき、通知には注文IDの代わりに注文番号が載ることを検証
+#[tokio::test]
+async fn test_orders_get_human_friendly_numbers_shown_in_notifications() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+    let sender = Arc::new(FlakyNotificationSender::default());
+    event_bus
+        .subscribe_order_confirmed(
+            NotificationHandler::new(logger.clone())
+                .with_sender(sender.clone())
+                .with_order_repository(order_repo.clone()),
+        )
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    )
+    .with_order_numbers(Arc::new(MockOrderNumberGenerator::default()));
+    let book_id = BookId::new();
+    inventory_repo.add_inventory(Inventory::new(book_id, 10)).await;
+
+    let year = Utc::now().year();
+    let first_id = confirm_order_for(&app_service, book_id, 1).await;
+    let second_id = confirm_order_for(&app_service, book_id, 1).await;
+    let second = app_service
+        .get_order_by_id(second_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let second_number = format!("BK-{}-000002", year);
+    assert_eq!(
+        second.order_number().map(OrderNumber::as_str),
+        Some(second_number.as_str())
+    );
+
+    // 注文番号は大文字・小文字を区別せずに検索できる
+    let found = app_service
+        .get_order_by_number(&second_number.to_lowercase())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id(), second_id);
+    assert!(app_service
+        .get_order_by_number(&format!("BK-{}-999999", year))
+        .await
+        .unwrap()
+        .is_none());
+    assert!(matches!(
+        app_service.get_order_by_number("not-a-number").await,
+        Err(ApplicationError::DomainError(DomainError::Validation(_)))
+    ));
+
+    // 確定の通知には注文IDではなく注文番号が載る
+    let sent = sender.sent.lock().await;
+    let message_for = |order_id: OrderId| {
+        sent.iter()
+            .find(|n| n.order_id == order_id)
+            .map(|n| n.message.clone())
+            .unwrap()
+    };
+    assert!(message_for(first_id).contains(&format!("注文番号: BK-{}-000001", year)));
+    assert!(message_for(second_id).contains(&format!("注文番号: {}", second_number)));
+    assert!(!message_for(second_id).contains("注文ID"));
+}
+
+/// 通知ハンドラーの通知が、注文した顧客の接続中の宛先（WebSocket）にだけ届くことを検証
+#[tokio::test]
+async fn test_notifications_are_pushed_to_connected_customer() {
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let hub = Arc::new(InMemoryNotificationHub::new());
+    event_bus
+        .subscribe_order_confirmed(
+            NotificationHandler::new(Arc::new(MockLogger))
+                .with_order_repository(order_repo.clone())
+                .with_channel(hub.clone()),
+        )
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let customer_id = CustomerId::new();
+    let mut connection = hub.connect(customer_id);
+    let mut other_customer = hub.connect(CustomerId::new());
+
+    let order_id = app_service.create_order(customer_id).await.unwrap();
+    app_service
+        .add_book_to_order(order_id, BookId::new(), 2, Money::jpy(1800))
+        .await
+        .unwrap();
+    app_service
+        .set_shipping_address_from_request(
+            order_id,
+            "1500001".to_string(),
+            "東京都".to_string(),
+            "渋谷区".to_string(),
+            "神宮前1-1-1".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.confirm_order(order_id).await.unwrap();
+
+    let notification = tokio::time::timeout(
+        tokio::time::Duration::from_secs(1),
+        connection.recv(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(notification.customer_id, customer_id);
+    assert_eq!(notification.order_id, order_id);
+    assert_eq!(notification.event_type, "OrderConfirmed");
+    assert!(notification.message.starts_with("ご注文が確定されました"));
+    assert!(other_customer.try_recv().is_err());
+}
+
+// 返品の再入庫イベントを記録するテスト用ハンドラー
+#[derive(Clone, Default)]
+struct ItemsRestockedRecorder {
+    events: Arc<Mutex<Vec<ItemsRestocked>>>,
+}
+
+#[async_trait]
+impl EventHandler<ItemsRestocked> for ItemsRestockedRecorder {
+    async fn handle(&self, event: ItemsRestocked) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+/// 配達済みの注文の返品は承認されてから在庫に戻り、再入庫イベントが発行されることを検証
+#[tokio::test]
+async fn test_approved_return_restocks_inventory() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let restocked = ItemsRestockedRecorder::default();
+    event_bus
+        .subscribe_return_approved(ReturnHandler::new(
+            inventory_repo.clone(),
+            event_bus.clone(),
+            Arc::new(MockProcessedEventRepository::default()),
+            Arc::new(MockLogger),
+        ))
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_items_restocked(restocked.clone())
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let book_id = BookId::new();
+    inventory_repo
+        .add_inventory(Inventory::new(book_id, 10))
+        .await;
+    let order_id = confirm_order_for(&app_service, book_id, 2).await;
+
+    // 配達前の注文は返品できない
+    assert!(matches!(
+        app_service.request_order_return(order_id, None).await,
+        Err(ApplicationError::DomainError(
+            DomainError::InvalidOrderState(_)
+        ))
+    ));
+    app_service
+        .mark_order_as_shipped(order_id, None)
+        .await
+        .unwrap();
+    app_service
+        .mark_order_as_delivered(order_id, None)
+        .await
+        .unwrap();
+
+    let acknowledgement = app_service
+        .request_order_return(order_id, Some("落丁があった".to_string()))
+        .await
+        .unwrap();
+    assert!(matches!(
+        acknowledgement.event,
+        DomainEvent::ReturnRequested(ref event) if event.reason.as_deref() == Some("落丁があった")
+    ));
+    // 承認するまで在庫は戻さない
+    let inventory = inventory_repo
+        .find_by_book_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 10);
+    assert!(restocked.events.lock().await.is_empty());
+
+    app_service.approve_order_return(order_id).await.unwrap();
+    let order = app_service
+        .get_order_by_id(order_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order.status(), OrderStatus::Returned);
+    let inventory = inventory_repo
+        .find_by_book_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inventory.quantity_on_hand(), 12);
+    let events = restocked.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].order_id, order_id);
+
+    // 承認済みの返品は再度承認できない
+    assert!(app_service.approve_order_return(order_id).await.is_err());
+}
+
+/// gRPC APIのテスト
+/// REST APIと同じ注文アプリケーションサービスを経由して注文を作成・取得できることを検証
+#[tokio::test]
+async fn test_grpc_order_service_shares_order_application_service() {
+    use bookstore_order_management::adapter::driver::grpc::proto::order_service_server::OrderService;
+    use bookstore_order_management::adapter::driver::grpc::{proto, GrpcOrderService, ERROR_CODE_METADATA_KEY};
+
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let app_service = Arc::new(OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus));
+    let grpc_service = GrpcOrderService::new(app_service.clone());
+
+    let customer_id = CustomerId::new();
+    let created = grpc_service
+        .create_order(tonic::Request::new(proto::CreateOrderRequest {
+            customer_id: customer_id.to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    let book_id = BookId::new();
+    grpc_service
+        .add_book(tonic::Request::new(proto::AddBookRequest {
+            order_id: created.order_id.clone(),
+            book_id: book_id.to_string(),
+            quantity: 2,
+            unit_price: 1500,
+            fulfillment_type: proto::FulfillmentType::Physical as i32,
+            spec: None,
+        }))
+        .await
+        .unwrap();
+
+    // REST APIと同じサービスから注文を参照できる
+    let order = grpc_service
+        .get_order(tonic::Request::new(proto::GetOrderRequest {
+            order_id: created.order_id.clone(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(order.customer_id, customer_id.to_string());
+    assert_eq!(order.lines.len(), 1);
+    let order_id = OrderId::from_uuid(created.order_id.parse().unwrap());
+    let stored = app_service.get_order_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.total_amount, stored.calculate_total().amount());
+
+    // 存在しない注文はNOT_FOUNDになり、REST APIと同じエラーコードを返す
+    let status = grpc_service
+        .get_order(tonic::Request::new(proto::GetOrderRequest {
+            order_id: OrderId::new().to_string(),
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+    assert_eq!(
+        status.metadata().get(ERROR_CODE_METADATA_KEY).unwrap(),
+        "ORDER_NOT_FOUND"
+    );
+}
+
+// テスト用のモック強制遷移の監査記録リポジトリ
+#[derive(Default)]
+struct MockStatusOverrideAuditRepository {
+    audits: Mutex<Vec<StatusOverrideAudit>>,
+}
+
+#[async_trait]
+impl StatusOverrideAuditRepository for MockStatusOverrideAuditRepository {
+    async fn add(&self, audit: &StatusOverrideAudit) -> Result<(), RepositoryError> {
+        self.audits.lock().await.push(audit.clone());
+        Ok(())
+    }
+
+    async fn find_by_order(
+        &self,
+        order_id: OrderId,
+    ) -> Result<Vec<StatusOverrideAudit>, RepositoryError> {
+        let audits = self.audits.lock().await;
+        Ok(audits
+            .iter()
+            .filter(|audit| audit.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+}
+
+struct OrderStatusForcefullyChangedRecorder {
+    events: Arc<Mutex<Vec<OrderStatusForcefullyChanged>>>,
+}
+
+#[async_trait]
+impl EventHandler<OrderStatusForcefullyChanged> for OrderStatusForcefullyChangedRecorder {
+    async fn handle(&self, event: OrderStatusForcefullyChanged) -> Result<(), HandlerError> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+/// 管理者による強制遷移のテスト
+/// 強制遷移の遷移表で許可された遷移だけを受け付け、監査記録を残してOrderStatusForcefullyChangedを発行することを検証
+#[tokio::test]
+async fn test_forced_status_change_is_audited_and_published() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let forced = Arc::new(Mutex::new(Vec::new()));
+    event_bus
+        .subscribe_order_status_forcefully_changed(OrderStatusForcefullyChangedRecorder {
+            events: forced.clone(),
+        })
+        .await
+        .unwrap();
+    let audits = Arc::new(MockStatusOverrideAuditRepository::default());
+    let app_service = OrderApplicationService::new(InMemoryOrderRepository::new(), event_bus)
+        .with_status_override_audits(audits.clone());
+
+    let order_id = confirm_order_for(&app_service, BookId::new(), 1).await;
+    app_service
+        .mark_order_as_shipped(order_id, None)
+        .await
+        .unwrap();
+
+    // 強制遷移の遷移表にない遷移と、理由のない変更は受け付けない
+    assert!(matches!(
+        app_service
+            .force_order_status(order_id, OrderStatus::Pending, "誤登録", "alice", Utc::now())
+            .await,
+        Err(ApplicationError::DomainError(DomainError::InvalidOrderState(_)))
+    ));
+    // 在庫を予約した注文のキャンセルは在庫を解放する通常のキャンセルで行う
+    assert!(matches!(
+        app_service
+            .force_order_status(order_id, OrderStatus::Cancelled, "誤登録", "alice", Utc::now())
+            .await,
+        Err(ApplicationError::DomainError(DomainError::InvalidOrderState(_)))
+    ));
+    assert!(matches!(
+        app_service
+            .force_order_status(order_id, OrderStatus::Confirmed, "  ", "alice", Utc::now())
+            .await,
+        Err(ApplicationError::DomainError(DomainError::InvalidValue(_)))
+    ));
+    assert!(audits.find_by_order(order_id).await.unwrap().is_empty());
+
+    let acknowledgement = app_service
+        .force_order_status(
+            order_id,
+            OrderStatus::Confirmed,
+            "配送業者の集荷前に発送済みで登録した",
+            "alice",
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    let order = app_service.get_order_by_id(order_id).await.unwrap().unwrap();
+    assert_eq!(order.status(), OrderStatus::Confirmed);
+    assert_eq!(order.shipped_at(), None);
+
+    let recorded = audits.find_by_order(order_id).await.unwrap();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].from_status, "Shipped");
+    assert_eq!(recorded[0].to_status, "Confirmed");
+    assert_eq!(recorded[0].operator, "alice");
+    assert_eq!(recorded[0].event_id, acknowledgement.event.metadata().event_id);
+
+    let events = forced.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].reason, "配送業者の集荷前に発送済みで登録した");
+    assert!(events[0].metadata.sequence_number.is_some());
+
+    // 監査記録を設定していない構成では強制遷移を受け付けない
+    let without_audits = OrderApplicationService::new(
+        InMemoryOrderRepository::new(),
+        Arc::new(InMemoryEventBus::new(EventBusConfig::default())),
+    );
+    assert!(matches!(
+        without_audits
+            .force_order_status(order_id, OrderStatus::Cancelled, "誤登録", "alice", Utc::now())
+            .await,
+        Err(ApplicationError::Unsupported(_))
+    ));
+}
+
+// テスト用のモック割当枠リポジトリ
+#[derive(Default)]
+struct MockAllocationQuotaRepository {
+    quotas: Mutex<HashMap<BookId, AllocationQuota>>,
+}
+
+#[async_trait]
+impl AllocationQuotaRepository for MockAllocationQuotaRepository {
+    async fn find_by_book_id(
+        &self,
+        book_id: BookId,
+    ) -> Result<Option<AllocationQuota>, RepositoryError> {
+        Ok(self.quotas.lock().await.get(&book_id).cloned())
+    }
+
+    async fn save(&self, quota: &AllocationQuota) -> Result<(), RepositoryError> {
+        self.quotas.lock().await.insert(quota.book_id(), quota.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, book_id: BookId) -> Result<bool, RepositoryError> {
+        Ok(self.quotas.lock().await.remove(&book_id).is_some())
+    }
+}
+
+/// 限定版の割当枠のテスト
+/// 顧客ごとの購入上限と販売チャネル別の割当枠を超える予約は、仮押さえではQuotaExceededで断り、
+/// 確定後の在庫予約では補償フローで注文をキャンセルし、キャンセルした注文の割当は割当枠に戻すことを検証
+#[tokio::test]
+async fn test_allocation_quota_limits_reservations_per_customer_and_channel() {
+    let inventory_repo = Arc::new(InMemoryInventoryRepository::new());
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let quota_repo = Arc::new(MockAllocationQuotaRepository::default());
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+
+    let inventory_handler = InventoryReservationHandler::new(
+        inventory_repo.clone(),
+        order_repo.clone(),
+        event_bus.clone(),
+        Arc::new(MockProcessedEventRepository::default()),
+        logger.clone(),
+    )
+    .with_allocation_quotas(quota_repo.clone());
+    event_bus
+        .subscribe_order_confirmed(inventory_handler)
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_inventory_reservation_failed(
+            InventoryReservationFailureCompensationHandler::new(
+                order_repo.clone(),
+                event_bus.clone(),
+                logger.clone(),
+            ),
+        )
+        .await
+        .unwrap();
+    event_bus
+        .subscribe_order_cancelled(AllocationReturnHandler::new(quota_repo.clone(), logger))
+        .await
+        .unwrap();
+
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus.clone(),
+    );
+    let inventory_service = InventoryApplicationService::new(inventory_repo.clone(), event_bus.clone())
+        .with_allocation_quotas(quota_repo.clone());
+    let hold_service = CheckoutHoldApplicationService::new(
+        order_repo.clone(),
+        inventory_repo.clone(),
+        Arc::new(MockCheckoutHoldRepository::default()),
+        TimeDelta::minutes(15),
+    )
+    .with_allocation_quotas(quota_repo.clone());
+
+    // 在庫10冊のうち4冊を、オンライン50%・店頭50%で割り当て、1顧客2冊までとする
+    let book_id = BookId::new();
+    inventory_repo.add_inventory(Inventory::new(book_id, 10)).await;
+    let quota = inventory_service
+        .set_allocation_quota(
+            book_id,
+            Some(2),
+            BTreeMap::from([(SalesChannel::Online, 50), (SalesChannel::InStore, 50)]),
+            Some(4),
+        )
+        .await
+        .unwrap();
+    assert_eq!(quota.channel_limit(SalesChannel::InStore), Some(2));
+
+    let place_order = |customer_id: CustomerId, channel: SalesChannel, quantity: u32| {
+        let app_service = &app_service;
+        async move {
+            let order_id = app_service
+                .create_order_in_channel(customer_id, channel)
+                .await
+                .unwrap();
+            app_service
+                .add_book_to_order(order_id, book_id, quantity, Money::jpy(5000))
+                .await
+                .unwrap();
+            app_service
+                .set_shipping_address_from_request(
+                    order_id,
+                    "1500001".to_string(),
+                    "東京都".to_string(),
+                    "渋谷区".to_string(),
+                    "神宮前1-1-1".to_string(),
+                    None,
+                )
+                .await
+                .unwrap();
+            order_id
+        }
+    };
+    let status_of = |order_id: OrderId| {
+        let order_repo = order_repo.clone();
+        async move { order_repo.find_by_id(order_id).await.unwrap().unwrap().status() }
+    };
+
+    // 上限までの注文は予約され、割当が記録される
+    let customer = CustomerId::new();
+    let first = place_order(customer, SalesChannel::Online, 2).await;
+    app_service.confirm_order(first).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    assert_eq!(status_of(first).await, OrderStatus::Confirmed);
+
+    // 同じ顧客の追加の注文は、購入上限を超えるため補償フローでキャンセルされる（在庫は減らない）
+    let second = place_order(customer, SalesChannel::Online, 1).await;
+    app_service.confirm_order(second).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    assert_eq!(status_of(second).await, OrderStatus::Cancelled);
+
+    // 店頭の割当枠（2冊）を超える仮押さえはQuotaExceededで断る
+    let in_store = place_order(CustomerId::new(), SalesChannel::InStore, 3).await;
+    assert!(matches!(
+        hold_service.place_hold(in_store, Utc::now()).await,
+        Err(ApplicationError::DomainError(DomainError::QuotaExceeded(_)))
+    ));
+    assert_eq!(
+        inventory_repo
+            .find_by_book_id(book_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .quantity_on_hand(),
+        8
+    );
+
+    let quota = inventory_service
+        .get_allocation_quota(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(quota.channel_allocated()[&SalesChannel::Online], 2);
+    assert_eq!(quota.customer_allocated()[&customer], 2);
+
+    // 予約した注文をキャンセルすると割当が戻り、同じ顧客が上限まで再び予約できる
+    app_service.cancel_order(first).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    let quota = inventory_service
+        .get_allocation_quota(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(quota.channel_allocated()[&SalesChannel::Online], 0);
+    assert!(!quota.customer_allocated().contains_key(&customer));
+    let third = place_order(customer, SalesChannel::Online, 2).await;
+    app_service.confirm_order(third).await.unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    assert_eq!(status_of(third).await, OrderStatus::Confirmed);
+
+    // 割当枠を解除すると制限なく予約できる
+    inventory_service.remove_allocation_quota(book_id).await.unwrap();
+    assert!(hold_service.place_hold(in_store, Utc::now()).await.is_ok());
+}
+
+/// 顧客のデータのエクスポートが、注文を少しずつ読み込みながら注文・通知の履歴をすべてZIPに書き出すことを検証
+#[tokio::test]
+async fn test_customer_export_writes_all_orders_and_notifications_to_zip_archive() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let customer_repo = Arc::new(MockCustomerRepository::default());
+    let notification_repo = Arc::new(MockFailedNotificationRepository::default());
+    let storage = Arc::new(S3CompatibleObjectStorage::new(
+        "http://localhost:9000".to_string(),
+        "exports".to_string(),
+    ));
+    let customer_service =
+        CustomerApplicationService::new(customer_repo.clone(), order_repo.clone());
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus,
+    )
+    .with_customers(customer_repo.clone());
+
+    let customer = customer_service
+        .register_customer(
+            "佐藤一郎".to_string(),
+            "ichiro@example.com".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    let mut order_ids = Vec::new();
+    for _ in 0..3 {
+        let order_id = app_service.create_order(customer.id()).await.unwrap();
+        app_service
+            .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1200))
+            .await
+            .unwrap();
+        order_ids.push(order_id);
+    }
+    // 他の顧客の注文は含めない
+    let other = customer_service
+        .register_customer(
+            "佐藤二郎".to_string(),
+            "jiro@example.com".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    app_service.create_order(other.id()).await.unwrap();
+
+    let notification = Notification {
+        channel: NotificationChannel::Email,
+        order_id: order_ids[1],
+        phone: None,
+        event_type: "OrderConfirmed".to_string(),
+        message: "ご注文が確定されました".to_string(),
+        correlation_id: Uuid::new_v4(),
+    };
+    notification_repo
+        .save(&FailedNotification::new(
+            notification,
+            "送信サービスがタイムアウトしました",
+            &NotificationRetryPolicy::default(),
+            Utc::now(),
+        ))
+        .await
+        .unwrap();
+
+    // 1件ずつ読み込んでも、すべての注文を書き出す
+    let exporter = CustomerExporter::new(
+        customer_repo,
+        order_repo,
+        notification_repo,
+        storage,
+    )
+    .with_page_size(1);
+    let job = exporter.start(customer.id(), Utc::now()).await.unwrap();
+    assert_eq!(job.status, CustomerExportStatus::Pending);
+    let job = exporter.run(job).await;
+    assert_eq!(job.status, CustomerExportStatus::Completed);
+    assert_eq!(job.order_count, 3);
+    assert_eq!(
+        exporter.find_job(job.job_id).await.unwrap().unwrap().status,
+        CustomerExportStatus::Completed
+    );
+
+    let archive = exporter.read_archive(&job).await.unwrap().unwrap();
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(archive)).unwrap();
+    let read_json = |zip: &mut zip::ZipArchive<std::io::Cursor<Vec<u8>>>, name: &str| {
+        let file = zip.by_name(name).unwrap();
+        serde_json::from_reader::<_, serde_json::Value>(file).unwrap()
+    };
+
+    let customer_json = read_json(&mut zip, "customer.json");
+    assert_eq!(customer_json["email"], "ichiro@example.com");
+    for order_id in &order_ids {
+        let order_json = read_json(&mut zip, &format!("orders/{}/order.json", order_id));
+        assert_eq!(order_json["order_id"], order_id.to_string());
+    }
+    let notifications = read_json(&mut zip, &format!("orders/{}/notifications.json", order_ids[1]));
+    assert_eq!(notifications.as_array().unwrap().len(), 1);
+    let manifest = read_json(&mut zip, "manifest.json");
+    assert_eq!(manifest["order_count"], 3);
+    assert_eq!(manifest["notification_count"], 1);
+    assert_eq!(zip.len(), 1 + 3 * 2 + 1);
+
+    // 登録されていない顧客のエクスポートは開始できない
+    assert!(exporter.start(CustomerId::new(), Utc::now()).await.is_err());
+}
+
+/// 通知は注文した顧客の言語のテンプレートで作成され、言語を設定していない顧客には日本語で送られることを検証
+#[tokio::test]
+async fn test_notifications_are_rendered_in_customer_preferred_language() {
+    let event_bus = Arc::new(InMemoryEventBus::new(EventBusConfig::default()));
+    let logger = Arc::new(MockLogger);
+    let order_repo = Arc::new(InMemoryOrderRepository::new());
+    let customer_repo = Arc::new(MockCustomerRepository::default());
+    let sender = Arc::new(FlakyNotificationSender::default());
+    let notification_handler = NotificationHandler::new(logger)
+        .with_sender(sender.clone())
+        .with_order_repository(order_repo.clone())
+        .with_customer_repository(customer_repo.clone());
+    event_bus
+        .subscribe_order_cancelled(notification_handler)
+        .await
+        .unwrap();
+
+    let customer_service =
+        CustomerApplicationService::new(customer_repo.clone(), order_repo.clone());
+    let app_service = OrderApplicationService::new(
+        order_repo.as_ref().clone(),
+        event_bus,
+    )
+    .with_customers(customer_repo);
+
+    let english = customer_service
+        .register_customer("John Smith".to_string(), "john@example.com".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(english.preferred_language(), Language::Ja);
+    let english = customer_service
+        .set_preferred_language(english.id(), Language::En)
+        .await
+        .unwrap();
+    assert_eq!(english.preferred_language(), Language::En);
+    let japanese = customer_service
+        .register_customer("山田花子".to_string(), "hanako@example.com".to_string(), None)
+        .await
+        .unwrap();
+
+    for customer_id in [english.id(), japanese.id()] {
+        let order_id = app_service.create_order(customer_id).await.unwrap();
+        app_service
+            .add_book_to_order(order_id, BookId::new(), 1, Money::jpy(1500))
+            .await
+            .unwrap();
+        app_service
+            .cancel_order(order_id)
+            .await
+            .unwrap();
+    }
+
+    let sent = sender.sent.lock().await;
+    assert_eq!(sent.len(), 2);
+    assert!(sent[0].message.starts_with("Your order has been cancelled. Order ID: "));
+    assert!(sent[1].message.starts_with("ご注文がキャンセルされました。注文ID: "));
+
+    // 存在しない顧客の言語は設定できない
+    assert!(matches!(
+        customer_service
+            .set_preferred_language(CustomerId::new(), Language::En)
+            .await,
+        Err(ApplicationError::NotFound(_))
+    ));
+}
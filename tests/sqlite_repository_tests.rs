@@ -0,0 +1,459 @@
+//! SQLiteリポジトリのテスト
+//!
+//! テストごとにインメモリのSQLiteデータベースを作成するため、外部のデータベースなしで並列に実行できる。
+//! MySQLリポジトリのテスト（repository_tests）と同じ振る舞いを確認する。
+
+use bookstore_order_management::adapter::driven::{
+    SqliteInventoryRepository, SqliteOrderRepository,
+};
+use bookstore_order_management::adapter::SqliteDatabase;
+use bookstore_order_management::domain::error::DomainError;
+use bookstore_order_management::domain::model::{
+    BookId, CustomerId, Inventory, LineAttribute, Money, Order, OrderId, OrderNumber, OrderStatus,
+    ShippingAddress,
+};
+use bookstore_order_management::domain::port::{
+    InventoryRepository, OrderRepository, RepositoryError,
+};
+use bookstore_order_management::domain::purchase_policy::{
+    PurchaseCheck, PurchaseLimits, PurchasePolicy,
+};
+use bookstore_order_management::domain::shipping_fee::ShippingFeePolicy;
+use chrono::{TimeDelta, Utc};
+use sqlx::{Pool, Sqlite};
+
+fn shipping_address() -> ShippingAddress {
+    ShippingAddress::new(
+        "1500001".to_string(),
+        "東京都".to_string(),
+        "渋谷区".to_string(),
+        "神宮前1-1-1".to_string(),
+        None,
+    )
+    .unwrap()
+}
+
+/// 作成日時をずらす（MySQLのテストのINTERVALの代わりに、RFC3339の文字列で書き込む）
+async fn set_created_at(pool: &Pool<Sqlite>, order_id: OrderId, ago: TimeDelta) {
+    sqlx::query("UPDATE orders SET created_at = ? WHERE id = ?")
+        .bind(Utc::now() - ago)
+        .bind(order_id.to_string())
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_order_round_trip() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteOrderRepository::new(pool);
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    let book_id = BookId::new();
+    order.add_book(book_id, 2, Money::jpy(1500)).unwrap();
+    let attributes = vec![
+        LineAttribute::new("signed_copy".to_string(), "requested".to_string()).unwrap(),
+        LineAttribute::new("gift_wrap".to_string(), "青い包装紙".to_string()).unwrap(),
+    ];
+    order
+        .set_line_attributes(book_id, attributes.clone())
+        .unwrap();
+    order.set_shipping_address(shipping_address()).unwrap();
+    repository.save(&mut order).await.unwrap();
+
+    order.confirm().unwrap();
+    order.snapshot_totals(&ShippingFeePolicy::default());
+    repository.save(&mut order).await.unwrap();
+
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(found.status(), OrderStatus::Confirmed);
+    assert_eq!(found.subtotal(), Money::jpy(3000));
+    assert_eq!(found.shipping_address(), order.shipping_address());
+    assert_eq!(found.order_lines()[0].attributes(), attributes.as_slice());
+    assert_eq!(found.confirmed_at(), order.confirmed_at());
+    assert_eq!(found.confirmed_totals(), order.confirmed_totals());
+    assert_eq!(found.saga_correlation_id(), order.saga_correlation_id());
+    assert_eq!(found.version(), 2);
+
+    let token = order.tracking_token().cloned().unwrap();
+    let found = repository
+        .find_by_tracking_token(&token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id(), order.id());
+    assert!(repository
+        .find_by_id(OrderId::new())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_stale_order_save_is_rejected_with_concurrency_conflict() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteOrderRepository::new(pool);
+
+    let mut order = Order::new(OrderId::new(), CustomerId::new());
+    order.add_book(BookId::new(), 1, Money::jpy(1500)).unwrap();
+    order.set_shipping_address(shipping_address()).unwrap();
+    repository.save(&mut order).await.unwrap();
+
+    // 同じバージョンを読み込んだ2つの操作のうち、後から保存した方は競合する
+    let mut confirmed = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    let mut cancelled = confirmed.clone();
+    confirmed.confirm().unwrap();
+    repository.save(&mut confirmed).await.unwrap();
+    cancelled.cancel().unwrap();
+    assert!(matches!(
+        repository.save(&mut cancelled).await,
+        Err(RepositoryError::ConcurrencyConflict(_))
+    ));
+
+    // 同じIDの未保存の注文の追加も競合になる
+    let mut duplicate = Order::new(order.id(), order.customer_id());
+    assert!(matches!(
+        repository.save(&mut duplicate).await,
+        Err(RepositoryError::ConcurrencyConflict(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_duplicate_order_number_is_a_save_failure_not_a_conflict() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteOrderRepository::new(pool);
+    let order_number = OrderNumber::new("BK", 2024, 1, 6).unwrap();
+
+    let mut order =
+        Order::new(OrderId::new(), CustomerId::new()).with_order_number(Some(order_number.clone()));
+    repository.save(&mut order).await.unwrap();
+
+    // 注文IDの重複ではないため、再読み込みで解決する競合ではなく保存の失敗になる
+    let mut duplicate =
+        Order::new(OrderId::new(), CustomerId::new()).with_order_number(Some(order_number));
+    assert!(matches!(
+        repository.save(&mut duplicate).await,
+        Err(RepositoryError::OperationFailed(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_orders_are_listed_and_counted_by_customer() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteOrderRepository::new(pool.clone());
+    let customer_id = CustomerId::new();
+    let book_id = BookId::new();
+
+    let mut saved = Vec::new();
+    for (days_ago, quantity) in [(3, 1), (2, 2), (1, 4)] {
+        let mut order = Order::new(OrderId::new(), customer_id);
+        order.add_book(book_id, quantity, Money::jpy(1500)).unwrap();
+        repository.save(&mut order).await.unwrap();
+        set_created_at(&pool, order.id(), TimeDelta::days(days_ago)).await;
+        saved.push(order);
+    }
+    saved[0].cancel().unwrap();
+    repository.save(&mut saved[0]).await.unwrap();
+
+    // 作成日時の降順
+    let orders = repository.find_by_customer(customer_id).await.unwrap();
+    let order_ids: Vec<OrderId> = orders.iter().map(Order::id).collect();
+    assert_eq!(order_ids, vec![saved[2].id(), saved[1].id(), saved[0].id()]);
+
+    // 注文IDの昇順で少しずつ取得する
+    let mut sorted_ids: Vec<OrderId> = saved.iter().map(Order::id).collect();
+    sorted_ids.sort_by_key(|id| id.to_string());
+    let first_page = repository
+        .find_by_customer_page(customer_id, None, 2)
+        .await
+        .unwrap();
+    let second_page = repository
+        .find_by_customer_page(customer_id, Some(first_page[1].id()), 2)
+        .await
+        .unwrap();
+    let paged_ids: Vec<OrderId> = first_page
+        .iter()
+        .chain(&second_page)
+        .map(Order::id)
+        .collect();
+    assert_eq!(paged_ids, sorted_ids);
+
+    assert_eq!(
+        repository
+            .count_open_orders_by_customer(customer_id)
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        repository
+            .count_orders_by_customer_and_status(customer_id, OrderStatus::Cancelled)
+            .await
+            .unwrap(),
+        1
+    );
+    // キャンセル済みと、集計開始より前に作成された注文は含めない
+    assert_eq!(
+        repository
+            .sum_book_quantity_by_customer_since(
+                customer_id,
+                book_id,
+                Utc::now() - TimeDelta::hours(36)
+            )
+            .await
+            .unwrap(),
+        4
+    );
+    let created_before = repository
+        .find_by_status_created_before(OrderStatus::Pending, Utc::now() - TimeDelta::hours(36))
+        .await
+        .unwrap();
+    assert_eq!(created_before.len(), 1);
+    assert_eq!(created_before[0].id(), saved[1].id());
+}
+
+#[tokio::test]
+async fn test_similar_orders_match_customer_lines_and_creation_time() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteOrderRepository::new(pool.clone());
+    let customer_id = CustomerId::new();
+    let book_id = BookId::new();
+    let other_book_id = BookId::new();
+
+    let mut saved = Vec::new();
+    for (customer_id, lines) in [
+        (customer_id, vec![(book_id, 1), (other_book_id, 2)]),
+        (customer_id, vec![(other_book_id, 2), (book_id, 1)]),
+        (customer_id, vec![(other_book_id, 2), (book_id, 1)]),
+        (customer_id, vec![(book_id, 1)]),
+        (CustomerId::new(), vec![(book_id, 1), (other_book_id, 2)]),
+    ] {
+        let mut order = Order::new(OrderId::new(), customer_id);
+        for (book_id, quantity) in lines {
+            order.add_book(book_id, quantity, Money::jpy(1500)).unwrap();
+        }
+        repository.save(&mut order).await.unwrap();
+        saved.push(order.id());
+    }
+    // 3件目は2時間前に作成されたことにする
+    set_created_at(&pool, saved[2], TimeDelta::hours(2)).await;
+
+    let similar = repository
+        .find_similar_orders(saved[0], TimeDelta::minutes(30))
+        .await
+        .unwrap();
+    let similar_ids: Vec<OrderId> = similar.iter().map(Order::id).collect();
+    assert_eq!(similar_ids, vec![saved[1]]);
+
+    let similar = repository
+        .find_similar_orders(saved[0], TimeDelta::hours(3))
+        .await
+        .unwrap();
+    let similar_ids: Vec<OrderId> = similar.iter().map(Order::id).collect();
+    assert_eq!(similar_ids, vec![saved[2], saved[1]]);
+}
+
+#[tokio::test]
+async fn test_daily_book_demand_sums_confirmed_orders_by_date() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteOrderRepository::new(pool.clone());
+    let book_id = BookId::new();
+
+    let mut saved = Vec::new();
+    for quantity in [2, 3, 4, 5] {
+        let mut order = Order::new(OrderId::new(), CustomerId::new());
+        order.add_book(book_id, quantity, Money::jpy(1500)).unwrap();
+        order.set_shipping_address(shipping_address()).unwrap();
+        order.confirm().unwrap();
+        repository.save(&mut order).await.unwrap();
+        saved.push(order);
+    }
+    // 2件目は2日前、4件目は10日前に確定したことにする
+    for (order, days) in [(&saved[1], 2), (&saved[3], 10)] {
+        sqlx::query("UPDATE orders SET confirmed_at = ? WHERE id = ?")
+            .bind(order.confirmed_at().unwrap() - TimeDelta::days(days))
+            .bind(order.id().to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    // 3件目はキャンセルされたため需要に含めない
+    saved[2].cancel().unwrap();
+    repository.save(&mut saved[2]).await.unwrap();
+
+    let today = Utc::now().date_naive();
+    let demand = repository
+        .sum_daily_book_demand_since(book_id, Utc::now() - TimeDelta::days(5))
+        .await
+        .unwrap();
+    assert_eq!(
+        demand.into_iter().collect::<Vec<_>>(),
+        vec![(today - TimeDelta::days(2), 3), (today, 2)]
+    );
+}
+
+#[tokio::test]
+async fn test_save_within_purchase_limits_counts_orders_in_window() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteOrderRepository::new(pool.clone());
+    let policy = PurchasePolicy::new(PurchaseLimits {
+        max_quantity_per_book_per_day: Some(3),
+        max_open_orders_per_customer: Some(2),
+    });
+    let customer_id = CustomerId::new();
+    let book_id = BookId::new();
+
+    // 集計期間の開始直前に作成された注文は数えない
+    let mut old_order = Order::new(OrderId::new(), customer_id);
+    old_order.add_book(book_id, 3, Money::jpy(1500)).unwrap();
+    repository.save(&mut old_order).await.unwrap();
+    set_created_at(&pool, old_order.id(), TimeDelta::hours(25)).await;
+
+    let mut order = Order::new(OrderId::new(), customer_id);
+    order.add_book(book_id, 3, Money::jpy(1500)).unwrap();
+    let check = policy.book_quantity_check(book_id, 3, Utc::now()).unwrap();
+    repository
+        .save_within_purchase_limits(&mut order, &policy, check)
+        .await
+        .unwrap();
+
+    // 上限を超える追加は保存しない
+    order.add_book(book_id, 1, Money::jpy(1500)).unwrap();
+    let check = policy.book_quantity_check(book_id, 1, Utc::now()).unwrap();
+    assert!(matches!(
+        repository
+            .save_within_purchase_limits(&mut order, &policy, check)
+            .await,
+        Err(RepositoryError::PurchaseLimitExceeded(
+            DomainError::PurchaseQuantityLimitExceeded(_)
+        ))
+    ));
+    let found = repository.find_by_id(order.id()).await.unwrap().unwrap();
+    assert_eq!(found.order_lines()[0].quantity(), 3);
+
+    // 未完了の注文が上限に達している場合は新しい注文を保存しない
+    let mut new_order = Order::new(OrderId::new(), customer_id);
+    assert!(matches!(
+        repository
+            .save_within_purchase_limits(&mut new_order, &policy, PurchaseCheck::OpenOrder)
+            .await,
+        Err(RepositoryError::PurchaseLimitExceeded(
+            DomainError::OpenOrderLimitExceeded(_)
+        ))
+    ));
+    assert!(repository
+        .find_by_id(new_order.id())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_inventory_changes_are_recorded_as_movements() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteInventoryRepository::new(pool.clone());
+    let book_id = BookId::new();
+    let other_book_id = BookId::new();
+
+    repository.save(&Inventory::new(book_id, 5)).await.unwrap();
+    repository
+        .save(&Inventory::new(other_book_id, 1))
+        .await
+        .unwrap();
+    let mut inventory = repository.find_by_book_id(book_id).await.unwrap().unwrap();
+    inventory.reserve(2).unwrap();
+    repository.save(&inventory).await.unwrap();
+    // 在庫数が変わらない保存は記録しない
+    repository.save(&inventory).await.unwrap();
+
+    let snapshot = repository.take_snapshot(Utc::now()).await.unwrap();
+    assert_eq!(snapshot.version, 3);
+    assert_eq!(snapshot.entries.len(), 2);
+    assert!(snapshot
+        .entries
+        .iter()
+        .any(|entry| entry.book_id == book_id && entry.quantity_on_hand == 3));
+
+    let movements = repository.find_movements_since(1, 10).await.unwrap();
+    let deltas: Vec<(u64, i64, u32)> = movements
+        .iter()
+        .map(|movement| {
+            (
+                movement.version,
+                movement.quantity_delta,
+                movement.quantity_on_hand,
+            )
+        })
+        .collect();
+    assert_eq!(deltas, vec![(2, 1, 1), (3, -2, 3)]);
+
+    let low_stock = repository.find_by_max_quantity(3).await.unwrap();
+    assert_eq!(low_stock.len(), 2);
+    assert!(repository.find_ledger_drifts().await.unwrap().is_empty());
+    assert!(!repository.freeze_ledger_drift(book_id).await.unwrap());
+
+    // 在庫変動ログを経由しない変更は食い違いとして検出し、凍結する（在庫変動ログは補正しない）
+    sqlx::query("UPDATE inventories SET quantity_on_hand = 6 WHERE book_id = ?")
+        .bind(book_id.to_string())
+        .execute(&pool)
+        .await
+        .unwrap();
+    let drifts = repository.find_ledger_drifts().await.unwrap();
+    assert_eq!(drifts.len(), 1);
+    assert_eq!(drifts[0].book_id, book_id);
+    assert_eq!(drifts[0].quantity_on_hand, 6);
+    assert_eq!(drifts[0].ledger_quantity, 3);
+    assert!(repository.freeze_ledger_drift(book_id).await.unwrap());
+    assert!(repository
+        .find_by_book_id(book_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .is_frozen());
+    assert_eq!(repository.find_ledger_drifts().await.unwrap(), drifts);
+    assert!(repository
+        .find_movements_since(3, 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_stock_valuations_join_catalog_prices() {
+    let pool = SqliteDatabase::in_memory().await.unwrap();
+    let repository = SqliteInventoryRepository::new(pool.clone());
+
+    let priced_book = BookId::new();
+    let unpriced_book = BookId::new();
+    repository
+        .save(&Inventory::new(priced_book, 3))
+        .await
+        .unwrap();
+    repository
+        .save(&Inventory::new(unpriced_book, 2))
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO book_prices (book_id, unit_price_amount, unit_price_currency) VALUES (?, ?, ?)",
+    )
+    .bind(priced_book.to_string())
+    .bind(1800)
+    .bind("JPY")
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let valuations = repository.find_stock_valuations().await.unwrap();
+    assert_eq!(valuations.len(), 2);
+    let priced = valuations
+        .iter()
+        .find(|valuation| valuation.book_id == priced_book)
+        .unwrap();
+    assert_eq!(priced.quantity_on_hand, 3);
+    assert_eq!(priced.unit_price, Some(Money::jpy(1800)));
+    let unpriced = valuations
+        .iter()
+        .find(|valuation| valuation.book_id == unpriced_book)
+        .unwrap();
+    assert_eq!(unpriced.unit_price, None);
+}